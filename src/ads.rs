@@ -0,0 +1,198 @@
+//! NTFS alternate data streams
+//!
+//! Files on NTFS can carry named streams next to their contents. The common one
+//! is `Zone.Identifier`, the "downloaded from the internet" mark that makes
+//! Windows block or warn about a file; others are left by browsers, Office or
+//! malware. Streams don't show up in a file's length, so with
+//! `performance.scan_ads` on, [`crate::size`] and the Results list add them to
+//! reported sizes (one extra call per file, so it's off by default).
+//!
+//! [`unblock`] removes `Zone.Identifier` streams in bulk (`wole optimize --unblock`).
+//! Streams only exist on Windows; elsewhere everything here reports none.
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Stream Windows attaches to downloaded files
+pub const ZONE_IDENTIFIER: &str = "Zone.Identifier";
+
+static SCAN_ADS: AtomicBool = AtomicBool::new(false);
+
+/// Include alternate streams in reported sizes (`performance.scan_ads`)
+pub fn set_enabled(enabled: bool) {
+    SCAN_ADS.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether reported sizes include alternate streams
+pub fn enabled() -> bool {
+    SCAN_ADS.load(Ordering::Relaxed)
+}
+
+/// A named stream of a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stream {
+    /// Stream name without the `:` and `:$DATA` decoration, e.g. "Zone.Identifier"
+    pub name: String,
+    pub size: u64,
+}
+
+/// Strip the `:NAME:$DATA` decoration FindFirstStreamW returns
+///
+/// The unnamed main stream (`::$DATA`) yields None.
+#[cfg(any(windows, test))]
+fn stream_name(raw: &str) -> Option<String> {
+    let name = raw.strip_prefix(':')?;
+    let name = name.strip_suffix(":$DATA").unwrap_or(name);
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Alternate streams of `path` (empty for folders without streams, or off NTFS)
+#[cfg(windows)]
+pub fn streams(path: &Path) -> Vec<Stream> {
+    #[repr(C)]
+    struct Win32FindStreamData {
+        stream_size: i64,
+        stream_name: [u16; 296],
+    }
+    extern "system" {
+        fn FindFirstStreamW(
+            lpFileName: *const u16,
+            InfoLevel: i32,
+            lpFindStreamData: *mut Win32FindStreamData,
+            dwFlags: u32,
+        ) -> isize;
+        fn FindNextStreamW(hFindStream: isize, lpFindStreamData: *mut Win32FindStreamData) -> i32;
+        fn FindClose(hFindFile: isize) -> i32;
+    }
+    const INVALID_HANDLE_VALUE: isize = -1;
+
+    let wide: Vec<u16> = crate::utils::long_path_if_needed(path)
+        .as_os_str()
+        .to_string_lossy()
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .collect();
+
+    let mut found = Vec::new();
+    unsafe {
+        let mut data = Win32FindStreamData {
+            stream_size: 0,
+            stream_name: [0; 296],
+        };
+        // FindStreamInfoStandard = 0
+        let handle = FindFirstStreamW(wide.as_ptr(), 0, &mut data, 0);
+        if handle == INVALID_HANDLE_VALUE {
+            return found;
+        }
+        loop {
+            let len = data
+                .stream_name
+                .iter()
+                .position(|&c| c == 0)
+                .unwrap_or(data.stream_name.len());
+            let raw = String::from_utf16_lossy(&data.stream_name[..len]);
+            if let Some(name) = stream_name(&raw) {
+                found.push(Stream {
+                    name,
+                    size: data.stream_size.max(0) as u64,
+                });
+            }
+            if FindNextStreamW(handle, &mut data) == 0 {
+                break;
+            }
+        }
+        FindClose(handle);
+    }
+    found
+}
+
+#[cfg(not(windows))]
+pub fn streams(_path: &Path) -> Vec<Stream> {
+    Vec::new()
+}
+
+/// Bytes in the alternate streams of `path`, if [`enabled`]
+pub fn stream_bytes(path: &Path) -> u64 {
+    if !enabled() {
+        return 0;
+    }
+    streams(path).iter().map(|s| s.size).sum()
+}
+
+/// Remove the `Zone.Identifier` stream of `path`; false if it had none
+pub fn remove_zone_identifier(path: &Path) -> Result<bool> {
+    if !streams(path).iter().any(|s| s.name == ZONE_IDENTIFIER) {
+        return Ok(false);
+    }
+    let mut stream = path.as_os_str().to_owned();
+    stream.push(":");
+    stream.push(ZONE_IDENTIFIER);
+    crate::utils::safe_remove_file(Path::new(&stream))?;
+    Ok(true)
+}
+
+/// What [`unblock`] found and changed
+#[derive(Debug, Default)]
+pub struct UnblockSummary {
+    /// Files carrying a `Zone.Identifier` stream
+    pub blocked: Vec<PathBuf>,
+    pub unblocked: usize,
+    /// Files whose stream could not be removed, with the error
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+/// Remove `Zone.Identifier` from every file under `roots` (files are taken as is)
+///
+/// With `dry_run` (or in audit mode) blocked files are only listed.
+pub fn unblock(roots: &[PathBuf], dry_run: bool) -> UnblockSummary {
+    let dry_run = dry_run || crate::audit::is_enabled();
+    let mut summary = UnblockSummary::default();
+    for root in roots {
+        for entry in walkdir::WalkDir::new(root)
+            .follow_links(false)
+            .into_iter()
+            .flatten()
+            .filter(|e| e.file_type().is_file())
+        {
+            let path = entry.path();
+            if !streams(path).iter().any(|s| s.name == ZONE_IDENTIFIER) {
+                continue;
+            }
+            summary.blocked.push(path.to_path_buf());
+            if dry_run {
+                continue;
+            }
+            match remove_zone_identifier(path) {
+                Ok(_) => summary.unblocked += 1,
+                Err(e) => summary.failed.push((path.to_path_buf(), e.to_string())),
+            }
+        }
+    }
+    if crate::audit::is_enabled() && !summary.blocked.is_empty() {
+        crate::audit::note(&format!(
+            "remove Zone.Identifier from {} files",
+            summary.blocked.len()
+        ));
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_name() {
+        assert_eq!(stream_name("::$DATA"), None);
+        assert_eq!(
+            stream_name(":Zone.Identifier:$DATA"),
+            Some("Zone.Identifier".to_string())
+        );
+        assert_eq!(
+            stream_name(":com.dropbox.attrs:$DATA"),
+            Some("com.dropbox.attrs".to_string())
+        );
+        assert_eq!(stream_name("no-colon"), None);
+    }
+}
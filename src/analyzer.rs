@@ -6,6 +6,7 @@ use crate::output::{CategoryResult, OutputMode};
 use crate::progress;
 use anyhow::Result;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
@@ -366,6 +367,263 @@ pub fn group_by_category(files: &[CleanableFile]) -> HashMap<Category, Vec<&Clea
     groups
 }
 
+/// Extensions listed by name in [`Breakdown::by_extension`]; the rest are "other"
+const TOP_EXTENSIONS: usize = 15;
+
+/// Size buckets (upper bound in bytes, label), smallest first
+const SIZE_BUCKETS: [(u64, &str); 5] = [
+    (1024 * 1024, "< 1 MB"),
+    (10 * 1024 * 1024, "1-10 MB"),
+    (100 * 1024 * 1024, "10-100 MB"),
+    (1024 * 1024 * 1024, "100 MB-1 GB"),
+    (u64::MAX, ">= 1 GB"),
+];
+
+/// Age buckets by last modification (upper bound in days, label), newest first
+const AGE_BUCKETS: [(u64, &str); 6] = [
+    (7, "< 1 week"),
+    (30, "1 week-1 month"),
+    (180, "1-6 months"),
+    (365, "6-12 months"),
+    (3 * 365, "1-3 years"),
+    (u64::MAX, "> 3 years"),
+];
+
+/// Files and bytes in one bar of a distribution
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bucket {
+    pub label: String,
+    pub files: u64,
+    pub bytes: u64,
+}
+
+/// Distributions of the files under a root
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Breakdown {
+    pub root: PathBuf,
+    pub total_files: u64,
+    pub total_bytes: u64,
+    /// Largest extensions first, then "other"
+    pub by_extension: Vec<Bucket>,
+    /// Smallest files first
+    pub by_size: Vec<Bucket>,
+    /// Newest files first
+    pub by_age: Vec<Bucket>,
+}
+
+impl Breakdown {
+    /// No files under `root`
+    pub fn empty(root: &Path) -> Self {
+        Tally::default().finish(root)
+    }
+
+    /// The distributions with their names, in display order
+    pub fn distributions(&self) -> [(&'static str, &[Bucket]); 3] {
+        [
+            ("extension", &self.by_extension),
+            ("size", &self.by_size),
+            ("age", &self.by_age),
+        ]
+    }
+
+    /// One row per bucket: `distribution,bucket,files,bytes`
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("distribution,bucket,files,bytes\n");
+        for (name, buckets) in self.distributions() {
+            for bucket in buckets {
+                csv.push_str(&format!(
+                    "{},{},{},{}\n",
+                    name,
+                    crate::disk_usage::csv_field(&bucket.label),
+                    bucket.files,
+                    bucket.bytes
+                ));
+            }
+        }
+        csv
+    }
+
+    /// Write to `path` as JSON, or CSV for any other format
+    pub fn write_export(&self, path: &Path, format: crate::disk_usage::ExportFormat) -> Result<()> {
+        use anyhow::Context;
+
+        let content = match format {
+            crate::disk_usage::ExportFormat::Json => {
+                serde_json::to_string_pretty(self).context("Failed to serialize breakdown")?
+            }
+            _ => self.to_csv(),
+        };
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write export to {}", path.display()))
+    }
+}
+
+/// Running totals while walking
+#[derive(Default)]
+struct Tally {
+    files: u64,
+    bytes: u64,
+    extensions: HashMap<String, (u64, u64)>,
+    sizes: [(u64, u64); SIZE_BUCKETS.len()],
+    ages: [(u64, u64); AGE_BUCKETS.len()],
+}
+
+impl Tally {
+    fn add(&mut self, path: &Path, size: u64, age_days: Option<u64>) {
+        self.files += 1;
+        self.bytes += size;
+
+        let extension = path
+            .extension()
+            .map(|ext| format!(".{}", ext.to_string_lossy().to_lowercase()))
+            .unwrap_or_else(|| "(none)".to_string());
+        let entry = self.extensions.entry(extension).or_default();
+        entry.0 += 1;
+        entry.1 += size;
+
+        let bucket = SIZE_BUCKETS
+            .iter()
+            .position(|(limit, _)| size < *limit)
+            .unwrap_or(SIZE_BUCKETS.len() - 1);
+        self.sizes[bucket].0 += 1;
+        self.sizes[bucket].1 += size;
+
+        // Files with unreadable times count as new
+        let age_days = age_days.unwrap_or(0);
+        let bucket = AGE_BUCKETS
+            .iter()
+            .position(|(limit, _)| age_days < *limit)
+            .unwrap_or(AGE_BUCKETS.len() - 1);
+        self.ages[bucket].0 += 1;
+        self.ages[bucket].1 += size;
+    }
+
+    fn finish(self, root: &Path) -> Breakdown {
+        let bucket = |label: &str, (files, bytes): (u64, u64)| Bucket {
+            label: label.to_string(),
+            files,
+            bytes,
+        };
+
+        let mut extensions: Vec<Bucket> = self
+            .extensions
+            .into_iter()
+            .map(|(label, counts)| bucket(&label, counts))
+            .collect();
+        extensions.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.label.cmp(&b.label)));
+        if extensions.len() > TOP_EXTENSIONS {
+            let other = extensions.split_off(TOP_EXTENSIONS).into_iter().fold(
+                bucket("other", (0, 0)),
+                |mut other, b| {
+                    other.files += b.files;
+                    other.bytes += b.bytes;
+                    other
+                },
+            );
+            extensions.push(other);
+        }
+
+        Breakdown {
+            root: root.to_path_buf(),
+            total_files: self.files,
+            total_bytes: self.bytes,
+            by_extension: extensions,
+            by_size: SIZE_BUCKETS
+                .iter()
+                .zip(self.sizes)
+                .map(|((_, label), counts)| bucket(label, counts))
+                .collect(),
+            by_age: AGE_BUCKETS
+                .iter()
+                .zip(self.ages)
+                .map(|((_, label), counts)| bucket(label, counts))
+                .collect(),
+        }
+    }
+}
+
+/// Walk every file under `root` and tally it by extension, size and age
+///
+/// Excluded folders, symlinks and junctions are skipped, like in category
+/// scans. Ages are measured from the last modification.
+pub fn breakdown(
+    root: &Path,
+    config: &Config,
+    cancel: Option<&crate::cancel::CancellationToken>,
+) -> Result<Breakdown> {
+    use jwalk::WalkDir;
+    use std::sync::Arc;
+
+    let config_arc = Arc::new(config.clone());
+    let walk = WalkDir::new(root)
+        .follow_links(false)
+        .parallelism(crate::drive::walk_parallelism(
+            std::time::Duration::from_secs(1),
+        ))
+        .process_read_dir(move |_depth, _path, _state, children| {
+            children.retain(|entry| match entry {
+                Ok(e) => {
+                    let path = e.path();
+                    !e.file_type().is_symlink()
+                        && !(e.file_type().is_dir()
+                            && (crate::utils::is_windows_reparse_point(&path)
+                                || config_arc.is_excluded(&path)))
+                }
+                Err(_) => true,
+            });
+        });
+
+    let now = std::time::SystemTime::now();
+    let mut tally = Tally::default();
+    for entry in walk.into_iter().flatten() {
+        if cancel.is_some_and(|c| c.is_cancelled()) {
+            return Err(crate::cancel::Cancelled.into());
+        }
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let age_days = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok())
+            .map(|age| age.as_secs() / 86400);
+        tally.add(&entry.path(), metadata.len(), age_days);
+    }
+    Ok(tally.finish(root))
+}
+
+/// Print the distributions as bar charts
+pub fn print_breakdown(breakdown: &Breakdown) {
+    const BAR_WIDTH: usize = 30;
+
+    println!(
+        "{} files, {} under {}",
+        format_number(breakdown.total_files),
+        format_size(breakdown.total_bytes),
+        breakdown.root.display()
+    );
+    for (name, buckets) in breakdown.distributions() {
+        println!();
+        println!("By {}", name);
+        println!("{}", "-".repeat(60));
+        let largest = buckets.iter().map(|b| b.bytes).max().unwrap_or(0).max(1);
+        for bucket in buckets {
+            let filled = (bucket.bytes as u128 * BAR_WIDTH as u128 / largest as u128) as usize;
+            println!(
+                "  {:<14} {:<width$} {:>10}  {} files",
+                bucket.label,
+                "█".repeat(filled),
+                format_size(bucket.bytes),
+                format_number(bucket.files),
+                width = BAR_WIDTH
+            );
+        }
+    }
+}
+
 // Helper functions
 
 fn format_number(n: u64) -> String {
@@ -797,3 +1055,28 @@ fn convert_category_result(
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tally_buckets() {
+        let mut tally = Tally::default();
+        tally.add(Path::new("a/movie.MKV"), 2 * 1024 * 1024 * 1024, Some(400));
+        tally.add(Path::new("a/notes.txt"), 100, Some(1));
+        tally.add(Path::new("a/README"), 5 * 1024 * 1024, None);
+        let breakdown = tally.finish(Path::new("a"));
+
+        assert_eq!(breakdown.total_files, 3);
+        assert_eq!(breakdown.by_extension[0].label, ".mkv");
+        assert!(breakdown.by_extension.iter().any(|b| b.label == "(none)"));
+        let files = |buckets: &[Bucket]| buckets.iter().map(|b| b.files).collect::<Vec<_>>();
+        assert_eq!(files(&breakdown.by_size), vec![1, 1, 0, 0, 1]);
+        assert_eq!(files(&breakdown.by_age), vec![2, 0, 0, 0, 1, 0]);
+        assert!(breakdown.to_csv().starts_with(
+            "distribution,bucket,files,bytes
+extension,.mkv,1,"
+        ));
+    }
+}
@@ -0,0 +1,926 @@
+//! High-level library API
+//!
+//! A small, stable facade over the scanner, cleaner and restore engines for
+//! frontends that embed wole (GUIs, scripts, services) without going through the
+//! CLI or TUI. Every result type is serde-serializable.
+//!
+//! ```no_run
+//! use wole::api::{Category, Selection, Sweeper};
+//!
+//! # fn main() -> anyhow::Result<()> {
+//! let sweeper = Sweeper::builder()
+//!     .root("C:\\Users\\me")
+//!     .categories([Category::Cache, Category::Temp, Category::Build])
+//!     .build()?;
+//!
+//! let report = sweeper.scan()?;
+//! println!("{} reclaimable", bytesize::to_string(report.total_bytes, false));
+//!
+//! let cleaned = sweeper.clean(&Selection::all(&report))?;
+//! if let Some(session) = cleaned.session {
+//!     sweeper.restore(&session)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::cancel::CancellationToken;
+use crate::cli::ScanOptions;
+use crate::config::Config;
+use crate::output::{CategoryResult, OutputMode, ScanResults};
+use crate::plugin::{PluginCategory, PluginCategoryResult, PluginItem};
+pub use crate::users::ScanScope;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Built-in scan categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    Cache,
+    AppCache,
+    Temp,
+    Trash,
+    Build,
+    Downloads,
+    Large,
+    Old,
+    Applications,
+    Browser,
+    System,
+    Empty,
+    Duplicates,
+    WindowsUpdate,
+    EventLogs,
+    Thumbnails,
+    CrashDumps,
+    Logs,
+    GameCaches,
+    ToolchainCaches,
+    PythonEnvs,
+    MlModels,
+    Email,
+    CreativeCaches,
+    DeviceBackups,
+    GitIgnored,
+}
+
+impl Category {
+    /// Every built-in category
+    pub const ALL: [Category; 26] = [
+        Category::Cache,
+        Category::AppCache,
+        Category::Temp,
+        Category::Trash,
+        Category::Build,
+        Category::Downloads,
+        Category::Large,
+        Category::Old,
+        Category::Applications,
+        Category::Browser,
+        Category::System,
+        Category::Empty,
+        Category::Duplicates,
+        Category::WindowsUpdate,
+        Category::EventLogs,
+        Category::Thumbnails,
+        Category::CrashDumps,
+        Category::Logs,
+        Category::GameCaches,
+        Category::ToolchainCaches,
+        Category::PythonEnvs,
+        Category::MlModels,
+        Category::Email,
+        Category::CreativeCaches,
+        Category::DeviceBackups,
+        Category::GitIgnored,
+    ];
+
+    /// Categories that are safe to clean without review (what `wole clean` picks by default)
+    pub const SAFE: [Category; 5] = [
+        Category::Cache,
+        Category::AppCache,
+        Category::Temp,
+        Category::Trash,
+        Category::Build,
+    ];
+
+    /// Stable identifier used in reports ("app_cache", "windows_update", ...)
+    pub fn id(self) -> &'static str {
+        match self {
+            Category::Cache => "cache",
+            Category::AppCache => "app_cache",
+            Category::Temp => "temp",
+            Category::Trash => "trash",
+            Category::Build => "build",
+            Category::Downloads => "downloads",
+            Category::Large => "large",
+            Category::Old => "old",
+            Category::Applications => "applications",
+            Category::Browser => "browser",
+            Category::System => "system",
+            Category::Empty => "empty",
+            Category::Duplicates => "duplicates",
+            Category::WindowsUpdate => "windows_update",
+            Category::EventLogs => "event_logs",
+            Category::Thumbnails => "thumbnails",
+            Category::CrashDumps => "crash_dumps",
+            Category::Logs => "logs",
+            Category::GameCaches => "game_caches",
+            Category::ToolchainCaches => "toolchain_caches",
+            Category::PythonEnvs => "python_envs",
+            Category::MlModels => "ml_models",
+            Category::Email => "email",
+            Category::CreativeCaches => "creative_caches",
+            Category::DeviceBackups => "device_backups",
+            Category::GitIgnored => "git_ignored",
+        }
+    }
+
+    /// Display name, as shown by the CLI and TUI
+    pub fn name(self) -> &'static str {
+        match self {
+            Category::Cache => "Package Cache",
+            Category::AppCache => "Application Cache",
+            Category::Temp => "Temp Files",
+            Category::Trash => "Trash",
+            Category::Build => "Build Artifacts",
+            Category::Downloads => "Old Downloads",
+            Category::Large => "Large Files",
+            Category::Old => "Old Files",
+            Category::Applications => "Installed Applications",
+            Category::Browser => "Browser Cache",
+            Category::System => "System Cache",
+            Category::Empty => "Empty Folders",
+            Category::Duplicates => "Duplicates",
+            Category::WindowsUpdate => "Windows Update",
+            Category::EventLogs => "Event Logs",
+            Category::Thumbnails => "Thumbnail Cache",
+            Category::CrashDumps => "Crash Dumps",
+            Category::Logs => "Log Files",
+            Category::GameCaches => "Game Caches",
+            Category::ToolchainCaches => "Toolchain Caches",
+            Category::PythonEnvs => "Python Environments",
+            Category::MlModels => "ML Models",
+            Category::Email => "Email Data",
+            Category::CreativeCaches => "Creative App Caches",
+            Category::DeviceBackups => "Device Backups",
+            Category::GitIgnored => "Ignored Files (git)",
+        }
+    }
+
+    /// Whether items can be cleaned without review
+    pub fn is_safe(self) -> bool {
+        Self::SAFE.contains(&self)
+    }
+
+    /// Look up a category by its [`id`](Self::id)
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.id() == id)
+    }
+
+    pub(crate) fn result(self, results: &ScanResults) -> &CategoryResult {
+        match self {
+            Category::Cache => &results.cache,
+            Category::AppCache => &results.app_cache,
+            Category::Temp => &results.temp,
+            Category::Trash => &results.trash,
+            Category::Build => &results.build,
+            Category::Downloads => &results.downloads,
+            Category::Large => &results.large,
+            Category::Old => &results.old,
+            Category::Applications => &results.applications,
+            Category::Browser => &results.browser,
+            Category::System => &results.system,
+            Category::Empty => &results.empty,
+            Category::Duplicates => &results.duplicates,
+            Category::WindowsUpdate => &results.windows_update,
+            Category::EventLogs => &results.event_logs,
+            Category::Thumbnails => &results.thumbnails,
+            Category::CrashDumps => &results.crash_dumps,
+            Category::Logs => &results.logs,
+            Category::GameCaches => &results.game_caches,
+            Category::ToolchainCaches => &results.toolchain_caches,
+            Category::PythonEnvs => &results.python_envs,
+            Category::MlModels => &results.ml_models,
+            Category::Email => &results.email,
+            Category::CreativeCaches => &results.creative_caches,
+            Category::DeviceBackups => &results.device_backups,
+            Category::GitIgnored => &results.git_ignored,
+        }
+    }
+
+    pub(crate) fn result_mut(self, results: &mut ScanResults) -> &mut CategoryResult {
+        match self {
+            Category::Cache => &mut results.cache,
+            Category::AppCache => &mut results.app_cache,
+            Category::Temp => &mut results.temp,
+            Category::Trash => &mut results.trash,
+            Category::Build => &mut results.build,
+            Category::Downloads => &mut results.downloads,
+            Category::Large => &mut results.large,
+            Category::Old => &mut results.old,
+            Category::Applications => &mut results.applications,
+            Category::Browser => &mut results.browser,
+            Category::System => &mut results.system,
+            Category::Empty => &mut results.empty,
+            Category::Duplicates => &mut results.duplicates,
+            Category::WindowsUpdate => &mut results.windows_update,
+            Category::EventLogs => &mut results.event_logs,
+            Category::Thumbnails => &mut results.thumbnails,
+            Category::CrashDumps => &mut results.crash_dumps,
+            Category::Logs => &mut results.logs,
+            Category::GameCaches => &mut results.game_caches,
+            Category::ToolchainCaches => &mut results.toolchain_caches,
+            Category::PythonEnvs => &mut results.python_envs,
+            Category::MlModels => &mut results.ml_models,
+            Category::Email => &mut results.email,
+            Category::CreativeCaches => &mut results.creative_caches,
+            Category::DeviceBackups => &mut results.device_backups,
+            Category::GitIgnored => &mut results.git_ignored,
+        }
+    }
+
+    pub(crate) fn enable(self, options: &mut ScanOptions) {
+        let flag = match self {
+            Category::Cache => &mut options.cache,
+            Category::AppCache => &mut options.app_cache,
+            Category::Temp => &mut options.temp,
+            Category::Trash => &mut options.trash,
+            Category::Build => &mut options.build,
+            Category::Downloads => &mut options.downloads,
+            Category::Large => &mut options.large,
+            Category::Old => &mut options.old,
+            Category::Applications => &mut options.applications,
+            Category::Browser => &mut options.browser,
+            Category::System => &mut options.system,
+            Category::Empty => &mut options.empty,
+            Category::Duplicates => &mut options.duplicates,
+            Category::WindowsUpdate => &mut options.windows_update,
+            Category::EventLogs => &mut options.event_logs,
+            Category::Thumbnails => &mut options.thumbnails,
+            Category::CrashDumps => &mut options.crash_dumps,
+            Category::Logs => &mut options.logs,
+            Category::GameCaches => &mut options.game_caches,
+            Category::ToolchainCaches => &mut options.toolchain_caches,
+            Category::PythonEnvs => &mut options.python_envs,
+            Category::MlModels => &mut options.ml_models,
+            Category::Email => &mut options.email,
+            Category::CreativeCaches => &mut options.creative_caches,
+            Category::DeviceBackups => &mut options.device_backups,
+            Category::GitIgnored => &mut options.git_ignored,
+        };
+        *flag = true;
+    }
+}
+
+/// Scan options with every category off and the thresholds of `config`
+pub(crate) fn no_categories(config: &Config, min_size_bytes: u64, scope: ScanScope) -> ScanOptions {
+    ScanOptions {
+        cache: false,
+        app_cache: false,
+        temp: false,
+        trash: false,
+        build: false,
+        downloads: false,
+        large: false,
+        old: false,
+        applications: false,
+        browser: false,
+        system: false,
+        empty: false,
+        duplicates: false,
+        windows_update: false,
+        event_logs: false,
+        thumbnails: false,
+        crash_dumps: false,
+        logs: false,
+        game_caches: false,
+        toolchain_caches: false,
+        python_envs: false,
+        ml_models: false,
+        email: false,
+        creative_caches: false,
+        device_backups: false,
+        git_ignored: false,
+        project_age_days: config.thresholds.project_age_days,
+        min_age_days: config.thresholds.min_age_days,
+        min_size_bytes,
+        scope,
+    }
+}
+
+/// One category in a [`ScanReport`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryReport {
+    /// Built-in category, or None for plugin categories
+    pub category: Option<Category>,
+    /// Plugin that reported this category (plugin categories only)
+    pub plugin: Option<String>,
+    /// [`Category::id`] for built-ins, the plugin's own category id otherwise
+    pub id: String,
+    pub name: String,
+    pub safe: bool,
+    pub items: usize,
+    pub size_bytes: u64,
+    pub paths: Vec<PathBuf>,
+}
+
+/// Result of [`Sweeper::scan`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanReport {
+    pub root: PathBuf,
+    pub scanned_at: DateTime<Utc>,
+    /// Categories with at least one item, built-ins first
+    pub categories: Vec<CategoryReport>,
+    pub total_items: usize,
+    pub total_bytes: u64,
+}
+
+impl ScanReport {
+    fn from_results(root: &Path, results: &ScanResults) -> Self {
+        let mut categories: Vec<CategoryReport> = Category::ALL
+            .into_iter()
+            .filter_map(|category| {
+                let result = category.result(results);
+                (result.items > 0).then(|| CategoryReport {
+                    category: Some(category),
+                    plugin: None,
+                    id: category.id().to_string(),
+                    name: category.name().to_string(),
+                    safe: category.is_safe(),
+                    items: result.items,
+                    size_bytes: result.size_bytes,
+                    paths: result.paths.clone(),
+                })
+            })
+            .collect();
+        categories.extend(results.plugins.iter().map(|p| CategoryReport {
+            category: None,
+            plugin: Some(p.plugin.name.clone()),
+            id: p.category.id.clone(),
+            name: p.category.name.clone(),
+            safe: p.category.safe,
+            items: p.result.items,
+            size_bytes: p.result.size_bytes,
+            paths: p.result.paths.clone(),
+        }));
+
+        Self {
+            root: root.to_path_buf(),
+            scanned_at: Utc::now(),
+            total_items: categories.iter().map(|c| c.items).sum(),
+            total_bytes: categories.iter().map(|c| c.size_bytes).sum(),
+            categories,
+        }
+    }
+
+    /// Report for one built-in category, if it found anything
+    pub fn category(&self, category: Category) -> Option<&CategoryReport> {
+        self.categories
+            .iter()
+            .find(|c| c.category == Some(category))
+    }
+}
+
+/// Items chosen for [`Sweeper::clean`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Selection {
+    categories: Vec<CategoryReport>,
+}
+
+impl Selection {
+    /// Nothing selected
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Everything in the report
+    pub fn all(report: &ScanReport) -> Self {
+        Self {
+            categories: report.categories.clone(),
+        }
+    }
+
+    /// Only categories the report marks as safe
+    pub fn safe(report: &ScanReport) -> Self {
+        Self {
+            categories: report
+                .categories
+                .iter()
+                .filter(|c| c.safe)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Every item of the given built-in categories
+    pub fn categories(report: &ScanReport, categories: &[Category]) -> Self {
+        Self {
+            categories: report
+                .categories
+                .iter()
+                .filter(|c| c.category.is_some_and(|cat| categories.contains(&cat)))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Add some paths of a reported category.
+    ///
+    /// Paths not in the category are ignored. When only part of a category is
+    /// selected its size is estimated from the category's average item size.
+    pub fn add(&mut self, category: &CategoryReport, paths: &[PathBuf]) -> &mut Self {
+        let selected: Vec<PathBuf> = category
+            .paths
+            .iter()
+            .filter(|p| paths.contains(p))
+            .cloned()
+            .collect();
+        if selected.is_empty() {
+            return self;
+        }
+
+        let size_bytes = if selected.len() == category.paths.len() {
+            category.size_bytes
+        } else {
+            category.size_bytes / category.paths.len().max(1) as u64 * selected.len() as u64
+        };
+        self.categories.push(CategoryReport {
+            items: selected.len(),
+            size_bytes,
+            paths: selected,
+            ..category.clone()
+        });
+        self
+    }
+
+    /// Drop a path from the selection (e.g. a file the user unticked)
+    pub fn remove_path(&mut self, path: &Path) -> &mut Self {
+        for category in &mut self.categories {
+            if let Some(pos) = category.paths.iter().position(|p| p == path) {
+                let average = category.size_bytes / category.paths.len().max(1) as u64;
+                category.paths.remove(pos);
+                category.items = category.paths.len();
+                category.size_bytes = category.size_bytes.saturating_sub(average);
+            }
+        }
+        self.categories.retain(|c| !c.paths.is_empty());
+        self
+    }
+
+    /// Number of selected items
+    pub fn items(&self) -> usize {
+        self.categories.iter().map(|c| c.items).sum()
+    }
+
+    /// Estimated bytes selected
+    pub fn size_bytes(&self) -> u64 {
+        self.categories.iter().map(|c| c.size_bytes).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty()
+    }
+
+    fn to_results(&self) -> ScanResults {
+        let plugins = crate::plugin::plugins_dir()
+            .map(|dir| crate::plugin::discover_plugins(&dir))
+            .unwrap_or_default();
+
+        let mut results = ScanResults::default();
+        for selected in &self.categories {
+            if let Some(category) = selected.category {
+                let result = category.result_mut(&mut results);
+                result.items += selected.items;
+                result.size_bytes += selected.size_bytes;
+                result.paths.extend(selected.paths.iter().cloned());
+                continue;
+            }
+
+            // Plugin categories go back to the plugin that reported them
+            let Some(plugin) = plugins
+                .iter()
+                .find(|p| Some(&p.name) == selected.plugin.as_ref())
+            else {
+                continue;
+            };
+            let item_size = selected.size_bytes / selected.items.max(1) as u64;
+            results.plugins.push(PluginCategoryResult {
+                plugin: plugin.clone(),
+                category: PluginCategory {
+                    id: selected.id.clone(),
+                    name: selected.name.clone(),
+                    description: None,
+                    safe: selected.safe,
+                    items: selected
+                        .paths
+                        .iter()
+                        .map(|path| PluginItem {
+                            path: path.clone(),
+                            size_bytes: item_size,
+                        })
+                        .collect(),
+                },
+                result: CategoryResult {
+                    items: selected.items,
+                    size_bytes: selected.size_bytes,
+                    paths: selected.paths.clone(),
+                },
+            });
+        }
+        results
+    }
+}
+
+/// Result of [`Sweeper::clean`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CleanReport {
+    pub cleaned: u64,
+    pub cleaned_bytes: u64,
+    pub errors: u64,
+    pub dry_run: bool,
+    pub permanent: bool,
+    /// Stopped early through the sweeper's cancellation token
+    pub cancelled: bool,
+    /// Deletion session to pass to [`Sweeper::restore`] (None for dry runs)
+    pub session: Option<String>,
+}
+
+/// A past cleanup recorded in the deletion history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CleanSession {
+    /// Identifier accepted by [`Sweeper::restore`]
+    pub id: String,
+    pub started: DateTime<Utc>,
+    pub items: usize,
+    pub errors: usize,
+    pub bytes_cleaned: u64,
+    /// Items that went to the Recycle Bin and can be restored
+    pub restorable: usize,
+}
+
+/// Result of [`Sweeper::restore`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreReport {
+    pub restored: usize,
+    pub restored_bytes: u64,
+    pub errors: usize,
+    pub not_found: usize,
+    pub error_reasons: Vec<String>,
+    pub cancelled: bool,
+    /// Restored items that are missing or not the size they were deleted at
+    pub mismatches: Vec<crate::restore::RestoreMismatch>,
+}
+
+/// Builder for [`Sweeper`]
+#[derive(Debug, Clone, Default)]
+pub struct SweeperBuilder {
+    root: Option<PathBuf>,
+    categories: Vec<Category>,
+    config: Option<Config>,
+    project_age_days: Option<u64>,
+    min_age_days: Option<u64>,
+    min_size_bytes: Option<u64>,
+    exclusions: Vec<String>,
+    permanent: bool,
+    dry_run: bool,
+    use_cache: bool,
+    scope: ScanScope,
+    cancel: CancellationToken,
+}
+
+impl SweeperBuilder {
+    /// Directory to scan (default: the user's home directory)
+    pub fn root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    /// Categories to scan (default: [`Category::SAFE`])
+    pub fn categories(mut self, categories: impl IntoIterator<Item = Category>) -> Self {
+        self.categories = categories.into_iter().collect();
+        self
+    }
+
+    /// Use this config instead of loading `%APPDATA%\wole\config.toml`
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Build artifacts of projects untouched for this many days are reported
+    pub fn project_age_days(mut self, days: u64) -> Self {
+        self.project_age_days = Some(days);
+        self
+    }
+
+    /// Minimum age for old files and downloads
+    pub fn min_age_days(mut self, days: u64) -> Self {
+        self.min_age_days = Some(days);
+        self
+    }
+
+    /// Minimum size for large files
+    pub fn min_size_bytes(mut self, bytes: u64) -> Self {
+        self.min_size_bytes = Some(bytes);
+        self
+    }
+
+    /// Extra exclusion glob, on top of the configured ones
+    pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.exclusions.push(pattern.into());
+        self
+    }
+
+    /// Delete permanently instead of moving to the Recycle Bin
+    pub fn permanent(mut self, permanent: bool) -> Self {
+        self.permanent = permanent;
+        self
+    }
+
+    /// Report what would be cleaned without deleting anything
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Use the incremental scan cache (if enabled in config)
+    pub fn use_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
+
+    /// Also scan other users' cache/temp/downloads (`ScanScope::AllUsers` requires admin)
+    pub fn scope(mut self, scope: ScanScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Token that aborts a running scan, clean or restore from another thread
+    pub fn cancellation(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    pub fn build(self) -> Result<Sweeper> {
+        let root = match self.root {
+            Some(root) => root,
+            None => directories::UserDirs::new()
+                .context("Failed to get user directory")?
+                .home_dir()
+                .to_path_buf(),
+        };
+        if !root.exists() {
+            bail!("Scan root does not exist: {}", root.display());
+        }
+        if self.scope == ScanScope::AllUsers {
+            crate::users::ensure_elevated()?;
+        }
+
+        let mut config = self.config.unwrap_or_else(Config::load);
+        config.apply_cli_overrides(self.project_age_days, self.min_age_days, None);
+        config.exclusions.patterns.extend(self.exclusions);
+
+        let categories = if self.categories.is_empty() {
+            Category::SAFE.to_vec()
+        } else {
+            self.categories
+        };
+        let min_size_bytes = self
+            .min_size_bytes
+            .unwrap_or(config.thresholds.min_size_mb * 1024 * 1024);
+        let mut options = no_categories(&config, min_size_bytes, self.scope);
+        for category in categories {
+            category.enable(&mut options);
+        }
+
+        Ok(Sweeper {
+            root,
+            options,
+            config,
+            permanent: self.permanent,
+            dry_run: self.dry_run,
+            use_cache: self.use_cache,
+            cancel: self.cancel,
+        })
+    }
+}
+
+/// Scan, clean and restore with one set of options
+#[derive(Clone)]
+pub struct Sweeper {
+    root: PathBuf,
+    options: ScanOptions,
+    config: Config,
+    permanent: bool,
+    dry_run: bool,
+    use_cache: bool,
+    cancel: CancellationToken,
+}
+
+impl Sweeper {
+    pub fn builder() -> SweeperBuilder {
+        SweeperBuilder::default()
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Cancelling this token stops the current operation at the next file.
+    /// A cancelled scan returns a [`Cancelled`](crate::cancel::Cancelled) error;
+    /// clean and restore return what they got done with `cancelled` set.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancel
+    }
+
+    /// Scan the configured categories (and any plugins)
+    pub fn scan(&self) -> Result<ScanReport> {
+        let use_cache = self.use_cache && self.config.cache.enabled;
+        let (root, options, config) =
+            (self.root.clone(), self.options.clone(), self.config.clone());
+        // Progress events are not exposed here; they are dropped with the job
+        let job =
+            crate::scanner::Engine::global().submit(self.cancel.clone(), move |cancel, tx| {
+                let mut cache = if use_cache {
+                    crate::scan_cache::ScanCache::open().ok()
+                } else {
+                    None
+                };
+                crate::scanner::scan_all_with_progress(
+                    &root,
+                    options,
+                    &config,
+                    tx,
+                    cache.as_mut(),
+                    cancel,
+                    false,
+                )
+            });
+        let results = match job.wait() {
+            Some(results) => results?,
+            // Cancelled before it started, or the scan panicked
+            None => {
+                self.cancel.check()?;
+                bail!("Scan stopped unexpectedly");
+            }
+        };
+        Ok(ScanReport::from_results(&self.root, &results))
+    }
+
+    /// Clean the selected items without prompting.
+    ///
+    /// Items go to the Recycle Bin unless the sweeper was built with `permanent(true)`;
+    /// the returned session can be passed to [`restore`](Self::restore).
+    pub fn clean(&self, selection: &Selection) -> Result<CleanReport> {
+        crate::cleaner::apply_safety_settings(&self.config.safety);
+        crate::history::checksums::set_enabled(self.config.history.record_checksums);
+        let summary = crate::cleaner::clean_all(
+            &selection.to_results(),
+            true,
+            OutputMode::Quiet,
+            self.permanent,
+            self.dry_run,
+            &self.cancel,
+        )?;
+        Ok(CleanReport {
+            cleaned: summary.cleaned,
+            cleaned_bytes: summary.cleaned_bytes,
+            errors: summary.errors,
+            dry_run: self.dry_run,
+            permanent: self.permanent,
+            cancelled: summary.cancelled,
+            session: summary.log_path.as_deref().and_then(session_id),
+        })
+    }
+
+    /// Past cleanup sessions, newest first
+    pub fn sessions(&self) -> Result<Vec<CleanSession>> {
+        let mut sessions = Vec::new();
+        for path in crate::history::list_logs()? {
+            let (Some(id), Ok(log)) = (session_id(&path), crate::history::load_log(&path)) else {
+                continue;
+            };
+            sessions.push(CleanSession {
+                id,
+                started: log.session_start,
+                items: log.total_items,
+                errors: log.errors,
+                bytes_cleaned: log.total_bytes_cleaned,
+                restorable: log
+                    .records
+                    .iter()
+                    .filter(|r| r.success && !r.permanent)
+                    .count(),
+            });
+        }
+        Ok(sessions)
+    }
+
+    /// Move the items of a cleanup session back out of the Recycle Bin
+    pub fn restore(&self, session: &str) -> Result<RestoreReport> {
+        let path = crate::history::list_logs()?
+            .into_iter()
+            .find(|p| session_id(p).as_deref() == Some(session))
+            .with_context(|| format!("No deletion session named {}", session))?;
+        let log = crate::history::load_log(&path)?;
+        let result = crate::restore::restore_from_log_with_progress(
+            &log,
+            OutputMode::Quiet,
+            None,
+            &self.cancel,
+        )?;
+        Ok(RestoreReport {
+            restored: result.restored,
+            restored_bytes: result.restored_bytes,
+            errors: result.errors,
+            not_found: result.not_found,
+            error_reasons: result.error_reasons,
+            cancelled: result.cancelled,
+            mismatches: result.mismatches,
+        })
+    }
+}
+
+/// Session id of a history log ("cleanup_20250101_120000")
+fn session_id(log_path: &Path) -> Option<String> {
+    log_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_ids_round_trip() {
+        for category in Category::ALL {
+            assert_eq!(Category::from_id(category.id()), Some(category));
+            let json = serde_json::to_string(&category).unwrap();
+            assert_eq!(json, format!("\"{}\"", category.id()));
+        }
+    }
+
+    #[test]
+    fn test_selection_subset_and_remove() {
+        let mut results = ScanResults::default();
+        results.cache = CategoryResult {
+            items: 4,
+            size_bytes: 400,
+            paths: (0..4).map(|i| PathBuf::from(format!("c{}", i))).collect(),
+        };
+        results.large = CategoryResult {
+            items: 1,
+            size_bytes: 1000,
+            paths: vec![PathBuf::from("big.iso")],
+        };
+        let report = ScanReport::from_results(Path::new("."), &results);
+        assert_eq!(report.total_items, 5);
+        assert_eq!(report.total_bytes, 1400);
+
+        let safe = Selection::safe(&report);
+        assert_eq!(safe.items(), 4);
+
+        let mut selection = Selection::new();
+        let cache = report.category(Category::Cache).unwrap();
+        selection.add(cache, &[PathBuf::from("c1"), PathBuf::from("missing")]);
+        assert_eq!(selection.items(), 1);
+        assert_eq!(selection.size_bytes(), 100);
+
+        selection.remove_path(Path::new("c1"));
+        assert!(selection.is_empty());
+
+        let converted = Selection::categories(&report, &[Category::Large]).to_results();
+        assert_eq!(converted.large.items, 1);
+        assert_eq!(converted.cache.items, 0);
+    }
+
+    #[test]
+    fn test_builder_maps_categories() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let sweeper = Sweeper::builder()
+            .root(temp_dir.path())
+            .config(Config::default())
+            .categories([Category::Large, Category::EventLogs])
+            .min_size_bytes(42)
+            .build()
+            .unwrap();
+        assert!(sweeper.options.large && sweeper.options.event_logs);
+        assert!(!sweeper.options.cache);
+        assert_eq!(sweeper.options.min_size_bytes, 42);
+
+        assert!(Sweeper::builder()
+            .root(temp_dir.path().join("missing"))
+            .config(Config::default())
+            .build()
+            .is_err());
+    }
+}
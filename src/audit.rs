@@ -0,0 +1,43 @@
+//! Read-only audit mode (`wole --audit`, `safety.audit = true`)
+//!
+//! While audit mode is on, the cleaner, optimize and uninstall code refuse to
+//! change anything and report what they would have done instead. The check
+//! sits in those modules' entry points and deletion primitives rather than in
+//! the front ends, so the CLI, the TUI and [`crate::api`] embedders all get it.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(test)]
+thread_local! {
+    /// Audit mode for one test thread, so the rest of the test run still deletes
+    static TEST_ENABLED: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Turn audit mode on for the rest of the process
+///
+/// There is deliberately no way to turn it off again.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether modifications are currently refused
+pub fn is_enabled() -> bool {
+    #[cfg(test)]
+    if TEST_ENABLED.with(|enabled| enabled.get()) {
+        return true;
+    }
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Turn audit mode on for the calling test thread only
+#[cfg(test)]
+pub(crate) fn enable_for_test() {
+    TEST_ENABLED.with(|enabled| enabled.set(true));
+}
+
+/// Record a modification audit mode prevented (debug log only)
+pub fn note(action: &str) {
+    crate::debug_log::cleaning_log(&format!("[audit] would {}", action));
+}
@@ -0,0 +1,177 @@
+//! Smart auto-selection of scan results
+//!
+//! Instead of pre-selecting every item of a "safe" category, each result gets a
+//! confidence score from its category risk, age, size, project activity and
+//! duplicate status. Items scoring at or above the threshold set by
+//! `ui.auto_select_aggressiveness` are pre-selected, and the reasons behind the
+//! score are shown in the TUI's Preview screen. Items that look like personal
+//! data (see [`crate::sensitive`]) are never pre-selected.
+
+/// Points for being in a safe category; other categories start from nothing
+const SAFE_CATEGORY_POINTS: i32 = 60;
+
+/// Untouched for this long counts as old
+const OLD_DAYS: u64 = 180;
+
+/// Untouched for this long counts as stale
+const STALE_DAYS: u64 = 30;
+
+/// Used this recently counts as in use
+const RECENT_DAYS: u64 = 7;
+
+/// Items this big are worth more of the user's attention
+const LARGE_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// What the score of one item is based on
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SelectionFacts {
+    /// The category is safe to clean (cache, temp, trash, ...)
+    pub safe: bool,
+    /// Days since the item was last used (None if unknown)
+    pub age_days: Option<u64>,
+    pub size_bytes: u64,
+    /// For build artifacts: whether the owning project is still being worked on
+    pub active_project: Option<bool>,
+    /// Another copy of the file exists
+    pub duplicate: bool,
+    /// Why the item looks like personal data, if it does
+    pub sensitive: Option<&'static str>,
+}
+
+/// Confidence that an item can go, with the reasons for it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelectionScore {
+    /// 0 (keep) to 100 (clean without a second thought)
+    pub score: u8,
+    /// Human-readable reasons, "+" raised the score and "-" lowered it
+    pub reasons: Vec<String>,
+    /// Never pre-selected, whatever the aggressiveness
+    pub keep: bool,
+}
+
+impl SelectionScore {
+    /// Whether the item is pre-selected at the given aggressiveness (0-100)
+    pub fn selected(&self, aggressiveness: u8) -> bool {
+        !self.keep && self.score >= threshold(aggressiveness)
+    }
+}
+
+/// Lowest score that gets pre-selected: 0 selects nothing, 100 selects everything
+pub fn threshold(aggressiveness: u8) -> u8 {
+    if aggressiveness == 0 {
+        // Even a perfect score stays unselected
+        return u8::MAX;
+    }
+    100 - aggressiveness.min(100)
+}
+
+/// Score one item
+pub fn score(facts: &SelectionFacts) -> SelectionScore {
+    let mut points = SAFE_CATEGORY_POINTS;
+    let mut reasons = Vec::new();
+    if facts.safe {
+        reasons.push("+ Safe category".to_string());
+    }
+    let mut add = |delta: i32, reason: String| {
+        points += delta;
+        let sign = if delta >= 0 { '+' } else { '-' };
+        reasons.push(format!("{} {}", sign, reason));
+    };
+
+    if !facts.safe {
+        add(-SAFE_CATEGORY_POINTS, "Category needs review".to_string());
+    }
+
+    match facts.age_days {
+        Some(days) if days >= OLD_DAYS => add(25, format!("Not used for {} days", days)),
+        Some(days) if days >= STALE_DAYS => add(15, format!("Not used for {} days", days)),
+        Some(days) if days < RECENT_DAYS => add(-20, format!("Used {} day(s) ago", days)),
+        _ => {}
+    }
+
+    if facts.size_bytes >= LARGE_BYTES {
+        add(
+            10,
+            format!(
+                "Large ({}) - worth reclaiming",
+                bytesize::to_string(facts.size_bytes, false)
+            ),
+        );
+    }
+
+    match facts.active_project {
+        Some(true) => add(-40, "Project was worked on recently".to_string()),
+        Some(false) => add(15, "Project is inactive".to_string()),
+        None => {}
+    }
+
+    if facts.duplicate {
+        add(15, "Another copy of this file exists".to_string());
+    }
+
+    if let Some(why) = facts.sensitive {
+        add(-100, format!("May contain personal data ({})", why));
+    }
+
+    SelectionScore {
+        score: points.clamp(0, 100) as u8,
+        reasons,
+        keep: facts.sensitive.is_some(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_and_threshold() {
+        // A safe item with nothing else known keeps the old "safe means selected" default
+        let plain = score(&SelectionFacts {
+            safe: true,
+            ..Default::default()
+        });
+        assert_eq!(plain.score, 60);
+        assert!(plain.selected(40));
+        assert!(!plain.selected(0));
+
+        // Recent use and an active project pull a safe item below the threshold
+        let active = score(&SelectionFacts {
+            safe: true,
+            age_days: Some(2),
+            active_project: Some(true),
+            ..Default::default()
+        });
+        assert_eq!(active.score, 0);
+        assert!(!active.selected(40));
+        assert_eq!(active.reasons.len(), 3);
+        assert!(active.reasons[1].starts_with("- Used 2 day(s) ago"));
+
+        // An old, large duplicate needs a more aggressive setting
+        let duplicate = score(&SelectionFacts {
+            safe: false,
+            age_days: Some(400),
+            size_bytes: 2 * LARGE_BYTES,
+            duplicate: true,
+            ..Default::default()
+        });
+        assert_eq!(duplicate.score, 50);
+        assert!(!duplicate.selected(40));
+        assert!(duplicate.selected(50));
+        assert!(duplicate.selected(100));
+
+        // Personal data stays unselected even at full aggressiveness
+        let passport = score(&SelectionFacts {
+            safe: true,
+            age_days: Some(900),
+            sensitive: Some("passport"),
+            ..Default::default()
+        });
+        assert_eq!(passport.score, 0);
+        assert!(!passport.selected(100));
+
+        assert_eq!(threshold(0), u8::MAX);
+        assert_eq!(threshold(40), 60);
+        assert_eq!(threshold(200), 0);
+    }
+}
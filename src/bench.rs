@@ -0,0 +1,265 @@
+//! Scan/clean throughput benchmark on a synthetic directory tree
+//!
+//! Generates fake inactive Node projects (`package.json` + a populated
+//! `node_modules`), then times the size engine, the build-artifact scanner and
+//! permanent batch deletion against them. Used by `wole bench` to catch
+//! performance regressions in the scanner and cleaner.
+
+use crate::cancel::CancellationToken;
+use crate::cleaner;
+use crate::cli::ScanOptions;
+use crate::config::Config;
+use crate::output::OutputMode;
+use crate::scanner;
+use crate::size::{dir_size_with_options, DirSizeOptions};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Shape of the synthetic tree
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    /// Total number of files to generate
+    pub files: usize,
+    /// Size of each file in bytes
+    pub file_size: u64,
+    /// Number of fake projects (files are spread evenly between them)
+    pub projects: usize,
+    /// Files per directory inside each node_modules
+    pub files_per_dir: usize,
+    /// Skip the clean phase, leaving the generated tree in place
+    pub keep: bool,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            files: 20_000,
+            file_size: 4 * 1024,
+            projects: 20,
+            files_per_dir: 50,
+            keep: false,
+        }
+    }
+}
+
+/// Timing for one benchmark phase
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchPhase {
+    pub name: String,
+    pub duration_secs: f64,
+    /// Files (or items) processed
+    pub items: u64,
+    pub bytes: u64,
+    pub items_per_sec: f64,
+    pub mb_per_sec: f64,
+}
+
+impl BenchPhase {
+    fn new(name: &str, duration: Duration, items: u64, bytes: u64) -> Self {
+        let secs = duration.as_secs_f64().max(f64::EPSILON);
+        Self {
+            name: name.to_string(),
+            duration_secs: duration.as_secs_f64(),
+            items,
+            bytes,
+            items_per_sec: items as f64 / secs,
+            mb_per_sec: bytes as f64 / (1024.0 * 1024.0) / secs,
+        }
+    }
+}
+
+/// Full benchmark results
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchReport {
+    pub root: PathBuf,
+    pub files: u64,
+    pub bytes: u64,
+    pub projects: usize,
+    pub phases: Vec<BenchPhase>,
+}
+
+/// Create the synthetic tree under `root`; returns (files, bytes) written
+pub fn generate_tree(root: &Path, options: &BenchOptions) -> Result<(u64, u64)> {
+    let projects = options.projects.max(1);
+    let files_per_dir = options.files_per_dir.max(1);
+    let per_project = options.files.div_ceil(projects);
+    let contents = vec![b'x'; options.file_size as usize];
+
+    let mut files = 0u64;
+    let mut bytes = 0u64;
+    let mut remaining = options.files;
+
+    for p in 0..projects {
+        let project = root.join(format!("project-{:04}", p));
+        fs::create_dir_all(&project)
+            .with_context(|| format!("Failed to create {}", project.display()))?;
+        fs::write(
+            project.join("package.json"),
+            format!("{{\"name\": \"bench-project-{}\"}}", p),
+        )?;
+
+        let count = per_project.min(remaining);
+        remaining -= count;
+
+        for i in 0..count {
+            let dir = project
+                .join("node_modules")
+                .join(format!("pkg-{:04}", i / files_per_dir));
+            if i % files_per_dir == 0 {
+                fs::create_dir_all(&dir)?;
+            }
+            fs::write(dir.join(format!("file-{:06}.js", i)), &contents)?;
+            files += 1;
+            bytes += options.file_size;
+        }
+    }
+
+    Ok((files, bytes))
+}
+
+/// Generate a tree under `root`, run all phases, and report throughput.
+///
+/// `root` should be an empty scratch directory; everything under it is deleted by
+/// the clean phase, unless `options.keep` skips it.
+pub fn run_bench(root: &Path, options: &BenchOptions, config: &Config) -> Result<BenchReport> {
+    let mut phases = Vec::new();
+
+    // 1. Generate
+    let start = Instant::now();
+    let (files, bytes) = generate_tree(root, options)?;
+    phases.push(BenchPhase::new("generate", start.elapsed(), files, bytes));
+
+    // 2. Size engine (uncached full traversal)
+    let size_options = DirSizeOptions::default();
+    let start = Instant::now();
+    let totals = dir_size_with_options(root, &size_options, None).unwrap_or_default();
+    phases.push(BenchPhase::new(
+        "dir_size",
+        start.elapsed(),
+        totals.files,
+        totals.bytes,
+    ));
+
+    // 3. Scanner (build artifacts; every generated project is inactive at age 0)
+    let scan_options = ScanOptions {
+        cache: false,
+        app_cache: false,
+        temp: false,
+        trash: false,
+        build: true,
+        downloads: false,
+        large: false,
+        old: false,
+        applications: false,
+        browser: false,
+        system: false,
+        empty: false,
+        duplicates: false,
+        windows_update: false,
+        event_logs: false,
+        thumbnails: false,
+        crash_dumps: false,
+        logs: false,
+        game_caches: false,
+        toolchain_caches: false,
+        python_envs: false,
+        ml_models: false,
+        email: false,
+        creative_caches: false,
+        device_backups: false,
+        git_ignored: false,
+        project_age_days: 0,
+        min_age_days: 0,
+        min_size_bytes: 0,
+        scope: crate::users::ScanScope::CurrentUser,
+    };
+    let start = Instant::now();
+    let results = scanner::run_scan(root, scan_options, OutputMode::Quiet, config, &mut None)?;
+    phases.push(BenchPhase::new(
+        "scan",
+        start.elapsed(),
+        files,
+        results.build.size_bytes,
+    ));
+
+    // 4. Batch clean (permanent - never flood the Recycle Bin with bench data)
+    if !options.keep {
+        let start = Instant::now();
+        let cleaned =
+            cleaner::clean_paths_batch(&results.build.paths, true, &CancellationToken::new());
+        phases.push(BenchPhase::new(
+            "clean",
+            start.elapsed(),
+            if cleaned.error_count == 0 { files } else { 0 },
+            if cleaned.error_count == 0 {
+                results.build.size_bytes
+            } else {
+                0
+            },
+        ));
+    }
+
+    Ok(BenchReport {
+        root: root.to_path_buf(),
+        files,
+        bytes,
+        projects: options.projects.max(1),
+        phases,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_tree() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let options = BenchOptions {
+            files: 25,
+            file_size: 10,
+            projects: 3,
+            files_per_dir: 4,
+            keep: false,
+        };
+
+        let (files, bytes) = generate_tree(temp_dir.path(), &options).unwrap();
+        assert_eq!(files, 25);
+        assert_eq!(bytes, 250);
+        assert!(temp_dir.path().join("project-0000/package.json").exists());
+        assert!(temp_dir
+            .path()
+            .join("project-0000/node_modules/pkg-0000/file-000000.js")
+            .exists());
+    }
+
+    #[test]
+    fn test_phase_throughput() {
+        let phase = BenchPhase::new("scan", Duration::from_secs(2), 100, 4 * 1024 * 1024);
+        assert_eq!(phase.items_per_sec, 50.0);
+        assert_eq!(phase.mb_per_sec, 2.0);
+    }
+
+    #[test]
+    fn test_keep_skips_clean_phase() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let options = BenchOptions {
+            files: 10,
+            file_size: 10,
+            projects: 2,
+            files_per_dir: 5,
+            keep: true,
+        };
+
+        let report = run_bench(temp_dir.path(), &options, &Config::default()).unwrap();
+        let phases: Vec<&str> = report.phases.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(phases, ["generate", "dir_size", "scan"]);
+        assert!(temp_dir
+            .path()
+            .join("project-0000/node_modules/pkg-0000/file-000000.js")
+            .exists());
+    }
+}
@@ -0,0 +1,149 @@
+//! Big media detector for Large Files
+//!
+//! Videos, disc images and installers (`.mkv`, `.mp4`, `.iso`, `.vhdx`,
+//! `.msi`, ...) are usually the biggest Large Files, and outside a media
+//! library they're usually a download that was watched or installed once.
+//! Results shows them as their own subgroup of Large Files; media inside the
+//! folders in `categories.big_media.library_folders` is left with the other
+//! large files. Copies of the same episode or film ("Show.S01E02.720p.mkv"
+//! and "show s01e02 1080p.mp4") are matched by name and grouped on their
+//! own, so the spare copy is easy to spot. Only names are compared, never
+//! contents.
+
+use crate::config::Config;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Release tags dropped from names before comparing them
+const RELEASE_TAGS: [&str; 30] = [
+    "480p", "576p", "720p", "1080p", "1080i", "2160p", "4k", "uhd", "hdr", "hdr10", "dv", "x264",
+    "x265", "h264", "h265", "hevc", "avc", "av1", "aac", "ac3", "dts", "web", "webdl", "webrip",
+    "bluray", "brrip", "bdrip", "dvdrip", "hdtv", "repack",
+];
+
+/// Which Large Files are big media
+#[derive(Debug, Clone)]
+pub struct Detector {
+    extensions: Vec<String>,
+    libraries: Vec<PathBuf>,
+}
+
+impl Detector {
+    pub fn new(config: &Config) -> Self {
+        let settings = &config.categories.big_media;
+        let profile = std::env::var_os("USERPROFILE").map(PathBuf::from);
+        let libraries = settings
+            .library_folders
+            .iter()
+            .map(PathBuf::from)
+            .filter_map(|folder| {
+                // Relative folders are in the user profile
+                if folder.is_absolute() {
+                    Some(folder)
+                } else {
+                    profile.as_ref().map(|profile| profile.join(folder))
+                }
+            })
+            .collect();
+        Self {
+            extensions: settings
+                .extensions
+                .iter()
+                .map(|ext| ext.trim_start_matches('.').to_lowercase())
+                .collect(),
+            libraries,
+        }
+    }
+
+    /// Whether `path` is a video, disc image or installer outside the media libraries
+    pub fn is_big_media(&self, path: &Path) -> bool {
+        let Some(ext) = path.extension().and_then(|e| e.to_str()) else {
+            return false;
+        };
+        self.extensions.iter().any(|e| e.eq_ignore_ascii_case(ext))
+            && !self
+                .libraries
+                .iter()
+                .any(|library| path.starts_with(library))
+    }
+}
+
+/// The name a file is compared by: lowercase words of its stem without
+/// release tags, brackets or the year's parentheses
+pub fn episode_key(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?.to_lowercase();
+    let mut cleaned = String::with_capacity(stem.len());
+    let mut depth = 0usize;
+    for c in stem.chars() {
+        match c {
+            '[' | '(' | '{' => depth += 1,
+            ']' | ')' | '}' => depth = depth.saturating_sub(1),
+            _ if depth > 0 => {}
+            c if c.is_alphanumeric() => cleaned.push(c),
+            _ => cleaned.push(' '),
+        }
+    }
+    let words: Vec<&str> = cleaned
+        .split_whitespace()
+        .filter(|word| !RELEASE_TAGS.contains(word))
+        .collect();
+    // A name that's all tags says nothing about what the file is
+    (!words.is_empty()).then(|| words.join(" "))
+}
+
+/// Groups (indices into `paths`) of files that look like the same episode or
+/// film, biggest group first; files without a match are left out
+pub fn duplicate_episodes(paths: &[&Path]) -> Vec<Vec<usize>> {
+    let mut by_key: HashMap<String, Vec<usize>> = HashMap::new();
+    for (i, path) in paths.iter().enumerate() {
+        if let Some(key) = episode_key(path) {
+            by_key.entry(key).or_default().push(i);
+        }
+    }
+    let mut groups: Vec<Vec<usize>> = by_key
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .collect();
+    groups.sort_by(|a, b| b.len().cmp(&a.len()).then(a[0].cmp(&b[0])));
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_episode_key_ignores_release_tags() {
+        assert_eq!(
+            episode_key(Path::new("Show.Name.S01E02.720p.WEBRip.x264.mkv")),
+            episode_key(Path::new("show name s01e02 [1080p] HEVC.mp4"))
+        );
+        assert_ne!(
+            episode_key(Path::new("Show.Name.S01E02.mkv")),
+            episode_key(Path::new("Show.Name.S01E03.mkv"))
+        );
+        assert_eq!(episode_key(Path::new("1080p.x264.mkv")), None);
+    }
+
+    #[test]
+    fn test_duplicate_episodes() {
+        let paths = [
+            Path::new("D:/dl/Film (2019) 1080p.mkv"),
+            Path::new("D:/dl/Other.iso"),
+            Path::new("C:/Users/a/Desktop/film 2160p HDR.mp4"),
+        ];
+        assert_eq!(duplicate_episodes(&paths), [vec![0, 2]]);
+    }
+
+    #[test]
+    fn test_library_folders_are_skipped() {
+        let root = std::env::temp_dir();
+        let mut config = Config::default();
+        config.categories.big_media.library_folders =
+            vec![root.join("Media").to_string_lossy().to_string()];
+        let detector = Detector::new(&config);
+        assert!(detector.is_big_media(&root.join("Downloads").join("movie.MKV")));
+        assert!(!detector.is_big_media(&root.join("Media").join("movie.mkv")));
+        assert!(!detector.is_big_media(&root.join("Downloads").join("notes.txt")));
+    }
+}
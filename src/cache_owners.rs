@@ -0,0 +1,177 @@
+//! Which application owns a cache or temp item
+//!
+//! Application Cache, Browser Cache and Temp Files results are spread over many
+//! folders. Grouping them by the app they belong to (Discord, Teams, Spotify,
+//! VS Code, ...) shows which app wastes the most space, so its caches can be
+//! cleaned on their own. An item is attributed by its folder under
+//! %LOCALAPPDATA%, %APPDATA% or %TEMP%: well-known app folders first, then the
+//! folder's own name.
+
+use std::collections::HashMap;
+use std::env;
+use std::path::{Component, Path, PathBuf};
+
+/// Categories (display names) whose items are grouped by app
+pub const CATEGORIES: &[&str] = &["Application Cache", "Browser Cache", "Temp Files"];
+
+/// Files directly in %TEMP% and items outside the known roots
+pub const OTHER: &str = "Other";
+
+/// App folders relative to %LOCALAPPDATA%, %APPDATA% or %TEMP% (case-insensitive)
+const APP_FOLDERS: &[(&[&str], &str)] = &[
+    (&["discord"], "Discord"),
+    (&["Code"], "VS Code"),
+    (&["slack"], "Slack"),
+    (&["Spotify"], "Spotify"),
+    (&["Microsoft", "Teams"], "Teams"),
+    (&["Packages", "MSTeams_8wekyb3d8bbwe"], "Teams"),
+    (&["Microsoft", "Edge"], "Edge"),
+    (&["Microsoft", "Edge Beta"], "Edge"),
+    (&["Microsoft", "Edge Dev"], "Edge"),
+    (&["Microsoft", "OneDrive"], "OneDrive"),
+    (&["Google", "Chrome"], "Chrome"),
+    (&["Google", "Chrome Beta"], "Chrome"),
+    (&["Google", "Chrome Dev"], "Chrome"),
+    (&["Google", "AndroidStudio"], "Android Studio"),
+    (&["BraveSoftware"], "Brave"),
+    (&["Mozilla", "Firefox"], "Firefox"),
+    (&["Opera Software"], "Opera"),
+    (&["Vivaldi"], "Vivaldi"),
+    (&["Zoom"], "Zoom"),
+    (&["Telegram Desktop"], "Telegram"),
+    (&["Notion"], "Notion"),
+    (&["Figma"], "Figma"),
+    (&["Adobe"], "Adobe"),
+    (&["Dropbox"], "Dropbox"),
+    (&["GitHub Desktop"], "GitHub Desktop"),
+    (&["Postman"], "Postman"),
+    (&["Docker"], "Docker"),
+    (&["JetBrains"], "JetBrains"),
+    (&["Steam"], "Steam"),
+    (&["obs-studio"], "OBS Studio"),
+];
+
+/// Vendor folders that hold one folder per app (Microsoft\Word, Google\Earth)
+const VENDOR_FOLDERS: &[&str] = &["Microsoft", "Google", "Packages", "Programs"];
+
+/// Cache items of one application
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppUsage {
+    pub app: String,
+    pub size_bytes: u64,
+    /// Ids of the items, as passed to [`by_app`]
+    pub items: Vec<usize>,
+}
+
+/// Folders items are attributed under: %LOCALAPPDATA%, %APPDATA%, %TEMP%
+fn roots() -> Vec<PathBuf> {
+    let mut roots: Vec<PathBuf> = ["TEMP", "LOCALAPPDATA", "APPDATA"]
+        .iter()
+        .filter_map(|var| env::var_os(var).filter(|v| !v.is_empty()))
+        .map(PathBuf::from)
+        .collect();
+    // %TEMP% usually lives in %LOCALAPPDATA%; the most specific root must win
+    roots.sort_by_key(|root| std::cmp::Reverse(root.components().count()));
+    roots
+}
+
+/// The application owning `path` ([`OTHER`] if it can't be told)
+pub fn owner(path: &Path) -> String {
+    owner_under(path, &roots())
+}
+
+fn owner_under(path: &Path, roots: &[PathBuf]) -> String {
+    let Some(relative) = roots.iter().find_map(|root| path.strip_prefix(root).ok()) else {
+        return OTHER.to_string();
+    };
+    let parts: Vec<String> = relative
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect();
+    // A file directly in the root has no app folder
+    if parts.len() < 2 {
+        return OTHER.to_string();
+    }
+
+    let known = APP_FOLDERS
+        .iter()
+        .filter(|(folder, _)| {
+            folder.len() < parts.len()
+                && folder
+                    .iter()
+                    .zip(&parts)
+                    .all(|(a, b)| a.eq_ignore_ascii_case(b))
+        })
+        .max_by_key(|(folder, _)| folder.len());
+    if let Some((_, app)) = known {
+        return app.to_string();
+    }
+
+    if parts.len() > 2
+        && VENDOR_FOLDERS
+            .iter()
+            .any(|vendor| vendor.eq_ignore_ascii_case(&parts[0]))
+    {
+        return format!("{} {}", parts[0], parts[1]);
+    }
+    parts[0].clone()
+}
+
+/// Group `(id, path, size)` items by owning app, biggest first
+pub fn by_app<'a>(items: impl IntoIterator<Item = (usize, &'a Path, u64)>) -> Vec<AppUsage> {
+    let roots = roots();
+    let mut apps: HashMap<String, AppUsage> = HashMap::new();
+    for (id, path, size) in items {
+        let app = owner_under(path, &roots);
+        let usage = apps.entry(app.clone()).or_insert_with(|| AppUsage {
+            app,
+            size_bytes: 0,
+            items: Vec::new(),
+        });
+        usage.size_bytes += size;
+        usage.items.push(id);
+    }
+
+    let mut apps: Vec<AppUsage> = apps.into_values().collect();
+    apps.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes).then(a.app.cmp(&b.app)));
+    apps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_owner_under() {
+        let local = PathBuf::from("home").join("AppData").join("Local");
+        let temp = local.join("Temp");
+        let roots = vec![temp.clone(), local.clone()];
+        let owner = |parts: &[&str], base: &Path| {
+            let path = parts.iter().fold(base.to_path_buf(), |p, s| p.join(s));
+            owner_under(&path, &roots)
+        };
+
+        assert_eq!(owner(&["discord", "Cache", "data_1"], &local), "Discord");
+        assert_eq!(owner(&["Microsoft", "Teams", "Cache"], &local), "Teams");
+        assert_eq!(
+            owner(
+                &["Google", "Chrome", "User Data", "Default", "Cache"],
+                &local
+            ),
+            "Chrome"
+        );
+        // Unknown apps are named after their folder, vendor folders one level deeper
+        assert_eq!(owner(&["Bitwarden", "Cache"], &local), "Bitwarden");
+        assert_eq!(
+            owner(&["Microsoft", "Word", "cache"], &local),
+            "Microsoft Word"
+        );
+        // Temp wins over LocalAppData; loose temp files have no owner
+        assert_eq!(owner(&["Spotify", "update.tmp"], &temp), "Spotify");
+        assert_eq!(owner(&["setup.log"], &temp), OTHER);
+        assert_eq!(owner_under(Path::new("elsewhere"), &roots), OTHER);
+    }
+}
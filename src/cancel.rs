@@ -0,0 +1,112 @@
+//! Cooperative cancellation for scans, cleanups and restores
+//!
+//! A [`CancellationToken`] is cheap to clone and shared between the thread doing
+//! the work and whoever may abort it (the TUI's Esc handler, a GUI button, ...).
+//! Long-running loops check it between files and directories and stop early;
+//! operations that were cut short return a [`Cancelled`] error.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Shared "please stop" flag
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    flag: Arc<AtomicBool>,
+    /// Flags of the tokens this one was made from (see [`child`](Self::child))
+    parents: Vec<Arc<AtomicBool>>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Token that is also cancelled when this one is; cancelling the child
+    /// leaves this one running (e.g. skipping one category of a scan)
+    pub fn child(&self) -> Self {
+        let mut parents = self.parents.clone();
+        parents.push(self.flag.clone());
+        Self {
+            flag: Arc::new(AtomicBool::new(false)),
+            parents,
+        }
+    }
+
+    /// Ask every holder of this token to stop
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Relaxed) || self.parents.iter().any(|p| p.load(Ordering::Relaxed))
+    }
+
+    /// `Err(Cancelled)` once the token has been cancelled
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The underlying flag, for APIs that take an `Arc<AtomicBool>` (e.g. [`crate::size`])
+    ///
+    /// A child's flag does not reflect its parents.
+    pub fn as_flag(&self) -> &Arc<AtomicBool> {
+        &self.flag
+    }
+}
+
+/// Error returned by operations stopped through a [`CancellationToken`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Operation cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// Whether `err` (or its source chain) is a [`Cancelled`] error
+pub fn is_cancelled(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| cause.is::<Cancelled>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_shared_between_clones() {
+        let token = CancellationToken::new();
+        let other = token.clone();
+        assert!(token.check().is_ok());
+
+        other.cancel();
+        assert!(token.is_cancelled());
+        assert_eq!(token.check(), Err(Cancelled));
+
+        let err = anyhow::Error::new(Cancelled).context("scan failed");
+        assert!(is_cancelled(&err));
+        assert!(!is_cancelled(&anyhow::anyhow!("disk error")));
+    }
+
+    #[test]
+    fn test_child_token() {
+        let scan = CancellationToken::new();
+        let category = scan.child();
+        let nested = category.child();
+
+        category.cancel();
+        assert!(nested.is_cancelled());
+        assert!(!scan.is_cancelled());
+
+        let other = scan.child().child();
+        scan.cancel();
+        assert!(other.is_cancelled());
+    }
+}
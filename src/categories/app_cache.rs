@@ -1,3 +1,4 @@
+use crate::cancel::CancellationToken;
 use crate::config::Config;
 use crate::output::{CategoryResult, OutputMode};
 use crate::scan_events::{ScanPathReporter, ScanProgressEvent};
@@ -336,6 +337,7 @@ pub fn scan_with_progress(
     _root: &Path,
     config: &Config,
     tx: &Sender<ScanProgressEvent>,
+    cancel: &CancellationToken,
 ) -> Result<CategoryResult> {
     const CATEGORY: &str = "Application Cache";
     let mut result = CategoryResult::default();
@@ -360,6 +362,7 @@ pub fn scan_with_progress(
 
     // Scan known application caches
     for (idx, (_name, location)) in APP_CACHE_LOCATIONS.iter().enumerate() {
+        cancel.check()?;
         let cache_path = match location {
             AppCacheLocation::LocalAppDataNested(subpaths) => local_appdata.as_ref().map(|p| {
                 let mut path = p.clone();
@@ -372,7 +375,8 @@ pub fn scan_with_progress(
 
         if let Some(cache_path) = cache_path {
             if cache_path.exists() && !config.is_excluded(&cache_path) {
-                let size = utils::calculate_dir_size_with_progress(&cache_path, &on_path);
+                let size = utils::calculate_dir_size_cancellable(&cache_path, &on_path, cancel)
+                    .ok_or(crate::cancel::Cancelled)?;
                 if size > 0 {
                     known_paths.insert(cache_path.clone());
                     files_with_sizes.push((cache_path.clone(), size));
@@ -408,7 +412,8 @@ pub fn scan_with_progress(
 
         let app_caches = scan_app_caches(local_appdata_path, &mut known_paths, config);
         for cache_path in app_caches {
-            let size = utils::calculate_dir_size_with_progress(&cache_path, &on_path);
+            let size = utils::calculate_dir_size_cancellable(&cache_path, &on_path, cancel)
+                .ok_or(crate::cancel::Cancelled)?;
             if size > 0 {
                 files_with_sizes.push((cache_path, size));
             }
@@ -427,7 +432,8 @@ pub fn scan_with_progress(
 
         let app_caches = scan_app_caches(appdata_path, &mut known_paths, config);
         for cache_path in app_caches {
-            let size = utils::calculate_dir_size_with_progress(&cache_path, &on_path);
+            let size = utils::calculate_dir_size_cancellable(&cache_path, &on_path, cancel)
+                .ok_or(crate::cancel::Cancelled)?;
             if size > 0 {
                 files_with_sizes.push((cache_path, size));
             }
@@ -1,3 +1,4 @@
+use crate::cancel::CancellationToken;
 use crate::config::Config;
 use crate::output::{CategoryResult, OutputMode};
 use crate::scan_events::{ScanPathReporter, ScanProgressEvent};
@@ -408,6 +409,7 @@ pub fn scan_with_progress(
     _root: &Path,
     config: &Config,
     tx: &Sender<ScanProgressEvent>,
+    cancel: &CancellationToken,
 ) -> Result<CategoryResult> {
     const CATEGORY: &str = "Installed Applications";
 
@@ -439,6 +441,7 @@ pub fn scan_with_progress(
         let mut apps_with_sizes: Vec<AppEntry> = Vec::new();
 
         for (idx, app) in apps.iter().enumerate() {
+            cancel.check()?;
             if config.is_excluded(&app.install_location) {
                 continue;
             }
@@ -453,7 +456,12 @@ pub fn scan_with_progress(
             } else {
                 // Verify directory still exists before calculating size
                 if app.install_location.exists() && app.install_location.is_dir() {
-                    crate::utils::calculate_dir_size_with_progress(&app.install_location, &on_path)
+                    crate::utils::calculate_dir_size_cancellable(
+                        &app.install_location,
+                        &on_path,
+                        cancel,
+                    )
+                    .ok_or(crate::cancel::Cancelled)?
                 } else {
                     // Directory was deleted/moved since registry read - skip this app
                     continue;
@@ -554,6 +562,7 @@ pub fn scan_with_progress(
 
     #[cfg(not(windows))]
     {
+        let _ = cancel;
         let _ = tx.send(ScanProgressEvent::CategoryStarted {
             category: CATEGORY.to_string(),
             total_units: Some(0),
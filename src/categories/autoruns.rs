@@ -0,0 +1,311 @@
+//! Scheduled tasks and services that start on their own (`wole startup --audit`)
+//!
+//! Complements the Run keys and Startup folder of the startup manager with
+//! the two places apps put their background work: scheduled tasks in the Task
+//! Scheduler library (outside `\Microsoft\`) and services set to start
+//! automatically (outside the Windows folder). Entries whose program is gone
+//! are flagged. Disabling turns a task off in Task Scheduler and sets a
+//! service to start manually, so both can be turned back on.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Third-party tasks with the program of their first action
+#[cfg(windows)]
+const TASKS_SCRIPT: &str = r#"
+$tasks = Get-ScheduledTask | Where-Object { $_.TaskPath -notlike '\Microsoft\*' } | ForEach-Object {
+    $action = $_.Actions | Where-Object { $_.Execute } | Select-Object -First 1
+    [pscustomobject]@{
+        Path = $_.TaskPath + $_.TaskName
+        Author = [string]$_.Author
+        State = [string]$_.State
+        Execute = [string]$action.Execute
+        Arguments = [string]$action.Arguments
+    }
+}
+ConvertTo-Json -InputObject @($tasks) -Compress
+"#;
+
+/// Services that start with Windows
+#[cfg(windows)]
+const SERVICES_SCRIPT: &str = r#"
+$services = Get-CimInstance Win32_Service -Filter "StartMode='Auto'" | ForEach-Object {
+    [pscustomobject]@{
+        Name = $_.Name
+        DisplayName = [string]$_.DisplayName
+        PathName = [string]$_.PathName
+    }
+}
+ConvertTo-Json -InputObject @($services) -Compress
+"#;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AutorunKind {
+    ScheduledTask,
+    Service,
+}
+
+impl AutorunKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            AutorunKind::ScheduledTask => "Scheduled task",
+            AutorunKind::Service => "Service",
+        }
+    }
+}
+
+/// A scheduled task or automatic service added by an app
+#[derive(Debug, Clone, Serialize)]
+pub struct Autorun {
+    pub kind: AutorunKind,
+    /// Full task path (`\Vendor\Updater`) or service name
+    pub name: String,
+    /// Task author or service display name
+    pub description: String,
+    /// Command line it runs
+    pub command: String,
+    /// Program the command runs, when it can be told
+    pub executable: Option<PathBuf>,
+    /// The program no longer exists
+    pub missing: bool,
+    /// False for tasks disabled in Task Scheduler
+    pub enabled: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+#[cfg_attr(not(any(windows, test)), allow(dead_code))]
+struct TaskRecord {
+    path: String,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default)]
+    execute: Option<String>,
+    #[serde(default)]
+    arguments: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+#[cfg_attr(not(any(windows, test)), allow(dead_code))]
+struct ServiceRecord {
+    name: String,
+    #[serde(default)]
+    display_name: Option<String>,
+    #[serde(default)]
+    path_name: Option<String>,
+}
+
+/// Third-party scheduled tasks and automatic services
+///
+/// Always empty on other platforms.
+pub fn list_autoruns() -> Result<Vec<Autorun>> {
+    #[cfg(windows)]
+    {
+        let mut autoruns = parse_tasks(&run_powershell(TASKS_SCRIPT)?)?;
+        let system_root = std::env::var_os("SystemRoot")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("C:\\Windows"));
+        autoruns.extend(parse_services(
+            &run_powershell(SERVICES_SCRIPT)?,
+            &system_root,
+        )?);
+        Ok(autoruns)
+    }
+
+    #[cfg(not(windows))]
+    {
+        Ok(Vec::new())
+    }
+}
+
+/// Disable a task in Task Scheduler, or set a service to start manually
+pub fn disable_autorun(autorun: &Autorun) -> Result<()> {
+    #[cfg(windows)]
+    {
+        use anyhow::Context;
+        use std::process::Command;
+
+        let output = match autorun.kind {
+            AutorunKind::ScheduledTask => Command::new("schtasks")
+                .args(["/Change", "/TN", &autorun.name, "/DISABLE"])
+                .output(),
+            AutorunKind::Service => Command::new("sc")
+                .args(["config", &autorun.name, "start=", "demand"])
+                .output(),
+        }
+        .with_context(|| format!("Failed to disable {}", autorun.name))?;
+        if !output.status.success() {
+            // sc reports errors on stdout
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let error = if stderr.trim().is_empty() {
+                stdout
+            } else {
+                stderr
+            };
+            return Err(anyhow::anyhow!(
+                "Failed to disable {}: {}",
+                autorun.name,
+                error.trim()
+            ));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = autorun;
+        Err(anyhow::anyhow!(
+            "Startup management is only available on Windows"
+        ))
+    }
+}
+
+#[cfg(windows)]
+fn run_powershell(script: &str) -> Result<String> {
+    use anyhow::Context;
+
+    let output = std::process::Command::new("powershell")
+        .args(["-NoProfile", "-Command", script])
+        .output()
+        .context("Failed to run PowerShell")?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "PowerShell failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Relative programs ("rundll32.exe") are found through PATH, so only full paths can be missing
+#[cfg(any(windows, test))]
+fn missing(executable: &Option<PathBuf>) -> bool {
+    executable
+        .as_ref()
+        .is_some_and(|path| path.is_absolute() && !path.exists())
+}
+
+#[cfg(any(windows, test))]
+fn parse_tasks(json: &str) -> Result<Vec<Autorun>> {
+    let json = json.trim();
+    if json.is_empty() {
+        return Ok(Vec::new());
+    }
+    let records: Vec<TaskRecord> = serde_json::from_str(json)?;
+    Ok(records
+        .into_iter()
+        .map(|task| {
+            let execute = task.execute.unwrap_or_default();
+            let arguments = task.arguments.unwrap_or_default();
+            let executable = super::registry_audit::command_target(&execute);
+            Autorun {
+                kind: AutorunKind::ScheduledTask,
+                name: task.path,
+                description: task.author.unwrap_or_default(),
+                command: format!("{} {}", execute, arguments).trim().to_string(),
+                missing: missing(&executable),
+                executable,
+                enabled: !task
+                    .state
+                    .is_some_and(|state| state.eq_ignore_ascii_case("disabled")),
+            }
+        })
+        .collect())
+}
+
+/// Services whose program is in the Windows folder belong to Windows and are left out
+#[cfg(any(windows, test))]
+fn parse_services(json: &str, system_root: &std::path::Path) -> Result<Vec<Autorun>> {
+    let json = json.trim();
+    if json.is_empty() {
+        return Ok(Vec::new());
+    }
+    let system_root = system_root.to_string_lossy().to_lowercase();
+    let records: Vec<ServiceRecord> = serde_json::from_str(json)?;
+    Ok(records
+        .into_iter()
+        .filter_map(|service| {
+            let command = service.path_name.unwrap_or_default();
+            let executable = super::registry_audit::command_target(&command);
+            let windows = executable.as_ref().is_some_and(|path| {
+                path.to_string_lossy()
+                    .to_lowercase()
+                    .starts_with(&system_root)
+            });
+            (!windows).then(|| Autorun {
+                kind: AutorunKind::Service,
+                name: service.name,
+                description: service.display_name.unwrap_or_default(),
+                command,
+                missing: missing(&executable),
+                executable,
+                enabled: true,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tasks() {
+        let gone = std::env::temp_dir().join("wole-no-such-updater.exe");
+        let json = serde_json::json!([
+            {
+                "Path": "\\Vendor\\Updater",
+                "Author": "Vendor",
+                "State": "Ready",
+                "Execute": gone,
+                "Arguments": "/silent"
+            },
+            {
+                "Path": "\\Other",
+                "Author": null,
+                "State": "Disabled",
+                "Execute": "",
+                "Arguments": ""
+            }
+        ])
+        .to_string();
+        let tasks = parse_tasks(&json).unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert!(tasks[0].missing && tasks[0].enabled);
+        assert_eq!(tasks[0].command, format!("{} /silent", gone.display()));
+        assert!(!tasks[1].missing && !tasks[1].enabled);
+        assert_eq!(tasks[1].executable, None);
+        assert!(parse_tasks(" ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_services_skips_windows() {
+        let root = std::env::temp_dir().join("FakeWindows");
+        let json = serde_json::json!([
+            {
+                "Name": "Spooler",
+                "DisplayName": "Print Spooler",
+                "PathName": root.join("System32").join("spoolsv.exe")
+            },
+            {
+                "Name": "VendorSvc",
+                "DisplayName": "Vendor Service",
+                "PathName": "\"C:\\Program Files\\Vendor\\svc.exe\" -k"
+            }
+        ])
+        .to_string();
+        let services = parse_services(&json, &root).unwrap();
+        assert_eq!(services.len(), 1);
+        assert_eq!(services[0].name, "VendorSvc");
+        assert_eq!(
+            services[0].executable,
+            Some(PathBuf::from("C:\\Program Files\\Vendor\\svc.exe"))
+        );
+    }
+}
@@ -1,6 +1,8 @@
+use crate::cancel::CancellationToken;
 use crate::config::{CategoryConfig, Config};
 use crate::output::{CategoryResult, OutputMode};
 use crate::project;
+use crate::scan_cache::FrontierWalk;
 use crate::scan_events::{ScanPathReporter, ScanProgressEvent};
 use crate::theme::Theme;
 use crate::utils;
@@ -36,7 +38,7 @@ const DEFAULT_BUILD_ARTIFACTS: &[&str] = &[
 ];
 
 /// Get the list of build artifacts, merging defaults with custom artifacts from config
-fn get_build_artifacts(config: Option<&CategoryConfig>) -> Vec<String> {
+pub(crate) fn get_build_artifacts(config: Option<&CategoryConfig>) -> Vec<String> {
     let mut artifacts: Vec<String> = DEFAULT_BUILD_ARTIFACTS
         .iter()
         .map(|s| s.to_string())
@@ -87,7 +89,7 @@ pub fn scan(
         vec![root.to_path_buf()]
     } else {
         // Walk to find projects (with exclusion filtering)
-        project::find_project_roots(root, global_config)
+        project::find_project_roots(root, global_config, &CancellationToken::new())
     };
 
     // Show discovered projects
@@ -100,38 +102,42 @@ pub fn scan(
     }
 
     // Filter to only inactive projects (safety feature: don't delete from active projects)
-    let inactive_project_roots: Vec<PathBuf> = all_project_roots
-        .par_iter()
-        .filter_map(|project_root| {
-            // Check if project is inactive (not recently modified)
-            let is_active =
-                project::is_project_active(project_root, project_age_days).unwrap_or(true);
-
-            // Show project as it's being checked (always show in Normal+ mode)
-            if output_mode != OutputMode::Quiet {
-                let relative = utils::to_relative_path(project_root, root);
-                let status = if is_active {
-                    Theme::status_safe("active")
+    let inactive_project_roots: Vec<PathBuf> = crate::drive::install(|| {
+        all_project_roots
+            .par_iter()
+            .filter_map(|project_root| {
+                // Check if project is inactive (not recently modified)
+                let is_active =
+                    project::is_project_active(project_root, project_age_days).unwrap_or(true);
+
+                // Show project as it's being checked (always show in Normal+ mode)
+                if output_mode != OutputMode::Quiet {
+                    let relative = utils::to_relative_path(project_root, root);
+                    let status = if is_active {
+                        Theme::status_safe("active")
+                    } else {
+                        Theme::status_review("inactive")
+                    };
+                    println!("    {} {} ({})", Theme::muted("•"), relative, status);
+                }
+
+                if is_active {
+                    None // Active - skip it
                 } else {
-                    Theme::status_review("inactive")
-                };
-                println!("    {} {} ({})", Theme::muted("•"), relative, status);
-            }
-
-            if is_active {
-                None // Active - skip it
-            } else {
-                Some(project_root.clone()) // Inactive - include it
-            }
-        })
-        .collect();
+                    Some(project_root.clone()) // Inactive - include it
+                }
+            })
+            .collect()
+    });
 
     // Collect all artifact paths from inactive projects only (fast check for existence)
-    let all_artifact_paths: Vec<PathBuf> = inactive_project_roots
-        .par_iter()
-        .flat_map(|project_root| find_build_artifacts(project_root, &artifacts_to_scan))
-        .filter(|p| p.exists())
-        .collect();
+    let all_artifact_paths: Vec<PathBuf> = crate::drive::install(|| {
+        inactive_project_roots
+            .par_iter()
+            .flat_map(|project_root| find_build_artifacts(project_root, &artifacts_to_scan))
+            .filter(|p| p.exists())
+            .collect()
+    });
 
     // Show artifacts as they're found (after collection to avoid parallel counter issues)
     if output_mode != OutputMode::Quiet && !all_artifact_paths.is_empty() {
@@ -187,6 +193,9 @@ pub fn scan(
 }
 
 /// Scan for build artifacts with progress updates (files being visited during size calculation).
+///
+/// The artifacts left to size are checkpointed through `walk`.
+#[allow(clippy::too_many_arguments)]
 pub fn scan_with_progress(
     root: &Path,
     project_age_days: u64,
@@ -194,46 +203,59 @@ pub fn scan_with_progress(
     global_config: &Config,
     output_mode: OutputMode,
     tx: &Sender<ScanProgressEvent>,
+    cancel: &CancellationToken,
+    walk: &mut FrontierWalk,
 ) -> Result<CategoryResult> {
-    let reporter = Arc::new(ScanPathReporter::new("Build Artifacts", tx.clone(), 75));
+    let reporter =
+        Arc::new(ScanPathReporter::new("Build Artifacts", tx.clone(), 75).with_cancel(cancel));
 
     let mut result = CategoryResult::default();
     let artifacts_to_scan = get_build_artifacts(config);
 
-    let all_project_roots = if crate::project::detect_project_type(root).is_some() {
-        vec![root.to_path_buf()]
-    } else {
-        project::find_project_roots(root, global_config)
-    };
-
-    let inactive_project_roots: Vec<PathBuf> = all_project_roots
-        .par_iter()
-        .filter_map(|project_root| {
-            let is_active =
-                project::is_project_active(project_root, project_age_days).unwrap_or(true);
-            if is_active {
-                None
-            } else {
-                Some(project_root.clone())
-            }
-        })
-        .collect();
-
-    let all_artifact_paths: Vec<PathBuf> = inactive_project_roots
-        .par_iter()
-        .flat_map(|project_root| find_build_artifacts(project_root, &artifacts_to_scan))
-        .filter(|p| p.exists())
-        .collect();
-
-    let mut artifacts_with_sizes: Vec<(PathBuf, u64)> = all_artifact_paths
-        .iter()
-        .map(|path| {
-            let rep = Arc::clone(&reporter);
-            let size = utils::calculate_dir_size_with_progress(path, &|p| rep.emit_path(p));
-            (path.clone(), size)
-        })
-        .filter(|(_, size)| *size > 0)
-        .collect();
+    // Artifacts left to size (an interrupted scan continues with the ones it hadn't sized)
+    let mut frontier = walk.start(|| {
+        let all_project_roots = if crate::project::detect_project_type(root).is_some() {
+            vec![root.to_path_buf()]
+        } else {
+            project::find_project_roots(root, global_config, cancel)
+        };
+        cancel.check()?;
+
+        let inactive_project_roots: Vec<PathBuf> = crate::drive::install(|| {
+            all_project_roots
+                .par_iter()
+                .filter_map(|project_root| {
+                    let is_active =
+                        project::is_project_active(project_root, project_age_days).unwrap_or(true);
+                    if is_active {
+                        None
+                    } else {
+                        Some(project_root.clone())
+                    }
+                })
+                .collect()
+        });
+
+        Ok(crate::drive::install(|| {
+            inactive_project_roots
+                .par_iter()
+                .flat_map(|project_root| find_build_artifacts(project_root, &artifacts_to_scan))
+                .filter(|p| p.exists())
+                .collect()
+        }))
+    })?;
+
+    while let Some(path) = frontier.pending.first().cloned() {
+        let rep = Arc::clone(&reporter);
+        let size = utils::calculate_dir_size_with_progress(&path, &|p| rep.emit_path(p));
+        cancel.check()?;
+        frontier.pending.remove(0);
+        if size > 0 {
+            frontier.found.push((path, size));
+        }
+        walk.save(&frontier);
+    }
+    let mut artifacts_with_sizes = frontier.found;
 
     artifacts_with_sizes.par_sort_by(|a, b| b.1.cmp(&a.1));
 
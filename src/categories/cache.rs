@@ -1,7 +1,9 @@
+use crate::cancel::CancellationToken;
 use crate::config::Config;
 use crate::output::{CategoryResult, OutputMode};
 use crate::scan_events::{ScanPathReporter, ScanProgressEvent};
 use crate::theme::Theme;
+use crate::users::UserProfile;
 use crate::utils;
 use anyhow::{Context, Result};
 use bytesize;
@@ -11,7 +13,7 @@ use std::sync::mpsc::Sender;
 use std::sync::Arc;
 
 /// Package manager cache locations to scan
-/// Each tuple is (name, path_from_localappdata_or_userprofile)
+/// Each tuple is (name, path relative to one of the [`Roots`])
 const CACHE_LOCATIONS: &[(&str, CacheLocation)] = &[
     ("npm", CacheLocation::LocalAppData("npm-cache")),
     ("pip", CacheLocation::LocalAppDataNested(&["pip", "cache"])),
@@ -41,12 +43,161 @@ const CACHE_LOCATIONS: &[(&str, CacheLocation)] = &[
         "Gradle",
         CacheLocation::UserProfileNested(&[".gradle", "caches"]),
     ),
+    // Windows package managers
+    ("winget", CacheLocation::Temp(WINGET_TEMP_DIR)),
+    ("Scoop", CacheLocation::Scoop("cache")),
+    ("Chocolatey", CacheLocation::Temp(CHOCOLATEY_TEMP_DIR)),
+    ("Chocolatey lib-bad", CacheLocation::Chocolatey("lib-bad")),
+];
+
+/// winget's download cache in %TEMP%
+const WINGET_TEMP_DIR: &str = "WinGet";
+
+/// Chocolatey's download cache in %TEMP%
+const CHOCOLATEY_TEMP_DIR: &str = "chocolatey";
+
+/// The package manager's own command for clearing its cache, run through
+/// `cmd /C` (npm, yarn, pnpm and scoop are .cmd shims) when
+/// `[categories.cache] use_manager_commands` is on
+const CLEAR_COMMANDS: &[(&str, &[&str])] = &[
+    ("npm", &["npm", "cache", "clean", "--force"]),
+    ("pip", &["pip", "cache", "purge"]),
+    ("yarn", &["yarn", "cache", "clean"]),
+    ("pnpm-store", &["pnpm", "store", "prune"]),
+    (
+        "NuGet",
+        &["dotnet", "nuget", "locals", "http-cache", "--clear"],
+    ),
+    ("Scoop", &["scoop", "cache", "rm", "*"]),
+    ("Chocolatey", &["choco", "cache", "remove", "-y"]),
 ];
 
 enum CacheLocation {
     LocalAppData(&'static str),
     LocalAppDataNested(&'static [&'static str]),
     UserProfileNested(&'static [&'static str]),
+    /// In %TEMP%
+    Temp(&'static str),
+    /// In the Scoop root (%SCOOP%, default ~\scoop)
+    Scoop(&'static str),
+    /// In the Chocolatey install folder (%ChocolateyInstall%)
+    Chocolatey(&'static str),
+}
+
+/// Base folders the cache locations are relative to
+struct Roots {
+    local_appdata: Option<PathBuf>,
+    userprofile: Option<PathBuf>,
+    temp: Option<PathBuf>,
+    scoop: Option<PathBuf>,
+    chocolatey: Option<PathBuf>,
+}
+
+impl Roots {
+    /// The current user's folders
+    fn current() -> Self {
+        let local_appdata = env_path("LOCALAPPDATA");
+        let userprofile = env_path("USERPROFILE");
+        Self {
+            temp: env_path("TEMP").or_else(|| local_appdata.as_ref().map(|p| p.join("Temp"))),
+            scoop: env_path("SCOOP").or_else(|| userprofile.as_ref().map(|p| p.join("scoop"))),
+            chocolatey: env_path("ChocolateyInstall")
+                .or_else(|| env_path("ProgramData").map(|p| p.join("chocolatey"))),
+            local_appdata,
+            userprofile,
+        }
+    }
+
+    /// Another user's folders. Chocolatey is machine-wide, so it's only
+    /// reported by the current user's scan.
+    fn profile(profile: &UserProfile) -> Self {
+        let local_appdata = profile.local_appdata();
+        Self {
+            temp: Some(local_appdata.join("Temp")),
+            scoop: Some(profile.home.join("scoop")),
+            chocolatey: None,
+            local_appdata: Some(local_appdata),
+            userprofile: Some(profile.home.clone()),
+        }
+    }
+}
+
+fn env_path(var: &str) -> Option<PathBuf> {
+    env::var_os(var)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+impl CacheLocation {
+    /// Full path of this cache under the given roots
+    fn resolve(&self, roots: &Roots) -> Option<PathBuf> {
+        let (base, subpaths): (Option<&PathBuf>, &[&str]) = match self {
+            CacheLocation::LocalAppData(subpath) => {
+                (roots.local_appdata.as_ref(), std::slice::from_ref(subpath))
+            }
+            CacheLocation::LocalAppDataNested(subpaths) => {
+                (roots.local_appdata.as_ref(), *subpaths)
+            }
+            CacheLocation::UserProfileNested(subpaths) => (roots.userprofile.as_ref(), *subpaths),
+            CacheLocation::Temp(subpath) => (roots.temp.as_ref(), std::slice::from_ref(subpath)),
+            CacheLocation::Scoop(subpath) => (roots.scoop.as_ref(), std::slice::from_ref(subpath)),
+            CacheLocation::Chocolatey(subpath) => {
+                (roots.chocolatey.as_ref(), std::slice::from_ref(subpath))
+            }
+        };
+        base.map(|base| {
+            subpaths
+                .iter()
+                .fold(base.clone(), |path, sub| path.join(sub))
+        })
+    }
+}
+
+/// Whether a folder directly in %TEMP% is a package manager cache reported by
+/// this category (the Temp Files category skips those)
+pub fn is_temp_cache_dir(name: &str) -> bool {
+    name.eq_ignore_ascii_case(WINGET_TEMP_DIR) || name.eq_ignore_ascii_case(CHOCOLATEY_TEMP_DIR)
+}
+
+/// Package manager owning a cache folder found by this category
+pub fn manager_name(path: &Path) -> Option<&'static str> {
+    let roots = Roots::current();
+    CACHE_LOCATIONS
+        .iter()
+        .find(|(_, location)| location.resolve(&roots).is_some_and(|cache| cache == path))
+        .map(|(name, _)| *name)
+}
+
+/// Label for a cache folder in the results, e.g. "npm cache"
+pub fn display_name(path: &Path) -> Option<String> {
+    manager_name(path).map(|name| format!("{} cache", name))
+}
+
+/// Clear a cache folder with its package manager's own command
+///
+/// Returns `Ok(false)` when the manager has no such command (the folder is
+/// then deleted the normal way), and an error when the command failed or isn't
+/// installed.
+pub fn clear_with_manager(path: &Path) -> Result<bool> {
+    let Some(command) = manager_name(path).and_then(|name| {
+        CLEAR_COMMANDS
+            .iter()
+            .find(|(manager, _)| *manager == name)
+            .map(|(_, command)| *command)
+    }) else {
+        return Ok(false);
+    };
+
+    let output = std::process::Command::new("cmd")
+        .arg("/C")
+        .args(command)
+        .output()
+        .with_context(|| format!("Failed to run {}", command.join(" ")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("{} failed: {}", command.join(" "), stderr.trim());
+    }
+    Ok(true)
 }
 
 /// Scan for package manager cache directories
@@ -57,8 +208,7 @@ pub fn scan(_root: &Path, config: &Config, output_mode: OutputMode) -> Result<Ca
     let mut result = CategoryResult::default();
     let mut candidates = Vec::new();
 
-    let local_appdata = env::var("LOCALAPPDATA").ok().map(PathBuf::from);
-    let userprofile = env::var("USERPROFILE").ok().map(PathBuf::from);
+    let roots = Roots::current();
 
     if output_mode != OutputMode::Quiet {
         println!(
@@ -70,23 +220,7 @@ pub fn scan(_root: &Path, config: &Config, output_mode: OutputMode) -> Result<Ca
 
     // 1. Collect candidate paths
     for (name, location) in CACHE_LOCATIONS {
-        let cache_path = match location {
-            CacheLocation::LocalAppData(subpath) => local_appdata.as_ref().map(|p| p.join(subpath)),
-            CacheLocation::LocalAppDataNested(subpaths) => local_appdata.as_ref().map(|p| {
-                let mut path = p.clone();
-                for subpath in *subpaths {
-                    path = path.join(subpath);
-                }
-                path
-            }),
-            CacheLocation::UserProfileNested(subpaths) => userprofile.as_ref().map(|p| {
-                let mut path = p.clone();
-                for subpath in *subpaths {
-                    path = path.join(subpath);
-                }
-                path
-            }),
-        };
+        let cache_path = location.resolve(&roots);
 
         if let Some(cache_path) = cache_path {
             if cache_path.exists() && !config.is_excluded(&cache_path) {
@@ -99,17 +233,17 @@ pub fn scan(_root: &Path, config: &Config, output_mode: OutputMode) -> Result<Ca
     }
 
     // 2. Calculate sizes sequentially (one parallel walk at a time)
-    let mut paths_with_sizes: Vec<(PathBuf, u64)> = candidates
+    let mut paths_with_sizes: Vec<(&str, PathBuf, u64)> = candidates
         .iter()
-        .map(|(_name, p)| {
+        .map(|(name, p)| {
             let size = utils::calculate_dir_size(p);
-            (p.clone(), size)
+            (**name, p.clone(), size)
         })
-        .filter(|(_, size)| *size > 0)
+        .filter(|(_, _, size)| *size > 0)
         .collect();
 
     // Sort by size descending
-    paths_with_sizes.sort_by(|a, b| b.1.cmp(&a.1));
+    paths_with_sizes.sort_by(|a, b| b.2.cmp(&a.2));
 
     // Show found caches
     if output_mode != OutputMode::Quiet && !paths_with_sizes.is_empty() {
@@ -125,11 +259,12 @@ pub fn scan(_root: &Path, config: &Config, output_mode: OutputMode) -> Result<Ca
             OutputMode::Quiet => 0,
         };
 
-        for (i, (path, size)) in paths_with_sizes.iter().take(show_count).enumerate() {
+        for (i, (name, path, size)) in paths_with_sizes.iter().take(show_count).enumerate() {
             let size_str = bytesize::to_string(*size, false);
             println!(
-                "      {} {} ({})",
+                "      {} {}: {} ({})",
                 Theme::muted("→"),
+                name,
                 path.display(),
                 Theme::size(&size_str)
             );
@@ -145,7 +280,7 @@ pub fn scan(_root: &Path, config: &Config, output_mode: OutputMode) -> Result<Ca
         }
     }
 
-    for (path, size) in paths_with_sizes {
+    for (_name, path, size) in paths_with_sizes {
         result.items += 1;
         result.size_bytes += size;
         result.paths.push(path);
@@ -160,6 +295,7 @@ pub fn scan_with_progress(
     _root: &Path,
     config: &Config,
     tx: &Sender<ScanProgressEvent>,
+    cancel: &CancellationToken,
 ) -> Result<CategoryResult> {
     const CATEGORY: &str = "Package Cache";
     let total = CACHE_LOCATIONS.len() as u64;
@@ -167,8 +303,7 @@ pub fn scan_with_progress(
     let mut result = CategoryResult::default();
     let mut files_with_sizes: Vec<(PathBuf, u64)> = Vec::new();
 
-    let local_appdata = env::var("LOCALAPPDATA").ok().map(PathBuf::from);
-    let userprofile = env::var("USERPROFILE").ok().map(PathBuf::from);
+    let roots = Roots::current();
 
     let _ = tx.send(ScanProgressEvent::CategoryStarted {
         category: CATEGORY.to_string(),
@@ -181,27 +316,13 @@ pub fn scan_with_progress(
 
     // Scan known package manager caches
     for (idx, (_name, location)) in CACHE_LOCATIONS.iter().enumerate() {
-        let cache_path = match location {
-            CacheLocation::LocalAppData(subpath) => local_appdata.as_ref().map(|p| p.join(subpath)),
-            CacheLocation::LocalAppDataNested(subpaths) => local_appdata.as_ref().map(|p| {
-                let mut path = p.clone();
-                for subpath in *subpaths {
-                    path = path.join(subpath);
-                }
-                path
-            }),
-            CacheLocation::UserProfileNested(subpaths) => userprofile.as_ref().map(|p| {
-                let mut path = p.clone();
-                for subpath in *subpaths {
-                    path = path.join(subpath);
-                }
-                path
-            }),
-        };
+        cancel.check()?;
+        let cache_path = location.resolve(&roots);
 
         if let Some(cache_path) = cache_path {
             if cache_path.exists() && !config.is_excluded(&cache_path) {
-                let size = utils::calculate_dir_size_with_progress(&cache_path, &on_path);
+                let size = utils::calculate_dir_size_cancellable(&cache_path, &on_path, cancel)
+                    .ok_or(crate::cancel::Cancelled)?;
                 if size > 0 {
                     files_with_sizes.push((cache_path.clone(), size));
                 }
@@ -242,6 +363,40 @@ pub fn scan_with_progress(
     Ok(result)
 }
 
+/// Package caches of another user's profile (`--scope all-users`)
+pub fn scan_profile(
+    profile: &UserProfile,
+    config: &Config,
+    cancel: &CancellationToken,
+) -> Result<CategoryResult> {
+    let mut result = CategoryResult::default();
+    let roots = Roots::profile(profile);
+    let mut paths_with_sizes: Vec<(PathBuf, u64)> = Vec::new();
+
+    for (_name, location) in CACHE_LOCATIONS {
+        cancel.check()?;
+        let Some(cache_path) = location.resolve(&roots) else {
+            continue;
+        };
+        if cache_path.exists() && !config.is_excluded(&cache_path) {
+            let size = utils::calculate_dir_size_cancellable(&cache_path, &|_: &Path| {}, cancel)
+                .ok_or(crate::cancel::Cancelled)?;
+            if size > 0 {
+                paths_with_sizes.push((cache_path, size));
+            }
+        }
+    }
+
+    paths_with_sizes.sort_by(|a, b| b.1.cmp(&a.1));
+    for (path, size) in paths_with_sizes {
+        result.items += 1;
+        result.size_bytes += size;
+        result.paths.push(path);
+    }
+
+    Ok(result)
+}
+
 /// Clean (delete) a package cache directory by moving it to the Recycle Bin
 pub fn clean(path: &Path) -> Result<()> {
     if !path.exists() {
@@ -255,3 +410,43 @@ pub fn clean(path: &Path) -> Result<()> {
     })?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows_package_manager_locations() {
+        let roots = Roots {
+            local_appdata: Some(PathBuf::from("home").join("AppData").join("Local")),
+            userprofile: Some(PathBuf::from("home")),
+            temp: Some(PathBuf::from("tmp")),
+            scoop: Some(PathBuf::from("scoop")),
+            chocolatey: None,
+        };
+        let resolve = |manager: &str| {
+            CACHE_LOCATIONS
+                .iter()
+                .find(|(name, _)| *name == manager)
+                .and_then(|(_, location)| location.resolve(&roots))
+        };
+
+        assert_eq!(resolve("winget"), Some(PathBuf::from("tmp").join("WinGet")));
+        assert_eq!(
+            resolve("Chocolatey"),
+            Some(PathBuf::from("tmp").join("chocolatey"))
+        );
+        assert_eq!(resolve("Scoop"), Some(PathBuf::from("scoop").join("cache")));
+        // Machine-wide Chocolatey folders aren't reported without a root
+        assert_eq!(resolve("Chocolatey lib-bad"), None);
+
+        assert!(is_temp_cache_dir("winget"));
+        assert!(is_temp_cache_dir("Chocolatey"));
+        assert!(!is_temp_cache_dir("npm-cache"));
+
+        // Every clear command belongs to a known cache
+        for (manager, _) in CLEAR_COMMANDS {
+            assert!(CACHE_LOCATIONS.iter().any(|(name, _)| name == manager));
+        }
+    }
+}
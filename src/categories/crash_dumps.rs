@@ -0,0 +1,219 @@
+//! Crash dumps and Windows Error Reporting files
+//!
+//! Covers:
+//! - `%LOCALAPPDATA%\CrashDumps\*.dmp` (user-mode application dumps)
+//! - WER `ReportQueue` / `ReportArchive` folders, per user and machine-wide
+//!   under `%ProgramData%` (one item per report folder)
+//! - `%SystemRoot%\Minidump\*.dmp` and `%SystemRoot%\MEMORY.DMP` (kernel dumps)
+//!
+//! Only items older than `thresholds.crash_dump_age_days` are reported, so a
+//! crash that is still being investigated is never offered for cleaning.
+//! Machine-wide locations need admin rights and are skipped when unreadable.
+
+use crate::config::Config;
+use crate::output::CategoryResult;
+use crate::utils;
+use anyhow::{Context, Result};
+use std::env;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// How a dump location is turned into items
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    /// Every `*.dmp` file directly inside the folder
+    DumpFiles,
+    /// Every report folder directly inside the folder
+    ReportFolders,
+    /// The path itself is a single dump file
+    SingleFile,
+}
+
+fn windows_dir() -> PathBuf {
+    env::var("SystemRoot")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(r"C:\Windows"))
+}
+
+/// Every location this category looks at
+fn dump_locations() -> Vec<(PathBuf, Layout)> {
+    let mut locations = Vec::new();
+
+    if let Ok(local) = env::var("LOCALAPPDATA") {
+        let local = PathBuf::from(local);
+        locations.push((local.join("CrashDumps"), Layout::DumpFiles));
+        let wer = local.join("Microsoft").join("Windows").join("WER");
+        locations.push((wer.join("ReportQueue"), Layout::ReportFolders));
+        locations.push((wer.join("ReportArchive"), Layout::ReportFolders));
+    }
+
+    let program_data = env::var("ProgramData")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(r"C:\ProgramData"));
+    let wer = program_data.join("Microsoft").join("Windows").join("WER");
+    locations.push((wer.join("ReportQueue"), Layout::ReportFolders));
+    locations.push((wer.join("ReportArchive"), Layout::ReportFolders));
+
+    let windows = windows_dir();
+    locations.push((windows.join("Minidump"), Layout::DumpFiles));
+    locations.push((windows.join("MEMORY.DMP"), Layout::SingleFile));
+
+    locations
+}
+
+fn is_dump_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.to_string_lossy().eq_ignore_ascii_case("dmp"))
+}
+
+/// Whether `path` is one of the items this category reports
+///
+/// Used by [`clean`] so it never deletes anything outside the dump locations.
+fn is_dump_item(path: &Path) -> bool {
+    let normalize = |p: &Path| utils::display_path(p).to_lowercase();
+    let target = normalize(path);
+    dump_locations()
+        .iter()
+        .any(|(location, layout)| match layout {
+            Layout::SingleFile => normalize(location) == target,
+            Layout::DumpFiles | Layout::ReportFolders => {
+                let parent_matches = path
+                    .parent()
+                    .is_some_and(|parent| normalize(parent) == normalize(location));
+                parent_matches && (*layout == Layout::ReportFolders || is_dump_file(path))
+            }
+        })
+}
+
+/// Age of an item based on its last modification
+fn age(metadata: &std::fs::Metadata) -> Option<Duration> {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+}
+
+/// Size and age of one item, or None if it can't be read
+fn measure(path: &Path) -> Option<(u64, Duration)> {
+    let metadata = utils::safe_metadata(path).ok()?;
+    let age = age(&metadata)?;
+    let size = if metadata.is_dir() {
+        utils::calculate_dir_size(path)
+    } else {
+        metadata.len()
+    };
+    Some((size, age))
+}
+
+/// Candidate items of one location (before the age filter)
+fn location_items(location: &Path, layout: Layout) -> Vec<PathBuf> {
+    if layout == Layout::SingleFile {
+        return if utils::safe_exists(location) {
+            vec![location.to_path_buf()]
+        } else {
+            Vec::new()
+        };
+    }
+
+    let Ok(entries) = utils::safe_read_dir(location) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| match layout {
+            Layout::DumpFiles => is_dump_file(path) && !utils::safe_is_dir(path),
+            Layout::ReportFolders => utils::safe_is_dir(path),
+            Layout::SingleFile => false,
+        })
+        .collect()
+}
+
+/// Scan for crash dumps and error reports older than the configured age
+pub fn scan(_root: &Path, config: &Config) -> Result<CategoryResult> {
+    let mut result = CategoryResult::default();
+    let min_age = Duration::from_secs(config.thresholds.crash_dump_age_days * 24 * 60 * 60);
+
+    let mut items: Vec<(PathBuf, u64)> = Vec::new();
+    for (location, layout) in dump_locations() {
+        for path in location_items(&location, layout) {
+            if config.is_excluded(&path) {
+                continue;
+            }
+            let Some((size, age)) = measure(&path) else {
+                continue;
+            };
+            if age < min_age || size == 0 {
+                continue;
+            }
+            items.push((path, size));
+        }
+    }
+
+    // Largest first - a single MEMORY.DMP often outweighs everything else
+    items.sort_by(|a, b| b.1.cmp(&a.1));
+    result.items = items.len();
+    result.size_bytes = items.iter().map(|(_, size)| size).sum();
+    result.paths = items.into_iter().map(|(path, _)| path).collect();
+    Ok(result)
+}
+
+/// Size and age of an item for verbose output, e.g. "1.2 GB, 12 days old"
+pub fn describe(path: &Path) -> Option<String> {
+    let (size, age) = measure(path)?;
+    let days = age.as_secs() / (24 * 60 * 60);
+    let age = match days {
+        0 => "today".to_string(),
+        1 => "1 day old".to_string(),
+        n => format!("{} days old", n),
+    };
+    Some(format!("{}, {}", bytesize::to_string(size, false), age))
+}
+
+/// Delete a crash dump or error report
+///
+/// Machine-wide dumps (under Windows or ProgramData) are deleted permanently -
+/// the Recycle Bin can't hold them for other users anyway. Per-user dumps go
+/// through the normal Recycle Bin path.
+pub fn clean(path: &Path) -> Result<()> {
+    if !utils::safe_exists(path) {
+        return Ok(());
+    }
+    if !is_dump_item(path) {
+        anyhow::bail!("{} is not a crash dump or error report", path.display());
+    }
+
+    if utils::is_system_path(path) {
+        crate::history::checksums::capture(path);
+        let removed = if utils::safe_is_dir(path) {
+            utils::safe_remove_dir_all(path)
+        } else {
+            utils::safe_remove_file(path)
+        };
+        removed.with_context(|| format!("Failed to delete crash dump: {}", path.display()))?;
+        return Ok(());
+    }
+
+    crate::trash_ops::delete(path)
+        .with_context(|| format!("Failed to delete crash dump: {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_items_filters_by_layout() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(temp_dir.path().join("app.exe.1234.dmp"), b"dump").unwrap();
+        std::fs::write(temp_dir.path().join("notes.txt"), b"text").unwrap();
+        std::fs::create_dir(temp_dir.path().join("AppCrash_foo_123")).unwrap();
+
+        let dumps = location_items(temp_dir.path(), Layout::DumpFiles);
+        assert_eq!(dumps, vec![temp_dir.path().join("app.exe.1234.dmp")]);
+
+        let reports = location_items(temp_dir.path(), Layout::ReportFolders);
+        assert_eq!(reports, vec![temp_dir.path().join("AppCrash_foo_123")]);
+    }
+}
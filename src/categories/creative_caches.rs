@@ -0,0 +1,284 @@
+//! Video editing and creative app scratch data
+//!
+//! Built-in locations:
+//! - Adobe (Premiere Pro, After Effects, Media Encoder): the shared
+//!   `%APPDATA%\Adobe\Common\Media Cache Files` and `Peak Files` folders
+//! - DaVinci Resolve: `CacheClip` render caches in `Videos` and the Resolve
+//!   support folder
+//! - OBS Studio: recordings that were already remuxed (`clip.mkv` next to
+//!   `clip.mp4`) in every profile's recording folder
+//!
+//! More folders can be added with `categories.creative.custom_paths` (e.g. a
+//! media cache moved to another drive). Only entries untouched for
+//! `categories.creative.min_age_days` are reported, so a project that is open
+//! in an editor keeps its cache.
+
+use crate::config::Config;
+use crate::output::CategoryResult;
+use crate::utils;
+use anyhow::Result;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use walkdir::WalkDir;
+
+/// App a cache belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreativeApp {
+    Adobe,
+    Resolve,
+    Obs,
+    Custom,
+}
+
+impl CreativeApp {
+    pub const ALL: [CreativeApp; 4] = [
+        CreativeApp::Adobe,
+        CreativeApp::Resolve,
+        CreativeApp::Obs,
+        CreativeApp::Custom,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CreativeApp::Adobe => "Adobe Media Cache",
+            CreativeApp::Resolve => "DaVinci Resolve",
+            CreativeApp::Obs => "OBS remux leftovers",
+            CreativeApp::Custom => "Custom",
+        }
+    }
+}
+
+/// Recording formats OBS remuxes, and what they are remuxed into
+const REMUX_SOURCES: &[&str] = &["mkv", "flv", "ts"];
+const REMUX_TARGETS: &[&str] = &["mp4", "mov"];
+
+/// Built-in cache folders whose direct children are reported (age permitting)
+fn builtin_cache_folders() -> Vec<(CreativeApp, PathBuf)> {
+    let mut folders = Vec::new();
+
+    if let Ok(roaming) = env::var("APPDATA") {
+        let roaming = PathBuf::from(roaming);
+        let adobe = roaming.join("Adobe").join("Common");
+        folders.push((CreativeApp::Adobe, adobe.join("Media Cache Files")));
+        folders.push((CreativeApp::Adobe, adobe.join("Peak Files")));
+        folders.push((
+            CreativeApp::Resolve,
+            roaming
+                .join("Blackmagic Design")
+                .join("DaVinci Resolve")
+                .join("Support")
+                .join("CacheClip"),
+        ));
+    }
+    if let Ok(profile) = env::var("USERPROFILE") {
+        folders.push((
+            CreativeApp::Resolve,
+            PathBuf::from(profile).join("Videos").join("CacheClip"),
+        ));
+    }
+
+    folders
+}
+
+/// Built-in cache folders plus `categories.creative.custom_paths`
+fn cache_folders(config: &Config) -> Vec<(CreativeApp, PathBuf)> {
+    let mut folders = builtin_cache_folders();
+    for custom in &config.categories.creative.custom_paths {
+        let custom = PathBuf::from(custom);
+        // A typo like `C:\` must not turn a whole drive into cache
+        if custom.parent().is_some() && !utils::is_system_path(&custom) {
+            folders.push((CreativeApp::Custom, custom));
+        }
+    }
+    folders
+}
+
+/// Recording folders of every OBS profile (falls back to `Videos`)
+fn obs_recording_folders() -> Vec<PathBuf> {
+    let mut folders: Vec<PathBuf> = Vec::new();
+    if let Ok(roaming) = env::var("APPDATA") {
+        let profiles = PathBuf::from(roaming)
+            .join("obs-studio")
+            .join("basic")
+            .join("profiles");
+        if let Ok(entries) = utils::safe_read_dir(&profiles) {
+            for entry in entries.flatten() {
+                let Ok(ini) = std::fs::read_to_string(entry.path().join("basic.ini")) else {
+                    continue;
+                };
+                for folder in recording_paths_from_ini(&ini) {
+                    if !folders.contains(&folder) {
+                        folders.push(folder);
+                    }
+                }
+            }
+        }
+    }
+    if folders.is_empty() {
+        if let Ok(profile) = env::var("USERPROFILE") {
+            folders.push(PathBuf::from(profile).join("Videos"));
+        }
+    }
+    folders
+}
+
+/// Recording paths of an OBS profile (`basic.ini`, simple and advanced output)
+fn recording_paths_from_ini(ini: &str) -> Vec<PathBuf> {
+    ini.lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            let is_path_key = matches!(key.trim(), "FilePath" | "RecFilePath");
+            (is_path_key && !value.trim().is_empty())
+                .then(|| PathBuf::from(value.trim().replace('/', "\\")))
+        })
+        .collect()
+}
+
+fn extension_in(path: &Path, extensions: &[&str]) -> bool {
+    path.extension().is_some_and(|ext| {
+        let ext = ext.to_string_lossy().to_lowercase();
+        extensions.contains(&ext.as_str())
+    })
+}
+
+/// Recordings in `folder` that have a remuxed copy next to them
+fn remux_leftovers(folder: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = utils::safe_read_dir(folder) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| extension_in(path, REMUX_SOURCES))
+        .filter(|path| {
+            REMUX_TARGETS.iter().any(|target| {
+                utils::safe_metadata(&path.with_extension(target)).is_ok_and(|m| m.len() > 0)
+            })
+        })
+        .collect()
+}
+
+/// Total size and newest modification of a file or folder
+fn measure(path: &Path) -> (u64, Option<SystemTime>) {
+    let mut size = 0u64;
+    let mut newest: Option<SystemTime> = None;
+    for entry in WalkDir::new(path).follow_links(false).into_iter().flatten() {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if let Ok(modified) = metadata.modified() {
+            newest = newest.max(Some(modified));
+        }
+        if metadata.is_file() {
+            size += metadata.len();
+        }
+    }
+    (size, newest)
+}
+
+/// App owning a reported path (anything not built in came from `custom_paths`)
+pub fn app_for(path: &Path) -> CreativeApp {
+    if let Some((app, _)) = builtin_cache_folders()
+        .into_iter()
+        .find(|(_, folder)| path.starts_with(folder))
+    {
+        return app;
+    }
+    let is_remux_leftover = extension_in(path, REMUX_SOURCES)
+        && REMUX_TARGETS
+            .iter()
+            .any(|target| utils::safe_exists(&path.with_extension(target)));
+    if is_remux_leftover {
+        CreativeApp::Obs
+    } else {
+        CreativeApp::Custom
+    }
+}
+
+/// Display name of the app owning a reported path (for grouping)
+pub fn app_name(path: &Path) -> Option<&'static str> {
+    Some(app_for(path).name())
+}
+
+/// Scan creative app caches for entries older than the configured age
+pub fn scan(_root: &Path, config: &Config) -> Result<CategoryResult> {
+    let min_age = Duration::from_secs(config.categories.creative.min_age_days * 24 * 60 * 60);
+    let now = SystemTime::now();
+    let old_enough = |newest: Option<SystemTime>| {
+        newest
+            .and_then(|t| now.duration_since(t).ok())
+            .is_some_and(|age| age >= min_age)
+    };
+
+    let mut candidates: Vec<(CreativeApp, PathBuf)> = Vec::new();
+    for (app, folder) in cache_folders(config) {
+        if config.is_excluded(&folder) {
+            continue;
+        }
+        if let Ok(entries) = utils::safe_read_dir(&folder) {
+            candidates.extend(entries.flatten().map(|entry| (app, entry.path())));
+        }
+    }
+    for folder in obs_recording_folders() {
+        candidates.extend(
+            remux_leftovers(&folder)
+                .into_iter()
+                .map(|path| (CreativeApp::Obs, path)),
+        );
+    }
+
+    let mut found: Vec<(CreativeApp, PathBuf, u64)> = candidates
+        .into_iter()
+        .filter(|(_, path)| !config.is_excluded(path))
+        .filter_map(|(app, path)| {
+            let (size, newest) = measure(&path);
+            (size > 0 && old_enough(newest)).then_some((app, path, size))
+        })
+        .collect();
+
+    // App order first, then biggest first
+    found.sort_by(|a, b| {
+        let order = |app: &CreativeApp| CreativeApp::ALL.iter().position(|x| x == app);
+        order(&a.0).cmp(&order(&b.0)).then(b.2.cmp(&a.2))
+    });
+
+    let mut result = CategoryResult::default();
+    for (_, path, size) in found {
+        result.items += 1;
+        result.size_bytes += size;
+        result.paths.push(path);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recording_paths_from_ini() {
+        let ini = "[SimpleOutput]\nFilePath=C:/Users/me/Videos\nRecFormat2=mkv\n\n[AdvOut]\nRecFilePath=D:/Recordings\nFFFilePath=\n";
+        assert_eq!(
+            recording_paths_from_ini(ini),
+            vec![
+                PathBuf::from(r"C:\Users\me\Videos"),
+                PathBuf::from(r"D:\Recordings")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_remux_leftovers_need_a_remuxed_copy() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(dir.join("2024-01-01 10-00-00.mkv"), b"original").unwrap();
+        std::fs::write(dir.join("2024-01-01 10-00-00.mp4"), b"remuxed").unwrap();
+        std::fs::write(dir.join("2024-02-01 10-00-00.mkv"), b"not remuxed").unwrap();
+
+        assert_eq!(
+            remux_leftovers(dir),
+            vec![dir.join("2024-01-01 10-00-00.mkv")]
+        );
+    }
+}
@@ -0,0 +1,206 @@
+//! iPhone / iPad backups made by iTunes or the Apple Devices app
+//!
+//! Each backup is a folder named after the device UDID in
+//! `%APPDATA%\Apple Computer\MobileSync\Backup` (or
+//! `%USERPROFILE%\Apple\MobileSync\Backup` for the Microsoft Store build).
+//! Device name, model and backup date come from the backup's `Info.plist`.
+//!
+//! Every backup is one item, oldest first, and none is pre-selected: a backup
+//! may be the only copy of a device's data, so the user picks which to delete.
+
+use crate::config::Config;
+use crate::output::CategoryResult;
+use crate::utils;
+use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// What `Info.plist` says about a backup
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BackupInfo {
+    pub device_name: Option<String>,
+    pub product_type: Option<String>,
+    pub ios_version: Option<String>,
+    pub last_backup: Option<DateTime<Utc>>,
+}
+
+/// Folders holding one subfolder per backup
+fn backup_roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    if let Ok(roaming) = env::var("APPDATA") {
+        roots.push(
+            PathBuf::from(roaming)
+                .join("Apple Computer")
+                .join("MobileSync")
+                .join("Backup"),
+        );
+    }
+    if let Ok(profile) = env::var("USERPROFILE") {
+        roots.push(
+            PathBuf::from(profile)
+                .join("Apple")
+                .join("MobileSync")
+                .join("Backup"),
+        );
+    }
+    roots
+}
+
+/// Whether a folder looks like a device backup
+fn is_backup(path: &Path) -> bool {
+    utils::safe_is_dir(path)
+        && ["Info.plist", "Manifest.plist", "Manifest.db"]
+            .iter()
+            .any(|marker| utils::safe_exists(&path.join(marker)))
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Value following `<key>{key}</key>` in an XML plist (`<string>` or `<date>`)
+fn plist_value(xml: &str, key: &str) -> Option<String> {
+    let marker = format!("<key>{}</key>", key);
+    let rest = xml[xml.find(&marker)? + marker.len()..]
+        .trim_start()
+        .strip_prefix('<')?;
+    let (tag, rest) = rest.split_once('>')?;
+    if !matches!(tag, "string" | "date") {
+        return None;
+    }
+    let value = &rest[..rest.find(&format!("</{}>", tag))?];
+    Some(unescape_xml(value.trim()))
+}
+
+/// Parse the XML `Info.plist` of a backup
+fn parse_info(xml: &str) -> BackupInfo {
+    BackupInfo {
+        device_name: plist_value(xml, "Device Name"),
+        product_type: plist_value(xml, "Product Type"),
+        ios_version: plist_value(xml, "Product Version"),
+        last_backup: plist_value(xml, "Last Backup Date")
+            .and_then(|date| DateTime::parse_from_rfc3339(&date).ok())
+            .map(|date| date.with_timezone(&Utc)),
+    }
+}
+
+/// Read a backup's `Info.plist`; the backup date falls back to the folder's modification time
+pub fn backup_info(path: &Path) -> BackupInfo {
+    let mut info = std::fs::read_to_string(path.join("Info.plist"))
+        .map(|xml| parse_info(&xml))
+        .unwrap_or_default();
+    if info.last_backup.is_none() {
+        info.last_backup = utils::safe_metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .map(DateTime::<Utc>::from);
+    }
+    info
+}
+
+/// Scan for device backups
+pub fn scan(_root: &Path, config: &Config) -> Result<CategoryResult> {
+    let mut found: Vec<(PathBuf, Option<DateTime<Utc>>, u64)> = Vec::new();
+    for root in backup_roots() {
+        let Ok(entries) = utils::safe_read_dir(&root) else {
+            continue;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if !is_backup(&path) || config.is_excluded(&path) {
+                continue;
+            }
+            let size = utils::calculate_dir_size(&path);
+            if size > 0 {
+                found.push((path.clone(), backup_info(&path).last_backup, size));
+            }
+        }
+    }
+
+    // Oldest backups first
+    found.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let mut result = CategoryResult::default();
+    for (path, _, size) in found {
+        result.items += 1;
+        result.size_bytes += size;
+        result.paths.push(path);
+    }
+    Ok(result)
+}
+
+/// Label for a backup, e.g. "Alice's iPhone - 2024-05-01"
+pub fn display_name(path: &Path) -> Option<String> {
+    let info = backup_info(path);
+    let device = info.device_name.or(info.product_type)?;
+    Some(match info.last_backup {
+        Some(date) => format!(
+            "{} - {}",
+            device,
+            date.with_timezone(&Local).format("%Y-%m-%d")
+        ),
+        None => device,
+    })
+}
+
+/// Device, model and backup date for verbose output
+pub fn describe(path: &Path) -> Option<String> {
+    let info = backup_info(path);
+    let mut parts: Vec<String> = Vec::new();
+    if let Some(name) = info.device_name {
+        parts.push(name);
+    }
+    match (info.product_type, info.ios_version) {
+        (Some(model), Some(version)) => parts.push(format!("{}, iOS {}", model, version)),
+        (Some(model), None) => parts.push(model),
+        (None, Some(version)) => parts.push(format!("iOS {}", version)),
+        (None, None) => {}
+    }
+    if let Some(date) = info.last_backup {
+        parts.push(format!(
+            "backed up {}",
+            date.with_timezone(&Local).format("%Y-%m-%d")
+        ));
+    }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_info_plist() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0">
+<dict>
+	<key>Build Version</key>
+	<string>21F90</string>
+	<key>Device Name</key>
+	<string>Alice&apos;s iPhone</string>
+	<key>Last Backup Date</key>
+	<date>2024-05-01T08:30:00Z</date>
+	<key>Product Type</key>
+	<string>iPhone15,2</string>
+	<key>Product Version</key>
+	<string>17.5.1</string>
+	<key>Installed Applications</key>
+	<array/>
+</dict>
+</plist>"#;
+
+        let info = parse_info(xml);
+        assert_eq!(info.device_name.as_deref(), Some("Alice's iPhone"));
+        assert_eq!(info.product_type.as_deref(), Some("iPhone15,2"));
+        assert_eq!(info.ios_version.as_deref(), Some("17.5.1"));
+        assert_eq!(
+            info.last_backup.map(|d| d.to_rfc3339()),
+            Some("2024-05-01T08:30:00+00:00".to_string())
+        );
+        assert_eq!(plist_value(xml, "Installed Applications"), None);
+    }
+}
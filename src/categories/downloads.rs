@@ -1,7 +1,9 @@
+use crate::cancel::CancellationToken;
 use crate::config::Config;
 use crate::output::{CategoryResult, OutputMode};
 use crate::scan_events::{ScanPathReporter, ScanProgressEvent};
 use crate::theme::Theme;
+use crate::users::UserProfile;
 use anyhow::{Context, Result};
 use bytesize;
 use chrono::{Duration, Utc};
@@ -226,6 +228,56 @@ pub fn scan_with_progress(
     Ok(result)
 }
 
+/// Old downloads of another user's profile (`--scope all-users`)
+pub fn scan_profile(
+    profile: &UserProfile,
+    min_age_days: u64,
+    config: &Config,
+    cancel: &CancellationToken,
+) -> Result<CategoryResult> {
+    let cutoff = Utc::now() - Duration::days(min_age_days as i64);
+    let mut result = CategoryResult::default();
+    let downloads_path = profile.downloads();
+    if !downloads_path.exists() {
+        return Ok(result);
+    }
+
+    let mut files_with_sizes: Vec<(PathBuf, u64)> = Vec::new();
+    for entry in WalkDir::new(&downloads_path)
+        .max_depth(1)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| !(e.file_type().is_dir() && config.is_excluded(e.path())))
+        .flatten()
+    {
+        cancel.check()?;
+        if entry.path() == downloads_path {
+            continue;
+        }
+        if let Ok(metadata) = entry.metadata() {
+            if !metadata.is_file() {
+                continue;
+            }
+            if let Ok(modified) = metadata.modified() {
+                let modified_dt: chrono::DateTime<Utc> = modified.into();
+                if modified_dt < cutoff {
+                    files_with_sizes.push((entry.path().to_path_buf(), metadata.len()));
+                }
+            }
+        }
+    }
+
+    files_with_sizes.sort_by(|a, b| b.1.cmp(&a.1));
+    files_with_sizes.truncate(MAX_RESULTS);
+    for (path, size) in files_with_sizes {
+        result.items += 1;
+        result.size_bytes += size;
+        result.paths.push(path);
+    }
+
+    Ok(result)
+}
+
 /// Clean (delete) a file from Downloads by moving it to the Recycle Bin
 pub fn clean(path: &Path) -> Result<()> {
     crate::trash_ops::delete(path)
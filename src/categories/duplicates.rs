@@ -1,3 +1,4 @@
+use crate::cancel::CancellationToken;
 use crate::config::{Config, DuplicatesConfig};
 use crate::output::CategoryResult;
 use crate::scan_events::{ScanPathReporter, ScanProgressEvent};
@@ -7,6 +8,7 @@ use blake3::Hasher;
 use jwalk::WalkDir;
 use memmap2::MmapOptions;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, Read};
@@ -46,7 +48,7 @@ fn is_duplicate_filename(filename: &str) -> bool {
 }
 
 /// Duplicate file group
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DuplicateGroup {
     pub hash: String,
     pub size: u64,
@@ -151,14 +153,21 @@ pub fn scan_with_config(
 }
 
 /// Scan for duplicate files with configuration + TUI progress updates (current file path).
+///
+/// Stops between files once `cancel` is cancelled and returns a
+/// [`Cancelled`](crate::cancel::Cancelled) error.
 pub fn scan_with_config_with_progress(
     root: &Path,
     config: Option<&DuplicatesConfig>,
     global_config: &Config,
     tx: &Sender<ScanProgressEvent>,
+    cancel: &CancellationToken,
 ) -> Result<DuplicatesResult> {
-    let reporter = Arc::new(ScanPathReporter::new("Duplicates", tx.clone(), 75));
-    scan_with_config_internal(root, config, global_config, Some(reporter))
+    let reporter =
+        Arc::new(ScanPathReporter::new("Duplicates", tx.clone(), 75).with_cancel(cancel));
+    let result = scan_with_config_internal(root, config, global_config, Some(reporter))?;
+    cancel.check()?;
+    Ok(result)
 }
 
 fn scan_with_config_internal(
@@ -168,6 +177,13 @@ fn scan_with_config_internal(
     reporter: Option<Arc<ScanPathReporter>>,
 ) -> Result<DuplicatesResult> {
     let mut result = DuplicatesResult::default();
+    let cancelled = || reporter.as_ref().is_some_and(|r| r.is_cancelled());
+
+    // Hashing a slow removable drive takes ages; see `drive::ScanStrategy`
+    if !crate::drive::hash_duplicates() {
+        return Ok(result);
+    }
+    let sequential = crate::drive::sequential_reads();
 
     // Determine scan roots: use config paths if provided, otherwise use root argument
     let mut scan_roots: Vec<PathBuf> = if let Some(cfg) = config {
@@ -229,9 +245,9 @@ fn scan_with_config_internal(
             WalkDir::new(&dir)
                 .max_depth(MAX_DEPTH)
                 .follow_links(false) // CRITICAL: Prevents infinite loops on Windows junctions/reparse points
-                .parallelism(jwalk::Parallelism::RayonDefaultPool {
-                    busy_timeout: std::time::Duration::from_secs(1),
-                })
+                .parallelism(crate::drive::walk_parallelism(
+                    std::time::Duration::from_secs(1),
+                ))
                 .process_read_dir(move |_depth, _path, _read_dir_state, children| {
                     // Filter out directories we don't want to descend into
                     children.retain(|entry| {
@@ -281,6 +297,11 @@ fn scan_with_config_internal(
                 })
                 .into_iter()
                 .filter_map(|e| e.ok())
+                .take_while(|_| {
+                    !reporter_for_walk
+                        .as_ref()
+                        .is_some_and(|reporter| reporter.is_cancelled())
+                })
                 .for_each(|entry| {
                     let path = entry.path();
                     if let Some(ref reporter) = reporter_for_walk {
@@ -315,6 +336,9 @@ fn scan_with_config_internal(
 
         groups.into_inner().unwrap()
     };
+    if cancelled() {
+        return Ok(result);
+    }
 
     // Step 2: For files with same size, compute partial hash (PARALLELIZED)
     let mut partial_hash_groups: HashMap<String, Vec<PathBuf>> = HashMap::new();
@@ -325,24 +349,34 @@ fn scan_with_config_internal(
         .filter(|(_, paths)| paths.len() >= 2)
         .collect();
 
-    // Parallelize partial hash computation
+    // Parallelize partial hash computation (path order, one at a time on HDDs)
     let reporter_for_partial = reporter.as_ref().map(Arc::clone);
-    let partial_hash_results: Vec<(String, PathBuf)> = paths_to_hash
-        .par_iter()
-        .flat_map(|(_size, paths)| {
-            paths
+    let partial_hash_one = |path: &PathBuf| {
+        if let Some(ref reporter) = reporter_for_partial {
+            if reporter.is_cancelled() {
+                return None;
+            }
+            reporter.emit_path(path);
+        }
+        compute_partial_hash(path, buffer_size)
+            .ok()
+            .map(|hash| (hash, path.clone()))
+    };
+    let mut partial_paths: Vec<PathBuf> = paths_to_hash
+        .into_iter()
+        .flat_map(|(_size, paths)| paths)
+        .collect();
+    let partial_hash_results: Vec<(String, PathBuf)> = if sequential {
+        partial_paths.sort();
+        partial_paths.iter().filter_map(partial_hash_one).collect()
+    } else {
+        crate::drive::install(|| {
+            partial_paths
                 .par_iter()
-                .filter_map(|path| {
-                    if let Some(ref reporter) = reporter_for_partial {
-                        reporter.emit_path(path);
-                    }
-                    compute_partial_hash(path, buffer_size)
-                        .ok()
-                        .map(|hash| (hash, path.clone()))
-                })
-                .collect::<Vec<_>>()
+                .filter_map(partial_hash_one)
+                .collect()
         })
-        .collect();
+    };
 
     // Group by partial hash
     for (partial_hash, path) in partial_hash_results {
@@ -352,6 +386,10 @@ fn scan_with_config_internal(
             .push(path);
     }
 
+    if cancelled() {
+        return Ok(result);
+    }
+
     // Step 3: For partial hash matches, compute full hash (PARALLELIZED)
     let mut full_hash_groups: HashMap<String, Vec<PathBuf>> = HashMap::new();
 
@@ -362,26 +400,28 @@ fn scan_with_config_internal(
         .map(|(_, paths)| paths)
         .collect();
 
-    // Parallelize full hash computation
+    // Parallelize full hash computation (path order, one at a time on HDDs)
     let memmap_threshold_clone = memmap_threshold;
     let buffer_size_clone = buffer_size;
     let reporter_for_full = reporter.as_ref().map(Arc::clone);
-    let full_hash_results: Vec<(String, PathBuf)> = paths_for_full_hash
-        .par_iter()
-        .flat_map(|paths| {
-            paths
-                .par_iter()
-                .filter_map(|path| {
-                    if let Some(ref reporter) = reporter_for_full {
-                        reporter.emit_path(path);
-                    }
-                    compute_full_hash(path, memmap_threshold_clone, buffer_size_clone)
-                        .ok()
-                        .map(|hash| (hash, path.clone()))
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect();
+    let full_hash_one = |path: &PathBuf| {
+        if let Some(ref reporter) = reporter_for_full {
+            if reporter.is_cancelled() {
+                return None;
+            }
+            reporter.emit_path(path);
+        }
+        compute_full_hash(path, memmap_threshold_clone, buffer_size_clone)
+            .ok()
+            .map(|hash| (hash, path.clone()))
+    };
+    let mut full_paths: Vec<PathBuf> = paths_for_full_hash.into_iter().flatten().collect();
+    let full_hash_results: Vec<(String, PathBuf)> = if sequential {
+        full_paths.sort();
+        full_paths.iter().filter_map(full_hash_one).collect()
+    } else {
+        crate::drive::install(|| full_paths.par_iter().filter_map(full_hash_one).collect())
+    };
 
     // Group by full hash
     for (full_hash, path) in full_hash_results {
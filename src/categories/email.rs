@@ -0,0 +1,229 @@
+//! Email client storage (Outlook OST/PST, Thunderbird)
+//!
+//! Guidance only: deleting mail stores is rarely the right fix, but knowing a
+//! 30 GB OST exists is. Items are reported with advice (see [`describe`]), are
+//! never auto-selected in the TUI, and `wole clean` skips them - review them in
+//! the TUI, where `O` opens the containing folder.
+//!
+//! Covers:
+//! - Outlook data files over [`MAILBOX_MIN_SIZE`]: `*.ost` / `*.pst` in
+//!   `%LOCALAPPDATA%\Microsoft\Outlook` and `Documents\Outlook Files`
+//! - Thunderbird caches over [`CACHE_MIN_SIZE`]: per-profile `cache2`, offline
+//!   IMAP copies (`ImapMail\<server>`) and the search index `global-messages-db.sqlite`
+
+use crate::config::Config;
+use crate::output::CategoryResult;
+use crate::utils;
+use anyhow::Result;
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Outlook data files smaller than this are not worth mentioning
+pub const MAILBOX_MIN_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// Thunderbird caches smaller than this are not worth mentioning
+pub const CACHE_MIN_SIZE: u64 = 100 * 1024 * 1024;
+
+/// What an email item is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailItem {
+    OutlookOst,
+    OutlookPst,
+    ThunderbirdCache,
+    ThunderbirdImap,
+    ThunderbirdIndex,
+}
+
+impl EmailItem {
+    /// What the user can do about it
+    pub fn advice(self) -> &'static str {
+        match self {
+            EmailItem::OutlookOst => {
+                "Outlook offline cache - shrink it with a shorter 'Download email for the past' setting"
+            }
+            EmailItem::OutlookPst => {
+                "Outlook archive - may hold the only copy of old mail; compact or move it instead"
+            }
+            EmailItem::ThunderbirdCache => "Thunderbird cache - safe to clear with Thunderbird closed",
+            EmailItem::ThunderbirdImap => {
+                "Thunderbird offline IMAP copy - re-downloaded from the server if removed"
+            }
+            EmailItem::ThunderbirdIndex => {
+                "Thunderbird search index - rebuilt automatically if removed"
+            }
+        }
+    }
+
+    fn min_size(self) -> u64 {
+        match self {
+            EmailItem::OutlookOst | EmailItem::OutlookPst => MAILBOX_MIN_SIZE,
+            _ => CACHE_MIN_SIZE,
+        }
+    }
+}
+
+fn child_entries(dir: &Path) -> Vec<PathBuf> {
+    match utils::safe_read_dir(dir) {
+        Ok(entries) => entries.flatten().map(|entry| entry.path()).collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn has_extension(path: &Path, ext: &str) -> bool {
+    path.extension()
+        .is_some_and(|e| e.to_string_lossy().eq_ignore_ascii_case(ext))
+}
+
+/// Outlook data files in one folder
+fn outlook_items(dir: &Path) -> Vec<(PathBuf, EmailItem)> {
+    child_entries(dir)
+        .into_iter()
+        .filter_map(|path| {
+            if has_extension(&path, "ost") {
+                Some((path, EmailItem::OutlookOst))
+            } else if has_extension(&path, "pst") {
+                Some((path, EmailItem::OutlookPst))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Thunderbird items of one profiles folder (`...\Thunderbird\Profiles`)
+fn thunderbird_items(profiles: &Path) -> Vec<(PathBuf, EmailItem)> {
+    let mut items = Vec::new();
+    for profile in child_entries(profiles)
+        .into_iter()
+        .filter(|p| utils::safe_is_dir(p))
+    {
+        let cache = profile.join("cache2");
+        if utils::safe_is_dir(&cache) {
+            items.push((cache, EmailItem::ThunderbirdCache));
+        }
+        for server in child_entries(&profile.join("ImapMail"))
+            .into_iter()
+            .filter(|p| utils::safe_is_dir(p))
+        {
+            items.push((server, EmailItem::ThunderbirdImap));
+        }
+        let index = profile.join("global-messages-db.sqlite");
+        if utils::safe_exists(&index) {
+            items.push((index, EmailItem::ThunderbirdIndex));
+        }
+    }
+    items
+}
+
+/// Every candidate item (before the size thresholds)
+fn email_items() -> Vec<(PathBuf, EmailItem)> {
+    let mut items = Vec::new();
+
+    if let Ok(local) = env::var("LOCALAPPDATA") {
+        let local = PathBuf::from(local);
+        items.extend(outlook_items(&local.join("Microsoft").join("Outlook")));
+        // Thunderbird keeps its cache under Local and mail under Roaming
+        items.extend(thunderbird_items(
+            &local.join("Thunderbird").join("Profiles"),
+        ));
+    }
+    if let Ok(profile) = env::var("USERPROFILE") {
+        items.extend(outlook_items(
+            &PathBuf::from(profile)
+                .join("Documents")
+                .join("Outlook Files"),
+        ));
+    }
+    if let Ok(roaming) = env::var("APPDATA") {
+        items.extend(thunderbird_items(
+            &PathBuf::from(roaming).join("Thunderbird").join("Profiles"),
+        ));
+    }
+
+    items
+}
+
+/// Kind of a reported email item
+pub fn item_kind(path: &Path) -> Option<EmailItem> {
+    email_items()
+        .into_iter()
+        .find(|(p, _)| p == path)
+        .map(|(_, kind)| kind)
+}
+
+/// Scan for oversized email data files and caches
+pub fn scan(_root: &Path, config: &Config) -> Result<CategoryResult> {
+    let mut found: Vec<(PathBuf, u64)> = email_items()
+        .into_iter()
+        .filter(|(path, _)| !config.is_excluded(path))
+        .filter_map(|(path, kind)| {
+            let size = if utils::safe_is_dir(&path) {
+                utils::calculate_dir_size(&path)
+            } else {
+                utils::safe_metadata(&path).map(|m| m.len()).ok()?
+            };
+            (size >= kind.min_size()).then_some((path, size))
+        })
+        .collect();
+
+    // Biggest first
+    found.sort_by(|a, b| b.1.cmp(&a.1));
+
+    let mut result = CategoryResult::default();
+    for (path, size) in found {
+        result.items += 1;
+        result.size_bytes += size;
+        result.paths.push(path);
+    }
+    Ok(result)
+}
+
+/// Advice for an item, for verbose output
+pub fn describe(path: &Path) -> Option<String> {
+    item_kind(path).map(|kind| kind.advice().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outlook_and_thunderbird_items() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let outlook = temp_dir.path().join("Outlook");
+        std::fs::create_dir_all(&outlook).unwrap();
+        std::fs::write(outlook.join("me@example.com.ost"), b"ost").unwrap();
+        std::fs::write(outlook.join("archive.PST"), b"pst").unwrap();
+        std::fs::write(outlook.join("Outlook.xml"), b"xml").unwrap();
+
+        let mut items = outlook_items(&outlook);
+        items.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            items,
+            vec![
+                (outlook.join("archive.PST"), EmailItem::OutlookPst),
+                (outlook.join("me@example.com.ost"), EmailItem::OutlookOst),
+            ]
+        );
+
+        let profiles = temp_dir.path().join("Profiles");
+        let profile = profiles.join("abcd.default-release");
+        std::fs::create_dir_all(profile.join("ImapMail").join("imap.example.com")).unwrap();
+        std::fs::write(profile.join("global-messages-db.sqlite"), b"db").unwrap();
+
+        let items = thunderbird_items(&profiles);
+        assert_eq!(
+            items,
+            vec![
+                (
+                    profile.join("ImapMail").join("imap.example.com"),
+                    EmailItem::ThunderbirdImap
+                ),
+                (
+                    profile.join("global-messages-db.sqlite"),
+                    EmailItem::ThunderbirdIndex
+                ),
+            ]
+        );
+    }
+}
@@ -1,5 +1,7 @@
+use crate::cancel::CancellationToken;
 use crate::config::Config;
 use crate::output::CategoryResult;
+use crate::scan_cache::FrontierWalk;
 use crate::scan_events::{ScanPathReporter, ScanProgressEvent};
 use crate::utils;
 use anyhow::{Context, Result};
@@ -13,14 +15,18 @@ use walkdir::WalkDir;
 /// An empty folder is one that contains no files (recursively).
 /// Folders that only contain other empty folders are also considered empty.
 pub fn scan(_root: &Path, config: &Config) -> Result<CategoryResult> {
-    scan_internal(_root, config, None)
+    scan_internal(_root, config, None, &mut FrontierWalk::none())
 }
 
 /// Scan for empty folders with TUI progress updates (current directory path).
+///
+/// The user folders left to walk are checkpointed through `walk`.
 pub fn scan_with_progress(
     root: &Path,
     config: &Config,
     tx: &Sender<ScanProgressEvent>,
+    cancel: &CancellationToken,
+    walk: &mut FrontierWalk,
 ) -> Result<CategoryResult> {
     const CATEGORY: &str = "Empty Folders";
 
@@ -30,8 +36,10 @@ pub fn scan_with_progress(
         current_path: None,
     });
 
-    let reporter = ScanPathReporter::new(CATEGORY, tx.clone(), 75);
-    scan_internal(root, config, Some(reporter))
+    let reporter = ScanPathReporter::new(CATEGORY, tx.clone(), 75).with_cancel(cancel);
+    let result = scan_internal(root, config, Some(reporter), walk)?;
+    cancel.check()?;
+    Ok(result)
 }
 
 /// Internal scan function that optionally uses a progress reporter
@@ -39,17 +47,14 @@ fn scan_internal(
     _root: &Path,
     config: &Config,
     reporter: Option<ScanPathReporter>,
+    walk: &mut FrontierWalk,
 ) -> Result<CategoryResult> {
-    let mut result = CategoryResult::default();
-    let mut paths = Vec::new();
+    let cancelled = || reporter.as_ref().is_some_and(|r| r.is_cancelled());
 
-    // Get user directories to scan
-    let user_dirs = get_user_directories()?;
-
-    for dir in user_dirs {
-        if !dir.exists() {
-            continue;
-        }
+    // User directories to scan (the checkpointed ones left by an interrupted scan)
+    let mut frontier = walk.start(get_user_directories)?;
+    while let Some(dir) = frontier.pending.first().cloned() {
+        let mut found = Vec::new();
 
         // Walk directories, checking each one
         // Limit depth to prevent stack overflow, especially on Windows with smaller stack size
@@ -74,6 +79,10 @@ fn scan_internal(
                 true
             })
         {
+            if cancelled() {
+                break;
+            }
+
             let entry = match entry {
                 Ok(e) => e,
                 Err(_) => continue,
@@ -98,15 +107,26 @@ fn scan_internal(
 
             // Check if directory is empty
             if is_dir_empty(path)? {
-                result.items += 1;
                 // Empty folders don't take up meaningful space, but we count them
-                result.size_bytes += 0;
-                paths.push(path.to_path_buf());
+                found.push((path.to_path_buf(), 0));
             }
         }
+
+        // A folder the walk didn't finish stays in the frontier
+        if cancelled() {
+            break;
+        }
+        frontier.pending.remove(0);
+        frontier.found.extend(found);
+        walk.save(&frontier);
     }
 
-    result.paths = paths;
+    let mut result = CategoryResult::default();
+    for (path, size) in frontier.found {
+        result.items += 1;
+        result.size_bytes += size;
+        result.paths.push(path);
+    }
     Ok(result)
 }
 
@@ -120,6 +120,7 @@ pub fn clean(path: &Path) -> Result<()> {
         }
 
         // Delete the file (permanent delete for event logs - they're system files)
+        crate::history::checksums::capture(path);
         utils::safe_remove_file(path)
             .with_context(|| format!("Failed to delete event log: {}", path.display()))?;
         return Ok(());
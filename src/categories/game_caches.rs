@@ -0,0 +1,338 @@
+//! Game launcher caches (Steam, Epic Games, Origin, Battle.net)
+//!
+//! Each launcher contributes its shader caches, download/web caches and
+//! leftover installers. Launchers rewrite these while running, so the caches
+//! of a running launcher are skipped during the scan and refused by [`clean`].
+//! Results are grouped per launcher in the TUI via [`launcher_name`].
+
+use crate::config::Config;
+use crate::output::CategoryResult;
+use crate::utils;
+use anyhow::{Context, Result};
+use std::env;
+use std::path::{Path, PathBuf};
+
+#[cfg(windows)]
+use winreg::enums::*;
+#[cfg(windows)]
+use winreg::RegKey;
+
+/// A supported game launcher
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Launcher {
+    Steam,
+    Epic,
+    Origin,
+    BattleNet,
+}
+
+impl Launcher {
+    pub const ALL: [Launcher; 4] = [
+        Launcher::Steam,
+        Launcher::Epic,
+        Launcher::Origin,
+        Launcher::BattleNet,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Launcher::Steam => "Steam",
+            Launcher::Epic => "Epic Games",
+            Launcher::Origin => "Origin / EA",
+            Launcher::BattleNet => "Battle.net",
+        }
+    }
+
+    /// Executables that mean the launcher is running (lowercase)
+    fn processes(self) -> &'static [&'static str] {
+        match self {
+            Launcher::Steam => &["steam.exe", "steamwebhelper.exe"],
+            Launcher::Epic => &["epicgameslauncher.exe", "epicwebhelper.exe"],
+            Launcher::Origin => &["origin.exe", "eadesktop.exe", "eabackgroundservice.exe"],
+            Launcher::BattleNet => &["battle.net.exe", "agent.exe"],
+        }
+    }
+}
+
+/// One cache folder of a launcher
+struct CacheLocation {
+    launcher: Launcher,
+    path: PathBuf,
+    /// Report each child folder separately (e.g. one shader cache per game)
+    per_child: bool,
+}
+
+impl CacheLocation {
+    fn new(launcher: Launcher, path: PathBuf) -> Self {
+        Self {
+            launcher,
+            path,
+            per_child: false,
+        }
+    }
+
+    fn per_child(launcher: Launcher, path: PathBuf) -> Self {
+        Self {
+            launcher,
+            path,
+            per_child: true,
+        }
+    }
+
+    /// Whether `path` is this location or (for per-child locations) one of its children
+    fn covers(&self, path: &Path) -> bool {
+        let normalize = |p: &Path| utils::display_path(p).to_lowercase();
+        let target = if self.per_child {
+            match path.parent() {
+                Some(parent) => normalize(parent),
+                None => return false,
+            }
+        } else {
+            normalize(path)
+        };
+        target == normalize(&self.path)
+    }
+}
+
+/// Steam install folder (registry first, then the default location)
+fn steam_root() -> Option<PathBuf> {
+    #[cfg(windows)]
+    {
+        let from_registry = RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey(r"Software\Valve\Steam")
+            .and_then(|key| key.get_value::<String, _>("SteamPath"))
+            .ok()
+            .map(|p| PathBuf::from(p.replace('/', "\\")));
+        if from_registry.is_some() {
+            return from_registry;
+        }
+    }
+    env::var("ProgramFiles(x86)")
+        .ok()
+        .map(|p| PathBuf::from(p).join("Steam"))
+}
+
+fn cache_locations() -> Vec<CacheLocation> {
+    let mut locations = Vec::new();
+    let local = env::var("LOCALAPPDATA").ok().map(PathBuf::from);
+    let program_data = env::var("ProgramData")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(r"C:\ProgramData"));
+
+    if let Some(steam) = steam_root() {
+        let steamapps = steam.join("steamapps");
+        locations.push(CacheLocation::per_child(
+            Launcher::Steam,
+            steamapps.join("shadercache"),
+        ));
+        locations.push(CacheLocation::new(
+            Launcher::Steam,
+            steam.join("appcache").join("httpcache"),
+        ));
+        locations.push(CacheLocation::new(
+            Launcher::Steam,
+            steam.join("depotcache"),
+        ));
+        locations.push(CacheLocation::new(Launcher::Steam, steamapps.join("temp")));
+        // DirectX / VC++ redistributable installers shared by all games
+        locations.push(CacheLocation::new(
+            Launcher::Steam,
+            steamapps
+                .join("common")
+                .join("Steamworks Shared")
+                .join("_CommonRedist"),
+        ));
+    }
+
+    if let Some(ref local) = local {
+        let saved = local.join("EpicGamesLauncher").join("Saved");
+        for webcache in ["webcache", "webcache_4147", "webcache_4430"] {
+            locations.push(CacheLocation::new(Launcher::Epic, saved.join(webcache)));
+        }
+    }
+    locations.push(CacheLocation::new(
+        Launcher::Epic,
+        program_data
+            .join("Epic")
+            .join("EpicGamesLauncher")
+            .join("VaultCache"),
+    ));
+
+    locations.push(CacheLocation::new(
+        Launcher::Origin,
+        program_data.join("Origin").join("DownloadCache"),
+    ));
+    if let Some(ref local) = local {
+        // Leftover installer payloads from Origin self-updates
+        locations.push(CacheLocation::new(
+            Launcher::Origin,
+            local.join("Origin").join("ThinSetup"),
+        ));
+    }
+
+    locations.push(CacheLocation::new(
+        Launcher::BattleNet,
+        program_data.join("Battle.net").join("Setup"),
+    ));
+    locations.push(CacheLocation::new(
+        Launcher::BattleNet,
+        program_data
+            .join("Blizzard Entertainment")
+            .join("Battle.net")
+            .join("Cache"),
+    ));
+    if let Some(ref local) = local {
+        locations.push(CacheLocation::new(
+            Launcher::BattleNet,
+            local.join("Battle.net").join("Cache"),
+        ));
+    }
+
+    locations
+}
+
+/// Launchers with a process currently running
+pub fn running_launchers() -> Vec<Launcher> {
+    use sysinfo::System;
+
+    let mut system = System::new();
+    system.refresh_processes(sysinfo::ProcessesToUpdate::All, false);
+    let names: Vec<String> = system
+        .processes()
+        .values()
+        .map(|p| p.name().to_string_lossy().to_lowercase())
+        .collect();
+
+    Launcher::ALL
+        .into_iter()
+        .filter(|launcher| {
+            launcher
+                .processes()
+                .iter()
+                .any(|exe| names.iter().any(|n| n == exe))
+        })
+        .collect()
+}
+
+/// Launcher owning a reported cache path
+pub fn launcher_for(path: &Path) -> Option<Launcher> {
+    cache_locations()
+        .into_iter()
+        .find(|location| location.covers(path))
+        .map(|location| location.launcher)
+}
+
+/// Display name of the launcher owning a reported cache path (for grouping)
+pub fn launcher_name(path: &Path) -> Option<&'static str> {
+    launcher_for(path).map(Launcher::name)
+}
+
+/// Items (and their sizes) of one cache location
+fn location_items(location: &CacheLocation) -> Vec<(PathBuf, u64)> {
+    if !utils::safe_is_dir(&location.path) {
+        return Vec::new();
+    }
+    let dirs: Vec<PathBuf> = if location.per_child {
+        match utils::safe_read_dir(&location.path) {
+            Ok(entries) => entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|p| utils::safe_is_dir(p))
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    } else {
+        vec![location.path.clone()]
+    };
+    dirs.into_iter()
+        .map(|dir| {
+            let size = utils::calculate_dir_size(&dir);
+            (dir, size)
+        })
+        .filter(|(_, size)| *size > 0)
+        .collect()
+}
+
+/// Scan game launcher caches, skipping launchers that are running
+pub fn scan(_root: &Path, config: &Config) -> Result<CategoryResult> {
+    let running = running_launchers();
+    let mut items: Vec<(Launcher, PathBuf, u64)> = Vec::new();
+
+    for location in cache_locations() {
+        if running.contains(&location.launcher) || config.is_excluded(&location.path) {
+            continue;
+        }
+        for (path, size) in location_items(&location) {
+            if !config.is_excluded(&path) {
+                items.push((location.launcher, path, size));
+            }
+        }
+    }
+
+    // Launcher order first, then biggest caches first
+    items.sort_by(|a, b| {
+        let order = |l: &Launcher| Launcher::ALL.iter().position(|x| x == l);
+        order(&a.0).cmp(&order(&b.0)).then(b.2.cmp(&a.2))
+    });
+
+    let mut result = CategoryResult::default();
+    for (_, path, size) in items {
+        result.items += 1;
+        result.size_bytes += size;
+        result.paths.push(path);
+    }
+    Ok(result)
+}
+
+/// Delete a launcher cache folder
+///
+/// Refuses paths that aren't known launcher caches and caches of a launcher
+/// that is running. Caches under Program Files / ProgramData are removed
+/// permanently (the launcher re-downloads them); the rest go to the Recycle Bin.
+pub fn clean(path: &Path) -> Result<()> {
+    let Some(launcher) = launcher_for(path) else {
+        anyhow::bail!("{} is not a game launcher cache", path.display());
+    };
+    if running_launchers().contains(&launcher) {
+        anyhow::bail!(
+            "{} is running; close it to clean its caches",
+            launcher.name()
+        );
+    }
+    if !utils::safe_exists(path) {
+        return Ok(());
+    }
+
+    if utils::is_system_path(path) {
+        crate::history::checksums::capture(path);
+        utils::safe_remove_dir_all(path)
+            .with_context(|| format!("Failed to delete game cache: {}", path.display()))?;
+        return Ok(());
+    }
+
+    crate::trash_ops::delete(path)
+        .with_context(|| format!("Failed to delete game cache: {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_per_child_location_covers_children_only() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let shadercache = temp_dir.path().join("shadercache");
+        std::fs::create_dir_all(shadercache.join("570")).unwrap();
+        std::fs::write(shadercache.join("570").join("cache.bin"), b"shader").unwrap();
+        std::fs::create_dir_all(shadercache.join("730")).unwrap();
+
+        let location = CacheLocation::per_child(Launcher::Steam, shadercache.clone());
+        assert!(location.covers(&shadercache.join("570")));
+        assert!(!location.covers(&shadercache));
+
+        // Empty shader caches are not reported
+        let items = location_items(&location);
+        assert_eq!(items, vec![(shadercache.join("570"), 6)]);
+    }
+}
@@ -0,0 +1,138 @@
+//! Files ignored by git
+//!
+//! For every git repository under the scan path that is inactive (see
+//! [`project::is_project_active`]), lists what `git clean -ndX` would remove:
+//! untracked files and folders matched by `.gitignore`. The list is computed by
+//! [`git::ignored_paths`] without running git.
+//!
+//! Build artifact folders are left to Build Artifacts so nothing is counted
+//! twice. Files matching `[categories.git_ignored] keep` (local env files, IDE
+//! settings and private keys by default) are never listed, and neither is an
+//! ignored folder that contains one.
+
+use crate::config::Config;
+use crate::git;
+use crate::output::CategoryResult;
+use crate::project;
+use crate::utils;
+use anyhow::Result;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::path::{Path, PathBuf};
+
+/// Compile the `keep` patterns; they match file and folder names
+fn keep_set(config: &Config) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in &config.categories.git_ignored.keep {
+        if let Ok(glob) = GlobBuilder::new(pattern)
+            .case_insensitive(cfg!(windows))
+            .build()
+        {
+            builder.add(glob);
+        }
+    }
+    builder.build().unwrap_or_else(|_| GlobSet::empty())
+}
+
+fn is_kept(keep: &GlobSet, path: &Path) -> bool {
+    path.file_name().is_some_and(|name| keep.is_match(name))
+}
+
+/// Size of an ignored path, or None if it is (or holds) something to keep
+fn size_unless_kept(path: &Path, keep: &GlobSet) -> Option<u64> {
+    if is_kept(keep, path) {
+        return None;
+    }
+    let metadata = utils::safe_symlink_metadata(path).ok()?;
+    if !metadata.is_dir() {
+        return Some(metadata.len());
+    }
+    let mut size = 0;
+    for entry in walkdir::WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .flatten()
+    {
+        if is_kept(keep, entry.path()) {
+            return None;
+        }
+        if entry.file_type().is_file() {
+            size += entry.metadata().map(|m| m.len()).unwrap_or(0);
+        }
+    }
+    Some(size)
+}
+
+/// Name of the repository a result belongs to, for grouping
+pub fn repo_name(path: &Path) -> Option<String> {
+    let repo = git::find_git_root(path.parent()?)?;
+    Some(repo.file_name()?.to_string_lossy().to_string())
+}
+
+/// Scan for ignored files in inactive git repositories
+pub fn scan(root: &Path, project_age_days: u64, config: &Config) -> Result<CategoryResult> {
+    let mut result = CategoryResult::default();
+    let keep = keep_set(config);
+    let artifacts = crate::categories::build::get_build_artifacts(Some(&config.categories.build));
+
+    for repo in git::find_repo_roots(root, config) {
+        if project::is_project_active(&repo, project_age_days).unwrap_or(true) {
+            continue;
+        }
+        let mut found: Vec<(PathBuf, u64)> = Vec::new();
+        for path in git::ignored_paths(&repo) {
+            let is_artifact = path
+                .file_name()
+                .is_some_and(|name| artifacts.iter().any(|a| name == a.as_str()))
+                && utils::safe_is_dir(&path);
+            if is_artifact || config.is_excluded(&path) {
+                continue;
+            }
+            if let Some(size) = size_unless_kept(&path, &keep) {
+                found.push((path, size));
+            }
+        }
+        // Biggest first within each repository
+        found.sort_by(|a, b| b.1.cmp(&a.1));
+        for (path, size) in found {
+            result.items += 1;
+            result.size_bytes += size;
+            result.paths.push(path);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_scan_leaves_env_files_and_artifacts() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = temp_dir.path().join("app");
+        fs::create_dir_all(repo.join(".git")).unwrap();
+        fs::create_dir_all(repo.join("node_modules")).unwrap();
+        fs::create_dir_all(repo.join("out")).unwrap();
+        fs::create_dir_all(repo.join("secrets")).unwrap();
+        fs::write(
+            repo.join(".gitignore"),
+            "node_modules/\nout/\nsecrets/\n.env\n*.tmp\n",
+        )
+        .unwrap();
+        fs::write(repo.join(".env"), "TOKEN=1").unwrap();
+        fs::write(repo.join("secrets").join("prod.env.local"), "x").unwrap();
+        fs::write(repo.join("out").join("bundle.js"), "12345").unwrap();
+        fs::write(repo.join("scratch.tmp"), "1").unwrap();
+
+        let config = Config::default();
+        let result = scan(temp_dir.path(), 0, &config).unwrap();
+        assert_eq!(
+            result.paths,
+            vec![repo.join("out"), repo.join("scratch.tmp")]
+        );
+        assert_eq!(result.size_bytes, 6);
+        assert_eq!(repo_name(&repo.join("out")), Some("app".to_string()));
+    }
+}
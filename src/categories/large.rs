@@ -1,7 +1,9 @@
+use crate::cancel::CancellationToken;
 use crate::config::Config;
 use crate::git;
 use crate::output::{CategoryResult, OutputMode};
 use crate::project;
+use crate::scan_cache::FrontierWalk;
 use crate::scan_events::{ScanPathReporter, ScanProgressEvent};
 use crate::theme::Theme;
 use crate::utils;
@@ -112,29 +114,39 @@ pub fn scan(
 }
 
 /// Scan for large files with TUI progress updates (current file path).
+///
+/// The user folders left to walk are checkpointed through `walk`.
 pub fn scan_with_progress(
     root: &Path,
     min_size_bytes: u64,
     config: &Config,
     output_mode: OutputMode,
     tx: &Sender<ScanProgressEvent>,
+    cancel: &CancellationToken,
+    walk: &mut FrontierWalk,
 ) -> Result<CategoryResult> {
-    let reporter = Arc::new(ScanPathReporter::new("Large Files", tx.clone(), 75));
+    let reporter =
+        Arc::new(ScanPathReporter::new("Large Files", tx.clone(), 75).with_cancel(cancel));
 
     let mut result = CategoryResult::default();
-    let user_dirs = get_user_directories()?;
-    let mut files_with_sizes: Vec<(PathBuf, u64)> = Vec::new();
-
-    for dir in &user_dirs {
+    // Continues from the checkpointed folders of an interrupted scan
+    let mut frontier = walk.start(get_user_directories)?;
+    while let Some(dir) = frontier.pending.first().cloned() {
+        let mut found = Vec::new();
         scan_directory(
-            dir,
+            &dir,
             min_size_bytes,
-            &mut files_with_sizes,
+            &mut found,
             config,
             output_mode,
             Some(Arc::clone(&reporter)),
         )?;
+        cancel.check()?;
+        frontier.pending.remove(0);
+        frontier.found.extend(found);
+        walk.save(&frontier);
     }
+    let mut files_with_sizes = frontier.found;
 
     files_with_sizes.sort_by(|a, b| b.1.cmp(&a.1));
     files_with_sizes.truncate(MAX_RESULTS);
@@ -189,14 +201,18 @@ fn scan_directory(
     let found_files: Arc<Mutex<Vec<(PathBuf, u64)>>> = Arc::new(Mutex::new(Vec::new()));
     // Clone Arc for the closure
     let found_files_clone = Arc::clone(&found_files);
+    let cancelled = {
+        let reporter = reporter.clone();
+        move || reporter.as_ref().is_some_and(|r| r.is_cancelled())
+    };
 
     // Use jwalk for parallel directory traversal
     WalkDir::new(dir)
         .max_depth(MAX_DEPTH)
         .follow_links(false)
-        .parallelism(jwalk::Parallelism::RayonDefaultPool {
-            busy_timeout: std::time::Duration::from_secs(1),
-        })
+        .parallelism(crate::drive::walk_parallelism(
+            std::time::Duration::from_secs(1),
+        ))
         .process_read_dir(move |_depth, _path, _read_dir_state, children| {
             // Filter out directories we don't want to descend into
             children.retain(|entry| {
@@ -247,6 +263,7 @@ fn scan_directory(
             });
         })
         .into_iter()
+        .take_while(|_| !cancelled())
         .filter_map(|e| e.ok())
         .for_each(move |entry| {
             let path = entry.path();
@@ -299,6 +316,24 @@ fn scan_directory(
     Ok(())
 }
 
+/// Tier (floor in MB) a file falls in; `tiers` is biggest first, as from
+/// `Thresholds::large_file_tiers`
+pub fn size_tier(size_bytes: u64, tiers: &[u64]) -> Option<u64> {
+    tiers
+        .iter()
+        .copied()
+        .find(|&mb| size_bytes >= mb * 1024 * 1024)
+}
+
+/// Heading of a size tier, e.g. "Over 10 GB"
+pub fn tier_label(tier_mb: u64) -> String {
+    if tier_mb >= 1024 && tier_mb % 1024 == 0 {
+        format!("Over {} GB", tier_mb / 1024)
+    } else {
+        format!("Over {} MB", tier_mb)
+    }
+}
+
 /// Get file type for a large file (for display purposes)
 pub fn get_file_type(path: &Path) -> utils::FileType {
     utils::detect_file_type(path)
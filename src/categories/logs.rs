@@ -0,0 +1,324 @@
+//! Log file sprawl
+//!
+//! Finds runaway logs under the scan path:
+//! - single log files over `categories.logs.min_size_mb`
+//! - rotating log sets (`app.log.1`, `app.2.log`, `app-2024-01-01.log`, ...)
+//!   with at least `categories.logs.max_segments` segments; the newest segment
+//!   is kept since the application is still writing to it
+//!
+//! Results are ordered by folder so each application's logs stay together.
+//! A log modified within [`ACTIVE_WINDOW`] is "active": it is skipped, or, with
+//! `categories.logs.truncate_active`, reported and truncated instead of deleted.
+
+use crate::cancel::CancellationToken;
+use crate::config::{Config, LogsConfig};
+use crate::output::CategoryResult;
+use crate::scan_events::{ScanPathReporter, ScanProgressEvent};
+use crate::utils;
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+use std::time::{Duration, SystemTime};
+use walkdir::WalkDir;
+
+/// Logs modified more recently than this are considered in use
+pub const ACTIVE_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+/// Suffixes added by log rotation/compression on top of the log name
+const ROTATION_SUFFIXES: &[&str] = &[".gz", ".zip", ".bak", ".old"];
+
+/// Whether a file name looks like a (possibly rotated) log file
+fn is_log_file(name: &str) -> bool {
+    let mut name = name.to_lowercase();
+    for suffix in ROTATION_SUFFIXES {
+        if let Some(stripped) = name.strip_suffix(suffix) {
+            name = stripped.to_string();
+            break;
+        }
+    }
+    if name.ends_with(".log") {
+        return true;
+    }
+    // app.log.1, app.log.20240101
+    name.rsplit_once(".log.")
+        .is_some_and(|(_, rest)| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn is_separator(c: char) -> bool {
+    matches!(c, '.' | '-' | '_')
+}
+
+/// Name shared by every segment of a rotating log set
+///
+/// Digits are dropped and the separators around them collapsed, so
+/// `app.log.12`, `app.12.log` and `app-2024-01-01.log` all map to `app.log`.
+fn segment_base(name: &str) -> String {
+    let mut name = name.to_lowercase();
+    for suffix in ROTATION_SUFFIXES {
+        if let Some(stripped) = name.strip_suffix(suffix) {
+            name = stripped.to_string();
+            break;
+        }
+    }
+
+    let mut base = String::with_capacity(name.len());
+    for c in name.chars().filter(|c| !c.is_ascii_digit()) {
+        match base.chars().last() {
+            Some(last) if is_separator(c) && is_separator(last) => {
+                // Keep the extension dot when a run of separators ends in one
+                if c == '.' {
+                    base.pop();
+                    base.push('.');
+                }
+            }
+            _ => base.push(c),
+        }
+    }
+    base.trim_end_matches(is_separator).to_string()
+}
+
+struct LogFile {
+    path: PathBuf,
+    size: u64,
+    modified: SystemTime,
+}
+
+impl LogFile {
+    fn is_active(&self) -> bool {
+        is_recent(self.modified)
+    }
+}
+
+fn is_recent(modified: SystemTime) -> bool {
+    SystemTime::now()
+        .duration_since(modified)
+        .map(|age| age < ACTIVE_WINDOW)
+        .unwrap_or(true)
+}
+
+/// Whether a log is still being written to (recently modified)
+pub fn is_active(path: &Path) -> bool {
+    utils::safe_metadata(path)
+        .and_then(|m| m.modified())
+        .map(is_recent)
+        .unwrap_or(false)
+}
+
+/// Scan for runaway log files under `root`
+pub fn scan(root: &Path, config: &Config) -> Result<CategoryResult> {
+    scan_internal(root, config, None)
+}
+
+/// Scan for runaway log files with TUI progress updates (current directory path)
+pub fn scan_with_progress(
+    root: &Path,
+    config: &Config,
+    tx: &Sender<ScanProgressEvent>,
+    cancel: &CancellationToken,
+) -> Result<CategoryResult> {
+    const CATEGORY: &str = "Log Files";
+
+    let _ = tx.send(ScanProgressEvent::CategoryStarted {
+        category: CATEGORY.to_string(),
+        total_units: None,
+        current_path: None,
+    });
+
+    let reporter = ScanPathReporter::new(CATEGORY, tx.clone(), 75).with_cancel(cancel);
+    let result = scan_internal(root, config, Some(reporter))?;
+    cancel.check()?;
+    Ok(result)
+}
+
+fn scan_internal(
+    root: &Path,
+    config: &Config,
+    reporter: Option<ScanPathReporter>,
+) -> Result<CategoryResult> {
+    let settings = &config.categories.logs;
+
+    // Log files grouped by (folder, rotation base name)
+    let mut sets: HashMap<(PathBuf, String), Vec<LogFile>> = HashMap::new();
+
+    const MAX_DEPTH: usize = 12;
+    for entry in WalkDir::new(root)
+        .max_depth(MAX_DEPTH)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| !should_skip_entry(e, config))
+    {
+        if reporter.as_ref().is_some_and(|r| r.is_cancelled()) {
+            break;
+        }
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if entry.file_type().is_dir() {
+            if let Some(ref reporter) = reporter {
+                reporter.emit_path(entry.path());
+            }
+            continue;
+        }
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy();
+        if !is_log_file(&name) || config.is_excluded(entry.path()) {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let Some(parent) = entry.path().parent() else {
+            continue;
+        };
+        sets.entry((parent.to_path_buf(), segment_base(&name)))
+            .or_default()
+            .push(LogFile {
+                path: entry.path().to_path_buf(),
+                size: metadata.len(),
+                modified: metadata.modified().unwrap_or_else(|_| SystemTime::now()),
+            });
+    }
+
+    let mut found: Vec<LogFile> = sets
+        .into_values()
+        .flat_map(|set| select_from_set(set, settings))
+        .collect();
+
+    // Group by application folder, biggest files first within a folder
+    found.sort_by(|a, b| {
+        a.path
+            .parent()
+            .cmp(&b.path.parent())
+            .then(b.size.cmp(&a.size))
+    });
+
+    let mut result = CategoryResult::default();
+    for log in found {
+        result.items += 1;
+        result.size_bytes += log.size;
+        result.paths.push(log.path);
+    }
+    Ok(result)
+}
+
+/// Pick the files worth reporting from one rotating set (or a lone log file)
+fn select_from_set(mut set: Vec<LogFile>, settings: &LogsConfig) -> Vec<LogFile> {
+    let min_size = settings.min_size_mb * 1024 * 1024;
+    let keep_active = |log: &LogFile| settings.truncate_active || !log.is_active();
+
+    if set.len() >= settings.max_segments.max(2) {
+        // Runaway rotation: everything except the newest segment
+        set.sort_by(|a, b| b.modified.cmp(&a.modified));
+        return set.into_iter().skip(1).filter(keep_active).collect();
+    }
+
+    set.into_iter()
+        .filter(|log| log.size >= min_size && log.size > 0)
+        .filter(keep_active)
+        .collect()
+}
+
+/// Check if we should skip walking into this directory
+fn should_skip_entry(entry: &walkdir::DirEntry, config: &Config) -> bool {
+    if !entry.file_type().is_dir() {
+        return false;
+    }
+    if utils::should_skip_entry(entry.path()) || utils::is_system_path(entry.path()) {
+        return true;
+    }
+    let name = entry.file_name().to_string_lossy().to_lowercase();
+    if utils::SKIP_WALK_DIRS.contains(&name.as_str()) {
+        return true;
+    }
+    config.is_excluded(entry.path())
+}
+
+/// What [`clean`] did with a log file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogCleanAction {
+    /// Moved to the Recycle Bin (or deleted permanently)
+    Deleted,
+    /// Still in use, so emptied in place
+    Truncated,
+}
+
+/// Delete a log file, or truncate it when it is active and `truncate_active` is set
+pub fn clean(path: &Path, permanent: bool, truncate_active: bool) -> Result<LogCleanAction> {
+    if !utils::safe_exists(path) {
+        return Ok(LogCleanAction::Deleted);
+    }
+
+    if truncate_active && is_active(path) {
+        truncate(path).with_context(|| format!("Failed to truncate log: {}", path.display()))?;
+        return Ok(LogCleanAction::Truncated);
+    }
+
+    if permanent {
+        crate::history::checksums::capture(path);
+        utils::safe_remove_file(path)
+            .with_context(|| format!("Failed to delete log: {}", path.display()))?;
+    } else {
+        crate::trash_ops::delete(path)
+            .with_context(|| format!("Failed to delete log: {}", path.display()))?;
+    }
+    Ok(LogCleanAction::Deleted)
+}
+
+/// Empty a log file in place (the writer keeps its handle)
+fn truncate(path: &Path) -> std::io::Result<()> {
+    let file = std::fs::OpenOptions::new()
+        .write(true)
+        .open(utils::long_path_if_needed(path))?;
+    file.set_len(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_file_names() {
+        assert!(is_log_file("app.log"));
+        assert!(is_log_file("App.LOG"));
+        assert!(is_log_file("app.log.3"));
+        assert!(is_log_file("app.log.3.gz"));
+        assert!(is_log_file("app-2024-01-01.log"));
+        assert!(!is_log_file("catalog.txt"));
+        assert!(!is_log_file("app.log.bak.txt"));
+    }
+
+    #[test]
+    fn test_segment_base() {
+        assert_eq!(segment_base("app.log"), "app.log");
+        assert_eq!(segment_base("app.log.12"), "app.log");
+        assert_eq!(segment_base("app.12.log"), "app.log");
+        assert_eq!(segment_base("app-2024-01-01.log"), "app.log");
+        assert_eq!(segment_base("app_20240101_120000.log.gz"), "app.log");
+        assert_ne!(segment_base("server.log"), segment_base("client.log"));
+    }
+
+    #[test]
+    fn test_rotating_set_keeps_newest_segment() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        for i in 0..5 {
+            std::fs::write(temp_dir.path().join(format!("app.log.{}", i)), b"x").unwrap();
+        }
+        std::fs::write(temp_dir.path().join("other.log"), b"small").unwrap();
+
+        let mut config = Config::default();
+        config.categories.logs.max_segments = 5;
+        config.categories.logs.truncate_active = true;
+        let result = scan(temp_dir.path(), &config).unwrap();
+
+        // 4 of the 5 segments; the small standalone log is below the size threshold
+        assert_eq!(result.items, 4);
+        assert!(result
+            .paths
+            .iter()
+            .all(|p| segment_base(&p.file_name().unwrap().to_string_lossy()) == "app.log"));
+    }
+}
@@ -0,0 +1,446 @@
+//! Machine learning model and dataset caches
+//!
+//! Lists individual models instead of whole caches, so a user can drop the one
+//! 15 GB checkpoint they tried once and keep the rest:
+//! - HuggingFace hub (`HF_HUB_CACHE`, `HF_HOME\hub` or `~\.cache\huggingface\hub`):
+//!   one item per `models--*` / `datasets--*` folder
+//! - torch hub (`TORCH_HOME` or `~\.cache\torch`): downloaded checkpoints,
+//!   hub repos and sentence-transformers models
+//! - Ollama (`OLLAMA_MODELS` or `~\.ollama\models`): one item per model manifest;
+//!   its size is the blobs no other model uses, and [`clean`] removes those blobs
+//! - LM Studio (`~\.cache\lm-studio\models`, `~\.lmstudio\models`) and Keras
+//!   (`~\.keras\models`, `~\.keras\datasets`)
+//!
+//! The pip wheel cache is reported by the package cache category.
+
+use crate::config::Config;
+use crate::output::CategoryResult;
+use crate::utils;
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+/// Where a model comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelStore {
+    HuggingFace,
+    Torch,
+    Ollama,
+    LmStudio,
+    Keras,
+}
+
+impl ModelStore {
+    pub const ALL: [ModelStore; 5] = [
+        ModelStore::HuggingFace,
+        ModelStore::Torch,
+        ModelStore::Ollama,
+        ModelStore::LmStudio,
+        ModelStore::Keras,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ModelStore::HuggingFace => "HuggingFace",
+            ModelStore::Torch => "torch hub",
+            ModelStore::Ollama => "Ollama",
+            ModelStore::LmStudio => "LM Studio",
+            ModelStore::Keras => "Keras",
+        }
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var("USERPROFILE").ok().map(PathBuf::from)
+}
+
+fn env_path(var: &str) -> Option<PathBuf> {
+    env::var(var)
+        .ok()
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+fn huggingface_hub() -> Option<PathBuf> {
+    env_path("HF_HUB_CACHE")
+        .or_else(|| env_path("HF_HOME").map(|h| h.join("hub")))
+        .or_else(|| home_dir().map(|h| h.join(".cache").join("huggingface").join("hub")))
+}
+
+fn torch_home() -> Option<PathBuf> {
+    env_path("TORCH_HOME").or_else(|| home_dir().map(|h| h.join(".cache").join("torch")))
+}
+
+fn ollama_models() -> Option<PathBuf> {
+    env_path("OLLAMA_MODELS").or_else(|| home_dir().map(|h| h.join(".ollama").join("models")))
+}
+
+/// Root folder of every store (used to tell which store a path belongs to)
+fn store_roots() -> Vec<(ModelStore, PathBuf)> {
+    let mut roots = Vec::new();
+    if let Some(hub) = huggingface_hub() {
+        roots.push((ModelStore::HuggingFace, hub));
+    }
+    if let Some(torch) = torch_home() {
+        roots.push((ModelStore::Torch, torch));
+    }
+    if let Some(ollama) = ollama_models() {
+        roots.push((ModelStore::Ollama, ollama));
+    }
+    if let Some(home) = home_dir() {
+        roots.push((
+            ModelStore::LmStudio,
+            home.join(".cache").join("lm-studio").join("models"),
+        ));
+        roots.push((ModelStore::LmStudio, home.join(".lmstudio").join("models")));
+        roots.push((ModelStore::Keras, home.join(".keras")));
+    }
+    roots
+}
+
+/// Store a reported model path belongs to
+pub fn store_for(path: &Path) -> Option<ModelStore> {
+    store_roots()
+        .into_iter()
+        .find(|(_, root)| path.starts_with(root))
+        .map(|(store, _)| store)
+}
+
+/// Display name of the store a reported model path belongs to (for grouping)
+pub fn store_name(path: &Path) -> Option<&'static str> {
+    store_for(path).map(ModelStore::name)
+}
+
+fn child_entries(dir: &Path) -> Vec<PathBuf> {
+    match utils::safe_read_dir(dir) {
+        Ok(entries) => entries.flatten().map(|entry| entry.path()).collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+    child_entries(dir)
+        .into_iter()
+        .filter(|p| utils::safe_is_dir(p))
+        .collect()
+}
+
+/// Model folders and files of the file-based stores (everything except Ollama)
+fn file_store_items() -> Vec<(ModelStore, PathBuf)> {
+    let mut items = Vec::new();
+
+    if let Some(hub) = huggingface_hub() {
+        for dir in child_dirs(&hub) {
+            let name = dir
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if name.starts_with("models--") || name.starts_with("datasets--") {
+                items.push((ModelStore::HuggingFace, dir));
+            }
+        }
+    }
+
+    if let Some(torch) = torch_home() {
+        let hub = torch.join("hub");
+        for path in child_entries(&hub.join("checkpoints")) {
+            items.push((ModelStore::Torch, path));
+        }
+        for dir in child_dirs(&hub) {
+            if dir.file_name().is_some_and(|n| n != "checkpoints") {
+                items.push((ModelStore::Torch, dir));
+            }
+        }
+        for dir in child_dirs(&torch.join("sentence_transformers")) {
+            items.push((ModelStore::Torch, dir));
+        }
+    }
+
+    if let Some(home) = home_dir() {
+        // LM Studio: models\<publisher>\<model>
+        for root in [
+            home.join(".cache").join("lm-studio").join("models"),
+            home.join(".lmstudio").join("models"),
+        ] {
+            for publisher in child_dirs(&root) {
+                for model in child_dirs(&publisher) {
+                    items.push((ModelStore::LmStudio, model));
+                }
+            }
+        }
+        let keras = home.join(".keras");
+        for path in child_entries(&keras.join("models"))
+            .into_iter()
+            .chain(child_entries(&keras.join("datasets")))
+        {
+            items.push((ModelStore::Keras, path));
+        }
+    }
+
+    items
+}
+
+/// Blob file of an Ollama digest (`sha256:abc` is stored as `sha256-abc`)
+fn ollama_blob(models: &Path, digest: &str) -> PathBuf {
+    models.join("blobs").join(digest.replace(':', "-"))
+}
+
+/// Digests referenced by an Ollama manifest (config and layers)
+fn ollama_manifest_digests(manifest: &Path) -> Option<Vec<String>> {
+    let contents = std::fs::read_to_string(utils::long_path_if_needed(manifest)).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&contents).ok()?;
+    let mut digests = Vec::new();
+    if let Some(digest) = json["config"]["digest"].as_str() {
+        digests.push(digest.to_string());
+    }
+    for layer in json["layers"].as_array()? {
+        if let Some(digest) = layer["digest"].as_str() {
+            digests.push(digest.to_string());
+        }
+    }
+    Some(digests)
+}
+
+/// Every Ollama manifest with the digests it references
+fn ollama_manifests(models: &Path) -> Vec<(PathBuf, Vec<String>)> {
+    WalkDir::new(models.join("manifests"))
+        .max_depth(6)
+        .follow_links(false)
+        .into_iter()
+        .flatten()
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let digests = ollama_manifest_digests(e.path())?;
+            Some((e.path().to_path_buf(), digests))
+        })
+        .collect()
+}
+
+/// Blobs of `manifest` that no other manifest references
+fn ollama_unique_blobs(
+    models: &Path,
+    manifest: &Path,
+    manifests: &[(PathBuf, Vec<String>)],
+) -> Vec<PathBuf> {
+    let Some((_, own)) = manifests.iter().find(|(path, _)| path == manifest) else {
+        return Vec::new();
+    };
+    let shared: HashSet<&String> = manifests
+        .iter()
+        .filter(|(path, _)| path != manifest)
+        .flat_map(|(_, digests)| digests)
+        .collect();
+    own.iter()
+        .filter(|digest| !shared.contains(digest))
+        .map(|digest| ollama_blob(models, digest))
+        .collect()
+}
+
+/// Size and last use (newest access or modification) of a file or folder
+fn measure(path: &Path) -> (u64, Option<SystemTime>) {
+    let mut size = 0u64;
+    let mut last_used: Option<SystemTime> = None;
+    for entry in WalkDir::new(path).follow_links(false).into_iter().flatten() {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        size += metadata.len();
+        let used = [metadata.accessed().ok(), metadata.modified().ok()]
+            .into_iter()
+            .flatten()
+            .max();
+        last_used = last_used.max(used);
+    }
+    (size, last_used)
+}
+
+/// Size and last use of an Ollama model (its blobs that aren't shared)
+fn measure_ollama(
+    models: &Path,
+    manifest: &Path,
+    manifests: &[(PathBuf, Vec<String>)],
+) -> (u64, Option<SystemTime>) {
+    let (mut size, mut last_used) = measure(manifest);
+    for blob in ollama_unique_blobs(models, manifest, manifests) {
+        let (blob_size, blob_used) = measure(&blob);
+        size += blob_size;
+        last_used = last_used.max(blob_used);
+    }
+    (size, last_used)
+}
+
+/// Scan ML model stores, one item per model
+pub fn scan(_root: &Path, config: &Config) -> Result<CategoryResult> {
+    let mut models: Vec<(ModelStore, PathBuf, u64)> = Vec::new();
+
+    for (store, path) in file_store_items() {
+        if config.is_excluded(&path) {
+            continue;
+        }
+        let (size, _) = measure(&path);
+        models.push((store, path, size));
+    }
+
+    if let Some(ollama) = ollama_models() {
+        let manifests = ollama_manifests(&ollama);
+        for (manifest, _) in &manifests {
+            if config.is_excluded(manifest) {
+                continue;
+            }
+            let (size, _) = measure_ollama(&ollama, manifest, &manifests);
+            models.push((ModelStore::Ollama, manifest.clone(), size));
+        }
+    }
+
+    // Store order first, then biggest models first
+    models.retain(|(_, _, size)| *size > 0);
+    models.sort_by(|a, b| {
+        let order = |s: &ModelStore| ModelStore::ALL.iter().position(|x| x == s);
+        order(&a.0).cmp(&order(&b.0)).then(b.2.cmp(&a.2))
+    });
+
+    let mut result = CategoryResult::default();
+    for (_, path, size) in models {
+        result.items += 1;
+        result.size_bytes += size;
+        result.paths.push(path);
+    }
+    Ok(result)
+}
+
+/// Human name of a model path, e.g. `meta-llama/Llama-3-8B` or `llama3:8b`
+pub fn model_name(path: &Path) -> String {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    match store_for(path) {
+        Some(ModelStore::HuggingFace) => file_name
+            .split_once("--")
+            .map(|(_, rest)| rest.replace("--", "/"))
+            .unwrap_or(file_name),
+        Some(ModelStore::Ollama) => {
+            // manifests\<registry>\<namespace>\<model>\<tag>
+            let model = path
+                .parent()
+                .and_then(|p| p.file_name())
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            format!("{}:{}", model, file_name)
+        }
+        _ => file_name,
+    }
+}
+
+/// Model name and last use for verbose output, e.g. "llama3:8b, last used 40 days ago"
+pub fn describe(path: &Path) -> Option<String> {
+    let (_, last_used) = if store_for(path) == Some(ModelStore::Ollama) {
+        let models = ollama_models()?;
+        let manifests = ollama_manifests(&models);
+        measure_ollama(&models, path, &manifests)
+    } else {
+        measure(path)
+    };
+    let days = last_used
+        .and_then(|t| SystemTime::now().duration_since(t).ok())
+        .map(|age| age.as_secs() / (24 * 60 * 60));
+    let used = match days {
+        Some(0) => "used today".to_string(),
+        Some(1) => "last used 1 day ago".to_string(),
+        Some(n) => format!("last used {} days ago", n),
+        None => "never used".to_string(),
+    };
+    Some(format!("{}, {}", model_name(path), used))
+}
+
+/// Delete one model
+///
+/// Ollama models are removed like `ollama rm`: the manifest plus the blobs no
+/// other model uses. Everything else is a plain file or folder.
+pub fn clean(path: &Path, permanent: bool) -> Result<()> {
+    let remove = |target: &Path| -> Result<()> {
+        if !utils::safe_exists(target) {
+            return Ok(());
+        }
+        if permanent {
+            crate::history::checksums::capture(target);
+            let removed = if utils::safe_is_dir(target) {
+                utils::safe_remove_dir_all(target)
+            } else {
+                utils::safe_remove_file(target)
+            };
+            removed.with_context(|| format!("Failed to delete model: {}", target.display()))
+        } else {
+            crate::trash_ops::delete(target)
+                .with_context(|| format!("Failed to delete model: {}", target.display()))
+        }
+    };
+
+    if store_for(path) == Some(ModelStore::Ollama) {
+        if let Some(models) = ollama_models() {
+            let manifests = ollama_manifests(&models);
+            let blobs = ollama_unique_blobs(&models, path, &manifests);
+            remove(path)?;
+            for blob in blobs {
+                remove(&blob)?;
+            }
+            return Ok(());
+        }
+    }
+
+    remove(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_manifest(models: &Path, name: &str, tag: &str, digests: &[&str]) -> PathBuf {
+        let dir = models
+            .join("manifests")
+            .join("registry.ollama.ai")
+            .join("library")
+            .join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        let layers: Vec<serde_json::Value> = digests[1..]
+            .iter()
+            .map(|d| serde_json::json!({ "digest": d }))
+            .collect();
+        let manifest = serde_json::json!({
+            "config": { "digest": digests[0] },
+            "layers": layers,
+        });
+        let path = dir.join(tag);
+        std::fs::write(&path, manifest.to_string()).unwrap();
+        for digest in digests {
+            std::fs::create_dir_all(models.join("blobs")).unwrap();
+            std::fs::write(ollama_blob(models, digest), digest.as_bytes()).unwrap();
+        }
+        path
+    }
+
+    #[test]
+    fn test_ollama_unique_blobs_skip_shared_layers() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let models = temp_dir.path();
+        let small = write_manifest(models, "llama3", "8b", &["sha256:c1", "sha256:base"]);
+        write_manifest(
+            models,
+            "llama3",
+            "8b-instruct",
+            &["sha256:c2", "sha256:base"],
+        );
+
+        let manifests = ollama_manifests(models);
+        assert_eq!(manifests.len(), 2);
+
+        let blobs = ollama_unique_blobs(models, &small, &manifests);
+        assert_eq!(blobs, vec![models.join("blobs").join("sha256-c1")]);
+    }
+}
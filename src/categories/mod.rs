@@ -1,16 +1,29 @@
 pub mod app_cache;
 pub mod applications;
+pub mod autoruns;
 pub mod browser;
 pub mod build;
 pub mod cache;
+pub mod crash_dumps;
+pub mod creative_caches;
+pub mod device_backups;
 pub mod downloads;
 pub mod duplicates;
+pub mod email;
 pub mod empty;
 pub mod event_logs;
+pub mod game_caches;
+pub mod git_ignored;
 pub mod large;
+pub mod logs;
+pub mod ml_models;
 pub mod old;
+pub mod python_envs;
+pub mod registry_audit;
 pub mod startup;
 pub mod system;
 pub mod temp;
+pub mod thumbnails;
+pub mod toolchain_caches;
 pub mod trash;
 pub mod windows_update;
@@ -1,7 +1,9 @@
+use crate::cancel::CancellationToken;
 use crate::config::Config;
 use crate::git;
 use crate::output::{CategoryResult, OutputMode};
 use crate::project;
+use crate::scan_cache::FrontierWalk;
 use crate::scan_events::{ScanPathReporter, ScanProgressEvent};
 use crate::theme::Theme;
 use crate::utils;
@@ -9,9 +11,11 @@ use anyhow::{Context, Result};
 use bytesize;
 use chrono::{Duration, Utc};
 use std::env;
+use std::fs::Metadata;
 use std::path::{Path, PathBuf};
 use std::sync::mpsc::Sender;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 /// Maximum number of results to return
 const MAX_RESULTS: usize = 200;
@@ -19,6 +23,95 @@ const MAX_RESULTS: usize = 200;
 /// Minimum file size to consider (skip tiny files that add noise)
 const MIN_FILE_SIZE: u64 = 10 * 1024; // 10 KB
 
+/// Which timestamp a file's age is measured from (`categories.old.time_basis`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeBasis {
+    Modified,
+    Accessed,
+    Created,
+    /// The latest of modified, accessed and created
+    Max,
+}
+
+impl TimeBasis {
+    /// Parse `categories.old.time_basis` (unknown values mean "modified")
+    pub fn from_config(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "accessed" | "access" | "atime" => TimeBasis::Accessed,
+            "created" | "creation" | "ctime" => TimeBasis::Created,
+            "max" | "max-of" | "latest" => TimeBasis::Max,
+            _ => TimeBasis::Modified,
+        }
+    }
+
+    /// The basis configured for Old Files
+    pub fn of(config: &Config) -> Self {
+        Self::from_config(&config.categories.old.time_basis)
+    }
+
+    /// Name as written in the config
+    pub fn name(self) -> &'static str {
+        match self {
+            TimeBasis::Modified => "modified",
+            TimeBasis::Accessed => "accessed",
+            TimeBasis::Created => "created",
+            TimeBasis::Max => "max",
+        }
+    }
+
+    /// Verb for descriptions: "Files not {verb} in 30 days"
+    pub fn verb(self) -> &'static str {
+        match self {
+            TimeBasis::Modified => "modified",
+            TimeBasis::Accessed => "accessed",
+            TimeBasis::Created => "created",
+            TimeBasis::Max => "touched",
+        }
+    }
+
+    /// The file's timestamp on this basis (None if the filesystem lacks it)
+    pub fn time(self, metadata: &Metadata) -> Option<SystemTime> {
+        match self {
+            TimeBasis::Modified => metadata.modified().ok(),
+            TimeBasis::Accessed => metadata.accessed().ok(),
+            TimeBasis::Created => metadata.created().ok(),
+            TimeBasis::Max => [metadata.modified(), metadata.accessed(), metadata.created()]
+                .into_iter()
+                .filter_map(|time| time.ok())
+                .max(),
+        }
+    }
+}
+
+/// Whether Windows stopped updating last-access times
+///
+/// `NtfsDisableLastAccessUpdate` has the low bit set when updates are off
+/// (the default on many installs). Access times then only say when a file
+/// was created or copied, so "accessed" finds files people still use.
+#[cfg(windows)]
+pub fn last_access_disabled() -> bool {
+    use winreg::enums::HKEY_LOCAL_MACHINE;
+    use winreg::RegKey;
+
+    RegKey::predef(HKEY_LOCAL_MACHINE)
+        .open_subkey(r"SYSTEM\CurrentControlSet\Control\FileSystem")
+        .and_then(|key| key.get_value::<u32, _>("NtfsDisableLastAccessUpdate"))
+        .is_ok_and(|value| value & 1 == 1)
+}
+
+#[cfg(not(windows))]
+pub fn last_access_disabled() -> bool {
+    false
+}
+
+/// Warning for an "accessed" basis on a system that doesn't track access
+pub fn time_basis_warning(config: &Config) -> Option<&'static str> {
+    (TimeBasis::of(config) == TimeBasis::Accessed && last_access_disabled()).then_some(
+        "Last-access updates are disabled on this system, so access times are unreliable; \
+         consider categories.old.time_basis = \"modified\"",
+    )
+}
+
 /// Scan for old files in user directories
 ///
 /// Optimizations:
@@ -43,11 +136,15 @@ pub fn scan(
 
     if output_mode != OutputMode::Quiet && !user_dirs.is_empty() {
         println!(
-            "  {} Scanning {} directories for old files (older than {} days)...",
+            "  {} Scanning {} directories for files not {} in {} days...",
             Theme::muted("→"),
             user_dirs.len(),
+            TimeBasis::of(config).verb(),
             min_age_days
         );
+        if let Some(warning) = time_basis_warning(config) {
+            println!("  {} {}", Theme::warning("!"), warning);
+        }
     }
 
     // Collect files with sizes for sorting
@@ -135,29 +232,38 @@ fn get_user_directories() -> Result<Vec<PathBuf>> {
 }
 
 /// Scan for old files with TUI progress updates (current file path).
+///
+/// The user folders left to walk are checkpointed through `walk`.
 pub fn scan_with_progress(
     root: &Path,
     min_age_days: u64,
     config: &Config,
     output_mode: OutputMode,
     tx: &Sender<ScanProgressEvent>,
+    cancel: &CancellationToken,
+    walk: &mut FrontierWalk,
 ) -> Result<CategoryResult> {
-    let reporter = Arc::new(ScanPathReporter::new("Old Files", tx.clone(), 75));
+    let reporter = Arc::new(ScanPathReporter::new("Old Files", tx.clone(), 75).with_cancel(cancel));
 
     let cutoff = Utc::now() - Duration::days(min_age_days as i64);
-    let user_dirs = get_user_directories()?;
-    let mut files_with_sizes: Vec<(PathBuf, u64)> = Vec::new();
-
-    for dir in &user_dirs {
+    // Continues from the checkpointed folders of an interrupted scan
+    let mut frontier = walk.start(get_user_directories)?;
+    while let Some(dir) = frontier.pending.first().cloned() {
+        let mut found = Vec::new();
         scan_directory(
-            dir,
+            &dir,
             &cutoff,
-            &mut files_with_sizes,
+            &mut found,
             config,
             output_mode,
             Some(Arc::clone(&reporter)),
         )?;
+        cancel.check()?;
+        frontier.pending.remove(0);
+        frontier.found.extend(found);
+        walk.save(&frontier);
     }
+    let mut files_with_sizes = frontier.found;
 
     files_with_sizes.sort_by(|a, b| b.1.cmp(&a.1));
     files_with_sizes.truncate(MAX_RESULTS);
@@ -190,15 +296,17 @@ fn scan_directory(
 
     const MAX_DEPTH: usize = 20;
 
+    let basis = TimeBasis::of(config);
+
     // Clone config for thread-safe access
     let config_arc = Arc::new(config.clone());
 
     let walk = WalkDir::new(dir)
         .max_depth(MAX_DEPTH)
         .follow_links(false)
-        .parallelism(jwalk::Parallelism::RayonDefaultPool {
-            busy_timeout: std::time::Duration::from_secs(1),
-        })
+        .parallelism(crate::drive::walk_parallelism(
+            std::time::Duration::from_secs(1),
+        ))
         .process_read_dir(move |_depth, _path, _state, children| {
             let config = Arc::clone(&config_arc);
             children.retain(|entry| {
@@ -255,6 +363,9 @@ fn scan_directory(
     for e in walk.into_iter().flatten() {
         let path = e.path();
         if let Some(ref reporter) = reporter {
+            if reporter.is_cancelled() {
+                break;
+            }
             reporter.emit_path(&path);
         }
 
@@ -274,9 +385,9 @@ fn scan_directory(
         }
 
         // Check age
-        if let Ok(modified) = metadata.modified() {
-            let modified_dt: chrono::DateTime<Utc> = modified.into();
-            if modified_dt < *cutoff {
+        if let Some(time) = basis.time(&metadata) {
+            let time: chrono::DateTime<Utc> = time.into();
+            if time < *cutoff {
                 // Skip files in active projects (using CACHED git lookup)
                 // PERFORMANCE: Both find_git_root_cached and is_project_active are now cached
                 if let Some(project_root) = git::find_git_root_cached(&path) {
@@ -301,3 +412,21 @@ pub fn clean(path: &Path) -> Result<()> {
         .with_context(|| format!("Failed to delete old file: {}", path.display()))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_basis_from_config() {
+        assert_eq!(TimeBasis::from_config(""), TimeBasis::Modified);
+        assert_eq!(TimeBasis::from_config("Accessed"), TimeBasis::Accessed);
+        assert_eq!(TimeBasis::from_config("max-of"), TimeBasis::Max);
+        assert_eq!(TimeBasis::from_config("bogus"), TimeBasis::Modified);
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let metadata = file.as_file().metadata().unwrap();
+        let max = TimeBasis::Max.time(&metadata).unwrap();
+        assert!(max >= TimeBasis::Modified.time(&metadata).unwrap());
+    }
+}
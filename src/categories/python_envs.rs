@@ -0,0 +1,244 @@
+//! Stale Python environments
+//!
+//! Finds, under the scan path:
+//! - virtualenvs (any folder with a `pyvenv.cfg`, e.g. `.venv`, `venv`, `~/Envs/foo`)
+//! - conda environments (`<install>\envs\<name>` with a `conda-meta` folder),
+//!   including the ones conda lists in `~/.conda/environments.txt`
+//! - `__pycache__` bytecode trees
+//!
+//! An environment inside a project is stale when the project is inactive
+//! (see [`project::is_project_active`]). Environments outside any project are
+//! stale when they haven't been modified (packages installed or updated) for the
+//! same number of days. Base conda installs are never reported.
+
+use crate::config::Config;
+use crate::output::CategoryResult;
+use crate::project;
+use crate::utils;
+use anyhow::Result;
+use std::collections::HashSet;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use walkdir::WalkDir;
+
+/// Kind of Python environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvKind {
+    Virtualenv,
+    Conda,
+    Bytecode,
+}
+
+impl EnvKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            EnvKind::Virtualenv => "virtualenv",
+            EnvKind::Conda => "conda env",
+            EnvKind::Bytecode => "bytecode cache",
+        }
+    }
+}
+
+/// Classify a folder as a Python environment
+pub fn env_kind(path: &Path) -> Option<EnvKind> {
+    if path
+        .file_name()
+        .is_some_and(|n| n.eq_ignore_ascii_case("__pycache__"))
+    {
+        return Some(EnvKind::Bytecode);
+    }
+    if utils::safe_exists(&path.join("pyvenv.cfg")) {
+        return Some(EnvKind::Virtualenv);
+    }
+    // Only named envs - a base install also has conda-meta but lives elsewhere
+    let in_envs_folder = path
+        .parent()
+        .and_then(|p| p.file_name())
+        .is_some_and(|n| n.eq_ignore_ascii_case("envs"));
+    if in_envs_folder && utils::safe_is_dir(&path.join("conda-meta")) {
+        return Some(EnvKind::Conda);
+    }
+    None
+}
+
+/// Project owning an environment: the nearest ancestor that is a project root
+fn owning_project(env_path: &Path) -> Option<PathBuf> {
+    env_path
+        .ancestors()
+        .skip(1)
+        .take(6)
+        .find(|dir| project::detect_project_type(dir).is_some())
+        .map(Path::to_path_buf)
+}
+
+/// Last time packages were installed into an environment
+fn env_last_modified(env_path: &Path, kind: EnvKind) -> Option<SystemTime> {
+    let markers: Vec<PathBuf> = match kind {
+        EnvKind::Virtualenv => vec![
+            env_path.join("pyvenv.cfg"),
+            env_path.join("Lib").join("site-packages"),
+            env_path.join("lib"),
+        ],
+        EnvKind::Conda => vec![
+            env_path.join("conda-meta").join("history"),
+            env_path.join("conda-meta"),
+        ],
+        EnvKind::Bytecode => vec![env_path.to_path_buf()],
+    };
+    markers
+        .iter()
+        .filter_map(|p| utils::safe_metadata(p).and_then(|m| m.modified()).ok())
+        .max()
+}
+
+/// Whether an environment is stale (its project, or the env itself, is idle)
+fn is_stale(env_path: &Path, kind: EnvKind, age_days: u64) -> bool {
+    if let Some(project_root) = owning_project(env_path) {
+        return !project::is_project_active(&project_root, age_days).unwrap_or(true);
+    }
+    let min_age = Duration::from_secs(age_days * 24 * 60 * 60);
+    env_last_modified(env_path, kind)
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age >= min_age)
+}
+
+/// Environments conda knows about (`~/.conda/environments.txt`)
+fn registered_conda_envs() -> Vec<PathBuf> {
+    let Some(home) = env::var("USERPROFILE").ok().map(PathBuf::from) else {
+        return Vec::new();
+    };
+    let Ok(contents) = std::fs::read_to_string(home.join(".conda").join("environments.txt")) else {
+        return Vec::new();
+    };
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(PathBuf::from)
+        .collect()
+}
+
+/// Check if we should skip walking into this directory
+fn should_skip_dir(entry: &walkdir::DirEntry, config: &Config) -> bool {
+    if utils::should_skip_entry(entry.path()) || utils::is_system_path(entry.path()) {
+        return true;
+    }
+    let name = entry.file_name().to_string_lossy().to_lowercase();
+    matches!(
+        name.as_str(),
+        "node_modules" | ".git" | ".hg" | ".svn" | "target"
+    ) || config.is_excluded(entry.path())
+}
+
+/// Find every Python environment below `root`
+fn find_envs(root: &Path, config: &Config) -> Vec<(PathBuf, EnvKind)> {
+    const MAX_DEPTH: usize = 10;
+    let mut found = Vec::new();
+
+    let mut walker = WalkDir::new(root)
+        .max_depth(MAX_DEPTH)
+        .follow_links(false)
+        .into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+        if should_skip_dir(&entry, config) {
+            walker.skip_current_dir();
+            continue;
+        }
+        if let Some(kind) = env_kind(entry.path()) {
+            found.push((entry.path().to_path_buf(), kind));
+            // Nothing inside an environment is reported separately
+            walker.skip_current_dir();
+        }
+    }
+
+    found
+}
+
+/// Scan for stale virtualenvs, conda environments and `__pycache__` trees
+pub fn scan(root: &Path, project_age_days: u64, config: &Config) -> Result<CategoryResult> {
+    let mut envs = find_envs(root, config);
+
+    let mut seen: HashSet<PathBuf> = envs.iter().map(|(p, _)| p.clone()).collect();
+    for path in registered_conda_envs() {
+        if seen.contains(&path) || config.is_excluded(&path) {
+            continue;
+        }
+        if let Some(kind @ EnvKind::Conda) = env_kind(&path) {
+            seen.insert(path.clone());
+            envs.push((path, kind));
+        }
+    }
+
+    let mut items: Vec<(PathBuf, u64)> = envs
+        .into_iter()
+        .filter(|(path, kind)| is_stale(path, *kind, project_age_days))
+        .map(|(path, _)| {
+            let size = utils::calculate_dir_size(&path);
+            (path, size)
+        })
+        .filter(|(_, size)| *size > 0)
+        .collect();
+
+    // Biggest environments first
+    items.sort_by(|a, b| b.1.cmp(&a.1));
+
+    let mut result = CategoryResult::default();
+    for (path, size) in items {
+        result.items += 1;
+        result.size_bytes += size;
+        result.paths.push(path);
+    }
+    Ok(result)
+}
+
+/// Kind of environment and owning project for verbose output, e.g. "virtualenv of my-app"
+pub fn describe(path: &Path) -> Option<String> {
+    let kind = env_kind(path)?;
+    let detail = match owning_project(path).and_then(|p| p.file_name().map(|n| n.to_owned())) {
+        Some(project) => format!("{} of {}", kind.label(), project.to_string_lossy()),
+        None => kind.label().to_string(),
+    };
+    Some(detail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_envs_classifies_and_stops_at_env() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+
+        let venv = root.join("app").join(".venv");
+        std::fs::create_dir_all(venv.join("Lib").join("site-packages").join("__pycache__"))
+            .unwrap();
+        std::fs::write(venv.join("pyvenv.cfg"), b"home = C:\\Python312").unwrap();
+
+        let pycache = root.join("app").join("src").join("__pycache__");
+        std::fs::create_dir_all(&pycache).unwrap();
+
+        let conda_env = root.join("miniconda3").join("envs").join("ml");
+        std::fs::create_dir_all(conda_env.join("conda-meta")).unwrap();
+        // The base install is not an environment to clean
+        std::fs::create_dir_all(root.join("miniconda3").join("conda-meta")).unwrap();
+
+        let mut envs = find_envs(root, &Config::default());
+        envs.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            envs,
+            vec![
+                (venv, EnvKind::Virtualenv),
+                (pycache, EnvKind::Bytecode),
+                (conda_env, EnvKind::Conda),
+            ]
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! Registry junk report (`wole registry`)
+//!
+//! Finds registry entries that point at files which are gone: uninstall
+//! entries for programs whose folder was deleted by hand, file associations
+//! whose program is missing and App Paths keys for executables that no longer
+//! exist. This is a report only - wole never deletes registry keys, and
+//! nothing here runs unless `wole registry` is asked for.
+
+use serde::Serialize;
+use std::path::PathBuf;
+
+#[cfg(windows)]
+use winreg::enums::*;
+#[cfg(windows)]
+use winreg::{RegKey, HKEY};
+
+/// Where Programs and Features reads installed programs from
+#[cfg(windows)]
+const UNINSTALL_KEYS: [(HKEY, &str, &str); 3] = [
+    (
+        HKEY_LOCAL_MACHINE,
+        "HKLM",
+        "Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+    ),
+    (
+        HKEY_LOCAL_MACHINE,
+        "HKLM",
+        "Software\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+    ),
+    (
+        HKEY_CURRENT_USER,
+        "HKCU",
+        "Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+    ),
+];
+
+#[cfg(windows)]
+const APP_PATHS_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\App Paths";
+
+/// What kind of stale entry a finding is
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingKind {
+    /// Uninstall entry whose install folder or uninstaller is gone
+    OrphanedUninstall,
+    /// File type whose "open" program is gone
+    InvalidAssociation,
+    /// App Paths key for an executable that is gone
+    BrokenAppPath,
+}
+
+impl FindingKind {
+    pub const ALL: [FindingKind; 3] = [
+        FindingKind::OrphanedUninstall,
+        FindingKind::InvalidAssociation,
+        FindingKind::BrokenAppPath,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FindingKind::OrphanedUninstall => "Orphaned uninstall entries",
+            FindingKind::InvalidAssociation => "Invalid file associations",
+            FindingKind::BrokenAppPath => "Broken App Paths",
+        }
+    }
+
+    fn id(self) -> &'static str {
+        match self {
+            FindingKind::OrphanedUninstall => "orphaned_uninstall",
+            FindingKind::InvalidAssociation => "invalid_association",
+            FindingKind::BrokenAppPath => "broken_app_path",
+        }
+    }
+}
+
+/// A registry entry pointing at a missing file or folder
+#[derive(Debug, Clone, Serialize)]
+pub struct RegistryFinding {
+    pub kind: FindingKind,
+    /// Program name, `.ext (ProgID)` or executable name
+    pub name: String,
+    /// Full key, e.g. `HKLM\Software\...\Uninstall\{GUID}`
+    pub key: String,
+    /// The missing file or folder
+    pub target: PathBuf,
+}
+
+/// Look through the uninstall entries, file associations and App Paths
+///
+/// Always empty on other platforms.
+pub fn audit() -> Vec<RegistryFinding> {
+    #[cfg(windows)]
+    {
+        let mut findings = orphaned_uninstall_entries();
+        findings.extend(invalid_associations());
+        findings.extend(broken_app_paths());
+        findings
+    }
+
+    #[cfg(not(windows))]
+    {
+        Vec::new()
+    }
+}
+
+/// The findings as CSV: kind, name, key, target
+pub fn to_csv(findings: &[RegistryFinding]) -> String {
+    use crate::disk_usage::csv_field;
+    let mut csv = String::from("kind,name,key,target\n");
+    for finding in findings {
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            finding.kind.id(),
+            csv_field(&finding.name),
+            csv_field(&finding.key),
+            csv_field(&finding.target.to_string_lossy())
+        ));
+    }
+    csv
+}
+
+/// The program a command line runs, e.g. `C:\Tools\app.exe` from
+/// `"C:\Tools\app.exe" "%1"` or `%ProgramFiles%\App\un.exe /S`
+pub fn command_target(command: &str) -> Option<PathBuf> {
+    let command = expand_env(command.trim());
+    let path = if let Some(quoted) = command.strip_prefix('"') {
+        quoted.split('"').next()?.to_string()
+    } else {
+        // Unquoted paths can contain spaces, so cut after the first executable extension
+        let lower = command.to_ascii_lowercase();
+        let end = [".exe", ".com", ".bat", ".cmd"]
+            .iter()
+            .filter_map(|ext| lower.find(ext).map(|i| i + ext.len()))
+            .min();
+        match end {
+            Some(end) => command[..end].to_string(),
+            None => command.split_whitespace().next()?.to_string(),
+        }
+    };
+    let path = path.trim();
+    (!path.is_empty()).then(|| PathBuf::from(path))
+}
+
+/// Replace `%VAR%` with the variable's value; unknown variables (and `%1`) stay as they are
+fn expand_env(value: &str) -> String {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('%') {
+        let Some(len) = rest[start + 1..].find('%') else {
+            break;
+        };
+        let name = &rest[start + 1..start + 1 + len];
+        expanded.push_str(&rest[..start]);
+        let var = (!name.is_empty() && !name.contains(['=', '\0']))
+            .then(|| std::env::var(name).ok())
+            .flatten();
+        match var {
+            Some(var) => {
+                expanded.push_str(&var);
+                rest = &rest[start + len + 2..];
+            }
+            None => {
+                // Keep the first % and look for a variable from the second one on
+                expanded.push('%');
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+/// Relative paths ("rundll32.exe") are found through PATH, so only full paths can be missing
+#[cfg(windows)]
+fn is_missing(path: &std::path::Path) -> bool {
+    path.is_absolute() && !path.exists()
+}
+
+#[cfg(windows)]
+fn orphaned_uninstall_entries() -> Vec<RegistryFinding> {
+    let mut findings = Vec::new();
+    for (hkey, root, path) in UNINSTALL_KEYS {
+        let Ok(uninstall) = RegKey::predef(hkey).open_subkey(path) else {
+            continue;
+        };
+        for name in uninstall.enum_keys().flatten() {
+            let Ok(entry) = uninstall.open_subkey(&name) else {
+                continue;
+            };
+            let display_name: String = entry.get_value("DisplayName").unwrap_or_default();
+            // Entries without a name aren't listed in Programs and Features
+            if display_name.trim().is_empty() {
+                continue;
+            }
+            let location: String = entry.get_value("InstallLocation").unwrap_or_default();
+            let location = location.trim().trim_matches('"');
+            let target = if location.is_empty() {
+                entry
+                    .get_value::<String, _>("UninstallString")
+                    .ok()
+                    .and_then(|command| command_target(&command))
+            } else {
+                Some(PathBuf::from(expand_env(location)))
+            };
+            if let Some(target) = target.filter(|target| is_missing(target)) {
+                findings.push(RegistryFinding {
+                    kind: FindingKind::OrphanedUninstall,
+                    name: display_name,
+                    key: format!("{}\\{}\\{}", root, path, name),
+                    target,
+                });
+            }
+        }
+    }
+    findings
+}
+
+#[cfg(windows)]
+fn invalid_associations() -> Vec<RegistryFinding> {
+    let mut findings = Vec::new();
+    // HKCR is the merged view of the machine and user classes
+    let classes = RegKey::predef(HKEY_CLASSES_ROOT);
+    for ext in classes.enum_keys().flatten() {
+        if !ext.starts_with('.') {
+            continue;
+        }
+        let Ok(ext_key) = classes.open_subkey(&ext) else {
+            continue;
+        };
+        let prog_id: String = ext_key.get_value("").unwrap_or_default();
+        if prog_id.is_empty() {
+            continue;
+        }
+        let Ok(command) = classes
+            .open_subkey(format!("{}\\shell\\open\\command", prog_id))
+            .and_then(|key| key.get_value::<String, _>(""))
+        else {
+            continue;
+        };
+        if let Some(target) = command_target(&command).filter(|target| is_missing(target)) {
+            findings.push(RegistryFinding {
+                kind: FindingKind::InvalidAssociation,
+                name: format!("{} ({})", ext, prog_id),
+                key: format!("HKCR\\{}\\shell\\open\\command", prog_id),
+                target,
+            });
+        }
+    }
+    findings
+}
+
+#[cfg(windows)]
+fn broken_app_paths() -> Vec<RegistryFinding> {
+    let mut findings = Vec::new();
+    for (hkey, root) in [(HKEY_LOCAL_MACHINE, "HKLM"), (HKEY_CURRENT_USER, "HKCU")] {
+        let Ok(app_paths) = RegKey::predef(hkey).open_subkey(APP_PATHS_KEY) else {
+            continue;
+        };
+        for name in app_paths.enum_keys().flatten() {
+            let Ok(exe) = app_paths
+                .open_subkey(&name)
+                .and_then(|key| key.get_value::<String, _>(""))
+            else {
+                continue;
+            };
+            if let Some(target) = command_target(&exe).filter(|target| is_missing(target)) {
+                findings.push(RegistryFinding {
+                    kind: FindingKind::BrokenAppPath,
+                    name: name.clone(),
+                    key: format!("{}\\{}\\{}", root, APP_PATHS_KEY, name),
+                    target,
+                });
+            }
+        }
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_target() {
+        assert_eq!(
+            command_target("\"C:\\Program Files\\App\\app.exe\" \"%1\""),
+            Some(PathBuf::from("C:\\Program Files\\App\\app.exe"))
+        );
+        assert_eq!(
+            command_target("C:\\Program Files\\App\\uninst.EXE /S"),
+            Some(PathBuf::from("C:\\Program Files\\App\\uninst.EXE"))
+        );
+        assert_eq!(
+            command_target("rundll32.exe shell32.dll,OpenAs %1"),
+            Some(PathBuf::from("rundll32.exe"))
+        );
+        assert_eq!(command_target("  "), None);
+    }
+
+    #[test]
+    fn test_expand_env() {
+        std::env::set_var("WOLE_REGISTRY_TEST", "C:\\Apps");
+        assert_eq!(
+            expand_env("%WOLE_REGISTRY_TEST%\\tool.exe \"%1\""),
+            "C:\\Apps\\tool.exe \"%1\""
+        );
+        assert_eq!(expand_env("100% %NO_SUCH_VAR_X%"), "100% %NO_SUCH_VAR_X%");
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let findings = [RegistryFinding {
+            kind: FindingKind::BrokenAppPath,
+            name: "old, app.exe".to_string(),
+            key: "HKCU\\App Paths\\old, app.exe".to_string(),
+            target: PathBuf::from("C:\\Old\\app.exe"),
+        }];
+        assert_eq!(
+            to_csv(&findings),
+            "kind,name,key,target\nbroken_app_path,\"old, app.exe\",\"HKCU\\App Paths\\old, app.exe\",C:\\Old\\app.exe\n"
+        );
+    }
+}
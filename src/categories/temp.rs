@@ -1,6 +1,8 @@
+use crate::cancel::CancellationToken;
 use crate::config::Config;
 use crate::output::CategoryResult;
 use crate::scan_events::{ScanPathReporter, ScanProgressEvent};
+use crate::users::UserProfile;
 use anyhow::{Context, Result};
 use chrono::{Duration, Utc};
 use std::env;
@@ -67,6 +69,7 @@ pub fn scan_with_progress(
     _root: &Path,
     config: &Config,
     tx: &Sender<ScanProgressEvent>,
+    cancel: &CancellationToken,
 ) -> Result<CategoryResult> {
     const CATEGORY: &str = "Temp Files";
     let cutoff = Utc::now() - Duration::days(1);
@@ -103,7 +106,7 @@ pub fn scan_with_progress(
         return Ok(result);
     }
 
-    let reporter = ScanPathReporter::new(CATEGORY, tx.clone(), 10);
+    let reporter = ScanPathReporter::new(CATEGORY, tx.clone(), 10).with_cancel(cancel);
 
     for (idx, root) in temp_roots.iter().enumerate() {
         if root.exists() {
@@ -115,6 +118,7 @@ pub fn scan_with_progress(
                 Some(&reporter),
             );
         }
+        cancel.check()?;
         let _ = tx.send(ScanProgressEvent::CategoryProgress {
             category: CATEGORY.to_string(),
             completed_units: (idx + 1) as u64,
@@ -142,6 +146,43 @@ pub fn scan_with_progress(
     Ok(result)
 }
 
+/// Temp files of another user's profile (`--scope all-users`)
+pub fn scan_profile(
+    profile: &UserProfile,
+    config: &Config,
+    cancel: &CancellationToken,
+) -> Result<CategoryResult> {
+    let cutoff = Utc::now() - Duration::days(1);
+    let mut result = CategoryResult::default();
+    let mut files_with_sizes: Vec<(PathBuf, u64)> = Vec::new();
+
+    let local_temp = profile.local_appdata().join("Temp");
+    if local_temp.exists() {
+        // The reporter only carries the cancellation token here; nobody listens for paths
+        let (tx, _) = std::sync::mpsc::channel();
+        let reporter = ScanPathReporter::new("Temp Files", tx, 10).with_cancel(cancel);
+        scan_temp_dir(
+            &local_temp,
+            &cutoff,
+            &mut files_with_sizes,
+            config,
+            Some(&reporter),
+        );
+    }
+    cancel.check()?;
+
+    files_with_sizes.sort_by(|a, b| b.1.cmp(&a.1));
+    files_with_sizes.truncate(MAX_RESULTS);
+
+    for (path, size) in files_with_sizes {
+        result.items += 1;
+        result.size_bytes += size;
+        result.paths.push(path);
+    }
+
+    Ok(result)
+}
+
 fn scan_temp_dir(
     temp_path: &Path,
     cutoff: &chrono::DateTime<Utc>,
@@ -159,6 +200,13 @@ fn scan_temp_dir(
             if e.file_type().is_dir() && config.is_excluded(e.path()) {
                 return false;
             }
+            // winget/Chocolatey download caches are reported by Package Cache
+            if e.depth() == 1
+                && e.file_type().is_dir()
+                && super::cache::is_temp_cache_dir(&e.file_name().to_string_lossy())
+            {
+                return false;
+            }
             true
         })
     {
@@ -168,6 +216,9 @@ fn scan_temp_dir(
         };
 
         if let Some(reporter) = reporter {
+            if reporter.is_cancelled() {
+                return;
+            }
             reporter.emit_path(entry.path());
         }
 
@@ -0,0 +1,139 @@
+//! Explorer thumbnail and icon caches
+//!
+//! `thumbcache_*.db` and `iconcache_*.db` in
+//! `%LOCALAPPDATA%\Microsoft\Windows\Explorer`, plus the legacy
+//! `%LOCALAPPDATA%\IconCache.db`. Explorer keeps these open, so cleaning stops
+//! Explorer, deletes the files (icon caches through
+//! [`crate::optimize::rebuild_icon_cache`]) and starts it again. Windows
+//! rebuilds both caches on demand, so they are deleted permanently.
+
+use crate::config::Config;
+use crate::output::CategoryResult;
+use crate::utils;
+use anyhow::Result;
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// `%LOCALAPPDATA%\Microsoft\Windows\Explorer`
+fn explorer_cache_dir() -> Option<PathBuf> {
+    env::var("LOCALAPPDATA").ok().map(|local| {
+        PathBuf::from(local)
+            .join("Microsoft")
+            .join("Windows")
+            .join("Explorer")
+    })
+}
+
+fn is_thumbnail_cache(name: &str) -> bool {
+    let name = name.to_lowercase();
+    name.starts_with("thumbcache_") && name.ends_with(".db")
+}
+
+fn is_icon_cache(name: &str) -> bool {
+    let name = name.to_lowercase();
+    name == "iconcache.db" || (name.starts_with("iconcache_") && name.ends_with(".db"))
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Scan the current user's thumbnail and icon cache databases
+pub fn scan(_root: &Path, config: &Config) -> Result<CategoryResult> {
+    let mut result = CategoryResult::default();
+
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    if let Some(dir) = explorer_cache_dir() {
+        if let Ok(entries) = utils::safe_read_dir(&dir) {
+            candidates.extend(entries.flatten().map(|entry| entry.path()).filter(|p| {
+                let name = file_name(p);
+                is_thumbnail_cache(&name) || is_icon_cache(&name)
+            }));
+        }
+    }
+    if let Ok(local) = env::var("LOCALAPPDATA") {
+        candidates.push(PathBuf::from(local).join("IconCache.db"));
+    }
+
+    for path in candidates {
+        if config.is_excluded(&path) {
+            continue;
+        }
+        let Ok(metadata) = utils::safe_metadata(&path) else {
+            continue;
+        };
+        if !metadata.is_file() || metadata.len() == 0 {
+            continue;
+        }
+        result.items += 1;
+        result.size_bytes += metadata.len();
+        result.paths.push(path);
+    }
+
+    result.paths.sort();
+    Ok(result)
+}
+
+/// Outcome of clearing the caches
+#[derive(Debug, Default)]
+pub struct ThumbnailCleanResult {
+    pub deleted: Vec<PathBuf>,
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+/// Stop Explorer, delete `paths` and start Explorer again
+pub fn clean(paths: &[PathBuf]) -> ThumbnailCleanResult {
+    let mut result = ThumbnailCleanResult::default();
+    if paths.is_empty() {
+        return result;
+    }
+
+    let explorer_stopped = crate::optimize::stop_explorer().is_ok();
+
+    let (icon_caches, thumbnail_caches): (Vec<&PathBuf>, Vec<&PathBuf>) =
+        paths.iter().partition(|p| is_icon_cache(&file_name(p)));
+
+    for path in thumbnail_caches {
+        match utils::safe_remove_file(path) {
+            Ok(()) => result.deleted.push(path.clone()),
+            Err(e) => result.failed.push((path.clone(), e.to_string())),
+        }
+    }
+
+    if !icon_caches.is_empty() {
+        // Deletes IconCache.db and every iconcache_*.db; Explorer is handled here
+        crate::optimize::rebuild_icon_cache(false, false);
+        for path in icon_caches {
+            if utils::safe_exists(path) {
+                result
+                    .failed
+                    .push((path.clone(), "Icon cache is still in use".to_string()));
+            } else {
+                result.deleted.push(path.clone());
+            }
+        }
+    }
+
+    if explorer_stopped {
+        let _ = crate::optimize::start_explorer();
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_file_names() {
+        assert!(is_thumbnail_cache("thumbcache_256.db"));
+        assert!(is_thumbnail_cache("ThumbCache_idx.db"));
+        assert!(!is_thumbnail_cache("thumbcache_256.db-journal"));
+        assert!(is_icon_cache("iconcache_32.db"));
+        assert!(is_icon_cache("IconCache.db"));
+        assert!(!is_icon_cache("thumbcache_32.db"));
+    }
+}
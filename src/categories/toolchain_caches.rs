@@ -0,0 +1,421 @@
+//! IDE and toolchain caches (Gradle, Maven, JetBrains, Visual Studio, sccache)
+//!
+//! Unlike the package cache category, which reports `.gradle\caches` or
+//! `.m2\repository` as a whole, this looks inside each cache and reports the
+//! parts that have gone unused:
+//! - Gradle: per-version caches, wrapper distributions, daemon folders and
+//!   per-group dependency caches (`caches\modules-2\files-2.1\<group>`)
+//! - Maven: individual artifact versions in `.m2\repository`
+//! - JetBrains: per-version IDE system folders in `%LOCALAPPDATA%\JetBrains`
+//! - Visual Studio: `ComponentModelCache` of each installed instance
+//! - sccache: the compiler cache (`SCCACHE_DIR` or the default location)
+//!
+//! Safety rules: an item is only reported when nothing in it was modified for
+//! `thresholds.toolchain_cache_age_days`, and for versioned folders (Gradle
+//! versions, JetBrains products) the most recently used version is always kept.
+
+use crate::config::Config;
+use crate::output::CategoryResult;
+use crate::utils;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use walkdir::WalkDir;
+
+/// A tool whose caches this category looks at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tool {
+    Gradle,
+    Maven,
+    JetBrains,
+    VisualStudio,
+    Sccache,
+}
+
+impl Tool {
+    pub const ALL: [Tool; 5] = [
+        Tool::Gradle,
+        Tool::Maven,
+        Tool::JetBrains,
+        Tool::VisualStudio,
+        Tool::Sccache,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Tool::Gradle => "Gradle",
+            Tool::Maven => "Maven",
+            Tool::JetBrains => "JetBrains",
+            Tool::VisualStudio => "Visual Studio",
+            Tool::Sccache => "sccache",
+        }
+    }
+}
+
+/// How a cache location is split into items
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    /// Every child folder is an item
+    Children,
+    /// Every child folder whose name contains a version; the newest one per
+    /// product (name before the version) is kept
+    VersionedChildren,
+    /// Every artifact version folder (one containing a `.pom`) below the location
+    MavenVersions,
+    /// The location itself is a single item
+    Whole,
+}
+
+struct ToolLocation {
+    tool: Tool,
+    path: PathBuf,
+    layout: Layout,
+}
+
+impl ToolLocation {
+    fn new(tool: Tool, path: PathBuf, layout: Layout) -> Self {
+        Self { tool, path, layout }
+    }
+}
+
+fn tool_locations() -> Vec<ToolLocation> {
+    let mut locations = Vec::new();
+    let local = env::var("LOCALAPPDATA").ok().map(PathBuf::from);
+    let home = env::var("USERPROFILE").ok().map(PathBuf::from);
+
+    let gradle_home = env::var("GRADLE_USER_HOME")
+        .ok()
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|h| h.join(".gradle")));
+    if let Some(gradle) = gradle_home {
+        let caches = gradle.join("caches");
+        locations.push(ToolLocation::new(
+            Tool::Gradle,
+            caches.join("modules-2").join("files-2.1"),
+            Layout::Children,
+        ));
+        locations.push(ToolLocation::new(
+            Tool::Gradle,
+            caches,
+            Layout::VersionedChildren,
+        ));
+        locations.push(ToolLocation::new(
+            Tool::Gradle,
+            gradle.join("wrapper").join("dists"),
+            Layout::VersionedChildren,
+        ));
+        locations.push(ToolLocation::new(
+            Tool::Gradle,
+            gradle.join("daemon"),
+            Layout::VersionedChildren,
+        ));
+    }
+
+    if let Some(ref home) = home {
+        locations.push(ToolLocation::new(
+            Tool::Maven,
+            home.join(".m2").join("repository"),
+            Layout::MavenVersions,
+        ));
+    }
+
+    if let Some(ref local) = local {
+        locations.push(ToolLocation::new(
+            Tool::JetBrains,
+            local.join("JetBrains"),
+            Layout::VersionedChildren,
+        ));
+
+        let vs_root = local.join("Microsoft").join("VisualStudio");
+        if let Ok(entries) = utils::safe_read_dir(&vs_root) {
+            for entry in entries.flatten() {
+                let cache = entry.path().join("ComponentModelCache");
+                if utils::safe_is_dir(&cache) {
+                    locations.push(ToolLocation::new(Tool::VisualStudio, cache, Layout::Whole));
+                }
+            }
+        }
+    }
+
+    let sccache_dir = env::var("SCCACHE_DIR").ok().map(PathBuf::from).or_else(|| {
+        local
+            .as_ref()
+            .map(|l| l.join("Mozilla").join("sccache").join("cache"))
+    });
+    if let Some(sccache) = sccache_dir {
+        locations.push(ToolLocation::new(Tool::Sccache, sccache, Layout::Whole));
+    }
+
+    locations
+}
+
+/// Tool owning a reported cache path
+pub fn tool_for(path: &Path) -> Option<Tool> {
+    tool_locations()
+        .into_iter()
+        .find(|location| path.starts_with(&location.path))
+        .map(|location| location.tool)
+}
+
+/// Display name of the tool owning a reported cache path (for grouping)
+pub fn tool_name(path: &Path) -> Option<&'static str> {
+    tool_for(path).map(Tool::name)
+}
+
+/// Product part of a versioned folder name: `IntelliJIdea2023.2` -> `IntelliJIdea`
+fn product_key(name: &str) -> Option<String> {
+    let digit = name.find(|c: char| c.is_ascii_digit())?;
+    Some(name[..digit].to_lowercase())
+}
+
+/// Artifact version folders (containing a `.pom`) below a Maven repository folder
+fn maven_versions(dir: &Path, depth: usize, out: &mut Vec<PathBuf>) {
+    const MAX_DEPTH: usize = 12;
+    let Ok(entries) = utils::safe_read_dir(dir) else {
+        return;
+    };
+    let mut subdirs = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if utils::safe_is_dir(&path) {
+            subdirs.push(path);
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("pom"))
+        {
+            out.push(dir.to_path_buf());
+            return;
+        }
+    }
+    if depth < MAX_DEPTH {
+        for subdir in subdirs {
+            maven_versions(&subdir, depth + 1, out);
+        }
+    }
+}
+
+/// Candidate items of one location (before the age rules)
+fn location_items(location: &ToolLocation) -> Vec<PathBuf> {
+    if !utils::safe_is_dir(&location.path) {
+        return Vec::new();
+    }
+    let child_dirs = || -> Vec<PathBuf> {
+        match utils::safe_read_dir(&location.path) {
+            Ok(entries) => entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|p| utils::safe_is_dir(p))
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    };
+    match location.layout {
+        Layout::Whole => vec![location.path.clone()],
+        Layout::Children => child_dirs(),
+        Layout::VersionedChildren => child_dirs()
+            .into_iter()
+            .filter(|p| {
+                p.file_name()
+                    .and_then(|n| product_key(&n.to_string_lossy()))
+                    .is_some()
+            })
+            .collect(),
+        Layout::MavenVersions => {
+            let mut versions = Vec::new();
+            maven_versions(&location.path, 0, &mut versions);
+            versions
+        }
+    }
+}
+
+/// Total size and the newest file modification inside a folder
+///
+/// Folders without files fall back to the folder's own modification time.
+fn measure(path: &Path) -> (u64, Option<SystemTime>) {
+    let mut size = 0u64;
+    let mut newest: Option<SystemTime> = None;
+    for entry in WalkDir::new(path).follow_links(false).into_iter().flatten() {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        size += metadata.len();
+        if let Ok(modified) = metadata.modified() {
+            newest = Some(newest.map_or(modified, |n| n.max(modified)));
+        }
+    }
+    let newest = newest.or_else(|| utils::safe_metadata(path).and_then(|m| m.modified()).ok());
+    (size, newest)
+}
+
+struct Candidate {
+    tool: Tool,
+    path: PathBuf,
+    size: u64,
+    last_used: Option<SystemTime>,
+}
+
+/// Apply the safety rules to one location's candidates
+fn select(
+    location: &ToolLocation,
+    mut candidates: Vec<Candidate>,
+    min_age: Duration,
+) -> Vec<Candidate> {
+    if location.layout == Layout::VersionedChildren {
+        // Keep the most recently used version of each product
+        let mut newest: HashMap<String, usize> = HashMap::new();
+        for (i, candidate) in candidates.iter().enumerate() {
+            let Some(key) = candidate
+                .path
+                .file_name()
+                .and_then(|n| product_key(&n.to_string_lossy()))
+            else {
+                continue;
+            };
+            match newest.get(&key) {
+                Some(&j) if candidates[j].last_used >= candidate.last_used => {}
+                _ => {
+                    newest.insert(key, i);
+                }
+            }
+        }
+        let keep: Vec<usize> = newest.into_values().collect();
+        candidates = candidates
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| !keep.contains(i))
+            .map(|(_, c)| c)
+            .collect();
+    }
+
+    let now = SystemTime::now();
+    candidates
+        .into_iter()
+        .filter(|c| c.size > 0)
+        .filter(|c| {
+            c.last_used
+                .and_then(|t| now.duration_since(t).ok())
+                .is_some_and(|age| age >= min_age)
+        })
+        .collect()
+}
+
+/// Scan IDE and toolchain caches for parts unused for the configured age
+pub fn scan(_root: &Path, config: &Config) -> Result<CategoryResult> {
+    let min_age = Duration::from_secs(config.thresholds.toolchain_cache_age_days * 24 * 60 * 60);
+    let mut found: Vec<Candidate> = Vec::new();
+
+    for location in tool_locations() {
+        if config.is_excluded(&location.path) {
+            continue;
+        }
+        let candidates: Vec<Candidate> = location_items(&location)
+            .into_iter()
+            .filter(|path| !config.is_excluded(path))
+            .map(|path| {
+                let (size, last_used) = measure(&path);
+                Candidate {
+                    tool: location.tool,
+                    path,
+                    size,
+                    last_used,
+                }
+            })
+            .collect();
+        found.extend(select(&location, candidates, min_age));
+    }
+
+    // Tool order first, then biggest caches first
+    found.sort_by(|a, b| {
+        let order = |t: &Tool| Tool::ALL.iter().position(|x| x == t);
+        order(&a.tool)
+            .cmp(&order(&b.tool))
+            .then(b.size.cmp(&a.size))
+    });
+
+    let mut result = CategoryResult::default();
+    for candidate in found {
+        result.items += 1;
+        result.size_bytes += candidate.size;
+        result.paths.push(candidate.path);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_aged(path: &Path, days: u64) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, b"cache").unwrap();
+        let modified = SystemTime::now() - Duration::from_secs(days * 24 * 60 * 60);
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_versioned_children_keep_newest_per_product() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        write_aged(
+            &root.join("IntelliJIdea2022.3").join("caches").join("a"),
+            400,
+        );
+        write_aged(
+            &root.join("IntelliJIdea2023.2").join("caches").join("a"),
+            200,
+        );
+        write_aged(&root.join("PyCharm2023.1").join("caches").join("a"), 300);
+        std::fs::create_dir_all(root.join("Toolbox")).unwrap();
+
+        let location = ToolLocation::new(
+            Tool::JetBrains,
+            root.to_path_buf(),
+            Layout::VersionedChildren,
+        );
+        let candidates = location_items(&location)
+            .into_iter()
+            .map(|path| {
+                let (size, last_used) = measure(&path);
+                Candidate {
+                    tool: location.tool,
+                    path,
+                    size,
+                    last_used,
+                }
+            })
+            .collect();
+        let selected = select(
+            &location,
+            candidates,
+            Duration::from_secs(90 * 24 * 60 * 60),
+        );
+
+        // Only the older IntelliJ version: PyCharm's single version is kept
+        let paths: Vec<PathBuf> = selected.into_iter().map(|c| c.path).collect();
+        assert_eq!(paths, vec![root.join("IntelliJIdea2022.3")]);
+    }
+
+    #[test]
+    fn test_maven_versions() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = temp_dir.path();
+        let artifact = repo.join("org").join("example").join("lib");
+        write_aged(&artifact.join("1.0").join("lib-1.0.pom"), 1);
+        write_aged(&artifact.join("2.0").join("lib-2.0.pom"), 1);
+
+        let mut versions = Vec::new();
+        maven_versions(repo, 0, &mut versions);
+        versions.sort();
+        assert_eq!(versions, vec![artifact.join("1.0"), artifact.join("2.0")]);
+    }
+}
@@ -1,31 +1,104 @@
 use crate::output::CategoryResult;
 use crate::trash_ops;
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 
-/// Scan the Recycle Bin for items
+/// One item in the Recycle Bin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    /// Where the item was before it was deleted
+    pub original_path: PathBuf,
+    /// When it was deleted (seconds since the Unix epoch)
+    pub deleted_at: i64,
+    pub size_bytes: u64,
+}
+
+impl TrashEntry {
+    /// Whole days since the item was deleted
+    pub fn age_days(&self) -> u64 {
+        let now = chrono::Utc::now().timestamp();
+        (now.saturating_sub(self.deleted_at).max(0) / 86400) as u64
+    }
+}
+
+/// Entries found by [`scan_entries`]
+#[derive(Debug, Default)]
+pub struct TrashScanResult {
+    pub entries: Vec<TrashEntry>,
+}
+
+impl TrashScanResult {
+    pub fn to_category_result(&self) -> CategoryResult {
+        category_result(&self.entries)
+    }
+}
+
+/// The Trash category's result for `entries`
+pub fn category_result(entries: &[TrashEntry]) -> CategoryResult {
+    CategoryResult {
+        items: entries.len(),
+        size_bytes: entries.iter().map(|e| e.size_bytes).sum(),
+        paths: entries.iter().map(|e| e.original_path.clone()).collect(),
+    }
+}
+
+fn original_path(item: &trash::TrashItem) -> PathBuf {
+    item.original_parent.join(&item.name)
+}
+
+/// Where the deleted item's data lives inside the bin, if it's a plain path
 ///
-/// Note: Size calculation is skipped as it would require reading each file,
-/// which is expensive. Only item count is tracked.
-pub fn scan() -> Result<CategoryResult> {
-    let mut result = CategoryResult::default();
-
-    match trash_ops::list() {
-        Ok(items) => {
-            result.items = items.len();
-            // TrashItem doesn't expose size, so we just count items
-            // Size would require reading each file which is expensive
-            result.size_bytes = 0;
-            result.paths = items
-                .iter()
-                .map(|i| i.original_parent.join(&i.name))
-                .collect();
-        }
-        Err(e) => {
-            eprintln!("Warning: Could not read Recycle Bin: {}", e);
-        }
+/// On Windows the item id is the `$R...` file in `$Recycle.Bin`; with the
+/// freedesktop trash it's the `.trashinfo` file next to `files/`.
+fn bin_copy(item: &trash::TrashItem) -> Option<PathBuf> {
+    let id = Path::new(&item.id);
+    if id.extension().is_some_and(|ext| ext == "trashinfo") {
+        let info_dir = id.parent()?;
+        let name = id.file_stem()?;
+        return Some(info_dir.parent()?.join("files").join(name));
     }
+    id.is_absolute().then(|| id.to_path_buf())
+}
+
+/// Size of a deleted item; folders are walked inside the bin
+fn entry_size(item: &trash::TrashItem) -> u64 {
+    match trash_ops::metadata(item).map(|m| m.size) {
+        Ok(trash::TrashItemSize::Bytes(bytes)) => bytes,
+        _ => bin_copy(item)
+            .filter(|path| path.is_dir())
+            .map(|path| crate::size::dir_size(&path))
+            .unwrap_or(0),
+    }
+}
 
-    Ok(result)
+/// List the Recycle Bin with each entry's original path, deletion date and size
+pub fn scan_entries() -> Result<TrashScanResult> {
+    let items = trash_ops::list().context("Failed to list Recycle Bin items")?;
+    let mut entries: Vec<TrashEntry> = items
+        .iter()
+        .map(|item| TrashEntry {
+            original_path: original_path(item),
+            deleted_at: item.time_deleted,
+            size_bytes: entry_size(item),
+        })
+        .collect();
+    entries.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes));
+    Ok(TrashScanResult { entries })
+}
+
+/// [`scan_entries`], with a warning and no entries if the bin can't be read
+pub fn scan_entries_or_empty() -> TrashScanResult {
+    scan_entries().unwrap_or_else(|e| {
+        eprintln!("Warning: Could not read Recycle Bin: {}", e);
+        TrashScanResult::default()
+    })
+}
+
+/// Scan the Recycle Bin for items
+pub fn scan() -> Result<CategoryResult> {
+    Ok(scan_entries_or_empty().to_category_result())
 }
 
 /// Empty the Recycle Bin by purging all items
@@ -38,3 +111,59 @@ pub fn clean() -> Result<()> {
 
     Ok(())
 }
+
+/// Bin items deleted from one of `paths`
+fn items_from(items: Vec<trash::TrashItem>, paths: &[PathBuf]) -> Vec<trash::TrashItem> {
+    let wanted: HashSet<&Path> = paths.iter().map(PathBuf::as_path).collect();
+    items
+        .into_iter()
+        .filter(|item| wanted.contains(original_path(item).as_path()))
+        .collect()
+}
+
+/// Purge the Recycle Bin entries whose original path is one of `paths`
+///
+/// Everything else stays in the bin. An item deleted from the same path more
+/// than once is purged in every copy. Returns the number of entries purged.
+pub fn clean_paths(paths: &[PathBuf]) -> Result<usize> {
+    let items = trash_ops::list().context("Failed to list Recycle Bin items")?;
+    let items = items_from(items, paths);
+
+    if !items.is_empty() {
+        trash_ops::purge_all(&items).context("Failed to purge Recycle Bin items")?;
+    }
+
+    Ok(items.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, parent: &str, name: &str) -> trash::TrashItem {
+        trash::TrashItem {
+            id: id.into(),
+            name: name.into(),
+            original_parent: PathBuf::from(parent),
+            time_deleted: 0,
+        }
+    }
+
+    #[test]
+    fn test_items_from_matches_original_paths() {
+        let items = vec![
+            item("/t/info/a.txt.trashinfo", "/home/u", "a.txt"),
+            item("/t/info/a.txt.2.trashinfo", "/home/u", "a.txt"),
+            item("/t/info/b.txt.trashinfo", "/home/u", "b.txt"),
+        ];
+        let picked = items_from(items, &[PathBuf::from("/home/u/a.txt")]);
+        assert_eq!(picked.len(), 2);
+        assert!(picked.iter().all(|i| i.name == "a.txt"));
+    }
+
+    #[test]
+    fn test_bin_copy_of_freedesktop_item() {
+        let item = item("/t/info/a.txt.trashinfo", "/home/u", "a.txt");
+        assert_eq!(bin_copy(&item), Some(PathBuf::from("/t/files/a.txt")));
+    }
+}
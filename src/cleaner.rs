@@ -1,10 +1,17 @@
 //! Deletion orchestration for cleaning operations.
 
+mod attributes;
 mod batch_deletion;
 mod category_cleaning;
+mod fast_delete;
 mod path_precheck;
 mod single_deletion;
 
+pub use attributes::apply_safety_settings;
 pub use batch_deletion::{clean_paths_batch, BatchDeleteResult};
-pub use category_cleaning::clean_all;
+pub use category_cleaning::{clean_all, clean_listed, CleanSummary, LISTED_CATEGORY};
+pub use fast_delete::{
+    failed_fast_deletes, fast_delete_progress, resume_fast_deletes, stage_folder,
+    wait_for_fast_deletes, FastDeleteProgress, StagedFolder,
+};
 pub use single_deletion::{clean_path, delete_with_precheck, DeleteOutcome};
@@ -0,0 +1,176 @@
+//! Attribute and ownership fixes for deletes that fail with access denied.
+//!
+//! Read-only files (`.git/objects`, some package caches) make DeleteFile fail
+//! with ERROR_ACCESS_DENIED, so that attribute is always cleared before a retry.
+//! Hidden/system attributes are only cleared with `safety.clear_hidden_system`;
+//! without it, denied files carrying the system attribute are reported as
+//! protected. With `safety.take_ownership`, an elevated wole takes ownership of
+//! denied paths inside user profiles (never elsewhere) and retries.
+
+use crate::config::SafetySettings;
+use crate::utils;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static CLEAR_HIDDEN_SYSTEM: AtomicBool = AtomicBool::new(false);
+static TAKE_OWNERSHIP: AtomicBool = AtomicBool::new(false);
+
+const FILE_ATTRIBUTE_READONLY: u32 = 0x1;
+const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+const FILE_ATTRIBUTE_SYSTEM: u32 = 0x4;
+
+/// Apply the `[safety]` attribute, ownership and audit settings for this process
+pub fn apply_safety_settings(settings: &SafetySettings) {
+    CLEAR_HIDDEN_SYSTEM.store(settings.clear_hidden_system, Ordering::Relaxed);
+    TAKE_OWNERSHIP.store(settings.take_ownership, Ordering::Relaxed);
+    if settings.audit {
+        crate::audit::enable();
+    }
+}
+
+/// Attribute bits that block deletion and may be cleared
+fn clearable_mask(clear_hidden_system: bool) -> u32 {
+    if clear_hidden_system {
+        FILE_ATTRIBUTE_READONLY | FILE_ATTRIBUTE_HIDDEN | FILE_ATTRIBUTE_SYSTEM
+    } else {
+        FILE_ATTRIBUTE_READONLY
+    }
+}
+
+#[cfg(windows)]
+fn attributes(path: &Path) -> Option<u32> {
+    use std::os::windows::fs::MetadataExt;
+    utils::safe_symlink_metadata(path)
+        .ok()
+        .map(|m| m.file_attributes())
+}
+
+#[cfg(not(windows))]
+fn attributes(_path: &Path) -> Option<u32> {
+    None
+}
+
+/// Clear `mask` from the attributes of a single entry; true if anything changed
+#[cfg(windows)]
+fn clear_attributes(path: &Path, mask: u32) -> bool {
+    let Some(current) = attributes(path) else {
+        return false;
+    };
+    if current & mask == 0 {
+        return false;
+    }
+
+    let wide: Vec<u16> = utils::long_path_if_needed(path)
+        .as_os_str()
+        .to_string_lossy()
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .collect();
+    unsafe {
+        extern "system" {
+            fn SetFileAttributesW(lpFileName: *const u16, dwFileAttributes: u32) -> i32;
+        }
+        SetFileAttributesW(wide.as_ptr(), current & !mask) != 0
+    }
+}
+
+#[cfg(not(windows))]
+fn clear_attributes(_path: &Path, _mask: u32) -> bool {
+    false
+}
+
+/// Clear blocking attributes on `path` and, for directories, everything below it
+///
+/// Returns how many entries were changed (0 means a retry won't help).
+pub(crate) fn clear_blocking_attributes(path: &Path) -> usize {
+    let mask = clearable_mask(CLEAR_HIDDEN_SYSTEM.load(Ordering::Relaxed));
+    let root = utils::long_path_if_needed(path);
+
+    walkdir::WalkDir::new(root.as_ref())
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| !(e.file_type().is_dir() && utils::is_windows_reparse_point(e.path())))
+        .flatten()
+        .filter(|e| clear_attributes(e.path(), mask))
+        .count()
+}
+
+/// Whether a denied `path` is a system-attribute file we are not allowed to touch
+pub(crate) fn is_protected(path: &Path) -> bool {
+    !CLEAR_HIDDEN_SYSTEM.load(Ordering::Relaxed)
+        && attributes(path).is_some_and(|a| a & FILE_ATTRIBUTE_SYSTEM != 0)
+}
+
+/// Whether `path` is strictly inside some user's profile folder
+fn is_inside_profile(path: &Path) -> bool {
+    let normalize =
+        |p: &Path| crate::restore::normalize_path_for_comparison(&utils::display_path(p));
+    let path = normalize(path);
+    crate::users::list_profiles().iter().any(|profile| {
+        let home = normalize(&profile.home);
+        path.strip_prefix(home.trim_end_matches(['/', '\\']))
+            .is_some_and(|rest| rest.len() > 1 && rest.starts_with(['/', '\\']))
+    })
+}
+
+/// Take ownership of a denied `path` (recursively) and grant Administrators full control
+///
+/// Only runs with `safety.take_ownership`, from an elevated process, for paths
+/// inside a user profile. Returns true if a retry is worthwhile.
+pub(crate) fn take_ownership(path: &Path) -> bool {
+    if !TAKE_OWNERSHIP.load(Ordering::Relaxed)
+        || utils::is_system_path(path)
+        || !crate::optimize::is_admin()
+        || !is_inside_profile(path)
+    {
+        return false;
+    }
+
+    #[cfg(windows)]
+    {
+        use std::process::Command;
+
+        let target = utils::display_path(path);
+        let recursive = utils::safe_is_dir(path);
+
+        let mut takeown = Command::new("takeown");
+        takeown.arg("/F").arg(&target);
+        if recursive {
+            // /D Y answers the "no list permission" prompt for subfolders
+            takeown.args(["/R", "/D", "Y"]);
+        }
+        if !takeown.output().is_ok_and(|o| o.status.success()) {
+            return false;
+        }
+
+        // S-1-5-32-544 is BUILTIN\Administrators (locale independent)
+        let mut icacls = Command::new("icacls");
+        icacls.arg(&target).args(["/grant", "*S-1-5-32-544:F"]);
+        if recursive {
+            icacls.arg("/T");
+        }
+        icacls
+            .args(["/C", "/Q"])
+            .output()
+            .is_ok_and(|o| o.status.success())
+    }
+
+    #[cfg(not(windows))]
+    {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clearable_mask() {
+        assert_eq!(clearable_mask(false), FILE_ATTRIBUTE_READONLY);
+        assert_eq!(
+            clearable_mask(true),
+            FILE_ATTRIBUTE_READONLY | FILE_ATTRIBUTE_HIDDEN | FILE_ATTRIBUTE_SYSTEM
+        );
+    }
+}
@@ -4,8 +4,11 @@
 
 use super::path_precheck::{precheck_path, PrecheckOutcome};
 use super::single_deletion::{classify_anyhow_error, delete_with_precheck, DeleteOutcome};
+use crate::cancel::CancellationToken;
 use crate::debug_log;
-use std::path::PathBuf;
+use crate::throttle;
+use crate::utils;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 pub struct BatchDeleteResult {
@@ -15,6 +18,10 @@ pub struct BatchDeleteResult {
     pub skipped_paths: Vec<PathBuf>,
     pub locked_paths: Vec<PathBuf>,
     pub permission_denied_paths: Vec<PathBuf>,
+    /// Denied files with the system attribute (see `safety.clear_hidden_system`)
+    pub protected_paths: Vec<PathBuf>,
+    /// Not attempted because the cancellation token fired
+    pub cancelled_paths: Vec<PathBuf>,
 }
 
 impl BatchDeleteResult {
@@ -26,6 +33,8 @@ impl BatchDeleteResult {
             skipped_paths: Vec::new(),
             locked_paths: Vec::new(),
             permission_denied_paths: Vec::new(),
+            protected_paths: Vec::new(),
+            cancelled_paths: Vec::new(),
         }
     }
 }
@@ -35,7 +44,7 @@ fn partition_existing(paths: Vec<PathBuf>) -> (Vec<PathBuf>, Vec<PathBuf>) {
     let mut deleted = Vec::new();
 
     for path in paths {
-        if path.exists() {
+        if utils::safe_exists(&path) {
             remaining.push(path);
         } else {
             deleted.push(path);
@@ -45,6 +54,50 @@ fn partition_existing(paths: Vec<PathBuf>) -> (Vec<PathBuf>, Vec<PathBuf>) {
     (remaining, deleted)
 }
 
+/// Retry a denied Recycle Bin delete through [`delete_with_precheck`], which
+/// clears blocking attributes (and takes ownership, if enabled) first
+fn retry_if_denied(path: &Path, outcome: Option<DeleteOutcome>) -> Option<DeleteOutcome> {
+    match outcome {
+        Some(denied) if denied.is_denied() => {
+            Some(delete_with_precheck(path, false).unwrap_or(denied))
+        }
+        other => other,
+    }
+}
+
+/// What [`clean_paths_batch`] would do, without deleting anything (audit mode)
+fn audit_batch(paths: &[PathBuf]) -> BatchDeleteResult {
+    let mut result = BatchDeleteResult::empty();
+    for path in paths {
+        match precheck_path(path) {
+            PrecheckOutcome::Missing | PrecheckOutcome::BlockedSystem => {
+                result.skipped_paths.push(path.clone());
+            }
+            PrecheckOutcome::Locked => {
+                result.error_count += 1;
+                result.locked_paths.push(path.clone());
+            }
+            PrecheckOutcome::Eligible => {
+                result.success_count += 1;
+                result.deleted_paths.push(path.clone());
+            }
+        }
+    }
+    crate::audit::note(&format!("delete {} paths", result.success_count));
+    result
+}
+
+/// Recycle Bin batch size while deletes are rate limited
+const PACED_BATCH_SIZE: usize = 10;
+
+/// Size counted against the MB/s limit: files only, folders count towards the
+/// files/s limit alone (sizing them would cost another walk)
+fn paced_bytes(path: &Path) -> u64 {
+    std::fs::symlink_metadata(path)
+        .map(|meta| if meta.is_file() { meta.len() } else { 0 })
+        .unwrap_or(0)
+}
+
 /// Batch clean multiple paths - MUCH faster than one-by-one deletion
 ///
 /// For Recycle Bin deletion, uses `trash::delete_all()` which is 10-50x faster
@@ -52,11 +105,25 @@ fn partition_existing(paths: Vec<PathBuf>) -> (Vec<PathBuf>, Vec<PathBuf>) {
 ///
 /// **CRITICAL**: System paths are filtered out before deletion for safety.
 ///
+/// Recycle Bin batches are capped at `performance.delete_batch_size`, deletes
+/// are paced to `performance.delete_files_per_sec`/`delete_mb_per_sec` and the
+/// deleting thread runs at `performance.io_priority` (see [`crate::throttle`]).
+///
+/// Paths not yet attempted when `cancel` is cancelled are reported as cancelled.
+///
+/// In audit mode ([`crate::audit`]) nothing is deleted: eligible paths are
+/// reported as deleted, the rest as they would have been.
+///
 /// Returns a detailed batch deletion result
-pub fn clean_paths_batch(paths: &[PathBuf], permanent: bool) -> BatchDeleteResult {
+pub fn clean_paths_batch(
+    paths: &[PathBuf],
+    permanent: bool,
+    cancel: &CancellationToken,
+) -> BatchDeleteResult {
     if paths.is_empty() {
         return BatchDeleteResult::empty();
     }
+    let permanent = crate::policy::permanent_allowed(permanent);
 
     let first_path = paths
         .first()
@@ -74,19 +141,38 @@ pub fn clean_paths_batch(paths: &[PathBuf], permanent: bool) -> BatchDeleteResul
         last_path
     ));
 
+    if crate::audit::is_enabled() {
+        return audit_batch(paths);
+    }
+
+    // Honor performance.io_priority for the deleting thread
+    let _priority = throttle::PriorityGuard::current();
+    // ...and the deletion speed limit (background mode in the TUI)
+    let rate_limited = throttle::delete_rate().is_limited();
+    let mut pacer = throttle::DeletePacer::new();
+
     let mut success_count = 0;
     let mut error_count = 0;
     let mut deleted_paths = Vec::with_capacity(paths.len());
     let mut skipped_paths: Vec<PathBuf> = Vec::new();
     let mut locked_paths: Vec<PathBuf> = Vec::new();
     let mut permission_denied_paths: Vec<PathBuf> = Vec::new();
+    let mut protected_paths: Vec<PathBuf> = Vec::new();
+    let mut cancelled_paths: Vec<PathBuf> = Vec::new();
 
     if permanent {
         // Permanent deletes are already fast (direct filesystem ops)
         // Delete one-by-one to track individual successes/failures
         for path in paths {
+            if cancel.is_cancelled() {
+                cancelled_paths.push(path.clone());
+                continue;
+            }
+            if rate_limited {
+                pacer.pace(1, paced_bytes(path), cancel);
+            }
             match delete_with_precheck(path, true) {
-                Ok(DeleteOutcome::Deleted) => {
+                Ok(DeleteOutcome::Deleted | DeleteOutcome::Audited) => {
                     success_count += 1;
                     deleted_paths.push(path.clone());
                 }
@@ -101,13 +187,17 @@ pub fn clean_paths_batch(paths: &[PathBuf], permanent: bool) -> BatchDeleteResul
                     error_count += 1;
                     permission_denied_paths.push(path.clone());
                 }
+                Ok(DeleteOutcome::SkippedProtected) => {
+                    error_count += 1;
+                    protected_paths.push(path.clone());
+                }
                 Err(_) => error_count += 1,
             }
         }
     } else {
         // Batch to Recycle Bin - this is the big performance win
         // First, filter out locked, missing, and system paths (they would cause batch to fail)
-        let mut unlocked: Vec<PathBuf> = Vec::new();
+        let mut eligible: Vec<PathBuf> = Vec::new();
         for path in paths {
             match precheck_path(path) {
                 PrecheckOutcome::Missing | PrecheckOutcome::BlockedSystem => {
@@ -117,11 +207,83 @@ pub fn clean_paths_batch(paths: &[PathBuf], permanent: bool) -> BatchDeleteResul
                     error_count += 1;
                     locked_paths.push(path.clone());
                 }
-                PrecheckOutcome::Eligible => unlocked.push(path.clone()),
+                PrecheckOutcome::Eligible => eligible.push(path.clone()),
             }
         }
 
-        if !unlocked.is_empty() {
+        // Network shares have no Recycle Bin: items go to the share's quarantine
+        // folder one rename at a time, without the batch retry fallbacks below
+        let (network, eligible): (Vec<PathBuf>, Vec<PathBuf>) = eligible
+            .into_iter()
+            .partition(|p| crate::network::is_network_path(p));
+        for path in network {
+            if cancel.is_cancelled() {
+                cancelled_paths.push(path);
+                continue;
+            }
+            if rate_limited {
+                pacer.pace(1, paced_bytes(&path), cancel);
+            }
+            match crate::trash_ops::delete(&path) {
+                Ok(()) => {
+                    success_count += 1;
+                    deleted_paths.push(path);
+                }
+                Err(err) => {
+                    debug_log::cleaning_log(&format!(
+                        "quarantine failed: path={} error={}",
+                        path.display(),
+                        err
+                    ));
+                    match retry_if_denied(&path, classify_anyhow_error(&path, &err)) {
+                        Some(DeleteOutcome::Deleted) => {
+                            success_count += 1;
+                            deleted_paths.push(path);
+                        }
+                        Some(DeleteOutcome::SkippedMissing) => skipped_paths.push(path),
+                        Some(DeleteOutcome::SkippedLocked) => {
+                            error_count += 1;
+                            locked_paths.push(path);
+                        }
+                        Some(DeleteOutcome::SkippedPermission) => {
+                            error_count += 1;
+                            permission_denied_paths.push(path);
+                        }
+                        Some(DeleteOutcome::SkippedProtected) => {
+                            error_count += 1;
+                            protected_paths.push(path);
+                        }
+                        _ => error_count += 1,
+                    }
+                }
+            }
+        }
+
+        // performance.delete_batch_size caps each Recycle Bin call (0 = one batch)
+        let chunk_size = match throttle::delete_batch_size() {
+            0 => eligible.len().max(1),
+            n => n,
+        };
+        // Small batches keep a rate-limited clean steady instead of bursty
+        let chunk_size = if rate_limited {
+            chunk_size.min(PACED_BATCH_SIZE)
+        } else {
+            chunk_size
+        };
+
+        for unlocked in eligible.chunks(chunk_size).map(|chunk| chunk.to_vec()) {
+            if cancel.is_cancelled() {
+                cancelled_paths.extend(unlocked);
+                continue;
+            }
+            if rate_limited {
+                let bytes = unlocked.iter().map(|p| paced_bytes(p)).sum();
+                pacer.pace(unlocked.len() as u64, bytes, cancel);
+                if cancel.is_cancelled() {
+                    cancelled_paths.extend(unlocked);
+                    continue;
+                }
+            }
             // Try batch delete first (fastest path)
             match crate::trash_ops::delete_all(&unlocked) {
                 Ok(()) => {
@@ -192,8 +354,12 @@ pub fn clean_paths_batch(paths: &[PathBuf], permanent: bool) -> BatchDeleteResul
                             );
                         }
                         for path in remaining {
+                            if cancel.is_cancelled() {
+                                cancelled_paths.push(path);
+                                continue;
+                            }
                             // Double-check file exists before attempting deletion
-                            if !path.exists() {
+                            if !utils::safe_exists(&path) {
                                 success_count += 1;
                                 deleted_paths.push(path);
                                 continue;
@@ -204,11 +370,18 @@ pub fn clean_paths_batch(paths: &[PathBuf], permanent: bool) -> BatchDeleteResul
                                     deleted_paths.push(path.clone());
                                 }
                                 Err(_err) => {
-                                    if !path.exists() {
+                                    if !utils::safe_exists(&path) {
                                         success_count += 1;
                                         deleted_paths.push(path.clone());
                                     } else {
-                                        match classify_anyhow_error(&path, &_err) {
+                                        match retry_if_denied(
+                                            &path,
+                                            classify_anyhow_error(&path, &_err),
+                                        ) {
+                                            Some(DeleteOutcome::Deleted) => {
+                                                success_count += 1;
+                                                deleted_paths.push(path.clone());
+                                            }
                                             Some(DeleteOutcome::SkippedLocked) => {
                                                 error_count += 1;
                                                 locked_paths.push(path.clone());
@@ -217,6 +390,10 @@ pub fn clean_paths_batch(paths: &[PathBuf], permanent: bool) -> BatchDeleteResul
                                                 error_count += 1;
                                                 permission_denied_paths.push(path.clone());
                                             }
+                                            Some(DeleteOutcome::SkippedProtected) => {
+                                                error_count += 1;
+                                                protected_paths.push(path.clone());
+                                            }
                                             _ => {
                                                 error_count += 1;
                                             }
@@ -243,12 +420,14 @@ pub fn clean_paths_batch(paths: &[PathBuf], permanent: bool) -> BatchDeleteResul
     }
 
     debug_log::cleaning_log(&format!(
-        "batch delete done: success={} errors={} skipped={} locked={} permission_denied={}",
+        "batch delete done: success={} errors={} skipped={} locked={} permission_denied={} protected={} cancelled={}",
         success_count,
         error_count,
         skipped_paths.len(),
         locked_paths.len(),
-        permission_denied_paths.len()
+        permission_denied_paths.len(),
+        protected_paths.len(),
+        cancelled_paths.len()
     ));
 
     BatchDeleteResult {
@@ -258,5 +437,52 @@ pub fn clean_paths_batch(paths: &[PathBuf], permanent: bool) -> BatchDeleteResul
         skipped_paths,
         locked_paths,
         permission_denied_paths,
+        protected_paths,
+        cancelled_paths,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_mode_reports_without_deleting() {
+        crate::audit::enable_for_test();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join("app.log");
+        std::fs::write(&file, b"log").unwrap();
+        let dir = temp_dir.path().join("build");
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(dir.join("out.o"), b"obj").unwrap();
+        let missing = temp_dir.path().join("gone.tmp");
+        let paths = vec![file.clone(), dir.clone(), missing.clone()];
+
+        for permanent in [false, true] {
+            let result = clean_paths_batch(&paths, permanent, &CancellationToken::new());
+            assert_eq!(result.success_count, 2);
+            assert_eq!(result.error_count, 0);
+            assert_eq!(result.deleted_paths, [file.clone(), dir.clone()]);
+            assert_eq!(result.skipped_paths, std::slice::from_ref(&missing));
+        }
+        assert!(file.exists());
+        assert!(dir.join("out.o").exists());
+    }
+
+    #[test]
+    fn test_cancelled_batch_leaves_paths_untouched() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join("app.log");
+        std::fs::write(&file, b"log").unwrap();
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        for permanent in [false, true] {
+            let result = clean_paths_batch(std::slice::from_ref(&file), permanent, &cancel);
+            assert_eq!(result.success_count, 0);
+            assert!(result.skipped_paths.is_empty());
+            assert_eq!(result.cancelled_paths, std::slice::from_ref(&file));
+        }
+        assert!(file.exists());
     }
 }
@@ -4,7 +4,9 @@
 
 use super::batch_deletion::{clean_paths_batch, BatchDeleteResult};
 use super::single_deletion::{delete_with_precheck, DeleteOutcome};
+use crate::cancel::CancellationToken;
 use crate::categories;
+use crate::debug_log;
 use crate::history::DeletionLog;
 use crate::output::{OutputMode, ScanResults};
 use crate::progress;
@@ -45,16 +47,31 @@ fn read_line_from_stdin() -> io::Result<String> {
     Ok(input)
 }
 
+/// Options shared by every category of one cleaning run
+struct CleanRun<'a> {
+    permanent: bool,
+    dry_run: bool,
+    mode: OutputMode,
+    cancel: &'a CancellationToken,
+}
+
 /// Helper function to batch clean a category (10-50x faster than one-by-one)
+///
+/// `category_key` is the category id its throttle overrides are keyed on.
 fn batch_clean_category_internal(
     paths: &[PathBuf],
     category_name: &str,
-    permanent: bool,
-    dry_run: bool,
+    category_key: Option<&str>,
     progress: Option<&indicatif::ProgressBar>,
     history: Option<&mut DeletionLog>,
-    mode: OutputMode,
+    run: &CleanRun,
 ) -> (u64, u64) {
+    let CleanRun {
+        permanent,
+        dry_run,
+        mode,
+        cancel,
+    } = *run;
     if paths.is_empty() {
         return (0, 0);
     }
@@ -78,7 +95,7 @@ fn batch_clean_category_internal(
     // Always calculate sizes if history logging is enabled
     if history.is_some() {
         for path in paths {
-            let size = if path.is_dir() {
+            let size = if utils::safe_is_dir(path) {
                 utils::calculate_dir_size(path)
             } else {
                 utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0)
@@ -96,7 +113,13 @@ fn batch_clean_category_internal(
         skipped_paths,
         locked_paths,
         permission_denied_paths,
-    } = clean_paths_batch(paths, permanent);
+        protected_paths,
+        cancelled_paths,
+    } = {
+        // Batch size and I/O priority from [performance.category_overrides]
+        let _throttle = category_key.map(crate::throttle::enter_category);
+        clean_paths_batch(paths, permanent, cancel)
+    };
 
     // Log successes and failures using pre-calculated sizes
     if let Some(log) = history {
@@ -119,11 +142,23 @@ fn batch_clean_category_internal(
             let size = path_sizes.get(path).copied().unwrap_or(0);
             log.log_failure(path, size, category_name, permanent, "Permission denied");
         }
+        for path in &protected_paths {
+            let size = path_sizes.get(path).copied().unwrap_or(0);
+            log.log_failure(
+                path,
+                size,
+                category_name,
+                permanent,
+                DeleteOutcome::SkippedProtected.reason(),
+            );
+        }
         for path in paths {
             if deleted_paths.contains(path)
                 || skipped_paths.contains(path)
                 || locked_paths.contains(path)
                 || permission_denied_paths.contains(path)
+                || protected_paths.contains(path)
+                || cancelled_paths.contains(path)
             {
                 continue;
             }
@@ -155,16 +190,135 @@ fn batch_clean_category_internal(
     (success_count as u64, error_count as u64)
 }
 
+/// Stage the folders among `paths` for fast delete (see [`super::stage_folder`])
+/// when `enabled` and the policy allows permanent deletes; returns the staged
+/// folders and the paths left for a normal delete. Staged folders reach the
+/// history once their background delete finishes.
+fn fast_clean_folders(
+    paths: &[PathBuf],
+    category_name: &str,
+    enabled: bool,
+    progress: Option<&indicatif::ProgressBar>,
+    cancel: &CancellationToken,
+) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    if !enabled || !crate::policy::permanent_allowed(true) {
+        return (Vec::new(), paths.to_vec());
+    }
+
+    let mut staged = Vec::new();
+    let mut rest = Vec::new();
+    for path in paths {
+        if cancel.is_cancelled() || !utils::safe_is_dir(path) {
+            rest.push(path.clone());
+            continue;
+        }
+        let size = utils::calculate_dir_size(path);
+        match super::stage_folder(path, size, category_name) {
+            Ok(()) => {
+                staged.push(path.clone());
+                if let Some(pb) = progress {
+                    pb.inc(1);
+                }
+            }
+            Err(e) => {
+                debug_log::cleaning_log(&format!("fast delete skipped: {:#}", e));
+                rest.push(path.clone());
+            }
+        }
+    }
+    (staged, rest)
+}
+
+/// Clear the caches among `paths` with their package manager's own command
+/// (see [`categories::cache::clear_with_manager`]) when `enabled`; returns how
+/// many were cleared and the paths left for a normal delete
+fn manager_clean_caches(
+    paths: &[PathBuf],
+    enabled: bool,
+    progress: Option<&indicatif::ProgressBar>,
+    mut history: Option<&mut DeletionLog>,
+    mode: OutputMode,
+    cancel: &CancellationToken,
+) -> (u64, Vec<PathBuf>) {
+    if !enabled {
+        return (0, paths.to_vec());
+    }
+
+    let mut cleared = 0u64;
+    let mut rest = Vec::new();
+    for path in paths {
+        if cancel.is_cancelled() {
+            rest.push(path.clone());
+            continue;
+        }
+        let size = utils::calculate_dir_size(path);
+        match categories::cache::clear_with_manager(path) {
+            Ok(true) => {
+                cleared += 1;
+                // What the manager removed can't be restored
+                if let Some(log) = history.as_deref_mut() {
+                    log.log_success(path, size, "cache", true);
+                }
+                if let Some(pb) = progress {
+                    pb.inc(1);
+                }
+            }
+            Ok(false) => rest.push(path.clone()),
+            Err(e) => {
+                if mode != OutputMode::Quiet {
+                    eprintln!("[WARNING] {:#}; deleting {} instead", e, path.display());
+                }
+                rest.push(path.clone());
+            }
+        }
+    }
+    (cleared, rest)
+}
+
+/// Totals of one `clean_all` run
+#[derive(Debug, Clone, Default)]
+pub struct CleanSummary {
+    pub cleaned: u64,
+    pub cleaned_bytes: u64,
+    pub errors: u64,
+    /// True if the run was stopped through its cancellation token
+    pub cancelled: bool,
+    /// Deletion log written for this run (None for dry runs or if saving failed)
+    pub log_path: Option<PathBuf>,
+}
+
 /// Clean all categories based on scan results
 ///
-/// Handles confirmation prompts, error tracking, and provides progress feedback
+/// Handles confirmation prompts, error tracking, and provides progress feedback.
+/// Cancelling `cancel` stops before the next item; what was already deleted stays
+/// deleted and is recorded in the history log. In audit mode
+/// ([`crate::audit`]) this always behaves as a dry run.
 pub fn clean_all(
     results: &ScanResults,
     skip_confirm: bool,
     mode: OutputMode,
     permanent: bool,
     dry_run: bool,
-) -> Result<()> {
+    cancel: &CancellationToken,
+) -> Result<CleanSummary> {
+    // The machine policy may lock permanent deletes; log what really happens
+    if permanent && !crate::policy::permanent_allowed(permanent) && mode != OutputMode::Quiet {
+        println!(
+            "{}",
+            Theme::warning("Permanent delete is disabled by policy - using the Recycle Bin")
+        );
+    }
+    let permanent = crate::policy::permanent_allowed(permanent);
+
+    // Audit mode turns every clean into a dry run, whatever the caller asked for
+    if crate::audit::is_enabled() && !dry_run && mode != OutputMode::Quiet {
+        println!(
+            "{}",
+            Theme::warning("Audit mode is on - nothing will be deleted")
+        );
+    }
+    let dry_run = dry_run || crate::audit::is_enabled();
+
     let total_items = results.cache.items
         + results.app_cache.items
         + results.temp.items
@@ -178,7 +332,18 @@ pub fn clean_all(
         + results.empty.items
         + results.duplicates.items
         + results.windows_update.items
-        + results.event_logs.items;
+        + results.event_logs.items
+        + results.thumbnails.items
+        + results.crash_dumps.items
+        + results.logs.items
+        + results.game_caches.items
+        + results.toolchain_caches.items
+        + results.python_envs.items
+        + results.ml_models.items
+        + results.creative_caches.items
+        + results.device_backups.items
+        + results.git_ignored.items
+        + results.plugin_items();
     let total_bytes = results.cache.size_bytes
         + results.app_cache.size_bytes
         + results.temp.size_bytes
@@ -192,13 +357,42 @@ pub fn clean_all(
         + results.empty.size_bytes
         + results.duplicates.size_bytes
         + results.windows_update.size_bytes
-        + results.event_logs.size_bytes;
+        + results.event_logs.size_bytes
+        + results.thumbnails.size_bytes
+        + results.crash_dumps.size_bytes
+        + results.logs.size_bytes
+        + results.game_caches.size_bytes
+        + results.toolchain_caches.size_bytes
+        + results.python_envs.size_bytes
+        + results.ml_models.size_bytes
+        + results.creative_caches.size_bytes
+        + results.device_backups.size_bytes
+        + results.git_ignored.size_bytes
+        + results.plugin_bytes();
+
+    // Email data is report-only: list it, never delete it
+    if results.email.items > 0 && mode != OutputMode::Quiet {
+        println!(
+            "{} {} email data item(s) ({}) are report-only and will not be deleted:",
+            Theme::muted("→"),
+            Theme::value(&results.email.items.to_string()),
+            bytesize::to_string(results.email.size_bytes, false)
+        );
+        for path in &results.email.paths {
+            println!("    {} {}", Theme::muted("•"), path.display());
+        }
+        println!(
+            "  {}",
+            Theme::muted("Review them in the TUI (`wole`), where O opens the containing folder.")
+        );
+        println!();
+    }
 
     if total_items == 0 {
         if mode != OutputMode::Quiet {
             println!("{}", Theme::success("Nothing to clean."));
         }
-        return Ok(());
+        return Ok(CleanSummary::default());
     }
 
     if dry_run && mode != OutputMode::Quiet {
@@ -230,7 +424,7 @@ pub fn clean_all(
 
         if !confirmed {
             println!("{}", Theme::muted("Cancelled."));
-            return Ok(());
+            return Ok(CleanSummary::default());
         }
     }
 
@@ -255,17 +449,38 @@ pub fn clean_all(
     let mut cleaned = 0u64;
     let mut cleaned_bytes = 0u64;
     let mut errors = 0;
+    let run = CleanRun {
+        permanent,
+        dry_run,
+        mode,
+        cancel,
+    };
+
+    // Categories opted into fast delete (folders renamed aside, deleted in the background)
+    let category_settings = crate::config::Config::load().categories;
+    let mut fast_deleted = Vec::new();
+    if !dry_run {
+        super::resume_fast_deletes();
+    }
 
-    // Clean cache (batch)
+    // Clean cache (package manager commands first when enabled, then batch)
     if results.cache.items > 0 {
-        let (success, errs) = batch_clean_category_internal(
+        let (cleared, rest) = manager_clean_caches(
             &results.cache.paths,
-            "cache",
-            permanent,
-            dry_run,
+            category_settings.cache.use_manager_commands && !dry_run,
             progress.as_ref(),
             history.as_mut(),
             mode,
+            cancel,
+        );
+        cleaned += cleared;
+        let (success, errs) = batch_clean_category_internal(
+            &rest,
+            "cache",
+            Some("cache"),
+            progress.as_ref(),
+            history.as_mut(),
+            &run,
         );
         cleaned += success;
         errors += errs;
@@ -277,11 +492,10 @@ pub fn clean_all(
         let (success, errs) = batch_clean_category_internal(
             &results.app_cache.paths,
             "application cache",
-            permanent,
-            dry_run,
+            Some("app_cache"),
             progress.as_ref(),
             history.as_mut(),
-            mode,
+            &run,
         );
         cleaned += success;
         errors += errs;
@@ -293,11 +507,10 @@ pub fn clean_all(
         let (success, errs) = batch_clean_category_internal(
             &results.temp.paths,
             "temp files",
-            permanent,
-            dry_run,
+            Some("temp"),
             progress.as_ref(),
             history.as_mut(),
-            mode,
+            &run,
         );
         cleaned += success;
         errors += errs;
@@ -305,7 +518,7 @@ pub fn clean_all(
     }
 
     // Clean trash
-    if results.trash.items > 0 {
+    if results.trash.items > 0 && !cancel.is_cancelled() {
         if let Some(ref pb) = progress {
             pb.set_message("Emptying Recycle Bin...");
         }
@@ -316,20 +529,23 @@ pub fn clean_all(
             }
             cleaned_bytes += results.trash.size_bytes;
         } else {
-            match categories::trash::clean() {
-                Ok(()) => {
+            // Purge what the scan listed (and --select kept), not the whole bin
+            match categories::trash::clean_paths(&results.trash.paths) {
+                Ok(_) => {
                     cleaned += results.trash.items as u64;
                     if let Some(ref pb) = progress {
                         pb.inc(results.trash.items as u64);
                     }
                     cleaned_bytes += results.trash.size_bytes;
                     if let Some(ref mut log) = history {
-                        log.log_success(
-                            Path::new("Recycle Bin"),
-                            results.trash.size_bytes,
-                            "trash",
-                            true,
-                        );
+                        for path in &results.trash.paths {
+                            let size = results
+                                .trash_entries
+                                .iter()
+                                .find(|entry| &entry.original_path == path)
+                                .map_or(0, |entry| entry.size_bytes);
+                            log.log_success(path, size, "trash", true);
+                        }
                     }
                 }
                 Err(e) => {
@@ -355,19 +571,31 @@ pub fn clean_all(
     }
 
     // Clean build artifacts (batch)
+    let mut rebuild_hints = Vec::new();
     if results.build.items > 0 {
-        let (success, errs) = batch_clean_category_internal(
+        let (staged, rest) = fast_clean_folders(
             &results.build.paths,
             "build artifacts",
-            permanent,
-            dry_run,
+            category_settings.build.fast_delete && !dry_run,
+            progress.as_ref(),
+            cancel,
+        );
+        let (success, errs) = batch_clean_category_internal(
+            &rest,
+            "build artifacts",
+            Some("build"),
             progress.as_ref(),
             history.as_mut(),
-            mode,
+            &run,
         );
+        let success = staged.len() as u64 + success;
+        fast_deleted.extend(staged);
         cleaned += success;
         errors += errs;
         cleaned_bytes += results.build.size_bytes;
+        if success > 0 && mode != OutputMode::Quiet {
+            rebuild_hints = rebuild_hints_for(&results.build.paths);
+        }
     }
 
     // Clean downloads (batch)
@@ -375,11 +603,10 @@ pub fn clean_all(
         let (success, errs) = batch_clean_category_internal(
             &results.downloads.paths,
             "old downloads",
-            permanent,
-            dry_run,
+            Some("downloads"),
             progress.as_ref(),
             history.as_mut(),
-            mode,
+            &run,
         );
         cleaned += success;
         errors += errs;
@@ -388,32 +615,46 @@ pub fn clean_all(
 
     // Clean large files (batch)
     if results.large.items > 0 {
-        let (success, errs) = batch_clean_category_internal(
+        let (staged, rest) = fast_clean_folders(
             &results.large.paths,
             "large files",
-            permanent,
-            dry_run,
+            category_settings.large.fast_delete && !dry_run,
+            progress.as_ref(),
+            cancel,
+        );
+        let (success, errs) = batch_clean_category_internal(
+            &rest,
+            "large files",
+            Some("large"),
             progress.as_ref(),
             history.as_mut(),
-            mode,
+            &run,
         );
-        cleaned += success;
+        cleaned += staged.len() as u64 + success;
+        fast_deleted.extend(staged);
         errors += errs;
         cleaned_bytes += results.large.size_bytes;
     }
 
     // Clean old files (batch)
     if results.old.items > 0 {
-        let (success, errs) = batch_clean_category_internal(
+        let (staged, rest) = fast_clean_folders(
             &results.old.paths,
             "old files",
-            permanent,
-            dry_run,
+            category_settings.old.fast_delete && !dry_run,
+            progress.as_ref(),
+            cancel,
+        );
+        let (success, errs) = batch_clean_category_internal(
+            &rest,
+            "old files",
+            Some("old"),
             progress.as_ref(),
             history.as_mut(),
-            mode,
+            &run,
         );
-        cleaned += success;
+        cleaned += staged.len() as u64 + success;
+        fast_deleted.extend(staged);
         errors += errs;
         cleaned_bytes += results.old.size_bytes;
     }
@@ -424,7 +665,10 @@ pub fn clean_all(
             pb.set_message("Cleaning browser caches...");
         }
         for path in &results.browser.paths {
-            let size = if path.is_dir() {
+            if cancel.is_cancelled() {
+                break;
+            }
+            let size = if utils::safe_is_dir(path) {
                 utils::calculate_dir_size(path)
             } else {
                 utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0)
@@ -436,7 +680,7 @@ pub fn clean_all(
                 }
             } else {
                 match delete_with_precheck(path, permanent) {
-                    Ok(DeleteOutcome::Deleted) => {
+                    Ok(DeleteOutcome::Deleted | DeleteOutcome::Audited) => {
                         cleaned += 1;
                         if let Some(ref pb) = progress {
                             pb.inc(1);
@@ -465,16 +709,19 @@ pub fn clean_all(
                             );
                         }
                     }
-                    Ok(DeleteOutcome::SkippedPermission) => {
+                    Ok(
+                        outcome @ (DeleteOutcome::SkippedPermission
+                        | DeleteOutcome::SkippedProtected),
+                    ) => {
                         errors += 1;
                         if let Some(ref mut log) = history {
-                            log.log_failure(path, size, "browser", permanent, "Permission denied");
+                            log.log_failure(path, size, "browser", permanent, outcome.reason());
                         }
                         if mode != OutputMode::Quiet {
                             eprintln!(
                                 "[WARNING] Failed to clean {}: {}",
                                 Theme::secondary(&path.display().to_string()),
-                                Theme::error("Permission denied")
+                                Theme::error(outcome.reason())
                             );
                         }
                     }
@@ -503,7 +750,10 @@ pub fn clean_all(
             pb.set_message("Cleaning system caches...");
         }
         for path in &results.system.paths {
-            let size = if path.is_dir() {
+            if cancel.is_cancelled() {
+                break;
+            }
+            let size = if utils::safe_is_dir(path) {
                 utils::calculate_dir_size(path)
             } else {
                 utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0)
@@ -515,7 +765,7 @@ pub fn clean_all(
                 }
             } else {
                 match delete_with_precheck(path, permanent) {
-                    Ok(DeleteOutcome::Deleted) => {
+                    Ok(DeleteOutcome::Deleted | DeleteOutcome::Audited) => {
                         cleaned += 1;
                         if let Some(ref pb) = progress {
                             pb.inc(1);
@@ -544,16 +794,19 @@ pub fn clean_all(
                             );
                         }
                     }
-                    Ok(DeleteOutcome::SkippedPermission) => {
+                    Ok(
+                        outcome @ (DeleteOutcome::SkippedPermission
+                        | DeleteOutcome::SkippedProtected),
+                    ) => {
                         errors += 1;
                         if let Some(ref mut log) = history {
-                            log.log_failure(path, size, "system", permanent, "Permission denied");
+                            log.log_failure(path, size, "system", permanent, outcome.reason());
                         }
                         if mode != OutputMode::Quiet {
                             eprintln!(
                                 "[WARNING] Failed to clean {}: {}",
                                 Theme::secondary(&path.display().to_string()),
-                                Theme::error("Permission denied")
+                                Theme::error(outcome.reason())
                             );
                         }
                     }
@@ -582,6 +835,9 @@ pub fn clean_all(
             pb.set_message("Cleaning empty folders...");
         }
         for path in &results.empty.paths {
+            if cancel.is_cancelled() {
+                break;
+            }
             if dry_run {
                 cleaned += 1;
                 if let Some(ref pb) = progress {
@@ -589,7 +845,7 @@ pub fn clean_all(
                 }
             } else {
                 match delete_with_precheck(path, permanent) {
-                    Ok(DeleteOutcome::Deleted) => {
+                    Ok(DeleteOutcome::Deleted | DeleteOutcome::Audited) => {
                         cleaned += 1;
                         if let Some(ref pb) = progress {
                             pb.inc(1);
@@ -618,16 +874,19 @@ pub fn clean_all(
                             );
                         }
                     }
-                    Ok(DeleteOutcome::SkippedPermission) => {
+                    Ok(
+                        outcome @ (DeleteOutcome::SkippedPermission
+                        | DeleteOutcome::SkippedProtected),
+                    ) => {
                         errors += 1;
                         if let Some(ref mut log) = history {
-                            log.log_failure(path, 0, "empty", permanent, "Permission denied");
+                            log.log_failure(path, 0, "empty", permanent, outcome.reason());
                         }
                         if mode != OutputMode::Quiet {
                             eprintln!(
                                 "[WARNING] Failed to clean {}: {}",
                                 Theme::secondary(&path.display().to_string()),
-                                Theme::error("Permission denied")
+                                Theme::error(outcome.reason())
                             );
                         }
                     }
@@ -655,11 +914,10 @@ pub fn clean_all(
         let (success, errs) = batch_clean_category_internal(
             &results.duplicates.paths,
             "duplicate files",
-            permanent,
-            dry_run,
+            Some("duplicates"),
             progress.as_ref(),
             history.as_mut(),
-            mode,
+            &run,
         );
         cleaned += success;
         errors += errs;
@@ -678,8 +936,11 @@ pub fn clean_all(
         let log_as_permanent = true;
 
         for path in &results.applications.paths {
+            if cancel.is_cancelled() {
+                break;
+            }
             let size = categories::applications::get_app_size(path).unwrap_or_else(|| {
-                if path.is_dir() {
+                if utils::safe_is_dir(path) {
                     utils::calculate_dir_size(path)
                 } else {
                     utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0)
@@ -753,9 +1014,13 @@ pub fn clean_all(
                 let artifacts = categories::applications::get_app_artifact_paths(path);
                 for artifact in artifacts {
                     match delete_with_precheck(&artifact, permanent) {
-                        Ok(DeleteOutcome::Deleted) => {}
+                        Ok(DeleteOutcome::Deleted | DeleteOutcome::Audited) => {}
                         Ok(DeleteOutcome::SkippedMissing | DeleteOutcome::SkippedSystem) => {}
-                        Ok(DeleteOutcome::SkippedLocked | DeleteOutcome::SkippedPermission) => {
+                        Ok(
+                            DeleteOutcome::SkippedLocked
+                            | DeleteOutcome::SkippedPermission
+                            | DeleteOutcome::SkippedProtected,
+                        ) => {
                             had_error = true;
                         }
                         Err(_) => had_error = true,
@@ -794,7 +1059,10 @@ pub fn clean_all(
             pb.set_message("Cleaning Windows Update files...");
         }
         for path in &results.windows_update.paths {
-            let size = if path.is_dir() {
+            if cancel.is_cancelled() {
+                break;
+            }
+            let size = if utils::safe_is_dir(path) {
                 utils::calculate_dir_size(path)
             } else {
                 utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0)
@@ -846,7 +1114,10 @@ pub fn clean_all(
             pb.set_message("Cleaning Event Logs...");
         }
         for path in &results.event_logs.paths {
-            let size = if path.is_dir() {
+            if cancel.is_cancelled() {
+                break;
+            }
+            let size = if utils::safe_is_dir(path) {
                 utils::calculate_dir_size(path)
             } else {
                 utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0)
@@ -886,6 +1157,418 @@ pub fn clean_all(
         cleaned_bytes += results.event_logs.size_bytes;
     }
 
+    // Clean Crash Dumps
+    if results.crash_dumps.items > 0 {
+        if let Some(ref pb) = progress {
+            pb.set_message("Cleaning Crash Dumps...");
+        }
+        for path in &results.crash_dumps.paths {
+            if cancel.is_cancelled() {
+                break;
+            }
+            let size = if utils::safe_is_dir(path) {
+                utils::calculate_dir_size(path)
+            } else {
+                utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0)
+            };
+            if dry_run {
+                cleaned += 1;
+                if let Some(ref pb) = progress {
+                    pb.inc(1);
+                }
+            } else {
+                match categories::crash_dumps::clean(path) {
+                    Ok(()) => {
+                        cleaned += 1;
+                        if let Some(ref pb) = progress {
+                            pb.inc(1);
+                        }
+                        if let Some(ref mut log) = history {
+                            log.log_success(path, size, "crash_dumps", permanent);
+                        }
+                    }
+                    Err(e) => {
+                        errors += 1;
+                        if let Some(ref mut log) = history {
+                            log.log_failure(path, size, "crash_dumps", permanent, &e.to_string());
+                        }
+                        if mode != OutputMode::Quiet {
+                            eprintln!(
+                                "[WARNING] Failed to clean {}: {}",
+                                Theme::secondary(&path.display().to_string()),
+                                Theme::error(&e.to_string())
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        cleaned_bytes += results.crash_dumps.size_bytes;
+    }
+
+    // Clean game launcher caches (refused while the launcher is running)
+    if results.game_caches.items > 0 {
+        if let Some(ref pb) = progress {
+            pb.set_message("Cleaning Game Caches...");
+        }
+        for path in &results.game_caches.paths {
+            if cancel.is_cancelled() {
+                break;
+            }
+            let size = if utils::safe_is_dir(path) {
+                utils::calculate_dir_size(path)
+            } else {
+                utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0)
+            };
+            if dry_run {
+                cleaned += 1;
+                if let Some(ref pb) = progress {
+                    pb.inc(1);
+                }
+            } else {
+                match categories::game_caches::clean(path) {
+                    Ok(()) => {
+                        cleaned += 1;
+                        if let Some(ref pb) = progress {
+                            pb.inc(1);
+                        }
+                        if let Some(ref mut log) = history {
+                            log.log_success(path, size, "game_caches", permanent);
+                        }
+                    }
+                    Err(e) => {
+                        errors += 1;
+                        if let Some(ref mut log) = history {
+                            log.log_failure(path, size, "game_caches", permanent, &e.to_string());
+                        }
+                        if mode != OutputMode::Quiet {
+                            eprintln!(
+                                "[WARNING] Failed to clean {}: {}",
+                                Theme::secondary(&path.display().to_string()),
+                                Theme::error(&e.to_string())
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        cleaned_bytes += results.game_caches.size_bytes;
+    }
+
+    // Clean IDE/toolchain caches (batch)
+    if results.toolchain_caches.items > 0 {
+        let (success, errs) = batch_clean_category_internal(
+            &results.toolchain_caches.paths,
+            "toolchain caches",
+            Some("toolchain_caches"),
+            progress.as_ref(),
+            history.as_mut(),
+            &run,
+        );
+        cleaned += success;
+        errors += errs;
+        cleaned_bytes += results.toolchain_caches.size_bytes;
+    }
+
+    // Clean stale Python environments (batch)
+    if results.python_envs.items > 0 {
+        let (success, errs) = batch_clean_category_internal(
+            &results.python_envs.paths,
+            "python environments",
+            Some("python_envs"),
+            progress.as_ref(),
+            history.as_mut(),
+            &run,
+        );
+        cleaned += success;
+        errors += errs;
+        cleaned_bytes += results.python_envs.size_bytes;
+    }
+
+    // Clean creative app caches and OBS remux leftovers (batch)
+    if results.creative_caches.items > 0 {
+        let (success, errs) = batch_clean_category_internal(
+            &results.creative_caches.paths,
+            "creative app caches",
+            Some("creative_caches"),
+            progress.as_ref(),
+            history.as_mut(),
+            &run,
+        );
+        cleaned += success;
+        errors += errs;
+        cleaned_bytes += results.creative_caches.size_bytes;
+    }
+
+    // Clean device backups (batch, each backup is one folder)
+    if results.device_backups.items > 0 {
+        let (success, errs) = batch_clean_category_internal(
+            &results.device_backups.paths,
+            "device backups",
+            Some("device_backups"),
+            progress.as_ref(),
+            history.as_mut(),
+            &run,
+        );
+        cleaned += success;
+        errors += errs;
+        cleaned_bytes += results.device_backups.size_bytes;
+    }
+
+    // Clean ignored files in git repositories (batch)
+    if results.git_ignored.items > 0 {
+        let (success, errs) = batch_clean_category_internal(
+            &results.git_ignored.paths,
+            "ignored files",
+            Some("git_ignored"),
+            progress.as_ref(),
+            history.as_mut(),
+            &run,
+        );
+        cleaned += success;
+        errors += errs;
+        cleaned_bytes += results.git_ignored.size_bytes;
+    }
+
+    // Clean ML models (Ollama models also drop their unshared blobs)
+    if results.ml_models.items > 0 {
+        if let Some(ref pb) = progress {
+            pb.set_message("Cleaning ML Models...");
+        }
+        for path in &results.ml_models.paths {
+            if cancel.is_cancelled() {
+                break;
+            }
+            let size = if utils::safe_is_dir(path) {
+                utils::calculate_dir_size(path)
+            } else {
+                utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0)
+            };
+            if dry_run {
+                cleaned += 1;
+                if let Some(ref pb) = progress {
+                    pb.inc(1);
+                }
+            } else {
+                match categories::ml_models::clean(path, permanent) {
+                    Ok(()) => {
+                        cleaned += 1;
+                        if let Some(ref pb) = progress {
+                            pb.inc(1);
+                        }
+                        if let Some(ref mut log) = history {
+                            log.log_success(path, size, "ml_models", permanent);
+                        }
+                    }
+                    Err(e) => {
+                        errors += 1;
+                        if let Some(ref mut log) = history {
+                            log.log_failure(path, size, "ml_models", permanent, &e.to_string());
+                        }
+                        if mode != OutputMode::Quiet {
+                            eprintln!(
+                                "[WARNING] Failed to clean {}: {}",
+                                Theme::secondary(&path.display().to_string()),
+                                Theme::error(&e.to_string())
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        cleaned_bytes += results.ml_models.size_bytes;
+    }
+
+    // Clean Log Files (active logs are truncated with categories.logs.truncate_active)
+    if results.logs.items > 0 {
+        if let Some(ref pb) = progress {
+            pb.set_message("Cleaning Log Files...");
+        }
+        let truncate_active = crate::config::Config::load()
+            .categories
+            .logs
+            .truncate_active;
+        for path in &results.logs.paths {
+            if cancel.is_cancelled() {
+                break;
+            }
+            let size = utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0);
+            if dry_run {
+                cleaned += 1;
+                cleaned_bytes += size;
+                if let Some(ref pb) = progress {
+                    pb.inc(1);
+                }
+                continue;
+            }
+            match categories::logs::clean(path, permanent, truncate_active) {
+                Ok(action) => {
+                    cleaned += 1;
+                    cleaned_bytes += size;
+                    if let Some(ref pb) = progress {
+                        pb.inc(1);
+                    }
+                    if let Some(ref mut log) = history {
+                        // Truncation can't be undone, so it is recorded as permanent
+                        let permanent =
+                            permanent || action == categories::logs::LogCleanAction::Truncated;
+                        log.log_success(path, size, "logs", permanent);
+                    }
+                }
+                Err(e) => {
+                    errors += 1;
+                    if let Some(ref mut log) = history {
+                        log.log_failure(path, size, "logs", permanent, &e.to_string());
+                    }
+                    if mode != OutputMode::Quiet {
+                        eprintln!(
+                            "[WARNING] Failed to clean {}: {}",
+                            Theme::secondary(&path.display().to_string()),
+                            Theme::error(&e.to_string())
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    // Clean thumbnail/icon caches (always permanent - Explorer rebuilds them)
+    if results.thumbnails.items > 0 && !cancel.is_cancelled() {
+        if let Some(ref pb) = progress {
+            pb.set_message("Restarting Explorer to clear thumbnail cache...");
+        }
+        if dry_run {
+            cleaned += results.thumbnails.items as u64;
+            cleaned_bytes += results.thumbnails.size_bytes;
+            if let Some(ref pb) = progress {
+                pb.inc(results.thumbnails.items as u64);
+            }
+        } else {
+            let sizes: HashMap<&Path, u64> = results
+                .thumbnails
+                .paths
+                .iter()
+                .map(|p| {
+                    let size = utils::safe_metadata(p).map(|m| m.len()).unwrap_or(0);
+                    (p.as_path(), size)
+                })
+                .collect();
+            let outcome = categories::thumbnails::clean(&results.thumbnails.paths);
+            for path in &outcome.deleted {
+                let size = sizes.get(path.as_path()).copied().unwrap_or(0);
+                cleaned += 1;
+                cleaned_bytes += size;
+                if let Some(ref pb) = progress {
+                    pb.inc(1);
+                }
+                if let Some(ref mut log) = history {
+                    log.log_success(path, size, "thumbnails", true);
+                }
+            }
+            for (path, reason) in &outcome.failed {
+                let size = sizes.get(path.as_path()).copied().unwrap_or(0);
+                errors += 1;
+                if let Some(ref mut log) = history {
+                    log.log_failure(path, size, "thumbnails", true, reason);
+                }
+                if mode != OutputMode::Quiet {
+                    eprintln!(
+                        "[WARNING] Failed to clean {}: {}",
+                        Theme::secondary(&path.display().to_string()),
+                        Theme::error(reason)
+                    );
+                }
+            }
+        }
+    }
+
+    // Clean plugin categories (handed back to the plugin that reported them)
+    if !results.plugins.is_empty() {
+        let config = crate::config::Config::load();
+        for plugin in results.plugins.iter().filter(|p| p.result.items > 0) {
+            if cancel.is_cancelled() {
+                break;
+            }
+            if let Some(ref pb) = progress {
+                pb.set_message(format!("Cleaning {}...", plugin.display_name()));
+            }
+            if dry_run {
+                cleaned += plugin.result.items as u64;
+                cleaned_bytes += plugin.result.size_bytes;
+                if let Some(ref pb) = progress {
+                    pb.inc(plugin.result.items as u64);
+                }
+                continue;
+            }
+
+            match crate::plugin::clean_with_plugin(
+                &plugin.plugin,
+                &plugin.category.id,
+                &plugin.result.paths,
+                permanent,
+                &config,
+            ) {
+                Ok(response) => {
+                    for path in &response.cleaned {
+                        let size = plugin.item_size(path);
+                        cleaned += 1;
+                        cleaned_bytes += size;
+                        if let Some(ref mut log) = history {
+                            log.log_success(path, size, &plugin.category.id, permanent);
+                        }
+                    }
+                    for failure in &response.failed {
+                        errors += 1;
+                        if let Some(ref mut log) = history {
+                            log.log_failure(
+                                &failure.path,
+                                plugin.item_size(&failure.path),
+                                &plugin.category.id,
+                                permanent,
+                                &failure.error,
+                            );
+                        }
+                        if mode != OutputMode::Quiet {
+                            eprintln!(
+                                "[WARNING] Failed to clean {}: {}",
+                                Theme::secondary(&failure.path.display().to_string()),
+                                Theme::error(&failure.error)
+                            );
+                        }
+                    }
+                }
+                Err(e) => {
+                    errors += plugin.result.items as u64;
+                    if mode != OutputMode::Quiet {
+                        eprintln!(
+                            "[WARNING] Plugin {} failed: {}",
+                            Theme::secondary(&plugin.plugin.name),
+                            Theme::error(&e.to_string())
+                        );
+                    }
+                }
+            }
+            if let Some(ref pb) = progress {
+                pb.inc(plugin.result.items as u64);
+            }
+        }
+    }
+
+    // Folders staged for fast delete are gone from view; let them finish before exiting
+    if super::fast_delete_progress().folders > 0 {
+        if let Some(ref pb) = progress {
+            pb.set_message("Finishing background deletes...");
+        }
+        super::wait_for_fast_deletes();
+    }
+    // Background deletes that failed count as errors, not cleaned items
+    if !fast_deleted.is_empty() {
+        let failed = super::failed_fast_deletes();
+        let failed = fast_deleted.iter().filter(|p| failed.contains(p)).count() as u64;
+        cleaned -= failed;
+        errors += failed;
+    }
+
     // Finish progress bar
     if let Some(pb) = progress {
         pb.finish_and_clear();
@@ -931,8 +1614,132 @@ pub fn clean_all(
             );
         }
 
+        print_rebuild_hints(&rebuild_hints);
+
         // Print log path if saved
-        if let Some(path) = log_path {
+        if let Some(ref path) = log_path {
+            println!(
+                "{}",
+                Theme::muted(&format!("Deletion log saved to: {}", path.display()))
+            );
+        }
+    }
+
+    let summary = CleanSummary {
+        cleaned,
+        cleaned_bytes,
+        errors,
+        cancelled: cancel.is_cancelled(),
+        log_path,
+    };
+
+    if !dry_run {
+        if let Err(e) =
+            crate::policy::send_report(&crate::policy::CleanReport::new(&summary, permanent))
+        {
+            if mode != OutputMode::Quiet {
+                eprintln!("[WARNING] {:#}", e);
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// History category of items deleted by [`clean_listed`]
+pub const LISTED_CATEGORY: &str = "from stdin";
+
+/// Delete exactly `paths`, as given (`wole clean --from-stdin`)
+///
+/// Goes through the same deletion path as [`clean_all`]: system, missing and
+/// locked paths are skipped, the machine policy decides about permanent
+/// deletes, audit mode turns it into a dry run and the run is recorded in the
+/// history under [`LISTED_CATEGORY`]. There's no confirmation prompt since
+/// stdin carries the paths; callers must check exclusions themselves.
+pub fn clean_listed(
+    paths: &[PathBuf],
+    mode: OutputMode,
+    permanent: bool,
+    dry_run: bool,
+    cancel: &CancellationToken,
+) -> Result<CleanSummary> {
+    let permanent = crate::policy::permanent_allowed(permanent);
+    let dry_run = dry_run || crate::audit::is_enabled();
+    if paths.is_empty() {
+        if mode != OutputMode::Quiet {
+            println!("{}", Theme::success("Nothing to clean."));
+        }
+        return Ok(CleanSummary::default());
+    }
+
+    let cleaned_bytes: u64 = paths
+        .iter()
+        .map(|path| {
+            if utils::safe_is_dir(path) {
+                utils::calculate_dir_size(path)
+            } else {
+                utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0)
+            }
+        })
+        .sum();
+    let progress = if mode != OutputMode::Quiet {
+        Some(progress::create_progress_bar(
+            paths.len() as u64,
+            "Cleaning...",
+        ))
+    } else {
+        None
+    };
+    let mut history = if !dry_run {
+        Some(DeletionLog::new())
+    } else {
+        None
+    };
+
+    let run = CleanRun {
+        permanent,
+        dry_run,
+        mode,
+        cancel,
+    };
+    let (cleaned, errors) = batch_clean_category_internal(
+        paths,
+        LISTED_CATEGORY,
+        None,
+        progress.as_ref(),
+        history.as_mut(),
+        &run,
+    );
+    if let Some(pb) = progress {
+        pb.finish_and_clear();
+    }
+
+    let log_path = history.and_then(|log| match log.save() {
+        Ok(path) => Some(path),
+        Err(e) => {
+            if mode != OutputMode::Quiet {
+                eprintln!("[WARNING] Failed to save deletion log: {}", e);
+            }
+            None
+        }
+    });
+
+    if mode != OutputMode::Quiet {
+        let verb = if dry_run {
+            "would be cleaned"
+        } else {
+            "cleaned"
+        };
+        println!(
+            "{} {} of {} items {} ({}), {} errors",
+            if dry_run { "[DRY RUN]" } else { "[OK]" },
+            Theme::value(&cleaned.to_string()),
+            Theme::value(&paths.len().to_string()),
+            verb,
+            Theme::size(&bytesize::to_string(cleaned_bytes, false)),
+            Theme::error(&errors.to_string())
+        );
+        if let Some(ref path) = log_path {
             println!(
                 "{}",
                 Theme::muted(&format!("Deletion log saved to: {}", path.display()))
@@ -940,7 +1747,69 @@ pub fn clean_all(
         }
     }
 
-    Ok(())
+    let summary = CleanSummary {
+        cleaned,
+        cleaned_bytes,
+        errors,
+        cancelled: cancel.is_cancelled(),
+        log_path,
+    };
+    if !dry_run {
+        if let Err(e) =
+            crate::policy::send_report(&crate::policy::CleanReport::new(&summary, permanent))
+        {
+            if mode != OutputMode::Quiet {
+                eprintln!("[WARNING] {:#}", e);
+            }
+        }
+    }
+    Ok(summary)
+}
+
+/// Projects whose build artifacts are in `paths`, with the command that regenerates them
+fn rebuild_hints_for(paths: &[PathBuf]) -> Vec<(PathBuf, &'static str)> {
+    let mut hints: Vec<(PathBuf, &'static str)> = Vec::new();
+    for path in paths {
+        let Some(project) = crate::project::artifact_project_root(path) else {
+            continue;
+        };
+        if hints.iter().any(|(seen, _)| *seen == project) {
+            continue;
+        }
+        if let Some(command) = crate::project::rebuild_command(&project) {
+            hints.push((project, command));
+        }
+    }
+    hints
+}
+
+/// Tell the user the build artifacts can be regenerated, and how
+fn print_rebuild_hints(hints: &[(PathBuf, &'static str)]) {
+    const SHOWN: usize = 10;
+
+    if hints.is_empty() {
+        return;
+    }
+    println!(
+        "{}",
+        Theme::muted("Build artifacts are regenerated by rebuilding their project:")
+    );
+    for (project, command) in hints.iter().take(SHOWN) {
+        println!(
+            "  {}  {}",
+            Theme::command(command),
+            Theme::muted(&project.display().to_string())
+        );
+    }
+    if hints.len() > SHOWN {
+        println!(
+            "{}",
+            Theme::muted(&format!(
+                "  ... and {} more project(s)",
+                hints.len() - SHOWN
+            ))
+        );
+    }
 }
 
 #[cfg(test)]
@@ -960,7 +1829,14 @@ mod tests {
 
         // Should return Ok without doing anything
         // Use Quiet mode in tests to avoid spinner thread issues
-        let result = clean_all(&results, true, OutputMode::Quiet, false, false);
+        let result = clean_all(
+            &results,
+            true,
+            OutputMode::Quiet,
+            false,
+            false,
+            &CancellationToken::new(),
+        );
         assert!(result.is_ok());
     }
 
@@ -977,7 +1853,14 @@ mod tests {
 
         // Dry run should not delete the file
         // Use Quiet mode in tests to avoid spinner thread issues
-        let result = clean_all(&results, true, OutputMode::Quiet, false, true);
+        let result = clean_all(
+            &results,
+            true,
+            OutputMode::Quiet,
+            false,
+            true,
+            &CancellationToken::new(),
+        );
         assert!(result.is_ok());
         assert!(file.exists()); // File should still exist
     }
@@ -0,0 +1,306 @@
+//! Fast delete feature.
+//!
+//! Sending a huge `node_modules` to the Recycle Bin can take minutes. For
+//! categories with `fast_delete = true`, folders are renamed into a staging
+//! folder on the same volume instead, which is instant and makes them vanish
+//! from the project right away, and a background thread deletes them
+//! permanently.
+//!
+//! Staged folders are tracked in `fast_delete.json` in wole's data folder, so
+//! folders left behind when wole exits early are deleted on the next run.
+//! The deletion history only records a folder once its background delete has
+//! finished (or failed), in a session saved whenever the queue runs empty.
+//! Fast delete is permanent, so it is off while the machine policy locks
+//! permanent deletes.
+
+use super::path_precheck::{precheck_path, PrecheckOutcome};
+use crate::debug_log;
+use crate::history::DeletionLog;
+use crate::throttle;
+use crate::utils;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Condvar, Mutex, Once, OnceLock};
+
+/// Staging folder created at the root of each volume
+const STAGING_DIR: &str = ".wole-deleting";
+
+/// Tracking file, next to the history folder
+const MANIFEST_FILE: &str = "fast_delete.json";
+
+/// A folder renamed aside and waiting to be deleted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StagedFolder {
+    /// Where the folder was
+    pub original: PathBuf,
+    /// Where it was renamed to
+    pub staged: PathBuf,
+    pub size_bytes: u64,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub staged_at: DateTime<Utc>,
+    /// History category the folder is recorded under once deleted
+    #[serde(default)]
+    pub category: String,
+}
+
+/// Background deletes not finished yet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FastDeleteProgress {
+    pub folders: usize,
+    pub bytes: u64,
+    /// Background deletes that failed in this run (retried next run)
+    pub failed: usize,
+}
+
+#[derive(Default)]
+struct Queue {
+    pending: Vec<StagedFolder>,
+    /// Couldn't be deleted; kept in the manifest to retry next run
+    failed: Vec<StagedFolder>,
+    /// Finished deletes not saved to the history yet
+    history: Option<DeletionLog>,
+    worker: Option<Sender<StagedFolder>>,
+}
+
+static QUEUE: OnceLock<(Mutex<Queue>, Condvar)> = OnceLock::new();
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+fn queue() -> &'static (Mutex<Queue>, Condvar) {
+    QUEUE.get_or_init(|| (Mutex::new(Queue::default()), Condvar::new()))
+}
+
+/// Rename `path` aside and queue it for permanent deletion in the background
+///
+/// Fails, leaving `path` where it is, for anything but an unlocked folder,
+/// while the policy locks permanent deletes, or when the rename doesn't work
+/// (e.g. the staging folder is on another volume); callers then fall back to
+/// a normal delete. The folder is recorded in the history under `category`
+/// once the background delete is done.
+pub fn stage_folder(path: &Path, size_bytes: u64, category: &str) -> Result<()> {
+    anyhow::ensure!(
+        !crate::audit::is_enabled(),
+        "Audit mode is on - nothing is deleted"
+    );
+    anyhow::ensure!(
+        crate::policy::permanent_allowed(true),
+        "Permanent delete is disabled by policy"
+    );
+    anyhow::ensure!(
+        crate::utils::safe_is_dir(path),
+        "{} is not a folder",
+        path.display()
+    );
+    anyhow::ensure!(
+        precheck_path(path) == PrecheckOutcome::Eligible,
+        "{} can't be deleted (missing, locked or protected)",
+        path.display()
+    );
+
+    crate::history::checksums::capture(path);
+    let staged = rename_aside(path)?;
+    debug_log::cleaning_log(&format!(
+        "fast delete staged: {} -> {}",
+        path.display(),
+        staged.display()
+    ));
+    enqueue(StagedFolder {
+        original: path.to_path_buf(),
+        staged,
+        size_bytes,
+        staged_at: Utc::now(),
+        category: category.to_string(),
+    });
+    Ok(())
+}
+
+/// Folders still being deleted in the background
+pub fn fast_delete_progress() -> FastDeleteProgress {
+    let queue = queue().0.lock().unwrap();
+    FastDeleteProgress {
+        folders: queue.pending.len(),
+        bytes: queue.pending.iter().map(|f| f.size_bytes).sum(),
+        failed: queue.failed.len(),
+    }
+}
+
+/// Original paths of the folders whose background delete failed in this run
+pub fn failed_fast_deletes() -> Vec<PathBuf> {
+    let queue = queue().0.lock().unwrap();
+    queue.failed.iter().map(|f| f.original.clone()).collect()
+}
+
+/// Block until every staged folder has been deleted (or failed to)
+pub fn wait_for_fast_deletes() {
+    let (lock, done) = queue();
+    let mut queue = lock.lock().unwrap();
+    while !queue.pending.is_empty() {
+        queue = done.wait(queue).unwrap();
+    }
+}
+
+/// Queue the folders an earlier run staged but didn't get to delete (once per process)
+pub fn resume_fast_deletes() {
+    static RESUMED: Once = Once::new();
+    RESUMED.call_once(|| {
+        for folder in load_manifest() {
+            if crate::utils::safe_exists(&folder.staged) {
+                enqueue(folder);
+            }
+        }
+        // Drops entries whose folders are already gone
+        save_manifest(&queue().0.lock().unwrap());
+    });
+}
+
+/// Move `path` into the staging folder of its volume
+fn rename_aside(path: &Path) -> Result<PathBuf> {
+    let root = crate::recycle_bin::volume_root(path)
+        .or_else(|| path.parent().map(Path::to_path_buf))
+        .context("No folder to stage into")?;
+    let staging = root.join(STAGING_DIR);
+    utils::safe_create_dir_all(&staging)
+        .with_context(|| format!("Failed to create {}", staging.display()))?;
+
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "folder".to_string());
+    let staged = staging.join(format!(
+        "{}-{}-{}",
+        name,
+        Utc::now().timestamp_millis(),
+        NEXT_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    utils::safe_rename(path, &staged)
+        .with_context(|| format!("Failed to move {} aside", path.display()))?;
+    Ok(staged)
+}
+
+fn enqueue(folder: StagedFolder) {
+    let mut queue = queue().0.lock().unwrap();
+    if queue.pending.iter().any(|f| f.staged == folder.staged) {
+        return;
+    }
+    queue.pending.push(folder.clone());
+    save_manifest(&queue);
+    let worker = queue.worker.get_or_insert_with(spawn_worker);
+    let _ = worker.send(folder);
+}
+
+fn spawn_worker() -> Sender<StagedFolder> {
+    let (tx, rx) = mpsc::channel::<StagedFolder>();
+    std::thread::spawn(move || {
+        // Honor performance.io_priority like every other delete
+        let _priority = throttle::PriorityGuard::current();
+        for folder in rx {
+            let result = utils::safe_remove_dir_all(&folder.staged);
+            if let Err(ref e) = result {
+                debug_log::cleaning_log(&format!(
+                    "fast delete failed: {} ({})",
+                    folder.staged.display(),
+                    e
+                ));
+            }
+            finish(&folder, result.err().map(|e| e.to_string()));
+        }
+    });
+    tx
+}
+
+/// Record a finished background delete; `error` is why it failed
+fn finish(folder: &StagedFolder, error: Option<String>) {
+    let (lock, done) = queue();
+    let mut queue = lock.lock().unwrap();
+    queue.pending.retain(|f| f.staged != folder.staged);
+    if error.is_some() {
+        queue.failed.push(folder.clone());
+    }
+    let history = queue.history.get_or_insert_with(DeletionLog::new);
+    match error {
+        None => history.log_success(&folder.original, folder.size_bytes, &folder.category, true),
+        Some(error) => history.log_failure(
+            &folder.original,
+            folder.size_bytes,
+            &folder.category,
+            true,
+            &format!("Background delete failed: {}", error),
+        ),
+    }
+    if queue.pending.is_empty() {
+        if let Some(Err(e)) = queue.history.take().map(|log| log.save()) {
+            debug_log::cleaning_log(&format!("fast delete history not saved: {:#}", e));
+        }
+    }
+    save_manifest(&queue);
+    // Only succeeds once the staging folder is empty
+    if let Some(staging) = folder.staged.parent() {
+        let _ = std::fs::remove_dir(staging);
+    }
+    done.notify_all();
+}
+
+fn manifest_path() -> Option<PathBuf> {
+    crate::history::get_history_dir()
+        .ok()
+        .and_then(|dir| dir.parent().map(|p| p.join(MANIFEST_FILE)))
+}
+
+fn load_manifest() -> Vec<StagedFolder> {
+    manifest_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_manifest(queue: &Queue) {
+    let Some(path) = manifest_path() else {
+        return;
+    };
+    let folders: Vec<&StagedFolder> = queue.pending.iter().chain(&queue.failed).collect();
+    let result: Result<()> = if folders.is_empty() {
+        match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    } else {
+        serde_json::to_string_pretty(&folders)
+            .map_err(anyhow::Error::from)
+            .and_then(|json| std::fs::write(&path, json).map_err(Into::into))
+    };
+    if let Err(e) = result {
+        debug_log::cleaning_log(&format!("fast delete manifest not saved: {:#}", e));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rename_aside() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let folder = temp_dir.path().join("node_modules");
+        std::fs::create_dir_all(folder.join("left-pad")).unwrap();
+        std::fs::write(folder.join("left-pad").join("index.js"), "x").unwrap();
+
+        let staged = rename_aside(&folder).unwrap();
+        assert!(!folder.exists());
+        assert!(staged.join("left-pad").join("index.js").exists());
+        assert!(staged
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("node_modules-"));
+        assert_eq!(
+            staged.parent().unwrap().file_name().unwrap(),
+            std::ffi::OsStr::new(STAGING_DIR)
+        );
+
+        std::fs::remove_dir_all(&staged).unwrap();
+        let _ = std::fs::remove_dir(staged.parent().unwrap());
+    }
+}
@@ -17,12 +17,15 @@ pub(crate) enum PrecheckOutcome {
 ///
 /// Attempts to open the path with DELETE access and full sharing. If it fails with
 /// sharing/access errors, the path is considered in use and likely not deletable.
+///
+/// Paths on network shares are never probed: the open is a round trip, and the
+/// server rejects opens for reasons unrelated to local locks.
 #[cfg(windows)]
 pub(crate) fn is_path_locked(path: &Path) -> bool {
     use std::fs::OpenOptions;
     use std::os::windows::fs::OpenOptionsExt;
 
-    if !path.exists() {
+    if crate::network::is_network_path(path) || !utils::safe_exists(path) {
         return false;
     }
 
@@ -36,11 +39,11 @@ pub(crate) fn is_path_locked(path: &Path) -> bool {
     options
         .access_mode(DELETE)
         .share_mode(FILE_SHARE_READ | FILE_SHARE_WRITE | FILE_SHARE_DELETE);
-    if path.is_dir() {
+    if utils::safe_is_dir(path) {
         options.custom_flags(FILE_FLAG_BACKUP_SEMANTICS);
     }
 
-    match options.open(path) {
+    match options.open(utils::long_path_if_needed(path)) {
         Ok(_) => false,
         Err(e) if matches!(e.raw_os_error(), Some(5) | Some(32) | Some(33)) => true, // ERROR_ACCESS_DENIED, ERROR_SHARING_VIOLATION, ERROR_LOCK_VIOLATION
         Err(_) => false,
@@ -59,7 +62,7 @@ pub(crate) fn precheck_path(path: &Path) -> PrecheckOutcome {
         return PrecheckOutcome::BlockedSystem;
     }
 
-    if !path.exists() {
+    if !utils::safe_exists(path) {
         return PrecheckOutcome::Missing;
     }
 
@@ -2,6 +2,7 @@
 //!
 //! This module owns single-path deletion and precheck-based deletion.
 
+use super::attributes;
 use super::path_precheck::{is_path_locked, precheck_path, PrecheckOutcome};
 use crate::utils;
 use anyhow::{Context, Result};
@@ -11,9 +12,38 @@ use std::path::Path;
 pub enum DeleteOutcome {
     Deleted,
     SkippedMissing,
+    /// In use by another process
     SkippedLocked,
+    /// Access denied, even after clearing attributes (and taking ownership, if enabled)
     SkippedPermission,
+    /// Access denied on a file with the system attribute (see `safety.clear_hidden_system`)
+    SkippedProtected,
+    /// On the protected system path list; never attempted
     SkippedSystem,
+    /// Eligible, but left untouched because audit mode is on ([`crate::audit`])
+    Audited,
+}
+
+impl DeleteOutcome {
+    /// Short explanation for logs and warnings
+    pub fn reason(&self) -> &'static str {
+        match self {
+            DeleteOutcome::Deleted => "Deleted",
+            DeleteOutcome::SkippedMissing => "Path no longer exists",
+            DeleteOutcome::SkippedLocked => "Path is locked by another process",
+            DeleteOutcome::SkippedPermission => "Permission denied",
+            DeleteOutcome::SkippedProtected => "Protected system file",
+            DeleteOutcome::SkippedSystem => "Protected system path",
+            DeleteOutcome::Audited => "Audit mode - left untouched",
+        }
+    }
+
+    pub(crate) fn is_denied(&self) -> bool {
+        matches!(
+            self,
+            DeleteOutcome::SkippedPermission | DeleteOutcome::SkippedProtected
+        )
+    }
 }
 
 fn locked_error_codes() -> &'static [i32] {
@@ -23,6 +53,8 @@ fn locked_error_codes() -> &'static [i32] {
 fn classify_permission_denied(path: &Path) -> DeleteOutcome {
     if is_path_locked(path) {
         DeleteOutcome::SkippedLocked
+    } else if attributes::is_protected(path) {
+        DeleteOutcome::SkippedProtected
     } else {
         DeleteOutcome::SkippedPermission
     }
@@ -64,7 +96,16 @@ pub(crate) fn classify_anyhow_error(path: &Path, err: &anyhow::Error) -> Option<
     None
 }
 
+/// Delete a prechecked path
+///
+/// When access is denied, clears read-only (and, if configured, hidden/system)
+/// attributes and retries, then takes ownership and retries if that is enabled
+/// and allowed (see [`attributes`]).
+///
+/// In audit mode ([`crate::audit`]) eligible paths are left untouched and
+/// reported as [`DeleteOutcome::Audited`].
 pub fn delete_with_precheck(path: &Path, permanent: bool) -> Result<DeleteOutcome> {
+    let permanent = crate::policy::permanent_allowed(permanent);
     match precheck_path(path) {
         PrecheckOutcome::Missing => return Ok(DeleteOutcome::SkippedMissing),
         PrecheckOutcome::Locked => return Ok(DeleteOutcome::SkippedLocked),
@@ -72,8 +113,37 @@ pub fn delete_with_precheck(path: &Path, permanent: bool) -> Result<DeleteOutcom
         PrecheckOutcome::Eligible => {}
     }
 
+    if crate::audit::is_enabled() {
+        crate::audit::note(&format!("delete {}", path.display()));
+        return Ok(DeleteOutcome::Audited);
+    }
+
+    let outcome = delete_once(path, permanent)?;
+    if !outcome.is_denied() {
+        return Ok(outcome);
+    }
+
+    if attributes::clear_blocking_attributes(path) > 0 {
+        let outcome = delete_once(path, permanent)?;
+        if !outcome.is_denied() {
+            return Ok(outcome);
+        }
+    }
+
+    if attributes::take_ownership(path) {
+        // Ownership may have exposed read-only entries we couldn't see before
+        attributes::clear_blocking_attributes(path);
+        return delete_once(path, permanent);
+    }
+
+    Ok(outcome)
+}
+
+/// Single delete attempt, mapping expected failures to a [`DeleteOutcome`]
+fn delete_once(path: &Path, permanent: bool) -> Result<DeleteOutcome> {
     if permanent {
-        let result = if path.is_dir() {
+        crate::history::checksums::capture(path);
+        let result = if utils::safe_is_dir(path) {
             utils::safe_remove_dir_all(path)
         } else {
             utils::safe_remove_file(path)
@@ -84,7 +154,7 @@ pub fn delete_with_precheck(path: &Path, permanent: bool) -> Result<DeleteOutcom
             Err(err) => match classify_io_error(path, &err) {
                 Some(outcome) => Ok(outcome),
                 None => {
-                    if !path.exists() {
+                    if !utils::safe_exists(path) {
                         Ok(DeleteOutcome::SkippedMissing)
                     } else {
                         Err(err).with_context(|| {
@@ -100,7 +170,7 @@ pub fn delete_with_precheck(path: &Path, permanent: bool) -> Result<DeleteOutcom
             Err(err) => match classify_anyhow_error(path, &err) {
                 Some(outcome) => Ok(outcome),
                 None => {
-                    if !path.exists() {
+                    if !utils::safe_exists(path) {
                         Ok(DeleteOutcome::SkippedMissing)
                     } else {
                         Err(err).with_context(|| format!("Failed to delete: {}", path.display()))
@@ -118,7 +188,9 @@ pub fn delete_with_precheck(path: &Path, permanent: bool) -> Result<DeleteOutcom
 /// - Uses long path support for paths > 260 characters
 /// - Provides clear error messages
 /// - **CRITICAL**: Blocks deletion of system directories for safety
+/// - Touches nothing in audit mode ([`crate::audit`])
 pub fn clean_path(path: &Path, permanent: bool) -> Result<()> {
+    let permanent = crate::policy::permanent_allowed(permanent);
     // CRITICAL SAFETY CHECK: Never allow deletion of system paths
     // This provides defense-in-depth even if a system path somehow gets into the deletion list
     if utils::is_system_path(path) {
@@ -133,10 +205,16 @@ pub fn clean_path(path: &Path, permanent: bool) -> Result<()> {
         return Err(anyhow::anyhow!("Path is locked by another process"));
     }
 
+    if crate::audit::is_enabled() {
+        crate::audit::note(&format!("delete {}", path.display()));
+        return Ok(());
+    }
+
     if permanent {
         // Permanent delete - bypass Recycle Bin
         // Use safe_* functions for long path support
-        if path.is_dir() {
+        crate::history::checksums::capture(path);
+        if utils::safe_is_dir(path) {
             utils::safe_remove_dir_all(path).with_context(|| {
                 format!("Failed to permanently delete directory: {}", path.display())
             })?;
@@ -172,4 +250,35 @@ mod tests {
         let result = clean_path(&nonexistent, true);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_audit_mode_leaves_paths_alone() {
+        crate::audit::enable_for_test();
+        let temp_dir = create_test_dir();
+        let file = temp_dir.path().join("keep.txt");
+        std::fs::write(&file, b"data").unwrap();
+        let dir = temp_dir.path().join("keep-dir");
+        std::fs::create_dir(&dir).unwrap();
+
+        for permanent in [false, true] {
+            assert_eq!(
+                delete_with_precheck(&file, permanent).unwrap(),
+                DeleteOutcome::Audited
+            );
+            assert_eq!(
+                delete_with_precheck(&dir, permanent).unwrap(),
+                DeleteOutcome::Audited
+            );
+            assert!(clean_path(&file, permanent).is_ok());
+        }
+        assert!(file.exists());
+        assert!(dir.exists());
+
+        // Missing paths are still reported as missing
+        let missing = temp_dir.path().join("missing.txt");
+        assert_eq!(
+            delete_with_precheck(&missing, true).unwrap(),
+            DeleteOutcome::SkippedMissing
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use clap::{ArgAction, Parser, Subcommand};
+use clap::{ArgAction, CommandFactory, FromArgMatches, Parser, Subcommand};
 use std::path::PathBuf;
 
 use crate::output::OutputMode;
@@ -20,6 +20,7 @@ mod interactive_menu;
         wole scan --all              # Scan all categories\n  \
         wole scan --cache --temp     # Scan specific categories\n  \
         wole clean --all -y          # Clean all categories without confirmation\n  \
+        wole clean --select \"category=build && age>90d\"  # Clean what matches\n  \
         wole scan --large --min-size 500MB  # Find files over 500MB\n  \
         wole remove                  # Uninstall wole from your system"
 )]
@@ -34,6 +35,10 @@ pub struct Cli {
     /// Suppress all output except errors
     #[arg(short = 'q', long, global = true, conflicts_with = "verbose")]
     pub quiet: bool,
+
+    /// Read-only audit mode: never delete or change anything, only report what would happen
+    #[arg(long, global = true)]
+    pub audit: bool,
 }
 
 #[derive(Subcommand)]
@@ -73,7 +78,7 @@ pub enum Commands {
         #[arg(long)]
         large: bool,
 
-        /// Scan for files not accessed in N days
+        /// Scan for files not modified in N days (see categories.old.time_basis)
         #[arg(long)]
         old: bool,
 
@@ -89,6 +94,50 @@ pub enum Commands {
         #[arg(long)]
         event_logs: bool,
 
+        /// Scan Explorer thumbnail and icon caches (thumbcache_*.db, iconcache_*.db)
+        #[arg(long)]
+        thumbnails: bool,
+
+        /// Scan crash dumps and error reports (CrashDumps, WER queues, Minidump, MEMORY.DMP)
+        #[arg(long)]
+        crash_dumps: bool,
+
+        /// Scan for runaway log files (large *.log files, rotating logs with many segments)
+        #[arg(long)]
+        logs: bool,
+
+        /// Scan game launcher caches (Steam, Epic, Origin, Battle.net)
+        #[arg(long)]
+        game_caches: bool,
+
+        /// Scan IDE and toolchain caches unused for thresholds.toolchain_cache_age_days
+        #[arg(long)]
+        toolchain_caches: bool,
+
+        /// Scan stale virtualenvs, conda environments and __pycache__ folders
+        #[arg(long)]
+        python_envs: bool,
+
+        /// Scan downloaded ML models and datasets (HuggingFace, torch hub, Ollama, ...)
+        #[arg(long)]
+        ml_models: bool,
+
+        /// Report oversized Outlook OST/PST files and Thunderbird caches
+        #[arg(long)]
+        email: bool,
+
+        /// Scan Adobe media cache, DaVinci Resolve cache and OBS remux leftovers
+        #[arg(long)]
+        creative_caches: bool,
+
+        /// Scan iPhone/iPad backups made by iTunes or Apple Devices
+        #[arg(long)]
+        device_backups: bool,
+
+        /// Scan for files ignored by git in inactive repositories, grouped by repo
+        #[arg(long)]
+        git_ignored: bool,
+
         /// Root path to scan (default: home directory)
         #[arg(long, value_name = "PATH")]
         path: Option<PathBuf>,
@@ -97,6 +146,11 @@ pub enum Commands {
         #[arg(long)]
         json: bool,
 
+        /// Print only the found paths, NUL-terminated (for xargs -0, fzf --read0,
+        /// wole clean --from-stdin)
+        #[arg(long, conflicts_with = "json")]
+        print0: bool,
+
         /// Project inactivity threshold in days [default: 14]
         #[arg(long, default_value = "14", value_name = "DAYS")]
         project_age: u64,
@@ -109,10 +163,18 @@ pub enum Commands {
         #[arg(long, default_value = "100MB", value_name = "SIZE")]
         min_size: String,
 
+        /// Stop scanning once this much reclaimable space was found (e.g., 10GB); the remaining categories are skipped
+        #[arg(long, value_name = "SIZE")]
+        size_budget: Option<String>,
+
         /// Exclude paths matching pattern (repeatable)
         #[arg(long, value_name = "PATTERN")]
         exclude: Vec<String>,
 
+        /// Whose profiles to scan for cache/temp/downloads: current or all-users (requires admin) [default: current]
+        #[arg(long, value_name = "SCOPE")]
+        scope: Option<String>,
+
         /// Force full rescan (ignore cache)
         #[arg(long)]
         force_full: bool,
@@ -124,6 +186,11 @@ pub enum Commands {
         /// Clear scan cache before running
         #[arg(long)]
         clear_cache: bool,
+
+        /// Show only the items matching a saved Results view (ui.saved_views),
+        /// scanning the view's categories when no category flags are given
+        #[arg(long, value_name = "NAME")]
+        view: Option<String>,
     },
 
     /// Delete files found by scan (with confirmation)
@@ -161,7 +228,7 @@ pub enum Commands {
         #[arg(long)]
         large: bool,
 
-        /// Clean files not accessed in N days
+        /// Clean files not modified in N days (see categories.old.time_basis)
         #[arg(long)]
         old: bool,
 
@@ -193,10 +260,59 @@ pub enum Commands {
         #[arg(long)]
         event_logs: bool,
 
+        /// Clear Explorer thumbnail and icon caches (restarts Explorer)
+        #[arg(long)]
+        thumbnails: bool,
+
+        /// Clean crash dumps and error reports older than thresholds.crash_dump_age_days
+        #[arg(long)]
+        crash_dumps: bool,
+
+        /// Clean runaway log files (active logs are skipped or truncated, see [categories.logs])
+        #[arg(long)]
+        logs: bool,
+
+        /// Clean game launcher caches (Steam, Epic, Origin, Battle.net)
+        #[arg(long)]
+        game_caches: bool,
+
+        /// Clean IDE and toolchain caches unused for thresholds.toolchain_cache_age_days
+        #[arg(long)]
+        toolchain_caches: bool,
+
+        /// Clean stale virtualenvs, conda environments and __pycache__ folders
+        #[arg(long)]
+        python_envs: bool,
+
+        /// Clean downloaded ML models and datasets (HuggingFace, torch hub, Ollama, ...)
+        #[arg(long)]
+        ml_models: bool,
+
+        /// Report oversized Outlook OST/PST files and Thunderbird caches (never deleted by clean)
+        #[arg(long)]
+        email: bool,
+
+        /// Clean Adobe media cache, DaVinci Resolve cache and OBS remux leftovers
+        #[arg(long)]
+        creative_caches: bool,
+
+        /// Delete iPhone/iPad backups made by iTunes or Apple Devices
+        #[arg(long)]
+        device_backups: bool,
+
+        /// Clean files ignored by git in inactive repositories (env files are kept)
+        #[arg(long)]
+        git_ignored: bool,
+
         /// Root path to scan (default: home directory)
         #[arg(long, value_name = "PATH")]
         path: Option<PathBuf>,
 
+        /// Only clean inside the git repository containing the current directory
+        /// (defaults to --build --python-envs --git-ignored)
+        #[arg(long, conflicts_with = "path")]
+        repo: bool,
+
         /// Output results as JSON for scripting
         #[arg(long)]
         json: bool,
@@ -217,10 +333,28 @@ pub enum Commands {
         #[arg(long, default_value = "100MB", value_name = "SIZE")]
         min_size: String,
 
+        /// Stop scanning once this much reclaimable space was found (e.g., 10GB); the remaining categories are skipped
+        #[arg(long, value_name = "SIZE")]
+        size_budget: Option<String>,
+
         /// Exclude paths matching pattern (repeatable)
         #[arg(long, value_name = "PATTERN")]
         exclude: Vec<String>,
 
+        /// Only clean items matching an expression, e.g. "category=build && age>90d && size>500MB"
+        /// (without category flags, scans the categories it names)
+        #[arg(long, value_name = "EXPR")]
+        select: Option<String>,
+
+        /// Whose profiles to scan for cache/temp/downloads: current or all-users (requires admin) [default: current]
+        #[arg(long, value_name = "SCOPE")]
+        scope: Option<String>,
+
+        /// Delete exactly the paths read from stdin (NUL- or newline-separated) instead
+        /// of scanning; exclusions and system path protection still apply. Needs --yes
+        #[arg(long, conflicts_with_all = ["all", "repo", "select", "path"])]
+        from_stdin: bool,
+
         /// Permanently delete (bypass Recycle Bin)
         #[arg(long)]
         permanent: bool,
@@ -289,7 +423,7 @@ pub enum Commands {
         #[arg(long)]
         large: bool,
 
-        /// Scan for files not accessed in N days
+        /// Scan for files not modified in N days (see categories.old.time_basis)
         #[arg(long)]
         old: bool,
 
@@ -332,6 +466,22 @@ pub enum Commands {
         /// Exclude paths matching pattern (repeatable)
         #[arg(long, value_name = "PATTERN")]
         exclude: Vec<String>,
+
+        /// Write folder sizes, file counts and percentages to FILE (disk insights mode)
+        #[arg(long, value_name = "FILE")]
+        export: Option<PathBuf>,
+
+        /// Export format: csv, json or wiztree [default: from the file extension]
+        #[arg(long, value_name = "FORMAT", requires = "export")]
+        export_format: Option<String>,
+
+        /// Compare two JSON exports and show the folders that differ most
+        #[arg(long, num_args = 2, value_names = ["A", "B"])]
+        compare: Vec<PathBuf>,
+
+        /// Chart the files by extension, size and age (--export writes the buckets as CSV or JSON)
+        #[arg(long, conflicts_with_all = ["interactive", "compare"])]
+        breakdown: bool,
     },
 
     /// View or modify configuration
@@ -383,6 +533,28 @@ pub enum Commands {
         #[arg(long)]
         data: bool,
 
+        /// Remove everything wole left behind: config, data, scan cache,
+        /// scheduled tasks and startup/tray entries
+        #[arg(long)]
+        purge: bool,
+
+        /// Save config and history to a zip archive before removing anything
+        #[arg(long, value_name = "ZIP")]
+        export: Option<PathBuf>,
+
+        /// Instead of uninstalling, delete the paths listed in FILE: one path or
+        /// glob per line, `#` comments, relative paths from the file's folder
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["config", "data", "purge", "export"])]
+        manifest: Option<PathBuf>,
+
+        /// Permanently delete manifest paths (bypass Recycle Bin)
+        #[arg(long, requires = "manifest")]
+        permanent: bool,
+
+        /// Preview the manifest, don't delete
+        #[arg(long, requires = "manifest")]
+        dry_run: bool,
+
         /// Skip confirmation prompt
         #[arg(short = 'y', long = "yes")]
         yes: bool,
@@ -430,7 +602,7 @@ pub enum Commands {
         #[arg(long)]
         memory: bool,
 
-        /// Reset network stack - Winsock + IP (requires admin)
+        /// Reset network stack step by step until it connects - DNS, IP, Winsock, TCP/IP (requires admin)
         #[arg(long)]
         network: bool,
 
@@ -446,6 +618,34 @@ pub enum Commands {
         #[arg(long)]
         explorer: bool,
 
+        /// Clear stuck print jobs and restart the Print Spooler (requires admin)
+        #[arg(long)]
+        spooler: bool,
+
+        /// Purge and rebuild the Windows Search index - not part of --all (requires admin)
+        #[arg(long)]
+        search_index: bool,
+
+        /// Delete the font cache files and restart the font cache services (requires admin)
+        #[arg(long)]
+        font_cache: bool,
+
+        /// Compress folders in place (NTFS/compact.exe) instead of deleting them
+        #[arg(long, value_name = "PATH", num_args = 1..)]
+        compress: Vec<PathBuf>,
+
+        /// Compression algorithm: ntfs, xpress4k, xpress8k, xpress16k, or lzx [default: xpress8k]
+        #[arg(long, value_name = "MODE")]
+        compress_mode: Option<String>,
+
+        /// Remove Zone.Identifier streams ("downloaded from the internet" blocks) from files under these folders [default: Downloads]
+        #[arg(long, value_name = "PATH", num_args = 0..)]
+        unblock: Option<Vec<PathBuf>>,
+
+        /// Switch power plan: high, balanced, saver, a plan name or GUID, or restore the previous one
+        #[arg(long, value_name = "PLAN")]
+        power_plan: Option<String>,
+
         /// Preview only, don't execute
         #[arg(long)]
         dry_run: bool,
@@ -473,6 +673,118 @@ pub enum Commands {
         /// Use new experimental display format
         #[arg(long)]
         new: bool,
+
+        /// Show what is writing to a drive right now (Windows, needs admin)
+        #[arg(long, conflicts_with_all = ["json", "new"])]
+        writes: bool,
+
+        /// Drive to watch with --writes (default: the system drive)
+        #[arg(long, value_name = "DRIVE", requires = "writes")]
+        volume: Option<String>,
+    },
+
+    /// Show which folders grew or shrank the most since the previous scan
+    Diff {
+        /// Maximum number of folders to show
+        #[arg(short = 'n', long, default_value = "20", value_name = "N")]
+        limit: usize,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Suggest what to clean next, based on the last scan and the system drive
+    Recommend {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Find out where the space on a full drive went, step by step
+    WhyFull {
+        /// Drive to look at (default: the system drive)
+        #[arg(long, value_name = "DRIVE")]
+        drive: Option<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Report registry entries that point at missing programs (report only)
+    Registry {
+        /// Also write the report to FILE (CSV for .csv, JSON otherwise)
+        #[arg(long, value_name = "FILE")]
+        export: Option<PathBuf>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Check the hosts file, proxy and DNS settings for leftovers from VPNs and dev tools
+    Netcheck {
+        /// Revert issue N of the report (the previous state is backed up first)
+        #[arg(long, value_name = "N")]
+        revert: Option<usize>,
+
+        /// Revert every issue found
+        #[arg(long, conflicts_with = "revert")]
+        revert_all: bool,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Inspect the deletion history
+    History {
+        #[command(subcommand)]
+        action: HistoryCommands,
+    },
+
+    /// Inspect, compact, reset or move the scan cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheCommands,
+    },
+
+    /// Clean camera thumbnails and OS metadata off USB sticks and SD cards
+    Media {
+        #[command(subcommand)]
+        action: MediaCommands,
+    },
+
+    /// Benchmark scan and clean throughput on a synthetic directory tree
+    Bench {
+        /// Total number of files to generate
+        #[arg(long, default_value = "20000", value_name = "N")]
+        files: usize,
+
+        /// Size of each generated file (e.g. 4KB, 1MB)
+        #[arg(long, default_value = "4KB", value_name = "SIZE")]
+        file_size: String,
+
+        /// Number of fake projects (each with a node_modules folder)
+        #[arg(long, default_value = "20", value_name = "N")]
+        projects: usize,
+
+        /// Files per directory inside node_modules
+        #[arg(long, default_value = "50", value_name = "N")]
+        files_per_dir: usize,
+
+        /// Empty directory to generate the tree in [default: system temp]
+        #[arg(long, value_name = "PATH")]
+        path: Option<PathBuf>,
+
+        /// Keep the generated tree afterwards (skips the clean phase)
+        #[arg(long)]
+        keep: bool,
+
+        /// Output results as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// Manage Windows startup programs
@@ -482,7 +794,7 @@ pub enum Commands {
         #[arg(short = 'l', long)]
         list: bool,
 
-        /// Disable a startup program by name
+        /// Disable a startup program, scheduled task or automatic service by name
         #[arg(short = 'd', long, value_name = "NAME")]
         disable: Option<String>,
 
@@ -490,6 +802,10 @@ pub enum Commands {
         #[arg(short = 'e', long, value_name = "NAME")]
         enable: Option<String>,
 
+        /// List third-party scheduled tasks and automatic services, flagging missing programs
+        #[arg(long)]
+        audit: bool,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -498,7 +814,9 @@ pub enum Commands {
 
 impl Cli {
     pub fn parse() -> Self {
-        <Self as Parser>::parse()
+        crate::i18n::apply(&crate::config::Config::load().ui.language);
+        let matches = localize_help(<Self as CommandFactory>::command()).get_matches();
+        <Self as FromArgMatches>::from_arg_matches(&matches).unwrap_or_else(|e| e.exit())
     }
 
     pub fn run(self) -> anyhow::Result<()> {
@@ -512,11 +830,14 @@ impl Cli {
             OutputMode::Normal
         };
 
+        if self.audit || crate::config::Config::load().safety.audit {
+            crate::audit::enable();
+        }
+
         match self.command {
             None => {
                 // No command provided - show interactive menu
-                Self::show_interactive_menu();
-                Ok(())
+                Self::show_interactive_menu()
             }
             Some(command) => match command {
                 Commands::Scan {
@@ -532,15 +853,30 @@ impl Cli {
                     applications,
                     windows_update,
                     event_logs,
+                    thumbnails,
+                    crash_dumps,
+                    logs,
+                    game_caches,
+                    toolchain_caches,
+                    python_envs,
+                    ml_models,
+                    email,
+                    creative_caches,
+                    device_backups,
+                    git_ignored,
                     path,
                     json,
+                    print0,
                     project_age,
                     min_age,
                     min_size,
+                    size_budget,
                     exclude,
+                    scope,
                     force_full,
                     no_cache,
                     clear_cache,
+                    view,
                 } => commands::scan_command::handle_scan(
                     all,
                     cache,
@@ -554,15 +890,30 @@ impl Cli {
                     applications,
                     windows_update,
                     event_logs,
+                    thumbnails,
+                    crash_dumps,
+                    logs,
+                    game_caches,
+                    toolchain_caches,
+                    python_envs,
+                    ml_models,
+                    email,
+                    creative_caches,
+                    device_backups,
+                    git_ignored,
                     path,
                     json,
+                    print0,
                     project_age,
                     min_age,
                     min_size,
+                    size_budget,
                     exclude,
+                    scope,
                     force_full,
                     no_cache,
                     clear_cache,
+                    view,
                     output_mode,
                 ),
                 Commands::Clean {
@@ -582,13 +933,29 @@ impl Cli {
                     applications,
                     windows_update,
                     event_logs,
+                    thumbnails,
+                    crash_dumps,
+                    logs,
+                    game_caches,
+                    toolchain_caches,
+                    python_envs,
+                    ml_models,
+                    email,
+                    creative_caches,
+                    device_backups,
+                    git_ignored,
                     path,
+                    repo,
                     json,
                     yes,
                     project_age,
                     min_age,
                     min_size,
+                    size_budget,
                     exclude,
+                    select,
+                    scope,
+                    from_stdin,
                     permanent,
                     dry_run,
                 } => commands::clean_command::handle_clean(
@@ -608,13 +975,29 @@ impl Cli {
                     applications,
                     windows_update,
                     event_logs,
+                    thumbnails,
+                    crash_dumps,
+                    logs,
+                    game_caches,
+                    toolchain_caches,
+                    python_envs,
+                    ml_models,
+                    email,
+                    creative_caches,
+                    device_backups,
+                    git_ignored,
                     path,
+                    repo,
                     json,
                     yes,
                     project_age,
                     min_age,
                     min_size,
+                    size_budget,
                     exclude,
+                    select,
+                    scope,
+                    from_stdin,
                     permanent,
                     dry_run,
                     output_mode,
@@ -645,6 +1028,10 @@ impl Cli {
                     min_age,
                     min_size,
                     exclude,
+                    export,
+                    export_format,
+                    compare,
+                    breakdown,
                 } => commands::analyze_command::handle_analyze(
                     disk,
                     entire_disk,
@@ -671,6 +1058,10 @@ impl Cli {
                     min_age,
                     min_size,
                     exclude,
+                    export,
+                    export_format,
+                    compare,
+                    breakdown,
                     output_mode,
                 ),
                 Commands::Config {
@@ -692,9 +1083,32 @@ impl Cli {
                     self.quiet,
                     self.verbose,
                 ),
-                Commands::Remove { config, data, yes } => commands::remove_command::handle_remove(
+                Commands::Remove {
+                    manifest: Some(manifest),
+                    permanent,
+                    dry_run,
+                    yes,
+                    ..
+                } => commands::remove_command::handle_remove_manifest(
+                    &manifest,
+                    permanent,
+                    dry_run,
+                    yes,
+                    output_mode,
+                ),
+                Commands::Remove {
+                    config,
+                    data,
+                    purge,
+                    export,
+                    manifest: None,
+                    yes,
+                    ..
+                } => commands::remove_command::handle_remove(
                     config,
                     data,
+                    purge,
+                    export,
                     yes,
                     self.quiet,
                     self.verbose,
@@ -714,6 +1128,13 @@ impl Cli {
                     bluetooth,
                     search,
                     explorer,
+                    spooler,
+                    search_index,
+                    font_cache,
+                    compress,
+                    compress_mode,
+                    unblock,
+                    power_plan,
                     dry_run,
                     yes,
                 } => commands::optimize_command::handle_optimize(
@@ -728,6 +1149,13 @@ impl Cli {
                     bluetooth,
                     search,
                     explorer,
+                    spooler,
+                    search_index,
+                    font_cache,
+                    compress,
+                    compress_mode,
+                    unblock,
+                    power_plan,
                     dry_run,
                     yes,
                     output_mode,
@@ -769,20 +1197,206 @@ impl Cli {
                 //     }
                 //     Ok(())
                 // }
-                Commands::Status { json, watch, new } => {
-                    commands::status_command::handle_status(json, watch, new)
+                Commands::Status {
+                    json,
+                    watch,
+                    new,
+                    writes,
+                    volume,
+                } => {
+                    if writes {
+                        commands::status_command::handle_disk_writes(volume)
+                    } else {
+                        commands::status_command::handle_status(json, watch, new)
+                    }
+                }
+                Commands::Diff { limit, json } => {
+                    commands::diff_command::handle_diff(limit, json, output_mode)
+                }
+                Commands::Recommend { json } => {
+                    commands::recommend_command::handle_recommend(json, output_mode)
+                }
+                Commands::WhyFull { drive, json } => {
+                    commands::why_full_command::handle_why_full(drive, json, output_mode)
+                }
+                Commands::Registry { export, json } => {
+                    commands::registry_command::handle_registry(export, json, output_mode)
                 }
+                Commands::Netcheck {
+                    revert,
+                    revert_all,
+                    json,
+                } => commands::netcheck_command::handle_netcheck(
+                    revert,
+                    revert_all,
+                    json,
+                    output_mode,
+                ),
+                Commands::History { action } => match action {
+                    HistoryCommands::Verify { json } => {
+                        commands::history_command::handle_verify(json, output_mode)
+                    }
+                },
+                Commands::Cache { action } => match action {
+                    CacheCommands::Export { file } => {
+                        commands::cache_command::handle_export(file, output_mode)
+                    }
+                    CacheCommands::Import { file, yes } => {
+                        commands::cache_command::handle_import(file, yes, output_mode)
+                    }
+                    CacheCommands::Stats { json } => {
+                        commands::cache_command::handle_stats(json, output_mode)
+                    }
+                    CacheCommands::Vacuum => commands::cache_command::handle_vacuum(output_mode),
+                    CacheCommands::Reset { yes } => {
+                        commands::cache_command::handle_reset(yes, output_mode)
+                    }
+                },
+                Commands::Media { action } => match action {
+                    MediaCommands::List { json } => {
+                        commands::media_command::handle_list(json, output_mode)
+                    }
+                    MediaCommands::Clean {
+                        volume,
+                        only,
+                        dry_run,
+                        yes,
+                    } => commands::media_command::handle_clean(
+                        volume,
+                        only,
+                        dry_run,
+                        yes,
+                        output_mode,
+                    ),
+                },
+                Commands::Bench {
+                    files,
+                    file_size,
+                    projects,
+                    files_per_dir,
+                    path,
+                    keep,
+                    json,
+                } => commands::bench_command::handle_bench(
+                    files,
+                    file_size,
+                    projects,
+                    files_per_dir,
+                    path,
+                    keep,
+                    json,
+                    output_mode,
+                ),
                 Commands::Startup {
                     list,
                     disable,
                     enable,
+                    audit,
                     json,
-                } => commands::startup_command::handle_startup(list, disable, enable, json),
+                } => commands::startup_command::handle_startup(list, disable, enable, audit, json),
             },
         }
     }
 }
 
+/// Translate the `about` of every (sub)command and the help of the global flags
+fn localize_help(mut cmd: clap::Command) -> clap::Command {
+    if let Some(about) = cmd.get_about().map(|a| a.to_string()) {
+        cmd = cmd.about(crate::i18n::t(&about).to_string());
+    }
+    let args: Vec<String> = cmd
+        .get_arguments()
+        .filter(|a| a.is_global_set())
+        .map(|a| a.get_id().to_string())
+        .collect();
+    for id in args {
+        cmd = cmd.mut_arg(id, |arg| match arg.get_help().map(|h| h.to_string()) {
+            Some(help) => arg.help(crate::i18n::t(&help).to_string()),
+            None => arg,
+        });
+    }
+    let subcommands: Vec<String> = cmd
+        .get_subcommands()
+        .map(|s| s.get_name().to_string())
+        .collect();
+    for name in subcommands {
+        cmd = cmd.mut_subcommand(name, localize_help);
+    }
+    cmd
+}
+
+#[derive(Subcommand)]
+pub enum HistoryCommands {
+    /// Check the tamper-evident hash chain for modified or missing entries
+    Verify {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommands {
+    /// Save the config and latest scan baseline to a portable bundle
+    Export {
+        /// Bundle file to write (JSON)
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+    },
+    /// Restore the config and scan baseline from a bundle
+    Import {
+        /// Bundle written by `wole cache export`
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// Replace the current config without asking
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+    /// Show the size, age and contents of the scan cache
+    Stats {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check the scan cache for damage and compact it
+    Vacuum,
+    /// Delete the scan cache; the next scan starts from scratch
+    Reset {
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum MediaCommands {
+    /// List mounted removable drives and the junk on each
+    List {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Permanently delete the junk on a removable drive
+    Clean {
+        /// Drive to clean (e.g. E:\) [default: the only removable drive mounted]
+        #[arg(value_name = "DRIVE")]
+        volume: Option<PathBuf>,
+
+        /// Only these kinds: thumbnails, mac, windows, trash (comma-separated)
+        #[arg(long, value_delimiter = ',', value_name = "KINDS")]
+        only: Vec<String>,
+
+        /// Show what would be deleted without deleting
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+}
+
 #[derive(Clone)]
 pub struct ScanOptions {
     pub cache: bool,
@@ -800,7 +1414,20 @@ pub struct ScanOptions {
     pub duplicates: bool,
     pub windows_update: bool,
     pub event_logs: bool,
+    pub thumbnails: bool,
+    pub crash_dumps: bool,
+    pub logs: bool,
+    pub game_caches: bool,
+    pub toolchain_caches: bool,
+    pub python_envs: bool,
+    pub ml_models: bool,
+    pub email: bool,
+    pub creative_caches: bool,
+    pub device_backups: bool,
+    pub git_ignored: bool,
     pub project_age_days: u64,
     pub min_age_days: u64,
     pub min_size_bytes: u64,
+    /// Whose profiles the per-user categories (cache, temp, downloads) cover
+    pub scope: crate::users::ScanScope,
 }
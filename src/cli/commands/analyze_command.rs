@@ -7,7 +7,63 @@ use crate::config::Config;
 use crate::output::{self, OutputMode};
 use crate::scanner;
 use crate::size;
-use std::path::PathBuf;
+use crate::theme::Theme;
+use std::path::{Path, PathBuf};
+
+/// `wole analyze --breakdown`: distributions by extension, size and age
+fn analyze_breakdown(
+    scan_path: &Path,
+    config: &Config,
+    export: Option<PathBuf>,
+    export_format: Option<String>,
+    output_mode: OutputMode,
+) -> anyhow::Result<()> {
+    use crate::disk_usage::ExportFormat;
+
+    let export = match export {
+        Some(file) => {
+            let format = match export_format.as_deref() {
+                Some(value) => match ExportFormat::parse(value) {
+                    Some(format @ (ExportFormat::Csv | ExportFormat::Json)) => format,
+                    _ => {
+                        return Err(anyhow::anyhow!(
+                            "Unknown breakdown export format '{}' (expected csv or json)",
+                            value
+                        ))
+                    }
+                },
+                None => ExportFormat::from_path(&file),
+            };
+            Some((file, format))
+        }
+        None => None,
+    };
+
+    let spinner = if output_mode != OutputMode::Quiet {
+        Some(crate::progress::create_spinner(&format!(
+            "Analyzing {}...",
+            scan_path.display()
+        )))
+    } else {
+        None
+    };
+    let breakdown = crate::analyzer::breakdown(scan_path, config, None)?;
+    if let Some(sp) = spinner {
+        crate::progress::finish_and_clear(&sp);
+    }
+
+    if let Some((file, format)) = export {
+        breakdown.write_export(&file, format)?;
+        if output_mode != OutputMode::Quiet {
+            println!("Exported breakdown to {}", file.display());
+        }
+    }
+
+    if output_mode != OutputMode::Quiet {
+        crate::analyzer::print_breakdown(&breakdown);
+    }
+    Ok(())
+}
 
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn handle_analyze(
@@ -36,8 +92,16 @@ pub(crate) fn handle_analyze(
     min_age: u64,
     min_size: String,
     exclude: Vec<String>,
+    export: Option<PathBuf>,
+    export_format: Option<String>,
+    compare: Vec<PathBuf>,
+    breakdown: bool,
     output_mode: OutputMode,
 ) -> anyhow::Result<()> {
+    if let [a, b] = compare.as_slice() {
+        return compare_exports(a, b, top.unwrap_or(10), output_mode);
+    }
+
     // Load config first
     let config = Config::load();
 
@@ -56,11 +120,11 @@ pub(crate) fn handle_analyze(
         || duplicates
         || applications
         || all;
-    let disk_mode = disk || (!has_category_flags); // Default to disk mode if no category flags
+    let disk_mode = disk || breakdown || (!has_category_flags); // Default to disk mode if no category flags
 
     if disk_mode {
         // Disk insights mode
-        use crate::disk_usage::{scan_directory, SortBy};
+        use crate::disk_usage::{scan_directory, ExportFormat, SortBy};
         use crate::utils;
 
         // Determine scan path
@@ -86,6 +150,26 @@ pub(crate) fn handle_analyze(
             ));
         }
 
+        if breakdown {
+            return analyze_breakdown(&scan_path, &config, export, export_format, output_mode);
+        }
+
+        let export = match export {
+            Some(file) => {
+                let format = match export_format.as_deref() {
+                    Some(value) => ExportFormat::parse(value).ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Unknown export format '{}' (expected csv, json or wiztree)",
+                            value
+                        )
+                    })?,
+                    None => ExportFormat::from_path(&file),
+                };
+                Some((file, format))
+            }
+            None => None,
+        };
+
         // Parse sort option
         let sort_by = match sort.as_deref() {
             Some("name") => SortBy::Name,
@@ -124,6 +208,13 @@ pub(crate) fn handle_analyze(
             crate::progress::finish_and_clear(&sp);
         }
 
+        if let Some((file, format)) = export {
+            crate::disk_usage::write_export(&insights, &file, format)?;
+            if output_mode != OutputMode::Quiet {
+                println!("Exported disk insights to {}", file.display());
+            }
+        }
+
         if interactive {
             // Launch TUI mode
             use crate::tui;
@@ -232,7 +323,7 @@ pub(crate) fn handle_analyze(
             None
         };
 
-        let results = scanner::scan_all(
+        let results = scanner::run_scan(
             &scan_path,
             ScanOptions {
                 cache,
@@ -250,13 +341,25 @@ pub(crate) fn handle_analyze(
                 duplicates,
                 windows_update: false,
                 event_logs: false,
+                thumbnails: false,
+                crash_dumps: false,
+                logs: false,
+                game_caches: false,
+                toolchain_caches: false,
+                python_envs: false,
+                ml_models: false,
+                email: false,
+                creative_caches: false,
+                device_backups: false,
+                git_ignored: false,
                 project_age_days: config.thresholds.project_age_days,
                 min_age_days: config.thresholds.min_age_days,
                 min_size_bytes,
+                scope: crate::users::ScanScope::CurrentUser,
             },
             output_mode,
             &config,
-            scan_cache.as_mut(),
+            &mut scan_cache,
         )?;
 
         // Launch TUI if interactive mode requested
@@ -277,3 +380,81 @@ pub(crate) fn handle_analyze(
         Ok(())
     }
 }
+
+/// `wole analyze --compare A B`: the folders whose sizes differ most between two exports
+fn compare_exports(a: &Path, b: &Path, top: usize, output_mode: OutputMode) -> anyhow::Result<()> {
+    use crate::disk_usage::{compare_snapshots, InsightsSnapshot};
+
+    let first = InsightsSnapshot::load(a)?;
+    let second = InsightsSnapshot::load(b)?;
+    if output_mode == OutputMode::Quiet {
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", Theme::header("Disk Usage Comparison"));
+    println!("{}", Theme::divider_bold(60));
+    for (label, snapshot) in [("A", &first), ("B", &second)] {
+        println!(
+            "  {}  {}  {}  {}",
+            Theme::value(label),
+            Theme::size(&format!(
+                "{:>10}",
+                bytesize::to_string(snapshot.total_size_bytes, false)
+            )),
+            Theme::primary(&snapshot.root.display().to_string()),
+            Theme::muted(&format!(
+                "({}, exported {})",
+                snapshot.host.as_deref().unwrap_or("unknown host"),
+                snapshot
+                    .exported_at
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+            ))
+        );
+    }
+    let total = crate::scan_cache::DirChange {
+        path: PathBuf::new(),
+        old_size: first.total_size_bytes,
+        new_size: second.total_size_bytes,
+    };
+    println!("  B is {} compared to A", total.delta_human());
+    println!();
+
+    let changes = compare_snapshots(&first, &second, top);
+    if changes.is_empty() {
+        println!("  {}", Theme::success("No folder sizes differ"));
+        println!();
+        return Ok(());
+    }
+
+    println!("  {:>12} {:>12} {:>12}  Folder", "Difference", "A", "B");
+    println!("{}", Theme::divider(60));
+    for change in &changes {
+        let delta = format!("{:>12}", change.delta_human());
+        let delta = if change.delta() > 0 {
+            Theme::warning(&delta)
+        } else {
+            Theme::success(&delta)
+        };
+        let folder = if change.path.as_os_str().is_empty() {
+            "(root)".to_string()
+        } else {
+            change.path.display().to_string()
+        };
+        println!(
+            "  {} {:>12} {:>12}  {}",
+            delta,
+            bytesize::to_string(change.old_size, false),
+            bytesize::to_string(change.new_size, false),
+            folder
+        );
+    }
+    println!();
+    println!(
+        "{}",
+        Theme::muted("Folders are matched by their path below each export's root.")
+    );
+
+    Ok(())
+}
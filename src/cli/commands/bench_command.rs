@@ -0,0 +1,96 @@
+//! Bench command feature.
+//!
+//! This module owns and handles the "wole bench" command behavior.
+
+use crate::bench::{self, BenchOptions};
+use crate::config::Config;
+use crate::output::OutputMode;
+use crate::size;
+use crate::theme::Theme;
+use std::path::PathBuf;
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn handle_bench(
+    files: usize,
+    file_size: String,
+    projects: usize,
+    files_per_dir: usize,
+    path: Option<PathBuf>,
+    keep: bool,
+    json: bool,
+    output_mode: OutputMode,
+) -> anyhow::Result<()> {
+    let file_size = size::parse_size(&file_size)
+        .map_err(|e| anyhow::anyhow!("Invalid size format '{}': {}", file_size, e))?;
+
+    let root = path
+        .unwrap_or_else(|| std::env::temp_dir().join(format!("wole-bench-{}", std::process::id())));
+    if root.exists() && std::fs::read_dir(&root)?.next().is_some() {
+        anyhow::bail!(
+            "Bench directory {} is not empty (everything in it would be deleted)",
+            root.display()
+        );
+    }
+    std::fs::create_dir_all(&root)?;
+
+    let options = BenchOptions {
+        files,
+        file_size,
+        projects,
+        files_per_dir,
+        keep,
+    };
+
+    let config = Config::load();
+
+    if !json && output_mode != OutputMode::Quiet {
+        println!();
+        println!("{}", Theme::header("Scan/Clean Benchmark"));
+        println!("{}", Theme::divider_bold(60));
+        println!(
+            "  {} {} files × {} in {} projects",
+            Theme::muted("→"),
+            files,
+            bytesize::to_string(file_size, false),
+            projects.max(1)
+        );
+        println!("  {} {}", Theme::muted("→"), root.display());
+        println!();
+    }
+
+    let report = bench::run_bench(&root, &options, &config);
+
+    if !keep {
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    let report = report?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    if output_mode != OutputMode::Quiet {
+        println!(
+            "  {:<10} {:>10} {:>12} {:>14} {:>10}",
+            "Phase", "Time", "Items", "Items/s", "MB/s"
+        );
+        println!("{}", Theme::divider(60));
+        for phase in &report.phases {
+            println!(
+                "  {:<10} {:>9.2}s {:>12} {:>14.0} {:>10.1}",
+                phase.name, phase.duration_secs, phase.items, phase.items_per_sec, phase.mb_per_sec
+            );
+        }
+        println!();
+        if keep {
+            println!(
+                "{}",
+                Theme::muted(&format!("Kept bench directory: {}", root.display()))
+            );
+        }
+    }
+
+    Ok(())
+}
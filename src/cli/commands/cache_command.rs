@@ -0,0 +1,216 @@
+//! Cache command feature.
+//!
+//! This module owns and handles the "wole cache" command behavior.
+
+use crate::output::OutputMode;
+use crate::scan_cache::{bundle, CacheSummary, ScanCache};
+use crate::theme::Theme;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+/// Ask a yes/no question; anything but "y"/"yes" (or a read error) is no
+fn confirm(prompt: &str) -> bool {
+    print!("{} [y/N]: ", prompt);
+    io::stdout().flush().ok();
+    let mut input = String::new();
+    io::stdin().lock().read_line(&mut input).is_ok()
+        && matches!(input.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// `wole cache export <FILE>`
+pub(crate) fn handle_export(file: PathBuf, output_mode: OutputMode) -> anyhow::Result<()> {
+    let bundle = bundle::export_bundle(&file)?;
+    if output_mode == OutputMode::Quiet {
+        return Ok(());
+    }
+
+    println!(
+        "{} Exported config and scan baseline to {}",
+        Theme::success("OK"),
+        file.display()
+    );
+    match &bundle.baseline {
+        Some(baseline) => println!(
+            "  {} folders from the scan of {}",
+            baseline.dirs.len(),
+            baseline.scanned_at.format("%Y-%m-%d %H:%M")
+        ),
+        None => println!(
+            "  {}",
+            Theme::muted("No scan baseline yet; only the config was exported")
+        ),
+    }
+    println!(
+        "  {} exclusion patterns",
+        bundle.config.exclusions.patterns.len()
+    );
+    Ok(())
+}
+
+/// `wole cache import <FILE>` - replaces the config (a backup is kept)
+pub(crate) fn handle_import(
+    file: PathBuf,
+    yes: bool,
+    output_mode: OutputMode,
+) -> anyhow::Result<()> {
+    if !yes {
+        let preview = bundle::read_bundle(&file)?;
+        println!();
+        println!(
+            "Bundle exported {}",
+            preview.exported_at.format("%Y-%m-%d %H:%M")
+        );
+        println!(
+            "  • Replaces the current config ({} exclusion patterns in the bundle)",
+            preview.config.exclusions.patterns.len()
+        );
+        if let Some(baseline) = &preview.baseline {
+            println!(
+                "  • Adds a scan baseline of {} folders",
+                baseline.dirs.len()
+            );
+        }
+        if !confirm("Continue?") {
+            println!("Import cancelled.");
+            return Ok(());
+        }
+    }
+
+    let summary = bundle::import_bundle(&file)?;
+    if output_mode == OutputMode::Quiet {
+        return Ok(());
+    }
+
+    if crate::audit::is_enabled() {
+        println!(
+            "{} Would import config and scan baseline from {}",
+            Theme::muted("Audit"),
+            file.display()
+        );
+        return Ok(());
+    }
+    println!(
+        "{} Imported config from {}",
+        Theme::success("OK"),
+        file.display()
+    );
+    if let Some(backup) = &summary.config_backup {
+        println!(
+            "  {}",
+            Theme::muted(&format!("Previous config saved to {}", backup.display()))
+        );
+    }
+    if summary.baseline_dirs > 0 {
+        println!(
+            "  Scan baseline of {} folders added; the next scan is compared against it",
+            summary.baseline_dirs
+        );
+    }
+    Ok(())
+}
+
+/// `wole cache stats`
+pub(crate) fn handle_stats(json: bool, output_mode: OutputMode) -> anyhow::Result<()> {
+    let stats = ScanCache::open()?.stats()?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
+    if output_mode == OutputMode::Quiet {
+        return Ok(());
+    }
+
+    let last_scan = CacheSummary {
+        size_bytes: stats.size_bytes,
+        last_scan: stats.last_scan,
+    }
+    .age_label();
+    println!();
+    println!("{}", Theme::header("Scan Cache"));
+    println!("{}", Theme::divider_bold(60));
+    println!("  Location        {}", stats.path.display());
+    println!(
+        "  Size on disk    {}",
+        bytesize::to_string(stats.size_bytes, false)
+    );
+    println!("  Last scan       {}", last_scan);
+    println!(
+        "  Files tracked   {} ({})",
+        stats.file_records,
+        bytesize::to_string(stats.tracked_bytes, false)
+    );
+    println!("  Scan sessions   {}", stats.scan_sessions);
+    println!("  Snapshots       {}", stats.snapshots);
+    println!("  Folder sizes    {}", stats.dir_sizes);
+    println!("  Search index    {} files", stats.index_entries);
+    println!(
+        "  {}",
+        Theme::muted(&format!("Schema version {}", stats.schema_version))
+    );
+    println!();
+    Ok(())
+}
+
+/// `wole cache vacuum` - a damaged database is rebuilt instead of compacted
+pub(crate) fn handle_vacuum(output_mode: OutputMode) -> anyhow::Result<()> {
+    if crate::audit::is_enabled() {
+        crate::audit::note("vacuum the scan cache");
+        if output_mode != OutputMode::Quiet {
+            println!("{} Would compact the scan cache", Theme::muted("Audit"));
+        }
+        return Ok(());
+    }
+
+    let mut cache = ScanCache::open()?;
+    if let Err(e) = cache.check_integrity() {
+        drop(cache);
+        ScanCache::reset()?;
+        if output_mode != OutputMode::Quiet {
+            println!(
+                "{} {}. The scan cache was rebuilt; the next scan starts from scratch.",
+                Theme::warning("Rebuilt"),
+                e
+            );
+        }
+        return Ok(());
+    }
+
+    let (before, after) = cache.vacuum()?;
+    if output_mode != OutputMode::Quiet {
+        println!(
+            "{} Scan cache compacted: {} -> {}",
+            Theme::success("OK"),
+            bytesize::to_string(before, false),
+            bytesize::to_string(after, false)
+        );
+    }
+    Ok(())
+}
+
+/// `wole cache reset`
+pub(crate) fn handle_reset(yes: bool, output_mode: OutputMode) -> anyhow::Result<()> {
+    if !yes
+        && !confirm(
+            "Delete the scan cache (file signatures, scan history, snapshots and search index)?",
+        )
+    {
+        println!("Reset cancelled.");
+        return Ok(());
+    }
+    if crate::audit::is_enabled() {
+        crate::audit::note("delete the scan cache");
+        if output_mode != OutputMode::Quiet {
+            println!("{} Would delete the scan cache", Theme::muted("Audit"));
+        }
+        return Ok(());
+    }
+
+    ScanCache::reset()?;
+    if output_mode != OutputMode::Quiet {
+        println!(
+            "{} Scan cache deleted; the next scan starts from scratch",
+            Theme::success("OK")
+        );
+    }
+    Ok(())
+}
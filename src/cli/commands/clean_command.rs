@@ -2,13 +2,19 @@
 //!
 //! This module owns and handles the "wole clean" command behavior.
 
+use crate::api::{no_categories, Category};
+use crate::cancel::CancellationToken;
 use crate::cleaner;
 use crate::cli::ScanOptions;
 use crate::config::Config;
 use crate::output::{self, OutputMode};
 use crate::scanner;
+use crate::select::Selector;
 use crate::size;
 use crate::theme::Theme;
+use crate::users::ScanScope;
+use std::collections::HashSet;
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 // Helper function to format numbers (copied from output.rs for local use)
@@ -65,17 +71,48 @@ pub(crate) fn handle_clean(
     applications: bool,
     windows_update: bool,
     event_logs: bool,
+    thumbnails: bool,
+    crash_dumps: bool,
+    logs: bool,
+    game_caches: bool,
+    toolchain_caches: bool,
+    python_envs: bool,
+    ml_models: bool,
+    email: bool,
+    creative_caches: bool,
+    device_backups: bool,
+    git_ignored: bool,
     path: Option<PathBuf>,
+    repo: bool,
     json: bool,
     yes: bool,
     project_age: u64,
     min_age: u64,
     min_size: String,
+    size_budget: Option<String>,
     exclude: Vec<String>,
+    select: Option<String>,
+    scope: Option<String>,
+    from_stdin: bool,
     permanent: bool,
     dry_run: bool,
     output_mode: OutputMode,
 ) -> anyhow::Result<()> {
+    if from_stdin {
+        return clean_from_stdin(&exclude, yes, permanent, dry_run, output_mode);
+    }
+
+    let selector = select
+        .as_deref()
+        .map(Selector::parse)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("Invalid --select expression: {}", e))?;
+    // Categories a --select expression names (all of them if it names none)
+    let selected_categories = selector
+        .as_ref()
+        .map(|selector| selector.categories())
+        .filter(|categories| categories.len() < Category::ALL.len());
+
     // --all enables all categories
     let (
         cache,
@@ -93,10 +130,21 @@ pub(crate) fn handle_clean(
         duplicates,
         windows_update,
         event_logs,
+        thumbnails,
+        crash_dumps,
+        logs,
+        game_caches,
+        toolchain_caches,
+        python_envs,
+        ml_models,
+        email,
+        creative_caches,
+        device_backups,
+        git_ignored,
     ) = if all {
         (
             true, true, true, true, true, true, true, true, true, true, true, true, true, true,
-            true,
+            true, true, true, true, true, true, true, true, true, true, true, true,
         )
     } else if !cache
         && !app_cache
@@ -113,11 +161,69 @@ pub(crate) fn handle_clean(
         && !applications
         && !windows_update
         && !event_logs
+        && !thumbnails
+        && !crash_dumps
+        && !logs
+        && !game_caches
+        && !toolchain_caches
+        && !python_envs
+        && !ml_models
+        && !email
+        && !creative_caches
+        && !device_backups
+        && !git_ignored
     {
-        // No categories specified - show help message
-        eprintln!("No categories specified. Use --all or specify categories like --cache, --app-cache, --temp, --build");
-        eprintln!("Run 'wole clean --help' for more information.");
-        return Ok(());
+        if let Some(categories) = &selected_categories {
+            // --select on its own scans the categories its expression names
+            let mut options = no_categories(&Config::default(), 0, ScanScope::default());
+            for category in categories {
+                category.enable(&mut options);
+            }
+            (
+                options.cache,
+                options.app_cache,
+                options.temp,
+                options.trash,
+                options.build,
+                options.downloads,
+                options.large,
+                options.old,
+                options.applications,
+                options.browser,
+                options.system,
+                options.empty,
+                options.duplicates,
+                options.windows_update,
+                options.event_logs,
+                options.thumbnails,
+                options.crash_dumps,
+                options.logs,
+                options.game_caches,
+                options.toolchain_caches,
+                options.python_envs,
+                options.ml_models,
+                options.email,
+                options.creative_caches,
+                options.device_backups,
+                options.git_ignored,
+            )
+        } else if repo {
+            // --repo on its own cleans the repository's build artifacts, Python envs
+            // and ignored files
+            (
+                false, false, false, false, true, false, false, false, false, false, false, false,
+                false, false, false, false, false, false, false, false, true, false, false, false,
+                false, true,
+            )
+        } else {
+            // No categories specified - show help message
+            eprintln!("No categories specified. Use --all or specify categories like --cache, --app-cache, --temp, --build");
+            if selector.is_some() {
+                eprintln!("--select without category flags needs a category condition, e.g. \"category=build && size>500MB\"");
+            }
+            eprintln!("Run 'wole clean --help' for more information.");
+            return Ok(());
+        }
     } else {
         (
             cache,
@@ -135,15 +241,37 @@ pub(crate) fn handle_clean(
             duplicates,
             windows_update,
             event_logs,
+            thumbnails,
+            crash_dumps,
+            logs,
+            game_caches,
+            toolchain_caches,
+            python_envs,
+            ml_models,
+            email,
+            creative_caches,
+            device_backups,
+            git_ignored,
         )
     };
 
-    let scan_path = path.unwrap_or_else(|| {
-        directories::UserDirs::new()
-            .expect("Failed to get user directory")
-            .home_dir()
-            .to_path_buf()
-    });
+    let repo_root = if repo {
+        Some(crate::git::current_repo_root().ok_or_else(|| {
+            anyhow::anyhow!("--repo: the current directory is not inside a git repository")
+        })?)
+    } else {
+        None
+    };
+
+    let scan_path = match &repo_root {
+        Some(root) => root.clone(),
+        None => path.unwrap_or_else(|| {
+            directories::UserDirs::new()
+                .expect("Failed to get user directory")
+                .home_dir()
+                .to_path_buf()
+        }),
+    };
 
     // Load config first
     let mut config = Config::load();
@@ -158,10 +286,45 @@ pub(crate) fn handle_clean(
                 / (1024 * 1024),
         ), // Convert bytes to MB for config
     );
+    // --size-budget overrides [performance] scan_size_budget_mb
+    if let Some(ref budget) = size_budget {
+        let bytes = size::parse_size(budget)
+            .map_err(|e| anyhow::anyhow!("Invalid size format '{}': {}", budget, e))?;
+        config.performance.scan_size_budget_mb = bytes.div_ceil(1024 * 1024);
+    }
+    if repo_root.is_some() {
+        // The repo was picked explicitly, so its artifacts count even while it's being worked on
+        config.thresholds.project_age_days = 0;
+    }
+    crate::throttle::apply(&config.performance);
+    crate::cleaner::apply_safety_settings(&config.safety);
+    crate::history::checksums::set_enabled(config.history.record_checksums);
 
     // Merge CLI exclusions
     config.exclusions.patterns.extend(exclude.iter().cloned());
 
+    // --scope overrides [paths] scope
+    let scope = crate::users::resolve_scope(scope.as_deref(), &config)?;
+
+    // Network shares are slower to scan and have no Recycle Bin
+    if output_mode != OutputMode::Quiet {
+        if let Some((notice, latency)) = crate::network::scan_notice(&scan_path) {
+            let notice = if permanent {
+                format!(
+                    "Network share ({})",
+                    crate::network::describe_latency(latency)
+                )
+            } else {
+                notice
+            };
+            if json {
+                eprintln!("{}", notice);
+            } else {
+                println!("{}", Theme::warning(&notice));
+            }
+        }
+    }
+
     let mut scan_cache = if config.cache.enabled {
         match crate::scan_cache::ScanCache::open() {
             Ok(cache) => Some(cache),
@@ -254,19 +417,38 @@ pub(crate) fn handle_clean(
         duplicates,
         windows_update,
         event_logs,
+        thumbnails,
+        crash_dumps,
+        logs,
+        game_caches,
+        toolchain_caches,
+        python_envs,
+        ml_models,
+        email,
+        creative_caches,
+        device_backups,
+        git_ignored,
         project_age_days: config.thresholds.project_age_days,
         min_age_days: config.thresholds.min_age_days,
         min_size_bytes,
+        scope,
     };
 
-    let results = scanner::scan_all(
+    let mut results = scanner::run_scan(
         &scan_path,
         scan_options.clone(),
         output_mode,
         &config,
-        scan_cache.as_mut(),
+        &mut scan_cache,
     )?;
 
+    if let Some(root) = &repo_root {
+        scanner::retain_within(&mut results, root);
+    }
+    if let Some(selector) = &selector {
+        selector.retain(&mut results);
+    }
+
     if json {
         output::print_json(&results)?;
     } else {
@@ -317,7 +499,72 @@ pub(crate) fn handle_clean(
         }
     }
 
-    cleaner::clean_all(&results, yes, output_mode, permanent, dry_run)?;
+    cleaner::clean_all(
+        &results,
+        yes,
+        output_mode,
+        permanent,
+        dry_run,
+        &CancellationToken::new(),
+    )?;
+
+    Ok(())
+}
+
+/// `wole clean --from-stdin`: delete the piped paths and nothing else
+fn clean_from_stdin(
+    exclude: &[String],
+    yes: bool,
+    permanent: bool,
+    dry_run: bool,
+    output_mode: OutputMode,
+) -> anyhow::Result<()> {
+    if !yes && !dry_run {
+        anyhow::bail!(
+            "--from-stdin needs --yes (or --dry-run): the paths arrive on stdin, so there is no prompt"
+        );
+    }
+    let mut input = Vec::new();
+    std::io::stdin().read_to_end(&mut input)?;
+
+    let mut config = Config::load();
+    config.exclusions.patterns.extend(exclude.iter().cloned());
+    crate::throttle::apply(&config.performance);
+    crate::cleaner::apply_safety_settings(&config.safety);
+    crate::history::checksums::set_enabled(config.history.record_checksums);
 
+    // Exclusions (the user's and the machine policy's) are checked here since
+    // no scan filtered these paths; system paths are refused by the cleaner
+    let mut seen = HashSet::new();
+    let mut paths = Vec::new();
+    for path in output::parse_path_list(&input) {
+        let skipped = if !path.is_absolute() {
+            Some("not an absolute path")
+        } else if config.is_excluded(&path) {
+            Some("excluded")
+        } else {
+            None
+        };
+        match skipped {
+            Some(reason) => {
+                if output_mode != OutputMode::Quiet {
+                    eprintln!("[SKIPPED] {} ({})", path.display(), reason);
+                }
+            }
+            None => {
+                if seen.insert(path.clone()) {
+                    paths.push(path);
+                }
+            }
+        }
+    }
+
+    cleaner::clean_listed(
+        &paths,
+        output_mode,
+        permanent,
+        dry_run,
+        &CancellationToken::new(),
+    )?;
     Ok(())
 }
@@ -20,7 +20,32 @@ pub(crate) fn handle_config(
         println!("Thresholds:");
         println!("  Project age: {} days", config.thresholds.project_age_days);
         println!("  Min age: {} days", config.thresholds.min_age_days);
+        println!(
+            "  Old Files time basis: {}",
+            crate::categories::old::TimeBasis::of(&config).name()
+        );
+        if let Some(warning) = crate::categories::old::time_basis_warning(&config) {
+            println!("  {}", Theme::warning(warning));
+        }
         println!("  Min size: {} MB", config.thresholds.min_size_mb);
+        println!(
+            "  Large file tiers: {}",
+            config
+                .thresholds
+                .large_file_tiers()
+                .into_iter()
+                .map(crate::categories::large::tier_label)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        println!(
+            "  Crash dump age: {} days",
+            config.thresholds.crash_dump_age_days
+        );
+        println!(
+            "  Toolchain cache age: {} days",
+            config.thresholds.toolchain_cache_age_days
+        );
         println!();
         println!("Paths:");
         if config.paths.scan_roots.is_empty() {
@@ -30,6 +55,9 @@ pub(crate) fn handle_config(
                 println!("  {}", path);
             }
         }
+        println!("  Scope: {}", config.paths.scope);
+        println!();
+        println!("Policy: {}", crate::policy::current().describe());
         println!();
         println!("Exclusions:");
         if config.exclusions.patterns.is_empty() {
@@ -49,6 +77,11 @@ pub(crate) fn handle_config(
         println!("  Output mode: {}", config.ui.output_mode);
         println!("  Animations: {}", config.ui.animations);
         println!("  Refresh rate: {} ms", config.ui.refresh_rate_ms);
+        println!(
+            "  Auto-select aggressiveness: {}",
+            config.ui.auto_select_aggressiveness
+        );
+        println!("  Language: {}", config.ui.language);
         println!();
         println!("Safety Settings:");
         println!("  Always confirm: {}", config.safety.always_confirm);
@@ -60,6 +93,15 @@ pub(crate) fn handle_config(
         );
         println!("  Skip locked files: {}", config.safety.skip_locked_files);
         println!("  Dry run default: {}", config.safety.dry_run_default);
+        println!(
+            "  Clear hidden/system attributes: {}",
+            config.safety.clear_hidden_system
+        );
+        println!(
+            "  Take ownership when elevated: {}",
+            config.safety.take_ownership
+        );
+        println!("  Audit mode (read-only): {}", config.safety.audit);
         println!();
         println!("Performance Settings:");
         println!(
@@ -71,6 +113,41 @@ pub(crate) fn handle_config(
             "  Parallel scanning: {}",
             config.performance.parallel_scanning
         );
+        println!(
+            "  Max threads: {} (0 = auto)",
+            config.performance.max_threads
+        );
+        println!("  I/O priority: {}", config.performance.io_priority);
+        println!(
+            "  Delete batch size: {} (0 = one batch)",
+            config.performance.delete_batch_size
+        );
+        let mut overrides: Vec<_> = config.performance.category_overrides.iter().collect();
+        overrides.sort_by(|a, b| a.0.cmp(b.0));
+        for (category, overrides) in overrides {
+            println!("    {}: {}", category, describe_overrides(overrides));
+        }
+        println!(
+            "  Clean workers: {} (0 = auto)",
+            config.performance.clean_workers
+        );
+        println!(
+            "  Delete speed limit: {} files/s, {} MB/s (0 = unlimited)",
+            config.performance.delete_files_per_sec, config.performance.delete_mb_per_sec
+        );
+        println!(
+            "  Category time budget: {}s (0 = never ask)",
+            config.performance.category_time_budget_secs
+        );
+        let mut budgets: Vec<_> = config.performance.category_time_budgets.iter().collect();
+        budgets.sort();
+        for (category, secs) in budgets {
+            println!("    {}: {}s", category, secs);
+        }
+        println!(
+            "  Scan size budget: {} MB (0 = scan everything)",
+            config.performance.scan_size_budget_mb
+        );
         println!();
         println!("History Settings:");
         println!("  Enabled: {}", config.history.enabled);
@@ -82,16 +159,38 @@ pub(crate) fn handle_config(
             "  Max age: {} days (0 = forever)",
             config.history.max_age_days
         );
+        println!("  Tamper-evident: {}", config.history.tamper_evident);
         println!();
         println!("Cache Settings:");
         println!("  Enabled: {}", config.cache.enabled);
         println!("  Full disk baseline: {}", config.cache.full_disk_baseline);
+        println!("  Search index: {}", config.cache.search_index);
         println!("  Max age: {} days", config.cache.max_age_days);
         println!(
             "  Content hash threshold: {}",
             bytesize::to_string(config.cache.content_hash_threshold_bytes, false)
         );
         println!();
+        println!("Plugin Settings:");
+        println!("  Enabled: {}", config.plugins.enabled);
+        println!("  Timeout: {}s", config.plugins.timeout_secs);
+        if let Ok(dir) = crate::plugin::plugins_dir() {
+            println!("  Directory: {}", dir.display());
+        }
+        println!();
+        println!("Optimize Settings:");
+        println!(
+            "  Auto-trim standby list: {}",
+            config.optimize.auto_trim_standby
+        );
+        println!("  Trim threshold: {} MB", config.optimize.trim_threshold_mb);
+        println!("  Trim cooldown: {}s", config.optimize.trim_cooldown_secs);
+        println!();
+        println!("Temperature Settings:");
+        println!("  Warning: {:.0}°C", config.temperature.warning_celsius);
+        println!("  Critical: {:.0}°C", config.temperature.critical_celsius);
+        println!("  Log readings: {}", config.temperature.log);
+        println!();
         if let Ok(path) = Config::config_path() {
             println!("Config file: {}", path.display());
         }
@@ -133,7 +232,32 @@ pub(crate) fn handle_config(
         println!("Thresholds:");
         println!("  Project age: {} days", config.thresholds.project_age_days);
         println!("  Min age: {} days", config.thresholds.min_age_days);
+        println!(
+            "  Old Files time basis: {}",
+            crate::categories::old::TimeBasis::of(&config).name()
+        );
+        if let Some(warning) = crate::categories::old::time_basis_warning(&config) {
+            println!("  {}", Theme::warning(warning));
+        }
         println!("  Min size: {} MB", config.thresholds.min_size_mb);
+        println!(
+            "  Large file tiers: {}",
+            config
+                .thresholds
+                .large_file_tiers()
+                .into_iter()
+                .map(crate::categories::large::tier_label)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        println!(
+            "  Crash dump age: {} days",
+            config.thresholds.crash_dump_age_days
+        );
+        println!(
+            "  Toolchain cache age: {} days",
+            config.thresholds.toolchain_cache_age_days
+        );
         println!();
         println!("Paths:");
         if config.paths.scan_roots.is_empty() {
@@ -143,6 +267,9 @@ pub(crate) fn handle_config(
                 println!("  {}", path);
             }
         }
+        println!("  Scope: {}", config.paths.scope);
+        println!();
+        println!("Policy: {}", crate::policy::current().describe());
         println!();
         println!("Exclusions:");
         if config.exclusions.patterns.is_empty() {
@@ -162,6 +289,11 @@ pub(crate) fn handle_config(
         println!("  Output mode: {}", config.ui.output_mode);
         println!("  Animations: {}", config.ui.animations);
         println!("  Refresh rate: {} ms", config.ui.refresh_rate_ms);
+        println!(
+            "  Auto-select aggressiveness: {}",
+            config.ui.auto_select_aggressiveness
+        );
+        println!("  Language: {}", config.ui.language);
         println!();
         println!("Safety Settings:");
         println!("  Always confirm: {}", config.safety.always_confirm);
@@ -173,6 +305,15 @@ pub(crate) fn handle_config(
         );
         println!("  Skip locked files: {}", config.safety.skip_locked_files);
         println!("  Dry run default: {}", config.safety.dry_run_default);
+        println!(
+            "  Clear hidden/system attributes: {}",
+            config.safety.clear_hidden_system
+        );
+        println!(
+            "  Take ownership when elevated: {}",
+            config.safety.take_ownership
+        );
+        println!("  Audit mode (read-only): {}", config.safety.audit);
         println!();
         println!("Performance Settings:");
         println!(
@@ -184,6 +325,41 @@ pub(crate) fn handle_config(
             "  Parallel scanning: {}",
             config.performance.parallel_scanning
         );
+        println!(
+            "  Max threads: {} (0 = auto)",
+            config.performance.max_threads
+        );
+        println!("  I/O priority: {}", config.performance.io_priority);
+        println!(
+            "  Delete batch size: {} (0 = one batch)",
+            config.performance.delete_batch_size
+        );
+        let mut overrides: Vec<_> = config.performance.category_overrides.iter().collect();
+        overrides.sort_by(|a, b| a.0.cmp(b.0));
+        for (category, overrides) in overrides {
+            println!("    {}: {}", category, describe_overrides(overrides));
+        }
+        println!(
+            "  Clean workers: {} (0 = auto)",
+            config.performance.clean_workers
+        );
+        println!(
+            "  Delete speed limit: {} files/s, {} MB/s (0 = unlimited)",
+            config.performance.delete_files_per_sec, config.performance.delete_mb_per_sec
+        );
+        println!(
+            "  Category time budget: {}s (0 = never ask)",
+            config.performance.category_time_budget_secs
+        );
+        let mut budgets: Vec<_> = config.performance.category_time_budgets.iter().collect();
+        budgets.sort();
+        for (category, secs) in budgets {
+            println!("    {}: {}s", category, secs);
+        }
+        println!(
+            "  Scan size budget: {} MB (0 = scan everything)",
+            config.performance.scan_size_budget_mb
+        );
         println!();
         println!("History Settings:");
         println!("  Enabled: {}", config.history.enabled);
@@ -195,19 +371,60 @@ pub(crate) fn handle_config(
             "  Max age: {} days (0 = forever)",
             config.history.max_age_days
         );
+        println!("  Tamper-evident: {}", config.history.tamper_evident);
         println!();
         println!("Cache Settings:");
         println!("  Enabled: {}", config.cache.enabled);
         println!("  Full disk baseline: {}", config.cache.full_disk_baseline);
+        println!("  Search index: {}", config.cache.search_index);
         println!("  Max age: {} days", config.cache.max_age_days);
         println!(
             "  Content hash threshold: {}",
             bytesize::to_string(config.cache.content_hash_threshold_bytes, false)
         );
         println!();
+        println!("Plugin Settings:");
+        println!("  Enabled: {}", config.plugins.enabled);
+        println!("  Timeout: {}s", config.plugins.timeout_secs);
+        if let Ok(dir) = crate::plugin::plugins_dir() {
+            println!("  Directory: {}", dir.display());
+        }
+        println!();
+        println!("Optimize Settings:");
+        println!(
+            "  Auto-trim standby list: {}",
+            config.optimize.auto_trim_standby
+        );
+        println!("  Trim threshold: {} MB", config.optimize.trim_threshold_mb);
+        println!("  Trim cooldown: {}s", config.optimize.trim_cooldown_secs);
+        println!();
+        println!("Temperature Settings:");
+        println!("  Warning: {:.0}°C", config.temperature.warning_celsius);
+        println!("  Critical: {:.0}°C", config.temperature.critical_celsius);
+        println!("  Log readings: {}", config.temperature.log);
+        println!();
         if let Ok(path) = Config::config_path() {
             println!("Config file: {}", path.display());
         }
     }
     Ok(())
 }
+
+/// "max_threads = 2, io_priority = low" for a `[performance.category_overrides]` entry
+fn describe_overrides(overrides: &crate::config::CategoryPerformance) -> String {
+    let mut parts = Vec::new();
+    if let Some(threads) = overrides.max_threads {
+        parts.push(format!("max_threads = {}", threads));
+    }
+    if let Some(priority) = &overrides.io_priority {
+        parts.push(format!("io_priority = {}", priority));
+    }
+    if let Some(size) = overrides.delete_batch_size {
+        parts.push(format!("delete_batch_size = {}", size));
+    }
+    if parts.is_empty() {
+        "no overrides".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
@@ -0,0 +1,87 @@
+//! Diff command feature.
+//!
+//! This module owns and handles the "wole diff" command behavior.
+
+use crate::output::OutputMode;
+use crate::scan_cache::ScanCache;
+use crate::theme::Theme;
+
+pub(crate) fn handle_diff(limit: usize, json: bool, output_mode: OutputMode) -> anyhow::Result<()> {
+    let cache = ScanCache::open()?;
+    let Some(diff) = cache.diff_latest_snapshots(limit)? else {
+        if json {
+            println!("null");
+        } else if output_mode != OutputMode::Quiet {
+            println!(
+                "{}",
+                Theme::warning_msg(
+                    "Need at least two scans to compare. Run 'wole scan' again later."
+                )
+            );
+        }
+        return Ok(());
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+        return Ok(());
+    }
+
+    if output_mode == OutputMode::Quiet {
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", Theme::header("What Changed"));
+    println!("{}", Theme::divider_bold(60));
+    println!(
+        "  {} scan #{} ({}) → scan #{} ({})",
+        Theme::muted("→"),
+        diff.from_scan,
+        diff.from_time
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M"),
+        diff.to_scan,
+        diff.to_time
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+    );
+    println!();
+
+    if diff.changes.is_empty() {
+        println!(
+            "  {}",
+            Theme::success("No size changes since the last scan")
+        );
+        println!();
+        return Ok(());
+    }
+
+    println!(
+        "  {:>12} {:>12} {:>12}  Folder",
+        "Change", "Before", "After"
+    );
+    println!("{}", Theme::divider(60));
+    for change in &diff.changes {
+        let delta = format!("{:>12}", change.delta_human());
+        let delta = if change.delta() > 0 {
+            Theme::warning(&delta)
+        } else {
+            Theme::success(&delta)
+        };
+        println!(
+            "  {} {:>12} {:>12}  {}",
+            delta,
+            bytesize::to_string(change.old_size, false),
+            bytesize::to_string(change.new_size, false),
+            change.path.display()
+        );
+    }
+    println!();
+    println!(
+        "{}",
+        Theme::muted("Sizes cover what wole scans (enabled categories), not the whole disk.")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+//! History command feature.
+//!
+//! This module owns and handles the "wole history" command behavior.
+
+use crate::history::verify_chain;
+use crate::output::OutputMode;
+use crate::theme::Theme;
+
+/// `wole history verify` - fails (non-zero exit) when the chain is broken
+pub(crate) fn handle_verify(json: bool, output_mode: OutputMode) -> anyhow::Result<()> {
+    let report = verify_chain()?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else if output_mode != OutputMode::Quiet {
+        println!();
+        println!("{}", Theme::header("History Verification"));
+        println!("{}", Theme::divider_bold(60));
+        println!(
+            "  {} sessions, {} sealed records, {} unsealed",
+            report.sessions, report.sealed, report.unsealed
+        );
+        if report.unsealed > 0 {
+            println!(
+                "  {}",
+                Theme::muted(
+                    "Unsealed records were saved with history.tamper_evident off and are not covered"
+                )
+            );
+        }
+        println!();
+
+        if report.sealed == 0 && report.is_intact() {
+            println!(
+                "  {}",
+                Theme::warning_msg(
+                    "No sealed records yet. Set history.tamper_evident = true in the config."
+                )
+            );
+        } else if report.is_intact() {
+            println!("  {}", Theme::success("✓ History chain is intact"));
+        } else {
+            for issue in &report.issues {
+                println!("  {} {}", Theme::error("✗"), issue);
+            }
+        }
+        println!();
+    }
+
+    if !report.is_intact() {
+        anyhow::bail!(
+            "History verification failed: {} issue(s) found",
+            report.issues.len()
+        );
+    }
+    Ok(())
+}
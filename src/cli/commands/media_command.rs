@@ -0,0 +1,227 @@
+//! Media command feature.
+//!
+//! This module owns and handles the "wole media" command behavior.
+
+use crate::cancel::CancellationToken;
+use crate::config::Config;
+use crate::drive::{self, RemovableVolume};
+use crate::output::OutputMode;
+use crate::removable::{self, MediaFinding, MediaJunk};
+use crate::theme::Theme;
+use serde::Serialize;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+/// One volume in `wole media list --json`
+#[derive(Serialize)]
+struct VolumeReport<'a> {
+    #[serde(flatten)]
+    volume: &'a RemovableVolume,
+    junk_bytes: u64,
+    findings: &'a [MediaFinding],
+}
+
+/// Parse `--only thumbnails,mac` (all kinds when empty)
+fn parse_kinds(only: &[String]) -> anyhow::Result<Vec<MediaJunk>> {
+    if only.is_empty() {
+        return Ok(MediaJunk::ALL.to_vec());
+    }
+    only.iter()
+        .map(|id| {
+            MediaJunk::from_id(id).ok_or_else(|| {
+                let known: Vec<&str> = MediaJunk::ALL.iter().map(|k| k.id()).collect();
+                anyhow::anyhow!("Unknown media category '{}' (use {})", id, known.join(", "))
+            })
+        })
+        .collect()
+}
+
+/// The volume to clean: the one given, or the only removable volume mounted
+fn pick_volume(volume: Option<PathBuf>) -> anyhow::Result<PathBuf> {
+    if let Some(volume) = volume {
+        if !drive::is_removable_path(&volume) {
+            anyhow::bail!(
+                "{} is not on a removable drive; use `wole clean` for fixed drives",
+                volume.display()
+            );
+        }
+        return Ok(volume);
+    }
+    let volumes = drive::removable_volumes();
+    match volumes.as_slice() {
+        [] => anyhow::bail!("No USB stick or SD card is mounted"),
+        [only] => Ok(only.mount.clone()),
+        _ => {
+            let mounts: Vec<String> = volumes
+                .iter()
+                .map(|v| v.mount.display().to_string())
+                .collect();
+            anyhow::bail!(
+                "Several removable drives are mounted; pass one of: {}",
+                mounts.join(", ")
+            )
+        }
+    }
+}
+
+fn print_findings(findings: &[MediaFinding]) {
+    if findings.is_empty() {
+        println!("    {}", Theme::muted("Nothing to clean"));
+        return;
+    }
+    for finding in findings {
+        println!(
+            "    {:<20} {:>6} items  {:>10}",
+            finding.kind.label(),
+            finding.items.len(),
+            bytesize::to_string(finding.size_bytes, false)
+        );
+    }
+}
+
+/// `wole media list`
+pub(crate) fn handle_list(json: bool, output_mode: OutputMode) -> anyhow::Result<()> {
+    let config = Config::load();
+    let volumes = drive::removable_volumes();
+    let scanned: Vec<(RemovableVolume, Vec<MediaFinding>)> = volumes
+        .into_iter()
+        .map(|volume| {
+            let findings = removable::scan_volume(&volume.mount, &MediaJunk::ALL, &config);
+            (volume, findings)
+        })
+        .collect();
+
+    if json {
+        let reports: Vec<VolumeReport> = scanned
+            .iter()
+            .map(|(volume, findings)| VolumeReport {
+                volume,
+                junk_bytes: findings.iter().map(|f| f.size_bytes).sum(),
+                findings,
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+        return Ok(());
+    }
+    if output_mode == OutputMode::Quiet {
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", Theme::header("Removable Drives"));
+    println!("{}", Theme::divider_bold(60));
+    if scanned.is_empty() {
+        println!("  {}", Theme::muted("No USB stick or SD card is mounted"));
+        println!();
+        return Ok(());
+    }
+    for (volume, findings) in &scanned {
+        println!();
+        println!(
+            "  {}  {} free of {}",
+            Theme::primary(&volume.describe()),
+            bytesize::to_string(volume.available_bytes, false),
+            bytesize::to_string(volume.total_bytes, false)
+        );
+        print_findings(findings);
+    }
+    println!();
+    println!(
+        "  {}",
+        Theme::muted("Run `wole media clean <DRIVE>` to delete these permanently")
+    );
+    println!();
+    Ok(())
+}
+
+/// `wole media clean [VOLUME]` - always permanent, never the Recycle Bin
+pub(crate) fn handle_clean(
+    volume: Option<PathBuf>,
+    only: Vec<String>,
+    dry_run: bool,
+    yes: bool,
+    output_mode: OutputMode,
+) -> anyhow::Result<()> {
+    let kinds = parse_kinds(&only)?;
+    let root = pick_volume(volume)?;
+    let config = Config::load();
+    crate::history::checksums::set_enabled(config.history.record_checksums);
+    let findings = removable::scan_volume(&root, &kinds, &config);
+    let total: u64 = findings.iter().map(|f| f.size_bytes).sum();
+    let items: usize = findings.iter().map(|f| f.items.len()).sum();
+
+    if output_mode != OutputMode::Quiet {
+        println!();
+        println!(
+            "{}",
+            Theme::header(&format!("Removable drive {}", root.display()))
+        );
+        println!("{}", Theme::divider_bold(60));
+        print_findings(&findings);
+        println!();
+    }
+    if items == 0 {
+        return Ok(());
+    }
+    if dry_run {
+        if output_mode != OutputMode::Quiet {
+            println!(
+                "[DRY RUN] {} items would be deleted permanently ({})",
+                items,
+                bytesize::to_string(total, false)
+            );
+        }
+        return Ok(());
+    }
+
+    if !yes {
+        print!(
+            "Permanently delete {} items ({})? Removable drives have no usable Recycle Bin. [y/N]: ",
+            items,
+            bytesize::to_string(total, false)
+        );
+        io::stdout().flush().ok();
+        let mut input = String::new();
+        io::stdin().lock().read_line(&mut input)?;
+        if !matches!(input.trim().to_lowercase().as_str(), "y" | "yes") {
+            println!("Cancelled.");
+            return Ok(());
+        }
+    }
+
+    let summary = removable::clean_findings(&findings, &CancellationToken::new());
+    if output_mode == OutputMode::Quiet {
+        return Ok(());
+    }
+    if crate::audit::is_enabled() {
+        println!(
+            "{} Would permanently delete {} items ({})",
+            Theme::muted("Audit"),
+            summary.cleaned,
+            bytesize::to_string(summary.cleaned_bytes, false)
+        );
+        return Ok(());
+    }
+    if summary.errors > 0 {
+        println!(
+            "[WARNING] Cleaned {} items ({}), {} errors",
+            summary.cleaned,
+            bytesize::to_string(summary.cleaned_bytes, false),
+            Theme::error(&summary.errors.to_string())
+        );
+    } else {
+        println!(
+            "{} Cleaned {} items ({})",
+            Theme::success("OK"),
+            summary.cleaned,
+            bytesize::to_string(summary.cleaned_bytes, false)
+        );
+    }
+    if let Some(log_path) = &summary.log_path {
+        println!(
+            "  {}",
+            Theme::muted(&format!("Deletion log: {}", log_path.display()))
+        );
+    }
+    Ok(())
+}
@@ -3,12 +3,21 @@
 //! Each module owns one command feature.
 
 pub mod analyze_command;
+pub mod bench_command;
+pub mod cache_command;
 pub mod clean_command;
 pub mod config_command;
+pub mod diff_command;
+pub mod history_command;
+pub mod media_command;
+pub mod netcheck_command;
 pub mod optimize_command;
+pub mod recommend_command;
+pub mod registry_command;
 pub mod remove_command;
 pub mod restore_command;
 pub mod scan_command;
 pub mod startup_command;
 pub mod status_command;
 pub mod update_command;
+pub mod why_full_command;
@@ -0,0 +1,128 @@
+//! Netcheck command feature.
+//!
+//! This module owns and handles the "wole netcheck" command behavior: report
+//! hosts file entries, proxy settings and DNS overrides that look like
+//! leftovers, and revert them on request after backing them up.
+
+use crate::net_hygiene::{self, NetIssue};
+use crate::output::OutputMode;
+use crate::theme::Theme;
+
+pub(crate) fn handle_netcheck(
+    revert: Option<usize>,
+    revert_all: bool,
+    json: bool,
+    output_mode: OutputMode,
+) -> anyhow::Result<()> {
+    if !cfg!(windows) {
+        anyhow::bail!("Network checks are only available on Windows");
+    }
+
+    let show = !json && output_mode != OutputMode::Quiet;
+    let spinner = show.then(|| crate::progress::create_spinner("Checking network settings..."));
+    let issues = net_hygiene::check();
+    if let Some(spinner) = spinner {
+        crate::progress::finish_and_clear(&spinner);
+    }
+
+    let to_revert: Vec<&NetIssue> = if revert_all {
+        issues.iter().collect()
+    } else if let Some(n) = revert {
+        let issue = n
+            .checked_sub(1)
+            .and_then(|i| issues.get(i))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No issue {} - run `wole netcheck` to see the numbered list",
+                    n
+                )
+            })?;
+        vec![issue]
+    } else {
+        Vec::new()
+    };
+
+    if to_revert.is_empty() {
+        if json {
+            println!("{}", serde_json::to_string_pretty(&issues)?);
+        } else if show {
+            print_report(&issues);
+        }
+        return Ok(());
+    }
+
+    let mut reverted = Vec::new();
+    let mut failed = 0;
+    for issue in to_revert {
+        match net_hygiene::revert(issue) {
+            Ok(backup) => {
+                if show {
+                    println!(
+                        "{} {} {} - backup: {}",
+                        Theme::success("OK"),
+                        issue.label(),
+                        issue.summary(),
+                        backup.display()
+                    );
+                }
+                reverted.push(serde_json::json!({ "issue": issue, "backup": backup }));
+            }
+            Err(e) => {
+                failed += 1;
+                if show {
+                    println!(
+                        "{} {} {}: {}",
+                        Theme::error("FAILED"),
+                        issue.label(),
+                        issue.summary(),
+                        e
+                    );
+                }
+            }
+        }
+    }
+    if json {
+        println!("{}", serde_json::to_string_pretty(&reverted)?);
+    }
+    if failed > 0 {
+        anyhow::bail!("{} change(s) could not be reverted", failed);
+    }
+    Ok(())
+}
+
+fn print_report(issues: &[NetIssue]) {
+    println!();
+    println!("{}", Theme::header("Hosts file, proxy and DNS check"));
+    println!("{}", Theme::divider_bold(60));
+    println!();
+    if issues.is_empty() {
+        println!("{}", Theme::success("Nothing unusual found"));
+        println!();
+        return;
+    }
+    for (i, issue) in issues.iter().enumerate() {
+        println!(
+            "{} {} {}",
+            Theme::muted(&format!("{:>2}.", i + 1)),
+            Theme::primary(issue.label()),
+            Theme::value(&issue.summary())
+        );
+        println!("    {}", issue.reason());
+        println!(
+            "    {} {}",
+            Theme::muted("revert:"),
+            Theme::muted(issue.revert_action())
+        );
+    }
+    println!();
+    println!(
+        "Revert one with {} or all with {}.",
+        Theme::command("wole netcheck --revert N"),
+        Theme::command("wole netcheck --revert-all")
+    );
+    println!(
+        "{}",
+        Theme::muted("The previous state is backed up to %LOCALAPPDATA%\\wole\\backups first.")
+    );
+    println!();
+}
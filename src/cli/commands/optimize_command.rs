@@ -2,9 +2,11 @@
 //!
 //! This module owns and handles the "wole optimize" command behavior.
 
+use crate::compress::CompressionMode;
 use crate::optimize;
 use crate::output::OutputMode;
 use crate::theme::Theme;
+use std::path::PathBuf;
 
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn handle_optimize(
@@ -19,10 +21,31 @@ pub(crate) fn handle_optimize(
     bluetooth: bool,
     search: bool,
     explorer: bool,
+    spooler: bool,
+    search_index: bool,
+    font_cache: bool,
+    compress: Vec<PathBuf>,
+    compress_mode: Option<String>,
+    unblock: Option<Vec<PathBuf>>,
+    power_plan: Option<String>,
     dry_run: bool,
     yes: bool,
     output_mode: OutputMode,
 ) -> anyhow::Result<()> {
+    let compress_mode = match compress_mode.as_deref() {
+        Some(mode) => mode.parse::<CompressionMode>()?,
+        None => CompressionMode::default(),
+    };
+    // A bare --unblock means the Downloads folder
+    let unblock: Vec<PathBuf> = match unblock {
+        Some(paths) if paths.is_empty() => directories::UserDirs::new()
+            .and_then(|dirs| dirs.download_dir().map(|d| d.to_path_buf()))
+            .into_iter()
+            .collect(),
+        Some(paths) => paths,
+        None => Vec::new(),
+    };
+
     // If no options specified, default to --all
     let all = if !all
         && !dns
@@ -35,6 +58,12 @@ pub(crate) fn handle_optimize(
         && !bluetooth
         && !search
         && !explorer
+        && !spooler
+        && !search_index
+        && !font_cache
+        && compress.is_empty()
+        && unblock.is_empty()
+        && power_plan.is_none()
     {
         if output_mode != OutputMode::Quiet {
             println!();
@@ -75,6 +104,13 @@ pub(crate) fn handle_optimize(
         bluetooth,
         search,
         explorer,
+        spooler,
+        search_index,
+        font_cache,
+        &compress,
+        compress_mode,
+        &unblock,
+        power_plan.as_deref(),
         dry_run,
         yes,
         output_mode,
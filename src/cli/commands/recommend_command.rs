@@ -0,0 +1,49 @@
+//! Recommend command feature.
+//!
+//! This module owns and handles the "wole recommend" command behavior.
+
+use crate::api::Category;
+use crate::output::OutputMode;
+use crate::recommend::{recommend, RecommendInput};
+use crate::scan_cache::ScanCache;
+use crate::theme::Theme;
+
+pub(crate) fn handle_recommend(json: bool, output_mode: OutputMode) -> anyhow::Result<()> {
+    // The scan cache remembers which categories the last scan covered
+    let scanned = ScanCache::open()
+        .ok()
+        .and_then(|cache| cache.get_last_scan().ok().flatten())
+        .map(|session| {
+            session
+                .categories
+                .iter()
+                .filter_map(|id| Category::from_id(id))
+                .collect::<Vec<_>>()
+        });
+    let recommendations = recommend(&RecommendInput::gather(scanned, Vec::new()));
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&recommendations)?);
+        return Ok(());
+    }
+
+    if output_mode == OutputMode::Quiet {
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", Theme::header("What's Next"));
+    println!("{}", Theme::divider_bold(60));
+    if recommendations.is_empty() {
+        println!("  {}", Theme::success("Nothing to suggest - all caught up"));
+    }
+    for recommendation in &recommendations {
+        println!("  {} {}", Theme::muted("→"), recommendation.message);
+        if let Some(ref command) = recommendation.command {
+            println!("    {}", Theme::command(command));
+        }
+    }
+    println!();
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Registry command feature.
+//!
+//! This module owns and handles the "wole registry" command behavior: report
+//! stale registry entries and optionally export them. Nothing is changed.
+
+use crate::categories::registry_audit::{self, FindingKind};
+use crate::output::OutputMode;
+use crate::theme::Theme;
+use std::path::PathBuf;
+
+pub(crate) fn handle_registry(
+    export: Option<PathBuf>,
+    json: bool,
+    output_mode: OutputMode,
+) -> anyhow::Result<()> {
+    if !cfg!(windows) {
+        anyhow::bail!("The registry report is only available on Windows");
+    }
+
+    let show = !json && output_mode != OutputMode::Quiet;
+    let spinner = show.then(|| crate::progress::create_spinner("Reading the registry..."));
+    let findings = registry_audit::audit();
+    if let Some(spinner) = spinner {
+        crate::progress::finish_and_clear(&spinner);
+    }
+
+    if let Some(path) = &export {
+        let is_csv = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+        let contents = if is_csv {
+            registry_audit::to_csv(&findings)
+        } else {
+            serde_json::to_string_pretty(&findings)?
+        };
+        std::fs::write(path, contents)?;
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&findings)?);
+        return Ok(());
+    }
+    if !show {
+        return Ok(());
+    }
+
+    println!();
+    println!(
+        "{}",
+        Theme::header("Registry entries pointing at missing files")
+    );
+    println!("{}", Theme::divider_bold(60));
+    for kind in FindingKind::ALL {
+        let found: Vec<_> = findings.iter().filter(|f| f.kind == kind).collect();
+        println!();
+        println!(
+            "{} {}",
+            Theme::primary(kind.label()),
+            Theme::muted(&format!("({})", found.len()))
+        );
+        if found.is_empty() {
+            println!("  {}", Theme::muted("None found"));
+        }
+        for finding in found {
+            println!("  {}", Theme::value(&finding.name));
+            println!(
+                "    {} {}",
+                Theme::muted("missing:"),
+                finding.target.display()
+            );
+            println!(
+                "    {} {}",
+                Theme::muted("key:"),
+                Theme::muted(&finding.key)
+            );
+        }
+    }
+    println!();
+    println!(
+        "{}",
+        Theme::muted("Report only - wole doesn't change the registry. Remove entries with the program's own uninstaller or regedit.")
+    );
+    if let Some(path) = export {
+        println!(
+            "{} Report written to {}",
+            Theme::success("OK"),
+            path.display()
+        );
+    }
+    println!();
+    Ok(())
+}
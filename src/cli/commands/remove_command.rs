@@ -2,10 +2,20 @@
 //!
 //! This module owns and handles the "wole remove" command behavior.
 
+use crate::cleaner::{self, DeleteOutcome};
+use crate::config::Config;
+use crate::history::DeletionLog;
+use crate::manifest;
 use crate::output::OutputMode;
 use crate::theme::Theme;
 use crate::uninstall;
+use crate::utils;
+use std::collections::HashSet;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// History category for paths removed through `--manifest`
+const MANIFEST_CATEGORY: &str = "manifest";
 
 /// Read a line from stdin, handling terminal focus loss issues on Windows.
 /// This function ensures stdin is properly synchronized and clears any stale input
@@ -40,6 +50,8 @@ fn read_line_from_stdin() -> io::Result<String> {
 pub(crate) fn handle_remove(
     config: bool,
     data: bool,
+    purge: bool,
+    export: Option<PathBuf>,
     yes: bool,
     quiet: bool,
     verbose: u8,
@@ -54,6 +66,10 @@ pub(crate) fn handle_remove(
         OutputMode::Normal
     };
 
+    // --purge removes everything, including config and data
+    let config = config || purge;
+    let data = data || purge;
+
     // Confirm unless --yes flag is provided
     if !yes {
         println!();
@@ -80,6 +96,22 @@ pub(crate) fn handle_remove(
                     .map(|p| p.display().to_string())
                     .unwrap_or_else(|_| "%LOCALAPPDATA%\\wole".to_string())
             );
+            println!("    (history, scan cache and disk insights cache)");
+        }
+        if purge {
+            println!("  • Remove wole's scheduled tasks");
+            println!("  • Remove wole's startup and tray entries");
+        }
+        if let Some(ref path) = export {
+            println!("  • First export config and history to {}", path.display());
+        } else if purge {
+            println!();
+            println!(
+                "{}",
+                Theme::warning(
+                    "History and config will be lost. Use --export <ZIP> to keep a copy."
+                )
+            );
         }
         println!();
         print!("Are you sure you want to continue? [y/N]: ");
@@ -98,6 +130,347 @@ pub(crate) fn handle_remove(
         }
     }
 
-    uninstall::uninstall(config, data, output_mode)?;
+    // Export before removing anything; a failed export aborts the uninstall
+    if let Some(path) = export {
+        let exported = uninstall::export_user_data(&path)?;
+        if output_mode != OutputMode::Quiet {
+            if exported == 0 {
+                println!("{} No config or history to export", Theme::muted("Note"));
+            } else if crate::audit::is_enabled() {
+                println!(
+                    "{} Would export config and history to {}",
+                    Theme::muted("Audit"),
+                    path.display()
+                );
+            } else {
+                println!(
+                    "{} Exported config and history to {}",
+                    Theme::success("OK"),
+                    path.display()
+                );
+            }
+        }
+    }
+
+    uninstall::uninstall(config, data, purge, output_mode)?;
+    Ok(())
+}
+
+/// One line of the `--manifest` outcome table
+struct ManifestRow {
+    path: String,
+    size_bytes: Option<u64>,
+    result: &'static str,
+    detail: Option<String>,
+    failed: bool,
+}
+
+impl ManifestRow {
+    fn new(path: &Path, result: &'static str) -> Self {
+        Self {
+            path: path.display().to_string(),
+            size_bytes: None,
+            result,
+            detail: None,
+            failed: false,
+        }
+    }
+}
+
+fn path_size(path: &Path) -> u64 {
+    if utils::safe_is_dir(path) {
+        utils::calculate_dir_size(path)
+    } else {
+        utils::safe_metadata(path).map(|m| m.len()).unwrap_or(0)
+    }
+}
+
+/// `wole remove --manifest`: delete the paths listed in a file
+/// Manifest lines resolved to the paths to delete
+#[derive(Default)]
+struct ExpandedManifest {
+    /// Lines that expanded to nothing, failed to expand or are excluded
+    rows: Vec<ManifestRow>,
+    targets: Vec<(PathBuf, u64)>,
+    errors: u64,
+}
+
+/// Expand every manifest line relative to `base`, skipping duplicates and excluded paths
+fn expand_manifest(
+    entries: &[manifest::ManifestEntry],
+    base: &Path,
+    config: &Config,
+) -> ExpandedManifest {
+    let mut expanded = ExpandedManifest::default();
+    let mut seen = HashSet::new();
+    for entry in entries {
+        let paths = match manifest::expand(entry, base) {
+            Ok(paths) => paths,
+            Err(e) => {
+                let mut row = ManifestRow::new(Path::new(&entry.pattern), "Invalid");
+                row.detail = Some(format!("line {}: {:#}", entry.line, e));
+                row.failed = true;
+                expanded.rows.push(row);
+                expanded.errors += 1;
+                continue;
+            }
+        };
+        if paths.is_empty() {
+            expanded
+                .rows
+                .push(ManifestRow::new(Path::new(&entry.pattern), "No match"));
+        }
+        for path in paths {
+            if !seen.insert(path.clone()) {
+                continue;
+            }
+            if config.is_excluded(&path) {
+                expanded.rows.push(ManifestRow::new(&path, "Excluded"));
+            } else {
+                let size_bytes = path_size(&path);
+                expanded.targets.push((path, size_bytes));
+            }
+        }
+    }
+    expanded
+}
+
+/// Ask before deleting the manifest's paths; true if the user agreed
+fn confirm_removal(targets: &[(PathBuf, u64)], permanent: bool, manifest_path: &Path) -> bool {
+    let total: u64 = targets.iter().map(|(_, size)| size).sum();
+    println!();
+    println!(
+        "{} {} paths ({}) listed in {}",
+        Theme::warning(if permanent {
+            "Permanently delete"
+        } else {
+            "Move to the Recycle Bin"
+        }),
+        Theme::value(&targets.len().to_string()),
+        Theme::size(&bytesize::to_string(total, false)),
+        manifest_path.display()
+    );
+    print!("Are you sure you want to continue? [y/N]: ");
+    io::stdout().flush().ok();
+    let input = read_line_from_stdin()
+        .map(|line| line.trim().to_lowercase())
+        .unwrap_or_default();
+    input == "y" || input == "yes"
+}
+
+/// Totals of one `--manifest` run
+#[derive(Default)]
+struct ManifestOutcome {
+    removed: u64,
+    removed_bytes: u64,
+    errors: u64,
+    log_path: Option<PathBuf>,
+}
+
+/// Delete (or in a dry run, check) every target, adding one row per path
+fn execute_manifest(
+    targets: Vec<(PathBuf, u64)>,
+    permanent: bool,
+    dry_run: bool,
+    rows: &mut Vec<ManifestRow>,
+    output_mode: OutputMode,
+) -> ManifestOutcome {
+    let mut outcome = ManifestOutcome::default();
+    let mut history = (!dry_run).then(DeletionLog::new);
+    for (path, size_bytes) in targets {
+        let mut row = ManifestRow::new(&path, "Deleted");
+        row.size_bytes = Some(size_bytes);
+        if dry_run {
+            if utils::safe_metadata(&path).is_err() {
+                row.result = "Missing";
+            } else {
+                row.result = "Would delete";
+                outcome.removed += 1;
+                outcome.removed_bytes += size_bytes;
+            }
+            rows.push(row);
+            continue;
+        }
+
+        let error = match cleaner::delete_with_precheck(&path, permanent) {
+            Ok(DeleteOutcome::Deleted) => {
+                outcome.removed += 1;
+                outcome.removed_bytes += size_bytes;
+                if let Some(history) = history.as_mut() {
+                    history.log_success(&path, size_bytes, MANIFEST_CATEGORY, permanent);
+                }
+                None
+            }
+            Ok(DeleteOutcome::Audited) => {
+                row.result = "Would delete";
+                outcome.removed += 1;
+                outcome.removed_bytes += size_bytes;
+                None
+            }
+            Ok(DeleteOutcome::SkippedMissing) => {
+                row.result = "Missing";
+                None
+            }
+            Ok(skipped @ DeleteOutcome::SkippedSystem) => {
+                row.result = "Protected";
+                row.detail = Some(skipped.reason().to_string());
+                None
+            }
+            Ok(skipped @ DeleteOutcome::SkippedLocked) => {
+                row.result = "Locked";
+                Some(skipped.reason().to_string())
+            }
+            Ok(skipped @ (DeleteOutcome::SkippedPermission | DeleteOutcome::SkippedProtected)) => {
+                row.result = "Denied";
+                Some(skipped.reason().to_string())
+            }
+            Err(e) => {
+                row.result = "Error";
+                Some(e.to_string())
+            }
+        };
+        if let Some(error) = error {
+            if let Some(history) = history.as_mut() {
+                history.log_failure(&path, size_bytes, MANIFEST_CATEGORY, permanent, &error);
+            }
+            outcome.errors += 1;
+            row.failed = true;
+            row.detail = Some(error);
+        }
+        rows.push(row);
+    }
+
+    outcome.log_path = history
+        .filter(|log| !log.records.is_empty())
+        .and_then(|log| match log.save() {
+            Ok(path) => Some(path),
+            Err(e) => {
+                if output_mode != OutputMode::Quiet {
+                    eprintln!("[WARNING] Failed to save deletion log: {}", e);
+                }
+                None
+            }
+        });
+    outcome
+}
+
+/// Print the outcome table and totals
+fn print_manifest_report(rows: &[ManifestRow], outcome: &ManifestOutcome, dry_run: bool) {
+    println!();
+    println!("  {:<12} {:>10}  Path", "Result", "Size");
+    println!("{}", Theme::divider(60));
+    for row in rows {
+        let result = format!("{:<12}", row.result);
+        let result = if row.failed {
+            Theme::error(&result)
+        } else if row.size_bytes.is_some() && row.result != "Missing" {
+            Theme::success(&result)
+        } else {
+            Theme::muted(&result)
+        };
+        let size = row
+            .size_bytes
+            .map(|size| bytesize::to_string(size, false))
+            .unwrap_or_default();
+        let detail = row
+            .detail
+            .as_ref()
+            .map(|detail| Theme::muted(&format!(" ({})", detail)))
+            .unwrap_or_default();
+        println!(
+            "  {} {}  {}{}",
+            result,
+            Theme::size(&format!("{:>10}", size)),
+            row.path,
+            detail
+        );
+    }
+    println!();
+    println!(
+        "{} {} paths {} ({}), {} errors",
+        if dry_run { "[DRY RUN]" } else { "[OK]" },
+        Theme::value(&outcome.removed.to_string()),
+        if dry_run {
+            "would be deleted"
+        } else {
+            "deleted"
+        },
+        Theme::size(&bytesize::to_string(outcome.removed_bytes, false)),
+        Theme::error(&outcome.errors.to_string())
+    );
+    if let Some(ref path) = outcome.log_path {
+        println!(
+            "{}",
+            Theme::muted(&format!("Deletion log saved to: {}", path.display()))
+        );
+    }
+}
+
+/// `wole remove --manifest`: delete the paths listed in a file
+pub(crate) fn handle_remove_manifest(
+    manifest_path: &Path,
+    permanent: bool,
+    dry_run: bool,
+    yes: bool,
+    output_mode: OutputMode,
+) -> anyhow::Result<()> {
+    let entries = manifest::load(manifest_path)?;
+    let manifest_path = if manifest_path.is_absolute() {
+        manifest_path.to_path_buf()
+    } else {
+        std::env::current_dir()?.join(manifest_path)
+    };
+    let base = manifest_path.parent().unwrap_or(Path::new(""));
+
+    let config = Config::load();
+    crate::throttle::apply(&config.performance);
+    cleaner::apply_safety_settings(&config.safety);
+    crate::history::checksums::set_enabled(config.history.record_checksums);
+    let permanent = crate::policy::permanent_allowed(permanent);
+    let dry_run = dry_run || crate::audit::is_enabled();
+
+    let ExpandedManifest {
+        mut rows,
+        targets,
+        errors,
+    } = expand_manifest(&entries, base, &config);
+
+    if !yes
+        && !dry_run
+        && !targets.is_empty()
+        && !confirm_removal(&targets, permanent, &manifest_path)
+    {
+        println!("Removal cancelled.");
+        return Ok(());
+    }
+
+    let mut outcome = execute_manifest(targets, permanent, dry_run, &mut rows, output_mode);
+    // Lines that failed to expand count as errors too
+    outcome.errors += errors;
+
+    if output_mode != OutputMode::Quiet {
+        print_manifest_report(&rows, &outcome, dry_run);
+    }
+
+    if !dry_run {
+        let summary = cleaner::CleanSummary {
+            cleaned: outcome.removed,
+            cleaned_bytes: outcome.removed_bytes,
+            errors: outcome.errors,
+            cancelled: false,
+            log_path: outcome.log_path,
+        };
+        if let Err(e) =
+            crate::policy::send_report(&crate::policy::CleanReport::new(&summary, permanent))
+        {
+            if output_mode != OutputMode::Quiet {
+                eprintln!("[WARNING] {:#}", e);
+            }
+        }
+    }
+
+    if outcome.errors > 0 {
+        anyhow::bail!("{} manifest entries could not be removed", outcome.errors);
+    }
     Ok(())
 }
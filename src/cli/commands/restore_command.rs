@@ -2,6 +2,7 @@
 //!
 //! This module owns and handles the "wole restore" command behavior.
 
+use crate::cancel::CancellationToken;
 use crate::history;
 use crate::output::OutputMode;
 use crate::restore;
@@ -29,17 +30,8 @@ pub(crate) fn handle_restore(
 
     if all {
         // Restore all contents of Recycle Bin in bulk
-        match restore::restore_all_bin(output_mode, None) {
-            Ok(result) => {
-                if output_mode != OutputMode::Quiet {
-                    println!();
-                    println!(
-                        "{} {}",
-                        Theme::success("OK"),
-                        Theme::success(&result.summary())
-                    );
-                }
-            }
+        match restore::restore_all_bin(output_mode, None, &CancellationToken::new()) {
+            Ok(result) => print_result(&result, output_mode),
             Err(e) => {
                 return Err(anyhow::anyhow!("Failed to restore: {}", e));
             }
@@ -47,16 +39,7 @@ pub(crate) fn handle_restore(
     } else if last {
         // Restore from last deletion session
         match restore::restore_last(output_mode) {
-            Ok(result) => {
-                if output_mode != OutputMode::Quiet {
-                    println!();
-                    println!(
-                        "{} {}",
-                        Theme::success("OK"),
-                        Theme::success(&result.summary())
-                    );
-                }
-            }
+            Ok(result) => print_result(&result, output_mode),
             Err(e) => {
                 return Err(anyhow::anyhow!("Failed to restore: {}", e));
             }
@@ -64,16 +47,7 @@ pub(crate) fn handle_restore(
     } else if let Some(ref restore_path) = path {
         // Restore specific path
         match restore::restore_path(restore_path, output_mode) {
-            Ok(result) => {
-                if output_mode != OutputMode::Quiet {
-                    println!();
-                    println!(
-                        "{} {}",
-                        Theme::success("OK"),
-                        Theme::success(&result.summary())
-                    );
-                }
-            }
+            Ok(result) => print_result(&result, output_mode),
             Err(e) => {
                 return Err(anyhow::anyhow!("Failed to restore: {}", e));
             }
@@ -83,16 +57,7 @@ pub(crate) fn handle_restore(
         let log = history::load_log(log_path)
             .with_context(|| format!("Failed to load log file: {}", log_path.display()))?;
         match restore::restore_from_log(&log, output_mode) {
-            Ok(result) => {
-                if output_mode != OutputMode::Quiet {
-                    println!();
-                    println!(
-                        "{} {}",
-                        Theme::success("OK"),
-                        Theme::success(&result.summary())
-                    );
-                }
-            }
+            Ok(result) => print_result(&result, output_mode),
             Err(e) => {
                 return Err(anyhow::anyhow!("Failed to restore: {}", e));
             }
@@ -100,16 +65,7 @@ pub(crate) fn handle_restore(
     } else {
         // Default: restore from last session
         match restore::restore_last(output_mode) {
-            Ok(result) => {
-                if output_mode != OutputMode::Quiet {
-                    println!();
-                    println!(
-                        "{} {}",
-                        Theme::success("OK"),
-                        Theme::success(&result.summary())
-                    );
-                }
-            }
+            Ok(result) => print_result(&result, output_mode),
             Err(e) => {
                 return Err(anyhow::anyhow!("Failed to restore: {}", e));
             }
@@ -118,3 +74,24 @@ pub(crate) fn handle_restore(
 
     Ok(())
 }
+
+fn print_result(result: &restore::RestoreResult, output_mode: OutputMode) {
+    if output_mode == OutputMode::Quiet {
+        return;
+    }
+    println!();
+    if result.mismatches.is_empty() {
+        println!(
+            "{} {}",
+            Theme::success("OK"),
+            Theme::success(&result.summary())
+        );
+    } else {
+        // Some items came back missing or smaller (listed above)
+        println!(
+            "{} {}",
+            Theme::warning("WARN"),
+            Theme::warning(&result.summary())
+        );
+    }
+}
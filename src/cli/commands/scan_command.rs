@@ -2,12 +2,17 @@
 //!
 //! This module owns and handles the "wole scan" command behavior.
 
+use crate::api::{no_categories, Category};
 use crate::cli::ScanOptions;
-use crate::config::Config;
-use crate::output::{self, OutputMode};
+use crate::config::{Config, SavedView};
+use crate::output::{self, OutputMode, ScanResults};
 use crate::scanner;
 use crate::size;
 use crate::theme::Theme;
+use crate::tui::state::{AppState, ResultsRow};
+use crate::users::ScanScope;
+use serde::Serialize;
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 // Helper function to format numbers (copied from output.rs for local use)
@@ -60,17 +65,41 @@ pub(crate) fn handle_scan(
     applications: bool,
     windows_update: bool,
     event_logs: bool,
+    thumbnails: bool,
+    crash_dumps: bool,
+    logs: bool,
+    game_caches: bool,
+    toolchain_caches: bool,
+    python_envs: bool,
+    ml_models: bool,
+    email: bool,
+    creative_caches: bool,
+    device_backups: bool,
+    git_ignored: bool,
     path: Option<PathBuf>,
     json: bool,
+    print0: bool,
     project_age: u64,
     min_age: u64,
     min_size: String,
+    size_budget: Option<String>,
     exclude: Vec<String>,
+    scope: Option<String>,
     force_full: bool,
     no_cache: bool,
     clear_cache: bool,
+    view: Option<String>,
     output_mode: OutputMode,
 ) -> anyhow::Result<()> {
+    // --print0 owns stdout: nothing but the paths goes there
+    let output_mode = if print0 {
+        OutputMode::Quiet
+    } else {
+        output_mode
+    };
+
+    let saved_view = view.as_deref().map(find_view).transpose()?;
+
     // --all enables all categories
     let (
         cache,
@@ -88,10 +117,21 @@ pub(crate) fn handle_scan(
         duplicates,
         windows_update,
         event_logs,
+        thumbnails,
+        crash_dumps,
+        logs,
+        game_caches,
+        toolchain_caches,
+        python_envs,
+        ml_models,
+        email,
+        creative_caches,
+        device_backups,
+        git_ignored,
     ) = if all {
         (
             true, true, true, true, true, true, true, true, true, true, true, true, true, true,
-            true,
+            true, true, true, true, true, true, true, true, true, true, true, true,
         )
     } else if !cache
         && !app_cache
@@ -104,11 +144,66 @@ pub(crate) fn handle_scan(
         && !applications
         && !windows_update
         && !event_logs
+        && !thumbnails
+        && !crash_dumps
+        && !logs
+        && !game_caches
+        && !toolchain_caches
+        && !python_envs
+        && !ml_models
+        && !email
+        && !creative_caches
+        && !device_backups
+        && !git_ignored
     {
-        // No categories specified - show help message
-        eprintln!("No categories specified. Use --all or specify categories like --cache, --app-cache, --temp, --build");
-        eprintln!("Run 'wole scan --help' for more information.");
-        return Ok(());
+        if let Some(view) = &saved_view {
+            // --view on its own scans the view's categories (all of them if it names none)
+            let mut options = no_categories(&Config::default(), 0, ScanScope::default());
+            let categories: Vec<Category> = if view.categories.is_empty() {
+                Category::ALL.to_vec()
+            } else {
+                view.categories
+                    .iter()
+                    .filter_map(|id| Category::from_id(id))
+                    .collect()
+            };
+            for category in categories {
+                category.enable(&mut options);
+            }
+            (
+                options.cache,
+                options.app_cache,
+                options.temp,
+                options.trash,
+                options.build,
+                options.downloads,
+                options.large,
+                options.old,
+                options.applications,
+                options.browser,
+                options.system,
+                options.empty,
+                options.duplicates,
+                options.windows_update,
+                options.event_logs,
+                options.thumbnails,
+                options.crash_dumps,
+                options.logs,
+                options.game_caches,
+                options.toolchain_caches,
+                options.python_envs,
+                options.ml_models,
+                options.email,
+                options.creative_caches,
+                options.device_backups,
+                options.git_ignored,
+            )
+        } else {
+            // No categories specified - show help message
+            eprintln!("No categories specified. Use --all or specify categories like --cache, --app-cache, --temp, --build");
+            eprintln!("Run 'wole scan --help' for more information.");
+            return Ok(());
+        }
     } else {
         // Scan command doesn't support browser, system, empty, duplicates
         (
@@ -127,6 +222,17 @@ pub(crate) fn handle_scan(
             false,
             windows_update,
             event_logs,
+            thumbnails,
+            crash_dumps,
+            logs,
+            game_caches,
+            toolchain_caches,
+            python_envs,
+            ml_models,
+            email,
+            creative_caches,
+            device_backups,
+            git_ignored,
         )
     };
 
@@ -151,10 +257,30 @@ pub(crate) fn handle_scan(
                 / (1024 * 1024),
         ), // Convert bytes to MB for config
     );
+    // --size-budget overrides [performance] scan_size_budget_mb
+    if let Some(ref budget) = size_budget {
+        let bytes = size::parse_size(budget)
+            .map_err(|e| anyhow::anyhow!("Invalid size format '{}': {}", budget, e))?;
+        config.performance.scan_size_budget_mb = bytes.div_ceil(1024 * 1024);
+    }
 
     // Merge CLI exclusions
     config.exclusions.patterns.extend(exclude.iter().cloned());
 
+    // --scope overrides [paths] scope
+    let scope = crate::users::resolve_scope(scope.as_deref(), &config)?;
+
+    // Network shares are slower to scan and have no Recycle Bin
+    if output_mode != OutputMode::Quiet {
+        if let Some((notice, _)) = crate::network::scan_notice(&scan_path) {
+            if json {
+                eprintln!("{}", notice);
+            } else {
+                println!("{}", Theme::warning(&notice));
+            }
+        }
+    }
+
     // Handle cache flags
     let use_cache = !no_cache && config.cache.enabled && !force_full;
 
@@ -174,6 +300,17 @@ pub(crate) fn handle_scan(
                     "applications",
                     "windows_update",
                     "event_logs",
+                    "thumbnails",
+                    "crash_dumps",
+                    "logs",
+                    "game_caches",
+                    "toolchain_caches",
+                    "python_envs",
+                    "ml_models",
+                    "email",
+                    "creative_caches",
+                    "device_backups",
+                    "git_ignored",
                 ]
             } else {
                 let mut cats = Vec::new();
@@ -210,6 +347,39 @@ pub(crate) fn handle_scan(
                 if event_logs {
                     cats.push("event_logs");
                 }
+                if thumbnails {
+                    cats.push("thumbnails");
+                }
+                if crash_dumps {
+                    cats.push("crash_dumps");
+                }
+                if logs {
+                    cats.push("logs");
+                }
+                if game_caches {
+                    cats.push("game_caches");
+                }
+                if toolchain_caches {
+                    cats.push("toolchain_caches");
+                }
+                if python_envs {
+                    cats.push("python_envs");
+                }
+                if ml_models {
+                    cats.push("ml_models");
+                }
+                if email {
+                    cats.push("email");
+                }
+                if creative_caches {
+                    cats.push("creative_caches");
+                }
+                if device_backups {
+                    cats.push("device_backups");
+                }
+                if git_ignored {
+                    cats.push("git_ignored");
+                }
                 cats
             };
 
@@ -245,9 +415,21 @@ pub(crate) fn handle_scan(
         duplicates,
         windows_update,
         event_logs,
+        thumbnails,
+        crash_dumps,
+        logs,
+        game_caches,
+        toolchain_caches,
+        python_envs,
+        ml_models,
+        email,
+        creative_caches,
+        device_backups,
+        git_ignored,
         project_age_days: config.thresholds.project_age_days,
         min_age_days: config.thresholds.min_age_days,
         min_size_bytes,
+        scope,
     };
 
     // Open scan cache if enabled
@@ -326,14 +508,23 @@ pub(crate) fn handle_scan(
         }
     }
 
-    let results = scanner::scan_all(
+    let results = scanner::run_scan(
         &scan_path,
         scan_options.clone(),
         output_mode,
         &config,
-        scan_cache.as_mut(),
+        &mut scan_cache,
     )?;
 
+    if let Some(view) = &saved_view {
+        return print_view(view, &scan_path, results, &config, json, print0);
+    }
+
+    if print0 {
+        output::write_paths0(&results, &mut std::io::stdout().lock())?;
+        return Ok(());
+    }
+
     if json {
         output::print_json(&results)?;
     } else {
@@ -386,3 +577,116 @@ pub(crate) fn handle_scan(
 
     Ok(())
 }
+
+/// Look up a saved view by name, listing the saved ones if there's no such view
+fn find_view(name: &str) -> anyhow::Result<SavedView> {
+    let config = Config::load();
+    if let Some(view) = config.ui.saved_view(name) {
+        return Ok(view.clone());
+    }
+    let names: Vec<&str> = config
+        .ui
+        .saved_views
+        .iter()
+        .map(|view| view.name.as_str())
+        .collect();
+    if names.is_empty() {
+        anyhow::bail!(
+            "No saved view \"{}\" (save one with V in the Results screen)",
+            name
+        );
+    }
+    anyhow::bail!(
+        "No saved view \"{}\" (saved views: {})",
+        name,
+        names.join(", ")
+    )
+}
+
+/// `wole scan --view --json` item
+#[derive(Serialize)]
+struct ViewItem<'a> {
+    category: &'a str,
+    path: &'a Path,
+    size_bytes: u64,
+}
+
+/// Print the results a saved view shows: the scan is loaded the way the TUI
+/// loads it and filtered by the Results search
+fn print_view(
+    view: &SavedView,
+    scan_path: &Path,
+    results: ScanResults,
+    config: &Config,
+    json: bool,
+    print0: bool,
+) -> anyhow::Result<()> {
+    let mut state = AppState::new();
+    state.config = config.clone();
+    state.scan_path = scan_path.to_path_buf();
+    state.scan_results = Some(results);
+    state.flatten_results();
+    state.search_query = view.search();
+
+    let rows = state.filtered_results_rows();
+    let mut seen = HashSet::new();
+    let items: Vec<_> = rows
+        .iter()
+        .filter_map(|row| match row {
+            ResultsRow::Item { item_idx, .. } if seen.insert(*item_idx) => {
+                state.all_items.get(*item_idx)
+            }
+            _ => None,
+        })
+        .collect();
+
+    if print0 {
+        use std::io::Write;
+        let mut out = std::io::stdout().lock();
+        for item in &items {
+            out.write_all(item.path.to_string_lossy().as_bytes())?;
+            out.write_all(b"\0")?;
+        }
+        out.flush()?;
+        return Ok(());
+    }
+    if json {
+        let items: Vec<ViewItem> = items
+            .iter()
+            .map(|item| ViewItem {
+                category: &item.category,
+                path: &item.path,
+                size_bytes: item.size_bytes,
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&items)?);
+        return Ok(());
+    }
+
+    let total: u64 = items.iter().map(|item| item.size_bytes).sum();
+    println!();
+    println!(
+        "{}",
+        Theme::header(&format!(
+            "View \"{}\": {} items, {}",
+            view.name,
+            items.len(),
+            bytesize::to_string(total, false)
+        ))
+    );
+    println!("  {}", Theme::muted(&view.search()));
+    println!("{}", Theme::divider_bold(60));
+    for item in &items {
+        println!(
+            "  {}  {}  {}",
+            Theme::size(&format!(
+                "{:>10}",
+                bytesize::to_string(item.size_bytes, false)
+            )),
+            Theme::muted(&format!("{:<20}", item.category)),
+            item.path.display()
+        );
+    }
+    println!();
+    Ok(())
+}
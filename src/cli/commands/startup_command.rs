@@ -2,12 +2,14 @@
 //!
 //! This module owns and handles the "wole startup" command behavior.
 
+use crate::categories::autoruns::{self, AutorunKind};
 use crate::theme::Theme;
 
 pub(crate) fn handle_startup(
     _list: bool,
     disable: Option<String>,
     enable: Option<String>,
+    audit: bool,
     json: bool,
 ) -> anyhow::Result<()> {
     use crate::categories::startup;
@@ -23,9 +25,28 @@ pub(crate) fn handle_startup(
                     Theme::value(&name)
                 );
             }
+        } else if let Some(autorun) = autoruns::list_autoruns()?
+            .into_iter()
+            .find(|a| a.name.eq_ignore_ascii_case(&name))
+        {
+            autoruns::disable_autorun(&autorun)?;
+            if !json {
+                let done = match autorun.kind {
+                    AutorunKind::ScheduledTask => "Disabled scheduled task:",
+                    AutorunKind::Service => "Set to start manually:",
+                };
+                println!(
+                    "{} {} {}",
+                    Theme::success("✓"),
+                    done,
+                    Theme::value(&autorun.name)
+                );
+            }
         } else {
             return Err(anyhow::anyhow!("Startup program not found: {}", name));
         }
+    } else if audit {
+        print_audit(json)?;
     } else if let Some(name) = enable {
         let programs = startup::list_startup_programs()?;
         if let Some(program) = programs.iter().find(|p| p.name == name) {
@@ -94,3 +115,69 @@ pub(crate) fn handle_startup(
 
     Ok(())
 }
+
+/// Third-party scheduled tasks and automatic services (`--audit`)
+fn print_audit(json: bool) -> anyhow::Result<()> {
+    let autoruns = autoruns::list_autoruns()?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&autoruns)?);
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", Theme::header("Scheduled Tasks and Services"));
+    println!("{}", Theme::divider_bold(60));
+    for (kind, title) in [
+        (
+            AutorunKind::ScheduledTask,
+            "Scheduled tasks (outside \\Microsoft\\)",
+        ),
+        (
+            AutorunKind::Service,
+            "Services set to Auto (outside Windows)",
+        ),
+    ] {
+        let entries: Vec<_> = autoruns.iter().filter(|a| a.kind == kind).collect();
+        println!();
+        println!(
+            "{} {}",
+            Theme::primary(title),
+            Theme::muted(&format!("({})", entries.len()))
+        );
+        if entries.is_empty() {
+            println!("  {}", Theme::muted("None found"));
+        }
+        for autorun in entries {
+            let status = if autorun.missing {
+                Theme::warning("missing program")
+            } else if !autorun.enabled {
+                Theme::muted("disabled")
+            } else {
+                String::new()
+            };
+            println!("  {} {}", Theme::value(&autorun.name), status);
+            if !autorun.description.is_empty() {
+                println!("    {}", Theme::muted(&autorun.description));
+            }
+            println!("    {}", Theme::muted(&autorun.command));
+        }
+    }
+
+    let missing = autoruns.iter().filter(|a| a.missing).count();
+    println!();
+    if missing > 0 {
+        println!(
+            "{} {} entr{} point to a program that no longer exists",
+            Theme::warning("!"),
+            missing,
+            if missing == 1 { "y" } else { "ies" }
+        );
+    }
+    println!(
+        "{} Use {} to disable a task or set a service to start manually",
+        Theme::muted("→"),
+        Theme::command("wole startup --disable <name>")
+    );
+    println!();
+    Ok(())
+}
@@ -57,3 +57,11 @@ pub(crate) fn handle_status(json: bool, _watch: bool, new_format: bool) -> anyho
         }
     }
 }
+
+/// `wole status --writes`: the Disk Writes screen on its own
+pub(crate) fn handle_disk_writes(volume: Option<String>) -> anyhow::Result<()> {
+    let volume = volume.unwrap_or_else(crate::disk_writes::system_volume);
+    let mut app_state = crate::tui::state::AppState::new();
+    app_state.open_disk_writes(&volume);
+    crate::tui::run(Some(app_state))
+}
@@ -0,0 +1,196 @@
+//! Why-full command feature.
+//!
+//! This module owns and handles the "wole why-full" command behavior: run
+//! the wizard's steps with a line per step, print the ranked findings and
+//! act on the one picked by number.
+
+use crate::cli::interactive_menu::read_choice;
+use crate::config::Config;
+use crate::output::OutputMode;
+use crate::theme::Theme;
+use crate::tui::events::run_dashboard_action;
+use crate::tui::state::{AppState, DashboardAction, Screen};
+use crate::why_full::{self, Action, Finding, Step, StepReport};
+use serde::Serialize;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+
+/// `wole why-full --json` output
+#[derive(Serialize)]
+struct WhyFullReport {
+    root: PathBuf,
+    steps: Vec<StepReport>,
+    /// Biggest first
+    ranked: Vec<Finding>,
+}
+
+/// Ask a yes/no question; anything but "y"/"yes" (or a read error) is no
+fn confirm(prompt: &str) -> bool {
+    print!("{} [y/N]: ", prompt);
+    io::stdout().flush().ok();
+    let mut input = String::new();
+    io::stdin().lock().read_line(&mut input).is_ok()
+        && matches!(input.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+pub(crate) fn handle_why_full(
+    drive: Option<String>,
+    json: bool,
+    output_mode: OutputMode,
+) -> anyhow::Result<()> {
+    let config = Config::load();
+    let root = why_full::drive_root(drive.as_deref())?;
+    let show = !json && output_mode != OutputMode::Quiet;
+
+    if show {
+        println!();
+        println!(
+            "{}",
+            Theme::header(&format!("Where did the space on {} go?", root.display()))
+        );
+        println!("{}", Theme::divider_bold(60));
+    }
+
+    let mut steps = Vec::new();
+    for (i, step) in Step::ALL.into_iter().enumerate() {
+        let counter = format!("[{}/{}]", i + 1, Step::ALL.len());
+        let spinner = show
+            .then(|| crate::progress::create_spinner(&format!("{} {}...", counter, step.label())));
+        let report = why_full::run_step(step, &root, &config);
+        if let Some(spinner) = spinner {
+            crate::progress::finish_and_clear(&spinner);
+        }
+        if show {
+            println!(
+                "  {} {} {}",
+                Theme::muted(&counter),
+                Theme::primary(&format!("{:<24}", step.label())),
+                report.summary
+            );
+        }
+        steps.push(report);
+    }
+    let ranked = why_full::rank(&steps);
+
+    if json {
+        let report = WhyFullReport {
+            root,
+            steps,
+            ranked,
+        };
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+    if !show {
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", Theme::primary("This is where your space went:"));
+    println!();
+    if ranked.is_empty() {
+        println!("  {}", Theme::muted("Nothing stands out"));
+        println!();
+        return Ok(());
+    }
+    for (i, finding) in ranked.iter().enumerate() {
+        println!(
+            "  {}  {}  {}",
+            Theme::command(&format!("{:>2}", i + 1)),
+            Theme::size(&format!(
+                "{:>10}",
+                bytesize::to_string(finding.bytes, false)
+            )),
+            finding.title
+        );
+        let action = match &finding.action {
+            Some(action) => action.describe(),
+            None => {
+                "Managed by Windows (System Properties > Performance > Virtual memory)".to_string()
+            }
+        };
+        println!("{:16}{} {}", "", Theme::muted("→"), Theme::muted(&action));
+    }
+    println!();
+
+    if !io::stdin().is_terminal() {
+        return Ok(());
+    }
+    print!(
+        "{}",
+        Theme::primary("Pick a finding to act on (Enter to quit): ")
+    );
+    let Some(input) = read_choice() else {
+        return Ok(());
+    };
+    let Some(finding) = input
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_sub(1))
+        .and_then(|i| ranked.get(i))
+    else {
+        if !input.is_empty() {
+            println!("{}", Theme::warning_msg("Unknown finding"));
+        }
+        return Ok(());
+    };
+    let Some(action) = &finding.action else {
+        println!("{}", Theme::warning_msg("wole can't change this one"));
+        return Ok(());
+    };
+    act(action, &config)
+}
+
+fn act(action: &Action, config: &Config) -> anyhow::Result<()> {
+    match action {
+        Action::Analyze { path } => {
+            let spinner =
+                crate::progress::create_spinner(&format!("Scanning {}...", path.display()));
+            let insights = crate::disk_usage::scan_directory(path, config.ui.scan_depth_user);
+            crate::progress::finish_and_clear(&spinner);
+            let mut state = AppState::new();
+            state.screen = Screen::DiskInsights {
+                insights: insights?,
+                current_path: path.clone(),
+                cursor: 0,
+                sort_by: crate::disk_usage::SortBy::Size,
+                selected_paths: std::collections::HashSet::new(),
+            };
+            crate::tui::run(Some(state))
+        }
+        Action::Clean { categories } => {
+            let mut state = AppState::new();
+            let ids: Vec<String> = categories.iter().map(|c| c.id().to_string()).collect();
+            if state.apply_history_categories(&ids) == 0 {
+                println!(
+                    "{}",
+                    Theme::warning_msg("Those categories aren't available")
+                );
+                return Ok(());
+            }
+            run_dashboard_action(&mut state, DashboardAction::Clean);
+            crate::tui::run(Some(state))
+        }
+        Action::ShrinkShadowStorage { volume, max_size } => {
+            if !confirm(&action.describe()) {
+                return Ok(());
+            }
+            crate::shadow::resize(volume, max_size)?;
+            println!(
+                "{} Shadow storage on {} capped at {}",
+                Theme::success("OK"),
+                volume,
+                max_size
+            );
+            Ok(())
+        }
+        Action::DisableHibernation => {
+            if !confirm(&action.describe()) {
+                return Ok(());
+            }
+            why_full::disable_hibernation()?;
+            println!("{} Hibernation is off", Theme::success("OK"));
+            Ok(())
+        }
+    }
+}
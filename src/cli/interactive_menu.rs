@@ -1,130 +1,187 @@
 //! Interactive menu feature.
 //!
-//! This module owns the CLI interactive menu display.
+//! This module owns the CLI interactive menu shown when `wole` gets flags but
+//! no command (e.g. `wole -v`): recent cleanup sessions, quick actions and
+//! cleaning profiles. A choice launches the TUI straight into it, through the
+//! same [`run_dashboard_action`] the dashboard's Actions list uses.
 
 use super::Cli;
 use crate::theme::Theme;
+use crate::tui::events::run_dashboard_action;
+use crate::tui::state::{AppState, CleanProfile, DashboardAction};
+use anyhow::Result;
+use std::io::{BufRead, IsTerminal, Write};
+
+/// Sessions listed under "Recent Sessions"
+const RECENT_SESSIONS: usize = 3;
+
+/// What a menu entry does once picked
+#[derive(Debug, Clone)]
+enum MenuChoice {
+    /// Clean the categories of the last session again
+    RepeatLastClean(Vec<String>),
+    SafeClean,
+    OpenTui,
+    Status,
+    /// Open the dashboard with a profile's categories enabled
+    Profile(CleanProfile),
+}
+
+/// One line of a past cleanup session
+struct RecentSession {
+    when: String,
+    items: usize,
+    bytes: u64,
+    categories: Vec<String>,
+}
+
+/// The newest cleanup sessions in history (empty if there is none)
+fn recent_sessions(limit: usize) -> Vec<RecentSession> {
+    let Ok(logs) = crate::history::list_logs() else {
+        return Vec::new();
+    };
+    logs.iter()
+        .filter_map(|path| crate::history::load_log(path).ok())
+        .filter(|log| log.records.iter().any(|r| r.success))
+        .take(limit)
+        .map(|log| RecentSession {
+            when: log
+                .session_start
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            items: log.records.iter().filter(|r| r.success).count(),
+            bytes: log.total_bytes_cleaned,
+            categories: log.categories(),
+        })
+        .collect()
+}
+
+/// Read one line from stdin (None on EOF or error)
+pub(crate) fn read_choice() -> Option<String> {
+    std::io::stdout().flush().ok()?;
+    let mut input = String::new();
+    match std::io::stdin().lock().read_line(&mut input) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(input.trim().to_string()),
+    }
+}
 
 impl Cli {
     /// Show interactive menu when no command is provided
-    pub fn show_interactive_menu() {
+    ///
+    /// Without a terminal on stdin the menu is only printed.
+    pub fn show_interactive_menu() -> Result<()> {
         println!();
         println!("{}", Theme::header("Wole - Reclaim Disk Space on Windows"));
         println!("{}", Theme::divider_bold(60));
         println!();
-        println!("{}", Theme::primary("Available Commands:"));
-        println!();
-        println!(
-            "  {}  {}  {}",
-            Theme::command("scan"),
-            Theme::muted("or"),
-            Theme::command("s"),
-        );
-        println!(
-            "     {} Find cleanable files (safe, dry-run)",
-            Theme::muted("→")
-        );
-        println!();
-        println!(
-            "  {}  {}  {}",
-            Theme::command("clean"),
-            Theme::muted("or"),
-            Theme::command("c"),
-        );
-        println!("     {} Delete files found by scan", Theme::muted("→"));
-        println!();
-        println!(
-            "  {}  {}  {}",
-            Theme::command("analyze"),
-            Theme::muted("or"),
-            Theme::command("a"),
-        );
-        println!(
-            "     {} Show detailed analysis with file lists",
-            Theme::muted("→")
-        );
-        println!();
-        println!("  {}", Theme::command("config"));
-        println!("     {} View or modify configuration", Theme::muted("→"));
-        println!();
-        println!(
-            "  {}  {}  {}",
-            Theme::command("restore"),
-            Theme::muted("or"),
-            Theme::command("r"),
-        );
-        println!(
-            "     {} Restore files from last deletion",
-            Theme::muted("→")
-        );
-        println!();
-        println!("  {}", Theme::command("remove"));
-        println!("     {} Uninstall wole from your system", Theme::muted("→"));
+
+        let sessions = recent_sessions(RECENT_SESSIONS);
+        println!("{}", Theme::primary("Recent Sessions:"));
+        println!();
+        if sessions.is_empty() {
+            println!("  {}", Theme::muted("No cleanups yet"));
+        }
+        for session in &sessions {
+            println!(
+                "  {}  {} items  {}  {}",
+                Theme::muted(&session.when),
+                Theme::value(&session.items.to_string()),
+                Theme::size(&bytesize::to_string(session.bytes, false)),
+                Theme::muted(&session.categories.join(", ")),
+            );
+        }
         println!();
-        println!("  {}", Theme::command("update"));
-        println!("     {} Check for and install updates", Theme::muted("→"));
+
+        let mut entries: Vec<(String, MenuChoice)> = Vec::new();
+        if let Some(last) = sessions.first() {
+            entries.push((
+                format!("Repeat last clean ({})", last.categories.join(", ")),
+                MenuChoice::RepeatLastClean(last.categories.clone()),
+            ));
+        }
+        entries.push((
+            format!("Safe clean - {}", CleanProfile::Safe.description()),
+            MenuChoice::SafeClean,
+        ));
+        entries.push(("Open TUI".to_string(), MenuChoice::OpenTui));
+        entries.push((
+            format!("Status - {}", DashboardAction::Status.description()),
+            MenuChoice::Status,
+        ));
+        let quick_actions = entries.len();
+        for profile in CleanProfile::ALL {
+            entries.push((
+                format!("{} - {}", profile.label(), profile.description()),
+                MenuChoice::Profile(profile),
+            ));
+        }
+
+        println!("{}", Theme::primary("Quick Actions:"));
         println!();
-        println!(
-            "  {}  {}  {}",
-            Theme::command("optimize"),
-            Theme::muted("or"),
-            Theme::command("o"),
-        );
-        println!(
-            "     {} Optimize Windows system performance",
-            Theme::muted("→")
-        );
-        println!();
-        println!(
-            "  {}  {}  {}",
-            Theme::command("status"),
-            Theme::muted("or"),
-            Theme::command("st"),
-        );
-        println!(
-            "     {} Show real-time system status dashboard",
-            Theme::muted("→")
-        );
+        for (i, (label, _)) in entries.iter().enumerate() {
+            if i == quick_actions {
+                println!();
+                println!("{}", Theme::primary("Profiles (opens the dashboard):"));
+                println!();
+            }
+            println!("  {}  {}", Theme::command(&(i + 1).to_string()), label);
+        }
         println!();
         println!("{}", Theme::divider(60));
-        println!();
-        println!("{}", Theme::primary("Quick Examples:"));
-        println!();
-        println!("  {} Launch interactive TUI mode", Theme::command("wole"));
         println!(
-            "  {} Scan all categories",
-            Theme::command("wole scan --all")
+            "  {} {}",
+            Theme::muted("Commands:"),
+            Theme::command(
+                "scan clean analyze restore optimize status why-full config update remove"
+            ),
         );
-        println!(
-            "  {} Scan specific categories",
-            Theme::command("wole scan --cache --temp")
-        );
-        println!(
-            "  {} Clean all files",
-            Theme::command("wole clean --all -y")
-        );
-        println!(
-            "  {} Find large files",
-            Theme::command("wole scan --large --min-size 500MB")
-        );
-        println!(
-            "  {} Interactive disk insights",
-            Theme::command("wole analyze --interactive")
-        );
-        println!(
-            "  {} Restore last deletion",
-            Theme::command("wole restore --last")
-        );
-        println!(
-            "  {} Run all system optimizations",
-            Theme::command("wole optimize --all")
-        );
-        println!("  {} Show system status", Theme::command("wole status"));
-        println!();
         println!(
             "{}",
             Theme::muted("Tip: Use --help with any command for detailed options")
         );
         println!();
+
+        if !std::io::stdin().is_terminal() {
+            return Ok(());
+        }
+        print!("{}", Theme::primary("Choose an option (Enter to quit): "));
+        let Some(input) = read_choice() else {
+            return Ok(());
+        };
+        let Some((_, choice)) = input
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|i| entries.get(i))
+        else {
+            if !input.is_empty() {
+                println!("{}", Theme::warning_msg("Unknown option"));
+            }
+            return Ok(());
+        };
+
+        let mut state = AppState::new();
+        match choice {
+            MenuChoice::RepeatLastClean(categories) => {
+                if state.apply_history_categories(categories) == 0 {
+                    println!(
+                        "{}",
+                        Theme::warning_msg("The last session's categories are no longer available")
+                    );
+                    return Ok(());
+                }
+                run_dashboard_action(&mut state, DashboardAction::Clean);
+            }
+            MenuChoice::SafeClean => {
+                state.apply_profile(CleanProfile::Safe);
+                run_dashboard_action(&mut state, DashboardAction::Clean);
+            }
+            MenuChoice::OpenTui => {}
+            MenuChoice::Status => run_dashboard_action(&mut state, DashboardAction::Status),
+            MenuChoice::Profile(profile) => state.apply_profile(*profile),
+        }
+        crate::tui::run(Some(state))
     }
 }
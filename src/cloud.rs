@@ -0,0 +1,341 @@
+//! Cloud-synced folders (OneDrive, Dropbox, Google Drive)
+//!
+//! Deleting a file inside a synced folder deletes it from the cloud and from
+//! every other device syncing it. With `exclusions.exclude_cloud_folders` (on
+//! by default) these folders are left out of scans entirely, like any other
+//! exclusion; with it off, results inside them are flagged in Results and
+//! Preview. The user's known folders (Desktop, Documents, Downloads, ...) are
+//! never excluded this way, even when OneDrive's folder backup has moved them
+//! into the OneDrive folder: their results are flagged instead.
+//!
+//! For OneDrive, [`free_up_space`] is offered as an alternative to deleting:
+//! like Explorer's "Free up space", it marks the files online-only and OneDrive
+//! removes the local copies while keeping them in the cloud.
+//!
+//! Sync roots are found once per process:
+//! - OneDrive: the `OneDrive`, `OneDriveConsumer` and `OneDriveCommercial`
+//!   environment variables and `OneDrive*` folders in the home folder;
+//! - Dropbox: the `info.json` the client writes, or `~/Dropbox`;
+//! - Google Drive: the "Google Drive" virtual drive of Drive for desktop, or
+//!   `~/Google Drive` / `~/My Drive` mirror folders.
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Sync client owning a folder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloudProvider {
+    OneDrive,
+    Dropbox,
+    GoogleDrive,
+}
+
+impl CloudProvider {
+    pub fn label(self) -> &'static str {
+        match self {
+            CloudProvider::OneDrive => "OneDrive",
+            CloudProvider::Dropbox => "Dropbox",
+            CloudProvider::GoogleDrive => "Google Drive",
+        }
+    }
+
+    /// Whether [`free_up_space`] works for this provider
+    pub fn can_free_up_space(self) -> bool {
+        self == CloudProvider::OneDrive && cfg!(windows)
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    directories::UserDirs::new().map(|dirs| dirs.home_dir().to_path_buf())
+}
+
+/// Dropbox folders listed in the client's `info.json`
+fn dropbox_info_paths(json: &str) -> Vec<PathBuf> {
+    let Ok(serde_json::Value::Object(accounts)) = serde_json::from_str(json) else {
+        return Vec::new();
+    };
+    accounts
+        .values()
+        .filter_map(|account| account.get("path")?.as_str())
+        .map(PathBuf::from)
+        .collect()
+}
+
+fn detect_roots() -> Vec<(PathBuf, CloudProvider)> {
+    let mut roots: Vec<(PathBuf, CloudProvider)> = Vec::new();
+    let home = home_dir();
+
+    for var in ["OneDrive", "OneDriveConsumer", "OneDriveCommercial"] {
+        if let Some(dir) = std::env::var_os(var) {
+            roots.push((PathBuf::from(dir), CloudProvider::OneDrive));
+        }
+    }
+    if let Some(home) = &home {
+        if let Ok(entries) = crate::utils::safe_read_dir(home) {
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if name.starts_with("OneDrive") && entry.path().is_dir() {
+                    roots.push((entry.path(), CloudProvider::OneDrive));
+                }
+            }
+        }
+    }
+
+    let mut info_files = Vec::new();
+    for var in ["APPDATA", "LOCALAPPDATA"] {
+        if let Some(dir) = std::env::var_os(var) {
+            info_files.push(PathBuf::from(dir).join("Dropbox").join("info.json"));
+        }
+    }
+    if let Some(home) = &home {
+        info_files.push(home.join(".dropbox").join("info.json"));
+        roots.push((home.join("Dropbox"), CloudProvider::Dropbox));
+    }
+    for file in info_files {
+        if let Ok(json) = std::fs::read_to_string(&file) {
+            roots.extend(
+                dropbox_info_paths(&json)
+                    .into_iter()
+                    .map(|path| (path, CloudProvider::Dropbox)),
+            );
+        }
+    }
+
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    for disk in disks.list() {
+        if disk.name().to_string_lossy() == "Google Drive" {
+            roots.push((disk.mount_point().to_path_buf(), CloudProvider::GoogleDrive));
+        }
+    }
+    if let Some(home) = &home {
+        for name in ["Google Drive", "My Drive"] {
+            roots.push((home.join(name), CloudProvider::GoogleDrive));
+        }
+    }
+
+    roots.retain(|(path, _)| path.is_dir());
+    roots.sort_by(|a, b| a.0.cmp(&b.0));
+    roots.dedup_by(|a, b| a.0 == b.0);
+    roots
+}
+
+/// Sync roots on this machine (detected on first use)
+pub fn sync_roots() -> &'static [(PathBuf, CloudProvider)] {
+    static ROOTS: OnceLock<Vec<(PathBuf, CloudProvider)>> = OnceLock::new();
+    ROOTS.get_or_init(detect_roots)
+}
+
+/// The user's Desktop, Documents, Downloads, Pictures, Music and Videos
+/// folders that live inside a sync root (detected on first use)
+pub fn synced_known_folders() -> &'static [PathBuf] {
+    static FOLDERS: OnceLock<Vec<PathBuf>> = OnceLock::new();
+    FOLDERS.get_or_init(|| {
+        let Some(dirs) = directories::UserDirs::new() else {
+            return Vec::new();
+        };
+        [
+            dirs.desktop_dir(),
+            dirs.document_dir(),
+            dirs.download_dir(),
+            dirs.picture_dir(),
+            dirs.audio_dir(),
+            dirs.video_dir(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|folder| provider_in(folder, sync_roots()).is_some())
+        .map(Path::to_path_buf)
+        .collect()
+    })
+}
+
+/// Whether `path` is `root` or inside it
+fn is_within(path: &Path, root: &Path) -> bool {
+    let normalize = |p: &Path| {
+        crate::restore::normalize_path_for_comparison(&p.to_string_lossy())
+            .trim_end_matches(['/', '\\'])
+            .to_string()
+    };
+    let (path, root) = (normalize(path), normalize(root));
+    path.strip_prefix(root.as_str())
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '\\']))
+}
+
+/// Provider of the innermost root in `roots` that holds `path`
+fn provider_in(path: &Path, roots: &[(PathBuf, CloudProvider)]) -> Option<CloudProvider> {
+    roots
+        .iter()
+        .filter(|(root, _)| is_within(path, root))
+        .max_by_key(|(root, _)| root.as_os_str().len())
+        .map(|(_, provider)| *provider)
+}
+
+/// Whether the cloud exclusion leaves `path` out: it is in a sync root, but
+/// neither in one of `known_folders` nor on the way to one
+fn excluded_in(path: &Path, roots: &[(PathBuf, CloudProvider)], known_folders: &[PathBuf]) -> bool {
+    provider_in(path, roots).is_some()
+        && !known_folders
+            .iter()
+            .any(|folder| is_within(path, folder) || is_within(folder, path))
+}
+
+/// Whether `exclusions.exclude_cloud_folders` leaves `path` out of scans
+pub fn is_excluded(path: &Path) -> bool {
+    let roots = sync_roots();
+    !roots.is_empty() && excluded_in(path, roots, synced_known_folders())
+}
+
+/// Provider syncing `path`, if it is inside a cloud-synced folder
+pub fn provider_for(path: &Path) -> Option<CloudProvider> {
+    let roots = sync_roots();
+    if roots.is_empty() {
+        return None;
+    }
+    provider_in(path, roots)
+}
+
+#[cfg(windows)]
+mod on_demand {
+    use std::path::Path;
+
+    pub const FILE_ATTRIBUTE_PINNED: u32 = 0x0008_0000;
+    pub const FILE_ATTRIBUTE_UNPINNED: u32 = 0x0010_0000;
+    pub const FILE_ATTRIBUTE_RECALL_ON_DATA_ACCESS: u32 = 0x0040_0000;
+
+    pub fn set_attributes(path: &Path, attributes: u32) -> bool {
+        let wide: Vec<u16> = crate::utils::long_path_if_needed(path)
+            .as_os_str()
+            .to_string_lossy()
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .collect();
+        unsafe {
+            extern "system" {
+                fn SetFileAttributesW(lpFileName: *const u16, dwFileAttributes: u32) -> i32;
+            }
+            SetFileAttributesW(wide.as_ptr(), attributes) != 0
+        }
+    }
+}
+
+/// Mark everything in `path` online-only so OneDrive frees the local copies
+///
+/// Returns the bytes of files that were still stored locally; OneDrive
+/// removes them in the background. In audit mode nothing is changed.
+#[cfg(windows)]
+pub fn free_up_space(path: &Path) -> Result<u64> {
+    use on_demand::*;
+    use std::os::windows::fs::MetadataExt;
+
+    if provider_for(path) != Some(CloudProvider::OneDrive) {
+        anyhow::bail!("{} is not in a OneDrive folder", path.display());
+    }
+    let audit = crate::audit::is_enabled();
+    let mut freed = 0;
+    let mut changed = 0;
+    for entry in walkdir::WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .flatten()
+    {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let attributes = metadata.file_attributes();
+        let local = attributes & FILE_ATTRIBUTE_RECALL_ON_DATA_ACCESS == 0;
+        let unpinned = (attributes & !FILE_ATTRIBUTE_PINNED) | FILE_ATTRIBUTE_UNPINNED;
+        if audit || set_attributes(entry.path(), unpinned) {
+            changed += 1;
+            if metadata.is_file() && local {
+                freed += metadata.len();
+            }
+        }
+    }
+    if audit {
+        crate::audit::note(&format!("free up space in {}", path.display()));
+    } else if changed == 0 {
+        anyhow::bail!("Could not mark {} online-only", path.display());
+    }
+    Ok(freed)
+}
+
+#[cfg(not(windows))]
+pub fn free_up_space(path: &Path) -> Result<u64> {
+    anyhow::bail!(
+        "Freeing up space is only available for OneDrive on Windows ({})",
+        path.display()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_in_innermost_root() {
+        let roots = vec![
+            (PathBuf::from("/home/me/Dropbox"), CloudProvider::Dropbox),
+            (
+                PathBuf::from("/home/me/Dropbox/Mirrored Drive"),
+                CloudProvider::GoogleDrive,
+            ),
+        ];
+        assert_eq!(
+            provider_in(Path::new("/home/me/Dropbox/photos/a.jpg"), &roots),
+            Some(CloudProvider::Dropbox)
+        );
+        assert_eq!(
+            provider_in(Path::new("/home/me/Dropbox/Mirrored Drive/b"), &roots),
+            Some(CloudProvider::GoogleDrive)
+        );
+        assert_eq!(
+            provider_in(Path::new("/home/me/Dropbox"), &roots),
+            Some(CloudProvider::Dropbox)
+        );
+        assert_eq!(
+            provider_in(Path::new("/home/me/DropboxOld/x"), &roots),
+            None
+        );
+    }
+
+    #[test]
+    fn test_known_folders_stay_in_scans() {
+        let roots = vec![(PathBuf::from("/home/me/OneDrive"), CloudProvider::OneDrive)];
+        let known = vec![PathBuf::from("/home/me/OneDrive/Documents")];
+        // The redirected Documents folder and the way to it are kept
+        assert!(!excluded_in(
+            Path::new("/home/me/OneDrive/Documents/old.zip"),
+            &roots,
+            &known
+        ));
+        assert!(!excluded_in(Path::new("/home/me/OneDrive"), &roots, &known));
+        // The rest of the sync root is not
+        assert!(excluded_in(
+            Path::new("/home/me/OneDrive/Shared/big.iso"),
+            &roots,
+            &known
+        ));
+        assert!(!excluded_in(
+            Path::new("/home/me/Downloads/a.zip"),
+            &roots,
+            &known
+        ));
+    }
+
+    #[test]
+    fn test_dropbox_info_paths() {
+        let json = r#"{"personal": {"path": "/home/me/Dropbox", "host": 1},
+                       "business": {"path": "/home/me/Dropbox (Acme)"}}"#;
+        let mut paths = dropbox_info_paths(json);
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("/home/me/Dropbox"),
+                PathBuf::from("/home/me/Dropbox (Acme)")
+            ]
+        );
+        assert!(dropbox_info_paths("not json").is_empty());
+    }
+}
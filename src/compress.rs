@@ -0,0 +1,248 @@
+//! Transparent folder compression - an alternative to deleting large or old folders
+//!
+//! Uses `compact.exe` to either set classic NTFS compression (LZNT1, new files are
+//! compressed too) or apply the Windows 10+ "CompactOS" algorithms (`/exe:`), which
+//! compress better but are undone when a file is rewritten. Sizes are measured
+//! before and after as size on disk so the savings are real.
+
+use crate::size::{dir_size_with_options, DirSizeOptions};
+use anyhow::{bail, Result};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Compression algorithm passed to `compact.exe`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompressionMode {
+    /// Classic NTFS compression (LZNT1); sticks to the folder for new files
+    Ntfs,
+    /// XPRESS with 4K chunks (fastest)
+    Xpress4k,
+    /// XPRESS with 8K chunks
+    #[default]
+    Xpress8k,
+    /// XPRESS with 16K chunks
+    Xpress16k,
+    /// LZX (best ratio, slowest; good for rarely-used files)
+    Lzx,
+}
+
+impl CompressionMode {
+    /// Name as accepted on the command line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompressionMode::Ntfs => "ntfs",
+            CompressionMode::Xpress4k => "xpress4k",
+            CompressionMode::Xpress8k => "xpress8k",
+            CompressionMode::Xpress16k => "xpress16k",
+            CompressionMode::Lzx => "lzx",
+        }
+    }
+
+    /// Arguments for `compact.exe` (run from inside the target folder)
+    fn compact_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "/c".to_string(),
+            "/s".to_string(),
+            "/i".to_string(),
+            "/q".to_string(),
+        ];
+        if *self != CompressionMode::Ntfs {
+            args.push(format!("/exe:{}", self.as_str()));
+        }
+        args
+    }
+}
+
+impl FromStr for CompressionMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "ntfs" | "lznt1" => Ok(CompressionMode::Ntfs),
+            "xpress4k" => Ok(CompressionMode::Xpress4k),
+            "xpress8k" | "xpress" => Ok(CompressionMode::Xpress8k),
+            "xpress16k" => Ok(CompressionMode::Xpress16k),
+            "lzx" => Ok(CompressionMode::Lzx),
+            other => bail!(
+                "Unknown compression mode: {}. Supported: ntfs, xpress4k, xpress8k, xpress16k, lzx",
+                other
+            ),
+        }
+    }
+}
+
+/// Outcome of compressing one folder
+#[derive(Debug, Clone)]
+pub struct CompressionResult {
+    pub path: PathBuf,
+    /// Size on disk before compressing
+    pub before_bytes: u64,
+    /// Size on disk after compressing (equal to `before_bytes` on dry run or failure)
+    pub after_bytes: u64,
+    pub success: bool,
+    pub message: String,
+}
+
+impl CompressionResult {
+    /// Bytes freed by compression
+    pub fn saved_bytes(&self) -> u64 {
+        self.before_bytes.saturating_sub(self.after_bytes)
+    }
+}
+
+/// Size on disk, always walked (compression doesn't change mtimes, so a cached
+/// size would be stale)
+fn size_on_disk(path: &Path) -> u64 {
+    let options = DirSizeOptions {
+        size_on_disk: true,
+        ..Default::default()
+    };
+    dir_size_with_options(path, &options, None)
+        .map(|totals| totals.size(&options))
+        .unwrap_or(0)
+}
+
+/// Compress a folder in place with `compact.exe`
+pub fn compress_path(path: &Path, mode: CompressionMode, dry_run: bool) -> CompressionResult {
+    let before_bytes = size_on_disk(path);
+    let result = |success: bool, after_bytes: u64, message: String| CompressionResult {
+        path: path.to_path_buf(),
+        before_bytes,
+        after_bytes,
+        success,
+        message,
+    };
+
+    if !path.is_dir() {
+        return result(false, before_bytes, "Not a directory".to_string());
+    }
+
+    if crate::audit::is_enabled() {
+        crate::audit::note(&format!("compress {}", path.display()));
+    }
+    // Audit mode reports the compact.exe run instead of changing anything
+    let dry_run = dry_run || crate::audit::is_enabled();
+    if dry_run {
+        return result(
+            true,
+            before_bytes,
+            format!(
+                "Dry run mode - would run: compact {}",
+                mode.compact_args().join(" ")
+            ),
+        );
+    }
+
+    #[cfg(windows)]
+    {
+        use std::process::Command;
+
+        match Command::new("compact")
+            .args(mode.compact_args())
+            .current_dir(path)
+            .output()
+        {
+            Ok(output) if output.status.success() => {
+                let after_bytes = size_on_disk(path);
+                result(
+                    true,
+                    after_bytes,
+                    format!(
+                        "{} → {}",
+                        bytesize::to_string(before_bytes, false),
+                        bytesize::to_string(after_bytes, false)
+                    ),
+                )
+            }
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                result(
+                    false,
+                    size_on_disk(path),
+                    format!("compact failed: {}", stderr.trim()),
+                )
+            }
+            Err(e) => result(
+                false,
+                before_bytes,
+                format!("Failed to execute compact: {}", e),
+            ),
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        result(
+            false,
+            before_bytes,
+            "Compression is only supported on Windows".to_string(),
+        )
+    }
+}
+
+/// Compress several folders, one `compact.exe` run per folder
+pub fn compress_paths(
+    paths: &[PathBuf],
+    mode: CompressionMode,
+    dry_run: bool,
+) -> Vec<CompressionResult> {
+    paths
+        .iter()
+        .map(|path| compress_path(path, mode, dry_run))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_compression_mode() {
+        assert_eq!(
+            "ntfs".parse::<CompressionMode>().unwrap(),
+            CompressionMode::Ntfs
+        );
+        assert_eq!(
+            "XPRESS16K".parse::<CompressionMode>().unwrap(),
+            CompressionMode::Xpress16k
+        );
+        assert_eq!(
+            "lzx".parse::<CompressionMode>().unwrap(),
+            CompressionMode::Lzx
+        );
+        assert!("zip".parse::<CompressionMode>().is_err());
+    }
+
+    #[test]
+    fn test_compact_args() {
+        assert!(!CompressionMode::Ntfs
+            .compact_args()
+            .iter()
+            .any(|a| a.starts_with("/exe")));
+        assert!(CompressionMode::Lzx
+            .compact_args()
+            .contains(&"/exe:lzx".to_string()));
+    }
+
+    #[test]
+    fn test_dry_run_keeps_size() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(temp_dir.path().join("data.bin"), vec![0u8; 8192]).unwrap();
+
+        let result = compress_path(temp_dir.path(), CompressionMode::default(), true);
+        assert!(result.success);
+        assert_eq!(result.saved_bytes(), 0);
+    }
+
+    #[test]
+    fn test_audit_mode_only_reports() {
+        crate::audit::enable_for_test();
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(temp_dir.path().join("data.bin"), vec![0u8; 8192]).unwrap();
+
+        let result = compress_path(temp_dir.path(), CompressionMode::Lzx, false);
+        assert!(result.success);
+        assert_eq!(result.saved_bytes(), 0);
+        assert!(result.message.contains("/exe:lzx"));
+    }
+}
@@ -33,6 +33,15 @@ pub struct Config {
 
     #[serde(default)]
     pub cache: CacheSettings,
+
+    #[serde(default)]
+    pub plugins: PluginSettings,
+
+    #[serde(default)]
+    pub optimize: OptimizeSettings,
+
+    #[serde(default)]
+    pub temperature: TemperatureSettings,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,12 +54,30 @@ pub struct Thresholds {
 
     #[serde(default = "default_min_size_mb")]
     pub min_size_mb: u64,
+
+    /// Large Files are grouped into tiers starting at these sizes (MB);
+    /// min_size_mb is always the lowest tier
+    #[serde(default = "default_large_file_tiers_mb")]
+    pub large_file_tiers_mb: Vec<u64>,
+
+    /// Crash dumps and error reports younger than this are left alone
+    #[serde(default = "default_crash_dump_age")]
+    pub crash_dump_age_days: u64,
+
+    /// IDE/toolchain cache parts unused for less than this are left alone
+    #[serde(default = "default_toolchain_cache_age")]
+    pub toolchain_cache_age_days: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Paths {
     #[serde(default)]
     pub scan_roots: Vec<String>,
+
+    /// Whose profiles to scan for cache/temp/downloads: "current" or "all-users"
+    /// "all-users" needs an elevated prompt (falls back to "current" in the TUI)
+    #[serde(default = "default_scope")]
+    pub scope: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -58,6 +85,12 @@ pub struct Exclusions {
     #[serde(default)]
     pub patterns: Vec<String>,
 
+    /// Leave OneDrive, Dropbox and Google Drive folders out of scans: deleting
+    /// there deletes from the cloud too (see [`crate::cloud`]). Known folders
+    /// moved into OneDrive (Documents, Downloads, ...) are still scanned.
+    #[serde(default = "default_true")]
+    pub exclude_cloud_folders: bool,
+
     /// Compiled glob patterns for fast matching (lazily initialized)
     #[serde(skip)]
     #[allow(dead_code)]
@@ -95,6 +128,114 @@ pub struct UiSettings {
     /// Higher values scan deeper but take longer
     #[serde(default = "default_scan_depth_entire_disk")]
     pub scan_depth_entire_disk: u8,
+
+    /// How eagerly scan results are pre-selected, 0-100 (0 = select nothing,
+    /// 100 = select everything; see `auto_select`)
+    #[serde(default = "default_auto_select_aggressiveness")]
+    pub auto_select_aggressiveness: u8,
+
+    /// UI language: "auto" (system locale), "en", "de", "es" or "fr"
+    #[serde(default = "default_language")]
+    pub language: String,
+
+    /// Folders pinned for Disk Insights; the first nine are on keys 1-9
+    /// on the Dashboard and in Disk Insights
+    #[serde(default)]
+    pub pinned_dirs: Vec<String>,
+
+    /// Saved Results searches, applied with V in Results or `wole scan --view NAME`
+    #[serde(default)]
+    pub saved_views: Vec<SavedView>,
+}
+
+/// A named Results search ("smart view")
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedView {
+    pub name: String,
+
+    /// Search as typed after `/` in Results, e.g. `/type:.iso` or `owner:alice setup`
+    #[serde(default)]
+    pub query: String,
+
+    /// Category ids shown ("large", "old", ...); empty = every category
+    #[serde(default)]
+    pub categories: Vec<String>,
+}
+
+impl SavedView {
+    /// Split a Results search into the query and its `cat:` categories
+    pub fn from_search(name: &str, search: &str) -> Self {
+        let mut categories = Vec::new();
+        let mut rest = Vec::new();
+        for word in search.split_whitespace() {
+            match word.get(..4) {
+                Some(prefix) if prefix.eq_ignore_ascii_case("cat:") => categories.extend(
+                    word[4..]
+                        .split(',')
+                        .filter(|id| !id.is_empty())
+                        .map(|id| id.to_lowercase().replace('-', "_")),
+                ),
+                _ => rest.push(word),
+            }
+        }
+        Self {
+            name: name.trim().to_string(),
+            query: rest.join(" "),
+            categories,
+        }
+    }
+
+    /// The Results search that shows this view
+    pub fn search(&self) -> String {
+        if self.categories.is_empty() {
+            self.query.clone()
+        } else {
+            format!("cat:{} {}", self.categories.join(","), self.query)
+                .trim_end()
+                .to_string()
+        }
+    }
+}
+
+impl UiSettings {
+    /// Pinned folders, in the order they were pinned
+    pub fn pinned_paths(&self) -> Vec<PathBuf> {
+        self.pinned_dirs.iter().map(PathBuf::from).collect()
+    }
+
+    /// Pin `dir`, or unpin it if it's already pinned. Returns whether it is pinned now.
+    pub fn toggle_pin(&mut self, dir: &Path) -> bool {
+        if let Some(index) = self
+            .pinned_dirs
+            .iter()
+            .position(|pinned| Path::new(pinned) == dir)
+        {
+            self.pinned_dirs.remove(index);
+            false
+        } else {
+            self.pinned_dirs.push(dir.display().to_string());
+            true
+        }
+    }
+
+    /// The saved view called `name` (case-insensitive)
+    pub fn saved_view(&self, name: &str) -> Option<&SavedView> {
+        self.saved_views
+            .iter()
+            .find(|view| view.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Save `view`, replacing the one with the same name
+    pub fn save_view(&mut self, view: SavedView) {
+        match self
+            .saved_views
+            .iter_mut()
+            .find(|saved| saved.name.eq_ignore_ascii_case(&view.name))
+        {
+            Some(saved) => *saved = view,
+            None => self.saved_views.push(view),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -122,6 +263,18 @@ pub struct SafetySettings {
     /// Dry run by default (don't actually delete, just show what would be deleted)
     #[serde(default = "default_false")]
     pub dry_run_default: bool,
+
+    /// Also clear hidden/system attributes when a delete is denied (read-only is always cleared)
+    #[serde(default = "default_false")]
+    pub clear_hidden_system: bool,
+
+    /// When elevated, take ownership of denied paths inside user profiles and retry
+    #[serde(default = "default_false")]
+    pub take_ownership: bool,
+
+    /// Read-only audit mode: never modify anything, only report what would happen
+    #[serde(default = "default_false")]
+    pub audit: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -137,6 +290,123 @@ pub struct PerformanceSettings {
     /// Enable parallel scanning (can be disabled for debugging)
     #[serde(default = "default_true")]
     pub parallel_scanning: bool,
+
+    /// Maximum worker threads for scanning and size calculation (0 = use scan_threads/auto)
+    /// Lower this on HDDs, where many parallel readers cause seek thrashing
+    #[serde(default = "default_threads_usize")]
+    pub max_threads: usize,
+
+    /// I/O priority for worker threads: "normal", "low", or "background"
+    /// "background" uses Windows background mode (low I/O and CPU priority)
+    #[serde(default = "default_io_priority")]
+    pub io_priority: String,
+
+    /// Maximum paths per Recycle Bin batch when cleaning (0 = one batch)
+    #[serde(default = "default_delete_batch_size")]
+    pub delete_batch_size: usize,
+
+    /// Per-category overrides of `max_threads`, `io_priority` and
+    /// `delete_batch_size`, keyed by category id
+    /// (e.g. `[performance.category_overrides.duplicates]`)
+    #[serde(default)]
+    pub category_overrides: std::collections::HashMap<String, CategoryPerformance>,
+
+    /// Volumes the TUI cleans or restores at the same time, one worker each
+    /// (0 = auto: up to max_threads or the CPU count; 1 = one volume after another)
+    #[serde(default)]
+    pub clean_workers: usize,
+
+    /// Deletion speed limit in files per second (0 = unlimited)
+    #[serde(default)]
+    pub delete_files_per_sec: u64,
+
+    /// Deletion speed limit in MB per second, counting file sizes (0 = unlimited)
+    #[serde(default)]
+    pub delete_mb_per_sec: u64,
+
+    /// Seconds a category may scan before the TUI asks whether to skip it (0 = never ask)
+    #[serde(default = "default_category_time_budget")]
+    pub category_time_budget_secs: u64,
+
+    /// Per-category overrides of `category_time_budget_secs`, keyed by category id
+    /// (e.g. `duplicates = 1800`)
+    #[serde(default)]
+    pub category_time_budgets: std::collections::HashMap<String, u64>,
+
+    /// Stop scanning further categories once the ones scanned so far found this
+    /// many MB to reclaim (0 = scan everything)
+    #[serde(default)]
+    pub scan_size_budget_mb: u64,
+
+    /// Scan strategy by drive type: "auto" (detect the scan root's drive),
+    /// "ssd", "hdd", "removable" or "off" (see `drive`)
+    #[serde(default = "default_drive_strategy")]
+    pub drive_strategy: String,
+
+    /// Hash files for Duplicates on removable drives too (skipped there by default)
+    #[serde(default)]
+    pub hash_removable: bool,
+
+    /// Count NTFS alternate data streams (Zone.Identifier and others) in
+    /// reported sizes; costs one extra call per file (see `ads`)
+    #[serde(default)]
+    pub scan_ads: bool,
+}
+
+/// Throttle settings one category uses instead of the `[performance]` ones
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryPerformance {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_threads: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io_priority: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_batch_size: Option<usize>,
+}
+
+impl PerformanceSettings {
+    /// These settings with the overrides of the category with id `key` applied
+    pub fn for_category(&self, key: &str) -> PerformanceSettings {
+        let mut settings = self.clone();
+        if let Some(overrides) = self.category_overrides.get(key) {
+            if let Some(threads) = overrides.max_threads {
+                settings.max_threads = threads;
+            }
+            if let Some(ref priority) = overrides.io_priority {
+                settings.io_priority = priority.clone();
+            }
+            if let Some(size) = overrides.delete_batch_size {
+                settings.delete_batch_size = size;
+            }
+        }
+        settings
+    }
+
+    /// Most volumes cleaned at once
+    pub fn clean_worker_limit(&self) -> usize {
+        match self.clean_workers {
+            0 if self.max_threads > 0 => self.max_threads,
+            0 => std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+            n => n,
+        }
+    }
+
+    /// Time budget for the category with id `key` (None = no budget)
+    pub fn category_budget(&self, key: &str) -> Option<std::time::Duration> {
+        let secs = self
+            .category_time_budgets
+            .get(key)
+            .copied()
+            .unwrap_or(self.category_time_budget_secs);
+        (secs > 0).then(|| std::time::Duration::from_secs(secs))
+    }
+
+    /// Reclaimable bytes after which a scan skips its remaining categories (None = no budget)
+    pub fn size_budget(&self) -> Option<u64> {
+        (self.scan_size_budget_mb > 0).then(|| self.scan_size_budget_mb * 1024 * 1024)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -152,6 +422,16 @@ pub struct HistorySettings {
     /// Maximum age of history entries in days (0 = keep forever)
     #[serde(default = "default_history_age_days")]
     pub max_age_days: u64,
+
+    /// Hash-chain every record and never overwrite a session file,
+    /// so `wole history verify` can detect edits and missing entries
+    #[serde(default)]
+    pub tamper_evident: bool,
+
+    /// SHA-256, size and modification time of every file in the history
+    /// record of a permanent delete; hashing reads everything being deleted
+    #[serde(default)]
+    pub record_checksums: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -167,6 +447,11 @@ pub struct CacheSettings {
     #[serde(default = "default_false")]
     pub full_disk_baseline: bool,
 
+    /// Also record every file from the baseline traversal in a name index for
+    /// the TUI search screen (default: false)
+    #[serde(default = "default_false")]
+    pub search_index: bool,
+
     /// Max age of cache entries in days (default: 30)
     #[serde(default = "default_cache_age")]
     pub max_age_days: u64,
@@ -182,12 +467,90 @@ impl Default for CacheSettings {
         Self {
             enabled: default_true(),
             full_disk_baseline: default_false(),
+            search_index: default_false(),
             max_age_days: default_cache_age(),
             content_hash_threshold_bytes: default_hash_threshold(),
         }
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginSettings {
+    /// Run category plugins from the plugins directory next to config.toml (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Seconds a plugin may take per scan or clean request before it is killed (default: 120)
+    #[serde(default = "default_plugin_timeout")]
+    pub timeout_secs: u64,
+}
+
+impl Default for PluginSettings {
+    fn default() -> Self {
+        Self {
+            enabled: default_true(),
+            timeout_secs: default_plugin_timeout(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimizeSettings {
+    /// Trim the standby list automatically while the Status screen is open (default: false)
+    #[serde(default)]
+    pub auto_trim_standby: bool,
+
+    /// Trim when available memory drops below this many MB (default: 1024)
+    #[serde(default = "default_trim_threshold_mb")]
+    pub trim_threshold_mb: u64,
+
+    /// Minimum seconds between two automatic trims (default: 300)
+    #[serde(default = "default_trim_cooldown_secs")]
+    pub trim_cooldown_secs: u64,
+
+    /// List a process as a possible leak on the Status screen once its memory
+    /// has grown this many MB without dropping (default: 500)
+    #[serde(default = "default_leak_threshold_mb")]
+    pub leak_threshold_mb: u64,
+}
+
+impl Default for OptimizeSettings {
+    fn default() -> Self {
+        Self {
+            auto_trim_standby: false,
+            trim_threshold_mb: default_trim_threshold_mb(),
+            trim_cooldown_secs: default_trim_cooldown_secs(),
+            leak_threshold_mb: default_leak_threshold_mb(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemperatureSettings {
+    /// Show a sensor as running hot from this temperature (default: 75)
+    #[serde(default = "default_warning_celsius")]
+    pub warning_celsius: f32,
+
+    /// Show a sensor as critical, and alert during scans and cleanups, from
+    /// this temperature or the sensor's own critical point if lower (default: 85)
+    #[serde(default = "default_critical_celsius")]
+    pub critical_celsius: f32,
+
+    /// Append readings to temperature.csv in the logs folder (default: false)
+    #[serde(default)]
+    pub log: bool,
+}
+
+impl Default for TemperatureSettings {
+    fn default() -> Self {
+        Self {
+            warning_celsius: default_warning_celsius(),
+            critical_celsius: default_critical_celsius(),
+            log: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CategorySettings {
     /// Default enabled categories for TUI (empty = use hardcoded defaults)
@@ -209,6 +572,30 @@ pub struct CategorySettings {
 
     #[serde(default)]
     pub duplicates: DuplicatesConfig,
+
+    #[serde(default)]
+    pub logs: LogsConfig,
+
+    #[serde(default)]
+    pub creative: CreativeCacheConfig,
+
+    #[serde(default)]
+    pub git_ignored: GitIgnoredConfig,
+
+    #[serde(default)]
+    pub big_media: BigMediaConfig,
+}
+
+impl CategorySettings {
+    /// Whether `fast_delete` is on for a category id (only build, large and old have it)
+    pub fn fast_delete(&self, category_id: &str) -> bool {
+        match category_id {
+            "build" => self.build.fast_delete,
+            "large" => self.large.fast_delete,
+            "old" => self.old.fast_delete,
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -225,6 +612,23 @@ pub struct CategoryConfig {
     /// Merged with default artifacts
     #[serde(default)]
     pub custom_artifacts: Vec<String>,
+
+    /// Rename folders aside and delete them permanently in the background
+    /// instead of using the Recycle Bin (build, large and old categories)
+    #[serde(default)]
+    pub fast_delete: bool,
+
+    /// Clear caches with the package manager's own command (npm, pip, yarn,
+    /// pnpm, NuGet, Scoop, Chocolatey) instead of deleting the folder
+    /// (cache category only)
+    #[serde(default)]
+    pub use_manager_commands: bool,
+
+    /// Timestamp a file's age is measured from: "modified" (default),
+    /// "accessed", "created" or "max" (the latest of the three)
+    /// (old category only, see `categories::old::TimeBasis`)
+    #[serde(default)]
+    pub time_basis: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -246,12 +650,84 @@ pub struct DuplicatesConfig {
     pub buffer_size_bytes: usize,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsConfig {
+    /// A single log file at least this large (MB) is reported
+    /// Default: 50MB
+    #[serde(default = "default_log_min_size_mb")]
+    pub min_size_mb: u64,
+
+    /// A rotating log set with at least this many segments is reported
+    /// Default: 100
+    #[serde(default = "default_log_max_segments")]
+    pub max_segments: usize,
+
+    /// Truncate logs that are still being written to instead of deleting them
+    /// Default: false (active logs are skipped)
+    #[serde(default = "default_false")]
+    pub truncate_active: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreativeCacheConfig {
+    /// Cache files touched more recently than this are kept
+    /// Default: 14 days
+    #[serde(default = "default_creative_min_age_days")]
+    pub min_age_days: u64,
+
+    /// Extra cache folders to treat like the built-in ones
+    /// (e.g. a Premiere media cache moved to another drive)
+    #[serde(default)]
+    pub custom_paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitIgnoredConfig {
+    /// Ignored files matching these patterns are never listed
+    /// Default: local env files, IDE settings and private keys
+    #[serde(default = "default_git_ignored_keep")]
+    pub keep: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BigMediaConfig {
+    /// Large Files with these extensions are shown as big media
+    /// Default: videos, disc and disk images, installers
+    #[serde(default = "default_big_media_extensions")]
+    pub extensions: Vec<String>,
+
+    /// Media libraries: big media in them stays with the other large files.
+    /// Relative folders are in the user profile
+    /// Default: ["Videos", "Music"]
+    #[serde(default = "default_big_media_libraries")]
+    pub library_folders: Vec<String>,
+}
+
+impl Thresholds {
+    /// Large Files tiers in MB, biggest first, ending with min_size_mb
+    pub fn large_file_tiers(&self) -> Vec<u64> {
+        let mut tiers: Vec<u64> = self
+            .large_file_tiers_mb
+            .iter()
+            .copied()
+            .filter(|&mb| mb > self.min_size_mb)
+            .chain([self.min_size_mb])
+            .collect();
+        tiers.sort_unstable_by(|a, b| b.cmp(a));
+        tiers.dedup();
+        tiers
+    }
+}
+
 impl Default for Thresholds {
     fn default() -> Self {
         Self {
             project_age_days: default_project_age(),
             min_age_days: default_min_age(),
             min_size_mb: default_min_size_mb(),
+            large_file_tiers_mb: default_large_file_tiers_mb(),
+            crash_dump_age_days: default_crash_dump_age(),
+            toolchain_cache_age_days: default_toolchain_cache_age(),
         }
     }
 }
@@ -260,12 +736,31 @@ impl Default for Exclusions {
     fn default() -> Self {
         Self {
             patterns: Vec::new(),
+            exclude_cloud_folders: true,
             compiled: OnceLock::new(),
         }
     }
 }
 
 impl Exclusions {
+    /// Add a pattern; returns false if it was already there
+    pub fn add(&mut self, pattern: String) -> bool {
+        if self.patterns.contains(&pattern) {
+            return false;
+        }
+        self.patterns.push(pattern);
+        self.compiled = OnceLock::new();
+        true
+    }
+
+    /// Remove a pattern; returns false if it wasn't there
+    pub fn remove(&mut self, pattern: &str) -> bool {
+        let before = self.patterns.len();
+        self.patterns.retain(|p| p != pattern);
+        self.compiled = OnceLock::new();
+        self.patterns.len() != before
+    }
+
     /// Get or compile the glob set for fast matching
     fn get_compiled(&self) -> Option<&GlobSet> {
         self.compiled
@@ -304,6 +799,19 @@ impl Default for UiSettings {
             show_storage_info: default_false(),
             scan_depth_user: default_scan_depth_user(),
             scan_depth_entire_disk: default_scan_depth_entire_disk(),
+            auto_select_aggressiveness: default_auto_select_aggressiveness(),
+            language: default_language(),
+            pinned_dirs: Vec::new(),
+            saved_views: Vec::new(),
+        }
+    }
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Self {
+            scan_roots: Vec::new(),
+            scope: default_scope(),
         }
     }
 }
@@ -317,6 +825,9 @@ impl Default for SafetySettings {
             max_size_no_confirm_mb: default_max_size_no_confirm(),
             skip_locked_files: default_true(),
             dry_run_default: default_false(),
+            clear_hidden_system: default_false(),
+            take_ownership: default_false(),
+            audit: default_false(),
         }
     }
 }
@@ -327,6 +838,19 @@ impl Default for PerformanceSettings {
             scan_threads: default_threads(),
             batch_size: default_batch_size(),
             parallel_scanning: default_true(),
+            max_threads: default_threads_usize(),
+            io_priority: default_io_priority(),
+            delete_batch_size: default_delete_batch_size(),
+            category_overrides: std::collections::HashMap::new(),
+            clean_workers: 0,
+            delete_files_per_sec: 0,
+            delete_mb_per_sec: 0,
+            category_time_budget_secs: default_category_time_budget(),
+            category_time_budgets: std::collections::HashMap::new(),
+            scan_size_budget_mb: 0,
+            drive_strategy: default_drive_strategy(),
+            hash_removable: false,
+            scan_ads: false,
         }
     }
 }
@@ -337,6 +861,44 @@ impl Default for HistorySettings {
             enabled: default_true(),
             max_entries: default_max_history(),
             max_age_days: default_history_age_days(),
+            tamper_evident: false,
+            record_checksums: false,
+        }
+    }
+}
+
+impl Default for LogsConfig {
+    fn default() -> Self {
+        Self {
+            min_size_mb: default_log_min_size_mb(),
+            max_segments: default_log_max_segments(),
+            truncate_active: default_false(),
+        }
+    }
+}
+
+impl Default for CreativeCacheConfig {
+    fn default() -> Self {
+        Self {
+            min_age_days: default_creative_min_age_days(),
+            custom_paths: Vec::new(),
+        }
+    }
+}
+
+impl Default for BigMediaConfig {
+    fn default() -> Self {
+        Self {
+            extensions: default_big_media_extensions(),
+            library_folders: default_big_media_libraries(),
+        }
+    }
+}
+
+impl Default for GitIgnoredConfig {
+    fn default() -> Self {
+        Self {
+            keep: default_git_ignored_keep(),
         }
     }
 }
@@ -376,6 +938,24 @@ fn default_threads() -> u32 {
 fn default_batch_size() -> usize {
     1000
 }
+fn default_threads_usize() -> usize {
+    0
+} // 0 = auto-detect
+fn default_scope() -> String {
+    "current".to_string()
+}
+fn default_io_priority() -> String {
+    "normal".to_string()
+}
+fn default_drive_strategy() -> String {
+    "auto".to_string()
+}
+fn default_delete_batch_size() -> usize {
+    0
+} // 0 = single batch
+fn default_category_time_budget() -> u64 {
+    600
+} // 10 minutes
 fn default_max_history() -> u64 {
     10000
 }
@@ -392,6 +972,44 @@ fn default_min_age() -> u64 {
 fn default_min_size_mb() -> u64 {
     100
 }
+fn default_large_file_tiers_mb() -> Vec<u64> {
+    vec![1024, 10 * 1024]
+}
+fn default_crash_dump_age() -> u64 {
+    7
+}
+fn default_toolchain_cache_age() -> u64 {
+    90
+}
+fn default_log_min_size_mb() -> u64 {
+    50
+}
+fn default_log_max_segments() -> usize {
+    100
+}
+fn default_creative_min_age_days() -> u64 {
+    14
+}
+fn default_big_media_extensions() -> Vec<String> {
+    [
+        "mkv", "mp4", "m4v", "avi", "mov", "wmv", "ts", "webm", "iso", "img", "vhd", "vhdx",
+        "vmdk", "msi", "msix", "exe",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+fn default_big_media_libraries() -> Vec<String> {
+    vec!["Videos".to_string(), "Music".to_string()]
+}
+fn default_git_ignored_keep() -> Vec<String> {
+    [
+        ".env", ".env.*", "*.local", ".idea", ".vscode", "*.pem", "*.key", "*.pfx",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
 fn default_memmap_threshold() -> u64 {
     10 * 1024 * 1024
 } // 10MB
@@ -404,9 +1022,35 @@ fn default_scan_depth_user() -> u8 {
 fn default_scan_depth_entire_disk() -> u8 {
     10
 }
+fn default_auto_select_aggressiveness() -> u8 {
+    40
+}
+fn default_language() -> String {
+    "auto".to_string()
+}
 fn default_cache_age() -> u64 {
     30
 }
+fn default_plugin_timeout() -> u64 {
+    120
+}
+fn default_trim_threshold_mb() -> u64 {
+    1024
+}
+fn default_trim_cooldown_secs() -> u64 {
+    300
+}
+fn default_leak_threshold_mb() -> u64 {
+    500
+}
+
+fn default_warning_celsius() -> f32 {
+    75.0
+}
+fn default_critical_celsius() -> f32 {
+    85.0
+}
+
 fn default_hash_threshold() -> u64 {
     10 * 1024 * 1024 // 10MB
 }
@@ -519,6 +1163,14 @@ impl Config {
     ///
     /// Uses pre-compiled glob patterns for O(1) matching instead of O(patterns)
     pub fn is_excluded(&self, path: &Path) -> bool {
+        // Exclusions forced by the machine policy always apply
+        if crate::policy::current().is_excluded(path) {
+            return true;
+        }
+        if self.exclusions.exclude_cloud_folders && crate::cloud::is_excluded(path) {
+            return true;
+        }
+
         // Fast path: no patterns
         if self.exclusions.patterns.is_empty() {
             return false;
@@ -542,6 +1194,29 @@ impl Config {
     }
 }
 
+/// Exclusion pattern that protects `folder` and everything in it
+///
+/// Glob characters in folder names are matched literally.
+pub fn folder_exclusion_pattern(folder: &Path) -> String {
+    let mut pattern = String::new();
+    for c in folder
+        .to_string_lossy()
+        .replace('\\', "/")
+        .trim_end_matches('/')
+        .chars()
+    {
+        if matches!(c, '*' | '?' | '[' | ']' | '{' | '}') {
+            pattern.push('[');
+            pattern.push(c);
+            pattern.push(']');
+        } else {
+            pattern.push(c);
+        }
+    }
+    pattern.push_str("/**");
+    pattern
+}
+
 /// Simple glob pattern matching
 /// Supports ** for recursive matching and * for wildcards
 fn matches_pattern(path_lower: &str, pattern: &str) -> bool {
@@ -688,6 +1363,54 @@ mod tests {
         assert!(!config.is_excluded(Path::new("C:/Users/me/other/file.txt")));
     }
 
+    #[test]
+    fn test_protect_folder_pattern() {
+        let mut config = Config::default();
+        let folder = Path::new("/home/me/Projects/keep [v2]");
+        let pattern = folder_exclusion_pattern(folder);
+        assert_eq!(pattern, "/home/me/Projects/keep [[]v2[]]/**");
+
+        // Matching is cached, so adding and removing must reset it
+        assert!(!config.is_excluded(&folder.join("node_modules")));
+        assert!(config.exclusions.add(pattern.clone()));
+        assert!(!config.exclusions.add(pattern.clone()));
+        assert!(config.is_excluded(&folder.join("node_modules")));
+        assert!(!config.is_excluded(Path::new("/home/me/Projects/other/node_modules")));
+        assert!(config.exclusions.remove(&pattern));
+        assert!(!config.is_excluded(&folder.join("node_modules")));
+    }
+
+    #[test]
+    fn test_pinned_dirs() {
+        let mut ui = UiSettings::default();
+        assert!(ui.toggle_pin(Path::new("/home/me/Downloads")));
+        assert!(ui.toggle_pin(Path::new("/media")));
+        assert_eq!(
+            ui.pinned_paths(),
+            vec![PathBuf::from("/home/me/Downloads"), PathBuf::from("/media")]
+        );
+        assert!(!ui.toggle_pin(Path::new("/home/me/Downloads/")));
+        assert_eq!(ui.pinned_paths(), vec![PathBuf::from("/media")]);
+
+        // Configs written before pins existed still load
+        let ui: UiSettings = toml::from_str("language = \"en\"").unwrap();
+        assert!(ui.pinned_dirs.is_empty());
+    }
+
+    #[test]
+    fn test_saved_views() {
+        let view = SavedView::from_search("Old ISOs", "cat:large,Old /type:.iso  setup");
+        assert_eq!(view.query, "/type:.iso setup");
+        assert_eq!(view.categories, ["large", "old"]);
+        assert_eq!(view.search(), "cat:large,old /type:.iso setup");
+
+        let mut ui = UiSettings::default();
+        ui.save_view(view);
+        ui.save_view(SavedView::from_search("old isos", "/type:.iso"));
+        assert_eq!(ui.saved_views.len(), 1);
+        assert_eq!(ui.saved_view("OLD ISOS").unwrap().search(), "/type:.iso");
+    }
+
     #[test]
     #[ignore = "temporarily disabled to debug stack overflow"]
     fn test_config_apply_cli_overrides() {
@@ -712,4 +1435,85 @@ mod tests {
         assert_eq!(config.thresholds.min_age_days, original_age);
         assert_eq!(config.thresholds.min_size_mb, 100); // Default
     }
+
+    #[test]
+    fn test_large_file_tiers() {
+        let mut thresholds = Thresholds::default();
+        assert_eq!(thresholds.large_file_tiers(), [10240, 1024, 100]);
+
+        // Tiers at or under the scan threshold are dropped
+        thresholds.min_size_mb = 2048;
+        thresholds.large_file_tiers_mb = vec![500, 2048, 4096, 4096];
+        assert_eq!(thresholds.large_file_tiers(), [4096, 2048]);
+    }
+
+    #[test]
+    fn test_clean_worker_limit() {
+        let mut performance = PerformanceSettings::default();
+        assert!(performance.clean_worker_limit() >= 1);
+        performance.max_threads = 3;
+        assert_eq!(performance.clean_worker_limit(), 3);
+        performance.clean_workers = 1;
+        assert_eq!(performance.clean_worker_limit(), 1);
+    }
+
+    #[test]
+    fn test_category_budget() {
+        let mut performance = PerformanceSettings::default();
+        performance
+            .category_time_budgets
+            .insert("duplicates".to_string(), 1800);
+        performance
+            .category_time_budgets
+            .insert("logs".to_string(), 0);
+
+        assert_eq!(
+            performance.category_budget("duplicates"),
+            Some(std::time::Duration::from_secs(1800))
+        );
+        assert_eq!(performance.category_budget("logs"), None);
+        assert_eq!(
+            performance.category_budget("empty"),
+            Some(std::time::Duration::from_secs(600))
+        );
+
+        performance.category_time_budget_secs = 0;
+        assert_eq!(performance.category_budget("empty"), None);
+
+        assert_eq!(performance.size_budget(), None);
+        performance.scan_size_budget_mb = 2;
+        assert_eq!(performance.size_budget(), Some(2 * 1024 * 1024));
+    }
+
+    #[test]
+    fn test_category_overrides() {
+        let config: Config = toml::from_str(
+            r#"
+[performance]
+max_threads = 8
+io_priority = "normal"
+delete_batch_size = 500
+
+[performance.category_overrides.duplicates]
+max_threads = 2
+io_priority = "background"
+
+[performance.category_overrides.build]
+delete_batch_size = 50
+"#,
+        )
+        .unwrap();
+        let performance = &config.performance;
+
+        let duplicates = performance.for_category("duplicates");
+        assert_eq!(duplicates.max_threads, 2);
+        assert_eq!(duplicates.io_priority, "background");
+        assert_eq!(duplicates.delete_batch_size, 500);
+
+        let build = performance.for_category("build");
+        assert_eq!(build.max_threads, 8);
+        assert_eq!(build.delete_batch_size, 50);
+
+        assert_eq!(performance.for_category("temp").max_threads, 8);
+    }
 }
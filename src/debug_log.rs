@@ -23,7 +23,7 @@ fn log_dir() -> Option<PathBuf> {
     Some(base_dir.join("wole").join("logs"))
 }
 
-fn log_path(file_name: &str) -> Option<PathBuf> {
+pub(crate) fn log_path(file_name: &str) -> Option<PathBuf> {
     let dir = log_dir()?;
     let _ = std::fs::create_dir_all(&dir);
     Some(dir.join(file_name))
@@ -46,3 +46,17 @@ fn append_line(file_name: &str, message: &str) {
 pub fn cleaning_log(message: &str) {
     append_line("cleaning.log", message);
 }
+
+/// Scan setup problems that fall back to defaults instead of failing the scan
+pub fn scan_log(message: &str) {
+    append_line("scan.log", message);
+}
+
+pub fn memory_log(message: &str) {
+    append_line("memory.log", message);
+}
+
+/// Warnings and errors shown as TUI toasts, which vanish from the screen
+pub fn tui_log(message: &str) {
+    append_line("tui.log", message);
+}
@@ -1,5 +1,6 @@
 //! Disk usage analysis - scan filesystem and calculate folder sizes
 
+use crate::scan_cache::snapshot::{diff_snapshots, DirChange, DirSnapshot};
 use crate::utils;
 use anyhow::Result;
 use jwalk::WalkDir;
@@ -77,15 +78,19 @@ pub type ProgressCallback = Box<dyn Fn(&Path) + Send + Sync>;
 /// Scan a directory and build a folder tree with sizes
 /// If progress_callback is provided, it will be called for each file being read
 pub fn scan_directory(path: &Path, max_depth: u8) -> Result<DiskInsights> {
-    scan_directory_with_progress(path, max_depth, None)
+    scan_directory_with_progress(path, max_depth, None, None)
 }
 
 /// Scan a directory with progress reporting
 /// Checks cache first, and saves results to cache after scanning
+///
+/// Folders at the depth limit are sized through `size_cache` when given (see
+/// [`crate::size::dir_size_cached`]).
 pub fn scan_directory_with_progress(
     path: &Path,
     max_depth: u8,
     progress_callback: Option<ProgressCallback>,
+    size_cache: Option<&std::sync::Mutex<crate::scan_cache::ScanCache>>,
 ) -> Result<DiskInsights> {
     // Check cache first
     if let Ok(Some(cached_insights)) =
@@ -116,9 +121,7 @@ pub fn scan_directory_with_progress(
     WalkDir::new(path)
         .max_depth(max_depth as usize)
         .follow_links(false)
-        .parallelism(jwalk::Parallelism::RayonDefaultPool {
-            busy_timeout: Duration::from_secs(1),
-        })
+        .parallelism(crate::drive::walk_parallelism(Duration::from_secs(1)))
         .process_read_dir(|_depth, _path, _state, children| {
             // Filter out entries we want to skip
             children.retain(|entry| {
@@ -161,6 +164,31 @@ pub fn scan_directory_with_progress(
                         }
                     }
 
+                    // Directories at the depth limit aren't walked further; size their
+                    // contents with the shared engine so totals don't stop at max_depth
+                    if e.file_type().is_dir() && e.depth == max_depth as usize {
+                        let options = crate::size::DirSizeOptions::default();
+                        let totals = match size_cache {
+                            Some(cache) => {
+                                crate::size::dir_size_cached(&entry_path, &options, cache, None)
+                            }
+                            None => crate::size::dir_size_with_options(&entry_path, &options, None),
+                        }
+                        .unwrap_or_default();
+                        if totals.files > 0 {
+                            total_size.fetch_add(totals.bytes, Ordering::Relaxed);
+                            total_files.fetch_add(totals.files, Ordering::Relaxed);
+                            add_to_ancestors(
+                                &mut dir_sizes.lock().unwrap(),
+                                &mut dir_file_counts.lock().unwrap(),
+                                &entry_path,
+                                path,
+                                totals.bytes,
+                                totals.files,
+                            );
+                        }
+                    }
+
                     if e.file_type().is_file() {
                         // Report progress for this file
                         if let Some(ref callback) = progress_callback {
@@ -269,6 +297,29 @@ pub fn scan_directory_with_progress(
     Ok(insights)
 }
 
+/// Add a subtree's size and file count to `dir` and each ancestor up to `root`
+fn add_to_ancestors(
+    sizes: &mut HashMap<PathBuf, u64>,
+    counts: &mut HashMap<PathBuf, u64>,
+    dir: &Path,
+    root: &Path,
+    size: u64,
+    files: u64,
+) {
+    let mut current = Some(dir);
+    while let Some(ancestor) = current {
+        if !ancestor.starts_with(root) {
+            break;
+        }
+        *sizes.entry(ancestor.to_path_buf()).or_insert(0) += size;
+        *counts.entry(ancestor.to_path_buf()).or_insert(0) += files;
+        if ancestor == root {
+            break;
+        }
+        current = ancestor.parent();
+    }
+}
+
 /// Build a folder tree from directory size map
 fn build_folder_tree(
     path: &Path,
@@ -531,3 +582,314 @@ pub fn get_breadcrumb(root: &FolderNode, target: &Path) -> Vec<String> {
 
     breadcrumb
 }
+
+/// File format for [`write_export`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One row per folder
+    Csv,
+    /// An [`InsightsSnapshot`], the format `wole analyze --compare` reads
+    Json,
+    /// The CSV layout WizTree exports, with folders and files
+    WizTree,
+}
+
+impl ExportFormat {
+    /// Parse a `--export-format` value
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            "wiztree" => Some(Self::WizTree),
+            _ => None,
+        }
+    }
+
+    /// Guess the format from a file extension (JSON for `.json`, CSV otherwise)
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Csv,
+        }
+    }
+}
+
+/// One folder of an exported analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FolderRow {
+    pub path: PathBuf,
+    /// Path below the analyzed root ("" for the root), for comparing machines
+    /// whose roots differ
+    pub relative_path: String,
+    pub depth: usize,
+    pub size_bytes: u64,
+    pub file_count: u64,
+    pub percent_of_parent: f64,
+    pub percent_of_total: f64,
+}
+
+/// Folder sizes of a [`DiskInsights`] scan flattened for export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InsightsSnapshot {
+    pub root: PathBuf,
+    pub host: Option<String>,
+    pub exported_at: chrono::DateTime<chrono::Utc>,
+    pub total_size_bytes: u64,
+    pub total_files: u64,
+    /// Every scanned folder, parents before their children
+    pub folders: Vec<FolderRow>,
+}
+
+impl InsightsSnapshot {
+    pub fn from_insights(insights: &DiskInsights) -> Self {
+        fn collect(node: &FolderNode, root: &FolderNode, depth: usize, rows: &mut Vec<FolderRow>) {
+            let relative_path = node
+                .path
+                .strip_prefix(&root.path)
+                .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+                .unwrap_or_default();
+            rows.push(FolderRow {
+                path: node.path.clone(),
+                relative_path,
+                depth,
+                size_bytes: node.size,
+                file_count: node.file_count,
+                percent_of_parent: if depth == 0 { 100.0 } else { node.percentage },
+                percent_of_total: if root.size > 0 {
+                    node.size as f64 / root.size as f64 * 100.0
+                } else {
+                    0.0
+                },
+            });
+            for child in &node.children {
+                collect(child, root, depth + 1, rows);
+            }
+        }
+
+        let mut folders = Vec::new();
+        collect(&insights.root, &insights.root, 0, &mut folders);
+        Self {
+            root: insights.root.path.clone(),
+            host: sysinfo::System::host_name(),
+            exported_at: chrono::Utc::now(),
+            total_size_bytes: insights.total_size,
+            total_files: insights.total_files,
+            folders,
+        }
+    }
+
+    /// Render the folders as CSV (one row per folder, with a header row)
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "path,relative_path,depth,size_bytes,file_count,percent_of_parent,percent_of_total\n",
+        );
+        for row in &self.folders {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{:.2},{:.2}\n",
+                csv_field(&row.path.display().to_string()),
+                csv_field(&row.relative_path),
+                row.depth,
+                row.size_bytes,
+                row.file_count,
+                row.percent_of_parent,
+                row.percent_of_total
+            ));
+        }
+        csv
+    }
+}
+
+impl InsightsSnapshot {
+    /// Read a snapshot written by [`write_export`] as JSON
+    pub fn load(path: &Path) -> Result<Self> {
+        use anyhow::Context;
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content).with_context(|| {
+            format!(
+                "{} is not a JSON export from `wole analyze --export <FILE>.json`",
+                path.display()
+            )
+        })
+    }
+
+    /// Folder sizes keyed by their path below the root (the root itself is ""),
+    /// so exports of different roots or machines line up
+    pub fn relative_sizes(&self) -> DirSnapshot {
+        self.folders
+            .iter()
+            .map(|row| (PathBuf::from(&row.relative_path), row.size_bytes))
+            .collect()
+    }
+}
+
+/// Folders whose sizes differ most between two exports, deepest responsible
+/// folder first (see [`diff_snapshots`]); paths are relative to each root
+pub fn compare_snapshots(
+    a: &InsightsSnapshot,
+    b: &InsightsSnapshot,
+    limit: usize,
+) -> Vec<DirChange> {
+    diff_snapshots(&a.relative_sizes(), &b.relative_sizes(), limit)
+}
+
+/// Quote a CSV field if it needs it
+pub(crate) fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render a scan in WizTree's CSV export layout, so tools that read WizTree
+/// exports can open it. Folders end with a path separator; wole doesn't track
+/// allocated size, modification times or attributes, so Allocated repeats Size
+/// and the other two are left empty.
+pub fn to_wiztree_csv(insights: &DiskInsights) -> String {
+    /// Returns the number of folders below `node`
+    fn write(node: &FolderNode, rows: &mut Vec<String>) -> u64 {
+        let index = rows.len();
+        rows.push(String::new());
+        let mut folders = 0;
+        for child in &node.children {
+            folders += 1 + write(child, rows);
+        }
+        for file in &node.files {
+            rows.push(format!(
+                "\"{}\",{},{},,,0,0",
+                file.path.display().to_string().replace('"', "\"\""),
+                file.size,
+                file.size
+            ));
+        }
+        let mut path = node.path.display().to_string();
+        if !path.ends_with(std::path::MAIN_SEPARATOR) {
+            path.push(std::path::MAIN_SEPARATOR);
+        }
+        rows[index] = format!(
+            "\"{}\",{},{},,,{},{}",
+            path.replace('"', "\"\""),
+            node.size,
+            node.size,
+            node.file_count,
+            folders
+        );
+        folders
+    }
+
+    let mut rows = Vec::new();
+    write(&insights.root, &mut rows);
+    format!(
+        "Generated by wole {}\nFile Name,Size,Allocated,Modified,Attributes,Files,Folders\n{}\n",
+        env!("CARGO_PKG_VERSION"),
+        rows.join("\n")
+    )
+}
+
+/// Write `insights` to `path` in `format`
+pub fn write_export(insights: &DiskInsights, path: &Path, format: ExportFormat) -> Result<()> {
+    use anyhow::Context;
+
+    let content = match format {
+        ExportFormat::Csv => InsightsSnapshot::from_insights(insights).to_csv(),
+        ExportFormat::Json => {
+            serde_json::to_string_pretty(&InsightsSnapshot::from_insights(insights))
+                .context("Failed to serialize disk insights")?
+        }
+        ExportFormat::WizTree => to_wiztree_csv(insights),
+    };
+    std::fs::write(path, content)
+        .with_context(|| format!("Failed to write export to {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DiskInsights {
+        let root = PathBuf::from("/data");
+        let media = FolderNode {
+            path: root.join("Media"),
+            name: "Media".to_string(),
+            size: 300,
+            file_count: 1,
+            children: Vec::new(),
+            files: vec![FileInfo {
+                path: root.join("Media").join("a, b.mkv"),
+                name: "a, b.mkv".to_string(),
+                size: 300,
+            }],
+            percentage: 75.0,
+        };
+        DiskInsights {
+            root: FolderNode {
+                path: root.clone(),
+                name: "data".to_string(),
+                size: 400,
+                file_count: 2,
+                children: vec![media],
+                files: vec![FileInfo {
+                    path: root.join("notes.txt"),
+                    name: "notes.txt".to_string(),
+                    size: 100,
+                }],
+                percentage: 100.0,
+            },
+            total_size: 400,
+            total_files: 2,
+            largest_files: Vec::new(),
+            scan_duration: Duration::from_secs(1),
+        }
+    }
+
+    #[test]
+    fn test_export_formats() {
+        let insights = sample();
+
+        let csv = InsightsSnapshot::from_insights(&insights).to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1], "/data,,0,400,2,100.00,100.00");
+        assert_eq!(lines[2], "/data/Media,Media,1,300,1,75.00,75.00");
+
+        let wiztree = to_wiztree_csv(&insights);
+        let lines: Vec<&str> = wiztree.lines().collect();
+        assert_eq!(
+            lines[1],
+            "File Name,Size,Allocated,Modified,Attributes,Files,Folders"
+        );
+        assert_eq!(lines[2], "\"/data/\",400,400,,,2,1");
+        assert_eq!(lines[3], "\"/data/Media/\",300,300,,,1,0");
+        assert_eq!(lines[4], "\"/data/Media/a, b.mkv\",300,300,,,0,0");
+        assert_eq!(lines[5], "\"/data/notes.txt\",100,100,,,0,0");
+
+        assert_eq!(
+            ExportFormat::from_path(Path::new("c.JSON")),
+            ExportFormat::Json
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("c.csv")),
+            ExportFormat::Csv
+        );
+        assert_eq!(ExportFormat::parse("WizTree"), Some(ExportFormat::WizTree));
+    }
+
+    #[test]
+    fn test_compare_snapshots_across_roots() {
+        let a = InsightsSnapshot::from_insights(&sample());
+        let mut other = sample();
+        other.root.path = PathBuf::from("/home/bob");
+        other.root.size = 5400;
+        other.root.children[0].path = PathBuf::from("/home/bob/Media");
+        other.root.children[0].size = 5300;
+        let b = InsightsSnapshot::from_insights(&other);
+
+        // Media carries the whole difference, so the root isn't repeated
+        let changes = compare_snapshots(&a, &b, 10);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, PathBuf::from("Media"));
+        assert_eq!(changes[0].delta(), 5000);
+    }
+}
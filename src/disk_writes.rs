@@ -0,0 +1,628 @@
+//! Live disk write monitor (`wole status --writes`)
+//!
+//! Answers "what is writing to my disk right now" while a drive is filling
+//! up. A real-time ETW session on the Microsoft-Windows-Kernel-File provider
+//! reports file opens and writes for every process: opens map the kernel's
+//! file object to a path, writes add their size to that path and the process
+//! that issued them. [`WriteWindow`] only keeps the last [`WINDOW_SECS`]
+//! seconds, so the table follows whoever is writing now rather than
+//! everything since the monitor started.
+//!
+//! Only writes to files opened after the monitor started can be attributed
+//! to a path. Cached writes flushed later by the system show up under the
+//! System process. Kernel trace sessions need an elevated prompt and ETW is
+//! Windows only; elsewhere [`WriteMonitor::start`] fails.
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Seconds of writes the table covers
+pub const WINDOW_SECS: u64 = 10;
+
+/// Writes to one path by one process
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Writer {
+    pub path: PathBuf,
+    pub pid: u32,
+    pub process: String,
+    pub bytes: u64,
+    pub writes: u64,
+}
+
+/// Writes by one process, all paths together
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProcessWrites {
+    pub pid: u32,
+    pub process: String,
+    pub bytes: u64,
+    pub writes: u64,
+    /// Distinct paths written
+    pub files: usize,
+}
+
+/// Writes of one second, keyed by process id and path
+type SecondWrites = HashMap<(u32, PathBuf), Writer>;
+
+/// Writes per path and process over the last few seconds
+#[derive(Debug)]
+pub struct WriteWindow {
+    seconds: u64,
+    /// One bucket per second, oldest first
+    buckets: VecDeque<(u64, SecondWrites)>,
+}
+
+impl WriteWindow {
+    pub fn new(seconds: u64) -> Self {
+        Self {
+            seconds: seconds.max(1),
+            buckets: VecDeque::new(),
+        }
+    }
+
+    /// Count a write of `bytes` to `path` by `pid`, `second` seconds into the monitor
+    pub fn record(&mut self, second: u64, pid: u32, process: &str, path: &Path, bytes: u64) {
+        // Events from other CPUs can arrive a little late; they join the newest bucket
+        if self.buckets.back().is_none_or(|(last, _)| *last < second) {
+            self.buckets.push_back((second, HashMap::new()));
+        }
+        if let Some((_, bucket)) = self.buckets.back_mut() {
+            let writer = bucket
+                .entry((pid, path.to_path_buf()))
+                .or_insert_with(|| Writer {
+                    path: path.to_path_buf(),
+                    pid,
+                    process: process.to_string(),
+                    bytes: 0,
+                    writes: 0,
+                });
+            writer.bytes += bytes;
+            writer.writes += 1;
+        }
+        while self
+            .buckets
+            .front()
+            .is_some_and(|(first, _)| first + self.seconds <= second)
+        {
+            self.buckets.pop_front();
+        }
+    }
+
+    /// Path/process pairs with the most bytes written in the window ending at `now`
+    pub fn top(&self, now: u64, limit: usize) -> Vec<Writer> {
+        let mut merged: HashMap<(u32, &Path), Writer> = HashMap::new();
+        for (_, bucket) in self.live(now) {
+            for ((pid, path), writer) in bucket {
+                let total = merged
+                    .entry((*pid, path.as_path()))
+                    .or_insert_with(|| Writer {
+                        bytes: 0,
+                        writes: 0,
+                        ..writer.clone()
+                    });
+                total.bytes += writer.bytes;
+                total.writes += writer.writes;
+            }
+        }
+        let mut writers: Vec<Writer> = merged.into_values().collect();
+        writers.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.path.cmp(&b.path)));
+        writers.truncate(limit);
+        writers
+    }
+
+    /// Processes with the most bytes written in the window ending at `now`
+    pub fn processes(&self, now: u64, limit: usize) -> Vec<ProcessWrites> {
+        let mut merged: HashMap<u32, (ProcessWrites, Vec<&Path>)> = HashMap::new();
+        for (_, bucket) in self.live(now) {
+            for ((pid, path), writer) in bucket {
+                let (total, paths) = merged.entry(*pid).or_insert_with(|| {
+                    (
+                        ProcessWrites {
+                            pid: *pid,
+                            process: writer.process.clone(),
+                            bytes: 0,
+                            writes: 0,
+                            files: 0,
+                        },
+                        Vec::new(),
+                    )
+                });
+                total.bytes += writer.bytes;
+                total.writes += writer.writes;
+                paths.push(path.as_path());
+            }
+        }
+        let mut processes: Vec<ProcessWrites> = merged
+            .into_values()
+            .map(|(mut total, mut paths)| {
+                paths.sort();
+                paths.dedup();
+                total.files = paths.len();
+                total
+            })
+            .collect();
+        processes.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.pid.cmp(&b.pid)));
+        processes.truncate(limit);
+        processes
+    }
+
+    /// Bytes and writes in the window ending at `now`
+    pub fn total(&self, now: u64) -> (u64, u64) {
+        self.live(now)
+            .flat_map(|(_, bucket)| bucket.values())
+            .fold((0, 0), |(bytes, writes), writer| {
+                (bytes + writer.bytes, writes + writer.writes)
+            })
+    }
+
+    fn live(&self, now: u64) -> impl Iterator<Item = &(u64, HashMap<(u32, PathBuf), Writer>)> {
+        self.buckets
+            .iter()
+            .filter(move |(second, _)| second + self.seconds > now)
+    }
+}
+
+/// A running ETW session counting writes to one volume; stopped on drop
+pub struct WriteMonitor {
+    /// Drive, e.g. "C:"
+    volume: String,
+    window: Arc<Mutex<WriteWindow>>,
+    started: Instant,
+    #[cfg(windows)]
+    _session: etw::Session,
+}
+
+impl std::fmt::Debug for WriteMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WriteMonitor")
+            .field("volume", &self.volume)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WriteMonitor {
+    /// Start watching writes to the drive of `volume` ("C:", "D:\\"...)
+    pub fn start(volume: &str) -> Result<Self> {
+        let volume = crate::shadow::volume_of(Path::new(volume))
+            .ok_or_else(|| anyhow!("Not a drive letter: {}", volume))?;
+        let window = Arc::new(Mutex::new(WriteWindow::new(WINDOW_SECS)));
+        let started = Instant::now();
+
+        #[cfg(windows)]
+        {
+            let session = etw::Session::start(&volume, Arc::clone(&window), started)?;
+            Ok(Self {
+                volume,
+                window,
+                started,
+                _session: session,
+            })
+        }
+
+        #[cfg(not(windows))]
+        {
+            let _ = (window, started);
+            anyhow::bail!(
+                "Watching disk writes on {} needs ETW, which is only available on Windows",
+                volume
+            )
+        }
+    }
+
+    /// Drive being watched, e.g. "C:"
+    pub fn volume(&self) -> &str {
+        &self.volume
+    }
+
+    pub fn top(&self, limit: usize) -> Vec<Writer> {
+        self.lock().top(self.now(), limit)
+    }
+
+    pub fn processes(&self, limit: usize) -> Vec<ProcessWrites> {
+        self.lock().processes(self.now(), limit)
+    }
+
+    /// Bytes and writes in the window
+    pub fn total(&self) -> (u64, u64) {
+        self.lock().total(self.now())
+    }
+
+    fn now(&self) -> u64 {
+        self.started.elapsed().as_secs()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, WriteWindow> {
+        self.window.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// The system drive ("C:" if unknown)
+pub fn system_volume() -> String {
+    std::env::var("SystemDrive").unwrap_or_else(|_| "C:".to_string())
+}
+
+#[cfg(windows)]
+mod etw {
+    use super::WriteWindow;
+    use anyhow::{bail, Context, Result};
+    use std::collections::HashMap;
+    use std::ffi::c_void;
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+    use std::thread::JoinHandle;
+    use std::time::Instant;
+    use windows::core::{GUID, PCWSTR, PWSTR};
+    use windows::Win32::Foundation::{ERROR_ACCESS_DENIED, ERROR_SUCCESS};
+    use windows::Win32::Storage::FileSystem::QueryDosDeviceW;
+    use windows::Win32::System::Diagnostics::Etw::{
+        CloseTrace, ControlTraceW, EnableTraceEx2, OpenTraceW, ProcessTrace, StartTraceW,
+        CONTROLTRACE_HANDLE, EVENT_CONTROL_CODE_ENABLE_PROVIDER, EVENT_RECORD,
+        EVENT_TRACE_CONTROL_STOP, EVENT_TRACE_LOGFILEW, EVENT_TRACE_PROPERTIES,
+        EVENT_TRACE_REAL_TIME_MODE, PROCESS_TRACE_MODE_EVENT_RECORD, PROCESS_TRACE_MODE_REAL_TIME,
+        WNODE_FLAG_TRACED_GUID,
+    };
+
+    const SESSION_NAME: &str = "wole-disk-writes";
+
+    /// Microsoft-Windows-Kernel-File
+    const KERNEL_FILE: GUID = GUID::from_u128(0xedd08927_9cc4_4e65_b970_c2560fb5c289);
+    const KEYWORD_FILENAME: u64 = 0x10;
+    const KEYWORD_CREATE: u64 = 0x80;
+    const KEYWORD_WRITE: u64 = 0x200;
+    const TRACE_LEVEL_INFORMATION: u8 = 4;
+
+    const EVENT_NAME_CREATE: u16 = 10;
+    const EVENT_CREATE: u16 = 12;
+    const EVENT_WRITE: u16 = 16;
+
+    /// Pointers in the payload are 4 bytes (32-bit process or kernel)
+    const EVENT_HEADER_FLAG_32_BIT_HEADER: u16 = 0x20;
+
+    /// Session properties followed by room for the session name
+    #[repr(C)]
+    struct Properties {
+        properties: EVENT_TRACE_PROPERTIES,
+        name: [u16; 64],
+    }
+
+    impl Properties {
+        fn new() -> Box<Self> {
+            // SAFETY: plain C structs, all-zero is their documented initial state
+            let mut properties: Box<Self> = Box::new(unsafe { std::mem::zeroed() });
+            properties.properties.Wnode.BufferSize = std::mem::size_of::<Self>() as u32;
+            properties.properties.Wnode.Flags = WNODE_FLAG_TRACED_GUID;
+            // Query performance counter timestamps
+            properties.properties.Wnode.ClientContext = 1;
+            properties.properties.LogFileMode = EVENT_TRACE_REAL_TIME_MODE;
+            properties.properties.LoggerNameOffset = std::mem::offset_of!(Self, name) as u32;
+            properties
+        }
+    }
+
+    fn wide(text: &str) -> Vec<u16> {
+        text.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    /// Stop the session `handle`, or the one called [`SESSION_NAME`] if the handle is empty
+    fn stop(handle: CONTROLTRACE_HANDLE) {
+        let name = wide(SESSION_NAME);
+        let mut properties = Properties::new();
+        unsafe {
+            let _ = ControlTraceW(
+                handle,
+                PCWSTR(name.as_ptr()),
+                &mut properties.properties,
+                EVENT_TRACE_CONTROL_STOP,
+            );
+        }
+    }
+
+    /// Kernel device of a drive: "C:" -> "\Device\HarddiskVolume3"
+    fn device_of(volume: &str) -> Result<String> {
+        let name = wide(volume);
+        let mut target = [0u16; 512];
+        let len = unsafe { QueryDosDeviceW(PCWSTR(name.as_ptr()), Some(&mut target)) } as usize;
+        if len == 0 {
+            bail!("Couldn't find the device behind {}", volume);
+        }
+        let end = target[..len].iter().position(|&c| c == 0).unwrap_or(len);
+        Ok(String::from_utf16_lossy(&target[..end]))
+    }
+
+    pub struct Session {
+        handle: CONTROLTRACE_HANDLE,
+        consumer: Option<JoinHandle<()>>,
+    }
+
+    impl Session {
+        pub fn start(
+            volume: &str,
+            window: Arc<Mutex<WriteWindow>>,
+            started: Instant,
+        ) -> Result<Self> {
+            let device = device_of(volume)?;
+            // A session left behind by a killed run keeps the name taken
+            stop(CONTROLTRACE_HANDLE::default());
+
+            let name = wide(SESSION_NAME);
+            let mut properties = Properties::new();
+            let mut handle = CONTROLTRACE_HANDLE::default();
+            let status = unsafe {
+                StartTraceW(
+                    &mut handle,
+                    PCWSTR(name.as_ptr()),
+                    &mut properties.properties,
+                )
+            };
+            if status == ERROR_ACCESS_DENIED {
+                bail!("Watching disk writes needs an elevated prompt (run as administrator)");
+            }
+            if status != ERROR_SUCCESS {
+                bail!("Couldn't start the ETW session (error {})", status.0);
+            }
+
+            let status = unsafe {
+                EnableTraceEx2(
+                    handle,
+                    &KERNEL_FILE,
+                    EVENT_CONTROL_CODE_ENABLE_PROVIDER,
+                    TRACE_LEVEL_INFORMATION,
+                    KEYWORD_FILENAME | KEYWORD_CREATE | KEYWORD_WRITE,
+                    0,
+                    0,
+                    None,
+                )
+            };
+            if status != ERROR_SUCCESS {
+                stop(handle);
+                bail!(
+                    "Couldn't enable the Kernel-File provider (error {})",
+                    status.0
+                );
+            }
+
+            let collector = Collector {
+                device,
+                volume: volume.to_string(),
+                window,
+                started,
+                by_object: HashMap::new(),
+                by_key: HashMap::new(),
+                names: HashMap::new(),
+                system: sysinfo::System::new(),
+            };
+            let consumer = std::thread::Builder::new()
+                .name("wole-disk-writes".to_string())
+                .spawn(move || consume(collector));
+            match consumer {
+                Ok(consumer) => Ok(Self {
+                    handle,
+                    consumer: Some(consumer),
+                }),
+                Err(e) => {
+                    stop(handle);
+                    Err(e).context("Couldn't start the ETW consumer thread")
+                }
+            }
+        }
+    }
+
+    impl Drop for Session {
+        fn drop(&mut self) {
+            // Stopping the session ends ProcessTrace on the consumer thread
+            stop(self.handle);
+            if let Some(consumer) = self.consumer.take() {
+                let _ = consumer.join();
+            }
+        }
+    }
+
+    /// Deliver the session's events to `collector` until it is stopped
+    fn consume(mut collector: Collector) {
+        let mut name = wide(SESSION_NAME);
+        let mut logfile = EVENT_TRACE_LOGFILEW {
+            LoggerName: PWSTR(name.as_mut_ptr()),
+            Context: &mut collector as *mut Collector as *mut c_void,
+            ..Default::default()
+        };
+        logfile.Anonymous1.ProcessTraceMode =
+            PROCESS_TRACE_MODE_REAL_TIME | PROCESS_TRACE_MODE_EVENT_RECORD;
+        logfile.Anonymous2.EventRecordCallback = Some(on_event);
+
+        unsafe {
+            let trace = OpenTraceW(&mut logfile);
+            if trace.Value == u64::MAX {
+                return;
+            }
+            // Blocks until the session is stopped
+            let _ = ProcessTrace(&[trace], None, None);
+            let _ = CloseTrace(trace);
+        }
+    }
+
+    unsafe extern "system" fn on_event(record: *mut EVENT_RECORD) {
+        let Some(record) = record.as_ref() else {
+            return;
+        };
+        let header = &record.EventHeader;
+        if header.ProviderId != KERNEL_FILE || record.UserData.is_null() {
+            return;
+        }
+        let Some(collector) = (record.UserContext as *mut Collector).as_mut() else {
+            return;
+        };
+        let data = std::slice::from_raw_parts(
+            record.UserData as *const u8,
+            record.UserDataLength as usize,
+        );
+        let pointer = if header.Flags & EVENT_HEADER_FLAG_32_BIT_HEADER != 0 {
+            4
+        } else {
+            8
+        };
+        collector.handle(
+            header.EventDescriptor.Id,
+            header.EventDescriptor.Version,
+            header.ProcessId,
+            data,
+            pointer,
+        );
+    }
+
+    /// State of the consumer thread
+    struct Collector {
+        /// e.g. "\Device\HarddiskVolume3"
+        device: String,
+        /// e.g. "C:"
+        volume: String,
+        window: Arc<Mutex<WriteWindow>>,
+        started: Instant,
+        /// File object -> path (None = another volume), from opens
+        by_object: HashMap<u64, Option<PathBuf>>,
+        /// File key -> path (None = another volume), from name events
+        by_key: HashMap<u64, Option<PathBuf>>,
+        names: HashMap<u32, String>,
+        system: sysinfo::System,
+    }
+
+    impl Collector {
+        fn handle(&mut self, id: u16, version: u8, pid: u32, data: &[u8], pointer: usize) {
+            match id {
+                // FileKey, FileName
+                EVENT_NAME_CREATE => {
+                    let key = read_pointer(data, 0, pointer);
+                    let name = read_wide(data, pointer);
+                    if let (Some(key), Some(name)) = (key, name) {
+                        let path = self.drive_path(&name);
+                        self.by_key.insert(key, path);
+                    }
+                }
+                // v0: Irp, TTID, FileObject, CreateOptions, CreateAttributes, ShareAccess, FileName
+                // v1: Irp, FileObject, IssuingThreadId, CreateOptions, CreateAttributes, ShareAccess, FileName
+                EVENT_CREATE => {
+                    let (object_at, name_at) = if version == 0 {
+                        (2 * pointer, 3 * pointer + 12)
+                    } else {
+                        (pointer, 2 * pointer + 16)
+                    };
+                    let object = read_pointer(data, object_at, pointer);
+                    let name = read_wide(data, name_at);
+                    if let (Some(object), Some(name)) = (object, name) {
+                        let path = self.drive_path(&name);
+                        self.by_object.insert(object, path);
+                    }
+                }
+                // v0: ByteOffset, Irp, TTID, FileObject, FileKey, IOSize, IOFlags
+                // v1: ByteOffset, Irp, FileObject, FileKey, IssuingThreadId, IOSize, IOFlags, ExtraFlags
+                EVENT_WRITE => {
+                    let (object_at, key_at, size_at) = if version == 0 {
+                        (8 + 2 * pointer, 8 + 3 * pointer, 8 + 4 * pointer)
+                    } else {
+                        (8 + pointer, 8 + 2 * pointer, 8 + 3 * pointer + 4)
+                    };
+                    let Some(size) = read_u32(data, size_at) else {
+                        return;
+                    };
+                    let path = read_pointer(data, object_at, pointer)
+                        .and_then(|object| self.by_object.get(&object))
+                        .or_else(|| {
+                            read_pointer(data, key_at, pointer)
+                                .and_then(|key| self.by_key.get(&key))
+                        });
+                    let Some(Some(path)) = path.cloned() else {
+                        return;
+                    };
+                    let process = self.process_name(pid);
+                    let second = self.started.elapsed().as_secs();
+                    self.window
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .record(second, pid, &process, &path, size as u64);
+                }
+                _ => {}
+            }
+        }
+
+        /// "\Device\HarddiskVolume3\Users\x" -> "C:\Users\x", None on other volumes
+        fn drive_path(&self, name: &str) -> Option<PathBuf> {
+            let prefix = name.get(..self.device.len())?;
+            let rest = &name[self.device.len()..];
+            (prefix.eq_ignore_ascii_case(&self.device) && rest.starts_with('\\'))
+                .then(|| PathBuf::from(format!("{}{}", self.volume, rest)))
+        }
+
+        fn process_name(&mut self, pid: u32) -> String {
+            if let Some(name) = self.names.get(&pid) {
+                return name.clone();
+            }
+            let sys_pid = sysinfo::Pid::from_u32(pid);
+            self.system
+                .refresh_processes(sysinfo::ProcessesToUpdate::Some(&[sys_pid]), false);
+            let name = match self.system.process(sys_pid) {
+                Some(process) => process.name().to_string_lossy().to_string(),
+                None if pid == 4 => "System".to_string(),
+                None => format!("pid {}", pid),
+            };
+            self.names.insert(pid, name.clone());
+            name
+        }
+    }
+
+    fn read_u32(data: &[u8], at: usize) -> Option<u32> {
+        let bytes = data.get(at..at + 4)?;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    fn read_pointer(data: &[u8], at: usize, pointer: usize) -> Option<u64> {
+        if pointer == 4 {
+            return read_u32(data, at).map(u64::from);
+        }
+        let bytes = data.get(at..at + 8)?;
+        Some(u64::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    /// NUL-terminated UTF-16 string at `at`
+    fn read_wide(data: &[u8], at: usize) -> Option<String> {
+        let units: Vec<u16> = data
+            .get(at..)?
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .take_while(|&unit| unit != 0)
+            .collect();
+        (!units.is_empty()).then(|| String::from_utf16_lossy(&units))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_ranks_recent_writers() {
+        let mut window = WriteWindow::new(10);
+        let log = Path::new(r"C:\logs\app.log");
+        let dump = Path::new(r"C:\dumps\crash.dmp");
+        window.record(0, 100, "app.exe", log, 4096);
+        window.record(1, 100, "app.exe", log, 4096);
+        window.record(1, 200, "dumper.exe", dump, 1 << 20);
+
+        let top = window.top(1, 10);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].process, "dumper.exe");
+        assert_eq!((top[1].bytes, top[1].writes), (8192, 2));
+        assert_eq!(window.total(1), ((1 << 20) + 8192, 3));
+
+        let processes = window.processes(1, 10);
+        assert_eq!(processes[1].pid, 100);
+        assert_eq!(processes[1].files, 1);
+
+        // Ten seconds on, only the newest write is left
+        window.record(11, 100, "app.exe", log, 512);
+        let top = window.top(11, 10);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].bytes, 512);
+    }
+}
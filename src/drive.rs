@@ -0,0 +1,402 @@
+//! Drive-type aware scanning
+//!
+//! Before scanning, the drive holding the scan root is classified (SSD, HDD,
+//! removable, network share) and a [`ScanStrategy`] is picked for it:
+//! - SSDs and NVMe drives handle deep request queues, so the walkers get two
+//!   threads per CPU;
+//! - HDDs pay a seek for every random read, so two walkers are used and
+//!   Duplicates hashes files one at a time in path order;
+//! - removable drives are treated like HDDs, and Duplicates doesn't hash them
+//!   at all unless `performance.hash_removable` is set;
+//! - network shares and unknown drives keep the defaults.
+//!
+//! `performance.max_threads` (or `scan_threads`), when set, always wins over
+//! the strategy's thread count, and a category's own `max_threads` or
+//! `io_priority` override gets it a pool of its own while it scans
+//! ([`activate_for_category`]). `performance.drive_strategy` forces a drive
+//! type or turns the adaptation off.
+//!
+//! The strategy is active while a scan runs ([`activate`]); the jwalk walkers
+//! pick up its thread pool through [`walk_parallelism`].
+//!
+//! [`removable_volumes`] and [`is_removable_path`] back the removable media
+//! cleanup (`wole media`) and keep deletions on those drives out of the
+//! Recycle Bin (see [`crate::trash_ops`]).
+
+use crate::config::Config;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// Kind of drive a scan root lives on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriveKind {
+    Ssd,
+    Hdd,
+    Removable,
+    Network,
+    Unknown,
+}
+
+impl DriveKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            DriveKind::Ssd => "SSD",
+            DriveKind::Hdd => "HDD",
+            DriveKind::Removable => "Removable drive",
+            DriveKind::Network => "Network share",
+            DriveKind::Unknown => "Unknown drive",
+        }
+    }
+
+    /// Parse `performance.drive_strategy` (None = detect)
+    fn from_config(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "ssd" | "nvme" => Some(DriveKind::Ssd),
+            "hdd" => Some(DriveKind::Hdd),
+            "removable" | "usb" => Some(DriveKind::Removable),
+            "off" | "none" => Some(DriveKind::Unknown),
+            _ => None,
+        }
+    }
+}
+
+/// Mount points and their kinds
+type MountList = Vec<(PathBuf, DriveKind)>;
+
+/// Mount points and their kinds, listed at most every [`MOUNTS_TTL`]
+static MOUNTS: Mutex<Option<(Instant, MountList)>> = Mutex::new(None);
+
+/// Drives come and go (USB sticks), but per-path checks during a clean must
+/// not list them every time
+const MOUNTS_TTL: Duration = Duration::from_secs(5);
+
+fn disk_kind(disk: &sysinfo::Disk) -> DriveKind {
+    if disk.is_removable() {
+        DriveKind::Removable
+    } else {
+        match disk.kind() {
+            sysinfo::DiskKind::SSD => DriveKind::Ssd,
+            sysinfo::DiskKind::HDD => DriveKind::Hdd,
+            sysinfo::DiskKind::Unknown(_) => DriveKind::Unknown,
+        }
+    }
+}
+
+fn mounts() -> MountList {
+    let mut cached = match MOUNTS.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    if let Some((listed, mounts)) = cached.as_ref() {
+        if listed.elapsed() < MOUNTS_TTL {
+            return mounts.clone();
+        }
+    }
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    let mounts: MountList = disks
+        .list()
+        .iter()
+        .map(|disk| (disk.mount_point().to_path_buf(), disk_kind(disk)))
+        .collect();
+    *cached = Some((Instant::now(), mounts.clone()));
+    mounts
+}
+
+/// Detect the kind of drive `path` is on
+pub fn detect(path: &Path) -> DriveKind {
+    if crate::network::is_network_path(path) {
+        return DriveKind::Network;
+    }
+    let path = if path.is_relative() {
+        std::env::current_dir()
+            .map(|dir| dir.join(path))
+            .unwrap_or_else(|_| path.to_path_buf())
+    } else {
+        path.to_path_buf()
+    };
+    kind_for_path(&path, &mounts())
+}
+
+/// Whether `path` is on a USB stick, SD card or other removable drive
+pub fn is_removable_path(path: &Path) -> bool {
+    detect(path) == DriveKind::Removable
+}
+
+/// A mounted removable volume
+#[derive(Debug, Clone, Serialize)]
+pub struct RemovableVolume {
+    pub mount: PathBuf,
+    /// Volume label (may be empty)
+    pub name: String,
+    pub file_system: String,
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+}
+
+impl RemovableVolume {
+    /// e.g. "E:\ (CANON_DC, FAT32)"
+    pub fn describe(&self) -> String {
+        let mut details = Vec::new();
+        if !self.name.is_empty() {
+            details.push(self.name.clone());
+        }
+        if !self.file_system.is_empty() {
+            details.push(self.file_system.clone());
+        }
+        if details.is_empty() {
+            self.mount.display().to_string()
+        } else {
+            format!("{} ({})", self.mount.display(), details.join(", "))
+        }
+    }
+}
+
+/// Removable volumes that are mounted right now
+pub fn removable_volumes() -> Vec<RemovableVolume> {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    let mut volumes: Vec<RemovableVolume> = disks
+        .list()
+        .iter()
+        .filter(|disk| disk_kind(disk) == DriveKind::Removable)
+        .map(|disk| RemovableVolume {
+            mount: disk.mount_point().to_path_buf(),
+            name: disk.name().to_string_lossy().to_string(),
+            file_system: disk.file_system().to_string_lossy().to_string(),
+            total_bytes: disk.total_space(),
+            available_bytes: disk.available_space(),
+        })
+        .collect();
+    volumes.sort_by(|a, b| a.mount.cmp(&b.mount));
+    volumes.dedup_by(|a, b| a.mount == b.mount);
+    volumes
+}
+
+/// Kind of the mount point that holds `path` (the longest matching one)
+fn kind_for_path(path: &Path, mounts: &[(PathBuf, DriveKind)]) -> DriveKind {
+    let normalize = |p: &Path| {
+        let s = p.to_string_lossy().replace('\\', "/");
+        if cfg!(windows) {
+            s.to_lowercase()
+        } else {
+            s
+        }
+    };
+    let path = normalize(path);
+    mounts
+        .iter()
+        .filter(|(mount, _)| Path::new(&path).starts_with(normalize(mount)))
+        .max_by_key(|(mount, _)| mount.as_os_str().len())
+        .map(|(_, kind)| *kind)
+        .unwrap_or(DriveKind::Unknown)
+}
+
+/// How a scan uses the drive
+#[derive(Debug, Clone)]
+pub struct ScanStrategy {
+    pub drive: DriveKind,
+    /// Walker/hasher threads (0 = rayon default, one per CPU)
+    pub threads: usize,
+    /// Hash files one at a time, in path order, to keep the disk head moving forward
+    pub sequential: bool,
+    /// Whether Duplicates hashes file contents
+    pub hash_duplicates: bool,
+    /// Whether the drive type was forced by `performance.drive_strategy`
+    pub forced: bool,
+    pool: Option<Arc<rayon::ThreadPool>>,
+}
+
+impl ScanStrategy {
+    /// Pick the strategy for scanning `path`
+    pub fn for_path(path: &Path, config: &Config) -> Self {
+        let forced = DriveKind::from_config(&config.performance.drive_strategy);
+        let drive = forced.unwrap_or_else(|| detect(path));
+        let mut strategy = Self::for_drive(drive, config);
+        strategy.forced = forced.is_some();
+        strategy
+    }
+
+    fn for_drive(drive: DriveKind, config: &Config) -> Self {
+        let cpus = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(4);
+        let configured = match config.performance.max_threads {
+            0 => config.performance.scan_threads as usize,
+            n => n,
+        };
+        let (threads, sequential) = match drive {
+            DriveKind::Ssd => ((cpus * 2).min(32), false),
+            DriveKind::Hdd | DriveKind::Removable => (2, true),
+            DriveKind::Network | DriveKind::Unknown => (0, false),
+        };
+        let threads = if configured > 0 { configured } else { threads };
+
+        Self {
+            drive,
+            threads,
+            sequential,
+            hash_duplicates: drive != DriveKind::Removable || config.performance.hash_removable,
+            forced: false,
+            pool: None,
+        }
+    }
+
+    /// One line for scan output, e.g. "HDD: 2 walkers, sequential reads"
+    pub fn describe(&self) -> String {
+        let mut parts = vec![if self.threads == 0 {
+            "default parallelism".to_string()
+        } else {
+            format!("{} walkers", self.threads)
+        }];
+        if self.sequential {
+            parts.push("sequential reads".to_string());
+        }
+        if !self.hash_duplicates {
+            parts.push("duplicate hashing skipped".to_string());
+        }
+        let drive = match (self.drive, self.forced) {
+            (DriveKind::Unknown, true) => "Drive detection off",
+            (drive, _) => drive.label(),
+        };
+        format!(
+            "{}{}: {}",
+            drive,
+            if self.forced && self.drive != DriveKind::Unknown {
+                " (configured)"
+            } else {
+                ""
+            },
+            parts.join(", ")
+        )
+    }
+}
+
+static ACTIVE: RwLock<Option<ScanStrategy>> = RwLock::new(None);
+
+/// Makes a strategy active until dropped (the one active before comes back)
+pub struct StrategyGuard {
+    previous: Option<ScanStrategy>,
+}
+
+impl Drop for StrategyGuard {
+    fn drop(&mut self) {
+        if let Ok(mut active) = ACTIVE.write() {
+            *active = self.previous.take();
+        }
+    }
+}
+
+/// Use `strategy` for the scan that is starting; its thread pool is built here
+///
+/// The pool is also built when only the I/O priority is set, so the walkers
+/// run at that priority whatever state the rayon global pool is in.
+pub fn activate(mut strategy: ScanStrategy) -> StrategyGuard {
+    if strategy.threads > 0 || crate::throttle::io_priority() != crate::throttle::IoPriority::Normal
+    {
+        match crate::throttle::build_pool(strategy.threads) {
+            Ok(pool) => strategy.pool = Some(Arc::new(pool)),
+            Err(e) => crate::debug_log::scan_log(&format!(
+                "couldn't build the scan thread pool, using rayon's default: {}",
+                e
+            )),
+        }
+    }
+    let previous = match ACTIVE.write() {
+        Ok(mut active) => active.replace(strategy),
+        Err(_) => None,
+    };
+    StrategyGuard { previous }
+}
+
+/// Strategy for category `key` of a scan of `path`, when the category
+/// overrides `max_threads` or `io_priority` (call after
+/// [`crate::throttle::enter_category`], so the pool runs at its priority)
+pub fn activate_for_category(path: &Path, config: &Config, key: &str) -> Option<StrategyGuard> {
+    let overrides = config.performance.category_overrides.get(key)?;
+    if overrides.max_threads.is_none() && overrides.io_priority.is_none() {
+        return None;
+    }
+    let mut category_config = config.clone();
+    category_config.performance = config.performance.for_category(key);
+    Some(activate(ScanStrategy::for_path(path, &category_config)))
+}
+
+fn with_active<R>(f: impl FnOnce(Option<&ScanStrategy>) -> R) -> R {
+    match ACTIVE.read() {
+        Ok(active) => f(active.as_ref()),
+        Err(_) => f(None),
+    }
+}
+
+/// jwalk parallelism for the active strategy (the rayon default pool outside scans)
+pub fn walk_parallelism(busy_timeout: Duration) -> jwalk::Parallelism {
+    with_active(|strategy| match strategy.and_then(|s| s.pool.clone()) {
+        Some(pool) => jwalk::Parallelism::RayonExistingPool {
+            pool,
+            busy_timeout: Some(busy_timeout),
+        },
+        None => jwalk::Parallelism::RayonDefaultPool { busy_timeout },
+    })
+}
+
+/// Run `op` (which may use rayon) in the active strategy's thread pool
+pub fn install<R: Send>(op: impl FnOnce() -> R + Send) -> R {
+    match with_active(|strategy| strategy.and_then(|s| s.pool.clone())) {
+        Some(pool) => pool.install(op),
+        None => op(),
+    }
+}
+
+/// Whether files should be read one at a time, in path order
+pub fn sequential_reads() -> bool {
+    with_active(|strategy| strategy.is_some_and(|s| s.sequential))
+}
+
+/// Whether Duplicates may hash file contents on the scanned drive
+pub fn hash_duplicates() -> bool {
+    with_active(|strategy| strategy.is_none_or(|s| s.hash_duplicates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_for_path_uses_longest_mount() {
+        let mounts = vec![
+            (PathBuf::from("/"), DriveKind::Ssd),
+            (PathBuf::from("/media/usb"), DriveKind::Removable),
+        ];
+        assert_eq!(
+            kind_for_path(Path::new("/media/usb/photos"), &mounts),
+            DriveKind::Removable
+        );
+        assert_eq!(
+            kind_for_path(Path::new("/home/me"), &mounts),
+            DriveKind::Ssd
+        );
+        assert_eq!(kind_for_path(Path::new("/home"), &[]), DriveKind::Unknown);
+    }
+
+    #[test]
+    fn test_strategy_for_drive() {
+        let mut config = Config::default();
+        let hdd = ScanStrategy::for_drive(DriveKind::Hdd, &config);
+        assert_eq!((hdd.threads, hdd.sequential), (2, true));
+        assert!(hdd.hash_duplicates);
+
+        let usb = ScanStrategy::for_drive(DriveKind::Removable, &config);
+        assert!(!usb.hash_duplicates);
+        assert!(usb.describe().contains("duplicate hashing skipped"));
+
+        config.performance.hash_removable = true;
+        config.performance.max_threads = 6;
+        let usb = ScanStrategy::for_drive(DriveKind::Removable, &config);
+        assert!(usb.hash_duplicates);
+        assert_eq!(usb.threads, 6);
+
+        assert_eq!(DriveKind::from_config("NVMe"), Some(DriveKind::Ssd));
+        assert_eq!(DriveKind::from_config("auto"), None);
+    }
+}
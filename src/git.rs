@@ -1,5 +1,6 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use globset::{GlobBuilder, GlobMatcher};
 // git2 dependency REMOVED - causes stack overflow on Windows during static init
 // use git2::Repository;
 use std::path::{Path, PathBuf};
@@ -18,7 +19,7 @@ use std::path::{Path, PathBuf};
 // Thread-local cache to avoid static initialization issues
 // Uses thread_local! macro for per-thread caching without static initialization
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 thread_local! {
     static GIT_ROOT_CACHE: RefCell<HashMap<PathBuf, Option<PathBuf>>> = RefCell::new(HashMap::new());
@@ -104,6 +105,329 @@ pub fn find_git_root(path: &Path) -> Option<PathBuf> {
     None
 }
 
+/// Git repository enclosing the current directory (`wole clean --repo`)
+pub fn current_repo_root() -> Option<PathBuf> {
+    let cwd = std::env::current_dir().ok()?;
+    find_git_root(&cwd)
+}
+
+// ============================================================================
+// Ignored Files
+// ============================================================================
+//
+// `git clean -ndX` without running git: `.gitignore` rules are matched with
+// globset, and `.git/index` is read to leave tracked files alone. Global
+// excludes (core.excludesFile) are not consulted.
+// ============================================================================
+
+/// Deepest folder level walked inside a repository
+const MAX_IGNORE_DEPTH: usize = 64;
+
+/// One `.gitignore` pattern
+struct IgnoreRule {
+    matcher: GlobMatcher,
+    negated: bool,
+    dir_only: bool,
+}
+
+/// Rules of one `.gitignore` (or `.git/info/exclude`), relative to `base`
+struct IgnoreFile {
+    base: PathBuf,
+    rules: Vec<IgnoreRule>,
+}
+
+impl IgnoreFile {
+    fn load(base: &Path, file: &Path) -> Option<Self> {
+        let text = std::fs::read_to_string(file).ok()?;
+        Some(Self::parse(base, &text))
+    }
+
+    fn parse(base: &Path, text: &str) -> Self {
+        Self {
+            base: base.to_path_buf(),
+            rules: text.lines().filter_map(parse_rule).collect(),
+        }
+    }
+
+    /// Some(true) if ignored, Some(false) if re-included by `!`, None if no rule matched
+    fn matches(&self, path: &Path, is_dir: bool) -> Option<bool> {
+        let relative = path.strip_prefix(&self.base).ok()?;
+        let relative = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| (is_dir || !rule.dir_only) && rule.matcher.is_match(&relative))
+            .map(|rule| !rule.negated)
+    }
+}
+
+fn parse_rule(line: &str) -> Option<IgnoreRule> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let (negated, pattern) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line.strip_prefix('\\').unwrap_or(line)),
+    };
+    let (dir_only, pattern) = match pattern.strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, pattern),
+    };
+    // A slash anywhere but the end anchors the pattern to the .gitignore's folder
+    let glob = match pattern.strip_prefix('/') {
+        Some(anchored) => anchored.to_string(),
+        None if pattern.contains('/') => pattern.to_string(),
+        None => format!("**/{}", pattern),
+    };
+    if glob.is_empty() {
+        return None;
+    }
+    let matcher = GlobBuilder::new(&glob)
+        .literal_separator(true)
+        .case_insensitive(cfg!(windows))
+        .build()
+        .ok()?
+        .compile_matcher();
+    Some(IgnoreRule {
+        matcher,
+        negated,
+        dir_only,
+    })
+}
+
+/// Deeper `.gitignore` files win; within one file the last matching rule wins
+fn is_ignored(stack: &[IgnoreFile], path: &Path, is_dir: bool) -> bool {
+    stack
+        .iter()
+        .rev()
+        .find_map(|file| file.matches(path, is_dir))
+        .unwrap_or(false)
+}
+
+/// Files tracked in a repository, plus every folder that contains one
+#[derive(Debug, Default)]
+pub struct TrackedPaths {
+    files: HashSet<PathBuf>,
+    dirs: HashSet<PathBuf>,
+}
+
+impl TrackedPaths {
+    pub fn contains(&self, path: &Path) -> bool {
+        self.files.contains(path) || self.dirs.contains(path)
+    }
+}
+
+/// Read the tracked paths from `.git/index` (index versions 2 to 4)
+///
+/// Returns None when the index can't be read, e.g. `.git` is a file (worktrees,
+/// submodules) or the format is unknown. A repo without commits or staged files
+/// has no index yet and tracks nothing.
+pub fn tracked_paths(repo_root: &Path) -> Option<TrackedPaths> {
+    let git_dir = repo_root.join(".git");
+    if !git_dir.is_dir() {
+        return None;
+    }
+    // Entries are laid out for SHA-1 object ids
+    let config = std::fs::read_to_string(git_dir.join("config")).unwrap_or_default();
+    if config.to_lowercase().contains("sha256") {
+        return None;
+    }
+    match std::fs::read(git_dir.join("index")) {
+        Ok(bytes) => parse_index(repo_root, &bytes),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Some(TrackedPaths::default()),
+        Err(_) => None,
+    }
+}
+
+fn parse_index(repo_root: &Path, bytes: &[u8]) -> Option<TrackedPaths> {
+    let be32 = |at: usize| -> Option<u32> {
+        Some(u32::from_be_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+    };
+    if bytes.get(..4)? != b"DIRC" {
+        return None;
+    }
+    let version = be32(4)?;
+    if !(2..=4).contains(&version) {
+        return None;
+    }
+    let count = be32(8)? as usize;
+
+    let mut tracked = TrackedPaths::default();
+    let mut at = 12;
+    let mut previous: Vec<u8> = Vec::new();
+    for _ in 0..count {
+        let entry_start = at;
+        // ctime, mtime, dev, ino, mode, uid, gid, size, object id, flags
+        let flags = u16::from_be_bytes(bytes.get(at + 60..at + 62)?.try_into().ok()?);
+        at += 62;
+        if version >= 3 && flags & 0x4000 != 0 {
+            at += 2;
+        }
+        let name = if version == 4 {
+            // Path is stored as "strip N bytes from the previous path" + suffix
+            let mut strip = 0usize;
+            loop {
+                let byte = *bytes.get(at)?;
+                at += 1;
+                strip = (strip << 7) | (byte & 0x7f) as usize;
+                if byte & 0x80 == 0 {
+                    break;
+                }
+                strip += 1;
+            }
+            let end = at + bytes.get(at..)?.iter().position(|&b| b == 0)?;
+            let mut name = previous[..previous.len().checked_sub(strip)?].to_vec();
+            name.extend_from_slice(&bytes[at..end]);
+            at = end + 1;
+            name
+        } else {
+            let end = at + bytes.get(at..)?.iter().position(|&b| b == 0)?;
+            let name = bytes[at..end].to_vec();
+            // Entries are NUL-padded to a multiple of 8 bytes
+            let len = end - entry_start;
+            at = entry_start + (len + 8) / 8 * 8;
+            name
+        };
+
+        let path = String::from_utf8_lossy(&name)
+            .split('/')
+            .fold(repo_root.to_path_buf(), |path, part| path.join(part));
+        let mut dir = path.parent();
+        while let Some(parent) = dir {
+            if parent == repo_root || !tracked.dirs.insert(parent.to_path_buf()) {
+                break;
+            }
+            dir = parent.parent();
+        }
+        tracked.files.insert(path);
+        previous = name;
+    }
+    Some(tracked)
+}
+
+/// Ignored, untracked files and folders of the repository at `repo_root`,
+/// the same list `git clean -ndX` prints
+///
+/// An ignored folder is listed as a whole unless it holds tracked files, in
+/// which case its untracked contents are listed instead. Nested repositories
+/// are skipped. Returns an empty list when the index can't be read.
+pub fn ignored_paths(repo_root: &Path) -> Vec<PathBuf> {
+    let Some(tracked) = tracked_paths(repo_root) else {
+        return Vec::new();
+    };
+    let mut stack: Vec<IgnoreFile> = Vec::new();
+    if let Some(exclude) = IgnoreFile::load(
+        repo_root,
+        &repo_root.join(".git").join("info").join("exclude"),
+    ) {
+        stack.push(exclude);
+    }
+    let mut found = Vec::new();
+    collect_ignored(repo_root, &mut stack, &tracked, &mut found, false, 0);
+    found
+}
+
+fn collect_ignored(
+    dir: &Path,
+    stack: &mut Vec<IgnoreFile>,
+    tracked: &TrackedPaths,
+    found: &mut Vec<PathBuf>,
+    parent_ignored: bool,
+    depth: usize,
+) {
+    if depth > MAX_IGNORE_DEPTH {
+        return;
+    }
+    let Ok(entries) = crate::utils::safe_read_dir(dir) else {
+        return;
+    };
+    let pushed = match IgnoreFile::load(dir, &dir.join(".gitignore")) {
+        Some(file) => {
+            stack.push(file);
+            true
+        }
+        None => false,
+    };
+
+    let mut entries: Vec<_> = entries.flatten().collect();
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        if entry.file_name() == ".git" {
+            continue;
+        }
+        let path = entry.path();
+        // Symlinks are never followed; git treats them as files
+        let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+        if is_dir && path.join(".git").exists() {
+            continue;
+        }
+        // Everything inside an ignored folder is ignored, even if the folder holds tracked files
+        let ignored = parent_ignored || is_ignored(stack, &path, is_dir);
+        if ignored && is_dir && tracked.dirs.contains(&path) {
+            collect_ignored(&path, stack, tracked, found, true, depth + 1);
+        } else if ignored {
+            if !tracked.contains(&path) {
+                found.push(path);
+            }
+        } else if is_dir {
+            collect_ignored(&path, stack, tracked, found, false, depth + 1);
+        }
+    }
+
+    if pushed {
+        stack.pop();
+    }
+}
+
+/// Git repositories at or under `root`
+///
+/// Folders excluded in the config and the usual dependency/tooling folders
+/// are not searched. A repository at the home folder (dotfiles) is skipped:
+/// it usually ignores everything else in the profile.
+pub fn find_repo_roots(root: &Path, config: &crate::config::Config) -> Vec<PathBuf> {
+    let home = directories::UserDirs::new().map(|dirs| dirs.home_dir().to_path_buf());
+    if home.as_deref() != Some(root) && root.join(".git").exists() {
+        return vec![root.to_path_buf()];
+    }
+    const MAX_DEPTH: usize = 6;
+    let mut repos = Vec::new();
+    let mut walker = walkdir::WalkDir::new(root)
+        .max_depth(MAX_DEPTH)
+        .follow_links(false)
+        .into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_lowercase();
+        if entry.depth() > 0
+            && (name.starts_with('.')
+                || matches!(
+                    name.as_str(),
+                    "node_modules" | "target" | "appdata" | "$recycle.bin" | "windows"
+                )
+                || config.is_excluded(path))
+        {
+            walker.skip_current_dir();
+            continue;
+        }
+        if path.join(".git").exists() && home.as_deref() != Some(path) {
+            repos.push(path.to_path_buf());
+            walker.skip_current_dir();
+        }
+    }
+    repos
+}
+
 /// Check if a git repository has uncommitted changes (dirty)
 /// DISABLED: git2 dependency removed due to Windows stack overflow
 pub fn is_dirty(_repo_path: &Path) -> Result<bool> {
@@ -170,6 +494,61 @@ mod tests {
         assert_eq!(result, Some(temp_dir.path().to_path_buf()));
     }
 
+    #[test]
+    fn test_ignore_rules() {
+        let root = Path::new("repo");
+        let file = IgnoreFile::parse(root, "# build\n/dist\n*.log\n!keep.log\ncache/\n");
+        assert_eq!(file.matches(&root.join("dist"), true), Some(true));
+        assert_eq!(file.matches(&root.join("src").join("dist"), true), None);
+        assert_eq!(
+            file.matches(&root.join("a").join("b.log"), false),
+            Some(true)
+        );
+        assert_eq!(file.matches(&root.join("keep.log"), false), Some(false));
+        assert_eq!(
+            file.matches(&root.join("x").join("cache"), true),
+            Some(true)
+        );
+        assert_eq!(file.matches(&root.join("cache"), false), None);
+    }
+
+    #[test]
+    fn test_ignored_paths_skips_tracked() {
+        let temp_dir = create_test_dir();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::create_dir_all(root.join("dist")).unwrap();
+        fs::create_dir_all(root.join("out")).unwrap();
+        fs::write(root.join(".gitignore"), "dist/\nout/\n*.tmp\n").unwrap();
+        fs::write(root.join("dist").join("app.js"), "x").unwrap();
+        fs::write(root.join("out").join("README"), "tracked").unwrap();
+        fs::write(root.join("out").join("a.bin"), "x").unwrap();
+        fs::write(root.join("main.rs"), "x").unwrap();
+        fs::write(root.join("scratch.tmp"), "x").unwrap();
+
+        // Version 2 index tracking out/README
+        let name = b"out/README";
+        let mut index = b"DIRC".to_vec();
+        index.extend_from_slice(&2u32.to_be_bytes());
+        index.extend_from_slice(&1u32.to_be_bytes());
+        let mut entry = vec![0u8; 60];
+        entry.extend_from_slice(&(name.len() as u16).to_be_bytes());
+        entry.extend_from_slice(name);
+        let padded = (entry.len() + 8) / 8 * 8;
+        entry.resize(padded, 0);
+        index.extend_from_slice(&entry);
+        fs::write(root.join(".git").join("index"), index).unwrap();
+
+        assert_eq!(
+            ignored_paths(root),
+            vec![
+                root.join("dist"),
+                root.join("out").join("a.bin"),
+                root.join("scratch.tmp")
+            ]
+        );
+    }
+
     #[test]
     #[ignore = "temporarily disabled to debug stack overflow"]
     fn test_is_dirty_no_repo() {
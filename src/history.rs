@@ -5,12 +5,17 @@
 //! - Undo information (path records)
 //! - Statistics tracking
 
+mod chain;
+pub mod checksums;
+
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+pub use chain::{verify_chain, ChainIssue, ChainReport};
+
 /// Record of a single deletion operation
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DeletionRecord {
@@ -29,6 +34,15 @@ pub struct DeletionRecord {
     pub success: bool,
     /// Error message if deletion failed
     pub error: Option<String>,
+    /// Hash of the previous record (tamper-evident mode only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_hash: Option<String>,
+    /// Hash of this record chained to `prev_hash` (tamper-evident mode only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// Every file removed, for permanent deletes with `history.record_checksums`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checksums: Vec<checksums::FileChecksum>,
 }
 
 impl DeletionRecord {
@@ -42,6 +56,9 @@ impl DeletionRecord {
             permanent,
             success: true,
             error: None,
+            prev_hash: None,
+            hash: None,
+            checksums: Vec::new(),
         }
     }
 
@@ -61,12 +78,23 @@ impl DeletionRecord {
             permanent,
             success: false,
             error: Some(error.to_string()),
+            prev_hash: None,
+            hash: None,
+            checksums: Vec::new(),
         }
     }
+
+    /// Case-insensitive substring match on category or path (empty filter matches everything)
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter = filter.trim().to_lowercase();
+        filter.is_empty()
+            || self.category.to_lowercase().contains(&filter)
+            || self.path.to_lowercase().contains(&filter)
+    }
 }
 
 /// Log of all deletions in a session
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct DeletionLog {
     /// When this session started
     #[serde(with = "chrono::serde::ts_seconds")]
@@ -105,10 +133,15 @@ impl DeletionLog {
     }
 
     /// Add a successful deletion
+    ///
+    /// Items on removable drives are recorded as permanent whatever was asked:
+    /// they never reach the Recycle Bin (see [`crate::trash_ops`]). A checksum
+    /// manifest captured before the delete is attached (see [`checksums`]).
     pub fn log_success(&mut self, path: &Path, size_bytes: u64, category: &str, permanent: bool) {
-        self.add_record(DeletionRecord::success(
-            path, size_bytes, category, permanent,
-        ));
+        let permanent = permanent || crate::trash_ops::deletes_outright(path);
+        let mut record = DeletionRecord::success(path, size_bytes, category, permanent);
+        record.checksums = checksums::take(path).unwrap_or_default();
+        self.add_record(record);
     }
 
     /// Add a failed deletion
@@ -120,6 +153,8 @@ impl DeletionLog {
         permanent: bool,
         error: &str,
     ) {
+        let permanent = permanent || crate::trash_ops::deletes_outright(path);
+        checksums::take(path);
         self.add_record(DeletionRecord::failure(
             path, size_bytes, category, permanent, error,
         ));
@@ -127,27 +162,91 @@ impl DeletionLog {
 
     /// Save the log to the history directory
     ///
+    /// Existing files are never overwritten. With `history.tamper_evident` on,
+    /// records are also hash-chained to the previous session.
+    ///
     /// Returns the path to the saved log file
     pub fn save(&self) -> Result<PathBuf> {
+        if crate::audit::is_enabled() {
+            anyhow::bail!("Audit mode is on - deletion log not written");
+        }
         let history_dir = get_history_dir()?;
-
-        // Create filename with timestamp
-        let filename = format!(
-            "cleanup_{}.json",
-            self.session_start.format("%Y%m%d_%H%M%S")
-        );
-        let log_path = history_dir.join(filename);
+        let tamper_evident = crate::config::Config::load().history.tamper_evident
+            || crate::policy::current().tamper_evident_history;
+
+        // Create filename with timestamp (sessions started in the same second,
+        // e.g. a clean and its background fast deletes, each keep their own file)
+        let stem = format!("cleanup_{}", self.session_start.format("%Y%m%d_%H%M%S"));
+        let mut log_path = history_dir.join(format!("{}.json", stem));
+        let mut n = 1;
+        while log_path.exists() {
+            log_path = history_dir.join(format!("{}_{}.json", stem, n));
+            n += 1;
+        }
 
         // Serialize and write
-        let json =
-            serde_json::to_string_pretty(self).context("Failed to serialize deletion log")?;
+        let mut sealed = None;
+        let json = if tamper_evident {
+            let mut log = self.clone();
+            sealed = Some(chain::seal(&mut log, &chain::current_head(&history_dir)?));
+            serde_json::to_string_pretty(&log)
+        } else {
+            serde_json::to_string_pretty(self)
+        }
+        .context("Failed to serialize deletion log")?;
 
         fs::write(&log_path, json)
             .with_context(|| format!("Failed to write deletion log to {}", log_path.display()))?;
 
+        if let Some(head) = sealed {
+            chain::write_head(&history_dir, &head)?;
+        }
+
         Ok(log_path)
     }
 
+    /// Distinct categories in this session, sorted
+    pub fn categories(&self) -> Vec<String> {
+        let mut categories: Vec<String> = self.records.iter().map(|r| r.category.clone()).collect();
+        categories.sort();
+        categories.dedup();
+        categories
+    }
+
+    /// Records that can still come back from the Recycle Bin (successful, non-permanent)
+    pub fn restorable_count(&self) -> usize {
+        self.records
+            .iter()
+            .filter(|r| r.success && !r.permanent)
+            .count()
+    }
+
+    /// Render the records as CSV (one row per record, with a header row)
+    pub fn to_csv(&self) -> String {
+        fn field(value: &str) -> String {
+            if value.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_string()
+            }
+        }
+
+        let mut csv = String::from("timestamp,path,size_bytes,category,permanent,success,error\n");
+        for record in &self.records {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                record.timestamp.to_rfc3339(),
+                field(&record.path),
+                record.size_bytes,
+                field(&record.category),
+                record.permanent,
+                record.success,
+                field(record.error.as_deref().unwrap_or(""))
+            ));
+        }
+        csv
+    }
+
     /// Get the summary message for this log
     pub fn summary(&self) -> String {
         format!(
@@ -230,6 +329,70 @@ pub fn load_log(path: &Path) -> Result<DeletionLog> {
     Ok(log)
 }
 
+/// A saved session and the history file it came from
+#[derive(Debug, Clone)]
+pub struct HistorySession {
+    pub path: PathBuf,
+    pub log: DeletionLog,
+}
+
+impl HistorySession {
+    /// Whether any record matches `filter` (see [`DeletionRecord::matches`])
+    pub fn matches(&self, filter: &str) -> bool {
+        self.log.records.iter().any(|r| r.matches(filter))
+    }
+}
+
+/// Load every saved session, newest first (unreadable files are skipped)
+pub fn load_sessions() -> Result<Vec<HistorySession>> {
+    Ok(list_logs()?
+        .into_iter()
+        .filter_map(|path| load_log(&path).ok().map(|log| HistorySession { path, log }))
+        .collect())
+}
+
+/// Export format for [`export_log`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+/// `%LOCALAPPDATA%\wole\exports\`, created if it doesn't exist
+pub fn get_export_dir() -> Result<PathBuf> {
+    let history_dir = get_history_dir()?;
+    let export_dir = history_dir
+        .parent()
+        .map(|p| p.join("exports"))
+        .unwrap_or_else(|| history_dir.join("exports"));
+    fs::create_dir_all(&export_dir).with_context(|| {
+        format!(
+            "Failed to create export directory: {}",
+            export_dir.display()
+        )
+    })?;
+    Ok(export_dir)
+}
+
+/// Write a session to `%LOCALAPPDATA%\wole\exports\` as CSV or JSON
+///
+/// Returns the path of the written file
+pub fn export_log(log: &DeletionLog, format: ExportFormat) -> Result<PathBuf> {
+    let export_dir = get_export_dir()?;
+
+    let stem = format!("cleanup_{}", log.session_start.format("%Y%m%d_%H%M%S"));
+    let (path, content) = match format {
+        ExportFormat::Csv => (export_dir.join(format!("{}.csv", stem)), log.to_csv()),
+        ExportFormat::Json => (
+            export_dir.join(format!("{}.json", stem)),
+            serde_json::to_string_pretty(log).context("Failed to serialize deletion log")?,
+        ),
+    };
+    fs::write(&path, content)
+        .with_context(|| format!("Failed to write export to {}", path.display()))?;
+    Ok(path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -334,4 +497,35 @@ mod tests {
         assert!(summary.contains("1000 bytes"));
         assert!(summary.contains("1 errors"));
     }
+
+    #[test]
+    fn test_deletion_log_filter_categories_and_csv() {
+        let mut log = DeletionLog::new();
+        log.log_success(Path::new("/cache/a,b.txt"), 1000, "cache", false);
+        log.log_success(Path::new("/build/target"), 2000, "build", true);
+        log.log_failure(Path::new("/temp/x"), 10, "temp", false, "Locked \"now\"");
+
+        assert_eq!(log.categories(), vec!["build", "cache", "temp"]);
+        assert_eq!(log.restorable_count(), 1);
+        assert!(log.records[0].matches("CACHE"));
+        assert!(log.records[1].matches("target"));
+        assert!(!log.records[1].matches("temp"));
+        assert!(log.records[2].matches(""));
+
+        let csv = log.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].contains(",\"/cache/a,b.txt\",1000,cache,false,true,"));
+        assert!(lines[3].ends_with(",temp,false,false,\"Locked \"\"now\"\"\""));
+    }
+
+    #[test]
+    fn test_save_refused_in_audit_mode() {
+        crate::audit::enable_for_test();
+        let mut log = DeletionLog::new();
+        log.log_success(Path::new("/test/file.txt"), 1024, "cache", false);
+
+        let err = log.save().unwrap_err();
+        assert!(err.to_string().contains("Audit mode"));
+    }
 }
@@ -0,0 +1,331 @@
+//! Tamper-evident history (`history.tamper_evident = true`)
+//!
+//! Every record gets `prev_hash` (the hash of the record before it, across
+//! sessions) and `hash` = blake3(prev_hash + record contents). The hash of the
+//! newest record is kept in `chain.head`, so [`verify_chain`] can tell when a
+//! record was edited, removed, reordered, or when whole sessions went missing.
+//!
+//! This makes tampering visible, it does not prevent it: someone who can write
+//! the history folder can rebuild the whole chain.
+
+use super::{get_history_dir, list_logs, load_log, DeletionLog, DeletionRecord};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// `prev_hash` of the very first record
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+const HEAD_FILE: &str = "chain.head";
+
+/// Hash of a record chained to `prev_hash`
+///
+/// Timestamps are hashed in whole seconds, the precision they are saved with.
+pub fn record_hash(record: &DeletionRecord, prev_hash: &str) -> String {
+    let contents = serde_json::to_vec(&(
+        record.timestamp.timestamp(),
+        &record.path,
+        record.size_bytes,
+        &record.category,
+        record.permanent,
+        record.success,
+        &record.error,
+    ))
+    .unwrap_or_default();
+
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(prev_hash.as_bytes());
+    hasher.update(&contents);
+    // Only records with a manifest hash it, so older chains still verify
+    if !record.checksums.is_empty() {
+        hasher.update(&serde_json::to_vec(&record.checksums).unwrap_or_default());
+    }
+    hasher.finalize().to_hex().to_string()
+}
+
+/// Chain every record of `log` after `prev_hash`; returns the new head hash
+pub fn seal(log: &mut DeletionLog, prev_hash: &str) -> String {
+    let mut prev = prev_hash.to_string();
+    for record in &mut log.records {
+        let hash = record_hash(record, &prev);
+        record.prev_hash = Some(prev);
+        record.hash = Some(hash.clone());
+        prev = hash;
+    }
+    prev
+}
+
+fn head_path(history_dir: &Path) -> PathBuf {
+    history_dir.join(HEAD_FILE)
+}
+
+/// Hash the next sealed record has to chain to
+///
+/// Falls back to the newest sealed record on disk when `chain.head` is missing
+/// (e.g. the first save after turning the mode on).
+pub fn current_head(history_dir: &Path) -> Result<String> {
+    if let Ok(head) = fs::read_to_string(head_path(history_dir)) {
+        let head = head.trim();
+        if !head.is_empty() {
+            return Ok(head.to_string());
+        }
+    }
+
+    for log_path in list_logs()? {
+        let Ok(log) = load_log(&log_path) else {
+            continue;
+        };
+        if let Some(hash) = log.records.iter().rev().find_map(|r| r.hash.clone()) {
+            return Ok(hash);
+        }
+    }
+    Ok(GENESIS_HASH.to_string())
+}
+
+/// Remember the newest hash after a sealed session was written
+pub fn write_head(history_dir: &Path, hash: &str) -> Result<()> {
+    let path = head_path(history_dir);
+    fs::write(&path, hash)
+        .with_context(|| format!("Failed to write history chain head: {}", path.display()))
+}
+
+/// Something `wole history verify` found wrong
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChainIssue {
+    /// The record's contents no longer match its hash
+    Modified {
+        session: String,
+        index: usize,
+        path: String,
+    },
+    /// The record doesn't follow the one before it: records in between were
+    /// removed, reordered or inserted
+    Broken {
+        session: String,
+        index: usize,
+        path: String,
+    },
+    /// Session totals don't add up to its records
+    TotalsMismatch { session: String },
+    /// The newest sealed record isn't the one `chain.head` points to
+    HeadMismatch { expected: String, found: String },
+    /// Sealed records exist but `chain.head` is gone
+    HeadMissing,
+    /// The history file could not be read
+    Unreadable { session: String, error: String },
+}
+
+impl std::fmt::Display for ChainIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChainIssue::Modified {
+                session,
+                index,
+                path,
+            } => write!(f, "{} record #{} was modified ({})", session, index + 1, path),
+            ChainIssue::Broken {
+                session,
+                index,
+                path,
+            } => write!(
+                f,
+                "{} record #{} does not follow the previous record - entries are missing or out of order ({})",
+                session,
+                index + 1,
+                path
+            ),
+            ChainIssue::TotalsMismatch { session } => {
+                write!(f, "{} totals do not match its records", session)
+            }
+            ChainIssue::HeadMismatch { .. } => write!(
+                f,
+                "the newest sealed record is not the last one written - newer entries are missing"
+            ),
+            ChainIssue::HeadMissing => write!(f, "chain.head is missing"),
+            ChainIssue::Unreadable { session, error } => {
+                write!(f, "{} could not be read: {}", session, error)
+            }
+        }
+    }
+}
+
+/// Result of [`verify_chain`]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ChainReport {
+    pub sessions: usize,
+    /// Records carrying a hash
+    pub sealed: usize,
+    /// Records saved while the mode was off (not covered by the chain)
+    pub unsealed: usize,
+    pub issues: Vec<ChainIssue>,
+}
+
+impl ChainReport {
+    pub fn is_intact(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Check the sessions (oldest first) against the chain
+fn verify_sessions(sessions: &[(String, DeletionLog)], head: Option<&str>) -> ChainReport {
+    let mut report = ChainReport {
+        sessions: sessions.len(),
+        ..Default::default()
+    };
+    let mut prev = GENESIS_HASH.to_string();
+
+    for (name, log) in sessions {
+        let cleaned: u64 = log
+            .records
+            .iter()
+            .filter(|r| r.success)
+            .map(|r| r.size_bytes)
+            .sum();
+        let errors = log.records.iter().filter(|r| !r.success).count();
+        if log.total_items != log.records.len()
+            || log.errors != errors
+            || log.total_bytes_cleaned != cleaned
+        {
+            report.issues.push(ChainIssue::TotalsMismatch {
+                session: name.clone(),
+            });
+        }
+
+        for (index, record) in log.records.iter().enumerate() {
+            let (Some(prev_hash), Some(hash)) = (&record.prev_hash, &record.hash) else {
+                report.unsealed += 1;
+                continue;
+            };
+            report.sealed += 1;
+
+            if *prev_hash != prev {
+                report.issues.push(ChainIssue::Broken {
+                    session: name.clone(),
+                    index,
+                    path: record.path.clone(),
+                });
+            }
+            if record_hash(record, prev_hash) != *hash {
+                report.issues.push(ChainIssue::Modified {
+                    session: name.clone(),
+                    index,
+                    path: record.path.clone(),
+                });
+            }
+            prev = hash.clone();
+        }
+    }
+
+    if report.sealed > 0 {
+        match head {
+            None => report.issues.push(ChainIssue::HeadMissing),
+            Some(head) if head != prev => report.issues.push(ChainIssue::HeadMismatch {
+                expected: head.to_string(),
+                found: prev,
+            }),
+            Some(_) => {}
+        }
+    }
+    report
+}
+
+/// Verify every saved session against the hash chain
+pub fn verify_chain() -> Result<ChainReport> {
+    let history_dir = get_history_dir()?;
+    let mut logs = list_logs()?;
+    logs.reverse(); // oldest first
+
+    let mut sessions = Vec::new();
+    let mut unreadable = Vec::new();
+    for path in logs {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+        match load_log(&path) {
+            Ok(log) => sessions.push((name, log)),
+            Err(e) => unreadable.push(ChainIssue::Unreadable {
+                session: name,
+                error: format!("{:#}", e),
+            }),
+        }
+    }
+
+    let head = fs::read_to_string(head_path(&history_dir))
+        .ok()
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty());
+    let mut report = verify_sessions(&sessions, head.as_deref());
+    report.sessions += unreadable.len();
+    report.issues.extend(unreadable);
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sealed_sessions() -> (Vec<(String, DeletionLog)>, String) {
+        let mut first = DeletionLog::new();
+        first.log_success(Path::new("/cache/a"), 100, "cache", false);
+        first.log_success(Path::new("/cache/b"), 200, "cache", false);
+        let mut second = DeletionLog::new();
+        second.log_failure(Path::new("/temp/c"), 50, "temp", false, "Locked");
+
+        let head = seal(&mut first, GENESIS_HASH);
+        let head = seal(&mut second, &head);
+        (
+            vec![
+                ("first.json".to_string(), first),
+                ("second.json".to_string(), second),
+            ],
+            head,
+        )
+    }
+
+    #[test]
+    fn test_verify_intact_chain() {
+        let (sessions, head) = sealed_sessions();
+        let report = verify_sessions(&sessions, Some(&head));
+        assert!(report.is_intact(), "{:?}", report.issues);
+        assert_eq!(report.sealed, 3);
+        assert_eq!(report.unsealed, 0);
+    }
+
+    #[test]
+    fn test_verify_detects_modified_and_missing_records() {
+        let (mut sessions, head) = sealed_sessions();
+        sessions[0].1.records[1].size_bytes = 1;
+        let report = verify_sessions(&sessions, Some(&head));
+        assert!(report.issues.contains(&ChainIssue::Modified {
+            session: "first.json".to_string(),
+            index: 1,
+            path: Path::new("/cache/b").display().to_string(),
+        }));
+
+        let (mut sessions, head) = sealed_sessions();
+        sessions[0].1.records.remove(1);
+        sessions[0].1.total_items = 1;
+        sessions[0].1.total_bytes_cleaned = 100;
+        let report = verify_sessions(&sessions, Some(&head));
+        assert_eq!(
+            report.issues,
+            vec![ChainIssue::Broken {
+                session: "second.json".to_string(),
+                index: 0,
+                path: Path::new("/temp/c").display().to_string(),
+            }]
+        );
+
+        // Dropping the newest session is caught by chain.head
+        let (mut sessions, head) = sealed_sessions();
+        sessions.pop();
+        let report = verify_sessions(&sessions, Some(&head));
+        assert!(matches!(
+            report.issues.as_slice(),
+            [ChainIssue::HeadMismatch { .. }]
+        ));
+    }
+}
@@ -0,0 +1,135 @@
+//! Checksum manifests for permanent deletes (`history.record_checksums = true`)
+//!
+//! A permanent delete can't be undone, so organizations that must prove what
+//! was removed need more than a path and a size. With the setting on, every
+//! file under a path is hashed (SHA-256) right before the path is deleted
+//! permanently; the manifest is held here until the caller logs the deletion,
+//! and [`super::DeletionLog::log_success`] attaches it to the record. In
+//! tamper-evident mode the manifest is part of the record's hash.
+//!
+//! Hashing reads every byte that is about to be deleted, which is why this is
+//! off by default. Recycle Bin deletes are not hashed: the files still exist.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// One file removed by a permanent delete
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FileChecksum {
+    /// Full path of the file
+    pub path: String,
+    pub size_bytes: u64,
+    /// Last modification time
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub modified: Option<DateTime<Utc>>,
+    /// Hex SHA-256 of the contents (None if the file couldn't be read)
+    pub sha256: Option<String>,
+}
+
+static RECORD_CHECKSUMS: AtomicBool = AtomicBool::new(false);
+
+/// Manifests captured but not yet logged, by deleted path
+static PENDING: Mutex<Option<HashMap<PathBuf, Vec<FileChecksum>>>> = Mutex::new(None);
+
+/// Hash permanent deletes for this process (`history.record_checksums`)
+pub fn set_enabled(enabled: bool) {
+    RECORD_CHECKSUMS.store(enabled, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    RECORD_CHECKSUMS.load(Ordering::Relaxed)
+}
+
+/// Hex SHA-256 of a file's contents
+pub fn sha256_file(path: &Path) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(crate::utils::long_path_if_needed(path))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
+
+/// Checksums of every file at or under `path`
+pub fn manifest(path: &Path) -> Vec<FileChecksum> {
+    let mut files: Vec<FileChecksum> = walkdir::WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| {
+            let metadata = entry.metadata().ok();
+            FileChecksum {
+                path: entry.path().display().to_string(),
+                size_bytes: metadata.as_ref().map(|m| m.len()).unwrap_or(0),
+                modified: metadata
+                    .and_then(|m| m.modified().ok())
+                    .map(DateTime::<Utc>::from),
+                sha256: sha256_file(entry.path()).ok(),
+            }
+        })
+        .collect();
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files
+}
+
+/// Hash `path` before it is deleted permanently (no-op unless enabled)
+pub fn capture(path: &Path) {
+    if !enabled() {
+        return;
+    }
+    let files = manifest(path);
+    if let Ok(mut pending) = PENDING.lock() {
+        pending
+            .get_or_insert_with(HashMap::new)
+            .insert(path.to_path_buf(), files);
+    }
+}
+
+/// The manifest captured for `path`, removing it from the pending set
+pub fn take(path: &Path) -> Option<Vec<FileChecksum>> {
+    PENDING.lock().ok()?.as_mut()?.remove(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_manifest_hashes_every_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path().join("old");
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(root.join("a.txt"), "abc").unwrap();
+        fs::write(root.join("sub").join("empty"), "").unwrap();
+
+        let files = manifest(&root);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].size_bytes, 3);
+        assert_eq!(
+            files[0].sha256.as_deref(),
+            Some("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        assert_eq!(
+            files[1].sha256.as_deref(),
+            Some("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+        );
+        assert!(files[0].modified.is_some());
+    }
+}
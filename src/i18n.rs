@@ -0,0 +1,178 @@
+//! Translations of the TUI labels and the CLI help
+//!
+//! Strings are looked up by their English text in small per-language catalogs
+//! (`i18n/de.rs`, ...). Anything missing from a catalog is shown in English, so
+//! a new label never breaks a translation, it just isn't translated yet.
+//!
+//! The language comes from `ui.language` ("auto" follows the system locale) and
+//! is applied process-wide with [`apply`]. Only call [`t`] where text is drawn or
+//! printed: category names, config values and other strings that are compared
+//! stay in English.
+
+mod de;
+mod es;
+mod fr;
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// A language the UI can be shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+    Spanish,
+    French,
+}
+
+impl Language {
+    pub const ALL: [Language; 4] = [
+        Language::English,
+        Language::German,
+        Language::Spanish,
+        Language::French,
+    ];
+
+    /// ISO 639-1 code, as used in `ui.language`
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+            Language::Spanish => "es",
+            Language::French => "fr",
+        }
+    }
+
+    /// Parse a language code or locale name ("de", "de-DE", "de_DE.UTF-8")
+    pub fn from_code(code: &str) -> Option<Self> {
+        let lang = code
+            .trim()
+            .split(['-', '_', '.', '@'])
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        Self::ALL.into_iter().find(|l| l.code() == lang)
+    }
+
+    fn catalog(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::English => &[],
+            Language::German => de::MESSAGES,
+            Language::Spanish => es::MESSAGES,
+            Language::French => fr::MESSAGES,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        Self::ALL
+            .get(value as usize)
+            .copied()
+            .unwrap_or(Language::English)
+    }
+}
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// Set the process-wide language from the `ui.language` setting
+pub fn apply(setting: &str) {
+    let language = resolve(setting);
+    let index = Language::ALL
+        .iter()
+        .position(|l| *l == language)
+        .unwrap_or(0);
+    CURRENT.store(index as u8, Ordering::Relaxed);
+}
+
+/// Language for a `ui.language` value: "auto" (or empty) follows the system
+/// locale, unknown codes fall back to English
+pub fn resolve(setting: &str) -> Language {
+    let setting = setting.trim();
+    if setting.is_empty() || setting.eq_ignore_ascii_case("auto") {
+        return system_locale()
+            .and_then(|locale| Language::from_code(&locale))
+            .unwrap_or_default();
+    }
+    Language::from_code(setting).unwrap_or_default()
+}
+
+/// The language set by the last [`apply`]
+pub fn current() -> Language {
+    Language::from_u8(CURRENT.load(Ordering::Relaxed))
+}
+
+/// Translate `text` into the current language (unchanged if there's no translation)
+pub fn t(text: &str) -> &str {
+    translate(current(), text)
+}
+
+/// Translate `text` into `language` (unchanged if there's no translation)
+pub fn translate(language: Language, text: &str) -> &str {
+    language
+        .catalog()
+        .iter()
+        .find(|(english, _)| *english == text)
+        .map(|(_, translated)| *translated)
+        .unwrap_or(text)
+}
+
+#[cfg(windows)]
+fn system_locale() -> Option<String> {
+    const LOCALE_NAME_MAX_LENGTH: usize = 85;
+    let mut buffer = [0u16; LOCALE_NAME_MAX_LENGTH];
+    let len = unsafe {
+        extern "system" {
+            fn GetUserDefaultLocaleName(lpLocaleName: *mut u16, cchLocaleName: i32) -> i32;
+        }
+        GetUserDefaultLocaleName(buffer.as_mut_ptr(), buffer.len() as i32)
+    };
+    if len <= 1 {
+        return None;
+    }
+    // len includes the terminating null
+    Some(String::from_utf16_lossy(&buffer[..len as usize - 1]))
+}
+
+#[cfg(not(windows))]
+fn system_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalogs_and_lookup() {
+        for language in Language::ALL {
+            let catalog = language.catalog();
+            for (i, (english, translated)) in catalog.iter().enumerate() {
+                assert!(!translated.is_empty(), "{}: {}", language.code(), english);
+                assert!(
+                    !catalog[..i].iter().any(|(other, _)| other == english),
+                    "{}: duplicate entry {}",
+                    language.code(),
+                    english
+                );
+            }
+        }
+
+        assert_eq!(translate(Language::German, "Quit"), "Beenden");
+        assert_eq!(translate(Language::French, "Trash"), "Corbeille");
+        // Missing entries and English fall back to the input
+        assert_eq!(
+            translate(Language::Spanish, "no such label"),
+            "no such label"
+        );
+        assert_eq!(translate(Language::English, "Quit"), "Quit");
+
+        assert_eq!(Language::from_code("de_DE.UTF-8"), Some(Language::German));
+        assert_eq!(Language::from_code("fr-CA"), Some(Language::French));
+        assert_eq!(Language::from_code("ES"), Some(Language::Spanish));
+        assert_eq!(Language::from_code("xx"), None);
+        assert_eq!(resolve("pt"), Language::English);
+        assert_eq!(resolve("es"), Language::Spanish);
+    }
+}
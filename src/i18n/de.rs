@@ -0,0 +1,374 @@
+//! German
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // CLI help
+    (
+        "Reclaim disk space on Windows by cleaning unused files",
+        "Speicherplatz unter Windows freigeben, indem ungenutzte Dateien bereinigt werden",
+    ),
+    (
+        "Find cleanable files (dry-run, safe to run anytime)",
+        "Bereinigbare Dateien finden (Probelauf, jederzeit sicher)",
+    ),
+    (
+        "Delete files found by scan (with confirmation)",
+        "Beim Scan gefundene Dateien löschen (mit Bestätigung)",
+    ),
+    (
+        "Show detailed analysis with file lists",
+        "Detaillierte Analyse mit Dateilisten anzeigen",
+    ),
+    (
+        "View or modify configuration",
+        "Konfiguration anzeigen oder ändern",
+    ),
+    (
+        "Restore files from the last deletion session",
+        "Dateien aus der letzten Löschsitzung wiederherstellen",
+    ),
+    (
+        "Uninstall wole from your system",
+        "wole von diesem System deinstallieren",
+    ),
+    (
+        "Check for and install updates",
+        "Nach Updates suchen und sie installieren",
+    ),
+    (
+        "Optimize Windows system performance",
+        "Windows-Systemleistung optimieren",
+    ),
+    (
+        "Show real-time system status dashboard",
+        "Systemstatus in Echtzeit anzeigen",
+    ),
+    (
+        "Show which folders grew or shrank the most since the previous scan",
+        "Zeigen, welche Ordner seit dem letzten Scan am stärksten gewachsen oder geschrumpft sind",
+    ),
+    (
+        "Suggest what to clean next, based on the last scan and the system drive",
+        "Vorschlagen, was als Nächstes bereinigt werden sollte, anhand des letzten Scans und des Systemlaufwerks",
+    ),
+    ("Inspect the deletion history", "Löschverlauf ansehen"),
+    (
+        "Inspect, compact, reset or move the scan cache",
+        "Scan-Cache anzeigen, verdichten, zurücksetzen oder übertragen",
+    ),
+    (
+        "Show the size, age and contents of the scan cache",
+        "Größe, Alter und Inhalt des Scan-Caches anzeigen",
+    ),
+    (
+        "Check the scan cache for damage and compact it",
+        "Scan-Cache auf Schäden prüfen und verdichten",
+    ),
+    (
+        "Delete the scan cache; the next scan starts from scratch",
+        "Scan-Cache löschen; der nächste Scan beginnt von vorn",
+    ),
+    (
+        "Save the config and latest scan baseline to a portable bundle",
+        "Konfiguration und letzte Scan-Basis in ein portables Paket speichern",
+    ),
+    (
+        "Restore the config and scan baseline from a bundle",
+        "Konfiguration und Scan-Basis aus einem Paket wiederherstellen",
+    ),
+    (
+        "Clean camera thumbnails and OS metadata off USB sticks and SD cards",
+        "Kamera-Miniaturen und Betriebssystem-Metadaten von USB-Sticks und SD-Karten entfernen",
+    ),
+    (
+        "List mounted removable drives and the junk on each",
+        "Eingesteckte Wechseldatenträger und den Datenmüll darauf auflisten",
+    ),
+    (
+        "Permanently delete the junk on a removable drive",
+        "Datenmüll auf einem Wechseldatenträger endgültig löschen",
+    ),
+    (
+        "Benchmark scan and clean throughput on a synthetic directory tree",
+        "Scan- und Bereinigungsdurchsatz an einem künstlichen Verzeichnisbaum messen",
+    ),
+    (
+        "Manage Windows startup programs",
+        "Windows-Autostartprogramme verwalten",
+    ),
+    (
+        "Check the tamper-evident hash chain for modified or missing entries",
+        "Die manipulationssichere Hash-Kette auf geänderte oder fehlende Einträge prüfen",
+    ),
+    (
+        "Increase output verbosity (-v, -vv for more)",
+        "Ausführlichere Ausgabe (-v, -vv für mehr)",
+    ),
+    (
+        "Suppress all output except errors",
+        "Alle Ausgaben außer Fehlern unterdrücken",
+    ),
+    (
+        "Read-only audit mode: never delete or change anything, only report what would happen",
+        "Nur-Lese-Prüfmodus: nichts löschen oder ändern, nur melden, was passieren würde",
+    ),
+    // Sidebar
+    ("Views", "Ansichten"),
+    ("Results", "Ergebnisse"),
+    ("Insights", "Analyse"),
+    ("Switch view", "Ansicht wechseln"),
+    // Dashboard
+    ("Actions", "Aktionen"),
+    ("Categories", "Kategorien"),
+    ("What would you like to do?", "Was möchten Sie tun?"),
+    ("Select categories to scan:", "Zu scannende Kategorien wählen:"),
+    ("Scan path: ", "Scan-Pfad: "),
+    ("(P to change)", "(P zum Ändern)"),
+    ("Pinned: ", "Angeheftet: "),
+    ("(1-9 to analyze)", "(1-9 zum Analysieren)"),
+    ("Scan", "Scannen"),
+    ("Clean", "Bereinigen"),
+    ("Analyze", "Analysieren"),
+    ("Optimize", "Optimieren"),
+    ("Status", "Status"),
+    ("Config", "Einstellungen"),
+    (
+        "Find cleanable files (safe, dry-run)",
+        "Bereinigbare Dateien finden (sicher, Probelauf)",
+    ),
+    ("Delete selected files", "Ausgewählte Dateien löschen"),
+    (
+        "Explore disk usage (folder sizes)",
+        "Speichernutzung erkunden (Ordnergrößen)",
+    ),
+    (
+        "Restore files from deletion or Recycle Bin",
+        "Gelöschte Dateien oder Papierkorb wiederherstellen",
+    ),
+    (
+        "Real-time system health dashboard",
+        "Systemzustand in Echtzeit",
+    ),
+    ("View or modify settings", "Einstellungen anzeigen oder ändern"),
+    (
+        "A. Quick Clean (recommended)",
+        "A. Schnellbereinigung (empfohlen)",
+    ),
+    ("B. Developer Cleanup", "B. Entwickler-Bereinigung"),
+    (
+        "C. Space Hunters (review required)",
+        "C. Platzfresser (Prüfung nötig)",
+    ),
+    (
+        "D. Advanced (admin required)",
+        "D. Erweitert (Administrator nötig)",
+    ),
+    // Category names
+    ("Package Cache", "Paket-Cache"),
+    ("Application Cache", "Anwendungs-Cache"),
+    ("Temp Files", "Temporäre Dateien"),
+    ("Trash", "Papierkorb"),
+    ("Build Artifacts", "Build-Artefakte"),
+    ("Old Downloads", "Alte Downloads"),
+    ("Large Files", "Große Dateien"),
+    ("Old Files", "Alte Dateien"),
+    ("Installed Applications", "Installierte Anwendungen"),
+    ("Browser Cache", "Browser-Cache"),
+    ("System Cache", "System-Cache"),
+    ("Empty Folders", "Leere Ordner"),
+    ("Duplicates", "Duplikate"),
+    ("Windows Update", "Windows Update"),
+    ("Event Logs", "Ereignisprotokolle"),
+    ("Thumbnail Cache", "Miniaturansicht-Cache"),
+    ("Crash Dumps", "Absturzabbilder"),
+    ("Log Files", "Protokolldateien"),
+    ("Game Caches", "Spiele-Caches"),
+    ("Toolchain Caches", "Toolchain-Caches"),
+    ("Python Environments", "Python-Umgebungen"),
+    ("ML Models", "ML-Modelle"),
+    ("Email Data", "E-Mail-Daten"),
+    ("Creative App Caches", "Caches von Kreativ-Apps"),
+    ("Device Backups", "Gerätesicherungen"),
+    ("Ignored Files (git)", "Ignorierte Dateien (git)"),
+    // Category descriptions
+    ("Recycle Bin contents", "Inhalt des Papierkorbs"),
+    ("Temporary system files", "Temporäre Systemdateien"),
+    ("Web browser data cache", "Datencache des Webbrowsers"),
+    (
+        "App data cache (Notion, VS Code, Slack, etc.)",
+        "App-Datencache (Notion, VS Code, Slack usw.)",
+    ),
+    ("Windows system cache files", "Windows-Systemcache-Dateien"),
+    ("Directories with no files", "Ordner ohne Dateien"),
+    (
+        "Package manager cache (npm, pip, nuget, etc.)",
+        "Cache der Paketmanager (npm, pip, nuget usw.)",
+    ),
+    (
+        "Unused Gradle/Maven/JetBrains/VS/sccache caches",
+        "Ungenutzte Gradle/Maven/JetBrains/VS/sccache-Caches",
+    ),
+    (
+        "Stale virtualenvs, conda envs and __pycache__",
+        "Veraltete virtualenvs, conda-Umgebungen und __pycache__",
+    ),
+    ("Uninstallable programs", "Deinstallierbare Programme"),
+    ("Unused download files", "Ungenutzte Downloads"),
+    ("Files over size threshold", "Dateien über der Größengrenze"),
+    (
+        "Files not accessed in X days",
+        "Dateien, die seit X Tagen nicht geöffnet wurden",
+    ),
+    ("Identical file copies", "Identische Dateikopien"),
+    (
+        "Oversized logs and runaway log rotation",
+        "Übergroße Protokolle und ausufernde Protokollrotation",
+    ),
+    (
+        "Steam/Epic/Origin/Battle.net caches and installers",
+        "Caches und Installer von Steam/Epic/Origin/Battle.net",
+    ),
+    (
+        "HuggingFace, torch, Ollama models (per model)",
+        "HuggingFace-, torch-, Ollama-Modelle (pro Modell)",
+    ),
+    (
+        "Large Outlook/Thunderbird files (report only)",
+        "Große Outlook/Thunderbird-Dateien (nur Bericht)",
+    ),
+    (
+        "Adobe/Resolve caches and OBS remux leftovers",
+        "Adobe/Resolve-Caches und OBS-Remux-Reste",
+    ),
+    (
+        "iPhone/iPad backups (pick the old ones)",
+        "iPhone/iPad-Sicherungen (alte auswählen)",
+    ),
+    (
+        "Untracked .gitignore matches in idle repos",
+        "Nicht versionierte .gitignore-Treffer in ruhenden Repos",
+    ),
+    (
+        "Update installation files (requires admin)",
+        "Update-Installationsdateien (Administrator nötig)",
+    ),
+    (
+        "System event logs (requires admin)",
+        "Systemereignisprotokolle (Administrator nötig)",
+    ),
+    (
+        "Explorer thumbnail/icon caches (restarts Explorer)",
+        "Miniaturansicht-/Symbol-Caches des Explorers (startet den Explorer neu)",
+    ),
+    (
+        "Old crash dumps and error reports (WER)",
+        "Alte Absturzabbilder und Fehlerberichte (WER)",
+    ),
+    // Shortcuts
+    ("Apps by cache", "Apps nach Cache"),
+    ("Clean this app", "Diese App bereinigen"),
+    ("Adjust", "Anpassen"),
+    ("Back", "Zurück"),
+    ("Back to Dashboard", "Zur Übersicht"),
+    ("Back to Options", "Zu den Optionen"),
+    ("Back to Results", "Zu den Ergebnissen"),
+    ("Background: off", "Hintergrund: aus"),
+    ("Background: on", "Hintergrund: an"),
+    ("Cancel", "Abbrechen"),
+    ("Clear Filter", "Filter löschen"),
+    ("Collapse group...", "Gruppe einklappen..."),
+    ("Compress", "Komprimieren"),
+    ("Dashboard", "Übersicht"),
+    ("Delete", "Löschen"),
+    ("Delete selected", "Auswahl löschen"),
+    ("Details", "Details"),
+    ("Discard Scan", "Scan verwerfen"),
+    ("Discard Session", "Sitzung verwerfen"),
+    ("Done", "Fertig"),
+    ("Drill In", "Öffnen"),
+    ("Edit/Toggle", "Bearbeiten/Umschalten"),
+    ("Exclude", "Ausschließen"),
+    ("Execute Action", "Aktion ausführen"),
+    ("Exit search", "Suche beenden"),
+    ("Expand", "Aufklappen"),
+    ("Export CSV/JSON", "CSV/JSON exportieren"),
+    ("Export CSV/JSON/WizTree", "CSV/JSON/WizTree exportieren"),
+    ("Filter", "Filter"),
+    ("Find Files", "Dateien suchen"),
+    ("Go Back", "Zurück"),
+    ("Group by owner", "Nach Besitzer gruppieren"),
+    ("Free up space", "Speicherplatz freigeben"),
+    ("Cap shadow storage", "Schattenkopien begrenzen"),
+    ("Confirm", "Bestätigen"),
+    ("Find items", "Einträge finden"),
+    ("Mark", "Markieren"),
+    ("Mark all matches", "Alle Treffer markieren"),
+    ("Clean marked", "Markierte bereinigen"),
+    ("Breakdown", "Aufschlüsselung"),
+    ("Next chart", "Nächstes Diagramm"),
+    ("Back to Disk Insights", "Zurück zu Disk Insights"),
+    ("Disk writes", "Schreibzugriffe"),
+    ("Path/process", "Pfad/Prozess"),
+    ("Restart", "Neu starten"),
+    ("Saved views", "Gespeicherte Ansichten"),
+    ("Apply", "Anwenden"),
+    ("Save search", "Suche speichern"),
+    ("View name", "Name der Ansicht"),
+    ("Undo", "Rückgängig"),
+    ("Redo", "Wiederholen"),
+    ("Select by rule", "Nach Regel auswählen"),
+    ("Rule", "Regel"),
+    ("Network check", "Netzwerkprüfung"),
+    ("End process", "Prozess beenden"),
+    ("Process tree", "Prozessbaum"),
+    ("Revert", "Zurücksetzen"),
+    ("Help", "Hilfe"),
+    ("Search:", "Suche:"),
+    ("No matching shortcuts", "Keine passenden Tastenkürzel"),
+    ("Type to search • ↑↓ Navigate • Esc Close", "Tippen zum Suchen • ↑↓ Navigieren • Esc Schließen"),
+    ("History", "Verlauf"),
+    ("Keep waiting", "Weiter warten"),
+    ("Narrow scope", "Bereich eingrenzen"),
+    ("Navigate", "Navigieren"),
+    ("Next Category", "Nächste Kategorie"),
+    ("Open", "Öffnen"),
+    ("Open File", "Datei öffnen"),
+    ("Open Folder", "Ordner öffnen"),
+    ("Pause", "Pause"),
+    ("Permanent", "Endgültig"),
+    ("Pin", "Anheften"),
+    ("Pinned", "Angeheftet"),
+    ("Protect folder", "Ordner schützen"),
+    ("Quarantine", "Quarantäne"),
+    ("Quit", "Beenden"),
+    ("Rebuild Index", "Index neu aufbauen"),
+    ("Refresh", "Aktualisieren"),
+    ("Reload", "Neu laden"),
+    ("Restore", "Wiederherstellen"),
+    ("Restore Session", "Sitzung wiederherstellen"),
+    ("Resume", "Fortsetzen"),
+    ("Resume Scan", "Scan fortsetzen"),
+    ("Retry", "Wiederholen"),
+    ("Run", "Ausführen"),
+    ("Save", "Speichern"),
+    ("Scan Path", "Scan-Pfad"),
+    ("Search", "Suchen"),
+    ("Select", "Auswählen"),
+    ("Select All", "Alle auswählen"),
+    ("Select Field", "Feld wählen"),
+    ("Sessions", "Sitzungen"),
+    ("Skip category", "Kategorie überspringen"),
+    ("Sort", "Sortieren"),
+    ("Stop cleaning", "Bereinigung stoppen"),
+    ("Switch Panel", "Bereich wechseln"),
+    ("Toggle", "Umschalten"),
+    ("Toggle (bool)", "Umschalten (ja/nein)"),
+    ("Toggle Category", "Kategorie umschalten"),
+    ("Up", "Hoch"),
+    ("Use & Save Default", "Verwenden & als Standard speichern"),
+    ("Use Folder", "Ordner verwenden"),
+    ("What Changed", "Was sich geändert hat"),
+    ("Why selected", "Warum ausgewählt"),
+    ("open", "öffnen"),
+    ("open folder", "Ordner öffnen"),
+    ("select/deselect", "aus-/abwählen"),
+];
@@ -0,0 +1,392 @@
+//! Spanish
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // CLI help
+    (
+        "Reclaim disk space on Windows by cleaning unused files",
+        "Recupera espacio en disco en Windows limpiando archivos sin usar",
+    ),
+    (
+        "Find cleanable files (dry-run, safe to run anytime)",
+        "Buscar archivos que se pueden limpiar (simulación, seguro en cualquier momento)",
+    ),
+    (
+        "Delete files found by scan (with confirmation)",
+        "Eliminar los archivos encontrados en el análisis (con confirmación)",
+    ),
+    (
+        "Show detailed analysis with file lists",
+        "Mostrar un análisis detallado con listas de archivos",
+    ),
+    (
+        "View or modify configuration",
+        "Ver o modificar la configuración",
+    ),
+    (
+        "Restore files from the last deletion session",
+        "Restaurar archivos de la última sesión de borrado",
+    ),
+    (
+        "Uninstall wole from your system",
+        "Desinstalar wole del sistema",
+    ),
+    (
+        "Check for and install updates",
+        "Buscar e instalar actualizaciones",
+    ),
+    (
+        "Optimize Windows system performance",
+        "Optimizar el rendimiento de Windows",
+    ),
+    (
+        "Show real-time system status dashboard",
+        "Mostrar el estado del sistema en tiempo real",
+    ),
+    (
+        "Show which folders grew or shrank the most since the previous scan",
+        "Mostrar qué carpetas crecieron o se redujeron más desde el análisis anterior",
+    ),
+    (
+        "Suggest what to clean next, based on the last scan and the system drive",
+        "Sugerir qué limpiar a continuación, según el último análisis y la unidad del sistema",
+    ),
+    (
+        "Inspect the deletion history",
+        "Consultar el historial de borrado",
+    ),
+    (
+        "Inspect, compact, reset or move the scan cache",
+        "Consultar, compactar, restablecer o trasladar la caché de análisis",
+    ),
+    (
+        "Show the size, age and contents of the scan cache",
+        "Mostrar el tamaño, la antigüedad y el contenido de la caché de análisis",
+    ),
+    (
+        "Check the scan cache for damage and compact it",
+        "Comprobar si la caché de análisis está dañada y compactarla",
+    ),
+    (
+        "Delete the scan cache; the next scan starts from scratch",
+        "Borrar la caché de análisis; el próximo análisis empieza desde cero",
+    ),
+    (
+        "Save the config and latest scan baseline to a portable bundle",
+        "Guardar la configuración y la última base de análisis en un paquete portátil",
+    ),
+    (
+        "Restore the config and scan baseline from a bundle",
+        "Restaurar la configuración y la base de análisis desde un paquete",
+    ),
+    (
+        "Clean camera thumbnails and OS metadata off USB sticks and SD cards",
+        "Eliminar miniaturas de cámara y metadatos del sistema de memorias USB y tarjetas SD",
+    ),
+    (
+        "List mounted removable drives and the junk on each",
+        "Listar las unidades extraíbles montadas y los residuos de cada una",
+    ),
+    (
+        "Permanently delete the junk on a removable drive",
+        "Eliminar permanentemente los residuos de una unidad extraíble",
+    ),
+    (
+        "Benchmark scan and clean throughput on a synthetic directory tree",
+        "Medir la velocidad de análisis y limpieza en un árbol de carpetas sintético",
+    ),
+    (
+        "Manage Windows startup programs",
+        "Administrar los programas de inicio de Windows",
+    ),
+    (
+        "Check the tamper-evident hash chain for modified or missing entries",
+        "Comprobar la cadena de hashes a prueba de manipulaciones en busca de entradas modificadas o ausentes",
+    ),
+    (
+        "Increase output verbosity (-v, -vv for more)",
+        "Aumentar el detalle de la salida (-v, -vv para más)",
+    ),
+    (
+        "Suppress all output except errors",
+        "Ocultar toda la salida salvo los errores",
+    ),
+    (
+        "Read-only audit mode: never delete or change anything, only report what would happen",
+        "Modo auditoría de solo lectura: no borra ni cambia nada, solo informa de lo que pasaría",
+    ),
+    // Sidebar
+    ("Views", "Vistas"),
+    ("Results", "Resultados"),
+    ("Insights", "Análisis"),
+    ("Switch view", "Cambiar vista"),
+    // Dashboard
+    ("Actions", "Acciones"),
+    ("Categories", "Categorías"),
+    ("What would you like to do?", "¿Qué quieres hacer?"),
+    (
+        "Select categories to scan:",
+        "Elige las categorías a analizar:",
+    ),
+    ("Scan path: ", "Ruta de análisis: "),
+    ("(P to change)", "(P para cambiar)"),
+    ("Pinned: ", "Fijadas: "),
+    ("(1-9 to analyze)", "(1-9 para analizar)"),
+    ("Scan", "Analizar"),
+    ("Clean", "Limpiar"),
+    ("Analyze", "Explorar"),
+    ("Optimize", "Optimizar"),
+    ("Status", "Estado"),
+    ("Config", "Configuración"),
+    (
+        "Find cleanable files (safe, dry-run)",
+        "Buscar archivos que se pueden limpiar (seguro, simulación)",
+    ),
+    ("Delete selected files", "Eliminar los archivos seleccionados"),
+    (
+        "Explore disk usage (folder sizes)",
+        "Explorar el uso del disco (tamaño de carpetas)",
+    ),
+    (
+        "Restore files from deletion or Recycle Bin",
+        "Restaurar archivos borrados o de la Papelera",
+    ),
+    (
+        "Real-time system health dashboard",
+        "Estado del sistema en tiempo real",
+    ),
+    (
+        "View or modify settings",
+        "Ver o modificar la configuración",
+    ),
+    (
+        "A. Quick Clean (recommended)",
+        "A. Limpieza rápida (recomendada)",
+    ),
+    ("B. Developer Cleanup", "B. Limpieza para desarrolladores"),
+    (
+        "C. Space Hunters (review required)",
+        "C. Cazadores de espacio (requiere revisión)",
+    ),
+    (
+        "D. Advanced (admin required)",
+        "D. Avanzado (requiere administrador)",
+    ),
+    // Category names
+    ("Package Cache", "Caché de paquetes"),
+    ("Application Cache", "Caché de aplicaciones"),
+    ("Temp Files", "Archivos temporales"),
+    ("Trash", "Papelera"),
+    ("Build Artifacts", "Artefactos de compilación"),
+    ("Old Downloads", "Descargas antiguas"),
+    ("Large Files", "Archivos grandes"),
+    ("Old Files", "Archivos antiguos"),
+    ("Installed Applications", "Aplicaciones instaladas"),
+    ("Browser Cache", "Caché del navegador"),
+    ("System Cache", "Caché del sistema"),
+    ("Empty Folders", "Carpetas vacías"),
+    ("Duplicates", "Duplicados"),
+    ("Windows Update", "Windows Update"),
+    ("Event Logs", "Registros de eventos"),
+    ("Thumbnail Cache", "Caché de miniaturas"),
+    ("Crash Dumps", "Volcados de bloqueo"),
+    ("Log Files", "Archivos de registro"),
+    ("Game Caches", "Cachés de juegos"),
+    ("Toolchain Caches", "Cachés de herramientas"),
+    ("Python Environments", "Entornos de Python"),
+    ("ML Models", "Modelos de ML"),
+    ("Email Data", "Datos de correo"),
+    ("Creative App Caches", "Cachés de apps creativas"),
+    ("Device Backups", "Copias de dispositivos"),
+    ("Ignored Files (git)", "Archivos ignorados (git)"),
+    // Category descriptions
+    ("Recycle Bin contents", "Contenido de la Papelera"),
+    ("Temporary system files", "Archivos temporales del sistema"),
+    (
+        "Web browser data cache",
+        "Caché de datos del navegador web",
+    ),
+    (
+        "App data cache (Notion, VS Code, Slack, etc.)",
+        "Caché de datos de apps (Notion, VS Code, Slack, etc.)",
+    ),
+    (
+        "Windows system cache files",
+        "Archivos de caché del sistema Windows",
+    ),
+    ("Directories with no files", "Carpetas sin archivos"),
+    (
+        "Package manager cache (npm, pip, nuget, etc.)",
+        "Caché de gestores de paquetes (npm, pip, nuget, etc.)",
+    ),
+    (
+        "Unused Gradle/Maven/JetBrains/VS/sccache caches",
+        "Cachés sin usar de Gradle/Maven/JetBrains/VS/sccache",
+    ),
+    (
+        "Stale virtualenvs, conda envs and __pycache__",
+        "virtualenvs y entornos conda abandonados y __pycache__",
+    ),
+    ("Uninstallable programs", "Programas que se pueden desinstalar"),
+    ("Unused download files", "Descargas sin usar"),
+    (
+        "Files over size threshold",
+        "Archivos que superan el tamaño límite",
+    ),
+    (
+        "Files not accessed in X days",
+        "Archivos sin abrir en X días",
+    ),
+    ("Identical file copies", "Copias idénticas de archivos"),
+    (
+        "Oversized logs and runaway log rotation",
+        "Registros enormes y rotación de registros descontrolada",
+    ),
+    (
+        "Steam/Epic/Origin/Battle.net caches and installers",
+        "Cachés e instaladores de Steam/Epic/Origin/Battle.net",
+    ),
+    (
+        "HuggingFace, torch, Ollama models (per model)",
+        "Modelos de HuggingFace, torch y Ollama (por modelo)",
+    ),
+    (
+        "Large Outlook/Thunderbird files (report only)",
+        "Archivos grandes de Outlook/Thunderbird (solo informe)",
+    ),
+    (
+        "Adobe/Resolve caches and OBS remux leftovers",
+        "Cachés de Adobe/Resolve y restos de remux de OBS",
+    ),
+    (
+        "iPhone/iPad backups (pick the old ones)",
+        "Copias de iPhone/iPad (elige las antiguas)",
+    ),
+    (
+        "Untracked .gitignore matches in idle repos",
+        "Coincidencias de .gitignore sin seguimiento en repos inactivos",
+    ),
+    (
+        "Update installation files (requires admin)",
+        "Archivos de instalación de actualizaciones (requiere administrador)",
+    ),
+    (
+        "System event logs (requires admin)",
+        "Registros de eventos del sistema (requiere administrador)",
+    ),
+    (
+        "Explorer thumbnail/icon caches (restarts Explorer)",
+        "Cachés de miniaturas e iconos del Explorador (reinicia el Explorador)",
+    ),
+    (
+        "Old crash dumps and error reports (WER)",
+        "Volcados de bloqueo e informes de errores antiguos (WER)",
+    ),
+    // Shortcuts
+    ("Apps by cache", "Apps por caché"),
+    ("Clean this app", "Limpiar esta app"),
+    ("Adjust", "Ajustar"),
+    ("Back", "Atrás"),
+    ("Back to Dashboard", "Volver al inicio"),
+    ("Back to Options", "Volver a opciones"),
+    ("Back to Results", "Volver a resultados"),
+    ("Background: off", "Segundo plano: no"),
+    ("Background: on", "Segundo plano: sí"),
+    ("Cancel", "Cancelar"),
+    ("Clear Filter", "Quitar filtro"),
+    ("Collapse group...", "Contraer grupo..."),
+    ("Compress", "Comprimir"),
+    ("Dashboard", "Inicio"),
+    ("Delete", "Eliminar"),
+    ("Delete selected", "Eliminar selección"),
+    ("Details", "Detalles"),
+    ("Discard Scan", "Descartar análisis"),
+    ("Discard Session", "Descartar sesión"),
+    ("Done", "Listo"),
+    ("Drill In", "Entrar"),
+    ("Edit/Toggle", "Editar/Alternar"),
+    ("Exclude", "Excluir"),
+    ("Execute Action", "Ejecutar acción"),
+    ("Exit search", "Salir de la búsqueda"),
+    ("Expand", "Expandir"),
+    ("Export CSV/JSON", "Exportar CSV/JSON"),
+    ("Export CSV/JSON/WizTree", "Exportar CSV/JSON/WizTree"),
+    ("Filter", "Filtrar"),
+    ("Find Files", "Buscar archivos"),
+    ("Go Back", "Volver"),
+    ("Group by owner", "Agrupar por propietario"),
+    ("Free up space", "Liberar espacio"),
+    ("Cap shadow storage", "Limitar instantáneas"),
+    ("Confirm", "Confirmar"),
+    ("Find items", "Buscar elementos"),
+    ("Mark", "Marcar"),
+    ("Mark all matches", "Marcar todas las coincidencias"),
+    ("Clean marked", "Limpiar marcados"),
+    ("Breakdown", "Desglose"),
+    ("Next chart", "Siguiente gráfico"),
+    ("Back to Disk Insights", "Volver a Disk Insights"),
+    ("Disk writes", "Escrituras en disco"),
+    ("Path/process", "Ruta/proceso"),
+    ("Restart", "Reiniciar"),
+    ("Saved views", "Vistas guardadas"),
+    ("Apply", "Aplicar"),
+    ("Save search", "Guardar búsqueda"),
+    ("View name", "Nombre de la vista"),
+    ("Undo", "Deshacer"),
+    ("Redo", "Rehacer"),
+    ("Select by rule", "Seleccionar por regla"),
+    ("Rule", "Regla"),
+    ("Network check", "Revisión de red"),
+    ("End process", "Finalizar proceso"),
+    ("Process tree", "Árbol de procesos"),
+    ("Revert", "Revertir"),
+    ("Help", "Ayuda"),
+    ("Search:", "Buscar:"),
+    ("No matching shortcuts", "Ningún atajo coincide"),
+    ("Type to search • ↑↓ Navigate • Esc Close", "Escriba para buscar • ↑↓ Navegar • Esc Cerrar"),
+    ("History", "Historial"),
+    ("Keep waiting", "Seguir esperando"),
+    ("Narrow scope", "Acotar alcance"),
+    ("Navigate", "Navegar"),
+    ("Next Category", "Siguiente categoría"),
+    ("Open", "Abrir"),
+    ("Open File", "Abrir archivo"),
+    ("Open Folder", "Abrir carpeta"),
+    ("Pause", "Pausa"),
+    ("Permanent", "Permanente"),
+    ("Pin", "Fijar"),
+    ("Pinned", "Fijadas"),
+    ("Protect folder", "Proteger carpeta"),
+    ("Quarantine", "Cuarentena"),
+    ("Quit", "Salir"),
+    ("Rebuild Index", "Reconstruir índice"),
+    ("Refresh", "Actualizar"),
+    ("Reload", "Recargar"),
+    ("Restore", "Restaurar"),
+    ("Restore Session", "Restaurar sesión"),
+    ("Resume", "Reanudar"),
+    ("Resume Scan", "Reanudar análisis"),
+    ("Retry", "Reintentar"),
+    ("Run", "Ejecutar"),
+    ("Save", "Guardar"),
+    ("Scan Path", "Ruta de análisis"),
+    ("Search", "Buscar"),
+    ("Select", "Seleccionar"),
+    ("Select All", "Seleccionar todo"),
+    ("Select Field", "Elegir campo"),
+    ("Sessions", "Sesiones"),
+    ("Skip category", "Omitir categoría"),
+    ("Sort", "Ordenar"),
+    ("Stop cleaning", "Detener limpieza"),
+    ("Switch Panel", "Cambiar panel"),
+    ("Toggle", "Alternar"),
+    ("Toggle (bool)", "Alternar (sí/no)"),
+    ("Toggle Category", "Alternar categoría"),
+    ("Up", "Subir"),
+    ("Use & Save Default", "Usar y guardar como predeterminada"),
+    ("Use Folder", "Usar carpeta"),
+    ("What Changed", "Qué cambió"),
+    ("Why selected", "Por qué se seleccionó"),
+    ("open", "abrir"),
+    ("open folder", "abrir carpeta"),
+    ("select/deselect", "marcar/desmarcar"),
+];
@@ -0,0 +1,395 @@
+//! French
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // CLI help
+    (
+        "Reclaim disk space on Windows by cleaning unused files",
+        "Libère de l'espace disque sous Windows en nettoyant les fichiers inutilisés",
+    ),
+    (
+        "Find cleanable files (dry-run, safe to run anytime)",
+        "Trouver les fichiers à nettoyer (simulation, sans risque à tout moment)",
+    ),
+    (
+        "Delete files found by scan (with confirmation)",
+        "Supprimer les fichiers trouvés par l'analyse (avec confirmation)",
+    ),
+    (
+        "Show detailed analysis with file lists",
+        "Afficher une analyse détaillée avec les listes de fichiers",
+    ),
+    (
+        "View or modify configuration",
+        "Afficher ou modifier la configuration",
+    ),
+    (
+        "Restore files from the last deletion session",
+        "Restaurer les fichiers de la dernière session de suppression",
+    ),
+    (
+        "Uninstall wole from your system",
+        "Désinstaller wole du système",
+    ),
+    (
+        "Check for and install updates",
+        "Rechercher et installer les mises à jour",
+    ),
+    (
+        "Optimize Windows system performance",
+        "Optimiser les performances de Windows",
+    ),
+    (
+        "Show real-time system status dashboard",
+        "Afficher l'état du système en temps réel",
+    ),
+    (
+        "Show which folders grew or shrank the most since the previous scan",
+        "Afficher les dossiers qui ont le plus grossi ou diminué depuis l'analyse précédente",
+    ),
+    (
+        "Suggest what to clean next, based on the last scan and the system drive",
+        "Suggérer quoi nettoyer ensuite, d'après la dernière analyse et le disque système",
+    ),
+    (
+        "Inspect the deletion history",
+        "Consulter l'historique des suppressions",
+    ),
+    (
+        "Inspect, compact, reset or move the scan cache",
+        "Consulter, compacter, réinitialiser ou transférer le cache d'analyse",
+    ),
+    (
+        "Show the size, age and contents of the scan cache",
+        "Afficher la taille, l'âge et le contenu du cache d'analyse",
+    ),
+    (
+        "Check the scan cache for damage and compact it",
+        "Vérifier l'intégrité du cache d'analyse et le compacter",
+    ),
+    (
+        "Delete the scan cache; the next scan starts from scratch",
+        "Supprimer le cache d'analyse ; la prochaine analyse repart de zéro",
+    ),
+    (
+        "Save the config and latest scan baseline to a portable bundle",
+        "Enregistrer la configuration et la dernière base d'analyse dans un paquet portable",
+    ),
+    (
+        "Restore the config and scan baseline from a bundle",
+        "Restaurer la configuration et la base d'analyse depuis un paquet",
+    ),
+    (
+        "Clean camera thumbnails and OS metadata off USB sticks and SD cards",
+        "Supprimer les miniatures d'appareil photo et les métadonnées système des clés USB et cartes SD",
+    ),
+    (
+        "List mounted removable drives and the junk on each",
+        "Lister les lecteurs amovibles montés et les fichiers inutiles de chacun",
+    ),
+    (
+        "Permanently delete the junk on a removable drive",
+        "Supprimer définitivement les fichiers inutiles d'un lecteur amovible",
+    ),
+    (
+        "Benchmark scan and clean throughput on a synthetic directory tree",
+        "Mesurer le débit d'analyse et de nettoyage sur une arborescence synthétique",
+    ),
+    (
+        "Manage Windows startup programs",
+        "Gérer les programmes de démarrage de Windows",
+    ),
+    (
+        "Check the tamper-evident hash chain for modified or missing entries",
+        "Vérifier la chaîne de hachage infalsifiable pour trouver les entrées modifiées ou manquantes",
+    ),
+    (
+        "Increase output verbosity (-v, -vv for more)",
+        "Augmenter le niveau de détail (-v, -vv pour plus)",
+    ),
+    (
+        "Suppress all output except errors",
+        "N'afficher que les erreurs",
+    ),
+    (
+        "Read-only audit mode: never delete or change anything, only report what would happen",
+        "Mode audit en lecture seule : ne supprime ni ne modifie rien, indique seulement ce qui se passerait",
+    ),
+    // Sidebar
+    ("Views", "Vues"),
+    ("Results", "Résultats"),
+    ("Insights", "Analyse"),
+    ("Switch view", "Changer de vue"),
+    // Dashboard
+    ("Actions", "Actions"),
+    ("Categories", "Catégories"),
+    ("What would you like to do?", "Que voulez-vous faire ?"),
+    (
+        "Select categories to scan:",
+        "Choisissez les catégories à analyser :",
+    ),
+    ("Scan path: ", "Chemin analysé : "),
+    ("(P to change)", "(P pour changer)"),
+    ("Pinned: ", "Épinglés : "),
+    ("(1-9 to analyze)", "(1-9 pour analyser)"),
+    ("Scan", "Analyser"),
+    ("Clean", "Nettoyer"),
+    ("Analyze", "Explorer"),
+    ("Optimize", "Optimiser"),
+    ("Status", "État"),
+    ("Config", "Réglages"),
+    (
+        "Find cleanable files (safe, dry-run)",
+        "Trouver les fichiers à nettoyer (sans risque, simulation)",
+    ),
+    (
+        "Delete selected files",
+        "Supprimer les fichiers sélectionnés",
+    ),
+    (
+        "Explore disk usage (folder sizes)",
+        "Explorer l'utilisation du disque (taille des dossiers)",
+    ),
+    (
+        "Restore files from deletion or Recycle Bin",
+        "Restaurer des fichiers supprimés ou de la Corbeille",
+    ),
+    (
+        "Real-time system health dashboard",
+        "État du système en temps réel",
+    ),
+    (
+        "View or modify settings",
+        "Afficher ou modifier les réglages",
+    ),
+    (
+        "A. Quick Clean (recommended)",
+        "A. Nettoyage rapide (recommandé)",
+    ),
+    ("B. Developer Cleanup", "B. Nettoyage développeur"),
+    (
+        "C. Space Hunters (review required)",
+        "C. Chasseurs d'espace (à vérifier)",
+    ),
+    (
+        "D. Advanced (admin required)",
+        "D. Avancé (administrateur requis)",
+    ),
+    // Category names
+    ("Package Cache", "Cache des paquets"),
+    ("Application Cache", "Cache des applications"),
+    ("Temp Files", "Fichiers temporaires"),
+    ("Trash", "Corbeille"),
+    ("Build Artifacts", "Artefacts de compilation"),
+    ("Old Downloads", "Anciens téléchargements"),
+    ("Large Files", "Gros fichiers"),
+    ("Old Files", "Anciens fichiers"),
+    ("Installed Applications", "Applications installées"),
+    ("Browser Cache", "Cache du navigateur"),
+    ("System Cache", "Cache système"),
+    ("Empty Folders", "Dossiers vides"),
+    ("Duplicates", "Doublons"),
+    ("Windows Update", "Windows Update"),
+    ("Event Logs", "Journaux d'événements"),
+    ("Thumbnail Cache", "Cache des miniatures"),
+    ("Crash Dumps", "Vidages sur incident"),
+    ("Log Files", "Fichiers journaux"),
+    ("Game Caches", "Caches de jeux"),
+    ("Toolchain Caches", "Caches d'outils"),
+    ("Python Environments", "Environnements Python"),
+    ("ML Models", "Modèles de ML"),
+    ("Email Data", "Données de messagerie"),
+    ("Creative App Caches", "Caches d'applis créatives"),
+    ("Device Backups", "Sauvegardes d'appareils"),
+    ("Ignored Files (git)", "Fichiers ignorés (git)"),
+    // Category descriptions
+    ("Recycle Bin contents", "Contenu de la Corbeille"),
+    ("Temporary system files", "Fichiers système temporaires"),
+    (
+        "Web browser data cache",
+        "Cache de données du navigateur web",
+    ),
+    (
+        "App data cache (Notion, VS Code, Slack, etc.)",
+        "Cache des applications (Notion, VS Code, Slack, etc.)",
+    ),
+    (
+        "Windows system cache files",
+        "Fichiers de cache du système Windows",
+    ),
+    ("Directories with no files", "Dossiers sans fichiers"),
+    (
+        "Package manager cache (npm, pip, nuget, etc.)",
+        "Cache des gestionnaires de paquets (npm, pip, nuget, etc.)",
+    ),
+    (
+        "Unused Gradle/Maven/JetBrains/VS/sccache caches",
+        "Caches Gradle/Maven/JetBrains/VS/sccache inutilisés",
+    ),
+    (
+        "Stale virtualenvs, conda envs and __pycache__",
+        "virtualenvs et environnements conda abandonnés, __pycache__",
+    ),
+    ("Uninstallable programs", "Programmes désinstallables"),
+    ("Unused download files", "Téléchargements inutilisés"),
+    (
+        "Files over size threshold",
+        "Fichiers au-delà du seuil de taille",
+    ),
+    (
+        "Files not accessed in X days",
+        "Fichiers non ouverts depuis X jours",
+    ),
+    ("Identical file copies", "Copies identiques de fichiers"),
+    (
+        "Oversized logs and runaway log rotation",
+        "Journaux démesurés et rotation de journaux incontrôlée",
+    ),
+    (
+        "Steam/Epic/Origin/Battle.net caches and installers",
+        "Caches et installateurs Steam/Epic/Origin/Battle.net",
+    ),
+    (
+        "HuggingFace, torch, Ollama models (per model)",
+        "Modèles HuggingFace, torch, Ollama (par modèle)",
+    ),
+    (
+        "Large Outlook/Thunderbird files (report only)",
+        "Gros fichiers Outlook/Thunderbird (rapport seulement)",
+    ),
+    (
+        "Adobe/Resolve caches and OBS remux leftovers",
+        "Caches Adobe/Resolve et restes de remux OBS",
+    ),
+    (
+        "iPhone/iPad backups (pick the old ones)",
+        "Sauvegardes iPhone/iPad (choisissez les anciennes)",
+    ),
+    (
+        "Untracked .gitignore matches in idle repos",
+        "Correspondances .gitignore non suivies des dépôts inactifs",
+    ),
+    (
+        "Update installation files (requires admin)",
+        "Fichiers d'installation des mises à jour (administrateur requis)",
+    ),
+    (
+        "System event logs (requires admin)",
+        "Journaux d'événements système (administrateur requis)",
+    ),
+    (
+        "Explorer thumbnail/icon caches (restarts Explorer)",
+        "Caches de miniatures et d'icônes de l'Explorateur (redémarre l'Explorateur)",
+    ),
+    (
+        "Old crash dumps and error reports (WER)",
+        "Anciens vidages sur incident et rapports d'erreurs (WER)",
+    ),
+    // Shortcuts
+    ("Apps by cache", "Applis par cache"),
+    ("Clean this app", "Nettoyer cette appli"),
+    ("Adjust", "Régler"),
+    ("Back", "Retour"),
+    ("Back to Dashboard", "Retour à l'accueil"),
+    ("Back to Options", "Retour aux options"),
+    ("Back to Results", "Retour aux résultats"),
+    ("Background: off", "Arrière-plan : non"),
+    ("Background: on", "Arrière-plan : oui"),
+    ("Cancel", "Annuler"),
+    ("Clear Filter", "Effacer le filtre"),
+    ("Collapse group...", "Replier le groupe..."),
+    ("Compress", "Compresser"),
+    ("Dashboard", "Accueil"),
+    ("Delete", "Supprimer"),
+    ("Delete selected", "Supprimer la sélection"),
+    ("Details", "Détails"),
+    ("Discard Scan", "Abandonner l'analyse"),
+    ("Discard Session", "Abandonner la session"),
+    ("Done", "Terminé"),
+    ("Drill In", "Entrer"),
+    ("Edit/Toggle", "Modifier/Basculer"),
+    ("Exclude", "Exclure"),
+    ("Execute Action", "Exécuter l'action"),
+    ("Exit search", "Quitter la recherche"),
+    ("Expand", "Déplier"),
+    ("Export CSV/JSON", "Exporter CSV/JSON"),
+    ("Export CSV/JSON/WizTree", "Exporter CSV/JSON/WizTree"),
+    ("Filter", "Filtrer"),
+    ("Find Files", "Chercher des fichiers"),
+    ("Go Back", "Revenir"),
+    ("Group by owner", "Grouper par propriétaire"),
+    ("Free up space", "Libérer de l'espace"),
+    ("Cap shadow storage", "Limiter les clichés instantanés"),
+    ("Confirm", "Confirmer"),
+    ("Find items", "Rechercher des éléments"),
+    ("Mark", "Marquer"),
+    ("Mark all matches", "Marquer tous les résultats"),
+    ("Clean marked", "Nettoyer les éléments marqués"),
+    ("Breakdown", "Répartition"),
+    ("Next chart", "Graphique suivant"),
+    ("Back to Disk Insights", "Retour à Disk Insights"),
+    ("Disk writes", "Écritures disque"),
+    ("Path/process", "Chemin/processus"),
+    ("Restart", "Redémarrer"),
+    ("Saved views", "Vues enregistrées"),
+    ("Apply", "Appliquer"),
+    ("Save search", "Enregistrer la recherche"),
+    ("View name", "Nom de la vue"),
+    ("Undo", "Annuler"),
+    ("Redo", "Rétablir"),
+    ("Select by rule", "Sélectionner par règle"),
+    ("Rule", "Règle"),
+    ("Network check", "Vérification réseau"),
+    ("End process", "Terminer le processus"),
+    ("Process tree", "Arbre des processus"),
+    ("Revert", "Rétablir"),
+    ("Help", "Aide"),
+    ("Search:", "Rechercher :"),
+    ("No matching shortcuts", "Aucun raccourci correspondant"),
+    ("Type to search • ↑↓ Navigate • Esc Close", "Tapez pour rechercher • ↑↓ Naviguer • Échap Fermer"),
+    ("History", "Historique"),
+    ("Keep waiting", "Continuer d'attendre"),
+    ("Narrow scope", "Restreindre"),
+    ("Navigate", "Naviguer"),
+    ("Next Category", "Catégorie suivante"),
+    ("Open", "Ouvrir"),
+    ("Open File", "Ouvrir le fichier"),
+    ("Open Folder", "Ouvrir le dossier"),
+    ("Pause", "Pause"),
+    ("Permanent", "Définitif"),
+    ("Pin", "Épingler"),
+    ("Pinned", "Épinglés"),
+    ("Protect folder", "Protéger le dossier"),
+    ("Quarantine", "Quarantaine"),
+    ("Quit", "Quitter"),
+    ("Rebuild Index", "Reconstruire l'index"),
+    ("Refresh", "Actualiser"),
+    ("Reload", "Recharger"),
+    ("Restore", "Restaurer"),
+    ("Restore Session", "Restaurer la session"),
+    ("Resume", "Reprendre"),
+    ("Resume Scan", "Reprendre l'analyse"),
+    ("Retry", "Réessayer"),
+    ("Run", "Lancer"),
+    ("Save", "Enregistrer"),
+    ("Scan Path", "Chemin analysé"),
+    ("Search", "Rechercher"),
+    ("Select", "Sélectionner"),
+    ("Select All", "Tout sélectionner"),
+    ("Select Field", "Choisir le champ"),
+    ("Sessions", "Sessions"),
+    ("Skip category", "Passer la catégorie"),
+    ("Sort", "Trier"),
+    ("Stop cleaning", "Arrêter le nettoyage"),
+    ("Switch Panel", "Changer de panneau"),
+    ("Toggle", "Basculer"),
+    ("Toggle (bool)", "Basculer (oui/non)"),
+    ("Toggle Category", "Basculer la catégorie"),
+    ("Up", "Monter"),
+    ("Use & Save Default", "Utiliser et enregistrer par défaut"),
+    ("Use Folder", "Utiliser le dossier"),
+    ("What Changed", "Ce qui a changé"),
+    ("Why selected", "Pourquoi sélectionné"),
+    ("open", "ouvrir"),
+    ("open folder", "ouvrir le dossier"),
+    ("select/deselect", "cocher/décocher"),
+];
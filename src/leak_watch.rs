@@ -0,0 +1,261 @@
+//! Memory leak suspects for the Status screen
+//!
+//! Every Status refresh reports the working set of each process to a
+//! [`LeakWatch`]. A process whose working set has not dropped for at least
+//! [`MIN_SAMPLES`] refreshes and has grown by more than
+//! `optimize.leak_threshold_mb` in that time is listed as a possible leak.
+//! A drop starts the count over, so caches that fill up and get trimmed
+//! aren't flagged. Only the current session is tracked; nothing is stored.
+
+use crate::status::ProcessSample;
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+/// Refreshes a process must grow over before it is flagged
+pub const MIN_SAMPLES: u32 = 5;
+
+/// How long a restart waits for the old process to exit
+const EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A process whose memory keeps growing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeakSuspect {
+    pub pid: u32,
+    pub name: String,
+    /// Working set when the growth started
+    pub start_bytes: u64,
+    pub current_bytes: u64,
+    /// Refreshes since the growth started
+    pub samples: u32,
+}
+
+impl LeakSuspect {
+    pub fn growth(&self) -> u64 {
+        self.current_bytes.saturating_sub(self.start_bytes)
+    }
+}
+
+/// Action waiting for its key to be pressed a second time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeakAction {
+    Kill,
+    Restart,
+}
+
+#[derive(Debug)]
+struct Track {
+    name: String,
+    start: u64,
+    last: u64,
+    samples: u32,
+    round: u64,
+}
+
+/// Follows working sets across Status refreshes
+#[derive(Debug, Default)]
+pub struct LeakWatch {
+    tracks: HashMap<u32, Track>,
+    round: u64,
+    suspects: Vec<LeakSuspect>,
+    /// Selected row of the "Possible leaks" panel
+    pub cursor: usize,
+    /// Kill or restart asked for once, with the pid it was asked for
+    pub pending: Option<(LeakAction, u32)>,
+}
+
+impl LeakWatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Possible leaks, biggest growth first
+    pub fn suspects(&self) -> &[LeakSuspect] {
+        &self.suspects
+    }
+
+    pub fn selected(&self) -> Option<&LeakSuspect> {
+        self.suspects.get(self.cursor)
+    }
+
+    /// Record one refresh worth of working sets
+    pub fn observe(&mut self, processes: &[ProcessSample], threshold_bytes: u64) {
+        self.round += 1;
+        let round = self.round;
+        for process in processes {
+            match self.tracks.get_mut(&process.pid) {
+                // A reused pid is a new process
+                Some(track) if track.name == process.name => {
+                    if process.memory_bytes < track.last {
+                        track.start = process.memory_bytes;
+                        track.samples = 1;
+                    } else {
+                        track.samples += 1;
+                    }
+                    track.last = process.memory_bytes;
+                    track.round = round;
+                }
+                _ => {
+                    self.tracks.insert(
+                        process.pid,
+                        Track {
+                            name: process.name.clone(),
+                            start: process.memory_bytes,
+                            last: process.memory_bytes,
+                            samples: 1,
+                            round,
+                        },
+                    );
+                }
+            }
+        }
+        // Processes that exited
+        self.tracks.retain(|_, track| track.round == round);
+
+        self.suspects = self
+            .tracks
+            .iter()
+            .filter(|(_, track)| {
+                track.samples >= MIN_SAMPLES && track.last - track.start >= threshold_bytes
+            })
+            .map(|(&pid, track)| LeakSuspect {
+                pid,
+                name: track.name.clone(),
+                start_bytes: track.start,
+                current_bytes: track.last,
+                samples: track.samples,
+            })
+            .collect();
+        self.suspects
+            .sort_by(|a, b| b.growth().cmp(&a.growth()).then(a.pid.cmp(&b.pid)));
+        self.cursor = self.cursor.min(self.suspects.len().saturating_sub(1));
+    }
+
+    /// Stop listing a process that was ended
+    pub fn forget(&mut self, pid: u32) {
+        self.tracks.remove(&pid);
+        self.suspects.retain(|suspect| suspect.pid != pid);
+        self.cursor = self.cursor.min(self.suspects.len().saturating_sub(1));
+    }
+}
+
+/// The process `pid`, if it is still the one called `name`
+fn refresh_process(system: &mut System, pid: Pid, name: &str) -> Result<()> {
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::everything(),
+    );
+    match system.process(pid) {
+        Some(process) if process.name().to_string_lossy() == name => Ok(()),
+        _ => Err(anyhow::anyhow!("{} is no longer running", name)),
+    }
+}
+
+/// End a process
+pub fn kill_process(pid: u32, name: &str) -> Result<()> {
+    if crate::audit::is_enabled() {
+        crate::audit::note(&format!("end {} (pid {})", name, pid));
+        anyhow::bail!("Audit mode is on - nothing was changed");
+    }
+    let mut system = System::new();
+    let pid = Pid::from_u32(pid);
+    refresh_process(&mut system, pid, name)?;
+    if !system.process(pid).is_some_and(|process| process.kill()) {
+        anyhow::bail!("Could not end {} (it may need administrator rights)", name);
+    }
+    Ok(())
+}
+
+/// End a process and start it again with the same command line
+pub fn restart_process(pid: u32, name: &str) -> Result<()> {
+    if crate::audit::is_enabled() {
+        crate::audit::note(&format!("restart {} (pid {})", name, pid));
+        anyhow::bail!("Audit mode is on - nothing was changed");
+    }
+    let mut system = System::new();
+    let sys_pid = Pid::from_u32(pid);
+    refresh_process(&mut system, sys_pid, name)?;
+    let (exe, args, cwd) = {
+        let process = system.process(sys_pid).context("Process disappeared")?;
+        let exe = process
+            .exe()
+            .map(|exe| exe.to_path_buf())
+            .with_context(|| format!("Can't tell which program {} runs", name))?;
+        let args: Vec<std::ffi::OsString> = process.cmd().iter().skip(1).cloned().collect();
+        (exe, args, process.cwd().map(|cwd| cwd.to_path_buf()))
+    };
+
+    kill_process(pid, name)?;
+    let started = Instant::now();
+    while refresh_process(&mut system, sys_pid, name).is_ok() {
+        if started.elapsed() > EXIT_TIMEOUT {
+            anyhow::bail!("{} did not exit in time; it was not restarted", name);
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+
+    let mut command = std::process::Command::new(&exe);
+    command.args(args);
+    if let Some(cwd) = cwd.filter(|cwd| cwd.is_dir()) {
+        command.current_dir(cwd);
+    }
+    command
+        .spawn()
+        .with_context(|| format!("Failed to start {}", exe.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MB: u64 = 1024 * 1024;
+
+    fn sample(pid: u32, name: &str, mb: u64) -> ProcessSample {
+        ProcessSample {
+            pid,
+            name: name.to_string(),
+            memory_bytes: mb * MB,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_flags_steady_growth_only() {
+        let mut watch = LeakWatch::new();
+        for i in 0..MIN_SAMPLES as u64 {
+            watch.observe(
+                &[
+                    sample(1, "leaky.exe", 100 + i * 60),
+                    // Grows as much but drops once
+                    sample(2, "cache.exe", if i == 2 { 50 } else { 100 + i * 60 }),
+                    sample(3, "steady.exe", 300),
+                ],
+                200 * MB,
+            );
+        }
+        let suspects = watch.suspects();
+        assert_eq!(suspects.len(), 1);
+        assert_eq!(suspects[0].name, "leaky.exe");
+        assert_eq!(suspects[0].growth(), 240 * MB);
+        assert_eq!(suspects[0].samples, MIN_SAMPLES);
+    }
+
+    #[test]
+    fn test_exited_and_reused_pids_start_over() {
+        let mut watch = LeakWatch::new();
+        for i in 0..MIN_SAMPLES as u64 {
+            watch.observe(&[sample(1, "leaky.exe", 100 + i * 100)], 200 * MB);
+        }
+        assert_eq!(watch.suspects().len(), 1);
+
+        // Same pid, different program
+        watch.observe(&[sample(1, "other.exe", 900)], 200 * MB);
+        assert!(watch.suspects().is_empty());
+
+        watch.observe(&[], 200 * MB);
+        assert!(watch.tracks.is_empty());
+    }
+}
@@ -1,32 +1,66 @@
 //! Wole library crate
 //!
-//! This crate provides both a CLI binary and a library API for programmatic use
+//! This crate provides both a CLI binary and a library API for programmatic use.
+//! Embedders should start with [`api::Sweeper`].
 
+pub mod ads;
 pub mod analyzer;
+pub mod api;
+pub mod audit;
+pub mod auto_select;
+pub mod bench;
+pub mod big_media;
+pub mod cache_owners;
+pub mod cancel;
 pub mod categories;
 pub mod cleaner;
 pub mod cli;
+pub mod cloud;
+pub mod compress;
 pub mod config;
 pub mod debug_log;
 pub mod disk_usage;
 mod disk_usage_cache;
+pub mod disk_writes;
+pub mod drive;
 pub mod git;
 pub mod history;
+pub mod i18n;
+pub mod leak_watch;
+pub mod manifest;
+pub mod net_hygiene;
+pub mod network;
 pub mod optimize;
 pub mod output;
+pub mod plugin;
+pub mod policy;
+pub mod power;
+pub mod process_tree;
 pub mod progress;
 pub mod project;
+pub mod recommend;
+pub mod recycle_bin;
+pub mod removable;
 pub mod restore;
 pub mod scan_cache;
 pub mod scan_events;
 pub mod scanner;
+pub mod select;
+pub mod sensitive;
+pub mod shadow;
 pub mod size;
 pub mod spinner;
 pub mod status;
+pub mod temperature;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod theme;
+pub mod throttle;
 pub mod tui;
 pub mod uninstall;
 pub mod update;
+pub mod users;
 pub mod utils;
+pub mod why_full;
 
 pub(crate) mod trash_ops;
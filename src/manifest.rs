@@ -0,0 +1,187 @@
+//! Path manifests for `wole remove --manifest`
+//!
+//! One path per line. Blank lines are skipped and `#` starts a comment, either
+//! on its own line or after a path following whitespace (`#` is valid in file
+//! names). Relative paths are relative to the manifest's folder. Lines with
+//! `*`, `?`, `[...]` or `**` are globs expanded against the file system: they
+//! match files and folders, `*` stays within one folder and `**` crosses any
+//! number of them. A folder that matches is listed once, not its contents.
+
+use anyhow::{Context, Result};
+use globset::GlobBuilder;
+use std::path::{Component, Path, PathBuf};
+
+/// A path or glob from one manifest line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    /// 1-based line number
+    pub line: usize,
+    pub pattern: String,
+}
+
+impl ManifestEntry {
+    pub fn is_glob(&self) -> bool {
+        is_glob(&self.pattern)
+    }
+}
+
+/// Read the entries of a manifest file
+pub fn load(path: &Path) -> Result<Vec<ManifestEntry>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read manifest {}", path.display()))?;
+    Ok(parse(&text))
+}
+
+pub fn parse(text: &str) -> Vec<ManifestEntry> {
+    text.lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let pattern = strip_comment(line).trim();
+            (!pattern.is_empty()).then(|| ManifestEntry {
+                line: i + 1,
+                pattern: pattern.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn strip_comment(line: &str) -> &str {
+    if line.trim_start().starts_with('#') {
+        return "";
+    }
+    match line.find(" #").or_else(|| line.find("\t#")) {
+        Some(at) => &line[..at],
+        None => line,
+    }
+}
+
+fn is_glob(text: &str) -> bool {
+    text.contains(['*', '?', '['])
+}
+
+/// The paths an entry stands for, sorted. A plain path is returned as is,
+/// even if it doesn't exist; a glob returns what it matches now.
+pub fn expand(entry: &ManifestEntry, base: &Path) -> Result<Vec<PathBuf>> {
+    let path = base.join(&entry.pattern);
+    if !entry.is_glob() {
+        return Ok(vec![path]);
+    }
+
+    // Walk from the deepest folder without glob characters
+    let mut root = PathBuf::new();
+    let mut rest = 0;
+    let mut any_depth = false;
+    for component in path.components() {
+        let text = component.as_os_str().to_string_lossy();
+        if rest == 0 && !is_glob(&text) {
+            root.push(component);
+            continue;
+        }
+        if matches!(component, Component::Normal(_)) {
+            rest += 1;
+            any_depth |= text.contains("**");
+        }
+    }
+
+    let pattern = path.to_string_lossy();
+    let pattern = if cfg!(windows) {
+        pattern.replace('\\', "/")
+    } else {
+        pattern.to_string()
+    };
+    let matcher = GlobBuilder::new(&pattern)
+        .literal_separator(true)
+        .case_insensitive(cfg!(windows))
+        .build()
+        .with_context(|| format!("Invalid pattern on line {}: {}", entry.line, entry.pattern))?
+        .compile_matcher();
+
+    let mut walker = walkdir::WalkDir::new(&root).min_depth(1);
+    if !any_depth {
+        walker = walker.max_depth(rest);
+    }
+    let mut matches = Vec::new();
+    let mut entries = walker.into_iter();
+    while let Some(found) = entries.next() {
+        let Ok(found) = found else {
+            continue;
+        };
+        if matcher.is_match(found.path()) {
+            matches.push(found.path().to_path_buf());
+            if found.file_type().is_dir() {
+                entries.skip_current_dir();
+            }
+        }
+    }
+    matches.sort();
+    Ok(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_skips_comments_and_blank_lines() {
+        let text =
+            "# cleanup list\n\nC:\\old\\build   # stale\n  logs/*.log\nC:\\a#b\n\t# indented\n";
+        let entries = parse(text);
+        assert_eq!(
+            entries,
+            [
+                ManifestEntry {
+                    line: 3,
+                    pattern: "C:\\old\\build".to_string()
+                },
+                ManifestEntry {
+                    line: 4,
+                    pattern: "logs/*.log".to_string()
+                },
+                ManifestEntry {
+                    line: 5,
+                    pattern: "C:\\a#b".to_string()
+                },
+            ]
+        );
+        assert!(entries[1].is_glob() && !entries[0].is_glob());
+    }
+
+    #[test]
+    fn test_expand_globs() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path();
+        fs::create_dir_all(base.join("logs/old")).unwrap();
+        fs::write(base.join("logs/a.log"), "a").unwrap();
+        fs::write(base.join("logs/b.txt"), "b").unwrap();
+        fs::write(base.join("logs/old/c.log"), "c").unwrap();
+        fs::create_dir_all(base.join("cache/x.log")).unwrap();
+        fs::write(base.join("cache/x.log/inner.log"), "d").unwrap();
+
+        let entry = |pattern: &str| ManifestEntry {
+            line: 1,
+            pattern: pattern.to_string(),
+        };
+
+        // `*` stays in its folder
+        assert_eq!(
+            expand(&entry("logs/*.log"), base).unwrap(),
+            [base.join("logs/a.log")]
+        );
+        // `**` goes deeper; a matching folder hides its contents
+        assert_eq!(
+            expand(&entry("**/*.log"), base).unwrap(),
+            [
+                base.join("cache/x.log"),
+                base.join("logs/a.log"),
+                base.join("logs/old/c.log")
+            ]
+        );
+        assert!(expand(&entry("missing/*.log"), base).unwrap().is_empty());
+        // Plain paths are returned as given
+        assert_eq!(
+            expand(&entry("gone.txt"), base).unwrap(),
+            [base.join("gone.txt")]
+        );
+    }
+}
@@ -0,0 +1,541 @@
+//! Hosts file, proxy and DNS override checks (`wole netcheck`)
+//!
+//! VPN clients, debugging proxies and dev tools change network settings and
+//! don't always put them back. This module reports the leftovers that break
+//! browsing or updates:
+//! - hosts file entries that redirect or block well-known domains, or point a
+//!   name at a fixed address;
+//! - a system proxy (or proxy script) in Internet Settings, with a check
+//!   whether a local proxy is still listening;
+//! - DNS servers set by hand on adapters that otherwise use DHCP.
+//!
+//! Each issue can be reverted on its own (the hosts line is commented out,
+//! the proxy turned off, the adapter's DNS set back to automatic). The
+//! previous state is always backed up to `%LOCALAPPDATA%\wole\backups` first.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::net::IpAddr;
+use std::path::PathBuf;
+
+/// Domains that should never be redirected or blocked
+const SENSITIVE_DOMAINS: [&str; 14] = [
+    "microsoft.com",
+    "windowsupdate.com",
+    "windows.com",
+    "update.microsoft.com",
+    "live.com",
+    "office.com",
+    "msftconnecttest.com",
+    "google.com",
+    "apple.com",
+    "paypal.com",
+    "amazon.com",
+    "facebook.com",
+    "github.com",
+    "cloudflare.com",
+];
+
+/// Prefix added to hosts lines wole comments out
+const DISABLED_PREFIX: &str = "# disabled by wole: ";
+
+/// A network setting that looks like a leftover
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NetIssue {
+    /// A hosts file line (1-based `line`)
+    HostsEntry {
+        line: usize,
+        text: String,
+        reason: String,
+    },
+    /// The system proxy or proxy script
+    Proxy {
+        server: Option<String>,
+        auto_config: Option<String>,
+        reason: String,
+    },
+    /// DNS servers set by hand on an adapter
+    DnsOverride {
+        interface: String,
+        servers: Vec<String>,
+        reason: String,
+    },
+}
+
+impl NetIssue {
+    pub fn label(&self) -> &'static str {
+        match self {
+            NetIssue::HostsEntry { .. } => "Hosts file",
+            NetIssue::Proxy { .. } => "Proxy",
+            NetIssue::DnsOverride { .. } => "DNS",
+        }
+    }
+
+    /// One line saying what the setting is
+    pub fn summary(&self) -> String {
+        match self {
+            NetIssue::HostsEntry { line, text, .. } => format!("line {}: {}", line, text),
+            NetIssue::Proxy {
+                server,
+                auto_config,
+                ..
+            } => match (server, auto_config) {
+                (Some(server), Some(script)) => format!("{} (script {})", server, script),
+                (Some(server), None) => server.clone(),
+                (None, Some(script)) => format!("script {}", script),
+                (None, None) => "on".to_string(),
+            },
+            NetIssue::DnsOverride {
+                interface, servers, ..
+            } => format!("{}: {}", interface, servers.join(", ")),
+        }
+    }
+
+    pub fn reason(&self) -> &str {
+        match self {
+            NetIssue::HostsEntry { reason, .. }
+            | NetIssue::Proxy { reason, .. }
+            | NetIssue::DnsOverride { reason, .. } => reason,
+        }
+    }
+
+    /// What reverting does
+    pub fn revert_action(&self) -> &'static str {
+        match self {
+            NetIssue::HostsEntry { .. } => "comment out the line",
+            NetIssue::Proxy { .. } => "turn the proxy off",
+            NetIssue::DnsOverride { .. } => "get DNS servers automatically",
+        }
+    }
+}
+
+/// Whether `host` is one of the sensitive domains or a subdomain of one
+fn is_sensitive(host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    SENSITIVE_DOMAINS
+        .iter()
+        .any(|domain| host == *domain || host.ends_with(&format!(".{}", domain)))
+}
+
+/// Loopback and 0.0.0.0: the address block lists use
+fn is_null_route(ip: &IpAddr) -> bool {
+    ip.is_loopback() || ip.is_unspecified()
+}
+
+#[cfg(any(windows, test))]
+fn is_private(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => v4.is_private() || v4.is_link_local(),
+        // Unique local (fc00::/7) and link-local (fe80::/10)
+        IpAddr::V6(v6) => {
+            (v6.segments()[0] & 0xfe00) == 0xfc00 || (v6.segments()[0] & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+/// Suspicious entries in a hosts file
+///
+/// Blocking ordinary domains (ad block lists) is left alone; blocking or
+/// redirecting a sensitive domain, and any name pointed at a real address,
+/// is reported.
+pub fn parse_hosts(contents: &str) -> Vec<NetIssue> {
+    let mut issues = Vec::new();
+    for (i, raw) in contents.lines().enumerate() {
+        let text = raw.split('#').next().unwrap_or("").trim();
+        let mut fields = text.split_whitespace();
+        let Some(ip) = fields.next().and_then(|ip| ip.parse::<IpAddr>().ok()) else {
+            continue;
+        };
+        let hosts: Vec<&str> = fields
+            .filter(|host| !host.eq_ignore_ascii_case("localhost"))
+            .collect();
+        let Some(first) = hosts.first() else {
+            continue;
+        };
+        let sensitive = hosts.iter().find(|host| is_sensitive(host));
+        let reason = match (is_null_route(&ip), sensitive) {
+            (true, Some(host)) => {
+                format!("blocks {} - updates, activation or sign-in can fail", host)
+            }
+            (true, None) => continue,
+            (false, Some(host)) => format!("redirects {} to {} - possible hijack", host, ip),
+            (false, None) if first.to_ascii_lowercase().ends_with(".docker.internal") => {
+                "added by Docker Desktop - a leftover if Docker is gone".to_string()
+            }
+            (false, None) => format!("pins {} to {} - often left by dev tools or VPNs", first, ip),
+        };
+        issues.push(NetIssue::HostsEntry {
+            line: i + 1,
+            text: raw.trim().to_string(),
+            reason,
+        });
+    }
+    issues
+}
+
+/// `contents` with line `line` (1-based) commented out, if it still reads `text`
+pub fn disable_hosts_line(contents: &str, line: usize, text: &str) -> Option<String> {
+    let mut found = false;
+    let mut out = String::with_capacity(contents.len() + DISABLED_PREFIX.len());
+    for (i, raw) in contents.split_inclusive('\n').enumerate() {
+        if i + 1 == line && raw.trim() == text {
+            found = true;
+            out.push_str(DISABLED_PREFIX);
+            out.push_str(raw.trim_start());
+        } else {
+            out.push_str(raw);
+        }
+    }
+    found.then_some(out)
+}
+
+/// The proxy as an issue, if one is set
+///
+/// `listening` tells whether a local proxy still accepts connections.
+#[cfg(any(windows, test))]
+fn proxy_issue(
+    enabled: bool,
+    server: Option<String>,
+    auto_config: Option<String>,
+    listening: impl Fn(&str) -> bool,
+) -> Option<NetIssue> {
+    let server = server.filter(|server| enabled && !server.trim().is_empty());
+    let auto_config = auto_config.filter(|script| !script.trim().is_empty());
+    let reason = match (&server, &auto_config) {
+        (None, None) => return None,
+        (Some(server), _) if is_local_proxy(server) => {
+            if listening(server) {
+                "local proxy - a debugging tool or VPN client is running".to_string()
+            } else {
+                "local proxy with nothing listening - pages won't load".to_string()
+            }
+        }
+        (Some(_), _) => "all web traffic goes through this proxy".to_string(),
+        (None, Some(_)) => "a proxy script decides where web traffic goes".to_string(),
+    };
+    Some(NetIssue::Proxy {
+        server,
+        auto_config,
+        reason,
+    })
+}
+
+/// `127.0.0.1:8888`, `localhost:8080` or `http=127.0.0.1:8888;https=...`
+#[cfg(any(windows, test))]
+fn is_local_proxy(server: &str) -> bool {
+    server.split(';').all(|part| {
+        let address = part.rsplit('=').next().unwrap_or(part).trim();
+        let host = address.rsplit_once(':').map_or(address, |(host, _)| host);
+        host.eq_ignore_ascii_case("localhost")
+            || host
+                .trim_matches(['[', ']'])
+                .parse::<IpAddr>()
+                .is_ok_and(|ip| ip.is_loopback())
+    })
+}
+
+/// The hand-set DNS servers of an adapter as an issue
+#[cfg(any(windows, test))]
+fn dns_issue(interface: &str, servers: Vec<String>) -> Option<NetIssue> {
+    let first = servers.first()?.parse::<IpAddr>().ok();
+    let reason = match first {
+        Some(ip) if ip.is_loopback() => {
+            "local DNS resolver - a leftover if the app that set it is gone"
+        }
+        Some(ip) if is_private(&ip) => "private DNS server - often left by a VPN",
+        _ => "set by hand instead of coming from the network",
+    };
+    Some(NetIssue::DnsOverride {
+        interface: interface.to_string(),
+        servers,
+        reason: reason.to_string(),
+    })
+}
+
+/// Backups of reverted settings: `%LOCALAPPDATA%\wole\backups`
+pub fn backup_dir() -> Result<PathBuf> {
+    let dir = crate::history::get_history_dir()?
+        .parent()
+        .map(|wole| wole.join("backups"))
+        .context("No wole data folder")?;
+    std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    Ok(dir)
+}
+
+/// Write `contents` to a new timestamped backup file
+#[cfg(windows)]
+fn write_backup(name: &str, extension: &str, contents: &[u8]) -> Result<PathBuf> {
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let path = backup_dir()?.join(format!("{}-{}.{}", name, stamp, extension));
+    std::fs::write(&path, contents)
+        .with_context(|| format!("Failed to write backup {}", path.display()))?;
+    Ok(path)
+}
+
+/// Everything that looks like a leftover
+///
+/// Always empty on other platforms.
+pub fn check() -> Vec<NetIssue> {
+    #[cfg(windows)]
+    {
+        let mut issues = std::fs::read_to_string(hosts_path())
+            .map(|contents| parse_hosts(&contents))
+            .unwrap_or_default();
+        issues.extend(windows::proxy());
+        issues.extend(windows::dns_overrides());
+        issues
+    }
+
+    #[cfg(not(windows))]
+    {
+        Vec::new()
+    }
+}
+
+/// Put a setting back, returning the backup of its previous state
+pub fn revert(issue: &NetIssue) -> Result<PathBuf> {
+    if crate::audit::is_enabled() {
+        crate::audit::note(&format!("{} ({})", issue.revert_action(), issue.summary()));
+        anyhow::bail!("Audit mode is on - nothing was changed");
+    }
+
+    #[cfg(windows)]
+    {
+        match issue {
+            NetIssue::HostsEntry { line, text, .. } => {
+                let path = hosts_path();
+                let contents = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                let updated = disable_hosts_line(&contents, *line, text)
+                    .context("The hosts file changed since it was checked - check again")?;
+                let backup = write_backup("hosts", "txt", contents.as_bytes())?;
+                std::fs::write(&path, updated).with_context(|| {
+                    format!(
+                        "Failed to write {} (run wole as Administrator)",
+                        path.display()
+                    )
+                })?;
+                Ok(backup)
+            }
+            NetIssue::Proxy { .. } => windows::disable_proxy(),
+            NetIssue::DnsOverride {
+                interface, servers, ..
+            } => windows::reset_dns(interface, servers),
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        Err(anyhow::anyhow!(
+            "Network settings can only be changed on Windows"
+        ))
+    }
+}
+
+#[cfg(windows)]
+fn hosts_path() -> PathBuf {
+    std::env::var_os("SystemRoot")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("C:\\Windows"))
+        .join("System32")
+        .join("drivers")
+        .join("etc")
+        .join("hosts")
+}
+
+#[cfg(windows)]
+mod windows {
+    use super::*;
+    use std::time::Duration;
+    use winreg::enums::*;
+    use winreg::RegKey;
+
+    const INTERNET_SETTINGS: &str =
+        "Software\\Microsoft\\Windows\\CurrentVersion\\Internet Settings";
+    const INTERFACES: &str = "SYSTEM\\CurrentControlSet\\Services\\Tcpip\\Parameters\\Interfaces";
+    /// Network adapter class; holds each adapter's display name
+    const NETWORK_CLASS: &str =
+        "SYSTEM\\CurrentControlSet\\Control\\Network\\{4D36E972-E325-11CE-BFC1-08002BE10318}";
+
+    fn listening(server: &str) -> bool {
+        use std::net::{TcpStream, ToSocketAddrs};
+        server.split(';').any(|part| {
+            let address = part.rsplit('=').next().unwrap_or(part).trim();
+            address.to_socket_addrs().is_ok_and(|mut addrs| {
+                addrs.any(|addr| {
+                    TcpStream::connect_timeout(&addr, Duration::from_millis(500)).is_ok()
+                })
+            })
+        })
+    }
+
+    pub(super) fn proxy() -> Option<NetIssue> {
+        let key = RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey(INTERNET_SETTINGS)
+            .ok()?;
+        let enabled: u32 = key.get_value("ProxyEnable").unwrap_or(0);
+        proxy_issue(
+            enabled != 0,
+            key.get_value("ProxyServer").ok(),
+            key.get_value("AutoConfigURL").ok(),
+            listening,
+        )
+    }
+
+    pub(super) fn disable_proxy() -> Result<PathBuf> {
+        let key = RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey_with_flags(INTERNET_SETTINGS, KEY_READ | KEY_WRITE)
+            .context("Failed to open Internet Settings")?;
+        let previous = serde_json::json!({
+            "ProxyEnable": key.get_value::<u32, _>("ProxyEnable").ok(),
+            "ProxyServer": key.get_value::<String, _>("ProxyServer").ok(),
+            "AutoConfigURL": key.get_value::<String, _>("AutoConfigURL").ok(),
+        });
+        let backup = write_backup("proxy", "json", &serde_json::to_vec_pretty(&previous)?)?;
+        key.set_value("ProxyEnable", &0u32)
+            .context("Failed to turn the proxy off")?;
+        if key.get_value::<String, _>("AutoConfigURL").is_ok() {
+            key.delete_value("AutoConfigURL")
+                .context("Failed to remove the proxy script")?;
+        }
+        Ok(backup)
+    }
+
+    /// Adapters that get their address from DHCP but have DNS servers set by hand
+    pub(super) fn dns_overrides() -> Vec<NetIssue> {
+        let machine = RegKey::predef(HKEY_LOCAL_MACHINE);
+        let Ok(interfaces) = machine.open_subkey(INTERFACES) else {
+            return Vec::new();
+        };
+        let mut issues = Vec::new();
+        for guid in interfaces.enum_keys().flatten() {
+            let Ok(interface) = interfaces.open_subkey(&guid) else {
+                continue;
+            };
+            let dhcp: u32 = interface.get_value("EnableDHCP").unwrap_or(0);
+            let servers: String = interface.get_value("NameServer").unwrap_or_default();
+            let servers: Vec<String> = servers
+                .split([',', ' '])
+                .filter(|server| !server.is_empty())
+                .map(str::to_string)
+                .collect();
+            if dhcp == 0 || servers.is_empty() {
+                continue;
+            }
+            // Adapters without a display name aren't shown in Network Connections
+            let Ok(name) = machine
+                .open_subkey(format!("{}\\{}\\Connection", NETWORK_CLASS, guid))
+                .and_then(|key| key.get_value::<String, _>("Name"))
+            else {
+                continue;
+            };
+            issues.extend(dns_issue(&name, servers));
+        }
+        issues
+    }
+
+    pub(super) fn reset_dns(interface: &str, servers: &[String]) -> Result<PathBuf> {
+        let previous = serde_json::json!({ "interface": interface, "servers": servers });
+        let backup = write_backup("dns", "json", &serde_json::to_vec_pretty(&previous)?)?;
+        let output = std::process::Command::new("netsh")
+            .args(["interface", "ip", "set", "dns"])
+            .arg(format!("name={}", interface))
+            .arg("source=dhcp")
+            .output()
+            .context("Failed to run netsh")?;
+        if !output.status.success() {
+            // netsh reports errors on stdout
+            anyhow::bail!(
+                "Failed to reset DNS on {}: {} (run wole as Administrator)",
+                interface,
+                String::from_utf8_lossy(&output.stdout).trim()
+            );
+        }
+        Ok(backup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOSTS: &str = "# Copyright (c) Microsoft Corp.\r\n\
+        127.0.0.1 localhost\r\n\
+        ::1 localhost\r\n\
+        0.0.0.0 ads.example.net\r\n\
+        0.0.0.0 settings-win.data.microsoft.com\r\n\
+        10.1.2.3 www.paypal.com # bank\r\n\
+        192.168.65.2 host.docker.internal\r\n\
+        172.16.0.9 intranet.corp\r\n";
+
+    #[test]
+    fn test_parse_hosts() {
+        let issues = parse_hosts(HOSTS);
+        let lines: Vec<(usize, &str)> = issues
+            .iter()
+            .map(|issue| match issue {
+                NetIssue::HostsEntry { line, reason, .. } => (*line, reason.as_str()),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                (
+                    5,
+                    "blocks settings-win.data.microsoft.com - updates, activation or sign-in can fail"
+                ),
+                (6, "redirects www.paypal.com to 10.1.2.3 - possible hijack"),
+                (7, "added by Docker Desktop - a leftover if Docker is gone"),
+                (8, "pins intranet.corp to 172.16.0.9 - often left by dev tools or VPNs"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_disable_hosts_line() {
+        let updated = disable_hosts_line(HOSTS, 6, "10.1.2.3 www.paypal.com # bank").unwrap();
+        assert!(updated.contains("\r\n# disabled by wole: 10.1.2.3 www.paypal.com # bank\r\n"));
+        assert!(parse_hosts(&updated)
+            .iter()
+            .all(|issue| !matches!(issue, NetIssue::HostsEntry { line: 6, .. })));
+        // The file changed since it was checked
+        assert_eq!(
+            disable_hosts_line(HOSTS, 5, "10.1.2.3 www.paypal.com"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_proxy_issue() {
+        assert_eq!(
+            proxy_issue(false, Some("127.0.0.1:8888".into()), None, |_| true),
+            None
+        );
+        let issue = proxy_issue(true, Some("127.0.0.1:8888".into()), None, |_| false).unwrap();
+        assert_eq!(
+            issue.reason(),
+            "local proxy with nothing listening - pages won't load"
+        );
+        let issue = proxy_issue(
+            true,
+            Some("http=proxy.corp:80;https=proxy.corp:80".into()),
+            None,
+            |_| false,
+        )
+        .unwrap();
+        assert_eq!(issue.reason(), "all web traffic goes through this proxy");
+        let issue =
+            proxy_issue(false, None, Some("http://wpad/proxy.pac".into()), |_| false).unwrap();
+        assert_eq!(issue.summary(), "script http://wpad/proxy.pac");
+    }
+
+    #[test]
+    fn test_dns_issue() {
+        let issue = dns_issue("Wi-Fi", vec!["10.8.0.1".into(), "1.1.1.1".into()]).unwrap();
+        assert_eq!(issue.summary(), "Wi-Fi: 10.8.0.1, 1.1.1.1");
+        assert_eq!(issue.reason(), "private DNS server - often left by a VPN");
+        assert_eq!(dns_issue("Wi-Fi", Vec::new()), None);
+    }
+}
@@ -0,0 +1,267 @@
+//! Network shares (mapped drives and UNC paths)
+//!
+//! Shares behave differently from local disks:
+//! - there is no Recycle Bin, so "recycled" items are moved to a quarantine
+//!   folder at the root of the share instead ([`quarantine`]) and can be moved
+//!   back by `wole restore`;
+//! - the locked-file probe and batch retries are skipped - a sharing violation
+//!   means another client has the file open, and probing costs a round trip;
+//! - every metadata call is a network round trip, so scans measure the share's
+//!   latency ([`probe_latency`]) to set expectations in progress output.
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Folder at the root of each share that holds quarantined items
+pub const QUARANTINE_DIR: &str = ".wole-quarantine";
+
+/// Latency above which a share is reported as slow
+pub const SLOW_LATENCY: Duration = Duration::from_millis(20);
+
+/// Whether `path` lives on a network share (UNC path or mapped network drive)
+pub fn is_network_path(path: &Path) -> bool {
+    let s = path.to_string_lossy();
+    if starts_with_ignore_case(&s, r"\\?\UNC\") {
+        return true;
+    }
+    if let Some(rest) = s.strip_prefix(r"\\?\").or_else(|| s.strip_prefix(r"\\.\")) {
+        return is_remote_drive(rest);
+    }
+    if s.starts_with(r"\\") || s.starts_with("//") {
+        return true;
+    }
+    is_remote_drive(&s)
+}
+
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.len() >= prefix.len()
+        && s.is_char_boundary(prefix.len())
+        && s[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+/// Drive letter of a `X:...` path
+fn drive_letter(path: &str) -> Option<char> {
+    let mut chars = path.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), Some(':')) if letter.is_ascii_alphabetic() => {
+            Some(letter.to_ascii_uppercase())
+        }
+        _ => None,
+    }
+}
+
+/// Whether a drive-letter path is on a mapped network drive (cached per letter)
+#[cfg(windows)]
+fn is_remote_drive(path: &str) -> bool {
+    use std::sync::Mutex;
+
+    static DRIVE_TYPES: Mutex<[Option<bool>; 26]> = Mutex::new([None; 26]);
+
+    let Some(letter) = drive_letter(path) else {
+        return false;
+    };
+    let index = (letter as u8 - b'A') as usize;
+    if let Some(remote) = DRIVE_TYPES.lock().ok().and_then(|types| types[index]) {
+        return remote;
+    }
+
+    let root: Vec<u16> = format!("{}:\\", letter)
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .collect();
+    const DRIVE_REMOTE: u32 = 4;
+    let remote = unsafe {
+        extern "system" {
+            fn GetDriveTypeW(lpRootPathName: *const u16) -> u32;
+        }
+        GetDriveTypeW(root.as_ptr()) == DRIVE_REMOTE
+    };
+
+    if let Ok(mut types) = DRIVE_TYPES.lock() {
+        types[index] = Some(remote);
+    }
+    remote
+}
+
+#[cfg(not(windows))]
+fn is_remote_drive(path: &str) -> bool {
+    let _ = drive_letter(path);
+    false
+}
+
+/// Root of the share or drive holding `path`: `\\server\share\` or `X:\`
+pub fn share_root(path: &Path) -> Option<PathBuf> {
+    let s = path.to_string_lossy();
+    let (prefix, rest) = if starts_with_ignore_case(&s, r"\\?\UNC\") {
+        (&s[..8], &s[8..])
+    } else if let Some(rest) = s.strip_prefix(r"\\?\").or_else(|| s.strip_prefix(r"\\.\")) {
+        let letter = drive_letter(rest)?;
+        return Some(PathBuf::from(format!("{}{}:\\", &s[..4], letter)));
+    } else if let Some(rest) = s.strip_prefix(r"\\").or_else(|| s.strip_prefix("//")) {
+        (r"\\", rest)
+    } else {
+        let letter = drive_letter(&s)?;
+        return Some(PathBuf::from(format!("{}:\\", letter)));
+    };
+
+    let mut parts = rest.split(['\\', '/']);
+    let server = parts.next().filter(|p| !p.is_empty())?;
+    let share = parts.next().filter(|p| !p.is_empty())?;
+    Some(PathBuf::from(format!("{}{}\\{}\\", prefix, server, share)))
+}
+
+/// `path` relative to its share root
+fn relative_to_root(path: &Path, root: &Path) -> Option<PathBuf> {
+    let s = path.to_string_lossy();
+    let root = root.to_string_lossy();
+    let rest = s.get(root.len()..)?;
+    let rest = rest.trim_start_matches(['\\', '/']);
+    if rest.is_empty() {
+        return None;
+    }
+    Some(PathBuf::from(rest))
+}
+
+/// Median time of a few metadata round trips to `path` (None for local paths)
+pub fn probe_latency(path: &Path) -> Option<Duration> {
+    if !is_network_path(path) {
+        return None;
+    }
+
+    let mut samples: Vec<Duration> = (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            let _ = fs::metadata(path);
+            start.elapsed()
+        })
+        .collect();
+    samples.sort();
+    Some(samples[1])
+}
+
+/// Short latency label for progress output, e.g. "share latency ~35 ms"
+pub fn describe_latency(latency: Duration) -> String {
+    let ms = latency.as_millis().max(1);
+    if latency >= SLOW_LATENCY {
+        format!("slow share, ~{} ms per request", ms)
+    } else {
+        format!("share latency ~{} ms", ms)
+    }
+}
+
+/// Notice shown before scanning a network share, or None for local paths
+///
+/// Probes the share's latency, so call it once per scan.
+pub fn scan_notice(path: &Path) -> Option<(String, Duration)> {
+    let latency = probe_latency(path)?;
+    let notice = format!(
+        "Network share ({}): deleted items go to {} instead of the Recycle Bin",
+        describe_latency(latency),
+        QUARANTINE_DIR
+    );
+    Some((notice, latency))
+}
+
+/// Move `path` into the share's quarantine folder (the Recycle Bin stand-in)
+///
+/// Items keep their path relative to the share root under a per-run timestamp
+/// folder, e.g. `\\nas\data\.wole-quarantine\20250101_120000\proj\node_modules`.
+/// Returns the new location.
+pub fn quarantine(path: &Path) -> Result<PathBuf> {
+    let root = share_root(path)
+        .with_context(|| format!("Cannot determine share root of {}", path.display()))?;
+    let relative = relative_to_root(path, &root)
+        .with_context(|| format!("Refusing to quarantine share root {}", path.display()))?;
+    if relative.starts_with(QUARANTINE_DIR) {
+        anyhow::bail!("{} is already quarantined", path.display());
+    }
+
+    let stamp = Local::now().format("%Y%m%d_%H%M%S").to_string();
+    let dest = root.join(QUARANTINE_DIR).join(stamp).join(&relative);
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create quarantine folder {}", parent.display()))?;
+    }
+    fs::rename(path, &dest).map_err(|e| {
+        anyhow::Error::new(e).context(format!("Failed to quarantine {}", path.display()))
+    })?;
+    Ok(dest)
+}
+
+/// Most recent quarantined copy of `original`, if any
+pub fn find_quarantined(original: &Path) -> Option<PathBuf> {
+    let root = share_root(original)?;
+    let relative = relative_to_root(original, &root)?;
+    let mut runs: Vec<PathBuf> = fs::read_dir(root.join(QUARANTINE_DIR))
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .collect();
+    // Timestamp folder names sort chronologically
+    runs.sort();
+    runs.into_iter()
+        .rev()
+        .map(|run| run.join(&relative))
+        .find(|candidate| candidate.exists())
+}
+
+/// Move the most recent quarantined copy of `original` back into place
+///
+/// Returns `Ok(false)` if nothing was quarantined for that path.
+pub fn restore_quarantined(original: &Path) -> Result<bool> {
+    let Some(quarantined) = find_quarantined(original) else {
+        return Ok(false);
+    };
+    if original.exists() {
+        anyhow::bail!(
+            "Cannot restore {}: the path exists again",
+            original.display()
+        );
+    }
+    if let Some(parent) = original.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(&quarantined, original)
+        .with_context(|| format!("Failed to restore {}", original.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unc_paths_are_network() {
+        assert!(is_network_path(Path::new(r"\\nas\data\projects")));
+        assert!(is_network_path(Path::new(r"\\?\UNC\nas\data\projects")));
+        assert!(!is_network_path(Path::new(r"\\?\C:\Users\me")));
+        assert!(!is_network_path(Path::new("relative/path")));
+    }
+
+    #[test]
+    fn test_share_root() {
+        assert_eq!(
+            share_root(Path::new(r"\\nas\data\projects\app")),
+            Some(PathBuf::from(r"\\nas\data\"))
+        );
+        assert_eq!(
+            share_root(Path::new(r"\\?\UNC\nas\data\projects")),
+            Some(PathBuf::from(r"\\?\UNC\nas\data\"))
+        );
+        assert_eq!(
+            share_root(Path::new(r"z:\projects")),
+            Some(PathBuf::from(r"Z:\"))
+        );
+        assert_eq!(share_root(Path::new(r"\\nas")), None);
+        assert_eq!(
+            relative_to_root(
+                Path::new(r"\\nas\data\projects\app"),
+                Path::new(r"\\nas\data\")
+            ),
+            Some(PathBuf::from(r"projects\app"))
+        );
+    }
+}
@@ -5,25 +5,40 @@
 //! - Thumbnail cache clearing
 //! - Icon cache rebuild
 //! - Browser database optimization (VACUUM)
-//! - Font cache service restart
+//! - Font cache service restart and font cache reset
 //! - Standby memory clearing
-//! - Network stack reset
+//! - Network stack reset, step by step with a connectivity check after each
 //! - Bluetooth service restart
-//! - Windows Search service restart
+//! - Windows Search service restart and search index rebuild
+//! - Print spooler clearing (stuck print jobs)
 //! - Explorer restart
+//! - Folder compression (compact.exe) as an alternative to deletion
+//! - Unblocking downloaded files (removing Zone.Identifier streams)
+//! - Power plan switching (with restore)
+//! - Automatic standby-list trimming while the Status screen is open
+//! - Before/after measurements shown with the results
 
 mod admin_check;
+mod auto_trim;
+mod catalog;
 mod operations;
 mod printing;
 mod result;
 mod run;
+mod snapshot;
 
 pub use admin_check::is_admin;
+pub use auto_trim::{StandbyTrimMonitor, TrimOutcome};
+pub use catalog::{Estimate, OperationInfo, OPERATIONS};
+pub(crate) use operations::restart_explorer::{start_explorer, stop_explorer};
 pub use operations::{
-    clear_standby_memory, clear_thumbnail_cache, flush_dns_cache, rebuild_icon_cache,
-    reset_network_stack, restart_bluetooth_service, restart_explorer, restart_font_cache_service,
-    restart_windows_search, vacuum_browser_databases,
+    check_connectivity, clear_print_spooler, clear_standby_memory, clear_thumbnail_cache,
+    compress_folders, flush_dns_cache, rebuild_icon_cache, rebuild_search_index, reset_font_cache,
+    reset_network_stack, reset_network_stack_with_progress, restart_bluetooth_service,
+    restart_explorer, restart_font_cache_service, restart_windows_search, set_power_plan,
+    unblock_files, vacuum_browser_databases, Connectivity,
 };
 pub use printing::print_summary;
 pub use result::OptimizeResult;
 pub use run::run_optimizations;
+pub use snapshot::{Metric, Snapshot};
@@ -0,0 +1,156 @@
+//! Standby-list auto-trim policy.
+//!
+//! While the Status screen is open, every refresh reports available memory to
+//! a [`StandbyTrimMonitor`]. When it drops under `optimize.trim_threshold_mb`
+//! (and `optimize.auto_trim_standby` is on), the standby list is trimmed with
+//! [`clear_standby_memory`] in the background, at most once per
+//! `optimize.trim_cooldown_secs`. Every trim is logged to `memory.log` with the
+//! amount of memory it reclaimed.
+
+use super::admin_check::is_admin;
+use super::operations::clear_standby_memory;
+use crate::config::OptimizeSettings;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+use sysinfo::System;
+
+/// Result of one automatic trim
+#[derive(Debug, Clone)]
+pub struct TrimOutcome {
+    pub available_before: u64,
+    pub available_after: u64,
+    pub success: bool,
+    pub message: String,
+}
+
+impl TrimOutcome {
+    /// Memory that became available (0 if the trim made no difference)
+    pub fn reclaimed(&self) -> u64 {
+        self.available_after.saturating_sub(self.available_before)
+    }
+}
+
+/// Decide whether to trim now
+fn should_trim(
+    settings: &OptimizeSettings,
+    available_bytes: u64,
+    since_last_trim: Option<Duration>,
+) -> bool {
+    let threshold = settings.trim_threshold_mb * 1024 * 1024;
+    let cooled_down = since_last_trim
+        .is_none_or(|elapsed| elapsed >= Duration::from_secs(settings.trim_cooldown_secs));
+    settings.auto_trim_standby && available_bytes < threshold && cooled_down
+}
+
+fn available_memory() -> u64 {
+    let mut system = System::new();
+    system.refresh_memory();
+    system.available_memory()
+}
+
+/// Watches available memory and trims the standby list when it runs low
+#[derive(Debug, Default)]
+pub struct StandbyTrimMonitor {
+    last_trim: Option<Instant>,
+    pending: Option<Receiver<TrimOutcome>>,
+    last_outcome: Option<TrimOutcome>,
+    warned_not_admin: bool,
+}
+
+impl StandbyTrimMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Most recent automatic trim, if any
+    pub fn last_outcome(&self) -> Option<&TrimOutcome> {
+        self.last_outcome.as_ref()
+    }
+
+    /// Feed the latest available memory reading; trims in the background when needed
+    pub fn observe(&mut self, settings: &OptimizeSettings, available_bytes: u64) {
+        if let Some(ref receiver) = self.pending {
+            match receiver.try_recv() {
+                Ok(outcome) => {
+                    crate::debug_log::memory_log(&format!(
+                        "auto-trim: {} (available {} -> {}, reclaimed {})",
+                        outcome.message,
+                        bytesize::to_string(outcome.available_before, false),
+                        bytesize::to_string(outcome.available_after, false),
+                        bytesize::to_string(outcome.reclaimed(), false)
+                    ));
+                    self.last_outcome = Some(outcome);
+                    self.pending = None;
+                }
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => self.pending = None,
+            }
+        }
+
+        if !should_trim(
+            settings,
+            available_bytes,
+            self.last_trim.map(|t| t.elapsed()),
+        ) {
+            return;
+        }
+
+        if crate::audit::is_enabled() {
+            crate::audit::note("trim the standby list");
+            self.last_trim = Some(Instant::now());
+            return;
+        }
+
+        if !is_admin() {
+            if !self.warned_not_admin {
+                crate::debug_log::memory_log(
+                    "auto-trim: available memory is low but wole is not running as Administrator",
+                );
+                self.warned_not_admin = true;
+            }
+            return;
+        }
+
+        self.last_trim = Some(Instant::now());
+        let (tx, rx) = mpsc::channel();
+        self.pending = Some(rx);
+        std::thread::spawn(move || {
+            let available_before = available_memory();
+            let result = clear_standby_memory(false);
+            let available_after = available_memory();
+            let _ = tx.send(TrimOutcome {
+                available_before,
+                available_after,
+                success: result.success,
+                message: result.message,
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MB: u64 = 1024 * 1024;
+
+    #[test]
+    fn test_should_trim_respects_switch_threshold_and_cooldown() {
+        let mut settings = OptimizeSettings::default();
+        assert!(!should_trim(&settings, 100 * MB, None));
+
+        settings.auto_trim_standby = true;
+        assert!(should_trim(&settings, 100 * MB, None));
+        assert!(!should_trim(&settings, 2048 * MB, None));
+        assert!(!should_trim(
+            &settings,
+            100 * MB,
+            Some(Duration::from_secs(60))
+        ));
+        assert!(should_trim(
+            &settings,
+            100 * MB,
+            Some(Duration::from_secs(300))
+        ));
+    }
+}
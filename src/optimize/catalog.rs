@@ -0,0 +1,166 @@
+//! What each optimize operation needs and what it does to the user.
+//!
+//! The Optimize screen lists these in order and labels every operation with
+//! whether it needs administrator rights, what it interrupts while it runs
+//! and roughly how long it takes. Operations that need rights wole doesn't
+//! have can't be selected, instead of failing once they run.
+
+/// Rough time an operation takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Estimate {
+    Seconds,
+    Minute,
+    /// Returns quickly, but Windows keeps working for hours in the background
+    Hours,
+}
+
+impl Estimate {
+    pub fn label(self) -> &'static str {
+        match self {
+            Estimate::Seconds => "seconds",
+            Estimate::Minute => "~1 min",
+            Estimate::Hours => "hours (background)",
+        }
+    }
+}
+
+/// An operation on the Optimize screen
+#[derive(Debug, Clone, Copy)]
+pub struct OperationInfo {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub requires_admin: bool,
+    /// What the user notices while it runs, if anything
+    pub interrupts: Option<&'static str>,
+    pub estimate: Estimate,
+}
+
+impl OperationInfo {
+    /// Whether it can run with the current privileges
+    pub fn available(&self, is_admin: bool) -> bool {
+        !self.requires_admin || is_admin
+    }
+}
+
+/// Optimize screen operations, in the order they are listed
+pub const OPERATIONS: [OperationInfo; 13] = [
+    OperationInfo {
+        name: "DNS Cache",
+        description: "Flush DNS cache (ipconfig /flushdns)",
+        requires_admin: false,
+        interrupts: None,
+        estimate: Estimate::Seconds,
+    },
+    OperationInfo {
+        name: "Thumbnails",
+        description: "Clear thumbnail cache",
+        requires_admin: false,
+        interrupts: None,
+        estimate: Estimate::Seconds,
+    },
+    OperationInfo {
+        name: "Icons",
+        description: "Rebuild icon cache and restart Explorer",
+        requires_admin: false,
+        interrupts: Some("restarts Explorer"),
+        estimate: Estimate::Seconds,
+    },
+    OperationInfo {
+        name: "Databases",
+        description: "Optimize browser databases (VACUUM)",
+        requires_admin: false,
+        interrupts: Some("close browsers first"),
+        estimate: Estimate::Minute,
+    },
+    OperationInfo {
+        name: "Fonts",
+        description: "Restart Font Cache Service - fixes font display issues",
+        requires_admin: true,
+        interrupts: None,
+        estimate: Estimate::Seconds,
+    },
+    OperationInfo {
+        name: "Memory",
+        description: "Clear standby memory - frees up RAM",
+        requires_admin: true,
+        interrupts: None,
+        estimate: Estimate::Seconds,
+    },
+    OperationInfo {
+        name: "Network",
+        description: "Reset network step by step until it connects - fixes connection issues",
+        requires_admin: true,
+        interrupts: Some("drops connections"),
+        estimate: Estimate::Seconds,
+    },
+    OperationInfo {
+        name: "Bluetooth",
+        description: "Restart Bluetooth service - fixes Bluetooth problems",
+        requires_admin: true,
+        interrupts: Some("disconnects devices"),
+        estimate: Estimate::Seconds,
+    },
+    OperationInfo {
+        name: "Search",
+        description: "Restart Windows Search - rebuilds search index",
+        requires_admin: true,
+        interrupts: Some("search unavailable briefly"),
+        estimate: Estimate::Seconds,
+    },
+    OperationInfo {
+        name: "Explorer",
+        description: "Restart Windows Explorer - refreshes desktop and file manager",
+        requires_admin: false,
+        interrupts: Some("restarts Explorer"),
+        estimate: Estimate::Seconds,
+    },
+    OperationInfo {
+        name: "Print Spooler",
+        description: "Clear stuck print jobs and restart the spooler",
+        requires_admin: true,
+        interrupts: Some("cancels print jobs"),
+        estimate: Estimate::Seconds,
+    },
+    OperationInfo {
+        name: "Search Index",
+        description: "Purge and rebuild the search index - slow re-indexing",
+        requires_admin: true,
+        interrupts: Some("search incomplete until re-indexed"),
+        estimate: Estimate::Hours,
+    },
+    OperationInfo {
+        name: "Font Cache",
+        description: "Delete font cache files and restart its services",
+        requires_admin: true,
+        interrupts: Some("restart finishes the reset"),
+        estimate: Estimate::Minute,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_available_depends_on_admin() {
+        let admin_only: Vec<&str> = OPERATIONS
+            .iter()
+            .filter(|op| !op.available(false))
+            .map(|op| op.name)
+            .collect();
+        assert_eq!(
+            admin_only,
+            [
+                "Fonts",
+                "Memory",
+                "Network",
+                "Bluetooth",
+                "Search",
+                "Print Spooler",
+                "Search Index",
+                "Font Cache"
+            ]
+        );
+        assert!(OPERATIONS.iter().all(|op| op.available(true)));
+    }
+}
@@ -0,0 +1,110 @@
+//! Clear print spooler operation.
+
+use super::super::admin_check::is_admin;
+use super::super::result::OptimizeResult;
+use super::service_control::{purge_files, with_services_stopped};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn spool_dir() -> PathBuf {
+    env::var("SystemRoot")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(r"C:\Windows"))
+        .join("System32")
+        .join("spool")
+        .join("PRINTERS")
+}
+
+/// Queued job files (`*.SPL` data and `*.SHD` shadow files)
+fn job_files() -> Vec<PathBuf> {
+    job_files_in(&spool_dir())
+}
+
+fn job_files_in(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| {
+                ext.eq_ignore_ascii_case("spl") || ext.eq_ignore_ascii_case("shd")
+            })
+        })
+        .collect()
+}
+
+/// Clear stuck print jobs: stop the Print Spooler, delete queued job files, start it again
+pub fn clear_print_spooler(dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
+    let action = "Clear Print Spooler";
+
+    if dry_run {
+        return OptimizeResult::skipped(
+            action,
+            &format!(
+                "Dry run mode - would stop Spooler and delete queued jobs in {}",
+                spool_dir().display()
+            ),
+            true,
+        );
+    }
+
+    if !is_admin() {
+        return OptimizeResult::failure(action, "Administrator privileges required", true);
+    }
+
+    let jobs = job_files();
+    if jobs.is_empty() {
+        return OptimizeResult::skipped(action, "No stuck print jobs", true);
+    }
+
+    match with_services_stopped(&["Spooler"], || purge_files(&job_files())) {
+        Ok(run) => {
+            let purged = run.output;
+            let message = format!(
+                "Removed {} job file(s) ({}), {} locked{}",
+                purged.files,
+                bytesize::to_string(purged.bytes, false),
+                purged.failed,
+                run.restart_note()
+            );
+            if purged.files > 0 && run.not_restarted.is_empty() {
+                OptimizeResult::success(action, &message, true)
+            } else {
+                OptimizeResult::failure(action, &message, true)
+            }
+        }
+        Err(e) => OptimizeResult::failure(action, &e, true),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_job_files_only_lists_spool_jobs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        for name in ["00012.SPL", "00012.shd", "printer.ini", "FP00001.tmp"] {
+            fs::write(temp_dir.path().join(name), b"job").unwrap();
+        }
+        let mut names: Vec<String> = job_files_in(temp_dir.path())
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["00012.SPL", "00012.shd"]);
+        assert!(job_files_in(&temp_dir.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_dry_run_leaves_spooler_alone() {
+        let result = clear_print_spooler(true);
+        assert!(result.success);
+        assert!(result.message.starts_with("Skipped: Dry run mode"));
+        assert!(result.requires_admin);
+    }
+}
@@ -8,6 +8,7 @@ use std::process::Command;
 /// This requires administrator privileges and the EmptyStandbyList.exe utility
 /// or direct Windows API calls
 pub fn clear_standby_memory(dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
     let action = "Clear Standby Memory";
 
     if dry_run {
@@ -7,6 +7,7 @@ use std::path::PathBuf;
 
 /// Clear thumbnail cache files from Windows Explorer
 pub fn clear_thumbnail_cache(dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
     let action = "Clear Thumbnail Cache";
 
     let local_app_data = match env::var("LOCALAPPDATA") {
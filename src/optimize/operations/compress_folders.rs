@@ -0,0 +1,61 @@
+//! Folder compression operation.
+
+use super::super::result::OptimizeResult;
+use crate::compress::{compress_paths, CompressionMode};
+use std::path::PathBuf;
+
+/// Compress folders in place with compact.exe instead of deleting them
+pub fn compress_folders(paths: &[PathBuf], mode: CompressionMode, dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
+    let action = "Compress Folders";
+
+    if paths.is_empty() {
+        return OptimizeResult::skipped(action, "No folders given", false);
+    }
+
+    let results = compress_paths(paths, mode, dry_run);
+
+    if dry_run {
+        let before: u64 = results.iter().map(|r| r.before_bytes).sum();
+        return OptimizeResult::skipped(
+            action,
+            &format!(
+                "Dry run mode - would compress {} folder(s) ({} on disk) using {}",
+                results.len(),
+                bytesize::to_string(before, false),
+                mode.as_str()
+            ),
+            false,
+        );
+    }
+
+    let failed: Vec<_> = results.iter().filter(|r| !r.success).collect();
+    let before: u64 = results.iter().map(|r| r.before_bytes).sum();
+    let after: u64 = results.iter().map(|r| r.after_bytes).sum();
+    let saved: u64 = results.iter().map(|r| r.saved_bytes()).sum();
+
+    if failed.len() == results.len() {
+        return OptimizeResult::failure(
+            action,
+            &format!(
+                "Failed to compress {}: {}",
+                failed[0].path.display(),
+                failed[0].message
+            ),
+            false,
+        );
+    }
+
+    let mut message = format!(
+        "Compressed {} folder(s): {} → {} (saved {})",
+        results.len() - failed.len(),
+        bytesize::to_string(before, false),
+        bytesize::to_string(after, false),
+        bytesize::to_string(saved, false)
+    );
+    if !failed.is_empty() {
+        message.push_str(&format!(", {} failed", failed.len()));
+    }
+
+    OptimizeResult::success(action, &message, false)
+}
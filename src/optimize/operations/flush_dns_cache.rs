@@ -5,6 +5,7 @@ use std::process::Command;
 
 /// Flush DNS cache using ipconfig /flushdns
 pub fn flush_dns_cache(dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
     let action = "Flush DNS Cache";
 
     if dry_run {
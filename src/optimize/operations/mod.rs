@@ -1,23 +1,38 @@
 //! Optimization operation features.
 
+pub mod clear_print_spooler;
 pub mod clear_standby_memory;
 pub mod clear_thumbnail_cache;
+pub mod compress_folders;
 pub mod flush_dns_cache;
 pub mod rebuild_icon_cache;
+pub mod rebuild_search_index;
+pub mod reset_font_cache;
 pub mod reset_network_stack;
 pub mod restart_bluetooth_service;
 pub mod restart_explorer;
 pub mod restart_font_cache_service;
 pub mod restart_windows_search;
+mod service_control;
+pub mod set_power_plan;
+pub mod unblock_files;
 pub mod vacuum_browser_databases;
 
+pub use clear_print_spooler::clear_print_spooler;
 pub use clear_standby_memory::clear_standby_memory;
 pub use clear_thumbnail_cache::clear_thumbnail_cache;
+pub use compress_folders::compress_folders;
 pub use flush_dns_cache::flush_dns_cache;
 pub use rebuild_icon_cache::rebuild_icon_cache;
-pub use reset_network_stack::reset_network_stack;
+pub use rebuild_search_index::rebuild_search_index;
+pub use reset_font_cache::reset_font_cache;
+pub use reset_network_stack::{
+    check_connectivity, reset_network_stack, reset_network_stack_with_progress, Connectivity,
+};
 pub use restart_bluetooth_service::restart_bluetooth_service;
 pub use restart_explorer::restart_explorer;
 pub use restart_font_cache_service::restart_font_cache_service;
 pub use restart_windows_search::restart_windows_search;
+pub use set_power_plan::set_power_plan;
+pub use unblock_files::unblock_files;
 pub use vacuum_browser_databases::vacuum_browser_databases;
@@ -1,13 +1,17 @@
 //! Rebuild icon cache operation.
 
 use super::super::result::OptimizeResult;
-use super::restart_explorer::do_restart_explorer;
+use super::restart_explorer::{start_explorer, stop_explorer};
 use std::env;
 use std::fs;
 use std::path::PathBuf;
 
 /// Rebuild icon cache by deleting IconCache.db and iconcache_*.db files
+///
+/// With `restart_explorer`, Explorer is stopped first (it keeps the caches
+/// open) and started again once the files are gone.
 pub fn rebuild_icon_cache(dry_run: bool, restart_explorer: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
     let action = "Rebuild Icon Cache";
 
     let local_app_data = match env::var("LOCALAPPDATA") {
@@ -41,6 +45,10 @@ pub fn rebuild_icon_cache(dry_run: bool, restart_explorer: bool) -> OptimizeResu
     let mut deleted_count = 0;
     let mut failed_count = 0;
 
+    if restart_explorer {
+        let _ = stop_explorer();
+    }
+
     // Delete main IconCache.db
     if icon_cache_path.exists() {
         match fs::remove_file(&icon_cache_path) {
@@ -66,9 +74,9 @@ pub fn rebuild_icon_cache(dry_run: bool, restart_explorer: bool) -> OptimizeResu
         }
     }
 
-    // Optionally restart Explorer
+    // Bring Explorer back; it rebuilds the caches on demand
     if restart_explorer {
-        let _ = do_restart_explorer();
+        let _ = start_explorer();
     }
 
     OptimizeResult::success(
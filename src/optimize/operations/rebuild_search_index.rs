@@ -0,0 +1,162 @@
+//! Purge and rebuild Windows Search index operation.
+
+use super::super::admin_check::is_admin;
+use super::super::result::OptimizeResult;
+use super::service_control::{purge_files, total_size, with_services_stopped};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn index_dir() -> PathBuf {
+    env::var("ProgramData")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(r"C:\ProgramData"))
+        .join("Microsoft")
+        .join("Search")
+        .join("Data")
+        .join("Applications")
+        .join("Windows")
+}
+
+/// Index database and its transaction logs
+/// (`Windows.edb` up to Windows 11 23H2, `Windows.db` / `Windows-gather.db` after)
+fn index_files() -> Vec<PathBuf> {
+    index_files_in(&index_dir())
+}
+
+fn index_files_in(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| {
+                let ext = ext.to_string_lossy().to_lowercase();
+                matches!(ext.as_str(), "edb" | "db" | "log" | "jrs" | "chk")
+            })
+        })
+        .collect()
+}
+
+/// Combined size of the index files (0 when there is no index)
+pub(crate) fn index_size() -> u64 {
+    total_size(&index_files())
+}
+
+/// Tell Windows Search to start from scratch on its next start
+fn request_rebuild() -> bool {
+    Command::new("reg")
+        .args([
+            "add",
+            r"HKLM\SOFTWARE\Microsoft\Windows Search",
+            "/v",
+            "SetupCompletedSuccessfully",
+            "/t",
+            "REG_DWORD",
+            "/d",
+            "0",
+            "/f",
+        ])
+        .output()
+        .map(|out| out.status.success())
+        .unwrap_or(false)
+}
+
+/// Purge the Windows Search index and let the WSearch service rebuild it
+pub fn rebuild_search_index(dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
+    let action = "Rebuild Search Index";
+
+    if dry_run {
+        return OptimizeResult::skipped(
+            action,
+            &format!(
+                "Dry run mode - would stop WSearch, purge the index in {} and rebuild it",
+                index_dir().display()
+            ),
+            true,
+        );
+    }
+
+    if !is_admin() {
+        return OptimizeResult::failure(action, "Administrator privileges required", true);
+    }
+
+    let files = index_files();
+    if files.is_empty() {
+        return OptimizeResult::skipped(action, "No search index found", true);
+    }
+    let index_size = total_size(&files);
+
+    let run = with_services_stopped(&["WSearch"], || {
+        let purged = purge_files(&index_files());
+        (purged, request_rebuild())
+    });
+
+    match run {
+        Ok(run) => {
+            let (purged, rebuild_requested) = run.output;
+            if purged.files == 0 && !rebuild_requested {
+                return OptimizeResult::failure(
+                    action,
+                    &format!(
+                        "Could not purge the search index ({} locked){}",
+                        purged.failed,
+                        run.restart_note()
+                    ),
+                    true,
+                );
+            }
+            let message = format!(
+                "Purged search index (was {}, freed {}) - Windows rebuilds it in the background{}",
+                bytesize::to_string(index_size, false),
+                bytesize::to_string(purged.bytes, false),
+                run.restart_note()
+            );
+            if run.not_restarted.is_empty() {
+                OptimizeResult::success(action, &message, true)
+            } else {
+                OptimizeResult::failure(action, &message, true)
+            }
+        }
+        Err(e) => OptimizeResult::failure(action, &e, true),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_files_cover_old_and_new_formats() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        for name in [
+            "Windows.edb",
+            "Windows.db",
+            "Windows-gather.db",
+            "MSS00001.log",
+            "MSS.chk",
+            "MSSres00001.jrs",
+            "readme.txt",
+        ] {
+            fs::write(temp_dir.path().join(name), b"index").unwrap();
+        }
+        fs::create_dir(temp_dir.path().join("Projects.db")).unwrap();
+
+        let files = index_files_in(temp_dir.path());
+        assert_eq!(files.len(), 6);
+        assert!(files.iter().all(|path| path.is_file()));
+        assert_eq!(total_size(&files), 30);
+    }
+
+    #[test]
+    fn test_dry_run_leaves_index_alone() {
+        let result = rebuild_search_index(true);
+        assert!(result.success);
+        assert!(result.message.starts_with("Skipped: Dry run mode"));
+    }
+}
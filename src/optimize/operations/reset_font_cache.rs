@@ -0,0 +1,126 @@
+//! Reset font cache operation.
+
+use super::super::admin_check::is_admin;
+use super::super::result::OptimizeResult;
+use super::service_control::{purge_files, with_services_stopped};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Font cache files of the FontCache and WPF FontCache3.0.0.0 services
+fn cache_files() -> Vec<PathBuf> {
+    let windows = env::var("SystemRoot")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(r"C:\Windows"));
+    cache_files_in(&windows)
+}
+
+fn cache_files_in(windows: &Path) -> Vec<PathBuf> {
+    let local_service = windows
+        .join("ServiceProfiles")
+        .join("LocalService")
+        .join("AppData")
+        .join("Local");
+
+    let mut files: Vec<PathBuf> = fs::read_dir(local_service.join("FontCache"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.file_name()
+                        .is_some_and(|n| n.to_string_lossy().starts_with("~FontCache-"))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for file in [
+        local_service.join("FontCache3.0.0.0.dat"),
+        windows.join("System32").join("FNTCACHE.DAT"),
+    ] {
+        if file.is_file() {
+            files.push(file);
+        }
+    }
+    files
+}
+
+/// Reset the font cache: stop the font cache services, delete their caches, start them again
+pub fn reset_font_cache(dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
+    let action = "Reset Font Cache";
+
+    if dry_run {
+        return OptimizeResult::skipped(
+            action,
+            "Dry run mode - would stop FontCache, delete the font cache files and restart it",
+            true,
+        );
+    }
+
+    if !is_admin() {
+        return OptimizeResult::failure(action, "Administrator privileges required", true);
+    }
+
+    match with_services_stopped(&["FontCache", "FontCache3.0.0.0"], || {
+        purge_files(&cache_files())
+    }) {
+        Ok(run) => {
+            let purged = run.output;
+            let message = format!(
+                "Deleted {} font cache file(s) ({}), {} locked{} - a restart finishes the reset",
+                purged.files,
+                bytesize::to_string(purged.bytes, false),
+                purged.failed,
+                run.restart_note()
+            );
+            if (purged.files > 0 || purged.failed == 0) && run.not_restarted.is_empty() {
+                OptimizeResult::success(action, &message, true)
+            } else {
+                OptimizeResult::failure(action, &message, true)
+            }
+        }
+        Err(e) => OptimizeResult::failure(action, &e, true),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_files_under_windows_dir() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let windows = temp_dir.path();
+        let local = windows.join("ServiceProfiles/LocalService/AppData/Local");
+        let font_cache = local.join("FontCache");
+        fs::create_dir_all(&font_cache).unwrap();
+        fs::create_dir_all(windows.join("System32")).unwrap();
+        fs::write(font_cache.join("~FontCache-S-1-5-21.dat"), b"cache").unwrap();
+        fs::write(font_cache.join("~FontCache-System.dat"), b"cache").unwrap();
+        fs::write(font_cache.join("Fonts.lst"), b"list").unwrap();
+        fs::write(windows.join("System32/FNTCACHE.DAT"), b"cache").unwrap();
+
+        let mut names: Vec<String> = cache_files_in(windows)
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            [
+                "FNTCACHE.DAT",
+                "~FontCache-S-1-5-21.dat",
+                "~FontCache-System.dat"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dry_run_leaves_font_cache_alone() {
+        let result = reset_font_cache(true);
+        assert!(result.success);
+        assert!(result.message.starts_with("Skipped: Dry run mode"));
+    }
+}
@@ -1,17 +1,185 @@
 //! Reset network stack operation.
+//!
+//! A guided reset: connectivity is checked first, then the steps run from
+//! least to most disruptive (flush DNS, renew the IP address, reset Winsock,
+//! reset TCP/IP) with a check after each one. The reset stops at the first
+//! step after which the network works again and reports that step. Winsock
+//! and TCP/IP resets only take full effect after a restart.
 
 use super::super::admin_check::is_admin;
 use super::super::result::OptimizeResult;
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
 use std::process::Command;
+use std::time::Duration;
 
-/// Reset network stack (Winsock and IP configuration)
+/// Public DNS servers a TCP connection is tried to
+const PROBE_SERVERS: [&str; 2] = ["1.1.1.1:53", "8.8.8.8:53"];
+
+/// Name resolved to check DNS (Windows' own connectivity test host)
+const PROBE_HOST: &str = "www.msftconnecttest.com:80";
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Time adapters get to settle after a step before connectivity is checked
+const SETTLE_TIME: Duration = Duration::from_secs(2);
+
+/// One step of the reset
+struct ResetStep {
+    label: &'static str,
+    commands: &'static [&'static [&'static str]],
+    needs_restart: bool,
+}
+
+/// Least disruptive first
+const STEPS: [ResetStep; 4] = [
+    ResetStep {
+        label: "Flush DNS cache",
+        commands: &[&["ipconfig", "/flushdns"]],
+        needs_restart: false,
+    },
+    ResetStep {
+        label: "Renew IP address",
+        commands: &[&["ipconfig", "/release"], &["ipconfig", "/renew"]],
+        needs_restart: false,
+    },
+    ResetStep {
+        label: "Reset Winsock",
+        commands: &[&["netsh", "winsock", "reset"]],
+        needs_restart: true,
+    },
+    ResetStep {
+        label: "Reset TCP/IP",
+        commands: &[&["netsh", "int", "ip", "reset"]],
+        needs_restart: true,
+    },
+];
+
+/// Result of a connectivity check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Connectivity {
+    /// A public server accepted a connection
+    pub reachable: bool,
+    /// A name resolved
+    pub dns: bool,
+}
+
+impl Connectivity {
+    pub fn ok(self) -> bool {
+        self.reachable && self.dns
+    }
+
+    pub fn describe(self) -> &'static str {
+        match (self.reachable, self.dns) {
+            (true, true) => "connected",
+            (true, false) => "connected, but DNS fails",
+            (false, true) => "names resolve, but servers are unreachable",
+            (false, false) => "offline",
+        }
+    }
+}
+
+/// Try a connection to a public server and a DNS lookup
+pub fn check_connectivity() -> Connectivity {
+    let reachable = PROBE_SERVERS.iter().any(|server| {
+        server
+            .parse::<SocketAddr>()
+            .is_ok_and(|addr| TcpStream::connect_timeout(&addr, PROBE_TIMEOUT).is_ok())
+    });
+    let dns = PROBE_HOST
+        .to_socket_addrs()
+        .is_ok_and(|mut addrs| addrs.next().is_some());
+    Connectivity { reachable, dns }
+}
+
+/// How far the reset went
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum WizardOutcome {
+    /// The network worked before anything ran
+    AlreadyConnected,
+    /// Connectivity came back after this step
+    Fixed(usize),
+    /// Every step ran without bringing the network back
+    NotFixed,
+}
+
+/// What the reset did: the outcome and, for each step that ran, whether its commands succeeded
+struct WizardRun {
+    outcome: WizardOutcome,
+    ran: Vec<bool>,
+}
+
+/// Run steps until `probe` reports a working network
+fn run_wizard(
+    steps: &[ResetStep],
+    probe: &mut dyn FnMut() -> Connectivity,
+    run_step: &mut dyn FnMut(&ResetStep) -> bool,
+    on_step: &mut dyn FnMut(&str),
+) -> WizardRun {
+    let mut ran = Vec::new();
+    let before = probe();
+    if before.ok() {
+        return WizardRun {
+            outcome: WizardOutcome::AlreadyConnected,
+            ran,
+        };
+    }
+    on_step(&format!("Before: {}", before.describe()));
+
+    for (i, step) in steps.iter().enumerate() {
+        let succeeded = run_step(step);
+        ran.push(succeeded);
+        let after = probe();
+        on_step(&format!(
+            "{}. {}: {}{}",
+            i + 1,
+            step.label,
+            if succeeded { "" } else { "command failed, " },
+            after.describe()
+        ));
+        if after.ok() {
+            return WizardRun {
+                outcome: WizardOutcome::Fixed(i),
+                ran,
+            };
+        }
+    }
+    WizardRun {
+        outcome: WizardOutcome::NotFixed,
+        ran,
+    }
+}
+
+fn run_reset_step(step: &ResetStep) -> bool {
+    let mut succeeded = true;
+    for args in step.commands {
+        succeeded &= Command::new(args[0])
+            .args(&args[1..])
+            .output()
+            .map(|out| out.status.success())
+            .unwrap_or(false);
+    }
+    std::thread::sleep(SETTLE_TIME);
+    succeeded
+}
+
+/// Reset the network step by step until it works again
 pub fn reset_network_stack(dry_run: bool) -> OptimizeResult {
+    reset_network_stack_with_progress(dry_run, &mut |_| {})
+}
+
+/// [`reset_network_stack`], reporting each step and the connectivity after it to `on_step`
+pub fn reset_network_stack_with_progress(
+    dry_run: bool,
+    on_step: &mut dyn FnMut(&str),
+) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
     let action = "Reset Network Stack";
 
     if dry_run {
         return OptimizeResult::skipped(
             action,
-            "Dry run mode - would run: netsh winsock reset, netsh int ip reset",
+            "Dry run mode - would check connectivity, then flush DNS, renew the IP address, \
+             reset Winsock and reset TCP/IP until the network works again",
             true,
         );
     }
@@ -20,31 +188,102 @@ pub fn reset_network_stack(dry_run: bool) -> OptimizeResult {
         return OptimizeResult::failure(action, "Administrator privileges required", true);
     }
 
-    // Reset Winsock
-    let winsock_result = Command::new("netsh").args(["winsock", "reset"]).output();
-
-    // Reset IP configuration
-    let ip_result = Command::new("netsh").args(["int", "ip", "reset"]).output();
-
-    match (winsock_result, ip_result) {
-        (Ok(ws), Ok(ip)) => {
-            let ws_ok = ws.status.success();
-            let ip_ok = ip.status.success();
-
-            if ws_ok && ip_ok {
-                OptimizeResult::success(
-                    action,
-                    "Network stack reset successfully (restart required to take effect)",
-                    true,
-                )
-            } else if ws_ok {
-                OptimizeResult::success(action, "Winsock reset, but IP reset failed", true)
-            } else if ip_ok {
-                OptimizeResult::success(action, "IP reset, but Winsock reset failed", true)
-            } else {
-                OptimizeResult::failure(action, "Failed to reset network stack", true)
+    let run = run_wizard(
+        &STEPS,
+        &mut check_connectivity,
+        &mut run_reset_step,
+        on_step,
+    );
+    wizard_result(action, &run)
+}
+
+fn wizard_result(action: &str, run: &WizardRun) -> OptimizeResult {
+    let restart_pending = run
+        .ran
+        .iter()
+        .zip(&STEPS)
+        .any(|(succeeded, step)| *succeeded && step.needs_restart);
+    match run.outcome {
+        WizardOutcome::AlreadyConnected => {
+            OptimizeResult::success(action, "Network is working - nothing was reset", true)
+        }
+        WizardOutcome::Fixed(step) => {
+            let mut message = format!("Connectivity restored by step {}: {}", step + 1, STEPS[step].label);
+            if restart_pending {
+                message.push_str(" (restart required to finish the reset)");
             }
+            OptimizeResult::success(action, &message, true)
         }
-        _ => OptimizeResult::failure(action, "Failed to execute netsh commands", true),
+        WizardOutcome::NotFixed if restart_pending => OptimizeResult::success(
+            action,
+            "Still offline after every step - Winsock and TCP/IP were reset, restart required to take effect",
+            true,
+        ),
+        WizardOutcome::NotFixed => OptimizeResult::failure(
+            action,
+            "Still offline after every step and the resets failed",
+            true,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OFFLINE: Connectivity = Connectivity {
+        reachable: false,
+        dns: false,
+    };
+    const ONLINE: Connectivity = Connectivity {
+        reachable: true,
+        dns: true,
+    };
+
+    /// Run the wizard against scripted connectivity checks
+    fn scripted(checks: &[Connectivity]) -> (WizardRun, Vec<&'static str>, Vec<String>) {
+        let mut checks = checks.iter().copied();
+        let mut ran = Vec::new();
+        let mut lines = Vec::new();
+        let run = run_wizard(
+            &STEPS,
+            &mut || checks.next().unwrap_or(OFFLINE),
+            &mut |step| {
+                ran.push(step.label);
+                true
+            },
+            &mut |line| lines.push(line.to_string()),
+        );
+        (run, ran, lines)
+    }
+
+    #[test]
+    fn test_wizard_stops_at_first_fix() {
+        let (run, ran, lines) = scripted(&[OFFLINE, OFFLINE, ONLINE]);
+        assert_eq!(run.outcome, WizardOutcome::Fixed(1));
+        assert_eq!(ran, ["Flush DNS cache", "Renew IP address"]);
+        assert_eq!(lines[2], "2. Renew IP address: connected");
+        let result = wizard_result("Reset Network Stack", &run);
+        assert!(result.success);
+        assert_eq!(
+            result.message,
+            "Connectivity restored by step 2: Renew IP address"
+        );
+    }
+
+    #[test]
+    fn test_wizard_skips_working_network() {
+        let (run, ran, lines) = scripted(&[ONLINE]);
+        assert_eq!(run.outcome, WizardOutcome::AlreadyConnected);
+        assert!(ran.is_empty() && lines.is_empty());
+    }
+
+    #[test]
+    fn test_wizard_not_fixed_needs_restart() {
+        let (run, ran, _) = scripted(&[]);
+        assert_eq!(run.outcome, WizardOutcome::NotFixed);
+        assert_eq!(ran.len(), STEPS.len());
+        let result = wizard_result("Reset Network Stack", &run);
+        assert!(result.success && result.message.contains("restart required"));
     }
 }
@@ -6,6 +6,7 @@ use std::process::Command;
 
 /// Restart the Bluetooth Support Service
 pub fn restart_bluetooth_service(dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
     let action = "Restart Bluetooth Service";
 
     if dry_run {
@@ -5,6 +5,7 @@ use std::process::{Command, Stdio};
 
 /// Restart Windows Explorer (equivalent to Dock refresh on macOS)
 pub fn restart_explorer(dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
     let action = "Restart Explorer";
 
     if dry_run {
@@ -18,30 +19,11 @@ pub fn restart_explorer(dry_run: bool) -> OptimizeResult {
 pub(crate) fn do_restart_explorer() -> OptimizeResult {
     let action = "Restart Explorer";
 
-    // Kill explorer gracefully - redirect output to prevent TUI corruption
-    let kill_result = Command::new("taskkill")
-        .args(["/F", "/IM", "explorer.exe"])
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .spawn();
-
-    // Small delay to let it fully close
-    std::thread::sleep(std::time::Duration::from_millis(500));
-
-    // Start explorer again - redirect output to prevent TUI corruption
-    let start_result = Command::new("cmd")
-        .args(["/C", "start", "explorer.exe"])
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .spawn();
+    let kill_result = stop_explorer();
+    let start_result = start_explorer();
 
     match (kill_result, start_result) {
-        (Ok(_), Ok(_)) => {
-            // Both commands spawned successfully
-            // Give Explorer a moment to start
-            std::thread::sleep(std::time::Duration::from_millis(500));
-            OptimizeResult::success(action, "Explorer restarted successfully", false)
-        }
+        (Ok(_), Ok(_)) => OptimizeResult::success(action, "Explorer restarted successfully", false),
         (Ok(_), Err(e)) => OptimizeResult::failure(
             action,
             &format!("Killed Explorer but failed to restart: {}", e),
@@ -52,3 +34,31 @@ pub(crate) fn do_restart_explorer() -> OptimizeResult {
         }
     }
 }
+
+/// Kill explorer.exe and give it a moment to release its files
+pub(crate) fn stop_explorer() -> std::io::Result<()> {
+    // Redirect output to prevent TUI corruption
+    Command::new("taskkill")
+        .args(["/F", "/IM", "explorer.exe"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+
+    // Small delay to let it fully close
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    Ok(())
+}
+
+/// Start explorer.exe again after [`stop_explorer`]
+pub(crate) fn start_explorer() -> std::io::Result<()> {
+    // Redirect output to prevent TUI corruption
+    Command::new("cmd")
+        .args(["/C", "start", "explorer.exe"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // Give Explorer a moment to start
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    Ok(())
+}
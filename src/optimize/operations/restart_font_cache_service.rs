@@ -6,6 +6,7 @@ use std::process::Command;
 
 /// Restart the Windows Font Cache Service
 pub fn restart_font_cache_service(dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
     let action = "Restart Font Cache Service";
 
     if dry_run {
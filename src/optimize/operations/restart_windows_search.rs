@@ -6,6 +6,7 @@ use std::process::Command;
 
 /// Restart the Windows Search service (equivalent to Spotlight rebuild on macOS)
 pub fn restart_windows_search(dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
     let action = "Restart Windows Search";
 
     if dry_run {
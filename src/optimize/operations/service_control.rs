@@ -0,0 +1,219 @@
+//! Service stop/start orchestration shared by maintenance operations.
+//!
+//! Maintenance that deletes a service's files (spooler jobs, search index,
+//! font caches) has to stop the service first and bring it back afterwards,
+//! even when the work in between fails.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Whether a Windows service is currently running (`sc query`)
+pub(crate) fn is_running(service: &str) -> bool {
+    Command::new("sc")
+        .args(["query", service])
+        .output()
+        .map(|out| String::from_utf8_lossy(&out.stdout).contains("RUNNING"))
+        .unwrap_or(false)
+}
+
+fn stop_service(service: &str) -> bool {
+    // /y also stops dependent services instead of prompting
+    let _ = Command::new("net").args(["stop", service, "/y"]).output();
+    !is_running(service)
+}
+
+fn start_service(service: &str) -> bool {
+    let _ = Command::new("net").args(["start", service]).output();
+    is_running(service)
+}
+
+/// Outcome of work done with services stopped
+pub(crate) struct ServiceRun<T> {
+    pub output: T,
+    /// Services that were running before but did not come back
+    pub not_restarted: Vec<String>,
+}
+
+impl<T> ServiceRun<T> {
+    /// Suffix for a result message about services that did not come back
+    pub fn restart_note(&self) -> String {
+        if self.not_restarted.is_empty() {
+            String::new()
+        } else {
+            format!(" - could not restart {}", self.not_restarted.join(", "))
+        }
+    }
+}
+
+/// Stop `services` (in order), run `work`, then start the ones that were running (in reverse).
+///
+/// Services that were not running are left alone. If a service refuses to stop,
+/// the already stopped ones are restarted and `work` does not run.
+pub(crate) fn with_services_stopped<T>(
+    services: &[&str],
+    work: impl FnOnce() -> T,
+) -> Result<ServiceRun<T>, String> {
+    let control = ServiceControl {
+        is_running: &mut is_running,
+        stop: &mut stop_service,
+        start: &mut start_service,
+    };
+    orchestrate(services, control, || {
+        // Give the services a moment to release their files
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        work()
+    })
+}
+
+/// How [`orchestrate`] queries, stops and starts services
+struct ServiceControl<'a> {
+    is_running: &'a mut dyn FnMut(&str) -> bool,
+    /// Returns whether the service is stopped afterwards
+    stop: &'a mut dyn FnMut(&str) -> bool,
+    /// Returns whether the service is running afterwards
+    start: &'a mut dyn FnMut(&str) -> bool,
+}
+
+fn orchestrate<T>(
+    services: &[&str],
+    control: ServiceControl,
+    work: impl FnOnce() -> T,
+) -> Result<ServiceRun<T>, String> {
+    let mut stopped: Vec<&str> = Vec::new();
+    for service in services {
+        if !(control.is_running)(service) {
+            continue;
+        }
+        if !(control.stop)(service) {
+            for started in stopped.iter().rev() {
+                (control.start)(started);
+            }
+            return Err(format!("Could not stop the {} service", service));
+        }
+        stopped.push(service);
+    }
+
+    let output = work();
+
+    let not_restarted = stopped
+        .iter()
+        .rev()
+        .filter(|service| !(control.start)(service))
+        .map(|service| service.to_string())
+        .collect();
+
+    Ok(ServiceRun {
+        output,
+        not_restarted,
+    })
+}
+
+/// Files deleted by [`purge_files`]
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Purged {
+    pub files: usize,
+    pub bytes: u64,
+    pub failed: usize,
+}
+
+/// Delete files, counting what was freed (locked files are counted as failed)
+pub(crate) fn purge_files(paths: &[PathBuf]) -> Purged {
+    let mut purged = Purged::default();
+    for path in paths {
+        let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        match fs::remove_file(path) {
+            Ok(()) => {
+                purged.files += 1;
+                purged.bytes += size;
+            }
+            Err(_) => purged.failed += 1,
+        }
+    }
+    purged
+}
+
+/// Total size of files (unreadable ones count as 0)
+pub(crate) fn total_size(paths: &[PathBuf]) -> u64 {
+    paths
+        .iter()
+        .filter_map(|path| fs::metadata(path).ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Run `orchestrate` against scripted services, returning it and the calls made
+    fn scripted(
+        running: &[&str],
+        refuse_stop: &[&str],
+        refuse_start: &[&str],
+    ) -> (Result<ServiceRun<bool>, String>, Vec<String>) {
+        let calls = RefCell::new(Vec::new());
+        let mut is_running = |service: &str| running.contains(&service);
+        let mut stop = |service: &str| {
+            calls.borrow_mut().push(format!("stop {}", service));
+            !refuse_stop.contains(&service)
+        };
+        let mut start = |service: &str| {
+            calls.borrow_mut().push(format!("start {}", service));
+            !refuse_start.contains(&service)
+        };
+        let control = ServiceControl {
+            is_running: &mut is_running,
+            stop: &mut stop,
+            start: &mut start,
+        };
+        let run = orchestrate(&["A", "B", "C"], control, || {
+            calls.borrow_mut().push("work".to_string());
+            true
+        });
+        (run, calls.into_inner())
+    }
+
+    #[test]
+    fn test_restarts_only_running_services_in_reverse() {
+        let (run, calls) = scripted(&["A", "C"], &[], &[]);
+        let run = run.unwrap();
+        assert!(run.output);
+        assert!(run.not_restarted.is_empty());
+        assert_eq!(run.restart_note(), "");
+        assert_eq!(calls, ["stop A", "stop C", "work", "start C", "start A"]);
+    }
+
+    #[test]
+    fn test_refused_stop_skips_work_and_restarts() {
+        let (run, calls) = scripted(&["A", "B", "C"], &["B"], &[]);
+        assert_eq!(run.err().unwrap(), "Could not stop the B service");
+        assert_eq!(calls, ["stop A", "stop B", "start A"]);
+    }
+
+    #[test]
+    fn test_reports_services_not_restarted() {
+        let (run, _) = scripted(&["A", "B"], &[], &["A"]);
+        let run = run.unwrap();
+        assert_eq!(run.not_restarted, ["A"]);
+        assert_eq!(run.restart_note(), " - could not restart A");
+    }
+
+    #[test]
+    fn test_purge_files_counts_freed_and_failed() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let job = temp_dir.path().join("00001.SPL");
+        let shadow = temp_dir.path().join("00001.SHD");
+        fs::write(&job, b"12345").unwrap();
+        fs::write(&shadow, b"123").unwrap();
+        let missing = temp_dir.path().join("00002.SPL");
+        let paths = vec![job.clone(), shadow, missing];
+
+        assert_eq!(total_size(&paths), 8);
+        let purged = purge_files(&paths);
+        assert_eq!((purged.files, purged.bytes, purged.failed), (2, 8, 1));
+        assert!(!job.exists());
+        assert_eq!(total_size(&paths), 0);
+    }
+}
@@ -0,0 +1,37 @@
+//! Switch power plan operation.
+
+use super::super::result::OptimizeResult;
+use crate::power;
+
+/// Switch to another power plan (`high`, `balanced`, `saver`, a plan name or GUID),
+/// or go back to the plan that was active before with `restore`
+pub fn set_power_plan(plan: &str, dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
+    let action = "Switch Power Plan";
+    let restore = plan.eq_ignore_ascii_case("restore");
+
+    if dry_run {
+        let message = if restore {
+            "Dry run mode - would restore the previous power plan".to_string()
+        } else {
+            format!("Dry run mode - would switch to the '{}' power plan", plan)
+        };
+        return OptimizeResult::skipped(action, &message, false);
+    }
+
+    let result = if restore {
+        power::restore_previous_plan().map(|p| format!("Restored power plan '{}'", p.name))
+    } else {
+        power::switch_to(plan).map(|p| {
+            format!(
+                "Switched to power plan '{}' (undo with --power-plan restore)",
+                p.name
+            )
+        })
+    };
+
+    match result {
+        Ok(message) => OptimizeResult::success(action, &message, false),
+        Err(e) => OptimizeResult::failure(action, &e.to_string(), false),
+    }
+}
@@ -0,0 +1,48 @@
+//! Downloaded-file unblocking operation.
+
+use super::super::result::OptimizeResult;
+use std::path::PathBuf;
+
+/// Remove Zone.Identifier streams so Windows stops blocking downloaded files
+pub fn unblock_files(paths: &[PathBuf], dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
+    let action = "Unblock Downloaded Files";
+
+    if !cfg!(windows) {
+        return OptimizeResult::skipped(action, "Only NTFS volumes on Windows have streams", false);
+    }
+    if paths.is_empty() {
+        return OptimizeResult::skipped(action, "No folders given", false);
+    }
+
+    let summary = crate::ads::unblock(paths, dry_run);
+
+    if summary.blocked.is_empty() {
+        return OptimizeResult::success(action, "No blocked files found", false);
+    }
+    if dry_run {
+        return OptimizeResult::skipped(
+            action,
+            &format!(
+                "Dry run mode - would unblock {} file(s)",
+                summary.blocked.len()
+            ),
+            false,
+        );
+    }
+
+    if summary.unblocked == 0 {
+        let (path, error) = &summary.failed[0];
+        return OptimizeResult::failure(
+            action,
+            &format!("Failed to unblock {}: {}", path.display(), error),
+            false,
+        );
+    }
+
+    let mut message = format!("Unblocked {} file(s)", summary.unblocked);
+    if !summary.failed.is_empty() {
+        message.push_str(&format!(", {} failed", summary.failed.len()));
+    }
+    OptimizeResult::success(action, &message, false)
+}
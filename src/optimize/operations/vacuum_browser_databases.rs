@@ -7,6 +7,7 @@ use std::path::PathBuf;
 
 /// Optimize browser SQLite databases using VACUUM
 pub fn vacuum_browser_databases(dry_run: bool) -> OptimizeResult {
+    let dry_run = dry_run || crate::audit::is_enabled();
     let action = "Optimize Browser Databases";
 
     let local_app_data = match env::var("LOCALAPPDATA") {
@@ -12,6 +12,16 @@ pub(crate) fn print_operation_start(message: &str, output_mode: OutputMode) {
     }
 }
 
+/// Progress under the operation that is running, each on its own line
+///
+/// The last line is left open; end it with `println!()` before the result.
+pub(crate) fn print_operation_step(message: &str, output_mode: OutputMode) {
+    if output_mode != OutputMode::Quiet {
+        print!("\n      {}", Theme::muted(message));
+        std::io::Write::flush(&mut std::io::stdout()).ok();
+    }
+}
+
 pub(crate) fn print_operation_result(result: &OptimizeResult, output_mode: OutputMode) {
     if output_mode == OutputMode::Quiet {
         return;
@@ -35,6 +45,9 @@ pub(crate) fn print_operation_result(result: &OptimizeResult, output_mode: Outpu
                 result.action,
                 Theme::success(&result.message)
             );
+            if let Some(snapshot) = &result.snapshot {
+                println!("    {}", Theme::value(&snapshot.describe()));
+            }
         }
     } else {
         println!(
@@ -75,7 +88,7 @@ pub fn print_summary(results: &[OptimizeResult], output_mode: OutputMode) {
 
     // Show restart hint if network was reset
     if results.iter().any(|r| {
-        r.action == "Reset Network Stack" && r.success && !r.message.starts_with("Skipped:")
+        r.action == "Reset Network Stack" && r.success && r.message.contains("restart required")
     }) {
         println!();
         println!(
@@ -1,5 +1,7 @@
 //! Optimization result feature.
 
+use super::snapshot::Snapshot;
+
 /// Result of an optimization operation
 #[derive(Debug, Clone)]
 pub struct OptimizeResult {
@@ -11,6 +13,8 @@ pub struct OptimizeResult {
     pub message: String,
     /// Whether this operation requires administrator privileges
     pub requires_admin: bool,
+    /// Before/after measurement, for operations that have one
+    pub snapshot: Option<Snapshot>,
 }
 
 impl OptimizeResult {
@@ -20,6 +24,7 @@ impl OptimizeResult {
             success: true,
             message: message.to_string(),
             requires_admin,
+            snapshot: None,
         }
     }
 
@@ -29,6 +34,7 @@ impl OptimizeResult {
             success: false,
             message: message.to_string(),
             requires_admin,
+            snapshot: None,
         }
     }
 
@@ -38,6 +44,7 @@ impl OptimizeResult {
             success: true, // Skipped is considered "success" (not an error)
             message: format!("Skipped: {}", message),
             requires_admin,
+            snapshot: None,
         }
     }
 }
@@ -2,14 +2,19 @@
 
 use super::admin_check::is_admin;
 use super::operations::{
-    clear_standby_memory, clear_thumbnail_cache, flush_dns_cache, rebuild_icon_cache,
-    reset_network_stack, restart_bluetooth_service, restart_explorer, restart_font_cache_service,
-    restart_windows_search, vacuum_browser_databases,
+    clear_print_spooler, clear_standby_memory, clear_thumbnail_cache, compress_folders,
+    flush_dns_cache, rebuild_icon_cache, rebuild_search_index, reset_font_cache,
+    reset_network_stack_with_progress, restart_bluetooth_service, restart_explorer,
+    restart_font_cache_service, restart_windows_search, set_power_plan, unblock_files,
+    vacuum_browser_databases,
 };
-use super::printing::{print_operation_result, print_operation_start};
+use super::printing::{print_operation_result, print_operation_start, print_operation_step};
 use super::result::OptimizeResult;
+use super::snapshot::{measured, Metric};
+use crate::compress::CompressionMode;
 use crate::output::OutputMode;
 use crate::theme::Theme;
+use std::path::PathBuf;
 
 /// Run all optimizations
 #[allow(clippy::too_many_arguments)]
@@ -25,11 +30,20 @@ pub fn run_optimizations(
     bluetooth: bool,
     search: bool,
     explorer: bool,
+    spooler: bool,
+    search_index: bool,
+    font_cache: bool,
+    compress: &[PathBuf],
+    compress_mode: CompressionMode,
+    unblock: &[PathBuf],
+    power_plan: Option<&str>,
     dry_run: bool,
     _yes: bool,
     output_mode: OutputMode,
 ) -> Vec<OptimizeResult> {
     let mut results = Vec::new();
+    // Audit mode reports every operation as a dry run
+    let dry_run = dry_run || crate::audit::is_enabled();
 
     // Determine which optimizations to run
     let run_dns = all || dns;
@@ -42,9 +56,20 @@ pub fn run_optimizations(
     let mut run_bluetooth = all || bluetooth;
     let mut run_search = all || search;
     let run_explorer = all || explorer;
+    let mut run_spooler = all || spooler;
+    // A rebuild re-indexes every file for hours, so --all never includes it
+    let mut run_search_index = search_index;
+    let mut run_font_cache = all || font_cache;
 
     // Check if any admin operations are requested
-    let needs_admin = run_fonts || run_memory || run_network || run_bluetooth || run_search;
+    let needs_admin = run_fonts
+        || run_memory
+        || run_network
+        || run_bluetooth
+        || run_search
+        || run_spooler
+        || run_search_index
+        || run_font_cache;
     let is_admin_user = is_admin();
 
     // If admin operations are needed and we're not running as admin, skip them automatically
@@ -65,12 +90,17 @@ pub fn run_optimizations(
         run_network = false;
         run_bluetooth = false;
         run_search = false;
+        run_spooler = false;
+        run_search_index = false;
+        run_font_cache = false;
     }
 
     // Run non-admin operations first
     if run_dns {
         print_operation_start("Flushing DNS cache...", output_mode);
-        let result = flush_dns_cache(dry_run);
+        let result = measured(Metric::DnsCacheEntries, dry_run, || {
+            flush_dns_cache(dry_run)
+        });
         print_operation_result(&result, output_mode);
         results.push(result);
     }
@@ -97,6 +127,27 @@ pub fn run_optimizations(
         results.push(result);
     }
 
+    if !compress.is_empty() {
+        print_operation_start("Compressing folders...", output_mode);
+        let result = compress_folders(compress, compress_mode, dry_run);
+        print_operation_result(&result, output_mode);
+        results.push(result);
+    }
+
+    if !unblock.is_empty() {
+        print_operation_start("Unblocking downloaded files...", output_mode);
+        let result = unblock_files(unblock, dry_run);
+        print_operation_result(&result, output_mode);
+        results.push(result);
+    }
+
+    if let Some(plan) = power_plan {
+        print_operation_start("Switching power plan...", output_mode);
+        let result = set_power_plan(plan, dry_run);
+        print_operation_result(&result, output_mode);
+        results.push(result);
+    }
+
     // Admin operations
     if run_fonts {
         print_operation_start("Restarting font cache service...", output_mode);
@@ -105,9 +156,18 @@ pub fn run_optimizations(
         results.push(result);
     }
 
+    if run_font_cache {
+        print_operation_start("Resetting font cache...", output_mode);
+        let result = reset_font_cache(dry_run);
+        print_operation_result(&result, output_mode);
+        results.push(result);
+    }
+
     if run_memory {
         print_operation_start("Clearing standby memory...", output_mode);
-        let result = clear_standby_memory(dry_run);
+        let result = measured(Metric::StandbyMemory, dry_run, || {
+            clear_standby_memory(dry_run)
+        });
         print_operation_result(&result, output_mode);
         results.push(result);
     }
@@ -117,7 +177,14 @@ pub fn run_optimizations(
         let already_skipped = results.iter().any(|r| r.action == "Reset Network Stack");
         if !already_skipped {
             print_operation_start("Resetting network stack...", output_mode);
-            let result = reset_network_stack(dry_run);
+            let mut stepped = false;
+            let result = reset_network_stack_with_progress(dry_run, &mut |step| {
+                stepped = true;
+                print_operation_step(step, output_mode);
+            });
+            if stepped && output_mode != OutputMode::Quiet {
+                println!();
+            }
             print_operation_result(&result, output_mode);
             results.push(result);
         }
@@ -137,6 +204,22 @@ pub fn run_optimizations(
         results.push(result);
     }
 
+    if run_search_index {
+        print_operation_start("Rebuilding search index...", output_mode);
+        let result = measured(Metric::SearchIndexSize, dry_run, || {
+            rebuild_search_index(dry_run)
+        });
+        print_operation_result(&result, output_mode);
+        results.push(result);
+    }
+
+    if run_spooler {
+        print_operation_start("Clearing print spooler...", output_mode);
+        let result = clear_print_spooler(dry_run);
+        print_operation_result(&result, output_mode);
+        results.push(result);
+    }
+
     // Explorer should be last as it refreshes the shell
     if run_explorer {
         print_operation_start("Restarting Explorer...", output_mode);
@@ -153,6 +236,9 @@ pub fn run_optimizations(
             (all || network, "--network"),
             (all || bluetooth, "--bluetooth"),
             (all || search, "--search"),
+            (all || spooler, "--spooler"),
+            (search_index, "--search-index"),
+            (all || font_cache, "--font-cache"),
         ]
         .iter()
         .filter(|(requested, _)| *requested)
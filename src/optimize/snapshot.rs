@@ -0,0 +1,190 @@
+//! Before/after measurements for optimize operations.
+//!
+//! Operations that should move a number are measured right before and right
+//! after they run: standby memory for the standby-list clear, DNS cache
+//! entries for the flush and the index size for a search index rebuild. The
+//! Optimize results show the change next to the message. Boot time only
+//! changes on the next boot, so no operation is measured by it.
+
+use super::operations::rebuild_search_index::index_size;
+use super::result::OptimizeResult;
+
+/// What an operation is measured by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    StandbyMemory,
+    DnsCacheEntries,
+    SearchIndexSize,
+}
+
+impl Metric {
+    pub fn label(self) -> &'static str {
+        match self {
+            Metric::StandbyMemory => "Standby memory",
+            Metric::DnsCacheEntries => "DNS cache entries",
+            Metric::SearchIndexSize => "Search index",
+        }
+    }
+
+    fn is_bytes(self) -> bool {
+        !matches!(self, Metric::DnsCacheEntries)
+    }
+
+    fn format(self, value: u64) -> String {
+        if self.is_bytes() {
+            bytesize::to_string(value, false)
+        } else {
+            value.to_string()
+        }
+    }
+
+    /// The current value, or None when it can't be read
+    fn measure(self) -> Option<u64> {
+        match self {
+            Metric::StandbyMemory => standby_memory(),
+            Metric::DnsCacheEntries => dns_cache_entries(),
+            Metric::SearchIndexSize => Some(index_size()),
+        }
+    }
+}
+
+/// A metric measured before and after an operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot {
+    pub metric: Metric,
+    pub before: u64,
+    pub after: u64,
+}
+
+impl Snapshot {
+    /// e.g. "Standby memory 3.1 GB → 410.0 MB (-2.7 GB)"
+    pub fn describe(&self) -> String {
+        let change = if self.after == self.before {
+            "no change".to_string()
+        } else if self.after < self.before {
+            format!("-{}", self.metric.format(self.before - self.after))
+        } else {
+            format!("+{}", self.metric.format(self.after - self.before))
+        };
+        format!(
+            "{} {} → {} ({})",
+            self.metric.label(),
+            self.metric.format(self.before),
+            self.metric.format(self.after),
+            change
+        )
+    }
+}
+
+/// Run an operation, measuring `metric` around it unless it is a dry run
+///
+/// Failed and skipped operations get no snapshot.
+pub(crate) fn measured(
+    metric: Metric,
+    dry_run: bool,
+    run: impl FnOnce() -> OptimizeResult,
+) -> OptimizeResult {
+    let before = if dry_run { None } else { metric.measure() };
+    let mut result = run();
+    if let Some(before) = before {
+        if result.success && !result.message.starts_with("Skipped:") {
+            result.snapshot = metric.measure().map(|after| Snapshot {
+                metric,
+                before,
+                after,
+            });
+        }
+    }
+    result
+}
+
+/// Run a PowerShell expression that prints one whole number
+#[cfg(windows)]
+fn powershell_number(script: &str) -> Option<u64> {
+    let output = std::process::Command::new("powershell")
+        .args(["-NoProfile", "-Command", script])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
+/// Standby list size from the raw memory performance counters
+fn standby_memory() -> Option<u64> {
+    #[cfg(windows)]
+    {
+        powershell_number(
+            "$m = Get-CimInstance Win32_PerfRawData_PerfOS_Memory; \
+             [uint64]$m.StandbyCacheCoreBytes + [uint64]$m.StandbyCacheNormalPriorityBytes + [uint64]$m.StandbyCacheReserveBytes",
+        )
+    }
+
+    #[cfg(not(windows))]
+    {
+        None
+    }
+}
+
+/// Number of records in the DNS client cache
+fn dns_cache_entries() -> Option<u64> {
+    #[cfg(windows)]
+    {
+        powershell_number("@(Get-DnsClientCache).Count")
+    }
+
+    #[cfg(not(windows))]
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        let snapshot = Snapshot {
+            metric: Metric::DnsCacheEntries,
+            before: 312,
+            after: 4,
+        };
+        assert_eq!(snapshot.describe(), "DNS cache entries 312 → 4 (-308)");
+        let snapshot = Snapshot {
+            metric: Metric::DnsCacheEntries,
+            before: 4,
+            after: 4,
+        };
+        assert_eq!(snapshot.describe(), "DNS cache entries 4 → 4 (no change)");
+        let snapshot = Snapshot {
+            metric: Metric::StandbyMemory,
+            before: 1_000,
+            after: 3_000,
+        };
+        assert_eq!(
+            snapshot.describe(),
+            "Standby memory 1.0 KB → 3.0 KB (+2.0 KB)"
+        );
+    }
+
+    #[test]
+    fn test_measured_skips_dry_runs_and_failures() {
+        let result = measured(Metric::SearchIndexSize, true, || {
+            OptimizeResult::success("Rebuild Search Index", "Purged", true)
+        });
+        assert_eq!(result.snapshot, None);
+        let result = measured(Metric::SearchIndexSize, false, || {
+            OptimizeResult::failure("Rebuild Search Index", "Locked", true)
+        });
+        assert_eq!(result.snapshot, None);
+        let result = measured(Metric::SearchIndexSize, false, || {
+            OptimizeResult::success("Rebuild Search Index", "Purged", true)
+        });
+        assert_eq!(
+            result.snapshot.map(|s| s.metric),
+            Some(Metric::SearchIndexSize)
+        );
+    }
+}
@@ -1,11 +1,12 @@
 use crate::cli::ScanOptions;
 use crate::theme::Theme;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 // Forward declaration for duplicate groups
 pub use crate::categories::duplicates::DuplicateGroup;
+pub use crate::categories::trash::TrashEntry;
 
 /// Get emoji for a category name in CLI output
 fn category_emoji(category_name: &str) -> &'static str {
@@ -25,10 +26,46 @@ fn category_emoji(category_name: &str) -> &'static str {
         "Duplicates" => "📋",
         "Windows Update" => "🔄",
         "Event Logs" => "📋",
+        "Thumbnail Cache" => "🖼️",
+        "Crash Dumps" => "💥",
+        "Log Files" => "📜",
+        "Game Caches" => "🎮",
+        "Toolchain Caches" => "🛠️",
+        "Python Environments" => "🐍",
+        "ML Models" => "🧠",
+        "Email Data" => "📧",
+        "Creative App Caches" => "🎬",
+        "Device Backups" => "📱",
+        "Ignored Files (git)" => "🙈",
         _ => "📁", // Default folder emoji
     }
 }
 
+/// Extra per-item detail for verbose listings (dump age, env kind, model last use, email advice, device, repo)
+fn item_detail(category_name: &str, path: &std::path::Path) -> String {
+    match category_name {
+        "Crash Dumps" => crate::categories::crash_dumps::describe(path)
+            .map(|detail| format!(" {}", Theme::muted(&format!("({})", detail))))
+            .unwrap_or_default(),
+        "Python Environments" => crate::categories::python_envs::describe(path)
+            .map(|detail| format!(" {}", Theme::muted(&format!("({})", detail))))
+            .unwrap_or_default(),
+        "ML Models" => crate::categories::ml_models::describe(path)
+            .map(|detail| format!(" {}", Theme::muted(&format!("({})", detail))))
+            .unwrap_or_default(),
+        "Email Data" => crate::categories::email::describe(path)
+            .map(|detail| format!(" {}", Theme::muted(&format!("({})", detail))))
+            .unwrap_or_default(),
+        "Device Backups" => crate::categories::device_backups::describe(path)
+            .map(|detail| format!(" {}", Theme::muted(&format!("({})", detail))))
+            .unwrap_or_default(),
+        "Ignored Files (git)" => crate::categories::git_ignored::repo_name(path)
+            .map(|repo| format!(" {}", Theme::muted(&format!("({})", repo))))
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
 /// Truncate a string to a maximum display width (adds ellipsis if needed).
 fn truncate_to_width(s: &str, max_width: usize) -> String {
     if UnicodeWidthStr::width(s) <= max_width {
@@ -113,17 +150,46 @@ pub struct ScanResults {
     pub duplicates: CategoryResult,
     pub windows_update: CategoryResult,
     pub event_logs: CategoryResult,
+    pub thumbnails: CategoryResult,
+    pub crash_dumps: CategoryResult,
+    pub logs: CategoryResult,
+    pub game_caches: CategoryResult,
+    pub toolchain_caches: CategoryResult,
+    pub python_envs: CategoryResult,
+    pub ml_models: CategoryResult,
+    pub email: CategoryResult,
+    pub creative_caches: CategoryResult,
+    pub device_backups: CategoryResult,
+    pub git_ignored: CategoryResult,
     /// Optional duplicate groups for enhanced display (only populated for duplicates category)
     pub duplicates_groups: Option<Vec<DuplicateGroup>>,
+    /// Recycle Bin entries behind `trash` (only populated for the trash category)
+    pub trash_entries: Vec<TrashEntry>,
+    /// Extra categories reported by external plugins
+    pub plugins: Vec<crate::plugin::PluginCategoryResult>,
+    /// Per-user breakdown of cache/temp/downloads (only for `--scope all-users`)
+    pub users: Vec<crate::users::UserScanResult>,
 }
 
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CategoryResult {
     pub items: usize,
     pub size_bytes: u64,
     pub paths: Vec<PathBuf>,
 }
 
+impl ScanResults {
+    /// Items found by plugin categories
+    pub fn plugin_items(&self) -> usize {
+        self.plugins.iter().map(|p| p.result.items).sum()
+    }
+
+    /// Bytes found by plugin categories
+    pub fn plugin_bytes(&self) -> u64 {
+        self.plugins.iter().map(|p| p.result.size_bytes).sum()
+    }
+}
+
 impl CategoryResult {
     pub fn size_human(&self) -> String {
         bytesize::to_string(self.size_bytes, false)
@@ -135,6 +201,9 @@ struct JsonResults {
     version: String,
     timestamp: String,
     categories: JsonCategories,
+    plugins: Vec<JsonPluginCategory>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    users: Vec<JsonUser>,
     summary: JsonSummary,
 }
 
@@ -155,6 +224,17 @@ struct JsonCategories {
     duplicates: JsonCategory,
     windows_update: JsonCategory,
     event_logs: JsonCategory,
+    thumbnails: JsonCategory,
+    crash_dumps: JsonCategory,
+    logs: JsonCategory,
+    game_caches: JsonCategory,
+    toolchain_caches: JsonCategory,
+    python_envs: JsonCategory,
+    ml_models: JsonCategory,
+    email: JsonCategory,
+    creative_caches: JsonCategory,
+    device_backups: JsonCategory,
+    git_ignored: JsonCategory,
 }
 
 #[derive(Serialize)]
@@ -165,6 +245,43 @@ struct JsonCategory {
     paths: Vec<String>,
 }
 
+#[derive(Serialize)]
+struct JsonPluginCategory {
+    plugin: String,
+    id: String,
+    name: String,
+    safe: bool,
+    #[serde(flatten)]
+    result: JsonCategory,
+}
+
+impl From<&CategoryResult> for JsonCategory {
+    fn from(result: &CategoryResult) -> Self {
+        Self {
+            items: result.items,
+            size_bytes: result.size_bytes,
+            size_human: result.size_human(),
+            paths: result
+                .paths
+                .iter()
+                .map(|p| p.to_string_lossy().to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Per-user breakdown for `--scope all-users`
+#[derive(Serialize)]
+struct JsonUser {
+    user: String,
+    home: String,
+    items: usize,
+    size_bytes: u64,
+    cache: JsonCategory,
+    temp: JsonCategory,
+    downloads: JsonCategory,
+}
+
 #[derive(Serialize)]
 struct JsonSummary {
     total_items: usize,
@@ -232,6 +349,45 @@ pub fn print_human_with_options(
             "[!] Requires admin",
         ),
         ("Event Logs", &results.event_logs, "[!] Requires admin"),
+        (
+            "Thumbnail Cache",
+            &results.thumbnails,
+            "[!] Restarts Explorer",
+        ),
+        ("Crash Dumps", &results.crash_dumps, "[OK] Safe to clean"),
+        ("Log Files", &results.logs, "[!] Review suggested"),
+        (
+            "Game Caches",
+            &results.game_caches,
+            "[OK] Launchers rebuild these",
+        ),
+        (
+            "Toolchain Caches",
+            &results.toolchain_caches,
+            "[OK] Unused for months",
+        ),
+        (
+            "Python Environments",
+            &results.python_envs,
+            "[OK] Inactive projects",
+        ),
+        ("ML Models", &results.ml_models, "[!] Review suggested"),
+        ("Email Data", &results.email, "[i] Report only"),
+        (
+            "Creative App Caches",
+            &results.creative_caches,
+            "[!] Review suggested",
+        ),
+        (
+            "Device Backups",
+            &results.device_backups,
+            "[!] Review suggested",
+        ),
+        (
+            "Ignored Files (git)",
+            &results.git_ignored,
+            "[!] Review suggested",
+        ),
     ];
 
     for (name, result, status) in categories {
@@ -319,7 +475,12 @@ pub fn print_human_with_options(
                     for path in result.paths.iter().take(show_count) {
                         let file_type = crate::utils::detect_file_type(path);
                         let emoji = file_type.emoji();
-                        println!("  {} {}", emoji, Theme::muted(&path.display().to_string()));
+                        println!(
+                            "  {} {}{}",
+                            emoji,
+                            Theme::muted(&path.display().to_string()),
+                            item_detail(name, path)
+                        );
                     }
                     if result.paths.len() > show_count {
                         println!(
@@ -335,13 +496,45 @@ pub fn print_human_with_options(
                     for path in &result.paths {
                         let file_type = crate::utils::detect_file_type(path);
                         let emoji = file_type.emoji();
-                        println!("  {} {}", emoji, Theme::muted(&path.display().to_string()));
+                        println!(
+                            "  {} {}{}",
+                            emoji,
+                            Theme::muted(&path.display().to_string()),
+                            item_detail(name, path)
+                        );
                     }
                 }
             }
         }
     }
 
+    for plugin in &results.plugins {
+        if plugin.result.items > 0 {
+            let status = if plugin.category.safe {
+                Theme::status_safe("[OK] Safe to clean")
+            } else {
+                Theme::status_review("[!] Review suggested")
+            };
+            print_table_row(&[
+                (
+                    Theme::category(&format!("🔌 {}", plugin.display_name())),
+                    col_widths[0],
+                ),
+                (
+                    Theme::value(&plugin.result.items.to_string()),
+                    col_widths[1],
+                ),
+                (Theme::size(&plugin.result.size_human()), col_widths[2]),
+                (status, col_widths[3]),
+            ]);
+            if mode == OutputMode::VeryVerbose {
+                for path in &plugin.result.paths {
+                    println!("  {}", Theme::muted(&path.display().to_string()));
+                }
+            }
+        }
+    }
+
     let total_items = results.cache.items
         + results.app_cache.items
         + results.temp.items
@@ -356,7 +549,19 @@ pub fn print_human_with_options(
         + results.empty.items
         + results.duplicates.items
         + results.windows_update.items
-        + results.event_logs.items;
+        + results.event_logs.items
+        + results.thumbnails.items
+        + results.crash_dumps.items
+        + results.logs.items
+        + results.game_caches.items
+        + results.toolchain_caches.items
+        + results.python_envs.items
+        + results.ml_models.items
+        + results.email.items
+        + results.creative_caches.items
+        + results.device_backups.items
+        + results.git_ignored.items
+        + results.plugin_items();
     let total_bytes = results.cache.size_bytes
         + results.app_cache.size_bytes
         + results.temp.size_bytes
@@ -371,7 +576,19 @@ pub fn print_human_with_options(
         + results.empty.size_bytes
         + results.duplicates.size_bytes
         + results.windows_update.size_bytes
-        + results.event_logs.size_bytes;
+        + results.event_logs.size_bytes
+        + results.thumbnails.size_bytes
+        + results.crash_dumps.size_bytes
+        + results.logs.size_bytes
+        + results.game_caches.size_bytes
+        + results.toolchain_caches.size_bytes
+        + results.python_envs.size_bytes
+        + results.ml_models.size_bytes
+        + results.email.size_bytes
+        + results.creative_caches.size_bytes
+        + results.device_backups.size_bytes
+        + results.git_ignored.size_bytes
+        + results.plugin_bytes();
 
     if total_items == 0 {
         print_table_separator(&col_widths, "└", "┴", "┘");
@@ -401,6 +618,46 @@ pub fn print_human_with_options(
         );
     }
     println!();
+
+    print_user_breakdown(results);
+}
+
+/// Per-user share of cache/temp/downloads (`--scope all-users` only)
+fn print_user_breakdown(results: &ScanResults) {
+    if results.users.is_empty() {
+        return;
+    }
+
+    println!("{}", Theme::header("By user"));
+    for user in &results.users {
+        let mut parts = Vec::new();
+        for (label, category) in [
+            ("cache", &user.cache),
+            ("temp", &user.temp),
+            ("downloads", &user.downloads),
+        ] {
+            if category.items > 0 {
+                parts.push(format!("{} {}", label, category.size_human()));
+            }
+        }
+        let detail = if parts.is_empty() {
+            Theme::muted("nothing found")
+        } else {
+            Theme::muted(&parts.join(", "))
+        };
+        println!(
+            "  {} {:<20} {:>6} items  {}  {}",
+            Theme::muted("•"),
+            user.user,
+            user.items(),
+            Theme::size(&format!(
+                "{:>10}",
+                bytesize::to_string(user.size_bytes(), false)
+            )),
+            detail
+        );
+    }
+    println!();
 }
 
 /// Build a clean command based on the scan options used
@@ -408,6 +665,11 @@ fn build_clean_command(options: Option<&ScanOptions>) -> String {
     let Some(opts) = options else {
         return "wole clean --all".to_string();
     };
+    let scope = if opts.scope == crate::users::ScanScope::AllUsers {
+        " --scope all-users"
+    } else {
+        ""
+    };
 
     // Count how many categories are enabled
     let enabled_count = [
@@ -426,14 +688,25 @@ fn build_clean_command(options: Option<&ScanOptions>) -> String {
         opts.duplicates,
         opts.windows_update,
         opts.event_logs,
+        opts.thumbnails,
+        opts.crash_dumps,
+        opts.logs,
+        opts.game_caches,
+        opts.toolchain_caches,
+        opts.python_envs,
+        opts.ml_models,
+        opts.email,
+        opts.creative_caches,
+        opts.device_backups,
+        opts.git_ignored,
     ]
     .iter()
     .filter(|&&x| x)
     .count();
 
     // If all categories are enabled, use --all
-    if enabled_count == 15 {
-        return "wole clean --all".to_string();
+    if enabled_count == 26 {
+        return format!("wole clean --all{}", scope);
     }
 
     // Build command with specific flags
@@ -483,13 +756,46 @@ fn build_clean_command(options: Option<&ScanOptions>) -> String {
     if opts.event_logs {
         flags.push("--event-logs");
     }
+    if opts.thumbnails {
+        flags.push("--thumbnails");
+    }
+    if opts.crash_dumps {
+        flags.push("--crash-dumps");
+    }
+    if opts.logs {
+        flags.push("--logs");
+    }
+    if opts.game_caches {
+        flags.push("--game-caches");
+    }
+    if opts.toolchain_caches {
+        flags.push("--toolchain-caches");
+    }
+    if opts.python_envs {
+        flags.push("--python-envs");
+    }
+    if opts.ml_models {
+        flags.push("--ml-models");
+    }
+    if opts.email {
+        flags.push("--email");
+    }
+    if opts.creative_caches {
+        flags.push("--creative-caches");
+    }
+    if opts.device_backups {
+        flags.push("--device-backups");
+    }
+    if opts.git_ignored {
+        flags.push("--git-ignored");
+    }
 
     // If no flags (shouldn't happen, but be safe), fall back to --all
     if flags.is_empty() {
-        return "wole clean --all".to_string();
+        return format!("wole clean --all{}", scope);
     }
 
-    format!("wole clean {}", flags.join(" "))
+    format!("wole clean {}{}", flags.join(" "), scope)
 }
 
 pub fn print_json(results: &ScanResults) -> anyhow::Result<()> {
@@ -662,7 +968,162 @@ pub fn print_json(results: &ScanResults) -> anyhow::Result<()> {
                     .map(|p| p.to_string_lossy().to_string())
                     .collect(),
             },
+            thumbnails: JsonCategory {
+                items: results.thumbnails.items,
+                size_bytes: results.thumbnails.size_bytes,
+                size_human: results.thumbnails.size_human(),
+                paths: results
+                    .thumbnails
+                    .paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            },
+            crash_dumps: JsonCategory {
+                items: results.crash_dumps.items,
+                size_bytes: results.crash_dumps.size_bytes,
+                size_human: results.crash_dumps.size_human(),
+                paths: results
+                    .crash_dumps
+                    .paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            },
+            logs: JsonCategory {
+                items: results.logs.items,
+                size_bytes: results.logs.size_bytes,
+                size_human: results.logs.size_human(),
+                paths: results
+                    .logs
+                    .paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            },
+            game_caches: JsonCategory {
+                items: results.game_caches.items,
+                size_bytes: results.game_caches.size_bytes,
+                size_human: results.game_caches.size_human(),
+                paths: results
+                    .game_caches
+                    .paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            },
+            toolchain_caches: JsonCategory {
+                items: results.toolchain_caches.items,
+                size_bytes: results.toolchain_caches.size_bytes,
+                size_human: results.toolchain_caches.size_human(),
+                paths: results
+                    .toolchain_caches
+                    .paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            },
+            python_envs: JsonCategory {
+                items: results.python_envs.items,
+                size_bytes: results.python_envs.size_bytes,
+                size_human: results.python_envs.size_human(),
+                paths: results
+                    .python_envs
+                    .paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            },
+            ml_models: JsonCategory {
+                items: results.ml_models.items,
+                size_bytes: results.ml_models.size_bytes,
+                size_human: results.ml_models.size_human(),
+                paths: results
+                    .ml_models
+                    .paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            },
+            email: JsonCategory {
+                items: results.email.items,
+                size_bytes: results.email.size_bytes,
+                size_human: results.email.size_human(),
+                paths: results
+                    .email
+                    .paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            },
+            creative_caches: JsonCategory {
+                items: results.creative_caches.items,
+                size_bytes: results.creative_caches.size_bytes,
+                size_human: results.creative_caches.size_human(),
+                paths: results
+                    .creative_caches
+                    .paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            },
+            device_backups: JsonCategory {
+                items: results.device_backups.items,
+                size_bytes: results.device_backups.size_bytes,
+                size_human: results.device_backups.size_human(),
+                paths: results
+                    .device_backups
+                    .paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            },
+            git_ignored: JsonCategory {
+                items: results.git_ignored.items,
+                size_bytes: results.git_ignored.size_bytes,
+                size_human: results.git_ignored.size_human(),
+                paths: results
+                    .git_ignored
+                    .paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            },
         },
+        plugins: results
+            .plugins
+            .iter()
+            .map(|p| JsonPluginCategory {
+                plugin: p.plugin.name.clone(),
+                id: p.category.id.clone(),
+                name: p.category.name.clone(),
+                safe: p.category.safe,
+                result: JsonCategory {
+                    items: p.result.items,
+                    size_bytes: p.result.size_bytes,
+                    size_human: p.result.size_human(),
+                    paths: p
+                        .result
+                        .paths
+                        .iter()
+                        .map(|p| p.to_string_lossy().to_string())
+                        .collect(),
+                },
+            })
+            .collect(),
+        users: results
+            .users
+            .iter()
+            .map(|u| JsonUser {
+                user: u.user.clone(),
+                home: u.home.to_string_lossy().to_string(),
+                items: u.items(),
+                size_bytes: u.size_bytes(),
+                cache: (&u.cache).into(),
+                temp: (&u.temp).into(),
+                downloads: (&u.downloads).into(),
+            })
+            .collect(),
         summary: JsonSummary {
             total_items: results.cache.items
                 + results.app_cache.items
@@ -678,7 +1139,19 @@ pub fn print_json(results: &ScanResults) -> anyhow::Result<()> {
                 + results.empty.items
                 + results.duplicates.items
                 + results.windows_update.items
-                + results.event_logs.items,
+                + results.event_logs.items
+                + results.thumbnails.items
+                + results.crash_dumps.items
+                + results.logs.items
+                + results.game_caches.items
+                + results.toolchain_caches.items
+                + results.python_envs.items
+                + results.ml_models.items
+                + results.email.items
+                + results.creative_caches.items
+                + results.device_backups.items
+                + results.git_ignored.items
+                + results.plugin_items(),
             total_bytes: results.cache.size_bytes
                 + results.app_cache.size_bytes
                 + results.temp.size_bytes
@@ -693,7 +1166,19 @@ pub fn print_json(results: &ScanResults) -> anyhow::Result<()> {
                 + results.empty.size_bytes
                 + results.duplicates.size_bytes
                 + results.windows_update.size_bytes
-                + results.event_logs.size_bytes,
+                + results.event_logs.size_bytes
+                + results.thumbnails.size_bytes
+                + results.crash_dumps.size_bytes
+                + results.logs.size_bytes
+                + results.game_caches.size_bytes
+                + results.toolchain_caches.size_bytes
+                + results.python_envs.size_bytes
+                + results.ml_models.size_bytes
+                + results.email.size_bytes
+                + results.creative_caches.size_bytes
+                + results.device_backups.size_bytes
+                + results.git_ignored.size_bytes
+                + results.plugin_bytes(),
             total_human: bytesize::to_string(
                 results.cache.size_bytes
                     + results.app_cache.size_bytes
@@ -709,7 +1194,19 @@ pub fn print_json(results: &ScanResults) -> anyhow::Result<()> {
                     + results.empty.size_bytes
                     + results.duplicates.size_bytes
                     + results.windows_update.size_bytes
-                    + results.event_logs.size_bytes,
+                    + results.event_logs.size_bytes
+                    + results.thumbnails.size_bytes
+                    + results.crash_dumps.size_bytes
+                    + results.logs.size_bytes
+                    + results.game_caches.size_bytes
+                    + results.toolchain_caches.size_bytes
+                    + results.python_envs.size_bytes
+                    + results.ml_models.size_bytes
+                    + results.email.size_bytes
+                    + results.creative_caches.size_bytes
+                    + results.device_backups.size_bytes
+                    + results.git_ignored.size_bytes
+                    + results.plugin_bytes(),
                 true,
             ),
         },
@@ -719,6 +1216,37 @@ pub fn print_json(results: &ScanResults) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Write every deletable path in `results` NUL-terminated (`wole scan --print0`)
+///
+/// The format `xargs -0`, `fzf --read0` and `wole clean --from-stdin` read.
+/// Email data is report-only and left out.
+pub fn write_paths0(results: &ScanResults, out: &mut impl std::io::Write) -> std::io::Result<()> {
+    let builtin = crate::api::Category::ALL
+        .into_iter()
+        .filter(|category| *category != crate::api::Category::Email)
+        .map(|category| category.result(results));
+    let plugins = results.plugins.iter().map(|plugin| &plugin.result);
+    for result in builtin.chain(plugins) {
+        for path in &result.paths {
+            out.write_all(path.to_string_lossy().as_bytes())?;
+            out.write_all(b"\0")?;
+        }
+    }
+    out.flush()
+}
+
+/// Paths piped to `wole clean --from-stdin`: NUL-separated if there's a NUL
+/// anywhere, one per line otherwise. Blank entries are dropped.
+pub fn parse_path_list(input: &[u8]) -> Vec<PathBuf> {
+    let text = String::from_utf8_lossy(input);
+    let separator = if text.contains('\0') { '\0' } else { '\n' };
+    text.split(separator)
+        .map(|entry| entry.trim_end_matches('\r'))
+        .filter(|entry| !entry.trim().is_empty())
+        .map(PathBuf::from)
+        .collect()
+}
+
 pub fn print_analyze(results: &ScanResults, mode: OutputMode) {
     if mode == OutputMode::Quiet {
         return;
@@ -734,6 +1262,17 @@ pub fn print_analyze(results: &ScanResults, mode: OutputMode) {
         ("Large Files", &results.large),
         ("Windows Update", &results.windows_update),
         ("Event Logs", &results.event_logs),
+        ("Thumbnail Cache", &results.thumbnails),
+        ("Crash Dumps", &results.crash_dumps),
+        ("Log Files", &results.logs),
+        ("Game Caches", &results.game_caches),
+        ("Toolchain Caches", &results.toolchain_caches),
+        ("Python Environments", &results.python_envs),
+        ("ML Models", &results.ml_models),
+        ("Email Data", &results.email),
+        ("Creative App Caches", &results.creative_caches),
+        ("Device Backups", &results.device_backups),
+        ("Ignored Files (git)", &results.git_ignored),
         ("System Cache", &results.system),
         ("Build Artifacts", &results.build),
         ("Old Downloads", &results.downloads),
@@ -858,6 +1397,14 @@ pub fn print_analyze(results: &ScanResults, mode: OutputMode) {
         }
     }
 
+    for plugin in results.plugins.iter().filter(|p| p.result.items > 0) {
+        print_table_row(&[
+            (format!("🔌 {}", plugin.display_name()), col_widths[0]),
+            (format_number(plugin.result.items as u64), col_widths[1]),
+            (plugin.result.size_human(), col_widths[2]),
+        ]);
+    }
+
     // Calculate totals
     let total_items = results.cache.items
         + results.app_cache.items
@@ -873,7 +1420,19 @@ pub fn print_analyze(results: &ScanResults, mode: OutputMode) {
         + results.empty.items
         + results.duplicates.items
         + results.windows_update.items
-        + results.event_logs.items;
+        + results.event_logs.items
+        + results.thumbnails.items
+        + results.crash_dumps.items
+        + results.logs.items
+        + results.game_caches.items
+        + results.toolchain_caches.items
+        + results.python_envs.items
+        + results.ml_models.items
+        + results.email.items
+        + results.creative_caches.items
+        + results.device_backups.items
+        + results.git_ignored.items
+        + results.plugin_items();
     let total_bytes = results.cache.size_bytes
         + results.app_cache.size_bytes
         + results.temp.size_bytes
@@ -888,7 +1447,19 @@ pub fn print_analyze(results: &ScanResults, mode: OutputMode) {
         + results.empty.size_bytes
         + results.duplicates.size_bytes
         + results.windows_update.size_bytes
-        + results.event_logs.size_bytes;
+        + results.event_logs.size_bytes
+        + results.thumbnails.size_bytes
+        + results.crash_dumps.size_bytes
+        + results.logs.size_bytes
+        + results.game_caches.size_bytes
+        + results.toolchain_caches.size_bytes
+        + results.python_envs.size_bytes
+        + results.ml_models.size_bytes
+        + results.email.size_bytes
+        + results.creative_caches.size_bytes
+        + results.device_backups.size_bytes
+        + results.git_ignored.size_bytes
+        + results.plugin_bytes();
 
     // Print separator and total
     print_table_separator(&col_widths, "├", "┼", "┤");
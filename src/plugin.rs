@@ -0,0 +1,438 @@
+//! External category plugins
+//!
+//! Any executable placed in `%APPDATA%\wole\plugins` can add categories to a scan.
+//! wole runs each plugin once per scan and once per clean, writing a single JSON
+//! request to its stdin and reading a single JSON response from its stdout:
+//!
+//! ```text
+//! → {"protocol":1,"command":"scan","scan_path":"C:\\Users\\me","thresholds":{...}}
+//! ← {"categories":[{"id":"docker","name":"Docker Images","safe":false,
+//!                   "items":[{"path":"D:\\docker\\img1","size_bytes":1048576}]}]}
+//!
+//! → {"protocol":1,"command":"clean","category":"docker","paths":["D:\\docker\\img1"],"permanent":false}
+//! ← {"cleaned":["D:\\docker\\img1"],"failed":[]}
+//! ```
+//!
+//! wole never deletes plugin items itself: cleaning is handed back to the plugin
+//! that reported them. Anything written to stderr is only used in error messages.
+//! The request/response types below are the protocol; plugins written in Rust can
+//! depend on this crate and deserialize them directly.
+
+use crate::config::Config;
+use crate::output::CategoryResult;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Protocol version sent with every request
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Scan thresholds passed to plugins (mirrors the `[thresholds]` config / CLI flags)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginThresholds {
+    pub project_age_days: u64,
+    pub min_age_days: u64,
+    pub min_size_bytes: u64,
+}
+
+impl From<&crate::cli::ScanOptions> for PluginThresholds {
+    fn from(options: &crate::cli::ScanOptions) -> Self {
+        Self {
+            project_age_days: options.project_age_days,
+            min_age_days: options.min_age_days,
+            min_size_bytes: options.min_size_bytes,
+        }
+    }
+}
+
+/// A request written to a plugin's stdin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum PluginCommand {
+    /// Report reclaimable items
+    Scan {
+        scan_path: PathBuf,
+        thresholds: PluginThresholds,
+    },
+    /// Remove the selected items of one category
+    Clean {
+        category: String,
+        paths: Vec<PathBuf>,
+        /// False means the user expects the items to be recoverable (Recycle Bin)
+        permanent: bool,
+    },
+}
+
+/// Envelope for every request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginRequest {
+    pub protocol: u32,
+    #[serde(flatten)]
+    pub command: PluginCommand,
+}
+
+/// One reclaimable item reported by a plugin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginItem {
+    pub path: PathBuf,
+    pub size_bytes: u64,
+}
+
+/// A category registered by a plugin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginCategory {
+    /// Stable identifier, sent back in clean requests
+    pub id: String,
+    /// Display name
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Whether items are safe to clean without review (pre-selected in the TUI)
+    #[serde(default)]
+    pub safe: bool,
+    #[serde(default)]
+    pub items: Vec<PluginItem>,
+}
+
+/// Response to a scan request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PluginScanResponse {
+    #[serde(default)]
+    pub categories: Vec<PluginCategory>,
+}
+
+/// A path the plugin failed to clean
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginFailure {
+    pub path: PathBuf,
+    pub error: String,
+}
+
+/// Response to a clean request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PluginCleanResponse {
+    #[serde(default)]
+    pub cleaned: Vec<PathBuf>,
+    #[serde(default)]
+    pub failed: Vec<PluginFailure>,
+}
+
+/// An executable found in the plugins directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plugin {
+    /// File stem of the executable (e.g. "docker" for docker.exe)
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// A plugin category merged into `ScanResults`
+#[derive(Debug, Clone)]
+pub struct PluginCategoryResult {
+    pub plugin: Plugin,
+    pub category: PluginCategory,
+    pub result: CategoryResult,
+}
+
+impl PluginCategoryResult {
+    /// Name shown in results ("Docker Images (docker)")
+    pub fn display_name(&self) -> String {
+        format!("{} ({})", self.category.name, self.plugin.name)
+    }
+
+    /// Size the plugin reported for `path` (0 if it wasn't one of its items)
+    pub fn item_size(&self, path: &Path) -> u64 {
+        self.category
+            .items
+            .iter()
+            .find(|item| item.path == path)
+            .map(|item| item.size_bytes)
+            .unwrap_or(0)
+    }
+}
+
+/// Default plugins directory: `%APPDATA%\wole\plugins`
+pub fn plugins_dir() -> Result<PathBuf> {
+    let config_path = Config::config_path()?;
+    Ok(config_path
+        .parent()
+        .map(|p| p.join("plugins"))
+        .unwrap_or_else(|| PathBuf::from("plugins")))
+}
+
+/// List plugin executables in `dir`, sorted by name (missing dir = no plugins)
+pub fn discover_plugins(dir: &Path) -> Vec<Plugin> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut plugins: Vec<Plugin> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && is_executable(p))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().to_string();
+            Some(Plugin { name, path })
+        })
+        .collect();
+    plugins.sort_by(|a, b| a.name.cmp(&b.name));
+    plugins
+}
+
+#[cfg(windows)]
+fn is_executable(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| matches!(e.to_lowercase().as_str(), "exe" | "bat" | "cmd" | "com"))
+        .unwrap_or(false)
+}
+
+#[cfg(not(windows))]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|m| m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+/// Run a plugin with one request and parse its response
+fn invoke<T: for<'de> Deserialize<'de>>(
+    plugin: &Plugin,
+    command: PluginCommand,
+    timeout: Duration,
+) -> Result<T> {
+    let request = serde_json::to_vec(&PluginRequest {
+        protocol: PROTOCOL_VERSION,
+        command,
+    })?;
+
+    let mut child = Command::new(&plugin.path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to start plugin {}", plugin.path.display()))?;
+
+    // Pipes are serviced on threads so neither side can block on a full buffer.
+    // Dropping stdin after the write tells the plugin the request is complete; a
+    // plugin that exits without reading it is not an error in itself.
+    let mut stdin = child.stdin.take().context("Plugin stdin unavailable")?;
+    std::thread::spawn(move || {
+        let _ = stdin.write_all(&request);
+    });
+    let mut stdout = child.stdout.take().context("Plugin stdout unavailable")?;
+    let mut stderr = child.stderr.take().context("Plugin stderr unavailable")?;
+    let out_thread = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stdout.read_to_end(&mut buf);
+        buf
+    });
+    let err_thread = std::thread::spawn(move || {
+        let mut buf = String::new();
+        let _ = stderr.read_to_string(&mut buf);
+        buf
+    });
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if start.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            bail!(
+                "Plugin {} timed out after {}s",
+                plugin.name,
+                timeout.as_secs()
+            );
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    };
+
+    let output = out_thread.join().unwrap_or_default();
+    let errors = err_thread.join().unwrap_or_default();
+    if !status.success() {
+        bail!(
+            "Plugin {} failed ({}): {}",
+            plugin.name,
+            status,
+            errors.trim()
+        );
+    }
+
+    serde_json::from_slice(&output)
+        .with_context(|| format!("Plugin {} returned invalid JSON", plugin.name))
+}
+
+/// Ask one plugin for its categories.
+///
+/// Items that don't exist, are system paths, or match exclusions are dropped, so a
+/// plugin can't put anything in front of the user that wole's own scanners wouldn't.
+pub fn scan_plugin(
+    plugin: &Plugin,
+    scan_path: &Path,
+    thresholds: &PluginThresholds,
+    config: &Config,
+) -> Result<Vec<PluginCategoryResult>> {
+    let response: PluginScanResponse = invoke(
+        plugin,
+        PluginCommand::Scan {
+            scan_path: scan_path.to_path_buf(),
+            thresholds: thresholds.clone(),
+        },
+        Duration::from_secs(config.plugins.timeout_secs.max(1)),
+    )?;
+
+    Ok(response
+        .categories
+        .into_iter()
+        .filter_map(|mut category| {
+            category.items.retain(|item| {
+                item.path.exists()
+                    && !crate::utils::is_system_path(&item.path)
+                    && !config.is_excluded(&item.path)
+            });
+            if category.items.is_empty() {
+                return None;
+            }
+
+            let result = CategoryResult {
+                items: category.items.len(),
+                size_bytes: category.items.iter().map(|i| i.size_bytes).sum(),
+                paths: category.items.iter().map(|i| i.path.clone()).collect(),
+            };
+            Some(PluginCategoryResult {
+                plugin: plugin.clone(),
+                category,
+                result,
+            })
+        })
+        .collect())
+}
+
+/// Run every discovered plugin; failures are reported as warnings and skipped
+pub fn scan_all_plugins(
+    scan_path: &Path,
+    thresholds: &PluginThresholds,
+    config: &Config,
+    mut on_error: impl FnMut(&Plugin, &anyhow::Error),
+) -> Vec<PluginCategoryResult> {
+    if !config.plugins.enabled {
+        return Vec::new();
+    }
+    let Ok(dir) = plugins_dir() else {
+        return Vec::new();
+    };
+
+    let mut results = Vec::new();
+    for plugin in discover_plugins(&dir) {
+        match scan_plugin(&plugin, scan_path, thresholds, config) {
+            Ok(categories) => results.extend(categories),
+            Err(e) => on_error(&plugin, &e),
+        }
+    }
+    results
+}
+
+/// Hand selected items of a plugin category back to the plugin for cleaning
+pub fn clean_with_plugin(
+    plugin: &Plugin,
+    category_id: &str,
+    paths: &[PathBuf],
+    permanent: bool,
+    config: &Config,
+) -> Result<PluginCleanResponse> {
+    invoke(
+        plugin,
+        PluginCommand::Clean {
+            category: category_id.to_string(),
+            paths: paths.to_vec(),
+            permanent,
+        },
+        Duration::from_secs(config.plugins.timeout_secs.max(1)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_wire_format() {
+        let request = PluginRequest {
+            protocol: PROTOCOL_VERSION,
+            command: PluginCommand::Clean {
+                category: "docker".to_string(),
+                paths: vec![PathBuf::from("img1")],
+                permanent: false,
+            },
+        };
+        let json: serde_json::Value = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["protocol"], 1);
+        assert_eq!(json["command"], "clean");
+        assert_eq!(json["category"], "docker");
+        assert_eq!(json["paths"][0], "img1");
+    }
+
+    #[test]
+    fn test_scan_response_defaults() {
+        let response: PluginScanResponse = serde_json::from_str(
+            r#"{"categories":[{"id":"docker","name":"Docker Images","items":[{"path":"a","size_bytes":5}]}]}"#,
+        )
+        .unwrap();
+        let category = &response.categories[0];
+        assert!(!category.safe);
+        assert_eq!(category.description, None);
+        assert_eq!(category.items[0].size_bytes, 5);
+    }
+
+    #[test]
+    fn test_discover_missing_dir() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        assert!(discover_plugins(&temp_dir.path().join("plugins")).is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_and_clean_round_trip() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let item = temp_dir.path().join("blob.bin");
+        std::fs::write(&item, b"data").unwrap();
+
+        let script = temp_dir.path().join("fake");
+        std::fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\nif grep -q '\"scan\"'; then\n  echo '{{\"categories\":[{{\"id\":\"blobs\",\"name\":\"Blobs\",\"items\":[{{\"path\":\"{0}\",\"size_bytes\":4}},{{\"path\":\"/nonexistent/x\",\"size_bytes\":9}}]}}]}}'\nelse\n  echo '{{\"cleaned\":[\"{0}\"]}}'\nfi\n",
+                item.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let plugins = discover_plugins(temp_dir.path());
+        assert_eq!(plugins.len(), 1);
+        assert_eq!(plugins[0].name, "fake");
+
+        let config = Config::default();
+        let thresholds = PluginThresholds {
+            project_age_days: 14,
+            min_age_days: 30,
+            min_size_bytes: 0,
+        };
+        let results = scan_plugin(&plugins[0], temp_dir.path(), &thresholds, &config).unwrap();
+        assert_eq!(results.len(), 1);
+        // The missing path is dropped
+        assert_eq!(results[0].result.paths, vec![item.clone()]);
+        assert_eq!(results[0].display_name(), "Blobs (fake)");
+
+        let cleaned =
+            clean_with_plugin(&plugins[0], "blobs", &[item.clone()], false, &config).unwrap();
+        assert_eq!(cleaned.cleaned, vec![item]);
+    }
+}
@@ -0,0 +1,303 @@
+//! Machine policy for managed (fleet) installs
+//!
+//! Administrators can deploy a `policy.toml` that overrides the user's config:
+//!
+//! - Windows: the file named by the `PolicyFile` value under
+//!   `HKLM\SOFTWARE\Policies\wole` (set through Group Policy), otherwise
+//!   `%ProgramData%\wole\policy.toml`
+//! - elsewhere: `/etc/wole/policy.toml`
+//!
+//! ```toml
+//! allow_permanent_delete = false     # everything goes to the Recycle Bin
+//! exclusions = ["**/Projects/**"]    # added to every user's exclusions
+//! tamper_evident_history = true      # force hash-chained history
+//!
+//! [report]                           # needs the `fleet-report` feature
+//! url = "https://fleet.example.com/wole/reports"
+//! token = "..."                      # sent as a Bearer token
+//! ```
+//!
+//! The policy is enforced by the cleaner itself (see [`permanent_allowed`] and
+//! [`Policy::is_excluded`]), so no front end can bypass it. A policy file that
+//! exists but can't be parsed locks permanent deletes rather than silently
+//! allowing everything.
+
+use crate::cleaner::CleanSummary;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Where to send clean summaries
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReportSink {
+    /// HTTPS endpoint that receives a JSON [`CleanReport`] per clean
+    pub url: String,
+    /// Sent as `Authorization: Bearer <token>`
+    #[serde(default)]
+    pub token: Option<String>,
+    #[serde(default = "default_report_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_report_timeout() -> u64 {
+    10
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Settings locked by the administrator
+#[derive(Debug, Deserialize)]
+pub struct Policy {
+    /// false = permanent deletes are turned into Recycle Bin deletes
+    #[serde(default = "default_true")]
+    pub allow_permanent_delete: bool,
+    /// Exclusion globs applied on top of the user's `[exclusions]`
+    #[serde(default)]
+    pub exclusions: Vec<String>,
+    /// Force `history.tamper_evident` on
+    #[serde(default)]
+    pub tamper_evident_history: bool,
+    #[serde(default)]
+    pub report: Option<ReportSink>,
+    /// File the policy was loaded from (None = no policy)
+    #[serde(skip)]
+    pub source: Option<PathBuf>,
+    #[serde(skip)]
+    compiled: OnceLock<Option<GlobSet>>,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            allow_permanent_delete: true,
+            exclusions: Vec::new(),
+            tamper_evident_history: false,
+            report: None,
+            source: None,
+            compiled: OnceLock::new(),
+        }
+    }
+}
+
+impl Policy {
+    /// Parse a policy file's contents
+    pub fn parse(content: &str) -> Result<Self> {
+        toml::from_str(content).context("Invalid policy file")
+    }
+
+    /// Whether the administrator's exclusions cover `path`
+    pub fn is_excluded(&self, path: &Path) -> bool {
+        let compiled = self.compiled.get_or_init(|| {
+            if self.exclusions.is_empty() {
+                return None;
+            }
+            let mut builder = GlobSetBuilder::new();
+            for pattern in &self.exclusions {
+                let normalized = if pattern.starts_with("**/") || pattern.starts_with('/') {
+                    pattern.clone()
+                } else {
+                    format!("**/{}", pattern)
+                };
+                if let Ok(glob) = Glob::new(&normalized.replace('\\', "/")) {
+                    builder.add(glob);
+                }
+            }
+            builder.build().ok()
+        });
+        compiled.as_ref().is_some_and(|set| {
+            set.is_match(path) || set.is_match(path.to_string_lossy().replace('\\', "/"))
+        })
+    }
+
+    /// One-line description for `wole config --show`
+    pub fn describe(&self) -> String {
+        let Some(ref source) = self.source else {
+            return "none".to_string();
+        };
+        let mut locks = Vec::new();
+        if !self.allow_permanent_delete {
+            locks.push("no permanent delete".to_string());
+        }
+        if !self.exclusions.is_empty() {
+            locks.push(format!("{} forced exclusion(s)", self.exclusions.len()));
+        }
+        if self.tamper_evident_history {
+            locks.push("tamper-evident history".to_string());
+        }
+        if self.report.is_some() {
+            locks.push("reports cleans".to_string());
+        }
+        if locks.is_empty() {
+            format!("{} (no locks)", source.display())
+        } else {
+            format!("{} ({})", source.display(), locks.join(", "))
+        }
+    }
+}
+
+#[cfg(windows)]
+fn registry_policy_path() -> Option<PathBuf> {
+    use winreg::enums::HKEY_LOCAL_MACHINE;
+    use winreg::RegKey;
+
+    let key = RegKey::predef(HKEY_LOCAL_MACHINE)
+        .open_subkey("SOFTWARE\\Policies\\wole")
+        .ok()?;
+    let path: String = key.get_value("PolicyFile").ok()?;
+    let path = path.trim();
+    (!path.is_empty()).then(|| PathBuf::from(path))
+}
+
+/// Location of the machine policy file
+pub fn policy_path() -> PathBuf {
+    #[cfg(windows)]
+    {
+        if let Some(path) = registry_policy_path() {
+            return path;
+        }
+        let program_data =
+            std::env::var("ProgramData").unwrap_or_else(|_| r"C:\ProgramData".to_string());
+        PathBuf::from(program_data).join("wole").join("policy.toml")
+    }
+
+    #[cfg(not(windows))]
+    {
+        PathBuf::from("/etc/wole/policy.toml")
+    }
+}
+
+fn load() -> Policy {
+    let path = policy_path();
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(_) => return Policy::default(),
+    };
+    let mut policy = match Policy::parse(&content) {
+        Ok(policy) => policy,
+        Err(e) => {
+            eprintln!(
+                "Warning: Failed to parse policy file {}: {:#}. Permanent deletes are disabled.",
+                path.display(),
+                e
+            );
+            Policy {
+                allow_permanent_delete: false,
+                ..Policy::default()
+            }
+        }
+    };
+    policy.source = Some(path);
+    policy
+}
+
+/// The machine policy (loaded once per process)
+pub fn current() -> &'static Policy {
+    static POLICY: OnceLock<Policy> = OnceLock::new();
+    POLICY.get_or_init(load)
+}
+
+/// `permanent`, unless the policy forbids permanent deletes
+pub fn permanent_allowed(permanent: bool) -> bool {
+    permanent && current().allow_permanent_delete
+}
+
+/// JSON body posted to the report sink after each clean
+#[derive(Debug, Clone, Serialize)]
+pub struct CleanReport {
+    pub host: String,
+    pub user: String,
+    pub version: String,
+    pub timestamp: DateTime<Utc>,
+    pub cleaned: u64,
+    pub cleaned_bytes: u64,
+    pub errors: u64,
+    pub cancelled: bool,
+    pub permanent: bool,
+}
+
+impl CleanReport {
+    pub fn new(summary: &CleanSummary, permanent: bool) -> Self {
+        Self {
+            host: sysinfo::System::host_name().unwrap_or_else(|| "unknown".to_string()),
+            user: std::env::var("USERNAME")
+                .or_else(|_| std::env::var("USER"))
+                .unwrap_or_default(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            timestamp: Utc::now(),
+            cleaned: summary.cleaned,
+            cleaned_bytes: summary.cleaned_bytes,
+            errors: summary.errors,
+            cancelled: summary.cancelled,
+            permanent,
+        }
+    }
+}
+
+/// Post a clean summary to the policy's report sink (no-op without one)
+#[cfg(feature = "fleet-report")]
+pub fn send_report(report: &CleanReport) -> Result<()> {
+    let Some(ref sink) = current().report else {
+        return Ok(());
+    };
+    if !sink.url.starts_with("https://") {
+        anyhow::bail!("Report sink must use HTTPS: {}", sink.url);
+    }
+
+    let mut request = ureq::post(&sink.url)
+        .timeout(std::time::Duration::from_secs(sink.timeout_secs))
+        .set("User-Agent", concat!("wole/", env!("CARGO_PKG_VERSION")));
+    if let Some(ref token) = sink.token {
+        request = request.set("Authorization", &format!("Bearer {}", token));
+    }
+    request
+        .send_json(report)
+        .with_context(|| format!("Failed to send clean report to {}", sink.url))?;
+    Ok(())
+}
+
+/// Post a clean summary to the policy's report sink (built without `fleet-report`)
+#[cfg(not(feature = "fleet-report"))]
+pub fn send_report(_report: &CleanReport) -> Result<()> {
+    if current().report.is_some() {
+        crate::debug_log::cleaning_log(
+            "policy has a report sink but wole was built without the fleet-report feature",
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_policy() {
+        let policy = Policy::parse(
+            r#"
+allow_permanent_delete = false
+exclusions = ["**/Projects/**", "keep-me"]
+
+[report]
+url = "https://fleet.example.com/wole"
+"#,
+        )
+        .unwrap();
+
+        assert!(!policy.allow_permanent_delete);
+        assert!(!policy.tamper_evident_history);
+        assert_eq!(policy.report.as_ref().unwrap().timeout_secs, 10);
+        assert!(policy.is_excluded(Path::new("/home/me/Projects/app/target")));
+        assert!(policy.is_excluded(Path::new("/data/keep-me")));
+        assert!(!policy.is_excluded(Path::new("/home/me/Downloads/a.zip")));
+
+        let empty = Policy::parse("").unwrap();
+        assert!(empty.allow_permanent_delete);
+        assert!(!empty.is_excluded(Path::new("/anything")));
+    }
+}
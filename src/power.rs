@@ -0,0 +1,301 @@
+//! Battery health report and power plans (`powercfg`)
+//!
+//! - [`battery_report`] runs `powercfg /batteryreport /xml` once per session and
+//!   reads design vs full-charge capacity from it; Windows keeps these numbers
+//!   more reliably than the battery driver reports them live.
+//! - [`list_power_plans`] / [`switch_to`] / [`restore_previous_plan`] back the
+//!   `wole optimize --power-plan` operation. Before switching, the active plan
+//!   is remembered in `power_plan_restore.txt` next to `config.toml`, so
+//!   `--power-plan restore` can go back to it.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Built-in power schemes
+pub const BALANCED_GUID: &str = "381b4222-f694-41f0-9685-ff5bb260df2e";
+pub const HIGH_PERFORMANCE_GUID: &str = "8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c";
+pub const POWER_SAVER_GUID: &str = "a1841308-3541-4fab-bc81-f71556f20b4a";
+
+/// Battery capacity from `powercfg /batteryreport`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatteryReport {
+    pub design_capacity_mwh: f64,
+    pub full_charge_capacity_mwh: f64,
+    pub cycle_count: Option<u32>,
+}
+
+impl BatteryReport {
+    /// Full-charge capacity as a share of the design capacity
+    pub fn capacity_percent(&self) -> f32 {
+        if self.design_capacity_mwh <= 0.0 {
+            return 0.0;
+        }
+        (self.full_charge_capacity_mwh / self.design_capacity_mwh * 100.0) as f32
+    }
+}
+
+/// Text of the first `<tag>...</tag>` element
+fn xml_element<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+    let open = format!("<{}>", tag);
+    let start = xml.find(&open)? + open.len();
+    let end = start + xml[start..].find(&format!("</{}>", tag))?;
+    Some(xml[start..end].trim())
+}
+
+/// Parse the XML battery report (first battery)
+fn parse_battery_report(xml: &str) -> Option<BatteryReport> {
+    let battery = xml_element(xml, "Battery")?;
+    let design = xml_element(battery, "DesignCapacity")?
+        .parse::<f64>()
+        .ok()?;
+    let full = xml_element(battery, "FullChargeCapacity")?
+        .parse::<f64>()
+        .ok()?;
+    let cycle_count = xml_element(battery, "CycleCount").and_then(|c| c.parse().ok());
+    (design > 0.0).then_some(BatteryReport {
+        design_capacity_mwh: design,
+        full_charge_capacity_mwh: full,
+        cycle_count,
+    })
+}
+
+fn generate_battery_report() -> Option<BatteryReport> {
+    let path = std::env::temp_dir().join(format!("wole-battery-report-{}.xml", std::process::id()));
+    let status = Command::new("powercfg")
+        .args(["/batteryreport", "/xml", "/output"])
+        .arg(&path)
+        .output()
+        .ok()?;
+    let report = if status.status.success() {
+        fs::read_to_string(&path)
+            .ok()
+            .and_then(|xml| parse_battery_report(&xml))
+    } else {
+        None
+    };
+    let _ = fs::remove_file(&path);
+    report
+}
+
+/// Battery report for this session (generated on first use; `None` without a battery)
+pub fn battery_report() -> Option<BatteryReport> {
+    static REPORT: OnceLock<Option<BatteryReport>> = OnceLock::new();
+    REPORT.get_or_init(generate_battery_report).clone()
+}
+
+/// A power scheme known to `powercfg`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PowerPlan {
+    pub guid: String,
+    pub name: String,
+    pub active: bool,
+}
+
+fn is_guid(token: &str) -> bool {
+    token.len() == 36
+        && token.chars().filter(|c| *c == '-').count() == 4
+        && token.chars().all(|c| c == '-' || c.is_ascii_hexdigit())
+}
+
+/// Parse one `Power Scheme GUID: <guid>  (<name>) *` line (the label is localized)
+fn parse_plan_line(line: &str) -> Option<PowerPlan> {
+    let guid = line
+        .split_whitespace()
+        .find(|token| is_guid(token))?
+        .to_lowercase();
+    let name = line
+        .split_once('(')
+        .and_then(|(_, rest)| rest.rsplit_once(')'))
+        .map(|(name, _)| name.trim().to_string())
+        .unwrap_or_else(|| guid.clone());
+    let active = line.trim_end().ends_with('*');
+    Some(PowerPlan { guid, name, active })
+}
+
+fn parse_power_plans(output: &str) -> Vec<PowerPlan> {
+    output.lines().filter_map(parse_plan_line).collect()
+}
+
+fn powercfg(args: &[&str]) -> Result<String> {
+    let output = Command::new("powercfg")
+        .args(args)
+        .output()
+        .context("Failed to run powercfg")?;
+    if !output.status.success() {
+        bail!(
+            "powercfg {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stdout).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Every power plan on this machine
+pub fn list_power_plans() -> Result<Vec<PowerPlan>> {
+    Ok(parse_power_plans(&powercfg(&["/list"])?))
+}
+
+/// Name of the active power plan (cached for 30 seconds - the Status screen refreshes every 2)
+pub fn active_plan_name() -> Option<String> {
+    static CACHE: Mutex<Option<(Instant, Option<String>)>> = Mutex::new(None);
+    let mut cache = CACHE.lock().ok()?;
+    if let Some((at, name)) = cache.as_ref() {
+        if at.elapsed() < Duration::from_secs(30) {
+            return name.clone();
+        }
+    }
+    let name = powercfg(&["/getactivescheme"])
+        .ok()
+        .and_then(|out| out.lines().find_map(parse_plan_line))
+        .map(|plan| plan.name);
+    *cache = Some((Instant::now(), name.clone()));
+    name
+}
+
+fn restore_file() -> Result<PathBuf> {
+    let config_path = Config::config_path()?;
+    let dir = config_path
+        .parent()
+        .context("Config path has no parent directory")?;
+    Ok(dir.join("power_plan_restore.txt"))
+}
+
+fn is_high_performance(wanted: &str) -> bool {
+    matches!(
+        wanted.to_lowercase().as_str(),
+        "high" | "high-performance" | "performance"
+    )
+}
+
+/// Resolve a `--power-plan` value: high, balanced, saver, or a plan name / GUID
+fn resolve_plan(plans: &[PowerPlan], wanted: &str) -> Option<PowerPlan> {
+    let guid = match wanted.to_lowercase().as_str() {
+        _ if is_high_performance(wanted) => HIGH_PERFORMANCE_GUID.to_string(),
+        "balanced" => BALANCED_GUID.to_string(),
+        "saver" | "power-saver" => POWER_SAVER_GUID.to_string(),
+        other => other.to_string(),
+    };
+    plans
+        .iter()
+        .find(|p| p.guid == guid || p.name.eq_ignore_ascii_case(wanted))
+        .cloned()
+}
+
+/// Make `wanted` the active plan, remembering the current one for [`restore_previous_plan`].
+///
+/// "high" creates the High performance scheme from its template when the
+/// machine hides it (common on laptops with Modern Standby).
+pub fn switch_to(wanted: &str) -> Result<PowerPlan> {
+    let plans = list_power_plans()?;
+    let target = match resolve_plan(&plans, wanted) {
+        Some(plan) => plan,
+        None if is_high_performance(wanted) => {
+            let output = powercfg(&["/duplicatescheme", HIGH_PERFORMANCE_GUID])?;
+            output
+                .lines()
+                .find_map(parse_plan_line)
+                .context("powercfg did not report the new scheme")?
+        }
+        None => bail!("No power plan named '{}'", wanted),
+    };
+
+    if let Some(current) = plans.iter().find(|p| p.active) {
+        if current.guid == target.guid {
+            return Ok(target);
+        }
+        // Keep the original plan if we switch several times in a row
+        let file = restore_file()?;
+        if !file.exists() {
+            if let Some(dir) = file.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&file, &current.guid)?;
+        }
+    }
+
+    powercfg(&["/setactive", &target.guid])?;
+    Ok(target)
+}
+
+/// Go back to the plan that was active before the first [`switch_to`]
+pub fn restore_previous_plan() -> Result<PowerPlan> {
+    let file = restore_file()?;
+    let guid = fs::read_to_string(&file)
+        .context("No previous power plan saved - nothing to restore")?
+        .trim()
+        .to_lowercase();
+    let plans = list_power_plans()?;
+    let plan = plans
+        .into_iter()
+        .find(|p| p.guid == guid)
+        .context("The saved power plan no longer exists")?;
+    powercfg(&["/setactive", &plan.guid])?;
+    let _ = fs::remove_file(&file);
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_battery_report() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<BatteryReport xmlns="http://schemas.microsoft.com/battery/2012">
+  <Batteries>
+    <Battery>
+      <Id>DELL 1VX1H</Id>
+      <Chemistry>LiP</Chemistry>
+      <DesignCapacity>56000</DesignCapacity>
+      <FullChargeCapacity>47600</FullChargeCapacity>
+      <CycleCount>212</CycleCount>
+    </Battery>
+  </Batteries>
+</BatteryReport>"#;
+
+        let report = parse_battery_report(xml).unwrap();
+        assert_eq!(report.design_capacity_mwh, 56000.0);
+        assert_eq!(report.full_charge_capacity_mwh, 47600.0);
+        assert_eq!(report.cycle_count, Some(212));
+        assert_eq!(report.capacity_percent().round(), 85.0);
+
+        assert_eq!(parse_battery_report("<BatteryReport />"), None);
+    }
+
+    #[test]
+    fn test_parse_power_plans() {
+        let output = "Existing Power Schemes (* Active)\n\
+            -----------------------------------\n\
+            Power Scheme GUID: 381b4222-f694-41f0-9685-ff5bb260df2e  (Balanced) *\n\
+            Power Scheme GUID: 8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c  (High performance)\n";
+
+        let plans = parse_power_plans(output);
+        assert_eq!(
+            plans,
+            vec![
+                PowerPlan {
+                    guid: BALANCED_GUID.to_string(),
+                    name: "Balanced".to_string(),
+                    active: true,
+                },
+                PowerPlan {
+                    guid: HIGH_PERFORMANCE_GUID.to_string(),
+                    name: "High performance".to_string(),
+                    active: false,
+                },
+            ]
+        );
+        assert_eq!(
+            resolve_plan(&plans, "high").map(|p| p.name),
+            Some("High performance".to_string())
+        );
+        assert_eq!(resolve_plan(&plans, "saver"), None);
+    }
+}
@@ -0,0 +1,287 @@
+//! Process tree for the Status screen's processes panel
+//!
+//! Builds the parent/child hierarchy from a Status refresh. Each row carries
+//! the CPU and memory of its whole subtree, so a collapsed browser is one line
+//! for all of its helper processes. A process whose children all share its
+//! name (browser tabs, Electron helpers) starts collapsed and the rest start
+//! expanded; Enter flips either. A filter keeps the processes whose name or
+//! pid matches, plus the parents leading to them.
+
+use crate::status::ProcessSample;
+use std::collections::{HashMap, HashSet};
+
+/// One line of the tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeRow {
+    pub pid: u32,
+    pub name: String,
+    pub depth: usize,
+    /// CPU of the process and everything under it
+    pub cpu_usage: f32,
+    /// Memory of the process and everything under it
+    pub memory_bytes: u64,
+    /// Processes in the subtree, this one included
+    pub count: usize,
+    pub has_children: bool,
+    pub expanded: bool,
+}
+
+/// Tree mode of the processes panel
+#[derive(Debug, Default)]
+pub struct TreeView {
+    pub enabled: bool,
+    pub cursor: usize,
+    pub filter: String,
+    pub editing_filter: bool,
+    /// Processes expanded or collapsed by hand, against their default
+    toggled: HashSet<u32>,
+}
+
+impl TreeView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expand a collapsed process or collapse an expanded one
+    pub fn toggle(&mut self, pid: u32) {
+        if !self.toggled.remove(&pid) {
+            self.toggled.insert(pid);
+        }
+    }
+
+    /// Visible rows, parents before their children, busiest first
+    pub fn rows(&self, processes: &[ProcessSample]) -> Vec<TreeRow> {
+        let tree = Tree::new(processes, &self.filter);
+        let mut rows = Vec::new();
+        let mut visited = HashSet::new();
+        for &root in &tree.roots {
+            self.visit(&tree, root, 0, false, &mut visited, &mut rows);
+        }
+        rows
+    }
+
+    fn visit(
+        &self,
+        tree: &Tree,
+        pid: u32,
+        depth: usize,
+        inside_match: bool,
+        visited: &mut HashSet<u32>,
+        rows: &mut Vec<TreeRow>,
+    ) {
+        if !visited.insert(pid) {
+            return;
+        }
+        let filtering = !tree.filter.is_empty();
+        let matched = inside_match || tree.matches(pid);
+        let children = tree.children(pid);
+        let process = tree.processes[&pid];
+        // Parents that are only shown because of a match below stay open
+        let expanded = if filtering && !matched {
+            true
+        } else {
+            let folded = !children.is_empty()
+                && children
+                    .iter()
+                    .all(|child| tree.processes[child].name == process.name);
+            folded == self.toggled.contains(&pid)
+        };
+        let (cpu_usage, memory_bytes, count) = tree.totals[&pid];
+        rows.push(TreeRow {
+            pid,
+            name: process.name.clone(),
+            depth,
+            cpu_usage,
+            memory_bytes,
+            count,
+            has_children: !children.is_empty(),
+            expanded,
+        });
+
+        if expanded {
+            for &child in children {
+                if !filtering || matched || tree.kept.contains(&child) {
+                    self.visit(tree, child, depth + 1, matched, visited, rows);
+                }
+            }
+        }
+    }
+}
+
+/// Hierarchy, subtree totals and filter matches for one refresh
+struct Tree<'a> {
+    processes: HashMap<u32, &'a ProcessSample>,
+    /// Children of each process, busiest first
+    children: HashMap<u32, Vec<u32>>,
+    roots: Vec<u32>,
+    /// CPU, memory and process count of each subtree
+    totals: HashMap<u32, (f32, u64, usize)>,
+    filter: String,
+    /// Processes that match the filter or have a match below them
+    kept: HashSet<u32>,
+}
+
+impl<'a> Tree<'a> {
+    fn new(samples: &'a [ProcessSample], filter: &str) -> Self {
+        let processes: HashMap<u32, &ProcessSample> =
+            samples.iter().map(|sample| (sample.pid, sample)).collect();
+        let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+        let mut roots = Vec::new();
+        for sample in samples {
+            match sample.parent {
+                Some(parent) if parent != sample.pid && processes.contains_key(&parent) => {
+                    children.entry(parent).or_default().push(sample.pid)
+                }
+                _ => roots.push(sample.pid),
+            }
+        }
+
+        let mut tree = Tree {
+            processes,
+            children,
+            roots,
+            totals: HashMap::new(),
+            filter: filter.trim().to_lowercase(),
+            kept: HashSet::new(),
+        };
+
+        // A parent pid reused by a later child can form a loop with no root;
+        // the lowest pid of such a loop stands in as one
+        let mut reached = HashSet::new();
+        for root in tree.roots.clone() {
+            tree.total(root, &mut reached);
+        }
+        let mut pids: Vec<u32> = tree.processes.keys().copied().collect();
+        pids.sort_unstable();
+        for pid in pids {
+            if !reached.contains(&pid) {
+                tree.roots.push(pid);
+                tree.total(pid, &mut reached);
+            }
+        }
+
+        let totals = &tree.totals;
+        let busiest = |a: &u32, b: &u32| {
+            let (cpu_a, memory_a, _) = totals[a];
+            let (cpu_b, memory_b, _) = totals[b];
+            cpu_b
+                .partial_cmp(&cpu_a)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(memory_b.cmp(&memory_a))
+                .then(a.cmp(b))
+        };
+        tree.roots.sort_by(busiest);
+        for siblings in tree.children.values_mut() {
+            siblings.sort_by(busiest);
+        }
+        tree
+    }
+
+    fn children(&self, pid: u32) -> &[u32] {
+        self.children.get(&pid).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    fn matches(&self, pid: u32) -> bool {
+        let process = self.processes[&pid];
+        self.filter.is_empty()
+            || process.name.to_lowercase().contains(&self.filter)
+            || pid.to_string() == self.filter
+    }
+
+    /// Fill in subtree totals and filter matches below `pid`
+    fn total(&mut self, pid: u32, reached: &mut HashSet<u32>) -> (f32, u64, usize) {
+        if !reached.insert(pid) {
+            return (0.0, 0, 0);
+        }
+        let process = self.processes[&pid];
+        let mut totals = (process.cpu_usage, process.memory_bytes, 1);
+        let mut keep = !self.filter.is_empty() && self.matches(pid);
+        for child in self.children(pid).to_vec() {
+            let (cpu, memory, count) = self.total(child, reached);
+            totals.0 += cpu;
+            totals.1 += memory;
+            totals.2 += count;
+            keep |= self.kept.contains(&child);
+        }
+        if keep {
+            self.kept.insert(pid);
+        }
+        self.totals.insert(pid, totals);
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, parent: Option<u32>, name: &str, cpu: f32, mb: u64) -> ProcessSample {
+        ProcessSample {
+            pid,
+            parent,
+            name: name.to_string(),
+            cpu_usage: cpu,
+            memory_bytes: mb * 1024 * 1024,
+        }
+    }
+
+    fn sample_tree() -> Vec<ProcessSample> {
+        let mut processes = vec![
+            process(1, None, "explorer.exe", 1.0, 100),
+            process(10, Some(1), "chrome.exe", 2.0, 300),
+            process(30, Some(1), "notepad.exe", 0.0, 10),
+        ];
+        // 40 tabs and helpers under the browser
+        for i in 0..40 {
+            processes.push(process(100 + i, Some(10), "chrome.exe", 0.5, 50));
+        }
+        processes
+    }
+
+    #[test]
+    fn test_same_name_children_roll_up() {
+        let mut view = TreeView::new();
+        let rows = view.rows(&sample_tree());
+        let names: Vec<&str> = rows.iter().map(|row| row.name.as_str()).collect();
+        assert_eq!(names, ["explorer.exe", "chrome.exe", "notepad.exe"]);
+
+        let chrome = &rows[1];
+        assert_eq!((chrome.depth, chrome.count), (1, 41));
+        assert!(chrome.has_children && !chrome.expanded);
+        assert_eq!(chrome.cpu_usage, 22.0);
+        assert_eq!(chrome.memory_bytes, (300 + 40 * 50) * 1024 * 1024);
+        assert_eq!(rows[0].count, 43);
+
+        view.toggle(10);
+        assert_eq!(view.rows(&sample_tree()).len(), 43);
+        view.toggle(1);
+        assert_eq!(view.rows(&sample_tree()).len(), 1);
+    }
+
+    #[test]
+    fn test_filter_keeps_parents_of_matches() {
+        let mut view = TreeView::new();
+        view.filter = "NOTE".to_string();
+        let rows = view.rows(&sample_tree());
+        let names: Vec<&str> = rows.iter().map(|row| row.name.as_str()).collect();
+        assert_eq!(names, ["explorer.exe", "notepad.exe"]);
+
+        // A matching browser keeps its children rolled up
+        view.filter = "chrome".to_string();
+        let rows = view.rows(&sample_tree());
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].count, 41);
+    }
+
+    #[test]
+    fn test_parent_loop_still_listed() {
+        let processes = vec![
+            process(5, Some(6), "a.exe", 0.0, 1),
+            process(6, Some(5), "b.exe", 0.0, 1),
+        ];
+        let rows = TreeView::new().rows(&processes);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].pid, 5);
+        assert_eq!(rows[1].depth, 1);
+    }
+}
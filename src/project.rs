@@ -1,3 +1,4 @@
+use crate::cancel::CancellationToken;
 use crate::config::Config;
 use anyhow::Result;
 use chrono::{DateTime, Duration, Utc};
@@ -57,6 +58,34 @@ pub fn detect_project_type(path: &Path) -> Option<ProjectType> {
     None
 }
 
+/// Command that regenerates the build artifacts of the project at `path`
+/// (e.g. `cargo build`), or None if it isn't a known project type
+pub fn rebuild_command(path: &Path) -> Option<&'static str> {
+    let command = match detect_project_type(path)? {
+        ProjectType::Node if path.join("pnpm-lock.yaml").exists() => "pnpm install",
+        ProjectType::Node if path.join("yarn.lock").exists() => "yarn install",
+        ProjectType::Node => "npm install",
+        ProjectType::Rust => "cargo build",
+        ProjectType::DotNet => "dotnet restore",
+        ProjectType::Python if path.join("requirements.txt").exists() => {
+            "pip install -r requirements.txt"
+        }
+        ProjectType::Python => "pip install .",
+        ProjectType::Java if path.join("build.gradle").exists() => "gradle build",
+        ProjectType::Java => "mvn package",
+    };
+    Some(command)
+}
+
+/// Walk up from a build artifact (e.g. `repo\target`) to the project it belongs to
+pub fn artifact_project_root(artifact_path: &Path) -> Option<PathBuf> {
+    artifact_path
+        .ancestors()
+        .skip(1)
+        .find(|dir| dir.parent().is_some() && detect_project_type(dir).is_some())
+        .map(Path::to_path_buf)
+}
+
 /// Get the marker file path for a project type
 #[allow(dead_code)]
 fn get_marker_file(path: &Path, project_type: ProjectType) -> Option<PathBuf> {
@@ -215,7 +244,12 @@ pub fn is_project_active(path: &Path, age_days: u64) -> Result<bool> {
 /// Find all project roots in a directory tree
 ///
 /// Uses jwalk for parallel directory traversal (2-4x faster than sequential).
-pub fn find_project_roots(root: &Path, config: &Config) -> Vec<PathBuf> {
+/// Stops walking once `cancel` fires, returning the roots found so far.
+pub fn find_project_roots(
+    root: &Path,
+    config: &Config,
+    cancel: &CancellationToken,
+) -> Vec<PathBuf> {
     // Skip if root itself is a project (avoid scanning into it)
     if detect_project_type(root).is_some() {
         return vec![root.to_path_buf()];
@@ -232,9 +266,9 @@ pub fn find_project_roots(root: &Path, config: &Config) -> Vec<PathBuf> {
     WalkDir::new(root)
         .max_depth(MAX_DEPTH)
         .follow_links(false)
-        .parallelism(jwalk::Parallelism::RayonDefaultPool {
-            busy_timeout: std::time::Duration::from_secs(1),
-        })
+        .parallelism(crate::drive::walk_parallelism(
+            std::time::Duration::from_secs(1),
+        ))
         .process_read_dir(move |_depth, _path, _state, children| {
             // Filter out directories we don't want to descend into
             children.retain(|entry| {
@@ -287,6 +321,7 @@ pub fn find_project_roots(root: &Path, config: &Config) -> Vec<PathBuf> {
             });
         })
         .into_iter()
+        .take_while(|_| !cancel.is_cancelled())
         .filter_map(|e| e.ok())
         .for_each(|entry| {
             let path = entry.path();
@@ -375,6 +410,21 @@ mod tests {
         assert_eq!(detect_project_type(temp_dir.path()), None);
     }
 
+    #[test]
+    fn test_rebuild_command() {
+        let temp_dir = create_test_dir();
+        let project = temp_dir.path().join("app");
+        let artifact = project.join("node_modules");
+        fs::create_dir_all(&artifact).unwrap();
+        assert_eq!(rebuild_command(&project), None);
+
+        fs::write(project.join("package.json"), "{}").unwrap();
+        assert_eq!(rebuild_command(&project), Some("npm install"));
+        fs::write(project.join("yarn.lock"), "").unwrap();
+        assert_eq!(rebuild_command(&project), Some("yarn install"));
+        assert_eq!(artifact_project_root(&artifact), Some(project));
+    }
+
     #[test]
     #[ignore = "temporarily disabled to debug stack overflow"]
     fn test_find_project_roots() {
@@ -389,7 +439,7 @@ mod tests {
         fs::write(project2.join("Cargo.toml"), "[package]").unwrap();
 
         let config = crate::config::Config::default();
-        let roots = find_project_roots(temp_dir.path(), &config);
+        let roots = find_project_roots(temp_dir.path(), &config, &CancellationToken::new());
         assert_eq!(roots.len(), 2);
     }
 }
@@ -0,0 +1,248 @@
+//! "What's next" suggestions after a cleanup
+//!
+//! Looks at what is still listed after a cleanup, which categories the scan
+//! covered and how full the system drive is, and suggests the next thing worth
+//! doing. Shown on the TUI's Success screen and by `wole recommend`.
+
+use crate::api::Category;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// The system drive counts as full from this much use
+const FULL_PERCENT: u64 = 90;
+
+/// Leftovers smaller than this aren't worth a suggestion
+const REMAINING_MIN_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Most suggestions shown at once
+const MAX_RECOMMENDATIONS: usize = 5;
+
+/// Categories worth suggesting when a scan left them out, most useful first
+const SUGGESTED_CATEGORIES: [Category; 5] = [
+    Category::Duplicates,
+    Category::Large,
+    Category::Build,
+    Category::Downloads,
+    Category::ToolchainCaches,
+];
+
+/// A suggested next step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Recommendation {
+    pub message: String,
+    /// Command that acts on it (None when it is done outside wole)
+    pub command: Option<String>,
+}
+
+impl Recommendation {
+    fn new(message: String, command: Option<String>) -> Self {
+        Self { message, command }
+    }
+}
+
+/// Usage of the drive Windows is installed on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriveUsage {
+    pub mount_point: PathBuf,
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+}
+
+impl DriveUsage {
+    pub fn used_percent(&self) -> u64 {
+        if self.total_bytes == 0 {
+            return 0;
+        }
+        self.total_bytes.saturating_sub(self.available_bytes) * 100 / self.total_bytes
+    }
+}
+
+/// Everything the suggestions are based on
+#[derive(Debug, Clone, Default)]
+pub struct RecommendInput {
+    /// Categories the last scan covered (None if there was no scan)
+    pub scanned: Option<Vec<Category>>,
+    /// What is still listed after the cleanup: (category, items, bytes)
+    pub remaining: Vec<(Category, usize, u64)>,
+    pub system_drive: Option<DriveUsage>,
+    /// `Windows.old` on the system drive, if it is there
+    pub windows_old: Option<PathBuf>,
+}
+
+impl RecommendInput {
+    /// Fill in the system drive details for the given scan state
+    pub fn gather(scanned: Option<Vec<Category>>, remaining: Vec<(Category, usize, u64)>) -> Self {
+        let root = system_drive_root();
+        let windows_old = Some(root.join("Windows.old"))
+            .filter(|path| cfg!(windows) && crate::utils::safe_exists(path));
+        Self {
+            scanned,
+            remaining,
+            system_drive: drive_usage(&root),
+            windows_old,
+        }
+    }
+}
+
+/// Suggestions for `input`, most important first
+pub fn recommend(input: &RecommendInput) -> Vec<Recommendation> {
+    let mut recommendations = Vec::new();
+    let scanned = |category: Category| {
+        input
+            .scanned
+            .as_ref()
+            .is_some_and(|scanned| scanned.contains(&category))
+    };
+
+    // A drive that is still nearly full comes first
+    if let Some(ref drive) = input.system_drive {
+        let used = drive.used_percent();
+        if used >= FULL_PERCENT {
+            recommendations.push(Recommendation::new(
+                format!(
+                    "{} is still {}% full ({} free)",
+                    drive.mount_point.display(),
+                    used,
+                    bytesize::to_string(drive.available_bytes, false)
+                ),
+                None,
+            ));
+            if let Some(ref windows_old) = input.windows_old {
+                recommendations.push(Recommendation::new(
+                    format!(
+                        "Consider removing {} with Disk Cleanup (\"Previous Windows installation(s)\")",
+                        windows_old.display()
+                    ),
+                    Some("cleanmgr".to_string()),
+                ));
+            }
+            if input.scanned.is_some() && !scanned(Category::WindowsUpdate) {
+                recommendations.push(not_scanned(Category::WindowsUpdate));
+            }
+        }
+    }
+
+    let mut remaining: Vec<&(Category, usize, u64)> = input
+        .remaining
+        .iter()
+        .filter(|(_, _, bytes)| *bytes >= REMAINING_MIN_BYTES)
+        .collect();
+    remaining.sort_by(|a, b| b.2.cmp(&a.2));
+    for (category, items, bytes) in remaining.into_iter().take(2) {
+        recommendations.push(Recommendation::new(
+            format!(
+                "{} still lists {} item(s), {} - review them",
+                category.name(),
+                items,
+                bytesize::to_string(*bytes, false)
+            ),
+            None,
+        ));
+    }
+
+    match input.scanned {
+        Some(_) => recommendations.extend(
+            SUGGESTED_CATEGORIES
+                .into_iter()
+                .filter(|category| !scanned(*category))
+                .take(2)
+                .map(not_scanned),
+        ),
+        None => recommendations.push(Recommendation::new(
+            "No scan yet - see what can be cleaned".to_string(),
+            Some("wole scan".to_string()),
+        )),
+    }
+
+    recommendations.truncate(MAX_RECOMMENDATIONS);
+    recommendations
+}
+
+fn not_scanned(category: Category) -> Recommendation {
+    Recommendation::new(
+        format!("{} not scanned - enable it?", category.name()),
+        Some(format!("wole clean --{}", category.id().replace('_', "-"))),
+    )
+}
+
+/// Root of the drive Windows is installed on (`/` elsewhere)
+pub fn system_drive_root() -> PathBuf {
+    if cfg!(windows) {
+        let drive = std::env::var("SystemDrive").unwrap_or_else(|_| "C:".to_string());
+        PathBuf::from(format!("{}\\", drive))
+    } else {
+        PathBuf::from("/")
+    }
+}
+
+/// Size and free space of the disk mounted at (or holding) `root`
+pub fn drive_usage(root: &Path) -> Option<DriveUsage> {
+    use sysinfo::Disks;
+
+    let disks = Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| root.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| DriveUsage {
+            mount_point: disk.mount_point().to_path_buf(),
+            total_bytes: disk.total_space(),
+            available_bytes: disk.available_space(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GB: u64 = 1024 * 1024 * 1024;
+
+    #[test]
+    fn test_recommendations() {
+        let input = RecommendInput {
+            scanned: Some(vec![Category::Cache, Category::Large, Category::Build]),
+            remaining: vec![
+                (Category::Large, 3, 5 * GB),
+                (Category::Cache, 10, 100),
+                (Category::Build, 2, 2 * GB),
+            ],
+            system_drive: Some(DriveUsage {
+                mount_point: PathBuf::from("C:\\"),
+                total_bytes: 100 * GB,
+                available_bytes: 8 * GB,
+            }),
+            windows_old: Some(PathBuf::from("C:\\Windows.old")),
+        };
+        let messages: Vec<String> = recommend(&input).into_iter().map(|r| r.message).collect();
+        assert_eq!(messages.len(), MAX_RECOMMENDATIONS);
+        assert!(messages[0].contains("92% full"));
+        assert!(messages[1].contains("Windows.old"));
+        assert_eq!(messages[2], "Windows Update not scanned - enable it?");
+        assert!(messages[3].starts_with("Large Files still lists 3 item(s)"));
+        assert!(messages[4].starts_with("Build Artifacts"));
+
+        // Plenty of space and nothing left: only the categories that were skipped
+        let input = RecommendInput {
+            scanned: Some(vec![Category::Duplicates]),
+            remaining: Vec::new(),
+            system_drive: Some(DriveUsage {
+                mount_point: PathBuf::from("C:\\"),
+                total_bytes: 100 * GB,
+                available_bytes: 50 * GB,
+            }),
+            windows_old: None,
+        };
+        let recommendations = recommend(&input);
+        assert_eq!(recommendations.len(), 2);
+        assert_eq!(
+            recommendations[0].command.as_deref(),
+            Some("wole clean --large")
+        );
+
+        assert_eq!(
+            recommend(&RecommendInput::default())[0].command.as_deref(),
+            Some("wole scan")
+        );
+    }
+}
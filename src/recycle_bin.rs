@@ -0,0 +1,398 @@
+//! Per-volume Recycle Bin capacity
+//!
+//! Every volume has its own Recycle Bin with its own size limit (Recycle Bin
+//! Properties → Custom size, stored under
+//! `HKCU\Software\Microsoft\Windows\CurrentVersion\Explorer\BitBucket\Volume\{GUID}`).
+//! The shell deletes items larger than that limit permanently instead of
+//! recycling them, and a selection bigger than the limit pushes older items out
+//! of the bin. [`check_selection`] finds the volumes where that would happen so
+//! the Confirm screen can warn first, and [`set_quarantine_volumes`] lets the
+//! user send those volumes' items to a quarantine folder instead (see
+//! [`crate::network::quarantine`]).
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Windows sizes a bin without a custom limit at roughly 5% of the volume
+#[cfg(windows)]
+const DEFAULT_CAPACITY_DIVISOR: u64 = 20;
+
+/// Size limit of one volume's Recycle Bin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinCapacity {
+    pub max_bytes: u64,
+    /// "Don't move files to the Recycle Bin" is set for the volume
+    pub nuke_on_delete: bool,
+}
+
+/// A volume whose Recycle Bin can't hold what is about to be recycled there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinOverflow {
+    /// Volume root, e.g. `C:\`
+    pub volume: PathBuf,
+    pub capacity_bytes: u64,
+    pub selected_bytes: u64,
+    pub items: usize,
+    /// Items that are individually larger than the bin
+    pub oversized: usize,
+    pub nuke_on_delete: bool,
+}
+
+impl BinOverflow {
+    /// One-line warning for the Confirm screen
+    pub fn describe(&self) -> String {
+        let volume = self.volume.display();
+        let selected = bytesize::to_string(self.selected_bytes, false);
+        if self.nuke_on_delete {
+            return format!(
+                "Recycle Bin is turned off on {} - {} item(s) ({}) would be deleted permanently",
+                volume, self.items, selected
+            );
+        }
+        let capacity = bytesize::to_string(self.capacity_bytes, false);
+        if self.oversized > 0 {
+            format!(
+                "Recycle Bin on {} holds {} - {} item(s) are too large for it and would be deleted permanently",
+                volume, capacity, self.oversized
+            )
+        } else {
+            format!(
+                "Recycle Bin on {} holds {} but {} is selected - older bin contents would be purged",
+                volume, capacity, selected
+            )
+        }
+    }
+}
+
+/// Volumes whose items go to quarantine instead of the Recycle Bin
+static QUARANTINE_VOLUMES: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// Send items on these volumes to quarantine instead of the Recycle Bin
+///
+/// Pass an empty list to go back to the Recycle Bin everywhere.
+pub fn set_quarantine_volumes(volumes: Vec<PathBuf>) {
+    if let Ok(mut guard) = QUARANTINE_VOLUMES.lock() {
+        *guard = volumes;
+    }
+}
+
+/// Whether a recycle of `path` should go to quarantine instead
+pub fn routes_to_quarantine(path: &Path) -> bool {
+    let Ok(guard) = QUARANTINE_VOLUMES.lock() else {
+        return false;
+    };
+    if guard.is_empty() {
+        return false;
+    }
+    volume_root(path).is_some_and(|root| guard.contains(&root))
+}
+
+/// Volumes (local only) where recycling `items` would overflow the Recycle Bin
+pub fn check_selection(items: &[(PathBuf, u64)]) -> Vec<BinOverflow> {
+    find_overflows(items, volume_root, capacity)
+}
+
+/// Split `items` by the volume of their path, keeping their order within each volume
+pub fn volume_buckets<T: Clone>(items: &[T], path_of: fn(&T) -> &Path) -> Vec<Vec<T>> {
+    bucket_by_volume(items, |item| volume_root(path_of(item)))
+}
+
+fn bucket_by_volume<T: Clone>(items: &[T], root_of: impl Fn(&T) -> Option<PathBuf>) -> Vec<Vec<T>> {
+    let mut buckets: Vec<(Option<PathBuf>, Vec<T>)> = Vec::new();
+    for item in items {
+        let volume = root_of(item);
+        match buckets.iter_mut().find(|(v, _)| *v == volume) {
+            Some((_, bucket)) => bucket.push(item.clone()),
+            None => buckets.push((volume, vec![item.clone()])),
+        }
+    }
+    buckets.into_iter().map(|(_, bucket)| bucket).collect()
+}
+
+/// Cut each volume's items into batches of up to `batch_size`, taking turns
+/// between volumes
+///
+/// Workers that take batches in this order spread over every volume at once,
+/// and a volume with many items still has its batches shared between them.
+pub fn interleaved_batches<T: Clone>(buckets: &[Vec<T>], batch_size: usize) -> Vec<Vec<T>> {
+    let mut chunks: Vec<_> = buckets
+        .iter()
+        .map(|bucket| bucket.chunks(batch_size.max(1)))
+        .collect();
+    let mut batches = Vec::new();
+    loop {
+        let before = batches.len();
+        for chunk in &mut chunks {
+            if let Some(batch) = chunk.next() {
+                batches.push(batch.to_vec());
+            }
+        }
+        if batches.len() == before {
+            return batches;
+        }
+    }
+}
+
+fn find_overflows(
+    items: &[(PathBuf, u64)],
+    root_of: impl Fn(&Path) -> Option<PathBuf>,
+    capacity_of: impl Fn(&Path) -> Option<BinCapacity>,
+) -> Vec<BinOverflow> {
+    let mut volumes: BTreeMap<PathBuf, Vec<u64>> = BTreeMap::new();
+    for (path, size) in items {
+        // Shares have no Recycle Bin; their items are always quarantined
+        if crate::network::is_network_path(path) {
+            continue;
+        }
+        if let Some(root) = root_of(path) {
+            volumes.entry(root).or_default().push(*size);
+        }
+    }
+
+    volumes
+        .into_iter()
+        .filter_map(|(volume, sizes)| {
+            let capacity = capacity_of(&volume)?;
+            let selected_bytes: u64 = sizes.iter().sum();
+            let oversized = sizes.iter().filter(|&&s| s > capacity.max_bytes).count();
+            let overflows = capacity.nuke_on_delete || selected_bytes > capacity.max_bytes;
+            overflows.then_some(BinOverflow {
+                volume,
+                capacity_bytes: capacity.max_bytes,
+                selected_bytes,
+                items: sizes.len(),
+                oversized,
+                nuke_on_delete: capacity.nuke_on_delete,
+            })
+        })
+        .collect()
+}
+
+/// `{GUID}` out of a volume name like `\\?\Volume{GUID}\`
+#[cfg_attr(not(windows), allow(dead_code))]
+fn parse_volume_guid(volume_name: &str) -> Option<&str> {
+    let start = volume_name.find('{')?;
+    let end = volume_name[start..].find('}')? + start;
+    Some(&volume_name[start..=end])
+}
+
+#[cfg(windows)]
+fn to_wide(path: &Path) -> Vec<u16> {
+    use std::os::windows::ffi::OsStrExt;
+    path.as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect()
+}
+
+#[cfg(windows)]
+fn from_wide(buf: &[u16]) -> PathBuf {
+    use std::os::windows::ffi::OsStringExt;
+    let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+    PathBuf::from(std::ffi::OsString::from_wide(&buf[..len]))
+}
+
+/// Root of the volume holding `path` (mount folders included), e.g. `C:\`
+#[cfg(windows)]
+pub fn volume_root(path: &Path) -> Option<PathBuf> {
+    extern "system" {
+        fn GetVolumePathNameW(
+            lpszFileName: *const u16,
+            lpszVolumePathName: *mut u16,
+            cchBufferLength: u32,
+        ) -> i32;
+    }
+
+    let wide = to_wide(path);
+    let mut buf = vec![0u16; 1024];
+    let ok = unsafe { GetVolumePathNameW(wide.as_ptr(), buf.as_mut_ptr(), buf.len() as u32) };
+    (ok != 0).then(|| from_wide(&buf))
+}
+
+/// Root of the volume holding `path` (Recycle Bin limits are Windows-only)
+#[cfg(not(windows))]
+pub fn volume_root(_path: &Path) -> Option<PathBuf> {
+    None
+}
+
+#[cfg(windows)]
+fn volume_guid(root: &Path) -> Option<String> {
+    extern "system" {
+        fn GetVolumeNameForVolumeMountPointW(
+            lpszVolumeMountPoint: *const u16,
+            lpszVolumeName: *mut u16,
+            cchBufferLength: u32,
+        ) -> i32;
+    }
+
+    let wide = to_wide(root);
+    let mut buf = vec![0u16; 64];
+    let ok = unsafe {
+        GetVolumeNameForVolumeMountPointW(wide.as_ptr(), buf.as_mut_ptr(), buf.len() as u32)
+    };
+    if ok == 0 {
+        return None;
+    }
+    let name = from_wide(&buf).to_string_lossy().to_string();
+    parse_volume_guid(&name).map(str::to_string)
+}
+
+#[cfg(windows)]
+fn volume_total_bytes(root: &Path) -> Option<u64> {
+    extern "system" {
+        fn GetDiskFreeSpaceExW(
+            lpDirectoryName: *const u16,
+            lpFreeBytesAvailableToCaller: *mut u64,
+            lpTotalNumberOfBytes: *mut u64,
+            lpTotalNumberOfFreeBytes: *mut u64,
+        ) -> i32;
+    }
+
+    let wide = to_wide(root);
+    let mut free_bytes_available: u64 = 0;
+    let mut total_bytes: u64 = 0;
+    let mut total_free_bytes: u64 = 0;
+    let ok = unsafe {
+        GetDiskFreeSpaceExW(
+            wide.as_ptr(),
+            &mut free_bytes_available,
+            &mut total_bytes,
+            &mut total_free_bytes,
+        )
+    };
+    (ok != 0).then_some(total_bytes)
+}
+
+/// Recycle Bin limit of the volume mounted at `root`
+#[cfg(windows)]
+pub fn capacity(root: &Path) -> Option<BinCapacity> {
+    use winreg::enums::HKEY_CURRENT_USER;
+    use winreg::RegKey;
+
+    let guid = volume_guid(root)?;
+    let key = RegKey::predef(HKEY_CURRENT_USER)
+        .open_subkey(format!(
+            "Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\BitBucket\\Volume\\{}",
+            guid
+        ))
+        .ok();
+
+    let max_mb: Option<u32> = key.as_ref().and_then(|k| k.get_value("MaxCapacity").ok());
+    let nuke: u32 = key
+        .as_ref()
+        .and_then(|k| k.get_value("NukeOnDelete").ok())
+        .unwrap_or(0);
+    let max_bytes = match max_mb {
+        Some(mb) => mb as u64 * 1024 * 1024,
+        None => volume_total_bytes(root)? / DEFAULT_CAPACITY_DIVISOR,
+    };
+
+    Some(BinCapacity {
+        max_bytes,
+        nuke_on_delete: nuke != 0,
+    })
+}
+
+/// Recycle Bin limit of the volume mounted at `root` (Windows-only)
+#[cfg(not(windows))]
+pub fn capacity(_root: &Path) -> Option<BinCapacity> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GB: u64 = 1024 * 1024 * 1024;
+
+    fn root_of(path: &Path) -> Option<PathBuf> {
+        path.components()
+            .next()
+            .map(|c| PathBuf::from(c.as_os_str()))
+    }
+
+    #[test]
+    fn test_parse_volume_guid() {
+        assert_eq!(
+            parse_volume_guid(r"\\?\Volume{1b3c0a2e-0000-0000-0000-100000000000}\"),
+            Some("{1b3c0a2e-0000-0000-0000-100000000000}")
+        );
+        assert_eq!(parse_volume_guid(r"C:\"), None);
+    }
+
+    #[test]
+    fn test_find_overflows() {
+        let items = vec![
+            (PathBuf::from("c/big.iso"), 6 * GB),
+            (PathBuf::from("c/cache"), GB),
+            (PathBuf::from("d/cache"), GB),
+            (PathBuf::from("e/cache"), GB),
+        ];
+        let overflows = find_overflows(&items, root_of, |root| match root.to_str() {
+            Some("c") => Some(BinCapacity {
+                max_bytes: 5 * GB,
+                nuke_on_delete: false,
+            }),
+            Some("d") => Some(BinCapacity {
+                max_bytes: 5 * GB,
+                nuke_on_delete: false,
+            }),
+            Some("e") => Some(BinCapacity {
+                max_bytes: 5 * GB,
+                nuke_on_delete: true,
+            }),
+            _ => None,
+        });
+
+        assert_eq!(overflows.len(), 2);
+        assert_eq!(overflows[0].volume, PathBuf::from("c"));
+        assert_eq!(overflows[0].selected_bytes, 7 * GB);
+        assert_eq!(overflows[0].items, 2);
+        assert_eq!(overflows[0].oversized, 1);
+        assert_eq!(overflows[1].volume, PathBuf::from("e"));
+        assert!(overflows[1].nuke_on_delete);
+    }
+
+    #[test]
+    fn test_bucket_by_volume_keeps_order() {
+        let paths: Vec<PathBuf> = ["c/a", "d/b", "c/c", "e/d", "d/e"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        let buckets = bucket_by_volume(&paths, |path| root_of(path));
+        let names = |bucket: &Vec<PathBuf>| -> Vec<String> {
+            bucket.iter().map(|p| p.display().to_string()).collect()
+        };
+        assert_eq!(buckets.len(), 3);
+        assert_eq!(names(&buckets[0]), ["c/a", "c/c"]);
+        assert_eq!(names(&buckets[1]), ["d/b", "d/e"]);
+        assert_eq!(names(&buckets[2]), ["e/d"]);
+
+        // Paths whose volume is unknown share one bucket
+        let buckets = bucket_by_volume(&paths, |_| None);
+        assert_eq!(buckets, std::slice::from_ref(&paths));
+        assert!(volume_buckets(&[] as &[PathBuf], PathBuf::as_path).is_empty());
+    }
+
+    #[test]
+    fn test_interleaved_batches() {
+        let buckets = vec![vec![1, 2, 3, 4, 5], vec![10, 11], vec![20]];
+        assert_eq!(
+            interleaved_batches(&buckets, 2),
+            [vec![1, 2], vec![10, 11], vec![20], vec![3, 4], vec![5]]
+        );
+
+        // Every item lands in exactly one batch, none over the batch size
+        let batches = interleaved_batches(&buckets, 3);
+        assert!(batches
+            .iter()
+            .all(|batch| !batch.is_empty() && batch.len() <= 3));
+        let mut items: Vec<i32> = batches.concat();
+        items.sort();
+        assert_eq!(items, buckets.concat());
+
+        assert!(interleaved_batches::<i32>(&[], 100).is_empty());
+        assert_eq!(interleaved_batches(&[vec![1, 2]], 0), [vec![1], vec![2]]);
+    }
+}
@@ -0,0 +1,282 @@
+//! Removable media cleanup (`wole media`)
+//!
+//! USB sticks and SD cards collect junk the regular categories don't look for,
+//! because it is written by cameras and other operating systems:
+//! - camera thumbnails: `.THM` sidecars next to videos under `DCIM`, and the
+//!   `.thumbnails` folders Android keeps on SD cards;
+//! - macOS metadata: `.Trashes`, `.Spotlight-V100`, `.fseventsd`,
+//!   `.TemporaryItems` at the volume root, `.DS_Store` and `._*` AppleDouble
+//!   files anywhere;
+//! - Windows metadata: `System Volume Information` at the volume root,
+//!   `Thumbs.db` and `ehthumbs.db` anywhere;
+//! - drive trash: `$RECYCLE.BIN`, `RECYCLER` and `.Trash-<uid>` at the root.
+//!
+//! Everything is deleted permanently: the Recycle Bin of a removable drive
+//! lives on the drive, so recycling would free nothing. When the machine
+//! policy forbids permanent deletes, items are quarantined instead (see
+//! [`crate::trash_ops`]).
+
+use crate::cancel::CancellationToken;
+use crate::config::Config;
+use crate::history::DeletionLog;
+use crate::utils;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Kinds of junk looked for on removable media
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaJunk {
+    CameraThumbnails,
+    MacMetadata,
+    WindowsMetadata,
+    DriveTrash,
+}
+
+impl MediaJunk {
+    pub const ALL: [MediaJunk; 4] = [
+        MediaJunk::CameraThumbnails,
+        MediaJunk::MacMetadata,
+        MediaJunk::WindowsMetadata,
+        MediaJunk::DriveTrash,
+    ];
+
+    /// Id used by `--only` and in the deletion history
+    pub fn id(self) -> &'static str {
+        match self {
+            MediaJunk::CameraThumbnails => "thumbnails",
+            MediaJunk::MacMetadata => "mac",
+            MediaJunk::WindowsMetadata => "windows",
+            MediaJunk::DriveTrash => "trash",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MediaJunk::CameraThumbnails => "Camera thumbnails",
+            MediaJunk::MacMetadata => "macOS metadata",
+            MediaJunk::WindowsMetadata => "Windows metadata",
+            MediaJunk::DriveTrash => "Drive trash",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        let id = id.trim().to_lowercase();
+        Self::ALL.into_iter().find(|kind| kind.id() == id)
+    }
+}
+
+/// Folders at the volume root that are junk as a whole
+fn root_folder_kind(name: &str) -> Option<MediaJunk> {
+    let lower = name.to_lowercase();
+    match lower.as_str() {
+        ".trashes" | ".spotlight-v100" | ".fseventsd" | ".temporaryitems" => {
+            Some(MediaJunk::MacMetadata)
+        }
+        "system volume information" => Some(MediaJunk::WindowsMetadata),
+        "$recycle.bin" | "recycler" => Some(MediaJunk::DriveTrash),
+        _ if lower.starts_with(".trash-") => Some(MediaJunk::DriveTrash),
+        _ => None,
+    }
+}
+
+/// Kind of a file or folder below the root (`in_dcim`: somewhere under `DCIM`)
+fn entry_kind(name: &str, is_dir: bool, in_dcim: bool) -> Option<MediaJunk> {
+    let lower = name.to_lowercase();
+    if is_dir {
+        return (lower == ".thumbnails").then_some(MediaJunk::CameraThumbnails);
+    }
+    match lower.as_str() {
+        ".ds_store" => Some(MediaJunk::MacMetadata),
+        "thumbs.db" | "ehthumbs.db" => Some(MediaJunk::WindowsMetadata),
+        _ if lower.starts_with("._") => Some(MediaJunk::MacMetadata),
+        _ if in_dcim && lower.ends_with(".thm") => Some(MediaJunk::CameraThumbnails),
+        _ => None,
+    }
+}
+
+/// Junk of one kind found on a volume
+#[derive(Debug, Clone, Serialize)]
+pub struct MediaFinding {
+    pub kind: MediaJunk,
+    pub size_bytes: u64,
+    /// With their sizes, biggest first
+    pub items: Vec<(PathBuf, u64)>,
+}
+
+/// Find junk of the `kinds` on the volume mounted at `root`
+pub fn scan_volume(root: &Path, kinds: &[MediaJunk], config: &Config) -> Vec<MediaFinding> {
+    let mut found: Vec<(MediaJunk, PathBuf, u64)> = Vec::new();
+    let mut walker = walkdir::WalkDir::new(root)
+        .follow_links(false)
+        .min_depth(1)
+        .into_iter();
+
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        let path = entry.path();
+        let is_dir = entry.file_type().is_dir();
+        if config.is_excluded(path) {
+            if is_dir {
+                walker.skip_current_dir();
+            }
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy();
+        let kind = if entry.depth() == 1 && is_dir {
+            root_folder_kind(&name).or_else(|| entry_kind(&name, is_dir, false))
+        } else {
+            let in_dcim = path
+                .strip_prefix(root)
+                .ok()
+                .and_then(|rest| rest.components().next())
+                .is_some_and(|first| first.as_os_str().eq_ignore_ascii_case("dcim"));
+            entry_kind(&name, is_dir, in_dcim)
+        };
+        let Some(kind) = kind else {
+            continue;
+        };
+        if is_dir {
+            walker.skip_current_dir();
+        }
+        if !kinds.contains(&kind) {
+            continue;
+        }
+
+        let size = if is_dir {
+            utils::calculate_dir_size(path)
+        } else {
+            entry.metadata().map(|m| m.len()).unwrap_or(0)
+        };
+        found.push((kind, path.to_path_buf(), size));
+    }
+
+    let mut findings: Vec<MediaFinding> = Vec::new();
+    for kind in MediaJunk::ALL {
+        let mut items: Vec<(PathBuf, u64)> = found
+            .iter()
+            .filter(|(k, _, _)| *k == kind)
+            .map(|(_, path, size)| (path.clone(), *size))
+            .collect();
+        if items.is_empty() {
+            continue;
+        }
+        items.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        findings.push(MediaFinding {
+            kind,
+            size_bytes: items.iter().map(|(_, size)| size).sum(),
+            items,
+        });
+    }
+    findings
+}
+
+/// What [`clean_findings`] removed
+#[derive(Debug, Default)]
+pub struct MediaCleanSummary {
+    pub cleaned: usize,
+    pub cleaned_bytes: u64,
+    pub errors: usize,
+    /// Deletion log, when one was written
+    pub log_path: Option<PathBuf>,
+}
+
+/// Permanently delete everything in `findings` and record it in the history
+pub fn clean_findings(findings: &[MediaFinding], cancel: &CancellationToken) -> MediaCleanSummary {
+    let mut summary = MediaCleanSummary::default();
+    let mut log = DeletionLog::new();
+    let permanent = crate::policy::permanent_allowed(true);
+
+    for finding in findings {
+        let paths: Vec<PathBuf> = finding.items.iter().map(|(p, _)| p.clone()).collect();
+        let result = crate::cleaner::clean_paths_batch(&paths, permanent, cancel);
+        for (path, size) in &finding.items {
+            if result.deleted_paths.contains(path) {
+                summary.cleaned += 1;
+                summary.cleaned_bytes += size;
+                log.log_success(path, *size, finding.kind.id(), permanent);
+            } else if !result.skipped_paths.contains(path) && !result.cancelled_paths.contains(path)
+            {
+                summary.errors += 1;
+                log.log_failure(path, *size, finding.kind.id(), permanent, "Deletion failed");
+            }
+        }
+    }
+
+    if !crate::audit::is_enabled() && log.total_items > 0 {
+        summary.log_path = log.save().ok();
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_scan_volume_finds_media_junk() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("DCIM").join("100CANON")).unwrap();
+        fs::create_dir_all(root.join(".Spotlight-V100").join("Store")).unwrap();
+        fs::create_dir_all(root.join("System Volume Information")).unwrap();
+        fs::create_dir_all(root.join("Docs").join(".Trashes")).unwrap();
+        fs::write(
+            root.join("DCIM").join("100CANON").join("MVI_0001.MOV"),
+            "video",
+        )
+        .unwrap();
+        fs::write(
+            root.join("DCIM").join("100CANON").join("MVI_0001.THM"),
+            "thm",
+        )
+        .unwrap();
+        fs::write(root.join("Docs").join("notes.thm"), "kept").unwrap();
+        fs::write(root.join("Docs").join("._notes.txt"), "ab").unwrap();
+        fs::write(root.join("Docs").join("Thumbs.db"), "1234").unwrap();
+        fs::write(
+            root.join(".Spotlight-V100").join("Store").join("db"),
+            "12345",
+        )
+        .unwrap();
+        fs::write(
+            root.join("System Volume Information")
+                .join("IndexerVolumeGuid"),
+            "1",
+        )
+        .unwrap();
+
+        let config = Config::default();
+        let findings = scan_volume(root, &MediaJunk::ALL, &config);
+        let kinds: Vec<MediaJunk> = findings.iter().map(|f| f.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                MediaJunk::CameraThumbnails,
+                MediaJunk::MacMetadata,
+                MediaJunk::WindowsMetadata
+            ]
+        );
+        assert_eq!(
+            findings[0].items,
+            vec![(root.join("DCIM").join("100CANON").join("MVI_0001.THM"), 3)]
+        );
+        // .Trashes only counts at the volume root
+        assert_eq!(
+            findings[1].items,
+            vec![
+                (root.join(".Spotlight-V100"), 5),
+                (root.join("Docs").join("._notes.txt"), 2)
+            ]
+        );
+        assert_eq!(findings[2].size_bytes, 5);
+
+        let only_trash = scan_volume(root, &[MediaJunk::DriveTrash], &config);
+        assert!(only_trash.is_empty());
+        assert_eq!(MediaJunk::from_id("Mac"), Some(MediaJunk::MacMetadata));
+    }
+}
@@ -1,11 +1,17 @@
 //! Restore functionality for recovering deleted files
 //!
-//! Provides ability to restore files from Recycle Bin using deletion history logs
+//! Provides ability to restore files from Recycle Bin using deletion history logs.
+//! Restores from a log are then verified against it: every restored item must
+//! be back with the size it was deleted at, so a folder that only partly came
+//! back is reported instead of counted as restored.
 
+use crate::cancel::CancellationToken;
 use crate::history::{list_logs, load_log, DeletionLog, DeletionRecord};
 use crate::theme::Theme;
 use crate::trash_ops;
+use crate::utils;
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::env;
 use std::io::Write;
@@ -27,24 +33,19 @@ pub fn get_restore_count() -> Result<usize> {
     let latest_log = load_log(&logs[0])?;
 
     // Count restorable items (successful, non-permanent deletions)
-    let count = latest_log
-        .records
-        .iter()
-        .filter(|r| r.success && !r.permanent)
-        .count();
-
-    Ok(count)
+    Ok(latest_log.restorable_count())
 }
 
 /// Restore files from the most recent deletion session
 pub fn restore_last(output_mode: crate::output::OutputMode) -> Result<RestoreResult> {
-    restore_last_with_progress(output_mode, None)
+    restore_last_with_progress(output_mode, None, &CancellationToken::new())
 }
 
 /// Restore files from the most recent deletion session with progress callback
 pub fn restore_last_with_progress(
     output_mode: crate::output::OutputMode,
     progress_callback: Option<RestoreProgressCallback>,
+    cancel: &CancellationToken,
 ) -> Result<RestoreResult> {
     let logs = list_logs()?;
 
@@ -56,7 +57,7 @@ pub fn restore_last_with_progress(
 
     // Get the most recent log
     let latest_log = load_log(&logs[0])?;
-    restore_from_log_with_progress(&latest_log, output_mode, progress_callback)
+    restore_from_log_with_progress(&latest_log, output_mode, progress_callback, cancel)
 }
 
 /// Normalize a path for comparison (handles case-insensitive matching on Windows)
@@ -105,15 +106,17 @@ pub fn restore_from_log(
     log: &DeletionLog,
     output_mode: crate::output::OutputMode,
 ) -> Result<RestoreResult> {
-    restore_from_log_with_progress(log, output_mode, None)
+    restore_from_log_with_progress(log, output_mode, None, &CancellationToken::new())
 }
 
 /// Restore files from a specific deletion log with progress callback
-/// Uses bulk restore operations for better performance on Windows
+/// Uses bulk restore operations for better performance on Windows.
+/// Cancelling `cancel` stops before the next batch and returns what was restored so far.
 pub fn restore_from_log_with_progress(
     log: &DeletionLog,
     output_mode: crate::output::OutputMode,
     mut progress_callback: Option<RestoreProgressCallback>,
+    cancel: &CancellationToken,
 ) -> Result<RestoreResult> {
     let mut result = RestoreResult::default();
 
@@ -143,6 +146,7 @@ pub fn restore_from_log_with_progress(
     let mut items_to_restore: Vec<(&DeletionRecord, trash::TrashItem, u64)> = Vec::new();
     let mut record_to_items: HashMap<String, Vec<(&DeletionRecord, trash::TrashItem, u64)>> =
         HashMap::new();
+    let mut quarantine_restored: Vec<&DeletionRecord> = Vec::new();
 
     // First pass: collect all items that need to be restored
     for record in &log.records {
@@ -188,6 +192,21 @@ pub fn restore_from_log_with_progress(
             }
 
             if !found_any {
+                // Items deleted from a network share sit in its quarantine folder
+                match crate::network::restore_quarantined(Path::new(&record.path)) {
+                    Ok(true) => {
+                        result.restored += 1;
+                        result.restored_bytes += record.size_bytes;
+                        quarantine_restored.push(record);
+                        continue;
+                    }
+                    Ok(false) => {}
+                    Err(e) => {
+                        result.errors += 1;
+                        result.error_reasons.push(e.to_string());
+                        continue;
+                    }
+                }
                 result.not_found += 1;
                 if output_mode == crate::output::OutputMode::VeryVerbose {
                     println!(
@@ -201,6 +220,8 @@ pub fn restore_from_log_with_progress(
     }
 
     if items_to_restore.is_empty() {
+        result.mismatches = verify_restored(quarantine_restored);
+        report_mismatches(&result.mismatches, output_mode);
         // Final progress update
         if let Some(ref mut callback) = progress_callback {
             callback(
@@ -239,8 +260,8 @@ pub fn restore_from_log_with_progress(
     }
 
     for parent in &parent_dirs {
-        if !parent.exists() {
-            if let Err(e) = std::fs::create_dir_all(parent) {
+        if !utils::safe_exists(parent) {
+            if let Err(e) = utils::safe_create_dir_all(parent) {
                 if output_mode != crate::output::OutputMode::Quiet {
                     eprintln!(
                         "[WARNING] Failed to create parent directory {}: {}",
@@ -258,6 +279,10 @@ pub fn restore_from_log_with_progress(
     let mut batch_num = 0;
 
     for batch in items_to_restore.chunks(BATCH_SIZE) {
+        if cancel.is_cancelled() {
+            result.cancelled = true;
+            break;
+        }
         batch_num += 1;
         let batch_items: Vec<trash::TrashItem> = batch
             .iter()
@@ -302,10 +327,14 @@ pub fn restore_from_log_with_progress(
             Err(_e) => {
                 // Bulk restore failed - fall back to individual restore
                 for (record, trash_item, _size_bytes) in batch {
+                    if cancel.is_cancelled() {
+                        result.cancelled = true;
+                        break;
+                    }
                     let dest = trash_item.original_parent.join(&trash_item.name);
 
                     // Skip if destination already exists (may have been restored in partial batch success)
-                    if dest.exists() {
+                    if utils::safe_exists(&dest) {
                         // Count as restored if it exists (likely from partial batch success)
                         if !restored_records.contains(&record.path) {
                             restored_records.insert(record.path.clone());
@@ -386,6 +415,14 @@ pub fn restore_from_log_with_progress(
         }
     }
 
+    let restored = log
+        .records
+        .iter()
+        .filter(|record| record.success && !record.permanent)
+        .filter(|record| restored_records.contains(&record.path));
+    result.mismatches = verify_restored(restored.chain(quarantine_restored));
+    report_mismatches(&result.mismatches, output_mode);
+
     // Final progress update
     if let Some(ref mut callback) = progress_callback {
         callback(
@@ -426,7 +463,7 @@ pub fn restore_path(path: &Path, output_mode: crate::output::OutputMode) -> Resu
                 Ok(()) => {
                     result.restored = 1;
                     // Get file size from restored file
-                    result.restored_bytes = std::fs::metadata(&restored_path)
+                    result.restored_bytes = utils::safe_metadata(&restored_path)
                         .map(|m| m.len())
                         .unwrap_or(0);
                     if output_mode != crate::output::OutputMode::Quiet {
@@ -469,7 +506,7 @@ pub fn restore_path(path: &Path, output_mode: crate::output::OutputMode) -> Resu
                 Ok(()) => {
                     restored_count += 1;
                     // Get file size from restored file
-                    restored_bytes += std::fs::metadata(&restored_path)
+                    restored_bytes += utils::safe_metadata(&restored_path)
                         .map(|m| m.len())
                         .unwrap_or(0);
                 }
@@ -509,6 +546,16 @@ pub fn restore_path(path: &Path, output_mode: crate::output::OutputMode) -> Resu
             }
         }
 
+        Ok(result)
+    } else if crate::network::restore_quarantined(path)? {
+        result.restored = 1;
+        if output_mode != crate::output::OutputMode::Quiet {
+            println!(
+                "{} Restored from quarantine: {}",
+                Theme::success("✓"),
+                Theme::secondary(&path.display().to_string())
+            );
+        }
         Ok(result)
     } else {
         Err(anyhow::anyhow!(
@@ -522,6 +569,7 @@ pub fn restore_path(path: &Path, output_mode: crate::output::OutputMode) -> Resu
 pub fn restore_all_bin(
     output_mode: crate::output::OutputMode,
     mut progress_callback: Option<RestoreProgressCallback>,
+    cancel: &CancellationToken,
 ) -> Result<RestoreResult> {
     let mut result = RestoreResult::default();
 
@@ -564,8 +612,8 @@ pub fn restore_all_bin(
     }
 
     for parent in &parent_dirs {
-        if !parent.exists() {
-            if let Err(e) = std::fs::create_dir_all(parent) {
+        if !utils::safe_exists(parent) {
+            if let Err(e) = utils::safe_create_dir_all(parent) {
                 if output_mode != crate::output::OutputMode::Quiet {
                     eprintln!(
                         "[WARNING] Failed to create parent directory {}: {}",
@@ -581,6 +629,10 @@ pub fn restore_all_bin(
     let mut batch_num = 0;
 
     for batch in recycle_bin_items.chunks(BATCH_SIZE) {
+        if cancel.is_cancelled() {
+            result.cancelled = true;
+            break;
+        }
         batch_num += 1;
 
         // Show batch progress
@@ -613,7 +665,7 @@ pub fn restore_all_bin(
                     result.restored += 1;
                     // Try to get size from restored file
                     let restored_path = item.original_parent.join(&item.name);
-                    if let Ok(metadata) = std::fs::metadata(&restored_path) {
+                    if let Ok(metadata) = utils::safe_metadata(&restored_path) {
                         result.restored_bytes += metadata.len();
                     }
                 }
@@ -622,13 +674,17 @@ pub fn restore_all_bin(
             Err(_e) => {
                 // Bulk restore failed - fall back to individual restore
                 for item in batch {
+                    if cancel.is_cancelled() {
+                        result.cancelled = true;
+                        break;
+                    }
                     let dest = item.original_parent.join(&item.name);
 
                     // Skip if destination already exists (may have been restored in partial batch success)
-                    if dest.exists() {
+                    if utils::safe_exists(&dest) {
                         // Count as restored if it exists (likely from partial batch success)
                         result.restored += 1;
-                        if let Ok(metadata) = std::fs::metadata(&dest) {
+                        if let Ok(metadata) = utils::safe_metadata(&dest) {
                             result.restored_bytes += metadata.len();
                         }
                         processed_count += 1;
@@ -646,7 +702,7 @@ pub fn restore_all_bin(
                         Ok(()) => {
                             result.restored += 1;
                             // Get file size from restored file
-                            if let Ok(metadata) = std::fs::metadata(&dest) {
+                            if let Ok(metadata) = utils::safe_metadata(&dest) {
                                 result.restored_bytes += metadata.len();
                             }
                             processed_count += 1;
@@ -699,12 +755,12 @@ pub fn restore_file(item: &trash::TrashItem) -> Result<()> {
 
     // Check if parent directory exists and is accessible
     if let Some(parent) = dest.parent() {
-        if !parent.exists() {
+        if !utils::safe_exists(parent) {
             // Try to create the parent directory
-            match std::fs::create_dir_all(parent) {
+            match utils::safe_create_dir_all(parent) {
                 Ok(()) => {
                     // Verify parent directory was actually created
-                    if !parent.exists() {
+                    if !utils::safe_exists(parent) {
                         return Err(anyhow::anyhow!(
                             "Parent directory does not exist and could not be created: {}",
                             parent.display()
@@ -721,7 +777,7 @@ pub fn restore_file(item: &trash::TrashItem) -> Result<()> {
             }
         } else {
             // Parent exists, but verify it's actually a directory and we have write access
-            match std::fs::metadata(parent) {
+            match utils::safe_metadata(parent) {
                 Ok(metadata) => {
                     if !metadata.is_dir() {
                         return Err(anyhow::anyhow!(
@@ -742,7 +798,7 @@ pub fn restore_file(item: &trash::TrashItem) -> Result<()> {
     }
 
     // Check if destination already exists
-    if dest.exists() {
+    if utils::safe_exists(&dest) {
         return Err(anyhow::anyhow!(
             "Destination already exists: {}",
             dest.display()
@@ -779,6 +835,79 @@ pub fn restore_file(item: &trash::TrashItem) -> Result<()> {
     }
 }
 
+/// A restored item that doesn't match its deletion record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestoreMismatch {
+    pub path: String,
+    /// Size in the deletion record
+    pub expected_bytes: u64,
+    /// Size found after the restore (None if nothing is there)
+    pub actual_bytes: Option<u64>,
+}
+
+impl RestoreMismatch {
+    pub fn describe(&self) -> String {
+        match self.actual_bytes {
+            None => format!("{}: missing after restore", self.path),
+            Some(actual) => format!(
+                "{}: {} of {} restored",
+                self.path,
+                bytesize::to_string(actual, false),
+                bytesize::to_string(self.expected_bytes, false)
+            ),
+        }
+    }
+}
+
+/// Check restored items against their deletion records
+///
+/// Each one must exist with the size it was deleted at; folders are compared
+/// by total size. Records without a size only need to exist.
+pub fn verify_restored<'a>(
+    records: impl IntoIterator<Item = &'a DeletionRecord>,
+) -> Vec<RestoreMismatch> {
+    let mut checked = HashSet::new();
+    let mut mismatches = Vec::new();
+    for record in records {
+        if !checked.insert(record.path.as_str()) {
+            continue;
+        }
+        let path = Path::new(&record.path);
+        let actual_bytes = match utils::safe_metadata(path) {
+            Ok(metadata) if metadata.is_dir() => Some(utils::calculate_dir_size(path)),
+            Ok(metadata) => Some(metadata.len()),
+            Err(_) => None,
+        };
+        let matches = match actual_bytes {
+            Some(actual) => record.size_bytes == 0 || actual == record.size_bytes,
+            None => false,
+        };
+        if !matches {
+            mismatches.push(RestoreMismatch {
+                path: record.path.clone(),
+                expected_bytes: record.size_bytes,
+                actual_bytes,
+            });
+        }
+    }
+    mismatches
+}
+
+fn report_mismatches(mismatches: &[RestoreMismatch], output_mode: crate::output::OutputMode) {
+    if mismatches.is_empty() || output_mode == crate::output::OutputMode::Quiet {
+        return;
+    }
+    println!();
+    println!(
+        "{} {} restored items don't match their deletion record:",
+        Theme::warning("Verification:"),
+        mismatches.len()
+    );
+    for mismatch in mismatches {
+        println!("  {} {}", Theme::warning("!"), mismatch.describe());
+    }
+}
+
 /// Result of a restore operation
 #[derive(Debug, Default)]
 pub struct RestoreResult {
@@ -787,17 +916,24 @@ pub struct RestoreResult {
     pub errors: usize,
     pub not_found: usize,
     pub error_reasons: Vec<String>, // Store error messages for display
+    pub cancelled: bool,            // Stopped early through a CancellationToken
+    /// Restored items that failed verification (restores from a log only)
+    pub mismatches: Vec<RestoreMismatch>,
 }
 
 impl RestoreResult {
     pub fn summary(&self) -> String {
-        format!(
+        let mut summary = format!(
             "Restored {} items ({}), {} errors, {} not found",
             self.restored,
             bytesize::to_string(self.restored_bytes, true),
             self.errors,
             self.not_found
-        )
+        );
+        if !self.mismatches.is_empty() {
+            summary.push_str(&format!(", {} incomplete", self.mismatches.len()));
+        }
+        summary
     }
 }
 
@@ -823,6 +959,8 @@ mod tests {
             errors: 1,
             not_found: 2,
             error_reasons: vec![],
+            cancelled: false,
+            mismatches: vec![],
         };
 
         let summary = result.summary();
@@ -852,4 +990,48 @@ mod tests {
             summary
         );
     }
+
+    #[test]
+    fn test_verify_restored_reports_missing_and_partial() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("report.txt");
+        std::fs::write(&file, "12345").unwrap();
+        let folder = dir.path().join("photos");
+        std::fs::create_dir(&folder).unwrap();
+        std::fs::write(folder.join("a.jpg"), "123").unwrap();
+        let gone = dir.path().join("gone.txt");
+        let notes = dir.path().join("notes.txt");
+        std::fs::write(&notes, "abc").unwrap();
+
+        let records = [
+            DeletionRecord::success(&file, 5, "temp", false),
+            // Only part of the folder came back
+            DeletionRecord::success(&folder, 10, "temp", false),
+            DeletionRecord::success(&gone, 5, "temp", false),
+            // No recorded size: existing is enough
+            DeletionRecord::success(&notes, 0, "temp", false),
+        ];
+        let mismatches = verify_restored(&records);
+        assert_eq!(
+            mismatches,
+            [
+                RestoreMismatch {
+                    path: folder.display().to_string(),
+                    expected_bytes: 10,
+                    actual_bytes: Some(3),
+                },
+                RestoreMismatch {
+                    path: gone.display().to_string(),
+                    expected_bytes: 5,
+                    actual_bytes: None,
+                },
+            ]
+        );
+
+        let result = RestoreResult {
+            mismatches,
+            ..Default::default()
+        };
+        assert!(result.summary().ends_with(", 2 incomplete"));
+    }
 }
@@ -0,0 +1,235 @@
+//! Portable cache bundles for moving to a new machine
+//!
+//! `wole cache export` writes a JSON bundle with the config (exclusions,
+//! thresholds, category settings, pinned folders, ...) and the directory
+//! snapshot of the latest scan. `wole cache import` restores the config and
+//! records the snapshot as a finished scan, so the first scan on the new
+//! machine is compared against it (`wole diff`) instead of starting from
+//! nothing, and the full-disk first-scan baseline is skipped.
+//!
+//! File signatures and the search index describe files on one disk and are
+//! not exported; the next scan rebuilds them. Paths under the exporting user's
+//! home folder are moved to the importing user's home folder.
+
+use crate::config::Config;
+use crate::scan_cache::snapshot::DirSnapshot;
+use crate::scan_cache::ScanCache;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Bumped when the file format changes; newer bundles are refused
+const BUNDLE_VERSION: u32 = 1;
+
+/// Everything `wole cache export` writes
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CacheBundle {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    /// Home folder of the user who exported the bundle
+    pub home: Option<PathBuf>,
+    pub config: Config,
+    pub baseline: Option<Baseline>,
+}
+
+/// Directory sizes recorded by the latest scan
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Baseline {
+    pub scanned_at: DateTime<Utc>,
+    /// Sorted by path so bundles diff cleanly
+    pub dirs: Vec<(PathBuf, u64)>,
+}
+
+/// What an import changed
+#[derive(Debug, Default)]
+pub struct ImportSummary {
+    /// Previous config file, if there was one
+    pub config_backup: Option<PathBuf>,
+    /// Folders in the imported baseline (0 = the bundle had none)
+    pub baseline_dirs: usize,
+}
+
+fn home_dir() -> Option<PathBuf> {
+    directories::UserDirs::new().map(|dirs| dirs.home_dir().to_path_buf())
+}
+
+/// Build a bundle from `config` and the newest snapshot in `cache`
+pub fn build_bundle(config: &Config, cache: Option<&ScanCache>) -> Result<CacheBundle> {
+    let mut baseline = None;
+    if let Some(cache) = cache {
+        if let Some(&(scan_id, scanned_at)) = cache.snapshot_sessions(1)?.first() {
+            let mut dirs: Vec<(PathBuf, u64)> =
+                cache.get_dir_snapshot(scan_id)?.into_iter().collect();
+            dirs.sort();
+            baseline = Some(Baseline { scanned_at, dirs });
+        }
+    }
+
+    Ok(CacheBundle {
+        version: BUNDLE_VERSION,
+        exported_at: Utc::now(),
+        home: home_dir(),
+        config: config.clone(),
+        baseline,
+    })
+}
+
+/// Write the bundle for the current config and scan cache to `dest`
+pub fn export_bundle(dest: &Path) -> Result<CacheBundle> {
+    let config = Config::load();
+    let cache = ScanCache::open().ok();
+    let bundle = build_bundle(&config, cache.as_ref())?;
+
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+    let json = serde_json::to_string_pretty(&bundle).context("Failed to serialize bundle")?;
+    fs::write(dest, json).with_context(|| format!("Failed to write {}", dest.display()))?;
+    Ok(bundle)
+}
+
+/// Read and check a bundle written by [`export_bundle`]
+pub fn read_bundle(src: &Path) -> Result<CacheBundle> {
+    let json =
+        fs::read_to_string(src).with_context(|| format!("Failed to read {}", src.display()))?;
+    let bundle: CacheBundle = serde_json::from_str(&json)
+        .with_context(|| format!("{} is not a wole cache bundle", src.display()))?;
+    if bundle.version > BUNDLE_VERSION {
+        anyhow::bail!(
+            "{} was exported by a newer wole (bundle version {}); update wole first",
+            src.display(),
+            bundle.version
+        );
+    }
+    Ok(bundle)
+}
+
+/// `path` moved from under `from` to under `to` (unchanged if it isn't under `from`)
+fn rehome(path: &Path, from: &Path, to: &Path) -> PathBuf {
+    match path.strip_prefix(from) {
+        Ok(rest) => to.join(rest),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+fn rehome_str(value: &mut String, from: &Path, to: &Path) {
+    *value = rehome(Path::new(value.as_str()), from, to)
+        .display()
+        .to_string();
+}
+
+/// Move every home-relative path in the bundle to the home folder `to`
+pub fn rehome_bundle(bundle: &mut CacheBundle, to: &Path) {
+    let Some(from) = bundle.home.take() else {
+        return;
+    };
+    if from == to {
+        bundle.home = Some(from);
+        return;
+    }
+
+    let config = &mut bundle.config;
+    let mut patterns = std::mem::take(&mut config.exclusions.patterns);
+    for pattern in &mut patterns {
+        rehome_str(pattern, &from, to);
+    }
+    for pattern in patterns {
+        config.exclusions.add(pattern);
+    }
+    for root in &mut config.paths.scan_roots {
+        rehome_str(root, &from, to);
+    }
+    for dir in &mut config.ui.pinned_dirs {
+        rehome_str(dir, &from, to);
+    }
+    if let Some(path) = config.ui.default_scan_path.as_mut() {
+        rehome_str(path, &from, to);
+    }
+
+    if let Some(baseline) = bundle.baseline.as_mut() {
+        for (path, _) in &mut baseline.dirs {
+            *path = rehome(path, &from, to);
+        }
+    }
+    bundle.home = Some(to.to_path_buf());
+}
+
+/// Restore the config and scan baseline from the bundle at `src`
+///
+/// The current config file is kept next to it as `config.toml.bak`.
+pub fn import_bundle(src: &Path) -> Result<ImportSummary> {
+    let mut bundle = read_bundle(src)?;
+    if let Some(home) = home_dir() {
+        rehome_bundle(&mut bundle, &home);
+    }
+
+    let mut summary = ImportSummary {
+        baseline_dirs: bundle.baseline.as_ref().map_or(0, |b| b.dirs.len()),
+        ..Default::default()
+    };
+    if crate::audit::is_enabled() {
+        crate::audit::note(&format!("import cache bundle {}", src.display()));
+        return Ok(summary);
+    }
+
+    let config_path = Config::config_path()?;
+    if config_path.exists() {
+        let backup = config_path.with_extension("toml.bak");
+        fs::copy(&config_path, &backup)
+            .with_context(|| format!("Failed to back up {}", config_path.display()))?;
+        summary.config_backup = Some(backup);
+    }
+    bundle.config.save()?;
+
+    if let Some(baseline) = bundle.baseline {
+        let snapshot: DirSnapshot = baseline.dirs.into_iter().collect();
+        let mut cache = ScanCache::open()?;
+        cache.import_snapshot(baseline.scanned_at, &snapshot)?;
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rehome_bundle() {
+        let from = PathBuf::from("/home/old");
+        let to = PathBuf::from("/home/new");
+        let mut config = Config::default();
+        config
+            .exclusions
+            .add(from.join("Projects").display().to_string());
+        config.exclusions.add("**/keep-me".to_string());
+        config.ui.pinned_dirs.push("/srv/data".to_string());
+        let mut bundle = CacheBundle {
+            version: BUNDLE_VERSION,
+            exported_at: Utc::now(),
+            home: Some(from.clone()),
+            config,
+            baseline: Some(Baseline {
+                scanned_at: Utc::now(),
+                dirs: vec![(from.join("Downloads"), 10), (PathBuf::from("/tmp"), 5)],
+            }),
+        };
+
+        rehome_bundle(&mut bundle, &to);
+        assert_eq!(bundle.home, Some(to.clone()));
+        assert_eq!(
+            bundle.config.exclusions.patterns,
+            vec![
+                to.join("Projects").display().to_string(),
+                "**/keep-me".to_string()
+            ]
+        );
+        assert_eq!(bundle.config.ui.pinned_dirs, vec!["/srv/data".to_string()]);
+        assert_eq!(
+            bundle.baseline.unwrap().dirs,
+            vec![(to.join("Downloads"), 10), (PathBuf::from("/tmp"), 5)]
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Checkpoints of an interrupted TUI scan
+//!
+//! The TUI scans one category at a time. Each finished category is written to
+//! the scan cache together with the scan root and settings, so a scan that was
+//! cancelled, closed or crashed can pick up at the first category that didn't
+//! finish instead of starting over. A scan that completes removes its checkpoint.
+//!
+//! Categories that walk a list of folders (large, old and empty files, and the
+//! sizing of build artifacts) also save a [`CategoryFrontier`] after each
+//! folder, so an interrupted category continues with the folders it hadn't
+//! walked and keeps what the others found.
+
+use crate::categories::duplicates::DuplicateGroup;
+use crate::categories::trash::TrashEntry;
+use crate::cli::ScanOptions;
+use crate::output::CategoryResult;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// What an interrupted scan got done
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanCheckpoint {
+    pub root: PathBuf,
+    /// Thresholds and scope of the scan (see [`checkpoint_settings`])
+    pub settings: String,
+    /// Every category of the scan in scan order (display names)
+    pub categories: Vec<String>,
+    /// Categories whose results are saved
+    pub done: Vec<String>,
+    /// Unfinished categories with a saved frontier
+    pub partial: Vec<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ScanCheckpoint {
+    /// Categories still to scan, in scan order
+    pub fn pending(&self) -> Vec<&str> {
+        self.categories
+            .iter()
+            .filter(|c| !self.done.contains(c))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Whether there is anything to continue from
+    pub fn has_progress(&self) -> bool {
+        !self.done.is_empty() || !self.partial.is_empty()
+    }
+
+    /// Whether a scan of `root` over `categories` with `settings` can continue from here
+    pub fn matches(&self, root: &Path, settings: &str, categories: &[&str]) -> bool {
+        self.root == root
+            && self.settings == settings
+            && self.categories.len() == categories.len()
+            && self.categories.iter().zip(categories).all(|(a, b)| a == b)
+    }
+}
+
+/// A finished category as saved in the checkpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointedCategory {
+    pub result: CategoryResult,
+    /// Only set for Duplicates
+    pub duplicate_groups: Option<Vec<DuplicateGroup>>,
+    /// Only set for Trash
+    #[serde(default)]
+    pub trash_entries: Vec<TrashEntry>,
+}
+
+/// How far an unfinished category got through its folders
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryFrontier {
+    /// Folders still to walk, in walk order
+    pub pending: Vec<PathBuf>,
+    /// What the walked folders found, with sizes
+    pub found: Vec<(PathBuf, u64)>,
+}
+
+/// Frontier checkpointing for one category scan
+///
+/// The scanner hands this to a category with the frontier the checkpoint saved
+/// (if any) and a callback that writes frontiers back; [`FrontierWalk::none`]
+/// walks from scratch without saving anything.
+pub struct FrontierWalk<'a> {
+    resume: Option<CategoryFrontier>,
+    save: Box<dyn FnMut(&CategoryFrontier) + 'a>,
+    last_saved: Option<Instant>,
+}
+
+/// Frontiers are written at most this often (each write holds the whole frontier)
+const SAVE_INTERVAL: Duration = Duration::from_secs(2);
+
+impl<'a> FrontierWalk<'a> {
+    pub fn new(resume: Option<CategoryFrontier>, save: impl FnMut(&CategoryFrontier) + 'a) -> Self {
+        Self {
+            resume,
+            save: Box::new(save),
+            last_saved: None,
+        }
+    }
+
+    /// A walk that is neither resumed nor checkpointed
+    pub fn none() -> Self {
+        Self::new(None, |_| {})
+    }
+
+    /// Frontier to start from: the saved one when resuming, all of `dirs()`
+    /// otherwise (not called when resuming)
+    pub fn start(
+        &mut self,
+        dirs: impl FnOnce() -> Result<Vec<PathBuf>>,
+    ) -> Result<CategoryFrontier> {
+        match self.resume.take() {
+            Some(frontier) => Ok(frontier),
+            None => Ok(CategoryFrontier {
+                pending: dirs()?,
+                found: Vec::new(),
+            }),
+        }
+    }
+
+    /// Checkpoint `frontier` (call once a folder is fully walked)
+    pub fn save(&mut self, frontier: &CategoryFrontier) {
+        if self
+            .last_saved
+            .is_some_and(|at| at.elapsed() < SAVE_INTERVAL)
+        {
+            return;
+        }
+        self.last_saved = Some(Instant::now());
+        (self.save)(frontier);
+    }
+}
+
+/// Scan settings besides the categories that change what a category finds
+pub fn checkpoint_settings(options: &ScanOptions) -> String {
+    format!(
+        "project_age={} min_age={} min_size={} scope={}",
+        options.project_age_days,
+        options.min_age_days,
+        options.min_size_bytes,
+        options.scope.as_str()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_and_matches() {
+        let checkpoint = ScanCheckpoint {
+            root: PathBuf::from("C:\\Users\\me"),
+            settings: "project_age=14 min_age=30 min_size=100 scope=current".to_string(),
+            categories: vec![
+                "Package Cache".to_string(),
+                "Temp Files".to_string(),
+                "Duplicates".to_string(),
+            ],
+            done: vec!["Package Cache".to_string()],
+            partial: vec!["Temp Files".to_string()],
+            updated_at: Utc::now(),
+        };
+
+        assert_eq!(checkpoint.pending(), vec!["Temp Files", "Duplicates"]);
+        assert!(checkpoint.has_progress());
+
+        let categories = ["Package Cache", "Temp Files", "Duplicates"];
+        assert!(checkpoint.matches(
+            Path::new("C:\\Users\\me"),
+            &checkpoint.settings,
+            &categories
+        ));
+        assert!(!checkpoint.matches(Path::new("D:\\"), &checkpoint.settings, &categories));
+        assert!(!checkpoint.matches(
+            Path::new("C:\\Users\\me"),
+            "project_age=7 min_age=30 min_size=100 scope=current",
+            &categories
+        ));
+        assert!(!checkpoint.matches(
+            Path::new("C:\\Users\\me"),
+            &checkpoint.settings,
+            &categories[..2]
+        ));
+    }
+
+    #[test]
+    fn test_frontier_walk_resumes_saved_frontier() {
+        let dirs = vec![PathBuf::from("a"), PathBuf::from("b")];
+        let from_scratch = FrontierWalk::none().start(|| Ok(dirs.clone())).unwrap();
+        assert_eq!(from_scratch.pending, dirs);
+
+        let saved = CategoryFrontier {
+            pending: vec![PathBuf::from("b")],
+            found: vec![(PathBuf::from("a").join("big.iso"), 42)],
+        };
+        let mut written = Vec::new();
+        let mut walk = FrontierWalk::new(Some(saved.clone()), |f| written.push(f.clone()));
+        let mut frontier = walk
+            .start(|| panic!("a resumed walk doesn't list its folders again"))
+            .unwrap();
+        assert_eq!(frontier, saved);
+
+        frontier.pending.clear();
+        walk.save(&frontier);
+        // Right after a write, the next one waits for the interval
+        walk.save(&CategoryFrontier::default());
+        drop(walk);
+        assert_eq!(written, vec![frontier]);
+    }
+}
@@ -1,18 +1,69 @@
 //! SQLite database operations for scan cache
 
+use crate::scan_cache::checkpoint::{CategoryFrontier, CheckpointedCategory, ScanCheckpoint};
+use crate::scan_cache::index::IndexedFile;
 use crate::scan_cache::session::{ScanSession, ScanStats};
 use crate::scan_cache::signature::{FileSignature, FileStatus};
+use crate::scan_cache::snapshot::{diff_snapshots, DirSnapshot, SnapshotDiff, SNAPSHOT_HISTORY};
+use crate::size::DirSize;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use rusqlite::{params, Connection, ErrorCode};
+use rusqlite::{params, Connection, ErrorCode, OpenFlags};
+use serde::{Deserialize, Serialize};
 use serde_json;
 use std::collections::HashMap;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-const SCHEMA_VERSION: i32 = 3;
+const SCHEMA_VERSION: i32 = 8;
 const DB_BUSY_TIMEOUT_SECS: u64 = 30;
+const DIR_SIZE_MAX_AGE_SECS: i64 = 24 * 60 * 60;
+/// How often opening the cache runs `PRAGMA quick_check`
+const INTEGRITY_CHECK_INTERVAL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+const DB_FILE_NAME: &str = "scan_cache.db";
+/// Touched after every passed integrity check
+const INTEGRITY_MARKER: &str = "scan_cache.checked";
+
+/// Size and contents of the scan cache (`wole cache stats`)
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheStats {
+    pub path: PathBuf,
+    /// Database plus its WAL and shared-memory files
+    pub size_bytes: u64,
+    pub schema_version: i32,
+    pub file_records: u64,
+    /// Total size of the files the cache knows about
+    pub tracked_bytes: u64,
+    pub scan_sessions: u64,
+    pub last_scan: Option<DateTime<Utc>>,
+    pub snapshots: u64,
+    pub dir_sizes: u64,
+    pub index_entries: u64,
+}
+
+/// Cheap summary of the scan cache for the Status screen
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheSummary {
+    pub size_bytes: u64,
+    pub last_scan: Option<DateTime<Utc>>,
+}
+
+impl CacheSummary {
+    /// "3d ago", "5h ago", ... or "never" if no scan has finished
+    pub fn age_label(&self) -> String {
+        let Some(last_scan) = self.last_scan else {
+            return "never".to_string();
+        };
+        let secs = (Utc::now() - last_scan).num_seconds().max(0);
+        match secs {
+            s if s < 60 => "just now".to_string(),
+            s if s < 3600 => format!("{}m ago", s / 60),
+            s if s < 86400 => format!("{}h ago", s / 3600),
+            s => format!("{}d ago", s / 86400),
+        }
+    }
+}
 
 /// Scan cache database
 pub struct ScanCache {
@@ -23,12 +74,16 @@ pub struct ScanCache {
 impl ScanCache {
     /// Open or create the scan cache database
     pub fn open() -> Result<Self> {
-        let cache_dir = get_cache_dir()?;
+        Self::open_in(&get_cache_dir()?)
+    }
+
+    /// Open or create the scan cache database in `cache_dir`
+    pub(crate) fn open_in(cache_dir: &Path) -> Result<Self> {
         std::fs::create_dir_all(&cache_dir).with_context(|| {
             format!("Failed to create cache directory: {}", cache_dir.display())
         })?;
 
-        let db_path = cache_dir.join("scan_cache.db");
+        let db_path = cache_dir.join(DB_FILE_NAME);
 
         let db = match Self::open_connection(&db_path) {
             Ok(db) => db,
@@ -52,9 +107,94 @@ impl ScanCache {
             return Self::recover_database(&db_path, e);
         }
 
+        // A database that opens fine can still have damaged pages; check it
+        // now and then and rebuild instead of failing halfway through a scan
+        let marker = cache_dir.join(INTEGRITY_MARKER);
+        if integrity_check_due(&marker) {
+            if let Err(e) = cache.check_integrity() {
+                drop(cache);
+                return Self::recover_database(&db_path, e);
+            }
+            let _ = std::fs::write(&marker, b"");
+        }
+
         Ok(cache)
     }
 
+    /// Delete the cache database and start over with an empty one
+    pub fn reset() -> Result<Self> {
+        let cache_dir = get_cache_dir()?;
+        remove_database_files(&cache_dir.join(DB_FILE_NAME))?;
+        Self::open_in(&cache_dir)
+    }
+
+    /// Run `PRAGMA quick_check`; fails if the database is damaged
+    pub fn check_integrity(&self) -> Result<()> {
+        let mut stmt = self.db.prepare("PRAGMA quick_check")?;
+        let problems: Vec<String> = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        match problems.as_slice() {
+            [ok] if ok == "ok" => Ok(()),
+            _ => Err(anyhow::anyhow!(
+                "Scan cache database is corrupted: {}",
+                problems.join("; ")
+            )),
+        }
+    }
+
+    /// Fold the WAL back into the database and compact the file
+    ///
+    /// Returns the size on disk before and after.
+    pub fn vacuum(&mut self) -> Result<(u64, u64)> {
+        let db_path = get_cache_dir()?.join(DB_FILE_NAME);
+        let before = database_size(&db_path);
+        self.db
+            .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))
+            .with_context(|| "Failed to checkpoint the WAL")?;
+        self.db
+            .execute_batch("VACUUM")
+            .with_context(|| "Failed to vacuum the scan cache")?;
+        Ok((before, database_size(&db_path)))
+    }
+
+    /// Size and row counts for `wole cache stats`
+    pub fn stats(&self) -> Result<CacheStats> {
+        let count = |table: &str| -> Result<u64> {
+            let n: i64 =
+                self.db
+                    .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+                        row.get(0)
+                    })?;
+            Ok(n.max(0) as u64)
+        };
+        let path = get_cache_dir()?.join(DB_FILE_NAME);
+        let (file_records, tracked_bytes) = self.get_cache_stats()?;
+        let schema_version: i32 =
+            self.db
+                .query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+                    row.get(0)
+                })?;
+        let snapshots: i64 = self.db.query_row(
+            "SELECT COUNT(DISTINCT scan_id) FROM dir_snapshots",
+            [],
+            |row| row.get(0),
+        )?;
+
+        Ok(CacheStats {
+            size_bytes: database_size(&path),
+            path,
+            schema_version,
+            file_records: file_records as u64,
+            tracked_bytes,
+            scan_sessions: count("scan_sessions")?,
+            last_scan: last_finished_scan(&self.db),
+            snapshots: snapshots.max(0) as u64,
+            dir_sizes: count("dir_sizes")?,
+            index_entries: count("file_index")?,
+        })
+    }
+
     fn open_connection(db_path: &Path) -> Result<Connection> {
         let db = Connection::open(db_path)
             .with_context(|| format!("Failed to open database: {}", db_path.display()))?;
@@ -298,6 +438,111 @@ impl ScanCache {
             )
             .with_context(|| "Failed to create file_categories path index")?;
 
+            // Update schema version
+            tx.execute("UPDATE schema_version SET version = ?1", [3])
+                .with_context(|| "Failed to update schema version")?;
+        }
+
+        if from_version < 4 {
+            // Migration to version 4: cache computed directory sizes keyed by directory mtime
+            tx.execute(
+                "CREATE TABLE IF NOT EXISTS dir_sizes (
+                    path TEXT PRIMARY KEY,
+                    mtime_secs INTEGER NOT NULL,
+                    mtime_nsecs INTEGER NOT NULL,
+                    size INTEGER NOT NULL,
+                    size_on_disk INTEGER NOT NULL,
+                    file_count INTEGER NOT NULL,
+                    updated_at INTEGER NOT NULL
+                )",
+                [],
+            )
+            .with_context(|| "Failed to create dir_sizes table")?;
+
+            // Update schema version
+            tx.execute("UPDATE schema_version SET version = ?1", [4])
+                .with_context(|| "Failed to update schema version")?;
+        }
+
+        if from_version < 5 {
+            // Migration to version 5: file name search index
+            tx.execute(
+                "CREATE TABLE IF NOT EXISTS file_index (
+                    path TEXT PRIMARY KEY,
+                    name TEXT NOT NULL,
+                    size INTEGER NOT NULL,
+                    mtime_secs INTEGER NOT NULL
+                )",
+                [],
+            )
+            .with_context(|| "Failed to create file_index table")?;
+            tx.execute(
+                "CREATE INDEX IF NOT EXISTS idx_file_index_name ON file_index(name)",
+                [],
+            )
+            .with_context(|| "Failed to create file_index name index")?;
+
+            // Update schema version
+            tx.execute("UPDATE schema_version SET version = ?1", [5])
+                .with_context(|| "Failed to update schema version")?;
+        }
+
+        if from_version < 6 {
+            // Migration to version 6: per-scan directory size snapshots
+            tx.execute(
+                "CREATE TABLE IF NOT EXISTS dir_snapshots (
+                    scan_id INTEGER NOT NULL,
+                    path TEXT NOT NULL,
+                    size INTEGER NOT NULL,
+                    PRIMARY KEY (scan_id, path)
+                )",
+                [],
+            )
+            .with_context(|| "Failed to create dir_snapshots table")?;
+
+            // Update schema version
+            tx.execute("UPDATE schema_version SET version = ?1", [6])
+                .with_context(|| "Failed to update schema version")?;
+        }
+
+        if from_version < 7 {
+            // Migration to version 7: checkpoint of an interrupted scan (at most one)
+            tx.execute(
+                "CREATE TABLE IF NOT EXISTS scan_checkpoint (
+                    id INTEGER PRIMARY KEY CHECK (id = 1),
+                    root TEXT NOT NULL,
+                    settings TEXT NOT NULL,
+                    categories TEXT NOT NULL,
+                    updated_at INTEGER NOT NULL
+                )",
+                [],
+            )
+            .with_context(|| "Failed to create scan_checkpoint table")?;
+            tx.execute(
+                "CREATE TABLE IF NOT EXISTS checkpoint_categories (
+                    category TEXT PRIMARY KEY,
+                    result TEXT NOT NULL
+                )",
+                [],
+            )
+            .with_context(|| "Failed to create checkpoint_categories table")?;
+
+            // Update schema version
+            tx.execute("UPDATE schema_version SET version = ?1", [7])
+                .with_context(|| "Failed to update schema version")?;
+        }
+
+        if from_version < 8 {
+            // Migration to version 8: folder frontiers of unfinished checkpointed categories
+            tx.execute(
+                "CREATE TABLE IF NOT EXISTS checkpoint_frontiers (
+                    category TEXT PRIMARY KEY,
+                    frontier TEXT NOT NULL
+                )",
+                [],
+            )
+            .with_context(|| "Failed to create checkpoint_frontiers table")?;
+
             // Update schema version
             tx.execute("UPDATE schema_version SET version = ?1", [SCHEMA_VERSION])
                 .with_context(|| "Failed to update schema version")?;
@@ -645,6 +890,437 @@ impl ScanCache {
         Ok(paths)
     }
 
+    /// Get a cached directory size if the tree's `mtime` stamp still matches
+    ///
+    /// The stamp is chosen by the caller (see [`crate::size::dir_size_cached`]).
+    /// Files edited in place don't change it, so entries also expire after
+    /// `DIR_SIZE_MAX_AGE_SECS`.
+    pub fn get_dir_size(&self, path: &Path, mtime: SystemTime) -> Result<Option<DirSize>> {
+        let path_str = normalize_path(path);
+        let (mtime_secs, mtime_nsecs) = system_time_to_secs_nsecs(mtime);
+        let min_updated_at = Utc::now().timestamp() - DIR_SIZE_MAX_AGE_SECS;
+
+        let result: Option<(i64, i64, i64)> = self
+            .db
+            .query_row(
+                "SELECT size, size_on_disk, file_count FROM dir_sizes
+                 WHERE path = ?1 AND mtime_secs = ?2 AND mtime_nsecs = ?3 AND updated_at >= ?4",
+                params![path_str, mtime_secs, mtime_nsecs, min_updated_at],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .ok();
+
+        Ok(result.map(|(size, size_on_disk, file_count)| DirSize {
+            bytes: size as u64,
+            size_on_disk: size_on_disk as u64,
+            files: file_count as u64,
+        }))
+    }
+
+    /// Store a computed directory size
+    pub fn put_dir_size(&mut self, path: &Path, mtime: SystemTime, totals: &DirSize) -> Result<()> {
+        let path_str = normalize_path(path);
+        let (mtime_secs, mtime_nsecs) = system_time_to_secs_nsecs(mtime);
+
+        self.db.execute(
+            "INSERT INTO dir_sizes (path, mtime_secs, mtime_nsecs, size, size_on_disk, file_count, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT(path) DO UPDATE SET
+                mtime_secs = excluded.mtime_secs,
+                mtime_nsecs = excluded.mtime_nsecs,
+                size = excluded.size,
+                size_on_disk = excluded.size_on_disk,
+                file_count = excluded.file_count,
+                updated_at = excluded.updated_at",
+            params![
+                path_str,
+                mtime_secs,
+                mtime_nsecs,
+                clamp_size_to_i64(totals.bytes),
+                clamp_size_to_i64(totals.size_on_disk),
+                clamp_size_to_i64(totals.files),
+                Utc::now().timestamp()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Add or refresh files in the search index
+    pub fn index_files_batch<'a>(
+        &mut self,
+        files: impl IntoIterator<Item = &'a FileSignature>,
+    ) -> Result<()> {
+        let tx = self
+            .db
+            .transaction()
+            .with_context(|| "Failed to start transaction")?;
+
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT INTO file_index (path, name, size, mtime_secs)
+                 VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT(path) DO UPDATE SET
+                    name = ?2,
+                    size = ?3,
+                    mtime_secs = ?4",
+            )?;
+
+            for sig in files {
+                let name = sig
+                    .path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_lowercase())
+                    .unwrap_or_default();
+                let (mtime_secs, _) = system_time_to_secs_nsecs(sig.mtime);
+                stmt.execute(params![
+                    normalize_path(&sig.path),
+                    name,
+                    clamp_size_to_i64(sig.size),
+                    mtime_secs
+                ])?;
+            }
+        }
+
+        tx.commit()
+            .with_context(|| "Failed to commit transaction")?;
+        Ok(())
+    }
+
+    /// Search the index by file name, largest files first
+    ///
+    /// Every whitespace-separated term must appear in the name (case-insensitive).
+    pub fn search_index(&self, query: &str, limit: usize) -> Result<Vec<IndexedFile>> {
+        let terms: Vec<String> = query
+            .split_whitespace()
+            .map(|t| format!("%{}%", escape_like(&t.to_lowercase())))
+            .collect();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let conditions = (1..=terms.len())
+            .map(|i| format!("name LIKE ?{} ESCAPE '\\'", i))
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        let sql = format!(
+            "SELECT path, size, mtime_secs FROM file_index WHERE {} ORDER BY size DESC LIMIT {}",
+            conditions, limit
+        );
+
+        let mut stmt = self.db.prepare(&sql)?;
+        let rows = stmt.query_map(rusqlite::params_from_iter(terms.iter()), |row| {
+            Ok(IndexedFile {
+                path: decode_path(&row.get::<_, String>(0)?),
+                size: row.get::<_, i64>(1)?.max(0) as u64,
+                modified: UNIX_EPOCH + Duration::from_secs(row.get::<_, i64>(2)?.max(0) as u64),
+            })
+        })?;
+
+        let mut results = Vec::new();
+        for row in rows {
+            results.push(row?);
+        }
+        Ok(results)
+    }
+
+    /// Remove a path from the search index (after it was deleted)
+    pub fn remove_from_index(&mut self, path: &Path) -> Result<()> {
+        self.db.execute(
+            "DELETE FROM file_index WHERE path = ?1",
+            [normalize_path(path)],
+        )?;
+        Ok(())
+    }
+
+    /// Drop every entry from the search index (before a rebuild)
+    pub fn clear_index(&mut self) -> Result<()> {
+        self.db.execute("DELETE FROM file_index", [])?;
+        Ok(())
+    }
+
+    /// Number of files in the search index
+    pub fn index_count(&self) -> Result<u64> {
+        let count: i64 = self
+            .db
+            .query_row("SELECT COUNT(*) FROM file_index", [], |row| row.get(0))?;
+        Ok(count as u64)
+    }
+
+    /// Store the directory snapshot for a scan session, pruning old sessions
+    pub fn put_dir_snapshot(&mut self, scan_id: i64, snapshot: &DirSnapshot) -> Result<()> {
+        let tx = self
+            .db
+            .transaction()
+            .with_context(|| "Failed to start transaction")?;
+
+        tx.execute("DELETE FROM dir_snapshots WHERE scan_id = ?1", [scan_id])?;
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT INTO dir_snapshots (scan_id, path, size) VALUES (?1, ?2, ?3)",
+            )?;
+            for (path, size) in snapshot {
+                stmt.execute(params![
+                    scan_id,
+                    normalize_path(path),
+                    clamp_size_to_i64(*size)
+                ])?;
+            }
+        }
+
+        // Only the most recent sessions are ever compared
+        tx.execute(
+            "DELETE FROM dir_snapshots WHERE scan_id NOT IN (
+                SELECT DISTINCT scan_id FROM dir_snapshots ORDER BY scan_id DESC LIMIT ?1
+            )",
+            [SNAPSHOT_HISTORY],
+        )?;
+
+        tx.commit()
+            .with_context(|| "Failed to commit transaction")?;
+        Ok(())
+    }
+
+    /// Load the directory snapshot recorded for a scan session
+    pub fn get_dir_snapshot(&self, scan_id: i64) -> Result<DirSnapshot> {
+        let mut stmt = self
+            .db
+            .prepare("SELECT path, size FROM dir_snapshots WHERE scan_id = ?1")?;
+        let rows = stmt.query_map([scan_id], |row| {
+            Ok((
+                decode_path(&row.get::<_, String>(0)?),
+                row.get::<_, i64>(1)?.max(0) as u64,
+            ))
+        })?;
+
+        let mut snapshot = DirSnapshot::new();
+        for row in rows {
+            let (path, size) = row?;
+            snapshot.insert(path, size);
+        }
+        Ok(snapshot)
+    }
+
+    /// Scan sessions that have a snapshot, newest first, with their start times
+    pub fn snapshot_sessions(&self, limit: usize) -> Result<Vec<(i64, DateTime<Utc>)>> {
+        let mut stmt = self.db.prepare(
+            "SELECT s.id, s.started_at FROM scan_sessions s
+             WHERE EXISTS (SELECT 1 FROM dir_snapshots d WHERE d.scan_id = s.id)
+             ORDER BY s.id DESC LIMIT ?1",
+        )?;
+        let rows = stmt.query_map([limit as i64], |row| {
+            let id: i64 = row.get(0)?;
+            let started_at: i64 = row.get(1)?;
+            Ok((
+                id,
+                DateTime::from_timestamp(started_at, 0).unwrap_or_else(Utc::now),
+            ))
+        })?;
+
+        let mut sessions = Vec::new();
+        for row in rows {
+            sessions.push(row?);
+        }
+        Ok(sessions)
+    }
+
+    /// Compare the latest snapshot with the one before it.
+    ///
+    /// Returns None until at least two scans have recorded snapshots.
+    pub fn diff_latest_snapshots(&self, limit: usize) -> Result<Option<SnapshotDiff>> {
+        let sessions = self.snapshot_sessions(2)?;
+        let [(to_scan, to_time), (from_scan, from_time)] = sessions[..] else {
+            return Ok(None);
+        };
+
+        let old = self.get_dir_snapshot(from_scan)?;
+        let new = self.get_dir_snapshot(to_scan)?;
+        Ok(Some(SnapshotDiff {
+            from_scan,
+            to_scan,
+            from_time,
+            to_time,
+            changes: diff_snapshots(&old, &new, limit),
+        }))
+    }
+
+    /// Record a snapshot taken elsewhere (an imported bundle) as a finished scan
+    ///
+    /// The session counts as a previous scan, so the next scan is compared
+    /// against it and doesn't run the first-scan baseline.
+    pub fn import_snapshot(
+        &mut self,
+        scanned_at: DateTime<Utc>,
+        snapshot: &DirSnapshot,
+    ) -> Result<i64> {
+        let timestamp = scanned_at.timestamp();
+        self.db.execute(
+            "INSERT INTO scan_sessions (started_at, finished_at, scan_type, categories, total_files, new_files, changed_files, removed_files)
+             VALUES (?1, ?1, 'import', '[]', 0, 0, 0, 0)",
+            [timestamp],
+        )?;
+        let scan_id = self.db.last_insert_rowid();
+        self.put_dir_snapshot(scan_id, snapshot)?;
+        Ok(scan_id)
+    }
+
+    /// Start a new checkpoint for a scan of `root`, dropping any previous one
+    pub fn begin_checkpoint(
+        &mut self,
+        root: &Path,
+        settings: &str,
+        categories: &[&str],
+    ) -> Result<()> {
+        let tx = self
+            .db
+            .transaction()
+            .with_context(|| "Failed to start transaction")?;
+        tx.execute("DELETE FROM checkpoint_categories", [])?;
+        tx.execute("DELETE FROM checkpoint_frontiers", [])?;
+        tx.execute(
+            "INSERT OR REPLACE INTO scan_checkpoint (id, root, settings, categories, updated_at)
+             VALUES (1, ?1, ?2, ?3, ?4)",
+            // Kept as typed (not normalized) so it compares equal to the next scan's root
+            params![
+                root.to_string_lossy(),
+                settings,
+                serde_json::to_string(categories)?,
+                Utc::now().timestamp()
+            ],
+        )?;
+        tx.commit()
+            .with_context(|| "Failed to commit transaction")?;
+        Ok(())
+    }
+
+    /// Save a finished category into the current checkpoint (dropping its frontier)
+    pub fn save_checkpoint_category(
+        &mut self,
+        category: &str,
+        saved: &CheckpointedCategory,
+    ) -> Result<()> {
+        self.db.execute(
+            "INSERT OR REPLACE INTO checkpoint_categories (category, result) VALUES (?1, ?2)",
+            params![category, serde_json::to_string(saved)?],
+        )?;
+        self.db.execute(
+            "DELETE FROM checkpoint_frontiers WHERE category = ?1",
+            [category],
+        )?;
+        self.db.execute(
+            "UPDATE scan_checkpoint SET updated_at = ?1 WHERE id = 1",
+            [Utc::now().timestamp()],
+        )?;
+        Ok(())
+    }
+
+    /// Save how far an unfinished category got into the current checkpoint
+    pub fn save_checkpoint_frontier(
+        &mut self,
+        category: &str,
+        frontier: &CategoryFrontier,
+    ) -> Result<()> {
+        self.db.execute(
+            "INSERT OR REPLACE INTO checkpoint_frontiers (category, frontier) VALUES (?1, ?2)",
+            params![category, serde_json::to_string(frontier)?],
+        )?;
+        self.db.execute(
+            "UPDATE scan_checkpoint SET updated_at = ?1 WHERE id = 1",
+            [Utc::now().timestamp()],
+        )?;
+        Ok(())
+    }
+
+    /// The checkpoint of an interrupted scan, if there is one
+    pub fn load_checkpoint(&self) -> Result<Option<ScanCheckpoint>> {
+        let row = self.db.query_row(
+            "SELECT root, settings, categories, updated_at FROM scan_checkpoint WHERE id = 1",
+            [],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, i64>(3)?,
+                ))
+            },
+        );
+        let (root, settings, categories, updated_at) = match row {
+            Ok(row) => row,
+            Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut stmt = self
+            .db
+            .prepare("SELECT category FROM checkpoint_categories")?;
+        let done = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        let mut stmt = self
+            .db
+            .prepare("SELECT category FROM checkpoint_frontiers")?;
+        let partial = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        Ok(Some(ScanCheckpoint {
+            root: PathBuf::from(root),
+            settings,
+            categories: serde_json::from_str(&categories)?,
+            done,
+            partial,
+            updated_at: DateTime::from_timestamp(updated_at, 0).unwrap_or_else(Utc::now),
+        }))
+    }
+
+    /// Results saved in the checkpoint, by category
+    pub fn checkpoint_categories(&self) -> Result<HashMap<String, CheckpointedCategory>> {
+        let mut stmt = self
+            .db
+            .prepare("SELECT category, result FROM checkpoint_categories")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+
+        let mut saved = HashMap::new();
+        for row in rows {
+            let (category, result) = row?;
+            // A result that no longer parses is simply scanned again
+            if let Ok(result) = serde_json::from_str(&result) {
+                saved.insert(category, result);
+            }
+        }
+        Ok(saved)
+    }
+
+    /// Frontiers of the unfinished categories saved in the checkpoint, by category
+    pub fn checkpoint_frontiers(&self) -> Result<HashMap<String, CategoryFrontier>> {
+        let mut stmt = self
+            .db
+            .prepare("SELECT category, frontier FROM checkpoint_frontiers")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+
+        let mut saved = HashMap::new();
+        for row in rows {
+            let (category, frontier) = row?;
+            // A frontier that no longer parses means walking the category from the start
+            if let Ok(frontier) = serde_json::from_str(&frontier) {
+                saved.insert(category, frontier);
+            }
+        }
+        Ok(saved)
+    }
+
+    /// Drop the checkpoint (the scan finished or the user discarded it)
+    pub fn clear_checkpoint(&mut self) -> Result<()> {
+        self.db.execute("DELETE FROM checkpoint_categories", [])?;
+        self.db.execute("DELETE FROM checkpoint_frontiers", [])?;
+        self.db.execute("DELETE FROM scan_checkpoint", [])?;
+        Ok(())
+    }
+
     /// Remove entries for deleted files (files that were in cache but no longer exist)
     /// With per-category scan IDs, we check each category's previous scan
     pub fn cleanup_stale(&mut self, _current_scan_session_id: i64) -> Result<usize> {
@@ -862,6 +1538,12 @@ impl ScanCache {
         self.db.execute("DELETE FROM file_records", [])?;
         // Scan history (used by get_previous_scan_id)
         self.db.execute("DELETE FROM scan_sessions", [])?;
+        // Cached directory sizes and search index
+        self.db.execute("DELETE FROM dir_sizes", [])?;
+        self.db.execute("DELETE FROM file_index", [])?;
+        // Snapshots belong to the scan sessions removed above
+        self.db.execute("DELETE FROM dir_snapshots", [])?;
+        self.clear_checkpoint()?;
         self.current_scan_id = None;
         Ok(())
     }
@@ -1008,6 +1690,70 @@ fn is_busy_error(err: &anyhow::Error) -> bool {
     }
 }
 
+/// Size of the scan cache and when it was last updated, without creating,
+/// migrating or locking the database (None if there is no cache yet)
+pub fn cache_summary() -> Option<CacheSummary> {
+    let db_path = get_cache_dir().ok()?.join(DB_FILE_NAME);
+    if !db_path.exists() {
+        return None;
+    }
+    let last_scan = Connection::open_with_flags(&db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .ok()
+        .and_then(|db| last_finished_scan(&db));
+    Some(CacheSummary {
+        size_bytes: database_size(&db_path),
+        last_scan,
+    })
+}
+
+fn last_finished_scan(db: &Connection) -> Option<DateTime<Utc>> {
+    db.query_row("SELECT MAX(finished_at) FROM scan_sessions", [], |row| {
+        row.get::<_, Option<i64>>(0)
+    })
+    .ok()
+    .flatten()
+    .and_then(|secs| DateTime::from_timestamp(secs, 0))
+}
+
+/// Database file plus its WAL and shared-memory files
+fn database_size(db_path: &Path) -> u64 {
+    [
+        db_path.to_path_buf(),
+        db_path.with_extension("db-wal"),
+        db_path.with_extension("db-shm"),
+    ]
+    .iter()
+    .filter_map(|path| std::fs::metadata(path).ok())
+    .map(|meta| meta.len())
+    .sum()
+}
+
+fn remove_database_files(db_path: &Path) -> Result<()> {
+    for path in [
+        db_path.to_path_buf(),
+        db_path.with_extension("db-wal"),
+        db_path.with_extension("db-shm"),
+    ] {
+        match std::fs::remove_file(&path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to delete {}", path.display()))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether the last passed integrity check (marker mtime) is old enough to run another
+fn integrity_check_due(marker: &Path) -> bool {
+    std::fs::metadata(marker)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|checked| SystemTime::now().duration_since(checked).ok())
+        .is_none_or(|age| age >= INTEGRITY_CHECK_INTERVAL)
+}
+
 /// Get cache directory path
 fn get_cache_dir() -> Result<PathBuf> {
     let base_dir = if cfg!(windows) {
@@ -1115,6 +1861,18 @@ fn system_time_to_secs_nsecs(time: SystemTime) -> (i64, i64) {
     }
 }
 
+/// Escape `%`, `_` and `\` for a LIKE pattern using `ESCAPE '\'`
+fn escape_like(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
 fn clamp_size_to_i64(size: u64) -> i64 {
     if size > i64::MAX as u64 {
         i64::MAX
@@ -1188,8 +1946,7 @@ mod tests {
 
     fn setup_test_cache() -> (TempDir, ScanCache) {
         let temp_dir = TempDir::new().unwrap();
-        std::env::set_var("LOCALAPPDATA", temp_dir.path());
-        let cache = ScanCache::open().unwrap();
+        let cache = ScanCache::open_in(temp_dir.path()).unwrap();
         (temp_dir, cache)
     }
 
@@ -1198,6 +1955,18 @@ mod tests {
         let (_temp_dir, _cache) = setup_test_cache();
     }
 
+    #[test]
+    fn test_open_rebuilds_corrupted_database() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join(DB_FILE_NAME);
+        fs::write(&db_path, b"definitely not a sqlite database").unwrap();
+
+        let mut cache = ScanCache::open_in(temp_dir.path()).unwrap();
+        assert!(cache.check_integrity().is_ok());
+        assert!(cache.start_scan("full", &["cache"]).unwrap() > 0);
+        assert!(db_path.with_extension("db.backup").exists());
+    }
+
     #[test]
     fn test_start_scan() {
         let (_temp_dir, mut cache) = setup_test_cache();
@@ -1251,6 +2020,137 @@ mod tests {
         assert!(matches!(status, FileStatus::Modified));
     }
 
+    #[test]
+    fn test_dir_size_cache() {
+        let (temp_dir, mut cache) = setup_test_cache();
+        let dir = temp_dir.path().join("sized");
+        fs::create_dir_all(&dir).unwrap();
+        let mtime = fs::metadata(&dir).unwrap().modified().unwrap();
+
+        let totals = DirSize {
+            bytes: 100,
+            size_on_disk: 4096,
+            files: 2,
+        };
+        cache.put_dir_size(&dir, mtime, &totals).unwrap();
+        assert_eq!(cache.get_dir_size(&dir, mtime).unwrap(), Some(totals));
+
+        // A different mtime is a miss
+        let later = mtime + Duration::from_secs(5);
+        assert_eq!(cache.get_dir_size(&dir, later).unwrap(), None);
+    }
+
+    #[test]
+    fn test_search_index() {
+        let (temp_dir, mut cache) = setup_test_cache();
+        let report = temp_dir.path().join("Quarterly_Report.pdf");
+        let other = temp_dir.path().join("notes.txt");
+        fs::write(&report, "report").unwrap();
+        fs::write(&other, "notes").unwrap();
+
+        let sigs = vec![
+            FileSignature::from_path(&report, false).unwrap(),
+            FileSignature::from_path(&other, false).unwrap(),
+        ];
+        cache.index_files_batch(&sigs).unwrap();
+        assert_eq!(cache.index_count().unwrap(), 2);
+
+        let found = cache.search_index("report PDF", 10).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].path, report);
+
+        // LIKE wildcards are matched literally
+        assert!(cache.search_index("%", 10).unwrap().is_empty());
+
+        cache.remove_from_index(&report).unwrap();
+        assert!(cache.search_index("report", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_diff_latest_snapshots() {
+        let (temp_dir, mut cache) = setup_test_cache();
+        let dir = temp_dir.path().join("downloads");
+
+        let first = cache.start_scan("full", &["large"]).unwrap();
+        cache
+            .put_dir_snapshot(first, &[(dir.clone(), 100)].into_iter().collect())
+            .unwrap();
+        assert!(cache.diff_latest_snapshots(10).unwrap().is_none());
+
+        let second = cache.start_scan("incremental", &["large"]).unwrap();
+        cache
+            .put_dir_snapshot(second, &[(dir.clone(), 600)].into_iter().collect())
+            .unwrap();
+
+        let diff = cache.diff_latest_snapshots(10).unwrap().unwrap();
+        assert_eq!((diff.from_scan, diff.to_scan), (first, second));
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].path, dir);
+        assert_eq!(diff.changes[0].delta(), 500);
+    }
+
+    #[test]
+    fn test_import_snapshot() {
+        let (temp_dir, mut cache) = setup_test_cache();
+        let dir = temp_dir.path().join("downloads");
+        assert_eq!(cache.get_previous_scan_id().unwrap(), None);
+
+        let imported = cache
+            .import_snapshot(Utc::now(), &[(dir.clone(), 100)].into_iter().collect())
+            .unwrap();
+        assert_eq!(cache.get_previous_scan_id().unwrap(), Some(imported));
+        assert_eq!(
+            cache.get_dir_snapshot(imported).unwrap().get(&dir),
+            Some(&100)
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_roundtrip() {
+        let (temp_dir, mut cache) = setup_test_cache();
+        assert!(cache.load_checkpoint().unwrap().is_none());
+
+        cache
+            .begin_checkpoint(temp_dir.path(), "settings", &["Temp Files", "Duplicates"])
+            .unwrap();
+        let saved = CheckpointedCategory {
+            result: crate::output::CategoryResult {
+                items: 1,
+                size_bytes: 42,
+                paths: vec![temp_dir.path().join("a.tmp")],
+            },
+            duplicate_groups: None,
+            trash_entries: Vec::new(),
+        };
+        let frontier = CategoryFrontier {
+            pending: vec![temp_dir.path().join("Videos")],
+            found: vec![(temp_dir.path().join("b.tmp"), 7)],
+        };
+        cache
+            .save_checkpoint_frontier("Temp Files", &frontier)
+            .unwrap();
+        cache
+            .save_checkpoint_frontier("Duplicates", &frontier)
+            .unwrap();
+        cache
+            .save_checkpoint_category("Temp Files", &saved)
+            .unwrap();
+
+        let checkpoint = cache.load_checkpoint().unwrap().unwrap();
+        assert_eq!(checkpoint.root, temp_dir.path());
+        assert_eq!(checkpoint.pending(), vec!["Duplicates"]);
+        assert_eq!(checkpoint.partial, vec!["Duplicates"]);
+        let results = cache.checkpoint_categories().unwrap();
+        assert_eq!(results["Temp Files"].result.size_bytes, 42);
+        let frontiers = cache.checkpoint_frontiers().unwrap();
+        assert_eq!(frontiers.len(), 1);
+        assert_eq!(frontiers["Duplicates"], frontier);
+
+        cache.clear_checkpoint().unwrap();
+        assert!(cache.load_checkpoint().unwrap().is_none());
+        assert!(cache.checkpoint_frontiers().unwrap().is_empty());
+    }
+
     #[test]
     fn test_invalidate() {
         let (temp_dir, mut cache) = setup_test_cache();
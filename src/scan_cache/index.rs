@@ -0,0 +1,78 @@
+//! File name search index
+//!
+//! Stores every file path and size seen by the baseline traversal (or an explicit
+//! rebuild) so the TUI search screen can find any file by name without walking
+//! the disk again.
+
+use crate::config::Config;
+use crate::scan_cache::database::ScanCache;
+use crate::scan_cache::signature::FileSignature;
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+/// Files written per transaction while rebuilding
+const INDEX_BATCH_SIZE: usize = 1000;
+
+/// A file found in the search index
+#[derive(Debug, Clone)]
+pub struct IndexedFile {
+    pub path: PathBuf,
+    pub size: u64,
+    pub modified: SystemTime,
+}
+
+/// Replace the index with every file under `root`; returns the number of files indexed.
+///
+/// Skips system paths, symlinks, reparse points and configured exclusions, like the
+/// baseline traversal. `on_progress` is called after each batch with the running total.
+pub fn build_search_index<F: FnMut(u64)>(
+    root: &Path,
+    config: &Config,
+    cache: &mut ScanCache,
+    mut on_progress: F,
+) -> Result<u64> {
+    cache.clear_index()?;
+
+    let mut batch: Vec<FileSignature> = Vec::with_capacity(INDEX_BATCH_SIZE);
+    let mut indexed = 0u64;
+
+    for entry in WalkDir::new(root)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| {
+            let entry_path = e.path();
+            if crate::utils::is_system_path(entry_path) || e.file_type().is_symlink() {
+                return false;
+            }
+            if e.file_type().is_dir() && crate::utils::is_windows_reparse_point(entry_path) {
+                return false;
+            }
+            !config.is_excluded(entry_path)
+        })
+        .filter_map(|e| e.ok())
+    {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        if let Ok(sig) = FileSignature::from_path(entry.path(), false) {
+            batch.push(sig);
+        }
+
+        if batch.len() >= INDEX_BATCH_SIZE {
+            cache.index_files_batch(&batch)?;
+            indexed += batch.len() as u64;
+            batch.clear();
+            on_progress(indexed);
+        }
+    }
+
+    if !batch.is_empty() {
+        cache.index_files_batch(&batch)?;
+        indexed += batch.len() as u64;
+        on_progress(indexed);
+    }
+
+    Ok(indexed)
+}
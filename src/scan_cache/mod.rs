@@ -4,12 +4,21 @@
 //! file signatures (metadata + optional content hash) and only rescanning files
 //! that are new or have changed.
 
+pub mod bundle;
+pub mod checkpoint;
 pub mod context;
 pub mod database;
+pub mod index;
 pub mod session;
 pub mod signature;
+pub mod snapshot;
 
+pub use checkpoint::{
+    checkpoint_settings, CategoryFrontier, CheckpointedCategory, FrontierWalk, ScanCheckpoint,
+};
 pub use context::CacheContext;
-pub use database::ScanCache;
+pub use database::{cache_summary, CacheStats, CacheSummary, ScanCache};
+pub use index::{build_search_index, IndexedFile};
 pub use session::{ScanSession, ScanStats};
 pub use signature::{FileSignature, FileStatus};
+pub use snapshot::{build_snapshot, DirChange, SnapshotDiff};
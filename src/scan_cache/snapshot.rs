@@ -0,0 +1,223 @@
+//! Per-scan directory size snapshots and "what changed" diffs
+//!
+//! Every finished scan records the total size of what it found, rolled up into
+//! each ancestor directory (down to [`SNAPSHOT_MAX_DEPTH`] path components).
+//! Comparing the snapshot of the latest scan with the previous one shows which
+//! directories grew or shrank the most.
+
+use crate::output::ScanResults;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Deepest directory (in path components, including the drive/root) kept in a snapshot
+pub const SNAPSHOT_MAX_DEPTH: usize = 8;
+
+/// Number of scan sessions whose snapshots are kept
+pub const SNAPSHOT_HISTORY: i64 = 10;
+
+/// Directory totals for one scan
+pub type DirSnapshot = HashMap<PathBuf, u64>;
+
+/// Size change of one directory between two scans
+#[derive(Debug, Clone, Serialize)]
+pub struct DirChange {
+    pub path: PathBuf,
+    pub old_size: u64,
+    pub new_size: u64,
+}
+
+impl DirChange {
+    /// Bytes gained (positive) or freed (negative)
+    pub fn delta(&self) -> i64 {
+        self.new_size as i64 - self.old_size as i64
+    }
+
+    /// Signed human-readable change, e.g. "+4.9 GiB" or "-200 B"
+    pub fn delta_human(&self) -> String {
+        let sign = if self.delta() < 0 { "-" } else { "+" };
+        format!(
+            "{}{}",
+            sign,
+            bytesize::to_string(self.delta().unsigned_abs(), false)
+        )
+    }
+}
+
+/// Changes between two scan sessions
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotDiff {
+    pub from_scan: i64,
+    pub to_scan: i64,
+    pub from_time: DateTime<Utc>,
+    pub to_time: DateTime<Utc>,
+    /// Largest absolute change first
+    pub changes: Vec<DirChange>,
+}
+
+/// Roll the sizes of everything in `results` up into their ancestor directories
+pub fn build_snapshot(results: &ScanResults) -> DirSnapshot {
+    let categories = [
+        &results.cache,
+        &results.app_cache,
+        &results.temp,
+        &results.trash,
+        &results.build,
+        &results.downloads,
+        &results.large,
+        &results.old,
+        &results.applications,
+        &results.browser,
+        &results.system,
+        &results.empty,
+        &results.duplicates,
+        &results.windows_update,
+        &results.event_logs,
+        &results.thumbnails,
+        &results.crash_dumps,
+        &results.logs,
+        &results.game_caches,
+        &results.toolchain_caches,
+        &results.python_envs,
+        &results.ml_models,
+        &results.email,
+        &results.creative_caches,
+        &results.device_backups,
+        &results.git_ignored,
+    ];
+
+    // The same path can show up in several categories (e.g. large + old)
+    let mut seen: HashSet<&Path> = HashSet::new();
+    let mut snapshot = DirSnapshot::new();
+
+    for path in categories.iter().flat_map(|c| c.paths.iter()) {
+        if !seen.insert(path.as_path()) {
+            continue;
+        }
+        let Ok(meta) = std::fs::symlink_metadata(path) else {
+            continue;
+        };
+        let size = if meta.is_dir() {
+            crate::size::dir_size(path)
+        } else {
+            meta.len()
+        };
+        if size == 0 {
+            continue;
+        }
+
+        let start = if meta.is_dir() {
+            Some(path.as_path())
+        } else {
+            path.parent()
+        };
+        for dir in start.into_iter().flat_map(|p| p.ancestors()) {
+            if dir.components().count() <= SNAPSHOT_MAX_DEPTH {
+                *snapshot.entry(dir.to_path_buf()).or_insert(0) += size;
+            }
+        }
+    }
+
+    snapshot
+}
+
+/// Compare two snapshots, keeping the deepest directory responsible for each change.
+///
+/// A directory is dropped when one of its children changed by exactly the same
+/// amount, so a 5 GB download shows up once instead of once per ancestor.
+pub fn diff_snapshots(old: &DirSnapshot, new: &DirSnapshot, limit: usize) -> Vec<DirChange> {
+    let paths: HashSet<&PathBuf> = old.keys().chain(new.keys()).collect();
+    let changes: Vec<DirChange> = paths
+        .into_iter()
+        .map(|path| DirChange {
+            path: path.clone(),
+            old_size: old.get(path).copied().unwrap_or(0),
+            new_size: new.get(path).copied().unwrap_or(0),
+        })
+        .filter(|change| change.delta() != 0)
+        .collect();
+
+    let mut child_deltas: HashMap<&Path, Vec<i64>> = HashMap::new();
+    for change in &changes {
+        if let Some(parent) = change.path.parent() {
+            child_deltas.entry(parent).or_default().push(change.delta());
+        }
+    }
+
+    let mut result: Vec<DirChange> = changes
+        .iter()
+        .filter(|change| {
+            !child_deltas
+                .get(change.path.as_path())
+                .is_some_and(|deltas| deltas.contains(&change.delta()))
+        })
+        .cloned()
+        .collect();
+
+    result.sort_by(|a, b| {
+        b.delta()
+            .unsigned_abs()
+            .cmp(&a.delta().unsigned_abs())
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    result.truncate(limit);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_keeps_deepest_directory() {
+        let root = PathBuf::from("/data");
+        let downloads = root.join("downloads");
+        let videos = downloads.join("videos");
+        let cache = root.join("cache");
+
+        let old: DirSnapshot = [
+            (root.clone(), 300),
+            (downloads.clone(), 100),
+            (videos.clone(), 100),
+            (cache.clone(), 200),
+        ]
+        .into_iter()
+        .collect();
+        let new: DirSnapshot = [
+            (root.clone(), 5100),
+            (downloads.clone(), 5100),
+            (videos.clone(), 5100),
+        ]
+        .into_iter()
+        .collect();
+
+        let changes = diff_snapshots(&old, &new, 10);
+        let paths: Vec<&PathBuf> = changes.iter().map(|c| &c.path).collect();
+
+        // videos carries all the growth of downloads, so downloads is dropped
+        assert_eq!(paths, vec![&videos, &root, &cache]);
+        assert_eq!(changes[0].delta(), 5000);
+        assert_eq!(changes[2].delta(), -200);
+        assert_eq!(changes[2].delta_human(), "-200 B");
+    }
+
+    #[test]
+    fn test_build_snapshot_rolls_up_sizes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join("big.bin");
+        std::fs::write(&file, vec![0u8; 1000]).unwrap();
+
+        let mut results = ScanResults::default();
+        results.large.paths.push(file.clone());
+        results.old.paths.push(file);
+
+        let snapshot = build_snapshot(&results);
+        let expected = temp_dir.path().components().count() <= SNAPSHOT_MAX_DEPTH;
+        assert_eq!(snapshot.get(temp_dir.path()).is_some(), expected);
+        if expected {
+            // Counted once even though it appears in two categories
+            assert_eq!(snapshot[temp_dir.path()], 1000);
+        }
+    }
+}
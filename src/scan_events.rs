@@ -1,8 +1,9 @@
 //! Progress events emitted during scanning (used by TUI)
 
+use crate::cancel::CancellationToken;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Real-time progress updates during scanning.
@@ -30,6 +31,14 @@ pub enum ScanProgressEvent {
         size_bytes: u64,
     },
 
+    /// A category has run past its time budget (sent again every budget
+    /// period until it finishes); `control` skips or narrows it.
+    CategorySlow {
+        category: String,
+        elapsed_secs: u64,
+        control: CategoryControl,
+    },
+
     /// Full disk traversal: reading a folder (first scan only)
     ReadingFolder { path: PathBuf },
 
@@ -37,13 +46,55 @@ pub enum ScanProgressEvent {
     ReadingFile { path: PathBuf },
 }
 
+/// Handle on a running category scan, for giving up on it or shrinking it
+#[derive(Debug, Clone)]
+pub struct CategoryControl {
+    cancel: CancellationToken,
+    narrow: Arc<AtomicBool>,
+    /// Smaller folder the category can be restarted on (only for categories
+    /// that walk the scan root)
+    pub narrow_to: Option<PathBuf>,
+}
+
+impl CategoryControl {
+    /// `cancel` must be the category's own token (a child of the scan's)
+    pub fn new(cancel: &CancellationToken, narrow_to: Option<PathBuf>) -> Self {
+        Self {
+            cancel: cancel.clone(),
+            narrow: Arc::new(AtomicBool::new(false)),
+            narrow_to,
+        }
+    }
+
+    /// Stop the category and move on with an empty result
+    pub fn skip(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Stop the category and start it again on [`narrow_to`](Self::narrow_to)
+    pub fn narrow(&self) {
+        if self.narrow_to.is_some() {
+            self.narrow.store(true, Ordering::Relaxed);
+            self.cancel.cancel();
+        }
+    }
+
+    pub fn narrow_requested(&self) -> bool {
+        self.narrow.load(Ordering::Relaxed)
+    }
+}
+
 /// Throttled emitter for current-path updates during scanning.
+///
+/// Also carries the scan's cancellation token, so walkers that report paths can
+/// stop as soon as the scan is cancelled.
 #[derive(Debug)]
 pub struct ScanPathReporter {
     category: String,
     tx: Mutex<std::sync::mpsc::Sender<ScanProgressEvent>>,
     min_interval_ms: u64,
     last_emit_ms: AtomicU64,
+    cancel: Option<CancellationToken>,
 }
 
 impl ScanPathReporter {
@@ -57,9 +108,21 @@ impl ScanPathReporter {
             tx: Mutex::new(tx),
             min_interval_ms,
             last_emit_ms: AtomicU64::new(0),
+            cancel: None,
         }
     }
 
+    /// Attach the scan's cancellation token
+    pub fn with_cancel(mut self, cancel: &CancellationToken) -> Self {
+        self.cancel = Some(cancel.clone());
+        self
+    }
+
+    /// Whether the scan this reporter belongs to has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(|c| c.is_cancelled())
+    }
+
     pub fn emit_path(&self, path: &std::path::Path) {
         let now_ms = SystemTime::now()
             .duration_since(UNIX_EPOCH)
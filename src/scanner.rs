@@ -1,3 +1,4 @@
+use crate::cancel::CancellationToken;
 use crate::categories;
 use crate::cli::ScanOptions;
 use crate::config::Config;
@@ -5,8 +6,9 @@ use crate::git;
 use crate::output::{CategoryResult, OutputMode, ScanResults};
 use crate::progress;
 use crate::scan_cache::{FileSignature, ScanCache, ScanStats};
-use crate::scan_events::ScanProgressEvent;
+use crate::scan_events::{CategoryControl, ScanProgressEvent};
 use crate::theme::Theme;
+use crate::users::ScanScope;
 use crate::utils;
 use anyhow::Result;
 // use rayon::prelude::*; // Disabled: using sequential scan to avoid thrashing
@@ -15,6 +17,9 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc::Sender;
 
+pub mod engine;
+pub use engine::{Engine, Job};
+
 #[derive(Debug)]
 struct RecycleBinIndex {
     exact: HashSet<String>,
@@ -136,6 +141,17 @@ fn save_results_to_cache_background(results: ScanResults, scan_session_id: i64)
         add_category_paths(&results.applications.paths, "applications");
         add_category_paths(&results.windows_update.paths, "windows_update");
         add_category_paths(&results.event_logs.paths, "event_logs");
+        add_category_paths(&results.thumbnails.paths, "thumbnails");
+        add_category_paths(&results.crash_dumps.paths, "crash_dumps");
+        add_category_paths(&results.logs.paths, "logs");
+        add_category_paths(&results.game_caches.paths, "game_caches");
+        add_category_paths(&results.toolchain_caches.paths, "toolchain_caches");
+        add_category_paths(&results.python_envs.paths, "python_envs");
+        add_category_paths(&results.ml_models.paths, "ml_models");
+        add_category_paths(&results.email.paths, "email");
+        add_category_paths(&results.creative_caches.paths, "creative_caches");
+        add_category_paths(&results.device_backups.paths, "device_backups");
+        add_category_paths(&results.git_ignored.paths, "git_ignored");
 
         // Save each category's files with its category-specific scan ID
         for (category, files) in category_batches {
@@ -158,6 +174,16 @@ fn save_results_to_cache_background(results: ScanResults, scan_session_id: i64)
     });
 }
 
+/// Store per-directory totals of this scan so the next one can be compared against it
+fn record_dir_snapshot(
+    cache: &mut ScanCache,
+    scan_session_id: i64,
+    results: &ScanResults,
+) -> Result<()> {
+    let snapshot = crate::scan_cache::build_snapshot(results);
+    cache.put_dir_snapshot(scan_session_id, &snapshot)
+}
+
 fn defer_finish_scan(scan_session_id: i64, stats: ScanStats) {
     std::thread::spawn(move || {
         let mut cache = match ScanCache::open() {
@@ -270,12 +296,18 @@ fn execute_category_scan(
     duplicate_groups: &std::cell::RefCell<
         Option<Vec<crate::categories::duplicates::DuplicateGroup>>,
     >,
+    trash_entries: &std::cell::RefCell<Vec<crate::categories::trash::TrashEntry>>,
 ) -> Result<CategoryResult> {
     match task {
         ScanTask::Cache => categories::cache::scan(path, config, mode),
         ScanTask::AppCache => categories::app_cache::scan(path, config, mode),
         ScanTask::Temp => categories::temp::scan(path, config),
-        ScanTask::Trash => categories::trash::scan(),
+        ScanTask::Trash => {
+            let trash = categories::trash::scan_entries_or_empty();
+            let result = trash.to_category_result();
+            *trash_entries.borrow_mut() = trash.entries;
+            Ok(result)
+        }
         ScanTask::Build(age) => {
             categories::build::scan(path, *age, Some(build_config), config, mode)
         }
@@ -298,9 +330,43 @@ fn execute_category_scan(
         ScanTask::Applications => categories::applications::scan(path, config, mode),
         ScanTask::WindowsUpdate => categories::windows_update::scan(path, config),
         ScanTask::EventLogs => categories::event_logs::scan(path, config),
+        ScanTask::Thumbnails => categories::thumbnails::scan(path, config),
+        ScanTask::CrashDumps => categories::crash_dumps::scan(path, config),
+        ScanTask::Logs => categories::logs::scan(path, config),
+        ScanTask::GameCaches => categories::game_caches::scan(path, config),
+        ScanTask::ToolchainCaches => categories::toolchain_caches::scan(path, config),
+        ScanTask::PythonEnvs(age) => categories::python_envs::scan(path, *age, config),
+        ScanTask::MlModels => categories::ml_models::scan(path, config),
+        ScanTask::Email => categories::email::scan(path, config),
+        ScanTask::CreativeCaches => categories::creative_caches::scan(path, config),
+        ScanTask::DeviceBackups => categories::device_backups::scan(path, config),
+        ScanTask::GitIgnored(age) => categories::git_ignored::scan(path, *age, config),
     }
 }
 
+/// [`scan_all`] as a job on the shared [`Engine`], for the CLI commands
+///
+/// The cache moves to the worker and back, so callers can still read it afterwards.
+pub fn run_scan(
+    path: &Path,
+    options: ScanOptions,
+    mode: OutputMode,
+    config: &Config,
+    scan_cache: &mut Option<ScanCache>,
+) -> Result<ScanResults> {
+    let path = path.to_path_buf();
+    let config = config.clone();
+    let mut cache = scan_cache.take();
+    let (results, cache) = Engine::global()
+        .run(move |_, _| {
+            let results = scan_all(&path, options, mode, &config, cache.as_mut());
+            (results, cache)
+        })
+        .ok_or_else(|| anyhow::anyhow!("Scan stopped unexpectedly"))?;
+    *scan_cache = cache;
+    results
+}
+
 /// Scan all requested categories and return aggregated results
 ///
 /// Optimizations:
@@ -316,6 +382,19 @@ pub fn scan_all(
     config: &Config,
     mut scan_cache: Option<&mut ScanCache>,
 ) -> Result<ScanResults> {
+    // Thread count and I/O priority for the parallel walkers
+    crate::throttle::apply(&config.performance);
+
+    // Walkers and hashing adapted to the drive being scanned
+    let strategy = crate::drive::ScanStrategy::for_path(path, config);
+    if mode != OutputMode::Quiet {
+        println!(
+            "{}",
+            Theme::muted(&format!("Scan strategy: {}", strategy.describe()))
+        );
+    }
+    let _strategy = crate::drive::activate(strategy);
+
     // Clear git cache for fresh scan
     git::clear_cache();
 
@@ -373,6 +452,56 @@ pub fn scan_all(
         enabled.push(("event_logs", ScanTask::EventLogs));
     }
 
+    if options.thumbnails {
+        enabled.push(("thumbnails", ScanTask::Thumbnails));
+    }
+
+    if options.crash_dumps {
+        enabled.push(("crash_dumps", ScanTask::CrashDumps));
+    }
+
+    if options.logs {
+        enabled.push(("logs", ScanTask::Logs));
+    }
+
+    if options.game_caches {
+        enabled.push(("game_caches", ScanTask::GameCaches));
+    }
+
+    if options.toolchain_caches {
+        enabled.push(("toolchain_caches", ScanTask::ToolchainCaches));
+    }
+
+    if options.python_envs {
+        enabled.push((
+            "python_envs",
+            ScanTask::PythonEnvs(options.project_age_days),
+        ));
+    }
+
+    if options.ml_models {
+        enabled.push(("ml_models", ScanTask::MlModels));
+    }
+
+    if options.email {
+        enabled.push(("email", ScanTask::Email));
+    }
+
+    if options.creative_caches {
+        enabled.push(("creative_caches", ScanTask::CreativeCaches));
+    }
+
+    if options.device_backups {
+        enabled.push(("device_backups", ScanTask::DeviceBackups));
+    }
+
+    if options.git_ignored {
+        enabled.push((
+            "git_ignored",
+            ScanTask::GitIgnored(options.project_age_days),
+        ));
+    }
+
     let total_categories = enabled.len();
 
     if total_categories == 0 {
@@ -461,6 +590,12 @@ pub fn scan_all(
     use std::cell::RefCell;
     let duplicate_groups: RefCell<Option<Vec<crate::categories::duplicates::DuplicateGroup>>> =
         RefCell::new(None);
+    let trash_entries: RefCell<Vec<crate::categories::trash::TrashEntry>> =
+        RefCell::new(Vec::new());
+
+    // With a size budget, the categories after the one that reached it are skipped
+    let size_budget = config.performance.size_budget();
+    let mut found_bytes = 0u64;
 
     // Run scans sequentially to avoid disk thrashing and thread pool explosion
     // Each individual scanner (large, duplicates, build) manages its own parallelism
@@ -472,6 +607,21 @@ pub fn scan_all(
             // Clone config for this task
             let config = &config_clone;
 
+            if size_budget.is_some_and(|budget| found_bytes >= budget) {
+                if mode != OutputMode::Quiet {
+                    println!();
+                    println!(
+                        "{}",
+                        Theme::muted(&format!("Skipping {}: size budget reached", name))
+                    );
+                }
+                return (*name, Ok(CategoryResult::default()));
+            }
+
+            // The category's own [performance.category_overrides] while it scans
+            let _throttle = crate::throttle::enter_category(name);
+            let _category_strategy = crate::drive::activate_for_category(&path_owned, config, name);
+
             // Update progress
             let count = scanned_count.fetch_add(1, Ordering::SeqCst) + 1;
             if let Some(ref sp) = spinner {
@@ -515,6 +665,7 @@ pub fn scan_all(
                                 &build_config,
                                 &duplicates_config,
                                 &duplicate_groups,
+                                &trash_entries,
                             )
                         }
                         Err(e) => {
@@ -534,6 +685,7 @@ pub fn scan_all(
                                 &build_config,
                                 &duplicates_config,
                                 &duplicate_groups,
+                                &trash_entries,
                             )
                         }
                     }
@@ -548,6 +700,7 @@ pub fn scan_all(
                         &build_config,
                         &duplicates_config,
                         &duplicate_groups,
+                        &trash_entries,
                     )
                 }
             } else {
@@ -561,9 +714,13 @@ pub fn scan_all(
                     &build_config,
                     &duplicates_config,
                     &duplicate_groups,
+                    &trash_entries,
                 )
             };
 
+            if let Ok(ref category_result) = result {
+                found_bytes += category_result.size_bytes;
+            }
             (*name, result)
         })
         .collect();
@@ -579,7 +736,10 @@ pub fn scan_all(
             ("cache", Ok(r)) => results.cache = r,
             ("app_cache", Ok(r)) => results.app_cache = r,
             ("temp", Ok(r)) => results.temp = r,
-            ("trash", Ok(r)) => results.trash = r,
+            ("trash", Ok(r)) => {
+                results.trash = r;
+                results.trash_entries = trash_entries.take();
+            }
             ("build", Ok(r)) => results.build = r,
             ("downloads", Ok(r)) => results.downloads = r,
             ("large", Ok(r)) => results.large = r,
@@ -595,6 +755,17 @@ pub fn scan_all(
             ("applications", Ok(r)) => results.applications = r,
             ("windows_update", Ok(r)) => results.windows_update = r,
             ("event_logs", Ok(r)) => results.event_logs = r,
+            ("thumbnails", Ok(r)) => results.thumbnails = r,
+            ("crash_dumps", Ok(r)) => results.crash_dumps = r,
+            ("logs", Ok(r)) => results.logs = r,
+            ("game_caches", Ok(r)) => results.game_caches = r,
+            ("toolchain_caches", Ok(r)) => results.toolchain_caches = r,
+            ("python_envs", Ok(r)) => results.python_envs = r,
+            ("ml_models", Ok(r)) => results.ml_models = r,
+            ("email", Ok(r)) => results.email = r,
+            ("creative_caches", Ok(r)) => results.creative_caches = r,
+            ("device_backups", Ok(r)) => results.device_backups = r,
+            ("git_ignored", Ok(r)) => results.git_ignored = r,
             (name, Err(e)) => {
                 if mode != OutputMode::Quiet {
                     eprintln!("[WARNING] {} scan failed: {}", name, e);
@@ -614,6 +785,31 @@ pub fn scan_all(
     // This ensures cleaned files don't appear in scan results
     filter_recycle_bin_files(&mut results);
 
+    // Thumbnail/icon caches are cleaned by their own category when it is enabled
+    take_thumbnails_from_system(&mut results);
+
+    // Other users' cache/temp/downloads (--scope all-users)
+    if options.scope == ScanScope::AllUsers && crate::users::has_per_user_categories(&options) {
+        if mode != OutputMode::Quiet {
+            println!();
+            println!("{}", Theme::header("Scanning other user profiles"));
+        }
+        crate::users::scan_other_profiles(
+            &mut results,
+            &options,
+            config,
+            &CancellationToken::new(),
+        )?;
+    }
+
+    // Extra categories from external plugins (filtered by the plugin module itself)
+    results.plugins =
+        crate::plugin::scan_all_plugins(path, &(&options).into(), config, |plugin, e| {
+            if mode != OutputMode::Quiet {
+                eprintln!("[WARNING] Plugin {} failed: {}", plugin.name, e);
+            }
+        });
+
     // Save scanned files to cache in background thread to avoid blocking UI
     // Return results immediately, cache writes happen asynchronously
     // CRITICAL: finish_scan() must be called synchronously to prevent race condition
@@ -635,7 +831,18 @@ pub fn scan_all(
                 + results.empty.items
                 + results.duplicates.items
                 + results.windows_update.items
-                + results.event_logs.items;
+                + results.event_logs.items
+                + results.thumbnails.items
+                + results.crash_dumps.items
+                + results.logs.items
+                + results.game_caches.items
+                + results.toolchain_caches.items
+                + results.python_envs.items
+                + results.ml_models.items
+                + results.email.items
+                + results.creative_caches.items
+                + results.device_backups.items
+                + results.git_ignored.items;
 
             // Finish scan synchronously to ensure finished_at is set before returning
             // This prevents race condition where next scan doesn't see this scan as finished
@@ -652,6 +859,13 @@ pub fn scan_all(
                 }
             }
 
+            // Directory totals for `wole diff` / the "What changed" screen
+            if let Err(e) = record_dir_snapshot(cache, scan_session_id, &results) {
+                if mode != OutputMode::Quiet {
+                    eprintln!("Warning: Failed to record scan snapshot: {}", e);
+                }
+            }
+
             // Clone results for background thread (only paths and counts, not heavy data)
             // Background thread handles file caching and cleanup
             let results_for_cache = results.clone();
@@ -662,6 +876,20 @@ pub fn scan_all(
     Ok(results)
 }
 
+/// Write a batch of baseline signatures to the cache (and the search index, if enabled)
+fn flush_baseline_batch(
+    scan_cache: &mut ScanCache,
+    batch: &[(crate::scan_cache::FileSignature, String)],
+    scan_id: i64,
+    config: &Config,
+) -> Result<()> {
+    scan_cache.upsert_files_batch(batch, scan_id)?;
+    if config.cache.search_index {
+        scan_cache.index_files_batch(batch.iter().map(|(sig, _)| sig))?;
+    }
+    Ok(())
+}
+
 /// Perform full disk traversal for first scan (CLI version with single-line updates)
 fn perform_full_disk_traversal_cli_grouped(
     root_path: &Path,
@@ -796,7 +1024,7 @@ fn perform_full_disk_traversal_cli_grouped(
                         let (count, size) = parent_folders.entry(parent.clone()).or_insert((0, 0));
 
                         // Get file size
-                        if let Ok(metadata) = std::fs::metadata(entry_path) {
+                        if let Ok(metadata) = utils::safe_metadata(entry_path) {
                             let file_size = metadata.len();
                             *count += 1;
                             *size += file_size;
@@ -826,7 +1054,9 @@ fn perform_full_disk_traversal_cli_grouped(
                         // Batch update cache periodically
                         if updates.len() >= BATCH_SIZE {
                             let batch = std::mem::take(&mut *updates);
-                            if let Err(e) = scan_cache.upsert_files_batch(&batch, scan_id) {
+                            if let Err(e) =
+                                flush_baseline_batch(scan_cache, &batch, scan_id, config)
+                            {
                                 eprintln!("\nWarning: Failed to update cache batch: {}", e);
                             }
                             // Light throttling: smooth CPU spikes around DB flushes.
@@ -877,7 +1107,7 @@ fn perform_full_disk_traversal_cli_grouped(
     // Flush remaining cache updates
     let remaining = cache_updates.into_inner().unwrap();
     if !remaining.is_empty() {
-        scan_cache.upsert_files_batch(&remaining, scan_id)?;
+        flush_baseline_batch(scan_cache, &remaining, scan_id, config)?;
         std::thread::sleep(SLEEP_AFTER_BATCH);
     }
 
@@ -891,6 +1121,7 @@ fn perform_full_disk_traversal(
     tx: &Sender<ScanProgressEvent>,
     scan_cache: &mut ScanCache,
     scan_id: i64,
+    cancel: &CancellationToken,
 ) -> Result<()> {
     use std::time::{Duration, Instant};
     use walkdir::WalkDir;
@@ -936,6 +1167,9 @@ fn perform_full_disk_traversal(
             true
         })
     {
+        if cancel.is_cancelled() {
+            break;
+        }
         match entry {
             Ok(e) => {
                 let entry_path = e.path();
@@ -966,7 +1200,9 @@ fn perform_full_disk_traversal(
                         // Batch update cache periodically
                         if cache_updates.len() >= BATCH_SIZE {
                             let batch = std::mem::take(&mut cache_updates);
-                            if let Err(e) = scan_cache.upsert_files_batch(&batch, scan_id) {
+                            if let Err(e) =
+                                flush_baseline_batch(scan_cache, &batch, scan_id, config)
+                            {
                                 eprintln!("Warning: Failed to update cache batch: {}", e);
                             }
                             // Light throttling: smooth CPU spikes around DB flushes.
@@ -991,7 +1227,7 @@ fn perform_full_disk_traversal(
 
     // Flush remaining cache updates
     if !cache_updates.is_empty() {
-        scan_cache.upsert_files_batch(&cache_updates, scan_id)?;
+        flush_baseline_batch(scan_cache, &cache_updates, scan_id, config)?;
         std::thread::sleep(SLEEP_AFTER_BATCH);
     }
 
@@ -999,13 +1235,31 @@ fn perform_full_disk_traversal(
 }
 
 /// Scan all requested categories and emit progress events for TUI.
+///
+/// Stops early with a [`Cancelled`](crate::cancel::Cancelled) error once `cancel` is
+/// cancelled; categories that walk the disk check it between entries.
+///
+/// With a cache, every finished category is checkpointed (see
+/// [`crate::scan_cache::checkpoint`]), and so is the folder frontier of the
+/// categories that walk folders while they run. With `resume`, categories saved
+/// by an interrupted scan of the same root and settings are taken from the
+/// checkpoint instead of being scanned again, and unfinished ones continue from
+/// their frontier.
 pub fn scan_all_with_progress(
     path: &Path,
     options: ScanOptions,
     config: &Config,
     tx: &Sender<ScanProgressEvent>,
     mut scan_cache: Option<&mut ScanCache>,
+    cancel: &CancellationToken,
+    resume: bool,
 ) -> Result<ScanResults> {
+    // Thread count and I/O priority for the parallel walkers
+    crate::throttle::apply(&config.performance);
+
+    // Walkers and hashing adapted to the drive being scanned
+    let _strategy = crate::drive::activate(crate::drive::ScanStrategy::for_path(path, config));
+
     // Clear git cache for fresh scan
     git::clear_cache();
 
@@ -1125,6 +1379,83 @@ pub fn scan_all_with_progress(
             task: ScanTask::EventLogs,
         });
     }
+    if options.thumbnails {
+        enabled.push(ScanJob {
+            key: "thumbnails",
+            display: "Thumbnail Cache",
+            task: ScanTask::Thumbnails,
+        });
+    }
+    if options.crash_dumps {
+        enabled.push(ScanJob {
+            key: "crash_dumps",
+            display: "Crash Dumps",
+            task: ScanTask::CrashDumps,
+        });
+    }
+    if options.logs {
+        enabled.push(ScanJob {
+            key: "logs",
+            display: "Log Files",
+            task: ScanTask::Logs,
+        });
+    }
+    if options.game_caches {
+        enabled.push(ScanJob {
+            key: "game_caches",
+            display: "Game Caches",
+            task: ScanTask::GameCaches,
+        });
+    }
+    if options.toolchain_caches {
+        enabled.push(ScanJob {
+            key: "toolchain_caches",
+            display: "Toolchain Caches",
+            task: ScanTask::ToolchainCaches,
+        });
+    }
+    if options.python_envs {
+        enabled.push(ScanJob {
+            key: "python_envs",
+            display: "Python Environments",
+            task: ScanTask::PythonEnvs(options.project_age_days),
+        });
+    }
+    if options.ml_models {
+        enabled.push(ScanJob {
+            key: "ml_models",
+            display: "ML Models",
+            task: ScanTask::MlModels,
+        });
+    }
+    if options.email {
+        enabled.push(ScanJob {
+            key: "email",
+            display: "Email Data",
+            task: ScanTask::Email,
+        });
+    }
+    if options.creative_caches {
+        enabled.push(ScanJob {
+            key: "creative_caches",
+            display: "Creative App Caches",
+            task: ScanTask::CreativeCaches,
+        });
+    }
+    if options.device_backups {
+        enabled.push(ScanJob {
+            key: "device_backups",
+            display: "Device Backups",
+            task: ScanTask::DeviceBackups,
+        });
+    }
+    if options.git_ignored {
+        enabled.push(ScanJob {
+            key: "git_ignored",
+            display: "Ignored Files (git)",
+            task: ScanTask::GitIgnored(options.project_age_days),
+        });
+    }
 
     if enabled.is_empty() {
         return Ok(results);
@@ -1155,13 +1486,41 @@ pub fn scan_all_with_progress(
         }
     }
 
+    // Categories an interrupted scan already finished, and a fresh checkpoint otherwise
+    let mut resumed: std::collections::HashMap<String, crate::scan_cache::CheckpointedCategory> =
+        std::collections::HashMap::new();
+    let mut frontiers: std::collections::HashMap<String, crate::scan_cache::CategoryFrontier> =
+        std::collections::HashMap::new();
+    let mut checkpointing = false;
+    if let Some(cache) = scan_cache.as_mut() {
+        if cache_enabled {
+            let settings = crate::scan_cache::checkpoint_settings(&options);
+            let categories: Vec<&str> = enabled.iter().map(|job| job.display).collect();
+            if resume {
+                if let Ok(Some(checkpoint)) = cache.load_checkpoint() {
+                    if checkpoint.matches(path, &settings, &categories) {
+                        resumed = cache.checkpoint_categories().unwrap_or_default();
+                        frontiers = cache.checkpoint_frontiers().unwrap_or_default();
+                    }
+                }
+            }
+            checkpointing = !resumed.is_empty()
+                || !frontiers.is_empty()
+                || cache.begin_checkpoint(path, &settings, &categories).is_ok();
+        }
+    }
+
     // On first scan, optionally perform full disk traversal to build baseline BEFORE category scans
     // (disabled by default; enable via config.cache.full_disk_baseline).
-    if is_first_scan && config.cache.full_disk_baseline {
+    if is_first_scan
+        && config.cache.full_disk_baseline
+        && resumed.is_empty()
+        && frontiers.is_empty()
+    {
         if let Some(cache) = scan_cache.as_mut() {
             if let Some(id) = scan_id {
                 // Perform full disk traversal with progress reporting
-                if let Err(e) = perform_full_disk_traversal(path, config, tx, cache, id) {
+                if let Err(e) = perform_full_disk_traversal(path, config, tx, cache, id, cancel) {
                     eprintln!(
                         "Warning: Full disk traversal failed: {}. Continuing with category scans.",
                         e
@@ -1171,6 +1530,8 @@ pub fn scan_all_with_progress(
         }
     }
 
+    cancel.check()?;
+
     let path_owned = path.to_path_buf();
 
     // Clone configs for use in scan tasks
@@ -1181,12 +1542,48 @@ pub fn scan_all_with_progress(
     use std::cell::RefCell;
     let duplicate_groups: RefCell<Option<Vec<crate::categories::duplicates::DuplicateGroup>>> =
         RefCell::new(None);
+    let trash_entries: RefCell<Vec<crate::categories::trash::TrashEntry>> =
+        RefCell::new(Vec::new());
+
+    // With a size budget, the categories after the one that reached it are skipped
+    let size_budget = config.performance.size_budget();
+    let mut found_bytes = 0u64;
 
     let scan_results: Vec<(&str, &str, Result<CategoryResult>)> = enabled
         .iter()
         .map(|job| {
             let display = job.display;
 
+            if cancel.is_cancelled() {
+                return (job.key, display, Err(crate::cancel::Cancelled.into()));
+            }
+
+            if let Some(saved) = resumed.remove(display) {
+                let _ = tx.send(ScanProgressEvent::CategoryFinished {
+                    category: display.to_string(),
+                    items: saved.result.items,
+                    size_bytes: saved.result.size_bytes,
+                });
+                if saved.duplicate_groups.is_some() {
+                    *duplicate_groups.borrow_mut() = saved.duplicate_groups;
+                }
+                if !saved.trash_entries.is_empty() {
+                    *trash_entries.borrow_mut() = saved.trash_entries;
+                }
+                found_bytes += saved.result.size_bytes;
+                return (job.key, display, Ok(saved.result));
+            }
+
+            // Not checkpointed, so a resumed scan looks at it again
+            if size_budget.is_some_and(|budget| found_bytes >= budget) {
+                let _ = tx.send(ScanProgressEvent::CategoryFinished {
+                    category: display.to_string(),
+                    items: 0,
+                    size_bytes: 0,
+                });
+                return (job.key, display, Ok(CategoryResult::default()));
+            }
+
             let send_started = || {
                 let _ = tx.send(ScanProgressEvent::CategoryStarted {
                     category: display.to_string(),
@@ -1195,79 +1592,258 @@ pub fn scan_all_with_progress(
                 });
             };
 
-            let result = match job.task {
-                ScanTask::Cache => categories::cache::scan_with_progress(&path_owned, config, tx),
-                ScanTask::AppCache => {
-                    categories::app_cache::scan_with_progress(&path_owned, config, tx)
-                }
-                ScanTask::Temp => categories::temp::scan_with_progress(&path_owned, config, tx),
-                ScanTask::Trash => {
-                    send_started();
-                    categories::trash::scan()
-                }
-                ScanTask::Build(age) => {
-                    send_started();
-                    categories::build::scan(
-                        &path_owned,
-                        age,
-                        Some(&build_config),
-                        config,
-                        OutputMode::Quiet,
-                    )
-                }
-                ScanTask::Downloads(age) => {
-                    send_started();
-                    categories::downloads::scan(&path_owned, age, config, OutputMode::Quiet)
-                }
-                ScanTask::Large(size) => {
-                    send_started();
-                    categories::large::scan(&path_owned, size, config, OutputMode::Quiet)
-                }
-                ScanTask::Old(age) => {
-                    send_started();
-                    categories::old::scan(&path_owned, age, config, OutputMode::Quiet)
-                }
-                ScanTask::Browser => {
-                    send_started();
-                    categories::browser::scan(&path_owned, config)
-                }
-                ScanTask::System => {
-                    send_started();
-                    categories::system::scan(&path_owned, config)
-                }
-                ScanTask::Empty => categories::empty::scan_with_progress(&path_owned, config, tx),
-                ScanTask::Duplicates => {
-                    send_started();
-                    match categories::duplicates::scan_with_config(
-                        &path_owned,
-                        Some(&duplicates_config),
-                        config,
-                    ) {
-                        Ok(dup_result) => {
-                            *duplicate_groups.borrow_mut() = Some(dup_result.groups.clone());
-                            Ok(dup_result.to_category_result())
+            // The category's own [performance.category_overrides] while it scans
+            let _throttle = crate::throttle::enter_category(job.key);
+            let _category_strategy =
+                crate::drive::activate_for_category(&path_owned, config, job.key);
+
+            // Where an interrupted run of this category got to
+            let mut frontier = frontiers.remove(display);
+
+            // A category that overruns its time budget can be skipped or
+            // restarted on a smaller folder from the TUI (see CategoryWatchdog)
+            let mut root = path_owned.clone();
+            let mut skipped = false;
+            let result = loop {
+                let category_cancel = cancel.child();
+                let narrow_to = if job.task.narrowable() && root == path_owned {
+                    narrow_target(&root)
+                } else {
+                    None
+                };
+                let control = CategoryControl::new(&category_cancel, narrow_to);
+                let _watchdog = config
+                    .performance
+                    .category_budget(job.key)
+                    .filter(|_| job.task.interruptible())
+                    .map(|budget| CategoryWatchdog::start(display, budget, control.clone(), tx));
+
+                let narrowed = root != path_owned;
+                // A narrowed scan walks other folders, so it is neither resumed nor checkpointed
+                let mut walk = match scan_cache.as_deref_mut() {
+                    Some(cache) if checkpointing && !narrowed => {
+                        crate::scan_cache::FrontierWalk::new(frontier.take(), move |f| {
+                            // Costs no more than resuming this category from the start
+                            let _ = cache.save_checkpoint_frontier(display, f);
+                        })
+                    }
+                    _ => crate::scan_cache::FrontierWalk::none(),
+                };
+                let result = {
+                    let cancel = &category_cancel;
+                    let path_owned = root.clone();
+                    match job.task {
+                        ScanTask::Cache => {
+                            categories::cache::scan_with_progress(&path_owned, config, tx, cancel)
+                        }
+                        ScanTask::AppCache => categories::app_cache::scan_with_progress(
+                            &path_owned,
+                            config,
+                            tx,
+                            cancel,
+                        ),
+                        ScanTask::Temp => {
+                            categories::temp::scan_with_progress(&path_owned, config, tx, cancel)
+                        }
+                        ScanTask::Trash => {
+                            send_started();
+                            let trash = categories::trash::scan_entries_or_empty();
+                            let result = trash.to_category_result();
+                            *trash_entries.borrow_mut() = trash.entries;
+                            Ok(result)
+                        }
+                        ScanTask::Build(age) => {
+                            send_started();
+                            categories::build::scan_with_progress(
+                                &path_owned,
+                                age,
+                                Some(&build_config),
+                                config,
+                                OutputMode::Quiet,
+                                tx,
+                                cancel,
+                                &mut walk,
+                            )
+                        }
+                        ScanTask::Downloads(age) => {
+                            send_started();
+                            categories::downloads::scan(&path_owned, age, config, OutputMode::Quiet)
+                        }
+                        ScanTask::Large(size) => {
+                            send_started();
+                            categories::large::scan_with_progress(
+                                &path_owned,
+                                size,
+                                config,
+                                OutputMode::Quiet,
+                                tx,
+                                cancel,
+                                &mut walk,
+                            )
+                        }
+                        ScanTask::Old(age) => {
+                            send_started();
+                            categories::old::scan_with_progress(
+                                &path_owned,
+                                age,
+                                config,
+                                OutputMode::Quiet,
+                                tx,
+                                cancel,
+                                &mut walk,
+                            )
+                        }
+                        ScanTask::Browser => {
+                            send_started();
+                            categories::browser::scan(&path_owned, config)
+                        }
+                        ScanTask::System => {
+                            send_started();
+                            categories::system::scan(&path_owned, config)
+                        }
+                        ScanTask::Empty => categories::empty::scan_with_progress(
+                            &path_owned,
+                            config,
+                            tx,
+                            cancel,
+                            &mut walk,
+                        ),
+                        ScanTask::Duplicates => {
+                            send_started();
+                            // A narrowed scan covers the new folder only, not the configured paths
+                            let narrowed_config;
+                            let duplicates_config = if narrowed {
+                                narrowed_config = crate::config::DuplicatesConfig {
+                                    scan_paths: Vec::new(),
+                                    ..duplicates_config.clone()
+                                };
+                                &narrowed_config
+                            } else {
+                                &duplicates_config
+                            };
+                            match categories::duplicates::scan_with_config_with_progress(
+                                &path_owned,
+                                Some(duplicates_config),
+                                config,
+                                tx,
+                                cancel,
+                            ) {
+                                Ok(dup_result) => {
+                                    *duplicate_groups.borrow_mut() =
+                                        Some(dup_result.groups.clone());
+                                    Ok(dup_result.to_category_result())
+                                }
+                                Err(e) => Err(e),
+                            }
+                        }
+                        ScanTask::Applications => categories::applications::scan_with_progress(
+                            &path_owned,
+                            config,
+                            tx,
+                            cancel,
+                        ),
+                        ScanTask::WindowsUpdate => {
+                            send_started();
+                            categories::windows_update::scan(&path_owned, config)
+                        }
+                        ScanTask::EventLogs => {
+                            send_started();
+                            categories::event_logs::scan(&path_owned, config)
+                        }
+                        ScanTask::Thumbnails => {
+                            send_started();
+                            categories::thumbnails::scan(&path_owned, config)
+                        }
+                        ScanTask::CrashDumps => {
+                            send_started();
+                            categories::crash_dumps::scan(&path_owned, config)
+                        }
+                        ScanTask::Logs => {
+                            categories::logs::scan_with_progress(&path_owned, config, tx, cancel)
+                        }
+                        ScanTask::GameCaches => {
+                            send_started();
+                            categories::game_caches::scan(&path_owned, config)
+                        }
+                        ScanTask::ToolchainCaches => {
+                            send_started();
+                            categories::toolchain_caches::scan(&path_owned, config)
+                        }
+                        ScanTask::PythonEnvs(age) => {
+                            send_started();
+                            categories::python_envs::scan(&path_owned, age, config)
+                        }
+                        ScanTask::MlModels => {
+                            send_started();
+                            categories::ml_models::scan(&path_owned, config)
+                        }
+                        ScanTask::Email => {
+                            send_started();
+                            categories::email::scan(&path_owned, config)
+                        }
+                        ScanTask::CreativeCaches => {
+                            send_started();
+                            categories::creative_caches::scan(&path_owned, config)
+                        }
+                        ScanTask::DeviceBackups => {
+                            send_started();
+                            categories::device_backups::scan(&path_owned, config)
+                        }
+                        ScanTask::GitIgnored(age) => {
+                            send_started();
+                            categories::git_ignored::scan(&path_owned, age, config)
                         }
-                        Err(e) => Err(e),
                     }
+                };
+
+                drop(walk);
+
+                if cancel.is_cancelled() || !category_cancel.is_cancelled() {
+                    break result;
                 }
-                ScanTask::Applications => {
-                    categories::applications::scan_with_progress(&path_owned, config, tx)
-                }
-                ScanTask::WindowsUpdate => {
-                    send_started();
-                    categories::windows_update::scan(&path_owned, config)
-                }
-                ScanTask::EventLogs => {
-                    send_started();
-                    categories::event_logs::scan(&path_owned, config)
+                match control.narrow_to {
+                    Some(ref narrow_to) if control.narrow_requested() => {
+                        root = narrow_to.clone();
+                    }
+                    _ => {
+                        skipped = true;
+                        break Ok(CategoryResult::default());
+                    }
                 }
             };
 
+            if let (Ok(category_result), Some(cache)) = (&result, scan_cache.as_mut()) {
+                // A skipped category is scanned again when the scan is resumed
+                if checkpointing && !skipped {
+                    let saved = crate::scan_cache::CheckpointedCategory {
+                        result: category_result.clone(),
+                        duplicate_groups: if matches!(job.task, ScanTask::Duplicates) {
+                            duplicate_groups.borrow().clone()
+                        } else {
+                            None
+                        },
+                        trash_entries: if matches!(job.task, ScanTask::Trash) {
+                            trash_entries.borrow().clone()
+                        } else {
+                            Vec::new()
+                        },
+                    };
+                    // A checkpoint that can't be written only costs the ability to resume
+                    let _ = cache.save_checkpoint_category(display, &saved);
+                }
+            }
+
             if let Ok(ref category_result) = result {
-                if !matches!(
-                    job.task,
-                    ScanTask::Cache | ScanTask::AppCache | ScanTask::Temp | ScanTask::Applications
-                ) {
+                found_bytes += category_result.size_bytes;
+                if skipped
+                    || !matches!(
+                        job.task,
+                        ScanTask::Cache
+                            | ScanTask::AppCache
+                            | ScanTask::Temp
+                            | ScanTask::Applications
+                    )
+                {
                     let _ = tx.send(ScanProgressEvent::CategoryFinished {
                         category: display.to_string(),
                         items: category_result.items,
@@ -1289,12 +1865,17 @@ pub fn scan_all_with_progress(
         })
         .collect();
 
+    cancel.check()?;
+
     for (category, _display, result) in scan_results {
         match (category, result) {
             ("cache", Ok(r)) => results.cache = r,
             ("app_cache", Ok(r)) => results.app_cache = r,
             ("temp", Ok(r)) => results.temp = r,
-            ("trash", Ok(r)) => results.trash = r,
+            ("trash", Ok(r)) => {
+                results.trash = r;
+                results.trash_entries = trash_entries.take();
+            }
             ("build", Ok(r)) => results.build = r,
             ("downloads", Ok(r)) => results.downloads = r,
             ("large", Ok(r)) => results.large = r,
@@ -1309,6 +1890,17 @@ pub fn scan_all_with_progress(
             ("applications", Ok(r)) => results.applications = r,
             ("windows_update", Ok(r)) => results.windows_update = r,
             ("event_logs", Ok(r)) => results.event_logs = r,
+            ("thumbnails", Ok(r)) => results.thumbnails = r,
+            ("crash_dumps", Ok(r)) => results.crash_dumps = r,
+            ("logs", Ok(r)) => results.logs = r,
+            ("game_caches", Ok(r)) => results.game_caches = r,
+            ("toolchain_caches", Ok(r)) => results.toolchain_caches = r,
+            ("python_envs", Ok(r)) => results.python_envs = r,
+            ("ml_models", Ok(r)) => results.ml_models = r,
+            ("email", Ok(r)) => results.email = r,
+            ("creative_caches", Ok(r)) => results.creative_caches = r,
+            ("device_backups", Ok(r)) => results.device_backups = r,
+            ("git_ignored", Ok(r)) => results.git_ignored = r,
             (_name, Err(_e)) => {}
             _ => {}
         }
@@ -1320,6 +1912,26 @@ pub fn scan_all_with_progress(
     // This ensures cleaned files don't appear in scan results
     filter_recycle_bin_files(&mut results);
 
+    // Thumbnail/icon caches are cleaned by their own category when it is enabled
+    take_thumbnails_from_system(&mut results);
+
+    // Other users' cache/temp/downloads (--scope all-users)
+    if options.scope == ScanScope::AllUsers && crate::users::has_per_user_categories(&options) {
+        crate::users::scan_other_profiles(&mut results, &options, config, cancel)?;
+    }
+
+    // Extra categories from external plugins (filtered by the plugin module itself)
+    results.plugins =
+        crate::plugin::scan_all_plugins(path, &(&options).into(), config, |_plugin, _e| {});
+    cancel.check()?;
+
+    // Nothing left to resume
+    if checkpointing {
+        if let Some(cache) = scan_cache.as_mut() {
+            let _ = cache.clear_checkpoint();
+        }
+    }
+
     // Save scanned files to cache in background thread to avoid blocking UI
     // Return results immediately, cache writes happen asynchronously
     // CRITICAL: finish_scan() must be called synchronously to prevent race condition
@@ -1341,7 +1953,18 @@ pub fn scan_all_with_progress(
                 + results.empty.items
                 + results.duplicates.items
                 + results.windows_update.items
-                + results.event_logs.items;
+                + results.event_logs.items
+                + results.thumbnails.items
+                + results.crash_dumps.items
+                + results.logs.items
+                + results.game_caches.items
+                + results.toolchain_caches.items
+                + results.python_envs.items
+                + results.ml_models.items
+                + results.email.items
+                + results.creative_caches.items
+                + results.device_backups.items
+                + results.git_ignored.items;
 
             // Finish scan synchronously to ensure finished_at is set before returning
             // This prevents race condition where next scan doesn't see this scan as finished
@@ -1358,6 +1981,9 @@ pub fn scan_all_with_progress(
                 }
             }
 
+            // Directory totals for the "What changed" screen (non-fatal)
+            let _ = record_dir_snapshot(cache, scan_session_id, &results);
+
             // Clone results for background thread (only paths and counts, not heavy data)
             // Background thread handles file caching and cleanup
             let results_for_cache = results.clone();
@@ -1386,6 +2012,106 @@ enum ScanTask {
     Applications,
     WindowsUpdate,
     EventLogs,
+    Thumbnails,
+    CrashDumps,
+    Logs,
+    GameCaches,
+    ToolchainCaches,
+    PythonEnvs(u64),
+    MlModels,
+    Email,
+    CreativeCaches,
+    DeviceBackups,
+    GitIgnored(u64),
+}
+
+impl ScanTask {
+    /// Categories that stop when their cancellation token is cancelled, so a
+    /// slow one can be skipped
+    fn interruptible(self) -> bool {
+        matches!(
+            self,
+            ScanTask::Cache
+                | ScanTask::AppCache
+                | ScanTask::Temp
+                | ScanTask::Empty
+                | ScanTask::Duplicates
+                | ScanTask::Applications
+                | ScanTask::Logs
+        )
+    }
+
+    /// Categories that walk the whole scan root, so they can be restarted on a
+    /// smaller folder
+    fn narrowable(self) -> bool {
+        matches!(self, ScanTask::Empty | ScanTask::Duplicates)
+    }
+}
+
+/// Folder a slow whole-root category can be narrowed to: the user profile,
+/// when the scan root is a drive or folder above it
+fn narrow_target(root: &Path) -> Option<PathBuf> {
+    let profile = PathBuf::from(std::env::var("USERPROFILE").ok()?);
+    (profile != root && profile.starts_with(root)).then_some(profile)
+}
+
+/// Sends [`ScanProgressEvent::CategorySlow`] every time a category runs
+/// another budget period past its start; stops when dropped
+struct CategoryWatchdog {
+    _stop: std::sync::mpsc::Sender<()>,
+}
+
+impl CategoryWatchdog {
+    fn start(
+        category: &str,
+        budget: std::time::Duration,
+        control: CategoryControl,
+        tx: &Sender<ScanProgressEvent>,
+    ) -> Self {
+        let (stop, stopped) = std::sync::mpsc::channel::<()>();
+        let category = category.to_string();
+        let tx = tx.clone();
+        let started = std::time::Instant::now();
+        std::thread::spawn(move || {
+            while let Err(std::sync::mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(budget)
+            {
+                let _ = tx.send(ScanProgressEvent::CategorySlow {
+                    category: category.clone(),
+                    elapsed_secs: started.elapsed().as_secs(),
+                    control: control.clone(),
+                });
+            }
+        });
+        Self { _stop: stop }
+    }
+}
+
+/// Drop the thumbnail/icon caches from System Cache when the thumbnails category found them
+///
+/// Explorer keeps those files open, so only the thumbnails category (which stops
+/// Explorer first) can actually delete them.
+fn take_thumbnails_from_system(results: &mut ScanResults) {
+    if results.thumbnails.paths.is_empty() || results.system.paths.is_empty() {
+        return;
+    }
+    let thumbnails: HashSet<&Path> = results
+        .thumbnails
+        .paths
+        .iter()
+        .map(|p| p.as_path())
+        .collect();
+    results
+        .system
+        .paths
+        .retain(|p| !thumbnails.contains(p.as_path()));
+    results.system.size_bytes = results
+        .system
+        .paths
+        .iter()
+        .filter_map(|p| utils::safe_metadata(p).ok())
+        .map(|m| m.len())
+        .sum();
+    results.system.items = results.system.paths.len();
 }
 
 /// Filter out files that are in the recycle bin from scan results
@@ -1411,7 +2137,7 @@ fn filter_recycle_bin_files(results: &mut ScanResults) {
             let in_recycle_bin = recycle_bin_index.contains(path);
             if in_recycle_bin {
                 // Calculate size of excluded path before removing
-                if let Ok(metadata) = std::fs::metadata(path) {
+                if let Ok(metadata) = utils::safe_metadata(path) {
                     if metadata.is_file() {
                         excluded_size += metadata.len();
                     } else if metadata.is_dir() {
@@ -1433,7 +2159,7 @@ fn filter_recycle_bin_files(results: &mut ScanResults) {
             // Recalculate for accuracy when many paths were excluded
             let mut total = 0u64;
             for path in paths.iter() {
-                if let Ok(metadata) = std::fs::metadata(path) {
+                if let Ok(metadata) = utils::safe_metadata(path) {
                     if metadata.is_file() {
                         total += metadata.len();
                     } else if metadata.is_dir() {
@@ -1478,6 +2204,44 @@ fn filter_recycle_bin_files(results: &mut ScanResults) {
         &mut results.event_logs.paths,
         &mut results.event_logs.size_bytes,
     );
+    filter_and_recalculate(
+        &mut results.thumbnails.paths,
+        &mut results.thumbnails.size_bytes,
+    );
+    filter_and_recalculate(
+        &mut results.crash_dumps.paths,
+        &mut results.crash_dumps.size_bytes,
+    );
+    filter_and_recalculate(&mut results.logs.paths, &mut results.logs.size_bytes);
+    filter_and_recalculate(
+        &mut results.game_caches.paths,
+        &mut results.game_caches.size_bytes,
+    );
+    filter_and_recalculate(
+        &mut results.toolchain_caches.paths,
+        &mut results.toolchain_caches.size_bytes,
+    );
+    filter_and_recalculate(
+        &mut results.python_envs.paths,
+        &mut results.python_envs.size_bytes,
+    );
+    filter_and_recalculate(
+        &mut results.ml_models.paths,
+        &mut results.ml_models.size_bytes,
+    );
+    filter_and_recalculate(&mut results.email.paths, &mut results.email.size_bytes);
+    filter_and_recalculate(
+        &mut results.creative_caches.paths,
+        &mut results.creative_caches.size_bytes,
+    );
+    filter_and_recalculate(
+        &mut results.device_backups.paths,
+        &mut results.device_backups.size_bytes,
+    );
+    filter_and_recalculate(
+        &mut results.git_ignored.paths,
+        &mut results.git_ignored.size_bytes,
+    );
     // NOTE: Do NOT filter results.trash - that category scans the recycle bin itself
 
     // Update item counts
@@ -1495,6 +2259,17 @@ fn filter_recycle_bin_files(results: &mut ScanResults) {
     results.applications.items = results.applications.paths.len();
     results.windows_update.items = results.windows_update.paths.len();
     results.event_logs.items = results.event_logs.paths.len();
+    results.thumbnails.items = results.thumbnails.paths.len();
+    results.crash_dumps.items = results.crash_dumps.paths.len();
+    results.logs.items = results.logs.paths.len();
+    results.game_caches.items = results.game_caches.paths.len();
+    results.toolchain_caches.items = results.toolchain_caches.paths.len();
+    results.python_envs.items = results.python_envs.paths.len();
+    results.ml_models.items = results.ml_models.paths.len();
+    results.email.items = results.email.paths.len();
+    results.creative_caches.items = results.creative_caches.paths.len();
+    results.device_backups.items = results.device_backups.paths.len();
+    results.git_ignored.items = results.git_ignored.paths.len();
 }
 
 /// Filter out paths matching exclusion patterns
@@ -1511,7 +2286,7 @@ fn filter_exclusions(results: &mut ScanResults, config: &Config) {
             let is_excluded = config.is_excluded(path);
             if is_excluded {
                 // Calculate size of excluded path before removing
-                if let Ok(metadata) = std::fs::metadata(path) {
+                if let Ok(metadata) = utils::safe_metadata(path) {
                     if metadata.is_file() {
                         excluded_size += metadata.len();
                     } else if metadata.is_dir() {
@@ -1533,7 +2308,7 @@ fn filter_exclusions(results: &mut ScanResults, config: &Config) {
             // Recalculate for accuracy when many paths were excluded
             *size_bytes = 0;
             for path in paths.iter() {
-                if let Ok(metadata) = std::fs::metadata(path) {
+                if let Ok(metadata) = utils::safe_metadata(path) {
                     if metadata.is_file() {
                         *size_bytes += metadata.len();
                     } else if metadata.is_dir() {
@@ -1586,6 +2361,51 @@ fn filter_exclusions(results: &mut ScanResults, config: &Config) {
     results.applications.items = results.applications.paths.len();
     results.windows_update.items = results.windows_update.paths.len();
     results.event_logs.items = results.event_logs.paths.len();
+    results.thumbnails.items = results.thumbnails.paths.len();
+    results.crash_dumps.items = results.crash_dumps.paths.len();
+    results.logs.items = results.logs.paths.len();
+    results.game_caches.items = results.game_caches.paths.len();
+    results.toolchain_caches.items = results.toolchain_caches.paths.len();
+    results.python_envs.items = results.python_envs.paths.len();
+    results.ml_models.items = results.ml_models.paths.len();
+    results.email.items = results.email.paths.len();
+    results.creative_caches.items = results.creative_caches.paths.len();
+    results.device_backups.items = results.device_backups.paths.len();
+    results.git_ignored.items = results.git_ignored.paths.len();
+}
+
+/// Size of a file or folder as the category scanners report it
+fn path_size(path: &Path) -> u64 {
+    match utils::safe_metadata(path) {
+        Ok(metadata) if metadata.is_dir() => utils::calculate_dir_size(path),
+        Ok(metadata) => metadata.len(),
+        Err(_) => 0,
+    }
+}
+
+/// Drop every result outside `root` (`wole clean --repo` keeps only the repository's own files)
+pub fn retain_within(results: &mut ScanResults, root: &Path) {
+    let retain = |result: &mut CategoryResult| {
+        if result.paths.iter().all(|path| path.starts_with(root)) {
+            return;
+        }
+        result.paths.retain(|path| path.starts_with(root));
+        result.items = result.paths.len();
+        result.size_bytes = result.paths.iter().map(|path| path_size(path)).sum();
+    };
+    for category in crate::api::Category::ALL {
+        retain(category.result_mut(results));
+    }
+    for plugin in &mut results.plugins {
+        retain(&mut plugin.result);
+    }
+    if !results.trash_entries.is_empty() {
+        results
+            .trash_entries
+            .retain(|entry| entry.original_path.starts_with(root));
+        results.trash = categories::trash::category_result(&results.trash_entries);
+    }
+    results.users.clear();
 }
 
 /// Calculate total size of paths (files only - not used for directories)
@@ -1632,9 +2452,21 @@ mod tests {
             duplicates: false,
             windows_update: false,
             event_logs: false,
+            thumbnails: false,
+            crash_dumps: false,
+            logs: false,
+            game_caches: false,
+            toolchain_caches: false,
+            python_envs: false,
+            ml_models: false,
+            email: false,
+            creative_caches: false,
+            device_backups: false,
+            git_ignored: false,
             project_age_days: 14,
             min_age_days: 30,
             min_size_bytes: 100 * 1024 * 1024,
+            scope: crate::users::ScanScope::CurrentUser,
         };
         let config = Config::default();
 
@@ -1695,4 +2527,26 @@ mod tests {
 
         assert_eq!(total, 10); // 5 bytes + 5 bytes
     }
+
+    #[test]
+    fn test_take_thumbnails_from_system() {
+        let temp_dir = create_test_dir();
+        let thumbcache = temp_dir.path().join("thumbcache_256.db");
+        let other = temp_dir.path().join("other.db");
+        fs::write(&thumbcache, "thumbs").unwrap();
+        fs::write(&other, "cache").unwrap();
+
+        let mut results = ScanResults::default();
+        results.system.paths = vec![thumbcache.clone(), other.clone()];
+        results.system.items = 2;
+        results.system.size_bytes = 11;
+        results.thumbnails.paths = vec![thumbcache];
+        results.thumbnails.items = 1;
+
+        take_thumbnails_from_system(&mut results);
+
+        assert_eq!(results.system.paths, vec![other]);
+        assert_eq!(results.system.items, 1);
+        assert_eq!(results.system.size_bytes, 5);
+    }
 }
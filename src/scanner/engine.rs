@@ -0,0 +1,175 @@
+//! Shared scan engine: worker threads, job queue and progress bus
+//!
+//! Every scan goes through [`Engine::global`]. That covers the CLI commands,
+//! the TUI's category scan, its Disk Insights walk, the API, `wole bench` and
+//! the golden scans of [`crate::testing`]. Jobs queue up for a fixed set of
+//! worker threads, so no more than [`WORKERS`] scans touch the disk at once,
+//! whoever started them. The walkers inside a scan run on a thread pool set up
+//! from `[performance]` (see [`crate::throttle`]).
+//!
+//! Each job gets a [`CancellationToken`] and a [`ScanProgressEvent`] channel,
+//! and the submitter polls the returned [`Job`] for both. A job cancelled
+//! while still queued never runs.
+
+use crate::cancel::CancellationToken;
+use crate::scan_events::ScanProgressEvent;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Scans that run at the same time; later jobs wait in the queue
+pub const WORKERS: usize = 2;
+
+type Task = Box<dyn FnOnce() + Send>;
+
+/// Worker threads fed from one job queue
+pub struct Engine {
+    queue: Mutex<Sender<Task>>,
+}
+
+impl Engine {
+    fn new(workers: usize) -> Self {
+        let (queue, tasks) = mpsc::channel::<Task>();
+        let tasks = Arc::new(Mutex::new(tasks));
+        for i in 0..workers {
+            let tasks = Arc::clone(&tasks);
+            let _ = std::thread::Builder::new()
+                .name(format!("wole-scan-{}", i))
+                .spawn(move || loop {
+                    let task = match tasks.lock() {
+                        Ok(tasks) => tasks.recv(),
+                        Err(_) => return,
+                    };
+                    match task {
+                        // A panicking job drops its result sender; the worker lives on
+                        Ok(task) => {
+                            let _ = catch_unwind(AssertUnwindSafe(task));
+                        }
+                        Err(_) => return,
+                    }
+                });
+        }
+        Self {
+            queue: Mutex::new(queue),
+        }
+    }
+
+    /// The engine shared by everything in this process
+    pub fn global() -> &'static Engine {
+        static ENGINE: OnceLock<Engine> = OnceLock::new();
+        ENGINE.get_or_init(|| Engine::new(WORKERS))
+    }
+
+    /// Queue `work`; it gets `cancel` and the job's progress sender
+    pub fn submit<T, F>(&self, cancel: CancellationToken, work: F) -> Job<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&CancellationToken, &Sender<ScanProgressEvent>) -> T + Send + 'static,
+    {
+        let (result_tx, result) = mpsc::channel();
+        let (progress_tx, progress) = mpsc::channel();
+        let token = cancel.clone();
+        let task: Task = Box::new(move || {
+            if token.is_cancelled() {
+                return;
+            }
+            let _ = result_tx.send(work(&token, &progress_tx));
+        });
+        if let Ok(queue) = self.queue.lock() {
+            let _ = queue.send(task);
+        }
+        Job {
+            cancel,
+            progress,
+            result,
+        }
+    }
+
+    /// Run `work` on the engine and wait for it (CLI commands)
+    ///
+    /// None if the job panicked.
+    pub fn run<T, F>(&self, work: F) -> Option<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&CancellationToken, &Sender<ScanProgressEvent>) -> T + Send + 'static,
+    {
+        self.submit(CancellationToken::new(), work).wait()
+    }
+}
+
+/// A queued or running job
+pub struct Job<T> {
+    cancel: CancellationToken,
+    progress: Receiver<ScanProgressEvent>,
+    result: Receiver<T>,
+}
+
+impl<T> std::fmt::Debug for Job<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Job")
+            .field("cancelled", &self.cancel.is_cancelled())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Job<T> {
+    pub fn cancel_token(&self) -> &CancellationToken {
+        &self.cancel
+    }
+
+    /// Ask the job to stop (or not to start)
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Progress sent so far, oldest first
+    pub fn progress(&self) -> mpsc::TryIter<'_, ScanProgressEvent> {
+        self.progress.try_iter()
+    }
+
+    /// The result once the job is done
+    ///
+    /// `Disconnected` means it will never come: the job panicked or was
+    /// cancelled before it started.
+    pub fn try_result(&self) -> Result<T, TryRecvError> {
+        self.result.try_recv()
+    }
+
+    /// Block until the job is done (None if it panicked or never ran)
+    pub fn wait(self) -> Option<T> {
+        self.result.recv().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_jobs_report_progress_and_results() {
+        let engine = Engine::new(1);
+        let job = engine.submit(CancellationToken::new(), |_, progress| {
+            let _ = progress.send(ScanProgressEvent::ReadingFolder {
+                path: PathBuf::from("a"),
+            });
+            42
+        });
+        // Progress is sent before the result, so it's all there by now
+        assert_eq!(job.result.recv().unwrap(), 42);
+        assert_eq!(job.progress().count(), 1);
+
+        // Panics don't take the worker down
+        assert!(engine.run(|_, _| -> u8 { panic!("job failed") }).is_none());
+        assert_eq!(engine.run(|_, _| 7), Some(7));
+    }
+
+    #[test]
+    fn test_cancelled_job_never_runs() {
+        let engine = Engine::new(1);
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let job = engine.submit(cancel, |_, _| 1);
+        assert!(job.wait().is_none());
+    }
+}
@@ -0,0 +1,581 @@
+//! Selection expressions for non-interactive cleans (`wole clean --select`)
+//!
+//! An expression is a filter over scan results, e.g.
+//! `category=build && age>90d && size>500MB`. Every item found by the scan is
+//! checked against it and only the matching ones are cleaned.
+//!
+//! Conditions compare a field with a value:
+//!
+//! - `category` (`=`, `!=`): a category id (`build`, `app_cache` or
+//!   `app-cache`) or a plugin category id
+//! - `size` (`<`, `<=`, `>`, `>=`, `=`, `!=`): `500MB`, `1.5GB`, `4096`
+//! - `age` (same operators): days since the item was last modified, `90d`,
+//!   `12h`, `6w`, `3m` (30 days), `1y` (365 days)
+//! - `path` (`~` contains, `!~` doesn't, `=`, `!=`): case-insensitive
+//! - `ext` (`=`, `!=`): file extension without the dot
+//!
+//! Conditions combine with `&&`, `||` and `!`, grouped with parentheses;
+//! `&&` binds tighter than `||`. Values with spaces go in double quotes.
+
+use crate::api::Category;
+use crate::output::{CategoryResult, ScanResults};
+use crate::size::parse_size;
+use crate::utils;
+use anyhow::{bail, Result};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+    NotContains,
+}
+
+impl Op {
+    fn compare(self, left: u64, right: u64) -> bool {
+        match self {
+            Op::Eq => left == right,
+            Op::Ne => left != right,
+            Op::Lt => left < right,
+            Op::Le => left <= right,
+            Op::Gt => left > right,
+            Op::Ge => left >= right,
+            Op::Contains | Op::NotContains => false,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Eq => "=",
+            Op::Ne => "!=",
+            Op::Lt => "<",
+            Op::Le => "<=",
+            Op::Gt => ">",
+            Op::Ge => ">=",
+            Op::Contains => "~",
+            Op::NotContains => "!~",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    Category(Op, String),
+    /// Bytes
+    Size(Op, u64),
+    /// Seconds
+    Age(Op, u64),
+    Path(Op, String),
+    Ext(Op, String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Cond(Condition),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+/// What an expression is checked against: one item of the scan results
+pub struct Item<'a> {
+    /// Category id (`build`, or the plugin category's id)
+    pub category: &'a str,
+    pub path: &'a Path,
+    pub size: u64,
+    /// Time since last modification, or since deletion for Trash (None if unknown)
+    pub age: Option<Duration>,
+}
+
+/// A parsed selection expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selector {
+    expr: Expr,
+}
+
+impl Selector {
+    pub fn parse(input: &str) -> Result<Self> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            bail!("empty selection expression");
+        }
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.or()?;
+        if let Some(token) = parser.tokens.get(parser.pos) {
+            bail!("unexpected '{}' in selection expression", token.text());
+        }
+        Ok(Self { expr })
+    }
+
+    pub fn matches(&self, item: &Item) -> bool {
+        eval(&self.expr, item)
+    }
+
+    /// Built-in categories the expression can match, for picking what to
+    /// scan when no category flags are given
+    pub fn categories(&self) -> Vec<Category> {
+        Category::ALL
+            .into_iter()
+            .filter(|category| could_match(&self.expr, category.id()))
+            .collect()
+    }
+
+    /// Keep only the items that match, recomputing counts and sizes
+    ///
+    /// Sizes and ages are read from disk; folders are sized recursively.
+    pub fn retain(&self, results: &mut ScanResults) {
+        for category in Category::ALL {
+            if category == Category::Trash && !results.trash_entries.is_empty() {
+                self.retain_trash(results);
+                continue;
+            }
+            self.retain_category(category.id(), category.result_mut(results));
+        }
+        for plugin in &mut results.plugins {
+            let id = plugin.category.id.clone();
+            self.retain_category(&id, &mut plugin.result);
+        }
+        results.users.clear();
+    }
+
+    /// Trash entries carry their own size, and their age is since deletion
+    fn retain_trash(&self, results: &mut ScanResults) {
+        let now = chrono::Utc::now().timestamp();
+        results.trash_entries.retain(|entry| {
+            self.matches(&Item {
+                category: Category::Trash.id(),
+                path: &entry.original_path,
+                size: entry.size_bytes,
+                age: u64::try_from(now - entry.deleted_at)
+                    .ok()
+                    .map(Duration::from_secs),
+            })
+        });
+        results.trash = crate::categories::trash::category_result(&results.trash_entries);
+    }
+
+    fn retain_category(&self, id: &str, result: &mut CategoryResult) {
+        if result.paths.is_empty() {
+            return;
+        }
+        let now = SystemTime::now();
+        let mut size_bytes = 0;
+        result.paths.retain(|path| {
+            let metadata = utils::safe_metadata(path).ok();
+            let size = match &metadata {
+                Some(metadata) if metadata.is_dir() => utils::calculate_dir_size(path),
+                Some(metadata) => metadata.len(),
+                None => 0,
+            };
+            let age = metadata
+                .and_then(|metadata| metadata.modified().ok())
+                .and_then(|modified| now.duration_since(modified).ok());
+            let keep = self.matches(&Item {
+                category: id,
+                path,
+                size,
+                age,
+            });
+            if keep {
+                size_bytes += size;
+            }
+            keep
+        });
+        result.items = result.paths.len();
+        result.size_bytes = size_bytes;
+    }
+}
+
+/// The expression for the quick rules of the Results "select by rule" dialog,
+/// e.g. `age>30 && size>100MB && (ext=log || ext=tmp) && path~"cache"`
+///
+/// Bare numbers are days and MB; blank fields are left out. None if every
+/// field is blank.
+pub fn quick_rule(
+    older_than: &str,
+    larger_than: &str,
+    extensions: &str,
+    path_contains: &str,
+) -> Option<String> {
+    let mut conditions = Vec::new();
+    let older_than = older_than.trim();
+    if !older_than.is_empty() {
+        conditions.push(format!("age>{}", older_than));
+    }
+    let larger_than = larger_than.trim();
+    if !larger_than.is_empty() {
+        let unit = if larger_than.ends_with(|c: char| c.is_ascii_digit()) {
+            "MB"
+        } else {
+            ""
+        };
+        conditions.push(format!("size>{}{}", larger_than, unit));
+    }
+    let extensions: Vec<String> = extensions
+        .split([',', ' ', ';'])
+        .map(|ext| ext.trim().trim_start_matches('.'))
+        .filter(|ext| !ext.is_empty())
+        .map(|ext| format!("ext={}", ext))
+        .collect();
+    match extensions.len() {
+        0 => {}
+        1 => conditions.push(extensions[0].clone()),
+        _ => conditions.push(format!("({})", extensions.join(" || "))),
+    }
+    let path_contains = path_contains.trim().replace('"', "");
+    if !path_contains.is_empty() {
+        conditions.push(format!("path~\"{}\"", path_contains));
+    }
+    (!conditions.is_empty()).then(|| conditions.join(" && "))
+}
+
+fn eval(expr: &Expr, item: &Item) -> bool {
+    match expr {
+        Expr::Cond(condition) => check(condition, item),
+        Expr::Not(inner) => !eval(inner, item),
+        Expr::And(left, right) => eval(left, item) && eval(right, item),
+        Expr::Or(left, right) => eval(left, item) || eval(right, item),
+    }
+}
+
+fn check(condition: &Condition, item: &Item) -> bool {
+    match condition {
+        Condition::Category(op, value) => {
+            let same = normalize_category(item.category) == *value;
+            if *op == Op::Eq {
+                same
+            } else {
+                !same
+            }
+        }
+        Condition::Size(op, bytes) => op.compare(item.size, *bytes),
+        // Unknown age never matches, whichever way it's compared
+        Condition::Age(op, secs) => item.age.is_some_and(|age| op.compare(age.as_secs(), *secs)),
+        Condition::Path(op, value) => {
+            let path = item.path.to_string_lossy().to_lowercase();
+            match op {
+                Op::Contains => path.contains(value),
+                Op::NotContains => !path.contains(value),
+                Op::Eq => path == *value,
+                _ => path != *value,
+            }
+        }
+        Condition::Ext(op, value) => {
+            let ext = item
+                .path
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            if *op == Op::Eq {
+                ext == *value
+            } else {
+                ext != *value
+            }
+        }
+    }
+}
+
+/// Whether some item of category `id` could match (other fields unknown)
+fn could_match(expr: &Expr, id: &str) -> bool {
+    // Three-valued: Some(known result) or None (depends on the item)
+    fn known(expr: &Expr, id: &str) -> Option<bool> {
+        match expr {
+            Expr::Cond(Condition::Category(op, value)) => {
+                let same = normalize_category(id) == *value;
+                Some(if *op == Op::Eq { same } else { !same })
+            }
+            Expr::Cond(_) => None,
+            Expr::Not(inner) => known(inner, id).map(|known| !known),
+            Expr::And(left, right) => match (known(left, id), known(right, id)) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            Expr::Or(left, right) => match (known(left, id), known(right, id)) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+        }
+    }
+    known(expr, id) != Some(false)
+}
+
+fn normalize_category(id: &str) -> String {
+    id.trim().to_lowercase().replace('-', "_")
+}
+
+/// `90d`, `12h`, `6w`, `3m`, `1y` in seconds (bare numbers are days)
+fn parse_age(value: &str) -> Result<u64> {
+    let value = value.trim().to_lowercase();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| anyhow::anyhow!("invalid age '{}' (e.g. 90d, 6w, 1y)", value))?;
+    let unit_secs = match unit {
+        "h" => 60 * 60,
+        "" | "d" => DAY_SECS,
+        "w" => 7 * DAY_SECS,
+        "m" => 30 * DAY_SECS,
+        "y" => 365 * DAY_SECS,
+        _ => bail!("unknown age unit '{}' (use h, d, w, m or y)", unit),
+    };
+    Ok((number * unit_secs as f64) as u64)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Op(Op),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+impl Token {
+    fn text(&self) -> String {
+        match self {
+            Token::Word(word) => word.clone(),
+            Token::Op(op) => op.symbol().to_string(),
+            Token::And => "&&".to_string(),
+            Token::Or => "||".to_string(),
+            Token::Not => "!".to_string(),
+            Token::Open => "(".to_string(),
+            Token::Close => ")".to_string(),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let (token, len) = match (c, next) {
+            _ if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            ('&', Some('&')) => (Token::And, 2),
+            ('|', Some('|')) => (Token::Or, 2),
+            ('!', Some('=')) => (Token::Op(Op::Ne), 2),
+            ('!', Some('~')) => (Token::Op(Op::NotContains), 2),
+            ('<', Some('=')) => (Token::Op(Op::Le), 2),
+            ('>', Some('=')) => (Token::Op(Op::Ge), 2),
+            ('=', Some('=')) => (Token::Op(Op::Eq), 2),
+            ('!', _) => (Token::Not, 1),
+            ('=', _) => (Token::Op(Op::Eq), 1),
+            ('<', _) => (Token::Op(Op::Lt), 1),
+            ('>', _) => (Token::Op(Op::Gt), 1),
+            ('~', _) => (Token::Op(Op::Contains), 1),
+            ('(', _) => (Token::Open, 1),
+            (')', _) => (Token::Close, 1),
+            ('"', _) => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|&c| c == '"')
+                    .ok_or_else(|| anyhow::anyhow!("unterminated quote in selection expression"))?;
+                let word: String = chars[i + 1..i + 1 + end].iter().collect();
+                (Token::Word(word), end + 2)
+            }
+            _ => {
+                let len = chars[i..]
+                    .iter()
+                    .position(|&c| c.is_whitespace() || "&|!=<>~()\"".contains(c))
+                    .unwrap_or(chars.len() - i);
+                if len == 0 {
+                    bail!("unexpected '{}' in selection expression", c);
+                }
+                (Token::Word(chars[i..i + len].iter().collect()), len)
+            }
+        };
+        tokens.push(token);
+        i += len;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.tokens.get(self.pos) == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr> {
+        let mut expr = self.and()?;
+        while self.eat(&Token::Or) {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut expr = self.unary()?;
+        while self.eat(&Token::And) {
+            expr = Expr::And(Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        match self.next() {
+            Some(Token::Not) => Ok(Expr::Not(Box::new(self.unary()?))),
+            Some(Token::Open) => {
+                let expr = self.or()?;
+                if !self.eat(&Token::Close) {
+                    bail!("missing ')' in selection expression");
+                }
+                Ok(expr)
+            }
+            Some(Token::Word(field)) => {
+                let op = match self.next() {
+                    Some(Token::Op(op)) => op,
+                    _ => bail!("expected an operator after '{}'", field),
+                };
+                let value = match self.next() {
+                    Some(Token::Word(value)) => value,
+                    _ => bail!("expected a value after '{} {}'", field, op.symbol()),
+                };
+                condition(&field, op, &value).map(Expr::Cond)
+            }
+            Some(token) => bail!("unexpected '{}' in selection expression", token.text()),
+            None => bail!("selection expression ends too early"),
+        }
+    }
+}
+
+fn condition(field: &str, op: Op, value: &str) -> Result<Condition> {
+    let field = field.to_lowercase();
+    let equality = matches!(op, Op::Eq | Op::Ne);
+    let ordered = !matches!(op, Op::Contains | Op::NotContains);
+    let condition = match field.as_str() {
+        "category" | "cat" if equality => Condition::Category(op, normalize_category(value)),
+        "size" if ordered => Condition::Size(
+            op,
+            parse_size(value).map_err(|e| anyhow::anyhow!("invalid size '{}': {}", value, e))?,
+        ),
+        "age" if ordered => Condition::Age(op, parse_age(value)?),
+        "path" if equality || !ordered => Condition::Path(op, value.to_lowercase()),
+        "ext" if equality => Condition::Ext(op, value.trim_start_matches('.').to_lowercase()),
+        "category" | "cat" | "size" | "age" | "path" | "ext" => {
+            bail!("'{}' can't be compared with '{}'", field, op.symbol())
+        }
+        _ => bail!(
+            "unknown field '{}' (use category, size, age, path or ext)",
+            field
+        ),
+    };
+    Ok(condition)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item<'a>(category: &'a str, path: &'a str, size: u64, age_days: u64) -> Item<'a> {
+        Item {
+            category,
+            path: Path::new(path),
+            size,
+            age: Some(Duration::from_secs(age_days * DAY_SECS)),
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        let selector = Selector::parse("category=build && age>90d && size>500MB").unwrap();
+        let big = 600 * 1024 * 1024;
+        assert!(selector.matches(&item("build", "C:/p/node_modules", big, 120)));
+        assert!(!selector.matches(&item("build", "C:/p/node_modules", big, 30)));
+        assert!(!selector.matches(&item("cache", "C:/p/node_modules", big, 120)));
+        assert!(!selector.matches(&item("build", "C:/p/node_modules", 1024, 120)));
+
+        let selector = Selector::parse("(cat=app-cache || ext=LOG) && !path~\"My Stuff\"").unwrap();
+        assert!(selector.matches(&item("app_cache", "C:/a/b", 1, 0)));
+        assert!(selector.matches(&item("temp", "C:/a/run.log", 1, 0)));
+        assert!(!selector.matches(&item("temp", "C:/my stuff/run.log", 1, 0)));
+        assert!(!selector.matches(&item("temp", "C:/a/run.txt", 1, 0)));
+    }
+
+    #[test]
+    fn test_categories_to_scan() {
+        let categories = |expr: &str| Selector::parse(expr).unwrap().categories();
+        assert_eq!(
+            categories("category=build && size>1GB"),
+            vec![Category::Build]
+        );
+        assert_eq!(
+            categories("category=temp || cat=downloads"),
+            vec![Category::Temp, Category::Downloads]
+        );
+        assert_eq!(categories("size>1GB").len(), Category::ALL.len());
+        assert_eq!(categories("category!=build").len(), Category::ALL.len() - 1);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for bad in [
+            "",
+            "size>",
+            "size>lots",
+            "age>3 fortnights",
+            "color=red",
+            "category>build",
+            "(size>1GB",
+            "size>1GB &&",
+            "path~\"open",
+        ] {
+            assert!(Selector::parse(bad).is_err(), "{:?} should not parse", bad);
+        }
+        assert_eq!(parse_age("12h").unwrap(), 12 * 60 * 60);
+        assert_eq!(parse_age("1y").unwrap(), 365 * DAY_SECS);
+    }
+
+    #[test]
+    fn test_quick_rule() {
+        assert_eq!(quick_rule(" ", "", "", ""), None);
+        let rule = quick_rule("30", "100", ".log, tmp", "node cache").unwrap();
+        assert_eq!(
+            rule,
+            "age>30 && size>100MB && (ext=log || ext=tmp) && path~\"node cache\""
+        );
+        let selector = Selector::parse(&rule).unwrap();
+        let big = 200 * 1024 * 1024;
+        assert!(selector.matches(&item("temp", "C:/node cache/a.tmp", big, 40)));
+        assert!(!selector.matches(&item("temp", "C:/node cache/a.txt", big, 40)));
+        assert_eq!(
+            quick_rule("", "2GB", "iso", ""),
+            Some("size>2GB && ext=iso".into())
+        );
+    }
+}
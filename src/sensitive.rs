@@ -0,0 +1,142 @@
+//! Personal-data heuristics for scan results
+//!
+//! Old Files and Old Downloads are where tax returns, scanned passports and
+//! exported keys end up forgotten. Before those results are shown, each one is
+//! checked against its name: certificate and key-store extensions (`.pfx`,
+//! `.pem`, `.kdbx`, ...) and words like "passport" or "password". A match is
+//! never pre-selected, whatever `ui.auto_select_aggressiveness` says, and is
+//! marked in Results and Preview. It's a filename check only - contents are
+//! never read.
+
+use std::path::Path;
+
+/// Categories whose results are checked
+pub const CHECKED_CATEGORIES: [&str; 2] = ["Old Files", "Old Downloads"];
+
+/// Extensions of certificates, private keys and password databases
+const KEY_EXTENSIONS: [&str; 19] = [
+    "pfx", "p12", "pem", "key", "cer", "crt", "der", "p7b", "csr", "jks", "keystore", "ppk", "gpg",
+    "pgp", "asc", "kdbx", "kdb", "1pif", "ovpn",
+];
+
+/// Files that are keys or wallets by name alone
+const KEY_FILES: [&str; 6] = [
+    "id_rsa",
+    "id_dsa",
+    "id_ecdsa",
+    "id_ed25519",
+    "wallet.dat",
+    ".env",
+];
+
+/// Name fragments matched anywhere in the name, ignoring spaces, `_` and `-`
+/// (long enough not to misfire)
+const NAME_FRAGMENTS: [(&str, &str); 12] = [
+    ("passport", "passport"),
+    ("password", "passwords"),
+    ("passwort", "passwords"),
+    ("credential", "credentials"),
+    ("recoverycode", "recovery codes"),
+    ("payslip", "payslip"),
+    ("paystub", "payslip"),
+    ("salary", "salary"),
+    ("bankstatement", "bank statement"),
+    ("taxreturn", "tax return"),
+    ("steuer", "tax return"),
+    ("证书", "certificate"),
+];
+
+/// Short words that only count as whole words ("tax", not "syntax")
+const NAME_WORDS: [(&str, &str); 10] = [
+    ("tax", "tax return"),
+    ("taxes", "tax return"),
+    ("w2", "tax return"),
+    ("1099", "tax return"),
+    ("ssn", "social security number"),
+    ("passwd", "passwords"),
+    ("secrets", "credentials"),
+    ("visa", "passport"),
+    ("license", "ID document"),
+    ("licence", "ID document"),
+];
+
+/// Why `path` looks like it holds personal data, if it does
+pub fn reason(path: &Path) -> Option<&'static str> {
+    let name = path.file_name()?.to_string_lossy().to_lowercase();
+
+    if KEY_FILES.contains(&name.as_str()) {
+        return Some("private key");
+    }
+    if let Some(ext) = path.extension() {
+        let ext = ext.to_string_lossy().to_lowercase();
+        if KEY_EXTENSIONS.contains(&ext.as_str()) {
+            return Some(match ext.as_str() {
+                "kdbx" | "kdb" | "1pif" => "password database",
+                "ovpn" => "VPN profile",
+                "gpg" | "pgp" | "asc" => "encrypted file or key",
+                _ => "certificate or private key",
+            });
+        }
+    }
+
+    let squashed: String = name
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .collect();
+    if let Some((_, why)) = NAME_FRAGMENTS
+        .iter()
+        .find(|(frag, _)| squashed.contains(frag))
+    {
+        return Some(why);
+    }
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    stem.split(|c: char| !c.is_alphanumeric())
+        .find_map(|word| NAME_WORDS.iter().find(|(w, _)| *w == word))
+        .map(|(_, why)| *why)
+}
+
+/// [`reason`] for results of the checked categories, None elsewhere
+pub fn reason_in(category: &str, path: &Path) -> Option<&'static str> {
+    if !CHECKED_CATEGORIES.contains(&category) {
+        return None;
+    }
+    reason(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reason() {
+        assert_eq!(
+            reason(Path::new("Downloads/cert.PFX")),
+            Some("certificate or private key")
+        );
+        assert_eq!(reason(Path::new("vault.kdbx")), Some("password database"));
+        assert_eq!(reason(Path::new(".ssh/id_rsa")), Some("private key"));
+        assert_eq!(
+            reason(Path::new("Scan_Passport_2019.pdf")),
+            Some("passport")
+        );
+        assert_eq!(reason(Path::new("2023 tax-return.pdf")), Some("tax return"));
+        assert_eq!(reason(Path::new("2023_tax.pdf")), Some("tax return"));
+        assert_eq!(
+            reason(Path::new("Bank-Statement March.pdf")),
+            Some("bank statement")
+        );
+        assert_eq!(reason(Path::new("学位证书.jpg")), Some("certificate"));
+        // Whole words only for short keywords
+        assert_eq!(reason(Path::new("syntax_notes.txt")), None);
+        assert_eq!(reason(Path::new("holiday.mp4")), None);
+
+        assert_eq!(reason_in("Large Files", Path::new("vault.kdbx")), None);
+        assert_eq!(
+            reason_in("Old Downloads", Path::new("vault.kdbx")),
+            Some("password database")
+        );
+    }
+}
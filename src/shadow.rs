@@ -0,0 +1,227 @@
+//! Volume Shadow Copy storage (`vssadmin`)
+//!
+//! System Restore points and other shadow copies keep the old contents of
+//! changed and deleted files in a per-volume storage area. Deleting a file
+//! that a shadow copy still references frees nothing until Windows drops the
+//! oldest copies, so a cleanup can look smaller on the free-space counter than
+//! on the Success screen. After a TUI cleanup, [`storage_for`] reports how much
+//! the cleaned volumes hold in shadow storage, and [`resize`] caps it
+//! (`vssadmin resize shadowstorage`), which deletes the oldest restore points.
+//!
+//! `vssadmin` needs an elevated prompt for both; without one nothing is
+//! reported.
+
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Shadow copy storage of one volume
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShadowStorage {
+    /// Drive, e.g. "C:"
+    pub volume: String,
+    pub used_bytes: u64,
+    pub allocated_bytes: u64,
+    /// None = unbounded
+    pub max_bytes: Option<u64>,
+    /// Maximum as a share of the volume (None = unbounded)
+    pub max_percent: Option<f32>,
+}
+
+impl ShadowStorage {
+    /// Maximum to suggest for `vssadmin resize shadowstorage`, if lowering it is worth it
+    pub fn suggested_max(&self) -> Option<&'static str> {
+        match self.max_percent {
+            None => Some("10%"),
+            Some(percent) if percent > 10.0 => Some("10%"),
+            Some(percent) if percent > 5.0 => Some("5%"),
+            _ => None,
+        }
+    }
+
+    /// The command that caps this volume's storage at `max_size` (e.g. "10%")
+    pub fn resize_command(&self, max_size: &str) -> String {
+        format!(
+            "vssadmin resize shadowstorage /for={0} /on={0} /maxsize={1}",
+            self.volume, max_size
+        )
+    }
+}
+
+/// Drive of `path` ("C:"), if it has a drive letter
+pub fn volume_of(path: &Path) -> Option<String> {
+    let text = path.to_string_lossy();
+    let text = text.strip_prefix(r"\\?\").unwrap_or(&text);
+    let mut chars = text.chars();
+    let letter = chars.next().filter(|c| c.is_ascii_alphabetic())?;
+    (chars.next() == Some(':')).then(|| format!("{}:", letter.to_ascii_uppercase()))
+}
+
+/// Parse a `2.5 GB (1%)` or `UNBOUNDED (100%)` amount into bytes and percent
+fn parse_amount(text: &str) -> (Option<u64>, Option<f32>) {
+    let percent = text
+        .rsplit_once('(')
+        .and_then(|(_, rest)| rest.split_once('%'))
+        .and_then(|(number, _)| number.trim().replace(',', ".").parse::<f32>().ok());
+    let mut parts = text.split_whitespace();
+    let bytes = parts.next().zip(parts.next()).and_then(|(number, unit)| {
+        let number = number.replace(',', ".").parse::<f64>().ok()?;
+        let scale: u64 = match unit.to_uppercase().as_str() {
+            "B" | "BYTES" => 1,
+            "KB" => 1 << 10,
+            "MB" => 1 << 20,
+            "GB" => 1 << 30,
+            "TB" => 1 << 40,
+            "PB" => 1 << 50,
+            _ => return None,
+        };
+        Some((number * scale as f64) as u64)
+    });
+    (bytes, percent)
+}
+
+/// Parse `vssadmin list shadowstorage`
+///
+/// The labels are localized, so each association is read by position: the
+/// first `(X:)` names the volume, then the three lines with a percentage are
+/// used, allocated and maximum space.
+fn parse_shadow_storage(output: &str) -> Vec<ShadowStorage> {
+    let mut found = Vec::new();
+    let output = output.replace("\r\n", "\n");
+    for block in output.split("\n\n") {
+        let volume = block.lines().find_map(|line| {
+            let (_, rest) = line.split_once('(')?;
+            let (drive, _) = rest.split_once(')')?;
+            volume_of(Path::new(drive.trim()))
+        });
+        let Some(volume) = volume else {
+            continue;
+        };
+        let amounts: Vec<(Option<u64>, Option<f32>)> = block
+            .lines()
+            .filter(|line| line.contains('%'))
+            .filter_map(|line| line.split_once(": ").map(|(_, amount)| amount))
+            .map(parse_amount)
+            .collect();
+        let [used, allocated, max] = amounts.as_slice() else {
+            continue;
+        };
+        let storage = ShadowStorage {
+            volume,
+            used_bytes: used.0.unwrap_or(0),
+            allocated_bytes: allocated.0.unwrap_or(0),
+            max_bytes: max.0,
+            max_percent: max.0.and(max.1),
+        };
+        if !found.contains(&storage) {
+            found.push(storage);
+        }
+    }
+    found
+}
+
+fn vssadmin(args: &[&str]) -> Result<String> {
+    let output = Command::new("vssadmin")
+        .args(args)
+        .output()
+        .context("Failed to run vssadmin")?;
+    if !output.status.success() {
+        bail!(
+            "vssadmin {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stdout).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Shadow storage of the drives holding `paths` (empty when not elevated)
+pub fn storage_for(paths: &[PathBuf]) -> Vec<ShadowStorage> {
+    if !cfg!(windows) || !crate::optimize::is_admin() {
+        return Vec::new();
+    }
+    let volumes: Vec<String> = paths.iter().filter_map(|p| volume_of(p)).collect();
+    if volumes.is_empty() {
+        return Vec::new();
+    }
+    let Ok(output) = vssadmin(&["list", "shadowstorage"]) else {
+        return Vec::new();
+    };
+    parse_shadow_storage(&output)
+        .into_iter()
+        .filter(|storage| storage.used_bytes > 0 && volumes.contains(&storage.volume))
+        .collect()
+}
+
+/// Cap the shadow storage of `volume` at `max_size` ("10%", "20GB", ...)
+///
+/// Windows deletes the oldest shadow copies that no longer fit. In audit mode
+/// nothing is changed.
+pub fn resize(volume: &str, max_size: &str) -> Result<()> {
+    if crate::audit::is_enabled() {
+        crate::audit::note(&format!(
+            "resize shadow storage on {} to {}",
+            volume, max_size
+        ));
+        return Ok(());
+    }
+    if !crate::optimize::is_admin() {
+        bail!("Resizing shadow storage requires an elevated prompt");
+    }
+    let for_arg = format!("/for={}", volume);
+    let on_arg = format!("/on={}", volume);
+    let max_arg = format!("/maxsize={}", max_size);
+    vssadmin(&["resize", "shadowstorage", &for_arg, &on_arg, &max_arg])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str =
+        "vssadmin 1.1 - Volume Shadow Copy Service administrative command-line tool\n\
+(C) Copyright 2001-2013 Microsoft Corp.\n\
+\n\
+Shadow Copy Storage association\n\
+   For volume: (C:)\\\\?\\Volume{1b2c}\\\n\
+   Shadow Copy Storage volume: (C:)\\\\?\\Volume{1b2c}\\\n\
+   Used Shadow Copy Storage space: 2.50 GB (1%)\n\
+   Allocated Shadow Copy Storage space: 3 GB (1%)\n\
+   Maximum Shadow Copy Storage space: 47.6 GB (20%)\n\
+\n\
+Shadow Copy Storage association\n\
+   For volume: (D:)\\\\?\\Volume{9f00}\\\n\
+   Shadow Copy Storage volume: (D:)\\\\?\\Volume{9f00}\\\n\
+   Used Shadow Copy Storage space: 0 B (0%)\n\
+   Allocated Shadow Copy Storage space: 0 B (0%)\n\
+   Maximum Shadow Copy Storage space: UNBOUNDED (100%)\n";
+
+    #[test]
+    fn test_parse_shadow_storage() {
+        let storage = parse_shadow_storage(OUTPUT);
+        assert_eq!(storage.len(), 2);
+        assert_eq!(storage[0].volume, "C:");
+        assert_eq!(storage[0].used_bytes, 2_684_354_560);
+        assert_eq!(storage[0].allocated_bytes, 3 << 30);
+        assert_eq!(storage[0].max_percent, Some(20.0));
+        assert_eq!(storage[0].suggested_max(), Some("10%"));
+        assert_eq!(storage[1].volume, "D:");
+        assert_eq!(storage[1].max_bytes, None);
+        assert_eq!(storage[1].max_percent, None);
+        assert_eq!(
+            storage[0].resize_command("10%"),
+            "vssadmin resize shadowstorage /for=C: /on=C: /maxsize=10%"
+        );
+    }
+
+    #[test]
+    fn test_volume_of() {
+        assert_eq!(volume_of(Path::new(r"c:\Users\me")), Some("C:".to_string()));
+        assert_eq!(
+            volume_of(Path::new(r"\\?\D:\very\long")),
+            Some("D:".to_string())
+        );
+        assert_eq!(volume_of(Path::new("/home/me")), None);
+    }
+}
@@ -1,4 +1,28 @@
+//! Size parsing and directory size computation
+//!
+//! [`dir_size`] is the shared engine for "how big is this folder". Every category
+//! and the Disk Insights scan go through it so sizes agree everywhere. Disk
+//! Insights and the Results list use [`dir_size_cached`] when the scan cache is
+//! enabled, which reuses a result while no folder in the tree has changed; the
+//! category scanners always walk. With `performance.scan_ads`
+//! on, file sizes include NTFS alternate data streams (see [`crate::ads`]) and
+//! the cache is bypassed.
+//!
+//! [`real_free_estimate`] answers a different question - how much space a
+//! delete would actually free once hard links and NTFS compression are taken
+//! into account.
+
+use crate::scan_cache::ScanCache;
 use anyhow::{bail, Result};
+use jwalk::WalkDir;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+/// Default traversal depth for directory size computation
+pub const DEFAULT_DIR_SIZE_DEPTH: usize = 15;
 
 /// Parse human-readable size strings to bytes
 ///
@@ -56,6 +80,549 @@ pub fn parse_size(s: &str) -> Result<u64> {
     Ok(bytes)
 }
 
+/// Options for [`dir_size_with_options`]
+#[derive(Debug, Clone)]
+pub struct DirSizeOptions {
+    /// Report allocated size ("size on disk", rounded up to whole clusters)
+    /// instead of the sum of file lengths
+    pub size_on_disk: bool,
+    /// Maximum depth below the root to traverse
+    pub max_depth: usize,
+}
+
+impl Default for DirSizeOptions {
+    fn default() -> Self {
+        Self {
+            size_on_disk: false,
+            max_depth: DEFAULT_DIR_SIZE_DEPTH,
+        }
+    }
+}
+
+/// Totals for a directory tree
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DirSize {
+    /// Sum of file lengths
+    pub bytes: u64,
+    /// Sum of allocated sizes (cluster-rounded on Windows, block count on Unix)
+    pub size_on_disk: u64,
+    /// Number of files counted
+    pub files: u64,
+}
+
+impl DirSize {
+    /// The size selected by `options.size_on_disk`
+    pub fn size(&self, options: &DirSizeOptions) -> u64 {
+        if options.size_on_disk {
+            self.size_on_disk
+        } else {
+            self.bytes
+        }
+    }
+}
+
+/// Total size of all files under `path` (logical bytes)
+///
+/// Skips symlinks and `.git` internals. Returns 0 if the path can't be read.
+pub fn dir_size(path: &Path) -> u64 {
+    let options = DirSizeOptions::default();
+    dir_size_with_options(path, &options, None)
+        .map(|totals| totals.size(&options))
+        .unwrap_or(0)
+}
+
+/// Compute directory totals with explicit options
+///
+/// Returns `None` if `cancel` was set before the traversal finished; partial
+/// totals are never returned or cached.
+pub fn dir_size_with_options(
+    path: &Path,
+    options: &DirSizeOptions,
+    cancel: Option<&Arc<AtomicBool>>,
+) -> Option<DirSize> {
+    walk_dir_size(path, options.max_depth, cancel, None::<&fn(&Path)>)
+}
+
+/// [`dir_size_with_options`] that reuses the size stored in `cache` while the
+/// tree is unchanged
+///
+/// The cache entry is keyed by the latest mtime of `path` and every folder
+/// below it, so adding, removing or renaming a file anywhere in the tree
+/// invalidates it. Editing a file in place touches no folder; those entries
+/// still expire after a day.
+pub fn dir_size_cached(
+    path: &Path,
+    options: &DirSizeOptions,
+    cache: &Mutex<ScanCache>,
+    cancel: Option<&Arc<AtomicBool>>,
+) -> Option<DirSize> {
+    // Only full-depth results are cached, so the key doesn't need the depth
+    // Cached sizes don't say whether they include alternate streams
+    if options.max_depth != DEFAULT_DIR_SIZE_DEPTH || crate::ads::enabled() {
+        return dir_size_with_options(path, options, cancel);
+    }
+
+    // Taken before walking, so a change during the walk makes the entry stale
+    let Some(stamp) = tree_mtime(path, options.max_depth) else {
+        return dir_size_with_options(path, options, cancel);
+    };
+    if let Some(totals) = cache
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get_dir_size(path, stamp).ok().flatten())
+    {
+        return Some(totals);
+    }
+
+    let totals = dir_size_with_options(path, options, cancel)?;
+    if let Ok(mut cache) = cache.lock() {
+        let _ = cache.put_dir_size(path, stamp, &totals);
+    }
+    Some(totals)
+}
+
+/// Latest mtime of `path` and the folders below it (files aren't looked at)
+fn tree_mtime(path: &Path, max_depth: usize) -> Option<SystemTime> {
+    let mut latest = crate::utils::safe_metadata(path)
+        .and_then(|m| m.modified())
+        .ok()?;
+    for entry in WalkDir::new(path)
+        .max_depth(max_depth)
+        .follow_links(false)
+        .parallelism(crate::drive::walk_parallelism(Duration::from_secs(1)))
+        .process_read_dir(|_depth, _path, _state, children| {
+            // Same folders as the size walk: no symlinks, no .git internals
+            children.retain(|entry| {
+                entry.as_ref().is_ok_and(|e| {
+                    e.file_type().is_dir()
+                        && !e.file_type().is_symlink()
+                        && e.file_name.to_string_lossy() != ".git"
+                })
+            });
+        })
+        .into_iter()
+        .flatten()
+    {
+        if let Some(modified) = entry.metadata().ok().and_then(|m| m.modified().ok()) {
+            latest = latest.max(modified);
+        }
+    }
+    Some(latest)
+}
+
+/// Compute directory totals, calling `on_file` for every file visited
+///
+/// Always walks the tree, since progress needs real paths.
+pub fn dir_size_with_progress<F>(path: &Path, on_file: &F) -> DirSize
+where
+    F: Fn(&Path) + Sync,
+{
+    dir_size_with_progress_cancellable(path, on_file, None).unwrap_or_default()
+}
+
+/// [`dir_size_with_progress`] that stops early (returning `None`) once `cancel` is set
+pub fn dir_size_with_progress_cancellable<F>(
+    path: &Path,
+    on_file: &F,
+    cancel: Option<&Arc<AtomicBool>>,
+) -> Option<DirSize>
+where
+    F: Fn(&Path) + Sync,
+{
+    walk_dir_size(path, DEFAULT_DIR_SIZE_DEPTH, cancel, Some(on_file))
+}
+
+fn walk_dir_size<F>(
+    path: &Path,
+    max_depth: usize,
+    cancel: Option<&Arc<AtomicBool>>,
+    on_file: Option<&F>,
+) -> Option<DirSize>
+where
+    F: Fn(&Path) + Sync,
+{
+    let cancelled = || cancel.is_some_and(|c| c.load(Ordering::Relaxed));
+    let walk_cancel = cancel.cloned();
+    let cluster = cluster_size(path);
+
+    let bytes = AtomicU64::new(0);
+    let on_disk = AtomicU64::new(0);
+    let files = AtomicU64::new(0);
+
+    WalkDir::new(path)
+        .max_depth(max_depth)
+        .follow_links(false)
+        .parallelism(crate::drive::walk_parallelism(Duration::from_secs(1)))
+        .process_read_dir(move |_depth, _path, _state, children| {
+            // Stop descending once cancelled; the walk drains quickly
+            if walk_cancel
+                .as_ref()
+                .is_some_and(|c| c.load(Ordering::Relaxed))
+            {
+                children.clear();
+                return;
+            }
+            children.retain(|entry| {
+                if let Ok(ref e) = entry {
+                    if e.file_type().is_symlink() {
+                        return false;
+                    }
+                    // Skip .git internals
+                    if e.file_type().is_dir() && e.file_name.to_string_lossy() == ".git" {
+                        return false;
+                    }
+                }
+                true
+            });
+        })
+        .into_iter()
+        .for_each(|entry| {
+            if let Ok(e) = entry {
+                if e.file_type().is_file() {
+                    if let Some(on_file) = on_file {
+                        on_file(&e.path());
+                    }
+                    // Retry past MAX_PATH (deep node_modules trees)
+                    let meta = e
+                        .metadata()
+                        .ok()
+                        .or_else(|| crate::utils::safe_metadata(&e.path()).ok());
+                    if let Some(meta) = meta {
+                        let streams = crate::ads::stream_bytes(&e.path());
+                        bytes.fetch_add(meta.len() + streams, Ordering::Relaxed);
+                        on_disk.fetch_add(
+                            allocated_size(&e.path(), &meta, cluster)
+                                + round_up_to_cluster(streams, cluster),
+                            Ordering::Relaxed,
+                        );
+                        files.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+        });
+
+    if cancelled() {
+        return None;
+    }
+
+    Some(DirSize {
+        bytes: bytes.load(Ordering::Relaxed),
+        size_on_disk: on_disk.load(Ordering::Relaxed),
+        files: files.load(Ordering::Relaxed),
+    })
+}
+
+/// Round `len` up to a whole number of clusters (0 stays 0)
+pub fn round_up_to_cluster(len: u64, cluster: u64) -> u64 {
+    if cluster == 0 || len == 0 {
+        return len;
+    }
+    len.div_ceil(cluster).saturating_mul(cluster)
+}
+
+#[cfg(unix)]
+fn allocated_size(_path: &Path, meta: &std::fs::Metadata, _cluster: u64) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    meta.blocks() * 512
+}
+
+#[cfg(windows)]
+fn allocated_size(path: &Path, meta: &std::fs::Metadata, cluster: u64) -> u64 {
+    use std::os::windows::ffi::OsStrExt;
+
+    // GetCompressedFileSizeW reports the bytes actually stored for NTFS-compressed
+    // and sparse files (and the plain length for everything else)
+    let wide: Vec<u16> = path
+        .as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+
+    let stored = unsafe {
+        extern "system" {
+            fn GetCompressedFileSizeW(lpFileName: *const u16, lpFileSizeHigh: *mut u32) -> u32;
+        }
+
+        let mut high: u32 = 0;
+        let low = GetCompressedFileSizeW(wide.as_ptr(), &mut high);
+        // INVALID_FILE_SIZE signals failure; fall back to the logical length
+        if low == u32::MAX && high == 0 {
+            meta.len()
+        } else {
+            ((high as u64) << 32) | low as u64
+        }
+    };
+
+    round_up_to_cluster(stored, cluster)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn allocated_size(_path: &Path, meta: &std::fs::Metadata, cluster: u64) -> u64 {
+    round_up_to_cluster(meta.len(), cluster)
+}
+
+/// Cluster size of the volume containing `path` (0 if unknown)
+fn cluster_size(path: &Path) -> u64 {
+    #[cfg(windows)]
+    {
+        use std::os::windows::ffi::OsStrExt;
+
+        // Volume root, e.g. C:\ or \\server\share\
+        let root = match path.ancestors().last() {
+            Some(root) => root,
+            None => return 0,
+        };
+        let root: Vec<u16> = root
+            .as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
+
+        let mut sectors_per_cluster: u32 = 0;
+        let mut bytes_per_sector: u32 = 0;
+        let mut free_clusters: u32 = 0;
+        let mut total_clusters: u32 = 0;
+
+        unsafe {
+            extern "system" {
+                fn GetDiskFreeSpaceW(
+                    lpRootPathName: *const u16,
+                    lpSectorsPerCluster: *mut u32,
+                    lpBytesPerSector: *mut u32,
+                    lpNumberOfFreeClusters: *mut u32,
+                    lpTotalNumberOfClusters: *mut u32,
+                ) -> i32;
+            }
+
+            let result = GetDiskFreeSpaceW(
+                root.as_ptr(),
+                &mut sectors_per_cluster,
+                &mut bytes_per_sector,
+                &mut free_clusters,
+                &mut total_clusters,
+            );
+
+            if result != 0 {
+                return sectors_per_cluster as u64 * bytes_per_sector as u64;
+            }
+        }
+        0
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = path;
+        0
+    }
+}
+
+/// Space a delete would really free, as opposed to the selection's apparent size
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FreeEstimate {
+    /// Sum of file lengths (the size shown for the selection)
+    pub apparent_bytes: u64,
+    /// Bytes that would actually be freed
+    pub reclaim_bytes: u64,
+    pub files: u64,
+    /// Files with more than one hard link
+    pub hardlinked_files: u64,
+    /// Bytes that stay on disk because other hard links outside the selection
+    /// still point at them
+    pub shared_bytes: u64,
+    /// Bytes that were never on disk (NTFS-compressed or sparse files)
+    pub compressed_bytes: u64,
+}
+
+impl FreeEstimate {
+    /// Apparent bytes that won't come back as free space
+    pub fn unreclaimable_bytes(&self) -> u64 {
+        self.apparent_bytes.saturating_sub(self.reclaim_bytes)
+    }
+}
+
+/// What [`real_free_estimate`] needs to know about one file
+#[derive(Debug, Clone, Copy)]
+struct FileFacts {
+    /// (volume, file id) - only needed for files with several links
+    id: Option<(u64, u64)>,
+    links: u64,
+    len: u64,
+    /// Bytes stored on disk (compressed/sparse aware)
+    allocated: u64,
+}
+
+#[derive(Default)]
+struct FreeEstimator {
+    estimate: FreeEstimate,
+    /// Hard-linked files seen so far: id -> (links, bytes, links seen)
+    linked: HashMap<(u64, u64), (u64, u64, u64)>,
+}
+
+impl FreeEstimator {
+    fn add(&mut self, facts: FileFacts) {
+        self.estimate.files += 1;
+        self.estimate.apparent_bytes += facts.len;
+
+        // Compressed and sparse files free what they occupy, not their length
+        let stored = if facts.allocated < facts.len {
+            self.estimate.compressed_bytes += facts.len - facts.allocated;
+            facts.allocated
+        } else {
+            facts.len
+        };
+
+        match facts.id.filter(|_| facts.links > 1) {
+            Some(id) => {
+                let entry = self.linked.entry(id).or_insert((facts.links, stored, 0));
+                if entry.2 == 0 {
+                    self.estimate.hardlinked_files += 1;
+                }
+                entry.2 += 1;
+            }
+            None => self.estimate.reclaim_bytes += stored,
+        }
+    }
+
+    /// A hard-linked file is only freed once every link to it is deleted
+    fn finish(mut self) -> FreeEstimate {
+        for (links, stored, seen) in self.linked.into_values() {
+            if seen >= links {
+                self.estimate.reclaim_bytes += stored;
+            } else {
+                self.estimate.shared_bytes += stored;
+            }
+        }
+        self.estimate
+    }
+}
+
+/// Estimate how much deleting `paths` (files or folders) would really free
+///
+/// Hard-linked files only count once, and only when every link is part of the
+/// selection; NTFS-compressed and sparse files count what they occupy on disk.
+/// Returns `None` if `cancel` was set before the walk finished.
+pub fn real_free_estimate(
+    paths: &[PathBuf],
+    cancel: Option<&Arc<AtomicBool>>,
+) -> Option<FreeEstimate> {
+    let cancelled = || cancel.is_some_and(|c| c.load(Ordering::Relaxed));
+    let mut estimator = FreeEstimator::default();
+
+    for path in paths {
+        if cancelled() {
+            return None;
+        }
+        let Ok(meta) = crate::utils::safe_symlink_metadata(path) else {
+            continue;
+        };
+        if meta.is_file() {
+            let cluster = cluster_size(path);
+            estimator.add(file_facts(path, &meta, cluster));
+            continue;
+        }
+        if !meta.is_dir() {
+            continue;
+        }
+
+        let cluster = cluster_size(path);
+        for entry in WalkDir::new(path)
+            .max_depth(DEFAULT_DIR_SIZE_DEPTH)
+            .follow_links(false)
+            .process_read_dir(|_depth, _path, _state, children| {
+                children.retain(|entry| match entry {
+                    Ok(e) => !e.file_type().is_symlink(),
+                    Err(_) => true,
+                });
+            })
+            .into_iter()
+            .flatten()
+        {
+            if cancelled() {
+                return None;
+            }
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let entry_path = entry.path();
+            let meta = entry
+                .metadata()
+                .ok()
+                .or_else(|| crate::utils::safe_metadata(&entry_path).ok());
+            if let Some(meta) = meta {
+                estimator.add(file_facts(&entry_path, &meta, cluster));
+            }
+        }
+    }
+
+    Some(estimator.finish())
+}
+
+fn file_facts(path: &Path, meta: &std::fs::Metadata, cluster: u64) -> FileFacts {
+    let (links, id) = link_info(path, meta);
+    FileFacts {
+        id,
+        links,
+        len: meta.len(),
+        allocated: allocated_size(path, meta, cluster),
+    }
+}
+
+/// Link count and (device, inode) of a file
+#[cfg(unix)]
+fn link_info(_path: &Path, meta: &std::fs::Metadata) -> (u64, Option<(u64, u64)>) {
+    use std::os::unix::fs::MetadataExt;
+    (meta.nlink(), Some((meta.dev(), meta.ino())))
+}
+
+/// Link count and (volume serial, file index) of a file
+#[cfg(windows)]
+fn link_info(path: &Path, _meta: &std::fs::Metadata) -> (u64, Option<(u64, u64)>) {
+    use std::os::windows::fs::OpenOptionsExt;
+    use std::os::windows::io::AsRawHandle;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct ByHandleFileInformation {
+        file_attributes: u32,
+        creation_time: [u32; 2],
+        last_access_time: [u32; 2],
+        last_write_time: [u32; 2],
+        volume_serial_number: u32,
+        file_size_high: u32,
+        file_size_low: u32,
+        number_of_links: u32,
+        file_index_high: u32,
+        file_index_low: u32,
+    }
+
+    extern "system" {
+        fn GetFileInformationByHandle(
+            hFile: *mut std::ffi::c_void,
+            lpFileInformation: *mut ByHandleFileInformation,
+        ) -> i32;
+    }
+
+    // No access rights needed to query the link count, so locked files work too
+    let Ok(file) = std::fs::OpenOptions::new().access_mode(0).open(path) else {
+        return (1, None);
+    };
+    let mut info = ByHandleFileInformation::default();
+    let ok = unsafe { GetFileInformationByHandle(file.as_raw_handle(), &mut info) };
+    if ok == 0 {
+        return (1, None);
+    }
+    let index = ((info.file_index_high as u64) << 32) | info.file_index_low as u64;
+    (
+        info.number_of_links as u64,
+        Some((info.volume_serial_number as u64, index)),
+    )
+}
+
+#[cfg(not(any(unix, windows)))]
+fn link_info(_path: &Path, _meta: &std::fs::Metadata) -> (u64, Option<(u64, u64)>) {
+    (1, None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,6 +644,32 @@ mod tests {
         assert_eq!(parse_size("1gb").unwrap(), parse_size("1GB").unwrap());
     }
 
+    #[test]
+    fn test_free_estimate_hard_links_and_compression() {
+        let file = |id: u64, links: u64, len: u64, allocated: u64| FileFacts {
+            id: Some((1, id)),
+            links,
+            len,
+            allocated,
+        };
+
+        let mut estimator = FreeEstimator::default();
+        estimator.add(file(1, 1, 1000, 4096)); // plain file
+        estimator.add(file(2, 2, 500, 4096)); // both links selected
+        estimator.add(file(2, 2, 500, 4096));
+        estimator.add(file(3, 3, 800, 4096)); // one of three links selected
+        estimator.add(file(4, 1, 10_000, 4096)); // compressed
+        let estimate = estimator.finish();
+
+        assert_eq!(estimate.files, 5);
+        assert_eq!(estimate.apparent_bytes, 12_800);
+        assert_eq!(estimate.reclaim_bytes, 1000 + 500 + 4096);
+        assert_eq!(estimate.hardlinked_files, 2);
+        assert_eq!(estimate.shared_bytes, 800);
+        assert_eq!(estimate.compressed_bytes, 10_000 - 4096);
+        assert_eq!(estimate.unreclaimable_bytes(), 12_800 - 5596);
+    }
+
     #[test]
     fn test_parse_size_errors() {
         assert!(parse_size("").is_err());
@@ -89,4 +682,91 @@ mod tests {
         assert_eq!(parse_size("0.5GB").unwrap(), 536_870_912);
         assert_eq!(parse_size("2.5MB").unwrap(), 2_621_440);
     }
+
+    #[test]
+    fn test_round_up_to_cluster() {
+        assert_eq!(round_up_to_cluster(0, 4096), 0);
+        assert_eq!(round_up_to_cluster(1, 4096), 4096);
+        assert_eq!(round_up_to_cluster(4096, 4096), 4096);
+        assert_eq!(round_up_to_cluster(4097, 4096), 8192);
+        assert_eq!(round_up_to_cluster(123, 0), 123);
+    }
+
+    #[test]
+    fn test_dir_size_counts_nested_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let nested = temp_dir.path().join("a").join("b");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(temp_dir.path().join("one.txt"), "12345").unwrap();
+        std::fs::write(nested.join("two.txt"), "1234567890").unwrap();
+
+        let options = DirSizeOptions::default();
+        let totals = dir_size_with_options(temp_dir.path(), &options, None).unwrap();
+        assert_eq!(totals.bytes, 15);
+        assert_eq!(totals.files, 2);
+    }
+
+    #[test]
+    fn test_dir_size_skips_git() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let git = temp_dir.path().join(".git");
+        std::fs::create_dir_all(&git).unwrap();
+        std::fs::write(git.join("HEAD"), "ref: refs/heads/main").unwrap();
+        std::fs::write(temp_dir.path().join("file.txt"), "abc").unwrap();
+
+        let options = DirSizeOptions::default();
+        let totals = dir_size_with_options(temp_dir.path(), &options, None).unwrap();
+        assert_eq!(totals.bytes, 3);
+    }
+
+    #[test]
+    fn test_dir_size_cancelled() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("sub")).unwrap();
+        std::fs::write(temp_dir.path().join("sub").join("file.txt"), "abc").unwrap();
+
+        let cancel = Arc::new(AtomicBool::new(true));
+        let options = DirSizeOptions::default();
+        assert!(dir_size_with_options(temp_dir.path(), &options, Some(&cancel)).is_none());
+    }
+
+    #[test]
+    fn test_dir_size_cache_sees_deeper_changes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache_dir = tempfile::tempdir().unwrap();
+        let cache = Mutex::new(ScanCache::open_in(cache_dir.path()).unwrap());
+        let root = temp_dir.path().join("project");
+        let nested = root.join("a").join("b");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(nested.join("one.txt"), "abc").unwrap();
+
+        let options = DirSizeOptions::default();
+        let size =
+            |cache: &Mutex<ScanCache>| dir_size_cached(&root, &options, cache, None).unwrap().bytes;
+        assert_eq!(size(&cache), 3);
+
+        // An unchanged tree is answered from the cache
+        let stamp = tree_mtime(&root, DEFAULT_DIR_SIZE_DEPTH).unwrap();
+        let stored = DirSize {
+            bytes: 42,
+            ..Default::default()
+        };
+        cache
+            .lock()
+            .unwrap()
+            .put_dir_size(&root, stamp, &stored)
+            .unwrap();
+        assert_eq!(size(&cache), 42);
+
+        // A file added deep down leaves the root's mtime alone but not the tree's
+        let root_mtime = std::fs::metadata(&root).unwrap().modified().unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        std::fs::write(nested.join("two.txt"), "12345").unwrap();
+        assert_eq!(
+            std::fs::metadata(&root).unwrap().modified().unwrap(),
+            root_mtime
+        );
+        assert_eq!(size(&cache), 8);
+        assert_eq!(size(&cache), 8);
+    }
 }
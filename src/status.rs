@@ -28,6 +28,22 @@ lazy_static::lazy_static! {
 #[cfg(windows)]
 static DISK_BREAKDOWN_REFRESH_TRIGGERED: AtomicBool = AtomicBool::new(false);
 
+// Status refreshes run on a fresh thread each time, so per-process I/O
+// counters live in a static rather than in METRICS_STATE
+#[cfg(not(windows))]
+lazy_static::lazy_static! {
+    static ref PROCESS_IO_STATE: std::sync::Mutex<ProcessIoState> =
+        std::sync::Mutex::new(ProcessIoState::default());
+}
+
+/// Cumulative read/write bytes per process at the previous refresh
+#[cfg(not(windows))]
+#[derive(Debug, Default)]
+struct ProcessIoState {
+    sampled: Option<Instant>,
+    counters: HashMap<u32, (u64, u64)>,
+}
+
 #[derive(Debug)]
 struct MetricsState {
     network: NetworkState,
@@ -226,7 +242,11 @@ pub struct SystemStatus {
     pub temperature_sensors: Vec<TemperatureSensor>,
     pub gpu: Option<GpuMetrics>,
     pub processes: Vec<ProcessInfo>,
-    #[cfg(windows)]
+    /// Every process, for the leak watch and the process tree
+    #[serde(skip)]
+    pub all_processes: Vec<ProcessSample>,
+    /// Size of wole's scan cache and when a scan last updated it
+    pub scan_cache: Option<crate::scan_cache::CacheSummary>,
     pub top_io_processes: Vec<ProcessIOMetrics>,
     #[cfg(windows)]
     pub disk_breakdown: Option<DiskBreakdown>,
@@ -316,6 +336,12 @@ pub struct PowerMetrics {
     pub time_to_full_seconds: Option<u64>,
     pub voltage_volts: Option<f32>,
     pub energy_rate_watts: Option<f32>,
+    /// Full-charge vs design capacity from `powercfg /batteryreport` (percent)
+    #[serde(default)]
+    pub capacity_percent: Option<f32>,
+    /// Active power plan
+    #[serde(default)]
+    pub power_plan: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -340,6 +366,17 @@ pub struct ProcessInfo {
     pub page_faults_per_sec: Option<u32>,
 }
 
+/// One process at a Status refresh
+#[derive(Debug, Clone, Default)]
+pub struct ProcessSample {
+    pub pid: u32,
+    pub parent: Option<u32>,
+    pub name: String,
+    pub cpu_usage: f32,
+    /// Working set
+    pub memory_bytes: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessIOMetrics {
     pub name: String,
@@ -521,6 +558,8 @@ pub fn gather_status_with_options(
 
         #[cfg(not(windows))]
         let processes = gather_top_processes_basic(system, 10);
+        #[cfg(not(windows))]
+        let top_io_processes = gather_process_io_metrics(system);
 
         // Gather disk breakdown (cached, expensive operation)
         // Only use cached data to avoid blocking - don't scan on first load
@@ -533,6 +572,20 @@ pub fn gather_status_with_options(
             disk_breakdown
         };
 
+        let all_processes = system
+            .processes()
+            .iter()
+            .map(|(pid, proc)| ProcessSample {
+                pid: pid.as_u32(),
+                parent: proc.parent().map(|parent| parent.as_u32()),
+                name: proc.name().to_string_lossy().to_string(),
+                cpu_usage: proc.cpu_usage(),
+                memory_bytes: proc.memory(),
+            })
+            .collect();
+
+        let scan_cache = crate::scan_cache::cache_summary();
+
         // Calculate health score
         let health_score = calculate_health_score(&cpu, &memory, &disk, &power);
 
@@ -552,7 +605,8 @@ pub fn gather_status_with_options(
             temperature_sensors,
             gpu,
             processes,
-            #[cfg(windows)]
+            all_processes,
+            scan_cache,
             top_io_processes,
             #[cfg(windows)]
             disk_breakdown,
@@ -855,7 +909,7 @@ fn gather_power_metrics() -> Option<PowerMetrics> {
     let status = format!("{:?}", battery.state());
     // Battery 0.7 uses state_of_health() instead of health()
     let health_percent = battery.state_of_health().get::<percent>();
-    let health = if health_percent >= 80.0 {
+    let mut health = if health_percent >= 80.0 {
         "Good".to_string()
     } else if health_percent >= 50.0 {
         "Fair".to_string()
@@ -869,7 +923,7 @@ fn gather_power_metrics() -> Option<PowerMetrics> {
         kelvin - 273.15 // Convert Kelvin to Celsius
     });
 
-    let cycles = battery.cycle_count();
+    let mut cycles = battery.cycle_count();
 
     // Chemistry/Technology - returns Technology directly (not Option)
     // Only show if it's not Unknown
@@ -885,11 +939,31 @@ fn gather_power_metrics() -> Option<PowerMetrics> {
 
     // Design capacity (original capacity when new) - returns Energy directly, convert from Wh to mWh
     // Convert f32 to f64 for the struct field
-    let design_capacity_mwh = Some(battery.energy_full_design().get::<watt_hour>() as f64 * 1000.0);
+    let mut design_capacity_mwh =
+        Some(battery.energy_full_design().get::<watt_hour>() as f64 * 1000.0);
 
     // Full charge capacity (current maximum capacity) - returns Energy directly, convert from Wh to mWh
     // Convert f32 to f64 for the struct field
-    let full_charge_capacity_mwh = Some(battery.energy_full().get::<watt_hour>() as f64 * 1000.0);
+    let mut full_charge_capacity_mwh =
+        Some(battery.energy_full().get::<watt_hour>() as f64 * 1000.0);
+
+    // Prefer the capacities Windows records in its battery report
+    let report = crate::power::battery_report();
+    let mut capacity_percent = None;
+    if let Some(report) = &report {
+        design_capacity_mwh = Some(report.design_capacity_mwh);
+        full_charge_capacity_mwh = Some(report.full_charge_capacity_mwh);
+        cycles = cycles.or(report.cycle_count);
+        let capacity = report.capacity_percent();
+        capacity_percent = Some(capacity);
+        health = if capacity >= 80.0 {
+            "Good".to_string()
+        } else if capacity >= 50.0 {
+            "Fair".to_string()
+        } else {
+            "Poor".to_string()
+        };
+    }
 
     // Time estimates
     let time_to_empty_seconds = battery.time_to_empty().map(|t| t.get::<second>() as u64);
@@ -914,6 +988,8 @@ fn gather_power_metrics() -> Option<PowerMetrics> {
         time_to_full_seconds,
         voltage_volts,
         energy_rate_watts,
+        capacity_percent,
+        power_plan: crate::power::active_plan_name(),
     })
 }
 
@@ -1053,7 +1129,7 @@ fn gather_network_metrics(state: &mut NetworkState, elapsed: Duration) -> Networ
     }
 }
 
-fn gather_temperature_sensors() -> Vec<TemperatureSensor> {
+pub(crate) fn gather_temperature_sensors() -> Vec<TemperatureSensor> {
     use sysinfo::Components;
 
     let components = Components::new_with_refreshed_list();
@@ -2159,7 +2235,7 @@ fn gather_process_io_metrics() -> Vec<ProcessIOMetrics> {
         Err(_) => return Vec::new(),
     };
 
-    let mut io_metrics: Vec<ProcessIOMetrics> = results
+    let io_metrics: Vec<ProcessIOMetrics> = results
         .into_iter()
         .filter_map(|row| {
             let name_var = row.get("Name")?;
@@ -2189,7 +2265,100 @@ fn gather_process_io_metrics() -> Vec<ProcessIOMetrics> {
         })
         .collect();
 
-    // Sort by total I/O (read + write) descending
+    top_io(io_metrics)
+}
+
+/// Per-process I/O rates from the change in cumulative counters since the
+/// previous refresh (nothing on the first refresh)
+#[cfg(not(windows))]
+fn gather_process_io_metrics(system: &System) -> Vec<ProcessIOMetrics> {
+    let now = Instant::now();
+    let mut state = PROCESS_IO_STATE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let elapsed = state.sampled.map(|sampled| now.duration_since(sampled));
+
+    let mut counters = HashMap::new();
+    let mut io_metrics = Vec::new();
+    for (pid, process) in system.processes() {
+        let pid = pid.as_u32();
+        let Some(current) = process_io_bytes(pid) else {
+            continue;
+        };
+        let rates = elapsed
+            .zip(state.counters.get(&pid))
+            .and_then(|(elapsed, previous)| io_rates(*previous, current, elapsed));
+        if let Some((read_bytes_per_sec, write_bytes_per_sec)) = rates {
+            io_metrics.push(ProcessIOMetrics {
+                name: process.name().to_string_lossy().to_string(),
+                pid,
+                read_bytes_per_sec,
+                write_bytes_per_sec,
+            });
+        }
+        counters.insert(pid, current);
+    }
+    state.sampled = Some(now);
+    state.counters = counters;
+
+    top_io(io_metrics)
+}
+
+/// Bytes per second read and written between two counter readings, if any
+#[cfg(any(not(windows), test))]
+fn io_rates(previous: (u64, u64), current: (u64, u64), elapsed: Duration) -> Option<(f64, f64)> {
+    let secs = elapsed.as_secs_f64();
+    // Counters that went backwards belong to a reused pid
+    if secs < 0.1 || current.0 < previous.0 || current.1 < previous.1 {
+        return None;
+    }
+    let read = (current.0 - previous.0) as f64 / secs;
+    let write = (current.1 - previous.1) as f64 / secs;
+    (read > 0.0 || write > 0.0).then_some((read, write))
+}
+
+/// Cumulative bytes a process has read from and written to storage
+#[cfg(target_os = "linux")]
+fn process_io_bytes(pid: u32) -> Option<(u64, u64)> {
+    // Other users' processes are unreadable without root and are skipped
+    let text = std::fs::read_to_string(format!("/proc/{}/io", pid)).ok()?;
+    parse_proc_io(&text)
+}
+
+/// `read_bytes` and `write_bytes` from /proc/<pid>/io (storage I/O, unlike
+/// `rchar`/`wchar`, which count cache hits and pipes too)
+#[cfg(any(target_os = "linux", test))]
+fn parse_proc_io(text: &str) -> Option<(u64, u64)> {
+    let field = |name: &str| {
+        text.lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+            .and_then(|value| value.trim().parse::<u64>().ok())
+    };
+    Some((field("read_bytes")?, field("write_bytes")?))
+}
+
+#[cfg(target_os = "macos")]
+fn process_io_bytes(pid: u32) -> Option<(u64, u64)> {
+    // SAFETY: rusage_info_v2 is plain data and proc_pid_rusage fills at most
+    // that many bytes for RUSAGE_INFO_V2
+    let mut info: libc::rusage_info_v2 = unsafe { std::mem::zeroed() };
+    let result = unsafe {
+        libc::proc_pid_rusage(
+            pid as libc::c_int,
+            libc::RUSAGE_INFO_V2,
+            (&mut info as *mut libc::rusage_info_v2).cast::<libc::rusage_info_t>(),
+        )
+    };
+    (result == 0).then_some((info.ri_diskio_bytesread, info.ri_diskio_byteswritten))
+}
+
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
+fn process_io_bytes(_pid: u32) -> Option<(u64, u64)> {
+    None
+}
+
+/// The five processes with the most I/O (read + write)
+fn top_io(mut io_metrics: Vec<ProcessIOMetrics>) -> Vec<ProcessIOMetrics> {
     io_metrics.sort_by(|a, b| {
         let total_a = a.read_bytes_per_sec + a.write_bytes_per_sec;
         let total_b = b.read_bytes_per_sec + b.write_bytes_per_sec;
@@ -2197,16 +2366,9 @@ fn gather_process_io_metrics() -> Vec<ProcessIOMetrics> {
             .partial_cmp(&total_a)
             .unwrap_or(std::cmp::Ordering::Equal)
     });
-
-    // Return top 5 I/O processes
     io_metrics.into_iter().take(5).collect()
 }
 
-#[cfg(not(windows))]
-fn gather_process_io_metrics() -> Vec<ProcessIOMetrics> {
-    Vec::new()
-}
-
 #[cfg(windows)]
 fn gather_process_handle_counts() -> HashMap<u32, u32> {
     use std::collections::HashMap;
@@ -2784,11 +2946,25 @@ pub fn format_cli_output(status: &SystemStatus) -> String {
             ));
         }
 
-        // Power Full Charge Capacity
+        // Power Full Charge Capacity (with wear from the battery report)
         if let Some(full_cap) = power.full_charge_capacity_mwh {
+            match power.capacity_percent {
+                Some(percent) => output.push_str(&format!(
+                    "                                    Full    {:.0} mWh ({:.0}%)\n",
+                    full_cap, percent
+                )),
+                None => output.push_str(&format!(
+                    "                                    Full    {:.0} mWh\n",
+                    full_cap
+                )),
+            }
+        }
+
+        // Power Plan
+        if let Some(ref plan) = power.power_plan {
             output.push_str(&format!(
-                "                                    Full    {:.0} mWh\n",
-                full_cap
+                "                                    Plan    {}\n",
+                plan
             ));
         }
     } else {
@@ -3153,9 +3329,29 @@ fn format_disk_section_new(status: &SystemStatus) -> Vec<String> {
         }
     }
 
+    if let Some(cache) = &status.scan_cache {
+        lines.push(format_bar_value_line(
+            "Cache",
+            MAIN_LABEL_WIDTH,
+            None,
+            MAIN_BAR_WIDTH,
+            &scan_cache_label(cache),
+            MAIN_VALUE_WIDTH,
+        ));
+    }
+
     lines
 }
 
+/// "48.2 MB, 2d ago": size of the scan cache and age of the last scan
+pub fn scan_cache_label(cache: &crate::scan_cache::CacheSummary) -> String {
+    format!(
+        "{}, {}",
+        bytesize::to_string(cache.size_bytes, false),
+        cache.age_label()
+    )
+}
+
 fn format_power_section_new(status: &SystemStatus) -> Vec<String> {
     let mut lines = vec![];
     // Title - naturally left-aligned, no padding
@@ -3186,6 +3382,9 @@ fn format_power_section_new(status: &SystemStatus) -> Vec<String> {
 
         // Health - consistent format
         let mut health_value = power.health.clone();
+        if let Some(percent) = power.capacity_percent {
+            health_value.push_str(&format!(" {:.0}%", percent));
+        }
         if let Some(cycles) = power.cycles {
             health_value.push_str(&format!(" · {} cycles", cycles));
         }
@@ -3748,3 +3947,32 @@ fn create_colored_bar_inverted(value: f32, width: usize) -> String {
 
     format!("{}{}{}", color_code, bar, reset)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proc_io() {
+        let text = "rchar: 323934931\nwchar: 323929600\nsyscr: 632687\nsyscw: 632675\n\
+                    read_bytes: 4096\nwrite_bytes: 323932160\ncancelled_write_bytes: 0\n";
+        assert_eq!(parse_proc_io(text), Some((4096, 323932160)));
+        assert_eq!(parse_proc_io("rchar: 1\nwchar: 2\n"), None);
+    }
+
+    #[test]
+    fn test_io_rates() {
+        let two_secs = Duration::from_secs(2);
+        assert_eq!(
+            io_rates((1_000, 0), (5_000, 2_000), two_secs),
+            Some((2_000.0, 1_000.0))
+        );
+        // Idle, too soon, or a reused pid
+        assert_eq!(io_rates((1_000, 0), (1_000, 0), two_secs), None);
+        assert_eq!(
+            io_rates((0, 0), (1_000, 0), Duration::from_millis(50)),
+            None
+        );
+        assert_eq!(io_rates((5_000, 0), (1_000, 0), two_secs), None);
+    }
+}
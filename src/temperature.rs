@@ -0,0 +1,236 @@
+//! Temperature thresholds, alerts and logging
+//!
+//! Sensors are rated against `temperature.warning_celsius` and
+//! `temperature.critical_celsius` (or the sensor's own critical point when it
+//! reports a lower one). During long scans and cleanups the TUI samples the
+//! sensors in the background through a [`TempWatch`] and shows a toast when
+//! one crosses critical. With `temperature.log` on, readings are appended to
+//! `temperature.csv` next to wole's other logs.
+
+use crate::config::TemperatureSettings;
+use crate::status::TemperatureSensor;
+use std::collections::HashSet;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+
+/// How often sensors are sampled during a scan or cleanup, and how often
+/// readings are logged
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(10);
+
+const LOG_FILE: &str = "temperature.csv";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TempLevel {
+    Normal,
+    Warning,
+    Critical,
+}
+
+impl TempLevel {
+    pub fn label(self) -> &'static str {
+        match self {
+            TempLevel::Normal => "normal",
+            TempLevel::Warning => "warning",
+            TempLevel::Critical => "critical",
+        }
+    }
+}
+
+/// Critical point for a sensor: the configured one, or the sensor's own if lower
+pub fn critical_celsius(sensor: &TemperatureSensor, settings: &TemperatureSettings) -> f32 {
+    sensor
+        .critical_celsius
+        .filter(|critical| *critical > 0.0)
+        .map_or(settings.critical_celsius, |critical| {
+            critical.min(settings.critical_celsius)
+        })
+}
+
+pub fn level(sensor: &TemperatureSensor, settings: &TemperatureSettings) -> TempLevel {
+    if sensor.temperature_celsius >= critical_celsius(sensor, settings) {
+        TempLevel::Critical
+    } else if sensor.temperature_celsius >= settings.warning_celsius {
+        TempLevel::Warning
+    } else {
+        TempLevel::Normal
+    }
+}
+
+/// Quote a CSV field when it needs it
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Append one row per sensor to the temperature log
+fn log_readings(sensors: &[TemperatureSensor], settings: &TemperatureSettings) {
+    let Some(path) = crate::debug_log::log_path(LOG_FILE) else {
+        return;
+    };
+    let is_new = !path.exists();
+    let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&path) else {
+        return;
+    };
+    if is_new {
+        let _ = writeln!(file, "timestamp,sensor,celsius,level");
+    }
+    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+    for sensor in sensors {
+        let _ = writeln!(
+            file,
+            "{},{},{:.1},{}",
+            timestamp,
+            csv_field(&sensor.label),
+            sensor.temperature_celsius,
+            level(sensor, settings).label()
+        );
+    }
+}
+
+/// Samples sensors in the background and reports ones that go critical
+#[derive(Debug, Default)]
+pub struct TempWatch {
+    pending: Option<Receiver<Vec<TemperatureSensor>>>,
+    last_sample: Option<Instant>,
+    last_logged: Option<Instant>,
+    /// Sensors currently at or above critical, so each crossing alerts once
+    critical: HashSet<String>,
+}
+
+impl TempWatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a background sample when one is due and pick up a finished one.
+    /// Returns an alert for each sensor that just crossed critical.
+    pub fn poll(&mut self, settings: &TemperatureSettings) -> Vec<String> {
+        if let Some(ref receiver) = self.pending {
+            match receiver.try_recv() {
+                Ok(sensors) => {
+                    self.pending = None;
+                    return self.record(&sensors, settings);
+                }
+                Err(TryRecvError::Empty) => return Vec::new(),
+                Err(TryRecvError::Disconnected) => self.pending = None,
+            }
+        }
+
+        if self
+            .last_sample
+            .is_some_and(|sampled| sampled.elapsed() < SAMPLE_INTERVAL)
+        {
+            return Vec::new();
+        }
+        self.last_sample = Some(Instant::now());
+        let (tx, rx) = mpsc::channel();
+        self.pending = Some(rx);
+        std::thread::spawn(move || {
+            let _ = tx.send(crate::status::gather_temperature_sensors());
+        });
+        Vec::new()
+    }
+
+    /// Log a set of readings (at most once per sample interval) and return an
+    /// alert for each sensor that just crossed critical
+    pub fn record(
+        &mut self,
+        sensors: &[TemperatureSensor],
+        settings: &TemperatureSettings,
+    ) -> Vec<String> {
+        if settings.log
+            && self
+                .last_logged
+                .is_none_or(|logged| logged.elapsed() >= SAMPLE_INTERVAL)
+        {
+            self.last_logged = Some(Instant::now());
+            log_readings(sensors, settings);
+        }
+
+        let mut alerts = Vec::new();
+        for sensor in sensors {
+            if level(sensor, settings) == TempLevel::Critical {
+                if self.critical.insert(sensor.label.clone()) {
+                    alerts.push(format!(
+                        "{} is at {:.0}°C (critical: {:.0}°C)",
+                        sensor.label,
+                        sensor.temperature_celsius,
+                        critical_celsius(sensor, settings)
+                    ));
+                }
+            } else {
+                self.critical.remove(&sensor.label);
+            }
+        }
+        alerts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sensor(label: &str, celsius: f32, critical: Option<f32>) -> TemperatureSensor {
+        TemperatureSensor {
+            label: label.to_string(),
+            temperature_celsius: celsius,
+            max_celsius: None,
+            critical_celsius: critical,
+        }
+    }
+
+    #[test]
+    fn test_levels_use_lower_critical_point() {
+        let settings = TemperatureSettings::default();
+        assert_eq!(
+            level(&sensor("CPU", 60.0, None), &settings),
+            TempLevel::Normal
+        );
+        assert_eq!(
+            level(&sensor("CPU", settings.warning_celsius, None), &settings),
+            TempLevel::Warning
+        );
+        assert_eq!(
+            level(&sensor("CPU", settings.critical_celsius, None), &settings),
+            TempLevel::Critical
+        );
+        // The sensor's own critical point wins when it is lower
+        assert_eq!(
+            level(&sensor("SSD", 70.0, Some(70.0)), &settings),
+            TempLevel::Critical
+        );
+        // A zero critical point means the sensor doesn't report one
+        assert_eq!(
+            level(&sensor("GPU", 60.0, Some(0.0)), &settings),
+            TempLevel::Normal
+        );
+    }
+
+    #[test]
+    fn test_alerts_once_per_crossing() {
+        let settings = TemperatureSettings::default();
+        let mut watch = TempWatch::new();
+        let hot = [sensor("CPU", 99.0, None)];
+        let cool = [sensor("CPU", 50.0, None)];
+
+        assert_eq!(watch.record(&hot, &settings).len(), 1);
+        assert!(watch.record(&hot, &settings).is_empty());
+        assert!(watch.record(&cool, &settings).is_empty());
+        assert_eq!(
+            watch.record(&hot, &settings),
+            ["CPU is at 99°C (critical: 85°C)"]
+        );
+    }
+
+    #[test]
+    fn test_csv_field_quotes_when_needed() {
+        assert_eq!(csv_field("CPU Package"), "CPU Package");
+        assert_eq!(csv_field("Core 0, die"), "\"Core 0, die\"");
+        assert_eq!(csv_field("\"GPU\""), "\"\"\"GPU\"\"\"");
+    }
+}
@@ -0,0 +1,312 @@
+//! Filesystem fixtures and golden scans for tests (`--features testing`)
+//!
+//! A [`Fixture`] is a temporary folder laid out like a user profile: old
+//! downloads, temp files, build trees, big videos, empty folders. Most
+//! categories look at the profile (`USERPROFILE`, `TEMP`, `LOCALAPPDATA`...)
+//! rather than the scan root. [`Fixture::activate`] points those variables
+//! at the fixture until the returned guard is dropped, so a scan sees the
+//! fixture and never the real system.
+//!
+//! A [`GoldenScan`] enables only the categories it expects results for,
+//! scans the fixture and compares each category's paths with the expected
+//! ones:
+//!
+//! ```no_run
+//! use wole::api::Category;
+//! use wole::testing::{Fixture, GoldenScan};
+//!
+//! let fixture = Fixture::new();
+//! fixture.download("setup.exe", 1024, 90).download("today.zip", 1024, 0);
+//! GoldenScan::new()
+//!     .expect(Category::Downloads, &["Downloads/setup.exe"])
+//!     .run(&fixture);
+//! ```
+
+use crate::api::{no_categories, Category};
+use crate::cli::ScanOptions;
+use crate::config::Config;
+use crate::output::{OutputMode, ScanResults};
+use crate::users::ScanScope;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, SystemTime};
+use tempfile::TempDir;
+
+/// Environment variables a fixture takes over while active
+const PROFILE_VARS: [&str; 6] = [
+    "USERPROFILE",
+    "HOME",
+    "TEMP",
+    "TMP",
+    "LOCALAPPDATA",
+    "APPDATA",
+];
+
+/// Environment variables are process-wide: one active fixture at a time
+static ACTIVE: Mutex<()> = Mutex::new(());
+
+/// A fake user profile in a temporary folder, removed on drop
+pub struct Fixture {
+    dir: TempDir,
+}
+
+impl Default for Fixture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fixture {
+    pub fn new() -> Self {
+        Self {
+            // No leading dot: several categories skip hidden entries
+            dir: tempfile::Builder::new()
+                .prefix("wole-fixture-")
+                .tempdir()
+                .expect("fixture: can't create temp dir"),
+        }
+    }
+
+    /// The profile folder (also the scan root)
+    pub fn root(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// `relative` (with `/` separators) under the root
+    pub fn path(&self, relative: &str) -> PathBuf {
+        relative
+            .split('/')
+            .fold(self.root().to_path_buf(), |path, part| path.join(part))
+    }
+
+    /// Create a folder (and its parents)
+    pub fn dir(&self, relative: &str) -> &Self {
+        fs::create_dir_all(self.path(relative)).expect("fixture: can't create folder");
+        self
+    }
+
+    /// Create a file of `size` bytes (sparse, so huge sizes are cheap)
+    pub fn file(&self, relative: &str, size: u64) -> &Self {
+        self.old_file(relative, size, 0)
+    }
+
+    /// Create a file of `size` bytes last modified `age_days` ago
+    pub fn old_file(&self, relative: &str, size: u64, age_days: u64) -> &Self {
+        let path = self.path(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("fixture: can't create folder");
+        }
+        let file = fs::File::create(&path).expect("fixture: can't create file");
+        file.set_len(size).expect("fixture: can't size file");
+        age(&file, age_days);
+        self
+    }
+
+    /// A file in `Downloads`
+    pub fn download(&self, name: &str, size: u64, age_days: u64) -> &Self {
+        self.old_file(&format!("Downloads/{}", name), size, age_days)
+    }
+
+    /// A file in `%TEMP%`
+    pub fn temp_file(&self, name: &str, size: u64, age_days: u64) -> &Self {
+        self.old_file(&format!("Temp/{}", name), size, age_days)
+    }
+
+    /// A Node project untouched for `age_days`, with a `node_modules` tree
+    pub fn node_project(&self, relative: &str, age_days: u64) -> &Self {
+        self.old_file(&format!("{}/package.json", relative), 2, age_days)
+            .old_file(&format!("{}/index.js", relative), 64, age_days)
+            .old_file(
+                &format!("{}/node_modules/left-pad/index.js", relative),
+                1024,
+                age_days,
+            )
+    }
+
+    /// A Rust project untouched for `age_days`, with a `target` tree
+    pub fn rust_project(&self, relative: &str, age_days: u64) -> &Self {
+        self.old_file(&format!("{}/Cargo.toml", relative), 2, age_days)
+            .old_file(&format!("{}/src/main.rs", relative), 64, age_days)
+            .old_file(&format!("{}/target/debug/app", relative), 4096, age_days)
+    }
+
+    /// Point the profile variables at the fixture until the guard is dropped
+    ///
+    /// Blocks while another fixture is active.
+    pub fn activate(&self) -> ActiveFixture<'_> {
+        let lock = ACTIVE.lock().unwrap_or_else(|e| e.into_inner());
+        let saved = PROFILE_VARS
+            .iter()
+            .map(|&var| (var, std::env::var_os(var)))
+            .collect();
+        let local = self.path("AppData/Local");
+        for (var, value) in [
+            ("USERPROFILE", self.root().to_path_buf()),
+            ("HOME", self.root().to_path_buf()),
+            ("TEMP", self.path("Temp")),
+            ("TMP", self.path("Temp")),
+            ("LOCALAPPDATA", local),
+            ("APPDATA", self.path("AppData/Roaming")),
+        ] {
+            std::env::set_var(var, value);
+        }
+        ActiveFixture {
+            _fixture: self,
+            saved,
+            _lock: lock,
+        }
+    }
+}
+
+/// Set a file's modification time `age_days` into the past
+fn age(file: &fs::File, age_days: u64) {
+    if age_days == 0 {
+        return;
+    }
+    let modified = SystemTime::now() - Duration::from_secs(age_days * 24 * 60 * 60);
+    file.set_modified(modified)
+        .expect("fixture: can't set modification time");
+}
+
+/// A fixture whose folders stand in for the user profile
+pub struct ActiveFixture<'a> {
+    _fixture: &'a Fixture,
+    saved: Vec<(&'static str, Option<OsString>)>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Drop for ActiveFixture<'_> {
+    fn drop(&mut self) {
+        for (var, value) in &self.saved {
+            match value {
+                Some(value) => std::env::set_var(var, value),
+                None => std::env::remove_var(var),
+            }
+        }
+    }
+}
+
+/// Expected paths per category for a scan of a [`Fixture`]
+pub struct GoldenScan {
+    config: Config,
+    expected: Vec<(Category, Vec<String>)>,
+}
+
+impl Default for GoldenScan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GoldenScan {
+    /// Built-in config defaults, no categories
+    pub fn new() -> Self {
+        Self {
+            config: Config::default(),
+            expected: Vec::new(),
+        }
+    }
+
+    /// Scan with `config` instead of the defaults (thresholds, exclusions...)
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Scan `category` and expect exactly `paths` (relative to the fixture
+    /// root, `/` separators, any order)
+    pub fn expect(mut self, category: Category, paths: &[&str]) -> Self {
+        self.expected.push((
+            category,
+            paths.iter().map(|path| path.to_string()).collect(),
+        ));
+        self
+    }
+
+    /// Scan options enabling the expected categories
+    pub fn options(&self) -> ScanOptions {
+        let mut options = no_categories(
+            &self.config,
+            self.config.thresholds.min_size_mb * 1024 * 1024,
+            ScanScope::CurrentUser,
+        );
+        for (category, _) in &self.expected {
+            category.enable(&mut options);
+        }
+        options
+    }
+
+    /// Scan `fixture` and panic on the first category that doesn't match
+    pub fn run(&self, fixture: &Fixture) -> ScanResults {
+        let results = {
+            let _active = fixture.activate();
+            crate::scanner::run_scan(
+                fixture.root(),
+                self.options(),
+                OutputMode::Quiet,
+                &self.config,
+                &mut None,
+            )
+            .expect("golden scan failed")
+        };
+
+        for (category, expected) in &self.expected {
+            let mut expected = expected.clone();
+            expected.sort();
+            let mut found: Vec<String> = category
+                .result(&results)
+                .paths
+                .iter()
+                .map(|path| relative(fixture.root(), path))
+                .collect();
+            found.sort();
+            assert_eq!(
+                found,
+                expected,
+                "{} found other paths than expected",
+                category.name()
+            );
+        }
+        results
+    }
+}
+
+/// `path` relative to `root` with `/` separators (absolute if outside it)
+fn relative(root: &Path, path: &Path) -> String {
+    match path.strip_prefix(root) {
+        Ok(relative) => relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"),
+        Err(_) => path.display().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixture_layout_and_env() {
+        let fixture = Fixture::new();
+        fixture.download("old.zip", 10, 90).dir("Documents/empty");
+        let download = fixture.path("Downloads/old.zip");
+        assert_eq!(fs::metadata(&download).unwrap().len(), 10);
+        let modified = fs::metadata(&download).unwrap().modified().unwrap();
+        assert!(modified < SystemTime::now() - Duration::from_secs(80 * 24 * 60 * 60));
+        assert_eq!(relative(fixture.root(), &download), "Downloads/old.zip");
+
+        let before = std::env::var_os("USERPROFILE");
+        {
+            let _active = fixture.activate();
+            assert_eq!(
+                std::env::var_os("USERPROFILE"),
+                Some(fixture.root().as_os_str().to_os_string())
+            );
+        }
+        assert_eq!(std::env::var_os("USERPROFILE"), before);
+    }
+}
@@ -0,0 +1,404 @@
+//! Process-wide scan/clean throttling: worker thread count and I/O priority
+//!
+//! Applied from the `[performance]` config section before scanning or cleaning.
+//! Scans don't touch the rayon global pool (it can only be configured once per
+//! process, and may already be running by then): every scan gets a dedicated
+//! pool from [`build_pool`] with `max_threads` workers at the configured I/O
+//! priority (see [`crate::drive::activate`]). `delete_batch_size`, the deletion
+//! speed limit and the I/O priority are updated on every call to [`apply`].
+//!
+//! `[performance.category_overrides]` replaces `max_threads`, `io_priority` and
+//! `delete_batch_size` for single categories: a thread scanning or cleaning a
+//! category enters it with [`enter_category`] and sees its overrides until the
+//! guard is dropped.
+//!
+//! Background mode (toggled from the TUI) paces deletes at the configured limit,
+//! or [`BACKGROUND_RATE`] when none is set, and deletes at background priority.
+
+use crate::cancel::CancellationToken;
+use crate::config::{CategoryPerformance, PerformanceSettings};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, AtomicUsize, Ordering};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+/// Thread priority for scan workers and deletes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IoPriority {
+    #[default]
+    Normal,
+    /// Below-normal CPU priority
+    Low,
+    /// Windows background mode: low I/O and memory priority, yields the disk to other apps
+    Background,
+}
+
+impl IoPriority {
+    /// Parse the config value (unknown values fall back to normal)
+    pub fn parse(s: &str) -> Self {
+        match s.trim().to_lowercase().as_str() {
+            "low" | "below_normal" => IoPriority::Low,
+            "background" | "idle" => IoPriority::Background,
+            _ => IoPriority::Normal,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => IoPriority::Low,
+            2 => IoPriority::Background,
+            _ => IoPriority::Normal,
+        }
+    }
+
+    fn as_u8(self) -> u8 {
+        match self {
+            IoPriority::Normal => 0,
+            IoPriority::Low => 1,
+            IoPriority::Background => 2,
+        }
+    }
+}
+
+/// Deletion speed limit (0 = unlimited)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeleteRate {
+    pub files_per_sec: u64,
+    pub mb_per_sec: u64,
+}
+
+impl DeleteRate {
+    pub fn is_limited(&self) -> bool {
+        self.files_per_sec > 0 || self.mb_per_sec > 0
+    }
+}
+
+/// Limit used in background mode when the config doesn't set one
+pub const BACKGROUND_RATE: DeleteRate = DeleteRate {
+    files_per_sec: 100,
+    mb_per_sec: 20,
+};
+
+static DELETE_BATCH_SIZE: AtomicUsize = AtomicUsize::new(0);
+static DELETE_FILES_PER_SEC: AtomicU64 = AtomicU64::new(0);
+static DELETE_MB_PER_SEC: AtomicU64 = AtomicU64::new(0);
+static BACKGROUND_MODE: AtomicBool = AtomicBool::new(false);
+static IO_PRIORITY: AtomicU8 = AtomicU8::new(0);
+static CATEGORY_OVERRIDES: RwLock<Option<HashMap<String, CategoryPerformance>>> = RwLock::new(None);
+
+thread_local! {
+    /// Overrides of the category this thread is working on (see [`enter_category`])
+    static CATEGORY: RefCell<Option<CategoryPerformance>> = const { RefCell::new(None) };
+}
+
+/// Apply performance settings for this process
+pub fn apply(settings: &PerformanceSettings) {
+    let priority = IoPriority::parse(&settings.io_priority);
+    DELETE_BATCH_SIZE.store(settings.delete_batch_size, Ordering::Relaxed);
+    DELETE_FILES_PER_SEC.store(settings.delete_files_per_sec, Ordering::Relaxed);
+    DELETE_MB_PER_SEC.store(settings.delete_mb_per_sec, Ordering::Relaxed);
+    IO_PRIORITY.store(priority.as_u8(), Ordering::Relaxed);
+    if let Ok(mut overrides) = CATEGORY_OVERRIDES.write() {
+        *overrides = Some(settings.category_overrides.clone());
+    }
+    crate::ads::set_enabled(settings.scan_ads);
+}
+
+/// Overrides configured for the category with id `key`
+pub fn category_overrides(key: &str) -> Option<CategoryPerformance> {
+    CATEGORY_OVERRIDES.read().ok()?.as_ref()?.get(key).cloned()
+}
+
+/// Puts back the previous category of the thread when dropped
+pub struct CategoryGuard {
+    previous: Option<CategoryPerformance>,
+}
+
+impl Drop for CategoryGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CATEGORY.with(|category| *category.borrow_mut() = previous);
+    }
+}
+
+/// Use the overrides of the category with id `key` on this thread until the guard is dropped
+pub fn enter_category(key: &str) -> CategoryGuard {
+    enter(category_overrides(key))
+}
+
+fn enter(overrides: Option<CategoryPerformance>) -> CategoryGuard {
+    CategoryGuard {
+        previous: CATEGORY.with(|category| category.replace(overrides)),
+    }
+}
+
+fn current_category<T>(f: impl FnOnce(&CategoryPerformance) -> Option<T>) -> Option<T> {
+    CATEGORY.with(|category| category.borrow().as_ref().and_then(f))
+}
+
+/// Maximum paths per Recycle Bin batch (0 = no limit)
+pub fn delete_batch_size() -> usize {
+    current_category(|c| c.delete_batch_size)
+        .unwrap_or_else(|| DELETE_BATCH_SIZE.load(Ordering::Relaxed))
+}
+
+/// Configured I/O priority (always background in background mode)
+pub fn io_priority() -> IoPriority {
+    if background_mode() {
+        return IoPriority::Background;
+    }
+    current_category(|c| c.io_priority.as_deref().map(IoPriority::parse))
+        .unwrap_or_else(|| IoPriority::from_u8(IO_PRIORITY.load(Ordering::Relaxed)))
+}
+
+/// Turn background mode on or off; takes effect from the next delete
+pub fn set_background_mode(enabled: bool) {
+    BACKGROUND_MODE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn background_mode() -> bool {
+    BACKGROUND_MODE.load(Ordering::Relaxed)
+}
+
+/// Deletion speed limit currently in effect
+pub fn delete_rate() -> DeleteRate {
+    let configured = DeleteRate {
+        files_per_sec: DELETE_FILES_PER_SEC.load(Ordering::Relaxed),
+        mb_per_sec: DELETE_MB_PER_SEC.load(Ordering::Relaxed),
+    };
+    if background_mode() && !configured.is_limited() {
+        BACKGROUND_RATE
+    } else {
+        configured
+    }
+}
+
+/// Spreads deletes out so they stay under [`delete_rate`]
+///
+/// Tracks the average rate since the pacer was created; the limit is re-read on
+/// every call, so background mode can be switched while a batch is running.
+pub struct DeletePacer {
+    started: Instant,
+    files: u64,
+    bytes: u64,
+}
+
+impl Default for DeletePacer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeletePacer {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            files: 0,
+            bytes: 0,
+        }
+    }
+
+    /// Record `files` deleted paths totalling `bytes`, then sleep until the
+    /// average rate is back under the limit (or `cancel` is cancelled)
+    pub fn pace(&mut self, files: u64, bytes: u64, cancel: &CancellationToken) {
+        self.files += files;
+        self.bytes += bytes;
+        let wait = pace_delay(
+            delete_rate(),
+            self.files,
+            self.bytes,
+            self.started.elapsed(),
+        );
+        let until = Instant::now() + wait;
+        while !cancel.is_cancelled() {
+            let now = Instant::now();
+            if now >= until {
+                break;
+            }
+            std::thread::sleep((until - now).min(Duration::from_millis(100)));
+        }
+    }
+}
+
+/// Time still to wait after deleting `files`/`bytes` in `elapsed` at `rate`
+fn pace_delay(rate: DeleteRate, files: u64, bytes: u64, elapsed: Duration) -> Duration {
+    let mut needed = Duration::ZERO;
+    if rate.files_per_sec > 0 {
+        needed = needed.max(Duration::from_secs_f64(
+            files as f64 / rate.files_per_sec as f64,
+        ));
+    }
+    if rate.mb_per_sec > 0 {
+        needed = needed.max(Duration::from_secs_f64(
+            bytes as f64 / (rate.mb_per_sec as f64 * 1024.0 * 1024.0),
+        ));
+    }
+    needed.saturating_sub(elapsed)
+}
+
+/// A dedicated pool of `threads` workers (0 = one per logical CPU) running at
+/// the configured I/O priority
+pub fn build_pool(threads: usize) -> Result<rayon::ThreadPool, rayon::ThreadPoolBuildError> {
+    let priority = io_priority();
+    let mut builder = rayon::ThreadPoolBuilder::new().num_threads(threads);
+    if priority != IoPriority::Normal {
+        builder = builder.start_handler(move |_| set_current_thread_priority(priority));
+    }
+    builder.build()
+}
+
+/// Lowers the current thread's priority until dropped
+pub struct PriorityGuard {
+    priority: IoPriority,
+}
+
+impl PriorityGuard {
+    /// Apply the configured I/O priority to the current thread
+    pub fn current() -> Self {
+        let priority = io_priority();
+        set_current_thread_priority(priority);
+        Self { priority }
+    }
+}
+
+impl Drop for PriorityGuard {
+    fn drop(&mut self) {
+        if self.priority != IoPriority::Normal {
+            restore_current_thread_priority(self.priority);
+        }
+    }
+}
+
+#[cfg(windows)]
+mod ffi {
+    pub const THREAD_MODE_BACKGROUND_BEGIN: i32 = 0x0001_0000;
+    pub const THREAD_MODE_BACKGROUND_END: i32 = 0x0002_0000;
+    pub const THREAD_PRIORITY_BELOW_NORMAL: i32 = -1;
+    pub const THREAD_PRIORITY_NORMAL: i32 = 0;
+
+    extern "system" {
+        pub fn GetCurrentThread() -> isize;
+        pub fn SetThreadPriority(hThread: isize, nPriority: i32) -> i32;
+    }
+}
+
+fn set_current_thread_priority(priority: IoPriority) {
+    #[cfg(windows)]
+    {
+        let value = match priority {
+            IoPriority::Normal => return,
+            IoPriority::Low => ffi::THREAD_PRIORITY_BELOW_NORMAL,
+            IoPriority::Background => ffi::THREAD_MODE_BACKGROUND_BEGIN,
+        };
+        unsafe {
+            ffi::SetThreadPriority(ffi::GetCurrentThread(), value);
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = priority;
+    }
+}
+
+fn restore_current_thread_priority(priority: IoPriority) {
+    #[cfg(windows)]
+    {
+        let value = match priority {
+            IoPriority::Normal => return,
+            IoPriority::Low => ffi::THREAD_PRIORITY_NORMAL,
+            IoPriority::Background => ffi::THREAD_MODE_BACKGROUND_END,
+        };
+        unsafe {
+            ffi::SetThreadPriority(ffi::GetCurrentThread(), value);
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = priority;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_io_priority() {
+        assert_eq!(IoPriority::parse("normal"), IoPriority::Normal);
+        assert_eq!(IoPriority::parse("LOW"), IoPriority::Low);
+        assert_eq!(IoPriority::parse("background"), IoPriority::Background);
+        assert_eq!(IoPriority::parse("turbo"), IoPriority::Normal);
+    }
+
+    #[test]
+    fn test_pace_delay() {
+        let rate = DeleteRate {
+            files_per_sec: 10,
+            mb_per_sec: 1,
+        };
+        // 20 files at 10/s need 2s
+        assert_eq!(
+            pace_delay(rate, 20, 0, Duration::from_millis(500)),
+            Duration::from_millis(1500)
+        );
+        // 3 MB at 1 MB/s need 3s, more than the file limit
+        assert_eq!(
+            pace_delay(rate, 1, 3 * 1024 * 1024, Duration::from_secs(1)),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            pace_delay(rate, 5, 0, Duration::from_secs(1)),
+            Duration::ZERO
+        );
+        assert_eq!(
+            pace_delay(DeleteRate::default(), 1000, 1 << 30, Duration::ZERO),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_priority_round_trip() {
+        for priority in [IoPriority::Normal, IoPriority::Low, IoPriority::Background] {
+            assert_eq!(IoPriority::from_u8(priority.as_u8()), priority);
+        }
+    }
+
+    #[test]
+    fn test_category_overrides_are_per_thread() {
+        let global_batch = delete_batch_size();
+        {
+            let _duplicates = enter(Some(CategoryPerformance {
+                delete_batch_size: Some(7),
+                io_priority: Some("low".to_string()),
+                ..Default::default()
+            }));
+            assert_eq!(delete_batch_size(), 7);
+            if !background_mode() {
+                assert_eq!(io_priority(), IoPriority::Low);
+            }
+            std::thread::spawn(move || assert_eq!(delete_batch_size(), global_batch))
+                .join()
+                .unwrap();
+
+            // Nested categories put the outer one back
+            {
+                let _other = enter(None);
+                assert_eq!(delete_batch_size(), global_batch);
+            }
+            assert_eq!(delete_batch_size(), 7);
+        }
+        assert_eq!(delete_batch_size(), global_batch);
+    }
+
+    #[test]
+    fn test_build_pool_is_dedicated() {
+        // Works whatever state the global pool is in, and doesn't change it
+        rayon::scope(|_| {});
+        let pool = build_pool(3).unwrap();
+        assert_eq!(pool.current_num_threads(), 3);
+        assert_eq!(pool.install(rayon::current_num_threads), 3);
+    }
+}
@@ -7,6 +7,14 @@
 //!   HRESULT `0x80010106` / `RPC_E_CHANGED_MODE`).
 //! - We treat panics from dependencies as errors so the CLI/TUI can continue
 //!   and report a useful message instead of crashing.
+//! - Network shares have no Recycle Bin; items on them are moved to the share's
+//!   quarantine folder instead (see [`crate::network`]), as are items on
+//!   volumes the user chose to quarantine because their Recycle Bin is too
+//!   small (see [`crate::recycle_bin`]).
+//! - Items on removable drives (USB sticks, SD cards) never go to the Recycle
+//!   Bin: it lives on the drive itself, so recycling frees nothing. They are
+//!   deleted outright, or quarantined when the machine policy forbids
+//!   permanent deletes.
 
 use anyhow::{anyhow, Result};
 use std::any::Any;
@@ -34,18 +42,64 @@ fn catch_trash_panic<R>(f: impl FnOnce() -> Result<R>) -> Result<R> {
     }
 }
 
+/// Whether `path` is deleted outright instead of recycled (removable drives)
+pub fn deletes_outright(path: &Path) -> bool {
+    crate::policy::current().allow_permanent_delete && crate::drive::is_removable_path(path)
+}
+
+/// Items that can't go to the Recycle Bin
+fn goes_to_quarantine(path: &Path) -> bool {
+    crate::network::is_network_path(path)
+        || crate::recycle_bin::routes_to_quarantine(path)
+        || (!crate::policy::current().allow_permanent_delete
+            && crate::drive::is_removable_path(path))
+}
+
+fn delete_outright(path: &Path) -> Result<()> {
+    crate::history::checksums::capture(path);
+    if crate::utils::safe_is_dir(path) {
+        crate::utils::safe_remove_dir_all(path)?;
+    } else {
+        crate::utils::safe_remove_file(path)?;
+    }
+    Ok(())
+}
+
 pub fn delete(path: &Path) -> Result<()> {
+    if deletes_outright(path) {
+        return delete_outright(path);
+    }
+    if goes_to_quarantine(path) {
+        return crate::network::quarantine(path).map(|_| ());
+    }
     catch_trash_panic(|| Ok(trash::delete(path)?))
 }
 
 pub fn delete_all(paths: &[PathBuf]) -> Result<()> {
-    catch_trash_panic(|| Ok(trash::delete_all(paths)?))
+    let (outright, paths): (Vec<PathBuf>, Vec<PathBuf>) =
+        paths.iter().cloned().partition(|p| deletes_outright(p));
+    for path in &outright {
+        delete_outright(path)?;
+    }
+    let (quarantined, local): (Vec<PathBuf>, Vec<PathBuf>) =
+        paths.into_iter().partition(|p| goes_to_quarantine(p));
+    for path in &quarantined {
+        crate::network::quarantine(path)?;
+    }
+    if local.is_empty() {
+        return Ok(());
+    }
+    catch_trash_panic(|| Ok(trash::delete_all(&local)?))
 }
 
 pub fn list() -> Result<Vec<trash::TrashItem>> {
     catch_trash_panic(|| Ok(trash::os_limited::list()?))
 }
 
+pub fn metadata(item: &trash::TrashItem) -> Result<trash::TrashItemMetadata> {
+    catch_trash_panic(|| Ok(trash::os_limited::metadata(item)?))
+}
+
 pub fn purge_all(items: &[trash::TrashItem]) -> Result<()> {
     catch_trash_panic(|| Ok(trash::os_limited::purge_all(items)?))
 }
@@ -1,7 +1,8 @@
 //! Event handling for TUI
 
-use crate::tui::state::AppState;
+use crate::tui::state::{AppState, DashboardAction, SidebarView, UndoStep};
 use crate::tui::widgets::logo::LOGO_WITH_TAGLINE_HEIGHT;
+use crate::tui::widgets::{help, sidebar};
 use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use std::process::Command;
 
@@ -18,6 +19,60 @@ pub fn handle_event(
     key: KeyCode,
     modifiers: KeyModifiers,
 ) -> EventResult {
+    // The help overlay takes every key while it's open
+    if let Some(mut help) = app_state.help.take() {
+        let matches = help::matching_shortcuts(app_state, &help.query).len();
+        if help.handle_key(key, matches) {
+            app_state.help = Some(help);
+        }
+        return EventResult::Continue;
+    }
+    if key == KeyCode::F(1) || (key == KeyCode::Char('?') && !typing_text(app_state)) {
+        app_state.help = Some(help::HelpOverlay::default());
+        return EventResult::Continue;
+    }
+
+    // Alt+1-7 switch views from any screen
+    if modifiers.contains(KeyModifiers::ALT) {
+        if let Some(view) = match key {
+            KeyCode::Char(c) => SidebarView::for_digit(c),
+            _ => None,
+        } {
+            switch_view(app_state, view);
+            return EventResult::Continue;
+        }
+    }
+
+    // Ctrl+Z / Ctrl+Y undo and redo selection, category and exclusion changes
+    if modifiers.contains(KeyModifiers::CONTROL)
+        && matches!(
+            app_state.screen,
+            crate::tui::state::Screen::Dashboard
+                | crate::tui::state::Screen::Results
+                | crate::tui::state::Screen::DiskInsights { .. }
+        )
+        && !typing_text(app_state)
+    {
+        let message = match key {
+            KeyCode::Char('z') | KeyCode::Char('Z') => Some(
+                app_state
+                    .undo()
+                    .unwrap_or_else(|| "Nothing to undo".to_string()),
+            ),
+            KeyCode::Char('y') | KeyCode::Char('Y') => Some(
+                app_state
+                    .redo()
+                    .unwrap_or_else(|| "Nothing to redo".to_string()),
+            ),
+            _ => None,
+        };
+        if let Some(message) = message {
+            app_state.protect_undo = false;
+            app_state.toasts.info(message);
+            return EventResult::Continue;
+        }
+    }
+
     match app_state.screen {
         crate::tui::state::Screen::Dashboard => handle_dashboard_event(app_state, key, modifiers),
         crate::tui::state::Screen::Config => handle_config_event(app_state, key, modifiers),
@@ -50,11 +105,80 @@ pub fn handle_event(
             handle_optimize_event(app_state, key, modifiers)
         }
         crate::tui::state::Screen::Status { .. } => handle_status_event(app_state, key, modifiers),
+        crate::tui::state::Screen::WhatChanged { .. } => {
+            handle_what_changed_event(app_state, key, modifiers)
+        }
+        crate::tui::state::Screen::Search { .. } => handle_search_event(app_state, key, modifiers),
+        crate::tui::state::Screen::History { .. } => {
+            handle_history_event(app_state, key, modifiers)
+        }
+        crate::tui::state::Screen::ScanPathPicker { .. } => {
+            handle_scan_path_picker_event(app_state, key, modifiers)
+        }
+        crate::tui::state::Screen::AppCaches { .. } => {
+            handle_app_caches_event(app_state, key, modifiers)
+        }
+        crate::tui::state::Screen::Finder { .. } => handle_finder_event(app_state, key, modifiers),
+        crate::tui::state::Screen::Breakdown { .. } => {
+            handle_breakdown_event(app_state, key, modifiers)
+        }
+        crate::tui::state::Screen::DiskWrites { .. } => {
+            handle_disk_writes_event(app_state, key, modifiers)
+        }
+        crate::tui::state::Screen::NetCheck { .. } => {
+            handle_net_check_event(app_state, key, modifiers)
+        }
+        crate::tui::state::Screen::SavedViews { .. } => {
+            handle_saved_views_event(app_state, key, modifiers)
+        }
+        crate::tui::state::Screen::SelectRule { .. } => {
+            handle_select_rule_event(app_state, key, modifiers)
+        }
     }
 }
 
+/// Whether typed characters go to a text field on the current screen
+fn typing_text(app_state: &AppState) -> bool {
+    use crate::tui::state::{ConfigEditorMode, Screen};
+    app_state.search_mode
+        || matches!(
+            app_state.screen,
+            Screen::Search { .. }
+                | Screen::Finder { .. }
+                | Screen::History {
+                    editing_filter: true,
+                    ..
+                }
+                | Screen::SavedViews {
+                    naming: Some(_),
+                    ..
+                }
+        )
+        || matches!(app_state.screen, Screen::SelectRule { cursor, .. } if cursor < SELECT_RULE_FIELDS)
+        || (matches!(app_state.screen, Screen::Status { .. })
+            && app_state.process_tree.editing_filter)
+        || (matches!(app_state.screen, Screen::Config)
+            && matches!(
+                app_state.config_editor.mode,
+                ConfigEditorMode::Editing { .. }
+            ))
+}
+
 /// Handle a mouse event
-pub fn handle_mouse_event(app_state: &mut AppState, mouse: MouseEvent) -> EventResult {
+pub fn handle_mouse_event(app_state: &mut AppState, mut mouse: MouseEvent) -> EventResult {
+    // Clicks on the sidebar switch views; the screens see columns relative to the main pane
+    if app_state.sidebar_width > 0 {
+        if mouse.column < app_state.sidebar_width {
+            if mouse.kind == MouseEventKind::Down(MouseButton::Left) {
+                if let Some(view) = sidebar::view_at(mouse.row) {
+                    switch_view(app_state, view);
+                }
+            }
+            return EventResult::Continue;
+        }
+        mouse.column -= app_state.sidebar_width;
+    }
+
     match mouse.kind {
         // Standard scrolling: Wheel Down -> View Down (Index Increase)
         MouseEventKind::ScrollDown => match app_state.screen {
@@ -79,6 +203,24 @@ pub fn handle_mouse_event(app_state: &mut AppState, mouse: MouseEvent) -> EventR
             crate::tui::state::Screen::Optimize { .. } => {
                 handle_optimize_event(app_state, KeyCode::Down, KeyModifiers::empty())
             }
+            crate::tui::state::Screen::WhatChanged { .. } => {
+                handle_what_changed_event(app_state, KeyCode::Down, KeyModifiers::empty())
+            }
+            crate::tui::state::Screen::Search { .. } => {
+                handle_search_event(app_state, KeyCode::Down, KeyModifiers::empty())
+            }
+            crate::tui::state::Screen::History { .. } => {
+                handle_history_event(app_state, KeyCode::Down, KeyModifiers::empty())
+            }
+            crate::tui::state::Screen::ScanPathPicker { .. } => {
+                handle_scan_path_picker_event(app_state, KeyCode::Down, KeyModifiers::empty())
+            }
+            crate::tui::state::Screen::AppCaches { .. } => {
+                handle_app_caches_event(app_state, KeyCode::Down, KeyModifiers::empty())
+            }
+            crate::tui::state::Screen::Finder { .. } => {
+                handle_finder_event(app_state, KeyCode::Down, KeyModifiers::empty())
+            }
             crate::tui::state::Screen::Status { .. } => {
                 EventResult::Continue // Status screen doesn't need scrolling
             }
@@ -106,6 +248,24 @@ pub fn handle_mouse_event(app_state: &mut AppState, mouse: MouseEvent) -> EventR
             crate::tui::state::Screen::Optimize { .. } => {
                 handle_optimize_event(app_state, KeyCode::Up, KeyModifiers::empty())
             }
+            crate::tui::state::Screen::WhatChanged { .. } => {
+                handle_what_changed_event(app_state, KeyCode::Up, KeyModifiers::empty())
+            }
+            crate::tui::state::Screen::Search { .. } => {
+                handle_search_event(app_state, KeyCode::Up, KeyModifiers::empty())
+            }
+            crate::tui::state::Screen::History { .. } => {
+                handle_history_event(app_state, KeyCode::Up, KeyModifiers::empty())
+            }
+            crate::tui::state::Screen::ScanPathPicker { .. } => {
+                handle_scan_path_picker_event(app_state, KeyCode::Up, KeyModifiers::empty())
+            }
+            crate::tui::state::Screen::AppCaches { .. } => {
+                handle_app_caches_event(app_state, KeyCode::Up, KeyModifiers::empty())
+            }
+            crate::tui::state::Screen::Finder { .. } => {
+                handle_finder_event(app_state, KeyCode::Up, KeyModifiers::empty())
+            }
             crate::tui::state::Screen::Status { .. } => {
                 EventResult::Continue // Status screen doesn't need scrolling
             }
@@ -238,7 +398,8 @@ fn handle_confirm_click(app_state: &mut AppState, row: u16, _col: u16) -> EventR
             if _col < 8 {
                 handle_confirm_event(app_state, KeyCode::Char(' '), KeyModifiers::empty());
             } else if let Some(
-                crate::tui::state::ConfirmRow::CategoryHeader { .. }
+                crate::tui::state::ConfirmRow::TierHeader { .. }
+                | crate::tui::state::ConfirmRow::CategoryHeader { .. }
                 | crate::tui::state::ConfirmRow::FolderHeader { .. },
             ) = rows.get(data_index)
             {
@@ -260,6 +421,25 @@ fn handle_config_click(_app_state: &mut AppState, _row: u16, _col: u16) -> Event
 }
 
 fn handle_dashboard_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+) -> EventResult {
+    // Record category toggles for Ctrl+Z
+    let enabled: Vec<bool> = app_state.categories.iter().map(|cat| cat.enabled).collect();
+    let result = handle_dashboard_key(app_state, key, modifiers);
+    if app_state
+        .categories
+        .iter()
+        .map(|cat| cat.enabled)
+        .ne(enabled.iter().copied())
+    {
+        app_state.record_undo(UndoStep::Categories(enabled));
+    }
+    result
+}
+
+fn handle_dashboard_key(
     app_state: &mut AppState,
     key: KeyCode,
     _modifiers: KeyModifiers,
@@ -304,8 +484,8 @@ fn handle_dashboard_event(
         }
         KeyCode::Down => {
             if app_state.focus_actions {
-                // Navigate in actions list (7 actions: Scan, Clean, Analyze, Restore, Config, Optimize, Status)
-                if app_state.action_cursor < 6 {
+                // Navigate in actions list
+                if app_state.action_cursor + 1 < DashboardAction::ALL.len() {
                     app_state.action_cursor += 1;
                 }
             } else {
@@ -327,6 +507,79 @@ fn handle_dashboard_event(
             }
             EventResult::Continue
         }
+        KeyCode::Char('/') => {
+            // File search over the persistent index
+            open_search(app_state);
+            EventResult::Continue
+        }
+        KeyCode::Char('h') | KeyCode::Char('H') => {
+            // Browse past cleanup sessions
+            app_state.screen = open_history();
+            EventResult::Continue
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            // Continue the interrupted scan
+            if app_state.prepare_resume() {
+                app_state.pending_action = crate::tui::state::PendingAction::None;
+                app_state.screen = category_scan_screen(app_state);
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('x') | KeyCode::Char('X') => {
+            // Dismiss whichever offer the Dashboard is showing
+            if app_state.resumable_scan.is_some() {
+                app_state.discard_resumable_scan();
+                app_state.dashboard_message = Some("Previous scan discarded".to_string());
+            } else if app_state.saved_session.take().is_some() {
+                crate::tui::session::SavedSession::discard();
+                app_state.dashboard_message = Some("Saved session discarded".to_string());
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            // Restore the review saved on the last exit, unless the files changed since
+            if let Some(saved) = app_state.saved_session.take() {
+                let changed = saved.changed_paths();
+                if changed > 0 {
+                    crate::tui::session::SavedSession::discard();
+                    app_state.dashboard_message = Some(format!(
+                        "Saved session discarded: {} item(s) changed since it was saved",
+                        changed
+                    ));
+                } else {
+                    saved.restore(app_state);
+                }
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('p') | KeyCode::Char('P') => {
+            // Pick a different folder to scan
+            app_state.screen = crate::tui::state::Screen::ScanPathPicker {
+                picker: crate::tui::widgets::dir_picker::DirPicker::new(&app_state.scan_path),
+            };
+            EventResult::Continue
+        }
+        KeyCode::Char(c @ '1'..='9') => {
+            // Analyze a pinned folder
+            match pinned_dir(&app_state.config, c) {
+                Some(dir) => start_analyze(app_state, dir),
+                None => {
+                    app_state.dashboard_message = Some(format!(
+                        "Nothing pinned on {} - pin folders with F in Disk Insights",
+                        c
+                    ));
+                }
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('w') | KeyCode::Char('W') => {
+            // Compare the last two scans
+            app_state.screen = crate::tui::state::Screen::WhatChanged {
+                diff: load_latest_diff(),
+                cursor: 0,
+            };
+            EventResult::Continue
+        }
         KeyCode::Char('a') | KeyCode::Char('A') => {
             // Toggle all categories
             let all_enabled = app_state.categories.iter().all(|c| c.enabled);
@@ -338,153 +591,199 @@ fn handle_dashboard_event(
             EventResult::Continue
         }
         KeyCode::Enter => {
-            // Based on action cursor, perform different actions
-            if let 0..=2 = app_state.action_cursor {
-                // Scan/Clean/Analyze require at least one category to be enabled
-                if !app_state.categories.iter().any(|c| c.enabled) {
-                    app_state.dashboard_message =
-                        Some("⚠ Please select at least one category first!".to_string());
-                    return EventResult::Continue;
-                }
+            if let Some(&action) = DashboardAction::ALL.get(app_state.action_cursor) {
+                run_dashboard_action(app_state, action);
             }
+            EventResult::Continue
+        }
+        _ => EventResult::Continue,
+    }
+}
 
-            match app_state.action_cursor {
-                0 => {
-                    // Scan action
-                    app_state.pending_action = crate::tui::state::PendingAction::None;
-                    // Initialize progress bars for all selected categories
-                    let mut category_progress = Vec::new();
-                    for cat in &app_state.categories {
-                        if cat.enabled {
-                            category_progress.push(crate::tui::state::CategoryProgress {
-                                name: cat.name.clone(),
-                                completed: false,
-                                progress_pct: 0.0,
-                                size: None,
-                            });
-                        }
-                    }
-
-                    // Start scanning
-                    app_state.screen = crate::tui::state::Screen::Scanning {
-                        progress: crate::tui::state::ScanProgress {
-                            current_category: String::new(),
-                            current_path: None,
-                            notice: None,
-                            category_progress,
-                            total_scanned: 0,
-                            total_found: 0,
-                            total_size: 0,
-                            start_time: std::time::Instant::now(),
-                        },
-                    };
-                }
-                1 => {
-                    // Clean action - need scan results first
-                    app_state.pending_action = crate::tui::state::PendingAction::Clean;
-                    let mut category_progress = Vec::new();
-                    for cat in &app_state.categories {
-                        if cat.enabled {
-                            category_progress.push(crate::tui::state::CategoryProgress {
-                                name: cat.name.clone(),
-                                completed: false,
-                                progress_pct: 0.0,
-                                size: None,
-                            });
-                        }
-                    }
-                    app_state.screen = crate::tui::state::Screen::Scanning {
-                        progress: crate::tui::state::ScanProgress {
-                            current_category: String::new(),
-                            current_path: None,
-                            notice: None,
-                            category_progress,
-                            total_scanned: 0,
-                            total_found: 0,
-                            total_size: 0,
-                            start_time: std::time::Instant::now(),
-                        },
-                    };
-                }
-                2 => {
-                    // Analyze action - launch Disk Insights
-                    // Determine scan path (default to user profile)
-                    let scan_path = if let Ok(userprofile) = std::env::var("USERPROFILE") {
-                        std::path::PathBuf::from(&userprofile)
-                    } else {
-                        std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."))
-                    };
+/// Start a dashboard action, as Enter on the Actions list does
+///
+/// Shared with the zero-argument menu, which launches the TUI straight into
+/// the chosen action.
+pub fn run_dashboard_action(app_state: &mut AppState, action: DashboardAction) {
+    app_state.action_cursor = action.index();
+    // Scan/Clean/Analyze require at least one category to be enabled
+    if action.needs_categories() && !app_state.categories.iter().any(|c| c.enabled) {
+        app_state.dashboard_message =
+            Some("⚠ Please select at least one category first!".to_string());
+        return;
+    }
 
-                    // Set pending action to trigger disk insights scan
-                    app_state.pending_action = crate::tui::state::PendingAction::Analyze;
-                    // Show scanning screen - the scan will be performed in the event loop
-                    app_state.screen = crate::tui::state::Screen::Scanning {
-                        progress: crate::tui::state::ScanProgress {
-                            current_category: "Disk Insights".to_string(),
-                            current_path: Some(scan_path),
-                            notice: None,
-                            category_progress: vec![crate::tui::state::CategoryProgress {
-                                name: "Analyzing disk usage".to_string(),
-                                completed: false,
-                                progress_pct: 0.0,
-                                size: None,
-                            }],
-                            total_scanned: 0,
-                            total_found: 0,
-                            total_size: 0,
-                            start_time: std::time::Instant::now(),
-                        },
-                    };
-                }
-                3 => {
-                    // Restore action - show restore selection screen
-                    app_state.screen = crate::tui::state::Screen::RestoreSelection { cursor: 0 };
-                }
-                4 => {
-                    // Optimize action - show optimize screen
-                    app_state.screen = crate::tui::state::Screen::Optimize {
-                        cursor: 0,
-                        selected: std::collections::HashSet::new(),
-                        results: Vec::new(),
-                        running: false,
-                        message: None,
+    match action {
+        DashboardAction::Scan => {
+            // Scan action
+            app_state.pending_action = crate::tui::state::PendingAction::None;
+            app_state.screen = category_scan_screen(app_state);
+        }
+        DashboardAction::Clean => {
+            // Clean action - need scan results first
+            app_state.pending_action = crate::tui::state::PendingAction::Clean;
+            app_state.screen = category_scan_screen(app_state);
+        }
+        DashboardAction::Analyze => {
+            // Analyze action - launch Disk Insights
+            start_analyze(app_state, analyze_root());
+        }
+        DashboardAction::Restore => {
+            // Restore action - show restore selection screen
+            app_state.screen = crate::tui::state::Screen::RestoreSelection { cursor: 0 };
+        }
+        DashboardAction::Optimize => {
+            // Optimize action - show optimize screen
+            app_state.screen = crate::tui::state::Screen::Optimize {
+                cursor: 0,
+                selected: std::collections::HashSet::new(),
+                results: Vec::new(),
+                running: false,
+                message: None,
+                admin: crate::optimize::is_admin(),
+            };
+        }
+        DashboardAction::Status => {
+            // Status action - show status screen
+            use crate::status::gather_status_fast;
+            use sysinfo::System;
+
+            // Don't call refresh_all() - gather_status will refresh what it needs
+            // This avoids blocking on expensive full system refresh
+            let mut system = System::new();
+            match gather_status_fast(&mut system) {
+                Ok(status) => {
+                    app_state.screen = crate::tui::state::Screen::Status {
+                        status: Box::new(status),
+                        last_refresh: std::time::Instant::now(),
+                        status_receiver: None,
                     };
                 }
-                5 => {
-                    // Status action - show status screen
-                    use crate::status::gather_status_fast;
-                    use sysinfo::System;
-
-                    // Don't call refresh_all() - gather_status will refresh what it needs
-                    // This avoids blocking on expensive full system refresh
-                    let mut system = System::new();
-                    match gather_status_fast(&mut system) {
-                        Ok(status) => {
-                            app_state.screen = crate::tui::state::Screen::Status {
-                                status: Box::new(status),
-                                last_refresh: std::time::Instant::now(),
-                                status_receiver: None,
-                            };
-                        }
-                        Err(e) => {
-                            eprintln!("Failed to gather system status: {}", e);
-                            // Stay on dashboard
-                        }
-                    }
-                }
-                6 => {
-                    // Config action - show config screen
-                    // Ensure config exists on disk so we can open it
-                    app_state.config = crate::config::Config::load_or_create();
-                    app_state.apply_config_to_state();
-                    app_state.reset_config_editor();
-                    app_state.screen = crate::tui::state::Screen::Config;
+                Err(e) => {
+                    // Stay on dashboard
+                    app_state
+                        .toasts
+                        .error(format!("Failed to gather system status: {}", e));
                 }
-                _ => {}
             }
-            EventResult::Continue
         }
-        _ => EventResult::Continue,
+        DashboardAction::Config => {
+            // Config action - show config screen
+            // Ensure config exists on disk so we can open it
+            app_state.config = crate::config::Config::load_or_create();
+            app_state.apply_config_to_state();
+            app_state.reset_config_editor();
+            app_state.screen = crate::tui::state::Screen::Config;
+        }
+    }
+}
+
+/// Show `view` in the main pane (Alt+1-7, sidebar clicks)
+///
+/// The current screen is parked under its view and the target view's parked
+/// screen comes back as it was left; a view without one opens fresh. Nothing
+/// happens while a scan, cleanup, restore or optimization is running.
+pub fn switch_view(app_state: &mut AppState, view: SidebarView) {
+    let current = SidebarView::of(&app_state.screen);
+    if app_state.screen.is_busy() || current == view || !sidebar::view_available(app_state, view) {
+        return;
+    }
+
+    let leaving = std::mem::replace(&mut app_state.screen, crate::tui::state::Screen::Dashboard);
+    // The Success report is one-off, and the Dashboard has nothing to keep
+    if !matches!(
+        leaving,
+        crate::tui::state::Screen::Success { .. } | crate::tui::state::Screen::Dashboard
+    ) {
+        app_state.parked_screens.insert(current, leaving);
+    }
+    if let Some(screen) = app_state.parked_screens.remove(&view) {
+        app_state.screen = screen;
+        return;
+    }
+
+    match view {
+        SidebarView::Dashboard => {}
+        SidebarView::Results => app_state.screen = crate::tui::state::Screen::Results,
+        SidebarView::Insights => start_analyze(app_state, analyze_root()),
+        SidebarView::Optimize => run_dashboard_action(app_state, DashboardAction::Optimize),
+        SidebarView::Status => run_dashboard_action(app_state, DashboardAction::Status),
+        SidebarView::History => app_state.screen = open_history(),
+        SidebarView::Config => run_dashboard_action(app_state, DashboardAction::Config),
+    }
+}
+
+/// Folder Disk Insights starts in (the user profile)
+fn analyze_root() -> std::path::PathBuf {
+    if let Ok(userprofile) = std::env::var("USERPROFILE") {
+        std::path::PathBuf::from(&userprofile)
+    } else {
+        std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."))
+    }
+}
+
+/// Start a Disk Insights scan of `path`; the scan itself runs in the event loop
+fn start_analyze(app_state: &mut AppState, path: std::path::PathBuf) {
+    app_state.search_query.clear();
+    app_state.search_mode = false;
+    // Set pending action to trigger disk insights scan
+    app_state.pending_action = crate::tui::state::PendingAction::Analyze;
+    app_state.screen = crate::tui::state::Screen::Scanning {
+        progress: crate::tui::state::ScanProgress {
+            current_category: "Disk Insights".to_string(),
+            current_path: Some(path),
+            notice: None,
+            latency: None,
+            strategy: None,
+            category_progress: vec![crate::tui::state::CategoryProgress {
+                name: "Analyzing disk usage".to_string(),
+                completed: false,
+                progress_pct: 0.0,
+                size: None,
+            }],
+            slow: None,
+            total_scanned: 0,
+            total_found: 0,
+            total_size: 0,
+            start_time: std::time::Instant::now(),
+        },
+    };
+}
+
+/// Pinned folder on digit key `c` (1-9), if there is one
+fn pinned_dir(config: &crate::config::Config, c: char) -> Option<std::path::PathBuf> {
+    let index = c.to_digit(10)?.checked_sub(1)? as usize;
+    config.ui.pinned_paths().into_iter().nth(index)
+}
+
+/// Scanning screen with a progress bar for each selected category
+fn category_scan_screen(app_state: &AppState) -> crate::tui::state::Screen {
+    let category_progress = app_state
+        .categories
+        .iter()
+        .filter(|cat| cat.enabled)
+        .map(|cat| crate::tui::state::CategoryProgress {
+            name: cat.name.clone(),
+            completed: false,
+            progress_pct: 0.0,
+            size: None,
+        })
+        .collect();
+
+    crate::tui::state::Screen::Scanning {
+        progress: crate::tui::state::ScanProgress {
+            current_category: String::new(),
+            current_path: None,
+            notice: None,
+            latency: None,
+            strategy: None,
+            category_progress,
+            slow: None,
+            total_scanned: 0,
+            total_found: 0,
+            total_size: 0,
+            start_time: std::time::Instant::now(),
+        },
     }
 }
 
@@ -506,8 +805,9 @@ fn handle_config_event(
     // 7 scan_depth_user (u8)
     // 8 scan_depth_entire_disk (u8)
     // 9 full_disk_baseline (bool)
-    // 10 clear_cache (action)
-    let fields_len = 11usize;
+    // 10 auto_select_aggressiveness (u8, 0-100, +/- steps by 10)
+    // 11 clear_cache (action)
+    let fields_len = 12usize;
 
     // Editing mode has its own key handling.
     if let ConfigEditorMode::Editing { ref mut buffer } = app_state.config_editor.mode {
@@ -587,6 +887,17 @@ fn handle_config_event(
                             )
                         }
                     },
+                    10 => match raw.parse::<u8>() {
+                        Ok(v) if v <= 100 => {
+                            app_state.config.ui.auto_select_aggressiveness = v;
+                            changed = true;
+                        }
+                        _ => {
+                            err = Some(
+                                "Invalid auto-select aggressiveness. Must be 0-100.".to_string(),
+                            )
+                        }
+                    },
                     _ => {}
                 }
 
@@ -611,7 +922,7 @@ fn handle_config_event(
             KeyCode::Char(c) => {
                 let selected = app_state.config_editor.selected;
                 // Numeric fields accept digits only.
-                let is_numeric = matches!(selected, 0 | 1 | 2 | 5 | 7 | 8);
+                let is_numeric = matches!(selected, 0 | 1 | 2 | 5 | 7 | 8 | 10);
                 if is_numeric {
                     if c.is_ascii_digit() {
                         buffer.push(c);
@@ -777,6 +1088,13 @@ fn handle_config_event(
                         Some("Edit value (0-255), then Enter to save (Esc cancels).".to_string());
                 }
                 10 => {
+                    app_state.config_editor.mode = ConfigEditorMode::Editing {
+                        buffer: app_state.config.ui.auto_select_aggressiveness.to_string(),
+                    };
+                    app_state.config_editor.message =
+                        Some("Edit value (0-100), then Enter to save (Esc cancels).".to_string());
+                }
+                11 => {
                     // Clear scan cache
                     match crate::scan_cache::ScanCache::open() {
                         Ok(mut cache) => match cache.clear_all() {
@@ -799,6 +1117,22 @@ fn handle_config_event(
             }
             EventResult::Continue
         }
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Char('-')
+            if app_state.config_editor.selected == 10 =>
+        {
+            // Slide auto-select aggressiveness in steps of 10
+            let current = app_state.config.ui.auto_select_aggressiveness;
+            app_state.config.ui.auto_select_aggressiveness = if key == KeyCode::Char('-') {
+                current.saturating_sub(10)
+            } else {
+                current.saturating_add(10).min(100)
+            };
+            match app_state.config.save() {
+                Ok(()) => app_state.config_editor.message = Some("Saved.".to_string()),
+                Err(e) => app_state.config_editor.message = Some(format!("Save failed: {e}")),
+            }
+            EventResult::Continue
+        }
         KeyCode::Char('s') | KeyCode::Char('S') => {
             match app_state.config.save() {
                 Ok(()) => app_state.config_editor.message = Some("Saved.".to_string()),
@@ -845,6 +1179,73 @@ fn open_config_file() {
     }
 }
 
+/// Open the folder containing a file or directory, with the item selected
+fn open_containing_folder(path: &std::path::Path) {
+    let path_str = path.display().to_string();
+    // Best-effort: spawn and ignore any errors.
+    if cfg!(target_os = "windows") {
+        let _ = Command::new("explorer")
+            .arg("/select,")
+            .arg(&path_str)
+            .spawn();
+    } else if cfg!(target_os = "macos") {
+        let _ = Command::new("open").args(["-R", &path_str]).spawn();
+    } else if let Some(parent) = path.parent() {
+        let _ = Command::new("xdg-open").arg(parent).spawn();
+    }
+}
+
+/// Deepest folder containing every given path
+fn common_ancestor<'a>(
+    mut paths: impl Iterator<Item = &'a std::path::Path>,
+) -> Option<std::path::PathBuf> {
+    let mut common = paths.next()?.to_path_buf();
+    for path in paths {
+        while !path.starts_with(&common) {
+            if !common.pop() {
+                return None;
+            }
+        }
+    }
+    Some(common)
+}
+
+/// Add a folder to the exclusions and leave an undo toast in the results message
+fn protect_folder(app_state: &mut AppState, folder: &std::path::Path) {
+    let saved = app_state.protect_folder(folder);
+    let removed = match app_state.undo_steps.last() {
+        Some(UndoStep::Protected(protected)) => protected.removed.len(),
+        _ => 0,
+    };
+    let mut message = format!(
+        "Protected {} - {} item{} removed, press U to undo",
+        folder.display(),
+        removed,
+        if removed == 1 { "" } else { "s" }
+    );
+    if let Err(e) = saved {
+        message.push_str(&format!(" (couldn't save config: {})", e));
+    }
+    app_state.results_message = Some(message);
+}
+
+/// Undo the last protected folder if `key` is U. The undo is only offered
+/// until the next key; Ctrl+Z still takes it back after that.
+fn take_protect_undo(app_state: &mut AppState, key: KeyCode) -> bool {
+    if !std::mem::take(&mut app_state.protect_undo)
+        || !matches!(key, KeyCode::Char('u') | KeyCode::Char('U'))
+    {
+        return false;
+    }
+    let Some(UndoStep::Protected(protected)) = app_state.undo_steps.last() else {
+        return false;
+    };
+    let folder = protected.folder.clone();
+    app_state.undo();
+    app_state.results_message = Some(format!("{} is no longer protected", folder.display()));
+    true
+}
+
 /// Open a file or directory in the system's default application/file manager
 /// For files, opens the parent folder and selects/focuses the file
 fn open_file(path: &std::path::Path) {
@@ -895,11 +1296,45 @@ fn handle_scanning_event(
 ) -> EventResult {
     match key {
         KeyCode::Esc => {
-            // Cancel scan - return to dashboard
+            // Cancel scan - stop the background scan and return to dashboard
+            app_state.scan_cancel.cancel();
             app_state.screen = crate::tui::state::Screen::Dashboard;
             app_state.pending_action = crate::tui::state::PendingAction::None;
             EventResult::Continue
         }
+        // Answers to "<category> is taking long"
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            if let crate::tui::state::Screen::Scanning { ref mut progress } = app_state.screen {
+                if let Some(slow) = progress.slow.take() {
+                    slow.control.skip();
+                    progress.notice = Some(format!("Skipped {}", slow.name));
+                }
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('k') | KeyCode::Char('K') => {
+            if let crate::tui::state::Screen::Scanning { ref mut progress } = app_state.screen {
+                progress.slow = None;
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') => {
+            if let crate::tui::state::Screen::Scanning { ref mut progress } = app_state.screen {
+                if let Some(slow) = progress.slow.take() {
+                    if let Some(ref narrow_to) = slow.control.narrow_to {
+                        slow.control.narrow();
+                        progress.notice = Some(format!(
+                            "Scanning {} in {} only",
+                            slow.name,
+                            crate::utils::display_path(narrow_to)
+                        ));
+                    } else {
+                        progress.slow = Some(slow);
+                    }
+                }
+            }
+            EventResult::Continue
+        }
         _ => EventResult::Continue,
     }
 }
@@ -909,6 +1344,32 @@ fn handle_results_event(
     key: KeyCode,
     modifiers: KeyModifiers,
 ) -> EventResult {
+    // Record selection changes for Ctrl+Z; changes that take results out of
+    // the list record their own step
+    let selected = app_state.selected_items.clone();
+    let items = app_state.all_items.len();
+    let result = handle_results_key(app_state, key, modifiers);
+    if app_state.all_items.len() == items && app_state.selected_items != selected {
+        app_state.record_undo(UndoStep::Selection(selected));
+    }
+    result
+}
+
+fn handle_results_key(
+    app_state: &mut AppState,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+) -> EventResult {
+    // Answer to "compress these folders?"
+    if !app_state.compress_confirm.is_empty() {
+        if matches!(key, KeyCode::Char('y') | KeyCode::Char('Y')) {
+            app_state.start_compress();
+        } else {
+            app_state.compress_confirm.clear();
+        }
+        return EventResult::Continue;
+    }
+
     // If in search mode, handle typing
     if app_state.search_mode {
         match key {
@@ -1056,6 +1517,13 @@ fn handle_results_event(
 
     let visible_height = app_state.visible_height;
 
+    // Clear any temporary message on key press
+    app_state.results_message = None;
+
+    if take_protect_undo(app_state, key) {
+        return EventResult::Continue;
+    }
+
     match key {
         KeyCode::Char('q') | KeyCode::Char('Q') => EventResult::Quit,
         KeyCode::Char('/') => {
@@ -1101,7 +1569,7 @@ fn handle_results_event(
                 let row = rows[app_state.cursor];
                 match row {
                     crate::tui::state::ResultsRow::CategoryHeader { group_idx } => {
-                        if let Some(group) = app_state.category_groups.get_mut(group_idx) {
+                        if let Some(group) = app_state.category_groups_mut().get_mut(group_idx) {
                             if !group.expanded {
                                 group.expanded = true;
                             } else {
@@ -1114,7 +1582,7 @@ fn handle_results_event(
                         folder_idx,
                         ..
                     } => {
-                        if let Some(group) = app_state.category_groups.get_mut(group_idx) {
+                        if let Some(group) = app_state.category_groups_mut().get_mut(group_idx) {
                             if let Some(folder) = group.folder_groups.get_mut(folder_idx) {
                                 if !folder.expanded {
                                     folder.expanded = true;
@@ -1134,7 +1602,7 @@ fn handle_results_event(
                 let row = rows[app_state.cursor];
                 match row {
                     crate::tui::state::ResultsRow::CategoryHeader { group_idx } => {
-                        if let Some(group) = app_state.category_groups.get_mut(group_idx) {
+                        if let Some(group) = app_state.category_groups_mut().get_mut(group_idx) {
                             if group.expanded {
                                 group.expanded = false;
                             }
@@ -1147,7 +1615,7 @@ fn handle_results_event(
                     } => {
                         // If expanded, collapse
                         let mut collapsed_now = false;
-                        if let Some(group) = app_state.category_groups.get_mut(group_idx) {
+                        if let Some(group) = app_state.category_groups_mut().get_mut(group_idx) {
                             if let Some(folder) = group.folder_groups.get_mut(folder_idx) {
                                 if folder.expanded {
                                     folder.expanded = false;
@@ -1483,7 +1951,7 @@ fn handle_results_event(
                         return;
                     }
 
-                    if let Some(group) = app_state.category_groups.get_mut(group_idx) {
+                    if let Some(group) = app_state.category_groups_mut().get_mut(group_idx) {
                         let any_expanded = siblings
                             .iter()
                             .filter_map(|&idx| group.folder_groups.get(idx))
@@ -1502,8 +1970,8 @@ fn handle_results_event(
                     crate::tui::state::ResultsRow::CategoryHeader { group_idx: _ } => {
                         // Expand/collapse all sibling categories
                         // Determine the current state (if any sibling is expanded, collapse all; otherwise expand all)
-                        let any_expanded = app_state.category_groups.iter().any(|g| g.expanded);
-                        for group in &mut app_state.category_groups {
+                        let any_expanded = app_state.category_groups().iter().any(|g| g.expanded);
+                        for group in app_state.category_groups_mut() {
                             group.expanded = !any_expanded;
                         }
                     }
@@ -1528,8 +1996,8 @@ fn handle_results_event(
                                 );
                             } else {
                                 let any_expanded =
-                                    app_state.category_groups.iter().any(|g| g.expanded);
-                                for group in &mut app_state.category_groups {
+                                    app_state.category_groups().iter().any(|g| g.expanded);
+                                for group in app_state.category_groups_mut() {
                                     group.expanded = !any_expanded;
                                 }
                             }
@@ -1562,14 +2030,14 @@ fn handle_results_event(
                     folder_idx,
                     ..
                 } => {
-                    if let Some(group) = app_state.category_groups.get_mut(group_idx) {
+                    if let Some(group) = app_state.category_groups_mut().get_mut(group_idx) {
                         if let Some(folder) = group.folder_groups.get_mut(folder_idx) {
                             folder.expanded = !folder.expanded;
                         }
                     }
                 }
                 crate::tui::state::ResultsRow::CategoryHeader { group_idx } => {
-                    if let Some(group) = app_state.category_groups.get_mut(group_idx) {
+                    if let Some(group) = app_state.category_groups_mut().get_mut(group_idx) {
                         group.expanded = !group.expanded;
                     }
                 }
@@ -1591,56 +2059,238 @@ fn handle_results_event(
             }
             EventResult::Continue
         }
-        _ => EventResult::Continue,
-    }
-}
-
-fn handle_preview_event(
-    app_state: &mut AppState,
-    key: KeyCode,
-    _modifiers: KeyModifiers,
-) -> EventResult {
-    match key {
-        KeyCode::Esc => {
-            // Back to results
-            app_state.screen = crate::tui::state::Screen::Results;
+        KeyCode::Char('o') | KeyCode::Char('O') => {
+            // Open the containing folder of the item under the cursor
+            if let Some(crate::tui::state::ResultsRow::Item { item_idx, .. }) =
+                rows.get(app_state.cursor)
+            {
+                if let Some(item) = app_state.all_items.get(*item_idx) {
+                    open_containing_folder(&item.path);
+                }
+            }
             EventResult::Continue
         }
-        KeyCode::Char('d') | KeyCode::Char('D') => {
-            // Delete this item
-            if let crate::tui::state::Screen::Preview { index } = app_state.screen {
-                app_state.selected_items.insert(index);
-                // Snapshot current selection when entering confirm screen
-                app_state.confirm_snapshot = app_state.selected_items.clone();
-                // Cache confirm groups for stable ordering
-                app_state.cache_confirm_groups();
+        KeyCode::Char('v') | KeyCode::Char('V') => {
+            // Saved searches: apply one, or save the current search
+            app_state.open_saved_views();
+            EventResult::Continue
+        }
+        KeyCode::Char('g') | KeyCode::Char('G') => {
+            // Group by the account that owns each item (all-users scans record owners)
+            if app_state.owners_known() {
+                app_state.toggle_group_by_owner();
+            } else {
+                app_state.results_message = Some(
+                    "Owners are recorded when scanning with --scope all-users (elevated)"
+                        .to_string(),
+                );
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('p') | KeyCode::Char('P') => {
+            // Exclude the folder under the cursor from future scans and drop it from the results
+            let folder = match rows.get(app_state.cursor) {
+                Some(crate::tui::state::ResultsRow::Item { item_idx, .. }) => app_state
+                    .all_items
+                    .get(*item_idx)
+                    .and_then(|item| item.path.parent())
+                    .map(|parent| parent.to_path_buf()),
+                Some(crate::tui::state::ResultsRow::FolderHeader {
+                    group_idx,
+                    folder_idx,
+                    ..
+                }) => app_state
+                    .category_groups()
+                    .get(*group_idx)
+                    .and_then(|group| group.folder_groups.get(*folder_idx))
+                    .and_then(|folder| {
+                        common_ancestor(
+                            folder
+                                .items
+                                .iter()
+                                .filter_map(|idx| app_state.all_items.get(*idx)?.path.parent()),
+                        )
+                    }),
+                _ => None,
+            };
+            match folder {
+                Some(folder) => protect_folder(app_state, &folder),
+                None => {
+                    app_state.results_message =
+                        Some("Move to an item or folder to protect it".to_string());
+                }
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('f') | KeyCode::Char('F') if modifiers.contains(KeyModifiers::CONTROL) => {
+            // Fuzzy-find items across every category
+            app_state.open_finder();
+            EventResult::Continue
+        }
+        KeyCode::Char('a') | KeyCode::Char('A') => {
+            // Group application, browser and temp caches by the app they belong to
+            app_state.screen = crate::tui::state::Screen::AppCaches {
+                apps: app_state.app_cache_usage(),
+                cursor: 0,
+            };
+            EventResult::Continue
+        }
+        KeyCode::Char('i') | KeyCode::Char('I') => {
+            // Preview the item under the cursor, including why it was (not) pre-selected
+            if let Some(crate::tui::state::ResultsRow::Item { item_idx, .. }) =
+                rows.get(app_state.cursor)
+            {
+                app_state.screen = crate::tui::state::Screen::Preview { index: *item_idx };
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('z') | KeyCode::Char('Z') => {
+            // Compress selected folders (or the folder under the cursor) instead of deleting
+            if app_state.compress_job.is_some() {
+                app_state.results_message =
+                    Some("Compression is still running - wait for it to finish".to_string());
+                return EventResult::Continue;
+            }
+            let mut indices: Vec<usize> = app_state.selected_items.iter().copied().collect();
+            if indices.is_empty() {
+                if let Some(crate::tui::state::ResultsRow::Item { item_idx, .. }) =
+                    rows.get(app_state.cursor)
+                {
+                    indices.push(*item_idx);
+                }
+            }
+            indices.sort_unstable();
+
+            let mut paths: Vec<std::path::PathBuf> = Vec::new();
+            for idx in &indices {
+                if let Some(item) = app_state.all_items.get(*idx) {
+                    if item.path.is_dir() && !paths.contains(&item.path) {
+                        paths.push(item.path.clone());
+                    }
+                }
+            }
+
+            if paths.is_empty() {
+                app_state.results_message =
+                    Some("Only folders can be compressed - select a folder first".to_string());
+                return EventResult::Continue;
+            }
+
+            // Ask first; compact.exe runs in the background once confirmed
+            app_state.results_message = None;
+            app_state.compress_confirm = paths;
+            EventResult::Continue
+        }
+        KeyCode::Char('f') | KeyCode::Char('F') => {
+            // Select or deselect by age, size, extension or path (Ctrl+F is handled above)
+            app_state.open_select_rule();
+            EventResult::Continue
+        }
+        KeyCode::Char('l') | KeyCode::Char('L') => {
+            // Free up space (OneDrive online-only) for selected items or the one under the cursor
+            let mut indices: Vec<usize> = app_state.selected_items.iter().copied().collect();
+            if indices.is_empty() {
+                if let Some(crate::tui::state::ResultsRow::Item { item_idx, .. }) =
+                    rows.get(app_state.cursor)
+                {
+                    indices.push(*item_idx);
+                }
+            }
+            free_up_cloud_space(app_state, indices);
+            EventResult::Continue
+        }
+        _ => EventResult::Continue,
+    }
+}
+
+/// Mark OneDrive items online-only instead of deleting them
+///
+/// Freed items stay available in the cloud, so they're dropped from the selection.
+fn free_up_cloud_space(app_state: &mut AppState, mut indices: Vec<usize>) {
+    indices.sort_unstable();
+    let mut paths: Vec<std::path::PathBuf> = Vec::new();
+    for idx in &indices {
+        if let Some(item) = app_state.all_items.get(*idx) {
+            if item.cloud.is_some_and(|p| p.can_free_up_space()) && !paths.contains(&item.path) {
+                paths.push(item.path.clone());
+            }
+        }
+    }
+    if paths.is_empty() {
+        app_state.results_message =
+            Some("Free up space works on OneDrive items only - select one first".to_string());
+        return;
+    }
+
+    let mut freed = 0u64;
+    let mut done = 0usize;
+    let mut first_error = None;
+    for path in &paths {
+        match crate::cloud::free_up_space(path) {
+            Ok(bytes) => {
+                freed += bytes;
+                done += 1;
+                if let Some(indices) = app_state.path_to_indices.get(path) {
+                    for idx in indices {
+                        app_state.selected_items.remove(idx);
+                    }
+                }
+            }
+            Err(e) => {
+                first_error.get_or_insert_with(|| e.to_string());
+            }
+        }
+    }
+
+    let mut message = format!(
+        "Freed up {} in {} OneDrive item(s); they stay available online",
+        bytesize::to_string(freed, false),
+        done
+    );
+    if let Some(error) = first_error {
+        message.push_str(&format!(" ({} failed: {})", paths.len() - done, error));
+    }
+    app_state.results_message = Some(message);
+}
+
+fn handle_preview_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    _modifiers: KeyModifiers,
+) -> EventResult {
+    match key {
+        KeyCode::Esc => {
+            // Back to results
+            app_state.screen = crate::tui::state::Screen::Results;
+            EventResult::Continue
+        }
+        KeyCode::Char('d') | KeyCode::Char('D') => {
+            // Delete this item
+            if let crate::tui::state::Screen::Preview { index } = app_state.screen {
+                app_state.selected_items.insert(index);
+                // Snapshot current selection when entering confirm screen
+                app_state.confirm_snapshot = app_state.selected_items.clone();
+                // Cache confirm groups for stable ordering
+                app_state.cache_confirm_groups();
                 app_state.cursor = 0;
                 app_state.scroll_offset = 0;
                 app_state.screen = crate::tui::state::Screen::Confirm { permanent: false };
             }
             EventResult::Continue
         }
+        KeyCode::Char('l') | KeyCode::Char('L') => {
+            // Free up space instead of deleting (OneDrive), then show the outcome in Results
+            if let crate::tui::state::Screen::Preview { index } = app_state.screen {
+                free_up_cloud_space(app_state, vec![index]);
+                app_state.screen = crate::tui::state::Screen::Results;
+            }
+            EventResult::Continue
+        }
         KeyCode::Char('e') | KeyCode::Char('E') => {
             // Exclude from results
             if let crate::tui::state::Screen::Preview { index } = app_state.screen {
-                // Remove the item and reindex selection set.
-                app_state.all_items.remove(index);
-
-                let mut new_selected = std::collections::HashSet::new();
-                for &sel in &app_state.selected_items {
-                    if sel == index {
-                        continue;
-                    }
-                    if sel > index {
-                        new_selected.insert(sel - 1);
-                    } else {
-                        new_selected.insert(sel);
-                    }
-                }
-                app_state.selected_items = new_selected;
-
-                // Rebuild grouping so indices remain valid.
-                app_state.rebuild_groups_from_all_items();
+                // Ctrl+Z in Results brings it back
+                app_state.exclude_items(&[index]);
 
                 // Reset cursor/scroll to a safe position.
                 app_state.cursor = 0;
@@ -1731,11 +2381,8 @@ fn handle_confirm_event(
 
     match key {
         KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-            // Cancel - back to results
-            // Clear confirm snapshot and cache since we're leaving confirm screen
-            app_state.confirm_snapshot.clear();
-            app_state.clear_confirm_cache();
-            app_state.screen = crate::tui::state::Screen::Results;
+            // Cancel - back to results (or Disk Insights, if the items came from there)
+            app_state.cancel_confirm();
             EventResult::Continue
         }
         KeyCode::Up => {
@@ -1750,14 +2397,22 @@ fn handle_confirm_event(
             if !rows.is_empty() && app_state.cursor < rows.len() {
                 let row = rows[app_state.cursor];
                 match row {
+                    crate::tui::state::ConfirmRow::TierHeader { tier } => {
+                        if app_state.confirm_collapsed_tiers.contains(&tier) {
+                            app_state.toggle_confirm_tier(tier);
+                        } else {
+                            move_cursor(app_state, &rows, 1, visible_height);
+                        }
+                    }
                     crate::tui::state::ConfirmRow::CategoryHeader { cat_idx } => {
-                        let confirm_groups = app_state.confirm_category_groups();
-                        if let Some(group) = confirm_groups.get(cat_idx) {
-                            if !group.expanded {
-                                app_state.toggle_confirm_category(&group.name);
-                            } else {
-                                move_cursor(app_state, &rows, 1, visible_height);
-                            }
+                        match app_state
+                            .confirm_category_groups()
+                            .get(cat_idx)
+                            .map(|g| g.expanded)
+                        {
+                            Some(false) => app_state.toggle_confirm_category(cat_idx),
+                            Some(true) => move_cursor(app_state, &rows, 1, visible_height),
+                            None => {}
                         }
                     }
                     crate::tui::state::ConfirmRow::FolderHeader {
@@ -1765,16 +2420,15 @@ fn handle_confirm_event(
                         folder_idx,
                         ..
                     } => {
-                        let confirm_groups = app_state.confirm_category_groups();
-                        if let Some(group) = confirm_groups.get(cat_idx) {
-                            if let Some(folder) = group.folder_groups.get(folder_idx) {
-                                if !folder.expanded {
-                                    app_state
-                                        .toggle_confirm_folder(&group.name, &folder.folder_name);
-                                } else {
-                                    move_cursor(app_state, &rows, 1, visible_height);
-                                }
-                            }
+                        match app_state
+                            .confirm_category_groups()
+                            .get(cat_idx)
+                            .and_then(|g| g.folder_groups.get(folder_idx))
+                            .map(|f| f.expanded)
+                        {
+                            Some(false) => app_state.toggle_confirm_folder(cat_idx, folder_idx),
+                            Some(true) => move_cursor(app_state, &rows, 1, visible_height),
+                            None => {}
                         }
                     }
                     _ => {}
@@ -1786,11 +2440,25 @@ fn handle_confirm_event(
             if !rows.is_empty() && app_state.cursor < rows.len() {
                 let row = rows[app_state.cursor];
                 match row {
+                    crate::tui::state::ConfirmRow::TierHeader { tier } => {
+                        if !app_state.confirm_collapsed_tiers.contains(&tier) {
+                            app_state.toggle_confirm_tier(tier);
+                        }
+                    }
                     crate::tui::state::ConfirmRow::CategoryHeader { cat_idx } => {
-                        let confirm_groups = app_state.confirm_category_groups();
-                        if let Some(group) = confirm_groups.get(cat_idx) {
-                            if group.expanded {
-                                app_state.toggle_confirm_category(&group.name);
+                        let expanded = app_state
+                            .confirm_category_groups()
+                            .get(cat_idx)
+                            .is_some_and(|g| g.expanded);
+                        if expanded {
+                            app_state.toggle_confirm_category(cat_idx);
+                        } else if let Some(i) = (0..app_state.cursor).rev().find(|&i| {
+                            matches!(rows[i], crate::tui::state::ConfirmRow::TierHeader { .. })
+                        }) {
+                            // Already collapsed: jump to the risk tier
+                            app_state.cursor = i;
+                            if app_state.cursor < app_state.scroll_offset {
+                                app_state.scroll_offset = app_state.cursor;
                             }
                         }
                     }
@@ -1799,16 +2467,13 @@ fn handle_confirm_event(
                         folder_idx,
                         ..
                     } => {
-                        let confirm_groups = app_state.confirm_category_groups();
-                        let mut collapsed_now = false;
-                        if let Some(group) = confirm_groups.get(cat_idx) {
-                            if let Some(folder) = group.folder_groups.get(folder_idx) {
-                                if folder.expanded {
-                                    app_state
-                                        .toggle_confirm_folder(&group.name, &folder.folder_name);
-                                    collapsed_now = true;
-                                }
-                            }
+                        let collapsed_now = app_state
+                            .confirm_category_groups()
+                            .get(cat_idx)
+                            .and_then(|g| g.folder_groups.get(folder_idx))
+                            .is_some_and(|f| f.expanded);
+                        if collapsed_now {
+                            app_state.toggle_confirm_folder(cat_idx, folder_idx);
                         }
 
                         // If we didn't just collapse it (was already collapsed), jump to parent category
@@ -1838,7 +2503,8 @@ fn handle_confirm_event(
                         for i in (0..app_state.cursor).rev() {
                             if let Some(
                                 crate::tui::state::ConfirmRow::FolderHeader { .. }
-                                | crate::tui::state::ConfirmRow::CategoryHeader { .. },
+                                | crate::tui::state::ConfirmRow::CategoryHeader { .. }
+                                | crate::tui::state::ConfirmRow::TierHeader { .. },
                             ) = rows.get(i).copied()
                             {
                                 app_state.cursor = i;
@@ -1861,6 +2527,11 @@ fn handle_confirm_event(
             };
 
             match *row {
+                crate::tui::state::ConfirmRow::TierHeader { tier } => {
+                    // Toggle every item of the tier
+                    let items = app_state.confirm_tier_items(tier);
+                    app_state.toggle_items(items);
+                }
                 crate::tui::state::ConfirmRow::Item { item_idx, .. } => {
                     // Toggle selection - item stays visible, checkbox updates
                     app_state.toggle_items([item_idx]);
@@ -1936,15 +2607,30 @@ fn handle_confirm_event(
 
                 // Expand/collapse sibling groups based on current row
                 match *row {
+                    crate::tui::state::ConfirmRow::TierHeader { .. } => {
+                        // Collapse all tiers, or expand them all if they already are
+                        let tiers: std::collections::HashSet<_> = rows
+                            .iter()
+                            .filter_map(|r| match r {
+                                crate::tui::state::ConfirmRow::TierHeader { tier } => Some(*tier),
+                                _ => None,
+                            })
+                            .collect();
+                        if tiers.is_subset(&app_state.confirm_collapsed_tiers) {
+                            app_state.confirm_collapsed_tiers.clear();
+                        } else {
+                            app_state.confirm_collapsed_tiers.extend(tiers);
+                        }
+                    }
                     crate::tui::state::ConfirmRow::CategoryHeader { cat_idx: _ } => {
                         // Expand/collapse all sibling categories
                         // Determine the current state (if any sibling is expanded, collapse all; otherwise expand all)
-                        let any_expanded = app_state.category_groups.iter().any(|g| g.expanded);
-                        for group in &mut app_state.category_groups {
+                        let any_expanded = app_state.category_groups().iter().any(|g| g.expanded);
+                        for group in app_state.category_groups_mut() {
                             group.expanded = !any_expanded;
                         }
                         // Update cache in place to preserve ordering
-                        for cached_group in &mut app_state.confirm_groups_cache {
+                        for cached_group in &mut app_state.confirm_model.groups {
                             cached_group.expanded = !any_expanded;
                         }
                     }
@@ -1967,7 +2653,10 @@ fn handle_confirm_event(
                         }
                         let mut end: usize = rows.len();
                         for (i, row) in rows.iter().enumerate().skip(app_state.cursor + 1) {
-                            if matches!(row, ConfirmRow::CategoryHeader { .. }) {
+                            if matches!(
+                                row,
+                                ConfirmRow::CategoryHeader { .. } | ConfirmRow::TierHeader { .. }
+                            ) {
                                 end = i;
                                 break;
                             }
@@ -2084,7 +2773,7 @@ fn handle_confirm_event(
                         }
 
                         // Toggle in confirm cache (render source) and mirror to category_groups.
-                        if let Some(cached_group) = app_state.confirm_groups_cache.get_mut(cat_idx)
+                        if let Some(cached_group) = app_state.confirm_model.groups.get_mut(cat_idx)
                         {
                             let any_expanded = siblings
                                 .iter()
@@ -2101,24 +2790,23 @@ fn handle_confirm_event(
 
                             // Mirror to backing category_groups by matching folder_name
                             let cat_name = cached_group.name.clone();
+                            let folder_names: Vec<String> = siblings
+                                .iter()
+                                .filter_map(|&idx| cached_group.folder_groups.get(idx))
+                                .map(|f| f.folder_name.clone())
+                                .collect();
                             if let Some(orig_group) = app_state
-                                .category_groups
+                                .category_groups_mut()
                                 .iter_mut()
                                 .find(|g| g.name == cat_name)
                             {
-                                for &idx in &siblings {
-                                    if let Some(folder_name) = cached_group
+                                for folder_name in &folder_names {
+                                    if let Some(orig_folder) = orig_group
                                         .folder_groups
-                                        .get(idx)
-                                        .map(|f| f.folder_name.clone())
+                                        .iter_mut()
+                                        .find(|f| &f.folder_name == folder_name)
                                     {
-                                        if let Some(orig_folder) = orig_group
-                                            .folder_groups
-                                            .iter_mut()
-                                            .find(|f| f.folder_name == folder_name)
-                                        {
-                                            orig_folder.expanded = new_state;
-                                        }
+                                        orig_folder.expanded = new_state;
                                     }
                                 }
                             }
@@ -2126,6 +2814,7 @@ fn handle_confirm_event(
                     }
                     crate::tui::state::ConfirmRow::Spacer => {}
                 }
+                app_state.refresh_confirm_rows();
                 return EventResult::Continue;
             }
 
@@ -2140,26 +2829,18 @@ fn handle_confirm_event(
             };
 
             match *row {
+                crate::tui::state::ConfirmRow::TierHeader { tier } => {
+                    app_state.toggle_confirm_tier(tier);
+                }
                 crate::tui::state::ConfirmRow::CategoryHeader { cat_idx } => {
-                    // Get category name and toggle expansion
-                    let confirm_groups = app_state.confirm_category_groups();
-                    if let Some(group) = confirm_groups.get(cat_idx) {
-                        app_state.toggle_confirm_category(&group.name);
-                    }
+                    app_state.toggle_confirm_category(cat_idx);
                 }
                 crate::tui::state::ConfirmRow::FolderHeader {
                     cat_idx,
                     folder_idx,
                     ..
                 } => {
-                    // Toggle folder expansion
-                    let confirm_groups = app_state.confirm_category_groups();
-                    if let Some(group) = confirm_groups.get(cat_idx) {
-                        if let Some(folder) = group.folder_groups.get(folder_idx) {
-                            // Find and toggle the folder in the original category_groups
-                            app_state.toggle_confirm_folder(&group.name, &folder.folder_name);
-                        }
-                    }
+                    app_state.toggle_confirm_folder(cat_idx, folder_idx);
                 }
                 _ => {}
             }
@@ -2184,11 +2865,49 @@ fn handle_confirm_event(
                         cleaned: 0,
                         total: app_state.selected_count() as u64,
                         errors: 0,
+                        paused: false,
+                        stop_requested: false,
+                    },
+                };
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('q') | KeyCode::Char('Q') => {
+            // Quarantine instead of recycling on volumes whose Recycle Bin is too small
+            if app_state.selected_count() == 0 || app_state.bin_overflow.is_empty() {
+                return EventResult::Continue;
+            }
+            if let crate::tui::state::Screen::Confirm { permanent } = app_state.screen {
+                crate::recycle_bin::set_quarantine_volumes(
+                    app_state
+                        .bin_overflow
+                        .iter()
+                        .map(|overflow| overflow.volume.clone())
+                        .collect(),
+                );
+                app_state.permanent_delete = permanent;
+                // Clear confirm snapshot and cache since we're leaving confirm screen
+                app_state.confirm_snapshot.clear();
+                app_state.clear_confirm_cache();
+                app_state.screen = crate::tui::state::Screen::Cleaning {
+                    progress: crate::tui::state::CleanProgress {
+                        current_category: String::new(),
+                        current_path: None,
+                        cleaned: 0,
+                        total: app_state.selected_count() as u64,
+                        errors: 0,
+                        paused: false,
+                        stop_requested: false,
                     },
                 };
             }
             EventResult::Continue
         }
+        KeyCode::Char('b') | KeyCode::Char('B') => {
+            // Background mode: rate-limited, low-priority deletes
+            crate::throttle::set_background_mode(!crate::throttle::background_mode());
+            EventResult::Continue
+        }
         KeyCode::Char('p') | KeyCode::Char('P') => {
             // Permanent delete - toggle the permanent flag in Confirm screen
             if app_state.selected_count() == 0 {
@@ -2209,6 +2928,8 @@ fn handle_confirm_event(
                         cleaned: 0,
                         total: app_state.selected_count() as u64,
                         errors: 0,
+                        paused: false,
+                        stop_requested: false,
                     },
                 };
             }
@@ -2219,11 +2940,29 @@ fn handle_confirm_event(
 }
 
 fn handle_cleaning_event(
-    _app_state: &mut AppState,
-    _key: KeyCode,
+    app_state: &mut AppState,
+    key: KeyCode,
     _modifiers: KeyModifiers,
 ) -> EventResult {
-    // Cleaning is in progress - ignore input until complete
+    // perform_cleanup reads these flags between batches
+    if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+        match key {
+            KeyCode::Char('p') | KeyCode::Char('P') | KeyCode::Char(' ') => {
+                progress.paused = !progress.paused;
+            }
+            KeyCode::Char('b') | KeyCode::Char('B') => {
+                // Applies from the next batch
+                crate::throttle::set_background_mode(!crate::throttle::background_mode());
+            }
+            KeyCode::Esc if progress.paused => {
+                progress.stop_requested = true;
+                progress.paused = false;
+                // A batch already running stops at its next item
+                app_state.cleanup_cancel.cancel();
+            }
+            _ => {}
+        }
+    }
     EventResult::Continue
 }
 
@@ -2232,7 +2971,27 @@ fn handle_success_event(
     key: KeyCode,
     _modifiers: KeyModifiers,
 ) -> EventResult {
+    if app_state.shadow_resize_confirm {
+        app_state.shadow_resize_confirm = false;
+        if matches!(key, KeyCode::Char('y') | KeyCode::Char('Y')) {
+            if let Some((storage, max)) = app_state.shadow_resize_target() {
+                let volume = storage.volume.clone();
+                app_state.shadow_message = Some(match crate::shadow::resize(&volume, max) {
+                    Ok(()) => {
+                        app_state.shadow_storage.retain(|s| s.volume != volume);
+                        format!("Shadow storage on {} capped at {}", volume, max)
+                    }
+                    Err(e) => format!("Could not resize shadow storage: {}", e),
+                });
+            }
+        }
+        return EventResult::Continue;
+    }
     match key {
+        KeyCode::Char('v') | KeyCode::Char('V') if app_state.shadow_resize_target().is_some() => {
+            app_state.shadow_resize_confirm = true;
+            EventResult::Continue
+        }
         KeyCode::Esc | KeyCode::Backspace | KeyCode::Char('b') | KeyCode::Char('B') => {
             // Navigate back to Results if there are remaining items
             if !app_state.all_items.is_empty() {
@@ -2268,11 +3027,15 @@ fn handle_restore_selection_event(
                 EventResult::Continue
             }
             KeyCode::Down => {
-                if *cursor < 1 {
+                if *cursor < 2 {
                     *cursor += 1;
                 }
                 EventResult::Continue
             }
+            KeyCode::Enter if *cursor == 2 => {
+                app_state.screen = open_history();
+                EventResult::Continue
+            }
             KeyCode::Enter => {
                 // Start restore operation based on selection
                 let restore_all_bin = *cursor == 1;
@@ -2280,6 +3043,7 @@ fn handle_restore_selection_event(
                     progress: None,
                     result: None,
                     restore_all_bin,
+                    log_path: None,
                 };
                 EventResult::Continue
             }
@@ -2305,6 +3069,19 @@ fn handle_restore_event(
     key: KeyCode,
     _modifiers: KeyModifiers,
 ) -> EventResult {
+    // A restore still running is stopped rather than left behind
+    if let crate::tui::state::Screen::Restore {
+        progress: Some(_),
+        result: None,
+        ..
+    } = app_state.screen
+    {
+        if matches!(key, KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q')) {
+            app_state.restore_cancel.cancel();
+        }
+        return EventResult::Continue;
+    }
+
     match key {
         KeyCode::Esc
         | KeyCode::Backspace
@@ -2327,6 +3104,12 @@ fn handle_disk_insights_event(
 ) -> EventResult {
     use crate::disk_usage::{find_folder_by_path, SortBy};
 
+    // The protect toast shares the results message and lasts until the next key
+    app_state.results_message = None;
+    if take_protect_undo(app_state, key) {
+        return EventResult::Continue;
+    }
+
     if let crate::tui::state::Screen::DiskInsights {
         ref insights,
         ref mut current_path,
@@ -2414,7 +3197,7 @@ fn handle_disk_insights_event(
         match key {
             KeyCode::Char('q') | KeyCode::Char('Q') => {
                 // Go back to Results if there are scan results, otherwise Dashboard
-                if !app_state.all_items.is_empty() || !app_state.category_groups.is_empty() {
+                if !app_state.all_items.is_empty() || !app_state.category_groups().is_empty() {
                     app_state.screen = crate::tui::state::Screen::Results;
                 } else {
                     app_state.screen = crate::tui::state::Screen::Dashboard;
@@ -2544,6 +3327,83 @@ fn handle_disk_insights_event(
                 }
                 EventResult::Continue
             }
+            KeyCode::Char(c @ ('e' | 'E' | 'j' | 'J' | 'w' | 'W')) => {
+                // Export the whole analysis: E = CSV, J = JSON, W = WizTree CSV
+                use crate::disk_usage::ExportFormat;
+                let (format, suffix) = match c.to_ascii_lowercase() {
+                    'j' => (ExportFormat::Json, "json"),
+                    'w' => (ExportFormat::WizTree, "wiztree.csv"),
+                    _ => (ExportFormat::Csv, "csv"),
+                };
+                let exported = crate::history::get_export_dir().and_then(|dir| {
+                    let path = dir.join(format!(
+                        "disk_insights_{}.{}",
+                        chrono::Local::now().format("%Y%m%d_%H%M%S"),
+                        suffix
+                    ));
+                    crate::disk_usage::write_export(insights, &path, format).map(|()| path)
+                });
+                app_state.results_message = Some(match exported {
+                    Ok(path) => format!("Exported to {}", path.display()),
+                    Err(e) => format!("Export failed: {}", e),
+                });
+                EventResult::Continue
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                // Send the marked folders and files to the confirm screen
+                if app_state.clean_insights_selection() == 0 {
+                    app_state.results_message =
+                        Some("Mark folders or files with Space first".to_string());
+                }
+                EventResult::Continue
+            }
+            KeyCode::Char('b') | KeyCode::Char('B') => {
+                // Chart the open folder by extension, size and age
+                app_state.open_breakdown();
+                EventResult::Continue
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                // Pin the folder under the cursor (or the open folder, on a file) for quick access
+                let dir = if *cursor < children_count {
+                    children[*cursor].path.clone()
+                } else {
+                    current_path.clone()
+                };
+                app_state.results_message = Some(match app_state.toggle_pin(&dir) {
+                    Ok(true) => format!("Pinned {}", dir.display()),
+                    Ok(false) => format!("Unpinned {}", dir.display()),
+                    Err(e) => format!("Couldn't save pinned folders: {}", e),
+                });
+                EventResult::Continue
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                // Jump to a pinned folder, scanning it if it's outside this analysis
+                if let Some(dir) = pinned_dir(&app_state.config, c) {
+                    if find_folder_by_path(&insights.root, &dir).is_some() {
+                        *current_path = dir;
+                        *cursor = 0;
+                        app_state.search_query.clear();
+                    } else {
+                        start_analyze(app_state, dir);
+                    }
+                }
+                EventResult::Continue
+            }
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                // Exclude the folder under the cursor (or the folder holding the file) from scans
+                let folder = if *cursor < children_count {
+                    Some(children[*cursor].path.clone())
+                } else {
+                    files
+                        .get(*cursor - children_count)
+                        .and_then(|file| file.path.parent())
+                        .map(|parent| parent.to_path_buf())
+                };
+                if let Some(folder) = folder {
+                    protect_folder(app_state, &folder);
+                }
+                EventResult::Continue
+            }
             _ => EventResult::Continue,
         }
     } else {
@@ -2562,9 +3422,10 @@ fn handle_optimize_event(
         ref mut results,
         ref mut running,
         ref mut message,
+        admin,
     } = app_state.screen
     {
-        const OPTIONS_COUNT: usize = 10;
+        const OPTIONS_COUNT: usize = crate::optimize::OPERATIONS.len();
 
         match key {
             KeyCode::Char('q') | KeyCode::Esc => {
@@ -2629,22 +3490,27 @@ fn handle_optimize_event(
                 if !*running && results.is_empty() {
                     if selected.contains(cursor) {
                         selected.remove(cursor);
-                    } else {
+                    } else if crate::optimize::OPERATIONS[*cursor].available(admin) {
                         selected.insert(*cursor);
+                    } else {
+                        app_state
+                            .toasts
+                            .info("Needs administrator rights - restart wole as Administrator");
                     }
                 }
                 EventResult::Continue
             }
             KeyCode::Char('a') | KeyCode::Char('A') => {
-                // Toggle all
+                // Toggle all the operations that can run
                 if !*running && results.is_empty() {
-                    let all_selected = (0..OPTIONS_COUNT).all(|i| selected.contains(&i));
+                    let available: Vec<usize> = (0..OPTIONS_COUNT)
+                        .filter(|&i| crate::optimize::OPERATIONS[i].available(admin))
+                        .collect();
+                    let all_selected = available.iter().all(|i| selected.contains(i));
                     if all_selected {
                         selected.clear();
                     } else {
-                        for i in 0..OPTIONS_COUNT {
-                            selected.insert(i);
-                        }
+                        selected.extend(available);
                     }
                 }
                 EventResult::Continue
@@ -2665,6 +3531,9 @@ fn handle_optimize_event(
                     let bluetooth = selected.contains(&7);
                     let search = selected.contains(&8);
                     let explorer = selected.contains(&9);
+                    let spooler = selected.contains(&10);
+                    let search_index = selected.contains(&11);
+                    let font_cache = selected.contains(&12);
 
                     // Run optimizations with quiet output (TUI will show results)
                     // Explorer restart now uses spawn() instead of output() to avoid blocking
@@ -2680,6 +3549,13 @@ fn handle_optimize_event(
                         bluetooth,
                         search,
                         explorer,
+                        spooler,
+                        search_index,
+                        font_cache,
+                        &[], // compress (Results screen has its own action)
+                        crate::compress::CompressionMode::default(),
+                        &[],                              // unblock
+                        None,                             // power_plan
                         false,                            // dry_run
                         false,                            // yes (skip confirmation)
                         crate::output::OutputMode::Quiet, // Quiet mode for TUI
@@ -2711,46 +3587,61 @@ fn handle_optimize_event(
                                 bluetooth,
                                 search,
                                 explorer,
+                                spooler,
+                                search_index,
+                                font_cache,
                             ) = match action_name.as_str() {
                                 "Flush DNS Cache" => (
                                     false, true, false, false, false, false, false, false, false,
-                                    false, false,
+                                    false, false, false, false, false,
                                 ),
                                 "Clear Thumbnail Cache" => (
                                     false, false, true, false, false, false, false, false, false,
-                                    false, false,
+                                    false, false, false, false, false,
                                 ),
                                 "Rebuild Icon Cache" => (
                                     false, false, false, true, false, false, false, false, false,
-                                    false, false,
+                                    false, false, false, false, false,
                                 ),
                                 "Optimize Browser Databases" => (
                                     false, false, false, false, true, false, false, false, false,
-                                    false, false,
+                                    false, false, false, false, false,
                                 ),
                                 "Restart Font Cache Service" => (
                                     false, false, false, false, false, true, false, false, false,
-                                    false, false,
+                                    false, false, false, false, false,
                                 ),
                                 "Clear Standby Memory" => (
                                     false, false, false, false, false, false, true, false, false,
-                                    false, false,
+                                    false, false, false, false, false,
                                 ),
                                 "Reset Network Stack" => (
                                     false, false, false, false, false, false, false, true, false,
-                                    false, false,
+                                    false, false, false, false, false,
                                 ),
                                 "Restart Bluetooth Service" => (
                                     false, false, false, false, false, false, false, false, true,
-                                    false, false,
+                                    false, false, false, false, false,
                                 ),
                                 "Restart Windows Search" => (
                                     false, false, false, false, false, false, false, false, false,
-                                    true, false,
+                                    true, false, false, false, false,
                                 ),
                                 "Restart Explorer" => (
                                     false, false, false, false, false, false, false, false, false,
-                                    false, true,
+                                    false, true, false, false, false,
+                                ),
+                                "Clear Print Spooler" => (
+                                    false, false, false, false, false, false, false, false, false,
+                                    false, false, true, false, false,
+                                ),
+                                "Rebuild Search Index" => (
+                                    false, false, false, false, false, false, false, false, false,
+                                    false, false, false, true, false,
+                                ),
+                                "Reset Font Cache" => (
+                                    false, false, false, false, false, false, false, false, false,
+                                    false, false, false, false, true,
                                 ),
                                 _ => {
                                     // Unknown action, just go back to options
@@ -2776,6 +3667,13 @@ fn handle_optimize_event(
                                 bluetooth,
                                 search,
                                 explorer,
+                                spooler,
+                                search_index,
+                                font_cache,
+                                &[], // compress (Results screen has its own action)
+                                crate::compress::CompressionMode::default(),
+                                &[],                              // unblock
+                                None,                             // power_plan
                                 false,                            // dry_run
                                 false,                            // yes (skip confirmation)
                                 crate::output::OutputMode::Quiet, // Quiet mode for TUI
@@ -2821,11 +3719,17 @@ fn handle_status_event(
     _modifiers: KeyModifiers,
 ) -> EventResult {
     if let crate::tui::state::Screen::Status {
-        status: _,
+        ref status,
         last_refresh: _,
         ref mut status_receiver,
     } = app_state.screen
     {
+        if app_state.process_tree.enabled
+            && handle_process_tree_key(&mut app_state.process_tree, &status.all_processes, key)
+        {
+            return EventResult::Continue;
+        }
+
         match key {
             KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('b') => {
                 // Go back to dashboard
@@ -2850,28 +3754,968 @@ fn handle_status_event(
                 refresh_disk_breakdown_async();
                 EventResult::Continue
             }
-            _ => EventResult::Continue,
+            KeyCode::Char('w') | KeyCode::Char('W') => {
+                // Live table of what is writing to the system drive
+                app_state.open_disk_writes(&crate::disk_writes::system_volume());
+                EventResult::Continue
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                // Hosts file, proxy and DNS leftovers
+                app_state.open_net_check();
+                EventResult::Continue
+            }
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                // Switch the processes panel between the top list and the tree
+                let tree = &mut app_state.process_tree;
+                tree.enabled = !tree.enabled;
+                tree.cursor = 0;
+                EventResult::Continue
+            }
+            KeyCode::Up => {
+                let watch = &mut app_state.leak_watch;
+                watch.cursor = watch.cursor.saturating_sub(1);
+                watch.pending = None;
+                EventResult::Continue
+            }
+            KeyCode::Down => {
+                let watch = &mut app_state.leak_watch;
+                if watch.cursor + 1 < watch.suspects().len() {
+                    watch.cursor += 1;
+                }
+                watch.pending = None;
+                EventResult::Continue
+            }
+            KeyCode::Char('k') | KeyCode::Char('K') => {
+                handle_leak_action(app_state, crate::leak_watch::LeakAction::Kill);
+                EventResult::Continue
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                handle_leak_action(app_state, crate::leak_watch::LeakAction::Restart);
+                EventResult::Continue
+            }
+            _ => {
+                app_state.leak_watch.pending = None;
+                EventResult::Continue
+            }
         }
     } else {
         EventResult::Continue
     }
 }
 
-fn handle_optimize_click(app_state: &mut AppState, row: u16, _col: u16) -> EventResult {
-    if let crate::tui::state::Screen::Optimize {
-        ref mut cursor,
-        ref mut selected,
-        ref results,
-        ref running,
-        ref mut message,
-    } = app_state.screen
-    {
-        if *running {
-            // Can't interact while running
-            return EventResult::Continue;
+/// Navigate, expand/collapse and filter the process tree. Returns false for
+/// keys the tree doesn't use.
+fn handle_process_tree_key(
+    tree: &mut crate::process_tree::TreeView,
+    processes: &[crate::status::ProcessSample],
+    key: KeyCode,
+) -> bool {
+    if tree.editing_filter {
+        match key {
+            KeyCode::Esc | KeyCode::Enter => tree.editing_filter = false,
+            KeyCode::Backspace => {
+                tree.filter.pop();
+            }
+            KeyCode::Char(c) => tree.filter.push(c),
+            _ => {}
         }
+        tree.cursor = 0;
+        return true;
+    }
 
-        let header_height = LOGO_WITH_TAGLINE_HEIGHT;
+    let rows = tree.rows(processes);
+    match key {
+        KeyCode::Up => tree.cursor = tree.cursor.saturating_sub(1),
+        KeyCode::Down => tree.cursor = (tree.cursor + 1).min(rows.len().saturating_sub(1)),
+        KeyCode::Char('/') => tree.editing_filter = true,
+        KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right => {
+            if let Some(row) = rows.get(tree.cursor).filter(|row| row.has_children) {
+                let expand = match key {
+                    KeyCode::Left => false,
+                    KeyCode::Right => true,
+                    _ => !row.expanded,
+                };
+                if expand != row.expanded {
+                    tree.toggle(row.pid);
+                }
+            }
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// End or restart the selected possible leak; the key has to be pressed twice
+fn handle_leak_action(app_state: &mut AppState, action: crate::leak_watch::LeakAction) {
+    use crate::leak_watch::LeakAction;
+
+    let Some(suspect) = app_state.leak_watch.selected().cloned() else {
+        return;
+    };
+    if app_state.leak_watch.pending != Some((action, suspect.pid)) {
+        app_state.leak_watch.pending = Some((action, suspect.pid));
+        app_state.toasts.info(match action {
+            LeakAction::Kill => format!("Press K again to end {}", suspect.name),
+            LeakAction::Restart => format!("Press E again to restart {}", suspect.name),
+        });
+        return;
+    }
+
+    app_state.leak_watch.pending = None;
+    let result = match action {
+        LeakAction::Kill => crate::leak_watch::kill_process(suspect.pid, &suspect.name),
+        LeakAction::Restart => crate::leak_watch::restart_process(suspect.pid, &suspect.name),
+    };
+    match result {
+        Ok(()) => {
+            app_state.leak_watch.forget(suspect.pid);
+            app_state.toasts.info(match action {
+                LeakAction::Kill => format!("Ended {}", suspect.name),
+                LeakAction::Restart => format!("Restarted {}", suspect.name),
+            });
+        }
+        Err(e) => app_state.toasts.warn(e.to_string()),
+    }
+}
+
+/// Folders shown on the "What changed" screen
+const WHAT_CHANGED_LIMIT: usize = 50;
+
+fn load_latest_diff() -> Option<crate::scan_cache::SnapshotDiff> {
+    crate::scan_cache::ScanCache::open()
+        .and_then(|cache| cache.diff_latest_snapshots(WHAT_CHANGED_LIMIT))
+        .ok()
+        .flatten()
+}
+
+fn handle_what_changed_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    _modifiers: KeyModifiers,
+) -> EventResult {
+    let crate::tui::state::Screen::WhatChanged {
+        ref mut diff,
+        ref mut cursor,
+    } = app_state.screen
+    else {
+        return EventResult::Continue;
+    };
+    let len = diff.as_ref().map(|d| d.changes.len()).unwrap_or(0);
+
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') => {
+            app_state.screen = crate::tui::state::Screen::Dashboard;
+        }
+        KeyCode::Up => {
+            *cursor = cursor.saturating_sub(1);
+        }
+        KeyCode::Down => {
+            if *cursor + 1 < len {
+                *cursor += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if let Some(change) = diff.as_ref().and_then(|d| d.changes.get(*cursor)) {
+                open_file(&change.path);
+            }
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            *diff = load_latest_diff();
+            *cursor = 0;
+        }
+        _ => {}
+    }
+    EventResult::Continue
+}
+
+fn handle_app_caches_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    _modifiers: KeyModifiers,
+) -> EventResult {
+    let crate::tui::state::Screen::AppCaches {
+        ref apps,
+        ref mut cursor,
+    } = app_state.screen
+    else {
+        return EventResult::Continue;
+    };
+    let items = apps
+        .get(*cursor)
+        .map(|app| app.items.clone())
+        .unwrap_or_default();
+
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') => {
+            app_state.screen = crate::tui::state::Screen::Results;
+        }
+        KeyCode::Up => {
+            *cursor = cursor.saturating_sub(1);
+        }
+        KeyCode::Down => {
+            if *cursor + 1 < apps.len() {
+                *cursor += 1;
+            }
+        }
+        KeyCode::Char(' ') => {
+            // Select all of the app's items, or deselect them if they all are
+            if items.iter().all(|i| app_state.selected_items.contains(i)) {
+                for i in &items {
+                    app_state.selected_items.remove(i);
+                }
+            } else {
+                app_state.selected_items.extend(items);
+            }
+        }
+        KeyCode::Char('c') | KeyCode::Char('C') => {
+            // Clean just this app's caches
+            if !items.is_empty() {
+                app_state.selected_items = items.into_iter().collect();
+                app_state.confirm_snapshot = app_state.selected_items.clone();
+                app_state.cache_confirm_groups();
+                app_state.cursor = 0;
+                app_state.scroll_offset = 0;
+                app_state.screen = crate::tui::state::Screen::Confirm { permanent: false };
+            }
+        }
+        _ => {}
+    }
+    EventResult::Continue
+}
+
+fn handle_breakdown_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    _modifiers: KeyModifiers,
+) -> EventResult {
+    let crate::tui::state::Screen::Breakdown {
+        ref breakdown,
+        ref mut tab,
+        ref job,
+        ref mut message,
+        ref back,
+        ..
+    } = app_state.screen
+    else {
+        return EventResult::Continue;
+    };
+    let tabs = 3;
+
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') | KeyCode::Backspace => {
+            if let Some(job) = job {
+                job.cancel();
+            }
+            app_state.screen = (**back).clone();
+        }
+        KeyCode::Tab | KeyCode::Right | KeyCode::Down => {
+            *tab = (*tab + 1) % tabs;
+        }
+        KeyCode::BackTab | KeyCode::Left | KeyCode::Up => {
+            *tab = (*tab + tabs - 1) % tabs;
+        }
+        KeyCode::Char(c @ ('e' | 'E' | 'j' | 'J')) => {
+            // Export the buckets: E = CSV, J = JSON
+            use crate::disk_usage::ExportFormat;
+            let Some(breakdown) = breakdown else {
+                return EventResult::Continue;
+            };
+            let (format, suffix) = if c.eq_ignore_ascii_case(&'j') {
+                (ExportFormat::Json, "json")
+            } else {
+                (ExportFormat::Csv, "csv")
+            };
+            let exported = crate::history::get_export_dir().and_then(|dir| {
+                let path = dir.join(format!(
+                    "breakdown_{}.{}",
+                    chrono::Local::now().format("%Y%m%d_%H%M%S"),
+                    suffix
+                ));
+                breakdown.write_export(&path, format).map(|()| path)
+            });
+            *message = Some(match exported {
+                Ok(path) => format!("Exported to {}", path.display()),
+                Err(e) => format!("Export failed: {}", e),
+            });
+        }
+        _ => {}
+    }
+    EventResult::Continue
+}
+
+fn handle_disk_writes_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    _modifiers: KeyModifiers,
+) -> EventResult {
+    let crate::tui::state::Screen::DiskWrites {
+        ref mut by_process,
+        ref back,
+        ..
+    } = app_state.screen
+    else {
+        return EventResult::Continue;
+    };
+
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') | KeyCode::Backspace => {
+            // Dropping the monitor stops its ETW session
+            app_state.screen = (**back).clone();
+        }
+        KeyCode::Tab | KeyCode::BackTab | KeyCode::Left | KeyCode::Right => {
+            *by_process = !*by_process;
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app_state.restart_disk_writes();
+        }
+        _ => {}
+    }
+    EventResult::Continue
+}
+
+fn handle_net_check_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    _modifiers: KeyModifiers,
+) -> EventResult {
+    let crate::tui::state::Screen::NetCheck {
+        ref issues,
+        ref mut cursor,
+        ref back,
+        ..
+    } = app_state.screen
+    else {
+        return EventResult::Continue;
+    };
+
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') | KeyCode::Backspace => {
+            app_state.screen = (**back).clone();
+        }
+        KeyCode::Up => *cursor = cursor.saturating_sub(1),
+        KeyCode::Down => {
+            if *cursor + 1 < issues.len() {
+                *cursor += 1;
+            }
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => app_state.revert_net_issue(),
+        _ => {}
+    }
+    EventResult::Continue
+}
+
+fn handle_saved_views_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    _modifiers: KeyModifiers,
+) -> EventResult {
+    let count = app_state.config.ui.saved_views.len();
+    let crate::tui::state::Screen::SavedViews {
+        ref mut cursor,
+        ref mut naming,
+        ref mut message,
+    } = app_state.screen
+    else {
+        return EventResult::Continue;
+    };
+
+    // Typing a name for the current search
+    if let Some(name) = naming {
+        match key {
+            KeyCode::Esc => *naming = None,
+            KeyCode::Backspace => {
+                name.pop();
+            }
+            KeyCode::Enter => {
+                let name = name.trim().to_string();
+                if name.is_empty() {
+                    return EventResult::Continue;
+                }
+                *naming = None;
+                let result = app_state.save_current_view(&name);
+                let index = app_state
+                    .config
+                    .ui
+                    .saved_views
+                    .iter()
+                    .position(|view| view.name == name);
+                if let crate::tui::state::Screen::SavedViews {
+                    ref mut cursor,
+                    ref mut message,
+                    ..
+                } = app_state.screen
+                {
+                    *cursor = index.unwrap_or(*cursor);
+                    *message = Some(match result {
+                        Ok(()) => format!("Saved \"{}\"", name),
+                        Err(e) => format!("Couldn't save the view: {}", e),
+                    });
+                }
+            }
+            KeyCode::Char(c) if !c.is_control() => name.push(c),
+            _ => {}
+        }
+        return EventResult::Continue;
+    }
+
+    *message = None;
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Backspace => {
+            app_state.screen = crate::tui::state::Screen::Results;
+        }
+        KeyCode::Up => *cursor = cursor.saturating_sub(1),
+        KeyCode::Down => {
+            if *cursor + 1 < count {
+                *cursor += 1;
+            }
+        }
+        KeyCode::Enter => {
+            let index = *cursor;
+            app_state.apply_saved_view(index);
+        }
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            if app_state.search_query.trim().is_empty() {
+                *message = Some("Search in Results first (/), then save it here".to_string());
+            } else {
+                *naming = Some(String::new());
+            }
+        }
+        KeyCode::Char('d') | KeyCode::Char('D') | KeyCode::Delete => {
+            let index = *cursor;
+            let result = app_state.delete_saved_view(index);
+            let count = app_state.config.ui.saved_views.len();
+            if let crate::tui::state::Screen::SavedViews {
+                ref mut cursor,
+                ref mut message,
+                ..
+            } = app_state.screen
+            {
+                *cursor = (*cursor).min(count.saturating_sub(1));
+                if let Err(e) = result {
+                    *message = Some(format!("Couldn't save the config: {}", e));
+                }
+            }
+        }
+        _ => {}
+    }
+    EventResult::Continue
+}
+
+/// Text fields of the select-by-rule dialog; the scope and action rows follow
+const SELECT_RULE_FIELDS: usize = 4;
+
+fn handle_select_rule_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    _modifiers: KeyModifiers,
+) -> EventResult {
+    let crate::tui::state::Screen::SelectRule {
+        ref mut fields,
+        ref mut cursor,
+        group,
+        ref mut all,
+        ref mut deselect,
+        ref mut message,
+    } = app_state.screen
+    else {
+        return EventResult::Continue;
+    };
+
+    *message = None;
+    match key {
+        KeyCode::Esc => app_state.screen = crate::tui::state::Screen::Results,
+        KeyCode::Up | KeyCode::BackTab => *cursor = cursor.saturating_sub(1),
+        KeyCode::Down | KeyCode::Tab => *cursor = (*cursor + 1).min(SELECT_RULE_FIELDS + 1),
+        KeyCode::Backspace if *cursor < SELECT_RULE_FIELDS => {
+            fields[*cursor].pop();
+        }
+        KeyCode::Char(c) if *cursor < SELECT_RULE_FIELDS && !c.is_control() => {
+            fields[*cursor].push(c);
+        }
+        KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right => {
+            if *cursor == SELECT_RULE_FIELDS {
+                // Without a category under the cursor there's only "all results"
+                *all = !*all || group.is_none();
+            } else if *cursor == SELECT_RULE_FIELDS + 1 {
+                *deselect = !*deselect;
+            }
+        }
+        KeyCode::Enter => {
+            let Some(rule) =
+                crate::select::quick_rule(&fields[0], &fields[1], &fields[2], &fields[3])
+            else {
+                *message = Some("Fill in at least one rule".to_string());
+                return EventResult::Continue;
+            };
+            let selector = match crate::select::Selector::parse(&rule) {
+                Ok(selector) => selector,
+                Err(e) => {
+                    *message = Some(e.to_string());
+                    return EventResult::Continue;
+                }
+            };
+            let scope = if *all { None } else { group };
+            let select = !*deselect;
+            let before = app_state.selected_items.clone();
+            let changed = app_state.select_by_rule(&selector, scope, select);
+            if changed == 0 {
+                if let crate::tui::state::Screen::SelectRule {
+                    ref mut message, ..
+                } = app_state.screen
+                {
+                    *message = Some(format!(
+                        "Nothing to {} matches {}",
+                        if select { "select" } else { "deselect" },
+                        rule
+                    ));
+                }
+                return EventResult::Continue;
+            }
+            app_state.record_undo(UndoStep::Selection(before));
+            app_state.results_message = Some(format!(
+                "{} {} item{} matching {} - Ctrl+Z to undo",
+                if select { "Selected" } else { "Deselected" },
+                changed,
+                if changed == 1 { "" } else { "s" },
+                rule
+            ));
+            app_state.screen = crate::tui::state::Screen::Results;
+        }
+        _ => {}
+    }
+    EventResult::Continue
+}
+
+fn handle_finder_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+) -> EventResult {
+    let crate::tui::state::Screen::Finder {
+        ref mut query,
+        ref mut matches,
+        ref mut cursor,
+        ref mut marked,
+    } = app_state.screen
+    else {
+        return EventResult::Continue;
+    };
+
+    let mut requery = false;
+    match key {
+        KeyCode::Esc => {
+            app_state.screen = crate::tui::state::Screen::Results;
+            return EventResult::Continue;
+        }
+        KeyCode::Up => *cursor = cursor.saturating_sub(1),
+        KeyCode::Down => {
+            if *cursor + 1 < matches.len() {
+                *cursor += 1;
+            }
+        }
+        KeyCode::PageUp => *cursor = cursor.saturating_sub(10),
+        KeyCode::PageDown => *cursor = (*cursor + 10).min(matches.len().saturating_sub(1)),
+        KeyCode::Tab | KeyCode::BackTab => {
+            // Mark (or unmark) the item and move on, like fzf
+            if let Some(&item) = matches.get(*cursor) {
+                if !marked.remove(&item) {
+                    marked.insert(item);
+                }
+            }
+            if key == KeyCode::Tab {
+                if *cursor + 1 < matches.len() {
+                    *cursor += 1;
+                }
+            } else {
+                *cursor = cursor.saturating_sub(1);
+            }
+        }
+        KeyCode::Char('a') if modifiers.contains(KeyModifiers::CONTROL) => {
+            // Mark every match, or unmark them if they all are
+            if matches.iter().all(|item| marked.contains(item)) {
+                for item in matches.iter() {
+                    marked.remove(item);
+                }
+            } else {
+                marked.extend(matches.iter().copied());
+            }
+        }
+        KeyCode::Enter => {
+            // The marks, or the item under the cursor if nothing is marked
+            let chosen: std::collections::HashSet<usize> = if marked.is_empty() {
+                matches.get(*cursor).copied().into_iter().collect()
+            } else {
+                std::mem::take(marked)
+            };
+            if !chosen.is_empty() {
+                app_state.selected_items = chosen;
+                app_state.confirm_snapshot = app_state.selected_items.clone();
+                app_state.cache_confirm_groups();
+                app_state.cursor = 0;
+                app_state.scroll_offset = 0;
+                app_state.screen = crate::tui::state::Screen::Confirm { permanent: false };
+            }
+            return EventResult::Continue;
+        }
+        KeyCode::Backspace => requery = query.pop().is_some(),
+        KeyCode::Char(c) if !modifiers.contains(KeyModifiers::CONTROL) => {
+            query.push(c);
+            requery = true;
+        }
+        _ => {}
+    }
+
+    if requery {
+        let query = query.clone();
+        let found = app_state.find_items(&query);
+        if let crate::tui::state::Screen::Finder {
+            ref mut matches,
+            ref mut cursor,
+            ..
+        } = app_state.screen
+        {
+            *matches = found;
+            *cursor = 0;
+        }
+    }
+    EventResult::Continue
+}
+
+fn handle_scan_path_picker_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    _modifiers: KeyModifiers,
+) -> EventResult {
+    use crate::tui::widgets::dir_picker::PickerAction;
+
+    let crate::tui::state::Screen::ScanPathPicker { ref mut picker } = app_state.screen else {
+        return EventResult::Continue;
+    };
+
+    let (path, persist) = match picker.handle_key(key) {
+        PickerAction::None => return EventResult::Continue,
+        PickerAction::Cancel => {
+            app_state.screen = crate::tui::state::Screen::Dashboard;
+            return EventResult::Continue;
+        }
+        PickerAction::Pick(path) => (path, false),
+        PickerAction::PickAndSave(path) => (path, true),
+    };
+
+    let shown = path.display().to_string();
+    app_state.dashboard_message = Some(match app_state.set_scan_path(path, persist) {
+        Ok(()) if persist => format!("Scan path set to {} (saved as default)", shown),
+        Ok(()) => format!("Scan path set to {} for this session", shown),
+        Err(e) => format!("Scan path set to {}, but saving it failed: {}", shown, e),
+    });
+    app_state.screen = crate::tui::state::Screen::Dashboard;
+    EventResult::Continue
+}
+
+/// History screen over every saved session (newest first)
+fn open_history() -> crate::tui::state::Screen {
+    let (sessions, message) = match crate::history::load_sessions() {
+        Ok(sessions) => (sessions, None),
+        Err(e) => (Vec::new(), Some(format!("Failed to load history: {}", e))),
+    };
+    crate::tui::state::Screen::History {
+        sessions,
+        filter: String::new(),
+        editing_filter: false,
+        cursor: 0,
+        open_session: None,
+        record_cursor: 0,
+        message,
+    }
+}
+
+fn handle_history_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+) -> EventResult {
+    let crate::tui::state::Screen::History {
+        ref sessions,
+        ref mut filter,
+        ref mut editing_filter,
+        ref mut cursor,
+        ref mut open_session,
+        ref mut record_cursor,
+        ref mut message,
+    } = app_state.screen
+    else {
+        return EventResult::Continue;
+    };
+
+    if *editing_filter {
+        match key {
+            KeyCode::Esc | KeyCode::Enter => *editing_filter = false,
+            KeyCode::Backspace => {
+                filter.pop();
+            }
+            KeyCode::Char(c) if !modifiers.contains(KeyModifiers::CONTROL) => filter.push(c),
+            _ => return EventResult::Continue,
+        }
+        *cursor = 0;
+        *record_cursor = 0;
+        return EventResult::Continue;
+    }
+
+    let visible: Vec<usize> = (0..sessions.len())
+        .filter(|&i| sessions[i].matches(filter))
+        .collect();
+    let selected = match *open_session {
+        Some(index) => sessions.get(index),
+        None => visible.get(*cursor).and_then(|&i| sessions.get(i)),
+    };
+
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') => {
+            if open_session.is_some() {
+                *open_session = None;
+            } else {
+                app_state.screen = crate::tui::state::Screen::Dashboard;
+            }
+        }
+        KeyCode::Char('/') => {
+            *editing_filter = true;
+            *message = None;
+        }
+        KeyCode::Up => {
+            if open_session.is_some() {
+                *record_cursor = record_cursor.saturating_sub(1);
+            } else {
+                *cursor = cursor.saturating_sub(1);
+            }
+        }
+        KeyCode::Down => {
+            if let Some(session) = selected.filter(|_| open_session.is_some()) {
+                let len = session
+                    .log
+                    .records
+                    .iter()
+                    .filter(|r| r.matches(filter))
+                    .count();
+                if *record_cursor + 1 < len {
+                    *record_cursor += 1;
+                }
+            } else if *cursor + 1 < visible.len() {
+                *cursor += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if let Some(session) = selected {
+                if open_session.is_some() {
+                    // Show where the record was deleted from
+                    if let Some(record) = session
+                        .log
+                        .records
+                        .iter()
+                        .filter(|r| r.matches(filter))
+                        .nth(*record_cursor)
+                    {
+                        let path = std::path::PathBuf::from(&record.path);
+                        if let Some(parent) = path.parent().filter(|p| p.exists()) {
+                            open_file(parent);
+                        }
+                    }
+                } else {
+                    *open_session = visible.get(*cursor).copied();
+                    *record_cursor = 0;
+                }
+            }
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            if let Some(session) = selected {
+                if session.log.restorable_count() == 0 {
+                    *message = Some(
+                        "Nothing to restore - this session only has permanent or failed deletions"
+                            .to_string(),
+                    );
+                } else {
+                    app_state.screen = crate::tui::state::Screen::Restore {
+                        progress: None,
+                        result: None,
+                        restore_all_bin: false,
+                        log_path: Some(session.path.clone()),
+                    };
+                }
+            }
+        }
+        KeyCode::Char('c') | KeyCode::Char('C') | KeyCode::Char('j') | KeyCode::Char('J') => {
+            if let Some(session) = selected {
+                let format = if matches!(key, KeyCode::Char('c') | KeyCode::Char('C')) {
+                    crate::history::ExportFormat::Csv
+                } else {
+                    crate::history::ExportFormat::Json
+                };
+                *message = Some(match crate::history::export_log(&session.log, format) {
+                    Ok(path) => format!("Exported to {}", path.display()),
+                    Err(e) => format!("Export failed: {}", e),
+                });
+            }
+        }
+        _ => {}
+    }
+    EventResult::Continue
+}
+
+/// Open the Search screen along with the index database it queries until Esc
+fn open_search(app_state: &mut AppState) {
+    let message = match crate::scan_cache::ScanCache::open() {
+        Ok(cache) => {
+            app_state.search_cache = Some(cache);
+            None
+        }
+        Err(e) => {
+            app_state.search_cache = None;
+            Some(format!("Search index unavailable: {}", e))
+        }
+    };
+    app_state.screen = crate::tui::state::Screen::Search {
+        query: String::new(),
+        results: Vec::new(),
+        cursor: 0,
+        message,
+        index_receiver: None,
+    };
+}
+
+/// Search limit - the index is sorted largest first, so the top matches are the useful ones
+const SEARCH_RESULT_LIMIT: usize = 200;
+
+fn handle_search_event(
+    app_state: &mut AppState,
+    key: KeyCode,
+    modifiers: KeyModifiers,
+) -> EventResult {
+    let scan_path = app_state.scan_path.clone();
+    let config = app_state.config.clone();
+
+    let crate::tui::state::Screen::Search {
+        ref mut query,
+        ref mut results,
+        ref mut cursor,
+        ref mut message,
+        ref mut index_receiver,
+    } = app_state.screen
+    else {
+        return EventResult::Continue;
+    };
+
+    let mut requery = false;
+    let mut to_confirm = None;
+    match key {
+        KeyCode::Esc => {
+            app_state.search_cache = None;
+            app_state.screen = crate::tui::state::Screen::Dashboard;
+            return EventResult::Continue;
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') if modifiers.contains(KeyModifiers::CONTROL) => {
+            if index_receiver.is_none() {
+                // Rebuild in the background; run_loop polls the receiver
+                let (tx, rx) = std::sync::mpsc::channel();
+                *index_receiver = Some(rx);
+                *message = None;
+                std::thread::spawn(move || {
+                    let result = crate::scan_cache::ScanCache::open()
+                        .and_then(|mut cache| {
+                            crate::scan_cache::build_search_index(
+                                &scan_path,
+                                &config,
+                                &mut cache,
+                                |_| {},
+                            )
+                        })
+                        .map_err(|e| e.to_string());
+                    let _ = tx.send(result);
+                });
+            }
+        }
+        KeyCode::Char(c) if !modifiers.contains(KeyModifiers::CONTROL) => {
+            query.push(c);
+            requery = true;
+        }
+        KeyCode::Backspace => {
+            query.pop();
+            requery = true;
+        }
+        KeyCode::Up => {
+            *cursor = cursor.saturating_sub(1);
+        }
+        KeyCode::Down => {
+            if *cursor + 1 < results.len() {
+                *cursor += 1;
+            }
+        }
+        KeyCode::Enter => {
+            if let Some(file) = results.get(*cursor) {
+                open_file(&file.path);
+            }
+        }
+        KeyCode::Delete => {
+            if let Some(file) = results.get(*cursor) {
+                if file.path.exists() {
+                    to_confirm = Some((file.path.clone(), file.size));
+                } else {
+                    // Deleted outside wole since the index was built
+                    if let Some(cache) = app_state.search_cache.as_mut() {
+                        let _ = cache.remove_from_index(&file.path);
+                    }
+                    *message = Some(format!("{} no longer exists", file.path.display()));
+                    results.remove(*cursor);
+                    *cursor = (*cursor).min(results.len().saturating_sub(1));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if requery {
+        *cursor = 0;
+        *message = None;
+        *results = if query.trim().is_empty() {
+            Vec::new()
+        } else {
+            match app_state
+                .search_cache
+                .as_ref()
+                .map(|cache| cache.search_index(query, SEARCH_RESULT_LIMIT))
+            {
+                Some(Ok(found)) => found,
+                Some(Err(e)) => {
+                    *message = Some(format!("Search failed: {}", e));
+                    Vec::new()
+                }
+                None => Vec::new(),
+            }
+        };
+    }
+
+    // Deletes go through the confirm screen like any cleanup; cancel comes back here
+    if let Some((path, size)) = to_confirm {
+        app_state.clean_search_result(path, size);
+    }
+
+    EventResult::Continue
+}
+
+fn handle_optimize_click(app_state: &mut AppState, row: u16, _col: u16) -> EventResult {
+    if let crate::tui::state::Screen::Optimize {
+        ref mut cursor,
+        ref mut selected,
+        ref results,
+        ref running,
+        ref mut message,
+        admin,
+    } = app_state.screen
+    {
+        if *running {
+            // Can't interact while running
+            return EventResult::Continue;
+        }
+
+        let header_height = LOGO_WITH_TAGLINE_HEIGHT;
 
         // Calculate approximate positions
         // Title: 1 line, spacing: 1 line, then list starts
@@ -2892,14 +4736,14 @@ fn handle_optimize_click(app_state: &mut AppState, row: u16, _col: u16) -> Event
 
             // Each item is 2 lines, so divide by 2
             let clicked_index = (clicked_row_in_list / 2) as usize;
-            const OPTIONS_COUNT: usize = 10;
+            let operations = &crate::optimize::OPERATIONS;
 
-            if clicked_index < OPTIONS_COUNT {
+            if clicked_index < operations.len() {
                 *cursor = clicked_index;
-                // Toggle selection on click
+                // Toggle selection on click (operations that can't run stay unselected)
                 if selected.contains(&clicked_index) {
                     selected.remove(&clicked_index);
-                } else {
+                } else if operations[clicked_index].available(admin) {
                     selected.insert(clicked_index);
                 }
                 // Clear any messages when interacting
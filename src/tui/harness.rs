@@ -0,0 +1,151 @@
+//! Headless TUI harness for scripted end-to-end tests
+//!
+//! Runs the real event loop ([`crate::tui::run_loop`]) against a ratatui `TestBackend`
+//! with a queue of scripted key presses, so flows like
+//! scan → select → confirm → clean → success can be tested without a terminal.
+//!
+//! ```no_run
+//! use crossterm::event::KeyCode;
+//! use wole::tui::harness::TuiHarness;
+//!
+//! let mut harness = TuiHarness::new(120, 40);
+//! harness.press(KeyCode::Enter).press(KeyCode::Char('c'));
+//! harness.run().unwrap();
+//! assert!(harness.screen_text().contains("Confirm"));
+//! ```
+
+use crate::tui::input::EventSource;
+use crate::tui::screens::render;
+use crate::tui::state::AppState;
+use anyhow::{anyhow, Result};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, Terminal};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Scripted input for the event loop.
+///
+/// Events are only delivered when the loop is idle (waiting for input). While a scan
+/// is running, `poll_background` returns nothing, so a key scripted after "start scan"
+/// is always seen by the screen that follows the scan rather than racing it.
+#[derive(Debug, Default)]
+pub struct ScriptedEvents {
+    queue: VecDeque<Event>,
+}
+
+impl ScriptedEvents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, event: Event) {
+        self.queue.push_back(event);
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.queue.len()
+    }
+}
+
+impl EventSource for ScriptedEvents {
+    fn poll(&mut self, _timeout: Duration) -> Result<bool> {
+        Ok(!self.queue.is_empty())
+    }
+
+    fn read(&mut self) -> Result<Event> {
+        self.queue
+            .pop_front()
+            .ok_or_else(|| anyhow!("scripted input exhausted"))
+    }
+
+    fn poll_background(&mut self) -> Option<Event> {
+        None
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+/// A TUI instance rendered into an in-memory buffer
+pub struct TuiHarness {
+    terminal: Terminal<TestBackend>,
+    events: ScriptedEvents,
+    state: AppState,
+}
+
+impl TuiHarness {
+    /// Create a harness with a fresh `AppState` (loads config like the real TUI)
+    pub fn new(width: u16, height: u16) -> Self {
+        Self::with_state(width, height, AppState::new())
+    }
+
+    /// Create a harness around a prepared state (e.g. custom scan path or categories)
+    pub fn with_state(width: u16, height: u16, state: AppState) -> Self {
+        let terminal =
+            Terminal::new(TestBackend::new(width, height)).expect("TestBackend never fails");
+        Self {
+            terminal,
+            events: ScriptedEvents::new(),
+            state,
+        }
+    }
+
+    /// Queue a key press
+    pub fn press(&mut self, code: KeyCode) -> &mut Self {
+        self.press_with(code, KeyModifiers::empty())
+    }
+
+    /// Queue a key press with modifiers (e.g. Ctrl)
+    pub fn press_with(&mut self, code: KeyCode, modifiers: KeyModifiers) -> &mut Self {
+        self.events.push(Event::Key(KeyEvent::new(code, modifiers)));
+        self
+    }
+
+    /// Queue each character of `text` as a key press (for search boxes, config edits)
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for c in text.chars() {
+            self.press(KeyCode::Char(c));
+        }
+        self
+    }
+
+    /// Run the event loop until the script is consumed (or the app quits)
+    pub fn run(&mut self) -> Result<()> {
+        crate::tui::run_loop(&mut self.terminal, &mut self.events, &mut self.state)
+    }
+
+    /// Number of scripted events not yet consumed (non-zero after an early quit)
+    pub fn pending_events(&self) -> usize {
+        self.events.remaining()
+    }
+
+    /// Draw the current state once (useful before any input is queued)
+    pub fn render(&mut self) -> Result<()> {
+        let state = &mut self.state;
+        self.terminal.draw(|f| render(f, state))?;
+        Ok(())
+    }
+
+    /// The last rendered frame as plain text, one line per terminal row
+    pub fn screen_text(&self) -> String {
+        let buffer = self.terminal.backend().buffer();
+        let width = buffer.area.width.max(1) as usize;
+        let mut text = String::new();
+        for (i, cell) in buffer.content().iter().enumerate() {
+            text.push_str(cell.symbol());
+            if (i + 1) % width == 0 {
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    pub fn state(&self) -> &AppState {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut AppState {
+        &mut self.state
+    }
+}
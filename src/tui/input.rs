@@ -0,0 +1,47 @@
+//! Input sources for the TUI event loop
+//!
+//! The event loop reads terminal input through [`EventSource`] instead of calling
+//! crossterm directly, so it can be driven by a real terminal or by scripted input
+//! in tests (see [`crate::tui::harness`]).
+
+use anyhow::Result;
+use crossterm::event::{self, Event};
+use std::time::Duration;
+
+/// A source of terminal events for the TUI event loop
+pub trait EventSource {
+    /// Wait up to `timeout` for an event. Returns true if one is ready to read.
+    fn poll(&mut self, timeout: Duration) -> Result<bool>;
+
+    /// Read the next event (call after `poll` returned true)
+    fn read(&mut self) -> Result<Event>;
+
+    /// Non-blocking check used while background work (scans) is running,
+    /// so the user can cancel with Esc. Returns the next event if one is ready.
+    fn poll_background(&mut self) -> Option<Event> {
+        if self.poll(Duration::from_millis(0)).unwrap_or(false) {
+            self.read().ok()
+        } else {
+            None
+        }
+    }
+
+    /// True when no more events will ever arrive (scripted input ran out).
+    /// The event loop exits once this is true and no work is pending.
+    fn is_exhausted(&self) -> bool {
+        false
+    }
+}
+
+/// Live keyboard/mouse input from the terminal via crossterm
+pub struct CrosstermEvents;
+
+impl EventSource for CrosstermEvents {
+    fn poll(&mut self, timeout: Duration) -> Result<bool> {
+        Ok(event::poll(timeout)?)
+    }
+
+    fn read(&mut self) -> Result<Event> {
+        Ok(event::read()?)
+    }
+}
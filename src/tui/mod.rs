@@ -3,24 +3,31 @@
 //! Provides a full-screen terminal UI using Ratatui for interactive file cleanup
 
 pub mod events;
+pub mod harness;
+pub mod input;
 pub mod screens;
+pub mod session;
 pub mod state;
 pub mod theme;
 pub mod widgets;
 
 use anyhow::{anyhow, Context, Result};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyEventKind},
+    event::{DisableMouseCapture, EnableMouseCapture, Event, KeyEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{backend::CrosstermBackend, Terminal};
+use ratatui::{
+    backend::{Backend, CrosstermBackend},
+    Terminal,
+};
 use std::io::stdout;
 use std::path::PathBuf;
 use std::sync::mpsc;
 use std::time::Duration;
 
 use self::events::{handle_event, handle_mouse_event};
+use self::input::{CrosstermEvents, EventSource};
 use self::screens::render;
 use self::state::AppState;
 use crate::cleaner;
@@ -43,7 +50,57 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
 
     // Initialize app state (use provided or create new)
     let mut app_state = initial_state.unwrap_or_default();
-    let mut scan_pending = false;
+    app_state.load_resumable_scan();
+    if app_state.scan_results.is_none() {
+        app_state.saved_session = session::SavedSession::load();
+    }
+
+    let result = run_loop(&mut terminal, &mut CrosstermEvents, &mut app_state);
+
+    // Keep the review for next time (or drop the old one once everything was cleaned)
+    match session::SavedSession::capture(&app_state) {
+        Some(saved) => {
+            let _ = saved.save();
+        }
+        None if app_state.scan_results.is_some() => session::SavedSession::discard(),
+        None => {}
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    // Anything left is picked up on the next run, but finish it now if we can
+    let pending = cleaner::fast_delete_progress();
+    if pending.folders > 0 {
+        println!(
+            "Finishing background deletes ({} folder(s), {})...",
+            pending.folders,
+            bytesize::to_string(pending.bytes, false)
+        );
+        cleaner::wait_for_fast_deletes();
+    }
+
+    result
+}
+
+/// Drive the TUI until the user quits.
+///
+/// Generic over the backend and the input source so the same loop runs against a real
+/// terminal or a headless `TestBackend` with scripted keys (see [`harness`]). When the
+/// event source reports it is exhausted, the loop returns once no work is pending.
+pub fn run_loop<B: Backend, E: EventSource>(
+    terminal: &mut Terminal<B>,
+    events: &mut E,
+    app_state: &mut AppState,
+) -> Result<()> {
+    // A state prepared on the Scanning screen (the zero-argument menu) starts right away
+    let mut scan_pending = matches!(app_state.screen, crate::tui::state::Screen::Scanning { .. });
     let mut clean_pending = false;
     let mut last_tick_update = std::time::Instant::now();
 
@@ -69,6 +126,12 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
             app_state.tick = app_state.tick.wrapping_add(1);
         }
 
+        // Free-space estimate for the Confirm screen runs in the background
+        app_state.poll_free_estimate();
+        app_state.poll_shadow_check();
+        app_state.poll_compress();
+        app_state.poll_breakdown();
+
         // Auto-refresh Status screen every 2 seconds (using background thread)
         if let crate::tui::state::Screen::Status {
             ref mut status,
@@ -91,6 +154,23 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                         *status_receiver = None;
                         match result {
                             Ok(new_status) => {
+                                // Low memory may trigger an automatic standby-list trim
+                                let available_bytes =
+                                    (new_status.memory.available_gb * 1024.0 * 1024.0 * 1024.0)
+                                        as u64;
+                                app_state
+                                    .standby_trim
+                                    .observe(&app_state.config.optimize, available_bytes);
+                                app_state.leak_watch.observe(
+                                    &new_status.all_processes,
+                                    app_state.config.optimize.leak_threshold_mb * 1024 * 1024,
+                                );
+                                for alert in app_state.temp_watch.record(
+                                    &new_status.temperature_sensors,
+                                    &app_state.config.temperature,
+                                ) {
+                                    app_state.toasts.warn(alert);
+                                }
                                 **status = new_status;
                                 *last_refresh = std::time::Instant::now();
                             }
@@ -121,13 +201,38 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
             }
         }
 
-        terminal.draw(|f| render(f, &mut app_state))?;
+        // Pick up a finished background search index rebuild
+        if let crate::tui::state::Screen::Search {
+            ref mut message,
+            ref mut index_receiver,
+            ..
+        } = app_state.screen
+        {
+            if let Some(ref receiver) = index_receiver {
+                match receiver.try_recv() {
+                    Ok(result) => {
+                        *index_receiver = None;
+                        *message = Some(match result {
+                            Ok(count) => format!("Indexed {} files", count),
+                            Err(e) => format!("Index rebuild failed: {}", e),
+                        });
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => {}
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        *index_receiver = None;
+                    }
+                }
+            }
+        }
+
+        terminal.draw(|f| render(f, app_state))?;
 
         // Handle pending restore
         if let crate::tui::state::Screen::Restore {
             progress: None,
             result: None,
             restore_all_bin,
+            log_path,
         } = &app_state.screen
         {
             // Initialize restore progress
@@ -136,6 +241,9 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                 crate::trash_ops::list()
                     .map(|items| items.len())
                     .map_err(|e| anyhow::anyhow!("Failed to list Recycle Bin: {}", e))
+            } else if let Some(path) = log_path {
+                // Session picked in the History screen
+                crate::history::load_log(path).map(|log| log.restorable_count())
             } else {
                 // For restore from last deletion, get count from history
                 restore::get_restore_count()
@@ -155,11 +263,12 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                         }),
                         result: None,
                         restore_all_bin: *restore_all_bin,
+                        log_path: log_path.clone(),
                     };
                 }
                 Err(e) => {
                     // On error, show error message and return to dashboard
-                    eprintln!("Restore error: {}", e);
+                    app_state.toasts.error(format!("Restore failed: {}", e));
                     app_state.screen = crate::tui::state::Screen::Dashboard;
                 }
             }
@@ -171,14 +280,16 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
             ref mut progress,
             result: None,
             restore_all_bin,
+            ref log_path,
         } = app_state.screen
         {
             if progress.is_some() {
+                let log_path = log_path.clone();
                 // Perform restore operation with progress updates
                 let result = if restore_all_bin {
-                    perform_restore_all_bin(&mut app_state, &mut terminal)
+                    perform_restore_all_bin(app_state, terminal, events)
                 } else {
-                    perform_restore(&mut app_state, &mut terminal)
+                    perform_restore(app_state, terminal, log_path.as_deref())
                 };
 
                 match result {
@@ -191,13 +302,20 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                                 errors: result.errors,
                                 not_found: result.not_found,
                                 error_reasons: result.error_reasons,
+                                cancelled: result.cancelled,
+                                mismatches: result
+                                    .mismatches
+                                    .iter()
+                                    .map(|mismatch| mismatch.describe())
+                                    .collect(),
                             }),
                             restore_all_bin,
+                            log_path,
                         };
                     }
                     Err(e) => {
                         // On error, show error message and return to dashboard
-                        eprintln!("Restore error: {}", e);
+                        app_state.toasts.error(format!("Restore failed: {}", e));
                         app_state.screen = crate::tui::state::Screen::Dashboard;
                     }
                 }
@@ -215,6 +333,15 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                 continue;
             }
 
+            // A new scan replaces whatever was parked under its view
+            app_state.parked_screens.remove(
+                if app_state.pending_action == crate::tui::state::PendingAction::Analyze {
+                    &crate::tui::state::SidebarView::Insights
+                } else {
+                    &crate::tui::state::SidebarView::Results
+                },
+            );
+
             // Check if scan is already running (we store the receiver in app_state)
             // For now, just start the scan - the main loop will check for completion
 
@@ -249,7 +376,7 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                 }
 
                 // Redraw to show the scanning state before starting scan
-                terminal.draw(|f| render(f, &mut app_state))?;
+                terminal.draw(|f| render(f, app_state))?;
 
                 // Perform disk insights scan in background thread to allow animation
                 use crate::disk_usage::SortBy;
@@ -264,33 +391,40 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                     config.ui.scan_depth_user
                 };
 
-                // Spawn scan in background thread with progress reporting
-                let (tx, rx) = mpsc::channel();
-                let (progress_tx, progress_rx) = mpsc::channel();
+                // Run the scan on the shared engine with progress reporting
                 let scan_path_clone = scan_path.clone();
-
-                // Clone progress_tx for the callback
-                let progress_tx_clone = progress_tx.clone();
-                std::thread::spawn(move || {
-                    use crate::disk_usage::scan_directory_with_progress;
-                    let progress_callback: Option<crate::disk_usage::ProgressCallback> =
-                        Some(Box::new(move |path: &std::path::Path| {
-                            // Send progress update (ignore errors if receiver is dropped)
-                            let _ = progress_tx_clone.send(path.to_path_buf());
-                        }));
-                    let result = scan_directory_with_progress(
-                        &scan_path_clone,
-                        effective_depth,
-                        progress_callback,
-                    );
-                    let _ = tx.send(result);
-                });
+                let use_size_cache = config.cache.enabled;
+                let job = crate::scanner::Engine::global().submit(
+                    crate::cancel::CancellationToken::new(),
+                    move |_, progress_tx| {
+                        use crate::disk_usage::scan_directory_with_progress;
+                        let progress_tx = progress_tx.clone();
+                        let progress_callback: Option<crate::disk_usage::ProgressCallback> =
+                            Some(Box::new(move |path: &std::path::Path| {
+                                // Send progress update (ignore errors if receiver is dropped)
+                                let _ = progress_tx.send(ScanProgressEvent::ReadingFolder {
+                                    path: path.to_path_buf(),
+                                });
+                            }));
+                        // Folder sizes are cached too; without a cache they're just walked
+                        let size_cache = use_size_cache
+                            .then(|| crate::scan_cache::ScanCache::open().ok())
+                            .flatten()
+                            .map(std::sync::Mutex::new);
+                        scan_directory_with_progress(
+                            &scan_path_clone,
+                            effective_depth,
+                            progress_callback,
+                            size_cache.as_ref(),
+                        )
+                    },
+                );
 
                 // Wait for scan result while updating tick and redrawing for animation
                 let mut last_tick_update_scan = std::time::Instant::now();
                 loop {
                     // Check for scan completion
-                    match rx.try_recv() {
+                    match job.try_result() {
                         Ok(Ok(insights)) => {
                             // Check if scan was cancelled
                             if !matches!(
@@ -314,18 +448,22 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                         }
                         Ok(Err(e)) => {
                             // On error, return to dashboard
-                            eprintln!("Disk insights scan error: {}", e);
+                            app_state
+                                .toasts
+                                .error(format!("Disk Insights scan failed: {}", e));
                             app_state.screen = crate::tui::state::Screen::Dashboard;
                             app_state.pending_action = crate::tui::state::PendingAction::None;
                             break;
                         }
                         Err(mpsc::TryRecvError::Empty) => {
                             // Check for progress updates (individual files being read)
-                            while let Ok(file_path) = progress_rx.try_recv() {
-                                if let crate::tui::state::Screen::Scanning { ref mut progress } =
-                                    app_state.screen
+                            for event in job.progress() {
+                                if let (
+                                    ScanProgressEvent::ReadingFolder { path },
+                                    crate::tui::state::Screen::Scanning { progress },
+                                ) = (event, &mut app_state.screen)
                                 {
-                                    progress.current_path = Some(file_path);
+                                    progress.current_path = Some(path);
                                 }
                             }
 
@@ -334,7 +472,7 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                                 app_state.tick = app_state.tick.wrapping_add(1);
                                 last_tick_update_scan = std::time::Instant::now();
                                 // Redraw terminal to show spinner animation and current file
-                                let _ = terminal.draw(|f| render(f, &mut app_state));
+                                let _ = terminal.draw(|f| render(f, app_state));
                             }
 
                             // Check for cancellation
@@ -343,15 +481,16 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                                 crate::tui::state::Screen::Scanning { .. }
                             ) {
                                 // Scan was cancelled, exit loop
+                                job.cancel();
                                 break;
                             }
 
                             // Process events to allow cancellation
                             let mut cancelled = false;
-                            while event::poll(Duration::from_millis(0)).unwrap_or(false) {
-                                if let Ok(Event::Key(key)) = event::read() {
+                            while let Some(event) = events.poll_background() {
+                                if let Event::Key(key) = event {
                                     if key.kind == KeyEventKind::Press {
-                                        handle_event(&mut app_state, key.code, key.modifiers);
+                                        handle_event(app_state, key.code, key.modifiers);
                                         if !matches!(
                                             app_state.screen,
                                             crate::tui::state::Screen::Scanning { .. }
@@ -366,6 +505,7 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
 
                             // Exit outer loop if cancelled
                             if cancelled {
+                                job.cancel();
                                 break;
                             }
 
@@ -374,7 +514,9 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                         }
                         Err(mpsc::TryRecvError::Disconnected) => {
                             // Thread panicked or channel closed unexpectedly
-                            eprintln!("Disk insights scan thread error");
+                            app_state
+                                .toasts
+                                .error("Disk Insights scan stopped unexpectedly");
                             app_state.screen = crate::tui::state::Screen::Dashboard;
                             app_state.pending_action = crate::tui::state::PendingAction::None;
                             break;
@@ -396,14 +538,16 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
             app_state.tick = app_state.tick.wrapping_add(1);
 
             // Redraw to show the scanning state
-            terminal.draw(|f| render(f, &mut app_state))?;
+            terminal.draw(|f| render(f, app_state))?;
 
             // Perform actual scan with progress updates (runs in background, main loop continues)
-            match perform_scan_with_progress(&mut app_state, &mut terminal) {
+            match perform_scan_with_progress(app_state, terminal, events) {
                 Ok(()) => {
                     // Check if scan was cancelled (screen changed during scan)
                     if !matches!(app_state.screen, crate::tui::state::Screen::Scanning { .. }) {
-                        // Scan was cancelled, screen already changed to Dashboard
+                        // Scan was cancelled, screen already changed to Dashboard;
+                        // offer to pick it up where it stopped
+                        app_state.load_resumable_scan();
                         continue;
                     }
 
@@ -450,11 +594,11 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                     }
 
                     // Immediately redraw to show results screen without delay
-                    terminal.draw(|f| render(f, &mut app_state))?;
+                    terminal.draw(|f| render(f, app_state))?;
                 }
                 Err(e) => {
                     // On error, return to dashboard
-                    eprintln!("Scan error: {}", e);
+                    app_state.toasts.error(format!("Scan failed: {}", e));
                     app_state.screen = crate::tui::state::Screen::Dashboard;
                     app_state.pending_action = crate::tui::state::PendingAction::None;
                 }
@@ -473,9 +617,31 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                 continue; // Not in cleaning screen, skip
             }
 
+            // Volumes to check for shadow copy storage once the cleanup is done
+            let cleaned_paths: Vec<PathBuf> = app_state
+                .selected_items
+                .iter()
+                .filter_map(|&index| app_state.all_items.get(index))
+                .map(|item| item.path.clone())
+                .collect();
+
             // Now perform cleanup with real-time updates
-            match perform_cleanup(&mut app_state, permanent_delete, &mut terminal) {
+            let cleanup = perform_cleanup(app_state, permanent_delete, terminal, events);
+            app_state.confirm_back = None;
+            // Search hits that are gone leave the index along with the search session
+            if let Some(mut cache) = app_state.search_cache.take() {
+                for path in cleaned_paths.iter().filter(|path| !path.exists()) {
+                    let _ = cache.remove_from_index(path);
+                }
+            }
+            // A quarantine choice from the Confirm screen only covers this cleanup
+            crate::recycle_bin::set_quarantine_volumes(Vec::new());
+            match cleanup {
                 Ok((cleaned, cleaned_bytes, errors, failed_temp_files)) => {
+                    app_state.recommendations = cleanup_recommendations(app_state);
+                    if !crate::audit::is_enabled() {
+                        app_state.start_shadow_check(cleaned_paths);
+                    }
                     app_state.screen = crate::tui::state::Screen::Success {
                         cleaned,
                         cleaned_bytes,
@@ -485,7 +651,7 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                     app_state.permanent_delete = false; // Reset flag
                 }
                 Err(e) => {
-                    eprintln!("Cleanup error: {}", e);
+                    app_state.toasts.error(format!("Cleanup failed: {}", e));
                     app_state.screen = crate::tui::state::Screen::Results;
                     app_state.permanent_delete = false; // Reset flag
                 }
@@ -494,12 +660,12 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
         }
 
         // Use polling with timeout for animation updates
-        if event::poll(Duration::from_millis(100))? {
+        if events.poll(Duration::from_millis(100))? {
             // Read and handle the first event
-            match event::read()? {
+            match events.read()? {
                 Event::Key(key) => {
                     if key.kind == KeyEventKind::Press {
-                        match handle_event(&mut app_state, key.code, key.modifiers) {
+                        match handle_event(app_state, key.code, key.modifiers) {
                             events::EventResult::Quit => break,
                             events::EventResult::Continue => {
                                 // Check if we need to trigger a scan
@@ -516,20 +682,25 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                         }
                     }
                 }
-                Event::Mouse(mouse) => match handle_mouse_event(&mut app_state, mouse) {
+                Event::Mouse(mouse) => match handle_mouse_event(app_state, mouse) {
                     events::EventResult::Quit => break,
-                    events::EventResult::Continue => {}
+                    events::EventResult::Continue => {
+                        // Sidebar clicks can start a Disk Insights scan
+                        if let crate::tui::state::Screen::Scanning { .. } = app_state.screen {
+                            scan_pending = true;
+                        }
+                    }
                 },
                 _ => {}
             }
 
             // Drain any other pending events to prevent lag (smooth scrolling)
             let mut quit = false;
-            while event::poll(Duration::from_millis(0))? {
-                match event::read()? {
+            while events.poll(Duration::from_millis(0))? {
+                match events.read()? {
                     Event::Key(key) => {
                         if key.kind == KeyEventKind::Press {
-                            match handle_event(&mut app_state, key.code, key.modifiers) {
+                            match handle_event(app_state, key.code, key.modifiers) {
                                 events::EventResult::Quit => {
                                     quit = true;
                                     break;
@@ -549,7 +720,7 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
                             }
                         }
                     }
-                    Event::Mouse(mouse) => match handle_mouse_event(&mut app_state, mouse) {
+                    Event::Mouse(mouse) => match handle_mouse_event(app_state, mouse) {
                         events::EventResult::Quit => {
                             quit = true;
                             break;
@@ -562,18 +733,11 @@ pub fn run(initial_state: Option<AppState>) -> Result<()> {
             if quit {
                 break;
             }
+        } else if events.is_exhausted() {
+            break;
         }
     }
 
-    // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
-
     Ok(())
 }
 
@@ -610,12 +774,18 @@ fn can_reuse_scan_results(app_state: &AppState) -> bool {
 }
 
 /// Perform a scan with progress updates
-fn perform_scan_with_progress(
+fn perform_scan_with_progress<B: Backend, E: EventSource>(
     app_state: &mut AppState,
-    terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+    terminal: &mut Terminal<B>,
+    events: &mut E,
 ) -> anyhow::Result<()> {
+    // This scan replaces any interrupted one and the saved review
+    let resume = std::mem::take(&mut app_state.resume_scan);
+    app_state.resumable_scan = None;
+    app_state.saved_session = None;
+
     // Check if we can reuse existing scan results
-    if can_reuse_scan_results(app_state) {
+    if !resume && can_reuse_scan_results(app_state) {
         // We have existing results that match, just update the progress display
         // and proceed to process them
         let enabled_categories: Vec<String> = app_state
@@ -665,6 +835,30 @@ fn perform_scan_with_progress(
                     results.windows_update.size_bytes,
                 ),
                 "Event Logs" => (results.event_logs.items, results.event_logs.size_bytes),
+                "Thumbnail Cache" => (results.thumbnails.items, results.thumbnails.size_bytes),
+                "Crash Dumps" => (results.crash_dumps.items, results.crash_dumps.size_bytes),
+                "Log Files" => (results.logs.items, results.logs.size_bytes),
+                "Game Caches" => (results.game_caches.items, results.game_caches.size_bytes),
+                "Toolchain Caches" => (
+                    results.toolchain_caches.items,
+                    results.toolchain_caches.size_bytes,
+                ),
+                "Python Environments" => {
+                    (results.python_envs.items, results.python_envs.size_bytes)
+                }
+                "ML Models" => (results.ml_models.items, results.ml_models.size_bytes),
+                "Email Data" => (results.email.items, results.email.size_bytes),
+                "Creative App Caches" => (
+                    results.creative_caches.items,
+                    results.creative_caches.size_bytes,
+                ),
+                "Device Backups" => (
+                    results.device_backups.items,
+                    results.device_backups.size_bytes,
+                ),
+                "Ignored Files (git)" => {
+                    (results.git_ignored.items, results.git_ignored.size_bytes)
+                }
                 _ => (0, 0),
             };
 
@@ -722,6 +916,17 @@ fn perform_scan_with_progress(
     let mut duplicates = false;
     let mut windows_update = false;
     let mut event_logs = false;
+    let mut thumbnails = false;
+    let mut crash_dumps = false;
+    let mut logs = false;
+    let mut game_caches = false;
+    let mut toolchain_caches = false;
+    let mut python_envs = false;
+    let mut ml_models = false;
+    let mut email = false;
+    let mut creative_caches = false;
+    let mut device_backups = false;
+    let mut git_ignored = false;
 
     for cat in &app_state.categories {
         match cat.name.as_str() {
@@ -740,6 +945,17 @@ fn perform_scan_with_progress(
             "Duplicates" => duplicates = cat.enabled,
             "Windows Update" => windows_update = cat.enabled,
             "Event Logs" => event_logs = cat.enabled,
+            "Thumbnail Cache" => thumbnails = cat.enabled,
+            "Crash Dumps" => crash_dumps = cat.enabled,
+            "Log Files" => logs = cat.enabled,
+            "Game Caches" => game_caches = cat.enabled,
+            "Toolchain Caches" => toolchain_caches = cat.enabled,
+            "Python Environments" => python_envs = cat.enabled,
+            "ML Models" => ml_models = cat.enabled,
+            "Email Data" => email = cat.enabled,
+            "Creative App Caches" => creative_caches = cat.enabled,
+            "Device Backups" => device_backups = cat.enabled,
+            "Ignored Files (git)" => git_ignored = cat.enabled,
             _ => {}
         }
     }
@@ -750,6 +966,9 @@ fn perform_scan_with_progress(
     // Use config values for thresholds
     let min_size_bytes = config.thresholds.min_size_mb * 1024 * 1024;
 
+    // [paths] scope = "all-users" only applies when running elevated
+    let scope = crate::users::resolve_scope(None, &config).unwrap_or_default();
+
     let options = ScanOptions {
         cache,
         app_cache,
@@ -766,9 +985,21 @@ fn perform_scan_with_progress(
         duplicates,
         windows_update,
         event_logs,
+        thumbnails,
+        crash_dumps,
+        logs,
+        game_caches,
+        toolchain_caches,
+        python_envs,
+        ml_models,
+        email,
+        creative_caches,
+        device_backups,
+        git_ignored,
         project_age_days: config.thresholds.project_age_days,
         min_age_days: config.thresholds.min_age_days,
         min_size_bytes,
+        scope,
     };
 
     let mut first_scan_detected = false;
@@ -799,6 +1030,29 @@ fn perform_scan_with_progress(
         }
     }
 
+    if resume {
+        if let crate::tui::state::Screen::Scanning { ref mut progress } = app_state.screen {
+            progress.notice = Some(
+                "Resuming the previous scan: finished categories are not rescanned".to_string(),
+            );
+        }
+    }
+
+    // Network shares: measure latency and explain where deleted items go
+    if let Some((notice, latency)) = crate::network::scan_notice(&app_state.scan_path) {
+        if let crate::tui::state::Screen::Scanning { ref mut progress } = app_state.screen {
+            progress.latency = Some(latency);
+            progress.notice.get_or_insert(notice);
+        }
+    }
+
+    // Walkers and hashing adapt to the drive; show which strategy was picked
+    let strategy =
+        crate::drive::ScanStrategy::for_path(&app_state.scan_path, &app_state.config).describe();
+    if let crate::tui::state::Screen::Scanning { ref mut progress } = app_state.screen {
+        progress.strategy = Some(strategy);
+    }
+
     // Update progress incrementally before scan (simulated progress)
     // Simulate progress by updating each category incrementally
     for (idx, cat_name) in enabled_categories.iter().enumerate() {
@@ -809,8 +1063,8 @@ fn perform_scan_with_progress(
         }
 
         // Process any pending events (non-blocking) to allow cancellation
-        while event::poll(Duration::from_millis(0)).unwrap_or(false) {
-            if let Ok(Event::Key(key)) = event::read() {
+        while let Some(event) = events.poll_background() {
+            if let Event::Key(key) = event {
                 if key.kind == KeyEventKind::Press {
                     handle_event(app_state, key.code, key.modifiers);
                     // Check again if screen changed
@@ -853,23 +1107,24 @@ fn perform_scan_with_progress(
     let scan_options = options.clone();
     let scan_config = config.clone();
     let use_cache = scan_config.cache.enabled;
+    let cancel = crate::cancel::CancellationToken::new();
+    app_state.scan_cancel = cancel.clone();
 
-    let (result_tx, result_rx) = std::sync::mpsc::channel();
-    let (progress_tx, progress_rx) = std::sync::mpsc::channel();
-    let _scan_handle = std::thread::spawn(move || {
+    let job = scanner::Engine::global().submit(cancel.clone(), move |cancel, progress_tx| {
         let mut scan_cache = if use_cache {
             ScanCache::open().ok()
         } else {
             None
         };
-        let result = scanner::scan_all_with_progress(
+        scanner::scan_all_with_progress(
             &scan_path,
             scan_options,
             &scan_config,
-            &progress_tx,
+            progress_tx,
             scan_cache.as_mut(),
-        );
-        let _ = result_tx.send(result);
+            cancel,
+            resume,
+        )
     });
 
     // Wait for scan to complete, manually updating tick and redrawing for spinner animation
@@ -933,11 +1188,29 @@ fn perform_scan_with_progress(
                         }
                     }
                 }
+                ScanProgressEvent::CategorySlow {
+                    category,
+                    elapsed_secs,
+                    control,
+                } => {
+                    progress.slow = Some(crate::tui::state::SlowCategory {
+                        name: category,
+                        elapsed_secs,
+                        control,
+                    });
+                }
                 ScanProgressEvent::CategoryFinished {
                     category,
                     items,
                     size_bytes,
                 } => {
+                    if progress
+                        .slow
+                        .as_ref()
+                        .is_some_and(|slow| slow.name == category)
+                    {
+                        progress.slow = None;
+                    }
                     progress.current_category = category.clone();
                     if let Some(cat_progress) = progress
                         .category_progress
@@ -964,7 +1237,7 @@ fn perform_scan_with_progress(
 
     let results = loop {
         let mut progress_updated = false;
-        while let Ok(event) = progress_rx.try_recv() {
+        for event in job.progress() {
             apply_progress_event(event, app_state);
             progress_updated = true;
         }
@@ -973,12 +1246,17 @@ fn perform_scan_with_progress(
             last_progress_draw = std::time::Instant::now();
         }
 
-        match result_rx.try_recv() {
+        match job.try_result() {
             Ok(Ok(results)) => break results,
+            Ok(Err(e)) if crate::cancel::is_cancelled(&e) => return Ok(()),
             Ok(Err(e)) => return Err(e),
             Err(std::sync::mpsc::TryRecvError::Empty) => {
-                // Scan still in progress, check for cancellation
-                if !matches!(app_state.screen, crate::tui::state::Screen::Scanning { .. }) {
+                // Scan still in progress, check for cancellation (Esc cancels the token;
+                // any other way off the Scanning screen stops the scan too)
+                if cancel.is_cancelled()
+                    || !matches!(app_state.screen, crate::tui::state::Screen::Scanning { .. })
+                {
+                    cancel.cancel();
                     return Ok(());
                 }
 
@@ -986,20 +1264,22 @@ fn perform_scan_with_progress(
                 if last_tick_update.elapsed().as_millis() >= 100 {
                     app_state.tick = app_state.tick.wrapping_add(1);
                     last_tick_update = std::time::Instant::now();
+                    poll_temperatures(app_state);
                     // Redraw terminal to show spinner animation
                     let _ = terminal.draw(|f| render(f, app_state));
                     last_progress_draw = last_tick_update;
                 }
 
                 // Process events to allow cancellation
-                while event::poll(Duration::from_millis(0)).unwrap_or(false) {
-                    if let Ok(Event::Key(key)) = event::read() {
+                while let Some(event) = events.poll_background() {
+                    if let Event::Key(key) = event {
                         if key.kind == KeyEventKind::Press {
                             handle_event(app_state, key.code, key.modifiers);
                             if !matches!(
                                 app_state.screen,
                                 crate::tui::state::Screen::Scanning { .. }
                             ) {
+                                cancel.cancel();
                                 return Ok(());
                             }
                         }
@@ -1014,7 +1294,7 @@ fn perform_scan_with_progress(
         }
     };
 
-    while let Ok(event) = progress_rx.try_recv() {
+    for event in job.progress() {
         apply_progress_event(event, app_state);
     }
 
@@ -1096,6 +1376,13 @@ fn perform_scan_with_progress(
     Ok(())
 }
 
+/// Toast when a sensor goes critical during a long scan or cleanup
+fn poll_temperatures(app_state: &mut AppState) {
+    for alert in app_state.temp_watch.poll(&app_state.config.temperature) {
+        app_state.toasts.warn(alert);
+    }
+}
+
 fn empty_batch_result() -> cleaner::BatchDeleteResult {
     cleaner::BatchDeleteResult {
         success_count: 0,
@@ -1104,22 +1391,34 @@ fn empty_batch_result() -> cleaner::BatchDeleteResult {
         skipped_paths: Vec::new(),
         locked_paths: Vec::new(),
         permission_denied_paths: Vec::new(),
+        protected_paths: Vec::new(),
+        cancelled_paths: Vec::new(),
     }
 }
 
-fn run_batch_delete_with_ui(
+/// Batch delete `paths` on a worker thread while the UI keeps drawing
+///
+/// Keys are handled meanwhile, so stopping the cleanup (which cancels
+/// `app_state.cleanup_cancel`) ends the batch at its next item; the paths it
+/// didn't reach come back as `cancelled_paths`. The worker runs under the
+/// throttle overrides of `category` (an [`crate::api::Category::id`]), if any.
+fn run_batch_delete_with_ui<B: Backend, E: EventSource>(
     app_state: &mut AppState,
-    terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+    terminal: &mut Terminal<B>,
+    events: &mut E,
     paths: Vec<PathBuf>,
     permanent: bool,
+    category: Option<&'static str>,
 ) -> cleaner::BatchDeleteResult {
     if paths.is_empty() {
         return empty_batch_result();
     }
 
     let (tx, rx) = mpsc::channel();
+    let cancel = app_state.cleanup_cancel.clone();
     std::thread::spawn(move || {
-        let result = cleaner::clean_paths_batch(&paths, permanent);
+        let _throttle = category.map(crate::throttle::enter_category);
+        let result = cleaner::clean_paths_batch(&paths, permanent, &cancel);
         let _ = tx.send(result);
     });
 
@@ -1136,6 +1435,14 @@ fn run_batch_delete_with_ui(
             Err(std::sync::mpsc::TryRecvError::Empty) => {}
         }
 
+        while let Some(event) = events.poll_background() {
+            if let Event::Key(key) = event {
+                if key.kind == KeyEventKind::Press {
+                    handle_event(app_state, key.code, key.modifiers);
+                }
+            }
+        }
+
         if !warned && last_tick_update.elapsed().as_secs() >= 5 {
             debug_log::cleaning_log("batch delete still running after 5s");
             warned = true;
@@ -1144,6 +1451,7 @@ fn run_batch_delete_with_ui(
         if last_tick_update.elapsed().as_millis() >= 100 {
             app_state.tick = app_state.tick.wrapping_add(1);
             last_tick_update = std::time::Instant::now();
+            poll_temperatures(app_state);
             let _ = terminal.draw(|f| render(f, app_state));
         }
 
@@ -1151,9 +1459,9 @@ fn run_batch_delete_with_ui(
     }
 }
 
-fn run_delete_with_ui(
+fn run_delete_with_ui<B: Backend>(
     app_state: &mut AppState,
-    terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+    terminal: &mut Terminal<B>,
     path: PathBuf,
     permanent: bool,
 ) -> anyhow::Result<cleaner::DeleteOutcome> {
@@ -1186,6 +1494,7 @@ fn run_delete_with_ui(
         if last_tick_update.elapsed().as_millis() >= 100 {
             app_state.tick = app_state.tick.wrapping_add(1);
             last_tick_update = std::time::Instant::now();
+            poll_temperatures(app_state);
             let _ = terminal.draw(|f| render(f, app_state));
         }
 
@@ -1193,16 +1502,235 @@ fn run_delete_with_ui(
     }
 }
 
+/// "What's next" for the Success screen: what the scan left out or is still listed
+fn cleanup_recommendations(app_state: &AppState) -> Vec<crate::recommend::Recommendation> {
+    use crate::api::Category;
+
+    let by_name = |name: &str| Category::ALL.into_iter().find(|c| c.name() == name);
+    let scanned = app_state
+        .last_scan_categories
+        .as_ref()
+        .map(|names| names.iter().filter_map(|name| by_name(name)).collect());
+
+    let mut remaining: Vec<(Category, usize, u64)> = Vec::new();
+    for item in &app_state.all_items {
+        let Some(category) = by_name(&item.category) else {
+            continue;
+        };
+        match remaining.iter_mut().find(|(c, _, _)| *c == category) {
+            Some((_, items, bytes)) => {
+                *items += 1;
+                *bytes += item.size_bytes;
+            }
+            None => remaining.push((category, 1, item.size_bytes)),
+        }
+    }
+
+    crate::recommend::recommend(&crate::recommend::RecommendInput::gather(
+        scanned, remaining,
+    ))
+}
+
+/// `paths` cut into batches of up to `size` that each hold one category (by
+/// `keys`), so every batch can run under its category's throttle overrides
+fn category_batches<'a>(
+    paths: &'a [PathBuf],
+    keys: &std::collections::HashMap<PathBuf, &'static str>,
+    size: usize,
+) -> Vec<&'a [PathBuf]> {
+    paths
+        .chunk_by(|a, b| keys.get(a) == keys.get(b))
+        .flat_map(|run| run.chunks(size.max(1)))
+        .collect()
+}
+
+/// Merged result of [`run_volume_batches_with_ui`]
+#[derive(Default)]
+struct VolumeBatchOutcome {
+    success_count: usize,
+    error_count: usize,
+    deleted_paths: Vec<PathBuf>,
+    skipped_paths: Vec<PathBuf>,
+    /// Paths not reached because the user stopped the cleanup
+    left: Vec<PathBuf>,
+}
+
+enum VolumeBatchUpdate {
+    Started(PathBuf),
+    Finished(cleaner::BatchDeleteResult),
+    Stopped(Vec<PathBuf>),
+}
+
+/// Batch delete each bucket (one per volume) on up to `workers` threads, while
+/// the UI thread merges their progress and relays pause/stop to them
+///
+/// A worker takes a whole bucket, so a volume never has two deleters; pausing
+/// and stopping take effect between batches, as in the serial path. Batches
+/// run under the throttle overrides of their category, looked up in `keys`.
+/// `base` is (cleaned, errors) before these batches, for the progress counters.
+#[allow(clippy::too_many_arguments)]
+fn run_volume_batches_with_ui<B: Backend, E: EventSource>(
+    app_state: &mut AppState,
+    terminal: &mut Terminal<B>,
+    events: &mut E,
+    buckets: Vec<Vec<PathBuf>>,
+    keys: &std::collections::HashMap<PathBuf, &'static str>,
+    workers: usize,
+    chunk_size: usize,
+    permanent: bool,
+    base: (u64, usize),
+) -> VolumeBatchOutcome {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let total: usize = buckets.iter().map(Vec::len).sum();
+    let volumes = buckets.len();
+    let queue = std::sync::Mutex::new(buckets);
+    let paused = AtomicBool::new(false);
+    let stopped = AtomicBool::new(false);
+    let cancel = app_state.cleanup_cancel.clone();
+    let mut outcome = VolumeBatchOutcome::default();
+
+    std::thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel::<VolumeBatchUpdate>();
+        for _ in 0..workers {
+            let tx = tx.clone();
+            let (queue, paused, stopped, cancel) = (&queue, &paused, &stopped, &cancel);
+            scope.spawn(move || {
+                while let Some(bucket) = queue.lock().ok().and_then(|mut q| q.pop()) {
+                    for chunk in category_batches(&bucket, keys, chunk_size) {
+                        while paused.load(Ordering::Relaxed) && !stopped.load(Ordering::Relaxed) {
+                            std::thread::sleep(Duration::from_millis(50));
+                        }
+                        if stopped.load(Ordering::Relaxed) {
+                            let _ = tx.send(VolumeBatchUpdate::Stopped(chunk.to_vec()));
+                            continue;
+                        }
+                        let _ = tx.send(VolumeBatchUpdate::Started(chunk[0].clone()));
+                        let _throttle = keys
+                            .get(&chunk[0])
+                            .map(|k| crate::throttle::enter_category(k));
+                        let result = cleaner::clean_paths_batch(chunk, permanent, cancel);
+                        let _ = tx.send(VolumeBatchUpdate::Finished(result));
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+            progress.current_category =
+                format!("Batch deleting {} files on {} volumes...", total, volumes);
+        }
+        let mut last_tick_update = std::time::Instant::now();
+        loop {
+            // Pause/stop/background keys, relayed to the workers
+            while let Some(event) = events.poll_background() {
+                if let Event::Key(key) = event {
+                    if key.kind == KeyEventKind::Press {
+                        handle_event(app_state, key.code, key.modifiers);
+                    }
+                }
+            }
+            match app_state.screen {
+                crate::tui::state::Screen::Cleaning { ref progress } => {
+                    paused.store(progress.paused, Ordering::Relaxed);
+                    stopped.store(progress.stop_requested, Ordering::Relaxed);
+                }
+                _ => {
+                    stopped.store(true, Ordering::Relaxed);
+                    cancel.cancel();
+                }
+            }
+
+            match rx.recv_timeout(Duration::from_millis(50)) {
+                Ok(VolumeBatchUpdate::Started(path)) => {
+                    if let crate::tui::state::Screen::Cleaning { ref mut progress } =
+                        app_state.screen
+                    {
+                        progress.current_path = Some(path);
+                    }
+                }
+                Ok(VolumeBatchUpdate::Finished(result)) => {
+                    outcome.success_count += result.success_count;
+                    outcome.error_count += result.error_count;
+                    outcome.deleted_paths.extend(result.deleted_paths);
+                    outcome.skipped_paths.extend(result.skipped_paths);
+                    outcome.left.extend(result.cancelled_paths);
+                    if let crate::tui::state::Screen::Cleaning { ref mut progress } =
+                        app_state.screen
+                    {
+                        progress.cleaned = base.0 + outcome.success_count as u64;
+                        progress.errors = base.1 + outcome.error_count;
+                    }
+                }
+                Ok(VolumeBatchUpdate::Stopped(paths)) => outcome.left.extend(paths),
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+
+            if last_tick_update.elapsed().as_millis() >= 100 {
+                app_state.tick = app_state.tick.wrapping_add(1);
+                last_tick_update = std::time::Instant::now();
+                let _ = terminal.draw(|f| render(f, app_state));
+            }
+        }
+    });
+
+    outcome
+}
+
+/// Handle keys pressed while cleaning and hold the cleanup while it is paused
+///
+/// Called before each item or batch, so a pause or stop never splits a batch and
+/// the deletion history only ever records finished work. Returns false once the
+/// user has stopped the cleanup.
+fn cleanup_may_continue<B: Backend, E: EventSource>(
+    app_state: &mut AppState,
+    terminal: &mut Terminal<B>,
+    events: &mut E,
+) -> bool {
+    loop {
+        while let Some(event) = events.poll_background() {
+            if let Event::Key(key) = event {
+                if key.kind == KeyEventKind::Press {
+                    handle_event(app_state, key.code, key.modifiers);
+                }
+            }
+        }
+
+        let crate::tui::state::Screen::Cleaning { ref progress } = app_state.screen else {
+            return false;
+        };
+        if progress.stop_requested {
+            return false;
+        }
+        if !progress.paused {
+            return true;
+        }
+
+        app_state.tick = app_state.tick.wrapping_add(1);
+        let _ = terminal.draw(|f| render(f, app_state));
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
 /// Perform cleanup of selected items with real-time progress updates
 /// Returns (cleaned_count, cleaned_bytes, error_count, failed_temp_files)
-fn perform_cleanup(
+///
+/// Items the cleanup didn't reach because the user stopped it stay in the list,
+/// still selected; their number is left in `app_state.cleanup_left`.
+fn perform_cleanup<B: Backend, E: EventSource>(
     app_state: &mut AppState,
     permanent: bool,
-    terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+    terminal: &mut Terminal<B>,
+    events: &mut E,
 ) -> anyhow::Result<(u64, u64, usize, Vec<PathBuf>)> {
     use crate::categories;
     use crate::history::DeletionLog;
 
+    // The machine policy may lock permanent deletes
+    let permanent = crate::policy::permanent_allowed(permanent);
+
     // Create deletion log for audit trail
     let mut history = DeletionLog::new();
 
@@ -1236,42 +1764,75 @@ fn perform_cleanup(
         items_to_clean.len()
     ));
 
+    // Audit mode: report what would be cleaned and leave everything in place
+    if crate::audit::is_enabled() {
+        crate::audit::note(&format!(
+            "clean {} selected items",
+            app_state.selected_items.len()
+        ));
+        let bytes = trash_total_bytes
+            + items_to_clean
+                .iter()
+                .map(|(_, _, _, size)| size)
+                .sum::<u64>();
+        return Ok((
+            (trash_items.len() + items_to_clean.len()) as u64,
+            bytes,
+            0,
+            Vec::new(),
+        ));
+    }
+
+    // Paths not reached before the user stopped the cleanup
+    let mut left: std::collections::HashSet<PathBuf> = std::collections::HashSet::new();
+    app_state.cleanup_left = 0;
+    app_state.cleanup_cancel = crate::cancel::CancellationToken::new();
+
     // Handle trash items first (all at once)
     let mut trash_cleaned = 0u64;
     let mut trash_errors = 0usize;
 
-    if !trash_items.is_empty() {
+    if !trash_items.is_empty() && !cleanup_may_continue(app_state, terminal, events) {
+        left.extend(
+            trash_items
+                .iter()
+                .filter_map(|(idx, _)| app_state.all_items.get(*idx))
+                .map(|item| item.path.clone()),
+        );
+    } else if !trash_items.is_empty() {
         if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
             progress.current_category = "Cleaning Trash...".to_string();
             progress.current_path = Some(std::path::PathBuf::from("Recycle Bin"));
         }
         let _ = terminal.draw(|f| render(f, app_state));
 
+        // Only the selected entries are purged; the rest of the bin stays
+        let trash_paths: Vec<(PathBuf, u64)> = trash_items
+            .iter()
+            .filter_map(|(idx, size)| {
+                app_state
+                    .all_items
+                    .get(*idx)
+                    .map(|i| (i.path.clone(), *size))
+            })
+            .collect();
+        let paths: Vec<PathBuf> = trash_paths.iter().map(|(path, _)| path.clone()).collect();
+
         debug_log::cleaning_log("trash clean start");
-        match categories::trash::clean() {
-            Ok(()) => {
-                // All trash items are cleaned
+        match categories::trash::clean_paths(&paths) {
+            Ok(_) => {
                 trash_cleaned = trash_items.len() as u64;
-                // Log trash cleanup success
-                history.log_success(
-                    std::path::Path::new("Recycle Bin"),
-                    trash_total_bytes,
-                    "trash",
-                    true,
-                );
+                for (path, size) in &trash_paths {
+                    history.log_success(path, *size, "trash", true);
+                }
             }
             Err(e) => {
                 // If trash cleaning fails, all trash items failed
                 trash_errors = trash_items.len();
                 debug_log::cleaning_log(&format!("trash clean failed: {}", e));
-                // Log trash cleanup failure
-                history.log_failure(
-                    std::path::Path::new("Recycle Bin"),
-                    trash_total_bytes,
-                    "trash",
-                    true,
-                    &e.to_string(),
-                );
+                for (path, size) in &trash_paths {
+                    history.log_failure(path, *size, "trash", true, &e.to_string());
+                }
             }
         }
         std::thread::sleep(std::time::Duration::from_millis(100));
@@ -1305,8 +1866,27 @@ fn perform_cleanup(
     let mut cache_items: Vec<(usize, std::path::PathBuf, u64)> = Vec::new();
     let mut temp_items: Vec<(usize, std::path::PathBuf, u64)> = Vec::new();
     let mut batch_items: Vec<(usize, std::path::PathBuf, u64)> = Vec::new();
+    let mut thumbnail_items: Vec<(std::path::PathBuf, u64)> = Vec::new();
+    let mut log_items: Vec<(std::path::PathBuf, u64)> = Vec::new();
+    let mut game_cache_items: Vec<(std::path::PathBuf, u64)> = Vec::new();
+    let mut model_items: Vec<(std::path::PathBuf, u64)> = Vec::new();
+
+    // Plugin categories are handed back to the plugin that reported them
+    let plugin_categories: Vec<crate::plugin::PluginCategoryResult> = app_state
+        .scan_results
+        .as_ref()
+        .map(|r| r.plugins.clone())
+        .unwrap_or_default();
+    let mut plugin_items: Vec<Vec<std::path::PathBuf>> = vec![Vec::new(); plugin_categories.len()];
 
     for (idx, category, path, size) in items_to_clean {
+        if let Some(pos) = plugin_categories
+            .iter()
+            .position(|p| p.display_name() == category)
+        {
+            plugin_items[pos].push(path);
+            continue;
+        }
         match category.as_str() {
             "Installed Applications" => {
                 // Applications need a real uninstall step; don't batch-delete folders.
@@ -1324,6 +1904,22 @@ fn perform_cleanup(
                 // Temp files are more likely to be locked, so smaller batches reduce failures
                 temp_items.push((idx, path, size));
             }
+            "Thumbnail Cache" => {
+                // Explorer holds these open; cleared together around an Explorer restart
+                thumbnail_items.push((path, size));
+            }
+            "Log Files" => {
+                // Active logs may be truncated instead of deleted
+                log_items.push((path, size));
+            }
+            "Game Caches" => {
+                // Skipped while the owning launcher is running
+                game_cache_items.push((path, size));
+            }
+            "ML Models" => {
+                // Ollama models share blobs, so each model is removed on its own
+                model_items.push((path, size));
+            }
             _ => {
                 batch_items.push((idx, path, size));
             }
@@ -1355,6 +1951,10 @@ fn perform_cleanup(
         let mut last_tick_update = std::time::Instant::now();
 
         for (_idx, install_path, size_bytes) in applications_items {
+            if !cleanup_may_continue(app_state, terminal, events) {
+                left.insert(install_path);
+                continue;
+            }
             // Update current path display (uses install folder path; display name is shown elsewhere).
             if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
                 progress.current_path = Some(install_path.clone());
@@ -1390,13 +1990,16 @@ fn perform_cleanup(
                     crate::categories::applications::get_app_artifact_paths(&install_path);
                 for artifact in artifacts {
                     match cleaner::delete_with_precheck(&artifact, permanent) {
-                        Ok(cleaner::DeleteOutcome::Deleted) => {}
+                        Ok(cleaner::DeleteOutcome::Deleted | cleaner::DeleteOutcome::Audited) => {}
                         Ok(
                             cleaner::DeleteOutcome::SkippedMissing
                             | cleaner::DeleteOutcome::SkippedSystem,
                         ) => {}
                         Ok(cleaner::DeleteOutcome::SkippedLocked) => had_error = true,
-                        Ok(cleaner::DeleteOutcome::SkippedPermission) => had_error = true,
+                        Ok(
+                            cleaner::DeleteOutcome::SkippedPermission
+                            | cleaner::DeleteOutcome::SkippedProtected,
+                        ) => had_error = true,
                         Err(_) => had_error = true,
                     }
                 }
@@ -1433,71 +2036,295 @@ fn perform_cleanup(
         }
     }
 
-    // Handle special categories first (they need individual processing)
-    if !special_items.is_empty() {
+    // Handle thumbnail/icon caches: stop Explorer, delete, start Explorer (always permanent)
+    if !thumbnail_items.is_empty() && !cleanup_may_continue(app_state, terminal, events) {
+        left.extend(thumbnail_items.iter().map(|(path, _)| path.clone()));
+    } else if !thumbnail_items.is_empty() {
         debug_log::cleaning_log(&format!(
-            "cleanup special items start: count={}",
-            special_items.len()
+            "cleanup thumbnails start: count={}",
+            thumbnail_items.len()
         ));
         if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
-            progress.current_category = "Cleaning special items...".to_string();
+            progress.current_category =
+                "Restarting Explorer to clear thumbnail cache...".to_string();
+            progress.current_path = None;
         }
         let _ = terminal.draw(|f| render(f, app_state));
 
-        // Track last tick update for continuous animation
-        let mut last_tick_update = std::time::Instant::now();
+        let sizes: std::collections::HashMap<std::path::PathBuf, u64> =
+            thumbnail_items.iter().cloned().collect();
+        let paths: Vec<std::path::PathBuf> = thumbnail_items.into_iter().map(|(p, _)| p).collect();
+        let outcome = categories::thumbnails::clean(&paths);
+        for path in &outcome.deleted {
+            let size = sizes.get(path).copied().unwrap_or(0);
+            cleaned += 1;
+            cleaned_bytes += size;
+            history.log_success(path, size, "thumbnails", true);
+        }
+        for (path, reason) in &outcome.failed {
+            errors += 1;
+            let size = sizes.get(path).copied().unwrap_or(0);
+            history.log_failure(path, size, "thumbnails", true, reason);
+        }
 
-        for (_idx, category, path, size_bytes) in special_items {
-            // Update current path and tick for animation
+        if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+            progress.cleaned = cleaned;
+            progress.errors = errors;
+        }
+        let _ = terminal.draw(|f| render(f, app_state));
+    }
+
+    // Handle log files one by one (active logs are truncated with categories.logs.truncate_active)
+    if !log_items.is_empty() {
+        debug_log::cleaning_log(&format!("cleanup logs start: count={}", log_items.len()));
+        if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+            progress.current_category = "Cleaning log files...".to_string();
+        }
+        let _ = terminal.draw(|f| render(f, app_state));
+
+        let truncate_active = Config::load().categories.logs.truncate_active;
+        for (path, size) in log_items {
+            if !cleanup_may_continue(app_state, terminal, events) {
+                left.insert(path);
+                continue;
+            }
             if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
                 progress.current_path = Some(path.clone());
             }
-
-            // Continuously update tick and redraw for smooth spinner animation
-            if last_tick_update.elapsed().as_millis() >= 100 {
-                app_state.tick = app_state.tick.wrapping_add(1);
-                last_tick_update = std::time::Instant::now();
-                let _ = terminal.draw(|f| render(f, app_state));
-            }
-
-            let delete_result = run_delete_with_ui(app_state, terminal, path.clone(), permanent);
-
-            match delete_result {
-                Ok(cleaner::DeleteOutcome::Deleted) => {
+            match categories::logs::clean(&path, permanent, truncate_active) {
+                Ok(action) => {
                     cleaned += 1;
-                    cleaned_bytes += size_bytes;
-                    // Log success
-                    let category_lower = category.to_lowercase();
-                    history.log_success(&path, size_bytes, &category_lower, permanent);
+                    cleaned_bytes += size;
+                    let permanent =
+                        permanent || action == categories::logs::LogCleanAction::Truncated;
+                    history.log_success(&path, size, "logs", permanent);
                 }
-                Ok(
-                    cleaner::DeleteOutcome::SkippedMissing | cleaner::DeleteOutcome::SkippedSystem,
-                ) => {}
-                Ok(cleaner::DeleteOutcome::SkippedLocked) => {
+                Err(e) => {
                     errors += 1;
-                    debug_log::cleaning_log(&format!("special item locked: {}", path.display()));
-                    let category_lower = category.to_lowercase();
-                    history.log_failure(
-                        &path,
-                        size_bytes,
-                        &category_lower,
-                        permanent,
-                        "Path is locked by another process",
-                    );
+                    history.log_failure(&path, size, "logs", permanent, &e.to_string());
                 }
-                Ok(cleaner::DeleteOutcome::SkippedPermission) => {
-                    errors += 1;
-                    debug_log::cleaning_log(&format!(
-                        "special item permission denied: {}",
-                        path.display()
-                    ));
+            }
+            if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+                progress.cleaned = cleaned;
+                progress.errors = errors;
+            }
+            app_state.tick = app_state.tick.wrapping_add(1);
+            let _ = terminal.draw(|f| render(f, app_state));
+        }
+    }
+
+    // Handle game launcher caches one by one (refused while the launcher runs)
+    if !game_cache_items.is_empty() {
+        debug_log::cleaning_log(&format!(
+            "cleanup game caches start: count={}",
+            game_cache_items.len()
+        ));
+        if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+            progress.current_category = "Cleaning game launcher caches...".to_string();
+        }
+        let _ = terminal.draw(|f| render(f, app_state));
+
+        for (path, size) in game_cache_items {
+            if !cleanup_may_continue(app_state, terminal, events) {
+                left.insert(path);
+                continue;
+            }
+            if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+                progress.current_path = Some(path.clone());
+            }
+            match categories::game_caches::clean(&path) {
+                Ok(()) => {
+                    cleaned += 1;
+                    cleaned_bytes += size;
+                    history.log_success(&path, size, "game_caches", permanent);
+                }
+                Err(e) => {
+                    errors += 1;
+                    history.log_failure(&path, size, "game_caches", permanent, &e.to_string());
+                }
+            }
+            if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+                progress.cleaned = cleaned;
+                progress.errors = errors;
+            }
+            app_state.tick = app_state.tick.wrapping_add(1);
+            let _ = terminal.draw(|f| render(f, app_state));
+        }
+    }
+
+    // Handle ML models one by one
+    if !model_items.is_empty() {
+        debug_log::cleaning_log(&format!(
+            "cleanup ml models start: count={}",
+            model_items.len()
+        ));
+        if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+            progress.current_category = "Cleaning ML models...".to_string();
+        }
+        let _ = terminal.draw(|f| render(f, app_state));
+
+        for (path, size) in model_items {
+            if !cleanup_may_continue(app_state, terminal, events) {
+                left.insert(path);
+                continue;
+            }
+            if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+                progress.current_path = Some(path.clone());
+            }
+            match categories::ml_models::clean(&path, permanent) {
+                Ok(()) => {
+                    cleaned += 1;
+                    cleaned_bytes += size;
+                    history.log_success(&path, size, "ml_models", permanent);
+                }
+                Err(e) => {
+                    errors += 1;
+                    history.log_failure(&path, size, "ml_models", permanent, &e.to_string());
+                }
+            }
+            if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+                progress.cleaned = cleaned;
+                progress.errors = errors;
+            }
+            app_state.tick = app_state.tick.wrapping_add(1);
+            let _ = terminal.draw(|f| render(f, app_state));
+        }
+    }
+
+    // Handle plugin categories: one clean request per category
+    for (plugin, paths) in plugin_categories.iter().zip(plugin_items) {
+        if paths.is_empty() {
+            continue;
+        }
+        if !cleanup_may_continue(app_state, terminal, events) {
+            left.extend(paths);
+            continue;
+        }
+        debug_log::cleaning_log(&format!(
+            "cleanup plugin start: {} count={}",
+            plugin.display_name(),
+            paths.len()
+        ));
+        if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+            progress.current_category = format!("Cleaning {}...", plugin.display_name());
+            progress.current_path = None;
+        }
+        let _ = terminal.draw(|f| render(f, app_state));
+
+        let config = Config::load();
+        match crate::plugin::clean_with_plugin(
+            &plugin.plugin,
+            &plugin.category.id,
+            &paths,
+            permanent,
+            &config,
+        ) {
+            Ok(response) => {
+                for path in &response.cleaned {
+                    let size = plugin.item_size(path);
+                    cleaned += 1;
+                    cleaned_bytes += size;
+                    history.log_success(path, size, &plugin.category.id, permanent);
+                }
+                for failure in &response.failed {
+                    errors += 1;
+                    history.log_failure(
+                        &failure.path,
+                        plugin.item_size(&failure.path),
+                        &plugin.category.id,
+                        permanent,
+                        &failure.error,
+                    );
+                }
+            }
+            Err(e) => {
+                errors += paths.len();
+                debug_log::cleaning_log(&format!(
+                    "plugin clean failed: {} ({})",
+                    plugin.plugin.name, e
+                ));
+            }
+        }
+
+        if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+            progress.cleaned = cleaned;
+            progress.errors = errors;
+        }
+        let _ = terminal.draw(|f| render(f, app_state));
+    }
+
+    // Handle special categories first (they need individual processing)
+    if !special_items.is_empty() {
+        debug_log::cleaning_log(&format!(
+            "cleanup special items start: count={}",
+            special_items.len()
+        ));
+        if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+            progress.current_category = "Cleaning special items...".to_string();
+        }
+        let _ = terminal.draw(|f| render(f, app_state));
+
+        // Track last tick update for continuous animation
+        let mut last_tick_update = std::time::Instant::now();
+
+        for (_idx, category, path, size_bytes) in special_items {
+            if !cleanup_may_continue(app_state, terminal, events) {
+                left.insert(path);
+                continue;
+            }
+            // Update current path and tick for animation
+            if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+                progress.current_path = Some(path.clone());
+            }
+
+            // Continuously update tick and redraw for smooth spinner animation
+            if last_tick_update.elapsed().as_millis() >= 100 {
+                app_state.tick = app_state.tick.wrapping_add(1);
+                last_tick_update = std::time::Instant::now();
+                let _ = terminal.draw(|f| render(f, app_state));
+            }
+
+            let delete_result = run_delete_with_ui(app_state, terminal, path.clone(), permanent);
+
+            match delete_result {
+                Ok(cleaner::DeleteOutcome::Deleted | cleaner::DeleteOutcome::Audited) => {
+                    cleaned += 1;
+                    cleaned_bytes += size_bytes;
+                    // Log success
+                    let category_lower = category.to_lowercase();
+                    history.log_success(&path, size_bytes, &category_lower, permanent);
+                }
+                Ok(
+                    cleaner::DeleteOutcome::SkippedMissing | cleaner::DeleteOutcome::SkippedSystem,
+                ) => {}
+                Ok(cleaner::DeleteOutcome::SkippedLocked) => {
+                    errors += 1;
+                    debug_log::cleaning_log(&format!("special item locked: {}", path.display()));
+                    let category_lower = category.to_lowercase();
+                    history.log_failure(
+                        &path,
+                        size_bytes,
+                        &category_lower,
+                        permanent,
+                        "Path is locked by another process",
+                    );
+                }
+                Ok(
+                    outcome @ (cleaner::DeleteOutcome::SkippedPermission
+                    | cleaner::DeleteOutcome::SkippedProtected),
+                ) => {
+                    errors += 1;
+                    debug_log::cleaning_log(&format!(
+                        "special item permission denied: {}",
+                        path.display()
+                    ));
                     let category_lower = category.to_lowercase();
                     history.log_failure(
                         &path,
                         size_bytes,
                         &category_lower,
                         permanent,
-                        "Permission denied",
+                        outcome.reason(),
                     );
                 }
                 Err(e) => {
@@ -1544,6 +2371,10 @@ fn perform_cleanup(
         let mut last_tick_update = std::time::Instant::now();
 
         for (_idx, path, size_bytes) in cache_items {
+            if !cleanup_may_continue(app_state, terminal, events) {
+                left.insert(path);
+                continue;
+            }
             // Update current file being processed
             if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
                 progress.current_path = Some(path.clone());
@@ -1557,7 +2388,7 @@ fn perform_cleanup(
             }
 
             match run_delete_with_ui(app_state, terminal, path.clone(), permanent) {
-                Ok(cleaner::DeleteOutcome::Deleted) => {
+                Ok(cleaner::DeleteOutcome::Deleted | cleaner::DeleteOutcome::Audited) => {
                     cleaned += 1;
                     cleaned_bytes += size_bytes;
                     // Log success
@@ -1577,13 +2408,16 @@ fn perform_cleanup(
                         "Path is locked by another process",
                     );
                 }
-                Ok(cleaner::DeleteOutcome::SkippedPermission) => {
+                Ok(
+                    outcome @ (cleaner::DeleteOutcome::SkippedPermission
+                    | cleaner::DeleteOutcome::SkippedProtected),
+                ) => {
                     errors += 1;
                     debug_log::cleaning_log(&format!(
                         "cache item permission denied: {}",
                         path.display()
                     ));
-                    history.log_failure(&path, size_bytes, "cache", permanent, "Permission denied");
+                    history.log_failure(&path, size_bytes, "cache", permanent, outcome.reason());
                 }
                 Err(e) => {
                     errors += 1;
@@ -1618,9 +2452,6 @@ fn perform_cleanup(
             "cleanup temp items start: count={}",
             temp_items.len()
         ));
-        // Calculate total bytes for temp items
-        let temp_total_bytes: u64 = temp_items.iter().map(|(_, _, size)| size).sum();
-
         // Extract just the paths for batch deletion
         let paths: Vec<std::path::PathBuf> = temp_items.iter().map(|(_, p, _)| p.clone()).collect();
 
@@ -1643,6 +2474,10 @@ fn perform_cleanup(
         let mut last_tick_update = std::time::Instant::now();
 
         for batch_chunk in paths.chunks(TEMP_BATCH_SIZE) {
+            if !cleanup_may_continue(app_state, terminal, events) {
+                left.extend(batch_chunk.iter().cloned());
+                continue;
+            }
             // Update UI to show temp file deletion progress
             if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
                 progress.current_category =
@@ -1663,12 +2498,19 @@ fn perform_cleanup(
 
             // Delete this batch
             debug_log::cleaning_log(&format!("temp batch delete: count={}", batch_chunk.len()));
-            let batch_result =
-                run_batch_delete_with_ui(app_state, terminal, batch_chunk.to_vec(), permanent);
+            let batch_result = run_batch_delete_with_ui(
+                app_state,
+                terminal,
+                events,
+                batch_chunk.to_vec(),
+                permanent,
+                Some("temp"),
+            );
             temp_success += batch_result.success_count;
             temp_errors += batch_result.error_count;
             deleted_paths.extend(batch_result.deleted_paths);
             skipped_paths.extend(batch_result.skipped_paths);
+            left.extend(batch_result.cancelled_paths);
 
             // Update progress after each batch
             if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
@@ -1705,7 +2547,10 @@ fn perform_cleanup(
 
         // Log failures (paths that weren't deleted) and track them
         for path in &paths {
-            if !deleted_paths.contains(path) && !skipped_paths.contains(path) {
+            if !deleted_paths.contains(path)
+                && !skipped_paths.contains(path)
+                && !left.contains(path)
+            {
                 failed_temp_files.push(path.clone());
                 if let Some(size) = path_sizes.get(path) {
                     let category = path_to_category
@@ -1723,14 +2568,16 @@ fn perform_cleanup(
             }
         }
 
-        // Estimate cleaned bytes based on success ratio
+        // Estimate cleaned bytes based on success ratio (of the batches that ran)
+        let attempted: Vec<&PathBuf> = paths.iter().filter(|p| !left.contains(*p)).collect();
+        let temp_total_bytes: u64 = attempted.iter().filter_map(|p| path_sizes.get(*p)).sum();
         if temp_success > 0 {
             if temp_errors == 0 {
                 // All succeeded - add all bytes
                 cleaned_bytes += temp_total_bytes;
             } else {
                 // Partial success - estimate based on ratio
-                let ratio = temp_success as f64 / paths.len() as f64;
+                let ratio = temp_success as f64 / attempted.len() as f64;
                 cleaned_bytes += (temp_total_bytes as f64 * ratio) as u64;
             }
         }
@@ -1743,15 +2590,108 @@ fn perform_cleanup(
         let _ = terminal.draw(|f| render(f, app_state));
     }
 
+    // Folders of categories with fast_delete on are renamed aside right away and
+    // deleted in the background; anything that can't be staged is deleted normally
+    let mut normal_items = Vec::with_capacity(batch_items.len());
+    for (idx, path, size) in batch_items {
+        let category = app_state
+            .all_items
+            .get(idx)
+            .map(|item| item.category.clone())
+            .unwrap_or_default();
+        if !app_state.is_fast_delete(&category) || !crate::utils::safe_is_dir(&path) {
+            normal_items.push((idx, path, size));
+            continue;
+        }
+        if !cleanup_may_continue(app_state, terminal, events) {
+            left.insert(path);
+            continue;
+        }
+        cleaner::resume_fast_deletes();
+        if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+            progress.current_category = "Moving folders aside for fast delete...".to_string();
+            progress.current_path = Some(path.clone());
+        }
+        // Recorded in the history once the background delete finishes
+        match cleaner::stage_folder(&path, size, &category.to_lowercase()) {
+            Ok(()) => {
+                cleaned += 1;
+                cleaned_bytes += size;
+                if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+                    progress.cleaned = cleaned;
+                }
+            }
+            Err(e) => {
+                debug_log::cleaning_log(&format!("fast delete skipped: {:#}", e));
+                normal_items.push((idx, path, size));
+            }
+        }
+        let _ = terminal.draw(|f| render(f, app_state));
+    }
+    let mut batch_items = normal_items;
+
+    // With [categories.cache] use_manager_commands on, package caches are
+    // cleared by their manager's own command where it has one
+    if app_state.config.categories.cache.use_manager_commands {
+        let mut normal_items = Vec::with_capacity(batch_items.len());
+        for (idx, path, size) in batch_items {
+            let is_package_cache = app_state
+                .all_items
+                .get(idx)
+                .is_some_and(|item| item.category == "Package Cache");
+            if !is_package_cache {
+                normal_items.push((idx, path, size));
+                continue;
+            }
+            if !cleanup_may_continue(app_state, terminal, events) {
+                left.insert(path);
+                continue;
+            }
+            if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
+                progress.current_category = "Clearing package caches...".to_string();
+                progress.current_path = Some(path.clone());
+            }
+            let _ = terminal.draw(|f| render(f, app_state));
+            match crate::categories::cache::clear_with_manager(&path) {
+                Ok(true) => {
+                    cleaned += 1;
+                    cleaned_bytes += size;
+                    history.log_success(&path, size, "package cache", true);
+                    if let crate::tui::state::Screen::Cleaning { ref mut progress } =
+                        app_state.screen
+                    {
+                        progress.cleaned = cleaned;
+                    }
+                }
+                Ok(false) => normal_items.push((idx, path, size)),
+                Err(e) => {
+                    debug_log::cleaning_log(&format!("package manager clear failed: {:#}", e));
+                    normal_items.push((idx, path, size));
+                }
+            }
+        }
+        batch_items = normal_items;
+    }
+
     // Batch delete all remaining items (FAST PATH)
     if !batch_items.is_empty() {
         debug_log::cleaning_log(&format!(
             "cleanup batch items start: count={}",
             batch_items.len()
         ));
-        // Calculate total bytes for batch items
-        let batch_total_bytes: u64 = batch_items.iter().map(|(_, _, size)| size).sum();
-
+        // Category of each path, for its [performance.category_overrides];
+        // sorted so no batch mixes categories
+        let batch_keys: std::collections::HashMap<PathBuf, &'static str> = batch_items
+            .iter()
+            .filter_map(|(idx, path, _)| {
+                let display = &app_state.all_items.get(*idx)?.category;
+                let category = crate::api::Category::ALL
+                    .into_iter()
+                    .find(|c| c.name() == display)?;
+                Some((path.clone(), category.id()))
+            })
+            .collect();
+        batch_items.sort_by_key(|(_, path, _)| batch_keys.get(path).copied());
         // Extract just the paths for batch deletion
         let paths: Vec<std::path::PathBuf> =
             batch_items.iter().map(|(_, p, _)| p.clone()).collect();
@@ -1773,7 +2713,46 @@ fn perform_cleanup(
         // Track last tick update for continuous animation
         let mut last_tick_update = std::time::Instant::now();
 
-        for batch_chunk in paths.chunks(BATCH_SIZE) {
+        // Items on different volumes don't compete for the same disk, so each
+        // volume gets its own worker (bounded by performance.clean_workers)
+        let buckets = crate::recycle_bin::volume_buckets(&paths, PathBuf::as_path);
+        let workers = app_state
+            .config
+            .performance
+            .clean_worker_limit()
+            .min(buckets.len());
+        let serial_paths: &[PathBuf] = if workers > 1 {
+            debug_log::cleaning_log(&format!(
+                "batch delete on {} volumes with {} workers",
+                buckets.len(),
+                workers
+            ));
+            let outcome = run_volume_batches_with_ui(
+                app_state,
+                terminal,
+                events,
+                buckets,
+                &batch_keys,
+                workers,
+                BATCH_SIZE,
+                permanent,
+                (cleaned, errors),
+            );
+            batch_success = outcome.success_count;
+            batch_errors = outcome.error_count;
+            deleted_paths = outcome.deleted_paths;
+            skipped_paths = outcome.skipped_paths;
+            left.extend(outcome.left);
+            &[]
+        } else {
+            &paths
+        };
+
+        for batch_chunk in category_batches(serial_paths, &batch_keys, BATCH_SIZE) {
+            if !cleanup_may_continue(app_state, terminal, events) {
+                left.extend(batch_chunk.iter().cloned());
+                continue;
+            }
             // Update UI to show batch deletion progress
             if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
                 progress.current_category = format!("Batch deleting {} files...", paths.len());
@@ -1793,12 +2772,19 @@ fn perform_cleanup(
 
             // Delete this batch
             debug_log::cleaning_log(&format!("batch delete chunk: count={}", batch_chunk.len()));
-            let batch_result =
-                run_batch_delete_with_ui(app_state, terminal, batch_chunk.to_vec(), permanent);
+            let batch_result = run_batch_delete_with_ui(
+                app_state,
+                terminal,
+                events,
+                batch_chunk.to_vec(),
+                permanent,
+                batch_keys.get(&batch_chunk[0]).copied(),
+            );
             batch_success += batch_result.success_count;
             batch_errors += batch_result.error_count;
             deleted_paths.extend(batch_result.deleted_paths);
             skipped_paths.extend(batch_result.skipped_paths);
+            left.extend(batch_result.cancelled_paths);
 
             // Update progress after each batch
             if let crate::tui::state::Screen::Cleaning { ref mut progress } = app_state.screen {
@@ -1836,7 +2822,10 @@ fn perform_cleanup(
 
         // Log failures (paths that weren't deleted)
         for path in &paths {
-            if !deleted_paths.contains(path) && !skipped_paths.contains(path) {
+            if !deleted_paths.contains(path)
+                && !skipped_paths.contains(path)
+                && !left.contains(path)
+            {
                 if let Some(size) = path_sizes.get(path) {
                     let category = path_to_category
                         .get(path)
@@ -1847,14 +2836,16 @@ fn perform_cleanup(
             }
         }
 
-        // Estimate cleaned bytes based on success ratio
+        // Estimate cleaned bytes based on success ratio (of the batches that ran)
+        let attempted: Vec<&PathBuf> = paths.iter().filter(|p| !left.contains(*p)).collect();
+        let batch_total_bytes: u64 = attempted.iter().filter_map(|p| path_sizes.get(*p)).sum();
         if batch_success > 0 {
             if batch_errors == 0 {
                 // All succeeded - add all bytes
                 cleaned_bytes += batch_total_bytes;
             } else {
                 // Partial success - estimate based on ratio
-                let ratio = batch_success as f64 / paths.len() as f64;
+                let ratio = batch_success as f64 / attempted.len() as f64;
                 cleaned_bytes += (batch_total_bytes as f64 * ratio) as u64;
             }
         }
@@ -1871,50 +2862,93 @@ fn perform_cleanup(
     // Final redraw to ensure UI is up to date
     let _ = terminal.draw(|f| render(f, app_state));
 
-    // Remove cleaned items from the list
-    let mut indices_to_remove: Vec<usize> = app_state.selected_items.iter().cloned().collect();
+    // Remove cleaned items from the list (items a stopped cleanup didn't reach stay selected)
+    let mut indices_to_remove: Vec<usize> = app_state
+        .selected_items
+        .iter()
+        .copied()
+        .filter(|&idx| {
+            app_state
+                .all_items
+                .get(idx)
+                .is_none_or(|item| !left.contains(&item.path))
+        })
+        .collect();
     indices_to_remove.sort();
     indices_to_remove.reverse(); // Remove from end to preserve indices
 
     for idx in indices_to_remove {
         app_state.all_items.remove(idx);
     }
-    app_state.selected_items.clear();
+    app_state.selected_items = app_state
+        .all_items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| left.contains(&item.path))
+        .map(|(idx, _)| idx)
+        .collect();
+    app_state.cleanup_left = app_state.selected_items.len();
 
     // Rebuild groups from remaining items so navigation back to Results works
     app_state.rebuild_groups_from_all_items();
+    // The cleaned items are gone, so there's nothing to undo
+    app_state.undo_steps.clear();
+    app_state.redo_steps.clear();
 
     // Save deletion history log
     if let Err(e) = history.save() {
-        // Log error but don't fail the cleanup operation
-        // In production, this is silently ignored to avoid disrupting the UI
-        #[cfg(debug_assertions)]
-        eprintln!("[DEBUG] Failed to save deletion log: {}", e);
+        // Don't fail the cleanup operation, but restore needs this log
+        app_state
+            .toasts
+            .warn(format!("Failed to save deletion log: {}", e));
     }
 
     debug_log::cleaning_log(&format!(
-        "cleanup complete: cleaned={} errors={} cleaned_bytes={}",
-        cleaned, errors, cleaned_bytes
+        "cleanup complete: cleaned={} errors={} cleaned_bytes={} left={}",
+        cleaned, errors, cleaned_bytes, app_state.cleanup_left
     ));
 
+    // Report to the policy's sink without blocking the UI
+    if crate::policy::current().report.is_some() {
+        let report = crate::policy::CleanReport::new(
+            &cleaner::CleanSummary {
+                cleaned,
+                cleaned_bytes,
+                errors: errors as u64,
+                ..Default::default()
+            },
+            permanent,
+        );
+        std::thread::spawn(move || {
+            if let Err(e) = crate::policy::send_report(&report) {
+                debug_log::cleaning_log(&format!("clean report failed: {:#}", e));
+            }
+        });
+    }
+
     Ok((cleaned, cleaned_bytes, errors, failed_temp_files))
 }
 
 /// Perform restoration with real-time progress updates
-fn perform_restore(
+fn perform_restore<B: Backend>(
     app_state: &mut AppState,
-    terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+    terminal: &mut Terminal<B>,
+    log_path: Option<&std::path::Path>,
 ) -> anyhow::Result<restore::RestoreResult> {
-    // Get the most recent log
+    // Use the session picked in the History screen, or the most recent log
     use crate::history::{list_logs, load_log};
-    let logs = list_logs()?;
-    if logs.is_empty() {
-        return Err(anyhow::anyhow!(
-            "No deletion history found. Nothing to restore."
-        ));
-    }
-
-    let latest_log = load_log(&logs[0])?;
+    let latest_log = match log_path {
+        Some(path) => load_log(path)?,
+        None => {
+            let logs = list_logs()?;
+            if logs.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "No deletion history found. Nothing to restore."
+                ));
+            }
+            load_log(&logs[0])?
+        }
+    };
 
     // Get current Recycle Bin contents
     let recycle_bin_items =
@@ -1932,6 +2966,7 @@ fn perform_restore(
 
     let mut result = restore::RestoreResult::default();
     let mut error_reasons: Vec<String> = Vec::new(); // Track error messages
+    let mut restored_records = Vec::new();
     let mut files_since_redraw = 0;
     let mut last_redraw = std::time::Instant::now();
     let mut last_tick_update = std::time::Instant::now();
@@ -1971,6 +3006,7 @@ fn perform_restore(
                 Ok(()) => {
                     result.restored += 1;
                     result.restored_bytes += record.size_bytes;
+                    restored_records.push(record);
 
                     // Update progress
                     if let crate::tui::state::Screen::Restore {
@@ -2040,6 +3076,7 @@ fn perform_restore(
                 if restored_count > 0 {
                     result.restored += 1; // Count as one directory restored
                     result.restored_bytes += record.size_bytes; // Use the logged size
+                    restored_records.push(record);
                 }
                 result.errors += restore_errors;
 
@@ -2054,7 +3091,21 @@ fn perform_restore(
                     prog.errors = result.errors;
                 }
             } else {
-                result.not_found += 1;
+                // Items deleted from a network share sit in its quarantine folder
+                match crate::network::restore_quarantined(std::path::Path::new(&record.path)) {
+                    Ok(true) => {
+                        result.restored += 1;
+                        result.restored_bytes += record.size_bytes;
+                        restored_records.push(record);
+                    }
+                    Ok(false) => result.not_found += 1,
+                    Err(e) => {
+                        result.errors += 1;
+                        if error_reasons.len() < 5 {
+                            error_reasons.push(format!("{}: {}", record.path, e));
+                        }
+                    }
+                }
 
                 // Update progress
                 if let crate::tui::state::Screen::Restore {
@@ -2062,6 +3113,9 @@ fn perform_restore(
                     ..
                 } = app_state.screen
                 {
+                    prog.restored = result.restored;
+                    prog.restored_bytes = result.restored_bytes;
+                    prog.errors = result.errors;
                     prog.not_found = result.not_found;
                 }
             }
@@ -2084,13 +3138,17 @@ fn perform_restore(
 
     // Attach error reasons to result
     result.error_reasons = error_reasons;
+    result.mismatches = restore::verify_restored(restored_records);
     Ok(result)
 }
 
 /// Perform restoration of all Recycle Bin contents with real-time progress updates
-fn perform_restore_all_bin(
+///
+/// Esc stops it: batches already running finish, the rest stay in the bin.
+fn perform_restore_all_bin<B: Backend, E: EventSource>(
     app_state: &mut AppState,
-    terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+    terminal: &mut Terminal<B>,
+    events: &mut E,
 ) -> anyhow::Result<restore::RestoreResult> {
     // Get current Recycle Bin contents
     let recycle_bin_items =
@@ -2121,111 +3179,266 @@ fn perform_restore_all_bin(
         }
     }
 
-    // Restore in batches for better performance
-    for batch in recycle_bin_items.chunks(BATCH_SIZE) {
-        // Continuously update tick and redraw for smooth spinner animation
-        if last_tick_update.elapsed().as_millis() >= 100 {
-            app_state.tick = app_state.tick.wrapping_add(1);
-            last_tick_update = std::time::Instant::now();
-        }
-
-        // Update progress state
-        if let crate::tui::state::Screen::Restore {
-            progress: Some(ref mut prog),
-            ..
-        } = app_state.screen
-        {
-            if let Some(item) = batch.first() {
-                let path = item.original_parent.join(&item.name);
-                let relative_path_str = crate::utils::to_relative_path(&path, &app_state.scan_path);
-                prog.current_path = Some(std::path::PathBuf::from(relative_path_str));
-            }
+    // Batches take turns between volumes and are shared by a bounded set of
+    // workers (performance.clean_workers), so every drive makes progress and a
+    // drive with many items isn't left to a single worker
+    let buckets = crate::recycle_bin::volume_buckets(&recycle_bin_items, |item| {
+        item.original_parent.as_path()
+    });
+    let batches = crate::recycle_bin::interleaved_batches(&buckets, BATCH_SIZE);
+    let workers = app_state
+        .config
+        .performance
+        .clean_worker_limit()
+        .clamp(1, batches.len());
+    debug_log::cleaning_log(&format!(
+        "restore {} items on {} volumes in {} batches with {} workers",
+        recycle_bin_items.len(),
+        buckets.len(),
+        batches.len(),
+        workers
+    ));
+    let queue = std::sync::Mutex::new(std::collections::VecDeque::from(batches));
+    app_state.restore_cancel = crate::cancel::CancellationToken::new();
+    let cancel = app_state.restore_cancel.clone();
+
+    std::thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel::<RestoreBatchUpdate>();
+        for _ in 0..workers {
+            let tx = tx.clone();
+            let (queue, cancel) = (&queue, &cancel);
+            scope.spawn(move || loop {
+                if cancel.is_cancelled() {
+                    break;
+                }
+                let Some(batch) = queue.lock().ok().and_then(|mut q| q.pop_front()) else {
+                    break;
+                };
+                let first = batch[0].original_parent.join(&batch[0].name);
+                let _ = tx.send(RestoreBatchUpdate::Started(first));
+                let _ = tx.send(RestoreBatchUpdate::Finished(restore_bin_batch(&batch)));
+            });
         }
+        drop(tx);
 
-        // Redraw terminal periodically
-        let _ = terminal.draw(|f| render(f, app_state));
-
-        // Try bulk restore
-        match crate::trash_ops::restore_all(batch.iter().cloned()) {
-            Ok(()) => {
-                // Bulk restore succeeded
-                for item in batch {
-                    result.restored += 1;
-                    // Try to get size from restored file
-                    let restored_path = item.original_parent.join(&item.name);
-                    if let Ok(metadata) = std::fs::metadata(&restored_path) {
-                        result.restored_bytes += metadata.len();
+        loop {
+            while let Some(event) = events.poll_background() {
+                if let Event::Key(key) = event {
+                    if key.kind == KeyEventKind::Press {
+                        handle_event(app_state, key.code, key.modifiers);
                     }
                 }
-
-                // Update progress
-                if let crate::tui::state::Screen::Restore {
-                    progress: Some(ref mut prog),
-                    ..
-                } = app_state.screen
-                {
-                    prog.restored = result.restored;
-                    prog.restored_bytes = result.restored_bytes;
-                }
             }
-            Err(_e) => {
-                // Bulk restore failed - fall back to individual restore
-                for item in batch {
-                    let dest = item.original_parent.join(&item.name);
 
-                    // Skip if destination already exists
-                    if dest.exists() {
-                        result.restored += 1;
-                        if let Ok(metadata) = std::fs::metadata(&dest) {
-                            result.restored_bytes += metadata.len();
-                        }
-                        continue;
+            match rx.recv_timeout(Duration::from_millis(50)) {
+                Ok(RestoreBatchUpdate::Started(path)) => {
+                    if let crate::tui::state::Screen::Restore {
+                        progress: Some(ref mut prog),
+                        ..
+                    } = app_state.screen
+                    {
+                        let relative_path_str =
+                            crate::utils::to_relative_path(&path, &app_state.scan_path);
+                        prog.current_path = Some(std::path::PathBuf::from(relative_path_str));
                     }
+                }
+                Ok(RestoreBatchUpdate::Finished(batch)) => {
+                    result.restored += batch.restored;
+                    result.restored_bytes += batch.restored_bytes;
+                    result.errors += batch.errors;
+                    result.mismatches.extend(batch.mismatches);
+                    // Store error messages (limit to first 5 errors)
+                    let room = 5usize.saturating_sub(error_reasons.len());
+                    error_reasons.extend(batch.error_reasons.into_iter().take(room));
 
-                    match restore::restore_file(item) {
-                        Ok(()) => {
-                            result.restored += 1;
-                            // Get file size from restored file
-                            if let Ok(metadata) = std::fs::metadata(&dest) {
-                                result.restored_bytes += metadata.len();
-                            }
-
-                            // Update progress
-                            if let crate::tui::state::Screen::Restore {
-                                progress: Some(ref mut prog),
-                                ..
-                            } = app_state.screen
-                            {
-                                prog.restored = result.restored;
-                                prog.restored_bytes = result.restored_bytes;
-                            }
-                        }
-                        Err(err) => {
-                            result.errors += 1;
-                            // Store error message (limit to first 5 errors)
-                            if error_reasons.len() < 5 {
-                                error_reasons.push(format!("{}: {}", dest.display(), err));
-                            }
-
-                            // Update progress
-                            if let crate::tui::state::Screen::Restore {
-                                progress: Some(ref mut prog),
-                                ..
-                            } = app_state.screen
-                            {
-                                prog.errors = result.errors;
-                            }
-                        }
+                    if let crate::tui::state::Screen::Restore {
+                        progress: Some(ref mut prog),
+                        ..
+                    } = app_state.screen
+                    {
+                        prog.restored = result.restored;
+                        prog.restored_bytes = result.restored_bytes;
+                        prog.errors = result.errors;
                     }
                 }
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+
+            // Continuously update tick and redraw for smooth spinner animation
+            if last_tick_update.elapsed().as_millis() >= 100 {
+                app_state.tick = app_state.tick.wrapping_add(1);
+                last_tick_update = std::time::Instant::now();
+                let _ = terminal.draw(|f| render(f, app_state));
             }
         }
-    }
+    });
 
     // Final redraw
     let _ = terminal.draw(|f| render(f, app_state));
 
     // Attach error reasons to result
     result.error_reasons = error_reasons;
+    result.cancelled = cancel.is_cancelled();
     Ok(result)
 }
+
+enum RestoreBatchUpdate {
+    Started(PathBuf),
+    Finished(restore::RestoreResult),
+}
+
+/// Restore one batch of Recycle Bin items, falling back to one item at a time
+/// if the bulk restore fails
+///
+/// Restored items are checked with [`restore::verify_restored`] against the
+/// size the Recycle Bin reported for them (folders only need to exist).
+fn restore_bin_batch(batch: &[trash::TrashItem]) -> restore::RestoreResult {
+    let mut result = restore::RestoreResult::default();
+    let restored_size = |dest: &std::path::Path| std::fs::metadata(dest).map_or(0, |m| m.len());
+    let records: Vec<crate::history::DeletionRecord> = batch
+        .iter()
+        .map(|item| {
+            let size = crate::trash_ops::metadata(item)
+                .ok()
+                .and_then(|metadata| metadata.size.size())
+                .unwrap_or(0);
+            let dest = item.original_parent.join(&item.name);
+            crate::history::DeletionRecord::success(&dest, size, "Recycle Bin", false)
+        })
+        .collect();
+
+    if crate::trash_ops::restore_all(batch.iter().cloned()).is_ok() {
+        for item in batch {
+            result.restored += 1;
+            result.restored_bytes += restored_size(&item.original_parent.join(&item.name));
+        }
+        result.mismatches = restore::verify_restored(&records);
+        return result;
+    }
+
+    let mut restored = Vec::new();
+    for (item, record) in batch.iter().zip(&records) {
+        let dest = item.original_parent.join(&item.name);
+
+        // Already back (from a partial batch success)
+        if dest.exists() {
+            result.restored += 1;
+            result.restored_bytes += restored_size(&dest);
+            restored.push(record);
+            continue;
+        }
+
+        match restore::restore_file(item) {
+            Ok(()) => {
+                result.restored += 1;
+                result.restored_bytes += restored_size(&dest);
+                restored.push(record);
+            }
+            Err(err) => {
+                result.errors += 1;
+                result
+                    .error_reasons
+                    .push(format!("{}: {}", dest.display(), err));
+            }
+        }
+    }
+    result.mismatches = restore::verify_restored(restored);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::state::{CleanProgress, Screen};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::backend::TestBackend;
+    use std::collections::VecDeque;
+
+    /// Keys that arrive while the cleanup is running, the way a user types them
+    struct BackgroundKeys(VecDeque<Event>);
+
+    impl BackgroundKeys {
+        fn new(keys: &[KeyCode]) -> Self {
+            Self(
+                keys.iter()
+                    .map(|&code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+                    .collect(),
+            )
+        }
+    }
+
+    impl EventSource for BackgroundKeys {
+        fn poll(&mut self, _timeout: Duration) -> Result<bool> {
+            Ok(false)
+        }
+
+        fn read(&mut self) -> Result<Event> {
+            Err(anyhow!("no foreground input while cleaning"))
+        }
+
+        fn poll_background(&mut self) -> Option<Event> {
+            self.0.pop_front()
+        }
+    }
+
+    fn cleaning_state() -> AppState {
+        let mut state = AppState::new();
+        state.screen = Screen::Cleaning {
+            progress: CleanProgress {
+                current_category: String::new(),
+                current_path: None,
+                cleaned: 0,
+                total: 3,
+                errors: 0,
+                paused: false,
+                stop_requested: false,
+            },
+        };
+        state
+    }
+
+    fn may_continue(state: &mut AppState, keys: &[KeyCode]) -> bool {
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        cleanup_may_continue(state, &mut terminal, &mut BackgroundKeys::new(keys))
+    }
+
+    #[test]
+    fn test_cleanup_continues_after_resume() {
+        let mut state = cleaning_state();
+        assert!(may_continue(&mut state, &[]));
+        assert!(may_continue(
+            &mut state,
+            &[KeyCode::Char(' '), KeyCode::Char('p')]
+        ));
+        let Screen::Cleaning { ref progress } = state.screen else {
+            panic!("left the cleaning screen");
+        };
+        assert!(!progress.paused);
+        assert!(!progress.stop_requested);
+        assert!(!state.cleanup_cancel.is_cancelled());
+    }
+
+    #[test]
+    fn test_esc_only_stops_a_paused_cleanup() {
+        // Esc on its own is ignored so a stray key press can't abandon a cleanup
+        let mut state = cleaning_state();
+        assert!(may_continue(&mut state, &[KeyCode::Esc]));
+        assert!(!state.cleanup_cancel.is_cancelled());
+
+        assert!(!may_continue(
+            &mut state,
+            &[KeyCode::Char('p'), KeyCode::Esc]
+        ));
+        let Screen::Cleaning { ref progress } = state.screen else {
+            panic!("left the cleaning screen");
+        };
+        assert!(progress.stop_requested);
+        assert!(!progress.paused);
+        // The batch already running is told to stop too
+        assert!(state.cleanup_cancel.is_cancelled());
+
+        // Once stopped, it stays stopped
+        assert!(!may_continue(&mut state, &[]));
+    }
+}
@@ -0,0 +1,135 @@
+//! Apps by cache size screen - cache and temp results grouped by owning app
+
+use crate::tui::{
+    state::AppState,
+    theme::Styles,
+    widgets::{
+        logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
+        shortcuts::{get_shortcuts, render_shortcuts},
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Width of the size bar next to each app
+const BAR_WIDTH: usize = 20;
+
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let is_small = area.height < 20 || area.width < 60;
+    let shortcuts_height = if is_small { 2 } else { 3 };
+    let header_height = LOGO_WITH_TAGLINE_HEIGHT;
+    let min_content_height = 5;
+
+    if area.height < header_height + min_content_height + shortcuts_height || area.width < 20 {
+        let msg = Paragraph::new("Terminal too small. Please resize to at least 20x25")
+            .style(Styles::warning())
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(min_content_height),
+            Constraint::Length(shortcuts_height),
+        ])
+        .split(area);
+
+    render_logo(f, chunks[0]);
+    render_tagline(f, chunks[0]);
+    render_content(f, chunks[1], app_state);
+
+    let shortcuts = get_shortcuts(&app_state.screen, Some(app_state));
+    render_shortcuts(f, chunks[2], &shortcuts);
+}
+
+fn render_content(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let crate::tui::state::Screen::AppCaches { apps, cursor } = &app_state.screen else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Title
+            Constraint::Length(1), // What's included
+            Constraint::Length(1), // Spacing
+            Constraint::Min(2),    // Apps
+        ])
+        .split(area);
+
+    f.render_widget(
+        Paragraph::new("Apps by cache size").style(Styles::primary()),
+        chunks[0],
+    );
+    f.render_widget(
+        Paragraph::new(format!(
+            "{} grouped by the app they belong to",
+            crate::cache_owners::CATEGORIES.join(", ")
+        ))
+        .style(Styles::secondary()),
+        chunks[1],
+    );
+
+    if apps.is_empty() {
+        f.render_widget(
+            Paragraph::new("No application, browser or temp caches in these results")
+                .style(Styles::muted()),
+            chunks[3],
+        );
+        return;
+    }
+
+    let largest = apps.first().map(|a| a.size_bytes).unwrap_or(0).max(1);
+    let items: Vec<ListItem> = apps
+        .iter()
+        .map(|app| {
+            let selected = app
+                .items
+                .iter()
+                .filter(|i| app_state.selected_items.contains(*i))
+                .count();
+            let (checkbox, checkbox_style) = if selected == 0 {
+                ("[ ]", Styles::secondary())
+            } else if selected == app.items.len() {
+                ("[X]", Styles::checked())
+            } else {
+                ("[-]", Styles::checked())
+            };
+            let filled = ((app.size_bytes as f64 / largest as f64) * BAR_WIDTH as f64)
+                .round()
+                .max(1.0) as usize;
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} ", checkbox), checkbox_style),
+                Span::styled(
+                    format!("{:>10}  ", bytesize::to_string(app.size_bytes, false)),
+                    Styles::emphasis(),
+                ),
+                Span::styled(
+                    format!(
+                        "{}{}  ",
+                        "█".repeat(filled.min(BAR_WIDTH)),
+                        "░".repeat(BAR_WIDTH.saturating_sub(filled))
+                    ),
+                    Styles::secondary(),
+                ),
+                Span::styled(app.app.clone(), Styles::primary()),
+                Span::styled(
+                    format!("  ({} items, {} selected)", app.items.len(), selected),
+                    Styles::muted(),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(Styles::selected());
+    let mut state = ListState::default();
+    state.select(Some((*cursor).min(apps.len() - 1)));
+    f.render_stateful_widget(list, chunks[3], &mut state);
+}
@@ -0,0 +1,170 @@
+//! Breakdown screen - bar charts of a Disk Insights folder
+//!
+//! Opened with B from Disk Insights. One chart at a time (by extension,
+//! size or age), switched with Tab.
+
+use crate::analyzer::Bucket;
+use crate::tui::{
+    state::{AppState, Screen},
+    theme::Styles,
+    widgets::{
+        logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
+        shortcuts::{get_shortcuts, render_shortcuts},
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Width of the bucket label column
+const LABEL_WIDTH: usize = 16;
+
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let is_small = area.height < 20 || area.width < 60;
+    let shortcuts_height = if is_small { 2 } else { 3 };
+    let header_height = LOGO_WITH_TAGLINE_HEIGHT;
+    let min_content_height = 5;
+
+    if area.height < header_height + min_content_height + shortcuts_height || area.width < 20 {
+        let msg = Paragraph::new("Terminal too small. Please resize to at least 20x25")
+            .style(Styles::warning())
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(min_content_height),
+            Constraint::Length(shortcuts_height),
+        ])
+        .split(area);
+
+    render_logo(f, chunks[0]);
+    render_tagline(f, chunks[0]);
+    render_content(f, chunks[1], app_state);
+
+    let shortcuts = get_shortcuts(&app_state.screen, Some(app_state));
+    render_shortcuts(f, chunks[2], &shortcuts);
+}
+
+fn render_content(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let Screen::Breakdown {
+        root,
+        breakdown,
+        tab,
+        message,
+        ..
+    } = &app_state.screen
+    else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Root and totals
+            Constraint::Length(1), // Tabs
+            Constraint::Length(1), // Spacing
+            Constraint::Min(2),    // Chart
+            Constraint::Length(1), // Message
+        ])
+        .split(area);
+
+    let Some(breakdown) = breakdown else {
+        let spinner = crate::spinner::get_spinner(app_state.tick);
+        f.render_widget(
+            Paragraph::new(format!("{} Walking {}...", spinner, root.display()))
+                .style(Styles::secondary()),
+            chunks[0],
+        );
+        return;
+    };
+
+    f.render_widget(
+        Paragraph::new(format!(
+            "{}  •  {} files  •  {}",
+            root.display(),
+            breakdown.total_files,
+            bytesize::to_string(breakdown.total_bytes, false)
+        ))
+        .style(Styles::secondary()),
+        chunks[0],
+    );
+
+    let distributions = breakdown.distributions();
+    let tab = (*tab).min(distributions.len() - 1);
+    let mut tabs = Vec::new();
+    for (i, (name, _)) in distributions.iter().enumerate() {
+        if i > 0 {
+            tabs.push(Span::styled("  │  ", Styles::muted()));
+        }
+        let style = if i == tab {
+            Styles::emphasis()
+        } else {
+            Styles::muted()
+        };
+        tabs.push(Span::styled(format!("By {}", name), style));
+    }
+    f.render_widget(Paragraph::new(Line::from(tabs)), chunks[1]);
+
+    let (_, buckets) = distributions[tab];
+    f.render_widget(
+        Paragraph::new(chart_lines(buckets, breakdown.total_bytes, chunks[3].width)),
+        chunks[3],
+    );
+
+    if let Some(message) = message {
+        f.render_widget(
+            Paragraph::new(message.as_str()).style(Styles::secondary()),
+            chunks[4],
+        );
+    }
+}
+
+/// One line per bucket: label, bar scaled to the largest bucket, size, share, files
+fn chart_lines(buckets: &[Bucket], total_bytes: u64, width: u16) -> Vec<Line<'static>> {
+    // label + size + share + files columns
+    let bar_width = (width as usize).saturating_sub(LABEL_WIDTH + 40).max(4);
+    let largest = buckets.iter().map(|b| b.bytes).max().unwrap_or(0).max(1);
+    buckets
+        .iter()
+        .map(|bucket| {
+            let filled = (bucket.bytes as u128 * bar_width as u128 / largest as u128) as usize;
+            let share = if total_bytes > 0 {
+                bucket.bytes as f64 * 100.0 / total_bytes as f64
+            } else {
+                0.0
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{:<width$} ",
+                        bucket.label.chars().take(LABEL_WIDTH).collect::<String>(),
+                        width = LABEL_WIDTH
+                    ),
+                    Styles::primary(),
+                ),
+                Span::styled("█".repeat(filled), Styles::emphasis()),
+                Span::styled(
+                    "░".repeat(bar_width.saturating_sub(filled)),
+                    Styles::muted(),
+                ),
+                Span::styled(
+                    format!(
+                        " {:>10} {:>5.1}%",
+                        bytesize::to_string(bucket.bytes, false),
+                        share
+                    ),
+                    Styles::emphasis(),
+                ),
+                Span::styled(format!("  {} files", bucket.files), Styles::muted()),
+            ])
+        })
+        .collect()
+}
@@ -18,9 +18,7 @@ use crate::tui::{
 };
 use bytesize;
 
-pub fn render(f: &mut Frame, app_state: &AppState) {
-    let area = f.area();
-
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -238,12 +236,37 @@ fn render_body(f: &mut Frame, area: Rect, app_state: &AppState) {
     ]));
     field_lines.push(Line::from(""));
 
-    // 10 clear_cache (action button)
+    // 10 auto_select_aggressiveness (slider)
+    let aggressiveness = config.ui.auto_select_aggressiveness.min(100);
+    let filled = (aggressiveness / 10) as usize;
+    field_lines.push(Line::from(vec![
+        Span::styled("  Auto-select:        ", Styles::secondary()),
+        Span::styled(
+            if editing && selected == 10 {
+                edit_buffer.unwrap_or("").to_string()
+            } else {
+                format!(
+                    "[{}{}] {}",
+                    "█".repeat(filled),
+                    "░".repeat(10 - filled),
+                    aggressiveness
+                )
+            },
+            field_style(10),
+        ),
+        Span::styled(
+            "   (+/- adjusts; higher pre-selects riskier items)",
+            Styles::secondary(),
+        ),
+    ]));
+    field_lines.push(Line::from(""));
+
+    // 11 clear_cache (action button)
     field_lines.push(Line::from(vec![
         Span::styled("  Clear scan cache:   ", Styles::secondary()),
         Span::styled(
             "[Press Enter to clear]",
-            if selected == 10 {
+            if selected == 11 {
                 Styles::selected()
             } else {
                 Styles::warning()
@@ -1,7 +1,7 @@
 //! Confirmation screen overlay
 
 use crate::tui::{
-    state::AppState,
+    state::{AppState, CategoryGroup, RiskTier},
     theme::{category_style, Styles},
     widgets::{
         logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
@@ -19,6 +19,28 @@ use ratatui::{
     Frame,
 };
 
+/// Colour of a risk tier
+fn tier_style(tier: RiskTier) -> Style {
+    match tier {
+        RiskTier::Safe => Styles::success(),
+        RiskTier::Review => Styles::warning(),
+        RiskTier::HighRisk => Styles::danger(),
+    }
+}
+
+/// All items of a confirm category group
+fn group_items(group: &CategoryGroup) -> Vec<usize> {
+    if group.grouped_by_folder {
+        group
+            .folder_groups
+            .iter()
+            .flat_map(|fg| fg.items.iter().copied())
+            .collect()
+    } else {
+        group.items.clone()
+    }
+}
+
 /// Generate a fun comparison for the amount of space to be reclaimed
 fn fun_comparison(bytes: u64) -> Option<String> {
     const MB: u64 = 1_000_000;
@@ -69,9 +91,7 @@ fn fun_comparison(bytes: u64) -> Option<String> {
     }
 }
 
-pub fn render(f: &mut Frame, app_state: &mut AppState) {
-    let area = f.area();
-
+pub fn render(f: &mut Frame, area: Rect, app_state: &mut AppState) {
     // Layout: logo+tagline, warning, items area (split into summary and file list), actions, shortcuts
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -98,6 +118,12 @@ pub fn render(f: &mut Frame, app_state: &mut AppState) {
             .map(|it| it.category == "Installed Applications")
             .unwrap_or(false)
     });
+    let includes_fast_delete = app_state.selected_items.iter().any(|&index| {
+        app_state
+            .all_items
+            .get(index)
+            .is_some_and(|it| app_state.is_fast_delete(&it.category))
+    });
 
     let mut warning_lines = vec![Line::from("")];
 
@@ -114,7 +140,7 @@ pub fn render(f: &mut Frame, app_state: &mut AppState) {
         // Handle singular/plural
         let item_text = if selected_count == 1 { "ITEM" } else { "ITEMS" };
 
-        warning_lines.push(Line::from(vec![
+        let mut delete_line = vec![
             Span::styled("  ⚠  DELETE ", Styles::warning()),
             Span::styled(
                 format!("{} {}", selected_count, item_text),
@@ -124,7 +150,27 @@ pub fn render(f: &mut Frame, app_state: &mut AppState) {
                 format!(" ({})", bytesize::to_string(selected_size, false)),
                 Styles::secondary(),
             ),
-        ]));
+        ];
+        // Hard links and NTFS compression mean less comes back than the sizes add up to
+        if let Some(estimate) = app_state.free_estimate {
+            let unreclaimable = estimate.unreclaimable_bytes();
+            if unreclaimable > 0 {
+                delete_line.push(Span::styled(
+                    format!(
+                        " → ~{} actually freed ({} shared by hard links or compressed)",
+                        bytesize::to_string(selected_size.saturating_sub(unreclaimable), false),
+                        bytesize::to_string(unreclaimable, false)
+                    ),
+                    Styles::secondary(),
+                ));
+            }
+        } else if app_state.free_estimate_job.is_some() {
+            delete_line.push(Span::styled(
+                " · estimating real space freed...",
+                Styles::muted(),
+            ));
+        }
+        warning_lines.push(Line::from(delete_line));
 
         // Add fun comparison if applicable
         if let Some(comparison) = fun_comparison(selected_size) {
@@ -145,6 +191,20 @@ pub fn render(f: &mut Frame, app_state: &mut AppState) {
                 "     Other items follow the selected delete mode",
                 Styles::secondary(),
             )]));
+        } else if let Some(overflow) = app_state.bin_overflow.first() {
+            let more = match app_state.bin_overflow.len() {
+                1 => String::new(),
+                n => format!(" (+{} more volumes)", n - 1),
+            };
+            warning_lines.push(Line::from(vec![Span::styled(
+                format!("     {}{}", overflow.describe(), more),
+                Styles::warning(),
+            )]));
+        } else if includes_fast_delete {
+            warning_lines.push(Line::from(vec![Span::styled(
+                "     Fast-delete folders are removed permanently; other files go to the Recycle Bin",
+                Styles::warning(),
+            )]));
         } else {
             warning_lines.push(Line::from(vec![Span::styled(
                 "     Files will be moved to Recycle Bin (recoverable)",
@@ -170,8 +230,17 @@ pub fn render(f: &mut Frame, app_state: &mut AppState) {
         ])
         .split(chunks[2]);
 
-    // Category summary table (smaller, on the left)
-    render_summary_table(f, items_chunks[0], app_state);
+    // Summary by risk tier above the free-space projection (on the left)
+    let volumes = app_state.volume_projections();
+    let summary_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(6),
+            Constraint::Length(volumes.len().max(1) as u16 + 3),
+        ])
+        .split(items_chunks[0]);
+    render_summary_table(f, summary_chunks[0], app_state);
+    render_volumes(f, summary_chunks[1], &volumes);
 
     // File list (larger, on the right)
     render_file_list(f, items_chunks[1], app_state);
@@ -192,7 +261,18 @@ pub fn render(f: &mut Frame, app_state: &mut AppState) {
             Span::styled("       [N] ", Styles::secondary()),
             Span::styled("Cancel", Styles::secondary()),
         ]),
-        Line::from(""),
+        if app_state.bin_overflow.is_empty() {
+            Line::from("")
+        } else {
+            Line::from(vec![
+                Span::styled("    [Q] ", Styles::emphasis()),
+                Span::styled("Quarantine instead", Styles::primary()),
+                Span::styled(
+                    " (items on full Recycle Bin volumes go to .wole-quarantine, restorable)",
+                    Styles::secondary(),
+                ),
+            ])
+        },
         Line::from(vec![
             Span::styled("    [P] ", Styles::warning()),
             Span::styled("Permanent Delete", Styles::warning()),
@@ -237,20 +317,43 @@ fn render_summary_table(f: &mut Frame, area: Rect, app_state: &AppState) {
         Cell::from("SIZE").style(Styles::header()),
     ])];
 
-    let mut category_vec: Vec<_> = category_stats.iter().collect();
-    // Sort by size descending, then by category name for stable ordering when sizes are equal
+    let tier_of = |category: &str| {
+        let safe = app_state
+            .category_groups()
+            .iter()
+            .find(|g| g.name == category)
+            .is_some_and(|g| g.safe);
+        app_state.risk_tier(category, safe)
+    };
+    let mut category_vec: Vec<_> = category_stats
+        .iter()
+        .map(|(category, stats)| (tier_of(category.as_str()), category, stats))
+        .collect();
+    // Group by tier, then sort by size descending and by category name for stable ordering
     category_vec.sort_by(|a, b| {
-        let size_cmp = b.1 .1.cmp(&a.1 .1);
-        if size_cmp == std::cmp::Ordering::Equal {
-            a.0.cmp(b.0) // Secondary sort by category name for stability
-        } else {
-            size_cmp
-        }
+        a.0.cmp(&b.0)
+            .then(b.2 .1.cmp(&a.2 .1))
+            .then_with(|| a.1.cmp(b.1))
     });
 
-    for (category, (count, size)) in category_vec {
+    let mut current_tier = None;
+    for (tier, category, (count, size)) in &category_vec {
+        if current_tier != Some(*tier) {
+            current_tier = Some(*tier);
+            let (tier_count, tier_size) = category_vec
+                .iter()
+                .filter(|(t, _, _)| t == tier)
+                .fold((0, 0), |(c, s), (_, _, (count, size))| {
+                    (c + count, s + size)
+                });
+            rows.push(Row::new(vec![
+                Cell::from(format!("  {}", tier.label())).style(tier_style(*tier)),
+                Cell::from(format!("{}", tier_count)).style(tier_style(*tier)),
+                Cell::from(bytesize::to_string(tier_size, false)).style(tier_style(*tier)),
+            ]));
+        }
         rows.push(Row::new(vec![
-            Cell::from(format!("  {}", category)),
+            Cell::from(format!("    {}", crate::i18n::t(category))),
             Cell::from(format!("{}", count)),
             Cell::from(bytesize::to_string(*size, false)),
         ]));
@@ -286,6 +389,53 @@ fn render_summary_table(f: &mut Frame, area: Rect, app_state: &AppState) {
     f.render_widget(table, area);
 }
 
+/// Free space per volume now and after cleaning
+fn render_volumes(f: &mut Frame, area: Rect, volumes: &[crate::tui::state::VolumeProjection]) {
+    let mut lines: Vec<Line> = volumes
+        .iter()
+        .map(|volume| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {:<6}", volume.mount_point.display()),
+                    Styles::emphasis(),
+                ),
+                Span::styled(
+                    format!("{:>9}", bytesize::to_string(volume.free_bytes, false)),
+                    Styles::secondary(),
+                ),
+                Span::styled(" → ", Styles::secondary()),
+                Span::styled(
+                    bytesize::to_string(volume.free_bytes + volume.freed_bytes, false),
+                    Styles::success(),
+                ),
+                Span::styled(
+                    format!(" (+{})", bytesize::to_string(volume.freed_bytes, false)),
+                    Styles::secondary(),
+                ),
+            ])
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  Free space unknown",
+            Styles::muted(),
+        )));
+    }
+    // Recycled files keep using the volume until the bin is emptied (P bypasses it)
+    lines.push(Line::from(Span::styled(
+        "  Recycled files count once the bin is emptied",
+        Styles::muted(),
+    )));
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Styles::border())
+            .title("FREE SPACE"),
+    );
+    f.render_widget(paragraph, area);
+}
+
 fn render_file_list(f: &mut Frame, area: Rect, app_state: &mut AppState) {
     let block = Block::default()
         .borders(Borders::ALL)
@@ -359,7 +509,16 @@ fn render_file_list(f: &mut Frame, area: Rect, app_state: &mut AppState) {
     // Track the current folder path at each nesting depth so items can be displayed
     // relative to their parent folder (tree-style).
     let mut folder_stack: Vec<String> = Vec::new();
-    let base_indent = if skip_category_header { "  " } else { "      " };
+    let base_indent = if skip_category_header {
+        "    "
+    } else {
+        "        "
+    };
+    let new_marker_width = if app_state.confirm_new_items.is_empty() {
+        0
+    } else {
+        5
+    };
 
     // Build display lines from row model
     let mut lines: Vec<Line> = Vec::new();
@@ -376,6 +535,64 @@ fn render_file_list(f: &mut Frame, area: Rect, app_state: &mut AppState) {
         let prefix = if is_cursor { ">" } else { " " };
 
         match *row {
+            crate::tui::state::ConfirmRow::TierHeader { tier } => {
+                folder_stack.clear();
+                let tier_items: Vec<usize> = confirm_groups
+                    .iter()
+                    .filter(|g| app_state.risk_tier(&g.name, g.safe) == tier)
+                    .flat_map(group_items)
+                    .collect();
+                let selected_in_tier = tier_items
+                    .iter()
+                    .filter(|idx| app_state.selected_items.contains(*idx))
+                    .count();
+                let new_in_tier = tier_items
+                    .iter()
+                    .filter(|idx| app_state.confirm_new_items.contains(*idx))
+                    .count();
+                let tier_size: u64 = tier_items
+                    .iter()
+                    .filter_map(|&idx| app_state.all_items.get(idx))
+                    .map(|item| item.size_bytes)
+                    .sum();
+                let (checkbox, checkbox_style) = tri_checkbox(selected_in_tier, tier_items.len());
+                let exp_marker = if app_state.confirm_collapsed_tiers.contains(&tier) {
+                    "▸"
+                } else {
+                    "▾"
+                };
+
+                let mut spans = vec![
+                    Span::styled(format!(" {} ", prefix), row_style),
+                    Span::styled(checkbox, checkbox_style),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("{} {:<10}", exp_marker, tier.label()),
+                        tier_style(tier),
+                    ),
+                    Span::styled(
+                        format!("{:>9}", bytesize::to_string(tier_size, false)),
+                        Styles::primary(),
+                    ),
+                    Span::styled(
+                        format!(
+                            "    {}/{} items · {}",
+                            selected_in_tier,
+                            tier_items.len(),
+                            tier.hint()
+                        ),
+                        Styles::secondary(),
+                    ),
+                ];
+                if new_in_tier > 0 {
+                    spans.push(Span::styled(
+                        format!("  +{} new", new_in_tier),
+                        Styles::accent(),
+                    ));
+                }
+                lines.push(Line::from(spans));
+                line_to_row.push(row_idx);
+            }
             crate::tui::state::ConfirmRow::CategoryHeader { cat_idx } => {
                 if skip_category_header {
                     continue;
@@ -390,26 +607,22 @@ fn render_file_list(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                 let icon_style = category_style(group.safe);
 
                 // Calculate selected items in this category
-                let item_indices: Vec<usize> = if group.grouped_by_folder {
-                    group
-                        .folder_groups
-                        .iter()
-                        .flat_map(|fg| fg.items.iter().copied())
-                        .collect()
-                } else {
-                    group.items.clone()
-                };
+                let item_indices = group_items(group);
                 let selected_in_group = item_indices
                     .iter()
                     .filter(|&&idx| app_state.selected_items.contains(&idx))
                     .count();
+                let new_in_group = item_indices
+                    .iter()
+                    .filter(|idx| app_state.confirm_new_items.contains(*idx))
+                    .count();
                 let total_in_group = item_indices.len();
 
                 let (checkbox, checkbox_style) = tri_checkbox(selected_in_group, total_in_group);
                 let exp_marker = if group.expanded { "▾" } else { "▸" };
 
-                lines.push(Line::from(vec![
-                    Span::styled(format!(" {} ", prefix), row_style),
+                let mut spans = vec![
+                    Span::styled(format!("   {} ", prefix), row_style),
                     Span::styled(checkbox, checkbox_style),
                     Span::raw(" "),
                     Span::styled(format!("{} {} ", exp_marker, icon), icon_style),
@@ -428,7 +641,14 @@ fn render_file_list(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                     } else {
                         Span::styled("  [review recommended]", Styles::warning())
                     },
-                ]));
+                ];
+                if new_in_group > 0 {
+                    spans.push(Span::styled(
+                        format!("  +{} new", new_in_group),
+                        Styles::accent(),
+                    ));
+                }
+                lines.push(Line::from(spans));
                 line_to_row.push(row_idx);
             }
             crate::tui::state::ConfirmRow::FolderHeader {
@@ -577,7 +797,7 @@ fn render_file_list(f: &mut Frame, area: Rect, app_state: &mut AppState) {
 
                 // Calculate fixed widths for metadata columns (same as results screen)
                 // Size column: 2 spaces + 8 chars (e.g., "793.7 MiB")
-                let metadata_width = 2 + 8;
+                let metadata_width = 2 + 8 + new_marker_width;
 
                 let fixed_prefix = indent.len()
                     + 3 /*prefix+spaces*/
@@ -608,13 +828,23 @@ fn render_file_list(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                 let padding_needed = name_column_width.saturating_sub(path_display.chars().count());
                 let path_display_padded = format!("{}{}", path_display, " ".repeat(padding_needed));
 
+                // Items that weren't in the previous confirm visit stand out
+                let is_new = app_state.confirm_new_items.contains(&item_idx);
                 lines.push(Line::from(vec![
                     Span::styled(format!("{}{} ", indent, prefix), row_style),
                     Span::styled(checkbox, checkbox_style),
                     Span::raw(" "),
                     Span::styled(format!("{} ", emoji), Styles::secondary()),
-                    Span::styled(path_display_padded, Styles::primary()),
+                    Span::styled(
+                        path_display_padded,
+                        if is_new {
+                            Styles::accent()
+                        } else {
+                            Styles::primary()
+                        },
+                    ),
                     Span::styled(format!("  {:>8}", size_str), Styles::secondary()),
+                    Span::styled(if is_new { "  NEW" } else { "" }, Styles::accent()),
                 ]));
                 line_to_row.push(row_idx);
             }
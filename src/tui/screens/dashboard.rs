@@ -1,7 +1,8 @@
 //! Dashboard screen - category selection
 
+use crate::i18n::t;
 use crate::tui::{
-    state::AppState,
+    state::{AppState, DashboardAction},
     theme::Styles,
     widgets::{
         logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
@@ -16,9 +17,7 @@ use ratatui::{
     Frame,
 };
 
-pub fn render(f: &mut Frame, app_state: &AppState) {
-    let area = f.area();
-
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
     let is_small = area.height < 20 || area.width < 60;
     let shortcuts_height = if is_small { 2 } else { 3 };
 
@@ -53,20 +52,10 @@ fn render_header(f: &mut Frame, area: Rect, _is_small: bool) {
 }
 
 fn render_actions(f: &mut Frame, area: Rect, app_state: &AppState) {
-    let actions = [
-        ("Scan", "Find cleanable files (safe, dry-run)"),
-        ("Clean", "Delete selected files"),
-        ("Analyze", "Explore disk usage (folder sizes)"),
-        ("Restore", "Restore files from deletion or Recycle Bin"),
-        ("Optimize", "Optimize Windows system performance"),
-        ("Status", "Real-time system health dashboard"),
-        ("Config", "View or modify settings"),
-    ];
-
-    let items: Vec<ListItem> = actions
+    let items: Vec<ListItem> = DashboardAction::ALL
         .iter()
         .enumerate()
-        .map(|(i, (action, desc))| {
+        .map(|(i, action)| {
             let is_selected = i == app_state.action_cursor && app_state.focus_actions;
             let action_style = if is_selected {
                 Styles::selected()
@@ -79,9 +68,9 @@ fn render_actions(f: &mut Frame, area: Rect, app_state: &AppState) {
             // Always show full description - no truncation
             let line = Line::from(vec![
                 Span::styled(prefix, action_style),
-                Span::styled(*action, action_style),
+                Span::styled(t(action.label()), action_style),
                 Span::raw("\n   "),
-                Span::styled(*desc, Styles::secondary()),
+                Span::styled(t(action.description()), Styles::secondary()),
             ]);
             ListItem::new(line)
         })
@@ -89,7 +78,7 @@ fn render_actions(f: &mut Frame, area: Rect, app_state: &AppState) {
 
     let border_style = Styles::border();
 
-    let title = "Actions";
+    let title = t("Actions");
 
     // Adaptive padding based on screen size
     let padding = if area.width < 30 {
@@ -140,11 +129,41 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
 
     let (text, style) = if let Some(msg) = &app_state.dashboard_message {
         (
-            msg.as_str(),
+            msg.clone(),
+            Styles::warning().add_modifier(ratatui::style::Modifier::BOLD),
+        )
+    } else if let Some(checkpoint) = &app_state.resumable_scan {
+        let partly = if checkpoint.partial.is_empty() {
+            String::new()
+        } else {
+            format!(", {} partly", checkpoint.partial.len())
+        };
+        (
+            format!(
+                "Resume previous scan? ({}/{} done{})  [R] Resume  [X] Discard",
+                checkpoint.done.len(),
+                checkpoint.categories.len(),
+                partly
+            ),
+            Styles::warning().add_modifier(ratatui::style::Modifier::BOLD),
+        )
+    } else if let Some(saved) = &app_state.saved_session {
+        (
+            format!(
+                "Restore last session? ({} items, {})  [S] Restore  [X] Discard",
+                saved.items(),
+                saved
+                    .saved_at
+                    .with_timezone(&chrono::Local)
+                    .format("%b %d %H:%M")
+            ),
             Styles::warning().add_modifier(ratatui::style::Modifier::BOLD),
         )
     } else {
-        ("What would you like to do?", Styles::primary())
+        (
+            t("What would you like to do?").to_string(),
+            Styles::primary(),
+        )
     };
 
     let title = Paragraph::new(text)
@@ -155,36 +174,69 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
     render_actions(f, action_chunks[1], app_state);
 
     // Categories section with proper spacing
+    let pinned = app_state.pinned_shortcuts();
+    let pinned_height = if pinned.is_empty() { 0 } else { 1 };
     let category_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(1), // Title
-            Constraint::Length(1), // Spacing
-            Constraint::Min(1),    // Categories list
+            Constraint::Length(1),             // Title
+            Constraint::Length(1),             // Spacing
+            Constraint::Length(pinned_height), // Pinned folders
+            Constraint::Min(1),                // Categories list
         ])
         .split(chunks[1]);
 
     // Title
     let title = Paragraph::new(Line::from(vec![Span::styled(
-        "Select categories to scan:",
+        t("Select categories to scan:"),
         Styles::header(),
     )]))
     .style(Styles::primary())
     .alignment(ratatui::layout::Alignment::Left);
     f.render_widget(title, category_chunks[0]);
 
+    // Scan root (changed with P)
+    let scan_path = Paragraph::new(Line::from(vec![
+        Span::styled(t("Scan path: "), Styles::secondary()),
+        Span::styled(app_state.scan_path.display().to_string(), Styles::primary()),
+        Span::styled(format!("  {}", t("(P to change)")), Styles::muted()),
+    ]));
+    f.render_widget(scan_path, category_chunks[1]);
+
+    // Pinned folders (1-9 analyze them)
+    if !pinned.is_empty() {
+        let pinned = Paragraph::new(Line::from(vec![
+            Span::styled(t("Pinned: "), Styles::secondary()),
+            Span::styled(pinned, Styles::primary()),
+            Span::styled(format!("  {}", t("(1-9 to analyze)")), Styles::muted()),
+        ]));
+        f.render_widget(pinned, category_chunks[2]);
+    }
+
     // Helper function to determine which group a category belongs to
     fn get_category_group(cat_name: &str) -> Option<&'static str> {
         match cat_name {
             "Trash" | "Temp Files" | "Browser Cache" | "Application Cache" | "System Cache"
             | "Empty Folders" => Some("A. Quick Clean (recommended)"),
-            "Build Artifacts" | "Package Cache" => Some("B. Developer Cleanup"),
+            "Build Artifacts"
+            | "Package Cache"
+            | "Toolchain Caches"
+            | "Python Environments"
+            | "Ignored Files (git)" => Some("B. Developer Cleanup"),
             "Installed Applications"
             | "Old Downloads"
             | "Large Files"
             | "Old Files"
-            | "Duplicates" => Some("C. Space Hunters (review required)"),
-            "Windows Update" | "Event Logs" => Some("D. Advanced (admin required)"),
+            | "Duplicates"
+            | "Log Files"
+            | "Game Caches"
+            | "ML Models"
+            | "Email Data"
+            | "Creative App Caches"
+            | "Device Backups" => Some("C. Space Hunters (review required)"),
+            "Windows Update" | "Event Logs" | "Thumbnail Cache" | "Crash Dumps" => {
+                Some("D. Advanced (admin required)")
+            }
             _ => None,
         }
     }
@@ -201,7 +253,7 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
             if let Some(group_name) = group {
                 // Add group header
                 items.push(ListItem::new(Line::from(vec![Span::styled(
-                    format!("  {}", group_name),
+                    format!("  {}", t(group_name)),
                     Styles::header(),
                 )])));
             }
@@ -238,10 +290,12 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
         let prefix = if is_selected { "> " } else { "  " };
         // Truncate description on small screens
         let max_desc_len = (area.width.saturating_sub(20) as usize).max(15);
-        let desc_text = if cat.description.len() > max_desc_len {
-            format!("{}...", &cat.description[..max_desc_len])
+        let description = t(&cat.description);
+        let desc_text = if description.chars().count() > max_desc_len {
+            let cut: String = description.chars().take(max_desc_len).collect();
+            format!("{}...", cut)
         } else {
-            cat.description.clone()
+            description.to_string()
         };
 
         // Make description less prominent than the category name
@@ -253,7 +307,7 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
             Span::styled(inner_content.0, inner_content.1),
             Span::styled("]", bracket_style),
             Span::raw(" "),
-            Span::styled(&cat.name, name_style),
+            Span::styled(t(&cat.name), name_style),
             Span::raw("  "),
             Span::styled(desc_text, desc_style),
         ]);
@@ -264,7 +318,7 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
     let border_style = Styles::border();
 
     // Adaptive title and padding
-    let title = "Categories";
+    let title = t("Categories");
 
     let padding = if area.width < 30 {
         ratatui::widgets::Padding::new(0, 1, 0, 1) // Minimal padding on small screens
@@ -286,5 +340,5 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
         let display_index = category_to_display[app_state.cursor];
         list_state.select(Some(display_index));
     }
-    f.render_stateful_widget(list, category_chunks[2], &mut list_state);
+    f.render_stateful_widget(list, category_chunks[3], &mut list_state);
 }
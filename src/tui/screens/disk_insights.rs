@@ -18,9 +18,7 @@ use ratatui::{
     Frame,
 };
 
-pub fn render(f: &mut Frame, app_state: &mut AppState) {
-    let area = f.area();
-
+pub fn render(f: &mut Frame, area: Rect, app_state: &mut AppState) {
     // Extract values we need to avoid borrowing issues
     let (insights_clone, current_path_clone, cursor, sort_by, selected_paths_clone) =
         if let crate::tui::state::Screen::DiskInsights {
@@ -137,13 +135,20 @@ fn render_header(
 fn render_search_bar(f: &mut Frame, area: Rect, app_state: &AppState) {
     let search_text = if app_state.search_mode {
         format!("/ {}_", app_state.search_query) // Cursor indicator
+    } else if let Some(message) = &app_state.results_message {
+        message.clone()
     } else if app_state.search_query.is_empty() {
-        "Press / to filter folders...".to_string()
+        let pinned = app_state.pinned_shortcuts();
+        if pinned.is_empty() {
+            "Press / to filter folders...".to_string()
+        } else {
+            format!("Press / to filter folders...   Pinned: {}", pinned)
+        }
     } else {
         format!("Filter: {} (Esc to clear)", app_state.search_query)
     };
 
-    let style = if app_state.search_mode {
+    let style = if app_state.search_mode || app_state.results_message.is_some() {
         Styles::emphasis()
     } else {
         Styles::secondary()
@@ -284,6 +289,28 @@ fn render_content(
             Span::raw("  "),
             Span::styled(files_str, Styles::secondary()),
         ]);
+        let pinned = app_state.config.ui.pinned_paths().contains(&child.path);
+        let line = if pinned {
+            let mut spans = line.spans;
+            spans.push(Span::styled("  (pinned)", Styles::emphasis()));
+            Line::from(spans)
+        } else {
+            line
+        };
+        let protected_pattern = crate::config::folder_exclusion_pattern(&child.path);
+        let line = if app_state
+            .config
+            .exclusions
+            .patterns
+            .contains(&protected_pattern)
+            || app_state.config.is_excluded(&child.path)
+        {
+            let mut spans = line.spans;
+            spans.push(Span::styled("  (protected)", Styles::secondary()));
+            Line::from(spans)
+        } else {
+            line
+        };
 
         items.push(ListItem::new(line));
     }
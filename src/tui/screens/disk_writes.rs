@@ -0,0 +1,191 @@
+//! Disk Writes screen - what is writing to a drive right now
+//!
+//! Opened with W from Status (or `wole status --writes`). The table covers
+//! the last few seconds of writes and is redrawn every frame; Tab switches
+//! between paths and processes.
+
+use crate::disk_writes::WINDOW_SECS;
+use crate::tui::{
+    state::{AppState, Screen},
+    theme::Styles,
+    widgets::{
+        logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
+        shortcuts::{get_shortcuts, render_shortcuts},
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Width of the process column
+const PROCESS_WIDTH: usize = 22;
+
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let is_small = area.height < 20 || area.width < 60;
+    let shortcuts_height = if is_small { 2 } else { 3 };
+    let header_height = LOGO_WITH_TAGLINE_HEIGHT;
+    let min_content_height = 5;
+
+    if area.height < header_height + min_content_height + shortcuts_height || area.width < 20 {
+        let msg = Paragraph::new("Terminal too small. Please resize to at least 20x25")
+            .style(Styles::warning())
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(min_content_height),
+            Constraint::Length(shortcuts_height),
+        ])
+        .split(area);
+
+    render_logo(f, chunks[0]);
+    render_tagline(f, chunks[0]);
+    render_content(f, chunks[1], app_state);
+
+    let shortcuts = get_shortcuts(&app_state.screen, Some(app_state));
+    render_shortcuts(f, chunks[2], &shortcuts);
+}
+
+fn render_content(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let Screen::DiskWrites {
+        volume,
+        monitor,
+        by_process,
+        message,
+        ..
+    } = &app_state.screen
+    else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Volume and totals
+            Constraint::Length(1), // Tabs
+            Constraint::Length(1), // Spacing
+            Constraint::Length(1), // Column headers
+            Constraint::Min(2),    // Rows
+        ])
+        .split(area);
+
+    let Some(monitor) = monitor else {
+        f.render_widget(
+            Paragraph::new(format!("Not watching {}", volume)).style(Styles::secondary()),
+            chunks[0],
+        );
+        if let Some(message) = message {
+            f.render_widget(
+                Paragraph::new(message.as_str()).style(Styles::warning()),
+                chunks[1],
+            );
+        }
+        return;
+    };
+
+    let (bytes, writes) = monitor.total();
+    f.render_widget(
+        Paragraph::new(format!(
+            "Writes to {} in the last {} s  •  {}  •  {} writes",
+            monitor.volume(),
+            WINDOW_SECS,
+            bytesize::to_string(bytes, false),
+            writes
+        ))
+        .style(Styles::secondary()),
+        chunks[0],
+    );
+
+    let tab = |label: &'static str, active: bool| {
+        Span::styled(
+            label,
+            if active {
+                Styles::emphasis()
+            } else {
+                Styles::muted()
+            },
+        )
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            tab("By path", !*by_process),
+            Span::styled("  │  ", Styles::muted()),
+            tab("By process", *by_process),
+        ])),
+        chunks[1],
+    );
+
+    let last = if *by_process { "Files" } else { "Path" };
+    f.render_widget(
+        Paragraph::new(format!(
+            "{:>10}  {:>7}  {:<width$}  {}",
+            "Written",
+            "Writes",
+            "Process",
+            last,
+            width = PROCESS_WIDTH
+        ))
+        .style(Styles::muted()),
+        chunks[3],
+    );
+
+    let limit = chunks[4].height.max(1) as usize;
+    let lines: Vec<Line> = if *by_process {
+        monitor
+            .processes(limit)
+            .into_iter()
+            .map(|p| row(p.bytes, p.writes, &p.process, p.pid, p.files.to_string()))
+            .collect()
+    } else {
+        monitor
+            .top(limit)
+            .into_iter()
+            .map(|w| {
+                row(
+                    w.bytes,
+                    w.writes,
+                    &w.process,
+                    w.pid,
+                    w.path.display().to_string(),
+                )
+            })
+            .collect()
+    };
+
+    if lines.is_empty() {
+        f.render_widget(
+            Paragraph::new("No writes yet").style(Styles::muted()),
+            chunks[4],
+        );
+    } else {
+        f.render_widget(Paragraph::new(lines), chunks[4]);
+    }
+}
+
+fn row(bytes: u64, writes: u64, process: &str, pid: u32, last: String) -> Line<'static> {
+    let process = format!("{} ({})", process, pid);
+    Line::from(vec![
+        Span::styled(
+            format!("{:>10}  ", bytesize::to_string(bytes, false)),
+            Styles::emphasis(),
+        ),
+        Span::styled(format!("{:>7}  ", writes), Styles::secondary()),
+        Span::styled(
+            format!(
+                "{:<width$}  ",
+                process.chars().take(PROCESS_WIDTH).collect::<String>(),
+                width = PROCESS_WIDTH
+            ),
+            Styles::muted(),
+        ),
+        Span::styled(last, Styles::primary()),
+    ])
+}
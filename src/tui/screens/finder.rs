@@ -0,0 +1,155 @@
+//! Finder screen - fuzzy search over every result item, fzf-style
+//!
+//! Opened with Ctrl+F from Results. Typing filters all items across
+//! categories, Tab marks them and Enter sends the marks to Confirm.
+
+use crate::tui::{
+    state::{AppState, Screen},
+    theme::Styles,
+    widgets::{
+        logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
+        shortcuts::{get_shortcuts, render_shortcuts},
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Width of the category column
+const CATEGORY_WIDTH: usize = 18;
+
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let is_small = area.height < 20 || area.width < 60;
+    let shortcuts_height = if is_small { 2 } else { 3 };
+    let header_height = LOGO_WITH_TAGLINE_HEIGHT;
+    let min_content_height = 5;
+
+    if area.height < header_height + min_content_height + shortcuts_height || area.width < 20 {
+        let msg = Paragraph::new("Terminal too small. Please resize to at least 20x25")
+            .style(Styles::warning())
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(min_content_height),
+            Constraint::Length(shortcuts_height),
+        ])
+        .split(area);
+
+    render_logo(f, chunks[0]);
+    render_tagline(f, chunks[0]);
+    render_content(f, chunks[1], app_state);
+
+    let shortcuts = get_shortcuts(&app_state.screen, Some(app_state));
+    render_shortcuts(f, chunks[2], &shortcuts);
+}
+
+fn render_content(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let Screen::Finder {
+        query,
+        matches,
+        cursor,
+        marked,
+    } = &app_state.screen
+    else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Query
+            Constraint::Length(1), // Counts
+            Constraint::Length(1), // Spacing
+            Constraint::Min(2),    // Matches
+        ])
+        .split(area);
+
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("> ", Styles::emphasis()),
+            Span::styled(query.clone(), Styles::primary()),
+            Span::styled("_", Styles::emphasis()),
+        ])),
+        chunks[0],
+    );
+
+    let marked_size: u64 = marked
+        .iter()
+        .filter_map(|&i| app_state.all_items.get(i))
+        .map(|item| item.size_bytes)
+        .sum();
+    f.render_widget(
+        Paragraph::new(format!(
+            "{}/{} items  •  {} marked ({})",
+            matches.len(),
+            app_state.all_items.len(),
+            marked.len(),
+            bytesize::to_string(marked_size, false)
+        ))
+        .style(Styles::secondary()),
+        chunks[1],
+    );
+
+    if matches.is_empty() {
+        f.render_widget(
+            Paragraph::new("No items match").style(Styles::muted()),
+            chunks[3],
+        );
+        return;
+    }
+
+    // Only the visible window is turned into list items
+    let height = chunks[3].height.max(1) as usize;
+    let cursor = (*cursor).min(matches.len() - 1);
+    let start = (cursor + 1).saturating_sub(height);
+    let items: Vec<ListItem> = matches
+        .iter()
+        .skip(start)
+        .take(height)
+        .filter_map(|&i| app_state.all_items.get(i).map(|item| (i, item)))
+        .map(|(i, item)| {
+            let (checkbox, checkbox_style) = if marked.contains(&i) {
+                ("[X]", Styles::checked())
+            } else {
+                ("[ ]", Styles::secondary())
+            };
+            let name = match &item.display_name {
+                Some(name) => name.clone(),
+                None => item.path.display().to_string(),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} ", checkbox), checkbox_style),
+                Span::styled(
+                    format!("{:>10}  ", bytesize::to_string(item.size_bytes, false)),
+                    Styles::emphasis(),
+                ),
+                Span::styled(
+                    format!(
+                        "{:<width$}  ",
+                        item.category
+                            .chars()
+                            .take(CATEGORY_WIDTH)
+                            .collect::<String>(),
+                        width = CATEGORY_WIDTH
+                    ),
+                    Styles::muted(),
+                ),
+                Span::styled(name, Styles::primary()),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(Styles::selected());
+    let mut state = ListState::default();
+    state.select(Some(cursor - start));
+    f.render_stateful_widget(list, chunks[3], &mut state);
+}
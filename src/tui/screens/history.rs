@@ -0,0 +1,221 @@
+//! History screen - past cleanup sessions, with drill-down into their records
+
+use crate::history::HistorySession;
+use crate::tui::{
+    state::AppState,
+    theme::Styles,
+    widgets::{
+        logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
+        shortcuts::{get_shortcuts, render_shortcuts},
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let is_small = area.height < 20 || area.width < 60;
+    let shortcuts_height = if is_small { 2 } else { 3 };
+    let header_height = LOGO_WITH_TAGLINE_HEIGHT;
+    let min_content_height = 6;
+
+    if area.height < header_height + min_content_height + shortcuts_height || area.width < 20 {
+        let msg = Paragraph::new("Terminal too small. Please resize to at least 20x25")
+            .style(Styles::warning())
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(min_content_height),
+            Constraint::Length(shortcuts_height),
+        ])
+        .split(area);
+
+    render_logo(f, chunks[0]);
+    render_tagline(f, chunks[0]);
+    render_content(f, chunks[1], app_state);
+
+    let shortcuts = get_shortcuts(&app_state.screen, Some(app_state));
+    render_shortcuts(f, chunks[2], &shortcuts);
+}
+
+fn render_content(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let crate::tui::state::Screen::History {
+        sessions,
+        filter,
+        editing_filter,
+        cursor,
+        open_session,
+        record_cursor,
+        message,
+    } = &app_state.screen
+    else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Title
+            Constraint::Length(1), // Filter
+            Constraint::Length(1), // Message
+            Constraint::Min(2),    // Sessions or records
+        ])
+        .split(area);
+
+    let title = match open_session.and_then(|i| sessions.get(i)) {
+        Some(session) => format!("Cleanup history › {}", session_date(session)),
+        None => "Cleanup history".to_string(),
+    };
+    f.render_widget(Paragraph::new(title).style(Styles::primary()), chunks[0]);
+
+    let filter_line = if *editing_filter {
+        Line::from(vec![
+            Span::styled("Filter: ", Styles::emphasis()),
+            Span::styled(format!("{}_", filter), Styles::primary()),
+        ])
+    } else if filter.is_empty() {
+        Line::from(Span::styled(
+            "Press / to filter by category or path",
+            Styles::muted(),
+        ))
+    } else {
+        Line::from(vec![
+            Span::styled("Filter: ", Styles::secondary()),
+            Span::styled(filter.as_str(), Styles::primary()),
+        ])
+    };
+    f.render_widget(Paragraph::new(filter_line), chunks[1]);
+
+    if let Some(message) = message {
+        f.render_widget(
+            Paragraph::new(message.as_str()).style(Styles::secondary()),
+            chunks[2],
+        );
+    }
+
+    match open_session.and_then(|i| sessions.get(i)) {
+        Some(session) => render_records(f, chunks[3], session, filter, *record_cursor),
+        None => render_sessions(f, chunks[3], sessions, filter, *cursor),
+    }
+}
+
+fn session_date(session: &HistorySession) -> String {
+    session
+        .log
+        .session_start
+        .with_timezone(&chrono::Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+fn render_sessions(
+    f: &mut Frame,
+    area: Rect,
+    sessions: &[HistorySession],
+    filter: &str,
+    cursor: usize,
+) {
+    let visible: Vec<&HistorySession> = sessions.iter().filter(|s| s.matches(filter)).collect();
+    if visible.is_empty() {
+        let text = if sessions.is_empty() {
+            "No cleanup sessions recorded yet"
+        } else {
+            "No sessions match the filter"
+        };
+        f.render_widget(Paragraph::new(text).style(Styles::muted()), area);
+        return;
+    }
+
+    let items: Vec<ListItem> = visible
+        .iter()
+        .map(|session| {
+            let log = &session.log;
+            let errors_style = if log.errors > 0 {
+                Styles::warning()
+            } else {
+                Styles::secondary()
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{}  ", session_date(session)), Styles::primary()),
+                Span::styled(
+                    format!(
+                        "{:>10}  ",
+                        bytesize::to_string(log.total_bytes_cleaned, false)
+                    ),
+                    Styles::emphasis(),
+                ),
+                Span::styled(
+                    format!("{:>5} items  ", log.total_items - log.errors),
+                    Styles::secondary(),
+                ),
+                Span::styled(format!("{:>3} errors  ", log.errors), errors_style),
+                Span::styled(log.categories().join(", "), Styles::muted()),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(Styles::selected());
+    let mut state = ListState::default();
+    state.select(Some(cursor.min(visible.len() - 1)));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn render_records(
+    f: &mut Frame,
+    area: Rect,
+    session: &HistorySession,
+    filter: &str,
+    cursor: usize,
+) {
+    let records: Vec<_> = session
+        .log
+        .records
+        .iter()
+        .filter(|r| r.matches(filter))
+        .collect();
+    if records.is_empty() {
+        f.render_widget(
+            Paragraph::new("No records match the filter").style(Styles::muted()),
+            area,
+        );
+        return;
+    }
+
+    let items: Vec<ListItem> = records
+        .iter()
+        .map(|record| {
+            let (status, status_style) = match (record.success, record.permanent) {
+                (false, _) => ("failed   ", Styles::warning()),
+                (true, true) => ("permanent", Styles::secondary()),
+                (true, false) => ("recycled ", Styles::success()),
+            };
+            let mut spans = vec![
+                Span::styled(format!("{}  ", status), status_style),
+                Span::styled(
+                    format!("{:>10}  ", bytesize::to_string(record.size_bytes, false)),
+                    Styles::emphasis(),
+                ),
+                Span::styled(format!("{:<12} ", record.category), Styles::muted()),
+                Span::styled(record.path.as_str(), Styles::primary()),
+            ];
+            if let Some(ref error) = record.error {
+                spans.push(Span::styled(format!("  ({})", error), Styles::warning()));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(Styles::selected());
+    let mut state = ListState::default();
+    state.select(Some(cursor.min(records.len() - 1)));
+    f.render_stateful_widget(list, area, &mut state);
+}
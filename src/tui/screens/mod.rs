@@ -1,38 +1,89 @@
 //! Screen rendering modules
 
+pub mod app_caches;
+pub mod breakdown;
 pub mod config;
 pub mod confirm;
 pub mod dashboard;
 pub mod disk_insights;
+pub mod disk_writes;
+pub mod finder;
+pub mod history;
+pub mod net_check;
 pub mod optimize;
 pub mod preview;
 pub mod restore;
 pub mod restore_selection;
 pub mod results;
+pub mod saved_views;
+pub mod scan_path;
 pub mod scanning;
+pub mod search;
+pub mod select_rule;
 pub mod status;
 pub mod success;
+pub mod what_changed;
 
 use crate::tui::state::AppState;
-use ratatui::Frame;
+use crate::tui::widgets::{help, sidebar, toasts};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    Frame,
+};
 
 /// Main render function that dispatches to the appropriate screen
+///
+/// On wide terminals the sidebar takes the left edge and the screen gets the
+/// rest.
 pub fn render(f: &mut Frame, app_state: &mut AppState) {
+    app_state.toasts.prune();
+    let full = f.area();
+    app_state.sidebar_width = sidebar::sidebar_width(full.width);
+    let area = if app_state.sidebar_width > 0 {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(app_state.sidebar_width),
+                Constraint::Min(1),
+            ])
+            .split(full);
+        sidebar::render_sidebar(f, chunks[0], app_state);
+        chunks[1]
+    } else {
+        full
+    };
+
     match app_state.screen {
-        crate::tui::state::Screen::Dashboard => dashboard::render(f, app_state),
-        crate::tui::state::Screen::Config => config::render(f, app_state),
-        crate::tui::state::Screen::Scanning { .. } => scanning::render(f, app_state),
-        crate::tui::state::Screen::Results => results::render(f, app_state),
-        crate::tui::state::Screen::Preview { .. } => preview::render(f, app_state),
-        crate::tui::state::Screen::Confirm { .. } => confirm::render(f, app_state),
-        crate::tui::state::Screen::Cleaning { .. } => scanning::render_cleaning(f, app_state),
-        crate::tui::state::Screen::Success { .. } => success::render(f, app_state),
+        crate::tui::state::Screen::Dashboard => dashboard::render(f, area, app_state),
+        crate::tui::state::Screen::Config => config::render(f, area, app_state),
+        crate::tui::state::Screen::Scanning { .. } => scanning::render(f, area, app_state),
+        crate::tui::state::Screen::Results => results::render(f, area, app_state),
+        crate::tui::state::Screen::Preview { .. } => preview::render(f, area, app_state),
+        crate::tui::state::Screen::Confirm { .. } => confirm::render(f, area, app_state),
+        crate::tui::state::Screen::Cleaning { .. } => scanning::render_cleaning(f, area, app_state),
+        crate::tui::state::Screen::Success { .. } => success::render(f, area, app_state),
         crate::tui::state::Screen::RestoreSelection { .. } => {
-            restore_selection::render(f, app_state)
+            restore_selection::render(f, area, app_state)
         }
-        crate::tui::state::Screen::Restore { .. } => restore::render(f, app_state),
-        crate::tui::state::Screen::DiskInsights { .. } => disk_insights::render(f, app_state),
-        crate::tui::state::Screen::Optimize { .. } => optimize::render(f, app_state),
-        crate::tui::state::Screen::Status { .. } => status::render(f, app_state),
+        crate::tui::state::Screen::Restore { .. } => restore::render(f, area, app_state),
+        crate::tui::state::Screen::DiskInsights { .. } => disk_insights::render(f, area, app_state),
+        crate::tui::state::Screen::Optimize { .. } => optimize::render(f, area, app_state),
+        crate::tui::state::Screen::Status { .. } => status::render(f, area, app_state),
+        crate::tui::state::Screen::WhatChanged { .. } => what_changed::render(f, area, app_state),
+        crate::tui::state::Screen::Search { .. } => search::render(f, area, app_state),
+        crate::tui::state::Screen::History { .. } => history::render(f, area, app_state),
+        crate::tui::state::Screen::ScanPathPicker { .. } => scan_path::render(f, area, app_state),
+        crate::tui::state::Screen::AppCaches { .. } => app_caches::render(f, area, app_state),
+        crate::tui::state::Screen::Finder { .. } => finder::render(f, area, app_state),
+        crate::tui::state::Screen::Breakdown { .. } => breakdown::render(f, area, app_state),
+        crate::tui::state::Screen::DiskWrites { .. } => disk_writes::render(f, area, app_state),
+        crate::tui::state::Screen::NetCheck { .. } => net_check::render(f, area, app_state),
+        crate::tui::state::Screen::SavedViews { .. } => saved_views::render(f, area, app_state),
+        crate::tui::state::Screen::SelectRule { .. } => select_rule::render(f, area, app_state),
+    }
+
+    toasts::render_toasts(f, area, &app_state.toasts);
+    if let Some(help) = &app_state.help {
+        help::render_help(f, area, app_state, help);
     }
 }
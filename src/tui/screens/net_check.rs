@@ -0,0 +1,120 @@
+//! Network Check screen - hosts file, proxy and DNS leftovers
+//!
+//! Opened with N from Status. Lists what `wole netcheck` reports; R reverts
+//! the issue under the cursor after backing up its previous state, then the
+//! settings are checked again.
+
+use crate::tui::{
+    state::{AppState, Screen},
+    theme::Styles,
+    widgets::{
+        logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
+        shortcuts::{get_shortcuts, render_shortcuts},
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Width of the kind column
+const LABEL_WIDTH: usize = 10;
+
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let is_small = area.height < 20 || area.width < 60;
+    let shortcuts_height = if is_small { 2 } else { 3 };
+    let header_height = LOGO_WITH_TAGLINE_HEIGHT;
+    let min_content_height = 5;
+
+    if area.height < header_height + min_content_height + shortcuts_height || area.width < 20 {
+        let msg = Paragraph::new("Terminal too small. Please resize to at least 20x25")
+            .style(Styles::warning())
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(min_content_height),
+            Constraint::Length(shortcuts_height),
+        ])
+        .split(area);
+
+    render_logo(f, chunks[0]);
+    render_tagline(f, chunks[0]);
+    render_content(f, chunks[1], app_state);
+
+    let shortcuts = get_shortcuts(&app_state.screen, Some(app_state));
+    render_shortcuts(f, chunks[2], &shortcuts);
+}
+
+fn render_content(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let Screen::NetCheck {
+        issues,
+        cursor,
+        message,
+        ..
+    } = &app_state.screen
+    else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Title
+            Constraint::Length(1), // Message
+            Constraint::Length(1), // Spacing
+            Constraint::Min(2),    // Issues
+        ])
+        .split(area);
+
+    f.render_widget(
+        Paragraph::new("Hosts file, proxy and DNS leftovers").style(Styles::primary()),
+        chunks[0],
+    );
+    if let Some(message) = message {
+        f.render_widget(
+            Paragraph::new(message.as_str()).style(Styles::secondary()),
+            chunks[1],
+        );
+    }
+
+    if issues.is_empty() {
+        f.render_widget(
+            Paragraph::new("Nothing unusual found").style(Styles::success()),
+            chunks[3],
+        );
+        return;
+    }
+
+    let items: Vec<ListItem> = issues
+        .iter()
+        .map(|issue| {
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<width$}  ", issue.label(), width = LABEL_WIDTH),
+                        Styles::emphasis(),
+                    ),
+                    Span::styled(issue.summary(), Styles::primary()),
+                ]),
+                Line::from(vec![
+                    Span::raw(" ".repeat(LABEL_WIDTH + 2)),
+                    Span::styled(issue.reason().to_string(), Styles::warning()),
+                    Span::styled(format!(" · R: {}", issue.revert_action()), Styles::muted()),
+                ]),
+            ])
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(Styles::selected());
+    let mut state = ListState::default();
+    state.select(Some((*cursor).min(issues.len() - 1)));
+    f.render_stateful_widget(list, chunks[3], &mut state);
+}
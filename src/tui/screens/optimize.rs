@@ -1,6 +1,6 @@
 //! Optimize screen - Windows system optimization
 
-use crate::optimize::OptimizeResult;
+use crate::optimize::{OptimizeResult, OPERATIONS};
 use crate::tui::{
     state::AppState,
     theme::Styles,
@@ -16,9 +16,7 @@ use ratatui::{
     Frame,
 };
 
-pub fn render(f: &mut Frame, app_state: &AppState) {
-    let area = f.area();
-
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
     let is_small = area.height < 20 || area.width < 60;
     let shortcuts_height = if is_small { 2 } else { 3 };
 
@@ -65,6 +63,7 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
         results,
         running,
         message,
+        admin,
     } = &app_state.screen
     {
         // Calculate how much space we need
@@ -127,7 +126,7 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
             render_results_with_message(f, chunks[2], results, cursor, message);
         } else {
             // Show optimization options
-            render_options(f, chunks[2], cursor, selected);
+            render_options(f, chunks[2], cursor, selected, *admin);
         }
     }
 }
@@ -137,44 +136,8 @@ fn render_options(
     area: Rect,
     cursor: &usize,
     selected: &std::collections::HashSet<usize>,
+    admin: bool,
 ) {
-    let options = [
-        ("DNS Cache", "Flush DNS cache (ipconfig /flushdns)", false),
-        ("Thumbnails", "Clear thumbnail cache", false),
-        ("Icons", "Rebuild icon cache and restart Explorer", false),
-        ("Databases", "Optimize browser databases (VACUUM)", false),
-        (
-            "Fonts",
-            "Restart Font Cache Service - fixes font display issues (requires admin)",
-            true,
-        ),
-        (
-            "Memory",
-            "Clear standby memory - frees up RAM (requires admin)",
-            true,
-        ),
-        (
-            "Network",
-            "Reset network stack - fixes connection issues (requires admin)",
-            true,
-        ),
-        (
-            "Bluetooth",
-            "Restart Bluetooth service - fixes Bluetooth problems (requires admin)",
-            true,
-        ),
-        (
-            "Search",
-            "Restart Windows Search - rebuilds search index (requires admin)",
-            true,
-        ),
-        (
-            "Explorer",
-            "Restart Windows Explorer - refreshes desktop and file manager",
-            false,
-        ),
-    ];
-
     // Ensure area is valid (at least 7x20 for borders, padding, and at least one item)
     // List needs: 2 borders + 2 padding + 2 lines per item = minimum 6, but use 7 to be safe
     if area.width < 20 || area.height < 7 {
@@ -208,45 +171,82 @@ fn render_options(
     let max_desc_width = safe_area.width.saturating_sub(20).max(20) as usize;
 
     // Create items, but limit rendering to what fits
-    let items: Vec<ListItem> = options
+    let items: Vec<ListItem> = OPERATIONS
         .iter()
         .enumerate()
-        .map(|(i, (name, desc, needs_admin))| {
+        .map(|(i, op)| {
             let is_selected = i == *cursor;
             let is_checked = selected.contains(&i);
+            let available = op.available(admin);
             let name_style = if is_selected {
                 Styles::selected()
-            } else {
+            } else if available {
                 Styles::emphasis()
+            } else {
+                Styles::muted()
             };
 
             let prefix = if is_selected { "> " } else { "  " };
-            let checkbox = if is_checked { "[X]" } else { "[ ]" };
+            let checkbox = if !available {
+                "[-]"
+            } else if is_checked {
+                "[X]"
+            } else {
+                "[ ]"
+            };
             let checkbox_style = if is_checked {
                 Styles::checked()
             } else {
                 Styles::secondary()
             };
 
-            let admin_note = if *needs_admin { " (admin)" } else { "" };
-
+            let mut spans = vec![
+                Span::styled(prefix, name_style),
+                Span::styled(checkbox, checkbox_style),
+                Span::raw(" "),
+                Span::styled(op.name, name_style),
+            ];
+            if op.requires_admin {
+                let admin_style = if available {
+                    Styles::muted()
+                } else {
+                    Styles::warning()
+                };
+                spans.push(Span::styled(" (admin)", admin_style));
+            }
+            spans.push(Span::styled(
+                format!(" · {}", op.estimate.label()),
+                Styles::muted(),
+            ));
+            if let Some(interrupts) = op.interrupts {
+                spans.push(Span::styled(
+                    format!(" · {}", interrupts),
+                    Styles::warning(),
+                ));
+            }
+
+            let desc = if available {
+                op.description.to_string()
+            } else {
+                format!(
+                    "{} - restart wole as Administrator to run it",
+                    op.description
+                )
+            };
             // Truncate description if too long to prevent wrapping/overflow
-            let desc_text = if desc.len() > max_desc_width {
-                format!("{}...", &desc[..max_desc_width.saturating_sub(3)])
+            let desc_text = if desc.chars().count() > max_desc_width {
+                let kept: String = desc
+                    .chars()
+                    .take(max_desc_width.saturating_sub(3))
+                    .collect();
+                format!("{}...", kept)
             } else {
-                desc.to_string()
+                desc
             };
+            spans.push(Span::raw("\n   "));
+            spans.push(Span::styled(desc_text, Styles::secondary()));
 
-            let line = Line::from(vec![
-                Span::styled(prefix, name_style),
-                Span::styled(checkbox, checkbox_style),
-                Span::raw(" "),
-                Span::styled(*name, name_style),
-                Span::styled(admin_note, Styles::muted()),
-                Span::raw("\n   "),
-                Span::styled(desc_text, Styles::secondary()),
-            ]);
-            ListItem::new(line)
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -370,14 +370,19 @@ fn render_results(f: &mut Frame, area: Rect, results: &[OptimizeResult], cursor:
 
             let prefix = if is_selected { "> " } else { "  " };
 
-            let line = Line::from(vec![
+            let mut spans = vec![
                 Span::styled(prefix, action_style),
                 Span::styled(icon, icon_style),
                 Span::raw(" "),
                 Span::styled(&result.action, action_style),
                 Span::raw(" - "),
                 Span::styled(&result.message, message_style),
-            ]);
+            ];
+            if let Some(snapshot) = &result.snapshot {
+                spans.push(Span::styled(" · ", Styles::muted()));
+                spans.push(Span::styled(snapshot.describe(), Styles::primary()));
+            }
+            let line = Line::from(spans);
             ListItem::new(line)
         })
         .collect();
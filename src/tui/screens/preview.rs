@@ -17,9 +17,7 @@ use ratatui::{
 };
 use std::path::Path;
 
-pub fn render(f: &mut Frame, app_state: &AppState) {
-    let area = f.area();
-
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
     // Layout: logo+tagline, warning message, split view, shortcuts
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -97,7 +95,13 @@ pub fn render(f: &mut Frame, app_state: &AppState) {
         );
 
         // Right: Will Delete preview
-        render_delete_preview(f, split_chunks[1], item, &app_state.scan_path);
+        render_delete_preview(
+            f,
+            split_chunks[1],
+            item,
+            &app_state.scan_path,
+            app_state.config.ui.auto_select_aggressiveness,
+        );
     }
 
     // Shortcuts
@@ -110,6 +114,7 @@ fn render_delete_preview(
     area: Rect,
     item: &crate::tui::state::ResultItem,
     base_path: &Path,
+    aggressiveness: u8,
 ) {
     let path_display = crate::utils::to_relative_path(&item.path, base_path);
     let path_truncated = if path_display.len() > 50 {
@@ -118,7 +123,7 @@ fn render_delete_preview(
         path_display
     };
 
-    let lines = vec![
+    let mut lines = vec![
         Line::from(vec![Span::styled(
             "THIS FILE WILL BE DELETED:",
             Styles::danger(),
@@ -157,13 +162,108 @@ fn render_delete_preview(
                 },
             ),
         ]),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "  ⚠ Remember: [D] deletes ALL selected files, not just this one",
-            Styles::warning(),
-        )]),
     ];
 
+    if let Some(owner) = &item.owner {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("  Owner: ", Styles::header()),
+            Span::styled(owner.clone(), Styles::primary()),
+        ]));
+    }
+
+    if let Some(why) = item.sensitive {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("  Personal data: ", Styles::header()),
+            Span::styled(
+                format!(
+                    "possibly {} (by name) - never pre-selected, check before deleting",
+                    why
+                ),
+                Styles::danger(),
+            ),
+        ]));
+    }
+
+    if crate::ads::enabled() {
+        let streams: Vec<String> = crate::ads::streams(&item.path)
+            .iter()
+            .map(|s| format!("{} ({})", s.name, bytesize::to_string(s.size, false)))
+            .collect();
+        if !streams.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("  Streams: ", Styles::header()),
+                Span::styled(streams.join(", "), Styles::secondary()),
+            ]));
+        }
+    }
+
+    if let Some(provider) = item.cloud {
+        let mut spans = vec![
+            Span::styled("  Cloud: ", Styles::header()),
+            Span::styled(
+                format!(
+                    "synced with {} - deleting also deletes it from the cloud and other devices",
+                    provider.label()
+                ),
+                Styles::warning(),
+            ),
+        ];
+        if provider.can_free_up_space() {
+            spans.push(Span::styled(
+                " (F frees up space instead)",
+                Styles::secondary(),
+            ));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(spans));
+    }
+
+    if let Some(command) = item.rebuild_command {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("  Regenerable: ", Styles::header()),
+            Span::styled(
+                format!("run `{}` in the project", command),
+                Styles::success(),
+            ),
+        ]));
+    }
+
+    // Why the heuristic did or didn't pre-select this item
+    let auto_selected = item.selection.selected(aggressiveness);
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  Auto-select: ", Styles::header()),
+        Span::styled(format!("{}/100", item.selection.score), Styles::emphasis()),
+        Span::styled(
+            if auto_selected {
+                " - pre-selected because:"
+            } else {
+                " - not pre-selected because:"
+            },
+            Styles::secondary(),
+        ),
+    ]));
+    for reason in &item.selection.reasons {
+        lines.push(Line::from(vec![Span::styled(
+            format!("    {}", reason),
+            if reason.starts_with('-') {
+                Styles::warning()
+            } else {
+                Styles::success()
+            },
+        )]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "  ⚠ Remember: [D] deletes ALL selected files, not just this one",
+        Styles::warning(),
+    )]));
+
     let paragraph = Paragraph::new(lines).block(
         Block::default()
             .borders(Borders::ALL)
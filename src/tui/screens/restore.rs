@@ -17,9 +17,10 @@ use ratatui::{
     Frame,
 };
 
-pub fn render(f: &mut Frame, app_state: &AppState) {
-    let area = f.area();
+/// Incomplete restores listed by path before the rest are only counted
+const MAX_MISMATCHES_SHOWN: usize = 5;
 
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
     let is_small = area.height < 20 || area.width < 60;
     let shortcuts_height = if is_small { 2 } else { 3 };
 
@@ -56,8 +57,16 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
         ref progress,
         ref result,
         restore_all_bin,
+        ref log_path,
     } = app_state.screen
     {
+        // Sessions picked in the History screen aren't necessarily the last one
+        let session = if log_path.is_some() {
+            "the selected deletion session"
+        } else {
+            "last deletion session"
+        };
+
         if let Some(ref restore_result) = result {
             // Show restore results
             let chunks = Layout::default()
@@ -161,14 +170,58 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
                 ]));
             }
 
+            if !restore_result.mismatches.is_empty() {
+                lines.push(Line::from(vec![
+                    Span::styled("Incomplete: ", Styles::primary()),
+                    Span::styled(
+                        format!(
+                            "{} items don't match their deletion record",
+                            restore_result.mismatches.len()
+                        ),
+                        Styles::warning(),
+                    ),
+                ]));
+                let max_len = (area.width as usize).saturating_sub(4);
+                for mismatch in restore_result.mismatches.iter().take(MAX_MISMATCHES_SHOWN) {
+                    let chars = mismatch.chars().count();
+                    let display = if chars > max_len {
+                        let skip = chars.saturating_sub(max_len.saturating_sub(3));
+                        format!("...{}", mismatch.chars().skip(skip).collect::<String>())
+                    } else {
+                        mismatch.clone()
+                    };
+                    lines.push(Line::from(vec![
+                        Span::styled("  • ", Styles::muted()),
+                        Span::styled(display, Styles::warning()),
+                    ]));
+                }
+                if restore_result.mismatches.len() > MAX_MISMATCHES_SHOWN {
+                    lines.push(Line::from(vec![Span::styled(
+                        format!(
+                            "  ... and {} more",
+                            restore_result.mismatches.len() - MAX_MISMATCHES_SHOWN
+                        ),
+                        Styles::muted(),
+                    )]));
+                }
+            }
+
+            if restore_result.cancelled {
+                lines.push(Line::from(vec![Span::styled(
+                    "Stopped early - the rest is still in the Recycle Bin.",
+                    Styles::warning(),
+                )]));
+            }
+
             if restore_result.restored == 0
                 && restore_result.errors == 0
                 && restore_result.not_found == 0
+                && !restore_result.cancelled
             {
                 let message = if restore_all_bin {
-                    "Recycle Bin is empty. Nothing to restore."
+                    "Recycle Bin is empty. Nothing to restore.".to_string()
                 } else {
-                    "No files to restore from last deletion session."
+                    format!("No files to restore from {}.", session)
                 };
                 lines.push(Line::from(vec![Span::styled(message, Styles::muted())]));
             }
@@ -199,10 +252,7 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
             let title_text = if restore_all_bin {
                 format!("{}  Restoring all Recycle Bin contents...", spinner_char)
             } else {
-                format!(
-                    "{}  Restoring files from last deletion session...",
-                    spinner_char
-                )
+                format!("{}  Restoring files from {}...", spinner_char, session)
             };
             let title_block = if restore_all_bin {
                 "Restore All"
@@ -275,10 +325,13 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
             f.render_widget(current_file_paragraph, chunks[2]);
 
             // Status
-            let status_text = format!(
+            let mut status_text = format!(
                 "  Restored: {} items   │   Errors: {}   │   Not found: {}",
                 prog.restored, prog.errors, prog.not_found
             );
+            if restore_all_bin {
+                status_text.push_str("   │   Esc to stop");
+            }
             let status_paragraph = Paragraph::new(status_text).block(
                 Block::default()
                     .borders(Borders::ALL)
@@ -296,8 +349,8 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
                 )
             } else {
                 format!(
-                    "{}  Preparing to restore files from last deletion...",
-                    spinner_char
+                    "{}  Preparing to restore files from {}...",
+                    spinner_char, session
                 )
             };
             let message_block = if restore_all_bin {
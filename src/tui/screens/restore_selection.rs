@@ -15,9 +15,7 @@ use ratatui::{
     Frame,
 };
 
-pub fn render(f: &mut Frame, app_state: &AppState) {
-    let area = f.area();
-
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
     let is_small = area.height < 20 || area.width < 60;
     let shortcuts_height = if is_small { 2 } else { 3 };
 
@@ -60,6 +58,10 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
                 "Restore All Recycle Bin",
                 "Restore all contents from the Recycle Bin",
             ),
+            (
+                "Browse History",
+                "Pick an older session to inspect, restore or export",
+            ),
         ];
 
         let chunks = Layout::default()
@@ -1,5 +1,6 @@
 //! Results screen with grouped categories
 
+use crate::i18n::t;
 use crate::tui::{
     state::AppState,
     theme::Styles,
@@ -171,6 +172,17 @@ fn category_emoji(category_name: &str) -> &'static str {
         "Duplicates" => "📋",
         "Windows Update" => "🔄",
         "Event Logs" => "📋",
+        "Thumbnail Cache" => "🖼️",
+        "Crash Dumps" => "💥",
+        "Log Files" => "📜",
+        "Game Caches" => "🎮",
+        "Toolchain Caches" => "🛠️",
+        "Python Environments" => "🐍",
+        "ML Models" => "🧠",
+        "Email Data" => "📧",
+        "Creative App Caches" => "🎬",
+        "Device Backups" => "📱",
+        "Ignored Files (git)" => "🙈",
         _ => "📁", // Default folder emoji
     }
 }
@@ -369,15 +381,14 @@ fn fun_comparison(bytes: u64) -> Option<String> {
     }
 }
 
-pub fn render(f: &mut Frame, app_state: &mut AppState) {
-    let area = f.area();
-
+pub fn render(f: &mut Frame, area: Rect, app_state: &mut AppState) {
     // Layout: logo+tagline, summary, search bar (always visible), grouped results, shortcuts
-    // Adjust summary height if first scan stats are shown
+    // Adjust summary height if first scan stats are shown; the last row is
+    // the hint or the message (compression prompt and progress)
     let summary_height = if app_state.first_scan_stats.is_some() {
-        8
+        9
     } else {
-        5
+        6
     };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -398,7 +409,7 @@ pub fn render(f: &mut Frame, app_state: &mut AppState) {
     let total_size = app_state.selected_size();
     let total_items = app_state.all_items.len();
     let selected_count = app_state.selected_count();
-    let categories_count = app_state.category_groups.len();
+    let categories_count = app_state.category_groups().len();
     let disk_space = get_disk_space();
     let show_storage_info = app_state.config.ui.show_storage_info;
 
@@ -497,11 +508,28 @@ pub fn render(f: &mut Frame, app_state: &mut AppState) {
     }
 
     summary_lines.push(Line::from(""));
-    summary_lines.push(Line::from(vec![
-        Span::styled("  Press ", Styles::secondary()),
-        Span::styled("[C]", Styles::emphasis()),
-        Span::styled(" to clean selected items", Styles::secondary()),
-    ]));
+    if !app_state.compress_confirm.is_empty() {
+        summary_lines.push(Line::from(vec![Span::styled(
+            format!(
+                "  Compress {} folder(s) with compact.exe? They stay on disk, only smaller. [Y] Compress  [N] Cancel",
+                app_state.compress_confirm.len()
+            ),
+            Styles::warning(),
+        )]));
+    } else if let Some(msg) = &app_state.results_message {
+        summary_lines.push(Line::from(vec![
+            Span::styled("  ", Styles::secondary()),
+            Span::styled(msg.clone(), Styles::emphasis()),
+        ]));
+    } else {
+        summary_lines.push(Line::from(vec![
+            Span::styled("  Press ", Styles::secondary()),
+            Span::styled("[C]", Styles::emphasis()),
+            Span::styled(" to clean selected items, ", Styles::secondary()),
+            Span::styled("[Z]", Styles::emphasis()),
+            Span::styled(" to compress selected folders", Styles::secondary()),
+        ]));
+    }
 
     let summary = Paragraph::new(summary_lines).block(
         Block::default()
@@ -733,6 +761,52 @@ fn match_file_type_string(type_str: &str) -> Option<crate::utils::FileType> {
     None
 }
 
+/// Folder keys by depth in effect at `rows[start]`: the nearest folder header
+/// above it at each shallower depth, up to the enclosing spacer or category
+fn folder_stack_at(
+    app_state: &AppState,
+    rows: &[crate::tui::state::ResultsRow],
+    start: usize,
+) -> Vec<String> {
+    let mut folders: Vec<(usize, String)> = Vec::new();
+    let mut below = usize::MAX;
+    for row in rows[..start.min(rows.len())].iter().rev() {
+        match *row {
+            crate::tui::state::ResultsRow::Spacer
+            | crate::tui::state::ResultsRow::CategoryHeader { .. } => break,
+            crate::tui::state::ResultsRow::FolderHeader {
+                group_idx,
+                folder_idx,
+                depth,
+            } if depth < below => {
+                if let Some(folder) = app_state
+                    .category_groups()
+                    .get(group_idx)
+                    .and_then(|group| group.folder_groups.get(folder_idx))
+                {
+                    let folder_path = std::path::PathBuf::from(&folder.folder_name);
+                    folders.push((
+                        depth,
+                        crate::utils::to_relative_path(&folder_path, &app_state.scan_path),
+                    ));
+                }
+                below = depth;
+                if depth == 0 {
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut stack = Vec::new();
+    for (depth, key) in folders.into_iter().rev() {
+        stack.resize(depth, String::new());
+        stack.push(key);
+    }
+    stack
+}
+
 fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
     let block = Block::default()
         .borders(Borders::ALL)
@@ -742,7 +816,7 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
     let inner = block.inner(area);
     f.render_widget(block, area);
 
-    if app_state.category_groups.is_empty() {
+    if app_state.category_groups().is_empty() {
         let empty = Paragraph::new(Line::from(vec![Span::styled(
             "  No items found",
             Styles::secondary(),
@@ -762,20 +836,20 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
 
     // If rows is empty but we have category groups, something went wrong
     // Try to show items directly as a fallback - show ALL categories
-    if rows.is_empty() && !app_state.category_groups.is_empty() {
+    if rows.is_empty() && !app_state.category_groups().is_empty() {
         if app_state.search_query.is_empty() {
             // Fallback: show items directly from all category groups
-            for (group_idx, group) in app_state.category_groups.iter().enumerate() {
+            for (group_idx, group) in app_state.category_groups().iter().enumerate() {
                 let item_indices = app_state.category_item_indices(group_idx);
                 if !item_indices.is_empty() {
                     // Show category name as header
-                    if app_state.category_groups.len() > 1 {
+                    if app_state.category_groups().len() > 1 {
                         let category_emoji_icon = category_emoji(&group.name);
                         lines.push(Line::from(vec![
                             Span::styled("  ", Style::default()),
                             Span::styled(format!("{} ", category_emoji_icon), Styles::secondary()),
                             Span::styled(
-                                format!("{} ({} items)", group.name, item_indices.len()),
+                                format!("{} ({} items)", t(&group.name), item_indices.len()),
                                 Styles::emphasis(),
                             ),
                         ]));
@@ -792,9 +866,13 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                         } else {
                             Styles::secondary()
                         };
-                        // For applications, use display name from the registry map.
+                        // For applications, use display name from the registry map; device
+                        // backups use the device name and date, package caches the manager.
                         // Fallbacks are only for rare cases where lookup fails.
-                        let display_str = if item.category == "Installed Applications" {
+                        let display_str = if item.category == "Installed Applications"
+                            || item.category == "Device Backups"
+                            || item.category == "Package Cache"
+                        {
                             item.display_name
                                 .clone()
                                 .or_else(|| {
@@ -815,7 +893,7 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                         } else {
                             None
                         };
-                        let indent = if app_state.category_groups.len() > 1 {
+                        let indent = if app_state.category_groups().len() > 1 {
                             "    "
                         } else {
                             "  "
@@ -875,8 +953,8 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                         ]);
                         lines.push(Line::from(spans));
                     }
-                    if app_state.category_groups.len() > 1
-                        && group_idx < app_state.category_groups.len() - 1
+                    if app_state.category_groups().len() > 1
+                        && group_idx < app_state.category_groups().len() - 1
                     {
                         lines.push(Line::from(""));
                     }
@@ -906,15 +984,29 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
         }
     }
 
+    // Handle scrolling
+    let visible_height = inner.height as usize;
+    // Update cached visible height in app state for event handlers
+    app_state.visible_height = visible_height;
+    let total_lines = if rows.is_empty() {
+        lines.len()
+    } else {
+        rows.len()
+    };
+    let scroll = app_state
+        .scroll_offset
+        .min(total_lines.saturating_sub(visible_height));
+
     // Track the current folder path at each nesting depth so items can be displayed
-    // relative to their parent folder (tree-style).
-    let mut folder_stack: Vec<String> = Vec::new();
+    // relative to their parent folder (tree-style). Only the visible window is
+    // materialized, so start from the folders enclosing its first row.
+    let mut folder_stack = folder_stack_at(app_state, &rows, scroll);
 
     // When there's only one category, skip category header and adjust indentation
-    let skip_category_header = app_state.category_groups.len() == 1;
+    let skip_category_header = app_state.category_groups().len() == 1;
     let base_indent = if skip_category_header { "" } else { "    " };
 
-    for (row_idx, row) in rows.iter().enumerate() {
+    for (row_idx, row) in rows.iter().enumerate().skip(scroll).take(visible_height) {
         let is_cursor = row_idx == app_state.cursor;
         let row_style = if is_cursor {
             Styles::selected()
@@ -937,7 +1029,7 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                     continue;
                 }
 
-                let Some(group) = app_state.category_groups.get(group_idx) else {
+                let Some(group) = app_state.category_groups().get(group_idx) else {
                     continue;
                 };
                 folder_stack.clear();
@@ -967,7 +1059,10 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                         format!("{} ", category_emoji_icon),
                         apply_sel(Styles::secondary()),
                     ),
-                    Span::styled(format!("{:<12}", group.name), apply_sel(Styles::emphasis())),
+                    Span::styled(
+                        format!("{:<12}", t(&group.name)),
+                        apply_sel(Styles::emphasis()),
+                    ),
                     Span::styled(
                         format!("{:>8}", bytesize::to_string(group.total_size, false)),
                         apply_sel(Styles::primary()),
@@ -990,7 +1085,7 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                 folder_idx,
                 depth,
             } => {
-                let Some(group) = app_state.category_groups.get(group_idx) else {
+                let Some(group) = app_state.category_groups().get(group_idx) else {
                     continue;
                 };
                 let Some(folder) = group.folder_groups.get(folder_idx) else {
@@ -1046,9 +1141,18 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                 let size_str = bytesize::to_string(folder.total_size, false);
                 let folder_emoji_icon = folder_emoji(app_state, folder);
 
+                // Build artifacts: show how the project regenerates them
+                let rebuild_hint = folder
+                    .items
+                    .first()
+                    .and_then(|&idx| app_state.all_items.get(idx))
+                    .and_then(|item| item.rebuild_command)
+                    .map(|command| format!("  ↻ {}", command));
+
                 // Indent folder headers by nesting depth.
                 let indent = format!("{base_indent}{}", "  ".repeat(depth));
-                let fixed = indent.len() + 2 /*prefix*/ + 1 /*space*/ + 3 /*checkbox*/ + 1 /*space*/ + 2 /*exp*/ + 1 /*space*/ + 2 /*emoji + space*/ + 2 /*two spaces before size*/ + 8 + 2 /*two spaces before count*/ + 10;
+                let fixed = indent.len() + 2 /*prefix*/ + 1 /*space*/ + 3 /*checkbox*/ + 1 /*space*/ + 2 /*exp*/ + 1 /*space*/ + 2 /*emoji + space*/ + 2 /*two spaces before size*/ + 8 + 2 /*two spaces before count*/ + 10
+                    + rebuild_hint.as_ref().map_or(0, |hint| hint.chars().count());
                 let max_len = (inner.width as usize).saturating_sub(fixed).max(8);
                 let folder_display = truncate_end(&folder_str, max_len);
 
@@ -1077,6 +1181,10 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                         apply_sel(Styles::secondary()),
                     ),
                 ]);
+                if let Some(ref hint) = rebuild_hint {
+                    folder_header_spans
+                        .push(Span::styled(hint.clone(), apply_sel(Styles::secondary())));
+                }
                 lines.push(Line::from(folder_header_spans));
             }
             crate::tui::state::ResultsRow::Item { item_idx, depth } => {
@@ -1095,8 +1203,12 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                 // Indent items by their nesting depth.
                 let indent = format!("{base_indent}{}", "  ".repeat(depth));
 
-                // For applications, show the registry display name (fallback to filename/path).
-                let path_str = if item.category == "Installed Applications" {
+                // For applications, show the registry display name, for device backups the
+                // device name and date, for package caches the manager (fallback to filename/path).
+                let path_str = if item.category == "Installed Applications"
+                    || item.category == "Device Backups"
+                    || item.category == "Package Cache"
+                {
                     item.display_name
                         .clone()
                         .or_else(|| {
@@ -1158,7 +1270,17 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                 // Size column: 2 spaces + 8 chars (e.g., "793.7 MiB")
                 // Date column: 3 chars (" | ") + up to 10 chars (e.g., "yesterday", "2mo ago")
                 let date_width = if date_str.is_some() { 3 + 10 } else { 0 };
-                let metadata_width = 2 + 8 + date_width;
+                // Items in synced folders: deleting also deletes from the cloud
+                let cloud_tag = item
+                    .cloud
+                    .map(|provider| format!("  ☁ deletes from {} too", provider.label()));
+                let cloud_width = cloud_tag.as_ref().map_or(0, |tag| tag.chars().count());
+                // Likely personal data (never pre-selected)
+                let sensitive_tag = item.sensitive.map(|why| format!("  🔒 {}?", why));
+                let sensitive_width = sensitive_tag
+                    .as_ref()
+                    .map_or(0, |tag| tag.chars().count() + 1);
+                let metadata_width = 2 + 8 + date_width + cloud_width + sensitive_width;
 
                 let fixed_prefix = indent.len()
                     + 3 /*prefix+spaces*/
@@ -1207,6 +1329,12 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
                         Span::raw("")
                     },
                 ]);
+                if let Some(tag) = cloud_tag {
+                    item_spans.push(Span::styled(tag, apply_sel(Styles::warning())));
+                }
+                if let Some(tag) = sensitive_tag {
+                    item_spans.push(Span::styled(tag, apply_sel(Styles::danger())));
+                }
                 lines.push(Line::from(item_spans));
             }
             crate::tui::state::ResultsRow::Spacer => {
@@ -1216,20 +1344,16 @@ fn render_grouped_results(f: &mut Frame, area: Rect, app_state: &mut AppState) {
         }
     }
 
-    // Handle scrolling
-    let visible_height = inner.height as usize;
-    // Update cached visible height in app state for event handlers
-    app_state.visible_height = visible_height;
-    let total_lines = lines.len();
-    let scroll = app_state
-        .scroll_offset
-        .min(total_lines.saturating_sub(visible_height));
-
-    let visible_lines: Vec<Line> = lines
-        .into_iter()
-        .skip(scroll)
-        .take(visible_height)
-        .collect();
+    // The fallback lines above are built in full; row lines only for the window
+    let visible_lines: Vec<Line> = if rows.is_empty() {
+        lines
+            .into_iter()
+            .skip(scroll)
+            .take(visible_height)
+            .collect()
+    } else {
+        lines
+    };
 
     let paragraph = Paragraph::new(visible_lines);
     f.render_widget(paragraph, inner);
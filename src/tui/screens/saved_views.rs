@@ -0,0 +1,137 @@
+//! Saved Views screen - named Results searches
+//!
+//! Opened with V from Results. Enter shows Results filtered by a view, S
+//! saves the current Results search under a name and D deletes a view.
+//! Views are kept in `ui.saved_views`, so `wole scan --view NAME` can run
+//! them too.
+
+use crate::tui::{
+    state::{AppState, Screen},
+    theme::Styles,
+    widgets::{
+        logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
+        shortcuts::{get_shortcuts, render_shortcuts},
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Width of the name column
+const NAME_WIDTH: usize = 24;
+
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let is_small = area.height < 20 || area.width < 60;
+    let shortcuts_height = if is_small { 2 } else { 3 };
+    let header_height = LOGO_WITH_TAGLINE_HEIGHT;
+    let min_content_height = 5;
+
+    if area.height < header_height + min_content_height + shortcuts_height || area.width < 20 {
+        let msg = Paragraph::new("Terminal too small. Please resize to at least 20x25")
+            .style(Styles::warning())
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(min_content_height),
+            Constraint::Length(shortcuts_height),
+        ])
+        .split(area);
+
+    render_logo(f, chunks[0]);
+    render_tagline(f, chunks[0]);
+    render_content(f, chunks[1], app_state);
+
+    let shortcuts = get_shortcuts(&app_state.screen, Some(app_state));
+    render_shortcuts(f, chunks[2], &shortcuts);
+}
+
+fn render_content(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let Screen::SavedViews {
+        cursor,
+        naming,
+        message,
+    } = &app_state.screen
+    else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Current search
+            Constraint::Length(1), // Name prompt or message
+            Constraint::Length(1), // Spacing
+            Constraint::Min(2),    // Views
+        ])
+        .split(area);
+
+    let current = app_state.search_query.trim();
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("Current search: ", Styles::secondary()),
+            if current.is_empty() {
+                Span::styled("(none)", Styles::muted())
+            } else {
+                Span::styled(current.to_string(), Styles::primary())
+            },
+        ])),
+        chunks[0],
+    );
+
+    if let Some(name) = naming {
+        f.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("Save as: ", Styles::emphasis()),
+                Span::styled(name.clone(), Styles::primary()),
+                Span::styled("_", Styles::emphasis()),
+            ])),
+            chunks[1],
+        );
+    } else if let Some(message) = message {
+        f.render_widget(
+            Paragraph::new(message.as_str()).style(Styles::secondary()),
+            chunks[1],
+        );
+    }
+
+    let views = &app_state.config.ui.saved_views;
+    if views.is_empty() {
+        f.render_widget(
+            Paragraph::new("No saved views yet - search with / in Results, then press S here")
+                .style(Styles::muted()),
+            chunks[3],
+        );
+        return;
+    }
+
+    let items: Vec<ListItem> = views
+        .iter()
+        .map(|view| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{:<width$}  ",
+                        view.name.chars().take(NAME_WIDTH).collect::<String>(),
+                        width = NAME_WIDTH
+                    ),
+                    Styles::emphasis(),
+                ),
+                Span::styled(view.search(), Styles::muted()),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(Styles::selected());
+    let mut state = ListState::default();
+    state.select(Some((*cursor).min(views.len() - 1)));
+    f.render_stateful_widget(list, chunks[3], &mut state);
+}
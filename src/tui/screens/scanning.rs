@@ -10,7 +10,7 @@ use crate::tui::{
     },
 };
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
@@ -53,21 +53,17 @@ fn fun_comparison_short(bytes: u64) -> Option<String> {
     }
 }
 
-pub fn render(f: &mut Frame, app_state: &AppState) {
-    let area = f.area();
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
     let spinner = spinner::get_spinner(app_state.tick);
 
     // Detect small viewport to adjust rendering
     let is_small = area.height < 20 || area.width < 60;
-    let has_notice = matches!(
-        app_state.screen,
-        crate::tui::state::Screen::Scanning {
-            progress: crate::tui::state::ScanProgress {
-                notice: Some(_),
-                ..
-            }
+    let has_notice = match app_state.screen {
+        crate::tui::state::Screen::Scanning { ref progress } => {
+            progress.notice.is_some() || progress.slow.is_some()
         }
-    );
+        _ => false,
+    };
 
     // Adjust constraints for small viewports
     let status_height = if is_small {
@@ -130,8 +126,17 @@ pub fn render(f: &mut Frame, app_state: &AppState) {
             .iter()
             .filter(|c| c.completed)
             .count();
+        // On a slow share the early (local, profile-based) categories finish
+        // quickly while the ones walking the share crawl, so wait for half of
+        // them before extrapolating
+        let min_completed = match progress.latency {
+            Some(latency) if latency >= crate::network::SLOW_LATENCY => {
+                total_categories.div_ceil(2).max(1)
+            }
+            _ => 1,
+        };
         let estimated_remaining = if total_categories > 0
-            && completed_categories > 0
+            && completed_categories >= min_completed
             && completed_categories < total_categories
         {
             let avg_time_per_category = elapsed_secs as f64 / completed_categories as f64;
@@ -162,7 +167,7 @@ pub fn render(f: &mut Frame, app_state: &AppState) {
         )])];
 
         // Add time information
-        let time_info = if let Some(remaining) = estimated_remaining {
+        let mut time_info = if let Some(remaining) = estimated_remaining {
             format!(
                 "Elapsed: {} │ Est. remaining: {}",
                 elapsed_display, remaining
@@ -170,12 +175,32 @@ pub fn render(f: &mut Frame, app_state: &AppState) {
         } else {
             format!("Elapsed: {}", elapsed_display)
         };
+        if let Some(latency) = progress.latency {
+            time_info.push_str(&format!(" │ {}", crate::network::describe_latency(latency)));
+        }
+        if let Some(ref strategy) = progress.strategy {
+            time_info.push_str(&format!(" │ {}", strategy));
+        }
         status_lines.push(Line::from(vec![Span::styled(
             time_info,
             Styles::secondary(),
         )]));
 
-        if let Some(ref notice) = progress.notice {
+        // A slow category's prompt takes the notice line
+        if let Some(ref slow) = progress.slow {
+            let mut prompt = format!(
+                "{} is taking long ({}m) — [S] Skip  [K] Keep waiting",
+                slow.name,
+                slow.elapsed_secs / 60
+            );
+            if let Some(ref narrow_to) = slow.control.narrow_to {
+                prompt.push_str(&format!(
+                    "  [N] Narrow to {}",
+                    crate::utils::display_path(narrow_to)
+                ));
+            }
+            status_lines.push(Line::from(vec![Span::styled(prompt, Styles::emphasis())]));
+        } else if let Some(ref notice) = progress.notice {
             status_lines.push(Line::from(vec![Span::styled(
                 notice.clone(),
                 Styles::secondary(),
@@ -316,9 +341,7 @@ pub fn render(f: &mut Frame, app_state: &AppState) {
 }
 
 /// Render cleaning progress (similar to scanning)
-pub fn render_cleaning(f: &mut Frame, app_state: &AppState) {
-    let area = f.area();
-
+pub fn render_cleaning(f: &mut Frame, area: Rect, app_state: &AppState) {
     // Detect small viewport to adjust rendering
     let is_small = area.height < 20 || area.width < 60;
     let status_height = if is_small { 2 } else { 3 };
@@ -344,11 +367,21 @@ pub fn render_cleaning(f: &mut Frame, app_state: &AppState) {
     // Use faster animation for cleaning (every 2 ticks instead of default)
     let cleaning_spinner = spinner::get_spinner(app_state.tick * 2);
     // Show category-specific message in progress bar, keep header generic
-    let header = Paragraph::new(Line::from(vec![Span::styled(
-        format!("{}  Cleaning...", cleaning_spinner),
-        Styles::emphasis(),
-    )]))
-    .block(
+    let (header_text, header_style) = match app_state.screen {
+        crate::tui::state::Screen::Cleaning { ref progress } if progress.paused => (
+            "⏸  Paused between batches — [P] Resume  [Esc] Stop cleaning".to_string(),
+            Styles::warning(),
+        ),
+        crate::tui::state::Screen::Cleaning { ref progress } if progress.stop_requested => (
+            format!("{}  Stopping...", cleaning_spinner),
+            Styles::warning(),
+        ),
+        _ => (
+            format!("{}  Cleaning...", cleaning_spinner),
+            Styles::emphasis(),
+        ),
+    };
+    let header = Paragraph::new(Line::from(vec![Span::styled(header_text, header_style)])).block(
         Block::default()
             .borders(if is_small {
                 Borders::TOP | Borders::BOTTOM
@@ -435,10 +468,21 @@ pub fn render_cleaning(f: &mut Frame, app_state: &AppState) {
         f.render_widget(current_file_paragraph, progress_chunks[1]);
 
         // Status
-        let status_text = format!(
+        let mut status_text = format!(
             "  Cleaned: {} items   │   Errors: {}",
             progress.cleaned, progress.errors
         );
+        if crate::throttle::background_mode() {
+            let rate = crate::throttle::delete_rate();
+            let mut limits = Vec::new();
+            if rate.files_per_sec > 0 {
+                limits.push(format!("{} files/s", rate.files_per_sec));
+            }
+            if rate.mb_per_sec > 0 {
+                limits.push(format!("{} MB/s", rate.mb_per_sec));
+            }
+            status_text.push_str(&format!("   │   Background mode ({})", limits.join(", ")));
+        }
         let status_paragraph = Paragraph::new(status_text).block(
             Block::default()
                 .borders(if is_small {
@@ -0,0 +1,119 @@
+//! Search screen - find any indexed file by name
+
+use crate::tui::{
+    state::AppState,
+    theme::Styles,
+    widgets::{
+        logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
+        shortcuts::{get_shortcuts, render_shortcuts},
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let is_small = area.height < 20 || area.width < 60;
+    let shortcuts_height = if is_small { 2 } else { 3 };
+    let header_height = LOGO_WITH_TAGLINE_HEIGHT;
+    let min_content_height = 6;
+
+    if area.height < header_height + min_content_height + shortcuts_height || area.width < 20 {
+        let msg = Paragraph::new("Terminal too small. Please resize to at least 20x25")
+            .style(Styles::warning())
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(min_content_height),
+            Constraint::Length(shortcuts_height),
+        ])
+        .split(area);
+
+    render_logo(f, chunks[0]);
+    render_tagline(f, chunks[0]);
+    render_content(f, chunks[1], app_state);
+
+    let shortcuts = get_shortcuts(&app_state.screen, Some(app_state));
+    render_shortcuts(f, chunks[2], &shortcuts);
+}
+
+fn render_content(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let crate::tui::state::Screen::Search {
+        query,
+        results,
+        cursor,
+        message,
+        index_receiver,
+    } = &app_state.screen
+    else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Query box
+            Constraint::Length(1), // Status line
+            Constraint::Min(2),    // Results
+        ])
+        .split(area);
+
+    let query_box = Paragraph::new(Line::from(vec![
+        Span::styled("Find: ", Styles::secondary()),
+        Span::styled(query.as_str(), Styles::primary()),
+        Span::styled("█", Styles::emphasis()),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Styles::border()),
+    );
+    f.render_widget(query_box, chunks[0]);
+
+    let status = if index_receiver.is_some() {
+        Span::styled("Building index...", Styles::warning())
+    } else if let Some(msg) = message {
+        Span::styled(msg.as_str(), Styles::emphasis())
+    } else if query.trim().is_empty() {
+        Span::styled("Type to search file names", Styles::secondary())
+    } else {
+        Span::styled(format!("{} matches", results.len()), Styles::secondary())
+    };
+    f.render_widget(Paragraph::new(Line::from(status)), chunks[1]);
+
+    if results.is_empty() {
+        if !query.trim().is_empty() {
+            let empty = Paragraph::new("No matching files. Index empty? Press Ctrl+R to build")
+                .style(Styles::muted());
+            f.render_widget(empty, chunks[2]);
+        }
+        return;
+    }
+
+    let items: Vec<ListItem> = results
+        .iter()
+        .map(|file| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:>10}  ", bytesize::to_string(file.size, false)),
+                    Styles::emphasis(),
+                ),
+                Span::styled(file.path.display().to_string(), Styles::primary()),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(Styles::selected());
+    let mut state = ListState::default();
+    state.select(Some((*cursor).min(results.len() - 1)));
+    f.render_stateful_widget(list, chunks[2], &mut state);
+}
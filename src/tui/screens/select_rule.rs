@@ -0,0 +1,129 @@
+//! Select by Rule screen - bulk selection in Results
+//!
+//! Opened with R from Results. Items older than some days, larger than some
+//! MB, with one of some extensions or with some text in their path are
+//! selected (or deselected) in the category under the cursor or in all
+//! results. Blank rules are ignored; the rest must all match.
+
+use crate::tui::{
+    state::{AppState, Screen},
+    theme::Styles,
+    widgets::{
+        logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
+        shortcuts::{get_shortcuts, render_shortcuts},
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Labels of the text fields, in order
+const FIELD_LABELS: [&str; 4] = [
+    "Older than (days)",
+    "Larger than (MB)",
+    "Extensions",
+    "Path contains",
+];
+
+/// Width of the label column
+const LABEL_WIDTH: usize = 20;
+
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let is_small = area.height < 20 || area.width < 60;
+    let shortcuts_height = if is_small { 2 } else { 3 };
+    let header_height = LOGO_WITH_TAGLINE_HEIGHT;
+    let min_content_height = 9;
+
+    if area.height < header_height + min_content_height + shortcuts_height || area.width < 20 {
+        let msg = Paragraph::new("Terminal too small. Please resize to at least 20x25")
+            .style(Styles::warning())
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(min_content_height),
+            Constraint::Length(shortcuts_height),
+        ])
+        .split(area);
+
+    render_logo(f, chunks[0]);
+    render_tagline(f, chunks[0]);
+    render_content(f, chunks[1], app_state);
+
+    let shortcuts = get_shortcuts(&app_state.screen, Some(app_state));
+    render_shortcuts(f, chunks[2], &shortcuts);
+}
+
+fn render_content(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let Screen::SelectRule {
+        fields,
+        cursor,
+        group,
+        all,
+        deselect,
+        message,
+    } = &app_state.screen
+    else {
+        return;
+    };
+
+    let label = |text: &str, row: usize| {
+        let style = if *cursor == row {
+            Styles::selected()
+        } else {
+            Styles::secondary()
+        };
+        Span::styled(format!("{:<width$}", text, width = LABEL_WIDTH), style)
+    };
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "Select by rule - blank rules are ignored",
+            Styles::header(),
+        )),
+        Line::from(""),
+    ];
+    for (row, (name, value)) in FIELD_LABELS.iter().zip(fields.iter()).enumerate() {
+        let mut spans = vec![
+            label(name, row),
+            Span::styled(value.clone(), Styles::primary()),
+        ];
+        if *cursor == row {
+            spans.push(Span::styled("_", Styles::emphasis()));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    let group_name = group
+        .and_then(|group| app_state.category_groups().get(group))
+        .map(|group| group.name.as_str());
+    let scope = match group_name {
+        Some(name) if !*all => name.to_string(),
+        _ => "All results".to_string(),
+    };
+    lines.push(Line::from(vec![
+        label("Apply to", fields.len()),
+        Span::styled(scope, Styles::emphasis()),
+    ]));
+    lines.push(Line::from(vec![
+        label("Action", fields.len() + 1),
+        Span::styled(
+            if *deselect { "Deselect" } else { "Select" },
+            Styles::emphasis(),
+        ),
+    ]));
+    lines.push(Line::from(""));
+    if let Some(message) = message {
+        lines.push(Line::from(Span::styled(message.clone(), Styles::warning())));
+    }
+
+    f.render_widget(Paragraph::new(lines), area);
+}
@@ -1,6 +1,7 @@
 //! Status screen - Real-time system health dashboard
 
 use crate::status::SystemStatus;
+use crate::temperature::TempLevel;
 use crate::tui::{
     state::AppState,
     theme::Styles,
@@ -14,13 +15,12 @@ use chrono;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::Color,
-    widgets::{Block, Borders, Paragraph},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
-pub fn render(f: &mut Frame, app_state: &AppState) {
-    let area = f.area();
-
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
     let is_small = area.height < 20 || area.width < 60;
     let shortcuts_height = if is_small { 2 } else { 3 };
 
@@ -75,18 +75,85 @@ fn render_content(f: &mut Frame, area: Rect, app_state: &AppState, _is_small: bo
             ])
             .split(area);
 
-        render_status_header_with_indicator(f, header_chunks[0], status, last_refresh);
+        render_status_header_with_indicator(
+            f,
+            header_chunks[0],
+            status,
+            last_refresh,
+            app_state.standby_trim.last_outcome(),
+        );
 
-        // Main content area
-        render_status_dashboard(f, header_chunks[1], status);
+        // Possible leaks panel above the dashboard while there are suspects
+        let suspects = app_state.leak_watch.suspects();
+        if suspects.is_empty() {
+            render_status_dashboard(f, header_chunks[1], status, app_state);
+        } else {
+            let rows = suspects.len().min(LEAK_PANEL_ROWS) as u16;
+            let main_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(rows + 2), Constraint::Min(1)])
+                .split(header_chunks[1]);
+            render_leak_panel(f, main_chunks[0], &app_state.leak_watch);
+            render_status_dashboard(f, main_chunks[1], status, app_state);
+        }
     }
 }
 
+/// Rows of the "Possible leaks" panel before it scrolls
+const LEAK_PANEL_ROWS: usize = 3;
+
+fn render_leak_panel(f: &mut Frame, area: Rect, watch: &crate::leak_watch::LeakWatch) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Styles::border())
+        .title("▶ Possible leaks (K end · E restart)");
+
+    let items: Vec<ListItem> = watch
+        .suspects()
+        .iter()
+        .map(|suspect| {
+            let pending = watch
+                .pending
+                .filter(|(_, pid)| *pid == suspect.pid)
+                .map(|(action, _)| match action {
+                    crate::leak_watch::LeakAction::Kill => "  press K again to end",
+                    crate::leak_watch::LeakAction::Restart => "  press E again to restart",
+                })
+                .unwrap_or("");
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<20} {:>6}  ", suspect.name, suspect.pid),
+                    Styles::primary(),
+                ),
+                Span::styled(
+                    format!(
+                        "{} → {} (+{}) over {} refreshes",
+                        bytesize::to_string(suspect.start_bytes, false),
+                        bytesize::to_string(suspect.current_bytes, false),
+                        bytesize::to_string(suspect.growth(), false),
+                        suspect.samples
+                    ),
+                    Styles::warning(),
+                ),
+                Span::styled(pending, Styles::emphasis()),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Styles::selected());
+    let mut state = ListState::default();
+    state.select(watch.selected().map(|_| watch.cursor));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
 fn render_status_header_with_indicator(
     f: &mut Frame,
     area: Rect,
     status: &SystemStatus,
     last_refresh: &std::time::Instant,
+    last_trim: Option<&crate::optimize::TrimOutcome>,
 ) {
     let health_indicator = match status.health_score {
         80..=100 => ("●", Color::Green),
@@ -116,10 +183,20 @@ fn render_status_header_with_indicator(
         .split(area);
 
     // Line 1: Health status with live indicator
-    let health_text = format!(
+    let mut health_text = format!(
         "Health status: {} {}",
         health_indicator.0, status.health_score
     );
+    if let Some(trim) = last_trim {
+        if trim.success {
+            health_text.push_str(&format!(
+                " · Auto-trim reclaimed {}",
+                bytesize::to_string(trim.reclaimed(), false)
+            ));
+        } else {
+            health_text.push_str(" · Auto-trim failed");
+        }
+    }
     let health_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
@@ -151,7 +228,8 @@ fn render_status_header_with_indicator(
     f.render_widget(device_para, lines[1]);
 }
 
-fn render_status_dashboard(f: &mut Frame, area: Rect, status: &SystemStatus) {
+fn render_status_dashboard(f: &mut Frame, area: Rect, status: &SystemStatus, app_state: &AppState) {
+    let temperature = &app_state.config.temperature;
     // LAYOUT HIERARCHY:
     // 1. Primary Metrics: CPU, Memory, Disk (most important, side by side)
     // 2. Secondary Metrics: Network, Power (below primary)
@@ -181,14 +259,11 @@ fn render_status_dashboard(f: &mut Frame, area: Rect, status: &SystemStatus) {
     let min_secondary_height = 8u16; // Network + Power + System Diagnostics
 
     // Calculate Top Disk I/O section height (must be before processes_height calculation)
-    #[cfg(windows)]
     let top_io_height = if !status.top_io_processes.is_empty() {
         (status.top_io_processes.len().min(5) + 2) as u16
     } else {
         0u16
     };
-    #[cfg(not(windows))]
-    let top_io_height = 0u16;
 
     let io_spacing = if top_io_height > 0 { 1u16 } else { 0u16 };
 
@@ -338,6 +413,7 @@ fn render_status_dashboard(f: &mut Frame, area: Rect, status: &SystemStatus) {
                     f,
                     secondary_cols[6],
                     &status.temperature_sensors,
+                    temperature,
                 );
             }
             #[cfg(windows)]
@@ -374,6 +450,7 @@ fn render_status_dashboard(f: &mut Frame, area: Rect, status: &SystemStatus) {
                     f,
                     secondary_cols[6],
                     &status.temperature_sensors,
+                    temperature,
                 );
             } else {
                 #[cfg(windows)]
@@ -409,6 +486,7 @@ fn render_status_dashboard(f: &mut Frame, area: Rect, status: &SystemStatus) {
                     f,
                     secondary_cols[4],
                     &status.temperature_sensors,
+                    temperature,
                 );
             } else {
                 #[cfg(windows)]
@@ -460,7 +538,12 @@ fn render_status_dashboard(f: &mut Frame, area: Rect, status: &SystemStatus) {
             // }
 
             if has_temp_sensors && idx < stacked.len() {
-                render_temperature_sensors_section(f, stacked[idx], &status.temperature_sensors);
+                render_temperature_sensors_section(
+                    f,
+                    stacked[idx],
+                    &status.temperature_sensors,
+                    temperature,
+                );
                 idx += 2; // Skip spacing
             }
 
@@ -475,23 +558,30 @@ fn render_status_dashboard(f: &mut Frame, area: Rect, status: &SystemStatus) {
         }
     }
 
-    // Top Disk I/O section (Windows only) - at index 4
-    #[cfg(windows)]
-    {
-        if top_io_height > 0 && main_sections.len() > 4 && main_sections[4].height > 0 {
-            render_top_io_section(f, main_sections[4], status);
-        }
+    // Top Disk I/O section - at index 4
+    if top_io_height > 0 && main_sections.len() > 4 && main_sections[4].height > 0 {
+        render_top_io_section(f, main_sections[4], status);
     }
 
     // Processes section - at index 6
     let process_idx = 6;
     if main_sections.len() > process_idx && main_sections[process_idx].height > 0 {
-        render_processes_section(f, main_sections[process_idx], status);
+        render_processes_section(
+            f,
+            main_sections[process_idx],
+            status,
+            &app_state.process_tree,
+        );
     } else {
         // Fallback: try to render processes even if layout calculation was wrong
         // This ensures processes are always visible
         if main_sections.len() > 4 {
-            render_processes_section(f, main_sections[main_sections.len() - 1], status);
+            render_processes_section(
+                f,
+                main_sections[main_sections.len() - 1],
+                status,
+                &app_state.process_tree,
+            );
         }
     }
 }
@@ -872,12 +962,26 @@ fn render_memory_section(f: &mut Frame, area: Rect, status: &SystemStatus) {
     }
 }
 
+/// Right-aligned "Cache 48.2 MB, 2d ago" for the Disk block (empty without a cache)
+fn scan_cache_title(status: &SystemStatus) -> Line<'static> {
+    match &status.scan_cache {
+        Some(cache) => Line::from(format!(
+            " Cache {} ",
+            crate::status::scan_cache_label(cache)
+        ))
+        .style(Styles::secondary())
+        .right_aligned(),
+        None => Line::default(),
+    }
+}
+
 fn render_disk_section_compact(f: &mut Frame, area: Rect, status: &SystemStatus) {
     // Compact Disk section - shows essential info on left, breakdown on right
     let disk_block = Block::default()
         .borders(Borders::ALL)
         .border_style(Styles::border())
-        .title("💿 Disk");
+        .title("💿 Disk")
+        .title_top(scan_cache_title(status));
 
     let inner = disk_block.inner(area);
     f.render_widget(disk_block, area);
@@ -1247,7 +1351,8 @@ fn render_disk_section(f: &mut Frame, area: Rect, status: &SystemStatus) {
     let disk_block = Block::default()
         .borders(Borders::ALL)
         .border_style(Styles::border())
-        .title("▤ Disk");
+        .title("▤ Disk")
+        .title_top(scan_cache_title(status));
 
     let inner = disk_block.inner(area);
     f.render_widget(disk_block, area);
@@ -1303,10 +1408,14 @@ fn render_disk_section(f: &mut Frame, area: Rect, status: &SystemStatus) {
 
 fn render_power_section_compact(f: &mut Frame, area: Rect, status: &SystemStatus) {
     // Power section with all details - Level spans full width, then 2-column layout below
+    let title = match status.power.as_ref().and_then(|p| p.power_plan.as_ref()) {
+        Some(plan) => format!("🔋 Power · {}", plan),
+        None => "🔋 Power".to_string(),
+    };
     let power_block = Block::default()
         .borders(Borders::ALL)
         .border_style(Styles::border())
-        .title("🔋 Power");
+        .title(title);
 
     let inner = power_block.inner(area);
     f.render_widget(power_block, area);
@@ -1405,8 +1514,11 @@ fn render_power_section_compact(f: &mut Frame, area: Rect, status: &SystemStatus
         }
         left_idx += 1;
 
-        // Health (moved before Cycles)
-        let health_text = format!("Health  {}", power.health);
+        // Health (moved before Cycles), with the battery report's capacity when known
+        let health_text = match power.capacity_percent {
+            Some(percent) => format!("Health  {} {:.0}%", power.health, percent),
+            None => format!("Health  {}", power.health),
+        };
         let health_para = Paragraph::new(health_text).style(Styles::secondary());
         f.render_widget(health_para, left_lines[left_idx]);
         left_idx += 1;
@@ -1790,7 +1902,6 @@ fn render_network_section(f: &mut Frame, area: Rect, status: &SystemStatus) {
     }
 }
 
-#[cfg(windows)]
 fn render_top_io_section(f: &mut Frame, area: Rect, status: &SystemStatus) {
     if status.top_io_processes.is_empty() {
         return;
@@ -1851,7 +1962,17 @@ fn render_top_io_section(f: &mut Frame, area: Rect, status: &SystemStatus) {
     }
 }
 
-fn render_processes_section(f: &mut Frame, area: Rect, status: &SystemStatus) {
+fn render_processes_section(
+    f: &mut Frame,
+    area: Rect,
+    status: &SystemStatus,
+    tree: &crate::process_tree::TreeView,
+) {
+    if tree.enabled {
+        render_process_tree_section(f, area, status, tree);
+        return;
+    }
+
     // Maximized processes section with better visual presentation
     let processes_block = Block::default()
         .borders(Borders::ALL)
@@ -2438,6 +2559,7 @@ fn render_temperature_sensors_section(
     f: &mut Frame,
     area: Rect,
     sensors: &[crate::status::TemperatureSensor],
+    settings: &crate::config::TemperatureSettings,
 ) {
     if sensors.is_empty() {
         return;
@@ -2479,46 +2601,18 @@ fn render_temperature_sensors_section(
                 sensor.label.clone()
             };
 
-            // Determine style based on temperature
-            let temp_style = if let Some(critical) = sensor.critical_celsius {
-                if sensor.temperature_celsius >= critical {
-                    Styles::error()
-                } else if let Some(max) = sensor.max_celsius {
-                    if sensor.temperature_celsius >= max * 0.9 {
-                        Styles::warning()
-                    } else {
-                        Styles::secondary()
-                    }
-                } else {
-                    Styles::secondary()
-                }
-            } else if sensor.temperature_celsius > 85.0 {
-                Styles::error()
-            } else if sensor.temperature_celsius > 75.0 {
-                Styles::warning()
-            } else {
-                Styles::secondary()
+            // Colour and tag by the configured thresholds
+            let (temp_style, tag) = match crate::temperature::level(sensor, settings) {
+                TempLevel::Critical => (Styles::error(), " (CRIT)"),
+                TempLevel::Warning => (Styles::warning(), " (HOT)"),
+                TempLevel::Normal => (Styles::secondary(), ""),
             };
-
-            // Format temperature with max/critical indicators
-            let temp_text = if let Some(critical) = sensor.critical_celsius {
-                if sensor.temperature_celsius >= critical {
-                    format!("{:<12} {:.0}°C (CRIT)", label, sensor.temperature_celsius)
-                } else if let Some(max) = sensor.max_celsius {
-                    format!(
-                        "{:<12} {:.0}°C / {:.0}°C",
-                        label, sensor.temperature_celsius, max
-                    )
-                } else {
-                    format!("{:<12} {:.0}°C", label, sensor.temperature_celsius)
-                }
-            } else if let Some(max) = sensor.max_celsius {
-                format!(
-                    "{:<12} {:.0}°C / {:.0}°C",
-                    label, sensor.temperature_celsius, max
-                )
-            } else {
-                format!("{:<12} {:.0}°C", label, sensor.temperature_celsius)
+            let temp_text = match sensor.max_celsius.filter(|max| *max > 0.0) {
+                Some(max) => format!(
+                    "{:<12} {:.0}°C / {:.0}°C{}",
+                    label, sensor.temperature_celsius, max, tag
+                ),
+                None => format!("{:<12} {:.0}°C{}", label, sensor.temperature_celsius, tag),
             };
 
             let temp_para = Paragraph::new(temp_text)
@@ -2529,6 +2623,115 @@ fn render_temperature_sensors_section(
     }
 }
 
+/// Width of the indented name column in tree mode
+const TREE_NAME_WIDTH: usize = 34;
+
+fn render_process_tree_section(
+    f: &mut Frame,
+    area: Rect,
+    status: &SystemStatus,
+    tree: &crate::process_tree::TreeView,
+) {
+    let title = if tree.editing_filter || !tree.filter.is_empty() {
+        format!(
+            "▶ Process Tree · filter: {}{}",
+            tree.filter,
+            if tree.editing_filter { "_" } else { "" }
+        )
+    } else {
+        format!("▶ Process Tree ({} processes)", status.all_processes.len())
+    };
+    let tree_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Styles::border())
+        .title(title);
+
+    let inner = tree_block.inner(area);
+    f.render_widget(tree_block, area);
+    if inner.height < 3 {
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Header
+            Constraint::Length(1), // Separator
+            Constraint::Min(1),    // Rows
+        ])
+        .split(inner);
+
+    let header_text = format!(
+        "{:<width$} {:>6} {:>7} {:>9} {:>6}",
+        "Process",
+        "PID",
+        "CPU %",
+        "Memory",
+        "Procs",
+        width = TREE_NAME_WIDTH
+    );
+    f.render_widget(
+        Paragraph::new(header_text).style(Styles::primary()),
+        chunks[0],
+    );
+    f.render_widget(
+        Paragraph::new("─".repeat(inner.width as usize)).style(Styles::border()),
+        chunks[1],
+    );
+
+    let rows = tree.rows(&status.all_processes);
+    if rows.is_empty() {
+        let msg = if tree.filter.is_empty() {
+            "Loading..."
+        } else {
+            "No process matches the filter"
+        };
+        f.render_widget(Paragraph::new(msg).style(Styles::secondary()), chunks[2]);
+        return;
+    }
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| {
+            let marker = match (row.has_children, row.expanded) {
+                (false, _) => "  ",
+                (true, true) => "▾ ",
+                (true, false) => "▸ ",
+            };
+            let mut name = format!("{}{}{}", "  ".repeat(row.depth), marker, row.name);
+            if name.chars().count() > TREE_NAME_WIDTH {
+                name = name.chars().take(TREE_NAME_WIDTH - 1).collect::<String>() + "…";
+            }
+            let memory_mb = row.memory_bytes as f64 / (1024.0 * 1024.0);
+            let memory_str = if memory_mb >= 1024.0 {
+                format!("{:.1} GB", memory_mb / 1024.0)
+            } else {
+                format!("{:.0} MB", memory_mb)
+            };
+            let text = format!(
+                "{:<width$} {:>6} {:>6.1}% {:>9} {:>6}",
+                name,
+                row.pid,
+                row.cpu_usage,
+                memory_str,
+                row.count,
+                width = TREE_NAME_WIDTH
+            );
+            let style = if row.cpu_usage > 50.0 {
+                Styles::warning()
+            } else {
+                Styles::secondary()
+            };
+            ListItem::new(Line::from(Span::styled(text, style)))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(Styles::selected());
+    let mut state = ListState::default();
+    state.select(Some(tree.cursor.min(rows.len() - 1)));
+    f.render_stateful_widget(list, chunks[2], &mut state);
+}
+
 fn format_uptime(seconds: u64) -> String {
     let days = seconds / 86400;
     let hours = (seconds % 86400) / 3600;
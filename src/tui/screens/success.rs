@@ -9,7 +9,7 @@ use crate::tui::{
     },
 };
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
@@ -120,9 +120,7 @@ fn fun_comparison(bytes: u64) -> Option<String> {
     }
 }
 
-pub fn render(f: &mut Frame, app_state: &AppState) {
-    let area = f.area();
-
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
     // Layout: logo+tagline, success message, stats, actions, shortcuts
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -144,36 +142,78 @@ pub fn render(f: &mut Frame, app_state: &AppState) {
 
     // Success message with celebration
     if let crate::tui::state::Screen::Success { cleaned_bytes, .. } = app_state.screen {
-        let mut success_lines = vec![
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("  ✓ ", Styles::success()),
-                Span::styled("CLEANUP COMPLETE!", Styles::title()),
-            ]),
-            Line::from(""),
-        ];
-
-        // Show space freed and free space now
-        if let Some(free) = free_space {
-            success_lines.push(Line::from(vec![
-                Span::styled("    Space freed: ", Styles::secondary()),
-                Span::styled(
-                    bytesize::to_string(cleaned_bytes, false),
-                    Styles::emphasis(),
-                ),
-                Span::styled(" │ Free space now: ", Styles::secondary()),
-                Span::styled(bytesize::to_string(free, false), Styles::emphasis()),
-            ]));
+        let success_lines = if crate::audit::is_enabled() {
+            vec![
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("  ✓ ", Styles::success()),
+                    Span::styled("AUDIT COMPLETE - NOTHING WAS CHANGED", Styles::title()),
+                ]),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("    Cleaning would free ", Styles::primary()),
+                    Span::styled(
+                        bytesize::to_string(cleaned_bytes, false),
+                        Styles::emphasis(),
+                    ),
+                    Span::styled(" (read-only audit mode)", Styles::muted()),
+                ]),
+            ]
         } else {
-            success_lines.push(Line::from(vec![
-                Span::styled("    Successfully freed ", Styles::primary()),
-                Span::styled(
-                    bytesize::to_string(cleaned_bytes, false),
-                    Styles::emphasis(),
-                ),
-                Span::styled(" of disk space", Styles::primary()),
-            ]));
-        }
+            let mut success_lines = vec![
+                Line::from(""),
+                if app_state.cleanup_left > 0 {
+                    Line::from(vec![
+                        Span::styled("  ⏹ ", Styles::warning()),
+                        Span::styled("CLEANUP STOPPED", Styles::title()),
+                        Span::styled(
+                            format!(
+                                " - {} item(s) not cleaned, still selected in Results",
+                                app_state.cleanup_left
+                            ),
+                            Styles::secondary(),
+                        ),
+                    ])
+                } else {
+                    Line::from(vec![
+                        Span::styled("  ✓ ", Styles::success()),
+                        Span::styled("CLEANUP COMPLETE!", Styles::title()),
+                    ])
+                },
+                Line::from(""),
+            ];
+
+            // Hard links and compression keep part of the deleted size on disk
+            let unreclaimable = app_state
+                .free_estimate
+                .map(|estimate| estimate.unreclaimable_bytes())
+                .unwrap_or(0);
+            let freed = if unreclaimable > 0 {
+                format!(
+                    "~{}",
+                    bytesize::to_string(cleaned_bytes.saturating_sub(unreclaimable), false)
+                )
+            } else {
+                bytesize::to_string(cleaned_bytes, false)
+            };
+
+            // Show space freed and free space now
+            if let Some(free) = free_space {
+                success_lines.push(Line::from(vec![
+                    Span::styled("    Space freed: ", Styles::secondary()),
+                    Span::styled(freed, Styles::emphasis()),
+                    Span::styled(" │ Free space now: ", Styles::secondary()),
+                    Span::styled(bytesize::to_string(free, false), Styles::emphasis()),
+                ]));
+            } else {
+                success_lines.push(Line::from(vec![
+                    Span::styled("    Successfully freed ", Styles::primary()),
+                    Span::styled(freed, Styles::emphasis()),
+                    Span::styled(" of disk space", Styles::primary()),
+                ]));
+            }
+            success_lines
+        };
 
         let success_paragraph = Paragraph::new(success_lines).block(
             Block::default()
@@ -192,7 +232,7 @@ pub fn render(f: &mut Frame, app_state: &AppState) {
     } = app_state.screen
     {
         // Count categories that were processed
-        let categories_processed = app_state.category_groups.len();
+        let categories_processed = app_state.category_groups().len();
 
         let mut stats_lines = vec![
             Line::from(""),
@@ -304,7 +344,7 @@ pub fn render(f: &mut Frame, app_state: &AppState) {
             } else {
                 // Check if temp files were likely involved by checking if any category group is "Temp Files"
                 let has_temp_files = app_state
-                    .category_groups
+                    .category_groups()
                     .iter()
                     .any(|group| group.name == "Temp Files");
 
@@ -360,6 +400,93 @@ pub fn render(f: &mut Frame, app_state: &AppState) {
             )]));
         }
 
+        let background = crate::cleaner::fast_delete_progress();
+        if background.folders > 0 {
+            stats_lines.push(Line::from(vec![Span::styled(
+                format!(
+                    "    Deleting {} folder(s) in the background ({} left)",
+                    background.folders,
+                    bytesize::to_string(background.bytes, false)
+                ),
+                Styles::secondary(),
+            )]));
+        }
+        if background.failed > 0 {
+            stats_lines.push(Line::from(vec![Span::styled(
+                format!(
+                    "    {} background delete(s) failed (retried next run, see history)",
+                    background.failed
+                ),
+                Styles::warning(),
+            )]));
+        }
+
+        // Deleted data a restore point still references stays on disk
+        for storage in &app_state.shadow_storage {
+            stats_lines.push(Line::from(vec![
+                Span::styled("    Shadow copies:       ", Styles::secondary()),
+                Span::styled(
+                    format!(
+                        "{} held on {} by restore points",
+                        bytesize::to_string(storage.used_bytes, false),
+                        storage.volume
+                    ),
+                    Styles::warning(),
+                ),
+                Span::styled(
+                    match (storage.max_bytes, storage.max_percent) {
+                        (Some(max), Some(percent)) => format!(
+                            " (max {}, {:.0}%)",
+                            bytesize::to_string(max, false),
+                            percent
+                        ),
+                        _ => " (no maximum)".to_string(),
+                    },
+                    Styles::secondary(),
+                ),
+            ]));
+        }
+        if let Some((storage, max)) = app_state.shadow_resize_target() {
+            if app_state.shadow_resize_confirm {
+                stats_lines.push(Line::from(vec![
+                    Span::styled("    Run ", Styles::secondary()),
+                    Span::styled(storage.resize_command(max), Styles::emphasis()),
+                    Span::styled(
+                        "? Windows deletes the oldest restore points that don't fit. [Y] Run  [N] Cancel",
+                        Styles::warning(),
+                    ),
+                ]));
+            } else if app_state.shadow_message.is_none() {
+                stats_lines.push(Line::from(vec![Span::styled(
+                    format!(
+                        "    Deleted files still in restore points don't free space yet - press V to cap shadow storage on {} at {}",
+                        storage.volume, max
+                    ),
+                    Styles::secondary(),
+                )]));
+            }
+        }
+        if let Some(message) = &app_state.shadow_message {
+            stats_lines.push(Line::from(vec![Span::styled(
+                format!("    {}", message),
+                Styles::primary(),
+            )]));
+        }
+
+        if !app_state.recommendations.is_empty() {
+            stats_lines.push(Line::from(""));
+            stats_lines.push(Line::from(vec![Span::styled(
+                "    What's next:",
+                Styles::header(),
+            )]));
+            for recommendation in &app_state.recommendations {
+                stats_lines.push(Line::from(vec![
+                    Span::styled("    → ", Styles::secondary()),
+                    Span::styled(recommendation.message.clone(), Styles::primary()),
+                ]));
+            }
+        }
+
         let stats = Paragraph::new(stats_lines).block(
             Block::default()
                 .borders(Borders::ALL)
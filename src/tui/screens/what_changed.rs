@@ -0,0 +1,131 @@
+//! What changed screen - folders that grew or shrank since the previous scan
+
+use crate::tui::{
+    state::AppState,
+    theme::Styles,
+    widgets::{
+        logo::{render_logo, render_tagline, LOGO_WITH_TAGLINE_HEIGHT},
+        shortcuts::{get_shortcuts, render_shortcuts},
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let is_small = area.height < 20 || area.width < 60;
+    let shortcuts_height = if is_small { 2 } else { 3 };
+    let header_height = LOGO_WITH_TAGLINE_HEIGHT;
+    let min_content_height = 5;
+
+    if area.height < header_height + min_content_height + shortcuts_height || area.width < 20 {
+        let msg = Paragraph::new("Terminal too small. Please resize to at least 20x25")
+            .style(Styles::warning())
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(min_content_height),
+            Constraint::Length(shortcuts_height),
+        ])
+        .split(area);
+
+    render_logo(f, chunks[0]);
+    render_tagline(f, chunks[0]);
+    render_content(f, chunks[1], app_state);
+
+    let shortcuts = get_shortcuts(&app_state.screen, Some(app_state));
+    render_shortcuts(f, chunks[2], &shortcuts);
+}
+
+fn render_content(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let crate::tui::state::Screen::WhatChanged { diff, cursor } = &app_state.screen else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Title
+            Constraint::Length(1), // Scans compared
+            Constraint::Length(1), // Spacing
+            Constraint::Min(2),    // Changes
+        ])
+        .split(area);
+
+    f.render_widget(
+        Paragraph::new("What changed since the last scan").style(Styles::primary()),
+        chunks[0],
+    );
+
+    let Some(diff) = diff else {
+        f.render_widget(
+            Paragraph::new("Need at least two scans to compare. Scan again later.")
+                .style(Styles::muted()),
+            chunks[1],
+        );
+        return;
+    };
+
+    let format_time = |t: &chrono::DateTime<chrono::Utc>| {
+        t.with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+    };
+    f.render_widget(
+        Paragraph::new(format!(
+            "Scan #{} ({}) → scan #{} ({})",
+            diff.from_scan,
+            format_time(&diff.from_time),
+            diff.to_scan,
+            format_time(&diff.to_time)
+        ))
+        .style(Styles::secondary()),
+        chunks[1],
+    );
+
+    if diff.changes.is_empty() {
+        f.render_widget(
+            Paragraph::new("No size changes since the last scan").style(Styles::success()),
+            chunks[3],
+        );
+        return;
+    }
+
+    let items: Vec<ListItem> = diff
+        .changes
+        .iter()
+        .map(|change| {
+            let delta_style = if change.delta() > 0 {
+                Styles::warning()
+            } else {
+                Styles::success()
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:>12}  ", change.delta_human()), delta_style),
+                Span::styled(
+                    format!(
+                        "{:>10} → {:<10}  ",
+                        bytesize::to_string(change.old_size, false),
+                        bytesize::to_string(change.new_size, false)
+                    ),
+                    Styles::secondary(),
+                ),
+                Span::styled(change.path.display().to_string(), Styles::primary()),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(Styles::selected());
+    let mut state = ListState::default();
+    state.select(Some((*cursor).min(diff.changes.len() - 1)));
+    f.render_stateful_widget(list, chunks[3], &mut state);
+}
@@ -0,0 +1,329 @@
+//! Saved review session
+//!
+//! Quitting the TUI with scan results writes them to
+//! `%LOCALAPPDATA%\wole\tui_session.json`, together with the selection and
+//! which groups were expanded, and the Dashboard offers to restore them on the
+//! next launch. Every result path is stored with its size and modification
+//! time; if any of them changed or disappeared (or the scanned folder is gone)
+//! the session is thrown away instead of showing stale results.
+//!
+//! Plugin categories and the per-user breakdown are not saved.
+
+use crate::api::Category;
+use crate::categories::duplicates::DuplicateGroup;
+use crate::categories::trash::TrashEntry;
+use crate::output::{CategoryResult, ScanResults};
+use crate::tui::state::{AppState, Screen};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Bumped when the file format changes; older sessions are ignored
+const SESSION_VERSION: u32 = 1;
+
+/// What a result path looked like when the session was saved
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct PathStamp {
+    path: PathBuf,
+    /// Folders change whenever something inside them is written, so only
+    /// their existence is checked
+    is_dir: bool,
+    len: u64,
+    modified: u64,
+}
+
+impl PathStamp {
+    /// Current state of `path` (None if it is gone)
+    fn read(path: &Path) -> Option<Self> {
+        let meta = crate::utils::safe_metadata(path).ok()?;
+        let is_dir = meta.is_dir();
+        let modified = meta
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Some(Self {
+            path: path.to_path_buf(),
+            is_dir,
+            len: if is_dir { 0 } else { meta.len() },
+            modified: if is_dir { 0 } else { modified },
+        })
+    }
+}
+
+/// Expansion state of one result group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SavedGroup {
+    name: String,
+    expanded: bool,
+    /// (folder name, expanded)
+    folders: Vec<(String, bool)>,
+}
+
+/// Review state of the TUI, as written on exit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSession {
+    version: u32,
+    pub saved_at: DateTime<Utc>,
+    pub scan_path: PathBuf,
+    /// Categories the results cover (display names)
+    pub categories: Vec<String>,
+    /// Results by category id (see [`Category::id`])
+    results: BTreeMap<String, CategoryResult>,
+    duplicates_groups: Option<Vec<DuplicateGroup>>,
+    #[serde(default)]
+    trash_entries: Vec<TrashEntry>,
+    selected: Vec<PathBuf>,
+    groups: Vec<SavedGroup>,
+    stamps: Vec<PathStamp>,
+}
+
+impl SavedSession {
+    /// Capture the review in `app_state` (None if there is nothing left to review)
+    pub fn capture(app_state: &AppState) -> Option<Self> {
+        let results = app_state.scan_results.as_ref()?;
+
+        let stamps: HashMap<&PathBuf, PathStamp> = Category::ALL
+            .into_iter()
+            .flat_map(|c| c.result(results).paths.iter())
+            .filter_map(|p| PathStamp::read(p).map(|stamp| (p, stamp)))
+            .collect();
+
+        // Items already cleaned in this session are left out
+        let sizes: HashMap<&PathBuf, u64> = app_state
+            .all_items
+            .iter()
+            .map(|item| (&item.path, item.size_bytes))
+            .collect();
+        let saved: BTreeMap<String, CategoryResult> = Category::ALL
+            .into_iter()
+            .map(|c| {
+                let mut result = c.result(results).clone();
+                let before = result.paths.len();
+                let mut gone_bytes = 0u64;
+                result.paths.retain(|p| {
+                    let exists = stamps.contains_key(p);
+                    if !exists {
+                        gone_bytes += sizes.get(p).copied().unwrap_or(0);
+                    }
+                    exists
+                });
+                result.items = result.items.saturating_sub(before - result.paths.len());
+                result.size_bytes = result.size_bytes.saturating_sub(gone_bytes);
+                (c.id().to_string(), result)
+            })
+            .filter(|(_, result)| !result.paths.is_empty())
+            .collect();
+        if saved.is_empty() {
+            return None;
+        }
+
+        let selected: HashSet<PathBuf> = app_state
+            .selected_items
+            .iter()
+            .filter_map(|&idx| app_state.all_items.get(idx))
+            .map(|item| item.path.clone())
+            .collect();
+
+        let groups = app_state
+            .category_groups()
+            .iter()
+            .map(|group| SavedGroup {
+                name: group.name.clone(),
+                expanded: group.expanded,
+                folders: group
+                    .folder_groups
+                    .iter()
+                    .map(|folder| (folder.folder_name.clone(), folder.expanded))
+                    .collect(),
+            })
+            .collect();
+
+        let categories = match app_state.last_scan_categories {
+            Some(ref scanned) => scanned.iter().cloned().collect(),
+            None => app_state
+                .categories
+                .iter()
+                .filter(|cat| cat.enabled)
+                .map(|cat| cat.name.clone())
+                .collect(),
+        };
+
+        Some(Self {
+            version: SESSION_VERSION,
+            saved_at: Utc::now(),
+            scan_path: app_state.scan_path.clone(),
+            categories,
+            results: saved,
+            duplicates_groups: results.duplicates_groups.clone(),
+            trash_entries: results.trash_entries.clone(),
+            selected: selected.into_iter().collect(),
+            groups,
+            stamps: stamps.into_values().collect(),
+        })
+    }
+
+    /// Number of result paths in the session
+    pub fn items(&self) -> usize {
+        self.stamps.len()
+    }
+
+    /// Result paths that changed or disappeared since the session was saved
+    pub fn changed_paths(&self) -> usize {
+        if !self.scan_path.exists() {
+            return self.stamps.len().max(1);
+        }
+        self.stamps
+            .iter()
+            .filter(|saved| PathStamp::read(&saved.path).as_ref() != Some(*saved))
+            .count()
+    }
+
+    /// Put the saved review back into `app_state` and show the Results screen
+    pub fn restore(self, app_state: &mut AppState) {
+        let mut results = ScanResults::default();
+        for (id, result) in self.results {
+            if let Some(category) = Category::from_id(&id) {
+                *category.result_mut(&mut results) = result;
+            }
+        }
+        results.duplicates_groups = self.duplicates_groups;
+        results.trash_entries = self.trash_entries;
+
+        app_state.scan_path = self.scan_path;
+        for cat in &mut app_state.categories {
+            cat.enabled = self.categories.contains(&cat.name);
+        }
+        app_state.last_scan_categories = Some(self.categories.into_iter().collect());
+        app_state.scan_results = Some(results);
+        app_state.flatten_results();
+
+        let selected: HashSet<PathBuf> = self.selected.into_iter().collect();
+        app_state.selected_items = app_state
+            .all_items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| selected.contains(&item.path))
+            .map(|(idx, _)| idx)
+            .collect();
+
+        for group in app_state.category_groups_mut() {
+            let Some(saved) = self.groups.iter().find(|g| g.name == group.name) else {
+                continue;
+            };
+            group.expanded = saved.expanded;
+            for folder in &mut group.folder_groups {
+                if let Some((_, expanded)) = saved
+                    .folders
+                    .iter()
+                    .find(|(name, _)| *name == folder.folder_name)
+                {
+                    folder.expanded = *expanded;
+                }
+            }
+        }
+
+        app_state.cursor = 0;
+        app_state.scroll_offset = 0;
+        app_state.screen = Screen::Results;
+    }
+
+    /// Write the session, replacing any previous one
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&session_file()?)
+    }
+
+    fn save_to(&self, file: &Path) -> Result<()> {
+        let json = serde_json::to_string(self)?;
+        std::fs::write(file, json)
+            .with_context(|| format!("Failed to write session: {}", file.display()))
+    }
+
+    /// The saved session, if there is one this version can read
+    pub fn load() -> Option<Self> {
+        Self::load_from(&session_file().ok()?)
+    }
+
+    fn load_from(file: &Path) -> Option<Self> {
+        let json = std::fs::read_to_string(file).ok()?;
+        serde_json::from_str::<Self>(&json)
+            .ok()
+            .filter(|session| session.version == SESSION_VERSION)
+    }
+
+    /// Delete the saved session
+    pub fn discard() {
+        if let Ok(file) = session_file() {
+            let _ = std::fs::remove_file(file);
+        }
+    }
+}
+
+fn session_file() -> Result<PathBuf> {
+    let base_dir = if cfg!(windows) {
+        std::env::var("LOCALAPPDATA")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| {
+                std::env::var("USERPROFILE")
+                    .map(|p| PathBuf::from(p).join("AppData").join("Local"))
+                    .unwrap_or_else(|_| PathBuf::from("."))
+            })
+    } else {
+        std::env::var("HOME")
+            .map(|h| PathBuf::from(h).join(".local").join("share"))
+            .unwrap_or_else(|_| PathBuf::from("."))
+    };
+
+    let dir = base_dir.join("wole");
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    Ok(dir.join("tui_session.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_and_invalidation() {
+        let temp = tempfile::tempdir().unwrap();
+        let file = temp.path().join("old.log");
+        let folder = temp.path().join("cache");
+        std::fs::write(&file, "log").unwrap();
+        std::fs::create_dir(&folder).unwrap();
+
+        let session = SavedSession {
+            version: SESSION_VERSION,
+            saved_at: Utc::now(),
+            scan_path: temp.path().to_path_buf(),
+            categories: vec!["Log Files".to_string()],
+            results: BTreeMap::new(),
+            duplicates_groups: None,
+            trash_entries: Vec::new(),
+            selected: vec![file.clone()],
+            groups: Vec::new(),
+            stamps: vec![
+                PathStamp::read(&file).unwrap(),
+                PathStamp::read(&folder).unwrap(),
+            ],
+        };
+        let saved = temp.path().join("session.json");
+        session.save_to(&saved).unwrap();
+        let loaded = SavedSession::load_from(&saved).unwrap();
+        assert_eq!(loaded.items(), 2);
+        assert_eq!(loaded.changed_paths(), 0);
+
+        // Writing inside a folder doesn't count; a grown file does
+        std::fs::write(folder.join("entry"), "x").unwrap();
+        assert_eq!(loaded.changed_paths(), 0);
+        std::fs::write(&file, "log, now longer").unwrap();
+        assert_eq!(loaded.changed_paths(), 1);
+
+        std::fs::remove_dir_all(&folder).unwrap();
+        assert_eq!(loaded.changed_paths(), 2);
+    }
+}
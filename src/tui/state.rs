@@ -1,8 +1,11 @@
 //! Application state management for TUI
 
 use crate::output::ScanResults;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use std::time::SystemTime;
 
 #[derive(Debug, Clone)]
@@ -62,6 +65,10 @@ pub enum ResultsRow {
 /// Now matches ResultsRow with folder grouping support for consistent behavior.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConfirmRow {
+    /// A risk tier header; collapsing it hides all of its categories.
+    TierHeader {
+        tier: RiskTier,
+    },
     CategoryHeader {
         cat_idx: usize,
     },
@@ -83,6 +90,56 @@ pub enum ConfirmRow {
     Spacer,
 }
 
+/// What the Confirm screen shows
+///
+/// Built once on entry ([`AppState::cache_confirm_groups`]) and updated in
+/// place by the expand/collapse events, so rendering borrows it instead of
+/// regrouping the selection every frame.
+#[derive(Debug, Clone, Default)]
+pub struct ConfirmModel {
+    /// Categories of the confirm snapshot, in display order
+    pub groups: Vec<CategoryGroup>,
+    /// Flattened rows of `groups` under the current expansion state
+    pub rows: Arc<Vec<ConfirmRow>>,
+}
+
+/// How much is at stake when deleting a category, used to group the Confirm screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RiskTier {
+    /// Caches and leftovers that are regenerated when needed
+    Safe,
+    /// Personal files worth a second look
+    Review,
+    /// Can't be undone: uninstalls, emptying the Recycle Bin, fast-deleted folders
+    HighRisk,
+}
+
+impl RiskTier {
+    pub fn label(self) -> &'static str {
+        match self {
+            RiskTier::Safe => "SAFE",
+            RiskTier::Review => "REVIEW",
+            RiskTier::HighRisk => "HIGH RISK",
+        }
+    }
+
+    pub fn hint(self) -> &'static str {
+        match self {
+            RiskTier::Safe => "regenerated when needed",
+            RiskTier::Review => "check before deleting",
+            RiskTier::HighRisk => "cannot be undone",
+        }
+    }
+}
+
+/// Free space of a volume now and once the Confirm selection is cleaned
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VolumeProjection {
+    pub mount_point: PathBuf,
+    pub free_bytes: u64,
+    pub freed_bytes: u64,
+}
+
 /// Current screen being displayed
 #[derive(Debug)]
 pub enum Screen {
@@ -114,6 +171,8 @@ pub enum Screen {
         progress: Option<RestoreProgress>,
         result: Option<RestoreResult>,
         restore_all_bin: bool, // true = restore all bin, false = restore from last deletion
+        /// History file to restore instead of the most recent session
+        log_path: Option<PathBuf>,
     },
     DiskInsights {
         insights: crate::disk_usage::DiskInsights,
@@ -128,6 +187,8 @@ pub enum Screen {
         results: Vec<crate::optimize::OptimizeResult>,
         running: bool,
         message: Option<String>,
+        /// wole runs as Administrator; admin-only operations can't be selected otherwise
+        admin: bool,
     },
     Status {
         status: Box<crate::status::SystemStatus>,
@@ -135,6 +196,103 @@ pub enum Screen {
         status_receiver:
             Option<std::sync::mpsc::Receiver<anyhow::Result<crate::status::SystemStatus>>>,
     },
+    WhatChanged {
+        /// None until two scans have recorded snapshots
+        diff: Option<crate::scan_cache::SnapshotDiff>,
+        cursor: usize,
+    },
+    Search {
+        query: String,
+        results: Vec<crate::scan_cache::IndexedFile>,
+        cursor: usize,
+        message: Option<String>,
+        /// Pending background index rebuild (file count or error)
+        index_receiver: Option<std::sync::mpsc::Receiver<Result<u64, String>>>,
+    },
+    History {
+        sessions: Vec<crate::history::HistorySession>,
+        /// Category/path substring; hides sessions and records that don't match
+        filter: String,
+        /// Typed keys go to the filter
+        editing_filter: bool,
+        cursor: usize,
+        /// Index into `sessions` of the session being drilled into
+        open_session: Option<usize>,
+        record_cursor: usize,
+        message: Option<String>,
+    },
+    /// Choose the folder the TUI scans (from the Dashboard)
+    ScanPathPicker {
+        picker: crate::tui::widgets::dir_picker::DirPicker,
+    },
+    /// Application, browser and temp cache results grouped by owning app
+    AppCaches {
+        apps: Vec<crate::cache_owners::AppUsage>,
+        cursor: usize,
+    },
+    /// Fuzzy finder over every result item, whatever its category
+    Finder {
+        query: String,
+        /// Indices into `all_items` matching `query`, best match first
+        matches: Vec<usize>,
+        cursor: usize,
+        /// Items marked with Tab (indices into `all_items`)
+        marked: HashSet<usize>,
+    },
+    /// Bar charts of a Disk Insights folder by extension, size and age
+    Breakdown {
+        root: PathBuf,
+        /// None until the walk finishes
+        breakdown: Option<crate::analyzer::Breakdown>,
+        /// Index into [`crate::analyzer::Breakdown::distributions`]
+        tab: usize,
+        /// Running walk; started by the main loop while `breakdown` is None
+        job: Option<crate::scanner::Job<anyhow::Result<crate::analyzer::Breakdown>>>,
+        message: Option<String>,
+        /// Disk Insights screen to go back to
+        back: Box<Screen>,
+    },
+    /// Live table of the processes and paths writing to a drive
+    DiskWrites {
+        /// Drive, e.g. "C:"
+        volume: String,
+        /// None if the monitor couldn't start (see `message`)
+        monitor: Option<crate::disk_writes::WriteMonitor>,
+        /// Group the table by process instead of path
+        by_process: bool,
+        message: Option<String>,
+        /// Screen to go back to
+        back: Box<Screen>,
+    },
+    /// Hosts file, proxy and DNS leftovers (N on Status)
+    NetCheck {
+        issues: Vec<crate::net_hygiene::NetIssue>,
+        cursor: usize,
+        message: Option<String>,
+        /// Screen to go back to
+        back: Box<Screen>,
+    },
+    /// Saved Results searches ("smart views") from `ui.saved_views`
+    SavedViews {
+        cursor: usize,
+        /// Name being typed for the current search, while saving it
+        naming: Option<String>,
+        message: Option<String>,
+    },
+    /// Select or deselect Results items by quick rules (R in Results)
+    SelectRule {
+        /// Older than (days), larger than (MB), extensions, path contains
+        fields: [String; 4],
+        /// Row being edited: the fields, then scope and action
+        cursor: usize,
+        /// Category group under the Results cursor when the dialog opened
+        group: Option<usize>,
+        /// Apply to all results instead of `group`
+        all: bool,
+        /// Deselect the matching items instead of selecting them
+        deselect: bool,
+        message: Option<String>,
+    },
 }
 
 impl Clone for Screen {
@@ -169,10 +327,12 @@ impl Clone for Screen {
                 progress,
                 result,
                 restore_all_bin,
+                log_path,
             } => Screen::Restore {
                 progress: progress.clone(),
                 result: result.clone(),
                 restore_all_bin: *restore_all_bin,
+                log_path: log_path.clone(),
             },
             Screen::DiskInsights {
                 insights,
@@ -193,12 +353,14 @@ impl Clone for Screen {
                 results,
                 running,
                 message,
+                admin,
             } => Screen::Optimize {
                 cursor: *cursor,
                 selected: selected.clone(),
                 results: results.clone(),
                 running: *running,
                 message: message.clone(),
+                admin: *admin,
             },
             Screen::Status {
                 status,
@@ -210,8 +372,213 @@ impl Clone for Screen {
                 // Receiver cannot be cloned, so set to None
                 status_receiver: None,
             },
+            Screen::WhatChanged { diff, cursor } => Screen::WhatChanged {
+                diff: diff.clone(),
+                cursor: *cursor,
+            },
+            Screen::Search {
+                query,
+                results,
+                cursor,
+                message,
+                index_receiver: _,
+            } => Screen::Search {
+                query: query.clone(),
+                results: results.clone(),
+                cursor: *cursor,
+                message: message.clone(),
+                index_receiver: None,
+            },
+            Screen::History {
+                sessions,
+                filter,
+                editing_filter,
+                cursor,
+                open_session,
+                record_cursor,
+                message,
+            } => Screen::History {
+                sessions: sessions.clone(),
+                filter: filter.clone(),
+                editing_filter: *editing_filter,
+                cursor: *cursor,
+                open_session: *open_session,
+                record_cursor: *record_cursor,
+                message: message.clone(),
+            },
+            Screen::ScanPathPicker { picker } => Screen::ScanPathPicker {
+                picker: picker.clone(),
+            },
+            Screen::AppCaches { apps, cursor } => Screen::AppCaches {
+                apps: apps.clone(),
+                cursor: *cursor,
+            },
+            Screen::Finder {
+                query,
+                matches,
+                cursor,
+                marked,
+            } => Screen::Finder {
+                query: query.clone(),
+                matches: matches.clone(),
+                cursor: *cursor,
+                marked: marked.clone(),
+            },
+            Screen::Breakdown {
+                root,
+                breakdown,
+                tab,
+                job: _,
+                message,
+                back,
+            } => Screen::Breakdown {
+                root: root.clone(),
+                breakdown: breakdown.clone(),
+                tab: *tab,
+                job: None,
+                message: message.clone(),
+                back: back.clone(),
+            },
+            Screen::DiskWrites {
+                volume,
+                monitor: _,
+                by_process,
+                message: _,
+                back,
+            } => Screen::DiskWrites {
+                volume: volume.clone(),
+                // The ETW session belongs to the original; R starts a new one
+                monitor: None,
+                by_process: *by_process,
+                message: Some("Not watching - press R to restart".to_string()),
+                back: back.clone(),
+            },
+            Screen::NetCheck {
+                issues,
+                cursor,
+                message,
+                back,
+            } => Screen::NetCheck {
+                issues: issues.clone(),
+                cursor: *cursor,
+                message: message.clone(),
+                back: back.clone(),
+            },
+            Screen::SavedViews {
+                cursor,
+                naming,
+                message,
+            } => Screen::SavedViews {
+                cursor: *cursor,
+                naming: naming.clone(),
+                message: message.clone(),
+            },
+            Screen::SelectRule {
+                fields,
+                cursor,
+                group,
+                all,
+                deselect,
+                message,
+            } => Screen::SelectRule {
+                fields: fields.clone(),
+                cursor: *cursor,
+                group: *group,
+                all: *all,
+                deselect: *deselect,
+                message: message.clone(),
+            },
+        }
+    }
+}
+
+impl Screen {
+    /// Whether work is in progress that the screen itself drives (a scan, a
+    /// cleanup, a restore or optimizations), so it can't be switched away from
+    pub fn is_busy(&self) -> bool {
+        match self {
+            Screen::Scanning { .. } | Screen::Cleaning { .. } => true,
+            Screen::Restore { progress, .. } => progress.is_some(),
+            Screen::Optimize { running, .. } => *running,
+            _ => false,
+        }
+    }
+}
+
+/// Views in the sidebar, in display order (Alt+1 to Alt+7)
+///
+/// Each screen belongs to one view. Switching views parks the current screen
+/// in [`AppState::parked_screens`] and brings back the target view's parked
+/// screen, so a half-browsed Disk Insights or Status screen survives a trip
+/// to the Dashboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SidebarView {
+    Dashboard,
+    Results,
+    Insights,
+    Optimize,
+    Status,
+    History,
+    Config,
+}
+
+impl SidebarView {
+    pub const ALL: [SidebarView; 7] = [
+        SidebarView::Dashboard,
+        SidebarView::Results,
+        SidebarView::Insights,
+        SidebarView::Optimize,
+        SidebarView::Status,
+        SidebarView::History,
+        SidebarView::Config,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SidebarView::Dashboard => "Dashboard",
+            SidebarView::Results => "Results",
+            SidebarView::Insights => "Insights",
+            SidebarView::Optimize => "Optimize",
+            SidebarView::Status => "Status",
+            SidebarView::History => "History",
+            SidebarView::Config => "Config",
+        }
+    }
+
+    /// The view `screen` is shown under
+    pub fn of(screen: &Screen) -> SidebarView {
+        match screen {
+            Screen::Dashboard
+            | Screen::WhatChanged { .. }
+            | Screen::Search { .. }
+            | Screen::ScanPathPicker { .. } => SidebarView::Dashboard,
+            Screen::Scanning { .. }
+            | Screen::Results
+            | Screen::Preview { .. }
+            | Screen::Confirm { .. }
+            | Screen::Cleaning { .. }
+            | Screen::Success { .. }
+            | Screen::AppCaches { .. }
+            | Screen::Finder { .. }
+            | Screen::SavedViews { .. }
+            | Screen::SelectRule { .. } => SidebarView::Results,
+            Screen::DiskInsights { .. } | Screen::Breakdown { .. } => SidebarView::Insights,
+            Screen::Optimize { .. } => SidebarView::Optimize,
+            Screen::Status { .. } | Screen::DiskWrites { .. } | Screen::NetCheck { .. } => {
+                SidebarView::Status
+            }
+            Screen::History { .. } | Screen::RestoreSelection { .. } | Screen::Restore { .. } => {
+                SidebarView::History
+            }
+            Screen::Config => SidebarView::Config,
         }
     }
+
+    /// View for Alt+`c` (1-7)
+    pub fn for_digit(c: char) -> Option<SidebarView> {
+        let index = c.to_digit(10)?.checked_sub(1)? as usize;
+        Self::ALL.get(index).copied()
+    }
 }
 
 /// Result of a restore operation
@@ -222,6 +589,9 @@ pub struct RestoreResult {
     pub errors: usize,
     pub not_found: usize,
     pub error_reasons: Vec<String>, // Store error messages for display
+    pub cancelled: bool,            // Stopped with Esc before everything was restored
+    /// Restored items that don't match their deletion record
+    pub mismatches: Vec<String>,
 }
 
 /// Progress tracking for scanning
@@ -230,13 +600,27 @@ pub struct ScanProgress {
     pub current_category: String,
     pub current_path: Option<PathBuf>,
     pub notice: Option<String>,
+    /// Round-trip latency of the scanned network share (None for local disks)
+    pub latency: Option<std::time::Duration>,
+    /// Scan strategy picked for the scanned drive (see `drive::ScanStrategy`)
+    pub strategy: Option<String>,
     pub category_progress: Vec<CategoryProgress>,
+    /// Category that overran its time budget and is waiting for a decision
+    pub slow: Option<SlowCategory>,
     pub total_scanned: usize,
     pub total_found: usize,
     pub total_size: u64,
     pub start_time: std::time::Instant,
 }
 
+/// A category scan that is taking longer than its budget
+#[derive(Debug, Clone)]
+pub struct SlowCategory {
+    pub name: String,
+    pub elapsed_secs: u64,
+    pub control: crate::scan_events::CategoryControl,
+}
+
 /// Progress for a single category during scan
 #[derive(Debug, Clone)]
 pub struct CategoryProgress {
@@ -254,6 +638,10 @@ pub struct CleanProgress {
     pub cleaned: u64,
     pub total: u64,
     pub errors: usize,
+    /// Held between two batches until the user resumes
+    pub paused: bool,
+    /// The user asked to stop; the items not reached yet stay selected
+    pub stop_requested: bool,
 }
 
 /// Progress tracking for restoration
@@ -338,6 +726,27 @@ pub const CATEGORIES: &[CategoryDef] = &[
         default_enabled: false,
         description: "Package manager cache (npm, pip, nuget, etc.)",
     },
+    CategoryDef {
+        name: "Toolchain Caches",
+        scan_field: "toolchain_caches",
+        safe: false,
+        default_enabled: false,
+        description: "Unused Gradle/Maven/JetBrains/VS/sccache caches",
+    },
+    CategoryDef {
+        name: "Python Environments",
+        scan_field: "python_envs",
+        safe: false,
+        default_enabled: false,
+        description: "Stale virtualenvs, conda envs and __pycache__",
+    },
+    CategoryDef {
+        name: "Ignored Files (git)",
+        scan_field: "git_ignored",
+        safe: false,
+        default_enabled: false,
+        description: "Untracked .gitignore matches in idle repos",
+    },
     // C. Space Hunters (review required, biggest wins)
     CategoryDef {
         name: "Installed Applications",
@@ -374,6 +783,48 @@ pub const CATEGORIES: &[CategoryDef] = &[
         default_enabled: false,
         description: "Identical file copies",
     },
+    CategoryDef {
+        name: "Log Files",
+        scan_field: "logs",
+        safe: false,
+        default_enabled: false,
+        description: "Oversized logs and runaway log rotation",
+    },
+    CategoryDef {
+        name: "Game Caches",
+        scan_field: "game_caches",
+        safe: true,
+        default_enabled: false,
+        description: "Steam/Epic/Origin/Battle.net caches and installers",
+    },
+    CategoryDef {
+        name: "ML Models",
+        scan_field: "ml_models",
+        safe: false,
+        default_enabled: false,
+        description: "HuggingFace, torch, Ollama models (per model)",
+    },
+    CategoryDef {
+        name: "Email Data",
+        scan_field: "email",
+        safe: false,
+        default_enabled: false,
+        description: "Large Outlook/Thunderbird files (report only)",
+    },
+    CategoryDef {
+        name: "Creative App Caches",
+        scan_field: "creative_caches",
+        safe: false,
+        default_enabled: false,
+        description: "Adobe/Resolve caches and OBS remux leftovers",
+    },
+    CategoryDef {
+        name: "Device Backups",
+        scan_field: "device_backups",
+        safe: false,
+        default_enabled: false,
+        description: "iPhone/iPad backups (pick the old ones)",
+    },
     // D. Advanced (admin / system)
     CategoryDef {
         name: "Windows Update",
@@ -389,6 +840,20 @@ pub const CATEGORIES: &[CategoryDef] = &[
         default_enabled: false,
         description: "System event logs (requires admin)",
     },
+    CategoryDef {
+        name: "Thumbnail Cache",
+        scan_field: "thumbnails",
+        safe: false,
+        default_enabled: false,
+        description: "Explorer thumbnail/icon caches (restarts Explorer)",
+    },
+    CategoryDef {
+        name: "Crash Dumps",
+        scan_field: "crash_dumps",
+        safe: true,
+        default_enabled: false,
+        description: "Old crash dumps and error reports (WER)",
+    },
 ];
 
 /// Category selection state
@@ -407,6 +872,155 @@ pub enum PendingAction {
     Analyze,
 }
 
+/// Actions in the dashboard's Actions list, in display order
+///
+/// The zero-argument menu (`wole -v`) starts the same actions through
+/// [`crate::tui::events::run_dashboard_action`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DashboardAction {
+    Scan,
+    Clean,
+    Analyze,
+    Restore,
+    Optimize,
+    Status,
+    Config,
+}
+
+impl DashboardAction {
+    pub const ALL: [DashboardAction; 7] = [
+        DashboardAction::Scan,
+        DashboardAction::Clean,
+        DashboardAction::Analyze,
+        DashboardAction::Restore,
+        DashboardAction::Optimize,
+        DashboardAction::Status,
+        DashboardAction::Config,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DashboardAction::Scan => "Scan",
+            DashboardAction::Clean => "Clean",
+            DashboardAction::Analyze => "Analyze",
+            DashboardAction::Restore => "Restore",
+            DashboardAction::Optimize => "Optimize",
+            DashboardAction::Status => "Status",
+            DashboardAction::Config => "Config",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            DashboardAction::Scan => "Find cleanable files (safe, dry-run)",
+            DashboardAction::Clean => "Delete selected files",
+            DashboardAction::Analyze => "Explore disk usage (folder sizes)",
+            DashboardAction::Restore => "Restore files from deletion or Recycle Bin",
+            DashboardAction::Optimize => "Optimize Windows system performance",
+            DashboardAction::Status => "Real-time system health dashboard",
+            DashboardAction::Config => "View or modify settings",
+        }
+    }
+
+    /// Scan/Clean/Analyze need at least one enabled category
+    pub fn needs_categories(self) -> bool {
+        matches!(
+            self,
+            DashboardAction::Scan | DashboardAction::Clean | DashboardAction::Analyze
+        )
+    }
+
+    /// Position in the Actions list
+    pub fn index(self) -> usize {
+        Self::ALL.iter().position(|a| *a == self).unwrap_or(0)
+    }
+}
+
+/// Built-in category sets offered by the zero-argument menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanProfile {
+    /// Every category marked safe
+    Safe,
+    /// The dashboard's out-of-the-box selection
+    Default,
+    /// Build output, package caches and toolchains
+    Developer,
+    /// Big, review-required finds
+    SpaceHunter,
+}
+
+impl CleanProfile {
+    pub const ALL: [CleanProfile; 4] = [
+        CleanProfile::Safe,
+        CleanProfile::Default,
+        CleanProfile::Developer,
+        CleanProfile::SpaceHunter,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CleanProfile::Safe => "Safe",
+            CleanProfile::Default => "Default",
+            CleanProfile::Developer => "Developer",
+            CleanProfile::SpaceHunter => "Space hunter",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            CleanProfile::Safe => "Caches, temp files, Recycle Bin - nothing to review",
+            CleanProfile::Default => "The out-of-the-box dashboard selection",
+            CleanProfile::Developer => "Build artifacts, package caches, toolchains, venvs",
+            CleanProfile::SpaceHunter => "Large, old and duplicate files (review required)",
+        }
+    }
+
+    /// Whether `category` is part of this profile
+    pub fn includes(self, category: &CategoryDef) -> bool {
+        match self {
+            CleanProfile::Safe => category.safe,
+            CleanProfile::Default => category.default_enabled,
+            CleanProfile::Developer => matches!(
+                category.name,
+                "Build Artifacts"
+                    | "Package Cache"
+                    | "Toolchain Caches"
+                    | "Python Environments"
+                    | "Ignored Files (git)"
+            ),
+            CleanProfile::SpaceHunter => matches!(
+                category.name,
+                "Large Files" | "Old Files" | "Old Downloads" | "Duplicates"
+            ),
+        }
+    }
+}
+
+/// The category a history record's category refers to
+///
+/// Records carry either the display name ("Temp Files") or the scan field
+/// ("temp"), depending on which cleaner logged them.
+pub fn category_for_history_name(name: &str) -> Option<&'static CategoryDef> {
+    let key = name.trim().to_lowercase().replace(' ', "_");
+    CATEGORIES
+        .iter()
+        .find(|cat| cat.name.to_lowercase().replace(' ', "_") == key || cat.scan_field == key)
+}
+
+/// Old Files description for the configured age and time basis
+fn old_files_description(config: &crate::config::Config) -> String {
+    let basis = crate::categories::old::TimeBasis::of(config);
+    let mut description = format!(
+        "Files not {} in {} days",
+        basis.verb(),
+        config.thresholds.min_age_days
+    );
+    if crate::categories::old::time_basis_warning(config).is_some() {
+        description.push_str(" (access times not tracked)");
+    }
+    description
+}
+
 /// Folder group within a category (e.g., items grouped by parent project folder)
 #[derive(Debug, Clone)]
 pub struct FolderGroup {
@@ -456,6 +1070,20 @@ fn folder_key_for_display(scan_path: &Path, folder_name: &str) -> String {
     crate::utils::to_relative_path(&folder_path, scan_path)
 }
 
+/// Names the launcher, tool or store a path belongs to
+type SourceLookup = fn(&Path) -> Option<&'static str>;
+
+/// Source lookup and icon for categories grouped by launcher/tool/store instead of folder
+fn source_grouping(category: &str) -> Option<(SourceLookup, &'static str)> {
+    match category {
+        "Game Caches" => Some((crate::categories::game_caches::launcher_name, "🎮")),
+        "Toolchain Caches" => Some((crate::categories::toolchain_caches::tool_name, "🛠️")),
+        "ML Models" => Some((crate::categories::ml_models::store_name, "🧠")),
+        "Creative App Caches" => Some((crate::categories::creative_caches::app_name, "🎬")),
+        _ => None,
+    }
+}
+
 /// Get dashboard order index for a category (respects the order defined in CATEGORIES array)
 /// Returns the index in CATEGORIES array, or usize::MAX if not found
 fn dashboard_order_index(category_name: &str) -> usize {
@@ -469,7 +1097,10 @@ fn dashboard_order_index(category_name: &str) -> usize {
 /// Returns: 1 = Review (biggest wins), 2 = Safe, 3 = Admin/System
 fn results_group_priority(category_name: &str, safe: bool) -> u8 {
     // Admin/system categories
-    if category_name == "Windows Update" || category_name == "Event Logs" {
+    if matches!(
+        category_name,
+        "Windows Update" | "Event Logs" | "Thumbnail Cache"
+    ) {
         return 3;
     }
     // Review categories (not safe, not admin)
@@ -480,6 +1111,75 @@ fn results_group_priority(category_name: &str, safe: bool) -> u8 {
     2
 }
 
+/// Category of the items Disk Insights sends to the confirm screen
+pub const INSIGHTS_CATEGORY: &str = "Disk Insights";
+
+/// Category of the file search sends to the confirm screen
+pub const SEARCH_CATEGORY: &str = "Search";
+
+/// Categories whose items only exist for one visit to the confirm screen
+fn is_confirm_only(category: &str) -> bool {
+    category == INSIGHTS_CATEGORY || category == SEARCH_CATEGORY
+}
+
+/// Size of a folder or file in a Disk Insights tree
+fn insights_size(root: &crate::disk_usage::FolderNode, path: &Path) -> Option<u64> {
+    if let Some(folder) = crate::disk_usage::find_folder_by_path(root, path) {
+        return Some(folder.size);
+    }
+    let parent = crate::disk_usage::find_folder_by_path(root, path.parent()?)?;
+    parent
+        .files
+        .iter()
+        .find(|file| file.path == path)
+        .map(|file| file.size)
+}
+
+/// Split an `owner:NAME` token off a Results search query, returning the
+/// lowercased owner and the rest of the query
+fn split_owner_filter(query: &str) -> (Option<String>, String) {
+    let mut owner = None;
+    let mut rest = Vec::new();
+    for word in query.split_whitespace() {
+        match word.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("owner:") => {
+                owner = Some(word[6..].to_lowercase());
+            }
+            _ => rest.push(word),
+        }
+    }
+    (owner, rest.join(" "))
+}
+
+/// Whether `item` belongs to the `owner:` filter (case-insensitive; always true without one)
+fn owner_matches(filter: Option<&str>, item: &ResultItem) -> bool {
+    filter.is_none_or(|filter| {
+        item.owner
+            .as_deref()
+            .is_some_and(|owner| owner.to_lowercase() == filter)
+    })
+}
+
+/// Split a `cat:ID,ID` token off a Results search query, returning the
+/// category ids and the rest of the query
+fn split_category_filter(query: &str) -> (Option<Vec<String>>, String) {
+    let view = crate::config::SavedView::from_search("", query);
+    let categories = (!view.categories.is_empty()).then_some(view.categories);
+    (categories, view.query)
+}
+
+/// Whether `item` is in one of the `cat:` categories (always true without the token)
+fn category_matches(filter: Option<&[String]>, item: &ResultItem) -> bool {
+    filter.is_none_or(|ids| {
+        let id = crate::api::Category::ALL
+            .iter()
+            .find(|c| c.name() == item.category)
+            .map(|c| c.id().to_string())
+            .unwrap_or_else(|| item.category.to_lowercase().replace(' ', "_"));
+        ids.contains(&id)
+    })
+}
+
 pub(crate) fn build_folder_hierarchy(
     scan_path: &Path,
     group_name: &str,
@@ -545,6 +1245,84 @@ pub(crate) fn build_folder_hierarchy(
     FolderHierarchy { roots, children }
 }
 
+/// Background [`crate::size::real_free_estimate`] for the Confirm screen
+///
+/// Dropping the job stops the walk.
+pub struct FreeEstimateJob {
+    receiver: std::sync::mpsc::Receiver<crate::size::FreeEstimate>,
+    cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl FreeEstimateJob {
+    pub fn start(paths: Vec<PathBuf>) -> Self {
+        let (tx, receiver) = std::sync::mpsc::channel();
+        let cancel = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let thread_cancel = cancel.clone();
+        std::thread::spawn(move || {
+            if let Some(estimate) = crate::size::real_free_estimate(&paths, Some(&thread_cancel)) {
+                let _ = tx.send(estimate);
+            }
+        });
+        Self { receiver, cancel }
+    }
+
+    /// The estimate, once the walk has finished
+    pub fn try_take(&self) -> Option<crate::size::FreeEstimate> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+impl Drop for FreeEstimateJob {
+    fn drop(&mut self) {
+        self.cancel
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+/// Background `compact.exe` runs for the Results screen's compress action
+///
+/// Folders are compressed one at a time and reported as each one finishes.
+pub struct CompressJob {
+    receiver: std::sync::mpsc::Receiver<crate::compress::CompressionResult>,
+    total: usize,
+    results: Vec<crate::compress::CompressionResult>,
+}
+
+impl CompressJob {
+    pub fn start(paths: Vec<PathBuf>) -> Self {
+        let (tx, receiver) = std::sync::mpsc::channel();
+        let total = paths.len();
+        std::thread::spawn(move || {
+            for path in paths {
+                let result = crate::compress::compress_path(
+                    &path,
+                    crate::compress::CompressionMode::default(),
+                    false,
+                );
+                if tx.send(result).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            receiver,
+            total,
+            results: Vec::new(),
+        }
+    }
+
+    /// Collect finished folders; true once there are no more to come
+    fn poll(&mut self) -> bool {
+        loop {
+            match self.receiver.try_recv() {
+                Ok(result) => self.results.push(result),
+                Err(std::sync::mpsc::TryRecvError::Empty) => return false,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => return true,
+            }
+        }
+    }
+}
+
 /// Main application state
 pub struct AppState {
     pub screen: Screen,
@@ -557,7 +1335,8 @@ pub struct AppState {
     pub cursor: usize,
     pub scroll_offset: usize,
     pub all_items: Vec<ResultItem>, // flattened list of all items for display
-    pub category_groups: Vec<CategoryGroup>, // grouped results for display
+    category_groups: Vec<CategoryGroup>, // grouped results for display; changed through category_groups_mut()
+    results_generation: u64, // bumped whenever category_groups may change, keys the Results row cache
     pub path_to_indices: HashMap<PathBuf, Vec<usize>>, // maps file paths to all indices in all_items (for cross-category sync)
     pub permanent_delete: bool, // flag for permanent deletion (bypass Recycle Bin)
     pub action_cursor: usize,   // cursor for action selection (0=Scan, 1=Clean, etc.)
@@ -566,31 +1345,117 @@ pub struct AppState {
     pub tick: u64,              // animation tick counter
     pub visible_height: usize,  // cached visible height for scrolling calculations
     pub confirm_snapshot: HashSet<usize>, // snapshot of selected_items when entering confirm screen
-    pub confirm_groups_cache: Vec<CategoryGroup>, // cached category groups for confirm screen (stable ordering)
-    pub search_mode: bool,                        // whether search mode is active
-    pub search_query: String,                     // current search query
+    pub confirm_back: Option<Screen>, // screen the confirm screen's cancel returns to, instead of Results
+    pub confirm_model: ConfirmModel, // category groups and rows of the confirm screen (stable ordering)
+    pub confirm_collapsed_tiers: HashSet<RiskTier>, // risk tiers collapsed on the confirm screen
+    pub confirm_new_items: HashSet<usize>, // confirm items that weren't there on the previous confirm visit
+    pub confirm_last_visit: HashSet<PathBuf>, // paths shown on the previous confirm visit
+    pub confirm_volumes: Vec<(PathBuf, u64)>, // (mount point, free bytes) read when entering the confirm screen
+    pub bin_overflow: Vec<crate::recycle_bin::BinOverflow>, // volumes whose Recycle Bin can't hold the confirm selection
+    pub free_estimate: Option<crate::size::FreeEstimate>, // space the confirm selection would really free (hard links, compression)
+    pub free_estimate_job: Option<FreeEstimateJob>, // computes free_estimate in the background
+    pub search_mode: bool,                          // whether search mode is active
+    pub search_query: String,                       // current search query
     pub search_navigated: bool, // true if user navigated while in search mode (space should toggle selection)
     pub dashboard_message: Option<String>, // temporary message for dashboard (e.g. warnings)
+    pub results_message: Option<String>, // temporary message for results (e.g. compression summary)
     pub last_scan_categories: Option<std::collections::HashSet<String>>, // categories enabled during last scan (for result reuse)
     pub first_scan_stats: Option<(usize, u64)>, // (total_files, total_storage) for first scan summary
+    pub scan_cancel: crate::cancel::CancellationToken, // cancels the running scan (Esc on Scanning)
+    pub standby_trim: crate::optimize::StandbyTrimMonitor, // auto-trims the standby list on the Status screen
+    pub leak_watch: crate::leak_watch::LeakWatch, // processes whose memory keeps growing, for the Status screen
+    pub temp_watch: crate::temperature::TempWatch, // sensor alerts during scans and cleanups
+    pub process_tree: crate::process_tree::TreeView, // tree mode of the Status screen's processes panel
+    pub resumable_scan: Option<crate::scan_cache::ScanCheckpoint>, // interrupted scan offered on the Dashboard
+    pub resume_scan: bool, // next scan continues from the checkpoint instead of starting over
+    pub saved_session: Option<crate::tui::session::SavedSession>, // review saved on the last exit, offered on the Dashboard
+    pub cleanup_left: usize, // items a stopped cleanup didn't reach (still selected)
+    pub cleanup_cancel: crate::cancel::CancellationToken, // stops the running cleanup mid-batch (Esc while paused)
+    pub restore_cancel: crate::cancel::CancellationToken, // stops a running Restore All (Esc)
+    pub protect_undo: bool, // U undoes the folder just protected, until the next key press
+    pub undo_steps: Vec<UndoStep>, // Ctrl+Z history of selection, category and exclusion changes
+    pub redo_steps: Vec<UndoStep>, // steps Ctrl+Y can apply again
+    pub group_by_owner: bool, // Results grouped by owning account instead of folder
+    pub recommendations: Vec<crate::recommend::Recommendation>, // "what's next" on the Success screen
+    pub shadow_storage: Vec<crate::shadow::ShadowStorage>, // shadow copy storage on the cleaned volumes (Success screen)
+    pub shadow_job: Option<std::sync::mpsc::Receiver<Vec<crate::shadow::ShadowStorage>>>, // reads shadow_storage in the background
+    pub shadow_resize_confirm: bool, // Success screen asks before capping shadow storage
+    pub compress_confirm: Vec<PathBuf>, // folders Results asks about before compressing them
+    pub compress_job: Option<CompressJob>, // compresses folders in the background
+    pub shadow_message: Option<String>, // outcome of the shadow storage resize
+    pub parked_screens: HashMap<SidebarView, Screen>, // screens of views switched away from in the sidebar
+    pub sidebar_width: u16, // columns the sidebar took in the last frame (0 = hidden), for mouse hit-testing
+    pub help: Option<crate::tui::widgets::help::HelpOverlay>, // `?` overlay over the current screen
+    pub toasts: crate::tui::widgets::toasts::ToastQueue, // transient info/warning/error messages
+    pub search_cache: Option<crate::scan_cache::ScanCache>, // index database of the Search screen, opened once per search
+    results_rows_cache: Mutex<Option<(u64, Arc<Vec<ResultsRow>>)>>, // last Results rows, keyed by results_rows_key()
 }
 
-/// A single result item for display in the table
+/// A folder just protected from Results or Disk Insights, kept for undo
 #[derive(Debug, Clone)]
-pub struct ResultItem {
-    pub path: PathBuf,
-    pub size_bytes: u64,
-    pub age_days: Option<u64>,
+pub struct ProtectedFolder {
+    pub folder: PathBuf,
+    /// Exclusion pattern added to the config
+    pub pattern: String,
+    /// Results taken out because they're in the folder: index, item and whether it was selected
+    pub removed: Vec<(usize, ResultItem, bool)>,
+}
+
+/// How many changes Ctrl+Z can go back
+const UNDO_LIMIT: usize = 100;
+
+/// A change Ctrl+Z can take back. Applying a step gives the step that
+/// reverses it, which is what Ctrl+Y applies.
+#[derive(Debug, Clone)]
+pub enum UndoStep {
+    /// Results selection before the change
+    Selection(HashSet<usize>),
+    /// Which Dashboard categories were enabled before the change
+    Categories(Vec<bool>),
+    /// Results excluded from the list: index, item and whether it was selected
+    Excluded(Vec<(usize, ResultItem, bool)>),
+    /// Results put back by undoing an exclude
+    Included(Vec<usize>),
+    /// A folder protected from Results or Disk Insights
+    Protected(ProtectedFolder),
+    /// A protected folder put back by undoing the protect
+    Unprotected(PathBuf),
+}
+
+/// "exclude (3 items)", for the undo and redo messages
+fn exclude_description(count: usize) -> String {
+    format!(
+        "exclude ({} item{})",
+        count,
+        if count == 1 { "" } else { "s" }
+    )
+}
+
+/// A single result item for display in the table
+#[derive(Debug, Clone)]
+pub struct ResultItem {
+    pub path: PathBuf,
+    pub size_bytes: u64,
+    pub age_days: Option<u64>,
     pub last_opened: Option<SystemTime>, // currently only populated for Installed Applications
     pub category: String,
     pub safe: bool, // true for cache/temp/trash, false for large/old/duplicates
     pub display_name: Option<String>, // Optional display name (used for applications)
+    pub selection: crate::auto_select::SelectionScore, // auto-selection score and the reasons for it
+    pub rebuild_command: Option<&'static str>, // how to regenerate a build artifact (e.g. `cargo build`)
+    pub owner: Option<String>, // owning account, recorded for --scope all-users scans
+    pub cloud: Option<crate::cloud::CloudProvider>, // sync client; deleting removes the item from the cloud too
+    pub sensitive: Option<&'static str>, // why the item may hold personal data (never pre-selected)
 }
 
 impl AppState {
     pub fn new() -> Self {
         // Load config to use its values (create default file if needed)
         let config = crate::config::Config::load_or_create();
+        crate::throttle::apply(&config.performance);
+        crate::cleaner::apply_safety_settings(&config.safety);
+        crate::history::checksums::set_enabled(config.history.record_checksums);
+        crate::i18n::apply(&config.ui.language);
 
         // Determine scan path from config or use defaults
         let scan_path = if let Some(ref config_path) = config.ui.default_scan_path {
@@ -602,7 +1467,9 @@ impl AppState {
                 .map(|p| {
                     let base = PathBuf::from(&p);
                     let onedrive_docs = base.join("OneDrive").join("Documents");
-                    if onedrive_docs.exists() {
+                    // Unless it is the redirected Documents folder, OneDrive is
+                    // excluded by default and scanning it would find nothing
+                    if onedrive_docs.exists() && !config.is_excluded(&onedrive_docs) {
                         onedrive_docs
                     } else if base.join("Documents").exists() {
                         base.join("Documents")
@@ -662,10 +1529,7 @@ impl AppState {
 
                 // Handle dynamic descriptions that depend on config values
                 let description = match cat_def.name {
-                    "Old Files" => format!(
-                        "Files not accessed in {} days",
-                        config.thresholds.min_age_days
-                    ),
+                    "Old Files" => old_files_description(&config),
                     "Large Files" => format!("Files over {}MB", config.thresholds.min_size_mb),
                     _ => cat_def.description.to_string(),
                 };
@@ -690,6 +1554,7 @@ impl AppState {
             scroll_offset: 0,
             all_items: Vec::new(),
             category_groups: Vec::new(),
+            results_generation: 0,
             path_to_indices: HashMap::new(),
             permanent_delete: false,
             action_cursor: 0,
@@ -698,13 +1563,91 @@ impl AppState {
             tick: 0,
             visible_height: 20, // Default visible height, will be updated during rendering
             confirm_snapshot: HashSet::new(), // Empty initially, set when entering confirm screen
-            confirm_groups_cache: Vec::new(), // Cached category groups for confirm screen
+            confirm_back: None,
+            confirm_model: ConfirmModel::default(),
+            confirm_collapsed_tiers: HashSet::new(),
+            confirm_new_items: HashSet::new(),
+            confirm_last_visit: HashSet::new(),
+            confirm_volumes: Vec::new(),
+            bin_overflow: Vec::new(),
+            free_estimate: None,
+            free_estimate_job: None,
             search_mode: false,
             search_query: String::new(),
             search_navigated: false,
             dashboard_message: None,
+            results_message: None,
             last_scan_categories: None, // No previous scan initially
             first_scan_stats: None,     // No first scan stats initially
+            scan_cancel: crate::cancel::CancellationToken::new(),
+            standby_trim: crate::optimize::StandbyTrimMonitor::new(),
+            leak_watch: crate::leak_watch::LeakWatch::new(),
+            temp_watch: crate::temperature::TempWatch::new(),
+            process_tree: crate::process_tree::TreeView::new(),
+            resumable_scan: None,
+            resume_scan: false,
+            saved_session: None,
+            cleanup_left: 0,
+            cleanup_cancel: crate::cancel::CancellationToken::new(),
+            restore_cancel: crate::cancel::CancellationToken::new(),
+            protect_undo: false,
+            undo_steps: Vec::new(),
+            redo_steps: Vec::new(),
+            group_by_owner: false,
+            recommendations: Vec::new(),
+            shadow_storage: Vec::new(),
+            shadow_job: None,
+            shadow_resize_confirm: false,
+            compress_confirm: Vec::new(),
+            compress_job: None,
+            shadow_message: None,
+            parked_screens: HashMap::new(),
+            sidebar_width: 0,
+            help: None,
+            toasts: crate::tui::widgets::toasts::ToastQueue::default(),
+            search_cache: None,
+            results_rows_cache: Mutex::new(None),
+        }
+    }
+
+    /// Look for a scan that was interrupted after getting some categories done (or partly done)
+    pub fn load_resumable_scan(&mut self) {
+        self.resumable_scan = None;
+        if !self.config.cache.enabled {
+            return;
+        }
+        if let Ok(cache) = crate::scan_cache::ScanCache::open() {
+            self.resumable_scan = cache
+                .load_checkpoint()
+                .ok()
+                .flatten()
+                .filter(|checkpoint| checkpoint.has_progress());
+        }
+    }
+
+    /// Set up the next scan to continue the interrupted one: same root, same categories
+    pub fn prepare_resume(&mut self) -> bool {
+        let Some(checkpoint) = self.resumable_scan.take() else {
+            return false;
+        };
+        if checkpoint.root != self.scan_path {
+            self.scan_results = None;
+            self.last_scan_categories = None;
+        }
+        self.scan_path = checkpoint.root;
+        for cat in &mut self.categories {
+            cat.enabled = checkpoint.categories.contains(&cat.name);
+        }
+        self.resume_scan = true;
+        true
+    }
+
+    /// Forget the interrupted scan
+    pub fn discard_resumable_scan(&mut self) {
+        if self.resumable_scan.take().is_some() {
+            if let Ok(mut cache) = crate::scan_cache::ScanCache::open() {
+                let _ = cache.clear_checkpoint();
+            }
         }
     }
 
@@ -715,6 +1658,11 @@ impl AppState {
 
     /// Apply relevant config values to the live app state (scan path + descriptions).
     pub fn apply_config_to_state(&mut self) {
+        crate::throttle::apply(&self.config.performance);
+        crate::cleaner::apply_safety_settings(&self.config.safety);
+        crate::history::checksums::set_enabled(self.config.history.record_checksums);
+        crate::i18n::apply(&self.config.ui.language);
+
         // Store old scan path to detect changes
         let old_scan_path = self.scan_path.clone();
 
@@ -727,7 +1675,9 @@ impl AppState {
                 .map(|p| {
                     let base = PathBuf::from(&p);
                     let onedrive_docs = base.join("OneDrive").join("Documents");
-                    if onedrive_docs.exists() {
+                    // Unless it is the redirected Documents folder, OneDrive is
+                    // excluded by default and scanning it would find nothing
+                    if onedrive_docs.exists() && !self.config.is_excluded(&onedrive_docs) {
                         onedrive_docs
                     } else if base.join("Documents").exists() {
                         base.join("Documents")
@@ -750,12 +1700,7 @@ impl AppState {
                 "Large Files" => {
                     cat.description = format!("Files over {}MB", self.config.thresholds.min_size_mb)
                 }
-                "Old Files" => {
-                    cat.description = format!(
-                        "Files not accessed in {} days",
-                        self.config.thresholds.min_age_days
-                    )
-                }
+                "Old Files" => cat.description = old_files_description(&self.config),
                 _ => {}
             }
         }
@@ -767,6 +1712,9 @@ impl AppState {
             self.all_items.clear();
             self.selected_items.clear();
             self.category_groups.clear();
+            self.results_generation = self.results_generation.wrapping_add(1);
+            self.undo_steps.clear();
+            self.redo_steps.clear();
 
             // Clone scan_path to avoid borrow checker issues with mut self later
             let scan_path = self.scan_path.clone();
@@ -814,6 +1762,41 @@ impl AppState {
                 })
             };
 
+            // --scope all-users: per-user categories are grouped by profile
+            let user_homes: Vec<(String, PathBuf)> = results
+                .users
+                .iter()
+                .map(|u| (u.user.clone(), u.home.clone()))
+                .collect();
+
+            // Listed duplicates never include the copy that is kept
+            let duplicate_paths: std::collections::HashSet<&PathBuf> =
+                results.duplicates.paths.iter().collect();
+
+            let old_basis = crate::categories::old::TimeBasis::of(&self.config);
+            let large_tiers = self.config.thresholds.large_file_tiers();
+            let media_detector = crate::big_media::Detector::new(&self.config);
+
+            // Recycle Bin entries by original path (the path is gone, the bin has the data)
+            let trash_entries: std::collections::HashMap<
+                &PathBuf,
+                &crate::categories::trash::TrashEntry,
+            > = results
+                .trash_entries
+                .iter()
+                .map(|entry| (&entry.original_path, entry))
+                .collect();
+
+            // Folder sizes come from the scan cache while a tree is unchanged (when caching is on)
+            let size_cache = self
+                .config
+                .cache
+                .enabled
+                .then(|| crate::scan_cache::ScanCache::open().ok())
+                .flatten()
+                .map(std::sync::Mutex::new);
+            let size_options = crate::size::DirSizeOptions::default();
+
             // Helper to add items from a category
             let mut add_category = |paths: &[PathBuf],
                                     size_bytes: u64,
@@ -823,6 +1806,11 @@ impl AppState {
                 let mut total_size = 0u64;
 
                 for path in paths {
+                    // Folders protected since the scan stay out when results are reused
+                    if self.config.is_excluded(path) {
+                        continue;
+                    }
+
                     // "Age" is mostly used for Old/Large files. For Installed Applications, we'll
                     // treat age as "last opened" (best-effort).
                     let last_opened = if category == "Installed Applications" {
@@ -835,6 +1823,16 @@ impl AppState {
                         last_opened
                             .and_then(|t| t.elapsed().ok())
                             .map(|d| d.as_secs() / 86400)
+                    } else if category == "Trash" {
+                        // Days since the item was deleted
+                        trash_entries.get(path).map(|entry| entry.age_days())
+                    } else if category == "Old Files" {
+                        // Same timestamp the scan measured
+                        std::fs::metadata(path)
+                            .ok()
+                            .and_then(|m| old_basis.time(&m))
+                            .and_then(|t| t.elapsed().ok())
+                            .map(|d| d.as_secs() / 86400)
                     } else {
                         std::fs::metadata(path)
                             .ok()
@@ -846,13 +1844,29 @@ impl AppState {
                     // NOTE: `metadata.len()` on directories is NOT the folder's contents size.
                     // For Installed Applications we already computed real directory sizes during
                     // the scan (from registry EstimatedSize or a directory walk), so use that.
+                    // Other directories go through the shared size engine, cached by tree mtime
+                    // when the scan cache is enabled.
                     let item_size = if category == "Installed Applications" {
                         crate::categories::applications::get_app_size(path)
                             .unwrap_or_else(|| size_bytes / paths.len().max(1) as u64)
+                    } else if let Some(entry) =
+                        trash_entries.get(path).filter(|_| category == "Trash")
+                    {
+                        entry.size_bytes
                     } else {
-                        std::fs::metadata(path)
+                        std::fs::symlink_metadata(path)
                             .ok()
-                            .map(|m| m.len())
+                            .map(|m| {
+                                if !m.is_dir() {
+                                    m.len() + crate::ads::stream_bytes(path)
+                                } else if let Some(cache) = size_cache.as_ref() {
+                                    crate::size::dir_size_cached(path, &size_options, cache, None)
+                                        .map(|totals| totals.size(&size_options))
+                                        .unwrap_or(0)
+                                } else {
+                                    crate::size::dir_size(path)
+                                }
+                            })
                             .unwrap_or_else(|| size_bytes / paths.len().max(1) as u64)
                     };
 
@@ -867,9 +1881,41 @@ impl AppState {
                                 crate::categories::applications::get_app_display_name(&canon_path)
                             })
                         })
+                    } else if category == "Device Backups" {
+                        // Backup folders are named by UDID; show device and date instead
+                        crate::categories::device_backups::display_name(path)
+                    } else if category == "Package Cache" {
+                        // Show which package manager a cache belongs to
+                        crate::categories::cache::display_name(path)
+                    } else {
+                        None
+                    };
+
+                    let project = if category == "Build Artifacts" {
+                        find_project_root(path)
                     } else {
                         None
                     };
+                    let active_project = project.as_ref().map(|(_, _, is_active)| *is_active);
+                    let rebuild_command = project.as_ref().and_then(|(project_path, _, _)| {
+                        crate::project::rebuild_command(project_path)
+                    });
+                    // Attribute findings to users when several profiles were scanned
+                    let owner = if user_homes.is_empty() {
+                        None
+                    } else {
+                        crate::users::file_owner(path)
+                    };
+                    let sensitive = crate::sensitive::reason_in(category, path);
+                    let selection =
+                        crate::auto_select::score(&crate::auto_select::SelectionFacts {
+                            safe,
+                            age_days,
+                            size_bytes: item_size,
+                            active_project,
+                            duplicate: duplicate_paths.contains(path),
+                            sensitive,
+                        });
 
                     self.all_items.push(ResultItem {
                         path: path.clone(),
@@ -879,6 +1925,11 @@ impl AppState {
                         category: category.to_string(),
                         safe,
                         display_name,
+                        selection,
+                        rebuild_command,
+                        owner,
+                        cloud: crate::cloud::provider_for(path),
+                        sensitive,
                     });
                 }
 
@@ -981,6 +2032,189 @@ impl AppState {
                     }
 
                     folder_groups
+                } else if category == "Large Files" {
+                    // Big media outside the media libraries first (copies of one
+                    // episode or film on their own), then one group per size tier
+                    let (media, rest): (Vec<usize>, Vec<usize>) =
+                        items.iter().copied().partition(|&idx| {
+                            self.all_items
+                                .get(idx)
+                                .is_some_and(|item| media_detector.is_big_media(&item.path))
+                        });
+                    let media_paths: Vec<&Path> = media
+                        .iter()
+                        .map(|&idx| self.all_items[idx].path.as_path())
+                        .collect();
+                    let copies: Vec<usize> = crate::big_media::duplicate_episodes(&media_paths)
+                        .into_iter()
+                        .flatten()
+                        .map(|i| media[i])
+                        .collect();
+                    let media: Vec<usize> = media
+                        .into_iter()
+                        .filter(|idx| !copies.contains(idx))
+                        .collect();
+
+                    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+                    if !copies.is_empty() {
+                        groups.push(("🎞️ Duplicate episodes".to_string(), copies));
+                    }
+                    if !media.is_empty() {
+                        groups.push(("🎬 Videos, disc images & installers".to_string(), media));
+                    }
+                    let mut tier_groups: Vec<(Option<u64>, Vec<usize>)> = Vec::new();
+                    for idx in rest {
+                        let Some(item) = self.all_items.get(idx) else {
+                            continue;
+                        };
+                        let tier =
+                            crate::categories::large::size_tier(item.size_bytes, &large_tiers);
+                        match tier_groups.iter_mut().find(|(t, _)| *t == tier) {
+                            Some((_, group)) => group.push(idx),
+                            None => tier_groups.push((tier, vec![idx])),
+                        }
+                    }
+                    // None (under every tier after a threshold change) sorts last
+                    tier_groups.sort_by(|a, b| b.0.cmp(&a.0));
+                    groups.extend(tier_groups.into_iter().map(|(tier, group_items)| {
+                        let folder_name = match tier {
+                            Some(mb) => format!("📦 {}", crate::categories::large::tier_label(mb)),
+                            None => "(root)".to_string(),
+                        };
+                        (folder_name, group_items)
+                    }));
+
+                    groups
+                        .into_iter()
+                        .map(|(folder_name, group_items)| {
+                            let total_size: u64 = group_items
+                                .iter()
+                                .filter_map(|&idx| self.all_items.get(idx))
+                                .map(|item| item.size_bytes)
+                                .sum();
+                            FolderGroup {
+                                folder_name,
+                                items: group_items,
+                                total_size,
+                                expanded: true,
+                            }
+                        })
+                        .collect()
+                } else if category == "Ignored Files (git)" {
+                    // One group per repository, biggest first
+                    let mut repo_groups: Vec<(Option<PathBuf>, Vec<usize>)> = Vec::new();
+                    for &idx in &items {
+                        let Some(item) = self.all_items.get(idx) else {
+                            continue;
+                        };
+                        let repo = item
+                            .path
+                            .parent()
+                            .and_then(crate::git::find_git_root_cached);
+                        match repo_groups.iter_mut().find(|(root, _)| *root == repo) {
+                            Some((_, group)) => group.push(idx),
+                            None => repo_groups.push((repo, vec![idx])),
+                        }
+                    }
+                    let mut folder_groups: Vec<FolderGroup> = repo_groups
+                        .into_iter()
+                        .map(|(repo, group_items)| {
+                            let total_size: u64 = group_items
+                                .iter()
+                                .filter_map(|&idx| self.all_items.get(idx))
+                                .map(|item| item.size_bytes)
+                                .sum();
+                            let folder_name = repo
+                                .as_deref()
+                                .and_then(Path::file_name)
+                                .map(|name| format!("📂 {}", name.to_string_lossy()))
+                                .unwrap_or_else(|| "(root)".to_string());
+                            FolderGroup {
+                                folder_name,
+                                items: group_items,
+                                total_size,
+                                expanded: true,
+                            }
+                        })
+                        .collect();
+                    folder_groups.sort_by(|a, b| b.total_size.cmp(&a.total_size));
+                    folder_groups
+                } else if !user_homes.is_empty()
+                    && matches!(category, "Package Cache" | "Temp Files" | "Old Downloads")
+                {
+                    // One group per user profile, biggest first
+                    let mut remaining = items.clone();
+                    let mut folder_groups: Vec<FolderGroup> = Vec::new();
+                    for (user, home) in &user_homes {
+                        let (user_items, rest): (Vec<usize>, Vec<usize>) =
+                            remaining.into_iter().partition(|&idx| {
+                                self.all_items
+                                    .get(idx)
+                                    .is_some_and(|item| item.path.starts_with(home))
+                            });
+                        remaining = rest;
+                        if user_items.is_empty() {
+                            continue;
+                        }
+                        let group_size: u64 = user_items
+                            .iter()
+                            .filter_map(|&idx| self.all_items.get(idx))
+                            .map(|item| item.size_bytes)
+                            .sum();
+                        folder_groups.push(FolderGroup {
+                            folder_name: format!("👤 {}", user),
+                            items: user_items,
+                            total_size: group_size,
+                            expanded: true,
+                        });
+                    }
+                    folder_groups.sort_by(|a, b| b.total_size.cmp(&a.total_size));
+
+                    // Anything outside the profile folders (e.g. a relocated %TEMP%)
+                    if !remaining.is_empty() {
+                        let remaining_size: u64 = remaining
+                            .iter()
+                            .filter_map(|&idx| self.all_items.get(idx))
+                            .map(|item| item.size_bytes)
+                            .sum();
+                        folder_groups.push(FolderGroup {
+                            folder_name: "(root)".to_string(),
+                            items: remaining,
+                            total_size: remaining_size,
+                            expanded: true,
+                        });
+                    }
+
+                    folder_groups
+                } else if let Some((source_name, icon)) = source_grouping(category) {
+                    // One group per launcher/tool, in scan order
+                    let mut source_groups: Vec<(&'static str, Vec<usize>)> = Vec::new();
+                    for &idx in &items {
+                        let Some(item) = self.all_items.get(idx) else {
+                            continue;
+                        };
+                        let source = source_name(&item.path).unwrap_or("Other");
+                        match source_groups.iter_mut().find(|(name, _)| *name == source) {
+                            Some((_, group)) => group.push(idx),
+                            None => source_groups.push((source, vec![idx])),
+                        }
+                    }
+                    source_groups
+                        .into_iter()
+                        .map(|(source, group_items)| {
+                            let total_size: u64 = group_items
+                                .iter()
+                                .filter_map(|&idx| self.all_items.get(idx))
+                                .map(|item| item.size_bytes)
+                                .sum();
+                            FolderGroup {
+                                folder_name: format!("{} {}", icon, source),
+                                items: group_items,
+                                total_size,
+                                expanded: true,
+                            }
+                        })
+                        .collect()
                 } else {
                     // For other categories, group by common parent directory
                     // Find the highest common parent and nest sub-folders under it
@@ -1440,6 +2674,102 @@ impl AppState {
                     false,
                 );
             }
+            if is_category_enabled("Thumbnail Cache") {
+                add_category(
+                    &results.thumbnails.paths,
+                    results.thumbnails.size_bytes,
+                    "Thumbnail Cache",
+                    false,
+                );
+            }
+            if is_category_enabled("Crash Dumps") {
+                add_category(
+                    &results.crash_dumps.paths,
+                    results.crash_dumps.size_bytes,
+                    "Crash Dumps",
+                    true,
+                );
+            }
+            if is_category_enabled("Log Files") {
+                add_category(
+                    &results.logs.paths,
+                    results.logs.size_bytes,
+                    "Log Files",
+                    false,
+                );
+            }
+            if is_category_enabled("Game Caches") {
+                add_category(
+                    &results.game_caches.paths,
+                    results.game_caches.size_bytes,
+                    "Game Caches",
+                    true,
+                );
+            }
+            if is_category_enabled("Toolchain Caches") {
+                add_category(
+                    &results.toolchain_caches.paths,
+                    results.toolchain_caches.size_bytes,
+                    "Toolchain Caches",
+                    false,
+                );
+            }
+            if is_category_enabled("Python Environments") {
+                add_category(
+                    &results.python_envs.paths,
+                    results.python_envs.size_bytes,
+                    "Python Environments",
+                    false,
+                );
+            }
+            if is_category_enabled("ML Models") {
+                add_category(
+                    &results.ml_models.paths,
+                    results.ml_models.size_bytes,
+                    "ML Models",
+                    false,
+                );
+            }
+            if is_category_enabled("Email Data") {
+                add_category(
+                    &results.email.paths,
+                    results.email.size_bytes,
+                    "Email Data",
+                    false,
+                );
+            }
+            if is_category_enabled("Creative App Caches") {
+                add_category(
+                    &results.creative_caches.paths,
+                    results.creative_caches.size_bytes,
+                    "Creative App Caches",
+                    false,
+                );
+            }
+            if is_category_enabled("Device Backups") {
+                add_category(
+                    &results.device_backups.paths,
+                    results.device_backups.size_bytes,
+                    "Device Backups",
+                    false,
+                );
+            }
+            if is_category_enabled("Ignored Files (git)") {
+                add_category(
+                    &results.git_ignored.paths,
+                    results.git_ignored.size_bytes,
+                    "Ignored Files (git)",
+                    false,
+                );
+            }
+            for plugin in &results.plugins {
+                add_category(
+                    &plugin.result.paths,
+                    plugin.result.size_bytes,
+                    &plugin.display_name(),
+                    plugin.category.safe,
+                );
+            }
 
             // Sort category groups for results screen:
             // First: Respect dashboard order (Quick Clean -> Developer Cleanup -> Space Hunters -> Advanced)
@@ -1477,6 +2807,10 @@ impl AppState {
                 }
             });
 
+            if self.group_by_owner {
+                self.apply_owner_grouping();
+            }
+
             // Build path_to_indices mapping for cross-category selection sync
             // This allows selecting a file in one category to also select it in other categories
             self.path_to_indices.clear();
@@ -1490,16 +2824,10 @@ impl AppState {
             // Clear all selections first
             self.selected_items.clear();
 
-            // Build a set of safe category names for quick lookup
-            let safe_categories: std::collections::HashSet<String> = CATEGORIES
-                .iter()
-                .filter(|cat| cat.safe)
-                .map(|cat| cat.name.to_string())
-                .collect();
-
-            // Auto-select items from safe categories only
+            // Auto-select the items the heuristic is confident about
+            let aggressiveness = self.config.ui.auto_select_aggressiveness;
             for (i, item) in self.all_items.iter().enumerate() {
-                if safe_categories.contains(&item.category) {
+                if item.selection.selected(aggressiveness) {
                     self.selected_items.insert(i);
                 }
             }
@@ -1509,9 +2837,62 @@ impl AppState {
         }
     }
 
-    /// Build a flattened list of rows for the Results screen.
+    /// Flattened list of rows for the Results screen.
     /// When there's only one category, skip the category header.
-    pub fn results_rows(&self) -> Vec<ResultsRow> {
+    ///
+    /// Building the rows walks every item (and the folder hierarchy), which is
+    /// too slow to do every frame on scans with 100k+ items, so the rows are
+    /// cached until the expansion state, grouping or search query changes.
+    pub fn results_rows(&self) -> Arc<Vec<ResultsRow>> {
+        self.cached_rows(false, Self::build_results_rows)
+    }
+
+    /// Grouped results, as shown on the Results screen
+    pub fn category_groups(&self) -> &[CategoryGroup] {
+        &self.category_groups
+    }
+
+    /// Mutable access to the grouped results; invalidates the cached Results rows
+    pub fn category_groups_mut(&mut self) -> &mut Vec<CategoryGroup> {
+        self.results_generation = self.results_generation.wrapping_add(1);
+        &mut self.category_groups
+    }
+
+    /// Everything the Results rows are built from: the query and the groups
+    /// (their expansion, folders and items), which only change through
+    /// [`Self::category_groups_mut`]
+    fn results_rows_key(&self, filtered: bool) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        filtered.hash(&mut hasher);
+        self.search_query.trim().hash(&mut hasher);
+        self.scan_path.hash(&mut hasher);
+        self.all_items.len().hash(&mut hasher);
+        self.results_generation.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// The cached rows if they are still current, otherwise `build`'s
+    fn cached_rows(
+        &self,
+        filtered: bool,
+        build: fn(&Self) -> Vec<ResultsRow>,
+    ) -> Arc<Vec<ResultsRow>> {
+        let key = self.results_rows_key(filtered);
+        let mut cache = self
+            .results_rows_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some((cached_key, rows)) = cache.as_ref() {
+            if *cached_key == key {
+                return Arc::clone(rows);
+            }
+        }
+        let rows = Arc::new(build(self));
+        *cache = Some((key, Arc::clone(&rows)));
+        rows
+    }
+
+    fn build_results_rows(&self) -> Vec<ResultsRow> {
         let mut rows = Vec::new();
         let skip_category_header = self.category_groups.len() == 1;
 
@@ -1675,7 +3056,9 @@ impl AppState {
     /// - type_filter is Some(FileType) if /type: syntax matches a file type category
     /// - extension_filter is Some(extension) if /type: syntax specifies an exact extension
     fn parse_search_query(&self) -> (Option<crate::utils::FileType>, Option<String>, String) {
-        let query = self.search_query.trim();
+        let (_, query) = split_owner_filter(self.search_query.trim());
+        let (_, query) = split_category_filter(&query);
+        let query = query.as_str();
         if query.is_empty() {
             return (None, None, String::new());
         }
@@ -1859,13 +3242,19 @@ impl AppState {
     /// Returns all rows if search_query is empty.
     /// Only shows category/folder headers if they contain matching items.
     /// Supports /type:{filetype} syntax for filtering by file type.
-    pub fn filtered_results_rows(&self) -> Vec<ResultsRow> {
-        let query = self.search_query.trim().to_lowercase();
-        if query.is_empty() {
+    pub fn filtered_results_rows(&self) -> Arc<Vec<ResultsRow>> {
+        if self.search_query.trim().is_empty() {
             return self.results_rows();
         }
+        self.cached_rows(true, Self::build_filtered_results_rows)
+    }
+
+    fn build_filtered_results_rows(&self) -> Vec<ResultsRow> {
+        let query = self.search_query.trim().to_lowercase();
 
         let (type_filter, extension_filter, text_query) = self.parse_search_query();
+        let (owner_filter, _) = split_owner_filter(&query);
+        let (category_filter, _) = split_category_filter(&query);
 
         // Clone extension filter for use in closure
         let extension_filter_clone = extension_filter.clone();
@@ -1876,6 +3265,13 @@ impl AppState {
         // Helper to check if an item matches the query
         let item_matches = |item_idx: usize| -> bool {
             if let Some(item) = self.all_items.get(item_idx) {
+                if !owner_matches(owner_filter.as_deref(), item) {
+                    return false;
+                }
+                if !category_matches(category_filter.as_deref(), item) {
+                    return false;
+                }
+
                 // Check extension filter first (exact match)
                 if let Some(ref filter_ext) = extension_filter_clone {
                     if let Some(item_ext) = item.path.extension().and_then(|e| e.to_str()) {
@@ -2135,16 +3531,42 @@ impl AppState {
         filtered
     }
 
+    /// Flattened rows of the Confirm screen, as of the last expand/collapse
+    pub fn confirm_rows(&self) -> Arc<Vec<ConfirmRow>> {
+        Arc::clone(&self.confirm_model.rows)
+    }
+
+    /// Rebuild the confirm rows after the groups' or tiers' expansion changed
+    pub fn refresh_confirm_rows(&mut self) {
+        self.confirm_model.rows = Arc::new(self.build_confirm_rows());
+    }
+
     /// Build a flattened list of rows for the Confirm screen.
     /// Now includes folder grouping like results_rows() for consistent behavior.
-    pub fn confirm_rows(&self) -> Vec<ConfirmRow> {
+    fn build_confirm_rows(&self) -> Vec<ConfirmRow> {
         let mut rows = Vec::new();
 
         // Get confirm category groups (already built and sorted)
         let confirm_groups = self.confirm_category_groups();
         let skip_category_header = confirm_groups.len() == 1;
+        let mut current_tier: Option<RiskTier> = None;
 
         for (cat_idx, group) in confirm_groups.iter().enumerate() {
+            // Groups are sorted by risk tier; each tier starts with its own header
+            let tier = self.risk_tier(&group.name, group.safe);
+            if current_tier != Some(tier) {
+                if !rows.is_empty() {
+                    rows.push(ConfirmRow::Spacer);
+                }
+                rows.push(ConfirmRow::TierHeader { tier });
+                current_tier = Some(tier);
+            } else if !skip_category_header && !self.confirm_collapsed_tiers.contains(&tier) {
+                rows.push(ConfirmRow::Spacer);
+            }
+            if self.confirm_collapsed_tiers.contains(&tier) {
+                continue;
+            }
+
             // Skip category header if there's only one category
             if !skip_category_header {
                 rows.push(ConfirmRow::CategoryHeader { cat_idx });
@@ -2258,41 +3680,282 @@ impl AppState {
                     }
                 }
             }
-
-            // Only add spacer if there are multiple categories
-            if !skip_category_header && cat_idx < confirm_groups.len() - 1 {
-                rows.push(ConfirmRow::Spacer);
-            }
         }
 
         rows
     }
 
-    /// Build category groups for the confirm screen from selected items.
-    /// Uses confirm_snapshot to show all items that were selected when entering confirm screen,
-    /// regardless of current selection state. Current selection state is used for checkbox display.
-    /// Returns cached groups if available for stable ordering.
-    pub fn confirm_category_groups(&self) -> Vec<CategoryGroup> {
-        // Return cached groups if available (ensures stable ordering across renders)
-        if !self.confirm_groups_cache.is_empty() {
-            return self.confirm_groups_cache.clone();
+    /// Risk tier of a category (display name) on the Confirm screen
+    pub fn risk_tier(&self, category: &str, safe: bool) -> RiskTier {
+        if category == "Installed Applications"
+            || category == "Trash"
+            || self.is_fast_delete(category)
+        {
+            RiskTier::HighRisk
+        } else if safe {
+            RiskTier::Safe
+        } else {
+            RiskTier::Review
         }
+    }
 
-        self.build_confirm_category_groups()
+    /// All confirm items of the categories in `tier`
+    pub fn confirm_tier_items(&self, tier: RiskTier) -> Vec<usize> {
+        self.confirm_category_groups()
+            .iter()
+            .filter(|group| self.risk_tier(&group.name, group.safe) == tier)
+            .flat_map(|group| {
+                if group.grouped_by_folder {
+                    group
+                        .folder_groups
+                        .iter()
+                        .flat_map(|fg| fg.items.iter().copied())
+                        .collect::<Vec<_>>()
+                } else {
+                    group.items.clone()
+                }
+            })
+            .collect()
     }
 
-    /// Build and cache category groups for the confirm screen.
-    /// Call this when entering the confirm screen to ensure stable ordering.
-    pub fn cache_confirm_groups(&mut self) {
-        self.confirm_groups_cache = self.build_confirm_category_groups();
+    /// Collapse or expand a risk tier on the Confirm screen
+    pub fn toggle_confirm_tier(&mut self, tier: RiskTier) {
+        if !self.confirm_collapsed_tiers.remove(&tier) {
+            self.confirm_collapsed_tiers.insert(tier);
+        }
+        self.refresh_confirm_rows();
     }
 
-    /// Clear the confirm groups cache (call when leaving confirm screen).
-    pub fn clear_confirm_cache(&mut self) {
-        self.confirm_groups_cache.clear();
+    /// Free space of every volume in the current selection, before and after cleaning
+    ///
+    /// Like the free-space estimate, installed applications and the Recycle
+    /// Bin are left out.
+    pub fn volume_projections(&self) -> Vec<VolumeProjection> {
+        let mut projections: Vec<VolumeProjection> = Vec::new();
+        for item in self
+            .selected_items
+            .iter()
+            .filter_map(|&index| self.all_items.get(index))
+            .filter(|item| item.category != "Trash" && item.category != "Installed Applications")
+        {
+            // The most specific mount point wins (mounted folders)
+            let Some((mount_point, free_bytes)) = self
+                .confirm_volumes
+                .iter()
+                .filter(|(mount, _)| item.path.starts_with(mount))
+                .max_by_key(|(mount, _)| mount.as_os_str().len())
+            else {
+                continue;
+            };
+            match projections
+                .iter_mut()
+                .find(|p| &p.mount_point == mount_point)
+            {
+                Some(projection) => projection.freed_bytes += item.size_bytes,
+                None => projections.push(VolumeProjection {
+                    mount_point: mount_point.clone(),
+                    free_bytes: *free_bytes,
+                    freed_bytes: item.size_bytes,
+                }),
+            }
+        }
+        projections.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+        projections
     }
 
-    /// Internal method to build category groups for confirm screen.
+    /// Category groups of the confirm screen.
+    /// Built from confirm_snapshot when entering the confirm screen, so they show all items
+    /// that were selected then, regardless of current selection state. Current selection
+    /// state is used for checkbox display.
+    pub fn confirm_category_groups(&self) -> &[CategoryGroup] {
+        &self.confirm_model.groups
+    }
+
+    /// Build the confirm screen's groups and rows.
+    /// Call this when entering the confirm screen to ensure stable ordering.
+    pub fn cache_confirm_groups(&mut self) {
+        // Cancel goes back to Results unless the caller sets confirm_back after this
+        self.confirm_back = None;
+        self.confirm_model.groups = self.build_confirm_category_groups();
+        self.refresh_confirm_rows();
+        self.mark_new_confirm_items();
+        self.confirm_volumes = sysinfo::Disks::new_with_refreshed_list()
+            .list()
+            .iter()
+            .map(|disk| (disk.mount_point().to_path_buf(), disk.available_space()))
+            .collect();
+        self.bin_overflow = self.check_bin_capacity();
+        self.start_free_estimate();
+    }
+
+    /// Remember what this confirm visit shows and flag what the previous one didn't
+    ///
+    /// Nothing is flagged on the first visit.
+    fn mark_new_confirm_items(&mut self) {
+        let items = if self.confirm_snapshot.is_empty() {
+            &self.selected_items
+        } else {
+            &self.confirm_snapshot
+        };
+        self.confirm_new_items = if self.confirm_last_visit.is_empty() {
+            HashSet::new()
+        } else {
+            items
+                .iter()
+                .copied()
+                .filter(|&index| {
+                    self.all_items
+                        .get(index)
+                        .is_some_and(|item| !self.confirm_last_visit.contains(&item.path))
+                })
+                .collect()
+        };
+        self.confirm_last_visit = items
+            .iter()
+            .filter_map(|&index| self.all_items.get(index))
+            .map(|item| item.path.clone())
+            .collect();
+    }
+
+    /// Clear the confirm groups cache (call when leaving confirm screen).
+    ///
+    /// A finished free-space estimate is kept for the Success screen.
+    pub fn clear_confirm_cache(&mut self) {
+        self.confirm_model = ConfirmModel::default();
+        self.bin_overflow.clear();
+        self.free_estimate_job = None;
+    }
+
+    /// Start estimating what the selection would really free
+    ///
+    /// Installed applications and the Recycle Bin aren't files wole deletes
+    /// itself, so they're left out.
+    fn start_free_estimate(&mut self) {
+        let paths: Vec<PathBuf> = self
+            .selected_items
+            .iter()
+            .filter_map(|&index| self.all_items.get(index))
+            .filter(|item| item.category != "Trash" && item.category != "Installed Applications")
+            .map(|item| item.path.clone())
+            .collect();
+        self.free_estimate = None;
+        self.free_estimate_job = (!paths.is_empty()).then(|| FreeEstimateJob::start(paths));
+    }
+
+    /// Pick up a finished free-space estimate (call from the event loop)
+    pub fn poll_free_estimate(&mut self) {
+        if let Some(estimate) = self
+            .free_estimate_job
+            .as_ref()
+            .and_then(|job| job.try_take())
+        {
+            self.free_estimate = Some(estimate);
+            self.free_estimate_job = None;
+        }
+    }
+
+    /// Read shadow copy storage of the volumes `paths` were on, in the background
+    pub fn start_shadow_check(&mut self, paths: Vec<PathBuf>) {
+        let (tx, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = tx.send(crate::shadow::storage_for(&paths));
+        });
+        self.shadow_storage.clear();
+        self.shadow_resize_confirm = false;
+        self.shadow_message = None;
+        self.shadow_job = Some(receiver);
+    }
+
+    /// Pick up finished shadow storage figures (call from the event loop)
+    pub fn poll_shadow_check(&mut self) {
+        if let Some(storage) = self.shadow_job.as_ref().and_then(|rx| rx.try_recv().ok()) {
+            self.shadow_storage = storage;
+            self.shadow_job = None;
+        }
+    }
+
+    /// Compress the folders the Results screen asked about, in the background
+    pub fn start_compress(&mut self) {
+        let paths = std::mem::take(&mut self.compress_confirm);
+        if paths.is_empty() || self.compress_job.is_some() {
+            return;
+        }
+        self.results_message = Some(format!("Compressing 0 of {} folder(s)...", paths.len()));
+        self.compress_job = Some(CompressJob::start(paths));
+    }
+
+    /// Report compression progress, and the savings once done (call from the event loop)
+    pub fn poll_compress(&mut self) {
+        let Some(job) = self.compress_job.as_mut() else {
+            return;
+        };
+        if !job.poll() {
+            self.results_message = Some(format!(
+                "Compressing {} of {} folder(s)...",
+                job.results.len() + 1,
+                job.total
+            ));
+            return;
+        }
+        let Some(job) = self.compress_job.take() else {
+            return;
+        };
+
+        let results = job.results;
+        let compressed: Vec<_> = results.iter().filter(|r| r.success).collect();
+        let failed = results.len() - compressed.len();
+        let before: u64 = compressed.iter().map(|r| r.before_bytes).sum();
+        let after: u64 = compressed.iter().map(|r| r.after_bytes).sum();
+
+        // Compressed folders stay on disk, so drop them from the delete selection
+        for result in &compressed {
+            if let Some(indices) = self.path_to_indices.get(&result.path) {
+                for idx in indices {
+                    self.selected_items.remove(idx);
+                }
+            }
+        }
+
+        let mut message = format!(
+            "Compressed {} folder(s): {} → {}",
+            compressed.len(),
+            bytesize::to_string(before, false),
+            bytesize::to_string(after, false)
+        );
+        if failed > 0 {
+            let first_error = results
+                .iter()
+                .find(|r| !r.success)
+                .map(|r| r.message.clone())
+                .unwrap_or_default();
+            message.push_str(&format!(" ({} failed: {})", failed, first_error));
+        }
+        self.results_message = Some(message);
+    }
+
+    /// Shadow storage worth capping, with the suggested maximum
+    pub fn shadow_resize_target(&self) -> Option<(&crate::shadow::ShadowStorage, &'static str)> {
+        self.shadow_storage
+            .iter()
+            .filter_map(|storage| storage.suggested_max().map(|max| (storage, max)))
+            .max_by_key(|(storage, _)| storage.used_bytes)
+    }
+
+    /// Volumes whose Recycle Bin is too small for the selected items
+    ///
+    /// Trash and uninstalled applications never go through the Recycle Bin.
+    fn check_bin_capacity(&self) -> Vec<crate::recycle_bin::BinOverflow> {
+        let items: Vec<(PathBuf, u64)> = self
+            .selected_items
+            .iter()
+            .filter_map(|&index| self.all_items.get(index))
+            .filter(|item| item.category != "Trash" && item.category != "Installed Applications")
+            .map(|item| (item.path.clone(), item.size_bytes))
+            .collect();
+        crate::recycle_bin::check_selection(&items)
+    }
+
+    /// Internal method to build category groups for confirm screen.
     fn build_confirm_category_groups(&self) -> Vec<CategoryGroup> {
         use std::collections::HashMap;
 
@@ -2740,6 +4403,8 @@ impl AppState {
                 size_cmp
             }
         });
+        // Safe first, high risk last; the sort is stable so the order above holds within a tier
+        groups.sort_by_key(|group| self.risk_tier(&group.name, group.safe));
         groups
     }
 
@@ -2793,59 +4458,53 @@ impl AppState {
     }
 
     /// Toggle expansion for a category in the confirm screen.
-    /// This updates the corresponding category_group's expansion state and the cache.
-    pub fn toggle_confirm_category(&mut self, category_name: &str) {
-        // Update the original category group
-        if let Some(group) = self
-            .category_groups
-            .iter_mut()
-            .find(|g| g.name == category_name)
-        {
-            group.expanded = !group.expanded;
-        }
+    /// This updates the confirm model and mirrors it to the category_group of the same name.
+    pub fn toggle_confirm_category(&mut self, cat_idx: usize) {
+        let Some(group) = self.confirm_model.groups.get_mut(cat_idx) else {
+            return;
+        };
+        group.expanded = !group.expanded;
+        let (name, expanded) = (group.name.clone(), group.expanded);
 
-        // Update the cached groups to preserve ordering
-        if let Some(cached_group) = self
-            .confirm_groups_cache
+        if let Some(original) = self
+            .category_groups_mut()
             .iter_mut()
-            .find(|g| g.name == category_name)
+            .find(|g| g.name == name)
         {
-            cached_group.expanded = !cached_group.expanded;
+            original.expanded = expanded;
         }
+        self.refresh_confirm_rows();
     }
 
     /// Toggle expansion for a folder in the confirm screen.
-    /// This updates the corresponding folder_group's expansion state and the cache.
-    pub fn toggle_confirm_folder(&mut self, category_name: &str, folder_name: &str) {
-        // Update the original category group
-        if let Some(group) = self
-            .category_groups
-            .iter_mut()
-            .find(|g| g.name == category_name)
-        {
-            if let Some(folder) = group
-                .folder_groups
-                .iter_mut()
-                .find(|f| f.folder_name == folder_name)
-            {
-                folder.expanded = !folder.expanded;
-            }
-        }
-
-        // Update the cached groups to preserve ordering
-        if let Some(cached_group) = self
-            .confirm_groups_cache
+    /// This updates the confirm model and mirrors it to the matching folder_group.
+    pub fn toggle_confirm_folder(&mut self, cat_idx: usize, folder_idx: usize) {
+        let Some(group) = self.confirm_model.groups.get_mut(cat_idx) else {
+            return;
+        };
+        let Some(folder) = group.folder_groups.get_mut(folder_idx) else {
+            return;
+        };
+        folder.expanded = !folder.expanded;
+        let (name, folder_name, expanded) = (
+            group.name.clone(),
+            folder.folder_name.clone(),
+            folder.expanded,
+        );
+
+        if let Some(original) = self
+            .category_groups_mut()
             .iter_mut()
-            .find(|g| g.name == category_name)
+            .find(|g| g.name == name)
+            .and_then(|g| {
+                g.folder_groups
+                    .iter_mut()
+                    .find(|f| f.folder_name == folder_name)
+            })
         {
-            if let Some(cached_folder) = cached_group
-                .folder_groups
-                .iter_mut()
-                .find(|f| f.folder_name == folder_name)
-            {
-                cached_folder.expanded = !cached_folder.expanded;
-            }
+            original.expanded = expanded;
         }
+        self.refresh_confirm_rows();
     }
 
     /// Get all item indices for a given category name (from all_items, not just selected)
@@ -2919,12 +4578,21 @@ impl AppState {
         // If search query is active, filter items to only include matches
         if !self.search_query.trim().is_empty() {
             let (type_filter, extension_filter, text_query) = self.parse_search_query();
+            let (owner_filter, _) = split_owner_filter(self.search_query.trim());
+            let (category_filter, _) = split_category_filter(self.search_query.trim());
             let extension_filter_clone = extension_filter.clone();
 
             all_items
                 .into_iter()
                 .filter(|&item_idx| {
                     if let Some(item) = self.all_items.get(item_idx) {
+                        if !owner_matches(owner_filter.as_deref(), item) {
+                            return false;
+                        }
+                        if !category_matches(category_filter.as_deref(), item) {
+                            return false;
+                        }
+
                         // Check extension filter first (exact match)
                         if let Some(ref filter_ext) = extension_filter_clone {
                             if let Some(item_ext) = item.path.extension().and_then(|e| e.to_str()) {
@@ -3001,12 +4669,21 @@ impl AppState {
         // If search query is active, filter items to only include matches
         if !self.search_query.trim().is_empty() {
             let (type_filter, extension_filter, text_query) = self.parse_search_query();
+            let (owner_filter, _) = split_owner_filter(self.search_query.trim());
+            let (category_filter, _) = split_category_filter(self.search_query.trim());
             let extension_filter_clone = extension_filter.clone();
 
             all_items
                 .into_iter()
                 .filter(|&item_idx| {
                     if let Some(item) = self.all_items.get(item_idx) {
+                        if !owner_matches(owner_filter.as_deref(), item) {
+                            return false;
+                        }
+                        if !category_matches(category_filter.as_deref(), item) {
+                            return false;
+                        }
+
                         // Check extension filter first (exact match)
                         if let Some(ref filter_ext) = extension_filter_clone {
                             if let Some(item_ext) = item.path.extension().and_then(|e| e.to_str()) {
@@ -3056,7 +4733,7 @@ impl AppState {
     pub fn rebuild_groups_from_all_items(&mut self) {
         use std::collections::HashMap;
 
-        self.category_groups.clear();
+        self.category_groups_mut().clear();
 
         // Clone scan_path here too
         let scan_path = self.scan_path.clone();
@@ -3459,6 +5136,10 @@ impl AppState {
             }
         });
 
+        if self.group_by_owner {
+            self.apply_owner_grouping();
+        }
+
         // Rebuild path_to_indices mapping for cross-category selection sync
         self.path_to_indices.clear();
         for (idx, item) in self.all_items.iter().enumerate() {
@@ -3469,6 +5150,435 @@ impl AppState {
         }
     }
 
+    /// Whether folders of this category (display name) are fast deleted:
+    /// renamed aside and removed permanently in the background (never while
+    /// the policy locks permanent deletes; they go to the Recycle Bin then)
+    pub fn is_fast_delete(&self, category: &str) -> bool {
+        crate::policy::permanent_allowed(true)
+            && crate::api::Category::ALL
+                .iter()
+                .find(|c| c.name() == category)
+                .is_some_and(|c| self.config.categories.fast_delete(c.id()))
+    }
+
+    /// Application, browser and temp cache results grouped by owning app
+    /// (item ids are indices into `all_items`)
+    pub fn app_cache_usage(&self) -> Vec<crate::cache_owners::AppUsage> {
+        crate::cache_owners::by_app(
+            self.all_items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| {
+                    crate::cache_owners::CATEGORIES.contains(&item.category.as_str())
+                })
+                .map(|(i, item)| (i, item.path.as_path(), item.size_bytes)),
+        )
+    }
+
+    /// Items whose category and path fuzzy-match `query`, best match first
+    /// (biggest first among equal matches, and for an empty query)
+    pub fn find_items(&self, query: &str) -> Vec<usize> {
+        let mut scored: Vec<(i32, usize)> = self
+            .all_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let haystack = format!(
+                    "{} {} {}",
+                    item.category,
+                    item.display_name.as_deref().unwrap_or(""),
+                    item.path.display()
+                );
+                crate::tui::widgets::help::fuzzy_score(query, &haystack).map(|score| (score, i))
+            })
+            .collect();
+        scored.sort_by(|a, b| {
+            b.0.cmp(&a.0).then(
+                self.all_items[b.1]
+                    .size_bytes
+                    .cmp(&self.all_items[a.1].size_bytes),
+            )
+        });
+        scored.into_iter().map(|(_, i)| i).collect()
+    }
+
+    /// Open the fuzzy finder over the current results
+    pub fn open_finder(&mut self) {
+        self.screen = Screen::Finder {
+            query: String::new(),
+            matches: self.find_items(""),
+            cursor: 0,
+            marked: HashSet::new(),
+        };
+    }
+
+    /// Open the saved searches list from Results
+    pub fn open_saved_views(&mut self) {
+        self.search_mode = false;
+        self.screen = Screen::SavedViews {
+            cursor: 0,
+            naming: None,
+            message: None,
+        };
+    }
+
+    /// Show Results filtered by the saved view at `index`
+    pub fn apply_saved_view(&mut self, index: usize) {
+        let Some(view) = self.config.ui.saved_views.get(index) else {
+            return;
+        };
+        self.search_query = view.search();
+        self.search_mode = false;
+        self.cursor = 0;
+        self.scroll_offset = 0;
+        self.screen = Screen::Results;
+    }
+
+    /// Save the current Results search as `name`, replacing a view of that name
+    pub fn save_current_view(&mut self, name: &str) -> anyhow::Result<()> {
+        let view = crate::config::SavedView::from_search(name, &self.search_query);
+        self.config.ui.save_view(view);
+        self.config.save()
+    }
+
+    /// Delete the saved view at `index`
+    pub fn delete_saved_view(&mut self, index: usize) -> anyhow::Result<()> {
+        if index < self.config.ui.saved_views.len() {
+            self.config.ui.saved_views.remove(index);
+            self.config.save()?;
+        }
+        Ok(())
+    }
+
+    /// Open the select-by-rule dialog for the category under the Results cursor
+    pub fn open_select_rule(&mut self) {
+        let group = match self.results_rows().get(self.cursor) {
+            Some(ResultsRow::CategoryHeader { group_idx })
+            | Some(ResultsRow::FolderHeader { group_idx, .. }) => Some(*group_idx),
+            Some(ResultsRow::Item { item_idx, .. }) => {
+                self.category_groups.iter().position(|group| {
+                    group.items.contains(item_idx)
+                        || group
+                            .folder_groups
+                            .iter()
+                            .any(|folder| folder.items.contains(item_idx))
+                })
+            }
+            _ => None,
+        };
+        self.search_mode = false;
+        self.screen = Screen::SelectRule {
+            fields: Default::default(),
+            cursor: 0,
+            group,
+            all: group.is_none(),
+            deselect: false,
+            message: None,
+        };
+    }
+
+    /// Select (or deselect) the results matching `selector` in category
+    /// group `group`, or in every group when None; items hidden by the
+    /// Results search are left alone. Returns how many items changed.
+    pub fn select_by_rule(
+        &mut self,
+        selector: &crate::select::Selector,
+        group: Option<usize>,
+        select: bool,
+    ) -> usize {
+        let groups: Vec<usize> = match group {
+            Some(group) => vec![group],
+            None => (0..self.category_groups.len()).collect(),
+        };
+        let item_indices: Vec<usize> = groups
+            .into_iter()
+            .flat_map(|group| self.category_item_indices(group))
+            .collect();
+        let mut changed = HashSet::new();
+        for item_idx in item_indices {
+            let Some(item) = self.all_items.get(item_idx) else {
+                continue;
+            };
+            let matches = selector.matches(&crate::select::Item {
+                category: &item.category,
+                path: &item.path,
+                size: item.size_bytes,
+                age: item
+                    .age_days
+                    .map(|days| std::time::Duration::from_secs(days * 24 * 60 * 60)),
+            });
+            if !matches {
+                continue;
+            }
+            // The same file in other categories follows, as with Space
+            let related = self
+                .path_to_indices
+                .get(&item.path)
+                .cloned()
+                .unwrap_or_else(|| vec![item_idx]);
+            for idx in related {
+                let done = if select {
+                    self.selected_items.insert(idx)
+                } else {
+                    self.selected_items.remove(&idx)
+                };
+                if done {
+                    changed.insert(idx);
+                }
+            }
+        }
+        changed.len()
+    }
+
+    /// Chart the Disk Insights folder being viewed; the walk starts in the main loop
+    pub fn open_breakdown(&mut self) {
+        let Screen::DiskInsights { current_path, .. } = &self.screen else {
+            return;
+        };
+        let root = current_path.clone();
+        let back = std::mem::replace(&mut self.screen, Screen::Dashboard);
+        self.screen = Screen::Breakdown {
+            root,
+            breakdown: None,
+            tab: 0,
+            job: None,
+            message: None,
+            back: Box::new(back),
+        };
+    }
+
+    /// Send the paths marked in Disk Insights to the confirm screen
+    ///
+    /// Paths that aren't already results become "Disk Insights" items; a
+    /// marked path inside another marked folder is left to its folder.
+    /// Cancelling returns to Disk Insights. Returns how many items went.
+    pub fn clean_insights_selection(&mut self) -> usize {
+        let Screen::DiskInsights {
+            insights,
+            selected_paths,
+            ..
+        } = &self.screen
+        else {
+            return 0;
+        };
+        let mut marked: Vec<(PathBuf, u64)> = selected_paths
+            .iter()
+            .filter(|path| {
+                !path
+                    .ancestors()
+                    .skip(1)
+                    .any(|ancestor| selected_paths.contains(ancestor))
+            })
+            .filter_map(|path| insights_size(&insights.root, path).map(|size| (path.clone(), size)))
+            .collect();
+        if marked.is_empty() {
+            return 0;
+        }
+        marked.sort();
+        self.confirm_paths(marked, INSIGHTS_CATEGORY)
+    }
+
+    /// Send a file search hit to the confirm screen; cancelling returns to Search
+    pub fn clean_search_result(&mut self, path: PathBuf, size_bytes: u64) -> usize {
+        if !matches!(self.screen, Screen::Search { .. }) {
+            return 0;
+        }
+        self.confirm_paths(vec![(path, size_bytes)], SEARCH_CATEGORY)
+    }
+
+    /// Select `paths` on the confirm screen, adding the ones that aren't results under `category`
+    fn confirm_paths(&mut self, paths: Vec<(PathBuf, u64)>, category: &str) -> usize {
+        let mut selected = HashSet::new();
+        for (path, size_bytes) in paths {
+            if let Some(index) = self.all_items.iter().position(|item| item.path == path) {
+                selected.insert(index);
+                continue;
+            }
+            self.all_items.push(ResultItem {
+                cloud: crate::cloud::provider_for(&path),
+                path: path.clone(),
+                size_bytes,
+                age_days: None,
+                last_opened: None,
+                category: category.to_string(),
+                safe: false,
+                display_name: None,
+                selection: crate::auto_select::SelectionScore::default(),
+                rebuild_command: None,
+                owner: None,
+                sensitive: None,
+            });
+            let index = self.all_items.len() - 1;
+            self.path_to_indices.entry(path).or_default().push(index);
+            selected.insert(index);
+        }
+
+        let count = selected.len();
+        self.selected_items = selected;
+        self.confirm_snapshot = self.selected_items.clone();
+        self.cache_confirm_groups();
+        self.cursor = 0;
+        self.scroll_offset = 0;
+        let back = std::mem::replace(&mut self.screen, Screen::Confirm { permanent: false });
+        self.confirm_back = Some(back);
+        count
+    }
+
+    /// Leave the confirm screen without cleaning, dropping items Disk Insights or search added
+    pub fn cancel_confirm(&mut self) {
+        self.confirm_snapshot.clear();
+        self.clear_confirm_cache();
+        match self.confirm_back.take() {
+            Some(back) => {
+                self.selected_items.clear();
+                self.all_items
+                    .retain(|item| !is_confirm_only(&item.category));
+                if self
+                    .category_groups
+                    .iter()
+                    .any(|g| is_confirm_only(&g.name))
+                {
+                    // Left over from a stopped cleanup, so Results shows them
+                    self.rebuild_groups_from_all_items();
+                } else {
+                    // Appended after the scan results, so no other index moved
+                    let len = self.all_items.len();
+                    self.path_to_indices.retain(|_, indices| {
+                        indices.retain(|&i| i < len);
+                        !indices.is_empty()
+                    });
+                }
+                self.screen = back;
+            }
+            None => self.screen = Screen::Results,
+        }
+    }
+
+    /// Start or pick up the Breakdown screen's walk
+    pub fn poll_breakdown(&mut self) {
+        let Screen::Breakdown {
+            root,
+            breakdown,
+            job,
+            message,
+            ..
+        } = &mut self.screen
+        else {
+            return;
+        };
+        if breakdown.is_some() {
+            return;
+        }
+        match job {
+            None => {
+                let root = root.clone();
+                let config = self.config.clone();
+                *job = Some(
+                    crate::scanner::Engine::global()
+                        .submit(crate::cancel::CancellationToken::new(), move |cancel, _| {
+                            crate::analyzer::breakdown(&root, &config, Some(cancel))
+                        }),
+                );
+            }
+            Some(running) => match running.try_result() {
+                Ok(Ok(result)) => {
+                    *breakdown = Some(result);
+                    *job = None;
+                }
+                Ok(Err(e)) => {
+                    // An empty breakdown stops the walk from starting over
+                    *message = Some(format!("Breakdown failed: {}", e));
+                    *breakdown = Some(crate::analyzer::Breakdown::empty(root));
+                    *job = None;
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => {}
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    *message = Some("Breakdown stopped".to_string());
+                    *breakdown = Some(crate::analyzer::Breakdown::empty(root));
+                    *job = None;
+                }
+            },
+        }
+    }
+
+    /// Watch writes to `volume`; Esc comes back to the current screen
+    pub fn open_disk_writes(&mut self, volume: &str) {
+        let back = std::mem::replace(&mut self.screen, Screen::Dashboard);
+        self.screen = Screen::DiskWrites {
+            volume: volume.to_string(),
+            monitor: None,
+            by_process: false,
+            message: None,
+            back: Box::new(back),
+        };
+        self.restart_disk_writes();
+    }
+
+    /// (Re)start the Disk Writes screen's monitor
+    pub fn restart_disk_writes(&mut self) {
+        let Screen::DiskWrites {
+            volume,
+            monitor,
+            message,
+            ..
+        } = &mut self.screen
+        else {
+            return;
+        };
+        // Only one session can hold the name: stop the old one first
+        *monitor = None;
+        match crate::disk_writes::WriteMonitor::start(volume) {
+            Ok(started) => {
+                *monitor = Some(started);
+                *message = None;
+            }
+            Err(e) => *message = Some(e.to_string()),
+        }
+    }
+
+    /// Check network settings for leftovers; Esc comes back to the current screen
+    pub fn open_net_check(&mut self) {
+        let back = std::mem::replace(&mut self.screen, Screen::Dashboard);
+        self.screen = Screen::NetCheck {
+            issues: crate::net_hygiene::check(),
+            cursor: 0,
+            message: None,
+            back: Box::new(back),
+        };
+    }
+
+    /// Revert the Network Check issue under the cursor and check again
+    pub fn revert_net_issue(&mut self) {
+        let Screen::NetCheck { issues, cursor, .. } = &self.screen else {
+            return;
+        };
+        let Some(issue) = issues.get(*cursor).cloned() else {
+            return;
+        };
+        let result = crate::net_hygiene::revert(&issue);
+        let fresh = crate::net_hygiene::check();
+        let Screen::NetCheck {
+            issues,
+            cursor,
+            message,
+            ..
+        } = &mut self.screen
+        else {
+            return;
+        };
+        *issues = fresh;
+        *cursor = (*cursor).min(issues.len().saturating_sub(1));
+        *message = Some(match result {
+            Ok(backup) => format!(
+                "Reverted {} {} - backup: {}",
+                issue.label(),
+                issue.summary(),
+                backup.display()
+            ),
+            Err(e) => e.to_string(),
+        });
+    }
+
     /// Get total size of selected items
     pub fn selected_size(&self) -> u64 {
         self.selected_items
@@ -3483,6 +5593,341 @@ impl AppState {
         self.selected_items.len()
     }
 
+    /// Scan `path` from now on; with `persist`, also save it as `ui.default_scan_path`
+    pub fn set_scan_path(&mut self, path: PathBuf, persist: bool) -> anyhow::Result<()> {
+        // Results from another root can't be reused
+        if path != self.scan_path {
+            self.scan_results = None;
+            self.last_scan_categories = None;
+        }
+        self.scan_path = path;
+
+        if persist {
+            self.config.ui.default_scan_path = Some(self.scan_path.display().to_string());
+            self.config.save()?;
+        }
+        Ok(())
+    }
+
+    /// Pin or unpin `dir` for Disk Insights and save the config. Returns whether it is pinned now.
+    pub fn toggle_pin(&mut self, dir: &Path) -> anyhow::Result<bool> {
+        let pinned = self.config.ui.toggle_pin(dir);
+        self.config.save()?;
+        Ok(pinned)
+    }
+
+    /// Quick-jump labels for the pinned folders, e.g. "1 Downloads  2 Media"
+    pub fn pinned_shortcuts(&self) -> String {
+        self.config
+            .ui
+            .pinned_paths()
+            .iter()
+            .take(9)
+            .enumerate()
+            .map(|(i, dir)| {
+                let name = dir
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| dir.display().to_string());
+                format!("{} {}", i + 1, name)
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    }
+
+    /// Never clean `folder` again: add it to the exclusions (saved to the
+    /// config) and take the results inside it out of the results and selection
+    pub fn protect_folder(&mut self, folder: &Path) -> anyhow::Result<()> {
+        let protected = self.take_folder(folder);
+        self.record_undo(UndoStep::Protected(protected));
+        self.protect_undo = true;
+        self.config.save()
+    }
+
+    /// Add the exclusion for `folder` and take out the results inside it
+    fn take_folder(&mut self, folder: &Path) -> ProtectedFolder {
+        let pattern = crate::config::folder_exclusion_pattern(folder);
+        self.config.exclusions.add(pattern.clone());
+        let removed = self.take_items(|_, item| item.path.starts_with(folder));
+        self.rebuild_groups_keeping_expansion();
+        ProtectedFolder {
+            folder: folder.to_path_buf(),
+            pattern,
+            removed,
+        }
+    }
+
+    /// Take results out of the list (Exclude in Preview); Ctrl+Z puts them back
+    pub fn exclude_items(&mut self, indices: &[usize]) {
+        let removed = self.take_items(|index, _| indices.contains(&index));
+        if removed.is_empty() {
+            return;
+        }
+        self.rebuild_groups_keeping_expansion();
+        self.record_undo(UndoStep::Excluded(removed));
+    }
+
+    /// Remove the results `remove` picks, keeping each one's index and
+    /// whether it was selected so [`AppState::restore_items`] can put it back
+    fn take_items(
+        &mut self,
+        remove: impl Fn(usize, &ResultItem) -> bool,
+    ) -> Vec<(usize, ResultItem, bool)> {
+        let mut removed = Vec::new();
+        let mut kept = Vec::new();
+        let mut selected = HashSet::new();
+        for (index, item) in std::mem::take(&mut self.all_items).into_iter().enumerate() {
+            let was_selected = self.selected_items.contains(&index);
+            if remove(index, &item) {
+                removed.push((index, item, was_selected));
+            } else {
+                if was_selected {
+                    selected.insert(kept.len());
+                }
+                kept.push(item);
+            }
+        }
+        self.all_items = kept;
+        self.selected_items = selected;
+        removed
+    }
+
+    /// Put results taken by [`AppState::take_items`] back at their old
+    /// indices; returns those indices
+    fn restore_items(&mut self, removed: Vec<(usize, ResultItem, bool)>) -> Vec<usize> {
+        let mut removed = removed.into_iter().peekable();
+        let mut restored = Vec::new();
+        let mut items = Vec::new();
+        let mut selected = HashSet::new();
+        for (index, item) in std::mem::take(&mut self.all_items).into_iter().enumerate() {
+            while let Some((_, old, was_selected)) =
+                removed.next_if(|(old_index, ..)| *old_index <= items.len())
+            {
+                if was_selected {
+                    selected.insert(items.len());
+                }
+                restored.push(items.len());
+                items.push(old);
+            }
+            if self.selected_items.contains(&index) {
+                selected.insert(items.len());
+            }
+            items.push(item);
+        }
+        // The ones that were at the end
+        for (_, old, was_selected) in removed {
+            if was_selected {
+                selected.insert(items.len());
+            }
+            restored.push(items.len());
+            items.push(old);
+        }
+        self.all_items = items;
+        self.selected_items = selected;
+        restored
+    }
+
+    /// Remember a change for Ctrl+Z; a new change drops what Ctrl+Y could redo
+    pub fn record_undo(&mut self, step: UndoStep) {
+        self.redo_steps.clear();
+        self.undo_steps.push(step);
+        if self.undo_steps.len() > UNDO_LIMIT {
+            self.undo_steps.remove(0);
+        }
+    }
+
+    /// Take back the last change; returns what was undone
+    pub fn undo(&mut self) -> Option<String> {
+        let step = self.undo_steps.pop()?;
+        let (reverse, what) = self.apply_undo_step(step);
+        self.redo_steps.push(reverse);
+        Some(format!("Undid {}", what))
+    }
+
+    /// Apply the last undone change again; returns what was redone
+    pub fn redo(&mut self) -> Option<String> {
+        let step = self.redo_steps.pop()?;
+        let (reverse, what) = self.apply_undo_step(step);
+        self.undo_steps.push(reverse);
+        Some(format!("Redid {}", what))
+    }
+
+    /// Apply `step`; returns the step that reverses it and what it changed
+    fn apply_undo_step(&mut self, step: UndoStep) -> (UndoStep, String) {
+        match step {
+            UndoStep::Selection(selected) => {
+                let before = std::mem::replace(&mut self.selected_items, selected);
+                (UndoStep::Selection(before), "selection change".to_string())
+            }
+            UndoStep::Categories(enabled) => {
+                let before = self.categories.iter().map(|cat| cat.enabled).collect();
+                for (cat, enabled) in self.categories.iter_mut().zip(enabled) {
+                    cat.enabled = enabled;
+                }
+                self.sync_categories_to_config();
+                (UndoStep::Categories(before), "category change".to_string())
+            }
+            UndoStep::Excluded(removed) => {
+                let what = exclude_description(removed.len());
+                let restored = self.restore_items(removed);
+                self.rebuild_groups_keeping_expansion();
+                (UndoStep::Included(restored), what)
+            }
+            UndoStep::Included(indices) => {
+                let indices: HashSet<usize> = indices.into_iter().collect();
+                let removed = self.take_items(|index, _| indices.contains(&index));
+                self.rebuild_groups_keeping_expansion();
+                let what = exclude_description(removed.len());
+                (UndoStep::Excluded(removed), what)
+            }
+            UndoStep::Protected(protected) => {
+                self.config.exclusions.remove(&protected.pattern);
+                self.restore_items(protected.removed);
+                self.rebuild_groups_keeping_expansion();
+                self.save_exclusions();
+                let what = format!("protect {}", protected.folder.display());
+                (UndoStep::Unprotected(protected.folder), what)
+            }
+            UndoStep::Unprotected(folder) => {
+                let protected = self.take_folder(&folder);
+                self.save_exclusions();
+                let what = format!("protect {}", folder.display());
+                (UndoStep::Protected(protected), what)
+            }
+        }
+    }
+
+    /// Save the config after undo or redo changed the exclusions
+    fn save_exclusions(&mut self) {
+        if let Err(e) = self.config.save() {
+            self.toasts
+                .warn(format!("Could not save exclusions: {}", e));
+        }
+    }
+
+    /// Whether any result has a recorded owner (only `--scope all-users` scans record them)
+    pub fn owners_known(&self) -> bool {
+        self.all_items.iter().any(|item| item.owner.is_some())
+    }
+
+    /// Switch Results between folder and owner grouping
+    pub fn toggle_group_by_owner(&mut self) {
+        self.group_by_owner = !self.group_by_owner;
+        self.rebuild_groups_keeping_expansion();
+        self.cursor = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Replace each category's folder groups with one group per owner, biggest first
+    fn apply_owner_grouping(&mut self) {
+        self.results_generation = self.results_generation.wrapping_add(1);
+        for group in &mut self.category_groups {
+            let items: Vec<usize> = if group.grouped_by_folder {
+                let mut items: Vec<usize> = group
+                    .folder_groups
+                    .iter()
+                    .flat_map(|folder| folder.items.iter().copied())
+                    .collect();
+                items.sort_unstable();
+                items.dedup();
+                items
+            } else {
+                group.items.clone()
+            };
+
+            let mut owners: Vec<(Option<&str>, Vec<usize>)> = Vec::new();
+            for idx in items {
+                let owner = self
+                    .all_items
+                    .get(idx)
+                    .and_then(|item| item.owner.as_deref());
+                match owners.iter_mut().find(|(name, _)| *name == owner) {
+                    Some((_, owned)) => owned.push(idx),
+                    None => owners.push((owner, vec![idx])),
+                }
+            }
+
+            let mut folder_groups: Vec<FolderGroup> = owners
+                .into_iter()
+                .map(|(owner, items)| FolderGroup {
+                    folder_name: match owner {
+                        Some(owner) => format!("👤 {}", owner),
+                        None => "(unknown owner)".to_string(),
+                    },
+                    total_size: items
+                        .iter()
+                        .filter_map(|&idx| self.all_items.get(idx))
+                        .map(|item| item.size_bytes)
+                        .sum(),
+                    items,
+                    expanded: true,
+                })
+                .collect();
+            folder_groups.sort_by(|a, b| {
+                let a_unknown = a.folder_name == "(unknown owner)";
+                let b_unknown = b.folder_name == "(unknown owner)";
+                a_unknown
+                    .cmp(&b_unknown)
+                    .then_with(|| b.total_size.cmp(&a.total_size))
+            });
+
+            group.folder_groups = folder_groups;
+            group.grouped_by_folder = true;
+        }
+    }
+
+    /// Regroup `all_items` without collapsing or expanding any group
+    fn rebuild_groups_keeping_expansion(&mut self) {
+        let expanded: HashMap<String, (bool, HashMap<String, bool>)> = self
+            .category_groups
+            .iter()
+            .map(|group| {
+                let folders = group
+                    .folder_groups
+                    .iter()
+                    .map(|folder| (folder.folder_name.clone(), folder.expanded))
+                    .collect();
+                (group.name.clone(), (group.expanded, folders))
+            })
+            .collect();
+        self.rebuild_groups_from_all_items();
+        for group in self.category_groups_mut() {
+            let Some((group_expanded, folders)) = expanded.get(&group.name) else {
+                continue;
+            };
+            group.expanded = *group_expanded;
+            for folder in &mut group.folder_groups {
+                if let Some(&folder_expanded) = folders.get(&folder.folder_name) {
+                    folder.expanded = folder_expanded;
+                }
+            }
+        }
+    }
+
+    /// Enable exactly the categories of `profile` (not saved to config)
+    pub fn apply_profile(&mut self, profile: CleanProfile) {
+        for cat in &mut self.categories {
+            cat.enabled = CATEGORIES
+                .iter()
+                .find(|def| def.name == cat.name)
+                .is_some_and(|def| profile.includes(def));
+        }
+    }
+
+    /// Enable exactly the categories named in a history session (not saved to
+    /// config); returns how many were recognized
+    pub fn apply_history_categories(&mut self, names: &[String]) -> usize {
+        let wanted: Vec<&str> = names
+            .iter()
+            .filter_map(|name| category_for_history_name(name))
+            .map(|def| def.name)
+            .collect();
+        for cat in &mut self.categories {
+            cat.enabled = wanted.contains(&cat.name.as_str());
+        }
+        self.categories.iter().filter(|cat| cat.enabled).count()
+    }
+
     /// Sync category selections from app state to config and save
     pub fn sync_categories_to_config(&mut self) {
         // Update config with current category enabled states
@@ -3495,7 +5940,8 @@ impl AppState {
 
         // Save config (ignore errors silently - this is best-effort)
         if let Err(e) = self.config.save() {
-            eprintln!("Warning: Could not save category selections: {}", e);
+            self.toasts
+                .warn(format!("Could not save category selections: {}", e));
         }
     }
 }
@@ -3505,3 +5951,163 @@ impl Default for AppState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Category;
+
+    fn item(path: &str, category: &str, size_bytes: u64) -> ResultItem {
+        ResultItem {
+            path: PathBuf::from(path),
+            size_bytes,
+            age_days: None,
+            last_opened: None,
+            category: category.to_string(),
+            safe: false,
+            display_name: None,
+            selection: Default::default(),
+            rebuild_command: None,
+            owner: None,
+            cloud: None,
+            sensitive: None,
+        }
+    }
+
+    fn state_with(items: Vec<ResultItem>) -> AppState {
+        let mut state = AppState::new();
+        state.selected_items = (0..items.len()).collect();
+        state.all_items = items;
+        state
+    }
+
+    #[test]
+    fn test_risk_tier() {
+        let mut state = state_with(Vec::new());
+        state.config.categories.build.fast_delete = false;
+        assert_eq!(state.risk_tier("Log Files", true), RiskTier::Safe);
+        assert_eq!(state.risk_tier("Large Files", false), RiskTier::Review);
+        assert_eq!(state.risk_tier("Trash", true), RiskTier::HighRisk);
+        assert_eq!(
+            state.risk_tier("Installed Applications", false),
+            RiskTier::HighRisk
+        );
+
+        // Fast-deleted folders skip the Recycle Bin
+        let build = Category::Build.name();
+        assert_eq!(state.risk_tier(build, true), RiskTier::Safe);
+        state.config.categories.build.fast_delete = true;
+        assert_eq!(state.risk_tier(build, true), RiskTier::HighRisk);
+    }
+
+    #[test]
+    fn test_confirm_rows_grouped_by_tier() {
+        let mut state = state_with(vec![
+            item("/data/trash/a", "Trash", 10),
+            item("/data/big.iso", "Large Files", 20),
+        ]);
+        state.cache_confirm_groups();
+
+        // Review comes before high risk, whatever the sizes
+        let groups = state.confirm_category_groups();
+        assert_eq!(groups[0].name, "Large Files");
+        assert_eq!(groups[1].name, "Trash");
+        assert_eq!(state.confirm_tier_items(RiskTier::Review), vec![1]);
+        assert_eq!(state.confirm_tier_items(RiskTier::HighRisk), vec![0]);
+        assert!(state.confirm_tier_items(RiskTier::Safe).is_empty());
+
+        let rows = state.confirm_rows();
+        assert_eq!(
+            rows[0],
+            ConfirmRow::TierHeader {
+                tier: RiskTier::Review
+            }
+        );
+        assert!(rows.contains(&ConfirmRow::TierHeader {
+            tier: RiskTier::HighRisk
+        }));
+
+        // A collapsed tier keeps only its header
+        state.toggle_confirm_tier(RiskTier::HighRisk);
+        let rows = state.confirm_rows();
+        assert_eq!(
+            rows.last(),
+            Some(&ConfirmRow::TierHeader {
+                tier: RiskTier::HighRisk
+            })
+        );
+        state.toggle_confirm_tier(RiskTier::HighRisk);
+        assert!(state.confirm_rows().len() > rows.len());
+    }
+
+    #[test]
+    fn test_new_confirm_items() {
+        let mut state = state_with(vec![
+            item("/data/a.log", "Log Files", 1),
+            item("/data/b.log", "Log Files", 1),
+        ]);
+        state.selected_items = HashSet::from([0]);
+
+        // Nothing is new on the first visit
+        state.mark_new_confirm_items();
+        assert!(state.confirm_new_items.is_empty());
+
+        state.selected_items.insert(1);
+        state.mark_new_confirm_items();
+        assert_eq!(state.confirm_new_items, HashSet::from([1]));
+
+        // Seen on the previous visit, so no longer new
+        state.mark_new_confirm_items();
+        assert!(state.confirm_new_items.is_empty());
+    }
+
+    #[test]
+    fn test_results_rows_cache() {
+        let mut state = state_with(vec![
+            item("/data/a.log", "Log Files", 1),
+            item("/data/b.log", "Log Files", 1),
+            item("/data/big.iso", "Large Files", 20),
+        ]);
+        state.rebuild_groups_from_all_items();
+        let rows = state.results_rows();
+        assert!(Arc::ptr_eq(&rows, &state.results_rows()));
+
+        // Collapsing a group goes through category_groups_mut, which drops the cached rows
+        state.category_groups_mut()[0].expanded = false;
+        let collapsed = state.results_rows();
+        assert!(!Arc::ptr_eq(&rows, &collapsed));
+        assert!(collapsed.len() < rows.len());
+    }
+
+    #[test]
+    fn test_volume_projections() {
+        let mut state = state_with(vec![
+            item("/data/a.log", "Log Files", 100),
+            item("/data/b.log", "Log Files", 50),
+            item("/data/mnt/c.log", "Log Files", 7),
+            item("/data/trash/d", "Trash", 1000),
+            item("/other/e.log", "Log Files", 5),
+        ]);
+        state.confirm_volumes = vec![
+            (PathBuf::from("/data"), 1_000),
+            (PathBuf::from("/data/mnt"), 2_000),
+        ];
+
+        // The Recycle Bin and paths on unknown volumes are left out
+        assert_eq!(
+            state.volume_projections(),
+            vec![
+                VolumeProjection {
+                    mount_point: PathBuf::from("/data"),
+                    free_bytes: 1_000,
+                    freed_bytes: 150,
+                },
+                VolumeProjection {
+                    mount_point: PathBuf::from("/data/mnt"),
+                    free_bytes: 2_000,
+                    freed_bytes: 7,
+                },
+            ]
+        );
+    }
+}
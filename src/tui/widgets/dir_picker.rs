@@ -0,0 +1,270 @@
+//! Directory picker widget - browse drives and folders with the keyboard
+
+use crate::tui::theme::Styles;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::path::{Path, PathBuf};
+
+/// What the caller should do after a key press
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PickerAction {
+    None,
+    Cancel,
+    /// Use this folder
+    Pick(PathBuf),
+    /// Use this folder and remember it
+    PickAndSave(PathBuf),
+}
+
+/// Browsing state of the picker
+#[derive(Debug, Clone)]
+pub struct DirPicker {
+    /// Folder being listed (None = the list of drives)
+    pub current: Option<PathBuf>,
+    /// Subfolders of `current` (or the drives)
+    pub entries: Vec<PathBuf>,
+    pub cursor: usize,
+    /// Why `current` couldn't be listed
+    pub error: Option<String>,
+}
+
+impl DirPicker {
+    /// Start browsing at `start`, or at the drive list if it isn't a folder
+    pub fn new(start: &Path) -> Self {
+        let mut picker = Self {
+            current: None,
+            entries: Vec::new(),
+            cursor: 0,
+            error: None,
+        };
+        let start = start.is_dir().then(|| start.to_path_buf());
+        picker.open(start, None);
+        picker
+    }
+
+    /// List `dir` (None = drives), putting the cursor on `select` if present
+    fn open(&mut self, dir: Option<PathBuf>, select: Option<&Path>) {
+        let listing = match dir {
+            Some(ref dir) => list_subdirs(dir),
+            None => Ok(list_roots()),
+        };
+        match listing {
+            Ok(entries) => {
+                self.cursor = select
+                    .and_then(|s| entries.iter().position(|e| e == s))
+                    .unwrap_or(0);
+                self.entries = entries;
+                self.current = dir;
+                self.error = None;
+            }
+            // Stay where we are and say why
+            Err(e) => self.error = Some(e.to_string()),
+        }
+    }
+
+    fn highlighted(&self) -> Option<&PathBuf> {
+        self.entries.get(self.cursor)
+    }
+
+    /// Folder that S/D would pick: the open folder, or the highlighted drive
+    pub fn selection(&self) -> Option<PathBuf> {
+        self.current.clone().or_else(|| self.highlighted().cloned())
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> PickerAction {
+        match key {
+            KeyCode::Esc => PickerAction::Cancel,
+            KeyCode::Up => {
+                self.cursor = self.cursor.saturating_sub(1);
+                PickerAction::None
+            }
+            KeyCode::Down => {
+                if self.cursor + 1 < self.entries.len() {
+                    self.cursor += 1;
+                }
+                PickerAction::None
+            }
+            KeyCode::PageUp => {
+                self.cursor = self.cursor.saturating_sub(10);
+                PickerAction::None
+            }
+            KeyCode::PageDown => {
+                self.cursor = (self.cursor + 10).min(self.entries.len().saturating_sub(1));
+                PickerAction::None
+            }
+            KeyCode::Home => {
+                self.cursor = 0;
+                PickerAction::None
+            }
+            KeyCode::End => {
+                self.cursor = self.entries.len().saturating_sub(1);
+                PickerAction::None
+            }
+            KeyCode::Enter | KeyCode::Right => {
+                if let Some(dir) = self.highlighted().cloned() {
+                    self.open(Some(dir), None);
+                }
+                PickerAction::None
+            }
+            KeyCode::Left | KeyCode::Backspace => {
+                if let Some(current) = self.current.clone() {
+                    // Drive roots have no parent: go back to the drive list
+                    let parent = current.parent().map(Path::to_path_buf);
+                    self.open(parent, Some(&current));
+                }
+                PickerAction::None
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => self
+                .selection()
+                .map(PickerAction::Pick)
+                .unwrap_or(PickerAction::None),
+            KeyCode::Char('d') | KeyCode::Char('D') => self
+                .selection()
+                .map(PickerAction::PickAndSave)
+                .unwrap_or(PickerAction::None),
+            _ => PickerAction::None,
+        }
+    }
+}
+
+/// Drives (Windows) or the filesystem root
+fn list_roots() -> Vec<PathBuf> {
+    #[cfg(windows)]
+    {
+        (b'A'..=b'Z')
+            .map(|letter| PathBuf::from(format!("{}:\\", letter as char)))
+            .filter(|root| root.exists())
+            .collect()
+    }
+
+    #[cfg(not(windows))]
+    {
+        vec![PathBuf::from("/")]
+    }
+}
+
+/// Subfolders of `dir`, sorted by name (symlinks and junctions are skipped)
+fn list_subdirs(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut dirs: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|entry| entry.path())
+        .collect();
+    dirs.sort_by_key(|p| {
+        p.file_name()
+            .map(|n| n.to_string_lossy().to_lowercase())
+            .unwrap_or_default()
+    });
+    Ok(dirs)
+}
+
+/// Render the picker: current folder, folder list, and any listing error
+pub fn render_dir_picker(f: &mut Frame, area: Rect, picker: &DirPicker) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Current folder
+            Constraint::Length(1), // Error
+            Constraint::Min(1),    // Folders
+        ])
+        .split(area);
+
+    let location = match picker.current {
+        Some(ref dir) => dir.display().to_string(),
+        None => "Drives".to_string(),
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("Folder: ", Styles::secondary()),
+            Span::styled(location, Styles::primary()),
+        ])),
+        chunks[0],
+    );
+
+    if let Some(ref error) = picker.error {
+        f.render_widget(
+            Paragraph::new(format!("Cannot open folder: {}", error)).style(Styles::warning()),
+            chunks[1],
+        );
+    }
+
+    let items: Vec<ListItem> = if picker.entries.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "(no subfolders)",
+            Styles::muted(),
+        ))]
+    } else {
+        picker
+            .entries
+            .iter()
+            .map(|entry| {
+                let name = match picker.current {
+                    Some(_) => entry
+                        .file_name()
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_else(|| entry.display().to_string()),
+                    None => entry.display().to_string(),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled("📁 ", Styles::secondary()),
+                    Span::styled(name, Styles::primary()),
+                ]))
+            })
+            .collect()
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Styles::border()),
+        )
+        .highlight_style(Styles::selected());
+    let mut state = ListState::default();
+    if !picker.entries.is_empty() {
+        state.select(Some(picker.cursor));
+    }
+    f.render_stateful_widget(list, chunks[2], &mut state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigate_and_pick() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::create_dir(temp.path().join("beta")).unwrap();
+        std::fs::create_dir(temp.path().join("Alpha")).unwrap();
+        std::fs::write(temp.path().join("file.txt"), "x").unwrap();
+
+        let mut picker = DirPicker::new(temp.path());
+        assert_eq!(
+            picker.entries,
+            vec![temp.path().join("Alpha"), temp.path().join("beta")]
+        );
+
+        picker.handle_key(KeyCode::Down);
+        picker.handle_key(KeyCode::Enter);
+        assert_eq!(picker.current, Some(temp.path().join("beta")));
+        assert_eq!(
+            picker.handle_key(KeyCode::Char('s')),
+            PickerAction::Pick(temp.path().join("beta"))
+        );
+
+        // Going back up puts the cursor on the folder we came from
+        picker.handle_key(KeyCode::Backspace);
+        assert_eq!(picker.current, Some(temp.path().to_path_buf()));
+        assert_eq!(picker.cursor, 1);
+        assert_eq!(
+            picker.handle_key(KeyCode::Char('d')),
+            PickerAction::PickAndSave(temp.path().to_path_buf())
+        );
+        assert_eq!(picker.handle_key(KeyCode::Esc), PickerAction::Cancel);
+    }
+}
@@ -0,0 +1,210 @@
+//! Help overlay widget - every shortcut of the current screen, searchable
+//!
+//! Opened with `?` (or F1) from any screen. The list is the same data the
+//! shortcuts bar shows ([`get_shortcuts`]), so it never disagrees with it,
+//! plus the keys that work everywhere. Typing filters it with a fuzzy match
+//! over both the key and the (translated) description.
+
+use crate::i18n::t;
+use crate::tui::state::AppState;
+use crate::tui::theme::Styles;
+use crate::tui::widgets::shortcuts::get_shortcuts;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Padding, Paragraph},
+    Frame,
+};
+
+/// Shortcuts that work on every screen
+const GLOBAL_SHORTCUTS: [(&str, &str); 2] = [("Alt+1-7", "Switch view"), ("?/F1", "Help")];
+
+/// Search state of the overlay
+#[derive(Debug, Clone, Default)]
+pub struct HelpOverlay {
+    pub query: String,
+    pub cursor: usize,
+}
+
+impl HelpOverlay {
+    /// Handle a key; returns false once the overlay should close
+    pub fn handle_key(&mut self, key: KeyCode, matches: usize) -> bool {
+        match key {
+            KeyCode::Esc | KeyCode::F(1) => return false,
+            KeyCode::Char('?') if self.query.is_empty() => return false,
+            KeyCode::Up => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Down => {
+                if self.cursor + 1 < matches {
+                    self.cursor += 1;
+                }
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.cursor = 0;
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.cursor = 0;
+            }
+            _ => {}
+        }
+        true
+    }
+}
+
+/// Fuzzy match of `query` against `text`: every query character in order,
+/// case-insensitive. Higher scores for runs of consecutive characters and for
+/// matches at the start of a word; None if it doesn't match.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut previous: Option<usize> = None;
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = pos + text[pos..].iter().position(|&c| c == q)?;
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == found) {
+            score += 5;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        previous = Some(found);
+        pos = found + 1;
+    }
+    Some(score)
+}
+
+/// Shortcuts of the current screen matching `query`, best match first
+pub fn matching_shortcuts(app_state: &AppState, query: &str) -> Vec<(&'static str, &'static str)> {
+    let mut shortcuts = get_shortcuts(&app_state.screen, Some(app_state));
+    for global in GLOBAL_SHORTCUTS {
+        if !shortcuts.iter().any(|(_, desc)| *desc == global.1) {
+            shortcuts.push(global);
+        }
+    }
+    if query.trim().is_empty() {
+        return shortcuts;
+    }
+    let mut scored: Vec<(i32, (&'static str, &'static str))> = shortcuts
+        .into_iter()
+        .filter_map(|(key, desc)| {
+            let haystack = format!("{} {} {}", key, desc, t(desc));
+            fuzzy_score(query, &haystack).map(|score| (score, (key, desc)))
+        })
+        .collect();
+    // Stable sort keeps the screen's order among equal scores
+    scored.sort_by(|a, b| b.0.cmp(&a.0));
+    scored.into_iter().map(|(_, shortcut)| shortcut).collect()
+}
+
+/// Draw the overlay centered over `area`
+pub fn render_help(f: &mut Frame, area: Rect, app_state: &AppState, help: &HelpOverlay) {
+    let width = area.width.min(64);
+    let height = area.height.min(24);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Styles::emphasis())
+        .title(t("Help"))
+        .padding(Padding::new(1, 1, 0, 0));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Search line + blank
+            Constraint::Min(1),    // Shortcuts
+            Constraint::Length(1), // Hint
+        ])
+        .split(inner);
+
+    let search = Paragraph::new(Line::from(vec![
+        Span::styled(format!("{} ", t("Search:")), Styles::header()),
+        Span::styled(help.query.clone(), Styles::primary()),
+        Span::styled("_", Styles::emphasis()),
+    ]));
+    f.render_widget(search, chunks[0]);
+
+    let shortcuts = matching_shortcuts(app_state, &help.query);
+    let key_width = shortcuts
+        .iter()
+        .map(|(key, _)| key.chars().count())
+        .max()
+        .unwrap_or(0);
+    let items: Vec<ListItem> = if shortcuts.is_empty() {
+        vec![ListItem::new(Span::styled(
+            t("No matching shortcuts"),
+            Styles::secondary(),
+        ))]
+    } else {
+        shortcuts
+            .iter()
+            .map(|(key, desc)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<width$}  ", key, width = key_width),
+                        Styles::emphasis(),
+                    ),
+                    Span::styled(t(desc), Styles::primary()),
+                ]))
+            })
+            .collect()
+    };
+    let mut list_state = ListState::default();
+    if !shortcuts.is_empty() {
+        list_state.select(Some(help.cursor.min(shortcuts.len() - 1)));
+    }
+    f.render_stateful_widget(
+        List::new(items).highlight_style(Styles::selected()),
+        chunks[1],
+        &mut list_state,
+    );
+
+    let hint = Paragraph::new(Span::styled(
+        t("Type to search • ↑↓ Navigate • Esc Close"),
+        Styles::secondary(),
+    ));
+    f.render_widget(hint, chunks[2]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("dsel", "C Delete selected").is_some());
+        assert!(fuzzy_score("xyz", "C Delete selected").is_none());
+        // Order matters
+        assert!(fuzzy_score("led", "Delete").is_none());
+        // Consecutive and word-start matches rank higher
+        assert!(
+            fuzzy_score("prot", "P Protect folder").unwrap()
+                > fuzzy_score("prot", "P Pin root").unwrap()
+        );
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let mut help = HelpOverlay::default();
+        assert!(help.handle_key(KeyCode::Char('s'), 5));
+        assert!(help.handle_key(KeyCode::Down, 5));
+        assert_eq!((help.query.as_str(), help.cursor), ("s", 1));
+        // `?` is text once a search is typed
+        assert!(help.handle_key(KeyCode::Char('?'), 5));
+        help.query.clear();
+        assert!(!help.handle_key(KeyCode::Char('?'), 5));
+        assert!(!help.handle_key(KeyCode::Esc, 5));
+    }
+}
@@ -1,9 +1,13 @@
 //! Reusable TUI widgets
 
+pub mod dir_picker;
+pub mod help;
 pub mod logo;
 pub mod progress;
 pub mod shortcuts;
+pub mod sidebar;
 pub mod table;
+pub mod toasts;
 pub mod tree;
 
 pub use logo::*;
@@ -24,7 +24,7 @@ pub fn render_shortcuts(f: &mut Frame, area: Rect, shortcuts: &[(&str, &str)]) {
     for (i, (key, desc)) in shortcuts.iter().enumerate() {
         let separator = if i > 0 { " • " } else { "" };
         let key_text = format!("[{}]", key);
-        let desc_text = format!(" {}", desc);
+        let desc_text = format!(" {}", crate::i18n::t(desc));
         let item_text = format!("{}{}{}", separator, key_text, desc_text);
         let item_width = item_text.len() as u16;
 
@@ -59,32 +59,87 @@ pub fn render_shortcuts(f: &mut Frame, area: Rect, shortcuts: &[(&str, &str)]) {
     f.render_widget(paragraph, area);
 }
 
+/// Toggle for [`crate::throttle`]'s background mode
+fn background_shortcut() -> (&'static str, &'static str) {
+    if crate::throttle::background_mode() {
+        ("B", "Background: on")
+    } else {
+        ("B", "Background: off")
+    }
+}
+
+/// Whether Results has items that can be made online-only instead of deleted
+fn has_onedrive_items(app_state: Option<&crate::tui::state::AppState>) -> bool {
+    app_state.is_some_and(|s| {
+        s.all_items
+            .iter()
+            .any(|item| item.cloud.is_some_and(|p| p.can_free_up_space()))
+    })
+}
+
 /// Get shortcuts for a screen type
 pub fn get_shortcuts(
     screen: &crate::tui::state::Screen,
     app_state: Option<&crate::tui::state::AppState>,
 ) -> Vec<(&'static str, &'static str)> {
     match screen {
-        crate::tui::state::Screen::Dashboard => vec![
-            ("Tab", "Switch Panel"),
-            ("↑↓", "Navigate"),
-            ("Space", "Toggle Category"),
-            ("Enter", "Execute Action"),
-            ("A", "Select All"),
-            ("Q", "Quit"),
-        ],
+        crate::tui::state::Screen::Dashboard => {
+            let mut shortcuts = vec![
+                ("Tab", "Switch Panel"),
+                ("↑↓", "Navigate"),
+                ("Space", "Toggle Category"),
+                ("Enter", "Execute Action"),
+            ];
+            if app_state.is_some_and(|s| s.resumable_scan.is_some()) {
+                shortcuts.push(("R", "Resume Scan"));
+                shortcuts.push(("X", "Discard Scan"));
+            } else if app_state.is_some_and(|s| s.saved_session.is_some()) {
+                shortcuts.push(("S", "Restore Session"));
+                shortcuts.push(("X", "Discard Session"));
+            }
+            if app_state.is_some_and(|s| !s.config.ui.pinned_dirs.is_empty()) {
+                shortcuts.push(("1-9", "Pinned"));
+            }
+            shortcuts.extend([
+                ("A", "Select All"),
+                ("Ctrl+Z", "Undo"),
+                ("/", "Find Files"),
+                ("W", "What Changed"),
+                ("H", "History"),
+                ("P", "Scan Path"),
+                ("?", "Help"),
+                ("Q", "Quit"),
+            ]);
+            shortcuts
+        }
         crate::tui::state::Screen::Config => vec![
             ("↑↓", "Select Field"),
             ("Enter", "Edit/Toggle"),
             ("Space", "Toggle (bool)"),
+            ("+/-", "Adjust"),
             ("S", "Save"),
             ("R", "Reload"),
             ("O", "Open File"),
             ("Esc", "Back"),
         ],
-        crate::tui::state::Screen::Scanning { .. } => vec![("Esc", "Cancel")],
+        crate::tui::state::Screen::Scanning { progress } => match progress.slow {
+            Some(ref slow) if slow.control.narrow_to.is_some() => vec![
+                ("S", "Skip category"),
+                ("K", "Keep waiting"),
+                ("N", "Narrow scope"),
+                ("Esc", "Cancel"),
+            ],
+            Some(_) => vec![
+                ("S", "Skip category"),
+                ("K", "Keep waiting"),
+                ("Esc", "Cancel"),
+            ],
+            None => vec![("Esc", "Cancel")],
+        },
         crate::tui::state::Screen::Results => {
-            if app_state.map(|s| s.search_mode).unwrap_or(false) {
+            if app_state.is_some_and(|s| !s.compress_confirm.is_empty()) {
+                vec![("Y", "Compress"), ("N", "Cancel")]
+            } else if app_state.map(|s| s.search_mode).unwrap_or(false) {
                 vec![
                     ("Type", "Search"),
                     ("Esc", "Exit search"),
@@ -94,54 +149,118 @@ pub fn get_shortcuts(
                 .map(|s| !s.search_query.is_empty())
                 .unwrap_or(false)
             {
-                vec![
+                let mut shortcuts = vec![
                     ("Space", "select/deselect"),
                     ("C", "Delete selected"),
+                    ("Z", "Compress"),
                     ("/", "Search"),
                     ("↑↓", "Navigate"),
                     ("Tab", "Next Category"),
                     ("Enter", "open"),
+                    ("O", "open folder"),
+                    ("P", "Protect folder"),
+                    ("G", "Group by owner"),
+                    ("I", "Why selected"),
+                    ("A", "Apps by cache"),
+                    ("Ctrl+F", "Find items"),
+                    ("V", "Saved views"),
+                    ("F", "Select by rule"),
+                    ("Ctrl+Z", "Undo"),
+                    ("Ctrl+Y", "Redo"),
                     ("Ctrl+Enter", "Collapse group..."),
                     ("Esc", "Clear Filter"),
                     ("Q", "Quit"),
-                ]
+                ];
+                if has_onedrive_items(app_state) {
+                    shortcuts.insert(3, ("L", "Free up space"));
+                }
+                shortcuts
             } else {
-                vec![
+                let mut shortcuts = vec![
                     ("Space", "select/deselect"),
                     ("C", "Delete selected"),
+                    ("Z", "Compress"),
                     ("/", "Search"),
                     ("↑↓", "Navigate"),
                     ("Tab", "Next Category"),
                     ("Enter", "open"),
+                    ("O", "open folder"),
+                    ("P", "Protect folder"),
+                    ("G", "Group by owner"),
+                    ("I", "Why selected"),
+                    ("A", "Apps by cache"),
+                    ("Ctrl+F", "Find items"),
+                    ("V", "Saved views"),
+                    ("F", "Select by rule"),
+                    ("Ctrl+Z", "Undo"),
+                    ("Ctrl+Y", "Redo"),
                     ("Ctrl+Enter", "Collapse group..."),
                     ("Esc", "Back"),
                     ("Q", "Quit"),
-                ]
+                ];
+                if has_onedrive_items(app_state) {
+                    shortcuts.insert(3, ("L", "Free up space"));
+                }
+                shortcuts
             }
         }
-        crate::tui::state::Screen::Preview { .. } => {
-            vec![("Esc", "Back"), ("D", "Delete"), ("E", "Exclude")]
+        crate::tui::state::Screen::Preview { index } => {
+            let mut shortcuts = vec![("Esc", "Back"), ("D", "Delete"), ("E", "Exclude")];
+            if app_state
+                .and_then(|s| s.all_items.get(*index))
+                .and_then(|item| item.cloud)
+                .is_some_and(|provider| provider.can_free_up_space())
+            {
+                shortcuts.push(("L", "Free up space"));
+            }
+            shortcuts
+        }
+        crate::tui::state::Screen::Confirm { .. } => {
+            let mut shortcuts = vec![
+                ("↑↓", "Navigate"),
+                ("Space", "Toggle"),
+                ("Enter", "Expand"),
+                ("Y", "Delete"),
+                ("N", "Cancel"),
+                ("P", "Permanent"),
+            ];
+            if app_state.is_some_and(|state| !state.bin_overflow.is_empty()) {
+                shortcuts.push(("Q", "Quarantine"));
+            }
+            shortcuts.push(background_shortcut());
+            shortcuts
+        }
+        crate::tui::state::Screen::Cleaning { progress } => {
+            if progress.stop_requested {
+                vec![]
+            } else if progress.paused {
+                vec![
+                    ("P", "Resume"),
+                    ("Esc", "Stop cleaning"),
+                    background_shortcut(),
+                ]
+            } else {
+                vec![("P", "Pause"), background_shortcut()]
+            }
         }
-        crate::tui::state::Screen::Confirm { .. } => vec![
-            ("↑↓", "Navigate"),
-            ("Space", "Toggle"),
-            ("Enter", "Expand"),
-            ("Y", "Delete"),
-            ("N", "Cancel"),
-            ("P", "Permanent"),
-        ],
-        crate::tui::state::Screen::Cleaning { .. } => vec![],
         crate::tui::state::Screen::Success { .. } => {
             // Check if there are remaining items to show back navigation
             let has_remaining = app_state
                 .map(|state| !state.all_items.is_empty())
                 .unwrap_or(false);
 
-            if has_remaining {
+            if app_state.is_some_and(|state| state.shadow_resize_confirm) {
+                return vec![("Y", "Confirm"), ("N", "Cancel")];
+            }
+            let mut shortcuts = if has_remaining {
                 vec![("Esc/B", "Back to Results"), ("Any Key", "Dashboard")]
             } else {
                 vec![("Any Key", "Dashboard")]
+            };
+            if app_state.is_some_and(|state| state.shadow_resize_target().is_some()) {
+                shortcuts.insert(0, ("V", "Cap shadow storage"));
             }
+            shortcuts
         }
         crate::tui::state::Screen::RestoreSelection { .. } => {
             vec![("↑↓", "Navigate"), ("Enter", "Select"), ("Esc/B/Q", "Back")]
@@ -160,10 +279,16 @@ pub fn get_shortcuts(
             {
                 vec![
                     ("Space", "Select"),
+                    ("C", "Clean marked"),
                     ("↑↓", "Navigate"),
                     ("Enter", "Drill In"),
                     ("Backspace", "Go Back"),
                     ("S", "Sort"),
+                    ("P", "Protect folder"),
+                    ("F", "Pin"),
+                    ("1-9", "Pinned"),
+                    ("B", "Breakdown"),
+                    ("E/J/W", "Export CSV/JSON/WizTree"),
                     ("/", "Search"),
                     ("Esc", "Clear Filter"),
                     ("Q", "Quit"),
@@ -171,16 +296,148 @@ pub fn get_shortcuts(
             } else {
                 vec![
                     ("Space", "Select"),
+                    ("C", "Clean marked"),
                     ("↑↓", "Navigate"),
                     ("Enter", "Drill In"),
                     ("Backspace", "Go Back"),
                     ("S", "Sort"),
+                    ("P", "Protect folder"),
+                    ("F", "Pin"),
+                    ("1-9", "Pinned"),
+                    ("B", "Breakdown"),
+                    ("E/J/W", "Export CSV/JSON/WizTree"),
                     ("/", "Search"),
                     ("Q/Esc", "Quit"),
                 ]
             }
         }
-        crate::tui::state::Screen::Status { .. } => vec![("Esc/Q", "Back"), ("R", "Refresh")],
+        crate::tui::state::Screen::Status { .. } => {
+            let tree = app_state.map(|s| &s.process_tree);
+            if tree.is_some_and(|tree| tree.editing_filter) {
+                return vec![("Type", "Filter"), ("Enter/Esc", "Done")];
+            }
+            let mut shortcuts = vec![
+                ("Esc/Q", "Back"),
+                ("R", "Refresh"),
+                ("W", "Disk writes"),
+                ("N", "Network check"),
+                ("T", "Process tree"),
+            ];
+            if tree.is_some_and(|tree| tree.enabled) {
+                shortcuts.extend([("↑↓", "Navigate"), ("Enter", "Expand"), ("/", "Filter")]);
+            }
+            if app_state.is_some_and(|s| !s.leak_watch.suspects().is_empty()) {
+                if !tree.is_some_and(|tree| tree.enabled) {
+                    shortcuts.push(("↑↓", "Navigate"));
+                }
+                shortcuts.extend([("K", "End process"), ("E", "Restart")]);
+            }
+            shortcuts
+        }
+        crate::tui::state::Screen::NetCheck { .. } => {
+            vec![("↑↓", "Navigate"), ("R", "Revert"), ("Esc", "Back")]
+        }
+        crate::tui::state::Screen::DiskWrites { .. } => {
+            vec![("Tab", "Path/process"), ("R", "Restart"), ("Esc", "Back")]
+        }
+        crate::tui::state::Screen::SavedViews { naming, .. } => {
+            if naming.is_some() {
+                vec![("Type", "View name"), ("Enter", "Save"), ("Esc", "Cancel")]
+            } else {
+                vec![
+                    ("↑↓", "Navigate"),
+                    ("Enter", "Apply"),
+                    ("S", "Save search"),
+                    ("D", "Delete"),
+                    ("Esc", "Back"),
+                ]
+            }
+        }
+        crate::tui::state::Screen::SelectRule { cursor, .. } => {
+            if *cursor < 4 {
+                vec![
+                    ("Type", "Rule"),
+                    ("↑↓", "Navigate"),
+                    ("Enter", "Apply"),
+                    ("Esc", "Cancel"),
+                ]
+            } else {
+                vec![
+                    ("Space", "Toggle"),
+                    ("↑↓", "Navigate"),
+                    ("Enter", "Apply"),
+                    ("Esc", "Cancel"),
+                ]
+            }
+        }
+        crate::tui::state::Screen::WhatChanged { .. } => vec![
+            ("↑↓", "Navigate"),
+            ("Enter", "Open Folder"),
+            ("R", "Reload"),
+            ("Esc", "Back"),
+        ],
+        crate::tui::state::Screen::Search { .. } => vec![
+            ("Type", "Search"),
+            ("↑↓", "Navigate"),
+            ("Enter", "Open"),
+            ("Del", "Delete"),
+            ("Ctrl+R", "Rebuild Index"),
+            ("Esc", "Back"),
+        ],
+        crate::tui::state::Screen::AppCaches { .. } => vec![
+            ("↑↓", "Navigate"),
+            ("Space", "select/deselect"),
+            ("C", "Clean this app"),
+            ("Esc", "Back to Results"),
+        ],
+        crate::tui::state::Screen::Finder { .. } => vec![
+            ("Type", "Filter"),
+            ("↑↓", "Navigate"),
+            ("Tab", "Mark"),
+            ("Ctrl+A", "Mark all matches"),
+            ("Enter", "Clean marked"),
+            ("Esc", "Back to Results"),
+        ],
+        crate::tui::state::Screen::Breakdown { .. } => vec![
+            ("Tab/←→", "Next chart"),
+            ("E/J", "Export CSV/JSON"),
+            ("Esc", "Back to Disk Insights"),
+        ],
+        crate::tui::state::Screen::ScanPathPicker { .. } => vec![
+            ("↑↓", "Navigate"),
+            ("Enter/→", "Open"),
+            ("←", "Up"),
+            ("S", "Use Folder"),
+            ("D", "Use & Save Default"),
+            ("Esc", "Cancel"),
+        ],
+        crate::tui::state::Screen::History {
+            editing_filter,
+            open_session,
+            ..
+        } => {
+            if *editing_filter {
+                vec![("Type", "Filter"), ("Enter/Esc", "Done")]
+            } else if open_session.is_some() {
+                vec![
+                    ("↑↓", "Navigate"),
+                    ("Enter", "Open Folder"),
+                    ("R", "Restore Session"),
+                    ("C/J", "Export CSV/JSON"),
+                    ("/", "Filter"),
+                    ("Esc", "Sessions"),
+                ]
+            } else {
+                vec![
+                    ("↑↓", "Navigate"),
+                    ("Enter", "Details"),
+                    ("R", "Restore"),
+                    ("C/J", "Export CSV/JSON"),
+                    ("/", "Filter"),
+                    ("Esc", "Back"),
+                ]
+            }
+        }
         crate::tui::state::Screen::Optimize { .. } => {
             if app_state
                 .and_then(|s| {
@@ -0,0 +1,91 @@
+//! Sidebar widget - persistent view navigation on the left
+//!
+//! Lists the [`SidebarView`]s with the active one highlighted. Hidden on
+//! narrow terminals, where the screens keep the full width.
+
+use crate::i18n::t;
+use crate::tui::state::{AppState, SidebarView};
+use crate::tui::theme::Styles;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+/// Columns the sidebar takes, including its border
+pub const SIDEBAR_WIDTH: u16 = 16;
+
+/// Narrowest terminal that gets a sidebar
+const MIN_TERMINAL_WIDTH: u16 = 100;
+
+/// Rows above the first view (title + blank line)
+const LIST_TOP: u16 = 2;
+
+/// Sidebar width for a terminal `width` columns wide (0 = no sidebar)
+pub fn sidebar_width(width: u16) -> u16 {
+    if width >= MIN_TERMINAL_WIDTH {
+        SIDEBAR_WIDTH
+    } else {
+        0
+    }
+}
+
+/// View listed on terminal `row` of a sidebar starting at the top of the screen
+pub fn view_at(row: u16) -> Option<SidebarView> {
+    let index = row.checked_sub(LIST_TOP)? as usize;
+    SidebarView::ALL.get(index).copied()
+}
+
+/// Whether a view has anything to show yet (Results needs a scan)
+pub fn view_available(app_state: &AppState, view: SidebarView) -> bool {
+    match view {
+        SidebarView::Results => app_state.scan_results.is_some(),
+        _ => true,
+    }
+}
+
+pub fn render_sidebar(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let active = SidebarView::of(&app_state.screen);
+    let busy = app_state.screen.is_busy();
+
+    let mut lines = vec![
+        Line::from(Span::styled(t("Views"), Styles::header())),
+        Line::from(""),
+    ];
+    for (i, view) in SidebarView::ALL.iter().enumerate() {
+        let style = if *view == active {
+            Styles::selected()
+        } else if busy || !view_available(app_state, *view) {
+            Styles::muted()
+        } else {
+            Styles::primary()
+        };
+        // A dot marks views holding a parked screen
+        let parked = if app_state.parked_screens.contains_key(view) {
+            "•"
+        } else {
+            " "
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", i + 1), Styles::emphasis()),
+            Span::styled(t(view.label()), style),
+            Span::styled(format!(" {}", parked), Styles::secondary()),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("Alt+1-7 {}", t("Switch view")),
+        Styles::secondary(),
+    )));
+
+    let sidebar = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::RIGHT)
+                .border_style(Styles::border())
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(sidebar, area);
+}
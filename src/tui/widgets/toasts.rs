@@ -0,0 +1,194 @@
+//! Toast notifications - transient messages over the bottom-right corner
+//!
+//! Anything that goes wrong while the TUI owns the terminal is reported here
+//! instead of on stderr, which would scribble over the alternate screen. Each
+//! toast carries the time it was raised and disappears on its own (errors
+//! stay longest).
+
+use crate::i18n::t;
+use crate::tui::theme::Styles;
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Toasts shown at once; older ones are dropped first
+const MAX_TOASTS: usize = 4;
+
+/// Width of a toast, borders included
+const TOAST_WIDTH: u16 = 48;
+
+/// Rows kept clear at the bottom for the shortcuts bar
+const SHORTCUTS_HEIGHT: u16 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl ToastLevel {
+    /// How long a toast of this level stays up
+    fn lifetime(self) -> Duration {
+        match self {
+            ToastLevel::Info => Duration::from_secs(4),
+            ToastLevel::Warn => Duration::from_secs(7),
+            ToastLevel::Error => Duration::from_secs(12),
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            ToastLevel::Info => "Info",
+            ToastLevel::Warn => "Warning",
+            ToastLevel::Error => "Error",
+        }
+    }
+
+    fn style(self) -> Style {
+        match self {
+            ToastLevel::Info => Styles::accent(),
+            ToastLevel::Warn => Styles::warning(),
+            ToastLevel::Error => Styles::error(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub level: ToastLevel,
+    pub message: String,
+    /// Wall-clock time, shown on the toast
+    pub raised_at: DateTime<Local>,
+    /// For expiry
+    pub created: Instant,
+}
+
+impl Toast {
+    fn expired(&self, now: Instant) -> bool {
+        now.duration_since(self.created) >= self.level.lifetime()
+    }
+}
+
+/// Toasts waiting to expire, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct ToastQueue {
+    toasts: VecDeque<Toast>,
+}
+
+impl ToastQueue {
+    pub fn push(&mut self, level: ToastLevel, message: impl Into<String>) {
+        let message = message.into();
+        if level != ToastLevel::Info {
+            crate::debug_log::tui_log(&format!("{:?}: {}", level, message));
+        }
+        if self.toasts.len() == MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+        self.toasts.push_back(Toast {
+            level,
+            message,
+            raised_at: Local::now(),
+            created: Instant::now(),
+        });
+    }
+
+    pub fn info(&mut self, message: impl Into<String>) {
+        self.push(ToastLevel::Info, message);
+    }
+
+    pub fn warn(&mut self, message: impl Into<String>) {
+        self.push(ToastLevel::Warn, message);
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.push(ToastLevel::Error, message);
+    }
+
+    /// Drop the toasts whose time is up
+    pub fn prune(&mut self) {
+        let now = Instant::now();
+        self.toasts.retain(|toast| !toast.expired(now));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+}
+
+/// Stack the toasts in the bottom-right corner of `area`, newest at the bottom
+pub fn render_toasts(f: &mut Frame, area: Rect, toasts: &ToastQueue) {
+    let width = TOAST_WIDTH.min(area.width);
+    let text_width = width.saturating_sub(2).max(1) as usize;
+    let mut bottom = area.bottom().saturating_sub(SHORTCUTS_HEIGHT);
+
+    for toast in toasts.iter().rev() {
+        // Borders plus the wrapped message
+        let lines = toast.message.chars().count().div_ceil(text_width).max(1) as u16;
+        let height = (lines + 2).min(6);
+        if bottom < area.y + height {
+            break;
+        }
+        let rect = Rect {
+            x: area.right() - width,
+            y: bottom - height,
+            width,
+            height,
+        };
+        bottom = rect.y;
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(toast.level.style())
+            .title(Line::from(vec![
+                Span::styled(format!(" {} ", t(toast.level.title())), toast.level.style()),
+                Span::styled(
+                    format!("{} ", toast.raised_at.format("%H:%M:%S")),
+                    Styles::secondary(),
+                ),
+            ]));
+        f.render_widget(Clear, rect);
+        f.render_widget(
+            Paragraph::new(toast.message.as_str())
+                .style(Styles::primary())
+                .wrap(Wrap { trim: true })
+                .block(block),
+            rect,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_keeps_newest_and_expires() {
+        let mut queue = ToastQueue::default();
+        for i in 0..6 {
+            queue.info(format!("failure {}", i));
+        }
+        let messages: Vec<&str> = queue.iter().map(|t| t.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            ["failure 2", "failure 3", "failure 4", "failure 5"]
+        );
+
+        queue.toasts[0].created = Instant::now() - Duration::from_secs(60);
+        queue.prune();
+        assert_eq!(queue.iter().count(), 3);
+        queue.prune();
+        assert!(!queue.is_empty());
+    }
+}
@@ -206,8 +206,222 @@ fn remove_from_path(output_mode: OutputMode) -> Result<()> {
     Ok(())
 }
 
+/// Export config and history to a zip archive (used before `--purge`)
+///
+/// Returns the number of folders that went into the archive (or would have,
+/// in audit mode).
+pub fn export_user_data(dest: &Path) -> Result<usize> {
+    let mut sources = Vec::new();
+    let config_dir = get_config_dir()?;
+    if config_dir.exists() {
+        sources.push(config_dir);
+    }
+    let history_dir = get_data_dir()?.join("history");
+    if history_dir.exists() {
+        sources.push(history_dir);
+    }
+    if sources.is_empty() {
+        return Ok(0);
+    }
+    if crate::audit::is_enabled() {
+        crate::audit::note(&format!("export user data to {}", dest.display()));
+        return Ok(sources.len());
+    }
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    compress_archive(&sources, dest)?;
+    if !dest.exists() {
+        return Err(anyhow::anyhow!(
+            "Export archive was not created: {}",
+            dest.display()
+        ));
+    }
+    Ok(sources.len())
+}
+
+#[cfg(windows)]
+fn compress_archive(sources: &[PathBuf], dest: &Path) -> Result<()> {
+    use std::process::Command;
+
+    let quote = |p: &Path| format!("'{}'", p.display().to_string().replace('\'', "''"));
+    let ps_script = format!(
+        "Compress-Archive -Path {} -DestinationPath {} -Force",
+        sources
+            .iter()
+            .map(|p| quote(p))
+            .collect::<Vec<_>>()
+            .join(","),
+        quote(dest)
+    );
+
+    let output = Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-ExecutionPolicy",
+            "Bypass",
+            "-Command",
+            &ps_script,
+        ])
+        .output()
+        .context("Failed to execute PowerShell to create the export archive")?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let error = String::from_utf8_lossy(&output.stderr);
+        Err(anyhow::anyhow!("Failed to export data: {}", error))
+    }
+}
+
+#[cfg(not(windows))]
+fn compress_archive(_sources: &[PathBuf], _dest: &Path) -> Result<()> {
+    Err(anyhow::anyhow!(
+        "Exporting data is only supported on Windows"
+    ))
+}
+
+/// Names of wole's scheduled tasks in `schtasks /Query /FO CSV /NH` output
+#[cfg(any(windows, test))]
+fn wole_task_names(csv: &str) -> Vec<String> {
+    let mut names: Vec<String> = csv
+        .lines()
+        .filter_map(|line| line.split("\",\"").next())
+        .map(|name| name.trim().trim_matches('"').to_string())
+        .filter(|name| {
+            name.split('\\')
+                .any(|part| part.to_lowercase().starts_with("wole"))
+        })
+        .collect();
+    // schtasks repeats a task once per trigger
+    names.dedup();
+    names
+}
+
+/// Delete scheduled tasks registered by wole
+#[allow(unused_variables)]
+fn remove_scheduled_tasks(output_mode: OutputMode) -> Result<()> {
+    #[cfg(windows)]
+    {
+        use std::process::Command;
+
+        let output = Command::new("schtasks")
+            .args(["/Query", "/FO", "CSV", "/NH"])
+            .output()
+            .context("Failed to query scheduled tasks")?;
+        let tasks = wole_task_names(&String::from_utf8_lossy(&output.stdout));
+
+        for task in &tasks {
+            let deleted = Command::new("schtasks")
+                .args(["/Delete", "/TN", task, "/F"])
+                .output()
+                .map(|o| o.status.success())
+                .unwrap_or(false);
+            if output_mode != OutputMode::Quiet {
+                if deleted {
+                    println!("{} Removed scheduled task {}", Theme::success("OK"), task);
+                } else {
+                    eprintln!(
+                        "{} Could not remove scheduled task {}",
+                        Theme::warning("Warning"),
+                        task
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove wole's tray/startup entries from the HKCU Run key
+#[allow(unused_variables)]
+fn remove_startup_entries(output_mode: OutputMode) -> Result<()> {
+    #[cfg(windows)]
+    {
+        use winreg::enums::{HKEY_CURRENT_USER, KEY_READ, KEY_SET_VALUE};
+        use winreg::RegKey;
+
+        let run_key = match RegKey::predef(HKEY_CURRENT_USER).open_subkey_with_flags(
+            "Software\\Microsoft\\Windows\\CurrentVersion\\Run",
+            KEY_READ | KEY_SET_VALUE,
+        ) {
+            Ok(key) => key,
+            Err(_) => return Ok(()),
+        };
+
+        let entries: Vec<String> = run_key
+            .enum_values()
+            .flatten()
+            .filter(|(name, value)| {
+                name.to_lowercase().starts_with("wole")
+                    || value.to_string().to_lowercase().contains("wole.exe")
+            })
+            .map(|(name, _)| name)
+            .collect();
+
+        for name in entries {
+            run_key
+                .delete_value(&name)
+                .with_context(|| format!("Failed to remove startup entry: {}", name))?;
+            if output_mode != OutputMode::Quiet {
+                println!("{} Removed startup entry {}", Theme::success("OK"), name);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// List what [`uninstall`] would remove, without removing anything (audit mode)
+fn audit_uninstall(
+    remove_config: bool,
+    remove_data: bool,
+    purge: bool,
+    output_mode: OutputMode,
+) -> Result<()> {
+    crate::audit::note("uninstall wole");
+    if output_mode == OutputMode::Quiet {
+        return Ok(());
+    }
+
+    println!(
+        "{} Audit mode is on - nothing will be removed. Would remove:",
+        Theme::warning("Note")
+    );
+    let exe_path = get_executable_path()?;
+    if exe_path.exists() {
+        println!("  • Executable: {}", exe_path.display());
+    }
+    println!("  • wole from PATH");
+    if purge {
+        println!("  • Scheduled tasks and startup entries registered by wole");
+    }
+    if remove_config {
+        println!("  • Config directory: {}", get_config_dir()?.display());
+    }
+    if remove_data {
+        println!("  • Data directory: {}", get_data_dir()?.display());
+    }
+    Ok(())
+}
+
 /// Uninstall wole
-pub fn uninstall(remove_config: bool, remove_data: bool, output_mode: OutputMode) -> Result<()> {
+///
+/// `purge` also removes scheduled tasks and startup entries wole registered.
+/// In audit mode ([`crate::audit`]) this only lists what would be removed.
+pub fn uninstall(
+    remove_config: bool,
+    remove_data: bool,
+    purge: bool,
+    output_mode: OutputMode,
+) -> Result<()> {
+    if crate::audit::is_enabled() {
+        return audit_uninstall(remove_config, remove_data, purge, output_mode);
+    }
+
     // Check if executable exists
     let exe_path = get_executable_path()?;
     let install_dir = get_install_dir()?;
@@ -360,6 +574,11 @@ pub fn uninstall(remove_config: bool, remove_data: bool, output_mode: OutputMode
     // Remove from PATH (always attempt, even if exe doesn't exist)
     remove_from_path(output_mode)?;
 
+    if purge {
+        remove_scheduled_tasks(output_mode)?;
+        remove_startup_entries(output_mode)?;
+    }
+
     // Remove config directory if requested
     if remove_config {
         let config_dir = get_config_dir()?;
@@ -404,9 +623,29 @@ pub fn uninstall(remove_config: bool, remove_data: bool, output_mode: OutputMode
         }
         if !remove_config && !remove_data {
             println!("Note: Config and data directories were preserved.");
-            println!("Use --config and --data flags to remove them as well.");
+            println!("Use --config and --data (or --purge) to remove them as well.");
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wole_task_names() {
+        let csv = "\"\\wole-weekly-clean\",\"N/A\",\"Ready\"\n\
+                   \"\\wole-weekly-clean\",\"1/5/2026 9:00:00 AM\",\"Ready\"\n\
+                   \"\\Microsoft\\Windows\\Defrag\\ScheduledDefrag\",\"N/A\",\"Ready\"\n\
+                   \"\\Wole\\Scan\",\"N/A\",\"Disabled\"\n";
+        assert_eq!(
+            wole_task_names(csv),
+            vec![
+                "\\wole-weekly-clean".to_string(),
+                "\\Wole\\Scan".to_string()
+            ]
+        );
+    }
+}
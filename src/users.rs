@@ -0,0 +1,427 @@
+//! Scan scope: the current user's profile only, or every profile on the machine
+//!
+//! `--scope all-users` (or `[paths] scope = "all-users"`) scans the per-user
+//! categories - package cache, temp files and old downloads - of every profile
+//! under `C:\Users`. Reading other users' profiles needs an elevated prompt.
+//! Each profile's findings are merged into the regular category results (so
+//! totals, cleaning and JSON work unchanged) and kept per user in
+//! [`UserScanResult`] for grouped display.
+
+use crate::cancel::CancellationToken;
+use crate::categories;
+use crate::cli::ScanOptions;
+use crate::config::Config;
+use crate::output::{CategoryResult, ScanResults};
+use anyhow::{bail, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Which user profiles a scan covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScanScope {
+    /// Only the profile wole runs as (%USERPROFILE%)
+    #[default]
+    CurrentUser,
+    /// Every profile under C:\Users (requires admin)
+    AllUsers,
+}
+
+impl ScanScope {
+    /// Name as accepted on the command line and in the config file
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScanScope::CurrentUser => "current",
+            ScanScope::AllUsers => "all-users",
+        }
+    }
+}
+
+impl FromStr for ScanScope {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "current" | "current-user" | "me" => Ok(ScanScope::CurrentUser),
+            "all-users" | "all" => Ok(ScanScope::AllUsers),
+            other => bail!("Unknown scope: {}. Supported: current, all-users", other),
+        }
+    }
+}
+
+/// Profile folders under C:\Users that don't belong to a real account
+const SKIPPED_PROFILES: &[&str] = &[
+    "All Users",
+    "Default",
+    "Default User",
+    "defaultuser0",
+    "Public",
+];
+
+/// A user profile folder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserProfile {
+    /// Account name (the profile folder's name)
+    pub name: String,
+    pub home: PathBuf,
+}
+
+impl UserProfile {
+    /// The profile wole is running as
+    pub fn current() -> Option<Self> {
+        let home = PathBuf::from(env::var("USERPROFILE").ok()?);
+        let name = env::var("USERNAME")
+            .ok()
+            .or_else(|| home.file_name().map(|n| n.to_string_lossy().to_string()))?;
+        Some(Self { name, home })
+    }
+
+    /// %LOCALAPPDATA% of this profile
+    pub fn local_appdata(&self) -> PathBuf {
+        self.home.join("AppData").join("Local")
+    }
+
+    pub fn downloads(&self) -> PathBuf {
+        self.home.join("Downloads")
+    }
+
+    /// Whether `home` is this profile's folder (case-insensitive, like Windows)
+    fn is_home(&self, home: &Path) -> bool {
+        crate::restore::normalize_path_for_comparison(&self.home.to_string_lossy())
+            == crate::restore::normalize_path_for_comparison(&home.to_string_lossy())
+    }
+}
+
+/// Folder holding all profiles (normally C:\Users)
+fn profiles_dir() -> Option<PathBuf> {
+    env::var("USERPROFILE")
+        .ok()
+        .and_then(|p| Path::new(&p).parent().map(Path::to_path_buf))
+        .or_else(|| {
+            env::var("SystemDrive")
+                .ok()
+                .map(|drive| PathBuf::from(format!("{}\\Users", drive)))
+        })
+}
+
+/// All real user profiles on this machine, sorted by name
+///
+/// Skips the built-in Default/Public profiles and the legacy junctions
+/// ("All Users", "Default User"), and anything without an AppData folder.
+pub fn list_profiles() -> Vec<UserProfile> {
+    let Some(dir) = profiles_dir() else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    let mut profiles: Vec<UserProfile> = entries
+        .flatten()
+        .filter(|entry| {
+            // Junctions report as symlinks here; real profiles are plain directories
+            entry
+                .file_type()
+                .map(|t| t.is_dir() && !t.is_symlink())
+                .unwrap_or(false)
+        })
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if SKIPPED_PROFILES
+                .iter()
+                .any(|skipped| skipped.eq_ignore_ascii_case(&name))
+            {
+                return None;
+            }
+            let home = entry.path();
+            if !home.join("AppData").is_dir() {
+                return None;
+            }
+            Some(UserProfile { name, home })
+        })
+        .collect();
+
+    profiles.sort_by_key(|p| p.name.to_lowercase());
+    profiles
+}
+
+/// Fail unless the process can read other users' profiles
+pub fn ensure_elevated() -> Result<()> {
+    if !crate::optimize::is_admin() {
+        bail!(
+            "--scope all-users needs administrator rights to read other users' profiles. \
+             Re-run wole from an elevated prompt."
+        );
+    }
+    Ok(())
+}
+
+/// Scope for a scan: `--scope` if given, else `[paths] scope` from the config
+///
+/// An explicit `--scope all-users` without admin rights is an error; the config
+/// setting quietly falls back to the current user instead.
+pub fn resolve_scope(cli_scope: Option<&str>, config: &Config) -> Result<ScanScope> {
+    if let Some(scope) = cli_scope {
+        let scope: ScanScope = scope.parse()?;
+        if scope == ScanScope::AllUsers {
+            ensure_elevated()?;
+        }
+        return Ok(scope);
+    }
+
+    match config.paths.scope.parse() {
+        Ok(ScanScope::AllUsers) if crate::optimize::is_admin() => Ok(ScanScope::AllUsers),
+        _ => Ok(ScanScope::CurrentUser),
+    }
+}
+
+/// Per-user share of the cache, temp and downloads categories
+#[derive(Debug, Clone, Default)]
+pub struct UserScanResult {
+    pub user: String,
+    pub home: PathBuf,
+    pub cache: CategoryResult,
+    pub temp: CategoryResult,
+    pub downloads: CategoryResult,
+}
+
+impl UserScanResult {
+    pub fn items(&self) -> usize {
+        self.cache.items + self.temp.items + self.downloads.items
+    }
+
+    pub fn size_bytes(&self) -> u64 {
+        self.cache.size_bytes + self.temp.size_bytes + self.downloads.size_bytes
+    }
+}
+
+/// Whether `options` enables any per-user category
+pub fn has_per_user_categories(options: &ScanOptions) -> bool {
+    options.cache || options.temp || options.downloads
+}
+
+/// Extend `results` with the per-user categories of every other profile
+///
+/// `results` must already hold the current user's cache/temp/downloads findings;
+/// they become the first entry of `results.users`.
+pub fn scan_other_profiles(
+    results: &mut ScanResults,
+    options: &ScanOptions,
+    config: &Config,
+    cancel: &CancellationToken,
+) -> Result<()> {
+    let current = UserProfile::current();
+    let mut users = Vec::new();
+
+    if let Some(me) = &current {
+        users.push(UserScanResult {
+            user: me.name.clone(),
+            home: me.home.clone(),
+            cache: results.cache.clone(),
+            temp: results.temp.clone(),
+            downloads: results.downloads.clone(),
+        });
+    }
+
+    for profile in list_profiles() {
+        if current.as_ref().is_some_and(|me| me.is_home(&profile.home)) {
+            continue;
+        }
+        cancel.check()?;
+
+        let mut user = UserScanResult {
+            user: profile.name.clone(),
+            home: profile.home.clone(),
+            ..Default::default()
+        };
+        if options.cache {
+            user.cache = categories::cache::scan_profile(&profile, config, cancel)?;
+        }
+        if options.temp {
+            user.temp = categories::temp::scan_profile(&profile, config, cancel)?;
+        }
+        if options.downloads {
+            user.downloads = categories::downloads::scan_profile(
+                &profile,
+                options.min_age_days,
+                config,
+                cancel,
+            )?;
+        }
+
+        absorb(&mut results.cache, &user.cache);
+        absorb(&mut results.temp, &user.temp);
+        absorb(&mut results.downloads, &user.downloads);
+        users.push(user);
+    }
+
+    results.users = users;
+    Ok(())
+}
+
+/// Account name of the owner of a file or folder (`None` if it can't be read)
+///
+/// Used to attribute `--scope all-users` results: the profile a file sits in
+/// doesn't always say who put it there (shared folders, relocated temp dirs).
+#[cfg(windows)]
+pub fn file_owner(path: &Path) -> Option<String> {
+    use std::os::windows::ffi::OsStrExt;
+    use std::ptr;
+
+    #[link(name = "advapi32")]
+    extern "system" {
+        fn GetNamedSecurityInfoW(
+            pObjectName: *const u16,
+            ObjectType: u32,
+            SecurityInfo: u32,
+            ppsidOwner: *mut *mut std::ffi::c_void,
+            ppsidGroup: *mut *mut std::ffi::c_void,
+            ppDacl: *mut *mut std::ffi::c_void,
+            ppSacl: *mut *mut std::ffi::c_void,
+            ppSecurityDescriptor: *mut *mut std::ffi::c_void,
+        ) -> u32;
+        fn LookupAccountSidW(
+            lpSystemName: *const u16,
+            Sid: *mut std::ffi::c_void,
+            Name: *mut u16,
+            cchName: *mut u32,
+            ReferencedDomainName: *mut u16,
+            cchReferencedDomainName: *mut u32,
+            peUse: *mut u32,
+        ) -> i32;
+    }
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn LocalFree(hMem: *mut std::ffi::c_void) -> *mut std::ffi::c_void;
+    }
+
+    const SE_FILE_OBJECT: u32 = 1;
+    const OWNER_SECURITY_INFORMATION: u32 = 1;
+
+    let wide: Vec<u16> = path
+        .as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+
+    unsafe {
+        let mut owner = ptr::null_mut();
+        let mut descriptor = ptr::null_mut();
+        let status = GetNamedSecurityInfoW(
+            wide.as_ptr(),
+            SE_FILE_OBJECT,
+            OWNER_SECURITY_INFORMATION,
+            &mut owner,
+            ptr::null_mut(),
+            ptr::null_mut(),
+            ptr::null_mut(),
+            &mut descriptor,
+        );
+        if status != 0 || owner.is_null() {
+            return None;
+        }
+
+        let mut name = [0u16; 256];
+        let mut domain = [0u16; 256];
+        let mut name_len = name.len() as u32;
+        let mut domain_len = domain.len() as u32;
+        let mut sid_use = 0u32;
+        let found = LookupAccountSidW(
+            ptr::null(),
+            owner,
+            name.as_mut_ptr(),
+            &mut name_len,
+            domain.as_mut_ptr(),
+            &mut domain_len,
+            &mut sid_use,
+        ) != 0;
+        // The owner SID points into the descriptor, so free it only after the lookup
+        LocalFree(descriptor);
+
+        found.then(|| String::from_utf16_lossy(&name[..name_len as usize]))
+    }
+}
+
+/// Account name of the owner of a file or folder (`None` if it can't be read)
+#[cfg(unix)]
+pub fn file_owner(path: &Path) -> Option<String> {
+    use std::os::unix::fs::MetadataExt;
+
+    let uid = fs::symlink_metadata(path).ok()?.uid();
+    let passwd = fs::read_to_string("/etc/passwd").unwrap_or_default();
+    Some(passwd_name(&passwd, uid).unwrap_or_else(|| uid.to_string()))
+}
+
+#[cfg(not(any(unix, windows)))]
+pub fn file_owner(_path: &Path) -> Option<String> {
+    None
+}
+
+/// User name for `uid` in /etc/passwd contents
+#[cfg(unix)]
+fn passwd_name(passwd: &str, uid: u32) -> Option<String> {
+    passwd.lines().find_map(|line| {
+        let mut fields = line.split(':');
+        let name = fields.next()?;
+        let line_uid: u32 = fields.nth(1)?.parse().ok()?;
+        (line_uid == uid).then(|| name.to_string())
+    })
+}
+
+/// Add one user's findings to the machine-wide category
+fn absorb(total: &mut CategoryResult, user: &CategoryResult) {
+    total.items += user.items;
+    total.size_bytes += user.size_bytes;
+    total.paths.extend(user.paths.iter().cloned());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_from_str() {
+        assert_eq!(
+            "all-users".parse::<ScanScope>().unwrap(),
+            ScanScope::AllUsers
+        );
+        assert_eq!(
+            "Current".parse::<ScanScope>().unwrap(),
+            ScanScope::CurrentUser
+        );
+        assert!("everyone".parse::<ScanScope>().is_err());
+        assert_eq!(ScanScope::AllUsers.as_str(), "all-users");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_passwd_name() {
+        let passwd =
+            "root:x:0:0:root:/root:/bin/bash\n# comment\nalice:x:1000:1000::/home/alice:/bin/sh\n";
+        assert_eq!(passwd_name(passwd, 1000).as_deref(), Some("alice"));
+        assert_eq!(passwd_name(passwd, 0).as_deref(), Some("root"));
+        assert_eq!(passwd_name(passwd, 42), None);
+    }
+
+    #[test]
+    fn test_user_result_totals() {
+        let user = UserScanResult {
+            user: "alice".to_string(),
+            home: PathBuf::from("C:\\Users\\alice"),
+            cache: CategoryResult {
+                items: 1,
+                size_bytes: 100,
+                paths: vec![PathBuf::from("C:\\Users\\alice\\AppData\\Local\\npm-cache")],
+            },
+            temp: CategoryResult {
+                items: 2,
+                size_bytes: 50,
+                paths: vec![PathBuf::from("a.tmp"), PathBuf::from("b.tmp")],
+            },
+            downloads: CategoryResult::default(),
+        };
+        assert_eq!(user.items(), 3);
+        assert_eq!(user.size_bytes(), 150);
+    }
+}
@@ -52,13 +52,28 @@ pub fn display_path(path: &Path) -> String {
     path_str
 }
 
+/// Longest path Windows accepts without the `\\?\` prefix (MAX_PATH)
+pub const MAX_PATH: usize = 260;
+
+/// Paths at least this long get the `\\?\` prefix up front
+///
+/// Directory APIs stop at MAX_PATH - 12 (room for an 8.3 file name), so this
+/// is where creating or listing inside a deep `node_modules` tree starts to fail.
+const LONG_PATH_THRESHOLD: usize = MAX_PATH - 12;
+
 /// Convert to long path format for Windows (\\?\)
 ///
 /// Windows has a default path length limit of 260 characters (MAX_PATH).
 /// The \\?\ prefix enables extended-length paths up to ~32,767 characters.
 /// This is common in deep `node_modules` directories.
+///
+/// Extended-length paths skip Win32 normalization, so the path is made
+/// absolute, `/` becomes `\`, `.`/`..` components are resolved, and UNC paths
+/// (`\\server\share`) become `\\?\UNC\server\share`.
 #[cfg(windows)]
 pub fn to_long_path(path: &Path) -> PathBuf {
+    use std::path::{Component, Prefix};
+
     // Already has long path prefix
     if let Some(s) = path.to_str() {
         if s.starts_with(r"\\?\") {
@@ -75,12 +90,40 @@ pub fn to_long_path(path: &Path) -> PathBuf {
             .unwrap_or_else(|_| path.to_path_buf())
     };
 
-    // Add long path prefix
-    if let Some(s) = absolute.to_str() {
-        PathBuf::from(format!(r"\\?\{}", s))
-    } else {
-        path.to_path_buf()
+    let mut prefix = String::new();
+    let mut parts: Vec<String> = Vec::new();
+    for component in absolute.components() {
+        match component {
+            Component::Prefix(p) => match p.kind() {
+                Prefix::UNC(server, share) => {
+                    prefix = format!(
+                        r"\\?\UNC\{}\{}",
+                        server.to_string_lossy(),
+                        share.to_string_lossy()
+                    );
+                }
+                Prefix::Disk(letter) => prefix = format!(r"\\?\{}:", letter as char),
+                // Device and verbatim prefixes are left alone
+                _ => return path.to_path_buf(),
+            },
+            Component::RootDir | Component::CurDir => {}
+            Component::ParentDir => {
+                parts.pop();
+            }
+            Component::Normal(part) => match part.to_str() {
+                Some(part) => parts.push(part.to_string()),
+                None => return path.to_path_buf(),
+            },
+        }
     }
+    if prefix.is_empty() {
+        return path.to_path_buf();
+    }
+
+    let mut long = prefix;
+    long.push('\\');
+    long.push_str(&parts.join("\\"));
+    PathBuf::from(long)
 }
 
 #[cfg(not(windows))]
@@ -88,84 +131,99 @@ pub fn to_long_path(path: &Path) -> PathBuf {
     path.to_path_buf()
 }
 
-/// Safe metadata that falls back to long path on Windows when normal access fails
+/// Whether `path` is long enough that Win32 APIs need the `\\?\` prefix
+pub fn needs_long_path(path: &Path) -> bool {
+    cfg!(windows)
+        && path.as_os_str().len() >= LONG_PATH_THRESHOLD
+        && !path.to_string_lossy().starts_with(r"\\?\")
+}
+
+/// `path` itself, or its `\\?\` form when it is too long for Win32 APIs
+pub fn long_path_if_needed(path: &Path) -> std::borrow::Cow<'_, Path> {
+    if needs_long_path(path) {
+        std::borrow::Cow::Owned(to_long_path(path))
+    } else {
+        std::borrow::Cow::Borrowed(path)
+    }
+}
+
+/// Whether an I/O error is Windows rejecting an over-long path
 ///
-/// Handles ERROR_PATH_NOT_FOUND (3) which occurs when paths exceed 260 chars
-#[cfg(windows)]
-pub fn safe_metadata(path: &Path) -> std::io::Result<std::fs::Metadata> {
-    match std::fs::metadata(path) {
-        Ok(m) => Ok(m),
-        Err(e) if e.raw_os_error() == Some(3) => {
-            // ERROR_PATH_NOT_FOUND - try with long path prefix
-            std::fs::metadata(to_long_path(path))
+/// ERROR_PATH_NOT_FOUND (3) or ERROR_FILENAME_EXCED_RANGE (206).
+fn is_path_too_long_error(e: &std::io::Error) -> bool {
+    cfg!(windows) && matches!(e.raw_os_error(), Some(3) | Some(206))
+}
+
+/// Run a filesystem operation, retrying with the long path form on Windows
+///
+/// Paths past the threshold go straight to the `\\?\` form; shorter ones fall
+/// back to it when Windows reports the path as too long or not found.
+fn with_long_path<T>(path: &Path, op: impl Fn(&Path) -> std::io::Result<T>) -> std::io::Result<T> {
+    if needs_long_path(path) {
+        return op(&to_long_path(path));
+    }
+    match op(path) {
+        Err(e) if is_path_too_long_error(&e) && path.is_absolute() => {
+            let long = to_long_path(path);
+            if long.as_path() == path {
+                Err(e)
+            } else {
+                op(&long)
+            }
         }
-        Err(e) => Err(e),
+        result => result,
     }
 }
 
-#[cfg(not(windows))]
+/// Safe metadata that falls back to long path on Windows when normal access fails
 pub fn safe_metadata(path: &Path) -> std::io::Result<std::fs::Metadata> {
-    std::fs::metadata(path)
+    with_long_path(path, |p| std::fs::metadata(p))
 }
 
 /// Safe symlink_metadata that falls back to long path on Windows
-#[cfg(windows)]
-pub fn safe_symlink_metadata(path: &Path) -> std::io::Result<std::fs::Metadata> {
-    match std::fs::symlink_metadata(path) {
-        Ok(m) => Ok(m),
-        Err(e) if e.raw_os_error() == Some(3) => std::fs::symlink_metadata(to_long_path(path)),
-        Err(e) => Err(e),
-    }
-}
-
-#[cfg(not(windows))]
 pub fn safe_symlink_metadata(path: &Path) -> std::io::Result<std::fs::Metadata> {
-    std::fs::symlink_metadata(path)
+    with_long_path(path, |p| std::fs::symlink_metadata(p))
 }
 
 /// Safe read_dir that falls back to long path on Windows
-#[cfg(windows)]
-pub fn safe_read_dir(path: &Path) -> std::io::Result<std::fs::ReadDir> {
-    match std::fs::read_dir(path) {
-        Ok(rd) => Ok(rd),
-        Err(e) if e.raw_os_error() == Some(3) => std::fs::read_dir(to_long_path(path)),
-        Err(e) => Err(e),
-    }
-}
-
-#[cfg(not(windows))]
 pub fn safe_read_dir(path: &Path) -> std::io::Result<std::fs::ReadDir> {
-    std::fs::read_dir(path)
+    with_long_path(path, |p| std::fs::read_dir(p))
 }
 
 /// Safe remove_file that uses long path on Windows
-#[cfg(windows)]
-pub fn safe_remove_file(path: &Path) -> std::io::Result<()> {
-    match std::fs::remove_file(path) {
-        Ok(()) => Ok(()),
-        Err(e) if e.raw_os_error() == Some(3) => std::fs::remove_file(to_long_path(path)),
-        Err(e) => Err(e),
-    }
-}
-
-#[cfg(not(windows))]
 pub fn safe_remove_file(path: &Path) -> std::io::Result<()> {
-    std::fs::remove_file(path)
+    with_long_path(path, |p| std::fs::remove_file(p))
 }
 
 /// Safe remove_dir_all that uses long path on Windows
-#[cfg(windows)]
 pub fn safe_remove_dir_all(path: &Path) -> std::io::Result<()> {
-    match std::fs::remove_dir_all(path) {
-        Ok(()) => Ok(()),
-        Err(e) if e.raw_os_error() == Some(3) => std::fs::remove_dir_all(to_long_path(path)),
-        Err(e) => Err(e),
-    }
+    with_long_path(path, |p| std::fs::remove_dir_all(p))
 }
 
-#[cfg(not(windows))]
-pub fn safe_remove_dir_all(path: &Path) -> std::io::Result<()> {
-    std::fs::remove_dir_all(path)
+/// Safe create_dir_all that uses long path on Windows
+pub fn safe_create_dir_all(path: &Path) -> std::io::Result<()> {
+    with_long_path(path, |p| std::fs::create_dir_all(p))
+}
+
+/// Safe rename that uses long paths on Windows (for both ends once either needs it)
+pub fn safe_rename(from: &Path, to: &Path) -> std::io::Result<()> {
+    with_long_path(from, |p| {
+        if p == from && !needs_long_path(to) {
+            std::fs::rename(p, to)
+        } else {
+            std::fs::rename(p, to_long_path(to))
+        }
+    })
+}
+
+/// `Path::exists` that also sees paths longer than MAX_PATH
+pub fn safe_exists(path: &Path) -> bool {
+    safe_metadata(path).is_ok()
+}
+
+/// `Path::is_dir` that also sees paths longer than MAX_PATH
+pub fn safe_is_dir(path: &Path) -> bool {
+    safe_metadata(path).map(|m| m.is_dir()).unwrap_or(false)
 }
 
 /// Check if entry should be skipped (symlink, junction, or reparse point)
@@ -211,61 +269,14 @@ pub fn is_windows_reparse_point(path: &Path) -> bool {
 
 /// Calculate total size of a directory tree using parallel traversal.
 ///
-/// Uses jwalk for parallel directory traversal which is 2-4x faster than sequential.
-///
-/// Optimized to:
-/// - Use parallel traversal with rayon thread pool
-/// - Skip permission-denied errors gracefully
-/// - NOT walk into .git directories
-/// - Handle symlinks and reparse points safely (don't follow)
-/// - Limit depth to prevent runaway scans
-/// - Handle Windows long paths (>260 chars) gracefully
+/// Thin wrapper over [`crate::size::dir_size`], the shared size engine:
+/// - Parallel traversal with the rayon thread pool
+/// - Skips permission-denied errors gracefully
+/// - Does NOT walk into .git directories
+/// - Doesn't follow symlinks
+/// - Limits depth to prevent runaway scans
 pub fn calculate_dir_size(path: &Path) -> u64 {
-    use jwalk::WalkDir;
-    use std::sync::atomic::{AtomicU64, Ordering};
-
-    const MAX_DEPTH: usize = 15;
-
-    let total = AtomicU64::new(0);
-
-    WalkDir::new(path)
-        .max_depth(MAX_DEPTH)
-        .follow_links(false)
-        .parallelism(jwalk::Parallelism::RayonDefaultPool {
-            busy_timeout: std::time::Duration::from_secs(1),
-        })
-        .process_read_dir(|_depth, _path, _state, children| {
-            // Skip directories we don't want to descend into
-            children.retain(|entry| {
-                if let Ok(ref e) = entry {
-                    if e.file_type().is_symlink() {
-                        return false;
-                    }
-                    if e.file_type().is_dir() {
-                        if let Some(name) = e.path().file_name() {
-                            let name_str = name.to_string_lossy();
-                            // Skip .git internals
-                            if name_str == ".git" {
-                                return false;
-                            }
-                        }
-                    }
-                }
-                true
-            });
-        })
-        .into_iter()
-        .for_each(|entry| {
-            if let Ok(e) = entry {
-                if e.file_type().is_file() {
-                    if let Ok(meta) = e.metadata() {
-                        total.fetch_add(meta.len(), Ordering::Relaxed);
-                    }
-                }
-            }
-        });
-
-    total.load(Ordering::Relaxed)
+    crate::size::dir_size(path)
 }
 
 /// Calculate directory size and emit progress for each file visited.
@@ -275,53 +286,21 @@ pub fn calculate_dir_size_with_progress<F>(path: &Path, on_path: &F) -> u64
 where
     F: Fn(&Path) + Sync,
 {
-    use jwalk::WalkDir;
-    use std::sync::atomic::{AtomicU64, Ordering};
-
-    const MAX_DEPTH: usize = 15;
-
-    let total = AtomicU64::new(0);
-
-    WalkDir::new(path)
-        .max_depth(MAX_DEPTH)
-        .follow_links(false)
-        .parallelism(jwalk::Parallelism::RayonDefaultPool {
-            busy_timeout: std::time::Duration::from_secs(1),
-        })
-        .process_read_dir(|_depth, _path, _state, children| {
-            // Skip directories we don't want to descend into
-            children.retain(|entry| {
-                if let Ok(ref e) = entry {
-                    if e.file_type().is_symlink() {
-                        return false;
-                    }
-                    if e.file_type().is_dir() {
-                        if let Some(name) = e.path().file_name() {
-                            let name_str = name.to_string_lossy();
-                            // Skip .git internals
-                            if name_str == ".git" {
-                                return false;
-                            }
-                        }
-                    }
-                }
-                true
-            });
-        })
-        .into_iter()
-        .for_each(|entry| {
-            if let Ok(e) = entry {
-                if e.file_type().is_file() {
-                    let path = e.path();
-                    on_path(&path);
-                    if let Ok(meta) = e.metadata() {
-                        total.fetch_add(meta.len(), Ordering::Relaxed);
-                    }
-                }
-            }
-        });
+    crate::size::dir_size_with_progress(path, on_path).bytes
+}
 
-    total.load(Ordering::Relaxed)
+/// Like [`calculate_dir_size_with_progress`], but gives up (returning `None`) once
+/// `cancel` is cancelled, even in the middle of a directory.
+pub fn calculate_dir_size_cancellable<F>(
+    path: &Path,
+    on_path: &F,
+    cancel: &crate::cancel::CancellationToken,
+) -> Option<u64>
+where
+    F: Fn(&Path) + Sync,
+{
+    crate::size::dir_size_with_progress_cancellable(path, on_path, Some(cancel.as_flag()))
+        .map(|totals| totals.bytes)
 }
 
 /// Fast size calculation for a single directory level (no recursion).
@@ -1303,6 +1282,45 @@ mod tests {
         }
     }
 
+    #[cfg(windows)]
+    #[test]
+    fn test_to_long_path_normalizes() {
+        assert_eq!(
+            to_long_path(Path::new(r"C:/proj/./node_modules/../src")),
+            PathBuf::from(r"\\?\C:\proj\src")
+        );
+        assert_eq!(
+            to_long_path(Path::new(r"\\nas\data\proj")),
+            PathBuf::from(r"\\?\UNC\nas\data\proj")
+        );
+    }
+
+    #[test]
+    fn test_safe_ops_on_deep_tree() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut deep = temp_dir.path().to_path_buf();
+        while deep.as_os_str().len() <= MAX_PATH + 40 {
+            deep.push("node_modules");
+        }
+        assert!(needs_long_path(&deep) == cfg!(windows));
+
+        safe_create_dir_all(&deep).unwrap();
+        let file = deep.join("index.js");
+        with_long_path(&file, |p| fs::write(p, "x")).unwrap();
+
+        assert!(safe_is_dir(&deep));
+        assert!(safe_exists(&file));
+        assert_eq!(safe_metadata(&file).unwrap().len(), 1);
+
+        let moved = deep.with_file_name("moved");
+        safe_rename(&deep, &moved).unwrap();
+        assert!(!safe_exists(&file));
+        assert!(safe_exists(&moved.join("index.js")));
+
+        safe_remove_dir_all(&temp_dir.path().join("node_modules")).unwrap();
+        assert!(!safe_exists(&moved));
+    }
+
     #[test]
     fn test_safe_metadata() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -0,0 +1,501 @@
+//! Low disk space root cause wizard (`wole why-full`)
+//!
+//! Walks through the usual suspects for a full drive one [`Step`] at a time:
+//! how full the volume is, its biggest folders, which folders grew between
+//! the last two scans, shadow copies, the hibernation and page files, and
+//! what the safe categories would free. Each step reports a one-line summary
+//! and some [`Finding`]s; [`rank`] orders the findings by size so "this is
+//! where your space went" comes first, each with the [`Action`] that gets the
+//! space back.
+
+use crate::api::{Category, Sweeper};
+use crate::config::Config;
+use crate::scan_cache::DirChange;
+use anyhow::{bail, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Top-level folders reported by the biggest folders step
+const BIGGEST_FOLDERS: usize = 5;
+
+/// Folders that grew less than this between scans aren't findings
+const GROWTH_MIN_BYTES: u64 = 100 * 1024 * 1024;
+
+/// Growing folders reported
+const GROWTH_FINDINGS: usize = 5;
+
+/// Snapshot changes looked at for growth
+const GROWTH_CANDIDATES: usize = 200;
+
+/// Findings in the ranked list
+const MAX_FINDINGS: usize = 10;
+
+/// One stage of the wizard, in the order they run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Step {
+    Volume,
+    BiggestFolders,
+    RecentGrowth,
+    ShadowCopies,
+    SystemFiles,
+    Junk,
+}
+
+impl Step {
+    pub const ALL: [Step; 6] = [
+        Step::Volume,
+        Step::BiggestFolders,
+        Step::RecentGrowth,
+        Step::ShadowCopies,
+        Step::SystemFiles,
+        Step::Junk,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Step::Volume => "Volume",
+            Step::BiggestFolders => "Biggest folders",
+            Step::RecentGrowth => "Recent growth",
+            Step::ShadowCopies => "Shadow copies",
+            Step::SystemFiles => "Hibernation & page file",
+            Step::Junk => "Junk",
+        }
+    }
+}
+
+/// What picking a finding does
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Action {
+    /// Open the folder in Disk Insights
+    Analyze { path: PathBuf },
+    /// Scan these categories and review them in the TUI
+    Clean { categories: Vec<Category> },
+    /// Cap the volume's shadow storage (deletes the oldest restore points)
+    ShrinkShadowStorage { volume: String, max_size: String },
+    /// `powercfg /hibernate off`
+    DisableHibernation,
+}
+
+impl Action {
+    pub fn describe(&self) -> String {
+        match self {
+            Action::Analyze { path } => format!("Open {} in Disk Insights", path.display()),
+            Action::Clean { categories } => format!(
+                "Review and clean {} in the TUI",
+                categories
+                    .iter()
+                    .map(|c| c.name())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Action::ShrinkShadowStorage { volume, max_size } => format!(
+                "Cap shadow storage on {} at {} (deletes the oldest restore points)",
+                volume, max_size
+            ),
+            Action::DisableHibernation => {
+                "Turn off hibernation (also turns off Fast Startup)".to_string()
+            }
+        }
+    }
+}
+
+/// Something that takes space, and how to get it back
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Finding {
+    pub step: Step,
+    pub title: String,
+    pub bytes: u64,
+    /// None when it can only be dealt with outside wole
+    pub action: Option<Action>,
+}
+
+impl Finding {
+    fn new(step: Step, title: String, bytes: u64, action: Option<Action>) -> Self {
+        Self {
+            step,
+            title,
+            bytes,
+            action,
+        }
+    }
+}
+
+/// Outcome of one step
+#[derive(Debug, Clone, Serialize)]
+pub struct StepReport {
+    pub step: Step,
+    pub summary: String,
+    pub findings: Vec<Finding>,
+}
+
+/// Root of the drive `drive` names ("D:" -> `D:\`); the system drive for None
+pub fn drive_root(drive: Option<&str>) -> Result<PathBuf> {
+    let Some(drive) = drive else {
+        return Ok(crate::recommend::system_drive_root());
+    };
+    match crate::shadow::volume_of(Path::new(drive)) {
+        Some(volume) => Ok(PathBuf::from(format!("{}\\", volume))),
+        // Mount points elsewhere are taken as they are
+        None if Path::new(drive).is_dir() => Ok(PathBuf::from(drive)),
+        None => bail!("Not a drive: {}", drive),
+    }
+}
+
+/// Run `step` for the drive at `root`
+pub fn run_step(step: Step, root: &Path, config: &Config) -> StepReport {
+    let (summary, findings) = match step {
+        Step::Volume => volume(root),
+        Step::BiggestFolders => biggest_folders(root),
+        Step::RecentGrowth => recent_growth(root),
+        Step::ShadowCopies => shadow_copies(root),
+        Step::SystemFiles => system_files(root),
+        Step::Junk => junk(config),
+    };
+    StepReport {
+        step,
+        summary,
+        findings,
+    }
+}
+
+/// Every step's findings, biggest first
+pub fn rank(reports: &[StepReport]) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = reports
+        .iter()
+        .flat_map(|report| report.findings.iter().cloned())
+        .filter(|finding| finding.bytes > 0)
+        .collect();
+    findings.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+    findings.truncate(MAX_FINDINGS);
+    findings
+}
+
+fn volume(root: &Path) -> (String, Vec<Finding>) {
+    let summary = match crate::recommend::drive_usage(root) {
+        Some(drive) => format!(
+            "{} is {}% full: {} free of {}",
+            drive.mount_point.display(),
+            drive.used_percent(),
+            bytesize::to_string(drive.available_bytes, false),
+            bytesize::to_string(drive.total_bytes, false)
+        ),
+        None => format!("Couldn't read the size of {}", root.display()),
+    };
+    (summary, Vec::new())
+}
+
+fn biggest_folders(root: &Path) -> (String, Vec<Finding>) {
+    let insights = match crate::disk_usage::scan_directory(root, 1) {
+        Ok(insights) => insights,
+        Err(e) => {
+            return (
+                format!("Couldn't walk {}: {}", root.display(), e),
+                Vec::new(),
+            )
+        }
+    };
+    let mut folders: Vec<_> = insights.root.children.iter().collect();
+    folders.sort_by(|a, b| b.size.cmp(&a.size));
+    let findings: Vec<Finding> = folders
+        .iter()
+        .take(BIGGEST_FOLDERS)
+        .map(|folder| {
+            Finding::new(
+                Step::BiggestFolders,
+                format!("Folder {}", folder.path.display()),
+                folder.size,
+                Some(Action::Analyze {
+                    path: folder.path.clone(),
+                }),
+            )
+        })
+        .collect();
+    let summary = match folders.first() {
+        Some(largest) => format!(
+            "{} in {} files; largest is {} ({})",
+            bytesize::to_string(insights.total_size, false),
+            insights.total_files,
+            largest.name,
+            bytesize::to_string(largest.size, false)
+        ),
+        None => "No folders".to_string(),
+    };
+    (summary, findings)
+}
+
+fn recent_growth(root: &Path) -> (String, Vec<Finding>) {
+    let diff = crate::scan_cache::ScanCache::open()
+        .and_then(|cache| cache.diff_latest_snapshots(GROWTH_CANDIDATES))
+        .ok()
+        .flatten();
+    let Some(diff) = diff else {
+        return (
+            "No earlier scan to compare with - scans made with `wole scan` track growth"
+                .to_string(),
+            Vec::new(),
+        );
+    };
+    let since = diff
+        .from_time
+        .with_timezone(&chrono::Local)
+        .format("%Y-%m-%d")
+        .to_string();
+    let on_drive: Vec<DirChange> = diff
+        .changes
+        .into_iter()
+        .filter(|change| change.path.starts_with(root))
+        .collect();
+    let findings: Vec<Finding> = deepest_growth(&on_drive)
+        .into_iter()
+        .map(|change| {
+            Finding::new(
+                Step::RecentGrowth,
+                format!("{} grew since {}", change.path.display(), since),
+                change.delta().unsigned_abs(),
+                Some(Action::Analyze {
+                    path: change.path.clone(),
+                }),
+            )
+        })
+        .collect();
+    let grown: u64 = findings.iter().map(|f| f.bytes).sum();
+    let summary = if findings.is_empty() {
+        format!("Nothing grew noticeably since {}", since)
+    } else {
+        format!(
+            "{} folder(s) grew by {} since {}",
+            findings.len(),
+            bytesize::to_string(grown, false),
+            since
+        )
+    };
+    (summary, findings)
+}
+
+/// Growing folders, each at the deepest level that still explains its growth
+///
+/// Snapshot sizes roll up into every ancestor, so a parent that grew only
+/// because one subfolder did is replaced by that subfolder.
+fn deepest_growth(changes: &[DirChange]) -> Vec<&DirChange> {
+    let mut growing: Vec<&DirChange> = changes
+        .iter()
+        .filter(|change| change.delta() >= GROWTH_MIN_BYTES as i64)
+        .collect();
+    growing.sort_by(|a, b| b.delta().cmp(&a.delta()).then_with(|| a.path.cmp(&b.path)));
+
+    let mut kept: Vec<&DirChange> = Vec::new();
+    for change in growing {
+        // Holds at least 90% of a kept ancestor's growth: it takes its place
+        kept.retain(|ancestor| {
+            !(change.path.starts_with(&ancestor.path)
+                && change.delta() * 10 >= ancestor.delta() * 9)
+        });
+        kept.push(change);
+    }
+    kept.truncate(GROWTH_FINDINGS);
+    kept
+}
+
+fn shadow_copies(root: &Path) -> (String, Vec<Finding>) {
+    if !cfg!(windows) {
+        return ("Only on Windows".to_string(), Vec::new());
+    }
+    if !crate::optimize::is_admin() {
+        return (
+            "Skipped - reading shadow storage needs an elevated prompt".to_string(),
+            Vec::new(),
+        );
+    }
+    let storages = crate::shadow::storage_for(&[root.to_path_buf()]);
+    let Some(storage) = storages.first() else {
+        return ("No shadow copies".to_string(), Vec::new());
+    };
+    let limit = match (storage.max_bytes, storage.max_percent) {
+        (Some(bytes), Some(percent)) => {
+            format!("{} ({:.0}%)", bytesize::to_string(bytes, false), percent)
+        }
+        _ => "unbounded".to_string(),
+    };
+    let summary = format!(
+        "{} used on {}, limit {}",
+        bytesize::to_string(storage.used_bytes, false),
+        storage.volume,
+        limit
+    );
+    let finding = Finding::new(
+        Step::ShadowCopies,
+        format!("Shadow copies (System Restore) on {}", storage.volume),
+        storage.used_bytes,
+        storage
+            .suggested_max()
+            .map(|max_size| Action::ShrinkShadowStorage {
+                volume: storage.volume.clone(),
+                max_size: max_size.to_string(),
+            }),
+    );
+    (summary, vec![finding])
+}
+
+fn system_files(root: &Path) -> (String, Vec<Finding>) {
+    if !cfg!(windows) {
+        return ("Only on Windows".to_string(), Vec::new());
+    }
+    let findings: Vec<Finding> = [
+        (
+            "hiberfil.sys",
+            "Hibernation file",
+            Some(Action::DisableHibernation),
+        ),
+        ("pagefile.sys", "Page file", None),
+        ("swapfile.sys", "Swap file", None),
+    ]
+    .into_iter()
+    .filter_map(|(file, label, action)| {
+        let path = root.join(file);
+        let bytes = std::fs::metadata(&path).ok()?.len();
+        Some(Finding::new(
+            Step::SystemFiles,
+            format!("{} ({})", label, path.display()),
+            bytes,
+            action,
+        ))
+    })
+    .collect();
+    let summary = if findings.is_empty() {
+        "None on this drive".to_string()
+    } else {
+        findings
+            .iter()
+            .map(|f| format!("{} {}", f.title, bytesize::to_string(f.bytes, false)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    (summary, findings)
+}
+
+fn junk(config: &Config) -> (String, Vec<Finding>) {
+    let report = Sweeper::builder()
+        .config(config.clone())
+        .categories(Category::SAFE)
+        .build()
+        .and_then(|sweeper| sweeper.scan());
+    let report = match report {
+        Ok(report) => report,
+        Err(e) => return (format!("Scan failed: {}", e), Vec::new()),
+    };
+    let findings: Vec<Finding> = report
+        .categories
+        .iter()
+        .filter_map(|c| {
+            let category = c.category?;
+            Some(Finding::new(
+                Step::Junk,
+                format!("{} ({} items, safe to clean)", c.name, c.items),
+                c.size_bytes,
+                Some(Action::Clean {
+                    categories: vec![category],
+                }),
+            ))
+        })
+        .collect();
+    let summary = format!(
+        "{} in {} items that are safe to clean",
+        bytesize::to_string(report.total_bytes, false),
+        report.total_items
+    );
+    (summary, findings)
+}
+
+/// Turn hibernation off, which deletes `hiberfil.sys`
+///
+/// In audit mode nothing is changed.
+pub fn disable_hibernation() -> Result<()> {
+    if crate::audit::is_enabled() {
+        crate::audit::note("powercfg /hibernate off");
+        return Ok(());
+    }
+    if !crate::optimize::is_admin() {
+        bail!("Turning off hibernation requires an elevated prompt");
+    }
+    let output = std::process::Command::new("powercfg")
+        .args(["/hibernate", "off"])
+        .output()?;
+    if !output.status.success() {
+        bail!(
+            "powercfg /hibernate off failed: {}",
+            String::from_utf8_lossy(&output.stdout).trim()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MB: u64 = 1024 * 1024;
+
+    fn change(path: &str, old_mb: u64, new_mb: u64) -> DirChange {
+        DirChange {
+            path: PathBuf::from(path),
+            old_size: old_mb * MB,
+            new_size: new_mb * MB,
+        }
+    }
+
+    #[test]
+    fn test_growth_reported_at_deepest_folder() {
+        let changes = vec![
+            change("/home/u", 1000, 3000),
+            change("/home/u/Downloads", 100, 2000),
+            change("/home/u/Videos", 500, 550),
+            change("/home/u/.cache", 0, 300),
+        ];
+        let paths: Vec<&Path> = deepest_growth(&changes)
+            .into_iter()
+            .map(|c| c.path.as_path())
+            .collect();
+        // Downloads holds 95% of the profile's growth; Videos is too small
+        assert_eq!(
+            paths,
+            vec![Path::new("/home/u/Downloads"), Path::new("/home/u/.cache")]
+        );
+    }
+
+    #[test]
+    fn test_rank_orders_findings_across_steps() {
+        let reports = vec![
+            StepReport {
+                step: Step::SystemFiles,
+                summary: String::new(),
+                findings: vec![Finding::new(
+                    Step::SystemFiles,
+                    "Hibernation file".to_string(),
+                    16 * 1024 * MB,
+                    Some(Action::DisableHibernation),
+                )],
+            },
+            StepReport {
+                step: Step::Junk,
+                summary: String::new(),
+                findings: vec![
+                    Finding::new(Step::Junk, "Temp".to_string(), 0, None),
+                    Finding::new(
+                        Step::Junk,
+                        "Cache".to_string(),
+                        20 * 1024 * MB,
+                        Some(Action::Clean {
+                            categories: vec![Category::Cache],
+                        }),
+                    ),
+                ],
+            },
+        ];
+        let ranked = rank(&reports);
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].title, "Cache");
+        assert_eq!(ranked[1].action, Some(Action::DisableHibernation));
+    }
+}
@@ -0,0 +1,100 @@
+//! Golden scans: category scans against temp-folder profiles
+//!
+//! Run with `cargo test --features testing`.
+
+use wole::api::Category;
+use wole::config::Config;
+use wole::testing::{Fixture, GoldenScan};
+
+const KB: u64 = 1024;
+
+#[test]
+fn test_downloads_only_old_files() {
+    let fixture = Fixture::new();
+    fixture
+        .download("setup.exe", 4 * KB, 90)
+        .download("report.pdf", 4 * KB, 0);
+
+    GoldenScan::new()
+        .expect(Category::Downloads, &["Downloads/setup.exe"])
+        .run(&fixture);
+}
+
+#[test]
+fn test_temp_skips_files_from_today() {
+    let fixture = Fixture::new();
+    fixture
+        .temp_file("installer.log", KB, 3)
+        .temp_file("session.lock", KB, 0);
+
+    GoldenScan::new()
+        .expect(Category::Temp, &["Temp/installer.log"])
+        .run(&fixture);
+}
+
+#[test]
+fn test_empty_folders() {
+    let fixture = Fixture::new();
+    fixture
+        .dir("Documents/old-notes")
+        .file("Documents/keep.txt", KB);
+
+    GoldenScan::new()
+        .expect(Category::Empty, &["Documents/old-notes"])
+        .run(&fixture);
+}
+
+#[test]
+fn test_large_files_at_threshold() {
+    let config = Config::default();
+    let min_size = config.thresholds.min_size_mb * 1024 * KB;
+    let fixture = Fixture::new();
+    fixture
+        .file("Videos/movie.mkv", min_size + 1)
+        .file("Videos/clip.mp4", KB);
+
+    GoldenScan::new()
+        .config(config)
+        .expect(Category::Large, &["Videos/movie.mkv"])
+        .run(&fixture);
+}
+
+#[test]
+fn test_categories_stay_apart() {
+    let fixture = Fixture::new();
+    fixture
+        .download("old.zip", 4 * KB, 90)
+        .temp_file("crash.dmp", 4 * KB, 7)
+        .file("Music/playlist.m3u", KB);
+
+    let results = GoldenScan::new()
+        .expect(Category::Downloads, &["Downloads/old.zip"])
+        .expect(Category::Temp, &["Temp/crash.dmp"])
+        .expect(Category::Empty, &[])
+        .run(&fixture);
+    // Categories that weren't asked for aren't scanned
+    assert_eq!(results.large.items, 0);
+}
+
+#[test]
+fn test_size_budget_skips_remaining_categories() {
+    let fixture = Fixture::new();
+    fixture
+        .temp_file("huge.log", 2 * 1024 * KB, 3)
+        .download("setup.exe", 4 * KB, 90);
+
+    let mut config = Config::default();
+    config.performance.scan_size_budget_mb = 1;
+    // Temp is scanned before Downloads and already found more than the budget
+    GoldenScan::new()
+        .config(config)
+        .expect(Category::Temp, &["Temp/huge.log"])
+        .expect(Category::Downloads, &[])
+        .run(&fixture);
+
+    // Without a budget both are scanned
+    GoldenScan::new()
+        .expect(Category::Temp, &["Temp/huge.log"])
+        .expect(Category::Downloads, &["Downloads/setup.exe"])
+        .run(&fixture);
+}
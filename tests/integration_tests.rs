@@ -46,9 +46,21 @@ fn test_scan_build_artifacts_inactive_project() {
         duplicates: false,
         windows_update: false,
         event_logs: false,
+        thumbnails: false,
+        crash_dumps: false,
+        logs: false,
+        game_caches: false,
+        toolchain_caches: false,
+        python_envs: false,
+        ml_models: false,
+        email: false,
+        creative_caches: false,
+        device_backups: false,
+        git_ignored: false,
         project_age_days: 14,
         min_age_days: 30,
         min_size_bytes: 100 * 1024 * 1024,
+        scope: wole::users::ScanScope::CurrentUser,
     };
 
     let config = Config::default();
@@ -94,9 +106,21 @@ fn test_scan_empty_directory() {
         duplicates: false,
         windows_update: false,
         event_logs: false,
+        thumbnails: false,
+        crash_dumps: false,
+        logs: false,
+        game_caches: false,
+        toolchain_caches: false,
+        python_envs: false,
+        ml_models: false,
+        email: false,
+        creative_caches: false,
+        device_backups: false,
+        git_ignored: false,
         project_age_days: 14,
         min_age_days: 30,
         min_size_bytes: 100 * 1024 * 1024,
+        scope: wole::users::ScanScope::CurrentUser,
     };
 
     let config = Config::default();
@@ -338,3 +362,33 @@ fn test_calculate_dir_size_nested() {
     let size = utils::calculate_dir_size(&dir);
     assert_eq!(size, 8);
 }
+
+#[test]
+fn test_print0_paths_round_trip_through_from_stdin_parser() {
+    let mut results = wole::output::ScanResults::default();
+    results.temp.paths = vec![PathBuf::from("/tmp/a b.log"), PathBuf::from("/tmp/c")];
+    results.build.paths = vec![PathBuf::from("/src/app/target")];
+    // Email is report-only and never piped on
+    results.email.paths = vec![PathBuf::from("/mail/inbox.ost")];
+
+    let mut out = Vec::new();
+    wole::output::write_paths0(&results, &mut out).unwrap();
+    assert!(out.ends_with(b"\0"));
+
+    let mut paths = wole::output::parse_path_list(&out);
+    paths.sort();
+    assert_eq!(
+        paths,
+        vec![
+            PathBuf::from("/src/app/target"),
+            PathBuf::from("/tmp/a b.log"),
+            PathBuf::from("/tmp/c"),
+        ]
+    );
+
+    // Without a NUL anywhere the list is read line by line
+    assert_eq!(
+        wole::output::parse_path_list(b"/x/one\r\n\n/x/two\n"),
+        vec![PathBuf::from("/x/one"), PathBuf::from("/x/two")]
+    );
+}
@@ -0,0 +1,811 @@
+//! Scripted end-to-end tests for the TUI
+//!
+//! These drive the real event loop through the headless harness (TestBackend +
+//! scripted keys) to catch regressions in multi-screen flows.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+use wole::tui::events::run_dashboard_action;
+use wole::tui::harness::TuiHarness;
+use wole::tui::state::{
+    AppState, CleanProfile, DashboardAction, PendingAction, ResultsRow, Screen, SidebarView,
+};
+
+/// Point config, history, cache, and user folders at a throwaway directory so tests
+/// never touch the real profile. Shared because environment variables are process-wide.
+fn sandbox_home() -> &'static PathBuf {
+    static HOME: OnceLock<PathBuf> = OnceLock::new();
+    HOME.get_or_init(|| {
+        let dir = tempfile::tempdir().unwrap().keep();
+        std::env::set_var("APPDATA", dir.join("AppData").join("Roaming"));
+        std::env::set_var("LOCALAPPDATA", dir.join("AppData").join("Local"));
+        std::env::set_var("USERPROFILE", &dir);
+        std::env::set_var("HOME", &dir);
+        // "auto" follows the machine's locale; the assertions below are English
+        let mut config = wole::config::Config::default();
+        config.ui.language = "en".to_string();
+        config.save().unwrap();
+        dir
+    })
+}
+
+fn state_with_only(category: &str) -> AppState {
+    let mut state = AppState::new();
+    for cat in &mut state.categories {
+        cat.enabled = cat.name == category;
+    }
+    state
+}
+
+#[test]
+fn test_dashboard_renders() {
+    sandbox_home();
+    let mut harness = TuiHarness::new(120, 40);
+    harness.render().unwrap();
+
+    let text = harness.screen_text();
+    assert!(text.contains("Scan"));
+    assert!(text.contains("Empty Folders"));
+}
+
+#[test]
+fn test_quit_stops_consuming_script() {
+    sandbox_home();
+    let mut harness = TuiHarness::new(120, 40);
+    harness.press(KeyCode::Char('q')).press(KeyCode::Down);
+    harness.run().unwrap();
+
+    assert_eq!(harness.pending_events(), 1);
+}
+
+#[test]
+fn test_scan_confirm_clean_flow() {
+    let home = sandbox_home();
+    let documents = home.join("Documents");
+    let empty_dir = documents.join("flow-empty-folder");
+    fs::create_dir_all(&empty_dir).unwrap();
+    fs::write(documents.join("keep.txt"), "keep").unwrap();
+
+    let mut state = state_with_only("Empty Folders");
+    state.scan_path = home.clone();
+    let mut harness = TuiHarness::with_state(120, 40, state);
+
+    // Dashboard: Enter on the first action (Scan)
+    harness.press(KeyCode::Enter);
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Results));
+    assert!(harness
+        .state()
+        .all_items
+        .iter()
+        .any(|item| item.path == empty_dir));
+
+    // Results: a just-created folder scores too low to be pre-selected, so pick it
+    let index = harness
+        .state()
+        .all_items
+        .iter()
+        .position(|item| item.path == empty_dir)
+        .unwrap();
+    harness.state_mut().selected_items.insert(index);
+    harness.press(KeyCode::Char('c'));
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Confirm { .. }));
+    // Grouped by risk tier, with the free-space projection next to it
+    assert!(harness.screen_text().contains("SAFE"));
+    assert!(harness.screen_text().contains("FREE SPACE"));
+
+    // Confirm: permanent delete (no Recycle Bin in CI sandboxes)
+    harness.press(KeyCode::Char('p'));
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Success { .. }));
+    assert!(!empty_dir.exists());
+    assert!(documents.join("keep.txt").exists());
+}
+
+#[test]
+fn test_search_screen_round_trip() {
+    sandbox_home();
+    let mut harness = TuiHarness::new(120, 40);
+
+    harness
+        .press(KeyCode::Char('/'))
+        .type_text("no-such-file-xyz");
+    harness.run().unwrap();
+    match &harness.state().screen {
+        Screen::Search { query, results, .. } => {
+            assert_eq!(query, "no-such-file-xyz");
+            assert!(results.is_empty());
+        }
+        _ => panic!("expected search screen"),
+    }
+    assert!(harness.screen_text().contains("Ctrl+R"));
+
+    harness.press(KeyCode::Esc);
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Dashboard));
+}
+
+#[test]
+fn test_search_delete_goes_through_confirm() {
+    let home = sandbox_home();
+    let file = home.join("search-delete").join("big.iso");
+    fs::create_dir_all(file.parent().unwrap()).unwrap();
+    fs::write(&file, vec![b'x'; 2048]).unwrap();
+
+    let mut state = AppState::new();
+    state.screen = Screen::Search {
+        query: "big".to_string(),
+        results: vec![wole::scan_cache::IndexedFile {
+            path: file.clone(),
+            size: 2048,
+            modified: std::time::SystemTime::now(),
+        }],
+        cursor: 0,
+        message: None,
+        index_receiver: None,
+    };
+
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness.press(KeyCode::Delete);
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Confirm { .. }));
+    let marked: Vec<_> = harness
+        .state()
+        .selected_items
+        .iter()
+        .map(|&i| &harness.state().all_items[i])
+        .map(|item| (item.path.clone(), item.category.clone()))
+        .collect();
+    assert_eq!(marked, [(file.clone(), "Search".to_string())]);
+
+    // Cancelling goes back to the search with the file untouched
+    harness.press(KeyCode::Esc);
+    harness.run().unwrap();
+    let Screen::Search { results, .. } = &harness.state().screen else {
+        panic!("Esc should return to Search");
+    };
+    assert_eq!(results.len(), 1);
+    assert!(harness.state().all_items.is_empty());
+    assert!(file.exists());
+}
+
+#[test]
+fn test_menu_profiles_share_dashboard_actions() {
+    sandbox_home();
+    let mut state = AppState::new();
+    state.apply_profile(CleanProfile::Developer);
+    let enabled: Vec<&str> = state
+        .categories
+        .iter()
+        .filter(|c| c.enabled)
+        .map(|c| c.name.as_str())
+        .collect();
+    assert!(enabled.contains(&"Build Artifacts"));
+    assert!(!enabled.contains(&"Trash"));
+
+    run_dashboard_action(&mut state, DashboardAction::Clean);
+    assert_eq!(state.pending_action, PendingAction::Clean);
+    assert_eq!(state.action_cursor, DashboardAction::Clean.index());
+    assert!(matches!(state.screen, Screen::Scanning { .. }));
+
+    // History logs name categories either way; unknown ones are ignored
+    let mut state = AppState::new();
+    let names = [
+        "temp".to_string(),
+        "Old Files".to_string(),
+        "gone".to_string(),
+    ];
+    assert_eq!(state.apply_history_categories(&names), 2);
+    for cat in &mut state.categories {
+        cat.enabled = false;
+    }
+    run_dashboard_action(&mut state, DashboardAction::Scan);
+    assert!(matches!(state.screen, Screen::Dashboard));
+    assert!(state.dashboard_message.is_some());
+}
+
+#[test]
+fn test_sidebar_switch_keeps_view_state() {
+    sandbox_home();
+    let mut harness = TuiHarness::new(120, 40);
+    harness.render().unwrap();
+    assert!(harness.screen_text().contains("Views"));
+
+    // No scan yet: Results isn't available
+    harness.press_with(KeyCode::Char('2'), KeyModifiers::ALT);
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Dashboard));
+
+    harness
+        .press_with(KeyCode::Char('6'), KeyModifiers::ALT)
+        .press(KeyCode::Char('/'))
+        .type_text("cache")
+        .press(KeyCode::Enter)
+        .press_with(KeyCode::Char('1'), KeyModifiers::ALT);
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Dashboard));
+    assert!(harness
+        .state()
+        .parked_screens
+        .contains_key(&SidebarView::History));
+
+    harness.press_with(KeyCode::Char('6'), KeyModifiers::ALT);
+    harness.run().unwrap();
+    match &harness.state().screen {
+        Screen::History { filter, .. } => assert_eq!(filter, "cache"),
+        _ => panic!("expected history screen"),
+    }
+}
+
+#[test]
+fn test_results_group_and_filter_by_owner() {
+    let home = sandbox_home();
+    let folder = home.join("owner-attribution");
+    fs::create_dir_all(&folder).unwrap();
+    let owner = wole::users::file_owner(&folder).unwrap();
+
+    // An all-users scan: the per-user breakdown is what turns owner lookups on
+    let mut results = wole::output::ScanResults::default();
+    results.empty.items = 1;
+    results.empty.paths.push(folder.clone());
+    results.users.push(wole::users::UserScanResult {
+        user: owner.clone(),
+        home: home.clone(),
+        ..Default::default()
+    });
+
+    let mut state = AppState::new();
+    state.scan_path = home.clone();
+    state.scan_results = Some(results);
+    state.flatten_results();
+    state.screen = Screen::Results;
+    assert_eq!(state.all_items[0].owner.as_deref(), Some(owner.as_str()));
+
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness.press(KeyCode::Char('g'));
+    harness.run().unwrap();
+    let folder_names: Vec<&str> = harness.state().category_groups()[0]
+        .folder_groups
+        .iter()
+        .map(|folder| folder.folder_name.as_str())
+        .collect();
+    assert_eq!(folder_names, vec![format!("👤 {}", owner).as_str()]);
+
+    harness.state_mut().search_query = "owner:someone-else".to_string();
+    assert!(!harness
+        .state()
+        .filtered_results_rows()
+        .iter()
+        .any(|row| matches!(row, wole::tui::state::ResultsRow::Item { .. })));
+    harness.state_mut().search_query = format!("owner:{}", owner.to_uppercase());
+    assert!(harness
+        .state()
+        .filtered_results_rows()
+        .iter()
+        .any(|row| matches!(row, wole::tui::state::ResultsRow::Item { .. })));
+}
+
+#[test]
+fn test_large_results_render_visible_window_from_cached_rows() {
+    let home = sandbox_home();
+    let mut results = wole::output::ScanResults::default();
+    for i in 0..3000 {
+        results
+            .temp
+            .paths
+            .push(home.join("virtual-temp").join(format!("file-{:05}.tmp", i)));
+        results
+            .empty
+            .paths
+            .push(home.join("virtual-empty").join(format!("dir-{:05}", i)));
+    }
+    results.temp.items = 3000;
+    results.empty.items = 3000;
+
+    let mut state = AppState::new();
+    state.scan_path = home.clone();
+    state.scan_results = Some(results);
+    state.flatten_results();
+    state.screen = Screen::Results;
+    for group in state.category_groups_mut() {
+        group.expanded = true;
+        for folder in &mut group.folder_groups {
+            folder.expanded = true;
+        }
+    }
+
+    // Unchanged expansion state: the same rows, not a rebuild
+    let rows = state.results_rows();
+    assert!(Arc::ptr_eq(&rows, &state.results_rows()));
+    assert!(rows.len() > 6000);
+
+    // Scrolled to the very end, the last item is drawn
+    let last = rows
+        .iter()
+        .rposition(|row| matches!(row, ResultsRow::Item { .. }))
+        .unwrap();
+    let ResultsRow::Item { item_idx, .. } = rows[last] else {
+        unreachable!()
+    };
+    let last_name = state.all_items[item_idx]
+        .path
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .to_string();
+    state.cursor = last;
+    state.scroll_offset = last;
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness.render().unwrap();
+    assert!(harness.screen_text().contains(&last_name));
+
+    // Collapsing a category invalidates the cache
+    harness.state_mut().category_groups_mut()[0].expanded = false;
+    let collapsed = harness.state().results_rows();
+    assert!(!Arc::ptr_eq(&rows, &collapsed));
+    assert!(collapsed.len() < rows.len());
+}
+
+#[test]
+fn test_confirm_model_updates_in_place() {
+    let home = sandbox_home();
+    let mut results = wole::output::ScanResults::default();
+    for i in 0..50 {
+        results
+            .temp
+            .paths
+            .push(home.join("confirm-temp").join(format!("file-{:02}.tmp", i)));
+    }
+    results.temp.items = 50;
+
+    let mut state = AppState::new();
+    state.scan_path = home.clone();
+    state.scan_results = Some(results);
+    state.flatten_results();
+    state.selected_items = (0..state.all_items.len()).collect();
+    state.cache_confirm_groups();
+    state.screen = Screen::Confirm { permanent: false };
+
+    let rows = state.confirm_rows();
+    assert!(Arc::ptr_eq(&rows, &state.confirm_rows()));
+
+    // Collapsing the only tier rebuilds the rows but keeps the groups
+    let groups = state.confirm_category_groups().as_ptr();
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness.press(KeyCode::Enter);
+    harness.run().unwrap();
+    let collapsed = harness.state().confirm_rows();
+    assert!(collapsed.len() < rows.len());
+    assert_eq!(harness.state().confirm_category_groups().as_ptr(), groups);
+}
+
+#[test]
+fn test_finder_marks_across_categories_and_confirms() {
+    let home = sandbox_home();
+    let mut results = wole::output::ScanResults::default();
+    // Fixed paths: the sandbox's random name could fuzzy-match anything
+    results.temp.paths = vec![
+        PathBuf::from("/finder/tmp/setup.log"),
+        PathBuf::from("/finder/tmp/crash.dmp"),
+    ];
+    results.temp.items = 2;
+    results.empty.paths = vec![PathBuf::from("/finder/empty/old-logs")];
+    results.empty.items = 1;
+
+    let mut state = AppState::new();
+    state.scan_path = home.clone();
+    state.scan_results = Some(results);
+    state.flatten_results();
+    state.screen = Screen::Results;
+
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness
+        .press_with(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .type_text("log");
+    harness.run().unwrap();
+    let Screen::Finder { matches, .. } = &harness.state().screen else {
+        panic!("Ctrl+F should open the finder");
+    };
+    // setup.log and old-logs, not crash.dmp
+    assert_eq!(matches.len(), 2);
+    harness.render().unwrap();
+    assert!(harness.screen_text().contains("2/3 items"));
+
+    harness.press_with(KeyCode::Char('a'), KeyModifiers::CONTROL);
+    harness.press(KeyCode::Enter);
+    harness.run().unwrap();
+    let state = harness.state();
+    assert!(matches!(state.screen, Screen::Confirm { .. }));
+    let mut selected: Vec<String> = state
+        .selected_items
+        .iter()
+        .map(|&i| {
+            state.all_items[i]
+                .path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect();
+    selected.sort();
+    assert_eq!(selected, ["old-logs", "setup.log"]);
+}
+
+#[test]
+fn test_breakdown_charts_disk_insights_folder() {
+    let home = sandbox_home();
+    let dir = home.join("breakdown");
+    fs::create_dir_all(dir.join("sub")).unwrap();
+    fs::write(dir.join("notes.txt"), vec![b'x'; 2048]).unwrap();
+    fs::write(dir.join("sub").join("photo.jpg"), vec![b'x'; 4096]).unwrap();
+
+    let mut state = AppState::new();
+    state.screen = Screen::DiskInsights {
+        insights: wole::disk_usage::scan_directory(&dir, 3).unwrap(),
+        current_path: dir.clone(),
+        cursor: 0,
+        sort_by: wole::disk_usage::SortBy::Size,
+        selected_paths: Default::default(),
+    };
+
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness.press(KeyCode::Char('b'));
+    harness.run().unwrap();
+    // The walk runs on the scan engine; wait for it
+    for _ in 0..200 {
+        harness.state_mut().poll_breakdown();
+        if matches!(
+            harness.state().screen,
+            Screen::Breakdown {
+                breakdown: Some(_),
+                ..
+            }
+        ) {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(25));
+    }
+    let Screen::Breakdown {
+        breakdown: Some(breakdown),
+        ..
+    } = &harness.state().screen
+    else {
+        panic!("B should open a finished breakdown");
+    };
+    assert_eq!(breakdown.total_files, 2);
+    assert_eq!(breakdown.by_extension[0].label, ".jpg");
+    harness.render().unwrap();
+    assert!(harness.screen_text().contains("By extension"));
+
+    harness.press(KeyCode::Tab).press(KeyCode::Esc);
+    harness.run().unwrap();
+    assert!(matches!(
+        harness.state().screen,
+        Screen::DiskInsights { .. }
+    ));
+}
+
+#[test]
+fn test_saved_view_round_trip() {
+    let home = sandbox_home();
+    let mut results = wole::output::ScanResults::default();
+    results.temp.paths = vec![
+        PathBuf::from("/views/tmp/setup.log"),
+        PathBuf::from("/views/tmp/crash.dmp"),
+    ];
+    results.temp.items = 2;
+    results.empty.paths = vec![PathBuf::from("/views/empty/old-logs")];
+    results.empty.items = 1;
+
+    let mut state = AppState::new();
+    state.scan_path = home.clone();
+    state.scan_results = Some(results);
+    state.flatten_results();
+    state.search_query = "cat:empty".to_string();
+    state.screen = Screen::Results;
+
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness
+        .press(KeyCode::Char('v'))
+        .press(KeyCode::Char('s'))
+        .type_text("empties")
+        .press(KeyCode::Enter)
+        .press(KeyCode::Esc);
+    harness.run().unwrap();
+    let view = harness.state().config.ui.saved_view("Empties").unwrap();
+    assert_eq!(view.categories, ["empty"]);
+    assert!(view.query.is_empty());
+
+    harness.state_mut().search_query.clear();
+    harness.press(KeyCode::Char('v')).press(KeyCode::Enter);
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Results));
+    assert_eq!(harness.state().search_query, "cat:empty");
+    let items = harness
+        .state()
+        .filtered_results_rows()
+        .iter()
+        .filter(|row| matches!(row, ResultsRow::Item { .. }))
+        .count();
+    assert_eq!(items, 1);
+}
+
+#[test]
+fn test_undo_selection_and_exclude() {
+    let home = sandbox_home();
+    let mut results = wole::output::ScanResults::default();
+    results.temp.paths = vec![
+        PathBuf::from("/undo/tmp/a.log"),
+        PathBuf::from("/undo/tmp/b.log"),
+        PathBuf::from("/undo/tmp/c.log"),
+    ];
+    results.temp.items = 3;
+
+    let mut state = AppState::new();
+    state.scan_path = home.clone();
+    state.scan_results = Some(results);
+    state.flatten_results();
+    state.selected_items = (0..3).collect();
+    state.screen = Screen::Results;
+
+    // Space on the category header deselects the whole category
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness.press(KeyCode::Char(' '));
+    harness.run().unwrap();
+    assert!(harness.state().selected_items.is_empty());
+
+    harness.press_with(KeyCode::Char('z'), KeyModifiers::CONTROL);
+    harness.run().unwrap();
+    assert_eq!(harness.state().selected_items.len(), 3);
+
+    harness.press_with(KeyCode::Char('y'), KeyModifiers::CONTROL);
+    harness.run().unwrap();
+    assert!(harness.state().selected_items.is_empty());
+    harness.press_with(KeyCode::Char('z'), KeyModifiers::CONTROL);
+    harness.run().unwrap();
+
+    // Exclude an item from Preview, then bring it back in place
+    let excluded = harness.state().all_items[1].path.clone();
+    harness.state_mut().screen = Screen::Preview { index: 1 };
+    harness.press(KeyCode::Char('e'));
+    harness.run().unwrap();
+    assert_eq!(harness.state().all_items.len(), 2);
+    assert_eq!(harness.state().selected_items.len(), 2);
+
+    harness.press_with(KeyCode::Char('z'), KeyModifiers::CONTROL);
+    harness.run().unwrap();
+    assert_eq!(harness.state().all_items.len(), 3);
+    assert_eq!(harness.state().all_items[1].path, excluded);
+    assert_eq!(harness.state().selected_items.len(), 3);
+}
+
+#[test]
+fn test_select_by_rule() {
+    let home = sandbox_home();
+    let mut results = wole::output::ScanResults::default();
+    results.temp.paths = vec![
+        PathBuf::from("/rules/tmp/setup.log"),
+        PathBuf::from("/rules/tmp/crash.dmp"),
+        PathBuf::from("/rules/tmp/update.LOG"),
+    ];
+    results.temp.items = 3;
+
+    let mut state = AppState::new();
+    state.scan_path = home.clone();
+    state.scan_results = Some(results);
+    state.flatten_results();
+    state.selected_items.clear();
+    state.screen = Screen::Results;
+
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness
+        .press(KeyCode::Char('f'))
+        .press(KeyCode::Down)
+        .press(KeyCode::Down)
+        .type_text(".log")
+        .press(KeyCode::Enter);
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Results));
+    let state = harness.state();
+    let mut selected: Vec<_> = state
+        .selected_items
+        .iter()
+        .map(|&i| state.all_items[i].path.clone())
+        .collect();
+    selected.sort();
+    assert_eq!(
+        selected,
+        [
+            PathBuf::from("/rules/tmp/setup.log"),
+            PathBuf::from("/rules/tmp/update.LOG")
+        ]
+    );
+
+    harness.press_with(KeyCode::Char('z'), KeyModifiers::CONTROL);
+    harness.run().unwrap();
+    assert!(harness.state().selected_items.is_empty());
+}
+
+#[test]
+fn test_compress_asks_then_runs_in_background() {
+    let home = sandbox_home();
+    let dir = home.join("compress-me");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("data.bin"), vec![b'x'; 4096]).unwrap();
+    let mut results = wole::output::ScanResults::default();
+    results.temp.paths = vec![dir.clone()];
+    results.temp.items = 1;
+
+    let mut state = AppState::new();
+    state.scan_path = home.clone();
+    state.scan_results = Some(results);
+    state.flatten_results();
+    state.selected_items = [0].into_iter().collect();
+    state.screen = Screen::Results;
+
+    // Anything but Y backs out
+    let mut harness = TuiHarness::with_state(160, 40, state);
+    harness.press(KeyCode::Char('z'));
+    harness.run().unwrap();
+    assert_eq!(harness.state().compress_confirm, std::slice::from_ref(&dir));
+    assert!(harness.screen_text().contains("Compress 1 folder(s)"));
+    harness.press(KeyCode::Char('n'));
+    harness.run().unwrap();
+    assert!(harness.state().compress_confirm.is_empty());
+    assert!(harness.state().compress_job.is_none());
+
+    harness.press(KeyCode::Char('z')).press(KeyCode::Char('y'));
+    harness.run().unwrap();
+    for _ in 0..250 {
+        harness.state_mut().poll_compress();
+        if harness.state().compress_job.is_none() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+    assert!(harness.state().compress_job.is_none());
+    let message = harness.state().results_message.clone().unwrap();
+    assert!(message.starts_with("Compressed"), "{}", message);
+    assert!(dir.join("data.bin").exists());
+}
+
+#[test]
+fn test_disk_insights_sends_marked_paths_to_confirm() {
+    let home = sandbox_home();
+    let dir = home.join("insights-clean");
+    fs::create_dir_all(dir.join("cache").join("nested")).unwrap();
+    fs::write(
+        dir.join("cache").join("nested").join("blob.bin"),
+        vec![b'x'; 4096],
+    )
+    .unwrap();
+    fs::write(dir.join("notes.txt"), vec![b'x'; 1024]).unwrap();
+
+    let mut state = AppState::new();
+    state.screen = Screen::DiskInsights {
+        insights: wole::disk_usage::scan_directory(&dir, 3).unwrap(),
+        current_path: dir.clone(),
+        cursor: 0,
+        sort_by: wole::disk_usage::SortBy::Size,
+        // The nested folder goes with its parent
+        selected_paths: [
+            dir.join("cache"),
+            dir.join("cache").join("nested"),
+            dir.join("notes.txt"),
+        ]
+        .into_iter()
+        .collect(),
+    };
+
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness.press(KeyCode::Char('c'));
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Confirm { .. }));
+    let mut marked: Vec<_> = harness
+        .state()
+        .selected_items
+        .iter()
+        .map(|&i| &harness.state().all_items[i])
+        .map(|item| (item.path.clone(), item.size_bytes, item.category.clone()))
+        .collect();
+    marked.sort();
+    assert_eq!(
+        marked,
+        [
+            (dir.join("cache"), 4096, "Disk Insights".to_string()),
+            (dir.join("notes.txt"), 1024, "Disk Insights".to_string()),
+        ]
+    );
+
+    // Cancelling goes back to Disk Insights with the marks kept
+    harness.press(KeyCode::Esc);
+    harness.run().unwrap();
+    let Screen::DiskInsights { selected_paths, .. } = &harness.state().screen else {
+        panic!("Esc should return to Disk Insights");
+    };
+    assert_eq!(selected_paths.len(), 3);
+    assert!(harness.state().all_items.is_empty());
+    assert!(dir.join("notes.txt").exists());
+}
+
+#[test]
+#[cfg(not(windows))]
+fn test_disk_writes_explains_missing_etw() {
+    let mut state = AppState::new();
+    state.open_disk_writes("C:");
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness.render().unwrap();
+    assert!(harness.screen_text().contains("Not watching C:"));
+    assert!(harness.screen_text().contains("only available on Windows"));
+
+    harness.press(KeyCode::Esc);
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Dashboard));
+}
+
+#[test]
+fn test_optimize_skips_admin_operations_without_rights() {
+    sandbox_home();
+    let mut state = AppState::new();
+    state.screen = Screen::Optimize {
+        cursor: 0,
+        selected: std::collections::HashSet::new(),
+        results: Vec::new(),
+        running: false,
+        message: None,
+        admin: false,
+    };
+    let mut harness = TuiHarness::with_state(160, 60, state);
+    // Select all, then try the admin-only Memory operation by hand
+    harness.press(KeyCode::Char('a'));
+    for _ in 0..5 {
+        harness.press(KeyCode::Down);
+    }
+    harness.press(KeyCode::Char(' '));
+    harness.run().unwrap();
+    harness.render().unwrap();
+
+    let Screen::Optimize { selected, .. } = &harness.state().screen else {
+        panic!("should stay on Optimize");
+    };
+    let mut selected: Vec<usize> = selected.iter().copied().collect();
+    selected.sort();
+    assert_eq!(selected, [0, 1, 2, 3, 9]);
+    let text = harness.screen_text();
+    assert!(text.contains("restarts Explorer"));
+    assert!(text.contains("restart wole as Administrator"));
+}
+
+#[test]
+#[cfg(not(windows))]
+fn test_net_check_lists_and_reverts() {
+    sandbox_home();
+    let mut state = AppState::new();
+    state.open_net_check();
+    let Screen::NetCheck { issues, .. } = &mut state.screen else {
+        panic!("should open Network Check");
+    };
+    issues.extend(wole::net_hygiene::parse_hosts(
+        "127.0.0.1 localhost\n10.0.0.5 login.live.com\n",
+    ));
+    let mut harness = TuiHarness::with_state(120, 40, state);
+    harness.render().unwrap();
+    assert!(harness
+        .screen_text()
+        .contains("redirects login.live.com to 10.0.0.5"));
+
+    // Reverting changes nothing here and checks again
+    harness.press(KeyCode::Char('r'));
+    harness.run().unwrap();
+    harness.render().unwrap();
+    assert!(harness.screen_text().contains("only be changed on Windows"));
+    assert!(harness.screen_text().contains("Nothing unusual found"));
+
+    harness.press(KeyCode::Esc);
+    harness.run().unwrap();
+    assert!(matches!(harness.state().screen, Screen::Dashboard));
+}